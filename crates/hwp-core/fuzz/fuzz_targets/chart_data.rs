@@ -0,0 +1,9 @@
+//! 차트 데이터 파서 / Chart data parser
+#![no_main]
+
+use hwp_core::document::bodytext::ChartData;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = ChartData::parse(data);
+});
@@ -0,0 +1,26 @@
+//! 압축과 CFB를 거치지 않고 HWP 5.0 레코드 파서를 직접 실행
+//! Feeds HWP 5.0 record streams straight to the record parsers, bypassing deflate and CFB
+#![no_main]
+
+use hwp_core::document::{DocInfo, FileHeader, Section};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // 첫 바이트로 버전을 골라 버전별 분기도 확인 / The first byte picks a version to cover version-dependent fields
+    let Some((&selector, records)) = data.split_first() else {
+        return;
+    };
+    let version = [0x0500_0000, 0x0500_0302, 0x0501_0000, 0x0501_0100][usize::from(selector & 3)];
+    let header = FileHeader {
+        signature: "HWP Document File".to_string(),
+        version,
+        document_flags: 0, // 압축하지 않음 / Uncompressed
+        license_flags: 0,
+        encrypt_version: 0,
+        kogl_country: 0,
+        reserved: Vec::new(),
+    };
+
+    let _ = DocInfo::parse(records, &header);
+    let _ = Section::parse_data(records, version);
+});
@@ -0,0 +1,11 @@
+//! 임의 바이트로 전체 파서 실행 (HWP 5.0, HWPX 자동 감지)
+//! Runs the whole parser on arbitrary bytes (HWP 5.0 and HWPX auto-detection)
+#![no_main]
+
+use hwp_core::{HwpParser, ParseOptions};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = HwpParser::new().parse(data);
+    let _ = HwpParser::with_options(ParseOptions::default().with_lenient(true)).parse(data);
+});
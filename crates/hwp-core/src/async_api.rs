@@ -0,0 +1,175 @@
+/// 비동기 파싱 모듈 (`async` 기능) / Async parsing module (`async` feature)
+///
+/// 입력은 비동기로 읽고, CPU를 쓰는 파싱과 변환은 Tokio 블로킹 풀에서 돌려 Axum/Actix 같은
+/// 서버의 비동기 작업자를 막지 않습니다. 모든 함수는 Tokio 런타임 안에서 불러야 합니다.
+///
+/// 취소는 Tokio 방식대로 퓨처를 버리거나 작업을 `abort`하면 됩니다. 읽기는 바로 멈추고,
+/// 블로킹 풀에서 이미 파싱 중인 파일은 끝까지 돌지만 결과는 버려지며 다음 파일은 시작하지
+/// 않습니다.
+///
+/// Input is read asynchronously and the CPU-bound parsing and conversion run on the Tokio
+/// blocking pool, so the async workers of servers like Axum/Actix are never blocked. Every
+/// function must be called inside a Tokio runtime.
+///
+/// Cancel the Tokio way, by dropping the future or aborting its task. Reading stops right away;
+/// a file already being parsed on the blocking pool runs to completion but its result is
+/// discarded, and no further file is started.
+///
+/// ```ignore
+/// let file = tokio::fs::File::open("document.hwpx").await?;
+/// let document = hwp_core::parse_async(file).await?;
+/// ```
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::task;
+
+#[cfg(feature = "fs")]
+use crate::batch::{self, BatchOptions, BatchReport, Format};
+use crate::document::HwpDocument;
+use crate::error::HwpError;
+use crate::parser::ParseOptions;
+use crate::HwpParser;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+/// 비동기 입력에서 문서 파싱 / Parse a document from an async reader
+///
+/// 형식(HWP/HWPX/HWPML/HWP 3.0)은 `HwpParser::parse`처럼 자동으로 감지합니다.
+/// The format (HWP/HWPX/HWPML/HWP 3.0) is detected automatically, as with `HwpParser::parse`.
+pub async fn parse_async(reader: impl AsyncRead + Unpin) -> Result<HwpDocument, HwpError> {
+    parse_async_with_options(reader, ParseOptions::default()).await
+}
+
+/// 파싱 옵션을 주고 비동기 입력에서 문서 파싱 / Parse a document from an async reader with parse options
+pub async fn parse_async_with_options(
+    mut reader: impl AsyncRead + Unpin,
+    options: ParseOptions,
+) -> Result<HwpDocument, HwpError> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).await?;
+    blocking(move || HwpParser::with_options(options).parse(&data)).await
+}
+
+/// 디렉터리의 문서를 모두 비동기로 변환 / Convert every document in a directory asynchronously
+///
+/// `batch::convert_dir`과 같은 규칙으로 변환하되 파일마다 블로킹 풀에서 처리하고, 파일
+/// 사이에서 취소할 수 있습니다. 콜백은 블로킹 풀이 아니라 이 퓨처를 돌리는 작업에서 불립니다.
+/// Converts with the same rules as `batch::convert_dir`, but handles each file on the blocking
+/// pool and can be cancelled between files. Callbacks run on the task driving this future, not
+/// on the blocking pool.
+///
+/// # Returns / 반환값
+/// 변환 결과 보고서 / Conversion report
+#[cfg(feature = "fs")]
+pub async fn convert_dir_async(
+    input_dir: impl Into<PathBuf>,
+    output_dir: impl Into<PathBuf>,
+    format: Format,
+    options: BatchOptions,
+) -> Result<BatchReport, HwpError> {
+    let (input_dir, output_dir) = (input_dir.into(), output_dir.into());
+    let items = {
+        let options = options.clone();
+        blocking(move || batch::plan(&input_dir, &output_dir, format, &options)).await?
+    };
+
+    let mut report = BatchReport::default();
+    let total = items.len();
+    for (index, item) in items.into_iter().enumerate() {
+        let (skipped, result) = {
+            let (item, options) = (item.clone(), options.clone());
+            blocking(move || {
+                if options.skip_existing && item.output.exists() {
+                    return Ok((true, Ok(())));
+                }
+                let result = batch::convert_file(&item.input, &item.output, format, &options);
+                Ok((false, result))
+            })
+            .await?
+        };
+        batch::record(&mut report, &options, (index, total), item, skipped, result);
+    }
+    Ok(report)
+}
+
+/// 블로킹 풀에서 실행 / Run on the blocking pool
+async fn blocking<T: Send + 'static>(
+    work: impl FnOnce() -> Result<T, HwpError> + Send + 'static,
+) -> Result<T, HwpError> {
+    task::spawn_blocking(work)
+        .await
+        .map_err(|error| HwpError::InternalError {
+            message: format!("Blocking task failed: {error}"),
+        })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+    use tokio::runtime::{Builder, Runtime};
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().build().unwrap()
+    }
+
+    #[test]
+    fn test_parse_async_matches_sync_parse() {
+        let bytes = DocumentBuilder::new()
+            .add_heading(1, "제목")
+            .build()
+            .to_hwpx()
+            .unwrap();
+        let document = runtime().block_on(parse_async(bytes.as_slice())).unwrap();
+        assert_eq!(
+            document.to_json().unwrap(),
+            HwpParser::new().parse(&bytes).unwrap().to_json().unwrap()
+        );
+
+        let error = runtime()
+            .block_on(parse_async(&b"not a document"[..]))
+            .unwrap_err();
+        assert!(matches!(error.kind(), HwpError::UnknownFormat));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_convert_dir_async_reports_like_convert_dir() {
+        let root =
+            std::env::temp_dir().join(format!("hwp-core-async-batch-{}", std::process::id()));
+        let (input_dir, output_dir) = (root.join("in"), root.join("out"));
+        std::fs::create_dir_all(&input_dir).unwrap();
+        let bytes = DocumentBuilder::new()
+            .add_heading(1, "제목")
+            .build()
+            .to_hwpx()
+            .unwrap();
+        std::fs::write(input_dir.join("a.hwpx"), bytes).unwrap();
+        std::fs::write(input_dir.join("b.hwp"), b"broken").unwrap();
+
+        let report = runtime()
+            .block_on(convert_dir_async(
+                &input_dir,
+                &output_dir,
+                Format::Text,
+                BatchOptions::default(),
+            ))
+            .unwrap();
+        assert_eq!(report.converted.len(), 1);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(output_dir.join("a.txt")).unwrap(),
+            "제목"
+        );
+
+        let skipped = runtime()
+            .block_on(convert_dir_async(
+                &input_dir,
+                &output_dir,
+                Format::Text,
+                BatchOptions::default().with_skip_existing(true),
+            ))
+            .unwrap();
+        assert_eq!(skipped.skipped.len(), 1);
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -0,0 +1,433 @@
+/// 일괄 변환 모듈 / Batch conversion module
+///
+/// 디렉터리 안의 HWP/HWPX/HWPML 파일을 모두 읽어 같은 상대 경로로 출력 디렉터리에 변환합니다.
+/// 파일 하나가 실패해도 멈추지 않고 보고서에 남기며, 파일마다 진행/오류 콜백을 부릅니다.
+///
+/// Converts every HWP/HWPX/HWPML file in a directory into the output directory under the same
+/// relative path. A failing file does not stop the run; it is recorded in the report, and
+/// progress/error callbacks are called for each file.
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::HwpError;
+use crate::parser::{is_template_path, ParseOptions};
+use crate::viewer::{HtmlOptions, MarkdownOptions};
+use crate::HwpParser;
+
+/// 변환할 파일 확장자 / Extensions of files to convert
+const INPUT_EXTENSIONS: [&str; 5] = ["hwp", "hwpx", "hwt", "hwtx", "hml"];
+
+/// 출력 형식 / Output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// 마크다운 (.md) / Markdown (.md)
+    Markdown,
+    /// HTML (.html)
+    Html,
+    /// JSON (.json)
+    Json,
+    /// 일반 텍스트 (.txt) / Plain text (.txt)
+    Text,
+    /// HWPX (.hwpx)
+    Hwpx,
+}
+
+impl Format {
+    /// 출력 파일 확장자 / Output file extension
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+            Format::Json => "json",
+            Format::Text => "txt",
+            Format::Hwpx => "hwpx",
+        }
+    }
+
+    /// 이름으로 형식 찾기 ("markdown"/"md", "html", "json", "text"/"txt", "hwpx")
+    /// Look up a format by name ("markdown"/"md", "html", "json", "text"/"txt", "hwpx")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Some(Format::Markdown),
+            "html" => Some(Format::Html),
+            "json" => Some(Format::Json),
+            "text" | "txt" => Some(Format::Text),
+            "hwpx" => Some(Format::Hwpx),
+            _ => None,
+        }
+    }
+}
+
+/// 파일 하나의 진행 상황 / Progress of one file
+#[derive(Debug, Clone, Copy)]
+pub struct BatchProgress<'a> {
+    /// 처리한 파일 수 (이 파일 포함) / Files processed so far, including this one
+    pub current: usize,
+    /// 전체 파일 수 / Total number of files
+    pub total: usize,
+    /// 입력 파일 / Input file
+    pub input: &'a Path,
+    /// 출력 파일 / Output file
+    pub output: &'a Path,
+    /// 이미 있는 출력이라 건너뛰었는지 / Whether it was skipped because the output already existed
+    pub skipped: bool,
+}
+
+/// 변환한 파일 / Converted file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchItem {
+    /// 입력 파일 / Input file
+    pub input: PathBuf,
+    /// 출력 파일 / Output file
+    pub output: PathBuf,
+}
+
+/// 변환하지 못한 파일 / File that could not be converted
+#[derive(Debug, Clone)]
+pub struct BatchFailure {
+    /// 입력 파일 / Input file
+    pub input: PathBuf,
+    /// 실패 이유 / Why it failed
+    pub error: HwpError,
+}
+
+/// 일괄 변환 결과 / Batch conversion report
+#[derive(Debug, Clone, Default)]
+pub struct BatchReport {
+    /// 변환한 파일 / Converted files
+    pub converted: Vec<BatchItem>,
+    /// 출력이 이미 있어 건너뛴 파일 / Files skipped because the output already existed
+    pub skipped: Vec<BatchItem>,
+    /// 실패한 파일 / Failed files
+    pub failed: Vec<BatchFailure>,
+}
+
+impl BatchReport {
+    /// 찾은 파일 수 / Number of files found
+    pub fn total(&self) -> usize {
+        self.converted.len() + self.skipped.len() + self.failed.len()
+    }
+
+    /// 실패한 파일이 없는지 확인 / Whether no file failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// 진행 콜백 / Progress callback
+pub type ProgressCallback = Arc<dyn Fn(&BatchProgress) + Send + Sync>;
+/// 오류 콜백 / Error callback
+pub type ErrorCallback = Arc<dyn Fn(&BatchFailure) + Send + Sync>;
+
+/// 일괄 변환 옵션 / Batch conversion options
+#[derive(Clone, Default)]
+pub struct BatchOptions {
+    /// 파싱 옵션 / Parse options
+    pub parse_options: ParseOptions,
+    /// 하위 디렉터리까지 변환 / Also convert files in subdirectories
+    pub recursive: bool,
+    /// 출력 파일이 이미 있으면 건너뛰기 / Skip files whose output already exists
+    pub skip_existing: bool,
+    /// 마크다운 옵션 (None이면 `HwpDocument::to_markdown_with_dir`과 같음)
+    /// Markdown options (None behaves like `HwpDocument::to_markdown_with_dir`)
+    pub markdown_options: Option<MarkdownOptions>,
+    /// HTML 옵션 / HTML options
+    pub html_options: HtmlOptions,
+    /// 파일마다 변환하거나 건너뛴 뒤 호출 / Called after each converted or skipped file
+    pub on_progress: Option<ProgressCallback>,
+    /// 파일마다 실패한 뒤 호출 / Called after each failed file
+    pub on_error: Option<ErrorCallback>,
+}
+
+impl fmt::Debug for BatchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchOptions")
+            .field("parse_options", &self.parse_options)
+            .field("recursive", &self.recursive)
+            .field("skip_existing", &self.skip_existing)
+            .field("markdown_options", &self.markdown_options)
+            .field("html_options", &self.html_options)
+            .field("on_progress", &self.on_progress.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .finish()
+    }
+}
+
+impl BatchOptions {
+    /// 파싱 옵션 설정 / Set the parse options
+    pub fn with_parse_options(mut self, parse_options: ParseOptions) -> Self {
+        self.parse_options = parse_options;
+        self
+    }
+
+    /// 하위 디렉터리 포함 여부 설정 / Set whether subdirectories are converted
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// 기존 출력 건너뛰기 여부 설정 / Set whether existing outputs are skipped
+    pub fn with_skip_existing(mut self, skip_existing: bool) -> Self {
+        self.skip_existing = skip_existing;
+        self
+    }
+
+    /// 마크다운 옵션 설정 / Set the markdown options
+    pub fn with_markdown_options(mut self, markdown_options: MarkdownOptions) -> Self {
+        self.markdown_options = Some(markdown_options);
+        self
+    }
+
+    /// HTML 옵션 설정 / Set the HTML options
+    pub fn with_html_options(mut self, html_options: HtmlOptions) -> Self {
+        self.html_options = html_options;
+        self
+    }
+
+    /// 진행 콜백 설정 / Set the progress callback
+    pub fn with_on_progress(
+        mut self,
+        on_progress: impl Fn(&BatchProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// 오류 콜백 설정 / Set the error callback
+    pub fn with_on_error(
+        mut self,
+        on_error: impl Fn(&BatchFailure) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_error = Some(Arc::new(on_error));
+        self
+    }
+}
+
+/// 디렉터리의 문서를 모두 변환 / Convert every document in a directory
+///
+/// .hwp, .hwpx, .hwt, .hwtx, .hml 파일을 이름 순서로 변환해 `output_dir` 아래 같은 상대 경로에
+/// 확장자만 바꿔 씁니다. 파일별 실패는 보고서와 `on_error`로 알리고 다음 파일로 넘어갑니다.
+/// Converts .hwp, .hwpx, .hwt, .hwtx and .hml files in name order, writing each under
+/// `output_dir` at the same relative path with the extension replaced. Per-file failures are
+/// reported through the report and `on_error`, and the run moves on to the next file.
+///
+/// # Arguments / 매개변수
+/// * `input_dir` - 입력 디렉터리 / Input directory
+/// * `output_dir` - 출력 디렉터리 (없으면 만듦) / Output directory (created when missing)
+/// * `format` - 출력 형식 / Output format
+/// * `options` - 일괄 변환 옵션 / Batch conversion options
+///
+/// # Returns / 반환값
+/// 변환 결과 보고서; 입력 디렉터리를 읽을 수 없거나 출력 디렉터리를 만들 수 없을 때만 오류
+/// Conversion report; errors only when the input directory cannot be read or the output
+/// directory cannot be created
+pub fn convert_dir(
+    input_dir: impl AsRef<Path>,
+    output_dir: impl AsRef<Path>,
+    format: Format,
+    options: &BatchOptions,
+) -> Result<BatchReport, HwpError> {
+    let items = plan(input_dir.as_ref(), output_dir.as_ref(), format, options)?;
+    let mut report = BatchReport::default();
+    let total = items.len();
+    for (index, item) in items.into_iter().enumerate() {
+        let skipped = options.skip_existing && item.output.exists();
+        let result = if skipped {
+            Ok(())
+        } else {
+            convert_file(&item.input, &item.output, format, options)
+        };
+        record(&mut report, options, (index, total), item, skipped, result);
+    }
+    Ok(report)
+}
+
+/// 변환할 파일과 출력 경로 (이름 순서, 출력 디렉터리는 만들어 둠)
+/// Files to convert with their output paths (in name order; the output directory is created)
+pub(crate) fn plan(
+    input_dir: &Path,
+    output_dir: &Path,
+    format: Format,
+    options: &BatchOptions,
+) -> Result<Vec<BatchItem>, HwpError> {
+    let mut inputs = Vec::new();
+    collect_inputs(input_dir, output_dir, options.recursive, &mut inputs)?;
+    inputs.sort();
+    std::fs::create_dir_all(output_dir).map_err(io_error)?;
+    Ok(inputs
+        .into_iter()
+        .map(|input| {
+            let relative = input.strip_prefix(input_dir).unwrap_or(&input);
+            let output = output_dir.join(relative).with_extension(format.extension());
+            BatchItem { input, output }
+        })
+        .collect())
+}
+
+/// 파일 하나의 결과를 보고서에 넣고 콜백 호출 / Add one file's result to the report and call the callbacks
+///
+/// `position`은 (0부터 센 순번, 전체 파일 수) / `position` is (zero-based index, total files)
+pub(crate) fn record(
+    report: &mut BatchReport,
+    options: &BatchOptions,
+    (index, total): (usize, usize),
+    item: BatchItem,
+    skipped: bool,
+    result: Result<(), HwpError>,
+) {
+    if let Err(error) = result {
+        let failure = BatchFailure {
+            input: item.input,
+            error,
+        };
+        if let Some(on_error) = &options.on_error {
+            on_error(&failure);
+        }
+        report.failed.push(failure);
+        return;
+    }
+
+    if let Some(on_progress) = &options.on_progress {
+        on_progress(&BatchProgress {
+            current: index + 1,
+            total,
+            input: &item.input,
+            output: &item.output,
+            skipped,
+        });
+    }
+    if skipped {
+        report.skipped.push(item);
+    } else {
+        report.converted.push(item);
+    }
+}
+
+/// 파일 하나 변환 / Convert one file
+pub(crate) fn convert_file(
+    input: &Path,
+    output: &Path,
+    format: Format,
+    options: &BatchOptions,
+) -> Result<(), HwpError> {
+    let data = std::fs::read(input).map_err(io_error)?;
+    let parse_options = options
+        .parse_options
+        .clone()
+        .with_template(is_template_path(input));
+    let document = HwpParser::with_options(parse_options).parse(&data)?;
+
+    let bytes = match format {
+        Format::Markdown => match &options.markdown_options {
+            Some(markdown_options) => document.to_markdown(markdown_options),
+            None => document.to_markdown_with_dir(None),
+        }
+        .into_bytes(),
+        Format::Html => document.to_html(&options.html_options).into_bytes(),
+        Format::Json => document.to_json()?.into_bytes(),
+        Format::Text => document.text().into_bytes(),
+        Format::Hwpx => document.to_hwpx()?,
+    };
+
+    if let Some(parent) = output.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    std::fs::write(output, bytes).map_err(io_error)
+}
+
+/// 변환할 파일 찾기 (출력 디렉터리는 건너뜀) / Find files to convert (skipping the output directory)
+fn collect_inputs(
+    dir: &Path,
+    output_dir: &Path,
+    recursive: bool,
+    inputs: &mut Vec<PathBuf>,
+) -> Result<(), HwpError> {
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            if recursive && path != output_dir {
+                collect_inputs(&path, output_dir, recursive, inputs)?;
+            }
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                INPUT_EXTENSIONS
+                    .iter()
+                    .any(|input| ext.eq_ignore_ascii_case(input))
+            })
+        {
+            inputs.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn io_error(error: std::io::Error) -> HwpError {
+    HwpError::Io(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{DocumentBuilder, ParagraphStyle};
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_convert_dir_reports_progress_and_failures() {
+        let root = std::env::temp_dir().join(format!("hwp-batch-{}", std::process::id()));
+        let input = root.join("in");
+        let output = root.join("out");
+        std::fs::create_dir_all(input.join("nested")).unwrap();
+
+        let hwpx = DocumentBuilder::new()
+            .add_paragraph("첫 문서", &ParagraphStyle::default())
+            .build()
+            .to_hwpx()
+            .unwrap();
+        std::fs::write(input.join("a.hwpx"), &hwpx).unwrap();
+        std::fs::write(input.join("nested/b.HWPX"), &hwpx).unwrap();
+        std::fs::write(input.join("broken.hwp"), b"not a document").unwrap();
+        std::fs::write(input.join("notes.txt"), b"ignored").unwrap();
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let options = BatchOptions::default()
+            .with_recursive(true)
+            .with_on_progress({
+                let progress = progress.clone();
+                move |p| {
+                    progress
+                        .lock()
+                        .unwrap()
+                        .push((p.current, p.total, p.skipped))
+                }
+            })
+            .with_on_error({
+                let errors = errors.clone();
+                move |f| errors.lock().unwrap().push(f.input.clone())
+            });
+
+        let report = convert_dir(&input, &output, Format::Text, &options).unwrap();
+        assert_eq!(report.total(), 3);
+        assert_eq!(report.converted.len(), 2);
+        assert!(!report.is_success());
+        assert_eq!(
+            std::fs::read_to_string(output.join("a.txt")).unwrap(),
+            "첫 문서"
+        );
+        assert!(output.join("nested/b.txt").exists());
+        assert_eq!(*progress.lock().unwrap(), [(1, 3, false), (3, 3, false)]);
+        assert_eq!(*errors.lock().unwrap(), [input.join("broken.hwp")]);
+
+        // 다시 돌리면 이미 있는 출력은 건너뜀 / A rerun skips existing outputs
+        let options = BatchOptions::default()
+            .with_recursive(true)
+            .with_skip_existing(true);
+        let report = convert_dir(&input, &output, Format::Text, &options).unwrap();
+        assert_eq!((report.skipped.len(), report.failed.len()), (2, 1));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
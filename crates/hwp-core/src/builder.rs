@@ -0,0 +1,546 @@
+/// 문서 생성 모듈 / Document builder module
+///
+/// 빈 문서에서 시작해 문단, 제목, 표, 이미지를 차례로 추가하여 `HwpDocument`를 만듭니다.
+/// 만들어진 문서는 HWPX 파서가 만드는 것과 같은 구조이므로 `HwpDocument::to_hwpx`로
+/// 저장하거나 마크다운/HTML 뷰어로 바로 변환할 수 있습니다.
+///
+/// Builds an `HwpDocument` from scratch by appending paragraphs, headings, tables and images.
+/// The result has the same shape as documents produced by the HWPX parser, so it can be
+/// saved with `HwpDocument::to_hwpx` or passed straight to the markdown/HTML viewers.
+use crate::document::bindata::BinaryDataItem;
+use crate::document::bodytext::list_header::{
+    LineBreak, ListHeader, ListHeaderAttribute, TextDirection, VerticalAlign,
+};
+use crate::document::bodytext::para_header::ParaHeader;
+use crate::document::bodytext::table::{
+    CellAttributes, PageBreakBehavior, Table, TableAttribute, TableAttributes, TableCell,
+    TablePadding,
+};
+use crate::document::bodytext::{CharShapeInfo, ParaTextRun, Paragraph, ParagraphRecord, Section};
+use crate::document::docinfo::para_shape::{HeaderShapeType, ParagraphAlignment};
+use crate::document::{CharShape, DocumentProperties, FileHeader, HwpDocument, ParaShape};
+use crate::types::{pt_to_hwpunit, HWPUNIT, UINT16, WORD};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+/// 기본 글자 크기 (pt) / Default font size (pt)
+const DEFAULT_FONT_SIZE: f64 = 10.0;
+/// 표 전체 너비 (HWPUNIT, A4 본문 폭) / Total table width (HWPUNIT, A4 body width)
+const TABLE_WIDTH: u32 = 42520;
+/// 표 행 높이 (HWPUNIT) / Table row height (HWPUNIT)
+const TABLE_ROW_HEIGHT: u32 = 1000;
+
+/// 문단 서식 / Paragraph style
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParagraphStyle {
+    /// 진하게 / Bold
+    pub bold: bool,
+    /// 기울임 / Italic
+    pub italic: bool,
+    /// 밑줄 / Underline
+    pub underline: bool,
+    /// 글자 크기 (pt, None이면 10pt) / Font size (pt, 10pt when None)
+    pub font_size: Option<f64>,
+    /// 문단 정렬 / Paragraph alignment
+    pub alignment: ParagraphAlignment,
+}
+
+impl Default for ParagraphStyle {
+    fn default() -> Self {
+        Self {
+            bold: false,
+            italic: false,
+            underline: false,
+            font_size: None,
+            alignment: ParagraphAlignment::Justify,
+        }
+    }
+}
+
+impl ParagraphStyle {
+    /// 진하게 설정 / Set bold
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// 기울임 설정 / Set italic
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// 밑줄 설정 / Set underline
+    pub fn with_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// 글자 크기 설정 (pt) / Set font size (pt)
+    pub fn with_font_size(mut self, font_size: f64) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// 문단 정렬 설정 / Set paragraph alignment
+    pub fn with_alignment(mut self, alignment: ParagraphAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+/// 글자 모양 키 (중복 제거용) / Char shape key (for deduplication)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CharShapeKey {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    size: i32,
+}
+
+/// 문단 모양 키 (중복 제거용) / Para shape key (for deduplication)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ParaShapeKey {
+    alignment: ParagraphAlignment,
+    /// 개요 수준 (1~7) / Outline level (1~7)
+    outline_level: Option<u8>,
+}
+
+/// 문서 생성기 / Document builder
+///
+/// # Example
+/// ```ignore
+/// use hwp_core::builder::{DocumentBuilder, ParagraphStyle};
+///
+/// let document = DocumentBuilder::new()
+///     .add_heading(1, "보고서")
+///     .add_paragraph("본문입니다.", &ParagraphStyle::default())
+///     .add_table(&[vec!["항목", "값"], vec!["매출", "100"]])
+///     .build();
+/// let bytes = document.to_hwpx()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocumentBuilder {
+    document: HwpDocument,
+    /// 빌더가 추가한 글자 모양 (키, ID) / Char shapes added by the builder (key, ID)
+    char_shape_keys: Vec<(CharShapeKey, usize)>,
+    /// 빌더가 추가한 문단 모양 (키, ID) / Para shapes added by the builder (key, ID)
+    para_shape_keys: Vec<(ParaShapeKey, usize)>,
+}
+
+impl Default for DocumentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocumentBuilder {
+    /// 빈 문서로 시작 / Start from an empty document
+    pub fn new() -> Self {
+        let mut document = HwpDocument::new(FileHeader {
+            signature: "HWP Document File".to_string(),
+            version: 0x05010000,
+            document_flags: 0,
+            license_flags: 0,
+            encrypt_version: 0,
+            kogl_country: 0,
+            reserved: vec![0; 207],
+        });
+        document.doc_info.document_properties = Some(DocumentProperties {
+            area_count: 1,
+            start_number_info: 0,
+            page_start_number: 1,
+            footnote_start_number: 1,
+            endnote_start_number: 1,
+            image_start_number: 1,
+            table_start_number: 1,
+            formula_start_number: 1,
+            list_id: 0,
+            paragraph_id: 0,
+            character_position: 0,
+        });
+        document.body_text.sections.push(Section {
+            index: 0,
+            paragraphs: Vec::new(),
+        });
+
+        let mut builder = Self {
+            document,
+            char_shape_keys: Vec::new(),
+            para_shape_keys: Vec::new(),
+        };
+        // ID 0은 기본 글자/문단 모양 / ID 0 is the default char/para shape
+        builder.char_shape_id(&ParagraphStyle::default());
+        builder.para_shape_id(ParagraphAlignment::Justify, None);
+        builder
+    }
+
+    /// 서식 파일로 새 문서 시작 / Start a new document from a template
+    ///
+    /// 서식 파일의 글꼴, 스타일, 쪽 설정, 본문 내용을 그대로 가져오고 이후 추가하는 내용은
+    /// 마지막 구역 끝에 붙습니다. 만들어진 문서는 서식 파일이 아닌 일반 문서입니다.
+    /// Copies the template's fonts, styles, page setup and body; content added afterwards is
+    /// appended to the end of the last section. The built document is a regular document,
+    /// not a template.
+    ///
+    /// # Arguments / 매개변수
+    /// * `template` - .hwt/.hwtx에서 읽은 문서 (일반 문서도 가능) / Document read from an .hwt/.hwtx file (regular documents also work)
+    pub fn from_template(template: &HwpDocument) -> Self {
+        let mut document = template.clone();
+        document.is_template = false;
+        document.warnings.clear();
+        document.profile = None;
+        if document.body_text.sections.is_empty() {
+            document.body_text.sections.push(Section {
+                index: 0,
+                paragraphs: Vec::new(),
+            });
+        }
+        let mut builder = Self {
+            document,
+            char_shape_keys: Vec::new(),
+            para_shape_keys: Vec::new(),
+        };
+        // 기본 모양(ID 0)은 서식 파일 것을 사용, 없을 때만 추가
+        // Keep the template's default shapes (ID 0) and only add them when missing
+        if builder.document.doc_info.char_shapes.is_empty() {
+            builder.char_shape_id(&ParagraphStyle::default());
+        }
+        if builder.document.doc_info.para_shapes.is_empty() {
+            builder.para_shape_id(ParagraphAlignment::Justify, None);
+        }
+        builder
+    }
+
+    /// 문단 추가 / Add a paragraph
+    pub fn add_paragraph(mut self, text: &str, style: &ParagraphStyle) -> Self {
+        let char_shape_id = self.char_shape_id(style);
+        let para_shape_id = self.para_shape_id(style.alignment, None);
+        self.push(text_paragraph(text, char_shape_id, para_shape_id));
+        self
+    }
+
+    /// 제목 추가 (개요 문단, level 1~7) / Add a heading (outline paragraph, level 1~7)
+    pub fn add_heading(mut self, level: u8, text: &str) -> Self {
+        let level = level.clamp(1, 7);
+        let style = ParagraphStyle::default()
+            .with_bold(true)
+            .with_font_size(heading_font_size(level));
+        let char_shape_id = self.char_shape_id(&style);
+        let para_shape_id = self.para_shape_id(ParagraphAlignment::Left, Some(level));
+        self.push(text_paragraph(text, char_shape_id, para_shape_id));
+        self
+    }
+
+    /// 표 추가 (행마다 셀 텍스트 목록) / Add a table (a list of cell texts per row)
+    pub fn add_table<R, S>(mut self, rows: &[R]) -> Self
+    where
+        R: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        let row_count = rows.len() as UINT16;
+        let col_count = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0) as UINT16;
+        let cell_width = TABLE_WIDTH / u32::from(col_count.max(1));
+
+        let mut cells = Vec::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            for col_index in 0..col_count as usize {
+                let text = row
+                    .as_ref()
+                    .get(col_index)
+                    .map(|s| s.as_ref())
+                    .unwrap_or("");
+                cells.push(TableCell {
+                    list_header: ListHeader {
+                        paragraph_count: 1,
+                        attribute: ListHeaderAttribute {
+                            text_direction: TextDirection::Horizontal,
+                            line_break: LineBreak::Normal,
+                            vertical_align: VerticalAlign::Top,
+                        },
+                    },
+                    cell_attributes: CellAttributes {
+                        col_address: col_index as UINT16,
+                        row_address: row_index as UINT16,
+                        col_span: 1,
+                        row_span: 1,
+                        width: HWPUNIT(cell_width),
+                        height: HWPUNIT(TABLE_ROW_HEIGHT),
+                        left_margin: 510,
+                        right_margin: 510,
+                        top_margin: 141,
+                        bottom_margin: 141,
+                        border_fill_id: 0,
+                    },
+                    paragraphs: vec![text_paragraph(text, 0, 0)],
+                });
+            }
+        }
+
+        let table = Table {
+            attributes: TableAttributes {
+                attribute: TableAttribute {
+                    page_break: PageBreakBehavior::BreakByCell,
+                    header_row_repeat: true,
+                },
+                row_count,
+                col_count,
+                cell_spacing: 0,
+                padding: TablePadding {
+                    left: 510,
+                    right: 510,
+                    top: 141,
+                    bottom: 141,
+                },
+                row_sizes: vec![],
+                border_fill_id: 0,
+                zones: vec![],
+            },
+            cells,
+        };
+
+        self.push(Paragraph {
+            para_header: ParaHeader {
+                text_char_count: 1,
+                ..Default::default()
+            },
+            records: vec![ParagraphRecord::Table { table }],
+            resolved_number: None,
+        });
+        self
+    }
+
+    /// 이미지 추가 (PNG/JPEG/GIF/BMP 바이트) / Add an image (PNG/JPEG/GIF/BMP bytes)
+    pub fn add_image(mut self, bytes: &[u8]) -> Self {
+        let items = &mut self.document.bin_data.items;
+        let index = items.len() as WORD;
+        let name = format!("image{}", index + 1);
+        items.push(BinaryDataItem {
+            index,
+            data: STANDARD.encode(bytes),
+            name: Some(name.clone()),
+        });
+
+        self.push(Paragraph {
+            para_header: ParaHeader {
+                text_char_count: 1,
+                ..Default::default()
+            },
+            records: vec![ParagraphRecord::HwpxImage {
+                binary_item_ref: name,
+                width: 0,
+                height: 0,
+                effects: Default::default(),
+                placement: None,
+                description: None,
+                caption: None,
+            }],
+            resolved_number: None,
+        });
+        self
+    }
+
+    /// 문서 완성 / Finish the document
+    pub fn build(mut self) -> HwpDocument {
+        self.document.resolve_display_texts();
+        self.document
+    }
+
+    fn push(&mut self, paragraph: Paragraph) {
+        self.document
+            .body_text
+            .sections
+            .last_mut()
+            .expect("builder documents always have a section")
+            .paragraphs
+            .push(paragraph);
+    }
+
+    /// 서식에 맞는 글자 모양 ID (없으면 추가) / Char shape ID for a style (added when missing)
+    fn char_shape_id(&mut self, style: &ParagraphStyle) -> usize {
+        let key = CharShapeKey {
+            bold: style.bold,
+            italic: style.italic,
+            underline: style.underline,
+            size: pt_to_hwpunit(style.font_size.unwrap_or(DEFAULT_FONT_SIZE)) as i32,
+        };
+        if let Some((_, id)) = self.char_shape_keys.iter().find(|(k, _)| *k == key) {
+            return *id;
+        }
+
+        let mut char_shape =
+            CharShape::parse(&[0u8; 72], 0).expect("72 bytes is a valid CharShape");
+        char_shape.base_size = key.size;
+        char_shape.attributes.bold = key.bold;
+        char_shape.attributes.italic = key.italic;
+        char_shape.attributes.underline_type = u8::from(key.underline);
+        for value in [
+            &mut char_shape.font_stretch.korean,
+            &mut char_shape.font_stretch.english,
+            &mut char_shape.font_stretch.chinese,
+            &mut char_shape.font_stretch.japanese,
+            &mut char_shape.font_stretch.other,
+            &mut char_shape.font_stretch.symbol,
+            &mut char_shape.font_stretch.user,
+            &mut char_shape.relative_size.korean,
+            &mut char_shape.relative_size.english,
+            &mut char_shape.relative_size.chinese,
+            &mut char_shape.relative_size.japanese,
+            &mut char_shape.relative_size.other,
+            &mut char_shape.relative_size.symbol,
+            &mut char_shape.relative_size.user,
+        ] {
+            *value = 100;
+        }
+
+        let id = self.document.doc_info.char_shapes.len();
+        self.document.doc_info.char_shapes.push(char_shape);
+        self.char_shape_keys.push((key, id));
+        id
+    }
+
+    /// 정렬/개요 수준에 맞는 문단 모양 ID (없으면 추가) / Para shape ID for alignment/outline level (added when missing)
+    fn para_shape_id(&mut self, alignment: ParagraphAlignment, outline_level: Option<u8>) -> usize {
+        let key = ParaShapeKey {
+            alignment,
+            outline_level,
+        };
+        if let Some((_, id)) = self.para_shape_keys.iter().find(|(k, _)| *k == key) {
+            return *id;
+        }
+
+        let mut para_shape =
+            ParaShape::parse(&[0u8; 54], 0x05010000).expect("54 bytes is a valid ParaShape");
+        para_shape.attributes1.align = alignment;
+        if let Some(level) = outline_level {
+            para_shape.attributes1.header_shape_type = HeaderShapeType::Outline;
+            para_shape.attributes1.paragraph_level = level - 1;
+        }
+
+        let id = self.document.doc_info.para_shapes.len();
+        self.document.doc_info.para_shapes.push(para_shape);
+        self.para_shape_keys.push((key, id));
+        id
+    }
+}
+
+/// 제목 수준별 글자 크기 (pt) / Font size per heading level (pt)
+fn heading_font_size(level: u8) -> f64 {
+    match level {
+        1 => 16.0,
+        2 => 14.0,
+        3 => 12.0,
+        _ => 11.0,
+    }
+}
+
+/// 글자/문단 모양을 지정한 텍스트 문단 / Text paragraph with char/para shape IDs
+fn text_paragraph(text: &str, char_shape_id: usize, para_shape_id: usize) -> Paragraph {
+    Paragraph {
+        para_header: ParaHeader {
+            text_char_count: text.chars().count() as u32,
+            para_shape_id: para_shape_id as UINT16,
+            char_shape_count: 1,
+            ..Default::default()
+        },
+        records: vec![
+            ParagraphRecord::ParaText {
+                text: text.to_string(),
+                runs: vec![ParaTextRun::Text {
+                    text: text.to_string(),
+                }],
+                control_char_positions: vec![],
+                inline_control_params: vec![],
+            },
+            ParagraphRecord::ParaCharShape {
+                shapes: vec![CharShapeInfo {
+                    position: 0,
+                    shape_id: char_shape_id as u32,
+                }],
+            },
+        ],
+        resolved_number: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_deduplicates_shapes() {
+        let bold = ParagraphStyle::default().with_bold(true);
+        let document = DocumentBuilder::new()
+            .add_paragraph("하나", &bold)
+            .add_paragraph("둘", &bold)
+            .add_paragraph("셋", &ParagraphStyle::default())
+            .add_heading(1, "제목")
+            .build();
+
+        // 기본, 진하게, 제목 / default, bold, heading
+        assert_eq!(document.doc_info.char_shapes.len(), 3);
+        assert_eq!(document.doc_info.para_shapes.len(), 2);
+        let heading = &document.body_text.sections[0].paragraphs[3];
+        let para_shape = &document.doc_info.para_shapes[heading.para_header.para_shape_id as usize];
+        assert_eq!(
+            para_shape.attributes1.header_shape_type,
+            HeaderShapeType::Outline
+        );
+        assert_eq!(para_shape.attributes1.paragraph_level, 0);
+    }
+
+    #[test]
+    fn test_builder_table_and_image() {
+        let document = DocumentBuilder::new()
+            .add_table(&[vec!["항목", "값"], vec!["매출"]])
+            .add_image(&[0x89, b'P', b'N', b'G'])
+            .build();
+
+        let paragraphs = &document.body_text.sections[0].paragraphs;
+        let ParagraphRecord::Table { table } = &paragraphs[0].records[0] else {
+            panic!("expected table");
+        };
+        assert_eq!(table.attributes.row_count, 2);
+        assert_eq!(table.attributes.col_count, 2);
+        assert_eq!(table.cells.len(), 4);
+        assert_eq!(table.cells[2].paragraphs[0].text(), "매출");
+        assert_eq!(table.cells[3].paragraphs[0].text(), "");
+
+        assert!(matches!(
+            &paragraphs[1].records[0],
+            ParagraphRecord::HwpxImage { binary_item_ref, .. } if binary_item_ref == "image1"
+        ));
+        assert_eq!(document.bin_data.items[0].name.as_deref(), Some("image1"));
+    }
+
+    #[test]
+    fn test_builder_from_template() {
+        let mut template = DocumentBuilder::new()
+            .add_heading(1, "양식 제목")
+            .add_paragraph("굵게", &ParagraphStyle::default().with_bold(true))
+            .build();
+        template.is_template = true;
+        let template_shapes = template.doc_info.char_shapes.len();
+
+        let document = DocumentBuilder::from_template(&template)
+            .add_paragraph("작성 내용", &ParagraphStyle::default().with_italic(true))
+            .build();
+
+        assert!(!document.is_template);
+        let paragraphs = &document.body_text.sections[0].paragraphs;
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[0].text(), "양식 제목");
+        assert_eq!(paragraphs[2].text(), "작성 내용");
+
+        // 새 모양은 서식 파일 모양 뒤에 추가 / New shapes are appended after the template's
+        assert_eq!(document.doc_info.char_shapes.len(), template_shapes + 1);
+        let ParagraphRecord::ParaCharShape { shapes } = &paragraphs[2].records[1] else {
+            panic!("expected char shapes");
+        };
+        assert_eq!(shapes[0].shape_id as usize, template_shapes);
+        assert!(
+            document.doc_info.char_shapes[template_shapes]
+                .attributes
+                .italic
+        );
+    }
+}
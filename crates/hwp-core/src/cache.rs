@@ -0,0 +1,467 @@
+/// 내용 해시 캐시 모듈 (`cache` 기능) / Content-hash cache module (`cache` feature)
+///
+/// 입력 바이트의 SHA-256 해시를 키로 파싱 결과와 변환 결과를 저장해, 같은 문서를 여러 번
+/// 미리보기하는 서비스가 매번 다시 파싱하지 않게 합니다. 저장소는 `CacheStore`로 바꿀 수
+/// 있으며 메모리(`MemoryStore`)와 디렉터리(`DirectoryStore`, `fs` 기능) 구현을 제공합니다.
+/// 파싱 결과는 문서 JSON으로 저장했다가 `HwpDocument::from_json`으로 되살리고, JSON에 없는
+/// 원본 파트(`HwpDocument::raw_parts`)는 따로 저장합니다.
+///
+/// Stores parse results and converted outputs keyed by the SHA-256 hash of the input bytes, so
+/// a service previewing the same document many times does not parse it again each time. The
+/// storage is pluggable through `CacheStore`, with in-memory (`MemoryStore`) and directory
+/// (`DirectoryStore`, `fs` feature) implementations. Parse results are stored as document JSON
+/// and restored with `HwpDocument::from_json`; the raw parts JSON leaves out
+/// (`HwpDocument::raw_parts`) are stored in a separate entry.
+///
+/// ```ignore
+/// let cache = DocumentCache::new(Arc::new(MemoryStore::new()));
+/// let markdown = cache.convert(&bytes, "markdown", |document| {
+///     Ok(to_markdown(document, &options))
+/// })?;
+/// ```
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+
+use crate::document::HwpDocument;
+use crate::error::HwpError;
+use crate::parser::ParseOptions;
+use crate::HwpParser;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+/// 캐시 저장소 / Cache store
+///
+/// 키는 영숫자, `-`, `.`만 쓰므로 파일 이름이나 객체 저장소 키로 그대로 쓸 수 있습니다.
+/// Keys only use alphanumerics, `-` and `.`, so they can be used as file names or object
+/// storage keys as-is.
+pub trait CacheStore: std::fmt::Debug + Send + Sync {
+    /// 키로 값 읽기 (없으면 `None`) / Read a value by key (`None` when missing)
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, HwpError>;
+
+    /// 키에 값 저장 (있으면 덮어씀) / Store a value under a key (overwrites an existing one)
+    fn put(&self, key: &str, value: &[u8]) -> Result<(), HwpError>;
+}
+
+/// 메모리 캐시 저장소 / In-memory cache store
+///
+/// 항목을 지우지 않으므로 오래 도는 프로세스에서는 `clear`로 비우거나 크기 제한이 있는
+/// 저장소를 직접 구현하세요.
+/// Entries are never evicted, so long-running processes should empty it with `clear` or
+/// implement a bounded store.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryStore {
+    /// 빈 저장소 만들기 / Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 저장된 항목 수 / Number of stored entries
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// 비어 있는지 여부 / Whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// 모든 항목 지우기 / Remove every entry
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        // 다른 스레드가 패닉해도 맵은 온전함 / The map stays consistent even if another thread panicked
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, HwpError> {
+        Ok(self.lock().get(key).cloned())
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> Result<(), HwpError> {
+        self.lock().insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+}
+
+/// 디렉터리 캐시 저장소 (키마다 파일 하나) / Directory cache store (one file per key)
+///
+/// 임시 파일에 쓴 뒤 이름을 바꾸므로 여러 프로세스가 같은 디렉터리를 나눠 써도 반쯤 쓴
+/// 항목을 읽지 않습니다.
+/// Writes go to a temporary file that is then renamed, so processes sharing the directory never
+/// read a half-written entry.
+#[cfg(feature = "fs")]
+#[derive(Debug, Clone)]
+pub struct DirectoryStore {
+    dir: PathBuf,
+}
+
+#[cfg(feature = "fs")]
+impl DirectoryStore {
+    /// 디렉터리로 저장소 만들기 (없으면 처음 저장할 때 생성) / Create a store for a directory (created on the first put)
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[cfg(feature = "fs")]
+impl CacheStore for DirectoryStore {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, HwpError> {
+        let path = self.dir.join(key);
+        match std::fs::read(&path) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(HwpError::Io(format!(
+                "Failed to read cache entry '{}': {e}",
+                path.display()
+            ))),
+        }
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> Result<(), HwpError> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| {
+            HwpError::Io(format!(
+                "Failed to create directory '{}': {e}",
+                self.dir.display()
+            ))
+        })?;
+        let path = self.dir.join(key);
+        let temp = self.dir.join(format!(".{key}.{}.tmp", std::process::id()));
+        std::fs::write(&temp, value)
+            .and_then(|()| std::fs::rename(&temp, &path))
+            .map_err(|e| {
+                std::fs::remove_file(&temp).ok();
+                HwpError::Io(format!(
+                    "Failed to write cache entry '{}': {e}",
+                    path.display()
+                ))
+            })
+    }
+}
+
+/// 내용 해시로 파싱/변환 결과를 기억하는 캐시 / Cache memoizing parse and conversion results by content hash
+#[derive(Debug, Clone)]
+pub struct DocumentCache {
+    store: Arc<dyn CacheStore>,
+    parse_options: ParseOptions,
+}
+
+impl DocumentCache {
+    /// 저장소로 캐시 만들기 / Create a cache over a store
+    pub fn new(store: Arc<dyn CacheStore>) -> Self {
+        Self {
+            store,
+            parse_options: ParseOptions::default(),
+        }
+    }
+
+    /// 파싱 옵션 설정 (기본값: `ParseOptions::default()`) / Set the parse options (default: `ParseOptions::default()`)
+    ///
+    /// 결과를 바꾸는 옵션(XML 복구, 관대한 파싱, 서식 파일, 자원 한도)은 파싱과 변환 결과의
+    /// 키에 들어가므로 옵션이 다른 캐시가 같은 저장소를 나눠 써도 됩니다. 한도가 엄격한 캐시가
+    /// 한도 없이 파싱한 결과를 되살리지 않습니다.
+    /// Options that change the result (XML repair, lenient parsing, template, resource limits)
+    /// are part of the parse and conversion keys, so caches with different options can share a
+    /// store. A cache with strict limits never restores a result parsed without them.
+    pub fn with_parse_options(mut self, parse_options: ParseOptions) -> Self {
+        self.parse_options = parse_options;
+        self
+    }
+
+    /// 저장소 / The underlying store
+    pub fn store(&self) -> &Arc<dyn CacheStore> {
+        &self.store
+    }
+
+    /// 문서 파싱 (캐시에 있으면 되살림) / Parse a document (restored from the cache when present)
+    ///
+    /// 파싱 오류는 캐시하지 않습니다. 저장된 JSON을 읽을 수 없으면(이전 버전이 쓴 항목 등)
+    /// 다시 파싱해 덮어씁니다. 되살린 문서도 원본 파트와 `resource_limits`를 갖습니다.
+    /// Parse errors are not cached. When the stored JSON cannot be read (e.g. an entry written
+    /// by an older version), the document is parsed again and the entry overwritten. Restored
+    /// documents keep their raw parts and `resource_limits` too.
+    pub fn parse(&self, data: &[u8]) -> Result<HwpDocument, HwpError> {
+        let hash = content_hash(data);
+        let key = self.document_key(&hash);
+        if let Some(cached) = self.store.get(&key)? {
+            if let Some(document) = self.restore(&hash, &cached)? {
+                return Ok(document);
+            }
+        }
+        let document = HwpParser::with_options(self.parse_options.clone()).parse(data)?;
+        // 문서 항목보다 먼저 써서 문서가 있으면 원본 파트도 있게 함
+        // Written before the document entry, so a stored document always has its raw parts
+        if !document.unparsed_parts.is_empty() {
+            let parts: BTreeMap<&str, String> = document
+                .unparsed_parts
+                .iter()
+                .map(|(path, data)| (path.as_str(), STANDARD.encode(data)))
+                .collect();
+            let parts = serde_json::to_vec(&parts).map_err(|e| HwpError::EncodingError {
+                reason: format!("Failed to serialize raw parts: {e}"),
+            })?;
+            self.store.put(&self.parts_key(&hash), &parts)?;
+        }
+        self.store.put(&key, document.to_json()?.as_bytes())?;
+        Ok(document)
+    }
+
+    /// 저장된 문서 되살리기 (읽을 수 없으면 `None`) / Restore a stored document (`None` when unreadable)
+    fn restore(&self, hash: &str, cached: &[u8]) -> Result<Option<HwpDocument>, HwpError> {
+        let Some(mut document) = std::str::from_utf8(cached)
+            .ok()
+            .and_then(|json| HwpDocument::from_json(json).ok())
+        else {
+            return Ok(None);
+        };
+        if let Some(parts) = self.store.get(&self.parts_key(hash))? {
+            let parts = serde_json::from_slice::<BTreeMap<String, String>>(&parts)
+                .ok()
+                .and_then(|parts| {
+                    parts
+                        .into_iter()
+                        .map(|(path, data)| Some((path, STANDARD.decode(data).ok()?)))
+                        .collect::<Option<BTreeMap<_, _>>>()
+                });
+            match parts {
+                Some(parts) => document.unparsed_parts = parts,
+                None => return Ok(None),
+            }
+        }
+        document.resource_limits = self.parse_options.limits;
+        Ok(Some(document))
+    }
+
+    /// 변환 결과 캐시 / Cache a converted output
+    ///
+    /// `output`은 형식과 옵션을 구분하는 이름으로, 옵션을 바꾸면 이름도 바꿔야 합니다. 파싱
+    /// 옵션은 키에 따로 들어갑니다. 캐시에 없을 때만 문서를 (역시 캐시를 거쳐) 파싱하고
+    /// `convert`를 부릅니다.
+    /// `output` names the format and options, and must change when the options do; the parse
+    /// options are added to the key separately. The document is parsed (through the cache as
+    /// well) and `convert` called only on a miss.
+    ///
+    /// # Arguments / 매개변수
+    /// * `data` - 문서 바이트 / Document bytes
+    /// * `output` - 출력 이름 (예: "markdown", "html-a11y") / Output name (e.g. "markdown", "html-a11y")
+    /// * `convert` - 문서를 문자열로 바꾸는 함수 / Function turning the document into a string
+    pub fn convert(
+        &self,
+        data: &[u8],
+        output: &str,
+        convert: impl FnOnce(&HwpDocument) -> Result<String, HwpError>,
+    ) -> Result<String, HwpError> {
+        let key = format!(
+            "{}-{}-{}",
+            content_hash(data),
+            self.options_key(),
+            sanitize_key(output)
+        );
+        if let Some(cached) = self.store.get(&key)? {
+            if let Ok(text) = String::from_utf8(cached) {
+                return Ok(text);
+            }
+        }
+        let text = convert(&self.parse(data)?)?;
+        self.store.put(&key, text.as_bytes())?;
+        Ok(text)
+    }
+
+    /// 파싱 결과 키 / Parse result key
+    fn document_key(&self, hash: &str) -> String {
+        format!("{hash}-document-{}.json", self.options_key())
+    }
+
+    /// 원본 파트 키 / Raw parts key
+    fn parts_key(&self, hash: &str) -> String {
+        format!("{hash}-parts-{}.json", self.options_key())
+    }
+
+    /// 결과를 바꾸는 옵션 (XML 복구, 관대한 파싱, 서식 파일, 자원 한도)
+    /// Options that change the result (XML repair, lenient parsing, template, resource limits)
+    fn options_key(&self) -> String {
+        let ParseOptions {
+            repair_xml,
+            lenient,
+            template,
+            limits,
+            ..
+        } = self.parse_options;
+        format!(
+            "{}{}{}-{:x}.{:x}.{:x}.{:x}",
+            u8::from(repair_xml),
+            u8::from(lenient),
+            u8::from(template),
+            limits.max_entry_size,
+            limits.max_total_size,
+            limits.max_entry_count,
+            limits.max_xml_depth
+        )
+    }
+}
+
+/// 입력 바이트의 SHA-256 해시 (소문자 16진수) / SHA-256 hash of the input bytes (lowercase hex)
+pub fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// 키에 쓸 수 없는 문자를 `_`로 바꿈 / Replace characters not allowed in keys with `_`
+fn sanitize_key(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+    use crate::parser::{ResourceLimit, ResourceLimits};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn sample() -> Vec<u8> {
+        DocumentBuilder::new()
+            .add_heading(1, "제목")
+            .add_heading(2, "본문")
+            .build()
+            .to_hwpx()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_content_hash_is_sha256_hex() {
+        assert_eq!(
+            content_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_convert_hit_the_store() {
+        let store = Arc::new(MemoryStore::new());
+        let cache = DocumentCache::new(store.clone());
+        let bytes = sample();
+
+        // 문서 JSON과 원본 파트 / Document JSON and raw parts
+        let parsed = cache.parse(&bytes).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(
+            cache.parse(&bytes).unwrap().to_json().unwrap(),
+            parsed.to_json().unwrap()
+        );
+
+        let calls = AtomicUsize::new(0);
+        let convert = |document: &HwpDocument| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(document.text())
+        };
+        let first = cache.convert(&bytes, "text", convert).unwrap();
+        let second = cache.convert(&bytes, "text", convert).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(store.len(), 3);
+
+        // 옵션이 다르면 따로 저장 / Different options are stored separately
+        DocumentCache::new(store.clone())
+            .with_parse_options(ParseOptions::default().with_lenient(true))
+            .parse(&bytes)
+            .unwrap();
+        assert_eq!(store.len(), 5);
+
+        assert!(cache.parse(b"not a document").is_err());
+        assert_eq!(store.len(), 5);
+    }
+
+    #[test]
+    fn test_limits_are_not_bypassed_through_a_shared_store() {
+        let store = Arc::new(MemoryStore::new());
+        let bytes = sample();
+        let unlimited = DocumentCache::new(store.clone())
+            .with_parse_options(ParseOptions::default().with_limits(ResourceLimits::unlimited()));
+        unlimited.parse(&bytes).unwrap();
+        unlimited
+            .convert(&bytes, "text", |document| Ok(document.text()))
+            .unwrap();
+
+        let strict = DocumentCache::new(store).with_parse_options(
+            ParseOptions::default().with_limits(ResourceLimits::default().with_max_entry_size(16)),
+        );
+        let limit_exceeded = |error: HwpError| {
+            matches!(
+                error.kind(),
+                HwpError::ResourceLimitExceeded {
+                    limit: ResourceLimit::EntrySize,
+                    ..
+                }
+            )
+        };
+        assert!(strict.parse(&bytes).is_err_and(limit_exceeded));
+        assert!(strict
+            .convert(&bytes, "text", |document| Ok(document.text()))
+            .is_err_and(limit_exceeded));
+    }
+
+    #[test]
+    fn test_hit_keeps_raw_parts_and_limits() {
+        let store = Arc::new(MemoryStore::new());
+        let limits = ResourceLimits::default().with_max_xml_depth(64);
+        let cache = DocumentCache::new(store)
+            .with_parse_options(ParseOptions::default().with_limits(limits));
+        let bytes = sample();
+
+        let parsed = cache.parse(&bytes).unwrap();
+        assert!(!parsed.unparsed_parts.is_empty());
+        let restored = cache.parse(&bytes).unwrap();
+        assert_eq!(restored.unparsed_parts, parsed.unparsed_parts);
+        assert_eq!(restored.resource_limits, limits);
+    }
+
+    #[test]
+    fn test_unreadable_entry_is_replaced() {
+        let store = Arc::new(MemoryStore::new());
+        let cache = DocumentCache::new(store.clone());
+        let bytes = sample();
+        let key = cache.document_key(&content_hash(&bytes));
+        store.put(&key, b"{ broken").unwrap();
+
+        cache.parse(&bytes).unwrap();
+        let stored = store.get(&key).unwrap().unwrap();
+        assert!(HwpDocument::from_json(std::str::from_utf8(&stored).unwrap()).is_ok());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_directory_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!("hwp-core-cache-{}", std::process::id()));
+        let store = DirectoryStore::new(&dir);
+        assert_eq!(store.get("missing").unwrap(), None);
+        store.put("entry.txt", b"first").unwrap();
+        store.put("entry.txt", b"second").unwrap();
+        assert_eq!(store.get("entry.txt").unwrap().unwrap(), b"second");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -23,7 +23,7 @@ impl CfbParser {
     /// Parsed CompoundFile structure / 파싱된 CompoundFile 구조체
     pub fn parse(data: &[u8]) -> Result<CompoundFile<Cursor<&[u8]>>, HwpError> {
         let cursor = Cursor::new(data);
-        CompoundFile::open(cursor).map_err(|e| HwpError::CfbParse(e.to_string()))
+        CompoundFile::open(cursor).map_err(|e| HwpError::CfbParse(e.to_string()).caused_by(e))
     }
 
     /// Read a stream from CFB structure (root level)
@@ -44,9 +44,9 @@ impl CfbParser {
         match cfb.open_stream(stream_name) {
             Ok(mut stream) => {
                 let mut buffer = Vec::new();
-                stream
-                    .read_to_end(&mut buffer)
-                    .map_err(|e| HwpError::stream_read_error(stream_name, e.to_string()))?;
+                stream.read_to_end(&mut buffer).map_err(|e| {
+                    HwpError::stream_read_error(stream_name, e.to_string()).caused_by(e)
+                })?;
                 Ok(buffer)
             }
             Err(_) => {
@@ -342,7 +342,7 @@ impl CfbParser {
                 let mut buffer = Vec::new();
                 stream
                     .read_to_end(&mut buffer)
-                    .map_err(|e| HwpError::stream_read_error(&path, e.to_string()))?;
+                    .map_err(|e| HwpError::stream_read_error(&path, e.to_string()).caused_by(e))?;
                 Ok(buffer)
             }
             Err(_) => {
@@ -352,9 +352,9 @@ impl CfbParser {
                 match cfb.open_stream(&root_path) {
                     Ok(mut stream) => {
                         let mut buffer = Vec::new();
-                        stream
-                            .read_to_end(&mut buffer)
-                            .map_err(|e| HwpError::stream_read_error(&root_path, e.to_string()))?;
+                        stream.read_to_end(&mut buffer).map_err(|e| {
+                            HwpError::stream_read_error(&root_path, e.to_string()).caused_by(e)
+                        })?;
                         Ok(buffer)
                     }
                     Err(e) => Err(HwpError::stream_not_found(
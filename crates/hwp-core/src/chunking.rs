@@ -0,0 +1,542 @@
+/// 검색 증강 생성(RAG)용 텍스트 나누기 모듈 / Text chunking module for retrieval-augmented generation
+///
+/// 마크다운 출력을 잘라 쓰면 문단과 표의 경계를 잃으므로, 문서 트리에서 바로 문단은 문장으로, 표는
+/// 행으로 나눈 뒤 최대 글자 수/토큰 수 안에서 묶습니다. 한 덩어리에 들어가는 문단과 표는 나누지
+/// 않고, 덩어리마다 원본 문단 위치를 붙입니다. 머리말과 꼬리말은 페이지마다 되풀이되는 내용이라
+/// 넣지 않습니다.
+///
+/// Chunking the markdown output loses paragraph and table boundaries, so this splits the document
+/// tree directly (paragraphs into sentences, tables into rows) and packs the pieces within the
+/// maximum characters/tokens. Paragraphs and tables that fit in one chunk are never split, and
+/// each chunk carries the source paragraph locations. Headers and footers repeat on every page and
+/// are left out.
+use crate::document::bodytext::{Paragraph, Table, TableCell};
+use crate::document::{CtrlHeader, CtrlId, HwpDocument, ParagraphLocation};
+use crate::types::UINT16;
+use crate::viewer::core::visitor::{walk_document, DocumentVisitor};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 나누기 옵션 / Chunking options
+#[derive(Debug, Clone)]
+pub struct ChunkOptions {
+    /// 덩어리 최대 글자 수 / Maximum characters per chunk
+    pub max_chars: usize,
+    /// 덩어리 최대 토큰 수 (공백으로 나뉜 어절 수, None이면 제한 없음)
+    /// Maximum tokens per chunk (whitespace-separated words, None for no limit)
+    pub max_tokens: Option<usize>,
+    /// 앞 덩어리 끝에서 다시 가져올 최대 글자 수 (문장 단위) / Maximum characters repeated from the end of the previous chunk (whole sentences)
+    pub overlap: usize,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            max_chars: 1000,
+            max_tokens: None,
+            overlap: 0,
+        }
+    }
+}
+
+impl ChunkOptions {
+    /// 최대 글자 수 설정 / Set the maximum characters per chunk
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = max_chars;
+        self
+    }
+
+    /// 최대 토큰 수 설정 / Set the maximum tokens per chunk
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// 겹침 글자 수 설정 / Set the overlap in characters
+    pub fn with_overlap(mut self, overlap: usize) -> Self {
+        self.overlap = overlap;
+        self
+    }
+}
+
+/// 덩어리의 원본 위치 / Source location of a chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkAnchor {
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+    /// 이 위치의 문단에 든 표에서 온 텍스트인지 / Whether the text comes from a table in the paragraph at this location
+    pub in_table: bool,
+}
+
+/// 텍스트 덩어리 하나 / A single text chunk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Chunk {
+    pub text: String,
+    /// 글자 수 / Number of characters
+    pub char_count: usize,
+    /// 토큰 수 (공백으로 나뉜 어절 수) / Number of tokens (whitespace-separated words)
+    pub token_count: usize,
+    /// 덩어리에 든 원본 위치 (문서 순서, 중복 없음) / Source locations in the chunk (document order, unique)
+    pub anchors: Vec<ChunkAnchor>,
+}
+
+/// 문서를 덩어리로 나누기 / Split a document into chunks
+///
+/// # Arguments / 매개변수
+/// * `document` - 나눌 문서 / Document to split
+/// * `options` - 나누기 옵션 / Chunking options
+///
+/// # Returns / 반환값
+/// 문서 순서대로의 덩어리 (빈 문서면 빈 목록) / Chunks in document order (empty for an empty document)
+pub fn chunk_document(document: &HwpDocument, options: &ChunkOptions) -> Vec<Chunk> {
+    let mut collector = BlockCollector::default();
+    walk_document(document, &mut collector);
+
+    let limits = Limits {
+        max_chars: options.max_chars.max(1),
+        max_tokens: options.max_tokens.map(|max| max.max(1)),
+    };
+    let mut packer = Packer {
+        limits,
+        overlap: options.overlap,
+        chunks: Vec::new(),
+        pieces: Vec::new(),
+        seeded: 0,
+    };
+    for block in collector.blocks {
+        packer.push_block(block);
+    }
+    packer.flush();
+    packer.chunks
+}
+
+/// 문단 하나나 표 하나 / One paragraph or one table
+struct Block {
+    anchor: ChunkAnchor,
+    /// 문장 또는 표 행 / Sentences or table rows
+    pieces: Vec<String>,
+}
+
+/// 문서 순서대로 블록을 모으는 방문자 / Visitor collecting blocks in document order
+#[derive(Default)]
+struct BlockCollector<'a> {
+    blocks: Vec<Block>,
+    /// 현재 최상위 문단 (구역, 문단)과 그 중첩 문단 / Current top-level (section, paragraph) and its nested paragraphs
+    location: ParagraphLocation,
+    nested: Vec<&'a Paragraph>,
+    /// 열린 문단의 중첩 인덱스 / Nested indices of the open paragraphs
+    paragraphs: Vec<Option<usize>>,
+    /// 바깥 표의 셀 텍스트 ((행, 열) → 텍스트) / Cell texts of the outermost table ((row, column) → text)
+    cells: BTreeMap<(UINT16, UINT16), String>,
+    cell: Option<(UINT16, UINT16)>,
+    tables: usize,
+}
+
+impl BlockCollector<'_> {
+    fn anchor(&self, nested_index: Option<usize>, in_table: bool) -> ChunkAnchor {
+        ChunkAnchor {
+            location: self.location.nested(nested_index),
+            in_table,
+        }
+    }
+}
+
+impl<'a> DocumentVisitor<'a> for BlockCollector<'a> {
+    fn enter_paragraph(
+        &mut self,
+        paragraph: &'a Paragraph,
+        location: Option<(usize, usize)>,
+    ) -> bool {
+        let nested_index = match location {
+            Some((section_index, paragraph_index)) => {
+                self.location = ParagraphLocation::top_level(section_index, paragraph_index);
+                self.nested = paragraph.nested_paragraphs();
+                None
+            }
+            None => self
+                .nested
+                .iter()
+                .position(|nested| std::ptr::eq(*nested, paragraph)),
+        };
+        let text = paragraph.text();
+        let text = text.trim();
+        if self.tables > 0 {
+            // 셀 안의 문단 (안쪽 표 포함)은 셀 텍스트로 / Paragraphs in a cell (including inner tables) go into the cell text
+            if let (Some(cell), false) = (self.cell, text.is_empty()) {
+                let cell_text = self.cells.entry(cell).or_default();
+                if !cell_text.is_empty() {
+                    cell_text.push(' ');
+                }
+                cell_text.push_str(text);
+            }
+        } else if !text.is_empty() {
+            self.blocks.push(Block {
+                anchor: self.anchor(nested_index, false),
+                pieces: split_sentences(text),
+            });
+        }
+        self.paragraphs.push(nested_index);
+        true
+    }
+
+    fn leave_paragraph(&mut self, _paragraph: &'a Paragraph, _location: Option<(usize, usize)>) {
+        self.paragraphs.pop();
+    }
+
+    fn enter_control(&mut self, header: &'a CtrlHeader) -> bool {
+        // 머리말/꼬리말은 넣지 않음 / Headers and footers are left out
+        header.ctrl_id != CtrlId::HEADER && header.ctrl_id != CtrlId::FOOTER
+    }
+
+    fn enter_table(&mut self, _table: &'a Table) -> bool {
+        self.tables += 1;
+        true
+    }
+
+    fn leave_table(&mut self, _table: &'a Table) {
+        self.tables -= 1;
+        if self.tables > 0 {
+            return;
+        }
+        let mut rows: BTreeMap<UINT16, Vec<String>> = BTreeMap::new();
+        for ((row, _), text) in std::mem::take(&mut self.cells) {
+            rows.entry(row).or_default().push(text);
+        }
+        let pieces: Vec<String> = rows
+            .into_values()
+            .map(|cells| cells.join(" | "))
+            .filter(|row| {
+                !row.trim_matches(|c: char| c == '|' || c.is_whitespace())
+                    .is_empty()
+            })
+            .collect();
+        if !pieces.is_empty() {
+            // 표를 담은 문단의 위치 / Location of the paragraph holding the table
+            let anchor = self.anchor(self.paragraphs.last().copied().flatten(), true);
+            self.blocks.push(Block { anchor, pieces });
+        }
+    }
+
+    fn enter_cell(&mut self, cell: &'a TableCell) -> bool {
+        // 안쪽 표의 셀은 바깥 셀에 합침 / Cells of inner tables are merged into the outer cell
+        if self.tables == 1 {
+            let address = (
+                cell.cell_attributes.row_address,
+                cell.cell_attributes.col_address,
+            );
+            self.cells.entry(address).or_default();
+            self.cell = Some(address);
+        }
+        true
+    }
+}
+
+/// 문단을 문장으로 나눔 (앞뒤 공백은 버림) / Split a paragraph into sentences (surrounding whitespace dropped)
+fn split_sentences(text: &str) -> Vec<String> {
+    const TERMINATORS: &[char] = &['.', '?', '!', '。', '？', '！', '…'];
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let ends = c == '\n'
+            || (TERMINATORS.contains(&c)
+                && chars.peek().is_some_and(|(_, next)| next.is_whitespace()));
+        if !ends {
+            continue;
+        }
+        while let Some(&(_, next)) = chars.peek() {
+            if !next.is_whitespace() {
+                break;
+            }
+            chars.next();
+        }
+        let end = chars.peek().map_or(text.len(), |&(index, _)| index);
+        sentences.push(text[start..end].trim());
+        start = end;
+    }
+    sentences.push(text[start..].trim());
+    sentences
+        .into_iter()
+        .filter(|sentence| !sentence.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 덩어리 크기 제한 / Chunk size limits
+#[derive(Clone, Copy)]
+struct Limits {
+    max_chars: usize,
+    max_tokens: Option<usize>,
+}
+
+impl Limits {
+    fn fits(&self, chars: usize, tokens: usize) -> bool {
+        chars <= self.max_chars && !matches!(self.max_tokens, Some(max) if tokens > max)
+    }
+
+    /// 한도를 넘는 조각을 어절 경계에서 (어절도 넘으면 글자에서) 자름
+    /// Cut a piece over the limits at word boundaries (or at characters for an oversized word)
+    fn split(&self, text: &str) -> Vec<String> {
+        let mut parts = Vec::new();
+        let mut current = String::new();
+        for word in text.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current} {word}")
+            };
+            if self.fits(char_count(&candidate), token_count(&candidate)) {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+            }
+            if self.fits(char_count(word), token_count(word)) {
+                current = word.to_string();
+            } else {
+                let chars: Vec<char> = word.chars().collect();
+                let mut pieces = chars.chunks(self.max_chars);
+                let last = pieces.next_back();
+                parts.extend(pieces.map(|piece| piece.iter().collect::<String>()));
+                current = last.map(|piece| piece.iter().collect()).unwrap_or_default();
+            }
+        }
+        if !current.is_empty() {
+            parts.push(current);
+        }
+        parts
+    }
+}
+
+/// 덩어리에 넣은 조각 / A piece placed in a chunk
+struct Piece {
+    text: String,
+    anchor: ChunkAnchor,
+    /// 블록 번호 (같은 블록의 문장은 공백으로 이어짐) / Block number (sentences of one block join with a space)
+    block: usize,
+}
+
+/// 조각을 덩어리로 묶는 상태 / State packing pieces into chunks
+struct Packer {
+    limits: Limits,
+    overlap: usize,
+    chunks: Vec<Chunk>,
+    /// 현재 덩어리의 조각 / Pieces of the current chunk
+    pieces: Vec<Piece>,
+    /// 앞 덩어리에서 겹쳐 가져온 앞쪽 조각 수 / Number of leading pieces repeated from the previous chunk
+    seeded: usize,
+}
+
+impl Packer {
+    fn push_block(&mut self, block: Block) {
+        let index = self.chunks.len() + self.pieces.len();
+        let in_table = block.anchor.in_table;
+        let whole = block.pieces.join(if in_table { "\n" } else { " " });
+        let fits_alone = self.limits.fits(char_count(&whole), token_count(&whole));
+
+        if fits_alone {
+            // 한 덩어리에 들어가는 블록은 나누지 않음 / A block that fits in one chunk is never split
+            let candidate = self.text_with(&whole, index);
+            if !self
+                .limits
+                .fits(char_count(&candidate), token_count(&candidate))
+            {
+                self.flush();
+                self.trim_seed(&whole, index, in_table);
+            }
+            self.pieces.push(Piece {
+                text: whole,
+                anchor: block.anchor,
+                block: index,
+            });
+            return;
+        }
+
+        for text in block.pieces {
+            for text in self.limits.split(&text) {
+                let candidate = self.text_with(&text, index);
+                if !self
+                    .limits
+                    .fits(char_count(&candidate), token_count(&candidate))
+                {
+                    self.flush();
+                    self.trim_seed(&text, index, in_table);
+                }
+                self.pieces.push(Piece {
+                    text,
+                    anchor: block.anchor,
+                    block: index,
+                });
+            }
+        }
+    }
+
+    /// 조각을 더했을 때의 덩어리 텍스트 / Chunk text with a piece added
+    fn text_with(&self, text: &str, block: usize) -> String {
+        let mut result = self.text();
+        if let Some(last) = self.pieces.last() {
+            result.push_str(separator(last, block));
+        }
+        result.push_str(text);
+        result
+    }
+
+    fn text(&self) -> String {
+        let mut result = String::new();
+        for (index, piece) in self.pieces.iter().enumerate() {
+            if index > 0 {
+                result.push_str(separator(&self.pieces[index - 1], piece.block));
+            }
+            result.push_str(&piece.text);
+        }
+        result
+    }
+
+    /// 겹침 조각이 다음 조각과 함께 들어가지 않으면 앞에서부터 버림 (표 앞에서는 모두 버림)
+    /// Drop overlap pieces from the front until the next piece fits (all of them before a table)
+    fn trim_seed(&mut self, text: &str, block: usize, in_table: bool) {
+        if in_table {
+            self.pieces.clear();
+        }
+        while !self.pieces.is_empty() {
+            let candidate = self.text_with(text, block);
+            if self
+                .limits
+                .fits(char_count(&candidate), token_count(&candidate))
+            {
+                break;
+            }
+            self.pieces.remove(0);
+        }
+        self.seeded = self.pieces.len();
+    }
+
+    /// 현재 덩어리를 내보내고 겹칠 조각으로 다음 덩어리를 시작 / Emit the current chunk and seed the next one with overlap pieces
+    fn flush(&mut self) {
+        if self.pieces.len() <= self.seeded {
+            self.pieces.clear();
+            self.seeded = 0;
+            return;
+        }
+        let text = self.text();
+        let mut anchors: Vec<ChunkAnchor> = Vec::new();
+        for piece in &self.pieces {
+            if !anchors.contains(&piece.anchor) {
+                anchors.push(piece.anchor);
+            }
+        }
+        self.chunks.push(Chunk {
+            char_count: char_count(&text),
+            token_count: token_count(&text),
+            text,
+            anchors,
+        });
+
+        // 표 행은 되풀이하지 않고, 끝에서부터 겹침 글자 수 안의 문장만 / No table rows; only trailing sentences within the overlap
+        let mut keep = 0;
+        let mut chars = 0;
+        for piece in self.pieces.iter().rev() {
+            chars += char_count(&piece.text);
+            if piece.anchor.in_table || chars > self.overlap {
+                break;
+            }
+            keep += 1;
+        }
+        let start = self.pieces.len() - keep;
+        self.pieces.drain(..start);
+        self.seeded = self.pieces.len();
+    }
+}
+
+/// 앞 조각과 다음 조각 사이 구분자 (같은 문단의 문장은 공백, 표 행과 블록 사이는 줄바꿈)
+/// Separator between a piece and the next one (a space between sentences of one paragraph, a
+/// newline between table rows and blocks)
+fn separator(previous: &Piece, block: usize) -> &'static str {
+    if previous.block == block && !previous.anchor.in_table {
+        " "
+    } else {
+        "\n"
+    }
+}
+
+fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn token_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("첫 문장입니다. 두 번째인가요? 3.14는 숫자"),
+            vec!["첫 문장입니다.", "두 번째인가요?", "3.14는 숫자"]
+        );
+    }
+
+    #[test]
+    fn test_chunks_keep_paragraphs_and_tables_whole() {
+        let style = Default::default();
+        let document = DocumentBuilder::new()
+            .add_paragraph("가나다라 마바사. 아자차카.", &style)
+            .add_table(&[["이름", "값"], ["길이", "10"]])
+            .add_paragraph("타파하 끝.", &style)
+            .build();
+
+        let chunks = chunk_document(&document, &ChunkOptions::default().with_max_chars(25));
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "가나다라 마바사. 아자차카.",
+                "이름 | 값\n길이 | 10\n타파하 끝."
+            ]
+        );
+        assert!(chunks[1].anchors[0].in_table);
+        assert!(!chunks[1].anchors[1].in_table);
+        assert!(chunks.iter().all(|chunk| chunk.char_count <= 25));
+    }
+
+    #[test]
+    fn test_chunks_split_long_paragraphs_with_overlap() {
+        let style = Default::default();
+        let document = DocumentBuilder::new()
+            .add_paragraph("하나 둘. 셋 넷. 다섯 여섯. 일곱 여덟.", &style)
+            .build();
+
+        let options = ChunkOptions::default()
+            .with_max_chars(100)
+            .with_max_tokens(4)
+            .with_overlap(6);
+        let texts: Vec<_> = chunk_document(&document, &options)
+            .into_iter()
+            .map(|chunk| chunk.text)
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "하나 둘. 셋 넷.",
+                "셋 넷. 다섯 여섯.",
+                "다섯 여섯. 일곱 여덟."
+            ]
+        );
+
+        // 문장보다 작은 한도는 어절에서 자름 / Limits below a sentence cut at words
+        let texts: Vec<_> = chunk_document(&document, &ChunkOptions::default().with_max_chars(6))
+            .into_iter()
+            .map(|chunk| chunk.text)
+            .collect();
+        assert_eq!(
+            texts,
+            vec!["하나 둘.", "셋 넷.", "다섯 여섯.", "일곱 여덟."]
+        );
+    }
+}
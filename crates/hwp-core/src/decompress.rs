@@ -16,9 +16,9 @@ use std::io::Read;
 pub fn decompress_zlib(compressed_data: &[u8]) -> Result<Vec<u8>, HwpError> {
     let mut decoder = ZlibDecoder::new(compressed_data);
     let mut decompressed = Vec::new();
-    decoder
-        .read_to_end(&mut decompressed)
-        .map_err(|e| HwpError::decompress_error(CompressionFormat::Zlib, e.to_string()))?;
+    decoder.read_to_end(&mut decompressed).map_err(|e| {
+        HwpError::decompress_error(CompressionFormat::Zlib, e.to_string()).caused_by(e)
+    })?;
     Ok(decompressed)
 }
 
@@ -35,8 +35,8 @@ pub fn decompress_zlib(compressed_data: &[u8]) -> Result<Vec<u8>, HwpError> {
 pub fn decompress_deflate(compressed_data: &[u8]) -> Result<Vec<u8>, HwpError> {
     let mut decoder = DeflateDecoder::new(compressed_data);
     let mut decompressed = Vec::new();
-    decoder
-        .read_to_end(&mut decompressed)
-        .map_err(|e| HwpError::decompress_error(CompressionFormat::Deflate, e.to_string()))?;
+    decoder.read_to_end(&mut decompressed).map_err(|e| {
+        HwpError::decompress_error(CompressionFormat::Deflate, e.to_string()).caused_by(e)
+    })?;
     Ok(decompressed)
 }
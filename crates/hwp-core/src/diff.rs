@@ -0,0 +1,514 @@
+/// 문서 비교 모듈 / Document diff module
+///
+/// 두 문서의 구역별 최상위 문단을 순서대로 맞춰 보고 추가/삭제/변경된 문단과
+/// 표 셀 변경을 구조화된 결과로 돌려줍니다. 결과는 마크다운이나 HTML로 렌더링할 수 있습니다.
+///
+/// Aligns the top-level paragraphs of each section of two documents and returns
+/// added/removed/changed paragraphs and table cell changes as a structured result,
+/// which can be rendered as markdown or HTML.
+use crate::document::bodytext::{Paragraph, Section, Table};
+use crate::document::HwpDocument;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 변경 항목 하나 / A single change entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DiffChange {
+    /// 새 문서에만 있는 문단 / Paragraph only present in the new document
+    ParagraphAdded {
+        section_index: usize,
+        new_index: usize,
+        text: String,
+    },
+    /// 이전 문서에만 있는 문단 / Paragraph only present in the old document
+    ParagraphRemoved {
+        section_index: usize,
+        old_index: usize,
+        text: String,
+    },
+    /// 내용이 바뀐 문단 / Paragraph whose text changed
+    ParagraphChanged {
+        section_index: usize,
+        old_index: usize,
+        new_index: usize,
+        old_text: String,
+        new_text: String,
+    },
+    /// 표 셀 변경 (None이면 해당 쪽에 셀이 없음) / Table cell change (None means the cell does not exist on that side)
+    TableCellChanged {
+        section_index: usize,
+        old_index: usize,
+        new_index: usize,
+        /// 문단 안의 표 순서 / Table order within the paragraph
+        table_index: usize,
+        row: u16,
+        col: u16,
+        old_text: Option<String>,
+        new_text: Option<String>,
+    },
+}
+
+impl DiffChange {
+    /// 변경이 속한 구역 인덱스 / Section index of the change
+    pub fn section_index(&self) -> usize {
+        match self {
+            DiffChange::ParagraphAdded { section_index, .. }
+            | DiffChange::ParagraphRemoved { section_index, .. }
+            | DiffChange::ParagraphChanged { section_index, .. }
+            | DiffChange::TableCellChanged { section_index, .. } => *section_index,
+        }
+    }
+}
+
+/// 문서 비교 결과 / Document diff result
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentDiff {
+    /// 문서 순서대로 정렬된 변경 목록 / Changes in document order
+    pub changes: Vec<DiffChange>,
+}
+
+impl DocumentDiff {
+    /// 변경 사항이 없는지 확인 / Whether there are no changes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// 마크다운으로 렌더링 / Render as markdown
+    pub fn to_markdown(&self) -> String {
+        if self.changes.is_empty() {
+            return "No changes.\n".to_string();
+        }
+
+        let mut out = String::new();
+        let mut current_section = None;
+        for change in &self.changes {
+            if current_section != Some(change.section_index()) {
+                current_section = Some(change.section_index());
+                if !out.is_empty() {
+                    out.push('\n');
+                }
+                out.push_str(&format!("## Section {}\n\n", change.section_index() + 1));
+            }
+            let line = match change {
+                DiffChange::ParagraphAdded {
+                    new_index, text, ..
+                } => format!("- **Added** (paragraph {}): {}", new_index + 1, text),
+                DiffChange::ParagraphRemoved {
+                    old_index, text, ..
+                } => format!("- **Removed** (paragraph {}): ~~{}~~", old_index + 1, text),
+                DiffChange::ParagraphChanged {
+                    old_index,
+                    new_index,
+                    old_text,
+                    new_text,
+                    ..
+                } => format!(
+                    "- **Changed** (paragraph {} → {}): ~~{}~~ → {}",
+                    old_index + 1,
+                    new_index + 1,
+                    old_text,
+                    new_text
+                ),
+                DiffChange::TableCellChanged {
+                    new_index,
+                    table_index,
+                    row,
+                    col,
+                    old_text,
+                    new_text,
+                    ..
+                } => format!(
+                    "- **Table cell** (paragraph {}, table {}, row {}, col {}): {}",
+                    new_index + 1,
+                    table_index + 1,
+                    row + 1,
+                    col + 1,
+                    match (old_text, new_text) {
+                        (Some(old), Some(new)) => format!("~~{}~~ → {}", old, new),
+                        (Some(old), None) => format!("~~{}~~", old),
+                        (None, Some(new)) => new.clone(),
+                        (None, None) => String::new(),
+                    }
+                ),
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// HTML로 렌더링 (`<ins>`/`<del>` 사용) / Render as HTML (using `<ins>`/`<del>`)
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<div class=\"hwp-diff\">\n");
+        if self.changes.is_empty() {
+            out.push_str("<p>No changes.</p>\n");
+        }
+
+        let mut current_section = None;
+        for change in &self.changes {
+            if current_section != Some(change.section_index()) {
+                if current_section.is_some() {
+                    out.push_str("</ul>\n");
+                }
+                current_section = Some(change.section_index());
+                out.push_str(&format!(
+                    "<h2>Section {}</h2>\n<ul>\n",
+                    change.section_index() + 1
+                ));
+            }
+            let item = match change {
+                DiffChange::ParagraphAdded { text, .. } => format!(
+                    "<li class=\"hwp-diff-added\"><ins>{}</ins></li>",
+                    escape_html(text)
+                ),
+                DiffChange::ParagraphRemoved { text, .. } => format!(
+                    "<li class=\"hwp-diff-removed\"><del>{}</del></li>",
+                    escape_html(text)
+                ),
+                DiffChange::ParagraphChanged {
+                    old_text, new_text, ..
+                } => format!(
+                    "<li class=\"hwp-diff-changed\"><del>{}</del> <ins>{}</ins></li>",
+                    escape_html(old_text),
+                    escape_html(new_text)
+                ),
+                DiffChange::TableCellChanged {
+                    table_index,
+                    row,
+                    col,
+                    old_text,
+                    new_text,
+                    ..
+                } => format!(
+                    "<li class=\"hwp-diff-cell\" data-table=\"{}\" data-row=\"{}\" data-col=\"{}\">{}{}</li>",
+                    table_index,
+                    row,
+                    col,
+                    old_text
+                        .as_deref()
+                        .map(|t| format!("<del>{}</del>", escape_html(t)))
+                        .unwrap_or_default(),
+                    new_text
+                        .as_deref()
+                        .map(|t| format!("<ins>{}</ins>", escape_html(t)))
+                        .unwrap_or_default()
+                ),
+            };
+            out.push_str(&item);
+            out.push('\n');
+        }
+        if current_section.is_some() {
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</div>\n");
+        out
+    }
+}
+
+/// 두 문서 비교 / Compare two documents
+///
+/// # Arguments / 매개변수
+/// * `old` - 이전 문서 / Old document
+/// * `new` - 새 문서 / New document
+///
+/// # Returns / 반환값
+/// 구역 순서, 문단 순서대로 정렬된 변경 목록 / Changes ordered by section, then paragraph
+pub fn compare(old: &HwpDocument, new: &HwpDocument) -> DocumentDiff {
+    let old_sections = &old.body_text.sections;
+    let new_sections = &new.body_text.sections;
+    let mut changes = Vec::new();
+
+    for section_index in 0..old_sections.len().max(new_sections.len()) {
+        let old_blocks = section_blocks(old_sections.get(section_index));
+        let new_blocks = section_blocks(new_sections.get(section_index));
+        diff_section(section_index, &old_blocks, &new_blocks, &mut changes);
+    }
+
+    DocumentDiff { changes }
+}
+
+/// 비교 단위 (최상위 문단 하나) / Comparison unit (one top-level paragraph)
+#[derive(Debug, PartialEq, Eq)]
+struct Block {
+    text: String,
+    /// 표마다 (행, 열) → 셀 텍스트 / Per table: (row, col) → cell text
+    tables: Vec<BTreeMap<(u16, u16), String>>,
+}
+
+fn section_blocks(section: Option<&Section>) -> Vec<Block> {
+    section
+        .map(|section| section.paragraphs.iter().map(paragraph_block).collect())
+        .unwrap_or_default()
+}
+
+fn paragraph_block(paragraph: &Paragraph) -> Block {
+    Block {
+        text: paragraph.text().trim().to_string(),
+        tables: paragraph.tables().into_iter().map(table_cells).collect(),
+    }
+}
+
+fn table_cells(table: &Table) -> BTreeMap<(u16, u16), String> {
+    table
+        .cells
+        .iter()
+        .map(|cell| {
+            (
+                (
+                    cell.cell_attributes.row_address,
+                    cell.cell_attributes.col_address,
+                ),
+                cell.text(),
+            )
+        })
+        .collect()
+}
+
+/// 정렬 연산 / Alignment operation
+enum Op {
+    Equal,
+    Delete(usize),
+    Insert(usize),
+}
+
+/// 최장 공통 부분열로 블록 정렬 / Align blocks with a longest common subsequence
+fn align(old: &[Block], new: &[Block]) -> Vec<Op> {
+    // 공통 앞/뒤 부분은 표 없이 처리 / Common prefix/suffix skip the table
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let (n, m) = (old_mid.len(), new_mid.len());
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * (m + 1) + j] = if old_mid[i] == new_mid[j] {
+                lengths[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<Op> = (0..prefix).map(|_| Op::Equal).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old_mid[i] == new_mid[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if j < m
+            && (i == n || lengths[i * (m + 1) + j + 1] >= lengths[(i + 1) * (m + 1) + j])
+        {
+            ops.push(Op::Insert(prefix + j));
+            j += 1;
+        } else {
+            ops.push(Op::Delete(prefix + i));
+            i += 1;
+        }
+    }
+    ops.extend((0..suffix).map(|_| Op::Equal));
+    ops
+}
+
+fn diff_section(section_index: usize, old: &[Block], new: &[Block], out: &mut Vec<DiffChange>) {
+    let ops = align(old, new);
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    for op in ops {
+        match op {
+            Op::Delete(index) => removed.push(index),
+            Op::Insert(index) => added.push(index),
+            Op::Equal => {
+                flush_run(section_index, old, new, &removed, &added, out);
+                removed.clear();
+                added.clear();
+            }
+        }
+    }
+    flush_run(section_index, old, new, &removed, &added, out);
+}
+
+/// 연속된 삭제/추가 구간을 변경으로 짝지음 / Pair a run of deletions/insertions into changes
+fn flush_run(
+    section_index: usize,
+    old: &[Block],
+    new: &[Block],
+    removed: &[usize],
+    added: &[usize],
+    out: &mut Vec<DiffChange>,
+) {
+    let paired = removed.len().min(added.len());
+    for (&old_index, &new_index) in removed.iter().zip(added) {
+        let (a, b) = (&old[old_index], &new[new_index]);
+        if a.text != b.text {
+            out.push(DiffChange::ParagraphChanged {
+                section_index,
+                old_index,
+                new_index,
+                old_text: a.text.clone(),
+                new_text: b.text.clone(),
+            });
+        }
+        diff_tables(section_index, old_index, new_index, a, b, out);
+    }
+    for &old_index in &removed[paired..] {
+        out.push(DiffChange::ParagraphRemoved {
+            section_index,
+            old_index,
+            text: old[old_index].text.clone(),
+        });
+    }
+    for &new_index in &added[paired..] {
+        out.push(DiffChange::ParagraphAdded {
+            section_index,
+            new_index,
+            text: new[new_index].text.clone(),
+        });
+    }
+}
+
+fn diff_tables(
+    section_index: usize,
+    old_index: usize,
+    new_index: usize,
+    a: &Block,
+    b: &Block,
+    out: &mut Vec<DiffChange>,
+) {
+    let empty = BTreeMap::new();
+    for table_index in 0..a.tables.len().max(b.tables.len()) {
+        let old_cells = a.tables.get(table_index).unwrap_or(&empty);
+        let new_cells = b.tables.get(table_index).unwrap_or(&empty);
+        let mut keys: Vec<&(u16, u16)> = old_cells.keys().chain(new_cells.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for &(row, col) in keys {
+            let old_text = old_cells.get(&(row, col));
+            let new_text = new_cells.get(&(row, col));
+            if old_text != new_text {
+                out.push(DiffChange::TableCellChanged {
+                    section_index,
+                    old_index,
+                    new_index,
+                    table_index,
+                    row,
+                    col,
+                    old_text: old_text.cloned(),
+                    new_text: new_text.cloned(),
+                });
+            }
+        }
+    }
+}
+
+/// HTML 특수 문자 이스케이프 / Escape HTML special characters
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{document_with_paragraphs, text_paragraph};
+
+    fn document(texts: &[&str]) -> HwpDocument {
+        document_with_paragraphs(texts.iter().map(|t| text_paragraph(t)).collect())
+    }
+
+    #[test]
+    fn test_identical_documents_have_no_changes() {
+        let doc = document(&["가", "나"]);
+        let diff = compare(&doc, &doc);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_markdown(), "No changes.\n");
+    }
+
+    #[test]
+    fn test_added_removed_and_changed_paragraphs() {
+        let old = document(&["제1조 목적", "제2조 정의", "제3조 삭제될 조항", "부칙"]);
+        let new = document(&["제1조 목적", "제2조 용어의 정의", "부칙", "부칙 2"]);
+        let diff = compare(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![
+                DiffChange::ParagraphChanged {
+                    section_index: 0,
+                    old_index: 1,
+                    new_index: 1,
+                    old_text: "제2조 정의".to_string(),
+                    new_text: "제2조 용어의 정의".to_string(),
+                },
+                DiffChange::ParagraphRemoved {
+                    section_index: 0,
+                    old_index: 2,
+                    text: "제3조 삭제될 조항".to_string(),
+                },
+                DiffChange::ParagraphAdded {
+                    section_index: 0,
+                    new_index: 3,
+                    text: "부칙 2".to_string(),
+                },
+            ]
+        );
+
+        let markdown = diff.to_markdown();
+        assert!(markdown.contains("## Section 1"));
+        assert!(markdown.contains("~~제2조 정의~~ → 제2조 용어의 정의"));
+        let html = diff.to_html();
+        assert!(html.contains("<del>제3조 삭제될 조항</del>"));
+        assert!(html.contains("<ins>부칙 2</ins>"));
+    }
+
+    #[test]
+    fn test_table_cell_changes() {
+        let old = Block {
+            text: String::new(),
+            tables: vec![BTreeMap::from([
+                ((0, 0), "항목".to_string()),
+                ((0, 1), "100".to_string()),
+            ])],
+        };
+        let new = Block {
+            text: String::new(),
+            tables: vec![BTreeMap::from([
+                ((0, 0), "항목".to_string()),
+                ((0, 1), "120".to_string()),
+                ((1, 0), "<합계>".to_string()),
+            ])],
+        };
+        let mut changes = Vec::new();
+        diff_section(0, &[old], &[new], &mut changes);
+
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(
+            &changes[0],
+            DiffChange::TableCellChanged { row: 0, col: 1, old_text: Some(o), new_text: Some(n), .. }
+                if o == "100" && n == "120"
+        ));
+        assert!(matches!(
+            &changes[1],
+            DiffChange::TableCellChanged {
+                row: 1,
+                col: 0,
+                old_text: None,
+                ..
+            }
+        ));
+
+        let html = DocumentDiff { changes }.to_html();
+        assert!(html.contains("<ins>&lt;합계&gt;</ins>"));
+    }
+}
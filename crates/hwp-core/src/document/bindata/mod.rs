@@ -3,14 +3,19 @@
 /// This module handles parsing of HWP BinData storage.
 ///
 /// 스펙 문서 매핑: 표 2 - 바이너리 데이터 (BinData 스토리지)
+use crate::cfb::CfbParser;
 use crate::decompress::decompress_deflate;
 use crate::document::docinfo::BinDataRecord;
+use crate::document::{HwpDocument, SourceFormat};
 use crate::error::HwpError;
+use crate::parser::hwpx::container::HwpxContainer;
+use crate::parser::ResourceLimits;
 use crate::types::WORD;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use cfb::CompoundFile;
 use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read};
+#[cfg(feature = "fs")]
 use std::path::Path;
 
 /// Binary data output format
@@ -19,7 +24,8 @@ use std::path::Path;
 pub enum BinaryDataFormat {
     /// Base64 encoded string / Base64로 인코딩된 문자열
     Base64,
-    /// File path where binary data is saved / 바이너리 데이터가 저장된 파일 경로
+    /// File path where binary data is saved (requires the `fs` feature) / 바이너리 데이터가 저장된 파일 경로 (`fs` 기능 필요)
+    #[cfg(feature = "fs")]
     File(String),
 }
 
@@ -42,7 +48,174 @@ pub struct BinaryDataItem {
     pub name: Option<String>,
 }
 
+impl BinaryDataItem {
+    /// 메모리에 있는 base64 데이터를 디코딩하는 리더 / Reader decoding the in-memory base64 data
+    ///
+    /// 이미 메모리에 올라온 base64 문자열을 읽는 만큼 디코딩할 뿐, 스트리밍이 아닙니다. 큰
+    /// 동영상/OLE 데이터는 원본 파일에서 바로 읽는 [`HwpDocument::open_bin_data`]를 사용하세요.
+    /// Only decodes the base64 string that is already in memory as it is read; this is not
+    /// streaming. For large videos/OLE payloads use [`HwpDocument::open_bin_data`], which reads
+    /// straight from the source file.
+    pub fn reader(&self) -> impl Read + '_ {
+        base64::read::DecoderReader::new(self.data.as_bytes(), &STANDARD)
+    }
+}
+
+impl HwpDocument {
+    /// 원본 파일에서 바이너리 데이터 항목을 스트리밍으로 열기 / Open a binary data item as a stream from the source file
+    ///
+    /// HWP 5.0은 CFB 스트림을 읽는 만큼 압축 해제하고, HWPX는 ZIP 항목을 직접 읽으므로 큰
+    /// 동영상/OLE 데이터를 base64로 메모리에 올리지 않습니다. 이미 파싱한 DocInfo를 쓰므로 원본을
+    /// 다시 파싱하지 않습니다.
+    /// HWP 5.0 streams are decompressed on the fly and HWPX entries are read straight from the
+    /// ZIP, so large videos/OLE payloads never sit in memory as base64. The parsed DocInfo is
+    /// reused, so the source is not parsed again. HWPX is reopened with the
+    /// `ResourceLimits` the document was parsed with.
+    ///
+    /// # Arguments
+    /// * `data` - 이 문서를 파싱한 원본 파일 데이터 / Source file data this document was parsed from
+    /// * `index` - 열 항목의 `BinaryDataItem::index` / `BinaryDataItem::index` of the item to open
+    pub fn open_bin_data<'a>(
+        &self,
+        data: &'a [u8],
+        index: WORD,
+    ) -> Result<Box<dyn Read + 'a>, HwpError> {
+        match self.source_format {
+            SourceFormat::Hwp5 => BinData::open_stream(
+                CfbParser::parse(data)?,
+                index,
+                &self.doc_info.bin_data,
+                self.file_header.is_compressed(),
+            ),
+            SourceFormat::Hwpx => BinData::open_hwpx_item(data, index, self.resource_limits),
+            SourceFormat::Hwpml => Err(HwpError::UnsupportedFormat {
+                format: "HWPML".to_string(),
+                reason: "embedded binary data in <TAIL> is not read".to_string(),
+            }),
+            SourceFormat::Hwp3 => Err(HwpError::UnsupportedFormat {
+                format: "HWP 3.0".to_string(),
+                reason: "embedded pictures are not read".to_string(),
+            }),
+        }
+    }
+}
+
+/// CFB 스트림을 소유하는 바이너리 데이터 리더 / Binary data reader owning its CFB stream
+///
+/// `Stream`은 `CompoundFile`에 대한 약한 참조만 가지므로 함께 보관합니다.
+/// `Stream` only holds a weak reference to the `CompoundFile`, so both are kept together.
+struct CfbStreamReader<'a> {
+    inner: Box<dyn Read + 'a>,
+    _cfb: CompoundFile<Cursor<&'a [u8]>>,
+}
+
+impl Read for CfbStreamReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+/// BinData 스트림 후보 경로 / Candidate BinData stream paths
+fn candidate_stream_paths(binary_data_id: WORD, extension: Option<&str>) -> Vec<String> {
+    let base_stream_name = format!("BIN{binary_data_id:04X}");
+
+    // Try different path formats
+    // 다양한 경로 형식 시도
+    let mut paths = vec![
+        format!("BinData/{}", base_stream_name),
+        format!("Root Entry/BinData/{}", base_stream_name),
+        base_stream_name.clone(),
+    ];
+
+    // 표 17의 extension 정보가 있으면 확장자 포함 경로도 시도
+    // If extension info from Table 17 exists, also try paths with extension
+    if let Some(ext) = extension {
+        paths.push(format!("BinData/{base_stream_name}.{ext}"));
+        paths.push(format!("Root Entry/BinData/{base_stream_name}.{ext}"));
+    }
+    paths
+}
+
 impl BinData {
+    /// CFB에서 BinData 스트림을 지연 읽기로 엽니다. / Open a BinData stream from CFB lazily.
+    ///
+    /// 스트림 전체를 메모리에 올리지 않고 읽는 만큼 압축을 해제합니다.
+    /// Decompresses on the fly instead of loading the whole stream into memory.
+    ///
+    /// # Arguments
+    /// * `cfb` - CompoundFile structure (ownership is moved into the reader) / CompoundFile 구조체 (리더로 소유권 이동)
+    /// * `binary_data_id` - BinData ID (표 17) / BinData ID (Table 17)
+    /// * `bin_data_records` - BinData records from DocInfo / DocInfo의 BinData 레코드
+    /// * `storage_compressed` - FileHeader compression flag / FileHeader 압축 플래그
+    pub fn open_stream<'a>(
+        mut cfb: CompoundFile<Cursor<&'a [u8]>>,
+        binary_data_id: WORD,
+        bin_data_records: &[BinDataRecord],
+        storage_compressed: bool,
+    ) -> Result<Box<dyn Read + 'a>, HwpError> {
+        use crate::document::docinfo::bin_data::CompressionType;
+        use flate2::read::DeflateDecoder;
+
+        let (attributes, extension) = bin_data_records
+            .iter()
+            .find_map(|record| match record {
+                BinDataRecord::Embedding {
+                    attributes,
+                    embedding,
+                } if embedding.binary_data_id == binary_data_id => {
+                    Some((attributes, Some(embedding.extension.as_str())))
+                }
+                BinDataRecord::Storage {
+                    attributes,
+                    storage,
+                } if storage.binary_data_id == binary_data_id => Some((attributes, None)),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                HwpError::stream_not_found(format!("BIN{binary_data_id:04X}"), "BinData")
+            })?;
+
+        let compressed = match attributes.compression {
+            CompressionType::Compress => true,
+            CompressionType::NoCompress => false,
+            CompressionType::StorageDefault => storage_compressed,
+        };
+
+        let paths = candidate_stream_paths(binary_data_id, extension);
+        let stream = paths
+            .iter()
+            .find_map(|path| cfb.open_stream(path).ok())
+            .ok_or_else(|| {
+                HwpError::stream_not_found(format!("BIN{binary_data_id:04X}"), "BinData")
+            })?;
+
+        let inner: Box<dyn Read + 'a> = if compressed {
+            Box::new(DeflateDecoder::new(stream))
+        } else {
+            Box::new(stream)
+        };
+        Ok(Box::new(CfbStreamReader { inner, _cfb: cfb }))
+    }
+
+    /// HWPX 패키지의 BinData 항목을 지연 읽기로 엽니다. / Open a BinData entry of an HWPX package lazily.
+    ///
+    /// `index`는 패키지의 BinData 항목 순서입니다. / `index` is the position among the package's BinData entries.
+    pub(crate) fn open_hwpx_item(
+        data: &[u8],
+        index: WORD,
+        limits: ResourceLimits,
+    ) -> Result<Box<dyn Read + '_>, HwpError> {
+        let mut container = HwpxContainer::open_with_limits(data, limits)?;
+        let path = container
+            .get_bindata_files()
+            .into_iter()
+            .nth(index as usize)
+            .ok_or_else(|| HwpError::HwpxFileNotFound {
+                path: format!("BinData/#{index}"),
+            })?;
+        container.open_file_reader(&path)
+    }
+
     /// Parse BinData storage from CFB structure
     /// CFB 구조에서 BinData 스토리지를 파싱합니다.
     ///
@@ -79,22 +252,7 @@ impl BinData {
                 }
             };
 
-            let base_stream_name = format!("BIN{binary_data_id:04X}");
-
-            // Try different path formats
-            // 다양한 경로 형식 시도
-            let mut paths = vec![
-                format!("BinData/{}", base_stream_name),
-                format!("Root Entry/BinData/{}", base_stream_name),
-                base_stream_name.clone(),
-            ];
-
-            // 표 17의 extension 정보가 있으면 확장자 포함 경로도 시도
-            // If extension info from Table 17 exists, also try paths with extension
-            if let Some(ext) = &extension_opt {
-                paths.push(format!("BinData/{base_stream_name}.{ext}"));
-                paths.push(format!("Root Entry/BinData/{base_stream_name}.{ext}"));
-            }
+            let paths = candidate_stream_paths(binary_data_id, extension_opt.as_deref());
 
             let mut found = false;
             for path in &paths {
@@ -126,9 +284,11 @@ impl BinData {
                                         BinaryDataFormat::Base64 => {
                                             STANDARD.encode(&decompressed_buffer)
                                         }
+                                        #[cfg(feature = "fs")]
                                         BinaryDataFormat::File(dir_path) => {
                                             let ext = extension_opt.as_deref().unwrap_or("bin");
-                                            let file_name = format!("{base_stream_name}.{ext}");
+                                            let file_name =
+                                                format!("BIN{binary_data_id:04X}.{ext}");
                                             let file_path = Path::new(dir_path).join(&file_name);
 
                                             std::fs::create_dir_all(dir_path).map_err(|e| {
@@ -1141,11 +1141,7 @@ impl ChartData {
         // 첫 번째 객체가 VtChart여야 함
         match parse_chart_obj(data, &mut offset, &mut seen_types) {
             Ok(Some(vt_chart)) => {
-                let remaining = if offset < data.len() {
-                    data[offset..].to_vec()
-                } else {
-                    Vec::new()
-                };
+                let remaining = data.get(offset..).unwrap_or_default().to_vec();
 
                 Ok(ChartData {
                     vt_chart: Some(vt_chart),
@@ -1184,6 +1180,34 @@ impl ChartData {
     pub fn get_data_grid(&self) -> Option<&DataGrid> {
         self.vt_chart.as_ref().map(|vt| &vt.data_grid)
     }
+
+    /// 검색할 수 있는 차트 텍스트 / Searchable chart text
+    ///
+    /// 제목, 축 제목, 계열 이름(범례), 항목 이름, 각주 순서이며 빈 값과 중복은 뺍니다.
+    /// Title, axis titles, series names (legend), category labels and footnote in that order,
+    /// without blanks or duplicates.
+    pub fn labels(&self) -> Vec<&str> {
+        let Some(vt) = self.vt_chart.as_ref() else {
+            return Vec::new();
+        };
+        let candidates = vt
+            .title
+            .iter()
+            .map(|title| title.text.as_str())
+            .chain(vt.plot.axes.iter().map(|axis| axis.title.text.as_str()))
+            .chain(vt.plot.series.iter().map(|s| s.legend_text.as_str()))
+            .chain(vt.data_grid.column_labels.iter().map(String::as_str))
+            .chain(vt.data_grid.row_labels.iter().map(String::as_str))
+            .chain(vt.footnote.iter().map(|footnote| footnote.text.as_str()));
+
+        let mut labels: Vec<&str> = Vec::new();
+        for label in candidates.map(str::trim) {
+            if !label.is_empty() && !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        labels
+    }
 }
 
 /// ChartObj 파싱 / Parse ChartObj
@@ -1192,27 +1216,15 @@ fn parse_chart_obj(
     offset: &mut usize,
     seen_types: &mut std::collections::HashSet<i32>,
 ) -> Result<Option<VtChart>, HwpError> {
-    if *offset + 8 > data.len() {
+    if data.len().saturating_sub(*offset) < 8 {
         return Ok(None);
     }
 
     // id (long, 4바이트)
-    let _id = INT32::from_le_bytes([
-        data[*offset],
-        data[*offset + 1],
-        data[*offset + 2],
-        data[*offset + 3],
-    ]);
-    *offset += 4;
+    let _id = read_i32(data, offset, "ChartObj id")?;
 
     // StoredtypeId (long, 4바이트)
-    let stored_type_id = INT32::from_le_bytes([
-        data[*offset],
-        data[*offset + 1],
-        data[*offset + 2],
-        data[*offset + 3],
-    ]);
-    *offset += 4;
+    let stored_type_id = read_i32(data, offset, "ChartObj StoredtypeId")?;
 
     // Variable Data (StoredName, StoredVersion) - 동일 타입이 없으면 포함
     let _stored_name: Option<String>;
@@ -1220,29 +1232,20 @@ fn parse_chart_obj(
 
     if !seen_types.contains(&stored_type_id) {
         // StoredName 파싱 (null-terminated string)
-        let name_start = *offset;
-        while *offset < data.len() && data[*offset] != 0 {
-            *offset += 1;
-        }
-        if *offset < data.len() {
-            _stored_name = Some(String::from_utf8_lossy(&data[name_start..*offset]).to_string());
-            *offset += 1; // null terminator
-        } else {
-            _stored_name = None;
+        let rest = data.get(*offset..).unwrap_or_default();
+        match rest.iter().position(|&byte| byte == 0) {
+            Some(len) => {
+                _stored_name = Some(String::from_utf8_lossy(&rest[..len]).to_string());
+                *offset += len + 1; // null terminator
+            }
+            None => {
+                _stored_name = None;
+                *offset += rest.len();
+            }
         }
 
         // StoredVersion (int, 4바이트)
-        if *offset + 4 <= data.len() {
-            _stored_version = Some(INT32::from_le_bytes([
-                data[*offset],
-                data[*offset + 1],
-                data[*offset + 2],
-                data[*offset + 3],
-            ]));
-            *offset += 4;
-        } else {
-            _stored_version = None;
-        }
+        _stored_version = read_i32(data, offset, "ChartObj StoredVersion").ok();
 
         seen_types.insert(stored_type_id);
     } else {
@@ -1260,6 +1263,18 @@ fn parse_chart_obj(
     Ok(Some(vt_chart))
 }
 
+/// 범위를 확인하며 INT32 읽기 / Read an INT32 with bounds checking
+fn read_i32(data: &[u8], offset: &mut usize, field: &str) -> Result<INT32, HwpError> {
+    let bytes = offset
+        .checked_add(4)
+        .and_then(|end| data.get(*offset..end))
+        .ok_or_else(|| HwpError::insufficient_data(field, 4, data.len().saturating_sub(*offset)))?;
+    *offset += 4;
+    Ok(INT32::from_le_bytes([
+        bytes[0], bytes[1], bytes[2], bytes[3],
+    ]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1307,6 +1322,19 @@ mod tests {
         assert!(!chart.raw_data.is_empty());
     }
 
+    #[test]
+    fn test_parse_truncated_chart_obj() {
+        // 이름이 끝나지 않았거나 StoredVersion이 잘린 경우 / Unterminated name or truncated StoredVersion
+        let mut data = vec![1, 0, 0, 0, 2, 0, 0, 0];
+        data.extend_from_slice(b"VtChart");
+        for len in 0..=data.len() + 3 {
+            let mut input = data.clone();
+            input.resize(len, 0);
+            let chart = ChartData::parse(&input).unwrap();
+            assert_eq!(chart.vt_chart.is_some(), len >= 8);
+        }
+    }
+
     #[test]
     fn test_default_vt_chart() {
         let vt_chart = VtChart::default();
@@ -4,9 +4,112 @@
 /// 표 6: 제어 문자 / Table 6: Control characters
 use crate::error::HwpError;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 pub struct ControlChar;
 
+/// 인라인 제어 문자 종류 / Kind of inline control character
+///
+/// 파서는 각 종류를 문서 텍스트에 고유한 유니코드 문자로 남기고, 뷰어가 이를 출력 문자열로 바꿉니다.
+/// Parsers leave each kind in document text as its own Unicode character, and viewers turn it into
+/// their output string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InlineControl {
+    /// 탭 (HWP 9, HWPX `<hp:tab>`) / Tab (HWP 9, HWPX `<hp:tab>`)
+    Tab,
+    /// 하이픈 (HWP 24, HWPX `<hp:hyphen>`) / Hyphen (HWP 24, HWPX `<hp:hyphen>`)
+    Hyphen,
+    /// 묶음 빈칸 (HWP 30, HWPX `<hp:nbSpace>`) / Non-breaking space (HWP 30, HWPX `<hp:nbSpace>`)
+    NonBreakingSpace,
+    /// 고정폭 빈칸 (HWP 31, HWPX `<hp:fwSpace>`) / Fixed-width space (HWP 31, HWPX `<hp:fwSpace>`)
+    FixedWidthSpace,
+}
+
+impl InlineControl {
+    /// 모든 종류 / All kinds
+    pub const ALL: [InlineControl; 4] = [
+        InlineControl::Tab,
+        InlineControl::Hyphen,
+        InlineControl::NonBreakingSpace,
+        InlineControl::FixedWidthSpace,
+    ];
+
+    /// HWP 제어 문자 코드에서 변환 / Convert from an HWP control character code
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            ControlChar::TAB => Some(Self::Tab),
+            ControlChar::HYPHEN => Some(Self::Hyphen),
+            ControlChar::BOUND_SPACE => Some(Self::NonBreakingSpace),
+            ControlChar::FIXED_SPACE => Some(Self::FixedWidthSpace),
+            _ => None,
+        }
+    }
+
+    /// HWPX 요소 로컬 이름에서 변환 / Convert from an HWPX element local name
+    pub fn from_element(local_name: &str) -> Option<Self> {
+        match local_name {
+            "tab" => Some(Self::Tab),
+            "hyphen" => Some(Self::Hyphen),
+            "nbSpace" => Some(Self::NonBreakingSpace),
+            "fwSpace" => Some(Self::FixedWidthSpace),
+            _ => None,
+        }
+    }
+
+    /// 문서 텍스트 안의 문자에서 변환 / Convert from a character in document text
+    pub fn from_char(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|control| control.as_char() == c)
+    }
+
+    /// 문서 텍스트에 남는 문자 / Character left in document text
+    ///
+    /// 탭은 U+0009, 하이픈은 U+2010, 묶음 빈칸은 U+00A0, 고정폭 빈칸은 U+2007 (figure space)입니다.
+    /// Tab is U+0009, hyphen U+2010, non-breaking space U+00A0 and fixed-width space U+2007 (figure space).
+    pub fn as_char(self) -> char {
+        match self {
+            Self::Tab => '\t',
+            Self::Hyphen => '\u{2010}',
+            Self::NonBreakingSpace => '\u{00A0}',
+            Self::FixedWidthSpace => '\u{2007}',
+        }
+    }
+
+    /// 일반 텍스트 표현 / Plain text representation
+    pub fn plain_text(self) -> &'static str {
+        match self {
+            Self::Tab => "\t",
+            Self::Hyphen => "-",
+            Self::NonBreakingSpace | Self::FixedWidthSpace => " ",
+        }
+    }
+}
+
+/// 텍스트의 인라인 제어 문자를 출력 문자열로 바꿈 / Replace inline control characters in text with output strings
+///
+/// `overrides`에 없는 종류는 `default_output`을 씁니다. 바꿀 문자가 없으면 빌린 값을 그대로 돌려줍니다.
+/// Kinds missing from `overrides` use `default_output`. Returns the borrowed text when nothing changes.
+pub fn normalize_inline_controls<'a>(
+    text: &'a str,
+    overrides: &HashMap<InlineControl, String>,
+    default_output: fn(InlineControl) -> &'static str,
+) -> Cow<'a, str> {
+    if !text.chars().any(|c| InlineControl::from_char(c).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match InlineControl::from_char(c) {
+            Some(control) => match overrides.get(&control) {
+                Some(output) => result.push_str(output),
+                None => result.push_str(default_output(control)),
+            },
+            None => result.push(c),
+        }
+    }
+    Cow::Owned(result)
+}
+
 /// 제어 문자 위치 정보 / Control character position information
 ///
 /// 문단 텍스트 내에서 제어 문자의 위치와 종류를 나타냅니다.
@@ -177,18 +280,14 @@ impl ControlChar {
         )
     }
 
-    /// 제어 문자를 텍스트 표현으로 변환 / Convert control character to text representation
+    /// 제어 문자를 일반 텍스트 표현으로 변환 / Convert control character to plain text representation
     ///
     /// 변환 불가능한 경우 None 반환 / Returns None if conversion is not possible
     pub fn to_text(code: u8) -> Option<&'static str> {
         match code {
-            Self::TAB => Some("\t"), // 탭 문자 그대로 유지 / Keep tab character as-is
-            Self::LINE_BREAK => Some("\n"), // 한 줄 끝(line break) / Line break
-            Self::PARA_BREAK => Some("\n"), // 문단 끝(para break) - 줄바꿈으로 표현 / Paragraph break - expressed as line break
-            Self::HYPHEN => Some("-"),      // 하이픈 / Hyphen
-            Self::BOUND_SPACE => Some(" "), // 묶음 빈칸을 공백으로 변환 / Convert bound space to space
-            Self::FIXED_SPACE => Some(" "), // 고정폭 빈칸을 공백으로 변환 / Convert fixed-width space to space
-            _ => None,
+            // 줄/문단 끝은 줄바꿈으로 표현 / Line and paragraph breaks are expressed as newlines
+            Self::LINE_BREAK | Self::PARA_BREAK => Some("\n"),
+            _ => InlineControl::from_code(code).map(InlineControl::plain_text),
         }
     }
 
@@ -4,25 +4,17 @@ use crate::types::{UINT16, UINT32, UINT8};
 
 use super::types::CtrlHeaderData;
 
-/// 필드 파싱 (표 152) / Parse field (Table 152)
-pub(crate) fn parse_field(data: &[u8]) -> Result<CtrlHeaderData, HwpError> {
-    if data.len() < 15 {
-        return Err(HwpError::insufficient_data("Field data", 15, data.len()));
+/// 필드 파싱 (표 154) / Parse field (Table 154)
+///
+/// 필드는 컨트롤 ID 자체가 종류(`%dte`, `%hlk` 등)이므로 `ctrl_id`를 `field_type`으로 씁니다.
+/// A field's control ID is its type (`%dte`, `%hlk`, ...), so `ctrl_id` becomes `field_type`.
+pub(crate) fn parse_field(ctrl_id: &str, data: &[u8]) -> Result<CtrlHeaderData, HwpError> {
+    if data.len() < 7 {
+        return Err(HwpError::insufficient_data("Field data", 7, data.len()));
     }
 
     let mut offset = 0usize;
 
-    let field_type_bytes = [
-        data[offset + 3],
-        data[offset + 2],
-        data[offset + 1],
-        data[offset],
-    ];
-    let field_type = String::from_utf8_lossy(&field_type_bytes)
-        .trim_end_matches('\0')
-        .to_string();
-    offset += 4;
-
     let attribute = UINT32::from_le_bytes([
         data[offset],
         data[offset + 1],
@@ -57,7 +49,7 @@ pub(crate) fn parse_field(data: &[u8]) -> Result<CtrlHeaderData, HwpError> {
     };
 
     Ok(CtrlHeaderData::Field {
-        field_type,
+        field_type: ctrl_id.to_string(),
         attribute,
         other_attr,
         command_len: command_len as UINT16,
@@ -56,7 +56,11 @@ impl CtrlId {
     /// 덧말 / Comment
     pub const COMMENT: &str = "cmtt";
     /// 숨은 설명 / Hidden description
+    ///
+    /// NOTE: 실제 파일에서는 `"tcmt"`로 나타나므로 파서는 둘 다 숨은 설명으로 처리합니다.
     pub const HIDDEN_DESC: &str = "hide";
+    /// 숨은 설명(대체 CHID) / Hidden description (alternate CHID)
+    pub const HIDDEN_DESC_ALT: &str = "tcmt";
     /// 필드 시작 / Field start
     pub const FIELD_START: &str = "%%%%";
 
@@ -55,7 +55,6 @@ impl CtrlHeader {
             CtrlId::PAGE_NUMBER | CtrlId::PAGE_NUMBER_POS => {
                 page_number_position::parse_page_number_position(remaining_data)?
             }
-            CtrlId::FIELD_START => field::parse_field(remaining_data)?,
             CtrlId::SECTION_DEF => section_definition::parse_section_definition(remaining_data)?,
             CtrlId::AUTO_NUMBER | CtrlId::AUTO_NUMBER_ALT => {
                 auto_number::parse_auto_number(remaining_data)?
@@ -66,7 +65,9 @@ impl CtrlHeader {
             CtrlId::BOOKMARK_MARKER => bookmark_marker::parse_bookmark_marker(remaining_data)?,
             CtrlId::OVERLAP => overlap::parse_overlap(remaining_data)?,
             CtrlId::COMMENT => comment::parse_comment(remaining_data)?,
-            CtrlId::HIDDEN_DESC => CtrlHeaderData::HiddenDescription,
+            CtrlId::HIDDEN_DESC | CtrlId::HIDDEN_DESC_ALT => CtrlHeaderData::HiddenDescription,
+            // 필드 컨트롤 ID는 모두 '%'로 시작 (표 128) / Field control IDs all start with '%' (Table 128)
+            id if id.starts_with('%') => field::parse_field(id, remaining_data)?,
             _ => CtrlHeaderData::Other,
         };
 
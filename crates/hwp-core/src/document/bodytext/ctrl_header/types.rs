@@ -15,7 +15,7 @@ pub struct CtrlHeader {
 
 /// 캡션 정렬 (표 73) / Caption alignment (Table 73)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum CaptionAlign {
     Left = 0,
     Right = 1,
@@ -25,7 +25,7 @@ pub enum CaptionAlign {
 
 /// 캡션 수직 정렬 (ListHeaderProperty bit 5-6) / Caption vertical alignment (ListHeaderProperty bit 5-6)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum CaptionVAlign {
     Top = 0,
     Middle = 1,
@@ -152,6 +152,49 @@ pub enum CtrlHeaderData {
     Other,
 }
 
+impl CtrlHeaderData {
+    /// 개체 공통 속성의 배치 정보 / Placement from object common properties
+    ///
+    /// 개체 공통 속성이 아니면 `None`을 반환합니다. / Returns `None` for non-object controls.
+    pub fn object_placement(&self) -> Option<ObjectPlacement> {
+        match self {
+            CtrlHeaderData::ObjectCommon {
+                attribute,
+                offset_y,
+                offset_x,
+                z_order,
+                margin,
+                ..
+            } => Some(ObjectPlacement {
+                attribute: attribute.clone(),
+                offset_x: *offset_x,
+                offset_y: *offset_y,
+                z_order: *z_order,
+                margin: margin.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// 개체 배치 정보 / Object placement
+///
+/// 글자처럼 취급 여부, 위치 기준과 오프셋, 본문과의 배치, 바깥 여백을 묶은 값입니다.
+/// Bundles treat-as-letter, anchor and offsets, text wrap and outer margins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectPlacement {
+    /// 개체 속성 / Object attribute
+    pub attribute: ObjectAttribute,
+    /// 가로 오프셋 / Horizontal offset
+    pub offset_x: SHWPUNIT,
+    /// 세로 오프셋 / Vertical offset
+    pub offset_y: SHWPUNIT,
+    /// z-order
+    pub z_order: INT32,
+    /// 바깥 여백 / Outer margins
+    pub margin: Margin,
+}
+
 /// 개체 속성 / Object attribute
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectAttribute {
@@ -171,8 +214,30 @@ pub struct ObjectAttribute {
     pub size_protect: bool,
 }
 
+impl Default for ObjectAttribute {
+    /// 문단 기준으로 배치되고 어울림으로 둘러싸는 개체 / Paragraph-anchored object with square wrap
+    fn default() -> Self {
+        Self {
+            like_letters: false,
+            affect_line_spacing: false,
+            vert_rel_to: VertRelTo::Para,
+            vert_relative: 0,
+            horz_rel_to: HorzRelTo::Column,
+            horz_relative: 0,
+            vert_rel_to_para_limit: false,
+            overlap: false,
+            object_width_standard: ObjectWidthStandard::Absolute,
+            object_height_standard: ObjectHeightStandard::Absolute,
+            object_text_option: ObjectTextOption::Square,
+            object_text_position_option: ObjectTextPositionOption::BothSides,
+            object_category: ObjectCategory::None,
+            size_protect: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum VertRelTo {
     Paper,
     Page,
@@ -180,7 +245,7 @@ pub enum VertRelTo {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum HorzRelTo {
     Paper,
     Page,
@@ -189,7 +254,7 @@ pub enum HorzRelTo {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ObjectWidthStandard {
     Paper,
     Page,
@@ -199,14 +264,14 @@ pub enum ObjectWidthStandard {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ObjectHeightStandard {
     Paper,
     Page,
     Absolute,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ObjectTextOption {
     Square,
@@ -217,7 +282,7 @@ pub enum ObjectTextOption {
     InFrontOfText,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ObjectTextPositionOption {
     BothSides,
@@ -227,7 +292,7 @@ pub enum ObjectTextPositionOption {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ObjectCategory {
     None,
     Figure,
@@ -306,3 +371,14 @@ pub enum ApplyPage {
     EvenOnly,
     OddOnly,
 }
+
+impl ApplyPage {
+    /// 이 쪽 번호에 쓰이는지 / Whether it applies to this page number
+    pub fn applies_to(self, page_number: usize) -> bool {
+        match self {
+            ApplyPage::Both => true,
+            ApplyPage::EvenOnly => page_number % 2 == 0,
+            ApplyPage::OddOnly => page_number % 2 == 1,
+        }
+    }
+}
@@ -50,10 +50,11 @@ pub struct FootnoteShapeAttributes {
 }
 
 /// 번호 모양 / Number shape
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum NumberShape {
     /// 1, 2, 3
+    #[default]
     Arabic,
     /// 동그라미 쳐진 1, 2, 3
     CircledArabic,
@@ -93,6 +94,105 @@ pub enum NumberShape {
     CustomCharRepeat,
 }
 
+impl NumberShape {
+    /// 번호를 이 모양의 문자열로 변환 / Format a number in this shape
+    ///
+    /// 모양이 표현할 수 있는 범위를 넘으면(동그라미 숫자는 35까지 등) 동그라미 없는 모양이나
+    /// 아라비아 숫자로 씁니다. 사용자 지정 문자 반복은 문자를 모르므로 아라비아 숫자를 씁니다
+    /// (`FootnoteShape::format_number` 참고).
+    /// Numbers beyond what the shape can express (circled digits stop at 35, ...) fall back to
+    /// the uncircled shape or Arabic digits. Custom character repeat does not know the character
+    /// and uses Arabic digits (see `FootnoteShape::format_number`).
+    pub fn format(self, n: u32) -> String {
+        match self {
+            NumberShape::Arabic => n.to_string(),
+            NumberShape::RomanUpper => to_roman(n).to_uppercase(),
+            NumberShape::RomanLower => to_roman(n).to_lowercase(),
+            NumberShape::AlphaUpper => to_alpha(n, true),
+            NumberShape::AlphaLower => to_alpha(n, false),
+            NumberShape::CircledArabic => circled_number(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::CircledAlphaUpper => {
+                circled_alpha(n, true).unwrap_or_else(|| to_alpha(n, true))
+            }
+            NumberShape::CircledAlphaLower => {
+                circled_alpha(n, false).unwrap_or_else(|| to_alpha(n, false))
+            }
+            NumberShape::Hangul => hangul_gana(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::HangulJamo => hangul_jamo(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::CircledHangul => circled_hangul_gana(n)
+                .unwrap_or_else(|| hangul_gana(n).unwrap_or_else(|| n.to_string())),
+            NumberShape::CircledHangulJamo => circled_hangul_jamo(n)
+                .unwrap_or_else(|| hangul_jamo(n).unwrap_or_else(|| n.to_string())),
+            NumberShape::HangulNumber => hangul_number(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::ChineseNumber => chinese_number(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::CircledChineseNumber => circled_chinese_number(n)
+                .unwrap_or_else(|| chinese_number(n).unwrap_or_else(|| n.to_string())),
+            NumberShape::HeavenlyStem => heavenly_stem(n).unwrap_or_else(|| n.to_string()),
+            NumberShape::HeavenlyStemChinese => {
+                heavenly_stem_chinese(n).unwrap_or_else(|| n.to_string())
+            }
+            NumberShape::FourCharRepeat => {
+                repeat_symbol(n, &['*', '\u{2020}', '\u{2021}', '\u{A7}'])
+                    .unwrap_or_else(|| n.to_string())
+            }
+            NumberShape::CustomCharRepeat => n.to_string(),
+        }
+    }
+
+    /// 번호 모양 코드로 찾기 (표 134 bit 0-7, 자동 번호는 표 143 bit 4-11)
+    /// Look up by number shape code (Table 134 bits 0-7; Table 143 bits 4-11 for auto numbers)
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0 => NumberShape::Arabic,
+            1 => NumberShape::CircledArabic,
+            2 => NumberShape::RomanUpper,
+            3 => NumberShape::RomanLower,
+            4 => NumberShape::AlphaUpper,
+            5 => NumberShape::AlphaLower,
+            6 => NumberShape::CircledAlphaUpper,
+            7 => NumberShape::CircledAlphaLower,
+            8 => NumberShape::Hangul,
+            9 => NumberShape::CircledHangul,
+            10 => NumberShape::HangulJamo,
+            11 => NumberShape::CircledHangulJamo,
+            12 => NumberShape::HangulNumber,
+            13 => NumberShape::ChineseNumber,
+            14 => NumberShape::CircledChineseNumber,
+            15 => NumberShape::HeavenlyStem,
+            16 => NumberShape::HeavenlyStemChinese,
+            0x80 => NumberShape::FourCharRepeat,
+            0x81 => NumberShape::CustomCharRepeat,
+            _ => NumberShape::Arabic, // 기본값 / default
+        }
+    }
+
+    /// HWPX 번호 모양 이름으로 찾기 (`hp:autoNumFormat`의 `type`) / Look up an HWPX number format name (`type` of `hp:autoNumFormat`)
+    pub fn from_hwpx_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "DIGIT" => NumberShape::Arabic,
+            "CIRCLED_DIGIT" => NumberShape::CircledArabic,
+            "ROMAN_CAPITAL" => NumberShape::RomanUpper,
+            "ROMAN_SMALL" => NumberShape::RomanLower,
+            "LATIN_CAPITAL" => NumberShape::AlphaUpper,
+            "LATIN_SMALL" => NumberShape::AlphaLower,
+            "CIRCLED_LATIN_CAPITAL" => NumberShape::CircledAlphaUpper,
+            "CIRCLED_LATIN_SMALL" => NumberShape::CircledAlphaLower,
+            "HANGUL_SYLLABLE" => NumberShape::Hangul,
+            "CIRCLED_HANGUL_SYLLABLE" => NumberShape::CircledHangul,
+            "HANGUL_JAMO" => NumberShape::HangulJamo,
+            "CIRCLED_HANGUL_JAMO" => NumberShape::CircledHangulJamo,
+            "HANGUL_PHONETIC" => NumberShape::HangulNumber,
+            "IDEOGRAPH" => NumberShape::ChineseNumber,
+            "CIRCLED_IDEOGRAPH" => NumberShape::CircledChineseNumber,
+            "DECAGON_CIRCLE" => NumberShape::HeavenlyStem,
+            "DECAGON_CIRCLE_HANJA" => NumberShape::HeavenlyStemChinese,
+            "SYMBOL" => NumberShape::FourCharRepeat,
+            "USER_CHAR" => NumberShape::CustomCharRepeat,
+            _ => return None,
+        })
+    }
+}
+
 /// 한 페이지 내에서 각주를 다단에 위치시킬 방법 / Page position method for footnotes in multi-column
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -210,34 +310,40 @@ impl FootnoteShape {
             breakline_color,
         })
     }
+
+    /// 번호를 이 모양의 문자열로 변환 (사용자 지정 문자 반복은 `custom_symbol`을 반복)
+    /// Format a number in this shape (custom character repeat repeats `custom_symbol`)
+    pub fn format_number(&self, n: u32) -> String {
+        match (self.attributes.number_shape, decoration(self.custom_symbol)) {
+            (NumberShape::CustomCharRepeat, Some(symbol)) => {
+                repeat_symbol(n, &[symbol]).unwrap_or_else(|| n.to_string())
+            }
+            (shape, _) => shape.format(n),
+        }
+    }
+
+    /// 앞뒤 장식 문자를 붙인 번호 (예: "1)") / Number with the front and back decorations (e.g. "1)")
+    pub fn decorated_number(&self, n: u32) -> String {
+        let mut number = String::new();
+        number.extend(decoration(self.front_decoration));
+        number.push_str(&self.format_number(n));
+        number.extend(decoration(self.back_decoration));
+        number
+    }
+
+    /// 미주를 구역 끝에 모으는지 여부 (아니면 문서 끝) / Whether endnotes are gathered at the end of the section (otherwise the end of the document)
+    ///
+    /// 미주 모양에서만 의미가 있습니다. / Only meaningful for an endnote shape.
+    pub fn endnotes_at_section_end(&self) -> bool {
+        self.attributes.page_position == PagePosition::Across
+    }
 }
 
 /// 각주/미주 모양 속성 파싱 (표 134) / Parse footnote/endnote shape attributes (Table 134)
 fn parse_footnote_shape_attributes(value: UINT32) -> FootnoteShapeAttributes {
     // bit 0-7: 번호 모양 / bit 0-7: number shape
     let number_shape_value = (value & 0xFF) as u8;
-    let number_shape = match number_shape_value {
-        0 => NumberShape::Arabic,
-        1 => NumberShape::CircledArabic,
-        2 => NumberShape::RomanUpper,
-        3 => NumberShape::RomanLower,
-        4 => NumberShape::AlphaUpper,
-        5 => NumberShape::AlphaLower,
-        6 => NumberShape::CircledAlphaUpper,
-        7 => NumberShape::CircledAlphaLower,
-        8 => NumberShape::Hangul,
-        9 => NumberShape::CircledHangul,
-        10 => NumberShape::HangulJamo,
-        11 => NumberShape::CircledHangulJamo,
-        12 => NumberShape::HangulNumber,
-        13 => NumberShape::ChineseNumber,
-        14 => NumberShape::CircledChineseNumber,
-        15 => NumberShape::HeavenlyStem,
-        16 => NumberShape::HeavenlyStemChinese,
-        0x80 => NumberShape::FourCharRepeat,
-        0x81 => NumberShape::CustomCharRepeat,
-        _ => NumberShape::Arabic, // 기본값 / default
-    };
+    let number_shape = NumberShape::from_code(number_shape_value);
 
     // bit 8-9: 한 페이지 내에서 각주를 다단에 위치시킬 방법 / bit 8-9: page position method
     let page_position = match (value >> 8) & 0x03 {
@@ -269,3 +375,210 @@ fn parse_footnote_shape_attributes(value: UINT32) -> FootnoteShapeAttributes {
         prefix,
     }
 }
+
+/// 장식/기호 문자 (0이면 없음) / Decoration or symbol character (none when 0)
+fn decoration(value: WCHAR) -> Option<char> {
+    (value != 0).then(|| char::from_u32(value as u32)).flatten()
+}
+
+/// 기호를 차례로 쓰고 한 바퀴 돌 때마다 하나씩 더 반복 (*, †, ‡, §, **, ...)
+/// Cycle through the symbols, repeating each once more per round (*, †, ‡, §, **, ...)
+fn repeat_symbol(n: u32, symbols: &[char]) -> Option<String> {
+    if n == 0 || n > 100 {
+        return None;
+    }
+    let count = symbols.len() as u32;
+    let symbol = symbols[((n - 1) % count) as usize];
+    Some(symbol.to_string().repeat(((n - 1) / count + 1) as usize))
+}
+
+fn circled_number(n: u32) -> Option<String> {
+    // ①..⑳ (U+2460..U+2473) for 1..20
+    if (1..=20).contains(&n) {
+        let cp = 0x2460 + (n - 1);
+        return char::from_u32(cp).map(|c| c.to_string());
+    }
+    // ㉑..㉟ (U+3251..U+325F) for 21..35 (circled numbers in a circle)
+    if (21..=35).contains(&n) {
+        let cp = 0x3251 + (n - 21);
+        return char::from_u32(cp).map(|c| c.to_string());
+    }
+    None
+}
+
+fn circled_alpha(n: u32, upper: bool) -> Option<String> {
+    // Ⓐ..Ⓩ (U+24B6..U+24CF) for 1..26
+    // ⓐ..ⓩ (U+24D0..U+24E9) for 1..26
+    if !(1..=26).contains(&n) {
+        return None;
+    }
+    let base = if upper { 0x24B6 } else { 0x24D0 };
+    let cp = base + (n - 1);
+    char::from_u32(cp).map(|c| c.to_string())
+}
+
+fn hangul_gana(n: u32) -> Option<String> {
+    // 가..하 (14 chars) used commonly for numbering
+    const LIST: [&str; 14] = [
+        "가", "나", "다", "라", "마", "바", "사", "아", "자", "차", "카", "타", "파", "하",
+    ];
+    if n == 0 {
+        return None;
+    }
+    let idx = ((n - 1) % (LIST.len() as u32)) as usize;
+    Some(LIST[idx].to_string())
+}
+
+fn circled_hangul_gana(n: u32) -> Option<String> {
+    // ㉮..㉻ (U+326E..U+327B) for 1..14
+    if !(1..=14).contains(&n) {
+        return None;
+    }
+    let cp = 0x326E + (n - 1);
+    char::from_u32(cp).map(|c| c.to_string())
+}
+
+fn hangul_jamo(n: u32) -> Option<String> {
+    // ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅅ ㅇ ㅈ ㅊ ㅋ ㅌ ㅍ ㅎ
+    const LIST: [&str; 14] = [
+        "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ",
+    ];
+    if n == 0 {
+        return None;
+    }
+    let idx = ((n - 1) % (LIST.len() as u32)) as usize;
+    Some(LIST[idx].to_string())
+}
+
+fn circled_hangul_jamo(n: u32) -> Option<String> {
+    // ㉠..㉭ (U+3260..U+326D) for 1..14
+    if !(1..=14).contains(&n) {
+        return None;
+    }
+    let cp = 0x3260 + (n - 1);
+    char::from_u32(cp).map(|c| c.to_string())
+}
+
+fn hangul_number(n: u32) -> Option<String> {
+    // 일, 이, 삼 ... (up to 99 for now)
+    if n == 0 || n > 99 {
+        return None;
+    }
+    const DIGITS: [&str; 10] = ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+    if n < 10 {
+        return Some(DIGITS[n as usize].to_string());
+    }
+    if n == 10 {
+        return Some("십".to_string());
+    }
+    let tens = n / 10;
+    let ones = n % 10;
+    let mut out = String::new();
+    if tens > 1 {
+        out.push_str(DIGITS[tens as usize]);
+    }
+    out.push('십');
+    if ones > 0 {
+        out.push_str(DIGITS[ones as usize]);
+    }
+    Some(out)
+}
+
+fn chinese_number(n: u32) -> Option<String> {
+    // 一, 二, 三 ... (up to 99 for now)
+    if n == 0 || n > 99 {
+        return None;
+    }
+    const DIGITS: [&str; 10] = ["", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+    if n < 10 {
+        return Some(DIGITS[n as usize].to_string());
+    }
+    if n == 10 {
+        return Some("十".to_string());
+    }
+    let tens = n / 10;
+    let ones = n % 10;
+    let mut out = String::new();
+    if tens > 1 {
+        out.push_str(DIGITS[tens as usize]);
+    }
+    out.push('十');
+    if ones > 0 {
+        out.push_str(DIGITS[ones as usize]);
+    }
+    Some(out)
+}
+
+fn circled_chinese_number(n: u32) -> Option<String> {
+    // ㊀..㊉ (U+3280..U+3289) for 1..10
+    if !(1..=10).contains(&n) {
+        return None;
+    }
+    let cp = 0x3280 + (n - 1);
+    char::from_u32(cp).map(|c| c.to_string())
+}
+
+fn heavenly_stem(n: u32) -> Option<String> {
+    // 갑, 을, 병, 정, 무, 기, 경, 신, 임, 계
+    const LIST: [&str; 10] = ["갑", "을", "병", "정", "무", "기", "경", "신", "임", "계"];
+    if n == 0 {
+        return None;
+    }
+    let idx = ((n - 1) % 10) as usize;
+    Some(LIST[idx].to_string())
+}
+
+fn heavenly_stem_chinese(n: u32) -> Option<String> {
+    // 甲, 乙, 丙, 丁, 戊, 己, 庚, 辛, 壬, 癸
+    const LIST: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+    if n == 0 {
+        return None;
+    }
+    let idx = ((n - 1) % 10) as usize;
+    Some(LIST[idx].to_string())
+}
+
+fn to_roman(mut n: u32) -> String {
+    if n == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let pairs = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    for (v, s) in pairs {
+        while n >= v {
+            out.push_str(s);
+            n -= v;
+        }
+    }
+    out
+}
+
+fn to_alpha(mut n: u32, upper: bool) -> String {
+    // 1->A, 26->Z, 27->AA
+    if n == 0 {
+        return String::new();
+    }
+    let mut buf: Vec<u8> = Vec::new();
+    while n > 0 {
+        n -= 1;
+        let c = (n % 26) as u8;
+        buf.push(if upper { b'A' + c } else { b'a' + c });
+        n /= 26;
+    }
+    buf.reverse();
+    String::from_utf8_lossy(&buf).to_string()
+}
@@ -27,12 +27,21 @@ pub struct ListHeaderAttribute {
 
 /// 텍스트 방향 / Text direction
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum TextDirection {
     /// 가로 / Horizontal
     Horizontal,
-    /// 세로 / Vertical
+    /// 세로 (영문 눕힘) / Vertical (Latin text laid sideways)
     Vertical,
+    /// 세로 (영문 세움, HWPX `VERTICALALL`) / Vertical with upright Latin text (HWPX `VERTICALALL`)
+    VerticalUpright,
+}
+
+impl TextDirection {
+    /// 세로쓰기인지 여부 / Whether the text runs vertically
+    pub fn is_vertical(self) -> bool {
+        !matches!(self, TextDirection::Horizontal)
+    }
 }
 
 /// 문단의 줄바꿈 / Paragraph line break
@@ -49,7 +58,7 @@ pub enum LineBreak {
 
 /// 세로 정렬 / Vertical alignment
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum VerticalAlign {
     /// 위쪽 / Top
     Top,
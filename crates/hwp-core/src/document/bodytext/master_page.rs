@@ -0,0 +1,71 @@
+/// MasterPage 구조체 / MasterPage structure
+///
+/// 스펙 문서 매핑: 표 139 - 바탕쪽 정보 / Spec mapping: Table 139 - Master page information
+///
+/// 바탕쪽은 쪽마다 본문 뒤에 깔리는 내용(워터마크, 배경 그림, 쪽 장식)입니다. HWP는 구역 정의
+/// 컨트롤의 자식 LIST_HEADER로, HWPX는 `Contents/masterpage*.xml`로 저장합니다.
+/// Master pages hold content laid behind the body of every page (watermarks, background
+/// pictures, page decorations). HWP stores them as LIST_HEADER children of the section
+/// definition control, HWPX as `Contents/masterpage*.xml`.
+use crate::document::bodytext::ctrl_header::ApplyPage;
+use crate::document::bodytext::Paragraph;
+use crate::types::{HWPUNIT, UINT32};
+use serde::{Deserialize, Serialize};
+
+/// 바탕쪽 / Master page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MasterPage {
+    /// 적용할 쪽 / Pages it applies to
+    pub apply_page: ApplyPage,
+    /// 텍스트 영역의 폭 / Width of the text area
+    pub text_width: HWPUNIT,
+    /// 텍스트 영역의 높이 / Height of the text area
+    pub text_height: HWPUNIT,
+    /// 바탕쪽 문단 / Master page paragraphs
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl MasterPage {
+    /// 바탕쪽 정보 LIST_HEADER에서 만들기 / Build from a master page information LIST_HEADER
+    ///
+    /// 문단 리스트 헤더(hwplib 방식 8바이트) 뒤에 텍스트 영역 폭/높이가 옵니다. 바탕쪽 정보에는
+    /// 적용할 쪽이 없어 양쪽으로 둡니다.
+    /// The text area width/height follow the paragraph list header (8 bytes, hwplib layout). The
+    /// master page information has no target pages, so it applies to both.
+    pub fn from_list_header(data: &[u8], paragraphs: Vec<Paragraph>) -> Self {
+        let read = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|bytes| UINT32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                .unwrap_or(0)
+        };
+        Self {
+            apply_page: ApplyPage::Both,
+            text_width: HWPUNIT::from(read(8)),
+            text_height: HWPUNIT::from(read(12)),
+            paragraphs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_master_page_from_list_header() {
+        let mut data = vec![1, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(&42520u32.to_le_bytes());
+        data.extend_from_slice(&65762u32.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        let master_page = MasterPage::from_list_header(&data, Vec::new());
+        assert_eq!(master_page.apply_page, ApplyPage::Both);
+        assert_eq!(master_page.text_width, HWPUNIT::from(42520));
+        assert_eq!(master_page.text_height, HWPUNIT::from(65762));
+        assert!(master_page.apply_page.applies_to(2));
+
+        // 짧은 레코드는 크기 0 / Short records get zero sizes
+        let short = MasterPage::from_list_header(&data[..8], Vec::new());
+        assert_eq!(short.text_width, HWPUNIT::from(0));
+    }
+}
@@ -7,7 +7,9 @@ mod char_shape;
 pub mod constants;
 pub use constants::HwpTag;
 pub mod control_char;
-pub use control_char::{ControlChar, ControlCharPosition};
+pub use control_char::{
+    normalize_inline_controls, ControlChar, ControlCharPosition, InlineControl,
+};
 pub mod chart_data;
 pub mod ctrl_data;
 pub mod ctrl_header;
@@ -16,6 +18,7 @@ pub mod footnote_shape;
 pub mod form_object;
 pub mod line_seg;
 pub mod list_header;
+pub mod master_page;
 pub mod memo_list;
 pub mod memo_shape;
 pub mod page_border_fill;
@@ -89,12 +92,15 @@ pub use chart_data::{
     Weighting,
 };
 pub use ctrl_data::CtrlData;
-pub use ctrl_header::{CtrlHeader, CtrlHeaderData, CtrlId, Margin, PageNumberPosition};
+pub use ctrl_header::{
+    CtrlHeader, CtrlHeaderData, CtrlId, Margin, ObjectPlacement, PageNumberPosition,
+};
 pub use eqedit::EqEdit;
-pub use footnote_shape::{FootnoteShape, NumberShape};
+pub use footnote_shape::{FootnoteShape, NumberShape, NumberingMethod, PagePosition};
 pub use form_object::FormObject;
 pub use line_seg::{LineSegmentInfo, ParaLineSeg};
 pub use list_header::ListHeader;
+pub use master_page::MasterPage;
 pub use memo_list::MemoList;
 pub use memo_shape::MemoShape;
 pub use page_border_fill::PageBorderFill;
@@ -113,20 +119,29 @@ pub use shape_component::ShapeComponentPolygon;
 pub use shape_component::ShapeComponentRectangle;
 pub use shape_component::ShapeComponentTextArt;
 pub use shape_component::ShapeComponentUnknown;
+pub use shape_component::{ImageCrop, ImageEffects};
 pub use table::{Table, TableCell};
 pub use video_data::VideoData;
 
 use crate::cfb::CfbParser;
 use crate::decompress::decompress_deflate;
 use crate::document::bodytext::ctrl_header::Caption;
+use crate::document::citations::CitationKind;
+use crate::document::docinfo::TrackChangeKind;
 use crate::document::fileheader::FileHeader;
-use crate::error::HwpError;
+use crate::error::{ErrorContext, HwpError};
 use crate::types::{decode_utf16le, RecordHeader, WORD};
 use cfb::CompoundFile;
 use record_tree::RecordTreeNode;
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
 
+/// 레코드 재귀 파싱의 최대 깊이 / Max depth of recursive record parsing
+///
+/// 정상 문서는 중첩 표를 포함해도 이 깊이에 훨씬 못 미칩니다.
+/// Real documents, even with nested tables, stay far below this depth.
+const MAX_RECORD_DEPTH: usize = 24;
+
 /// Body text structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BodyText {
@@ -150,6 +165,10 @@ pub struct Paragraph {
     pub para_header: ParaHeader,
     /// Paragraph records (level 1 records)
     pub records: Vec<ParagraphRecord>,
+    /// 계산된 개요 번호 (`1.2.3`), 파생 값으로 `HwpDocument::resolve_outline_numbers`가 채움
+    /// Computed outline number (`1.2.3`), a derived value filled by `HwpDocument::resolve_outline_numbers`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub resolved_number: Option<String>,
 }
 
 /// Paragraph record (level 1 records)
@@ -239,6 +258,11 @@ pub enum ParagraphRecord {
         /// 쪽 테두리/배경 정보 / Page border/fill information
         page_border_fill: PageBorderFill,
     },
+    /// 바탕쪽 / Master page
+    MasterPage {
+        /// 바탕쪽 정보와 문단 / Master page information and paragraphs
+        master_page: MasterPage,
+    },
     /// 개체 요소 / Shape component
     ShapeComponent {
         /// 개체 요소 정보 / Shape component information
@@ -293,6 +317,85 @@ pub enum ParagraphRecord {
     HwpxImage {
         /// 바이너리 아이템 참조 이름 (예: "image1") / Binary item reference name (e.g., "image1")
         binary_item_ref: String,
+        /// 표시 너비 (HWPUNIT, 알 수 없으면 0) / Display width (HWPUNIT, 0 if unknown)
+        #[serde(default)]
+        width: u32,
+        /// 표시 높이 (HWPUNIT, 알 수 없으면 0) / Display height (HWPUNIT, 0 if unknown)
+        #[serde(default)]
+        height: u32,
+        /// 자르기/보정 정보 / Crop and adjustment information
+        #[serde(default, skip_serializing_if = "ImageEffects::is_default")]
+        effects: ImageEffects,
+        /// 배치 정보 (<hp:pos>, textWrap, <hp:outMargin>) / Placement (<hp:pos>, textWrap, <hp:outMargin>)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        placement: Option<ObjectPlacement>,
+        /// 개체 설명문 (<hp:shapeComment>) / Object description (<hp:shapeComment>)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// 캡션 텍스트 (<hp:caption>) / Caption text (<hp:caption>)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    /// HWPX 메모 / HWPX memo
+    /// `<hp:fieldBegin type="MEMO">`의 내용과 메모가 달린 본문 / Contents of `<hp:fieldBegin type="MEMO">` and the annotated body text
+    HwpxMemo {
+        /// 작성자 / Author
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        author: Option<String>,
+        /// 작성 일시 / Creation date
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        date: Option<String>,
+        /// 메모 내용 / Memo text
+        text: String,
+        /// 메모가 달린 본문 / Annotated body text
+        quoted: String,
+    },
+    /// HWPX 숨은 설명 / HWPX hidden comment
+    /// `<hp:hiddenComment>` 안 문단의 텍스트 (본문에는 넣지 않음) / Text of the paragraphs inside `<hp:hiddenComment>` (kept out of the body)
+    HwpxHiddenComment {
+        /// 숨은 설명 내용 / Hidden comment text
+        text: String,
+    },
+    /// HWPX 변경 추적 구간 / HWPX tracked change span
+    /// `<hp:insertBegin>`/`<hp:deleteBegin>`부터 끝 표시까지의 본문 / Body text from `<hp:insertBegin>`/`<hp:deleteBegin>` to the end marker
+    HwpxTrackChange {
+        /// 변경 종류 / Change kind
+        kind: TrackChangeKind,
+        /// header.xml의 변경 ID (`TcId`) / Change ID in header.xml (`TcId`)
+        change_id: u32,
+        /// 변경된 본문 / Changed body text
+        text: String,
+    },
+    /// HWPX 인용/상호 참조 필드 / HWPX citation or cross-reference field
+    /// `<hp:fieldBegin type="CITATION">`/`type="CROSSREF"`부터 `<hp:fieldEnd>`까지 / From `<hp:fieldBegin type="CITATION">`/`type="CROSSREF"` to `<hp:fieldEnd>`
+    HwpxCitation {
+        /// 필드 종류 / Field kind
+        kind: CitationKind,
+        /// 필드 이름 / Field name
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        /// 필드 명령 (`Command` 매개변수) / Field command (`Command` parameter)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        command: Option<String>,
+        /// 본문에 표시된 텍스트 / Text displayed in the body
+        text: String,
+    },
+    /// HWPX 필드 (날짜, 파일 경로, 요약 정보 등) / HWPX field (date, file path, summary information, ...)
+    /// 메모와 인용을 제외한 `<hp:fieldBegin>`부터 `<hp:fieldEnd>`까지 / From `<hp:fieldBegin>` to `<hp:fieldEnd>`, except memos and citations
+    HwpxField {
+        /// 필드 종류 (`type` 속성, 예: "DATE") / Field type (`type` attribute, e.g. "DATE")
+        field_type: String,
+        /// 필드 이름 / Field name
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        /// 필드 명령 (`Command` 매개변수) / Field command (`Command` parameter)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        command: Option<String>,
+        /// 저장된 표시 텍스트 / Stored display text
+        text: String,
+        /// 문단 텍스트에서 필드가 시작하는 문자 위치 / Character offset where the field starts in the paragraph text
+        #[serde(default)]
+        offset: usize,
     },
     /// 묶음 개체 / Container shape component
     ShapeComponentContainer {
@@ -378,7 +481,171 @@ pub enum ParaTextRun {
     },
 }
 
+impl Paragraph {
+    /// 계산된 개요 번호 (`1.2.3`), 개요 문단이 아니거나 번호가 없으면 None
+    /// Computed outline number (`1.2.3`), None for paragraphs without an outline number
+    pub fn resolved_number(&self) -> Option<&str> {
+        self.resolved_number.as_deref()
+    }
+
+    /// 문단 자체의 텍스트 (ParaText 레코드를 이어 붙임) / Paragraph's own text (ParaText records joined)
+    ///
+    /// 표 셀이나 글상자 등 중첩된 문단의 텍스트는 포함하지 않습니다.
+    /// Text of nested paragraphs (table cells, text boxes, etc.) is not included.
+    pub fn text(&self) -> String {
+        self.records
+            .iter()
+            .filter_map(|record| match record {
+                ParagraphRecord::ParaText { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 이 문단에 포함된 중첩 문단 목록 (깊이 우선 순서) / Nested paragraphs contained in this paragraph (depth-first order)
+    ///
+    /// 컨트롤 헤더(머리말/각주 등), 리스트 헤더, 표 셀, 개체 자식 안의 문단을 모두 모읍니다.
+    /// Collects paragraphs inside control headers (header/footnote, etc.), list headers, table cells and shape children.
+    pub fn nested_paragraphs(&self) -> Vec<&Paragraph> {
+        let mut result = Vec::new();
+        for record in &self.records {
+            collect_record_paragraphs(record, &mut result);
+        }
+        result
+    }
+
+    /// 이 문단에 직접 포함된 표 목록 / Tables directly contained in this paragraph
+    ///
+    /// HWPX는 표를 문단 레코드로, HWP5는 컨트롤 헤더의 자식으로 저장하므로 둘 다 확인합니다.
+    /// 표 셀 안의 중첩 표는 포함하지 않습니다.
+    /// HWPX stores tables as paragraph records and HWP5 as control header children, so both are checked.
+    /// Tables nested inside table cells are not included.
+    pub fn tables(&self) -> Vec<&Table> {
+        let mut tables = Vec::new();
+        for record in &self.records {
+            match record {
+                ParagraphRecord::Table { table } => tables.push(table),
+                ParagraphRecord::CtrlHeader { children, .. } => {
+                    for child in children {
+                        if let ParagraphRecord::Table { table } = child {
+                            tables.push(table);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        tables
+    }
+
+    /// 이 문단과 중첩 문단에 들어 있는 차트 목록 / Charts in this paragraph and its nested paragraphs
+    pub fn charts(&self) -> Vec<&ChartData> {
+        fn collect<'a>(records: &'a [ParagraphRecord], out: &mut Vec<&'a ChartData>) {
+            for record in records {
+                match record {
+                    ParagraphRecord::ChartData { chart_data } => out.push(chart_data),
+                    ParagraphRecord::CtrlHeader { children, .. }
+                    | ParagraphRecord::ShapeComponent { children, .. } => collect(children, out),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut charts = Vec::new();
+        collect(&self.records, &mut charts);
+        for nested in self.nested_paragraphs() {
+            collect(&nested.records, &mut charts);
+        }
+        charts
+    }
+}
+
+/// 레코드 안의 중첩 문단을 깊이 우선으로 수집 / Collect nested paragraphs of a record depth-first
+pub(crate) fn collect_record_paragraphs<'a>(
+    record: &'a ParagraphRecord,
+    out: &mut Vec<&'a Paragraph>,
+) {
+    fn push_all<'a>(paragraphs: &'a [Paragraph], out: &mut Vec<&'a Paragraph>) {
+        for paragraph in paragraphs {
+            out.push(paragraph);
+            for record in &paragraph.records {
+                collect_record_paragraphs(record, out);
+            }
+        }
+    }
+
+    match record {
+        ParagraphRecord::CtrlHeader {
+            children,
+            paragraphs,
+            ..
+        } => {
+            for child in children {
+                collect_record_paragraphs(child, out);
+            }
+            push_all(paragraphs, out);
+        }
+        ParagraphRecord::ListHeader { paragraphs, .. } => push_all(paragraphs, out),
+        ParagraphRecord::Table { table } => {
+            for cell in &table.cells {
+                push_all(&cell.paragraphs, out);
+            }
+        }
+        ParagraphRecord::ShapeComponent { children, .. } => {
+            for child in children {
+                collect_record_paragraphs(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
 impl Section {
+    /// 구역의 각주 모양과 미주 모양 / Footnote and endnote shapes of the section
+    ///
+    /// 구역 정의(첫 문단의 구역 정의 컨트롤 자식, HWPX는 첫 문단 레코드)에 각주, 미주 순으로
+    /// 저장된 FootnoteShape를 찾습니다.
+    /// Finds the FootnoteShape records stored footnote first, endnote second in the section
+    /// definition (children of the section definition control in the first paragraph, or the
+    /// first paragraph's records for HWPX).
+    ///
+    /// # Returns / 반환값
+    /// (각주 모양, 미주 모양) / (footnote shape, endnote shape)
+    pub fn note_shapes(&self) -> (Option<&FootnoteShape>, Option<&FootnoteShape>) {
+        let mut shapes = self.definition_records().filter_map(|record| match record {
+            ParagraphRecord::FootnoteShape { footnote_shape } => Some(footnote_shape),
+            _ => None,
+        });
+        (shapes.next(), shapes.next())
+    }
+
+    /// 구역의 바탕쪽 / Master pages of the section
+    ///
+    /// 각주/미주 모양과 같이 구역 정의에 저장된 바탕쪽을 저장된 순서대로 돌려줍니다.
+    /// Returns the master pages stored in the section definition, like the note shapes, in
+    /// stored order.
+    pub fn master_pages(&self) -> Vec<&MasterPage> {
+        self.definition_records()
+            .filter_map(|record| match record {
+                ParagraphRecord::MasterPage { master_page } => Some(master_page),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 첫 문단의 레코드와 그 컨트롤 자식 (구역 정의가 있는 곳)
+    /// Records of the first paragraph and their control children (where the section definition lives)
+    fn definition_records(&self) -> impl Iterator<Item = &ParagraphRecord> {
+        self.paragraphs
+            .first()
+            .into_iter()
+            .flat_map(|paragraph| &paragraph.records)
+            .flat_map(|record| match record {
+                ParagraphRecord::CtrlHeader { children, .. } => children.iter().collect(),
+                record => vec![record],
+            })
+    }
+
     /// Section 데이터를 파싱하여 Paragraph 리스트로 변환합니다. / Parse section data into paragraph list.
     ///
     /// 트리 구조를 먼저 파싱한 후 재귀적으로 방문하여 구조화된 데이터로 변환합니다.
@@ -398,7 +665,7 @@ impl Section {
         let mut paragraphs = Vec::new();
         for child in tree.children() {
             if child.tag_id() == HwpTag::PARA_HEADER {
-                paragraphs.push(Self::parse_paragraph_from_tree(child, version, data)?);
+                paragraphs.push(Self::parse_paragraph_from_tree(child, version, data, 0)?);
             }
         }
 
@@ -413,8 +680,9 @@ impl Section {
         node: &RecordTreeNode,
         version: u32,
         original_data: &[u8],
+        depth: usize,
     ) -> Result<Paragraph, HwpError> {
-        Self::parse_paragraph_from_tree_internal(node, version, original_data, false)
+        Self::parse_paragraph_from_tree_internal(node, version, original_data, false, depth)
     }
 
     /// 문단 헤더 노드와 그 자식들을 재귀적으로 처리합니다 (내부 함수).
@@ -426,6 +694,7 @@ impl Section {
         version: u32,
         original_data: &[u8],
         is_inside_control_header: bool,
+        depth: usize,
     ) -> Result<Paragraph, HwpError> {
         if node.tag_id() != HwpTag::PARA_HEADER {
             return Err(HwpError::UnexpectedValue {
@@ -435,12 +704,19 @@ impl Section {
             });
         }
 
-        let para_header = ParaHeader::parse(node.data(), version)?;
+        let para_header = ParaHeader::parse(node.data(), version).map_err(|error| {
+            error.with_context(ErrorContext::new().with_record_tag(HwpTag::PARA_HEADER))
+        })?;
         let mut records = Vec::new();
 
-        // 자식들을 처리 / Process children
+        // 자식들을 처리 (오류에는 가장 안쪽 레코드의 태그가 남음)
+        // Process children (errors keep the tag of the innermost record)
         for child in node.children() {
-            records.push(Self::parse_record_from_tree(child, version, original_data)?);
+            records.push(
+                Self::parse_record_from_tree(child, version, original_data, depth + 1).map_err(
+                    |error| error.with_context(ErrorContext::new().with_record_tag(child.tag_id())),
+                )?,
+            );
         }
 
         // 컨트롤 헤더 내부의 머리말/꼬리말/각주/미주 문단인 경우에만 ParaText 레코드 제거 (중복 방지, hwplib 방식)
@@ -494,6 +770,7 @@ impl Section {
         Ok(Paragraph {
             para_header,
             records,
+            resolved_number: None,
         })
     }
 
@@ -505,7 +782,14 @@ impl Section {
         node: &RecordTreeNode,
         version: u32,
         original_data: &[u8],
+        depth: usize,
     ) -> Result<ParagraphRecord, HwpError> {
+        // 손상된 파일에서 무한 재귀로 스택이 넘치지 않도록 깊이 제한 / Cap depth so corrupt files cannot overflow the stack
+        if depth > MAX_RECORD_DEPTH {
+            return Err(HwpError::RecordTreeParseError {
+                reason: format!("record nesting exceeds {MAX_RECORD_DEPTH} levels"),
+            });
+        }
         match node.tag_id() {
             HwpTag::PARA_TEXT => {
                 let data = node.data();
@@ -575,22 +859,14 @@ impl Section {
                             }
                         }
 
-                        // 텍스트로 표현 가능한 제어 문자는 텍스트에 변환된 형태로 추가 / Add convertible control characters as converted text
-                        // 단, PARA_BREAK와 LINE_BREAK는 control_char_positions에만 저장하고 텍스트에는 포함하지 않음
-                        // However, PARA_BREAK and LINE_BREAK are only stored in control_char_positions, not added to text
-                        if ControlChar::is_convertible(control_code) {
-                            // PARA_BREAK와 LINE_BREAK는 텍스트에 추가하지 않음 (control_char_positions에만 저장)
-                            // Don't add PARA_BREAK and LINE_BREAK to text (only store in control_char_positions)
-                            if control_code != ControlChar::PARA_BREAK
-                                && control_code != ControlChar::LINE_BREAK
-                            {
-                                if let Some(text_repr) = ControlChar::to_text(control_code) {
-                                    cleaned_text.push_str(text_repr);
-                                    runs.push(ParaTextRun::Text {
-                                        text: text_repr.to_string(),
-                                    });
-                                }
-                            }
+                        // 인라인 제어 문자(탭, 하이픈, 빈칸)는 고유한 문자로 텍스트에 남김 / Inline controls (tab, hyphen, spaces) stay in text as their own characters
+                        // PARA_BREAK와 LINE_BREAK는 control_char_positions에만 저장하고 텍스트에는 포함하지 않음
+                        // PARA_BREAK and LINE_BREAK are only stored in control_char_positions, not added to text
+                        if let Some(control) = InlineControl::from_code(control_code) {
+                            cleaned_text.push(control.as_char());
+                            runs.push(ParaTextRun::Text {
+                                text: control.as_char().to_string(),
+                            });
                         }
                         // 제거해야 할 제어 문자는 텍스트에 추가하지 않음 / Don't add removable control characters to text
 
@@ -598,13 +874,12 @@ impl Section {
                     } else {
                         // 텍스트 부분 디코딩 / Decode text portion
                         // 다음 제어 문자까지 또는 끝까지 / Until next control character or end
+                        // 홀수 길이면 마지막 1바이트는 무시 / Ignore a trailing odd byte
                         let mut text_end = idx;
-                        while text_end < data.len() {
-                            if text_end + 1 < data.len() {
-                                let code = data[text_end] as u32;
-                                if code <= 31 && data[text_end + 1] == 0 {
-                                    break; // 제어 문자 발견 / Control character found
-                                }
+                        while text_end + 1 < data.len() {
+                            let code = data[text_end] as u32;
+                            if code <= 31 && data[text_end + 1] == 0 {
+                                break; // 제어 문자 발견 / Control character found
                             }
                             text_end += 2; // UTF-16LE는 2바이트 단위 / UTF-16LE is 2-byte units
                         }
@@ -774,6 +1049,7 @@ impl Section {
                                 version,
                                 original_data,
                                 true, // 컨트롤 헤더 내부이므로 true / true because inside control header
+                                depth + 1,
                             )?;
                             paragraphs.push(paragraph);
                         }
@@ -791,7 +1067,7 @@ impl Section {
                         // libhwp 방식: TABLE 이후의 LIST_HEADER는 children에 추가하지 않음
                         // libhwp approach: LIST_HEADERs after TABLE are not added to children
                         let list_header_record =
-                            Self::parse_record_from_tree(child, version, original_data)?;
+                            Self::parse_record_from_tree(child, version, original_data, depth + 1)?;
                         // 테이블 셀로 처리하기 위해 paragraphs 추출 / Extract paragraphs for table cell processing
                         let paragraphs_for_cell = if let ParagraphRecord::ListHeader {
                             header: _,
@@ -897,8 +1173,25 @@ impl Section {
                             paragraphs_for_cell,
                             cell_attrs_opt,
                         ));
+                    } else if child.tag_id() == HwpTag::LIST_HEADER
+                        && ctrl_header.ctrl_id == CtrlId::SECTION_DEF
+                    {
+                        // 구역 정의의 LIST_HEADER는 바탕쪽 (표 139), 본문 문단으로 섞이지 않게 따로 둠
+                        // LIST_HEADERs of a section definition are master pages (Table 139), kept apart from body paragraphs
+                        let record =
+                            Self::parse_record_from_tree(child, version, original_data, depth + 1)?;
+                        if let ParagraphRecord::ListHeader { paragraphs, .. } = record {
+                            children.push(ParagraphRecord::MasterPage {
+                                master_page: MasterPage::from_list_header(child.data(), paragraphs),
+                            });
+                        }
                     } else {
-                        children.push(Self::parse_record_from_tree(child, version, original_data)?);
+                        children.push(Self::parse_record_from_tree(
+                            child,
+                            version,
+                            original_data,
+                            depth + 1,
+                        )?);
                     }
                 }
 
@@ -1182,6 +1475,7 @@ impl Section {
                                 child,
                                 version,
                                 original_data,
+                                depth + 1,
                             )?);
                         } else {
                             // 자식의 자식도 확인하기 위해 스택에 추가 / Add to stack to check children of children
@@ -1271,12 +1565,23 @@ impl Section {
                                                         children: Vec::new(),
                                                     };
 
-                                                    let parsed_record =
+                                                    // 다시 읽은 LIST_HEADER는 원본 데이터를 다시 찾지 않음 (같은 레코드를 계속 찾을 수 있음)
+                                                    // Re-read LIST_HEADERs don't rescan the raw data (they could keep finding themselves)
+                                                    let parsed_record = if child_header.tag_id
+                                                        == HwpTag::LIST_HEADER
+                                                    {
+                                                        ParagraphRecord::ListHeader {
+                                                            header: ListHeader::parse(child_data)?,
+                                                            paragraphs: Vec::new(),
+                                                        }
+                                                    } else {
                                                         Self::parse_record_from_tree(
                                                             &child_node,
                                                             version,
                                                             original_data,
-                                                        )?;
+                                                            depth + 1,
+                                                        )?
+                                                    };
                                                     // 디버그: ListHeader 내부의 ParaText 확인 / Debug: Check ParaText inside ListHeader
                                                     if let ParagraphRecord::ParaText {
                                                         text, ..
@@ -1302,6 +1607,7 @@ impl Section {
                                         paragraphs.push(Paragraph {
                                             para_header,
                                             records: para_records,
+                                            resolved_number: None,
                                         });
 
                                         para_count += 1;
@@ -1372,7 +1678,7 @@ impl Section {
                     if child.tag_id() == HwpTag::LIST_HEADER {
                         // LIST_HEADER 파싱 / Parse LIST_HEADER
                         let list_header_record =
-                            Self::parse_record_from_tree(child, version, original_data)?;
+                            Self::parse_record_from_tree(child, version, original_data, depth + 1)?;
 
                         // LIST_HEADER 다음에 PARA_HEADER가 있는지 확인하고 처리
                         // Check if PARA_HEADER follows LIST_HEADER and process it
@@ -1394,6 +1700,7 @@ impl Section {
                                     next_child,
                                     version,
                                     original_data,
+                                    depth + 1,
                                 ) {
                                     para_headers_found.push(paragraph);
                                     next_index += 1;
@@ -1429,7 +1736,12 @@ impl Section {
 
                         children.push(list_header_with_paragraphs);
                     } else {
-                        children.push(Self::parse_record_from_tree(child, version, original_data)?);
+                        children.push(Self::parse_record_from_tree(
+                            child,
+                            version,
+                            original_data,
+                            depth + 1,
+                        )?);
                         index += 1;
                     }
                 }
@@ -1672,12 +1984,15 @@ impl BodyText {
                 Ok(mut section_data) => {
                     // 압축 해제 (필요한 경우) / Decompress if needed
                     // HWP BodyText uses raw deflate format (windowBits: -15), not zlib
+                    let context = || ErrorContext::in_container(format!("BodyText/{stream_name}"));
                     if file_header.is_compressed() {
-                        section_data = decompress_deflate(&section_data)?;
+                        section_data = decompress_deflate(&section_data)
+                            .map_err(|error| error.with_context(context()))?;
                     }
 
                     // Section 데이터를 Paragraph 리스트로 파싱 / Parse section data into paragraph list
-                    let paragraphs = Section::parse_data(&section_data, file_header.version)?;
+                    let paragraphs = Section::parse_data(&section_data, file_header.version)
+                        .map_err(|error| error.with_context(context()))?;
 
                     sections.push(Section {
                         index: i,
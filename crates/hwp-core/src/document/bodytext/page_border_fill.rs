@@ -61,15 +61,15 @@ impl PageBorderFill {
     /// PageBorderFill를 바이트 배열에서 파싱합니다. / Parse PageBorderFill from byte array.
     ///
     /// # Arguments
-    /// * `data` - 최소 12바이트의 데이터 / At least 12 bytes of data
+    /// * `data` - 최소 14바이트의 데이터 / At least 14 bytes of data
     ///
     /// # Returns
     /// 파싱된 PageBorderFill 구조체 / Parsed PageBorderFill structure
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
-        if data.len() < 12 {
+        if data.len() < 14 {
             return Err(HwpError::insufficient_data(
                 "PageBorderFill",
-                12,
+                14,
                 data.len(),
             ));
         }
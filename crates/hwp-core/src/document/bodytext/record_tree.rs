@@ -2,7 +2,7 @@
 ///
 /// HWP 레코드는 계층 구조로 저장되며, 레벨 정보를 통해 트리 구조를 재구성할 수 있습니다.
 /// HWP records are stored in a hierarchical structure, and the tree structure can be reconstructed using level information.
-use crate::error::HwpError;
+use crate::error::{ErrorContext, HwpError};
 use crate::types::RecordHeader;
 
 /// 레코드 트리 노드 / Record tree node
@@ -58,8 +58,11 @@ impl RecordTreeNode {
 
         while offset < data.len() {
             // 레코드 헤더 파싱 / Parse record header
+            let record_start = offset;
             let remaining_data = &data[offset..];
-            let (header, header_size) = RecordHeader::parse(remaining_data)?;
+            let (header, header_size) = RecordHeader::parse(remaining_data).map_err(|error| {
+                error.with_context(ErrorContext::new().with_byte_offset(record_start as u64))
+            })?;
             offset += header_size;
 
             // 데이터 영역 읽기 / Read data area
@@ -69,7 +72,12 @@ impl RecordTreeNode {
                     field: format!("Record at offset {offset}"),
                     expected: offset + data_size,
                     actual: data.len(),
-                });
+                }
+                .with_context(
+                    ErrorContext::new()
+                        .with_byte_offset(record_start as u64)
+                        .with_record_tag(header.tag_id),
+                ));
             }
 
             let record_data = &data[offset..offset + data_size];
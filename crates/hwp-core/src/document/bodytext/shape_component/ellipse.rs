@@ -83,12 +83,12 @@ impl ShapeComponentEllipse {
     /// Current test file (`noori.hwp`) does not contain SHAPE_COMPONENT_ELLIPSE records, so it has not been verified with actual files.
     /// If an actual HWP file contains SHAPE_COMPONENT_ELLIPSE records, they will be automatically parsed.
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
-        // 표 96: 타원 개체 속성은 60바이트 / Table 96: Ellipse shape component attributes is 60 bytes
-        // UINT32(4) + INT32(4) * 14 = 60 bytes
-        if data.len() < 60 {
+        // 표 96: 타원 개체 속성은 64바이트 / Table 96: Ellipse shape component attributes is 64 bytes
+        // UINT32(4) + INT32(4) * 15 = 64 bytes
+        if data.len() < 64 {
             return Err(HwpError::insufficient_data(
                 "ShapeComponentEllipse",
-                60,
+                64,
                 data.len(),
             ));
         }
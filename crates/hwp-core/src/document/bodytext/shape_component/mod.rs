@@ -19,7 +19,7 @@ pub use curve::ShapeComponentCurve;
 pub use ellipse::ShapeComponentEllipse;
 pub use line::ShapeComponentLine;
 pub use ole::ShapeComponentOle;
-pub use picture::ShapeComponentPicture;
+pub use picture::{ImageCrop, ImageEffects, ShapeComponentPicture};
 pub use polygon::ShapeComponentPolygon;
 pub use rectangle::ShapeComponentRectangle;
 pub use textart::ShapeComponentTextArt;
@@ -95,7 +95,100 @@ pub struct PictureInfo {
     pub bindata_id: UINT16,
 }
 
+/// 그림 보정/자르기 정보 (렌더링용) / Picture adjustment and crop information (for rendering)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageEffects {
+    /// 자르기 영역 (없으면 전체 그림) / Crop area (None for the full picture)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crop: Option<ImageCrop>,
+    /// 밝기 (-100~100) / Brightness (-100 to 100)
+    #[serde(default)]
+    pub brightness: INT8,
+    /// 명암 (-100~100) / Contrast (-100 to 100)
+    #[serde(default)]
+    pub contrast: INT8,
+    /// 그림 효과 (`PictureInfo::effect`와 같은 값) / Picture effect (same values as `PictureInfo::effect`)
+    #[serde(default)]
+    pub effect: u8,
+    /// 투명도 (0: 불투명, 255: 완전 투명) / Transparency (0: opaque, 255: fully transparent)
+    #[serde(default)]
+    pub alpha: u8,
+}
+
+impl ImageEffects {
+    /// 보정/자르기가 없는지 여부 / Whether no adjustment or crop is applied
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// 원본 그림에서 각 가장자리를 잘라낸 비율 (0.0~1.0) / Fraction cut from each edge of the original picture (0.0 to 1.0)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ImageCrop {
+    /// 왼쪽 / Left
+    pub left: f64,
+    /// 위쪽 / Top
+    pub top: f64,
+    /// 오른쪽 / Right
+    pub right: f64,
+    /// 아래쪽 / Bottom
+    pub bottom: f64,
+}
+
+impl ImageCrop {
+    /// 원본 크기 기준 자르기 사각형에서 생성 / Create from a clip rectangle in original image coordinates
+    ///
+    /// # Arguments
+    /// * `rect` - 남길 영역 (left, top, right, bottom) / Area to keep (left, top, right, bottom)
+    /// * `image_width` - 원본 너비 / Original width
+    /// * `image_height` - 원본 높이 / Original height
+    ///
+    /// # Returns
+    /// 잘라낸 부분이 있으면 Some, 전체 그림이거나 값이 잘못되면 None
+    /// Some if anything is cut, None for the full picture or invalid values
+    pub fn from_rect(
+        rect: (i32, i32, i32, i32),
+        image_width: i32,
+        image_height: i32,
+    ) -> Option<Self> {
+        let (left, top, right, bottom) = rect;
+        if image_width <= 0 || image_height <= 0 || left >= right || top >= bottom {
+            return None;
+        }
+        let width = image_width as f64;
+        let height = image_height as f64;
+        let crop = ImageCrop {
+            left: (left.max(0) as f64 / width).min(1.0),
+            top: (top.max(0) as f64 / height).min(1.0),
+            right: ((image_width - right).max(0) as f64 / width).min(1.0),
+            bottom: ((image_height - bottom).max(0) as f64 / height).min(1.0),
+        };
+        if crop.left + crop.right >= 1.0 || crop.top + crop.bottom >= 1.0 {
+            return None;
+        }
+        if crop.left == 0.0 && crop.top == 0.0 && crop.right == 0.0 && crop.bottom == 0.0 {
+            return None;
+        }
+        Some(crop)
+    }
+}
+
 impl ShapeComponentPicture {
+    /// 렌더링에 적용할 그림 보정 정보 / Picture adjustments to apply when rendering
+    ///
+    /// 자르기 사각형은 레코드에 없는 원본 그림 크기 기준이므로 자르기는 포함하지 않습니다.
+    /// The crop rectangle is relative to the original image size, which the record does not
+    /// store, so no crop is reported.
+    pub fn image_effects(&self) -> ImageEffects {
+        ImageEffects {
+            crop: None,
+            brightness: self.picture_info.brightness,
+            contrast: self.picture_info.contrast,
+            effect: self.picture_info.effect,
+            alpha: 0,
+        }
+    }
+
     /// ShapeComponentPicture을 바이트 배열에서 파싱합니다. / Parse ShapeComponentPicture from byte array.
     ///
     /// # Arguments
@@ -1,6 +1,7 @@
 /// Table 구조체 / Table structure
 ///
 /// 스펙 문서 매핑: 표 74 - 표 개체 / Spec mapping: Table 74 - Table object
+use crate::document::docinfo::{BorderFill, FillInfo};
 use crate::error::HwpError;
 use crate::types::{HWPUNIT, HWPUNIT16, UINT16, UINT32};
 use serde::{Deserialize, Serialize};
@@ -125,6 +126,23 @@ pub struct CellAttributes {
     pub border_fill_id: UINT16,
 }
 
+impl TableCell {
+    /// 셀 텍스트 (비어 있지 않은 문단을 줄바꿈으로 연결) / Cell text (non-empty paragraphs joined by newlines)
+    ///
+    /// 셀 안의 중첩 표나 글상자의 문단도 포함합니다. / Includes paragraphs of nested tables and text boxes.
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .flat_map(|p| std::iter::once(p).chain(p.nested_paragraphs()))
+            .map(|p| p.text())
+            .filter(|t| !t.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string()
+    }
+}
+
 impl Table {
     /// Table을 바이트 배열에서 파싱합니다. / Parse Table from byte array.
     ///
@@ -164,6 +182,74 @@ impl Table {
 
         Ok(Table { attributes, cells })
     }
+
+    /// 머리행 수 / Number of header rows
+    ///
+    /// 제목 줄 반복이 켜진 표는 첫 행을 머리행으로 봅니다. 꺼져 있어도 첫 행 칸이 모두 같은
+    /// 배경색으로 칠해져 있고 나머지 칸에 그 색이 없으면 배경으로 구분한 머리행으로 봅니다.
+    /// 첫 행 칸이 모두 아래로 병합되어 있으면 병합된 행까지 머리행입니다.
+    /// Tables with header row repeat treat the first row as the header. Without it, the first row
+    /// is still a header when all of its cells share one background color that no other cell
+    /// uses. When every first-row cell spans downwards, the spanned rows belong to the header too.
+    ///
+    /// # Arguments / 매개변수
+    /// * `border_fills` - 문서의 테두리/배경 목록 (`doc_info.border_fill`) / Document border fills
+    pub fn header_rows(&self, border_fills: &[BorderFill]) -> usize {
+        let row_count = self.attributes.row_count as usize;
+        let first_row = self
+            .cells
+            .iter()
+            .map(|cell| cell.cell_attributes.row_address)
+            .min()
+            .unwrap_or(0);
+        let header: Vec<&TableCell> = self
+            .cells
+            .iter()
+            .filter(|cell| cell.cell_attributes.row_address == first_row)
+            .collect();
+        let depth = header
+            .iter()
+            .map(|cell| cell.cell_attributes.row_span.max(1) as usize)
+            .min()
+            .unwrap_or(1);
+        // 본문 행이 없는 표는 머리행도 없음 / Tables without body rows have no header either
+        if depth >= row_count {
+            return 0;
+        }
+        if self.attributes.attribute.header_row_repeat {
+            return depth;
+        }
+
+        // 테두리/배경 ID는 1부터 시작, 0이면 표의 테두리/배경 / Border fill IDs are 1-based, 0 falls back to the table's
+        let background = |cell: &TableCell| {
+            let id = match cell.cell_attributes.border_fill_id {
+                0 => self.attributes.border_fill_id,
+                id => id,
+            };
+            match &border_fills.get((id as usize).checked_sub(1)?)?.fill {
+                FillInfo::Solid(solid) => {
+                    let color = solid.background_color.0;
+                    (color != 0 && color < 0xFFFFFF).then_some(color)
+                }
+                _ => None,
+            }
+        };
+        let Some(color) = header.first().and_then(|cell| background(cell)) else {
+            return 0;
+        };
+        let body_row = first_row as usize + depth;
+        let shaded = header.iter().all(|cell| background(cell) == Some(color))
+            && self
+                .cells
+                .iter()
+                .filter(|cell| cell.cell_attributes.row_address as usize >= body_row)
+                .all(|cell| background(cell) != Some(color));
+        if shaded {
+            depth
+        } else {
+            0
+        }
+    }
 }
 
 /// 표 개체 속성 파싱 (표 75) / Parse table object attributes (Table 75)
@@ -236,6 +322,13 @@ fn parse_table_attributes(
     }
 
     // UINT16 Border Fill ID / UINT16 Border Fill ID
+    if *offset + 2 > data.len() {
+        return Err(HwpError::insufficient_data(
+            "border fill ID",
+            2,
+            data.len() - *offset,
+        ));
+    }
     let border_fill_id = UINT16::from_le_bytes([data[*offset], data[*offset + 1]]);
     *offset += 2;
 
@@ -442,3 +535,74 @@ fn parse_cell_list(
 
     Ok(cells)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+
+    fn sample_table() -> Table {
+        let document = DocumentBuilder::new()
+            .add_table(&[["구분", "값"], ["가", "1"], ["나", "2"]])
+            .build();
+        document.body_text.sections[0]
+            .paragraphs
+            .iter()
+            .find_map(|paragraph| paragraph.tables().first().map(|table| (*table).clone()))
+            .unwrap()
+    }
+
+    fn solid_fill(r: u8, g: u8, b: u8) -> BorderFill {
+        let line =
+            serde_json::json!({"line_type": 0, "width": 0, "color": {"r": 0, "g": 0, "b": 0}});
+        serde_json::from_value(serde_json::json!({
+            "attributes": {"has_3d_effect": false, "has_shadow": false, "slash_shape": 0,
+                "backslash_shape": 0, "slash_broken_line": 0, "backslash_broken_line": false,
+                "slash_rotated_180": false, "backslash_rotated_180": false, "has_center_line": false},
+            "borders": [line, line, line, line],
+            "diagonal": {"line_type": 1, "thickness": 0, "color": {"r": 0, "g": 0, "b": 0}},
+            "fill": {"type": "solid", "background_color": {"r": r, "g": g, "b": b},
+                "pattern_color": {"r": 0, "g": 0, "b": 0}, "pattern_type": -1}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_header_rows() {
+        // 제목 줄 반복 / Header row repeat
+        let mut table = sample_table();
+        assert_eq!(table.header_rows(&[]), 1);
+
+        // 반복도 배경도 없으면 머리행 없음 / No repeat and no shading means no header
+        table.attributes.attribute.header_row_repeat = false;
+        let fills = [solid_fill(255, 255, 255), solid_fill(217, 217, 217)];
+        assert_eq!(table.header_rows(&fills), 0);
+
+        // 첫 행만 회색으로 칠한 표 / Only the first row shaded grey
+        for cell in &mut table.cells {
+            cell.cell_attributes.border_fill_id = if cell.cell_attributes.row_address == 0 {
+                2
+            } else {
+                1
+            };
+        }
+        assert_eq!(table.header_rows(&fills), 1);
+
+        // 본문 칸에도 같은 색이 있으면 머리행이 아님 / Not a header when body cells share the color
+        table
+            .cells
+            .last_mut()
+            .unwrap()
+            .cell_attributes
+            .border_fill_id = 2;
+        assert_eq!(table.header_rows(&fills), 0);
+
+        // 본문 행이 없는 표 / Table without body rows
+        let mut single = sample_table();
+        single.attributes.row_count = 1;
+        single
+            .cells
+            .retain(|cell| cell.cell_attributes.row_address == 0);
+        assert_eq!(single.header_rows(&fills), 0);
+    }
+}
@@ -0,0 +1,112 @@
+/// 인용/상호 참조 필드 모듈 / Citation and cross-reference field module
+///
+/// 법률 문서의 판례·법령 인용과 상호 참조 필드를 위치와 함께 모아 판례/법령 목록(table of
+/// authorities)을 만들 수 있게 합니다. HWP 5.0의 필드는 표시 텍스트 구간을 아직 복원하지 않아
+/// 현재는 HWPX 문서만 항목이 채워집니다.
+/// Collects citation and cross-reference fields used in legal documents together with their
+/// location, so a table of authorities can be built. HWP 5.0 fields do not yet restore their
+/// displayed text span, so only HWPX documents currently produce entries.
+use super::bodytext::{Paragraph, ParagraphRecord};
+use super::{HwpDocument, ParagraphLocation};
+use serde::{Deserialize, Serialize};
+
+/// 인용 필드 종류 / Citation field kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationKind {
+    /// 인용 (`<hp:fieldBegin type="CITATION">`) / Citation (`<hp:fieldBegin type="CITATION">`)
+    Citation,
+    /// 상호 참조 (`<hp:fieldBegin type="CROSSREF">`) / Cross-reference (`<hp:fieldBegin type="CROSSREF">`)
+    CrossReference,
+}
+
+/// 인용 필드 하나 / A single citation field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Citation {
+    pub kind: CitationKind,
+    /// 필드 이름 (`name` 속성) / Field name (`name` attribute)
+    pub name: Option<String>,
+    /// 필드 명령 (인용 출처나 참조 대상) / Field command (citation source or reference target)
+    pub command: Option<String>,
+    /// 본문에 표시된 텍스트 / Text displayed in the body
+    pub text: String,
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+}
+
+impl Citation {
+    /// 참고 문헌 목록에 쓸 텍스트 (표시 텍스트가 비어 있으면 명령) / Text for a references list (the command when the displayed text is blank)
+    pub fn label(&self) -> &str {
+        let text = self.text.trim();
+        if text.is_empty() {
+            self.command.as_deref().map(str::trim).unwrap_or_default()
+        } else {
+            text
+        }
+    }
+}
+
+impl HwpDocument {
+    /// 문서의 인용/상호 참조 필드 모으기 / Collect the document's citation and cross-reference fields
+    ///
+    /// # Returns / 반환값
+    /// 문서 순서대로 정렬된 필드 목록 / Fields in document order
+    pub fn citations(&self) -> Vec<Citation> {
+        let mut citations = Vec::new();
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let location = ParagraphLocation::top_level(section_index, paragraph_index);
+                collect_citations(paragraph, location, &mut citations);
+                for (nested_index, nested) in paragraph.nested_paragraphs().into_iter().enumerate()
+                {
+                    collect_citations(nested, location.nested(Some(nested_index)), &mut citations);
+                }
+            }
+        }
+        citations
+    }
+
+    /// 참고 문헌 목록 / References list
+    ///
+    /// 인용 필드(상호 참조 제외)의 텍스트를 처음 나온 순서대로 중복 없이 돌려줍니다.
+    /// Citation field (not cross-reference) labels in first-appearance order, without duplicates.
+    pub fn references(&self) -> Vec<String> {
+        let mut references: Vec<String> = Vec::new();
+        for citation in self.citations() {
+            let label = citation.label();
+            if citation.kind == CitationKind::Citation
+                && !label.is_empty()
+                && !references.iter().any(|reference| reference == label)
+            {
+                references.push(label.to_string());
+            }
+        }
+        references
+    }
+}
+
+/// 문단 레코드에서 인용 필드 수집 / Collect citation fields from a paragraph's records
+fn collect_citations(
+    paragraph: &Paragraph,
+    location: ParagraphLocation,
+    citations: &mut Vec<Citation>,
+) {
+    for record in &paragraph.records {
+        if let ParagraphRecord::HwpxCitation {
+            kind,
+            name,
+            command,
+            text,
+        } = record
+        {
+            citations.push(Citation {
+                kind: *kind,
+                name: name.clone(),
+                command: command.clone(),
+                text: text.clone(),
+                location,
+            });
+        }
+    }
+}
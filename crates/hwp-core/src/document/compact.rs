@@ -0,0 +1,508 @@
+/// 문서 ID 정리 모듈 / Document ID compaction module
+///
+/// 병합, 분할, 편집 뒤에 남은 쓰이지 않는 글자 모양/문단 모양/테두리·배경/바이너리 데이터를 지우고
+/// 남은 항목의 ID를 빈틈없이 다시 매기며, 본문과 DocInfo의 모든 참조를 새 ID로 고칩니다.
+/// 없는 항목을 가리키는 참조는 기본값으로 바꿉니다.
+///
+/// Drops char shapes, para shapes, border fills and binary data left unused after merging,
+/// splitting or editing, renumbers the remaining entries without gaps, and rewrites every
+/// reference in the body and DocInfo. References to missing entries are reset to the default.
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::HwpDocument;
+use crate::document::bodytext::video_data::VideoAttributes;
+use crate::document::bodytext::{CtrlHeaderData, Paragraph, ParagraphRecord};
+use crate::document::docinfo::{BinDataRecord, DocInfo, FillInfo};
+use crate::types::{INT32, UINT16, UINT32, WORD};
+use serde::{Deserialize, Serialize};
+
+/// ID 정리 보고서 / ID compaction report
+///
+/// 각 매핑은 남은 항목의 이전 ID → 새 ID입니다. 지워진 항목은 매핑에 없습니다.
+/// Each mapping is old ID → new ID of a retained entry. Removed entries are absent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompactReport {
+    /// 글자 모양 ID 매핑 (0부터) / Char shape ID mapping (0-based)
+    pub char_shape_map: BTreeMap<UINT32, UINT32>,
+    /// 문단 모양 ID 매핑 (0부터) / Para shape ID mapping (0-based)
+    pub para_shape_map: BTreeMap<UINT32, UINT32>,
+    /// 테두리/배경 ID 매핑 (1부터, 0은 없음) / Border fill ID mapping (1-based, 0 means none)
+    pub border_fill_map: BTreeMap<UINT32, UINT32>,
+    /// 바이너리 데이터 ID 매핑 / Binary data ID mapping
+    pub bin_data_map: BTreeMap<UINT32, UINT32>,
+    /// 지운 항목 수 (모든 종류 합계) / Number of removed entries (all kinds)
+    pub removed: usize,
+    /// 없는 항목을 가리켜 기본값으로 바꾼 참조 수 / References to missing entries that were reset
+    pub dangling_references: usize,
+}
+
+impl CompactReport {
+    /// ID가 하나도 바뀌지 않았는지 확인 / Whether no ID changed
+    pub fn is_unchanged(&self) -> bool {
+        self.removed == 0
+            && self.dangling_references == 0
+            && [
+                &self.char_shape_map,
+                &self.para_shape_map,
+                &self.border_fill_map,
+                &self.bin_data_map,
+            ]
+            .iter()
+            .all(|map| map.iter().all(|(old, new)| old == new))
+    }
+}
+
+impl HwpDocument {
+    /// 쓰이지 않는 모양/바이너리 데이터를 지우고 ID를 다시 매김 / Drop unused shapes and binary data and renumber IDs
+    ///
+    /// 글자 모양과 문단 모양의 0번은 기본값이라 항상 남깁니다. 번호 문단과 글머리표의 참조는
+    /// 가리키는 항목이 있을 때만 고칩니다. HWPX 그림은 이름(`binaryItemIDRef`)으로 바이너리
+    /// 데이터를 찾으므로 이름은 바꾸지 않습니다.
+    ///
+    /// Char shape 0 and para shape 0 are the defaults and always kept. Numbering and bullet
+    /// references are only rewritten when they point at an existing entry. HWPX pictures find
+    /// binary data by name (`binaryItemIDRef`), so item names are left unchanged.
+    ///
+    /// # Returns / 반환값
+    /// ID 매핑 보고서 / ID mapping report
+    pub fn compact_ids(&mut self) -> CompactReport {
+        let doc_info = &self.doc_info;
+        // HWP 5.0 BinData ID는 1부터, HWPX와 빌더 아이템은 0부터 / HWP 5.0 BinData IDs start at 1, HWPX and builder items at 0
+        let bin_data_base = if doc_info.bin_data.is_empty() { 0 } else { 1 };
+        let mut compactor = Compactor {
+            existing: [
+                (0..doc_info.char_shapes.len() as UINT32).collect(),
+                (0..doc_info.para_shapes.len() as UINT32).collect(),
+                (1..=doc_info.border_fill.len() as UINT32).collect(),
+                doc_info
+                    .bin_data
+                    .iter()
+                    .enumerate()
+                    .map(|(position, record)| bin_data_record_id(record, position))
+                    .chain(self.bin_data.items.iter().map(|item| item.index as UINT32))
+                    .collect(),
+            ],
+            ..Default::default()
+        };
+
+        // 1단계: 참조 수집 / Pass 1: collect references
+        compactor.document(self);
+        for kind in [IdKind::CharShape, IdKind::ParaShape] {
+            if compactor.existing[kind as usize].contains(&0) {
+                compactor.used[kind as usize].insert(0);
+            }
+        }
+        for item in &self.bin_data.items {
+            if item
+                .name
+                .as_ref()
+                .is_some_and(|name| compactor.image_names.contains(name))
+            {
+                compactor.used[IdKind::BinData as usize].insert(item.index as UINT32);
+            }
+        }
+
+        let bases = [0, 0, 1, bin_data_base];
+        let maps: [BTreeMap<UINT32, UINT32>; 4] = std::array::from_fn(|kind| {
+            compactor.used[kind]
+                .iter()
+                .zip(bases[kind]..)
+                .map(|(&old, new)| (old, new))
+                .collect()
+        });
+
+        // 2단계: 참조 고치기 / Pass 2: rewrite references
+        compactor.maps = Some(maps.clone());
+        compactor.document(self);
+
+        let doc_info = &mut self.doc_info;
+        let before = doc_info.char_shapes.len()
+            + doc_info.para_shapes.len()
+            + doc_info.border_fill.len()
+            + compactor.existing[IdKind::BinData as usize].len();
+        let [char_shape_map, para_shape_map, border_fill_map, bin_data_map] = maps;
+        retain_mapped(&mut doc_info.char_shapes, &char_shape_map, 0);
+        retain_mapped(&mut doc_info.para_shapes, &para_shape_map, 0);
+        retain_mapped(&mut doc_info.border_fill, &border_fill_map, 1);
+
+        let mut position = 0;
+        doc_info.bin_data.retain_mut(|record| {
+            let old = bin_data_record_id(record, position);
+            position += 1;
+            let Some(&new) = bin_data_map.get(&old) else {
+                return false;
+            };
+            match record {
+                BinDataRecord::Embedding { embedding, .. } => {
+                    embedding.binary_data_id = new as WORD
+                }
+                BinDataRecord::Storage { storage, .. } => storage.binary_data_id = new as WORD,
+                BinDataRecord::Link { .. } => {}
+            }
+            true
+        });
+        self.bin_data.items.retain_mut(|item| {
+            let Some(&new) = bin_data_map.get(&(item.index as UINT32)) else {
+                return false;
+            };
+            item.index = new as WORD;
+            true
+        });
+
+        if let Some(id_mappings) = &mut doc_info.id_mappings {
+            id_mappings.char_shape = doc_info.char_shapes.len() as INT32;
+            id_mappings.paragraph_shape = doc_info.para_shapes.len() as INT32;
+            id_mappings.border_fill = doc_info.border_fill.len() as INT32;
+            id_mappings.binary_data = doc_info.bin_data.len() as INT32;
+        }
+
+        let after = char_shape_map.len()
+            + para_shape_map.len()
+            + border_fill_map.len()
+            + bin_data_map.len();
+        CompactReport {
+            char_shape_map,
+            para_shape_map,
+            border_fill_map,
+            bin_data_map,
+            removed: before - after,
+            dangling_references: compactor.dangling,
+        }
+    }
+}
+
+/// ID 종류 / Kind of ID
+#[derive(Debug, Clone, Copy)]
+enum IdKind {
+    CharShape = 0,
+    ParaShape = 1,
+    BorderFill = 2,
+    BinData = 3,
+}
+
+/// 두 단계로 문서를 도는 ID 정리기 / ID compactor walking the document in two passes
+///
+/// `maps`가 없으면 참조를 모으고, 있으면 참조를 새 ID로 고칩니다.
+/// Collects references while `maps` is unset and rewrites them to new IDs once it is set.
+#[derive(Default)]
+struct Compactor {
+    /// 존재하는 ID / Existing IDs
+    existing: [BTreeSet<UINT32>; 4],
+    /// 참조된 ID / Referenced IDs
+    used: [BTreeSet<UINT32>; 4],
+    /// HWPX 그림이 참조하는 아이템 이름 / Item names referenced by HWPX pictures
+    image_names: BTreeSet<String>,
+    /// 이전 ID → 새 ID / Old ID → new ID
+    maps: Option<[BTreeMap<UINT32, UINT32>; 4]>,
+    /// 없는 항목을 가리키는 참조 수 / References to missing entries
+    dangling: usize,
+}
+
+impl Compactor {
+    /// 참조 하나 처리 / Handle one reference
+    ///
+    /// `strict` 참조가 없는 항목을 가리키면 0으로 바꾸고, 그 외 참조는 그대로 둡니다.
+    /// A `strict` reference to a missing entry becomes 0; other references are left as is.
+    fn reference(&mut self, kind: IdKind, id: UINT32, strict: bool) -> UINT32 {
+        let kind = kind as usize;
+        match &self.maps {
+            None => {
+                if self.existing[kind].contains(&id) {
+                    self.used[kind].insert(id);
+                } else if strict {
+                    self.dangling += 1;
+                }
+                id
+            }
+            Some(maps) => match maps[kind].get(&id) {
+                Some(&new) => new,
+                None if strict => 0,
+                None => id,
+            },
+        }
+    }
+
+    fn word(&mut self, kind: IdKind, id: &mut UINT16) {
+        *id = self.reference(kind, *id as UINT32, true) as UINT16;
+    }
+
+    /// 0이 "없음"인 참조 / Reference where 0 means none
+    fn optional_word(&mut self, kind: IdKind, id: &mut UINT16) {
+        if *id != 0 {
+            self.word(kind, id);
+        }
+    }
+
+    fn document(&mut self, document: &mut HwpDocument) {
+        self.doc_info(&mut document.doc_info);
+        for section in &mut document.body_text.sections {
+            for paragraph in &mut section.paragraphs {
+                self.paragraph(paragraph);
+            }
+        }
+    }
+
+    fn doc_info(&mut self, doc_info: &mut DocInfo) {
+        for style in &mut doc_info.styles {
+            if let Some(id) = &mut style.char_shape_id {
+                self.word(IdKind::CharShape, id);
+            }
+            if let Some(id) = &mut style.para_shape_id {
+                self.word(IdKind::ParaShape, id);
+            }
+        }
+        for char_shape in &mut doc_info.char_shapes {
+            if let Some(id) = &mut char_shape.border_fill_id {
+                self.optional_word(IdKind::BorderFill, id);
+            }
+        }
+        for para_shape in &mut doc_info.para_shapes {
+            self.optional_word(IdKind::BorderFill, &mut para_shape.border_fill_id);
+        }
+        // 그림 정보: 밝기(1) 명암(1) 효과(1) BinItem ID(2) / Picture info: brightness, contrast, effect, BinItem ID
+        for border_fill in &mut doc_info.border_fill {
+            if let FillInfo::Image(image) = &mut border_fill.fill {
+                if let Some(bytes) = image.image_info.get_mut(3..5) {
+                    let id = UINT16::from_le_bytes([bytes[0], bytes[1]]);
+                    if id != 0 {
+                        let id = self.reference(IdKind::BinData, id as UINT32, false) as UINT16;
+                        bytes.copy_from_slice(&id.to_le_bytes());
+                    }
+                }
+            }
+        }
+        // 번호/글머리표의 참조는 "없음"(-1) 값이 흔해 있는 항목만 고침 / Numbering/bullet references often hold "none" (-1), so only existing ones are rewritten
+        for numbering in &mut doc_info.numbering {
+            for level in &mut numbering.levels {
+                level.char_shape_id = self.reference(IdKind::CharShape, level.char_shape_id, false);
+            }
+        }
+        for bullet in &mut doc_info.bullets {
+            if bullet.char_shape_id >= 0 {
+                bullet.char_shape_id =
+                    self.reference(IdKind::CharShape, bullet.char_shape_id as UINT32, false)
+                        as INT32;
+            }
+            if bullet.image_bullet_id > 0 {
+                bullet.image_bullet_id =
+                    self.reference(IdKind::BinData, bullet.image_bullet_id as UINT32, false)
+                        as INT32;
+            }
+        }
+    }
+
+    fn paragraph(&mut self, paragraph: &mut Paragraph) {
+        self.word(IdKind::ParaShape, &mut paragraph.para_header.para_shape_id);
+        for record in &mut paragraph.records {
+            self.record(record);
+        }
+    }
+
+    fn record(&mut self, record: &mut ParagraphRecord) {
+        match record {
+            ParagraphRecord::ParaCharShape { shapes } => {
+                for shape in shapes {
+                    shape.shape_id = self.reference(IdKind::CharShape, shape.shape_id, true);
+                }
+            }
+            ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } => {
+                if let CtrlHeaderData::Overlap { char_shape_ids, .. } = &mut header.data {
+                    for id in char_shape_ids {
+                        *id = self.reference(IdKind::CharShape, *id, false);
+                    }
+                }
+                for child in children {
+                    self.record(child);
+                }
+                for paragraph in paragraphs {
+                    self.paragraph(paragraph);
+                }
+            }
+            ParagraphRecord::ListHeader { paragraphs, .. } => {
+                for paragraph in paragraphs {
+                    self.paragraph(paragraph);
+                }
+            }
+            ParagraphRecord::MasterPage { master_page } => {
+                for paragraph in &mut master_page.paragraphs {
+                    self.paragraph(paragraph);
+                }
+            }
+            ParagraphRecord::Table { table } => {
+                self.optional_word(IdKind::BorderFill, &mut table.attributes.border_fill_id);
+                for zone in &mut table.attributes.zones {
+                    self.optional_word(IdKind::BorderFill, &mut zone.border_fill_id);
+                }
+                for cell in &mut table.cells {
+                    self.optional_word(
+                        IdKind::BorderFill,
+                        &mut cell.cell_attributes.border_fill_id,
+                    );
+                    for paragraph in &mut cell.paragraphs {
+                        self.paragraph(paragraph);
+                    }
+                }
+            }
+            ParagraphRecord::PageBorderFill { page_border_fill } => {
+                self.optional_word(IdKind::BorderFill, &mut page_border_fill.border_fill_id);
+            }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                for child in children {
+                    self.record(child);
+                }
+            }
+            ParagraphRecord::ShapeComponentPicture {
+                shape_component_picture,
+            } => {
+                self.optional_word(
+                    IdKind::BinData,
+                    &mut shape_component_picture.picture_info.bindata_id,
+                );
+            }
+            ParagraphRecord::ShapeComponentOle {
+                shape_component_ole,
+            } => {
+                self.optional_word(IdKind::BinData, &mut shape_component_ole.bindata_id);
+            }
+            ParagraphRecord::VideoData { video_data } => match &mut video_data.video_attributes {
+                VideoAttributes::Local {
+                    video_bindata_id,
+                    thumbnail_bindata_id,
+                } => {
+                    self.optional_word(IdKind::BinData, video_bindata_id);
+                    self.optional_word(IdKind::BinData, thumbnail_bindata_id);
+                }
+                VideoAttributes::Web {
+                    thumbnail_bindata_id,
+                    ..
+                } => self.optional_word(IdKind::BinData, thumbnail_bindata_id),
+            },
+            ParagraphRecord::HwpxImage {
+                binary_item_ref, ..
+            } => {
+                if self.maps.is_none() {
+                    self.image_names.insert(binary_item_ref.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// DocInfo BinData 레코드의 ID (LINK는 레코드 순서) / ID of a DocInfo BinData record (record order for LINK)
+fn bin_data_record_id(record: &BinDataRecord, position: usize) -> UINT32 {
+    match record {
+        BinDataRecord::Embedding { embedding, .. } => embedding.binary_data_id as UINT32,
+        BinDataRecord::Storage { storage, .. } => storage.binary_data_id as UINT32,
+        BinDataRecord::Link { .. } => position as UINT32 + 1,
+    }
+}
+
+/// 매핑에 남은 항목만 유지 (목록 순서 = ID 순서) / Keep only mapped entries (list order = ID order)
+fn retain_mapped<T>(items: &mut Vec<T>, map: &BTreeMap<UINT32, UINT32>, first_id: UINT32) {
+    let mut id = first_id;
+    items.retain(|_| {
+        let keep = map.contains_key(&id);
+        id += 1;
+        keep
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bindata::BinaryDataItem;
+    use crate::document::bodytext::CharShapeInfo;
+    use crate::document::docinfo::{CharShape, ParaShape};
+    use crate::testing::fixtures::empty_document;
+
+    fn document() -> HwpDocument {
+        let mut document = empty_document();
+        let char_shape = CharShape::parse(&[0u8; 72], 0).unwrap();
+        document.doc_info.char_shapes = vec![char_shape; 4];
+        document.doc_info.para_shapes = vec![ParaShape::parse(&[0u8; 54], 0).unwrap()];
+        document
+    }
+
+    fn paragraph(shape_ids: &[UINT32]) -> Paragraph {
+        let mut paragraph = Paragraph::default();
+        paragraph.records.push(ParagraphRecord::ParaCharShape {
+            shapes: shape_ids
+                .iter()
+                .map(|&shape_id| CharShapeInfo {
+                    position: 0,
+                    shape_id,
+                })
+                .collect(),
+        });
+        paragraph
+    }
+
+    fn shape_ids(document: &HwpDocument) -> Vec<UINT32> {
+        document.body_text.sections[0].paragraphs[0]
+            .records
+            .iter()
+            .flat_map(|record| match record {
+                ParagraphRecord::ParaCharShape { shapes } => {
+                    shapes.iter().map(|shape| shape.shape_id).collect()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compact_char_shapes() {
+        let mut document = document();
+        document.body_text.sections.push(Default::default());
+        document.body_text.sections[0]
+            .paragraphs
+            .push(paragraph(&[3, 9]));
+
+        let report = document.compact_ids();
+        assert_eq!(document.doc_info.char_shapes.len(), 2);
+        assert_eq!(shape_ids(&document), [1, 0]);
+        assert_eq!(report.char_shape_map, BTreeMap::from([(0, 0), (3, 1)]));
+        assert_eq!(report.removed, 2);
+        assert_eq!(report.dangling_references, 1);
+        assert!(!report.is_unchanged());
+
+        assert!(document.compact_ids().is_unchanged());
+    }
+
+    #[test]
+    fn test_compact_hwpx_images_by_name() {
+        let mut document = document();
+        document.bin_data.items = ["image1", "image2", "image3"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| BinaryDataItem {
+                index: index as WORD,
+                data: String::new(),
+                name: Some(name.to_string()),
+            })
+            .collect();
+        let mut paragraph = paragraph(&[0]);
+        paragraph.records.push(ParagraphRecord::HwpxImage {
+            binary_item_ref: "image3".to_string(),
+            width: 0,
+            height: 0,
+            effects: Default::default(),
+            placement: None,
+            description: None,
+            caption: None,
+        });
+        document.body_text.sections.push(Default::default());
+        document.body_text.sections[0].paragraphs.push(paragraph);
+
+        let report = document.compact_ids();
+        assert_eq!(report.bin_data_map, BTreeMap::from([(2, 0)]));
+        assert_eq!(document.bin_data.items.len(), 1);
+        assert_eq!(document.bin_data.items[0].index, 0);
+        assert_eq!(document.bin_data.items[0].name.as_deref(), Some("image3"));
+    }
+}
@@ -0,0 +1,445 @@
+/// 숨은 내용 걸러 내기 모듈 / Hidden content filtering module
+///
+/// 숨은 설명, 개인 정보 보호 필드의 표시 텍스트, 보이지 않는 문자(폭 없는 공백, 방향 제어 문자
+/// 등)를 문서에서 지우고 무엇을 지웠는지 알려 줍니다. 평문/Markdown/HTML 옵션의
+/// `content_filter`는 출력하기 전에 문서 사본에 같은 규칙을 적용합니다.
+/// Removes hidden comments, the display text of personal information fields and invisible
+/// characters (zero-width spaces, direction controls, ...) from a document and reports what was
+/// removed. The `content_filter` of the plain text, Markdown and HTML options applies the same
+/// rules to a copy of the document before output.
+use std::borrow::Cow;
+
+use super::bodytext::{collect_record_paragraphs, CtrlHeaderData, Paragraph, ParagraphRecord};
+use super::fields::collect_fields;
+use super::replace::{replace_in_paragraph, Target};
+use super::{FieldKind, HwpDocument, ParagraphLocation};
+use serde::{Deserialize, Serialize};
+
+/// 걸러 낼 내용 / Content to filter out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentFilter {
+    /// 숨은 설명 (HWP 숨은 설명 컨트롤, HWPX `<hp:hiddenComment>`) / Hidden comments (HWP hidden description controls, HWPX `<hp:hiddenComment>`)
+    #[serde(default)]
+    pub hidden_text: bool,
+    /// 개인 정보 보호 필드의 표시 텍스트 (`%cpr`, `PRIVATE_INFO`) / Display text of personal information fields (`%cpr`, `PRIVATE_INFO`)
+    #[serde(default)]
+    pub private_info: bool,
+    /// 보이지 않는 문자 ([`is_invisible_char`]) / Invisible characters ([`is_invisible_char`])
+    #[serde(default)]
+    pub invisible_chars: bool,
+}
+
+impl ContentFilter {
+    /// 모두 걸러 냄 / Filter out everything
+    pub fn all() -> Self {
+        Self {
+            hidden_text: true,
+            private_info: true,
+            invisible_chars: true,
+        }
+    }
+
+    /// 숨은 설명 제외 설정 / Set hidden comment exclusion
+    pub fn with_hidden_text(mut self, hidden_text: bool) -> Self {
+        self.hidden_text = hidden_text;
+        self
+    }
+
+    /// 개인 정보 필드 제외 설정 / Set personal information field exclusion
+    pub fn with_private_info(mut self, private_info: bool) -> Self {
+        self.private_info = private_info;
+        self
+    }
+
+    /// 보이지 않는 문자 제외 설정 / Set invisible character exclusion
+    pub fn with_invisible_chars(mut self, invisible_chars: bool) -> Self {
+        self.invisible_chars = invisible_chars;
+        self
+    }
+
+    /// 걸러 낼 것이 없는지 / Whether nothing is filtered out
+    pub fn is_empty(&self) -> bool {
+        !self.hidden_text && !self.private_info && !self.invisible_chars
+    }
+}
+
+/// 걸러 낸 내용의 종류 / Kind of filtered content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilteredKind {
+    /// 숨은 설명 / Hidden comment
+    HiddenText,
+    /// 개인 정보 보호 필드 / Personal information field
+    PrivateInfo,
+    /// 보이지 않는 문자 / Invisible characters
+    InvisibleChars,
+}
+
+/// 걸러 낸 내용 하나 / One piece of filtered content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilteredContent {
+    pub kind: FilteredKind,
+    /// 걸러 낸 텍스트 (보이지 않는 문자는 문단에서 지운 문자들) / Filtered text (for invisible characters, the characters removed from the paragraph)
+    pub text: String,
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+}
+
+/// 보이지 않는 문자인지 / Whether a character is invisible
+///
+/// 폭 없는 공백, 좌우 표시 문자와 방향 제어 문자, 단어 결합자와 보이지 않는 연산자, BOM, 선택
+/// 하이픈, 몽골어 모음 구분자가 해당합니다. 글자 모양을 바꾸는 폭 없는 결합자/비결합자
+/// (U+200C, U+200D)는 남깁니다.
+/// Zero-width space, left-to-right/right-to-left marks and direction controls, word joiner and
+/// invisible operators, BOM, soft hyphen and the Mongolian vowel separator. The zero-width
+/// non-joiner and joiner (U+200C, U+200D), which change glyph shaping, are kept.
+pub fn is_invisible_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{180E}'
+            | '\u{200B}'
+            | '\u{200E}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+impl HwpDocument {
+    /// 걸러 낼 내용 목록 (문서는 바꾸지 않음) / List the content a filter would remove, without changing the document
+    ///
+    /// 본문 문단과 표 셀, 글상자, 머리말/꼬리말, 각주/미주 안의 문단을 봅니다. 숨은 설명을 걸러
+    /// 내면 그 안의 문단은 숨은 설명째로 한 번만 나옵니다.
+    /// Looks at body paragraphs and the paragraphs inside table cells, text boxes,
+    /// headers/footers and footnotes/endnotes. When hidden comments are filtered, the paragraphs
+    /// inside them are reported once, as part of the hidden comment.
+    ///
+    /// # Returns / 반환값
+    /// 문서 순서대로 정렬된 목록 / Filtered content in document order
+    pub fn filtered_content(&self, filter: &ContentFilter) -> Vec<FilteredContent> {
+        let mut result = Vec::new();
+        if filter.is_empty() {
+            return result;
+        }
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let nested = paragraph.nested_paragraphs();
+                let mut hidden = Vec::new();
+                if filter.hidden_text {
+                    for owner in std::iter::once(paragraph).chain(nested.iter().copied()) {
+                        for record in owner.records.iter().filter(|r| is_hidden_comment(r)) {
+                            collect_record_paragraphs(record, &mut hidden);
+                        }
+                    }
+                }
+                let location = ParagraphLocation::top_level(section_index, paragraph_index);
+                collect_paragraph(paragraph, filter, location, &mut result);
+                for (nested_index, nested) in nested.into_iter().enumerate() {
+                    if hidden.iter().any(|inner| std::ptr::eq(*inner, nested)) {
+                        continue;
+                    }
+                    let location = location.nested(Some(nested_index));
+                    collect_paragraph(nested, filter, location, &mut result);
+                }
+            }
+        }
+        result
+    }
+
+    /// 문서에서 걸러 내기 / Filter content out of the document
+    ///
+    /// 숨은 설명 컨트롤은 남기고 그 내용만 비우므로 다른 컨트롤의 순서는 바뀌지 않습니다. 필드
+    /// 컨트롤도 남고 표시 텍스트만 지워집니다.
+    /// Hidden comment controls stay in place with their contents emptied, so the order of other
+    /// controls does not change. Field controls also stay; only their display text is removed.
+    ///
+    /// # Returns / 반환값
+    /// 걸러 낸 내용 ([`HwpDocument::filtered_content`]와 같음) / The filtered content (same as [`HwpDocument::filtered_content`])
+    pub fn apply_content_filter(&mut self, filter: &ContentFilter) -> Vec<FilteredContent> {
+        let filtered = self.filtered_content(filter);
+        if !filtered.is_empty() {
+            for section in &mut self.body_text.sections {
+                for paragraph in &mut section.paragraphs {
+                    filter_paragraph(paragraph, filter);
+                }
+            }
+        }
+        filtered
+    }
+
+    /// 걸러 낸 사본 (걸러 낼 것이 없으면 그대로) / Filtered copy (borrowed when nothing is filtered)
+    pub(crate) fn with_content_filter(&self, filter: Option<ContentFilter>) -> Cow<'_, Self> {
+        match filter.filter(|filter| !filter.is_empty()) {
+            Some(filter) => {
+                let mut document = self.clone();
+                document.apply_content_filter(&filter);
+                Cow::Owned(document)
+            }
+            None => Cow::Borrowed(self),
+        }
+    }
+}
+
+/// 숨은 설명 컨트롤인지 / Whether a record is a hidden comment control
+fn is_hidden_comment(record: &ParagraphRecord) -> bool {
+    matches!(
+        record,
+        ParagraphRecord::CtrlHeader { header, .. }
+            if matches!(header.data, CtrlHeaderData::HiddenDescription)
+    )
+}
+
+/// 문단 자체에서 걸러 낼 내용 모으기 / Collect the content to filter from the paragraph itself
+fn collect_paragraph(
+    paragraph: &Paragraph,
+    filter: &ContentFilter,
+    location: ParagraphLocation,
+    result: &mut Vec<FilteredContent>,
+) {
+    let mut push = |kind, text| {
+        result.push(FilteredContent {
+            kind,
+            text,
+            location,
+        })
+    };
+    if filter.hidden_text {
+        for record in &paragraph.records {
+            match record {
+                ParagraphRecord::HwpxHiddenComment { text } => {
+                    push(FilteredKind::HiddenText, text.clone())
+                }
+                ParagraphRecord::CtrlHeader {
+                    children,
+                    paragraphs,
+                    ..
+                } if is_hidden_comment(record) => {
+                    // 문단 목록이 없으면 목록 헤더의 문단 / The list header's paragraphs when there is no paragraph list
+                    let paragraphs = if paragraphs.is_empty() {
+                        children
+                            .iter()
+                            .filter_map(|child| match child {
+                                ParagraphRecord::ListHeader { paragraphs, .. } => Some(paragraphs),
+                                _ => None,
+                            })
+                            .flatten()
+                            .collect::<Vec<_>>()
+                    } else {
+                        paragraphs.iter().collect()
+                    };
+                    let text: Vec<String> = paragraphs.iter().map(|p| p.text()).collect();
+                    push(FilteredKind::HiddenText, text.join("\n"));
+                }
+                _ => {}
+            }
+        }
+    }
+    if filter.private_info {
+        let mut fields = Vec::new();
+        collect_fields(paragraph, location, &mut fields);
+        for field in fields {
+            if field.kind == FieldKind::PrivateInfo && !field.text.is_empty() {
+                push(FilteredKind::PrivateInfo, field.text);
+            }
+        }
+    }
+    if filter.invisible_chars {
+        let invisible: String = paragraph
+            .text()
+            .chars()
+            .filter(|c| is_invisible_char(*c))
+            .collect();
+        if !invisible.is_empty() {
+            push(FilteredKind::InvisibleChars, invisible);
+        }
+    }
+}
+
+/// 문단과 중첩 문단에서 걸러 내기 / Filter a paragraph and its nested paragraphs
+fn filter_paragraph(paragraph: &mut Paragraph, filter: &ContentFilter) {
+    if filter.hidden_text {
+        paragraph
+            .records
+            .retain(|record| !matches!(record, ParagraphRecord::HwpxHiddenComment { .. }));
+        for record in &mut paragraph.records {
+            if !is_hidden_comment(record) {
+                continue;
+            }
+            if let ParagraphRecord::CtrlHeader {
+                children,
+                paragraphs,
+                ..
+            } = record
+            {
+                children.retain(|child| !matches!(child, ParagraphRecord::ListHeader { .. }));
+                paragraphs.clear();
+            }
+        }
+    }
+    if filter.private_info {
+        remove_private_fields(paragraph);
+    }
+    if filter.invisible_chars {
+        let mut invisible: Vec<char> = paragraph
+            .text()
+            .chars()
+            .filter(|c| is_invisible_char(*c))
+            .collect();
+        invisible.sort_unstable();
+        invisible.dedup();
+        for c in invisible {
+            replace_in_paragraph(paragraph, Target::Text(&c.to_string()), "");
+        }
+    }
+    for record in &mut paragraph.records {
+        filter_record(record, filter);
+    }
+}
+
+/// 레코드 안의 중첩 문단 걸러 내기 / Filter paragraphs nested in a record
+fn filter_record(record: &mut ParagraphRecord, filter: &ContentFilter) {
+    match record {
+        ParagraphRecord::CtrlHeader {
+            children,
+            paragraphs,
+            ..
+        } => {
+            for child in children {
+                filter_record(child, filter);
+            }
+            for paragraph in paragraphs {
+                filter_paragraph(paragraph, filter);
+            }
+        }
+        ParagraphRecord::ListHeader { paragraphs, .. } => {
+            for paragraph in paragraphs {
+                filter_paragraph(paragraph, filter);
+            }
+        }
+        ParagraphRecord::Table { table } => {
+            for cell in &mut table.cells {
+                for paragraph in &mut cell.paragraphs {
+                    filter_paragraph(paragraph, filter);
+                }
+            }
+        }
+        ParagraphRecord::ShapeComponent { children, .. } => {
+            for child in children {
+                filter_record(child, filter);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 문단 자체의 개인 정보 필드 표시 텍스트 지우기 / Remove the display text of the paragraph's own personal information fields
+///
+/// HWP 5.0 필드 순서는 문단 텍스트의 필드 시작 제어 문자 순서와 같습니다.
+/// The order of HWP 5.0 fields matches the order of field start controls in the paragraph text.
+fn remove_private_fields(paragraph: &mut Paragraph) {
+    let mut hwp5_index = 0;
+    for record_index in 0..paragraph.records.len() {
+        let target = match &paragraph.records[record_index] {
+            ParagraphRecord::HwpxField {
+                field_type,
+                text,
+                offset,
+                ..
+            } if FieldKind::from_field_type(field_type) == FieldKind::PrivateInfo => {
+                Target::Chars {
+                    start: *offset,
+                    len: text.chars().count(),
+                }
+            }
+            ParagraphRecord::CtrlHeader { header, .. } => match &header.data {
+                CtrlHeaderData::Field { field_type, .. } => {
+                    hwp5_index += 1;
+                    if FieldKind::from_field_type(field_type) != FieldKind::PrivateInfo {
+                        continue;
+                    }
+                    Target::Field(hwp5_index - 1)
+                }
+                _ => continue,
+            },
+            _ => continue,
+        };
+        replace_in_paragraph(paragraph, target, "");
+        if let ParagraphRecord::HwpxField { text, .. } = &mut paragraph.records[record_index] {
+            text.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{document_with_paragraphs as document, text_paragraph};
+
+    fn paragraph(text: &str, records: Vec<ParagraphRecord>) -> Paragraph {
+        let mut paragraph = text_paragraph(text);
+        paragraph.records.extend(records);
+        paragraph
+    }
+
+    #[test]
+    fn test_filter_private_info_and_invisible_chars() {
+        let private = ParagraphRecord::HwpxField {
+            field_type: "PRIVATE_INFO".to_string(),
+            name: None,
+            command: None,
+            text: "900101-1234567".to_string(),
+            offset: 4,
+        };
+        let mut document = document(vec![
+            paragraph("주민번호900101-1234567 확인", vec![private]),
+            paragraph("보이지\u{200B}않는\u{FEFF} 문자", Vec::new()),
+        ]);
+
+        let filter = ContentFilter::default().with_private_info(true);
+        let listed = document.filtered_content(&filter);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].kind, FilteredKind::PrivateInfo);
+        assert_eq!(listed[0].text, "900101-1234567");
+        // 목록만 만들면 문서는 그대로 / Listing leaves the document unchanged
+        assert!(document.text().contains("1234567"));
+
+        let removed = document.apply_content_filter(&ContentFilter::all());
+        assert_eq!(removed.len(), 2);
+        assert_eq!(removed[1].kind, FilteredKind::InvisibleChars);
+        assert_eq!(removed[1].text, "\u{200B}\u{FEFF}");
+        assert_eq!(removed[1].location.paragraph_index, 1);
+        assert_eq!(document.text(), "주민번호 확인\n보이지않는 문자");
+        assert!(document.filtered_content(&ContentFilter::all()).is_empty());
+    }
+
+    #[test]
+    fn test_filter_hidden_comments() {
+        let hidden = ParagraphRecord::HwpxHiddenComment {
+            text: "검토용 메모".to_string(),
+        };
+        let mut document = document(vec![paragraph("본문", vec![hidden])]);
+        assert!(document
+            .filtered_content(&ContentFilter::default())
+            .is_empty());
+
+        let removed = document.apply_content_filter(&ContentFilter::all());
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].kind, FilteredKind::HiddenText);
+        assert_eq!(removed[0].text, "검토용 메모");
+        assert_eq!(
+            document.body_text.sections[0].paragraphs[0].records.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_is_invisible_char() {
+        assert!(is_invisible_char('\u{200B}'));
+        assert!(is_invisible_char('\u{202E}'));
+        assert!(!is_invisible_char('\u{200D}'));
+        assert!(!is_invisible_char(' '));
+    }
+}
@@ -0,0 +1,215 @@
+/// 문서 일시 해석 모듈 / Document date parsing module
+///
+/// 한컴 프로그램은 일시를 여러 형식으로 저장합니다. 시간대가 없는 값은 한국 표준시(UTC+9)로 해석합니다.
+/// Hancom applications store dates in several formats. Values without a time zone are
+/// interpreted as Korea Standard Time (UTC+9).
+///
+/// | 형식 / Format | 예 / Example | 출처 / Source |
+/// |---|---|---|
+/// | RFC 3339 | `2011-08-05T17:00:06Z`, `2011-08-05T17:00:06+09:00` | HWPX content.hpf, HWP 5.0 요약 정보 / summary information |
+/// | 시간대 없는 ISO 8601 / ISO 8601 without zone | `2024-03-01T09:00:00`, `2024-03-01 09:00:00`, `2024-03-01` | HWPX 변경 추적 / track changes |
+/// | 한국어 날짜 / Korean date | `2025년 12월 1일 월요일 오후 11:39:55` | 요약 정보 날짜 문자열 / summary date string |
+use std::sync::OnceLock;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use regex::Regex;
+
+use super::docinfo::TrackChangeContent;
+use super::SummaryInformation;
+use crate::review::ReviewEntry;
+
+/// 한국 표준시 오프셋 (초) / Korea Standard Time offset in seconds
+const KST_OFFSET_SECONDS: i32 = 9 * 3600;
+
+/// FILETIME이 비어 있을 때 요약 정보에 기록되는 값 / Value the summary information holds for an unset FILETIME
+const UNSET_FILETIME: &str = "1970-01-01T00:00:00+09:00";
+
+/// 한국 표준시 / Korea Standard Time (UTC+9)
+pub fn kst() -> FixedOffset {
+    FixedOffset::east_opt(KST_OFFSET_SECONDS).expect("UTC+9 is a valid offset")
+}
+
+/// 한컴 일시 문자열 해석 / Parse a Hancom date string
+///
+/// # Arguments / 매개변수
+/// * `text` - 문서에 저장된 일시 / Date as stored in the document
+///
+/// # Returns / 반환값
+/// 시간대가 붙은 일시, 알 수 없는 형식이면 None / Date with offset, or None for unknown formats
+pub fn parse_hancom_date(text: &str) -> Option<DateTime<FixedOffset>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(text) {
+        return Some(date_time);
+    }
+
+    const NAIVE_FORMATS: [&str; 4] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ];
+    let naive = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .or_else(|| parse_korean_date(text))?;
+    kst().from_local_datetime(&naive).single()
+}
+
+/// "2025년 12월 1일 월요일 오후 11:39:55" 형식 해석 / Parse the "2025년 12월 1일 월요일 오후 11:39:55" format
+fn parse_korean_date(text: &str) -> Option<NaiveDateTime> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(
+            r"^(\d{4})년\s*(\d{1,2})월\s*(\d{1,2})일(?:\s*\S+요일)?(?:\s*(오전|오후)?\s*(\d{1,2}):(\d{2})(?::(\d{2}))?)?$",
+        )
+        .expect("Korean date pattern is valid")
+    });
+    let captures = pattern.captures(text)?;
+    let number = |index: usize| -> Option<u32> {
+        captures
+            .get(index)
+            .map_or(Some(0), |m| m.as_str().parse().ok())
+    };
+
+    let date = NaiveDate::from_ymd_opt(
+        captures[1].parse().ok()?,
+        captures[2].parse().ok()?,
+        captures[3].parse().ok()?,
+    )?;
+    let mut hour = number(5)?;
+    match captures.get(4).map(|m| m.as_str()) {
+        // 오전 12시는 0시, 오후 1~11시는 13~23시 / 12 AM is hour 0, 1-11 PM are hours 13-23
+        Some("오전") if hour == 12 => hour = 0,
+        Some("오후") if hour < 12 => hour += 12,
+        _ => {}
+    }
+    let time = NaiveTime::from_hms_opt(hour, number(6)?, number(7)?)?;
+    Some(date.and_time(time))
+}
+
+/// 요약 정보의 FILETIME 일시 해석 (미설정 값은 None) / Parse a summary FILETIME date (None when unset)
+fn filetime_date(value: Option<&str>) -> Option<DateTime<FixedOffset>> {
+    value
+        .filter(|value| *value != UNSET_FILETIME)
+        .and_then(parse_hancom_date)
+}
+
+impl SummaryInformation {
+    /// 생성 일시 / Creation date
+    pub fn created(&self) -> Option<DateTime<FixedOffset>> {
+        filetime_date(self.create_time.as_deref())
+    }
+
+    /// 마지막 저장 일시 / Last saved date
+    pub fn modified(&self) -> Option<DateTime<FixedOffset>> {
+        filetime_date(self.last_saved_time.as_deref())
+    }
+
+    /// 마지막 인쇄 일시 (인쇄한 적 없으면 None) / Last printed date (None if never printed)
+    pub fn printed(&self) -> Option<DateTime<FixedOffset>> {
+        filetime_date(self.last_printed.as_deref())
+    }
+
+    /// 사용자 정의 날짜 문자열의 일시 / Date of the user-defined date string
+    pub fn date(&self) -> Option<DateTime<FixedOffset>> {
+        self.date_string.as_deref().and_then(parse_hancom_date)
+    }
+}
+
+impl TrackChangeContent {
+    /// 변경 일시 / Change date
+    pub fn date_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.date.as_deref().and_then(parse_hancom_date)
+    }
+}
+
+impl ReviewEntry {
+    /// 작성 일시 / Entry date
+    pub fn date_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.date.as_deref().and_then(parse_hancom_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst_date(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<FixedOffset> {
+        kst().with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let date = parse_hancom_date("2011-08-05T17:00:06Z").unwrap();
+        assert_eq!(date.offset().local_minus_utc(), 0);
+        assert_eq!(date, kst_date(2011, 8, 6, 2, 0, 6));
+        assert_eq!(
+            parse_hancom_date("2011-08-05T17:00:06+09:00").unwrap(),
+            kst_date(2011, 8, 5, 17, 0, 6)
+        );
+    }
+
+    #[test]
+    fn test_parse_naive_as_kst() {
+        let date = parse_hancom_date("2024-03-01 09:00:00").unwrap();
+        assert_eq!(date, kst_date(2024, 3, 1, 9, 0, 0));
+        assert_eq!(date.offset().local_minus_utc(), KST_OFFSET_SECONDS);
+        assert_eq!(
+            parse_hancom_date("2024-03-01T09:00:00").unwrap(),
+            kst_date(2024, 3, 1, 9, 0, 0)
+        );
+        assert_eq!(
+            parse_hancom_date("2024-03-01").unwrap(),
+            kst_date(2024, 3, 1, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_korean_date() {
+        assert_eq!(
+            parse_hancom_date("2025년 12월 1일 월요일 오후 11:39:55").unwrap(),
+            kst_date(2025, 12, 1, 23, 39, 55)
+        );
+        assert_eq!(
+            parse_hancom_date("2011년 8월 14일 일요일 오전 12:05:00").unwrap(),
+            kst_date(2011, 8, 14, 0, 5, 0)
+        );
+        assert_eq!(
+            parse_hancom_date("2011년 8월 14일 오후 12:30").unwrap(),
+            kst_date(2011, 8, 14, 12, 30, 0)
+        );
+        assert_eq!(
+            parse_hancom_date("2011년 8월 14일").unwrap(),
+            kst_date(2011, 8, 14, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(parse_hancom_date(""), None);
+        assert_eq!(parse_hancom_date("text"), None);
+        assert_eq!(parse_hancom_date("2025년 13월 1일"), None);
+    }
+
+    #[test]
+    fn test_summary_information_dates() {
+        let info = SummaryInformation {
+            create_time: Some("2011-08-05T17:00:06+09:00".to_string()),
+            last_printed: Some(UNSET_FILETIME.to_string()),
+            date_string: Some("2011년 8월 14일 일요일 오후 7:57:32".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(info.created(), Some(kst_date(2011, 8, 5, 17, 0, 6)));
+        assert_eq!(info.modified(), None);
+        assert_eq!(info.printed(), None);
+        assert_eq!(info.date(), Some(kst_date(2011, 8, 14, 19, 57, 32)));
+    }
+}
@@ -10,13 +10,16 @@ use serde::{Deserialize, Serialize};
 
 /// 바이너리 데이터 저장 타입 / Binary data storage type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "snake_case")]
 pub enum BinDataStorageType {
     /// LINK, 그림 외부 파일 참조 / Link, external file reference
+    #[serde(alias = "LINK")]
     Link = 0x0000,
     /// EMBEDDING, 그림 파일 포함 / Embedding, embedded file
+    #[serde(alias = "EMBEDDING")]
     Embedding = 0x0001,
     /// STORAGE, OLE 포함 / Storage, OLE embedded
+    #[serde(alias = "STORAGE")]
     Storage = 0x0002,
 }
 
@@ -134,9 +137,10 @@ pub struct BinDataStorage {
 
 /// 바이너리 데이터 레코드 / Binary data record
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "storage_type", rename_all = "UPPERCASE")]
+#[serde(tag = "storage_type", rename_all = "snake_case")]
 pub enum BinDataRecord {
     /// LINK 타입 / Link type
+    #[serde(alias = "LINK")]
     Link {
         /// 속성 / Attributes
         attributes: BinDataAttributes,
@@ -144,6 +148,7 @@ pub enum BinDataRecord {
         link: BinDataLink,
     },
     /// EMBEDDING 타입 / Embedding type
+    #[serde(alias = "EMBEDDING")]
     Embedding {
         /// 속성 / Attributes
         attributes: BinDataAttributes,
@@ -151,6 +156,7 @@ pub enum BinDataRecord {
         embedding: BinDataEmbedding,
     },
     /// STORAGE 타입 / Storage type
+    #[serde(alias = "STORAGE")]
     Storage {
         /// 속성 / Attributes
         attributes: BinDataAttributes,
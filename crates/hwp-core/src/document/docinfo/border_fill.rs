@@ -30,15 +30,19 @@ pub struct DiagonalLine {
 
 /// 채우기 종류 / Fill type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "snake_case")]
 pub enum FillType {
     /// 채우기 없음 / No fill
+    #[serde(alias = "NONE")]
     None = 0x00000000,
     /// 단색 채우기 / Solid fill
+    #[serde(alias = "SOLID")]
     Solid = 0x00000001,
     /// 이미지 채우기 / Image fill
+    #[serde(alias = "IMAGE")]
     Image = 0x00000002,
     /// 그러데이션 채우기 / Gradient fill
+    #[serde(alias = "GRADIENT")]
     Gradient = 0x00000004,
 }
 
@@ -91,7 +95,7 @@ pub struct ImageFill {
 
 /// 채우기 정보 / Fill information
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "lowercase")]
+#[serde(tag = "type", rename_all = "snake_case")]
 #[allow(dead_code)]
 pub enum FillInfo {
     /// 채우기 없음 / No fill
@@ -425,12 +429,14 @@ impl BorderFill {
                 offset += 2;
                 let color_count = INT16::from_le_bytes([data[offset], data[offset + 1]]);
                 offset += 2;
+                // 음수는 색상 없음으로 취급 / Treat a negative count as no colors
+                let color_bytes = 4 * color_count.max(0) as usize;
 
                 let positions = if color_count > 2 {
-                    if offset + (4 * color_count as usize) > data.len() {
+                    if offset + color_bytes > data.len() {
                         return Err(HwpError::insufficient_data(
                             "Gradient positions data",
-                            4 * color_count as usize,
+                            color_bytes,
                             data.len() - offset,
                         ));
                     }
@@ -449,10 +455,10 @@ impl BorderFill {
                     None
                 };
 
-                if offset + (4 * color_count as usize) > data.len() {
+                if offset + color_bytes > data.len() {
                     return Err(HwpError::insufficient_data(
                         "Gradient colors data",
-                        4 * color_count as usize,
+                        color_bytes,
                         data.len() - offset,
                     ));
                 }
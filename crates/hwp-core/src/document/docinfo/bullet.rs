@@ -24,7 +24,7 @@ pub struct BulletHeaderAttributes {
 
 /// 문단 정렬 종류 / Paragraph alignment type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum BulletAlignType {
     /// 왼쪽 / Left
     Left = 0,
@@ -46,7 +46,7 @@ impl BulletAlignType {
 
 /// 수준별 본문과의 거리 종류 / Distance type from body text by level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum BulletDistanceType {
     /// 글자 크기에 대한 상대 비율 / Relative ratio to font size
     Ratio = 0,
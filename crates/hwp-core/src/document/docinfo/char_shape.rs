@@ -139,15 +139,15 @@ impl CharShape {
     /// CharShape를 바이트 배열에서 파싱합니다. / Parse CharShape from byte array.
     ///
     /// # Arguments
-    /// * `data` - 최소 66바이트의 데이터 (기본 66바이트, 버전에 따라 최대 72바이트) / At least 66 bytes of data (basic 66 bytes, up to 72 bytes depending on version)
+    /// * `data` - 최소 68바이트의 데이터 (기본 68바이트, 버전에 따라 최대 74바이트) / At least 68 bytes of data (basic 68 bytes, up to 74 bytes depending on version)
     /// * `version` - FileHeader의 version (버전에 따라 필드 개수가 다를 수 있음) / FileHeader version (field count may vary by version)
     ///
     /// # Returns
     /// 파싱된 CharShape 구조체 / Parsed CharShape structure
     pub fn parse(data: &[u8], _version: u32) -> Result<Self, HwpError> {
-        // 최소 66바이트 필요 / Need at least 66 bytes
-        if data.len() < 66 {
-            return Err(HwpError::insufficient_data("CharShape", 66, data.len()));
+        // 최소 68바이트 필요 / Need at least 68 bytes
+        if data.len() < 68 {
+            return Err(HwpError::insufficient_data("CharShape", 68, data.len()));
         }
 
         let mut offset = 0;
@@ -298,8 +298,8 @@ impl CharShape {
         offset += 4;
 
         // UINT16 글자 테두리/배경 ID (5.0.2.1 이상) / UINT16 character border/fill ID (5.0.2.1 and above)
-        // 데이터 크기로 판단: 66바이트 이상이면 border_fill_id 필드 존재 / Determine by data size: border_fill_id exists if 66 bytes or more
-        let border_fill_id = if data.len() >= 66 && offset + 2 <= data.len() {
+        // 데이터 크기로 판단: 70바이트 이상이면 border_fill_id 필드 존재 / Determine by data size: border_fill_id exists if 70 bytes or more
+        let border_fill_id = if data.len() >= 70 && offset + 2 <= data.len() {
             Some(WORD::from_le_bytes([data[offset], data[offset + 1]]))
         } else {
             None
@@ -339,4 +339,33 @@ impl CharShape {
             strikethrough_color,
         })
     }
+
+    /// 위 첨자로 보이는지 여부 / Whether the text renders as superscript
+    ///
+    /// 위 첨자 속성 외에, 글자를 줄이고(상대 크기 100% 미만) 위로 올린(글자 위치 음수) 경우도 위 첨자로 봅니다.
+    /// Besides the superscript attribute, text that is shrunk (relative size below 100%) and
+    /// raised (negative text position) also counts as superscript.
+    pub fn is_superscript(&self) -> bool {
+        self.attributes.superscript || self.shifted_offset().is_some_and(|offset| offset < 0)
+    }
+
+    /// 아래 첨자로 보이는지 여부 / Whether the text renders as subscript
+    ///
+    /// 아래 첨자 속성 외에, 글자를 줄이고 아래로 내린(글자 위치 양수) 경우도 아래 첨자로 봅니다.
+    /// Besides the subscript attribute, text that is shrunk and lowered (positive text
+    /// position) also counts as subscript.
+    pub fn is_subscript(&self) -> bool {
+        !self.attributes.superscript
+            && (self.attributes.subscript || self.shifted_offset().is_some_and(|offset| offset > 0))
+    }
+
+    /// 줄어든 글자의 위치 (영문, 없으면 한글 기준) / Position of shrunk text (Latin, else Korean)
+    fn shifted_offset(&self) -> Option<INT8> {
+        let (offset, size) = if self.text_position.english != 0 {
+            (self.text_position.english, self.relative_size.english)
+        } else {
+            (self.text_position.korean, self.relative_size.korean)
+        };
+        (offset != 0 && size < 100).then_some(offset)
+    }
 }
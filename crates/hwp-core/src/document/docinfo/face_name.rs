@@ -18,13 +18,16 @@ mod flags {
 
 /// 대체 글꼴 유형 / Alternative font type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "snake_case")]
 pub enum AlternativeFontType {
     /// 원래 종류를 알 수 없을 때 / Unknown original type
+    #[serde(alias = "UNKNOWN")]
     Unknown = 0,
     /// 트루타입 글꼴(TTF) / TrueType font (TTF)
+    #[serde(rename = "ttf", alias = "TTF")]
     TTF = 1,
     /// 한글 전용 글꼴(HFT) / HWP font (HFT)
+    #[serde(rename = "hft", alias = "HFT")]
     HFT = 2,
 }
 
@@ -27,6 +27,7 @@ pub mod track_change_content;
 
 pub use crate::decompress::decompress_deflate;
 pub use crate::document::fileheader::FileHeader;
+use crate::error::ErrorContext;
 pub use crate::error::HwpError;
 pub use crate::types::RecordHeader;
 pub use bin_data::BinDataRecord;
@@ -50,7 +51,7 @@ pub use style::Style;
 pub use tab_def::TabDef;
 pub use track_change::TrackChange;
 pub use track_change_author::TrackChangeAuthor;
-pub use track_change_content::TrackChangeContent;
+pub use track_change_content::{TrackChangeContent, TrackChangeKind};
 
 /// Document information structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -121,8 +122,11 @@ impl DocInfo {
         // 레코드 단위로 파싱 / Parse record by record
         while offset < decompressed_data.len() {
             // 레코드 헤더 파싱 / Parse record header
+            let record_start = offset;
             let remaining_data = &decompressed_data[offset..];
-            let (header, header_size) = RecordHeader::parse(remaining_data)?;
+            let (header, header_size) = RecordHeader::parse(remaining_data).map_err(|error| {
+                error.with_context(ErrorContext::new().with_byte_offset(record_start as u64))
+            })?;
             offset += header_size;
 
             // 데이터 영역 읽기 / Read data area
@@ -138,154 +142,174 @@ impl DocInfo {
             let record_data = &decompressed_data[offset..offset + data_size];
             offset += data_size;
 
-            // 태그별로 분류하여 저장 / Classify and store by tag
-            match header.tag_id {
-                HwpTag::DOCUMENT_PROPERTIES => {
-                    if header.level == 0 {
-                        // DocumentProperties는 완전히 파싱 / Fully parse DocumentProperties
-                        let props = DocumentProperties::parse(record_data)?;
-                        doc_info.document_properties = Some(props);
-                    }
-                }
-                HwpTag::ID_MAPPINGS => {
-                    if header.level == 0 {
-                        // IdMappings는 완전히 파싱 / Fully parse IdMappings
-                        let id_mappings = IdMappings::parse(record_data, file_header.version)?;
-                        doc_info.id_mappings = Some(id_mappings);
-                    }
+            // 태그별로 분류하여 저장 (오류에는 레코드 위치를 붙임)
+            // Classify and store by tag (errors carry the record location)
+            doc_info
+                .parse_record(&header, record_data, file_header)
+                .map_err(|error| {
+                    error.with_context(
+                        ErrorContext::new()
+                            .with_byte_offset(record_start as u64)
+                            .with_record_tag(header.tag_id),
+                    )
+                })?;
+        }
+
+        Ok(doc_info)
+    }
+
+    /// 레코드 하나를 태그에 맞게 파싱하여 저장 / Parse one record by its tag and store it
+    fn parse_record(
+        &mut self,
+        header: &RecordHeader,
+        record_data: &[u8],
+        file_header: &FileHeader,
+    ) -> Result<(), HwpError> {
+        match header.tag_id {
+            HwpTag::DOCUMENT_PROPERTIES => {
+                if header.level == 0 {
+                    // DocumentProperties는 완전히 파싱 / Fully parse DocumentProperties
+                    let props = DocumentProperties::parse(record_data)?;
+                    self.document_properties = Some(props);
                 }
-                HwpTag::BIN_DATA => {
-                    if header.level == 1 {
-                        // BinDataRecord는 완전히 파싱 / Fully parse BinDataRecord
-                        let bin_data_record = BinDataRecord::parse(record_data)?;
-                        doc_info.bin_data.push(bin_data_record);
-                    }
+            }
+            HwpTag::ID_MAPPINGS => {
+                if header.level == 0 {
+                    // IdMappings는 완전히 파싱 / Fully parse IdMappings
+                    let id_mappings = IdMappings::parse(record_data, file_header.version)?;
+                    self.id_mappings = Some(id_mappings);
                 }
-                HwpTag::FACE_NAME => {
-                    if header.level == 1 {
-                        // FaceName은 완전히 파싱 / Fully parse FaceName
-                        let face_name = FaceName::parse(record_data)?;
-                        doc_info.face_names.push(face_name);
-                    }
+            }
+            HwpTag::BIN_DATA => {
+                if header.level == 1 {
+                    // BinDataRecord는 완전히 파싱 / Fully parse BinDataRecord
+                    let bin_data_record = BinDataRecord::parse(record_data)?;
+                    self.bin_data.push(bin_data_record);
                 }
-                HwpTag::BORDER_FILL => {
-                    if header.level == 1 {
-                        // BorderFill은 완전히 파싱 / Fully parse BorderFill
-                        let border_fill = BorderFill::parse(record_data)?;
-                        doc_info.border_fill.push(border_fill);
-                    }
+            }
+            HwpTag::FACE_NAME => {
+                if header.level == 1 {
+                    // FaceName은 완전히 파싱 / Fully parse FaceName
+                    let face_name = FaceName::parse(record_data)?;
+                    self.face_names.push(face_name);
                 }
-                HwpTag::CHAR_SHAPE => {
-                    if header.level == 1 {
-                        // CharShape는 완전히 파싱 / Fully parse CharShape
-                        let char_shape = CharShape::parse(record_data, file_header.version)?;
-                        doc_info.char_shapes.push(char_shape);
-                    }
+            }
+            HwpTag::BORDER_FILL => {
+                if header.level == 1 {
+                    // BorderFill은 완전히 파싱 / Fully parse BorderFill
+                    let border_fill = BorderFill::parse(record_data)?;
+                    self.border_fill.push(border_fill);
                 }
-                HwpTag::TAB_DEF => {
-                    if header.level == 1 {
-                        // TabDef는 완전히 파싱 / Fully parse TabDef
-                        let tab_def = TabDef::parse(record_data)?;
-                        doc_info.tab_defs.push(tab_def);
-                    }
+            }
+            HwpTag::CHAR_SHAPE => {
+                if header.level == 1 {
+                    // CharShape는 완전히 파싱 / Fully parse CharShape
+                    let char_shape = CharShape::parse(record_data, file_header.version)?;
+                    self.char_shapes.push(char_shape);
                 }
-                HwpTag::NUMBERING => {
-                    if header.level == 1 {
-                        // Numbering은 완전히 파싱 / Fully parse Numbering
-                        let numbering = Numbering::parse(record_data, file_header.version)?;
-                        doc_info.numbering.push(numbering);
-                    }
+            }
+            HwpTag::TAB_DEF => {
+                if header.level == 1 {
+                    // TabDef는 완전히 파싱 / Fully parse TabDef
+                    let tab_def = TabDef::parse(record_data)?;
+                    self.tab_defs.push(tab_def);
                 }
-                HwpTag::BULLET => {
-                    if header.level == 1 {
-                        // Bullet은 완전히 파싱 / Fully parse Bullet
-                        let bullet = Bullet::parse(record_data)?;
-                        doc_info.bullets.push(bullet);
-                    }
+            }
+            HwpTag::NUMBERING => {
+                if header.level == 1 {
+                    // Numbering은 완전히 파싱 / Fully parse Numbering
+                    let numbering = Numbering::parse(record_data, file_header.version)?;
+                    self.numbering.push(numbering);
                 }
-                HwpTag::PARA_SHAPE => {
-                    if header.level == 1 {
-                        // ParaShape은 완전히 파싱 / Fully parse ParaShape
-                        let para_shape = ParaShape::parse(record_data, file_header.version)?;
-                        doc_info.para_shapes.push(para_shape);
-                    }
+            }
+            HwpTag::BULLET => {
+                if header.level == 1 {
+                    // Bullet은 완전히 파싱 / Fully parse Bullet
+                    let bullet = Bullet::parse(record_data)?;
+                    self.bullets.push(bullet);
                 }
-                HwpTag::STYLE => {
-                    if header.level == 1 {
-                        let style = Style::parse(record_data)?;
-                        doc_info.styles.push(style);
-                    }
+            }
+            HwpTag::PARA_SHAPE => {
+                if header.level == 1 {
+                    // ParaShape은 완전히 파싱 / Fully parse ParaShape
+                    let para_shape = ParaShape::parse(record_data, file_header.version)?;
+                    self.para_shapes.push(para_shape);
                 }
-                HwpTag::DOC_DATA => {
-                    if header.level == 0 {
-                        // 문서 임의의 데이터 파싱 / Parse document arbitrary data
-                        let doc_data = DocData::parse(record_data)?;
-                        doc_info.doc_data.push(doc_data);
-                    }
+            }
+            HwpTag::STYLE => {
+                if header.level == 1 {
+                    let style = Style::parse(record_data)?;
+                    self.styles.push(style);
                 }
-                HwpTag::DISTRIBUTE_DOC_DATA => {
-                    if header.level == 0 {
-                        // 배포용 문서 데이터 파싱 / Parse distribution document data
-                        let distribute_doc_data = DistributeDocData::parse(record_data)?;
-                        doc_info.distribute_doc_data = Some(distribute_doc_data);
-                    }
+            }
+            HwpTag::DOC_DATA => {
+                if header.level == 0 {
+                    // 문서 임의의 데이터 파싱 / Parse document arbitrary data
+                    let doc_data = DocData::parse(record_data)?;
+                    self.doc_data.push(doc_data);
                 }
-                HwpTag::COMPATIBLE_DOCUMENT => {
-                    if header.level == 0 {
-                        // 호환 문서 파싱 / Parse compatible document
-                        let compatible_document = CompatibleDocument::parse(record_data)?;
-                        doc_info.compatible_document = Some(compatible_document);
-                    }
+            }
+            HwpTag::DISTRIBUTE_DOC_DATA => {
+                if header.level == 0 {
+                    // 배포용 문서 데이터 파싱 / Parse distribution document data
+                    let distribute_doc_data = DistributeDocData::parse(record_data)?;
+                    self.distribute_doc_data = Some(distribute_doc_data);
                 }
-                HwpTag::LAYOUT_COMPATIBILITY => {
-                    if header.level == 1 {
-                        // 레이아웃 호환성 파싱 / Parse layout compatibility
-                        let layout_compatibility = LayoutCompatibility::parse(record_data)?;
-                        doc_info.layout_compatibility = Some(layout_compatibility);
-                    }
+            }
+            HwpTag::COMPATIBLE_DOCUMENT => {
+                if header.level == 0 {
+                    // 호환 문서 파싱 / Parse compatible document
+                    let compatible_document = CompatibleDocument::parse(record_data)?;
+                    self.compatible_document = Some(compatible_document);
                 }
-                HwpTag::TRACKCHANGE => {
-                    if header.level == 1 {
-                        // 변경 추적 정보 파싱 / Parse track change information
-                        let track_change = TrackChange::parse(record_data)?;
-                        doc_info.track_change = Some(track_change);
-                    }
+            }
+            HwpTag::LAYOUT_COMPATIBILITY => {
+                if header.level == 1 {
+                    // 레이아웃 호환성 파싱 / Parse layout compatibility
+                    let layout_compatibility = LayoutCompatibility::parse(record_data)?;
+                    self.layout_compatibility = Some(layout_compatibility);
                 }
-                HwpTag::MEMO_SHAPE => {
-                    if header.level == 1 {
-                        // 메모 모양 파싱 / Parse memo shape
-                        let memo_shape = MemoShape::parse(record_data)?;
-                        doc_info.memo_shapes.push(memo_shape);
-                    }
+            }
+            HwpTag::TRACKCHANGE => {
+                if header.level == 1 {
+                    // 변경 추적 정보 파싱 / Parse track change information
+                    let track_change = TrackChange::parse(record_data)?;
+                    self.track_change = Some(track_change);
                 }
-                HwpTag::FORBIDDEN_CHAR => {
-                    if header.level == 0 {
-                        // 금칙처리 문자 파싱 / Parse forbidden character
-                        let forbidden_char = ForbiddenChar::parse(record_data)?;
-                        doc_info.forbidden_chars.push(forbidden_char);
-                    }
+            }
+            HwpTag::MEMO_SHAPE => {
+                if header.level == 1 {
+                    // 메모 모양 파싱 / Parse memo shape
+                    let memo_shape = MemoShape::parse(record_data)?;
+                    self.memo_shapes.push(memo_shape);
                 }
-                HwpTag::TRACK_CHANGE => {
-                    if header.level == 1 {
-                        // 변경 추적 내용 및 모양 파싱 / Parse track change content and shape
-                        let track_change_content = TrackChangeContent::parse(record_data)?;
-                        doc_info.track_change_contents.push(track_change_content);
-                    }
+            }
+            HwpTag::FORBIDDEN_CHAR => {
+                if header.level == 0 {
+                    // 금칙처리 문자 파싱 / Parse forbidden character
+                    let forbidden_char = ForbiddenChar::parse(record_data)?;
+                    self.forbidden_chars.push(forbidden_char);
                 }
-                HwpTag::TRACK_CHANGE_AUTHOR => {
-                    if header.level == 1 {
-                        // 변경 추적 작성자 파싱 / Parse track change author
-                        let track_change_author = TrackChangeAuthor::parse(record_data)?;
-                        doc_info.track_change_authors.push(track_change_author);
-                    }
+            }
+            HwpTag::TRACK_CHANGE => {
+                if header.level == 1 {
+                    // 변경 추적 내용 및 모양 파싱 / Parse track change content and shape
+                    let track_change_content = TrackChangeContent::parse(record_data)?;
+                    self.track_change_contents.push(track_change_content);
                 }
-                // 기타 태그는 무시 (나중에 구현 가능) / Other tags are ignored (can be implemented later)
-                _ => {
-                    // 알 수 없는 태그는 무시하고 계속 진행 / Unknown tags are ignored and continue
+            }
+            HwpTag::TRACK_CHANGE_AUTHOR => {
+                if header.level == 1 {
+                    // 변경 추적 작성자 파싱 / Parse track change author
+                    let track_change_author = TrackChangeAuthor::parse(record_data)?;
+                    self.track_change_authors.push(track_change_author);
                 }
             }
+            // 기타 태그는 무시 (나중에 구현 가능) / Other tags are ignored (can be implemented later)
+            _ => {
+                // 알 수 없는 태그는 무시하고 계속 진행 / Unknown tags are ignored and continue
+            }
         }
-
-        Ok(doc_info)
+        Ok(())
     }
 }
@@ -3,6 +3,7 @@
 /// 스펙 문서 매핑: 표 38 - 문단 번호 / Spec mapping: Table 38 - Paragraph numbering
 /// Tag ID: HWPTAG_NUMBERING
 /// 전체 길이: 가변 / Total length: variable
+use crate::document::bodytext::NumberShape;
 use crate::error::HwpError;
 use crate::types::{decode_utf16le, HWPUNIT16, UINT16, UINT32, WORD};
 use serde::{Deserialize, Serialize};
@@ -18,11 +19,14 @@ pub struct NumberingHeaderAttributes {
     pub auto_outdent: bool,
     /// 수준별 본문과의 거리 종류 / Distance type from body text by level
     pub distance_type: DistanceType,
+    /// 번호 모양 (bit 5-12, 표 134 코드) / Number shape (bits 5-12, Table 134 code)
+    #[serde(default)]
+    pub number_shape: NumberShape,
 }
 
 /// 문단 정렬 종류 / Paragraph alignment type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ParagraphAlignType {
     /// 왼쪽 / Left
     Left = 0,
@@ -44,7 +48,7 @@ impl ParagraphAlignType {
 
 /// 수준별 본문과의 거리 종류 / Distance type from body text by level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum DistanceType {
     /// 글자 크기에 대한 상대 비율 / Relative ratio to font size
     Ratio = 0,
@@ -77,7 +81,7 @@ pub struct NumberingLevelInfo {
     pub format_length: WORD,
     /// 번호 형식 문자열 / Number format string
     pub format_string: String,
-    /// 시작 번호 / Start number
+    /// 시작 번호 (레코드에 하나만 있어 모든 수준이 같은 값) / Start number (stored once per record, so every level has the same value)
     pub start_number: UINT16,
     /// 수준별 시작번호 (5.0.2.5 이상, 옵션) / Level-specific start number (5.0.2.5+, optional)
     pub level_start_number: Option<UINT32>,
@@ -136,6 +140,7 @@ impl Numbering {
                 instance_like: (attr_value & 0x00000004) != 0,
                 auto_outdent: (attr_value & 0x00000008) != 0,
                 distance_type: DistanceType::from_bit((attr_value & 0x00000010) != 0),
+                number_shape: NumberShape::from_code((attr_value >> 5) as u8),
             };
 
             // HWPUNIT16 너비 보정값 / HWPUNIT16 width correction value
@@ -180,29 +185,6 @@ impl Numbering {
                 (0, String::new())
             };
 
-            // UINT16 시작 번호 / UINT16 start number
-            let start_number = if offset + 2 <= data.len() {
-                let value = UINT16::from_le_bytes([data[offset], data[offset + 1]]);
-                offset += 2;
-                value
-            } else {
-                0
-            };
-
-            // UINT 수준별 시작번호 (5.0.2.5 이상, 옵션) / UINT level-specific start number (5.0.2.5+, optional)
-            let level_start_number = if version >= 0x00020500 && offset + 4 <= data.len() {
-                let value = UINT32::from_le_bytes([
-                    data[offset],
-                    data[offset + 1],
-                    data[offset + 2],
-                    data[offset + 3],
-                ]);
-                offset += 4;
-                Some(value)
-            } else {
-                None
-            };
-
             levels.push(NumberingLevelInfo {
                 attributes,
                 width,
@@ -210,19 +192,47 @@ impl Numbering {
                 char_shape_id,
                 format_length,
                 format_string,
-                start_number,
-                level_start_number,
+                start_number: 0,
+                level_start_number: None,
             });
         }
 
+        // UINT16 시작 번호 (모든 수준 공통) / UINT16 start number (shared by all levels)
+        if offset + 2 <= data.len() {
+            let start_number = UINT16::from_le_bytes([data[offset], data[offset + 1]]);
+            offset += 2;
+            for level in &mut levels {
+                level.start_number = start_number;
+            }
+        }
+
+        // UINT 수준별 시작번호 ×7 (5.0.2.5 이상, 옵션) / UINT level-specific start numbers ×7 (5.0.2.5+, optional)
+        if version >= 0x00020500 {
+            for level in &mut levels {
+                if offset + 4 > data.len() {
+                    break;
+                }
+                level.level_start_number = Some(UINT32::from_le_bytes([
+                    data[offset],
+                    data[offset + 1],
+                    data[offset + 2],
+                    data[offset + 3],
+                ]));
+                offset += 4;
+            }
+        }
+
         // 확장 번호 형식 파싱 (3개 수준, 8~10) / Parse extended number format (3 levels, 8~10)
         let mut extended_levels = Vec::new();
         for _ in 0..3 {
-            if offset + 2 > data.len() {
+            if offset + 14 > data.len() {
                 // 확장 레벨이 없을 수 있음 / Extended levels may not exist
                 break;
             }
 
+            // 문단 머리 정보 12바이트는 건너뜀 / Skip the 12-byte paragraph header information
+            offset += 12;
+
             // WORD 확장 번호 형식 길이 / WORD extended number format length
             let format_length = WORD::from_le_bytes([data[offset], data[offset + 1]]);
             offset += 2;
@@ -48,13 +48,15 @@ pub struct ParaShapeAttributes1 {
 
 /// 줄 간격 종류(한글 2007 이하 버전) / Line spacing type (HWP 2007 and below)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum LineSpacingTypeOld {
     /// 글자에 따라(%) / By character (%)
+    #[serde(alias = "bycharacter")]
     ByCharacter = 0,
     /// 고정값 / Fixed value
     Fixed = 1,
     /// 여백만 지정 / Margin only
+    #[serde(alias = "marginonly")]
     MarginOnly = 2,
 }
 
@@ -70,7 +72,7 @@ impl LineSpacingTypeOld {
 
 /// 정렬 방법 / Alignment method
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ParagraphAlignment {
     /// 양쪽 정렬 / Justify
     Justify = 0,
@@ -101,7 +103,7 @@ impl ParagraphAlignment {
 
 /// 줄 나눔 기준 단위 / Line break unit
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum LineDivideUnit {
     /// 단어 / Word
     Word = 0,
@@ -131,7 +133,7 @@ impl LineDivideUnit {
 
 /// 세로 정렬 / Vertical alignment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum VerticalAlignment {
     /// 글꼴 기준선 / Font baseline
     Baseline = 0,
@@ -156,7 +158,7 @@ impl VerticalAlignment {
 
 /// 문단 머리 모양 종류 / Paragraph header shape type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum HeaderShapeType {
     /// 없음 / None
     None = 0,
@@ -192,13 +194,15 @@ pub struct ParaShapeAttributes2 {
 
 /// 줄 간격 종류 (5.0.2.5 이상) / Line spacing type (5.0.2.5+)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum LineSpacingType {
     /// 글자에 따라 / By character
+    #[serde(alias = "bycharacter")]
     ByCharacter = 0,
     /// 고정값 / Fixed value
     Fixed = 1,
     /// 여백만 지정 / Margin only
+    #[serde(alias = "marginonly")]
     MarginOnly = 2,
     /// 최소값 / Minimum value
     Minimum = 3,
@@ -231,10 +235,8 @@ pub struct ParaShape {
     pub left_margin: INT32,
     /// 오른쪽 여백 / Right margin
     pub right_margin: INT32,
-    /// 들여쓰기 / Indent
+    /// 들여쓰기/내어쓰기 (음수면 내어쓰기) / Indent/outdent (negative for an outdent)
     pub indent: INT32,
-    /// 내어쓰기 / Outdent
-    pub outdent: INT32,
     /// 문단 위 간격 / Paragraph top spacing
     pub top_spacing: INT32,
     /// 문단 아래 간격 / Paragraph bottom spacing
@@ -261,6 +263,13 @@ pub struct ParaShape {
     pub attributes3: Option<ParaShapeAttributes3>,
     /// 줄 간격(5.0.2.5 이상, 옵션) / Line spacing (5.0.2.5+, optional)
     pub line_spacing: Option<INT32>,
+    /// 문단 수준 (5.1.0.0 이상, 옵션) / Paragraph level (5.1.0.0+, optional)
+    ///
+    /// 속성1의 3비트로는 개요 1~7 수준만 나타내므로, 개요 8~10 수준(7~9)은 여기에 0부터 센 값으로 둡니다.
+    /// The three bits in attributes 1 only cover outline levels 1-7, so levels 8-10 (7-9) are kept
+    /// here counting from 0.
+    #[serde(default)]
+    pub paragraph_level: Option<UINT32>,
 }
 
 impl ParaShape {
@@ -327,7 +336,7 @@ impl ParaShape {
         ]);
         offset += 4;
 
-        // INT32 들여쓰기 / INT32 indent
+        // INT32 들여쓰기/내어쓰기 / INT32 indent/outdent
         let indent = INT32::from_le_bytes([
             data[offset],
             data[offset + 1],
@@ -336,15 +345,6 @@ impl ParaShape {
         ]);
         offset += 4;
 
-        // INT32 내어쓰기 / INT32 outdent
-        let outdent = INT32::from_le_bytes([
-            data[offset],
-            data[offset + 1],
-            data[offset + 2],
-            data[offset + 3],
-        ]);
-        offset += 4;
-
         // INT32 문단 위 간격 / INT32 paragraph top spacing
         let top_spacing = INT32::from_le_bytes([
             data[offset],
@@ -442,18 +442,29 @@ impl ParaShape {
                 data[offset + 2],
                 data[offset + 3],
             ]);
-            let _ = offset + 4; // Mark as intentionally unused
+            offset += 4;
             Some(value)
         } else {
             None
         };
 
+        // UINT32 문단 수준 (5.1.0.0 이상, 옵션) / UINT32 paragraph level (5.1.0.0+, optional)
+        let paragraph_level = if offset + 4 <= data.len() {
+            Some(UINT32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ]))
+        } else {
+            None
+        };
+
         Ok(ParaShape {
             attributes1,
             left_margin,
             right_margin,
             indent,
-            outdent,
             top_spacing,
             bottom_spacing,
             line_spacing_old,
@@ -467,6 +478,7 @@ impl ParaShape {
             attributes2,
             attributes3,
             line_spacing,
+            paragraph_level,
         })
     }
 }
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// 스타일 종류 / Style type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum StyleType {
     /// 문단 스타일 / Paragraph style
     Paragraph = 0,
@@ -41,7 +41,7 @@ pub struct Style {
     pub lang_id: INT16,
     /// 문단 모양 ID 참조 값 (스타일 종류가 문단인 경우 필수) / Paragraph shape ID reference (required if style type is paragraph)
     pub para_shape_id: Option<UINT16>,
-    /// 글자 모양 ID (스타일 종류가 글자인 경우 필수) / Character shape ID (required if style type is character)
+    /// 글자 모양 ID (문단 스타일은 스타일의 기본 글자 모양) / Character shape ID (the default character shape for paragraph styles)
     pub char_shape_id: Option<UINT16>,
 }
 
@@ -155,7 +155,7 @@ impl Style {
             None
         };
 
-        // UINT16 글자 모양 ID (스타일 종류가 글자인 경우 필수) / UINT16 character shape ID (required if style type is character)
+        // UINT16 글자 모양 ID (문단 스타일도 글자 모양을 가짐) / UINT16 character shape ID (paragraph styles carry one too)
         if offset + 2 > data.len() {
             return Err(HwpError::insufficient_data(
                 "Style character shape ID",
@@ -163,13 +163,8 @@ impl Style {
                 data.len() - offset,
             ));
         }
-        let char_shape_id_value = UINT16::from_le_bytes([data[offset], data[offset + 1]]);
+        let char_shape_id = Some(UINT16::from_le_bytes([data[offset], data[offset + 1]]));
         // offset += 2; // Not needed, end of parsing
-        let char_shape_id = if style_type == StyleType::Character {
-            Some(char_shape_id_value)
-        } else {
-            None
-        };
 
         Ok(Style {
             local_name,
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// 탭 종류 / Tab type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum TabType {
     /// 왼쪽 / Left
     Left = 0,
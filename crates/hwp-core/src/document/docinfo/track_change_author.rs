@@ -2,15 +2,22 @@
 ///
 /// 스펙 문서 매핑: 표 4 - 변경 추적 작성자 / Spec mapping: Table 4 - Track change author
 /// 상세 구조는 스펙 문서에 명시되지 않음 / Detailed structure not specified in spec
+/// HWPX의 `<hh:trackChangeAuthor>`는 파싱된 필드로 채워짐 / HWPX `<hh:trackChangeAuthor>` fills the parsed fields
 use crate::error::HwpError;
 use serde::{Deserialize, Serialize};
 
 /// 변경 추적 작성자 / Track change author
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TrackChangeAuthor {
     /// Raw data (가변) / Raw data (variable)
     #[serde(skip)]
     pub raw_data: Vec<u8>,
+    /// 작성자 ID (HWPX) / Author ID (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// 작성자 이름 (HWPX) / Author name (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 impl TrackChangeAuthor {
@@ -24,6 +31,7 @@ impl TrackChangeAuthor {
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
         Ok(TrackChangeAuthor {
             raw_data: data.to_vec(),
+            ..Default::default()
         })
     }
 }
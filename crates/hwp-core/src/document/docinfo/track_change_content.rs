@@ -2,15 +2,40 @@
 ///
 /// 스펙 문서 매핑: 표 4 - 변경 추적 내용 및 모양 / Spec mapping: Table 4 - Track change content and shape
 /// 상세 구조는 스펙 문서에 명시되지 않음 / Detailed structure not specified in spec
+/// HWPX의 `<hh:trackChange>`는 파싱된 필드로 채워짐 / HWPX `<hh:trackChange>` fills the parsed fields
 use crate::error::HwpError;
 use serde::{Deserialize, Serialize};
 
+/// 변경 추적 종류 / Track change kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackChangeKind {
+    /// 삽입 / Insertion
+    Insert,
+    /// 삭제 / Deletion
+    Delete,
+    /// 서식 변경 / Formatting change
+    Format,
+}
+
 /// 변경 추적 내용 및 모양 / Track change content and shape
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TrackChangeContent {
     /// Raw data (가변) / Raw data (variable)
     #[serde(skip)]
     pub raw_data: Vec<u8>,
+    /// 변경 ID (HWPX) / Change ID (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// 변경 종류 (HWPX) / Change kind (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<TrackChangeKind>,
+    /// 작성자 ID (HWPX) / Author ID (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<u32>,
+    /// 변경 일시 (HWPX) / Change date (HWPX)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
 }
 
 impl TrackChangeContent {
@@ -24,6 +49,7 @@ impl TrackChangeContent {
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
         Ok(TrackChangeContent {
             raw_data: data.to_vec(),
+            ..Default::default()
         })
     }
 }
@@ -0,0 +1,215 @@
+/// 외부 참조 목록 모듈 / External reference inventory module
+///
+/// 하이퍼링크, 문서에 포함되지 않고 연결만 된 그림, 연결된 OLE 개체의 원본을 위치와 함께 모아
+/// 보관 전에 외부 의존성을 점검할 수 있게 합니다. 위치는 `Field`와 같은 (구역, 최상위 문단,
+/// 중첩 문단) 번호를 씁니다.
+/// Collects hyperlinks, linked (non-embedded) pictures and the sources of linked OLE objects
+/// with their locations, so documents can be audited for external dependencies before
+/// archiving. Locations use the same (section, top-level paragraph, nested paragraph) indices as
+/// `Field`.
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+
+use super::bodytext::{CtrlId, Paragraph, ParagraphRecord};
+use super::docinfo::BinDataRecord;
+use super::fields::{collect_fields, hyperlink_target, FieldKind};
+use super::security::{ole_link_target, ole_storage};
+use super::{HwpDocument, ParagraphLocation};
+use crate::types::WORD;
+
+/// 외부 참조 종류 / Kind of external reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalReferenceKind {
+    /// 하이퍼링크 / Hyperlink
+    Hyperlink,
+    /// 연결된 그림 / Linked picture
+    LinkedImage,
+    /// 연결된 OLE 개체 / Linked OLE object
+    OleLink,
+}
+
+/// 외부 참조 하나 / A single external reference
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalReference {
+    pub kind: ExternalReferenceKind,
+    /// 대상 주소나 파일 경로 / Target address or file path
+    pub target: String,
+    /// 하이퍼링크의 표시 텍스트 / Display text of a hyperlink
+    pub text: Option<String>,
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+}
+
+impl HwpDocument {
+    /// 문서의 외부 참조 모으기 / Collect the document's external references
+    ///
+    /// 표 셀, 글상자, 머리말, 각주 안의 참조도 찾습니다. 파서가 같은 문단을 두 곳에 두는 경우
+    /// (표 컨트롤의 문단, 각주의 목록 헤더) 문서 순회와 같은 규칙으로 한 번만 셉니다.
+    /// References inside table cells, text boxes, headers and footnotes are included. Where the
+    /// parser keeps the same paragraph in two places (table control paragraphs, note list
+    /// headers), it is counted once using the same rules as document traversal.
+    ///
+    /// # Returns / 반환값
+    /// 문서 순서대로 정렬된 외부 참조 목록 / External references in document order
+    pub fn external_references(&self) -> Vec<ExternalReference> {
+        let mut references = Vec::new();
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let nested = paragraph.nested_paragraphs();
+                let mut collector = Collector {
+                    document: self,
+                    nested: &nested,
+                    location: ParagraphLocation::top_level(section_index, paragraph_index),
+                    references: &mut references,
+                };
+                collector.paragraph(paragraph, None);
+            }
+        }
+        references
+    }
+}
+
+/// 최상위 문단 하나의 참조 수집기 / Reference collector for one top-level paragraph
+struct Collector<'d, 'r> {
+    document: &'d HwpDocument,
+    /// 최상위 문단의 중첩 문단 (위치 번호용) / Nested paragraphs of the top-level paragraph (for location indices)
+    nested: &'r [&'d Paragraph],
+    location: ParagraphLocation,
+    references: &'r mut Vec<ExternalReference>,
+}
+
+impl<'d> Collector<'d, '_> {
+    fn paragraph(&mut self, paragraph: &'d Paragraph, nested_index: Option<usize>) {
+        let mut fields = Vec::new();
+        collect_fields(paragraph, self.location.nested(nested_index), &mut fields);
+        for field in fields {
+            if field.kind != FieldKind::Hyperlink {
+                continue;
+            }
+            if let Some(target) = field.command.as_deref().map(hyperlink_target) {
+                let text = Some(field.text).filter(|text| !text.is_empty());
+                self.push(ExternalReferenceKind::Hyperlink, target, text, nested_index);
+            }
+        }
+
+        for record in &paragraph.records {
+            self.record(record, nested_index);
+        }
+    }
+
+    /// 문단 밖 레코드 (`nested_index`는 레코드를 담은 문단) / Record outside paragraph text (`nested_index` is the paragraph holding it)
+    fn record(&mut self, record: &'d ParagraphRecord, nested_index: Option<usize>) {
+        match record {
+            ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } => {
+                // 문서 순회와 같은 규칙으로 사본을 건너뜀 / Skip copies with the same rules as document traversal
+                let has_table = children
+                    .iter()
+                    .any(|child| matches!(child, ParagraphRecord::Table { .. }));
+                for child in children {
+                    if matches!(child, ParagraphRecord::ListHeader { .. }) && !paragraphs.is_empty()
+                    {
+                        continue;
+                    }
+                    self.record(child, nested_index);
+                }
+                if !(header.ctrl_id == CtrlId::TABLE && has_table) {
+                    self.paragraphs(paragraphs);
+                }
+            }
+            ParagraphRecord::ListHeader { paragraphs, .. } => self.paragraphs(paragraphs),
+            ParagraphRecord::Table { table } => {
+                for cell in &table.cells {
+                    self.paragraphs(&cell.paragraphs);
+                }
+            }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                for child in children {
+                    self.record(child, nested_index);
+                }
+            }
+            ParagraphRecord::ShapeComponentPicture {
+                shape_component_picture,
+            } => {
+                let bindata_id = shape_component_picture.picture_info.bindata_id;
+                if let Some(path) = self.linked_bin_data(bindata_id) {
+                    self.push(ExternalReferenceKind::LinkedImage, path, None, nested_index);
+                }
+            }
+            ParagraphRecord::HwpxImage {
+                binary_item_ref, ..
+            } => {
+                if let Some(href) = self.document.security.linked_items.get(binary_item_ref) {
+                    let href = href.clone();
+                    self.push(ExternalReferenceKind::LinkedImage, href, None, nested_index);
+                }
+            }
+            ParagraphRecord::ShapeComponentOle {
+                shape_component_ole,
+            } => {
+                let bindata_id = shape_component_ole.bindata_id;
+                let target = self.linked_bin_data(bindata_id).or_else(|| {
+                    let item = self
+                        .document
+                        .bin_data
+                        .items
+                        .iter()
+                        .find(|item| item.index == bindata_id)?;
+                    let data = STANDARD.decode(&item.data).ok()?;
+                    ole_link_target(ole_storage(&data)?)
+                });
+                if let Some(target) = target {
+                    self.push(ExternalReferenceKind::OleLink, target, None, nested_index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn paragraphs(&mut self, paragraphs: &'d [Paragraph]) {
+        for paragraph in paragraphs {
+            let nested_index = self
+                .nested
+                .iter()
+                .position(|nested| std::ptr::eq(*nested, paragraph));
+            self.paragraph(paragraph, nested_index);
+        }
+    }
+
+    /// HWP 5.0 연결 BinData 레코드의 경로 (ID는 레코드 순서로 1부터)
+    /// Path of an HWP 5.0 linked BinData record (IDs count records from 1)
+    fn linked_bin_data(&self, bindata_id: WORD) -> Option<String> {
+        let index = usize::from(bindata_id).checked_sub(1)?;
+        match self.document.doc_info.bin_data.get(index)? {
+            BinDataRecord::Link { link, .. } => {
+                let path = if link.absolute_path.trim().is_empty() {
+                    &link.relative_path
+                } else {
+                    &link.absolute_path
+                };
+                Some(path.trim().to_string()).filter(|path| !path.is_empty())
+            }
+            _ => None,
+        }
+    }
+
+    fn push(
+        &mut self,
+        kind: ExternalReferenceKind,
+        target: String,
+        text: Option<String>,
+        nested_index: Option<usize>,
+    ) {
+        self.references.push(ExternalReference {
+            kind,
+            target,
+            text,
+            location: self.location.nested(nested_index),
+        });
+    }
+}
@@ -0,0 +1,487 @@
+/// 필드 코드 모듈 / Field code module
+///
+/// 날짜, 파일 경로, 문서 요약 정보, 하이퍼링크 등의 필드를 종류와 명령, 저장된 표시 텍스트와 함께
+/// 모으고, 표시 텍스트가 비어 있는 필드에 계산한 값을 채웁니다. 쪽 번호처럼 조판이 있어야 알 수 있는
+/// 값은 계산하지 않고 저장된 텍스트만 씁니다.
+/// Collects date, file path, document summary, hyperlink and similar fields with their type,
+/// command and stored display text, and fills computed values into fields whose display text is
+/// empty. Values that need page layout, such as page numbers, are not computed; only the stored
+/// text is used.
+use super::bodytext::{ParaTextRun, Paragraph, ParagraphRecord};
+use super::{CtrlHeaderData, HwpDocument, ParagraphLocation, SummaryInformation};
+use serde::{Deserialize, Serialize};
+
+/// HWP 5.0 필드 시작 제어 문자 / HWP 5.0 field start control character
+pub(crate) const FIELD_START: u8 = 3;
+/// HWP 5.0 필드 끝 제어 문자 / HWP 5.0 field end control character
+pub(crate) const FIELD_END: u8 = 4;
+
+/// 필드 종류 / Field kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldKind {
+    /// 현재 날짜 (`DATE`, `%dte`) / Current date (`DATE`, `%dte`)
+    Date,
+    /// 문서 작성 날짜 (`DOC_DATE`, `%ddt`) / Document date (`DOC_DATE`, `%ddt`)
+    DocDate,
+    /// 파일 이름/경로 (`PATH`, `%pat`, `%fil`) / File name or path (`PATH`, `%pat`, `%fil`)
+    FilePath,
+    /// 쪽 번호 (`%pgn`) / Page number (`%pgn`)
+    PageNumber,
+    /// 문서 요약 정보 (`SUMMARY`, `%ttl`, `%aut` 등) / Document summary (`SUMMARY`, `%ttl`, `%aut`, ...)
+    Summary,
+    /// 하이퍼링크 (`HYPERLINK`, `%hlk`) / Hyperlink (`HYPERLINK`, `%hlk`)
+    Hyperlink,
+    /// 누름틀 (`CLICK_HERE`, `%clk`) / Click-here placeholder (`CLICK_HERE`, `%clk`)
+    ClickHere,
+    /// 개인 정보 보호 필드 (`PRIVATE_INFO`, `%cpr`) / Personal information field (`PRIVATE_INFO`, `%cpr`)
+    PrivateInfo,
+    /// 그 밖의 필드 / Any other field
+    Other,
+}
+
+impl FieldKind {
+    /// HWPX `type` 속성이나 HWP 5.0 컨트롤 ID로 종류 결정 / Kind from an HWPX `type` attribute or HWP 5.0 control ID
+    pub fn from_field_type(field_type: &str) -> Self {
+        match field_type {
+            "DATE" | "%dte" => FieldKind::Date,
+            "DOC_DATE" | "%ddt" => FieldKind::DocDate,
+            "PATH" | "%pat" | "%fil" => FieldKind::FilePath,
+            "%pgn" => FieldKind::PageNumber,
+            "SUMMARY" | "%dsm" | "%hsm" | "%ttl" | "%aut" | "%sub" | "%key" | "%cmt" | "%lsb"
+            | "%crt" | "%sav" => FieldKind::Summary,
+            "HYPERLINK" | "%hlk" => FieldKind::Hyperlink,
+            "CLICK_HERE" | "%clk" => FieldKind::ClickHere,
+            "PRIVATE_INFO" | "%cpr" => FieldKind::PrivateInfo,
+            _ => FieldKind::Other,
+        }
+    }
+}
+
+/// 필드 하나 / A single field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Field {
+    pub kind: FieldKind,
+    /// 원본 종류 문자열 (HWPX `type` 속성 또는 HWP 5.0 컨트롤 ID) / Original type string (HWPX `type` attribute or HWP 5.0 control ID)
+    pub field_type: String,
+    /// 필드 이름 / Field name
+    pub name: Option<String>,
+    /// 필드 명령 / Field command
+    pub command: Option<String>,
+    /// 저장된 표시 텍스트 / Stored display text
+    pub text: String,
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+}
+
+impl Field {
+    /// 표시할 값 (저장된 텍스트, 비어 있으면 계산한 값) / Value to display (stored text, or the computed value when empty)
+    ///
+    /// # Arguments / 매개변수
+    /// * `document` - 필드가 속한 문서 / Document holding the field
+    /// * `context` - 문서 밖에서 주는 값 / Values supplied from outside the document
+    pub fn value(&self, document: &HwpDocument, context: &FieldContext) -> Option<String> {
+        if !self.text.is_empty() {
+            return Some(self.text.clone());
+        }
+        computed_value(
+            self.kind,
+            &self.field_type,
+            self.command.as_deref(),
+            document,
+            context,
+        )
+    }
+}
+
+/// 필드 값 계산에 쓰는 문서 밖의 값 / Values from outside the document used to compute fields
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldContext {
+    /// 파일 이름/경로 필드에 넣을 값 / Value for file name/path fields
+    pub file_path: Option<String>,
+    /// 현재 날짜 필드에 넣을 값 / Value for current date fields
+    pub date: Option<String>,
+}
+
+impl FieldContext {
+    /// 파일 경로 설정 / Set file path
+    pub fn with_file_path(mut self, path: &str) -> Self {
+        self.file_path = Some(path.to_string());
+        self
+    }
+
+    /// 현재 날짜 설정 / Set current date
+    pub fn with_date(mut self, date: &str) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+}
+
+impl HwpDocument {
+    /// 문서의 필드 모으기 (메모와 인용 제외) / Collect the document's fields (memos and citations excluded)
+    ///
+    /// HWP 5.0 필드의 표시 텍스트는 문단 텍스트의 필드 시작/끝 제어 문자 사이에서 읽습니다.
+    /// The display text of HWP 5.0 fields is read between the field start/end control characters.
+    ///
+    /// # Returns / 반환값
+    /// 문서 순서대로 정렬된 필드 목록 / Fields in document order
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = Vec::new();
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let location = ParagraphLocation::top_level(section_index, paragraph_index);
+                collect_fields(paragraph, location, &mut fields);
+                for (nested_index, nested) in paragraph.nested_paragraphs().into_iter().enumerate()
+                {
+                    collect_fields(nested, location.nested(Some(nested_index)), &mut fields);
+                }
+            }
+        }
+        fields
+    }
+
+    /// 표시 텍스트가 빈 필드에 계산한 값 채우기 / Fill computed values into fields with empty display text
+    ///
+    /// 값은 필드 위치의 문단 텍스트에 들어가므로 이후 Markdown/HTML/텍스트 출력에 나타납니다.
+    /// HWP 5.0 필드는 표시 텍스트가 늘 함께 저장되므로 HWPX 필드만 바뀝니다.
+    /// Values are inserted into the paragraph text at the field position, so later Markdown, HTML
+    /// and text output shows them. HWP 5.0 fields always store their display text, so only HWPX
+    /// fields change.
+    ///
+    /// # Arguments / 매개변수
+    /// * `context` - 문서 밖에서 주는 값 / Values supplied from outside the document
+    ///
+    /// # Returns / 반환값
+    /// 값을 채운 필드 수 / Number of fields filled in
+    pub fn resolve_fields(&mut self, context: &FieldContext) -> usize {
+        let mut sections = std::mem::take(&mut self.body_text.sections);
+        let mut resolved = 0;
+        for section in &mut sections {
+            for paragraph in &mut section.paragraphs {
+                resolved += resolve_paragraph(paragraph, self, context);
+            }
+        }
+        self.body_text.sections = sections;
+        resolved
+    }
+}
+
+/// 문단 레코드에서 필드 수집 / Collect fields from a paragraph's records
+pub(crate) fn collect_fields(
+    paragraph: &Paragraph,
+    location: ParagraphLocation,
+    fields: &mut Vec<Field>,
+) {
+    let mut hwp5_texts = hwp5_field_texts(paragraph).into_iter();
+    for record in &paragraph.records {
+        let (field_type, name, command, text) = match record {
+            ParagraphRecord::HwpxField {
+                field_type,
+                name,
+                command,
+                text,
+                ..
+            } => (field_type, name.clone(), command.clone(), text.clone()),
+            ParagraphRecord::CtrlHeader { header, .. } => match &header.data {
+                CtrlHeaderData::Field {
+                    field_type,
+                    command,
+                    ..
+                } => (
+                    field_type,
+                    None,
+                    Some(command.clone()).filter(|c| !c.is_empty()),
+                    hwp5_texts.next().unwrap_or_default(),
+                ),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        fields.push(Field {
+            kind: FieldKind::from_field_type(field_type),
+            field_type: field_type.clone(),
+            name,
+            command,
+            text,
+            location,
+        });
+    }
+}
+
+/// HWP 5.0 문단에서 필드마다 시작/끝 제어 문자 사이의 텍스트 / Text between field start/end controls for each field of an HWP 5.0 paragraph
+pub(crate) fn hwp5_field_texts(paragraph: &Paragraph) -> Vec<String> {
+    let mut texts = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    for record in &paragraph.records {
+        let ParagraphRecord::ParaText { runs, .. } = record else {
+            continue;
+        };
+        for run in runs {
+            match run {
+                ParaTextRun::Control { code, .. } if *code == FIELD_START => {
+                    open.push(texts.len());
+                    texts.push(String::new());
+                }
+                ParaTextRun::Control { code, .. } if *code == FIELD_END => {
+                    open.pop();
+                }
+                ParaTextRun::Text { text } => {
+                    for &index in &open {
+                        texts[index].push_str(text);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    texts
+}
+
+/// 문단과 중첩 문단의 빈 HWPX 필드에 값 채우기 / Fill empty HWPX fields of a paragraph and its nested paragraphs
+fn resolve_paragraph(
+    paragraph: &mut Paragraph,
+    document: &HwpDocument,
+    context: &FieldContext,
+) -> usize {
+    let mut resolved = 0;
+    let mut inserts = Vec::new();
+    for record in paragraph.records.iter_mut() {
+        if let ParagraphRecord::HwpxField {
+            field_type,
+            command,
+            text,
+            offset,
+            ..
+        } = record
+        {
+            if !text.is_empty() {
+                continue;
+            }
+            let kind = FieldKind::from_field_type(field_type);
+            if let Some(value) =
+                computed_value(kind, field_type, command.as_deref(), document, context)
+            {
+                inserts.push((*offset, value.clone()));
+                *text = value;
+            }
+        }
+    }
+    // 뒤쪽 필드부터 넣어야 앞쪽 위치가 바뀌지 않음 / Insert from the back so earlier offsets stay valid
+    inserts.sort_by_key(|insert| std::cmp::Reverse(insert.0));
+    for (offset, value) in inserts {
+        insert_text(paragraph, offset, &value);
+        resolved += 1;
+    }
+
+    for record in &mut paragraph.records {
+        resolved += resolve_record(record, document, context);
+    }
+    resolved
+}
+
+/// 레코드 안의 중첩 문단 처리 / Process paragraphs nested in a record
+fn resolve_record(
+    record: &mut ParagraphRecord,
+    document: &HwpDocument,
+    context: &FieldContext,
+) -> usize {
+    let mut resolved = 0;
+    match record {
+        ParagraphRecord::CtrlHeader {
+            children,
+            paragraphs,
+            ..
+        } => {
+            for child in children {
+                resolved += resolve_record(child, document, context);
+            }
+            for paragraph in paragraphs {
+                resolved += resolve_paragraph(paragraph, document, context);
+            }
+        }
+        ParagraphRecord::ListHeader { paragraphs, .. } => {
+            for paragraph in paragraphs {
+                resolved += resolve_paragraph(paragraph, document, context);
+            }
+        }
+        ParagraphRecord::MasterPage { master_page } => {
+            for paragraph in &mut master_page.paragraphs {
+                resolved += resolve_paragraph(paragraph, document, context);
+            }
+        }
+        ParagraphRecord::Table { table } => {
+            for cell in &mut table.cells {
+                for paragraph in &mut cell.paragraphs {
+                    resolved += resolve_paragraph(paragraph, document, context);
+                }
+            }
+        }
+        ParagraphRecord::ShapeComponent { children, .. } => {
+            for child in children {
+                resolved += resolve_record(child, document, context);
+            }
+        }
+        _ => {}
+    }
+    resolved
+}
+
+/// 문단 텍스트의 문자 위치에 값 넣기 / Insert a value at a character offset of the paragraph text
+fn insert_text(paragraph: &mut Paragraph, offset: usize, value: &str) {
+    let Some(ParagraphRecord::ParaText { text, runs, .. }) = paragraph
+        .records
+        .iter_mut()
+        .find(|record| matches!(record, ParagraphRecord::ParaText { .. }))
+    else {
+        return;
+    };
+    text.insert_str(byte_index(text, offset), value);
+
+    // 위치를 담은 텍스트 조각에 넣고, 없으면 끝에 붙임 / Insert into the run holding the offset, or append at the end
+    let mut remaining = offset;
+    let mut inserted = false;
+    for run in runs.iter_mut() {
+        if let ParaTextRun::Text { text } = run {
+            let len = text.chars().count();
+            if remaining <= len {
+                text.insert_str(byte_index(text, remaining), value);
+                inserted = true;
+                break;
+            }
+            remaining -= len;
+        }
+    }
+    if !inserted {
+        runs.push(ParaTextRun::Text {
+            text: value.to_string(),
+        });
+    }
+    paragraph.para_header.text_char_count += value.chars().count() as u32;
+}
+
+/// 문자 위치의 바이트 인덱스 (끝을 넘으면 길이) / Byte index of a character offset (the length when past the end)
+fn byte_index(text: &str, offset: usize) -> usize {
+    text.char_indices()
+        .nth(offset)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len())
+}
+
+/// 저장된 텍스트 없이 계산할 수 있는 필드 값 / Field value that can be computed without stored text
+fn computed_value(
+    kind: FieldKind,
+    field_type: &str,
+    command: Option<&str>,
+    document: &HwpDocument,
+    context: &FieldContext,
+) -> Option<String> {
+    let summary = document.summary_information.as_ref();
+    let value = match kind {
+        FieldKind::Date => context.date.clone(),
+        FieldKind::DocDate => summary
+            .and_then(|s| s.create_time.as_deref())
+            .and_then(|time| time.get(..10))
+            .map(str::to_string),
+        FieldKind::FilePath => context.file_path.clone(),
+        FieldKind::Summary => summary.and_then(|s| summary_value(s, field_type, command?)),
+        FieldKind::Hyperlink => command.map(hyperlink_target),
+        FieldKind::PageNumber
+        | FieldKind::ClickHere
+        | FieldKind::PrivateInfo
+        | FieldKind::Other => None,
+    };
+    value.filter(|v| !v.is_empty())
+}
+
+/// 요약 정보 필드 값 (HWP 5.0은 컨트롤 ID, HWPX는 명령의 속성 이름으로 고름)
+/// Summary field value (chosen by control ID for HWP 5.0, by the property name in the command for HWPX)
+fn summary_value(summary: &SummaryInformation, field_type: &str, command: &str) -> Option<String> {
+    let command = command.to_ascii_lowercase();
+    let property = match field_type {
+        "%ttl" => "title",
+        "%aut" => "author",
+        "%sub" => "subject",
+        "%key" => "keyword",
+        "%cmt" => "comment",
+        "%lsb" => "lastsavedby",
+        _ => [
+            "title",
+            "author",
+            "subject",
+            "keyword",
+            "comment",
+            "lastsavedby",
+        ]
+        .into_iter()
+        .find(|property| command.contains(property))?,
+    };
+    let value = match property {
+        "title" => &summary.title,
+        "author" => &summary.author,
+        "subject" => &summary.subject,
+        "keyword" => &summary.keywords,
+        "comment" => &summary.comments,
+        _ => &summary.last_saved_by,
+    };
+    value.clone()
+}
+
+/// 하이퍼링크 명령에서 대상 주소 (`http\://a.com;1;0;0;` → `http://a.com`)
+/// Target address from a hyperlink command (`http\://a.com;1;0;0;` → `http://a.com`)
+pub(crate) fn hyperlink_target(command: &str) -> String {
+    let target = command.split(';').next().unwrap_or(command);
+    target.replace("\\:", ":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{document_with_paragraphs, text_paragraph};
+
+    fn hwpx_field(field_type: &str, command: Option<&str>, offset: usize) -> ParagraphRecord {
+        ParagraphRecord::HwpxField {
+            field_type: field_type.to_string(),
+            name: None,
+            command: command.map(str::to_string),
+            text: String::new(),
+            offset,
+        }
+    }
+
+    #[test]
+    fn test_resolve_fields_fills_empty_hwpx_fields() {
+        let text = "제목: , 작성일: , 파일: .";
+        let mut paragraph = text_paragraph(text);
+        paragraph.records.extend([
+            hwpx_field("SUMMARY", Some("$Title"), 4),
+            hwpx_field("DOC_DATE", None, 11),
+            hwpx_field("PATH", None, 17),
+            hwpx_field("DATE", None, 18),
+        ]);
+        let mut document = document_with_paragraphs(vec![paragraph]);
+        document.summary_information = Some(SummaryInformation {
+            title: Some("연간 보고서".to_string()),
+            create_time: Some("2024-03-01T09:00:00+09:00".to_string()),
+            ..Default::default()
+        });
+
+        let context = FieldContext::default().with_file_path("report.hwpx");
+        assert_eq!(document.resolve_fields(&context), 3);
+
+        let paragraph = &document.body_text.sections[0].paragraphs[0];
+        assert_eq!(
+            paragraph.text(),
+            "제목: 연간 보고서, 작성일: 2024-03-01, 파일: report.hwpx."
+        );
+        let fields = document.fields();
+        assert_eq!(fields[0].kind, FieldKind::Summary);
+        assert_eq!(fields[2].text, "report.hwpx");
+        // 현재 날짜를 주지 않으면 빈 채로 남음 / Left empty without a current date
+        assert_eq!(fields[3].value(&document, &context), None);
+        assert_eq!(
+            fields[3].value(&document, &context.with_date("2024-05-05")),
+            Some("2024-05-05".to_string())
+        );
+    }
+}
@@ -11,7 +11,10 @@ use serde::{Deserialize, Serialize};
 mod constants;
 mod serialize;
 
-use serialize::{serialize_document_flags, serialize_license_flags, serialize_version};
+use serialize::{
+    deserialize_document_flags, deserialize_license_flags, deserialize_version,
+    serialize_document_flags, serialize_license_flags, serialize_version,
+};
 
 /// FileHeader structure for HWP 5.0
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,22 +23,31 @@ pub struct FileHeader {
     pub signature: String,
     /// File version (4 bytes, DWORD)
     /// Format: 0xMMnnPPrr (e.g., 0x05000300 = 5.0.3.0)
-    #[serde(serialize_with = "serialize_version")]
+    #[serde(
+        serialize_with = "serialize_version",
+        deserialize_with = "deserialize_version"
+    )]
     pub version: DWORD,
     /// Document flags (4 bytes, DWORD)
     /// Bit flags: compression, encryption, distribution, script, DRM, electronic signature, etc.
-    #[serde(serialize_with = "serialize_document_flags")]
+    #[serde(
+        serialize_with = "serialize_document_flags",
+        deserialize_with = "deserialize_document_flags"
+    )]
     pub document_flags: DWORD,
     /// License flags (4 bytes, DWORD)
     /// Bit flags: CCL, KOGL license, copy restriction, etc.
-    #[serde(serialize_with = "serialize_license_flags")]
+    #[serde(
+        serialize_with = "serialize_license_flags",
+        deserialize_with = "deserialize_license_flags"
+    )]
     pub license_flags: DWORD,
     /// Encryption version (4 bytes, DWORD)
     pub encrypt_version: DWORD,
     /// KOGL license country (1 byte, BYTE)
     pub kogl_country: BYTE,
     /// Reserved (207 bytes) - excluded from JSON serialization
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub reserved: Vec<u8>,
 }
 
@@ -102,12 +114,52 @@ impl FileHeader {
         (self.document_flags & 0x02) != 0
     }
 
+    /// Check if file is a distribution document
+    /// 배포용 문서 여부 확인
+    pub fn is_distribution(&self) -> bool {
+        (self.document_flags & 0x04) != 0 // Bit 2
+    }
+
+    /// Check if file is DRM protected (DRM or certificate DRM)
+    /// DRM 보안 문서 여부 확인 (DRM 또는 공인 인증서 DRM)
+    pub fn is_drm(&self) -> bool {
+        (self.document_flags & (0x10 | 0x400)) != 0 // Bit 4, Bit 10
+    }
+
+    /// Check if file has an electronic signature
+    /// 전자 서명 정보 존재 여부 확인
+    pub fn has_electronic_signature(&self) -> bool {
+        (self.document_flags & 0x80) != 0 // Bit 7
+    }
+
     /// Check if XMLTemplate storage exists
     /// XMLTemplate 스토리지 존재 여부 확인
     pub fn has_xml_template(&self) -> bool {
         (self.document_flags & 0x20) != 0 // Bit 5
     }
 
+    /// Check that the body can be read without a DRM license or password
+    /// DRM 라이선스나 암호 없이 본문을 읽을 수 있는지 확인
+    ///
+    /// # Returns
+    /// `DrmProtected` for DRM documents, `PasswordRequired` for encrypted documents
+    pub fn ensure_readable(&self) -> Result<(), HwpError> {
+        if self.is_drm() {
+            let kind = if (self.document_flags & 0x400) != 0 {
+                "certificate DRM"
+            } else {
+                "DRM"
+            };
+            return Err(HwpError::DrmProtected {
+                reason: format!("HWP 5.0 FileHeader marks the document as {kind} protected"),
+            });
+        }
+        if self.is_encrypted() {
+            return Err(HwpError::PasswordRequired);
+        }
+        Ok(())
+    }
+
     /// Convert FileHeader to JSON string
     pub fn to_json(&self) -> Result<String, HwpError> {
         serde_json::to_string_pretty(self).map_err(HwpError::from)
@@ -115,10 +167,6 @@ impl FileHeader {
 
     /// Get version as string (e.g., "5.0.3.0")
     pub fn version_string(&self) -> String {
-        let major = (self.version >> 24) & 0xFF;
-        let minor = (self.version >> 16) & 0xFF;
-        let patch = (self.version >> 8) & 0xFF;
-        let revision = self.version & 0xFF;
-        format!("{major}.{minor}.{patch}.{revision}")
+        serialize::format_version(self.version)
     }
 }
@@ -1,5 +1,6 @@
 use crate::types::DWORD;
-use serde::Serializer;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
 
 use super::constants::{document_flags, license_flags};
 
@@ -13,7 +14,7 @@ where
 
 /// Format version DWORD to "M.n.P.r" string
 /// Format: 0xMMnnPPrr (e.g., 0x05000300 = "5.0.3.0")
-fn format_version(version: DWORD) -> String {
+pub(crate) fn format_version(version: DWORD) -> String {
     let major = (version >> 24) & 0xFF;
     let minor = (version >> 16) & 0xFF;
     let patch = (version >> 8) & 0xFF;
@@ -21,68 +22,65 @@ fn format_version(version: DWORD) -> String {
     format!("{major}.{minor}.{patch}.{revision}")
 }
 
+/// document_flags 비트와 이름 대응표 / document_flags bit-to-name table
+const DOCUMENT_FLAG_BITS: &[(DWORD, &str)] = &[
+    (0x01, document_flags::COMPRESSED),
+    (0x02, document_flags::ENCRYPTED),
+    (0x04, document_flags::DISTRIBUTION),
+    (0x08, document_flags::SCRIPT),
+    (0x10, document_flags::DRM),
+    (0x20, document_flags::XML_TEMPLATE),
+    (0x40, document_flags::HISTORY),
+    (0x80, document_flags::ELECTRONIC_SIGNATURE),
+    (0x100, document_flags::CERTIFICATE_ENCRYPTION),
+    (0x200, document_flags::SIGNATURE_PREVIEW),
+    (0x400, document_flags::CERTIFICATE_DRM),
+    (0x800, document_flags::CCL),
+    (0x1000, document_flags::MOBILE_OPTIMIZED),
+    (0x2000, document_flags::PRIVACY_SECURITY),
+    (0x4000, document_flags::TRACK_CHANGE),
+    (0x8000, document_flags::KOGL),
+    (0x10000, document_flags::VIDEO_CONTROL),
+    (0x20000, document_flags::TABLE_OF_CONTENTS),
+];
+
+/// license_flags 비트와 이름 대응표 / license_flags bit-to-name table
+const LICENSE_FLAG_BITS: &[(DWORD, &str)] = &[
+    (0x01, license_flags::CCL_KOGL),
+    (0x02, license_flags::COPY_RESTRICTED),
+    (0x04, license_flags::COPY_ALLOWED_SAME_CONDITION),
+];
+
 /// Serialize document_flags DWORD as array of flag constants
 pub fn serialize_document_flags<S>(flags: &DWORD, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    use serde::ser::SerializeSeq;
-    let mut active_flags = Vec::new();
+    serialize_flags(*flags, DOCUMENT_FLAG_BITS, serializer)
+}
 
-    if (*flags & 0x01) != 0 {
-        active_flags.push(document_flags::COMPRESSED);
-    }
-    if (*flags & 0x02) != 0 {
-        active_flags.push(document_flags::ENCRYPTED);
-    }
-    if (*flags & 0x04) != 0 {
-        active_flags.push(document_flags::DISTRIBUTION);
-    }
-    if (*flags & 0x08) != 0 {
-        active_flags.push(document_flags::SCRIPT);
-    }
-    if (*flags & 0x10) != 0 {
-        active_flags.push(document_flags::DRM);
-    }
-    if (*flags & 0x20) != 0 {
-        active_flags.push(document_flags::XML_TEMPLATE);
-    }
-    if (*flags & 0x40) != 0 {
-        active_flags.push(document_flags::HISTORY);
-    }
-    if (*flags & 0x80) != 0 {
-        active_flags.push(document_flags::ELECTRONIC_SIGNATURE);
-    }
-    if (*flags & 0x100) != 0 {
-        active_flags.push(document_flags::CERTIFICATE_ENCRYPTION);
-    }
-    if (*flags & 0x200) != 0 {
-        active_flags.push(document_flags::SIGNATURE_PREVIEW);
-    }
-    if (*flags & 0x400) != 0 {
-        active_flags.push(document_flags::CERTIFICATE_DRM);
-    }
-    if (*flags & 0x800) != 0 {
-        active_flags.push(document_flags::CCL);
-    }
-    if (*flags & 0x1000) != 0 {
-        active_flags.push(document_flags::MOBILE_OPTIMIZED);
-    }
-    if (*flags & 0x2000) != 0 {
-        active_flags.push(document_flags::PRIVACY_SECURITY);
-    }
-    if (*flags & 0x4000) != 0 {
-        active_flags.push(document_flags::TRACK_CHANGE);
-    }
-    if (*flags & 0x8000) != 0 {
-        active_flags.push(document_flags::KOGL);
-    }
-    if (*flags & 0x10000) != 0 {
-        active_flags.push(document_flags::VIDEO_CONTROL);
-    }
-    if (*flags & 0x20000) != 0 {
-        active_flags.push(document_flags::TABLE_OF_CONTENTS);
-    }
+/// Serialize license_flags DWORD as array of flag constants
+pub fn serialize_license_flags<S>(flags: &DWORD, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_flags(*flags, LICENSE_FLAG_BITS, serializer)
+}
+
+fn serialize_flags<S>(
+    flags: DWORD,
+    table: &[(DWORD, &str)],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::SerializeSeq;
+    let active_flags: Vec<&str> = table
+        .iter()
+        .filter(|(bit, _)| (flags & bit) != 0)
+        .map(|(_, name)| *name)
+        .collect();
 
     let mut seq = serializer.serialize_seq(Some(active_flags.len()))?;
     for flag in active_flags {
@@ -91,27 +89,75 @@ where
     seq.end()
 }
 
-/// Serialize license_flags DWORD as array of flag constants
-pub fn serialize_license_flags<S>(flags: &DWORD, serializer: S) -> Result<S::Ok, S::Error>
+/// Deserialize version from "M.n.P.r" string (or raw DWORD)
+/// "M.n.P.r" 문자열(또는 DWORD 숫자)에서 버전 역직렬화
+pub fn deserialize_version<'de, D>(deserializer: D) -> Result<DWORD, D::Error>
 where
-    S: Serializer,
+    D: Deserializer<'de>,
 {
-    use serde::ser::SerializeSeq;
-    let mut active_flags = Vec::new();
-
-    if (*flags & 0x01) != 0 {
-        active_flags.push(license_flags::CCL_KOGL);
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Version {
+        Raw(DWORD),
+        Text(String),
     }
-    if (*flags & 0x02) != 0 {
-        active_flags.push(license_flags::COPY_RESTRICTED);
+
+    match Version::deserialize(deserializer)? {
+        Version::Raw(value) => Ok(value),
+        Version::Text(text) => parse_version(&text)
+            .ok_or_else(|| D::Error::custom(format!("invalid version string: {text}"))),
     }
-    if (*flags & 0x04) != 0 {
-        active_flags.push(license_flags::COPY_ALLOWED_SAME_CONDITION);
+}
+
+/// "M.n.P.r" 문자열을 DWORD로 변환 / Parse "M.n.P.r" string into DWORD
+fn parse_version(text: &str) -> Option<DWORD> {
+    let parts: Vec<&str> = text.split('.').collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    let mut version: DWORD = 0;
+    for part in parts {
+        let value: u8 = part.trim().parse().ok()?;
+        version = (version << 8) | value as DWORD;
+    }
+    Some(version)
+}
+
+/// Deserialize document_flags from array of flag constants (or raw DWORD)
+pub fn deserialize_document_flags<'de, D>(deserializer: D) -> Result<DWORD, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_flags(deserializer, DOCUMENT_FLAG_BITS)
+}
+
+/// Deserialize license_flags from array of flag constants (or raw DWORD)
+pub fn deserialize_license_flags<'de, D>(deserializer: D) -> Result<DWORD, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_flags(deserializer, LICENSE_FLAG_BITS)
+}
+
+fn deserialize_flags<'de, D>(deserializer: D, table: &[(DWORD, &str)]) -> Result<DWORD, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flags {
+        Raw(DWORD),
+        Names(Vec<String>),
     }
 
-    let mut seq = serializer.serialize_seq(Some(active_flags.len()))?;
-    for flag in active_flags {
-        seq.serialize_element(flag)?;
+    match Flags::deserialize(deserializer)? {
+        Flags::Raw(value) => Ok(value),
+        Flags::Names(names) => names.iter().try_fold(0, |acc, name| {
+            table
+                .iter()
+                .find(|(_, flag)| flag == name)
+                .map(|(bit, _)| acc | bit)
+                .ok_or_else(|| D::Error::custom(format!("unknown flag: {name}")))
+        }),
     }
-    seq.end()
 }
@@ -0,0 +1,461 @@
+/// 문서 이미지 추출 모듈 / Document image extraction module
+///
+/// BinData에 들어 있는 그림을 디코딩된 바이트와 MIME 타입, 파일 이름과 함께 돌려줍니다.
+/// Returns pictures stored in BinData with decoded bytes, MIME type and file name.
+use super::HwpDocument;
+use crate::document::bindata::BinaryDataItem;
+use crate::document::bodytext::ctrl_header::CtrlHeaderData;
+use crate::document::{BinDataRecord, ParagraphRecord};
+#[cfg(feature = "fs")]
+use crate::error::HwpError;
+use crate::parser::hwpx::bindata::get_mime_type;
+use crate::types::WORD;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::fs;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+/// 문서에 포함된 이미지 / Image embedded in a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentImage {
+    /// BinData 아이템 인덱스 / BinData item index
+    pub index: WORD,
+    /// 파일 이름 (예: "BIN0001.png", "image1.jpg") / File name (e.g., "BIN0001.png", "image1.jpg")
+    pub name: String,
+    /// MIME 타입 / MIME type
+    pub mime_type: String,
+    /// 이미지 바이트 / Image bytes
+    pub data: Vec<u8>,
+}
+
+/// 그림의 설명문과 캡션 / Description and caption of a picture
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageLabel {
+    /// 개체 설명문 / Object description
+    pub description: Option<String>,
+    /// 캡션 텍스트 / Caption text
+    pub caption: Option<String>,
+}
+
+impl ImageLabel {
+    /// 대체 텍스트 (설명문, 캡션, "이미지" 순으로 쓰고 줄바꿈은 공백으로)
+    /// Alt text (the description, then the caption, then "이미지", with line breaks as spaces)
+    pub fn alt_text(&self) -> String {
+        let text = self
+            .description
+            .as_deref()
+            .or(self.caption.as_deref())
+            .unwrap_or("이미지");
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl HwpDocument {
+    /// 그림의 설명문과 캡션 / Description and caption of a picture
+    ///
+    /// HWP 5.0은 그림을 담은 개체 컨트롤의 설명문과 캡션 문단에서, HWPX는 `<hp:shapeComment>`와
+    /// `<hp:caption>`에서 읽습니다. 같은 BinData를 여러 그림이 쓰면 첫 그림의 것을 돌려줍니다.
+    /// Read from the description and caption paragraphs of the object control holding the
+    /// picture (HWP 5.0), or from `<hp:shapeComment>` and `<hp:caption>` (HWPX). When several
+    /// pictures use the same BinData item, the first picture's label is returned.
+    ///
+    /// # Arguments / 매개변수
+    /// * `bindata_id` - BinData 아이템 인덱스 / BinData item index
+    ///
+    /// # Returns / 반환값
+    /// 그림의 설명문과 캡션 (없으면 빈 값) / Description and caption (empty when there are none)
+    pub fn image_label(&self, bindata_id: WORD) -> ImageLabel {
+        let name = self
+            .bin_data
+            .items
+            .iter()
+            .find(|item| item.index == bindata_id)
+            .and_then(|item| item.name.as_deref());
+        self.body_text
+            .sections
+            .iter()
+            .flat_map(|section| &section.paragraphs)
+            .flat_map(|paragraph| std::iter::once(paragraph).chain(paragraph.nested_paragraphs()))
+            .flat_map(|paragraph| &paragraph.records)
+            .find_map(|record| record_image_label(record, bindata_id, name))
+            .unwrap_or_default()
+    }
+
+    /// 문서에 포함된 이미지 목록 / Images embedded in the document
+    ///
+    /// 이미지가 아닌 바이너리 데이터(OLE 개체 등)와 파일 경로로 저장된 아이템은 제외합니다.
+    /// Non-image binary data (OLE objects, etc.) and items stored as file paths are skipped.
+    ///
+    /// # Returns / 반환값
+    /// BinData 순서의 이미지 목록 / Images in BinData order
+    pub fn images(&self) -> Vec<DocumentImage> {
+        self.bin_data
+            .items
+            .iter()
+            .filter_map(|item| {
+                let data = STANDARD.decode(&item.data).ok()?;
+                let extension = self.bin_data_extension(item.index);
+                let mime_type = sniff_image_mime_type(&data)
+                    .or_else(|| {
+                        extension
+                            .as_deref()
+                            .map(get_mime_type)
+                            .filter(|mime| mime.starts_with("image/"))
+                    })?
+                    .to_string();
+                let extension = extension.unwrap_or_else(|| extension_for_mime(&mime_type).into());
+                Some(DocumentImage {
+                    index: item.index,
+                    name: image_file_name(item, &extension),
+                    mime_type,
+                    data,
+                })
+            })
+            .collect()
+    }
+
+    /// 이미지를 디렉토리에 파일로 저장 / Save images as files in a directory
+    ///
+    /// # Arguments / 매개변수
+    /// * `dir` - 저장할 디렉토리 (없으면 생성) / Output directory (created if missing)
+    ///
+    /// # Returns / 반환값
+    /// 저장한 파일 경로 목록 / Paths of the written files
+    #[cfg(feature = "fs")]
+    pub fn extract_images(&self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, HwpError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|e| {
+            HwpError::Io(format!(
+                "Failed to create directory '{}': {e}",
+                dir.display()
+            ))
+        })?;
+
+        self.images()
+            .into_iter()
+            .map(|image| {
+                let path = dir.join(&image.name);
+                fs::write(&path, &image.data).map_err(|e| {
+                    HwpError::Io(format!("Failed to write file '{}': {e}", path.display()))
+                })?;
+                Ok(path)
+            })
+            .collect()
+    }
+
+    /// 내용이 같은 BinData 아이템 묶음 / Groups of BinData items with identical content
+    ///
+    /// 내용 해시로 나눈 뒤 실제 내용을 비교하므로 해시 충돌로 다른 내용이 묶이지 않습니다.
+    /// 아이템이 하나뿐인 묶음은 포함하지 않습니다.
+    /// Items are bucketed by content hash and then compared, so a hash collision never groups
+    /// different content. Groups with a single item are not included.
+    ///
+    /// # Returns / 반환값
+    /// BinData 순서의 인덱스 묶음 (첫 인덱스가 대표) / Index groups in BinData order (the first index is canonical)
+    pub fn duplicate_bin_data(&self) -> Vec<Vec<WORD>> {
+        let mut groups: Vec<Vec<&BinaryDataItem>> = Vec::new();
+        let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+        for item in &self.bin_data.items {
+            let mut hasher = DefaultHasher::new();
+            item.data.hash(&mut hasher);
+            let bucket = buckets.entry(hasher.finish()).or_default();
+            match bucket
+                .iter()
+                .find(|&&group| groups[group][0].data == item.data)
+            {
+                Some(&group) => groups[group].push(item),
+                None => {
+                    bucket.push(groups.len());
+                    groups.push(vec![item]);
+                }
+            }
+        }
+        groups
+            .into_iter()
+            .filter(|group| group.len() > 1)
+            .map(|group| group.iter().map(|item| item.index).collect())
+            .collect()
+    }
+
+    /// 내용이 같은 첫 BinData 아이템의 인덱스 / Index of the first BinData item with the same content
+    ///
+    /// 뷰어는 이 인덱스로 파일 이름을 정해 같은 그림을 한 번만 저장합니다.
+    /// The viewers name files after this index so that the same picture is saved only once.
+    ///
+    /// # Returns / 반환값
+    /// 대표 인덱스 (아이템이 없으면 `index` 그대로) / Canonical index (`index` itself when there is no such item)
+    pub fn canonical_bin_data_index(&self, index: WORD) -> WORD {
+        let items = &self.bin_data.items;
+        items
+            .iter()
+            .find(|item| item.index == index)
+            .and_then(|item| items.iter().find(|other| other.data == item.data))
+            .map_or(index, |item| item.index)
+    }
+
+    /// 이름순으로 정렬한 BinData 안의 순번 (1부터) / Position in the BinData sorted by name (from 1)
+    ///
+    /// HWPX의 아이템 인덱스는 ZIP 항목 순서를 따르므로, 같은 문서를 다른 순서로 묶으면 달라집니다.
+    /// 이 순번은 (이름, 인덱스) 순서라 묶는 순서와 관계없이 같습니다. HWP 5.0처럼 이름이 없으면
+    /// 인덱스 순서와 같습니다. 내용이 같은 아이템은 그중 가장 앞선 순번을 함께 씁니다.
+    /// HWPX item indices follow the ZIP entry order, so the same document packed in another order
+    /// gets different ones. This position follows (name, index) order and stays the same however
+    /// the package is ordered. Without names, as in HWP 5.0, it follows the index order. Items
+    /// with identical content share the earliest of their positions.
+    ///
+    /// # Returns / 반환값
+    /// 순번 (아이템이 없으면 `index` 그대로) / Position (`index` itself when there is no such item)
+    pub fn stable_bin_data_index(&self, index: WORD) -> WORD {
+        let Some(target) = self.bin_data.items.iter().find(|item| item.index == index) else {
+            return index;
+        };
+        let mut items: Vec<&BinaryDataItem> = self.bin_data.items.iter().collect();
+        items.sort_by(|a, b| (&a.name, a.index).cmp(&(&b.name, b.index)));
+        items
+            .iter()
+            .position(|item| item.data == target.data)
+            .map_or(index, |position| (position + 1) as WORD)
+    }
+
+    /// DocInfo의 BinData 레코드에서 확장자 찾기 (HWP5) / Find extension from DocInfo BinData records (HWP5)
+    fn bin_data_extension(&self, bindata_id: WORD) -> Option<String> {
+        self.doc_info
+            .bin_data
+            .iter()
+            .find_map(|record| match record {
+                BinDataRecord::Embedding { embedding, .. }
+                    if embedding.binary_data_id == bindata_id
+                        && !embedding.extension.is_empty() =>
+                {
+                    Some(embedding.extension.to_lowercase())
+                }
+                _ => None,
+            })
+    }
+}
+
+/// 레코드가 그림을 담고 있으면 그 설명문과 캡션 / Description and caption when the record holds the picture
+fn record_image_label(
+    record: &ParagraphRecord,
+    bindata_id: WORD,
+    name: Option<&str>,
+) -> Option<ImageLabel> {
+    match record {
+        ParagraphRecord::HwpxImage {
+            binary_item_ref,
+            description,
+            caption,
+            ..
+        } if name == Some(binary_item_ref.as_str()) => Some(ImageLabel {
+            description: description.clone(),
+            caption: caption.clone(),
+        }),
+        ParagraphRecord::CtrlHeader {
+            header,
+            children,
+            paragraphs,
+            ..
+        } if contains_picture(children, bindata_id) => {
+            let CtrlHeaderData::ObjectCommon {
+                description,
+                caption,
+                ..
+            } = &header.data
+            else {
+                return Some(ImageLabel::default());
+            };
+            // 캡션이 있는 개체만 문단이 캡션 / Paragraphs are the caption only for objects with one
+            let caption_text = caption.as_ref().map(|_| {
+                paragraphs
+                    .iter()
+                    .map(|paragraph| paragraph.text())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
+            Some(ImageLabel {
+                description: description
+                    .as_deref()
+                    .filter(|text| !is_generated_description(text))
+                    .and_then(trimmed),
+                caption: caption_text.as_deref().and_then(trimmed),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// 자식 레코드(도형 안 포함)에 그림이 있는지 확인 / Whether the child records (including shapes) hold the picture
+fn contains_picture(records: &[ParagraphRecord], bindata_id: WORD) -> bool {
+    records.iter().any(|record| match record {
+        ParagraphRecord::ShapeComponentPicture {
+            shape_component_picture,
+        } => shape_component_picture.picture_info.bindata_id == bindata_id,
+        ParagraphRecord::ShapeComponent { children, .. } => contains_picture(children, bindata_id),
+        _ => false,
+    })
+}
+
+/// 한/글이 그림을 넣을 때 자동으로 채우는 설명문인지 확인
+/// Whether the description is the one Hangul fills in automatically when a picture is inserted
+///
+/// "그림입니다.\n원본 그림의 이름: ...\n원본 그림의 크기: ..." 형식이며 대체 텍스트로 쓸 만한 내용이 없습니다.
+/// It has the form "그림입니다.\n원본 그림의 이름: ...\n원본 그림의 크기: ..." and says nothing useful as alt text.
+fn is_generated_description(text: &str) -> bool {
+    text.trim_start().starts_with("그림입니다.")
+}
+
+/// 앞뒤 공백을 뺀 문자열, 비어 있으면 None / Trimmed string, or None when empty
+fn trimmed(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// 같은 내용이 이미 있지 않을 때만 파일 쓰기 / Write a file unless it already holds the same bytes
+///
+/// 같은 그림을 여러 번 참조하는 문서를 변환할 때 파일을 다시 쓰지 않습니다.
+/// Avoids rewriting the file when a document references the same picture several times.
+#[cfg(feature = "fs")]
+pub(crate) fn write_if_changed(path: &Path, data: &[u8]) -> Result<(), HwpError> {
+    let unchanged = fs::metadata(path).is_ok_and(|meta| meta.len() == data.len() as u64)
+        && fs::read(path).is_ok_and(|existing| existing == data);
+    if unchanged {
+        return Ok(());
+    }
+    fs::write(path, data)
+        .map_err(|e| HwpError::Io(format!("Failed to write file '{}': {e}", path.display())))
+}
+
+/// 파일 이름 결정 (HWPX는 아이템 이름, HWP5는 뷰어와 같은 BINxxxx 형식)
+/// Choose a file name (item name for HWPX, the viewers' BINxxxx format for HWP5)
+fn image_file_name(item: &BinaryDataItem, extension: &str) -> String {
+    match &item.name {
+        Some(name) => format!("{name}.{extension}"),
+        None => format!("BIN{:04X}.{extension}", item.index),
+    }
+}
+
+/// 매직 바이트로 이미지 MIME 타입 감지 / Detect image MIME type from magic bytes
+pub(crate) fn sniff_image_mime_type(data: &[u8]) -> Option<&'static str> {
+    let mime = if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "image/png"
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if data.starts_with(b"GIF8") {
+        "image/gif"
+    } else if data.starts_with(b"BM") {
+        "image/bmp"
+    } else if data.starts_with(b"II*\0") || data.starts_with(b"MM\0*") {
+        "image/tiff"
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        "image/webp"
+    } else if data.starts_with(&[0xD7, 0xCD, 0xC6, 0x9A]) {
+        "image/wmf"
+    } else if data.len() >= 44 && &data[40..44] == b" EMF" {
+        "image/emf"
+    } else {
+        return None;
+    };
+    Some(mime)
+}
+
+/// 매직 바이트로 MIME 타입 감지 (그림이 아닌 내용 포함) / Detect MIME type from magic bytes, including non-images
+///
+/// HWP 문서의 BinData에는 그림 외에도 OLE 개체(CFB), PDF, ZIP 등이 들어갈 수 있습니다.
+/// Besides pictures, BinData may hold OLE objects (CFB), PDFs, ZIP archives and more.
+pub(crate) fn sniff_mime_type(data: &[u8]) -> &'static str {
+    if let Some(mime) = sniff_image_mime_type(data) {
+        mime
+    } else if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+        "application/x-ole-storage"
+    } else if data.starts_with(b"%PDF") {
+        "application/pdf"
+    } else if data.starts_with(b"PK\x03\x04") {
+        "application/zip"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+/// base64 데이터 앞부분만 디코딩하여 MIME 타입 감지 / Detect MIME type by decoding only the start of base64 data
+pub(crate) fn sniff_base64_mime_type(base64_data: &str) -> &'static str {
+    // 64자 = 48바이트, EMF 서명(40..44)까지 충분 / 64 chars = 48 bytes, enough for the EMF signature (40..44)
+    let prefix = base64_data.get(..64).unwrap_or(base64_data);
+    let prefix = &prefix[..prefix.len() / 4 * 4];
+    STANDARD
+        .decode(prefix)
+        .map(|data| sniff_mime_type(&data))
+        .unwrap_or("application/octet-stream")
+}
+
+/// MIME 타입에 맞는 확장자 / Extension for a MIME type
+pub(crate) fn extension_for_mime(mime_type: &str) -> &'static str {
+    match mime_type {
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/bmp" => "bmp",
+        "image/tiff" => "tif",
+        "image/webp" => "webp",
+        "image/wmf" => "wmf",
+        "image/emf" => "emf",
+        "image/svg+xml" => "svg",
+        "application/x-ole-storage" => "ole",
+        "application/pdf" => "pdf",
+        "application/zip" => "zip",
+        mime if mime.starts_with("image/") => "jpg",
+        _ => "bin",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::empty_document;
+
+    #[test]
+    fn test_images_detect_type_and_skip_non_images() {
+        let mut document = empty_document();
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        document.bin_data.items = vec![
+            BinaryDataItem {
+                index: 0,
+                data: STANDARD.encode(png),
+                name: Some("image1".to_string()),
+            },
+            BinaryDataItem {
+                index: 1,
+                data: STANDARD.encode(b"not an image"),
+                name: Some("ole1".to_string()),
+            },
+            BinaryDataItem {
+                index: 2,
+                data: "/tmp/BIN0002.png".to_string(),
+                name: None,
+            },
+        ];
+
+        let images = document.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].name, "image1.png");
+        assert_eq!(images[0].mime_type, "image/png");
+        assert_eq!(images[0].data, png);
+    }
+
+    #[test]
+    fn test_sniff_non_image_payloads() {
+        let ole = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1, 0, 0];
+        assert_eq!(sniff_mime_type(&ole), "application/x-ole-storage");
+        assert_eq!(sniff_mime_type(b"%PDF-1.7"), "application/pdf");
+        assert_eq!(sniff_mime_type(b"text"), "application/octet-stream");
+        assert_eq!(
+            sniff_base64_mime_type(&STANDARD.encode(ole)),
+            "application/x-ole-storage"
+        );
+        assert_eq!(sniff_base64_mime_type("iVBORw0KGgo="), "image/png");
+        assert_eq!(extension_for_mime("application/x-ole-storage"), "ole");
+        assert_eq!(extension_for_mime("image/jpeg"), "jpg");
+    }
+}
@@ -0,0 +1,207 @@
+/// 문서 JSON 스키마 / Document JSON schema
+///
+/// `HwpDocument::to_json`이 내보내는 JSON은 최상위 `schema_version` 필드로 배치를 구분합니다.
+/// 필드 이름과 열거형 값은 모두 snake_case이며, 필드 순서는 구조체 선언 순서를 따르므로
+/// 같은 문서는 항상 같은 JSON이 됩니다.
+///
+/// The JSON written by `HwpDocument::to_json` identifies its layout with a top-level
+/// `schema_version` field. Field names and enum values are all snake_case, and fields follow
+/// struct declaration order, so the same document always produces the same JSON.
+///
+/// | 버전 / Version | 변경 / Changes |
+/// |---|---|
+/// | 1 | `schema_version` 없음, 일부 열거형이 소문자 붙여쓰기(`bycharacter`)나 대문자(`EMBEDDING`) / No `schema_version`, some enums lowercase-joined (`bycharacter`) or uppercase (`EMBEDDING`) |
+/// | 2 | `schema_version` 추가, 모든 열거형 값 snake_case / Adds `schema_version`, all enum values snake_case |
+/// | 3 | 문단 모양의 `outdent` 제거(레코드에 없는 필드를 읽느라 뒤 필드가 4바이트씩 밀렸음, 내어쓰기는 음수 `indent`), 문단 모양 `paragraph_level`과 문단 번호 `number_shape` 추가 / Drops the paragraph shape `outdent` (reading a field the record does not have shifted the following fields by 4 bytes; outdents are a negative `indent`), adds paragraph shape `paragraph_level` and numbering `number_shape` |
+///
+/// `from_json`은 모든 버전을 읽습니다. 이전 배치(스키마 1)로 내보내면 `outdent`를 음수 `indent`에서
+/// 다시 만듭니다.
+/// `from_json` reads every version. Exporting the previous layout (schema 1) rebuilds `outdent`
+/// from a negative `indent`.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::HwpDocument;
+use crate::error::HwpError;
+
+/// 현재 JSON 스키마 버전 / Current JSON schema version
+pub const JSON_SCHEMA_VERSION: u32 = 3;
+
+/// JSON 출력 옵션 / JSON output options
+#[derive(Debug, Clone, Default)]
+pub struct JsonOptions {
+    /// 이전 배치(스키마 1)로 출력 / Emit the previous layout (schema 1)
+    pub legacy_layout: bool,
+}
+
+impl JsonOptions {
+    /// 이전 배치 출력 여부 설정 / Set whether the previous layout (schema 1) is emitted
+    pub fn with_legacy_layout(mut self, legacy_layout: bool) -> Self {
+        self.legacy_layout = legacy_layout;
+        self
+    }
+}
+
+/// 스키마 버전을 앞에 붙인 문서 / Document prefixed with its schema version
+#[derive(Serialize)]
+struct VersionedDocument<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    document: &'a HwpDocument,
+}
+
+/// 스키마 버전만 읽기 위한 구조체 / Reads only the schema version
+#[derive(Deserialize)]
+struct SchemaProbe {
+    schema_version: Option<u32>,
+}
+
+/// 문서를 JSON으로 직렬화 / Serialize a document as JSON
+pub(crate) fn to_json(document: &HwpDocument, options: &JsonOptions) -> Result<String, HwpError> {
+    if !options.legacy_layout {
+        let versioned = VersionedDocument {
+            schema_version: JSON_SCHEMA_VERSION,
+            document,
+        };
+        return serde_json::to_string_pretty(&versioned).map_err(HwpError::from);
+    }
+
+    let mut value = serde_json::to_value(document)?;
+    downgrade_to_v1(&mut value);
+    restore_outdent(&mut value);
+    serde_json::to_string_pretty(&value).map_err(HwpError::from)
+}
+
+/// JSON에서 문서 복원 / Restore a document from JSON
+pub(crate) fn from_json(json: &str) -> Result<HwpDocument, HwpError> {
+    let probe: SchemaProbe = serde_json::from_str(json)?;
+    if let Some(version) = probe.schema_version {
+        if version > JSON_SCHEMA_VERSION {
+            return Err(HwpError::UnsupportedVersion {
+                version: format!("JSON schema {version}"),
+                supported_versions: format!("JSON schema 1-{JSON_SCHEMA_VERSION}"),
+            });
+        }
+    }
+    serde_json::from_str(json).map_err(HwpError::from)
+}
+
+/// 스키마 2 값을 스키마 1 배치로 되돌리기 / Rewrite schema 2 values into the schema 1 layout
+fn downgrade_to_v1(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match child {
+                    Value::String(text) => {
+                        if let Some(legacy) = legacy_enum_value(key, text) {
+                            *text = legacy;
+                        }
+                    }
+                    _ => downgrade_to_v1(child),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(downgrade_to_v1),
+        _ => {}
+    }
+}
+
+/// 스키마 1·2의 문단 모양 `outdent`를 `indent` 바로 뒤에 되살리기 (내어쓰기 양, 없으면 0)
+/// Bring back the schema 1/2 paragraph shape `outdent` right after `indent` (the outdent amount, 0 when none)
+fn restore_outdent(value: &mut Value) {
+    let Some(Value::Array(para_shapes)) = value.pointer_mut("/doc_info/para_shapes") else {
+        return;
+    };
+    for para_shape in para_shapes {
+        let Value::Object(fields) = para_shape else {
+            continue;
+        };
+        let outdent = fields
+            .get("indent")
+            .and_then(Value::as_i64)
+            .map_or(0, |indent| (-indent).max(0));
+        *fields = std::mem::take(fields)
+            .into_iter()
+            .flat_map(|(key, field)| {
+                let outdent = (key == "indent").then(|| ("outdent".to_string(), outdent.into()));
+                std::iter::once((key, field)).chain(outdent)
+            })
+            .collect();
+    }
+}
+
+/// 스키마 1에서 다르게 쓰던 열거형 값 / Enum value spelled differently in schema 1
+fn legacy_enum_value(key: &str, value: &str) -> Option<String> {
+    match key {
+        "line_spacing_type" | "line_spacing_type_old" => match value {
+            "by_character" => Some("bycharacter".to_string()),
+            "margin_only" => Some("marginonly".to_string()),
+            _ => None,
+        },
+        "storage_type" | "alternative_font_type" => Some(value.to_uppercase()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::empty_document;
+
+    #[test]
+    fn test_schema_version_comes_first() {
+        let json = to_json(&empty_document(), &JsonOptions::default()).unwrap();
+        assert!(json.starts_with("{\n  \"schema_version\": 3,\n  \"file_header\""));
+    }
+
+    #[test]
+    fn test_legacy_layout() {
+        let json = to_json(
+            &empty_document(),
+            &JsonOptions::default().with_legacy_layout(true),
+        )
+        .unwrap();
+        assert!(json.starts_with("{\n  \"file_header\""));
+        assert!(!json.contains("schema_version"));
+
+        let mut value = serde_json::json!({
+            "para_shape": { "line_spacing_type": "by_character", "align": "left" },
+            "records": [{ "storage_type": "embedding" }],
+        });
+        downgrade_to_v1(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "para_shape": { "line_spacing_type": "bycharacter", "align": "left" },
+                "records": [{ "storage_type": "EMBEDDING" }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_legacy_layout_restores_outdent() {
+        let mut value = serde_json::json!({
+            "doc_info": { "para_shapes": [
+                { "left_margin": 0, "indent": -2000, "top_spacing": 0 },
+                { "left_margin": 0, "indent": 1000, "top_spacing": 0 },
+            ] },
+        });
+        restore_outdent(&mut value);
+        let para_shapes = &value["doc_info"]["para_shapes"];
+        assert_eq!(para_shapes[0]["outdent"], 2000);
+        assert_eq!(para_shapes[1]["outdent"], 0);
+        let keys: Vec<&String> = para_shapes[0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["left_margin", "indent", "outdent", "top_spacing"]);
+    }
+
+    #[test]
+    fn test_from_json_rejects_newer_schema() {
+        let json = to_json(&empty_document(), &JsonOptions::default()).unwrap();
+        assert!(from_json(&json).is_ok());
+        let newer = json.replace("\"schema_version\": 3", "\"schema_version\": 99");
+        assert!(matches!(
+            from_json(&newer),
+            Err(HwpError::UnsupportedVersion { .. })
+        ));
+    }
+}
@@ -0,0 +1,269 @@
+/// 문단 머리 표시 문자열 모듈 / Paragraph head label module
+///
+/// 개요·문단 번호·글머리표 문단의 머리(예: "1.", "가)", "●")를 DocInfo의 문단 번호/글머리표
+/// 정의로 만듭니다. 번호는 앞선 문단에 따라 정해지므로, 계수기를 가진 `ListLabels`에 문단을
+/// 문서 순서대로 넘깁니다.
+///
+/// Builds the heads of outline, numbered and bulleted paragraphs ("1.", "가)", "●", ...) from
+/// the numbering and bullet definitions in DocInfo. Numbers depend on the paragraphs before, so
+/// paragraphs are passed in document order to `ListLabels`, which keeps the counters.
+use std::collections::HashMap;
+
+use super::bodytext::{CtrlHeaderData, NumberShape, Paragraph, ParagraphRecord};
+use super::docinfo::{Bullet, Numbering, ParaShape};
+use super::{HeaderShapeType, HwpDocument};
+
+/// 수준 수 (개요 1~10) / Number of levels (outline 1-10)
+const LEVELS: usize = 10;
+
+/// 문단 머리 계산기 / Paragraph head label resolver
+#[derive(Debug)]
+pub struct ListLabels<'a> {
+    document: &'a HwpDocument,
+    /// 현재 구역의 개요 문단 번호 ID / Outline numbering ID of the current section
+    outline_id: u16,
+    /// (개요 여부, 문단 번호 ID)별로 수준마다 마지막에 쓴 번호 (0은 아직 쓰지 않음)
+    /// Last number used on each level per (outline or not, numbering ID); 0 when not used yet
+    counters: HashMap<(bool, u16), [u32; LEVELS]>,
+}
+
+impl HwpDocument {
+    /// 문단 머리 계산기 만들기 / Create a paragraph head label resolver
+    pub fn list_labels(&self) -> ListLabels<'_> {
+        ListLabels {
+            document: self,
+            outline_id: 1,
+            counters: HashMap::new(),
+        }
+    }
+}
+
+impl ListLabels<'_> {
+    /// 문단 머리 표시 문자열 (머리가 없는 문단은 빈 문자열)
+    /// Head label of a paragraph (empty for paragraphs without one)
+    ///
+    /// 번호 문단은 계수기를 올리므로 문단마다 문서 순서로 한 번씩 부릅니다. 같은 문단 번호를 쓰는
+    /// 문단은 번호가 이어지고, 상위 수준 번호가 바뀌면 하위 수준은 다시 시작합니다.
+    /// Numbered paragraphs advance the counters, so call this once per paragraph in document
+    /// order. Paragraphs sharing a numbering continue its numbers, and lower levels restart
+    /// when a higher level moves on.
+    pub fn resolve_list_label(&mut self, paragraph: &Paragraph) -> String {
+        // 구역 정의가 개요 번호를 정함 (구역 첫 문단에 있음)
+        // The section definition picks the outline numbering (it sits in the section's first paragraph)
+        if let Some(id) = section_outline_id(paragraph) {
+            self.outline_id = id;
+        }
+        let doc_info = &self.document.doc_info;
+        let Some(shape) = doc_info
+            .para_shapes
+            .get(paragraph.para_header.para_shape_id as usize)
+        else {
+            return String::new();
+        };
+        let outline = match shape.attributes1.header_shape_type {
+            HeaderShapeType::None => return String::new(),
+            HeaderShapeType::Bullet => {
+                return by_id(&doc_info.bullets, shape.number_bullet_id)
+                    .map(bullet_label)
+                    .unwrap_or_default();
+            }
+            HeaderShapeType::Outline => true,
+            HeaderShapeType::Number => false,
+        };
+        // 개요 문단은 보통 ID 없이 구역의 개요 번호를 씀
+        // Outline paragraphs usually carry no ID and use the section's outline numbering
+        let id = match shape.number_bullet_id {
+            0 if outline => self.outline_id,
+            id => id,
+        };
+        let Some(numbering) = by_id(&doc_info.numbering, id) else {
+            return String::new();
+        };
+
+        let level = paragraph_level(shape);
+        let counters = self.counters.entry((outline, id)).or_insert([0; LEVELS]);
+        counters[level] = match counters[level] {
+            0 => start_number(numbering, level),
+            last => last + 1,
+        };
+        for deeper in &mut counters[level + 1..] {
+            *deeper = 0;
+        }
+        format_label(numbering, level, counters)
+    }
+}
+
+/// 문단에 있는 구역 정의의 개요 문단 번호 ID / Outline numbering ID of a section definition in the paragraph
+fn section_outline_id(paragraph: &Paragraph) -> Option<u16> {
+    paragraph.records.iter().find_map(|record| match record {
+        ParagraphRecord::CtrlHeader { header, .. } => match header.data {
+            CtrlHeaderData::SectionDefinition {
+                number_para_shape_id,
+                ..
+            } if number_para_shape_id != 0 => Some(number_para_shape_id),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// 1부터 센 ID로 찾기 (0은 없음) / Look up by an ID counted from 1 (0 is none)
+fn by_id<T>(items: &[T], id: u16) -> Option<&T> {
+    items.get(usize::from(id).checked_sub(1)?)
+}
+
+/// 0부터 센 문단 수준 / Paragraph level counted from 0
+fn paragraph_level(shape: &ParaShape) -> usize {
+    shape
+        .paragraph_level
+        .map_or(usize::from(shape.attributes1.paragraph_level), |level| {
+            level as usize
+        })
+        .min(LEVELS - 1)
+}
+
+/// 수준의 시작 번호 / Start number of a level
+fn start_number(numbering: &Numbering, level: usize) -> u32 {
+    numbering
+        .levels
+        .get(level)
+        .and_then(|info| info.level_start_number)
+        .unwrap_or(1)
+}
+
+/// 수준의 번호 형식과 번호 모양 (확장 수준 8~10은 아라비아 숫자)
+/// Number format and number shape of a level (Arabic digits for extended levels 8-10)
+fn level_format(numbering: &Numbering, level: usize) -> Option<(&str, NumberShape)> {
+    match numbering.levels.get(level) {
+        Some(info) => Some((&info.format_string, info.attributes.number_shape)),
+        None => numbering
+            .extended_levels
+            .get(level.checked_sub(numbering.levels.len())?)
+            .map(|extended| (extended.format_string.as_str(), NumberShape::Arabic)),
+    }
+}
+
+/// 번호 형식의 `^n` 자리에 n 수준 번호를 넣기 (형식이 없거나 null 문자뿐이면 빈 문자열)
+/// Put the level-n number into each `^n` of the number format (empty when the format is missing
+/// or only null characters)
+fn format_label(numbering: &Numbering, level: usize, counters: &[u32; LEVELS]) -> String {
+    let Some((format, _)) = level_format(numbering, level) else {
+        return String::new();
+    };
+    let format = format.trim_matches('\0');
+    let mut label = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        let Some(digit) = chars
+            .peek()
+            .and_then(|d| d.to_digit(10))
+            .filter(|_| c == '^')
+        else {
+            label.push(c);
+            continue;
+        };
+        chars.next();
+        // "^10"은 10 수준 / "^10" is level 10
+        let mut referenced = digit as usize;
+        if referenced == 1 && chars.peek() == Some(&'0') {
+            chars.next();
+            referenced = 10;
+        }
+        let Some(index) = referenced.checked_sub(1).filter(|index| *index < LEVELS) else {
+            continue;
+        };
+        let number = match counters[index] {
+            0 => start_number(numbering, index),
+            number => number,
+        };
+        let shape = level_format(numbering, index).map_or(NumberShape::Arabic, |(_, shape)| shape);
+        label.push_str(&shape.format(number));
+    }
+    label
+}
+
+/// 글머리표 문자 (기호 글꼴의 사용자 정의 영역 문자는 비슷한 유니코드 문자로)
+/// Bullet character (private-use characters of symbol fonts become a similar Unicode character)
+fn bullet_label(bullet: &Bullet) -> String {
+    let Some(c) = char::from_u32(u32::from(bullet.bullet_char)).filter(|c| *c != '\0') else {
+        return String::new();
+    };
+    // Wingdings 등 기호 글꼴은 U+F000~U+F0FF에 매핑됨 / Symbol fonts such as Wingdings map to U+F000-U+F0FF
+    let c = match u32::from(c) {
+        0xF06C => '●',
+        0xF06E => '■',
+        0xF06F => '□',
+        0xF075 => '◆',
+        0xF076 => '❖',
+        0xF09F => '•',
+        0xF0A1 => '○',
+        0xF0A7 => '▪',
+        0xF0D8 => '➢',
+        0xF0FC => '✓',
+        0xF000..=0xF0FF => '•',
+        _ => c,
+    };
+    c.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::docinfo::numbering::{
+        DistanceType, NumberingHeaderAttributes, NumberingLevelInfo, ParagraphAlignType,
+    };
+
+    fn numbering(formats: &[(&str, NumberShape)]) -> Numbering {
+        Numbering {
+            levels: formats
+                .iter()
+                .map(|(format, shape)| NumberingLevelInfo {
+                    attributes: NumberingHeaderAttributes {
+                        align_type: ParagraphAlignType::Left,
+                        instance_like: false,
+                        auto_outdent: true,
+                        distance_type: DistanceType::Ratio,
+                        number_shape: *shape,
+                    },
+                    width: 0,
+                    distance: 50,
+                    char_shape_id: u32::MAX,
+                    format_length: format.len() as u16,
+                    format_string: format.to_string(),
+                    start_number: 0,
+                    level_start_number: Some(1),
+                })
+                .collect(),
+            extended_levels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_format_label() {
+        let numbering = numbering(&[
+            ("^1.", NumberShape::Arabic),
+            ("^2)", NumberShape::Hangul),
+            ("^1.^2.^3", NumberShape::CircledArabic),
+        ]);
+        let mut counters = [0; LEVELS];
+        counters[0] = 3;
+        counters[1] = 2;
+        assert_eq!(format_label(&numbering, 0, &counters), "3.");
+        assert_eq!(format_label(&numbering, 1, &counters), "나)");
+        // 쓰지 않은 수준은 시작 번호 / Unused levels show their start number
+        assert_eq!(format_label(&numbering, 2, &counters), "3.나.①");
+        // 정의가 없는 수준은 빈 문자열 / Levels without a definition are empty
+        assert_eq!(format_label(&numbering, 5, &counters), "");
+    }
+
+    #[test]
+    fn test_bullet_label() {
+        let mut bullet = crate::document::Bullet::parse(&[0; 14]).unwrap();
+        bullet.bullet_char = 0xF06C;
+        assert_eq!(bullet_label(&bullet), "●");
+        bullet.bullet_char = u16::from(b'-');
+        assert_eq!(bullet_label(&bullet), "-");
+        bullet.bullet_char = 0;
+        assert_eq!(bullet_label(&bullet), "");
+    }
+}
@@ -1,17 +1,41 @@
 pub mod bindata;
 pub mod bodytext;
+pub mod citations;
+pub mod compact;
 pub mod constants;
+pub mod content_filter;
+#[cfg(feature = "chrono")]
+pub mod dates;
 pub mod docinfo;
+pub mod external;
+pub mod fields;
 /// HWP Document structure
 ///
 /// This module defines the main document structure for HWP files.
 ///
 /// 스펙 문서 매핑: 표 2 - 전체 구조
 pub mod fileheader;
+pub mod images;
+pub mod json;
+pub mod list_labels;
+pub(crate) mod notes;
 pub mod preview_image;
 pub mod preview_text;
+pub mod raw_parts;
+pub mod replace;
+pub mod resolved_style;
+pub mod script;
 pub mod scripts;
+pub mod search;
+pub mod security;
+pub mod stats;
+pub mod style_catalog;
 pub mod summary_information;
+pub mod tables;
+pub mod text;
+#[cfg(feature = "image-processing")]
+pub mod thumbnail;
+pub mod tokens;
 pub mod xml_template;
 
 pub use bindata::{BinData, BinaryDataFormat};
@@ -19,19 +43,64 @@ pub use bodytext::{
     BodyText, ColumnDivideType, CtrlHeader, CtrlHeaderData, CtrlId, PageNumberPosition, Paragraph,
     ParagraphRecord, Section,
 };
+pub use citations::{Citation, CitationKind};
+pub use compact::CompactReport;
+pub use content_filter::{ContentFilter, FilteredContent, FilteredKind};
 pub use docinfo::{
     BinDataRecord, BorderFill, Bullet, CharShape, DocInfo, DocumentProperties, FaceName, FillInfo,
     HeaderShapeType, IdMappings, Numbering, ParaShape, Style, TabDef,
 };
+pub use external::{ExternalReference, ExternalReferenceKind};
+pub use fields::{Field, FieldContext, FieldKind};
 pub use fileheader::FileHeader;
+pub use images::{DocumentImage, ImageLabel};
+pub use json::{JsonOptions, JSON_SCHEMA_VERSION};
+pub use list_labels::ListLabels;
 pub use preview_image::PreviewImage;
 pub use preview_text::PreviewText;
+pub use resolved_style::ResolvedStyle;
+pub use script::{segment_scripts, LanguageProfile, Script, ScriptSegment};
 pub use scripts::Scripts;
+pub use search::{SearchHit, SearchOptions};
+pub use security::{SecurityFinding, SecurityFindingKind, SecurityInfo};
+pub use stats::DocumentStats;
+pub use style_catalog::StyleEntry;
 pub use summary_information::SummaryInformation;
+pub use tables::{DocumentTable, MergedCell};
+pub use text::{ParagraphLocation, TextOffsetMap, TextOptions, TextPosition, TextSpan, TrimMode};
+pub use tokens::TextToken;
 pub use xml_template::XmlTemplate;
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::parser::{ParseProfile, ParseWarning, ResourceLimits};
+
+/// 원본 파일 형식 / Source file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceFormat {
+    /// HWP 5.0 (CFB 바이너리) / HWP 5.0 (CFB binary)
+    #[default]
+    Hwp5,
+    /// HWPX (ZIP + XML)
+    Hwpx,
+    /// HWPML (단일 XML 파일) / HWPML (single XML file)
+    Hwpml,
+    /// HWP 3.0 (구형 바이너리) / HWP 3.0 (legacy binary)
+    Hwp3,
+}
+
+/// 문서 형식 정보 / Document format information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormatInfo {
+    /// 원본 파일 형식 / Source file format
+    pub format: SourceFormat,
+    /// 버전 문자열 (예: "5.1.0.1") / Version string (e.g., "5.1.0.1")
+    pub version: String,
+}
+
 /// Main HWP document structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HwpDocument {
@@ -53,6 +122,30 @@ pub struct HwpDocument {
     pub xml_template: Option<XmlTemplate>,
     /// Summary Information (\005HwpSummaryInformation stream)
     pub summary_information: Option<SummaryInformation>,
+    /// 원본 파일 형식 / Source file format
+    #[serde(default)]
+    pub source_format: SourceFormat,
+    /// 서식 파일(.hwt, .hwtx)에서 읽은 문서인지 여부 / Whether the document was read from a template (.hwt, .hwtx)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_template: bool,
+    /// 파싱 중 복구되거나 무시된 문제 / Problems repaired or ignored during parsing
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ParseWarning>,
+    /// 파싱할 때 찾은 서명/DRM 스트림과 외부 연결 (`security_info`로 조회)
+    /// Signature and DRM streams and external links found while parsing (read via `security_info`)
+    #[serde(default, skip_serializing_if = "SecurityInfo::is_empty")]
+    pub security: SecurityInfo,
+    /// 단계별 파싱 시간 (`ParseOptions::profiling`을 켰을 때) / Per-phase parse timing (when `ParseOptions::profiling` is enabled)
+    #[serde(skip)]
+    pub profile: Option<ParseProfile>,
+    /// 모델에 없는 HWPX 파트의 원본 바이트 (`raw_parts`, `read_part`로 조회)
+    /// Raw bytes of HWPX parts the model does not cover (read via `raw_parts` and `read_part`)
+    #[serde(skip)]
+    pub unparsed_parts: BTreeMap<String, Vec<u8>>,
+    /// 파싱에 쓴 자원 한도 (`open_bin_data`가 원본을 다시 열 때 씀)
+    /// Resource limits the document was parsed with (used when `open_bin_data` reopens the source)
+    #[serde(skip)]
+    pub resource_limits: ResourceLimits,
 }
 
 impl HwpDocument {
@@ -68,9 +161,72 @@ impl HwpDocument {
             scripts: None,
             xml_template: None,
             summary_information: None,
+            source_format: SourceFormat::default(),
+            is_template: false,
+            warnings: Vec::new(),
+            security: SecurityInfo::default(),
+            profile: None,
+            unparsed_parts: BTreeMap::new(),
+            resource_limits: ResourceLimits::default(),
+        }
+    }
+
+    /// 원본 형식과 버전 문자열 / Source format and version string
+    ///
+    /// # Returns / 반환값
+    /// 형식 정보 / Format information
+    pub fn format(&self) -> FormatInfo {
+        FormatInfo {
+            format: self.source_format,
+            version: self.file_header.version_string(),
         }
     }
 
+    /// JSON 문자열에서 문서 모델 복원 / Restore document model from JSON string
+    ///
+    /// `to_json`으로 내보낸 JSON(예: Python `to_json()` 결과)을 다시 문서로 읽어옵니다.
+    /// 외부에서 JSON을 수정한 뒤 마크다운/HTML로 다시 변환하는 파이프라인에 사용합니다.
+    /// 현재 스키마와 이전 배치(스키마 1)를 모두 읽습니다.
+    /// Reads JSON exported with `to_json` (e.g. Python `to_json()` output) back into a document,
+    /// enabling pipelines that edit the JSON externally and re-export to markdown/HTML.
+    /// Both the current schema and the previous layout (schema 1) are accepted.
+    ///
+    /// # Arguments / 매개변수
+    /// * `json` - Document JSON / 문서 JSON
+    ///
+    /// # Returns / 반환값
+    /// Restored document / 복원된 문서
+    pub fn from_json(json: &str) -> Result<Self, crate::error::HwpError> {
+        json::from_json(json)
+    }
+
+    /// 문서를 JSON 문자열로 변환 / Convert document to JSON string
+    ///
+    /// 최상위에 `schema_version`이 붙습니다 (`json` 모듈 참고).
+    /// The output starts with `schema_version` (see the `json` module).
+    pub fn to_json(&self) -> Result<String, crate::error::HwpError> {
+        self.to_json_with_options(&JsonOptions::default())
+    }
+
+    /// 옵션을 지정해 JSON 문자열로 변환 / Convert document to JSON string with options
+    ///
+    /// # Arguments / 매개변수
+    /// * `options` - JSON 출력 옵션 / JSON output options
+    pub fn to_json_with_options(
+        &self,
+        options: &JsonOptions,
+    ) -> Result<String, crate::error::HwpError> {
+        json::to_json(self, options)
+    }
+
+    /// 문서를 HWPX 파일로 저장 / Write document as an HWPX file
+    ///
+    /// # Returns / 반환값
+    /// HWPX(ZIP) 바이트 / HWPX (ZIP) bytes
+    pub fn to_hwpx(&self) -> Result<Vec<u8>, crate::error::HwpError> {
+        crate::writer::write_hwpx(self)
+    }
+
     /// Convert HWP document to Markdown format
     /// HWP 문서를 마크다운 형식으로 변환
     ///
@@ -95,9 +251,22 @@ impl HwpDocument {
     pub fn to_markdown_with_dir(&self, image_output_dir: Option<&str>) -> String {
         let options = crate::viewer::markdown::MarkdownOptions {
             image_output_dir: image_output_dir.map(|s| s.to_string()),
+            image_sink: None,
             use_html: Some(true),
             include_version: Some(true),
             include_page_info: Some(true),
+            include_references: None,
+            include_toc: None,
+            line_break_mode: None,
+            preserve_alignment: None,
+            inline_controls: Default::default(),
+            image_max_dimension: None,
+            image_format: None,
+            flavor: None,
+            include_source_anchors: None,
+            strip_page_fields: None,
+            content_filter: None,
+            deterministic: false,
         };
         crate::viewer::to_markdown(self, &options)
     }
@@ -114,6 +283,64 @@ impl HwpDocument {
         crate::viewer::to_html(self, options)
     }
 
+    /// Convert HWP document to Pandoc JSON AST
+    /// HWP 문서를 Pandoc JSON AST로 변환
+    ///
+    /// `pandoc -f json -o out.docx`처럼 pandoc에 넘겨 다른 형식으로 바꿀 수 있습니다.
+    /// Can be piped to pandoc (e.g. `pandoc -f json -o out.docx`) to produce other formats.
+    ///
+    /// # Returns / 반환값
+    /// Pandoc JSON string / Pandoc JSON 문자열
+    pub fn to_pandoc_json(&self) -> Result<String, crate::error::HwpError> {
+        serde_json::to_string(&crate::viewer::to_pandoc(self)).map_err(crate::error::HwpError::from)
+    }
+
+    /// Convert HWP document to reStructuredText
+    /// HWP 문서를 reStructuredText로 변환
+    ///
+    /// # Returns / 반환값
+    /// reStructuredText string for Sphinx / Sphinx용 reStructuredText 문자열
+    pub fn to_rst(&self) -> String {
+        crate::viewer::to_rst(self)
+    }
+
+    /// Convert HWP document to AsciiDoc
+    /// HWP 문서를 AsciiDoc으로 변환
+    ///
+    /// # Returns / 반환값
+    /// AsciiDoc string for Antora/Asciidoctor / Antora/Asciidoctor용 AsciiDoc 문자열
+    pub fn to_asciidoc(&self) -> String {
+        crate::viewer::to_asciidoc(self)
+    }
+
+    /// Stream HWP document as Markdown into a writer
+    /// HWP 문서를 마크다운으로 변환하여 writer에 순차 출력
+    ///
+    /// # Arguments / 매개변수
+    /// * `options` - Markdown conversion options / 마크다운 변환 옵션
+    /// * `writer` - Output destination / 출력 대상
+    pub fn to_markdown_writer<W: std::io::Write + ?Sized>(
+        &self,
+        options: &crate::viewer::markdown::MarkdownOptions,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        crate::viewer::to_markdown_writer(self, options, writer)
+    }
+
+    /// Stream HWP document as HTML into a writer
+    /// HWP 문서를 HTML로 변환하여 writer에 순차 출력
+    ///
+    /// # Arguments / 매개변수
+    /// * `options` - HTML conversion options / HTML 변환 옵션
+    /// * `writer` - Output destination / 출력 대상
+    pub fn to_html_writer<W: std::io::Write + ?Sized>(
+        &self,
+        options: &crate::viewer::html::HtmlOptions,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        crate::viewer::to_html_writer(self, options, writer)
+    }
+
     /// Resolve derived display texts for control tokens (e.g., AUTO_NUMBER) into `ParaTextRun::Control.display_text`.
     ///
     /// IMPORTANT:
@@ -123,27 +350,8 @@ impl HwpDocument {
         use crate::document::bodytext::{ControlChar, ParaTextRun};
         use crate::document::ParagraphRecord;
 
-        // Footnote/endnote numbering rules are defined by FootnoteShape (spec Table 133/134).
-        // In practice, two FootnoteShape records appear (footnote, endnote). If only one exists,
-        // we reuse it for both.
-        let mut footnote_shape_opt: Option<crate::document::bodytext::FootnoteShape> = None;
-        let mut endnote_shape_opt: Option<crate::document::bodytext::FootnoteShape> = None;
-        for section in &self.body_text.sections {
-            for paragraph in &section.paragraphs {
-                for record in &paragraph.records {
-                    if let ParagraphRecord::FootnoteShape { footnote_shape } = record {
-                        if footnote_shape_opt.is_none() {
-                            footnote_shape_opt = Some(footnote_shape.clone());
-                        } else if endnote_shape_opt.is_none() {
-                            endnote_shape_opt = Some(footnote_shape.clone());
-                        }
-                    }
-                }
-            }
-        }
-        if endnote_shape_opt.is_none() {
-            endnote_shape_opt = footnote_shape_opt.clone();
-        }
+        // Footnote/endnote numbering follows each section's FootnoteShape (spec Table 133/134).
+        let mut notes = crate::document::notes::NoteNumbering::new(self);
 
         let mut table_no: u32 = self
             .doc_info
@@ -152,39 +360,6 @@ impl HwpDocument {
             .map(|p| p.table_start_number as u32)
             .unwrap_or(1);
 
-        let footnote_start: u32 = footnote_shape_opt
-            .as_ref()
-            .map(|s| s.start_number as u32)
-            .or_else(|| {
-                self.doc_info
-                    .document_properties
-                    .as_ref()
-                    .map(|p| p.footnote_start_number as u32)
-            })
-            .unwrap_or(1);
-        let endnote_start: u32 = endnote_shape_opt
-            .as_ref()
-            .map(|s| s.start_number as u32)
-            .or_else(|| {
-                self.doc_info
-                    .document_properties
-                    .as_ref()
-                    .map(|p| p.endnote_start_number as u32)
-            })
-            .unwrap_or(1);
-
-        let footnote_numbering = footnote_shape_opt
-            .as_ref()
-            .map(|s| s.attributes.numbering)
-            .unwrap_or(crate::document::bodytext::footnote_shape::NumberingMethod::Continue);
-        let endnote_numbering = endnote_shape_opt
-            .as_ref()
-            .map(|s| s.attributes.numbering)
-            .unwrap_or(crate::document::bodytext::footnote_shape::NumberingMethod::Continue);
-
-        // NOTE: PerPage is currently treated the same as Continue (requested).
-        let mut footnote_no: u32 = footnote_start;
-        let mut endnote_no: u32 = endnote_start;
         let mut image_no: u32 = self
             .doc_info
             .document_properties
@@ -221,270 +396,10 @@ impl HwpDocument {
             }
         }
 
-        fn parse_number_shape(
-            attribute: u32,
-        ) -> crate::document::bodytext::footnote_shape::NumberShape {
-            // spec Table 143 bit 4-11 number shape, mapping uses Table 134 (same as FootnoteShape)
-            let v = ((attribute >> 4) & 0xFF) as u8;
-            use crate::document::bodytext::footnote_shape::NumberShape;
-            match v {
-                0 => NumberShape::Arabic,
-                1 => NumberShape::CircledArabic,
-                2 => NumberShape::RomanUpper,
-                3 => NumberShape::RomanLower,
-                4 => NumberShape::AlphaUpper,
-                5 => NumberShape::AlphaLower,
-                6 => NumberShape::CircledAlphaUpper,
-                7 => NumberShape::CircledAlphaLower,
-                8 => NumberShape::Hangul,
-                9 => NumberShape::CircledHangul,
-                10 => NumberShape::HangulJamo,
-                11 => NumberShape::CircledHangulJamo,
-                12 => NumberShape::HangulNumber,
-                13 => NumberShape::ChineseNumber,
-                14 => NumberShape::CircledChineseNumber,
-                15 => NumberShape::HeavenlyStem,
-                16 => NumberShape::HeavenlyStemChinese,
-                0x80 => NumberShape::FourCharRepeat,
-                0x81 => NumberShape::CustomCharRepeat,
-                _ => NumberShape::Arabic,
-            }
-        }
-
-        fn format_number(
-            shape: crate::document::bodytext::footnote_shape::NumberShape,
-            n: u32,
-        ) -> String {
-            use crate::document::bodytext::footnote_shape::NumberShape;
-            match shape {
-                NumberShape::Arabic => n.to_string(),
-                NumberShape::RomanUpper => to_roman(n).to_uppercase(),
-                NumberShape::RomanLower => to_roman(n).to_lowercase(),
-                NumberShape::AlphaUpper => to_alpha(n, true),
-                NumberShape::AlphaLower => to_alpha(n, false),
-                NumberShape::CircledArabic => circled_number(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::CircledAlphaUpper => {
-                    circled_alpha(n, true).unwrap_or_else(|| to_alpha(n, true))
-                }
-                NumberShape::CircledAlphaLower => {
-                    circled_alpha(n, false).unwrap_or_else(|| to_alpha(n, false))
-                }
-                NumberShape::Hangul => hangul_gana(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::HangulJamo => hangul_jamo(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::CircledHangul => circled_hangul_gana(n)
-                    .unwrap_or_else(|| hangul_gana(n).unwrap_or_else(|| n.to_string())),
-                NumberShape::CircledHangulJamo => circled_hangul_jamo(n)
-                    .unwrap_or_else(|| hangul_jamo(n).unwrap_or_else(|| n.to_string())),
-                NumberShape::HangulNumber => hangul_number(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::ChineseNumber => chinese_number(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::CircledChineseNumber => circled_chinese_number(n)
-                    .unwrap_or_else(|| chinese_number(n).unwrap_or_else(|| n.to_string())),
-                NumberShape::HeavenlyStem => heavenly_stem(n).unwrap_or_else(|| n.to_string()),
-                NumberShape::HeavenlyStemChinese => {
-                    heavenly_stem_chinese(n).unwrap_or_else(|| n.to_string())
-                }
-                // NOTE: Repeat-based shapes require additional spec-backed inputs (e.g., custom symbol set).
-                // We intentionally fall back to Arabic until we can resolve the exact behavior from spec/fixtures.
-                NumberShape::FourCharRepeat | NumberShape::CustomCharRepeat => n.to_string(),
-            }
-        }
-
-        fn circled_number(n: u32) -> Option<String> {
-            // ①..⑳ (U+2460..U+2473) for 1..20
-            if (1..=20).contains(&n) {
-                let cp = 0x2460 + (n - 1);
-                return char::from_u32(cp).map(|c| c.to_string());
-            }
-            // ㉑..㉟ (U+3251..U+325F) for 21..35 (circled numbers in a circle)
-            if (21..=35).contains(&n) {
-                let cp = 0x3251 + (n - 21);
-                return char::from_u32(cp).map(|c| c.to_string());
-            }
-            None
-        }
-
-        fn circled_alpha(n: u32, upper: bool) -> Option<String> {
-            // Ⓐ..Ⓩ (U+24B6..U+24CF) for 1..26
-            // ⓐ..ⓩ (U+24D0..U+24E9) for 1..26
-            if !(1..=26).contains(&n) {
-                return None;
-            }
-            let base = if upper { 0x24B6 } else { 0x24D0 };
-            let cp = base + (n - 1);
-            char::from_u32(cp).map(|c| c.to_string())
-        }
-
-        fn hangul_gana(n: u32) -> Option<String> {
-            // 가..하 (14 chars) used commonly for numbering
-            const LIST: [&str; 14] = [
-                "가", "나", "다", "라", "마", "바", "사", "아", "자", "차", "카", "타", "파", "하",
-            ];
-            if n == 0 {
-                return None;
-            }
-            let idx = ((n - 1) % (LIST.len() as u32)) as usize;
-            Some(LIST[idx].to_string())
-        }
-
-        fn circled_hangul_gana(n: u32) -> Option<String> {
-            // ㉮..㉻ (U+326E..U+327B) for 1..14
-            if !(1..=14).contains(&n) {
-                return None;
-            }
-            let cp = 0x326E + (n - 1);
-            char::from_u32(cp).map(|c| c.to_string())
-        }
-
-        fn hangul_jamo(n: u32) -> Option<String> {
-            // ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅅ ㅇ ㅈ ㅊ ㅋ ㅌ ㅍ ㅎ
-            const LIST: [&str; 14] = [
-                "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ",
-            ];
-            if n == 0 {
-                return None;
-            }
-            let idx = ((n - 1) % (LIST.len() as u32)) as usize;
-            Some(LIST[idx].to_string())
-        }
-
-        fn circled_hangul_jamo(n: u32) -> Option<String> {
-            // ㉠..㉭ (U+3260..U+326D) for 1..14
-            if !(1..=14).contains(&n) {
-                return None;
-            }
-            let cp = 0x3260 + (n - 1);
-            char::from_u32(cp).map(|c| c.to_string())
-        }
-
-        fn hangul_number(n: u32) -> Option<String> {
-            // 일, 이, 삼 ... (up to 99 for now)
-            if n == 0 || n > 99 {
-                return None;
-            }
-            const DIGITS: [&str; 10] = ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
-            if n < 10 {
-                return Some(DIGITS[n as usize].to_string());
-            }
-            if n == 10 {
-                return Some("십".to_string());
-            }
-            let tens = n / 10;
-            let ones = n % 10;
-            let mut out = String::new();
-            if tens > 1 {
-                out.push_str(DIGITS[tens as usize]);
-            }
-            out.push('십');
-            if ones > 0 {
-                out.push_str(DIGITS[ones as usize]);
-            }
-            Some(out)
-        }
-
-        fn chinese_number(n: u32) -> Option<String> {
-            // 一, 二, 三 ... (up to 99 for now)
-            if n == 0 || n > 99 {
-                return None;
-            }
-            const DIGITS: [&str; 10] = ["", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
-            if n < 10 {
-                return Some(DIGITS[n as usize].to_string());
-            }
-            if n == 10 {
-                return Some("十".to_string());
-            }
-            let tens = n / 10;
-            let ones = n % 10;
-            let mut out = String::new();
-            if tens > 1 {
-                out.push_str(DIGITS[tens as usize]);
-            }
-            out.push('十');
-            if ones > 0 {
-                out.push_str(DIGITS[ones as usize]);
-            }
-            Some(out)
-        }
-
-        fn circled_chinese_number(n: u32) -> Option<String> {
-            // ㊀..㊉ (U+3280..U+3289) for 1..10
-            if !(1..=10).contains(&n) {
-                return None;
-            }
-            let cp = 0x3280 + (n - 1);
-            char::from_u32(cp).map(|c| c.to_string())
-        }
-
-        fn heavenly_stem(n: u32) -> Option<String> {
-            // 갑, 을, 병, 정, 무, 기, 경, 신, 임, 계
-            const LIST: [&str; 10] = ["갑", "을", "병", "정", "무", "기", "경", "신", "임", "계"];
-            if n == 0 {
-                return None;
-            }
-            let idx = ((n - 1) % 10) as usize;
-            Some(LIST[idx].to_string())
-        }
-
-        fn heavenly_stem_chinese(n: u32) -> Option<String> {
-            // 甲, 乙, 丙, 丁, 戊, 己, 庚, 辛, 壬, 癸
-            const LIST: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
-            if n == 0 {
-                return None;
-            }
-            let idx = ((n - 1) % 10) as usize;
-            Some(LIST[idx].to_string())
-        }
-
-        fn to_roman(mut n: u32) -> String {
-            if n == 0 {
-                return String::new();
-            }
-            let mut out = String::new();
-            let pairs = [
-                (1000, "M"),
-                (900, "CM"),
-                (500, "D"),
-                (400, "CD"),
-                (100, "C"),
-                (90, "XC"),
-                (50, "L"),
-                (40, "XL"),
-                (10, "X"),
-                (9, "IX"),
-                (5, "V"),
-                (4, "IV"),
-                (1, "I"),
-            ];
-            for (v, s) in pairs {
-                while n >= v {
-                    out.push_str(s);
-                    n -= v;
-                }
-            }
-            out
-        }
-
-        fn to_alpha(mut n: u32, upper: bool) -> String {
-            // 1->A, 26->Z, 27->AA
-            if n == 0 {
-                return String::new();
-            }
-            let mut buf: Vec<u8> = Vec::new();
-            while n > 0 {
-                n -= 1;
-                let c = (n % 26) as u8;
-                buf.push(if upper { b'A' + c } else { b'a' + c });
-                n /= 26;
-            }
-            buf.reverse();
-            String::from_utf8_lossy(&buf).to_string()
-        }
-
         fn resolve_paragraph_record(
             record: &mut ParagraphRecord,
             table_no: &mut u32,
-            footnote_no: &mut u32,
-            endnote_no: &mut u32,
+            notes: &mut crate::document::notes::NoteNumbering,
             _image_no: &mut u32,
             _formula_no: &mut u32,
         ) {
@@ -498,14 +413,14 @@ impl HwpDocument {
                     // Footnote/endnote ctrl headers ("fn  " / "en  "): assign number to all AUTO_NUMBER tokens in this subtree.
                     if header.ctrl_id == "fn  " || header.ctrl_id == "en  " {
                         let current = if header.ctrl_id == "fn  " {
-                            *footnote_no
+                            notes.footnote.take()
                         } else {
-                            *endnote_no
+                            notes.endnote.take()
                         };
 
                         fn fill_auto_numbers_in_records(
                             records: &mut [ParagraphRecord],
-                            value: u32,
+                            value: &str,
                         ) {
                             for r in records.iter_mut() {
                                 match r {
@@ -546,15 +461,9 @@ impl HwpDocument {
                             }
                         }
 
-                        fill_auto_numbers_in_records(children, current);
+                        fill_auto_numbers_in_records(children, &current.label);
                         for p in paragraphs.iter_mut() {
-                            fill_auto_numbers_in_records(&mut p.records, current);
-                        }
-
-                        if header.ctrl_id == "fn  " {
-                            *footnote_no += 1;
-                        } else {
-                            *endnote_no += 1;
+                            fill_auto_numbers_in_records(&mut p.records, &current.label);
                         }
                     }
 
@@ -594,52 +503,24 @@ impl HwpDocument {
 
                     // Recurse into nested structures for completeness.
                     for c in children.iter_mut() {
-                        resolve_paragraph_record(
-                            c,
-                            table_no,
-                            footnote_no,
-                            endnote_no,
-                            _image_no,
-                            _formula_no,
-                        );
+                        resolve_paragraph_record(c, table_no, notes, _image_no, _formula_no);
                     }
                     for p in paragraphs.iter_mut() {
                         for r in p.records.iter_mut() {
-                            resolve_paragraph_record(
-                                r,
-                                table_no,
-                                footnote_no,
-                                endnote_no,
-                                _image_no,
-                                _formula_no,
-                            );
+                            resolve_paragraph_record(r, table_no, notes, _image_no, _formula_no);
                         }
                     }
                 }
                 ParagraphRecord::ListHeader { paragraphs, .. } => {
                     for p in paragraphs.iter_mut() {
                         for r in p.records.iter_mut() {
-                            resolve_paragraph_record(
-                                r,
-                                table_no,
-                                footnote_no,
-                                endnote_no,
-                                _image_no,
-                                _formula_no,
-                            );
+                            resolve_paragraph_record(r, table_no, notes, _image_no, _formula_no);
                         }
                     }
                 }
                 ParagraphRecord::ShapeComponent { children, .. } => {
                     for c in children.iter_mut() {
-                        resolve_paragraph_record(
-                            c,
-                            table_no,
-                            footnote_no,
-                            endnote_no,
-                            _image_no,
-                            _formula_no,
-                        );
+                        resolve_paragraph_record(c, table_no, notes, _image_no, _formula_no);
                     }
                 }
                 _ => {}
@@ -647,16 +528,11 @@ impl HwpDocument {
         }
 
         for section in self.body_text.sections.iter_mut() {
-            // Apply "Restart" per section. PerPage is currently treated as Continue.
-            use crate::document::bodytext::footnote_shape::NumberingMethod;
-            if matches!(footnote_numbering, NumberingMethod::Restart) {
-                footnote_no = footnote_start;
-            }
-            if matches!(endnote_numbering, NumberingMethod::Restart) {
-                endnote_no = endnote_start;
-            }
+            notes.start_section(section);
 
             for paragraph in section.paragraphs.iter_mut() {
+                notes.start_paragraph(paragraph);
+
                 // If this paragraph has an AutoNumber/NewNumber ctrl header, use it to resolve AUTO_NUMBER tokens in its ParaText runs.
                 let mut para_auto_attr: Option<u32> = None;
                 let mut para_auto_prefix: Option<String> = None;
@@ -694,8 +570,6 @@ impl HwpDocument {
                 // Apply NEW_NUMBER to counters (bit 0-3: kind, number: new value)
                 if let Some((attr, num)) = new_number_attr_and_value {
                     match parse_auto_number_kind(attr) {
-                        AutoNumberKind::Footnote => footnote_no = num as u32,
-                        AutoNumberKind::Endnote => endnote_no = num as u32,
                         AutoNumberKind::Image => image_no = num as u32,
                         AutoNumberKind::Table => table_no = num as u32,
                         AutoNumberKind::Formula => formula_no = num as u32,
@@ -706,13 +580,16 @@ impl HwpDocument {
                 // Fill AUTO_NUMBER tokens in this paragraph if we know the kind.
                 if let Some(attr) = para_auto_attr {
                     let kind = parse_auto_number_kind(attr);
-                    let shape = parse_number_shape(attr);
+                    // spec Table 143 bit 4-11 number shape, mapping uses Table 134 (same as FootnoteShape)
+                    let shape = crate::document::bodytext::NumberShape::from_code(
+                        ((attr >> 4) & 0xFF) as u8,
+                    );
                     let prefix = para_auto_prefix.as_deref().unwrap_or("");
                     let suffix = para_auto_suffix.as_deref().unwrap_or("");
 
                     let next_value = match kind {
-                        AutoNumberKind::Footnote => Some(footnote_no),
-                        AutoNumberKind::Endnote => Some(endnote_no),
+                        AutoNumberKind::Footnote => Some(notes.footnote.take().value),
+                        AutoNumberKind::Endnote => Some(notes.endnote.take().value),
                         AutoNumberKind::Image => Some(image_no),
                         AutoNumberKind::Table => Some(table_no),
                         AutoNumberKind::Formula => Some(formula_no),
@@ -720,7 +597,7 @@ impl HwpDocument {
                     };
 
                     if let Some(v) = next_value {
-                        let formatted = format!("{}{}{}", prefix, format_number(shape, v), suffix);
+                        let formatted = format!("{}{}{}", prefix, shape.format(v), suffix);
                         for record in paragraph.records.iter_mut() {
                             if let ParagraphRecord::ParaText { runs, .. } = record {
                                 for run in runs.iter_mut() {
@@ -740,8 +617,6 @@ impl HwpDocument {
 
                         // Increment the counter once per paragraph auto-number usage.
                         match kind {
-                            AutoNumberKind::Footnote => footnote_no += 1,
-                            AutoNumberKind::Endnote => endnote_no += 1,
                             AutoNumberKind::Image => image_no += 1,
                             AutoNumberKind::Table => table_no += 1,
                             AutoNumberKind::Formula => formula_no += 1,
@@ -754,13 +629,48 @@ impl HwpDocument {
                     resolve_paragraph_record(
                         record,
                         &mut table_no,
-                        &mut footnote_no,
-                        &mut endnote_no,
+                        &mut notes,
                         &mut image_no,
                         &mut formula_no,
                     );
                 }
             }
         }
+
+        self.resolve_outline_numbers();
+    }
+
+    /// Resolve outline numbers of body paragraphs into `Paragraph::resolved_number`
+    /// 본문 문단의 개요 번호를 계산해 `Paragraph::resolved_number`에 채움
+    ///
+    /// 구역을 넘어 본문 최상위 문단을 문서 순서대로 세며, Markdown 변환과 같은 규칙으로
+    /// 번호 형식이 비어 있는 수준은 건너뜁니다. 번호는 `1.2.3`처럼 상위 수준부터 점으로 잇습니다.
+    /// Top-level body paragraphs are counted in document order across sections, skipping levels
+    /// whose number format is blank by the same rules as the Markdown viewer. Numbers are joined
+    /// with dots from the top level down, like `1.2.3`.
+    pub fn resolve_outline_numbers(&mut self) {
+        use crate::viewer::markdown::utils::{numbered_outline_level, OutlineNumberTracker};
+
+        let mut tracker = OutlineNumberTracker::new();
+        let numbers: Vec<Vec<Option<String>>> = self
+            .body_text
+            .sections
+            .iter()
+            .map(|section| {
+                section
+                    .paragraphs
+                    .iter()
+                    .map(|paragraph| {
+                        let level = numbered_outline_level(&paragraph.para_header, self)?;
+                        (tracker.get_and_increment(level) > 0).then(|| tracker.path(level))
+                    })
+                    .collect()
+            })
+            .collect();
+        for (section, numbers) in self.body_text.sections.iter_mut().zip(numbers) {
+            for (paragraph, number) in section.paragraphs.iter_mut().zip(numbers) {
+                paragraph.resolved_number = number;
+            }
+        }
     }
 }
@@ -0,0 +1,234 @@
+/// 각주/미주 번호 매기기 모듈 / Footnote and endnote numbering module
+///
+/// 구역마다 저장된 각주/미주 모양의 시작 번호, 번호 매기기 방법(앞 구역에 이어서, 구역마다,
+/// 쪽마다)과 번호 모양을 따라 번호를 매기고, 새 번호 컨트롤도 반영합니다. 표시 텍스트 계산과
+/// 뷰어가 같은 규칙을 씁니다.
+///
+/// Numbers notes following each section's footnote/endnote shape: start number, numbering
+/// method (continue from the previous section, restart per section, restart per page) and
+/// number shape, and applies new-number controls. Display text resolution and the viewers share
+/// these rules.
+use super::bodytext::ctrl_header::CtrlHeaderData;
+use super::bodytext::{FootnoteShape, NumberingMethod, Paragraph, ParagraphRecord, Section};
+use super::stats::PageTracker;
+use super::HwpDocument;
+
+/// 새 번호 컨트롤의 번호 종류 (표 143 bit 0-3) / Number kind of a new-number control (Table 143 bits 0-3)
+const KIND_FOOTNOTE: u32 = 1;
+const KIND_ENDNOTE: u32 = 2;
+
+/// 각주/미주 번호 / Footnote or endnote number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NoteNumber {
+    /// 번호 값 / Number value
+    pub value: u32,
+    /// 번호 모양대로 쓴 번호 (예: "①") / Number written in the number shape (e.g. "①")
+    pub label: String,
+}
+
+/// 각주 또는 미주 번호 계수기 / Footnote or endnote counter
+#[derive(Debug)]
+pub(crate) struct NoteCounter {
+    shape: Option<FootnoteShape>,
+    default_start: u32,
+    next: u32,
+}
+
+impl NoteCounter {
+    fn new(default_start: u32) -> Self {
+        Self {
+            shape: None,
+            default_start,
+            next: default_start,
+        }
+    }
+
+    fn start(&self) -> u32 {
+        self.shape
+            .as_ref()
+            .map_or(self.default_start, |shape| shape.start_number as u32)
+    }
+
+    fn numbering(&self) -> NumberingMethod {
+        self.shape
+            .as_ref()
+            .map_or(NumberingMethod::Continue, |shape| {
+                shape.attributes.numbering
+            })
+    }
+
+    fn start_section(&mut self, shape: Option<&FootnoteShape>, first: bool) {
+        if let Some(shape) = shape {
+            self.shape = Some(shape.clone());
+        }
+        // 구역은 새 쪽에서 시작 / A section starts on a new page
+        if first || self.numbering() != NumberingMethod::Continue {
+            self.next = self.start();
+        }
+    }
+
+    fn start_page(&mut self) {
+        if self.numbering() == NumberingMethod::PerPage {
+            self.next = self.start();
+        }
+    }
+
+    /// 현재 구역의 모양 / Shape of the current section
+    pub(crate) fn shape(&self) -> Option<&FootnoteShape> {
+        self.shape.as_ref()
+    }
+
+    /// 다음 번호를 쓰고 하나 늘림 / Take the next number and advance
+    pub(crate) fn take(&mut self) -> NoteNumber {
+        let value = self.next;
+        self.next += 1;
+        let label = match &self.shape {
+            Some(shape) => shape.format_number(value),
+            None => value.to_string(),
+        };
+        NoteNumber { value, label }
+    }
+}
+
+/// 문서 전체의 각주/미주 번호 매기기 / Footnote and endnote numbering across a document
+///
+/// 구역마다 `start_section`, 그 구역의 문단마다 차례로 `start_paragraph`를 부른 뒤 각주/미주
+/// 컨트롤을 만날 때마다 `take`로 번호를 받습니다.
+/// Call `start_section` for each section and `start_paragraph` for each of its paragraphs in
+/// order, then `take` a number for every footnote/endnote control.
+#[derive(Debug)]
+pub(crate) struct NoteNumbering {
+    /// 각주 계수기 / Footnote counter
+    pub footnote: NoteCounter,
+    /// 미주 계수기 / Endnote counter
+    pub endnote: NoteCounter,
+    pages: PageTracker,
+    started: bool,
+}
+
+impl NoteNumbering {
+    /// 문서 속성의 시작 번호로 만들기 / Create with the start numbers from the document properties
+    pub(crate) fn new(document: &HwpDocument) -> Self {
+        let properties = document.doc_info.document_properties.as_ref();
+        Self {
+            footnote: NoteCounter::new(properties.map_or(1, |p| p.footnote_start_number as u32)),
+            endnote: NoteCounter::new(properties.map_or(1, |p| p.endnote_start_number as u32)),
+            pages: PageTracker::default(),
+            started: false,
+        }
+    }
+
+    /// 구역 시작 (모양이 없는 구역은 앞 구역의 모양을 씀) / Start a section (sections without shapes keep the previous ones)
+    pub(crate) fn start_section(&mut self, section: &Section) {
+        let (footnote, endnote) = section.note_shapes();
+        let first = !self.started;
+        self.footnote.start_section(footnote, first);
+        self.endnote.start_section(endnote, first);
+        self.pages = PageTracker::default();
+        self.started = true;
+    }
+
+    /// 구역의 문단 시작 (쪽마다 새로 시작, 새 번호 컨트롤 반영) / Start a paragraph of the section (per-page restart, new-number controls)
+    pub(crate) fn start_paragraph(&mut self, paragraph: &Paragraph) {
+        if self.pages.new_pages(paragraph) > 0 {
+            self.footnote.start_page();
+            self.endnote.start_page();
+        }
+        for record in &paragraph.records {
+            let ParagraphRecord::CtrlHeader { header, .. } = record else {
+                continue;
+            };
+            if let CtrlHeaderData::NewNumber { attribute, number } = &header.data {
+                match attribute & 0x0F {
+                    KIND_FOOTNOTE => self.footnote.next = *number as u32,
+                    KIND_ENDNOTE => self.endnote.next = *number as u32,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{DocumentBuilder, ParagraphStyle};
+    use crate::document::bodytext::footnote_shape::FootnoteShapeAttributes;
+    use crate::document::bodytext::{ColumnDivideType, NumberShape, PagePosition};
+    use crate::types::COLORREF;
+
+    fn shape(number_shape: NumberShape, numbering: NumberingMethod) -> ParagraphRecord {
+        ParagraphRecord::FootnoteShape {
+            footnote_shape: FootnoteShape {
+                attributes: FootnoteShapeAttributes {
+                    number_shape,
+                    page_position: PagePosition::Separate,
+                    numbering,
+                    superscript: false,
+                    prefix: false,
+                },
+                custom_symbol: 0,
+                front_decoration: 0,
+                back_decoration: b')' as u16,
+                start_number: 1,
+                breakline_length: 0,
+                breakline_top_margin: 0,
+                breakline_bottom_margin: 0,
+                remark_between_margin: 0,
+                breakline_type: 0,
+                breakline_thickness: 0,
+                breakline_color: COLORREF(0),
+            },
+        }
+    }
+
+    #[test]
+    fn test_numbering_follows_section_rules() {
+        let style = ParagraphStyle::default();
+        let mut document = DocumentBuilder::new()
+            .add_paragraph("첫 쪽", &style)
+            .add_paragraph("둘째 쪽", &style)
+            .build();
+        // 첫 구역: 동그라미 숫자, 쪽마다 / First section: circled digits, per page
+        let first = &mut document.body_text.sections[0].paragraphs;
+        first[0]
+            .records
+            .push(shape(NumberShape::CircledArabic, NumberingMethod::PerPage));
+        first[0]
+            .records
+            .push(shape(NumberShape::RomanLower, NumberingMethod::Continue));
+        first[1]
+            .para_header
+            .column_divide_type
+            .push(ColumnDivideType::Page);
+
+        // 둘째 구역은 모양이 없어 앞 구역의 모양을 씀 / The second section has no shapes and keeps the previous ones
+        let mut second = document.body_text.sections[0].clone();
+        second.index = 1;
+        second.paragraphs[0]
+            .records
+            .retain(|record| !matches!(record, ParagraphRecord::FootnoteShape { .. }));
+        document.body_text.sections.push(second);
+
+        let mut numbering = NoteNumbering::new(&document);
+        let mut labels = Vec::new();
+        for section in &document.body_text.sections {
+            numbering.start_section(section);
+            for paragraph in &section.paragraphs {
+                numbering.start_paragraph(paragraph);
+                labels.push(numbering.footnote.take().label);
+                labels.push(numbering.footnote.take().label);
+                labels.push(numbering.endnote.take().label);
+            }
+        }
+        // 각주는 쪽과 구역마다 다시 시작, 미주는 이어서 / Footnotes restart per page and section, endnotes continue
+        assert_eq!(
+            labels,
+            ["①", "②", "i", "①", "②", "ii", "①", "②", "iii", "①", "②", "iv"]
+        );
+        assert_eq!(
+            numbering.footnote.shape().unwrap().decorated_number(3),
+            "③)"
+        );
+    }
+}
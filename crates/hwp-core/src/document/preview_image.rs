@@ -3,10 +3,13 @@
 /// 스펙 문서 매핑: 3.2.7 - 미리보기 이미지 / Spec mapping: 3.2.7 - Preview image
 ///
 /// `PrvImage` 스트림에는 미리보기 이미지가 BMP 또는 GIF 형식으로 저장됩니다.
+/// HWPX는 `Preview/PrvImage.png`에 PNG로 저장합니다.
 /// The `PrvImage` stream contains preview image stored as BMP or GIF format.
+/// HWPX stores it as PNG in `Preview/PrvImage.png`.
 use crate::error::HwpError;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "fs")]
 use std::path::Path;
 
 /// 미리보기 이미지 / Preview image
@@ -14,7 +17,7 @@ use std::path::Path;
 pub struct PreviewImage {
     /// Base64로 인코딩된 이미지 데이터 / Base64 encoded image data
     pub data: String,
-    /// 이미지 형식 ("BMP" 또는 "GIF") / Image format ("BMP" or "GIF")
+    /// 이미지 형식 ("BMP", "GIF", "PNG", "JPEG" 또는 "UNKNOWN") / Image format ("BMP", "GIF", "PNG", "JPEG" or "UNKNOWN")
     pub format: String,
     /// 파일로 저장된 경우 파일 경로 (선택적) / File path if saved as file (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,7 +29,7 @@ impl PreviewImage {
     ///
     /// # Arguments
     /// * `data` - PrvImage 스트림의 원시 바이트 데이터 / Raw byte data of PrvImage stream
-    /// * `save_to_file` - 파일로 저장할 디렉토리 경로 (선택적) / Optional directory path to save file
+    /// * `save_to_file` - 파일로 저장할 디렉토리 경로 (선택적, `fs` 기능 필요) / Optional directory path to save file (requires the `fs` feature)
     ///
     /// # Returns
     /// 파싱된 PreviewImage 구조체 / Parsed PreviewImage structure
@@ -50,8 +53,14 @@ impl PreviewImage {
         // 이미지 형식 감지 / Detect image format
         // BMP: "BM" (0x42 0x4D)로 시작 / BMP: starts with "BM" (0x42 0x4D)
         // GIF: "GIF87a" 또는 "GIF89a"로 시작 / GIF: starts with "GIF87a" or "GIF89a"
+        // PNG: 0x89 "PNG"로 시작 / PNG: starts with 0x89 "PNG"
+        // JPEG: 0xFF 0xD8 0xFF로 시작 / JPEG: starts with 0xFF 0xD8 0xFF
         let format = if data.len() >= 2 && data[0] == 0x42 && data[1] == 0x4D {
             "BMP".to_string()
+        } else if data.starts_with(b"\x89PNG") {
+            "PNG".to_string()
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            "JPEG".to_string()
         } else if data.len() >= 6 {
             let gif_header = &data[0..6];
             if gif_header == b"GIF87a" || gif_header == b"GIF89a" {
@@ -69,10 +78,21 @@ impl PreviewImage {
         let base64_data = STANDARD.encode(data);
 
         // 파일로 저장 (선택적) / Save to file (optional)
+        #[cfg(not(feature = "fs"))]
+        if save_to_file.is_some() {
+            return Err(HwpError::Io(
+                "Saving the preview image requires the `fs` feature".to_string(),
+            ));
+        }
+        #[cfg(not(feature = "fs"))]
+        let file_path = None;
+        #[cfg(feature = "fs")]
         let file_path = if let Some(dir_path) = save_to_file {
             let extension = match format.as_str() {
                 "BMP" => "bmp",
                 "GIF" => "gif",
+                "PNG" => "png",
+                "JPEG" => "jpg",
                 _ => "bin",
             };
             let file_name = format!("preview_image.{extension}");
@@ -101,4 +121,46 @@ impl PreviewImage {
             file_path,
         })
     }
+
+    /// 디코딩된 이미지 바이트 / Decoded image bytes
+    ///
+    /// # Returns / 반환값
+    /// 이미지 파일 바이트 / Image file bytes
+    pub fn bytes(&self) -> Result<Vec<u8>, HwpError> {
+        STANDARD
+            .decode(&self.data)
+            .map_err(|e| HwpError::InternalError {
+                message: format!("Failed to decode preview image data: {e}"),
+            })
+    }
+
+    /// MIME 타입 / MIME type
+    pub fn mime_type(&self) -> &'static str {
+        match self.format.as_str() {
+            "BMP" => "image/bmp",
+            "GIF" => "image/gif",
+            "PNG" => "image/png",
+            "JPEG" => "image/jpeg",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format_and_decode() {
+        let png = b"\x89PNG\r\n\x1a\nrest";
+        let image = PreviewImage::parse(png, None).unwrap();
+        assert_eq!(image.format, "PNG");
+        assert_eq!(image.mime_type(), "image/png");
+        assert_eq!(image.bytes().unwrap(), png);
+
+        let image = PreviewImage::parse(b"BM\x00\x00", None).unwrap();
+        assert_eq!(image.mime_type(), "image/bmp");
+        let image = PreviewImage::parse(b"\xFF\xD8\xFF\xE0", None).unwrap();
+        assert_eq!(image.format, "JPEG");
+    }
 }
@@ -0,0 +1,55 @@
+/// 모델에 없는 원본 파트 조회 / Access to raw parts the model does not cover
+///
+/// HWPX를 파싱할 때 본문 폴더(`Contents/`), `BinData/`, `Preview/` 밖의 파트를 원본 바이트 그대로
+/// 보관해 두므로, 이 크레이트가 모델로 읽지 않는 `Scripts/`, `Custom/`, `DocHistory/`,
+/// `settings.xml`, `META-INF/` 등을 직접 살펴볼 수 있습니다.
+///
+/// While parsing HWPX, parts outside the content folder (`Contents/`), `BinData/` and `Preview/`
+/// are kept as raw bytes, so `Scripts/`, `Custom/`, `DocHistory/`, `settings.xml`, `META-INF/`
+/// and other parts this crate does not read into the model can be inspected directly.
+use super::HwpDocument;
+
+impl HwpDocument {
+    /// 보관한 원본 파트 경로 (사전 순) / Paths of the kept raw parts (in lexical order)
+    ///
+    /// HWPX가 아닌 문서는 비어 있습니다.
+    /// Empty for documents that are not HWPX.
+    pub fn raw_parts(&self) -> Vec<&str> {
+        self.unparsed_parts.keys().map(String::as_str).collect()
+    }
+
+    /// 보관한 원본 파트 읽기 / Read a kept raw part
+    ///
+    /// # Arguments / 매개변수
+    /// * `path` - ZIP 안의 파트 경로 (예: "Custom/custom.xml") / Part path inside the ZIP (e.g. "Custom/custom.xml")
+    ///
+    /// # Returns / 반환값
+    /// 원본 바이트, 보관하지 않은 파트면 None / Raw bytes, or None for a part that was not kept
+    pub fn read_part(&self, path: &str) -> Option<&[u8]> {
+        self.unparsed_parts.get(path).map(Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::fixtures::empty_document;
+
+    #[test]
+    fn test_raw_parts() {
+        let mut document = empty_document();
+        assert!(document.raw_parts().is_empty());
+        document
+            .unparsed_parts
+            .insert("settings.xml".to_string(), b"<settings/>".to_vec());
+        document
+            .unparsed_parts
+            .insert("Custom/custom.xml".to_string(), b"<custom/>".to_vec());
+
+        assert_eq!(document.raw_parts(), ["Custom/custom.xml", "settings.xml"]);
+        assert_eq!(
+            document.read_part("settings.xml"),
+            Some(&b"<settings/>"[..])
+        );
+        assert_eq!(document.read_part("Contents/section0.xml"), None);
+    }
+}
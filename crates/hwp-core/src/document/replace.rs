@@ -0,0 +1,642 @@
+/// 문서 텍스트 바꾸기 모듈 / Document text replacement module
+///
+/// 문단 텍스트를 직접 고치면서 텍스트 조각, 제어 문자 위치, 글자 모양/줄/영역 태그 위치, 문단
+/// 글자 수를 함께 맞춰 둡니다. 위치 단위는 원본 스트림과 같은 WCHAR(UTF-16 코드 단위)이고,
+/// HWPX 필드 위치만 문자 단위입니다.
+/// Edits paragraph text in place while keeping text runs, control character positions,
+/// character shape/line/range tag positions and the paragraph character count in step. Positions
+/// are in WCHARs (UTF-16 code units) like the original stream; only HWPX field offsets are in
+/// characters.
+use super::bodytext::{CharShapeInfo, CtrlId, ParaTextRun, Paragraph, ParagraphRecord};
+use super::fields::{FIELD_END, FIELD_START};
+use super::HwpDocument;
+
+impl HwpDocument {
+    /// 본문 텍스트 찾아 바꾸기 / Find and replace body text
+    ///
+    /// 본문 문단과 표 셀, 글상자, 머리말/꼬리말, 각주/미주 안의 문단을 모두 고칩니다. 표나 개체
+    /// 같은 컨트롤을 가로지르는 텍스트는 찾지 않습니다. 파서가 같은 문단을 두 곳에 두는 경우
+    /// (표 컨트롤의 문단, 각주의 목록 헤더) 두 사본을 모두 고치지만 한 번만 셉니다.
+    /// Edits body paragraphs and the paragraphs inside table cells, text boxes, headers/footers
+    /// and footnotes/endnotes. Text running across a control such as a table or object is not
+    /// matched. Where the parser keeps the same paragraph in two places (table control paragraphs,
+    /// note list headers) both copies are edited but counted once.
+    ///
+    /// # Arguments / 매개변수
+    /// * `pattern` - 찾을 텍스트 / Text to find
+    /// * `replacement` - 바꿀 텍스트 / Replacement text
+    ///
+    /// # Returns / 반환값
+    /// 바꾼 횟수 (찾을 텍스트가 비어 있으면 0) / Number of replacements (0 for an empty pattern)
+    pub fn replace_text(&mut self, pattern: &str, replacement: &str) -> usize {
+        if pattern.is_empty() {
+            return 0;
+        }
+        let replace = Replace {
+            target: Target::Text(pattern),
+            replacement,
+        };
+        let mut replaced = 0;
+        for section in &mut self.body_text.sections {
+            for paragraph in &mut section.paragraphs {
+                replaced += replace.paragraph(paragraph, true);
+            }
+        }
+        replaced
+    }
+}
+
+/// 문단 하나에서 바꿀 곳 / What to replace within one paragraph
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Target<'p> {
+    /// 같은 텍스트 모두 / Every occurrence of a text
+    Text(&'p str),
+    /// 문단 텍스트의 문자 구간 (HWPX 필드의 표시 텍스트) / A character range of the paragraph text (display text of an HWPX field)
+    Chars { start: usize, len: usize },
+    /// n번째 필드 시작/끝 제어 문자 사이 (HWP 5.0 필드의 표시 텍스트) / Between the n-th field start/end controls (display text of an HWP 5.0 field)
+    Field(usize),
+}
+
+/// 중첩 문단은 건드리지 않고 문단 하나만 바꾸기 / Replace within one paragraph, leaving nested paragraphs alone
+///
+/// # Returns / 반환값
+/// 바꾼 횟수 / Number of replacements
+pub(crate) fn replace_in_paragraph(
+    paragraph: &mut Paragraph,
+    target: Target,
+    replacement: &str,
+) -> usize {
+    Replace {
+        target,
+        replacement,
+    }
+    .own_text(paragraph)
+}
+
+/// 바꿀 곳과 바꿀 텍스트 / What to replace and its replacement
+struct Replace<'p> {
+    target: Target<'p>,
+    replacement: &'p str,
+}
+
+/// 바꾼 구간 하나 (원본 위치 기준) / One replaced range (in original positions)
+#[derive(Debug, Clone, Copy)]
+struct Edit {
+    /// 시작 WCHAR 위치 / Start WCHAR position
+    start: usize,
+    /// 원래 WCHAR 길이 / Original WCHAR length
+    old_len: usize,
+    /// 바뀐 WCHAR 길이 / New WCHAR length
+    new_len: usize,
+    /// 시작 문자 위치 / Start character offset
+    char_start: usize,
+    /// 원래 문자 길이 / Original character length
+    old_chars: usize,
+    /// 바뀐 문자 길이 / New character length
+    new_chars: usize,
+}
+
+/// 문단을 훑는 동안의 상태 / State while scanning a paragraph
+///
+/// 문단 안 위치는 ParaText 레코드를 넘어 이어집니다.
+/// Positions within a paragraph continue across ParaText records.
+#[derive(Debug, Default)]
+struct Scan {
+    /// 원본 WCHAR 위치 / Original WCHAR position
+    position: usize,
+    /// 원본 문자 위치 / Original character offset
+    char_position: usize,
+    /// 지금까지 만난 필드 시작 수 / Number of field starts met so far
+    fields: usize,
+    /// 바꿀 필드 안에 있는지 / Whether inside the field to replace
+    in_field: bool,
+    edits: Vec<Edit>,
+}
+
+impl Replace<'_> {
+    /// 문단과 그 안의 중첩 문단 바꾸기 / Replace in a paragraph and its nested paragraphs
+    ///
+    /// `counted`가 `false`이면 고치기만 하고 세지 않습니다 (사본 문단).
+    /// With `counted` false the paragraph is edited but not counted (a duplicate copy).
+    fn paragraph(&self, paragraph: &mut Paragraph, counted: bool) -> usize {
+        let own = self.own_text(paragraph);
+        let mut replaced = if counted { own } else { 0 };
+        for record in &mut paragraph.records {
+            replaced += self.record(record, counted);
+        }
+        replaced
+    }
+
+    /// 문단 자체의 텍스트만 바꾸기 / Replace only the paragraph's own text
+    fn own_text(&self, paragraph: &mut Paragraph) -> usize {
+        let mut scan = Scan::default();
+        for record in &mut paragraph.records {
+            if let ParagraphRecord::ParaText { text, runs, .. } = record {
+                self.para_text(text, runs, &mut scan);
+            }
+        }
+        if !scan.edits.is_empty() {
+            shift_positions(paragraph, &scan.edits);
+        }
+        scan.edits.len()
+    }
+
+    /// 레코드 안의 중첩 문단 바꾸기 / Replace in paragraphs nested in a record
+    fn record(&self, record: &mut ParagraphRecord, counted: bool) -> usize {
+        let mut replaced = 0;
+        match record {
+            ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } => {
+                // 문서 순회와 같은 규칙으로 사본을 가림 / Tell copies apart with the same rules as document traversal
+                let has_paragraphs = !paragraphs.is_empty();
+                let has_table = children
+                    .iter()
+                    .any(|child| matches!(child, ParagraphRecord::Table { .. }));
+                for child in children {
+                    let copy =
+                        has_paragraphs && matches!(child, ParagraphRecord::ListHeader { .. });
+                    replaced += self.record(child, counted && !copy);
+                }
+                let copy = header.ctrl_id == CtrlId::TABLE && has_table;
+                for paragraph in paragraphs {
+                    replaced += self.paragraph(paragraph, counted && !copy);
+                }
+            }
+            ParagraphRecord::ListHeader { paragraphs, .. } => {
+                for paragraph in paragraphs {
+                    replaced += self.paragraph(paragraph, counted);
+                }
+            }
+            ParagraphRecord::Table { table } => {
+                for cell in &mut table.cells {
+                    for paragraph in &mut cell.paragraphs {
+                        replaced += self.paragraph(paragraph, counted);
+                    }
+                }
+            }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                for child in children {
+                    replaced += self.record(child, counted);
+                }
+            }
+            _ => {}
+        }
+        replaced
+    }
+
+    /// ParaText 레코드 하나의 텍스트와 텍스트 조각 바꾸기 / Replace the text and runs of one ParaText record
+    ///
+    /// 컨트롤 사이에 이어진 텍스트 조각들을 한 덩어리로 찾고, 바뀐 덩어리는 텍스트 조각 하나로
+    /// 합칩니다. 텍스트 조각이 없거나 레코드 텍스트와 맞지 않으면 레코드 텍스트 전체를 한
+    /// 덩어리로 봅니다.
+    /// Consecutive text runs between controls are searched as one block, and a changed block is
+    /// merged into a single text run. When there are no runs or they do not match the record text,
+    /// the whole record text is one block.
+    fn para_text(&self, text: &mut String, runs: &mut Vec<ParaTextRun>, scan: &mut Scan) {
+        let run_text: String = runs
+            .iter()
+            .filter_map(|run| match run {
+                ParaTextRun::Text { text } => Some(text.as_str()),
+                ParaTextRun::Control { .. } => None,
+            })
+            .collect();
+        if runs.is_empty() || run_text != *text {
+            let replaced = self.block(text, scan, false);
+            scan.position += utf16_len(text);
+            scan.char_position += text.chars().count();
+            if let Some(replaced) = replaced {
+                *text = replaced;
+                // 맞지 않는 텍스트 조각은 따로 바꿈 / Runs that do not match are replaced on their own
+                if let Target::Text(pattern) = self.target {
+                    for run in runs.iter_mut() {
+                        if let ParaTextRun::Text { text } = run {
+                            *text = text.replace(pattern, self.replacement);
+                        }
+                    }
+                }
+            }
+            return;
+        }
+
+        let mut rebuilt = Vec::with_capacity(runs.len());
+        let mut pending: Vec<String> = Vec::new();
+        for run in runs.drain(..) {
+            match run {
+                ParaTextRun::Text { text } => pending.push(text),
+                ParaTextRun::Control {
+                    position: start,
+                    code,
+                    name,
+                    size_wchars,
+                    display_text,
+                } => {
+                    // 바꿀 필드의 끝이면 그 사이 텍스트 전체를 바꿈 (비어 있어도)
+                    // At the end of the field to replace, the whole text in between is replaced (even when empty)
+                    let whole = scan.in_field && code == FIELD_END;
+                    self.flush(&mut pending, &mut rebuilt, scan, whole);
+                    // 필드 안에 다른 컨트롤이 있으면 바꾸지 않음 / Fields holding other controls are not replaced
+                    scan.in_field = false;
+                    if code == FIELD_START {
+                        scan.in_field =
+                            matches!(self.target, Target::Field(index) if index == scan.fields);
+                        scan.fields += 1;
+                    }
+                    scan.position = start + size_wchars;
+                    rebuilt.push(ParaTextRun::Control {
+                        position: start,
+                        code,
+                        name,
+                        size_wchars,
+                        display_text,
+                    });
+                }
+            }
+        }
+        self.flush(&mut pending, &mut rebuilt, scan, false);
+        *runs = rebuilt;
+        *text = runs
+            .iter()
+            .filter_map(|run| match run {
+                ParaTextRun::Text { text } => Some(text.as_str()),
+                ParaTextRun::Control { .. } => None,
+            })
+            .collect();
+    }
+
+    /// 모아 둔 텍스트 조각을 바꿔서 내보냄 / Replace and emit the pending text runs
+    ///
+    /// `whole`이면 덩어리 전체를 바꿀 텍스트로 바꿉니다.
+    /// With `whole` the entire block is replaced.
+    fn flush(
+        &self,
+        pending: &mut Vec<String>,
+        rebuilt: &mut Vec<ParaTextRun>,
+        scan: &mut Scan,
+        whole: bool,
+    ) {
+        if pending.is_empty() && !whole {
+            return;
+        }
+        let block: String = pending.concat();
+        match self.block(&block, scan, whole) {
+            Some(replaced) => {
+                pending.clear();
+                if !replaced.is_empty() {
+                    rebuilt.push(ParaTextRun::Text { text: replaced });
+                }
+            }
+            None => rebuilt.extend(pending.drain(..).map(|text| ParaTextRun::Text { text })),
+        }
+        scan.position += utf16_len(&block);
+        scan.char_position += block.chars().count();
+    }
+
+    /// 텍스트 덩어리 하나 바꾸기 (바꾼 곳이 없으면 `None`) / Replace in one text block (`None` when nothing matched)
+    fn block(&self, block: &str, scan: &mut Scan, whole: bool) -> Option<String> {
+        let matches: Vec<(usize, &str)> = match self.target {
+            _ if whole => vec![(0, block)],
+            Target::Text(pattern) => block.match_indices(pattern).collect(),
+            Target::Chars { start, len } => {
+                // 빈 구간이 두 덩어리의 경계에 걸리면 앞 덩어리에서 한 번만 바꿈
+                // An empty range on the boundary of two blocks is replaced once, in the first block
+                let offset = start.wrapping_sub(scan.char_position);
+                let chars = block.chars().count();
+                if !scan.edits.is_empty() || start < scan.char_position || offset + len > chars {
+                    Vec::new()
+                } else {
+                    let from = byte_index(block, offset);
+                    let to = byte_index(block, offset + len);
+                    vec![(from, &block[from..to])]
+                }
+            }
+            Target::Field(_) => Vec::new(),
+        };
+        if matches.is_empty() {
+            return None;
+        }
+
+        let mut output = String::with_capacity(block.len());
+        let mut last = 0;
+        // 앞 구간까지의 원본 위치 / Original positions up to the previous match
+        let mut wchars = scan.position;
+        let mut chars = scan.char_position;
+        for (index, found) in matches {
+            let before = &block[last..index];
+            wchars += utf16_len(before);
+            chars += before.chars().count();
+            scan.edits.push(Edit {
+                start: wchars,
+                old_len: utf16_len(found),
+                new_len: utf16_len(self.replacement),
+                char_start: chars,
+                old_chars: found.chars().count(),
+                new_chars: self.replacement.chars().count(),
+            });
+            wchars += utf16_len(found);
+            chars += found.chars().count();
+            output.push_str(before);
+            output.push_str(self.replacement);
+            last = index + found.len();
+        }
+        output.push_str(&block[last..]);
+        Some(output)
+    }
+}
+
+/// 바꾼 구간에 맞춰 문단의 위치 정보 옮기기 / Move the paragraph's positions to follow the replaced ranges
+fn shift_positions(paragraph: &mut Paragraph, edits: &[Edit]) {
+    let map = |position: usize| map_position(edits, position);
+    for record in &mut paragraph.records {
+        match record {
+            ParagraphRecord::ParaText {
+                runs,
+                control_char_positions,
+                inline_control_params,
+                ..
+            } => {
+                for run in runs.iter_mut() {
+                    if let ParaTextRun::Control { position, .. } = run {
+                        *position = map(*position);
+                    }
+                }
+                for control in control_char_positions.iter_mut() {
+                    control.position = map(control.position);
+                }
+                for (position, _) in inline_control_params.iter_mut() {
+                    *position = map(*position);
+                }
+            }
+            ParagraphRecord::ParaCharShape { shapes } => {
+                // 바뀐 구간 안에서 시작하던 모양은 구간 끝으로 밀리므로, 같은 위치면 뒤의 것만 남김
+                // Shapes starting inside a replaced range move to its end, so keep only the later
+                // one at the same position
+                let mut moved: Vec<CharShapeInfo> = Vec::with_capacity(shapes.len());
+                for mut shape in shapes.drain(..) {
+                    shape.position = map(shape.position as usize) as u32;
+                    match moved.last_mut() {
+                        Some(last) if last.position == shape.position => *last = shape,
+                        _ => moved.push(shape),
+                    }
+                }
+                *shapes = moved;
+            }
+            ParagraphRecord::ParaLineSeg { segments } => {
+                for segment in segments.iter_mut() {
+                    segment.text_start_position = map(segment.text_start_position as usize) as u32;
+                }
+            }
+            ParagraphRecord::ParaRangeTag { tags } => {
+                for tag in tags.iter_mut() {
+                    tag.start = map(tag.start as usize) as u32;
+                    tag.end = map(tag.end as usize) as u32;
+                }
+            }
+            ParagraphRecord::HwpxField { offset, .. } => {
+                *offset = map_char_offset(edits, *offset);
+            }
+            _ => {}
+        }
+    }
+
+    let delta: i64 = edits
+        .iter()
+        .map(|edit| edit.new_len as i64 - edit.old_len as i64)
+        .sum();
+    let count = &mut paragraph.para_header.text_char_count;
+    *count = (*count as i64 + delta).max(0) as u32;
+}
+
+/// 원본 WCHAR 위치를 바꾼 뒤 위치로 (바뀐 구간 안쪽은 구간 끝으로)
+/// Map an original WCHAR position to the edited text (positions inside a replaced range go to its end)
+///
+/// 빈 구간에 넣은 경우(빈 필드 채우기) 그 자리의 컨트롤과 모양은 넣은 텍스트 뒤로 밀립니다.
+/// For an insertion into an empty range (filling an empty field) the controls and shapes at that
+/// position move after the inserted text.
+fn map_position(edits: &[Edit], position: usize) -> usize {
+    let mut shift = 0i64;
+    for edit in edits {
+        if position < edit.start || (position == edit.start && edit.old_len > 0) {
+            break;
+        }
+        if position < edit.start + edit.old_len {
+            return (edit.start as i64 + shift) as usize + edit.new_len;
+        }
+        shift += edit.new_len as i64 - edit.old_len as i64;
+    }
+    (position as i64 + shift) as usize
+}
+
+/// 원본 문자 위치를 바꾼 뒤 위치로 / Map an original character offset to the edited text
+fn map_char_offset(edits: &[Edit], offset: usize) -> usize {
+    let mut shift = 0i64;
+    for edit in edits {
+        if offset <= edit.char_start {
+            break;
+        }
+        if offset < edit.char_start + edit.old_chars {
+            return (edit.char_start as i64 + shift) as usize + edit.new_chars;
+        }
+        shift += edit.new_chars as i64 - edit.old_chars as i64;
+    }
+    (offset as i64 + shift) as usize
+}
+
+/// 문자 위치의 바이트 인덱스 (끝을 넘으면 길이) / Byte index of a character offset (the length when past the end)
+fn byte_index(text: &str, offset: usize) -> usize {
+    text.char_indices()
+        .nth(offset)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len())
+}
+
+/// UTF-16 코드 단위 수 / Number of UTF-16 code units
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::ParaHeader;
+    use crate::testing::fixtures::document_with_paragraphs;
+
+    fn document(paragraph: Paragraph) -> HwpDocument {
+        document_with_paragraphs(vec![paragraph])
+    }
+
+    fn control(position: usize) -> ParaTextRun {
+        ParaTextRun::Control {
+            position,
+            code: 11,
+            name: "SHAPE_OBJECT".to_string(),
+            size_wchars: 8,
+            display_text: None,
+        }
+    }
+
+    #[test]
+    fn test_replace_text_shifts_positions() {
+        // "{{이름}}님 " [표 컨트롤 8 WCHAR] "{{이름}}" / "{{name}}님 " [table control, 8 WCHARs] "{{name}}"
+        let paragraph = Paragraph {
+            para_header: ParaHeader {
+                text_char_count: 23,
+                ..Default::default()
+            },
+            records: vec![
+                ParagraphRecord::ParaText {
+                    text: "{{이름}}님 {{이름}}".to_string(),
+                    runs: vec![
+                        ParaTextRun::Text {
+                            text: "{{이름}}님 ".to_string(),
+                        },
+                        control(8),
+                        ParaTextRun::Text {
+                            text: "{{이름}}".to_string(),
+                        },
+                    ],
+                    control_char_positions: Vec::new(),
+                    inline_control_params: Vec::new(),
+                },
+                ParagraphRecord::ParaCharShape {
+                    shapes: vec![
+                        CharShapeInfo {
+                            position: 0,
+                            shape_id: 1,
+                        },
+                        // 바꿀 텍스트 안에서 바뀌는 모양 / Shape changing inside the replaced text
+                        CharShapeInfo {
+                            position: 3,
+                            shape_id: 2,
+                        },
+                        CharShapeInfo {
+                            position: 6,
+                            shape_id: 3,
+                        },
+                        CharShapeInfo {
+                            position: 16,
+                            shape_id: 4,
+                        },
+                    ],
+                },
+            ],
+            resolved_number: None,
+        };
+        let mut document = document(paragraph);
+
+        assert_eq!(document.replace_text("{{이름}}", "홍길동"), 2);
+        assert_eq!(document.replace_text("", "x"), 0);
+
+        let paragraph = &document.body_text.sections[0].paragraphs[0];
+        assert_eq!(paragraph.text(), "홍길동님 홍길동");
+        assert_eq!(paragraph.para_header.text_char_count, 17);
+        let ParagraphRecord::ParaText { runs, .. } = &paragraph.records[0] else {
+            panic!("expected ParaText");
+        };
+        assert!(matches!(&runs[1], ParaTextRun::Control { position: 5, .. }));
+        let ParagraphRecord::ParaCharShape { shapes } = &paragraph.records[1] else {
+            panic!("expected ParaCharShape");
+        };
+        let positions: Vec<(u32, u32)> = shapes
+            .iter()
+            .map(|shape| (shape.position, shape.shape_id))
+            .collect();
+        assert_eq!(positions, vec![(0, 1), (3, 3), (13, 4)]);
+    }
+
+    #[test]
+    fn test_replace_text_does_not_cross_controls() {
+        let paragraph = Paragraph {
+            para_header: ParaHeader {
+                text_char_count: 10,
+                ..Default::default()
+            },
+            records: vec![ParagraphRecord::ParaText {
+                text: "ab".to_string(),
+                runs: vec![
+                    ParaTextRun::Text {
+                        text: "a".to_string(),
+                    },
+                    control(1),
+                    ParaTextRun::Text {
+                        text: "b".to_string(),
+                    },
+                ],
+                control_char_positions: Vec::new(),
+                inline_control_params: Vec::new(),
+            }],
+            resolved_number: None,
+        };
+        let mut document = document(paragraph);
+        assert_eq!(document.replace_text("ab", "x"), 0);
+        assert_eq!(document.replace_text("b", "bc"), 1);
+        assert_eq!(
+            document.body_text.sections[0].paragraphs[0].text(),
+            "abc".to_string()
+        );
+    }
+
+    #[test]
+    fn test_replace_field_text() {
+        let field = |code, position| ParaTextRun::Control {
+            position,
+            code,
+            name: String::new(),
+            size_wchars: 8,
+            display_text: None,
+        };
+        // "이름: " [필드 시작] "여기" [필드 끝] ", 부서: " [필드 시작] [필드 끝]
+        // "이름: " [field start] "여기" [field end] ", 부서: " [field start] [field end]
+        let mut paragraph = Paragraph {
+            para_header: ParaHeader {
+                text_char_count: 49,
+                ..Default::default()
+            },
+            records: vec![ParagraphRecord::ParaText {
+                text: "이름: 여기, 부서: ".to_string(),
+                runs: vec![
+                    ParaTextRun::Text {
+                        text: "이름: ".to_string(),
+                    },
+                    field(FIELD_START, 4),
+                    ParaTextRun::Text {
+                        text: "여기".to_string(),
+                    },
+                    field(FIELD_END, 14),
+                    ParaTextRun::Text {
+                        text: ", 부서: ".to_string(),
+                    },
+                    field(FIELD_START, 28),
+                    field(FIELD_END, 36),
+                ],
+                control_char_positions: Vec::new(),
+                inline_control_params: Vec::new(),
+            }],
+            resolved_number: None,
+        };
+
+        assert_eq!(
+            replace_in_paragraph(&mut paragraph, Target::Field(1), "인사팀"),
+            1
+        );
+        assert_eq!(
+            replace_in_paragraph(&mut paragraph, Target::Field(0), "홍길동"),
+            1
+        );
+        assert_eq!(paragraph.text(), "이름: 홍길동, 부서: 인사팀");
+        assert_eq!(paragraph.para_header.text_char_count, 53);
+        let ParagraphRecord::ParaText { runs, .. } = &paragraph.records[0] else {
+            panic!("expected ParaText");
+        };
+        let positions: Vec<usize> = runs
+            .iter()
+            .filter_map(|run| match run {
+                ParaTextRun::Control { position, .. } => Some(*position),
+                ParaTextRun::Text { .. } => None,
+            })
+            .collect();
+        assert_eq!(positions, vec![4, 15, 29, 40]);
+    }
+}
@@ -0,0 +1,258 @@
+/// 문단 스타일 해석 모듈 / Paragraph style resolution module
+///
+/// 문단의 스타일, 문단 모양(ParaShape), 글자 모양(CharShape) ID를 따라가
+/// 뷰어와 외부 소비자가 바로 쓸 수 있는 구체적인 값으로 합칩니다.
+/// Follows a paragraph's style, ParaShape and CharShape IDs and merges them into
+/// concrete values that viewers and external consumers can use directly.
+use super::HwpDocument;
+use crate::document::bodytext::{Paragraph, ParagraphRecord};
+use crate::document::docinfo::para_shape::{
+    LineSpacingType, LineSpacingTypeOld, ParagraphAlignment,
+};
+use crate::document::{CharShape, DocInfo, ParaShape, Style};
+use serde::{Deserialize, Serialize};
+
+/// 해석된 문단 스타일 / Resolved paragraph style
+///
+/// 길이 값은 HWPUNIT(1/7200 인치) 단위입니다. / Lengths are in HWPUNIT (1/7200 inch).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedStyle {
+    /// 스타일 이름 (로컬 이름) / Style name (local name)
+    pub style_name: Option<String>,
+    /// 글꼴 이름 (한글 글꼴) / Font name (Korean font)
+    pub font_name: Option<String>,
+    /// 글자 크기 (pt) / Font size (pt)
+    pub font_size: Option<f64>,
+    /// 진하게 / Bold
+    pub bold: bool,
+    /// 기울임 / Italic
+    pub italic: bool,
+    /// 밑줄 / Underline
+    pub underline: bool,
+    /// 정렬 / Alignment
+    pub alignment: ParagraphAlignment,
+    /// 줄 간격 종류 / Line spacing type
+    pub line_spacing_type: LineSpacingType,
+    /// 줄 간격 (글자에 따라이면 %, 그 외 HWPUNIT) / Line spacing (% for by-character, otherwise HWPUNIT)
+    pub line_spacing: i32,
+    /// 문단 위 간격 / Spacing before paragraph
+    pub spacing_before: i32,
+    /// 문단 아래 간격 / Spacing after paragraph
+    pub spacing_after: i32,
+    /// 왼쪽 여백 / Left margin
+    pub left_margin: i32,
+    /// 오른쪽 여백 / Right margin
+    pub right_margin: i32,
+    /// 들여쓰기 / Indent
+    pub indent: i32,
+}
+
+impl Default for ResolvedStyle {
+    fn default() -> Self {
+        Self {
+            style_name: None,
+            font_name: None,
+            font_size: None,
+            bold: false,
+            italic: false,
+            underline: false,
+            alignment: ParagraphAlignment::Justify,
+            line_spacing_type: LineSpacingType::ByCharacter,
+            line_spacing: 160,
+            spacing_before: 0,
+            spacing_after: 0,
+            left_margin: 0,
+            right_margin: 0,
+            indent: 0,
+        }
+    }
+}
+
+impl HwpDocument {
+    /// 문단의 스타일을 구체적인 값으로 해석 / Resolve a paragraph's style into concrete values
+    ///
+    /// 문단 머리의 문단 모양 ID와 첫 글자 모양을 우선 사용하고, 없거나 범위를 벗어나면
+    /// 문단 스타일이 가리키는 모양으로 대체합니다.
+    /// Prefers the paragraph header's ParaShape ID and the first character shape run,
+    /// falling back to the shapes referenced by the paragraph style when missing or out of range.
+    ///
+    /// # Arguments / 매개변수
+    /// * `paragraph` - 해석할 문단 / Paragraph to resolve
+    ///
+    /// # Returns / 반환값
+    /// 해석된 스타일 / Resolved style
+    pub fn resolved_style(&self, paragraph: &Paragraph) -> ResolvedStyle {
+        let doc_info = &self.doc_info;
+        let style: Option<&Style> = doc_info
+            .styles
+            .get(paragraph.para_header.para_style_id as usize);
+
+        let para_shape: Option<&ParaShape> = doc_info
+            .para_shapes
+            .get(paragraph.para_header.para_shape_id as usize)
+            .or_else(|| {
+                style
+                    .and_then(|s| s.para_shape_id)
+                    .and_then(|id| doc_info.para_shapes.get(id as usize))
+            });
+
+        let char_shape: Option<&CharShape> = first_char_shape_id(paragraph)
+            .and_then(|id| doc_info.char_shapes.get(id as usize))
+            .or_else(|| {
+                style
+                    .and_then(|s| s.char_shape_id)
+                    .and_then(|id| doc_info.char_shapes.get(id as usize))
+            });
+
+        ResolvedStyle::from_shapes(
+            doc_info,
+            style.map(|s| s.local_name.clone()),
+            para_shape,
+            char_shape,
+        )
+    }
+}
+
+impl ResolvedStyle {
+    /// 문단 모양과 글자 모양을 합쳐 만들기 (없는 모양은 기본값) / Build by merging a ParaShape and a CharShape (defaults for missing shapes)
+    pub(crate) fn from_shapes(
+        doc_info: &DocInfo,
+        style_name: Option<String>,
+        para_shape: Option<&ParaShape>,
+        char_shape: Option<&CharShape>,
+    ) -> Self {
+        let mut resolved = ResolvedStyle {
+            style_name,
+            ..Default::default()
+        };
+
+        if let Some(char_shape) = char_shape {
+            // HWP 파일의 font_id는 0-based indexing을 사용합니다 / HWP file uses 0-based indexing for font_id
+            resolved.font_name = doc_info
+                .face_names
+                .get(char_shape.font_ids.korean as usize)
+                .map(|f| f.name.clone());
+            resolved.font_size = Some(char_shape.base_size as f64 / 100.0);
+            resolved.bold = char_shape.attributes.bold;
+            resolved.italic = char_shape.attributes.italic;
+            resolved.underline = char_shape.attributes.underline_type != 0;
+        }
+
+        if let Some(para_shape) = para_shape {
+            resolved.alignment = para_shape.attributes1.align;
+            // 5.0.2.5 이상은 속성3의 줄 간격을 사용 / 5.0.2.5+ uses the attributes3 line spacing
+            match (&para_shape.attributes3, para_shape.line_spacing) {
+                (Some(attributes3), Some(line_spacing)) => {
+                    resolved.line_spacing_type = attributes3.line_spacing_type;
+                    resolved.line_spacing = line_spacing;
+                }
+                _ => {
+                    resolved.line_spacing_type = match para_shape.attributes1.line_spacing_type_old
+                    {
+                        LineSpacingTypeOld::ByCharacter => LineSpacingType::ByCharacter,
+                        LineSpacingTypeOld::Fixed => LineSpacingType::Fixed,
+                        LineSpacingTypeOld::MarginOnly => LineSpacingType::MarginOnly,
+                    };
+                    resolved.line_spacing = para_shape.line_spacing_old;
+                }
+            }
+            resolved.spacing_before = para_shape.top_spacing;
+            resolved.spacing_after = para_shape.bottom_spacing;
+            resolved.left_margin = para_shape.left_margin;
+            resolved.right_margin = para_shape.right_margin;
+            resolved.indent = para_shape.indent;
+        }
+
+        resolved
+    }
+}
+
+/// 문단 시작 위치의 글자 모양 ID / Character shape ID at the start of the paragraph
+fn first_char_shape_id(paragraph: &Paragraph) -> Option<u32> {
+    paragraph.records.iter().find_map(|record| match record {
+        ParagraphRecord::ParaCharShape { shapes } => shapes
+            .iter()
+            .filter(|s| s.position == 0)
+            .map(|s| s.shape_id)
+            .next()
+            .or_else(|| shapes.first().map(|s| s.shape_id)),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::CharShapeInfo;
+    use crate::document::docinfo::style::StyleType;
+    use crate::document::FaceName;
+    use crate::testing::fixtures::empty_document;
+
+    fn document() -> HwpDocument {
+        let mut document = empty_document();
+        let doc_info = &mut document.doc_info;
+        doc_info.face_names.push(FaceName {
+            name: "함초롬바탕".to_string(),
+            alternative_font_type: None,
+            alternative_font_name: None,
+            font_type_info: None,
+            default_font_name: None,
+        });
+
+        let mut body = CharShape::parse(&[0u8; 72], 0).unwrap();
+        body.base_size = 1000;
+        let mut heading = body.clone();
+        heading.base_size = 1600;
+        heading.attributes.bold = true;
+        doc_info.char_shapes = vec![body, heading];
+
+        // 속성1 bit 2-4 = 3 (가운데) / attributes1 bits 2-4 = 3 (center)
+        let mut data = [0u8; 54];
+        data[0] = 3 << 2;
+        doc_info.para_shapes = vec![
+            ParaShape::parse(&[0u8; 54], 0x05010000).unwrap(),
+            ParaShape::parse(&data, 0x05010000).unwrap(),
+        ];
+
+        doc_info.styles.push(Style {
+            local_name: "바탕글".to_string(),
+            english_name: "Normal".to_string(),
+            style_type: StyleType::Paragraph,
+            next_style_id: 0,
+            lang_id: 0,
+            para_shape_id: Some(1),
+            char_shape_id: Some(1),
+        });
+        document
+    }
+
+    #[test]
+    fn test_resolved_style_prefers_paragraph_shapes() {
+        let doc = document();
+        let mut paragraph = Paragraph {
+            para_header: Default::default(),
+            records: vec![ParagraphRecord::ParaCharShape {
+                shapes: vec![CharShapeInfo {
+                    position: 0,
+                    shape_id: 0,
+                }],
+            }],
+            resolved_number: None,
+        };
+
+        let resolved = doc.resolved_style(&paragraph);
+        assert_eq!(resolved.style_name.as_deref(), Some("바탕글"));
+        assert_eq!(resolved.font_name.as_deref(), Some("함초롬바탕"));
+        assert_eq!(resolved.font_size, Some(10.0));
+        assert!(!resolved.bold);
+        assert_eq!(resolved.alignment, ParagraphAlignment::Justify);
+
+        // 범위를 벗어난 ID는 스타일이 가리키는 모양으로 대체 / Out-of-range IDs fall back to the style's shapes
+        paragraph.para_header.para_shape_id = 9;
+        paragraph.records.clear();
+        let resolved = doc.resolved_style(&paragraph);
+        assert_eq!(resolved.font_size, Some(16.0));
+        assert!(resolved.bold);
+        assert_eq!(resolved.alignment, ParagraphAlignment::Center);
+    }
+}
@@ -0,0 +1,377 @@
+/// 문자 체계 분리 모듈 / Script segmentation module
+///
+/// 문단 텍스트를 문자 체계(한글, 라틴, 한자, 가나, 숫자)별 구간으로 나누고, 구간마다 글자 모양의
+/// 언어별 글꼴 슬롯에서 글꼴을 찾습니다. 문서 전체의 문자 체계 비율로 OCR/번역 파이프라인을 고를 수
+/// 있습니다.
+///
+/// Splits paragraph text into runs by script (Hangul, Latin, Han, Kana, digits) and looks up
+/// each run's font in the char shape's per-language font slot. The per-document script
+/// proportions can be used to route documents to an OCR/translation pipeline.
+use std::collections::BTreeMap;
+
+use super::HwpDocument;
+use crate::document::bodytext::{ParaTextRun, Paragraph, ParagraphRecord};
+use crate::document::docinfo::char_shape::LanguageFontInfo;
+use crate::types::{UINT32, WORD};
+use serde::{Deserialize, Serialize};
+
+/// 문자 체계 / Script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Script {
+    /// 한글 (음절, 자모) / Hangul (syllables, jamo)
+    Hangul,
+    /// 라틴 문자 / Latin letters
+    Latin,
+    /// 한자 / Han (CJK ideographs)
+    Han,
+    /// 히라가나, 가타카나 / Hiragana, katakana
+    Kana,
+    /// 숫자 / Digits
+    Digit,
+    /// 그 밖의 문자 (키릴, 그리스 등) / Other letters (Cyrillic, Greek, ...)
+    Other,
+    /// 공백, 문장 부호, 기호 / Whitespace, punctuation and symbols
+    Common,
+}
+
+impl Script {
+    /// 문자의 문자 체계 / Script of a character
+    pub fn of(c: char) -> Self {
+        match c {
+            '\u{AC00}'..='\u{D7A3}'
+            | '\u{1100}'..='\u{11FF}'
+            | '\u{3130}'..='\u{318F}'
+            | '\u{A960}'..='\u{A97F}'
+            | '\u{D7B0}'..='\u{D7FF}' => Script::Hangul,
+            '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3FFFF}' => Script::Han,
+            '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => {
+                Script::Kana
+            }
+            '0'..='9' | '\u{FF10}'..='\u{FF19}' => Script::Digit,
+            'A'..='Z'
+            | 'a'..='z'
+            | '\u{00C0}'..='\u{024F}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}' => match c {
+                '\u{00D7}' | '\u{00F7}' => Script::Common,
+                _ => Script::Latin,
+            },
+            _ if c.is_alphanumeric() => Script::Other,
+            _ => Script::Common,
+        }
+    }
+
+    /// 글자 모양에서 이 문자 체계에 쓰는 글꼴 ID / Font ID a char shape uses for this script
+    ///
+    /// 숫자와 공통 문자는 한/글처럼 영문 글꼴을 씁니다.
+    /// Digits and common characters use the English font, as Hangul does.
+    pub fn font_id(self, fonts: &LanguageFontInfo) -> WORD {
+        match self {
+            Script::Hangul => fonts.korean,
+            Script::Latin | Script::Digit | Script::Common => fonts.english,
+            Script::Han => fonts.chinese,
+            Script::Kana => fonts.japanese,
+            Script::Other => fonts.other,
+        }
+    }
+}
+
+/// 같은 문자 체계와 글자 모양의 텍스트 구간 / Run of text with one script and char shape
+///
+/// 공통 문자는 앞 구간(문단 처음이면 뒤 구간)에 붙습니다.
+/// Common characters join the preceding run (or the following one at the start of a paragraph).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptSegment {
+    /// 문자 체계 / Script
+    pub script: Script,
+    /// 텍스트 / Text
+    pub text: String,
+    /// 글자 모양 ID / Char shape ID
+    pub char_shape_id: Option<UINT32>,
+    /// 글자 모양의 언어별 글꼴 슬롯에서 찾은 글꼴 이름 / Font name from the char shape's per-language font slot
+    pub font_name: Option<String>,
+}
+
+/// 문서의 문자 체계별 글자 수 / Per-script character counts of a document
+///
+/// 공통 문자(공백, 문장 부호, 기호)는 세지 않습니다.
+/// Common characters (whitespace, punctuation, symbols) are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageProfile {
+    /// 문자 체계별 글자 수 / Character count per script
+    pub counts: BTreeMap<Script, usize>,
+}
+
+impl LanguageProfile {
+    /// 센 글자 수 합계 / Total number of counted characters
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// 문자 체계의 비율 (0.0~1.0) / Proportion of a script (0.0 to 1.0)
+    pub fn proportion(&self, script: Script) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.counts.get(&script).copied().unwrap_or(0) as f64 / total as f64,
+        }
+    }
+
+    /// 가장 많은 문자 체계 (숫자 제외) / Most frequent script, ignoring digits
+    pub fn dominant(&self) -> Option<Script> {
+        self.counts
+            .iter()
+            .filter(|(script, count)| **script != Script::Digit && **count > 0)
+            .max_by_key(|(_, count)| **count)
+            .map(|(script, _)| *script)
+    }
+}
+
+/// 텍스트를 문자 체계별 구간으로 나눔 / Split text into runs by script
+///
+/// # Arguments / 매개변수
+/// * `text` - 나눌 텍스트 / Text to split
+///
+/// # Returns / 반환값
+/// 글자 모양 정보가 없는 구간 목록 / Runs without char shape information
+pub fn segment_scripts(text: &str) -> Vec<ScriptSegment> {
+    let mut segmenter = Segmenter::default();
+    for c in text.chars() {
+        segmenter.push(c, None);
+    }
+    segmenter.finish()
+}
+
+impl HwpDocument {
+    /// 문단 텍스트를 문자 체계와 글자 모양별 구간으로 나눔 / Split paragraph text into runs by script and char shape
+    ///
+    /// 표 셀 등 중첩 문단의 텍스트는 포함하지 않습니다.
+    /// Text of nested paragraphs (table cells, ...) is not included.
+    ///
+    /// # Arguments / 매개변수
+    /// * `paragraph` - 나눌 문단 / Paragraph to split
+    ///
+    /// # Returns / 반환값
+    /// 구간마다 글자 모양의 언어별 글꼴 슬롯에서 찾은 글꼴 이름을 담은 목록
+    /// Runs, each with the font name from its char shape's per-language font slot
+    pub fn script_segments(&self, paragraph: &Paragraph) -> Vec<ScriptSegment> {
+        let shapes: Vec<_> = paragraph
+            .records
+            .iter()
+            .filter_map(|record| match record {
+                ParagraphRecord::ParaCharShape { shapes } => Some(shapes),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let shape_at = |position: UINT32| {
+            shapes
+                .iter()
+                .take_while(|shape| shape.position <= position)
+                .last()
+                .map(|shape| shape.shape_id)
+        };
+
+        let mut segmenter = Segmenter::default();
+        for_each_char_position(paragraph, |c, position| {
+            segmenter.push(c, shape_at(position));
+        });
+        let mut segments = segmenter.finish();
+        for segment in &mut segments {
+            segment.font_name = segment
+                .char_shape_id
+                .and_then(|id| self.doc_info.char_shapes.get(id as usize))
+                .and_then(|shape| {
+                    self.doc_info
+                        .face_names
+                        .get(segment.script.font_id(&shape.font_ids) as usize)
+                })
+                .map(|face| face.name.clone());
+        }
+        segments
+    }
+
+    /// 문서 전체의 문자 체계별 글자 수 / Per-script character counts of the whole document
+    ///
+    /// 본문과 표 셀, 머리말 등 중첩 문단의 텍스트를 모두 셉니다.
+    /// Counts the text of body paragraphs and nested paragraphs (table cells, headers, ...).
+    pub fn language_profile(&self) -> LanguageProfile {
+        let mut profile = LanguageProfile::default();
+        for section in &self.body_text.sections {
+            for paragraph in &section.paragraphs {
+                for paragraph in std::iter::once(paragraph).chain(paragraph.nested_paragraphs()) {
+                    for c in paragraph.text().chars() {
+                        let script = Script::of(c);
+                        if script != Script::Common {
+                            *profile.counts.entry(script).or_default() += 1;
+                        }
+                    }
+                }
+            }
+        }
+        profile
+    }
+}
+
+/// 문단의 글자와 원본 WCHAR 위치 (글자 모양 위치와 같은 기준) / Paragraph characters with their original WCHAR position (same basis as char shape positions)
+fn for_each_char_position(paragraph: &Paragraph, mut f: impl FnMut(char, UINT32)) {
+    let mut position = 0usize;
+    for record in &paragraph.records {
+        let ParagraphRecord::ParaText { text, runs, .. } = record else {
+            continue;
+        };
+        if runs.is_empty() {
+            for c in text.chars() {
+                f(c, position as UINT32);
+                position += c.len_utf16();
+            }
+            continue;
+        }
+        for run in runs {
+            match run {
+                ParaTextRun::Text { text } => {
+                    for c in text.chars() {
+                        f(c, position as UINT32);
+                        position += c.len_utf16();
+                    }
+                }
+                ParaTextRun::Control {
+                    position: start,
+                    size_wchars,
+                    ..
+                } => position = start + size_wchars,
+            }
+        }
+    }
+}
+
+/// 구간 나누기 상태 / Segmentation state
+#[derive(Default)]
+struct Segmenter {
+    segments: Vec<ScriptSegment>,
+    /// 첫 구간 앞의 공통 문자 / Common characters before the first run
+    leading: String,
+}
+
+impl Segmenter {
+    fn push(&mut self, c: char, char_shape_id: Option<UINT32>) {
+        let script = Script::of(c);
+        if let Some(last) = self.segments.last_mut() {
+            if last.char_shape_id == char_shape_id
+                && (script == Script::Common || script == last.script)
+            {
+                last.text.push(c);
+                return;
+            }
+            if last.script == Script::Common && last.char_shape_id == char_shape_id {
+                last.script = script;
+                last.text.push(c);
+                return;
+            }
+        } else if script == Script::Common {
+            self.leading.push(c);
+            return;
+        }
+        let mut text = std::mem::take(&mut self.leading);
+        text.push(c);
+        self.segments.push(ScriptSegment {
+            script,
+            text,
+            char_shape_id,
+            font_name: None,
+        });
+    }
+
+    fn finish(mut self) -> Vec<ScriptSegment> {
+        if !self.leading.is_empty() {
+            self.segments.push(ScriptSegment {
+                script: Script::Common,
+                text: self.leading,
+                char_shape_id: None,
+                font_name: None,
+            });
+        }
+        self.segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{DocumentBuilder, ParagraphStyle};
+    use crate::document::FaceName;
+
+    fn scripts(text: &str) -> Vec<(Script, String)> {
+        segment_scripts(text)
+            .into_iter()
+            .map(|segment| (segment.script, segment.text))
+            .collect()
+    }
+
+    #[test]
+    fn test_segment_scripts() {
+        assert_eq!(
+            scripts("(한글) HWP 文書 2024년"),
+            [
+                (Script::Hangul, "(한글) ".to_string()),
+                (Script::Latin, "HWP ".to_string()),
+                (Script::Han, "文書 ".to_string()),
+                (Script::Digit, "2024".to_string()),
+                (Script::Hangul, "년".to_string()),
+            ]
+        );
+        assert_eq!(scripts("..."), [(Script::Common, "...".to_string())]);
+        assert_eq!(Script::of('カ'), Script::Kana);
+        assert_eq!(Script::of('Ж'), Script::Other);
+    }
+
+    #[test]
+    fn test_script_segments_use_language_font_slots() {
+        let mut document = DocumentBuilder::new()
+            .add_paragraph("표준 API", &ParagraphStyle::default())
+            .build();
+        let paragraph = document.body_text.sections[0].paragraphs[0].clone();
+        let shape_id = paragraph
+            .records
+            .iter()
+            .find_map(|record| match record {
+                ParagraphRecord::ParaCharShape { shapes } => Some(shapes[0].shape_id),
+                _ => None,
+            })
+            .unwrap_or(0);
+        let doc_info = &mut document.doc_info;
+        doc_info.face_names = ["함초롬바탕", "Arial"]
+            .map(|name| FaceName {
+                name: name.to_string(),
+                alternative_font_type: None,
+                alternative_font_name: None,
+                font_type_info: None,
+                default_font_name: None,
+            })
+            .to_vec();
+        let fonts = &mut doc_info.char_shapes[shape_id as usize].font_ids;
+        (fonts.korean, fonts.english) = (0, 1);
+
+        let segments = document.script_segments(&paragraph);
+        let fonts: Vec<_> = segments
+            .iter()
+            .map(|segment| (segment.script, segment.font_name.as_deref()))
+            .collect();
+        assert_eq!(
+            fonts,
+            [
+                (Script::Hangul, Some("함초롬바탕")),
+                (Script::Latin, Some("Arial"))
+            ]
+        );
+
+        let profile = document.language_profile();
+        assert_eq!(profile.total(), 5);
+        assert_eq!(profile.dominant(), Some(Script::Latin));
+        assert!((profile.proportion(Script::Hangul) - 0.4).abs() < 1e-9);
+    }
+}
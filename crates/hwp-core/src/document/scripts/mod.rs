@@ -10,6 +10,8 @@ pub use script::Script;
 pub use script_version::ScriptVersion;
 
 use crate::cfb::CfbParser;
+use crate::decompress::decompress_deflate;
+use crate::document::FileHeader;
 use crate::error::HwpError;
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +31,7 @@ impl Scripts {
     ///
     /// # Arguments
     /// * `cfb` - CompoundFile structure (mutable reference required) / CompoundFile 구조체 (가변 참조 필요)
+    /// * `file_header` - FileHeader (압축 여부 확인용) / FileHeader (to check compression)
     ///
     /// # Returns
     /// Parsed Scripts structure / 파싱된 Scripts 구조체
@@ -40,14 +43,26 @@ impl Scripts {
     /// According to spec 3.2.9, Scripts storage contains the following streams:
     /// - JScriptVersion: Script version (Table 8)
     /// - DefaultJScript: Script content (Table 9)
-    pub fn parse(cfb: &mut cfb::CompoundFile<std::io::Cursor<&[u8]>>) -> Result<Self, HwpError> {
+    pub fn parse(
+        cfb: &mut cfb::CompoundFile<std::io::Cursor<&[u8]>>,
+        file_header: &FileHeader,
+    ) -> Result<Self, HwpError> {
         let mut scripts = Scripts::default();
+        // 압축 문서는 스크립트 스트림도 raw deflate로 압축됨 / Compressed documents also deflate the script streams
+        let read = |cfb: &mut cfb::CompoundFile<std::io::Cursor<&[u8]>>, stream: &str| {
+            let data = CfbParser::read_nested_stream(cfb, "Scripts", stream)?;
+            if file_header.is_compressed() {
+                decompress_deflate(&data)
+            } else {
+                Ok(data)
+            }
+        };
 
         // Parse JScriptVersion stream
         // JScriptVersion 스트림 파싱 / Parse JScriptVersion stream
         // 스펙 문서 표 8: 스크립트 버전은 8바이트 (DWORD HIGH + DWORD LOW)
         // Spec Table 8: Script version is 8 bytes (DWORD HIGH + DWORD LOW)
-        if let Ok(version_data) = CfbParser::read_nested_stream(cfb, "Scripts", "JScriptVersion") {
+        if let Ok(version_data) = read(cfb, "JScriptVersion") {
             match ScriptVersion::parse(&version_data) {
                 Ok(version) => {
                     scripts.version = Some(version);
@@ -63,7 +78,7 @@ impl Scripts {
         // DefaultJScript 스트림 파싱 / Parse DefaultJScript stream
         // 스펙 문서 표 9: 스크립트 내용은 가변 길이
         // Spec Table 9: Script content is variable length
-        if let Ok(script_data) = CfbParser::read_nested_stream(cfb, "Scripts", "DefaultJScript") {
+        if let Ok(script_data) = read(cfb, "DefaultJScript") {
             match Script::parse(&script_data) {
                 Ok(default_script) => {
                     scripts.default_script = Some(default_script);
@@ -0,0 +1,310 @@
+/// 문서 텍스트 검색 모듈 / Document text search module
+///
+/// 검색 결과를 구역/문단 위치와 문자 오프셋으로 돌려주어 색인 파이프라인에서
+/// 원본 문서 위치로 되돌아갈 수 있게 합니다.
+/// Returns hits with section/paragraph location and character offsets so indexing
+/// pipelines can map matches back to document locations.
+use super::{HwpDocument, ParagraphLocation};
+use crate::error::HwpError;
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+
+/// 검색 옵션 / Search options
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOptions {
+    /// 대소문자 무시 / Ignore case
+    pub case_insensitive: bool,
+    /// 검색어를 정규식으로 해석 / Interpret query as regular expression
+    pub regex: bool,
+    /// 앞뒤 문맥 길이 (문자 수) / Surrounding context length (in characters)
+    pub context_chars: usize,
+    /// 표 셀/머리말/각주 등 중첩 문단도 검색 / Also search nested paragraphs (table cells, headers, footnotes, etc.)
+    pub include_nested: bool,
+    /// 최대 결과 수 / Maximum number of hits
+    pub max_hits: Option<usize>,
+    /// 차트 제목, 축 제목, 범례, 항목 이름도 검색 / Also search chart titles, axis titles, legends and category labels
+    pub include_charts: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            case_insensitive: false,
+            regex: false,
+            context_chars: 30,
+            include_nested: true,
+            max_hits: None,
+            include_charts: false,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// 대소문자 무시 설정 / Set case-insensitive matching
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// 정규식 모드 설정 / Set regex mode
+    pub fn with_regex(mut self, regex: bool) -> Self {
+        self.regex = regex;
+        self
+    }
+
+    /// 문맥 길이 설정 / Set context length
+    pub fn with_context_chars(mut self, context_chars: usize) -> Self {
+        self.context_chars = context_chars;
+        self
+    }
+
+    /// 중첩 문단 검색 여부 설정 / Set whether nested paragraphs are searched
+    pub fn with_include_nested(mut self, include_nested: bool) -> Self {
+        self.include_nested = include_nested;
+        self
+    }
+
+    /// 최대 결과 수 설정 / Set maximum number of hits
+    pub fn with_max_hits(mut self, max_hits: usize) -> Self {
+        self.max_hits = Some(max_hits);
+        self
+    }
+
+    /// 차트 텍스트 검색 여부 설정 / Set whether chart text is searched
+    pub fn with_include_charts(mut self, include_charts: bool) -> Self {
+        self.include_charts = include_charts;
+        self
+    }
+}
+
+/// 검색 결과 하나 / A single search hit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchHit {
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+    /// 차트 인덱스 (`Paragraph::charts` 순서, 차트가 아니면 None). 오프셋은 `ChartData::labels`를 줄바꿈으로 이은 텍스트 기준
+    /// Chart index (in `Paragraph::charts` order, None outside charts). Offsets are within `ChartData::labels` joined by newlines
+    pub chart_index: Option<usize>,
+    /// 문단 텍스트 내 시작 위치 (문자 단위) / Start offset within the paragraph text (in characters)
+    pub char_offset: usize,
+    /// 일치한 길이 (문자 단위) / Match length (in characters)
+    pub char_length: usize,
+    /// 일치한 텍스트 / Matched text
+    pub matched_text: String,
+    /// 앞 문맥 / Context before the match
+    pub context_before: String,
+    /// 뒤 문맥 / Context after the match
+    pub context_after: String,
+}
+
+impl HwpDocument {
+    /// 문서 텍스트 검색 / Search document text
+    ///
+    /// # Arguments / 매개변수
+    /// * `query` - 검색어 또는 정규식 / Search text or regular expression
+    /// * `options` - 검색 옵션 / Search options
+    ///
+    /// # Returns / 반환값
+    /// 문서 순서대로 정렬된 검색 결과 / Hits in document order
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Result<Vec<SearchHit>, HwpError> {
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let pattern = if options.regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        let matcher = RegexBuilder::new(&pattern)
+            .case_insensitive(options.case_insensitive)
+            .build()
+            .map_err(|e| HwpError::InvalidQuery {
+                reason: e.to_string(),
+            })?;
+
+        let mut hits = Vec::new();
+        let limit = options.max_hits.unwrap_or(usize::MAX);
+
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                // (중첩 문단 인덱스, 차트 인덱스, 텍스트) / (nested paragraph index, chart index, text)
+                let mut targets: Vec<(Option<usize>, Option<usize>, String)> =
+                    vec![(None, None, paragraph.text())];
+                if options.include_nested {
+                    targets.extend(
+                        paragraph
+                            .nested_paragraphs()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, nested)| (Some(index), None, nested.text())),
+                    );
+                }
+                if options.include_charts {
+                    targets.extend(
+                        paragraph
+                            .charts()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, chart)| (None, Some(index), chart.labels().join("\n"))),
+                    );
+                }
+
+                for (nested_index, chart_index, text) in targets {
+                    // 앞 일치까지 센 바이트/문자 위치 / Byte and character position counted up to the previous match
+                    let (mut byte_position, mut char_position) = (0, 0);
+                    for found in matcher.find_iter(&text) {
+                        if found.as_str().is_empty() {
+                            continue;
+                        }
+                        if hits.len() >= limit {
+                            return Ok(hits);
+                        }
+                        char_position += text[byte_position..found.start()].chars().count();
+                        byte_position = found.start();
+                        let char_offset = char_position;
+                        hits.push(SearchHit {
+                            location: ParagraphLocation::top_level(section_index, paragraph_index)
+                                .nested(nested_index),
+                            chart_index,
+                            char_offset,
+                            char_length: found.as_str().chars().count(),
+                            matched_text: found.as_str().to_string(),
+                            context_before: tail_chars(
+                                &text[..found.start()],
+                                options.context_chars,
+                            ),
+                            context_after: head_chars(&text[found.end()..], options.context_chars),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(hits)
+    }
+}
+
+/// 문자열 끝에서 n개 문자 / Last n characters of a string
+fn tail_chars(text: &str, n: usize) -> String {
+    let start = text
+        .char_indices()
+        .rev()
+        .take(n)
+        .last()
+        .map_or(text.len(), |(index, _)| index);
+    text[start..].to_string()
+}
+
+/// 문자열 앞에서 n개 문자 / First n characters of a string
+fn head_chars(text: &str, n: usize) -> String {
+    text.chars().take(n).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::ParagraphRecord;
+    use crate::testing::fixtures::{document_with_paragraphs, text_paragraph};
+
+    fn document(texts: &[&str]) -> HwpDocument {
+        document_with_paragraphs(texts.iter().map(|t| text_paragraph(t)).collect())
+    }
+
+    #[test]
+    fn test_search_plain_reports_char_offsets() {
+        let doc = document(&["첫 문단", "한글 Hello 한글"]);
+        let hits = doc.search("한글", &SearchOptions::default()).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].location.paragraph_index, 1);
+        assert_eq!(hits[0].char_offset, 0);
+        assert_eq!(hits[1].char_offset, 9);
+        assert_eq!(hits[1].context_before, "한글 Hello ");
+    }
+
+    #[test]
+    fn test_search_case_insensitive_and_regex() {
+        let doc = document(&["Policy v1.2 and POLICY v2.0"]);
+        let plain = doc.search("policy", &SearchOptions::default()).unwrap();
+        assert!(plain.is_empty());
+
+        let options = SearchOptions::default().with_case_insensitive(true);
+        assert_eq!(doc.search("policy", &options).unwrap().len(), 2);
+
+        // 일반 모드에서는 정규식 메타 문자를 그대로 검색 / Plain mode treats regex metacharacters literally
+        assert_eq!(
+            doc.search("v1.2", &SearchOptions::default()).unwrap().len(),
+            1
+        );
+        assert!(doc
+            .search("v.\\.", &SearchOptions::default())
+            .unwrap()
+            .is_empty());
+
+        let regex = SearchOptions::default()
+            .with_regex(true)
+            .with_context_chars(0);
+        let hits = doc.search(r"v\d\.\d", &regex).unwrap();
+        assert_eq!(
+            hits.iter()
+                .map(|h| h.matched_text.as_str())
+                .collect::<Vec<_>>(),
+            vec!["v1.2", "v2.0"]
+        );
+        assert!(hits[0].context_before.is_empty());
+    }
+
+    #[test]
+    fn test_search_chart_labels() {
+        use crate::document::bodytext::{ChartData, VtChart};
+
+        let mut doc = document(&["본문"]);
+        let mut chart = VtChart::default();
+        chart.data_grid.column_labels = vec!["국내".to_string(), "해외 매출".to_string()];
+        doc.body_text.sections[0].paragraphs[0]
+            .records
+            .push(ParagraphRecord::ChartData {
+                chart_data: ChartData {
+                    vt_chart: Some(chart),
+                    raw_data: Vec::new(),
+                },
+            });
+
+        assert!(doc
+            .search("매출", &SearchOptions::default())
+            .unwrap()
+            .is_empty());
+        let options = SearchOptions::default().with_include_charts(true);
+        let hits = doc.search("매출", &options).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            (hits[0].location.nested_index, hits[0].chart_index),
+            (None, Some(0))
+        );
+        assert_eq!(hits[0].char_offset, 6);
+        assert_eq!(hits[0].context_before, "국내\n해외 ");
+    }
+
+    #[test]
+    fn test_search_many_hits_keeps_char_offsets() {
+        let doc = document(&[&"가a".repeat(1000)]);
+        let options = SearchOptions::default().with_context_chars(1);
+        let hits = doc.search("a", &options).unwrap();
+        assert_eq!(hits.len(), 1000);
+        assert!(hits
+            .iter()
+            .enumerate()
+            .all(|(index, hit)| hit.char_offset == index * 2 + 1 && hit.context_before == "가"));
+    }
+
+    #[test]
+    fn test_search_invalid_regex() {
+        let doc = document(&["text"]);
+        let options = SearchOptions::default().with_regex(true);
+        assert!(matches!(
+            doc.search("(", &options),
+            Err(HwpError::InvalidQuery { .. })
+        ));
+    }
+}
@@ -0,0 +1,676 @@
+/// 문서 보안 정보 모듈 / Document security information module
+///
+/// 전자 서명, DRM, 암호, 배포용 문서 여부와 외부 연결을 알려 줍니다. HWP 5.0은 FileHeader 속성과
+/// `DocOptions` 스토리지(`_LinkDoc`, DRM/전자 서명 스트림)를, HWPX는 `META-INF`의 서명/암호화 파트와
+/// 문서에 포함되지 않은 매니페스트 항목을 봅니다.
+/// Reports whether a document is signed, DRM protected, password protected or a distribution
+/// document, and which external files it links to. HWP 5.0 uses the FileHeader flags and the
+/// `DocOptions` storage (`_LinkDoc`, DRM and signature streams); HWPX uses the signature and
+/// encryption parts under `META-INF` and manifest items that are not embedded.
+///
+/// `security_findings`는 수신 첨부 파일 검사용으로 문서 스크립트와 OLE 개체, 그 안에 든 실행
+/// 파일처럼 위험할 수 있는 내용을 나열합니다.
+/// `security_findings` lists potentially dangerous content such as document scripts, OLE objects
+/// and executables packaged inside them, for scanning inbound attachments.
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+use std::sync::OnceLock;
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use cfb::CompoundFile;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::bindata::BinaryDataItem;
+use super::docinfo::BinDataRecord;
+use super::scripts::Script;
+use super::{FileHeader, HwpDocument, SourceFormat};
+use crate::cfb::CfbParser;
+use crate::error::HwpError;
+
+/// HWP 5.0 문서 옵션 스토리지 / HWP 5.0 document options storage
+const DOC_OPTIONS: &str = "DocOptions";
+/// 연결 문서 경로 스트림 / Linked document path stream
+const LINK_DOC: &str = "_LinkDoc";
+/// DRM 스트림 / DRM streams
+const DRM_STREAMS: &[&str] = &["DrmLicense", "DrmRootSect", "CertDrmHeader", "CertDrmInfo"];
+/// 전자 서명 스트림 / Digital signature streams
+const SIGNATURE_STREAMS: &[&str] = &["DigitalSignature", "PublicKeyInfo"];
+
+/// 기본 스크립트 틀의 전역 변수 선언 (공백 제거 후) / Global declarations of the default script template, whitespace removed
+const SCRIPT_TEMPLATE: &[&str] = &[
+    "varDocuments=XHwpDocuments;",
+    "varDocument=Documents.Active_XHwpDocument;",
+];
+/// OLE 패키지 개체의 원본 파일 스트림 / Stream holding the original file of an OLE package object
+const OLE10_NATIVE: &str = "\u{1}Ole10Native";
+/// OLE 개체 정보 스트림 (연결 정보 포함) / OLE object stream, including link information
+const OLE_STREAM: &str = "/\u{1}Ole";
+/// 파일 모니커 CLSID {00000303-0000-0000-C000-000000000046} / File moniker CLSID
+const FILE_MONIKER: [u8; 16] = [0x03, 0x03, 0, 0, 0, 0, 0, 0, 0xC0, 0, 0, 0, 0, 0, 0, 0x46];
+/// URL 모니커 CLSID {79EAC9E0-BAF9-11CE-8C82-00AA004BA90B} / URL moniker CLSID
+const URL_MONIKER: [u8; 16] = [
+    0xE0, 0xC9, 0xEA, 0x79, 0xF9, 0xBA, 0xCE, 0x11, 0x8C, 0x82, 0x00, 0xAA, 0x00, 0x4B, 0xA9, 0x0B,
+];
+/// 열면 실행될 수 있는 파일 확장자 / Extensions of files that can run when opened
+const EXECUTABLE_EXTENSIONS: &[&str] = &[
+    "bat", "cmd", "com", "cpl", "dll", "exe", "hta", "jar", "js", "jse", "lnk", "msi", "pif",
+    "ps1", "scr", "vbe", "vbs", "wsf", "wsh",
+];
+
+/// 문서 보안 정보 / Document security information
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityInfo {
+    /// 전자 서명 여부 / Whether the document is digitally signed
+    #[serde(default)]
+    pub signed: bool,
+    /// DRM 보안 문서 여부 (공인 인증서 DRM 포함) / Whether the document is DRM protected, including certificate DRM
+    #[serde(default)]
+    pub drm_protected: bool,
+    /// 암호 설정 여부 / Whether the document is password protected
+    #[serde(default)]
+    pub password_protected: bool,
+    /// 배포용 문서 여부 / Whether the document is a distribution document
+    #[serde(default)]
+    pub distribution: bool,
+    /// 연결 문서와 연결 그림의 경로 / Paths of the linked document and linked images
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_links: Vec<String>,
+    /// 찾은 보안 스트림/파트 이름 / Names of the security streams or parts found
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<String>,
+    /// HWPX 매니페스트 항목 ID별 연결 파일 경로 / Linked file paths by HWPX manifest item ID
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub linked_items: BTreeMap<String, String>,
+}
+
+impl SecurityInfo {
+    /// 보안 정보가 하나도 없는지 확인 / Whether no security information was found
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 외부 연결이 있는지 확인 / Whether the document links to external files
+    pub fn has_external_links(&self) -> bool {
+        !self.external_links.is_empty()
+    }
+
+    /// 본문을 읽을 수 없는 이유 (읽을 수 있으면 None)
+    /// Why the body cannot be read (None when it can)
+    ///
+    /// 업로드 파이프라인에서 파싱 전에 DRM/암호 문서를 걸러 낼 때 씁니다.
+    /// Used by upload pipelines to reject DRM or password protected documents before parsing.
+    pub fn rejection_reason(&self) -> Option<&'static str> {
+        if self.drm_protected {
+            Some("Document is DRM protected")
+        } else if self.password_protected {
+            Some("Document is password protected")
+        } else {
+            None
+        }
+    }
+
+    /// 본문을 읽을 수 있는지 확인 / Check that the body can be read
+    ///
+    /// # Returns / 반환값
+    /// DRM 문서는 `DrmProtected`, 암호 문서는 `PasswordRequired` / `DrmProtected` for DRM documents, `PasswordRequired` for password protected ones
+    pub(crate) fn ensure_readable(&self) -> Result<(), HwpError> {
+        if self.drm_protected {
+            return Err(HwpError::DrmProtected {
+                reason: format!("DRM parts found: {}", self.parts.join(", ")),
+            });
+        }
+        if self.password_protected {
+            return Err(HwpError::PasswordRequired);
+        }
+        Ok(())
+    }
+
+    /// FileHeader 속성 반영 / Apply the FileHeader flags
+    pub(crate) fn apply_file_header(&mut self, header: &FileHeader) {
+        self.signed |= header.has_electronic_signature();
+        self.drm_protected |= header.is_drm();
+        self.password_protected |= header.is_encrypted();
+        self.distribution |= header.is_distribution();
+    }
+
+    /// 외부 연결 경로 추가 (빈 경로와 중복은 건너뜀) / Add an external link, skipping empty and duplicate paths
+    pub(crate) fn add_external_link(&mut self, path: &str) {
+        let path = path.trim();
+        if !path.is_empty() && !self.external_links.iter().any(|link| link == path) {
+            self.external_links.push(path.to_string());
+        }
+    }
+
+    /// 연결 그림 경로 추가 (절대 경로가 없으면 상대 경로) / Add linked image paths (the relative path when there is no absolute one)
+    pub(crate) fn add_bin_data_links(&mut self, records: &[BinDataRecord]) {
+        for record in records {
+            if let BinDataRecord::Link { link, .. } = record {
+                let path = if link.absolute_path.trim().is_empty() {
+                    &link.relative_path
+                } else {
+                    &link.absolute_path
+                };
+                self.add_external_link(path);
+            }
+        }
+    }
+
+    /// HWP 5.0 DocOptions 스토리지 읽기 / Read the HWP 5.0 DocOptions storage
+    ///
+    /// FileHeader 속성은 `HwpDocument::security_info`가 더하므로 여기서는 스트림만 봅니다.
+    /// Only the streams are read here; `HwpDocument::security_info` adds the FileHeader flags.
+    ///
+    /// # Arguments / 매개변수
+    /// * `cfb` - CompoundFile structure / CompoundFile 구조체
+    ///
+    /// # Returns / 반환값
+    /// DocOptions 스트림에서 찾은 보안 정보 / Security information found in the DocOptions streams
+    pub(crate) fn parse_doc_options(cfb: &mut CompoundFile<Cursor<&[u8]>>) -> Self {
+        let mut info = Self::default();
+
+        // 스펙 문서 3.2.8: _LinkDoc에는 연결 문서 경로가 유니코드 문자열로 저장됨
+        // Spec 3.2.8: _LinkDoc holds the linked document path as a Unicode string
+        if let Ok(data) = CfbParser::read_nested_stream(cfb, DOC_OPTIONS, LINK_DOC) {
+            info.add_external_link(&decode_utf16_path(&data));
+        }
+        for &name in DRM_STREAMS.iter().chain(SIGNATURE_STREAMS) {
+            if has_nested_stream(cfb, name) {
+                info.drm_protected |= DRM_STREAMS.contains(&name);
+                info.signed |= SIGNATURE_STREAMS.contains(&name);
+                info.parts.push(format!("{DOC_OPTIONS}/{name}"));
+            }
+        }
+        info
+    }
+}
+
+impl HwpDocument {
+    /// 문서 보안 정보 / Document security information
+    ///
+    /// 파싱할 때 읽은 서명/DRM 정보에 FileHeader 속성과 연결 그림(`BinDataRecord::Link`) 경로를
+    /// 더해 돌려줍니다. DRM 문서는 파싱 자체가 `HwpError::DrmProtected`로 실패하므로, 파싱 전에
+    /// 확인하려면 `HwpParser::security_info`를 씁니다.
+    /// Returns the signature and DRM information read while parsing, combined with the
+    /// FileHeader flags and linked image (`BinDataRecord::Link`) paths. DRM documents fail to
+    /// parse with `HwpError::DrmProtected`, so use `HwpParser::security_info` to check before
+    /// parsing.
+    ///
+    /// # Returns / 반환값
+    /// 보안 정보 / Security information
+    pub fn security_info(&self) -> SecurityInfo {
+        let mut info = self.security.clone();
+        info.apply_file_header(&self.file_header);
+        info.add_bin_data_links(&self.doc_info.bin_data);
+        info
+    }
+}
+
+/// 보안 검사 항목 종류 / Kind of security finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityFindingKind {
+    /// 기본 틀 외의 코드가 있는 문서 스크립트 / Document script with code beyond the default template
+    Script,
+    /// 포함된 OLE 개체 / Embedded OLE object
+    OleObject,
+    /// 실행 파일이나 스크립트 파일 / Executable or script file
+    Executable,
+}
+
+/// 보안 검사 항목 / Security finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityFinding {
+    /// 항목 종류 / Finding kind
+    pub kind: SecurityFindingKind,
+    /// 스트림/파트 이름 / Stream or part name
+    pub location: String,
+    /// 설명 / Description
+    pub message: String,
+}
+
+impl SecurityFinding {
+    fn new(kind: SecurityFindingKind, location: &str, message: String) -> Self {
+        Self {
+            kind,
+            location: location.to_string(),
+            message,
+        }
+    }
+}
+
+impl HwpDocument {
+    /// 위험할 수 있는 내용 목록 / Potentially dangerous content
+    ///
+    /// 새 문서마다 들어가는 빈 스크립트 틀(`OnDocument_New`의 빈 함수 등)은 건너뛰고, 그 밖의
+    /// 코드가 있는 스크립트, OLE 개체, OLE 패키지나 바이너리 데이터로 들어간 실행 파일을 찾습니다.
+    /// Skips the empty script template every new document carries (such as an empty
+    /// `OnDocument_New`) and reports scripts with any other code, OLE objects, and executables
+    /// embedded as OLE packages or binary data.
+    ///
+    /// # Returns / 반환값
+    /// 스크립트, 바이너리 데이터 순서의 검사 항목 / Findings for the scripts, then the binary data in order
+    pub fn security_findings(&self) -> Vec<SecurityFinding> {
+        let mut findings = Vec::new();
+
+        let script = self
+            .scripts
+            .as_ref()
+            .and_then(|scripts| scripts.default_script.as_ref());
+        if let Some(length) = script.and_then(script_code_length) {
+            let location = match self.source_format {
+                SourceFormat::Hwpx => "Scripts/sourceScripts",
+                _ => "Scripts/DefaultJScript",
+            };
+            findings.push(SecurityFinding::new(
+                SecurityFindingKind::Script,
+                location,
+                format!("Document script contains {length} characters of code beyond the default template"),
+            ));
+        }
+
+        for item in &self.bin_data.items {
+            let Ok(data) = STANDARD.decode(&item.data) else {
+                continue;
+            };
+            let location = bin_data_location(item);
+            if let Some(storage) = ole_storage(&data) {
+                findings.push(SecurityFinding::new(
+                    SecurityFindingKind::OleObject,
+                    &location,
+                    "Embedded OLE object".to_string(),
+                ));
+                for (name, payload) in ole_packages(storage) {
+                    if is_executable(&name, &payload) {
+                        findings.push(SecurityFinding::new(
+                            SecurityFindingKind::Executable,
+                            &location,
+                            format!("OLE package embeds executable file '{name}'"),
+                        ));
+                    }
+                }
+            } else if data.starts_with(b"MZ") {
+                findings.push(SecurityFinding::new(
+                    SecurityFindingKind::Executable,
+                    &location,
+                    "Binary data is a Windows executable".to_string(),
+                ));
+            }
+        }
+        findings
+    }
+}
+
+/// 주석, 공백, 기본 틀을 뺀 스크립트 코드 길이 (없으면 None)
+/// Length of the script code without comments, whitespace and the default template (None when empty)
+fn script_code_length(script: &Script) -> Option<usize> {
+    static EMPTY_FUNCTION: OnceLock<Regex> = OnceLock::new();
+    let empty_function = EMPTY_FUNCTION.get_or_init(|| {
+        Regex::new(r"function[A-Za-z_$][\w$]*\([\w$,]*\)\{\}")
+            .expect("empty function pattern is valid")
+    });
+
+    let mut code: String = [
+        &script.header,
+        &script.pre_source,
+        &script.source,
+        &script.post_source,
+    ]
+    .iter()
+    .flat_map(|part| strip_comments(part).chars().collect::<Vec<_>>())
+    .filter(|c| !c.is_whitespace())
+    .collect();
+    for declaration in SCRIPT_TEMPLATE {
+        code = code.replace(declaration, "");
+    }
+    let code = empty_function.replace_all(&code, "");
+    (!code.is_empty()).then(|| code.chars().count())
+}
+
+/// JavaScript 주석 제거 (문자열 안은 그대로) / Remove JavaScript comments, leaving string literals alone
+fn strip_comments(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if let Some(open) = quote {
+            output.push(c);
+            if c == '\\' {
+                output.extend(chars.next());
+            } else if c == open {
+                quote = None;
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('/', Some('/')) => while chars.next_if(|&next| next != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+                output.push(' ');
+            }
+            ('"' | '\'' | '`', _) => {
+                quote = Some(c);
+                output.push(c);
+            }
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+/// 바이너리 데이터 위치 이름 / Location name of a binary data item
+fn bin_data_location(item: &BinaryDataItem) -> String {
+    match &item.name {
+        Some(name) => format!("BinData/{name}"),
+        None => format!("BinData/BIN{:04X}", item.index),
+    }
+}
+
+/// OLE 저장소 시작 부분 (HWP 5.0은 앞에 4바이트 크기가 붙음)
+/// Start of the OLE storage (HWP 5.0 prefixes it with a 4-byte size)
+pub(crate) fn ole_storage(data: &[u8]) -> Option<&[u8]> {
+    const MAGIC: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+    [0, 4]
+        .into_iter()
+        .find(|&offset| data.get(offset..offset + MAGIC.len()) == Some(&MAGIC[..]))
+        .map(|offset| &data[offset..])
+}
+
+/// 연결된 OLE 개체의 원본 (파일 경로나 URL) / Source of a linked OLE object (file path or URL)
+///
+/// `\u{1}Ole` 스트림(MS-OLEDS 2.3.3)에 연결 플래그가 있을 때 절대 모니커, 없으면 상대 모니커를
+/// 읽습니다. 파일 모니커와 URL 모니커만 해석합니다.
+/// When the `\u{1}Ole` stream (MS-OLEDS 2.3.3) has the link flag, reads the absolute moniker, or
+/// the relative one when it is missing. Only file and URL monikers are understood.
+pub(crate) fn ole_link_target(storage: &[u8]) -> Option<String> {
+    let mut cfb = CompoundFile::open(Cursor::new(storage)).ok()?;
+    let mut data = Vec::new();
+    cfb.open_stream(OLE_STREAM)
+        .ok()?
+        .read_to_end(&mut data)
+        .ok()?;
+    if read_dword(&data, 4)? & 1 == 0 {
+        return None;
+    }
+
+    // 버전(4), 플래그(4), 갱신 방식(4), 예약(4) 다음에 크기가 붙은 모니커 세 개
+    // Version (4), flags (4), update option (4) and reserved (4), then three sized monikers
+    let mut offset = 16;
+    let mut monikers = Vec::new();
+    for _ in 0..3 {
+        let size = read_dword(&data, offset)? as usize;
+        let start = offset + 4;
+        let end = start.checked_add(size)?;
+        monikers.push(data.get(start..end)?);
+        offset = end;
+    }
+    let [_, relative, absolute] = monikers[..] else {
+        return None;
+    };
+    moniker_target(absolute).or_else(|| moniker_target(relative))
+}
+
+/// 파일/URL 모니커의 대상 / Target of a file or URL moniker
+fn moniker_target(moniker: &[u8]) -> Option<String> {
+    let (clsid, data) = (moniker.get(..16)?, moniker.get(16..)?);
+    let target = if clsid == FILE_MONIKER {
+        // cAnti(2), ANSI 경로 길이(4)와 경로, endServer(2), 버전(2), 예약(20), 유니코드 부분 크기(4)
+        // cAnti (2), ANSI path length (4) and path, endServer (2), version (2), reserved (20), Unicode part size (4)
+        let ansi_length = read_dword(data, 2)? as usize;
+        let ansi = data.get(6..6usize.checked_add(ansi_length)?)?;
+        let ansi = String::from_utf8_lossy(ansi)
+            .trim_end_matches('\0')
+            .to_string();
+        let unicode_offset = 6 + ansi_length + 24;
+        let unicode = read_dword(data, unicode_offset)
+            .filter(|&size| size > 6)
+            .and_then(|_| {
+                let length = read_dword(data, unicode_offset + 4)? as usize;
+                let start = unicode_offset + 10;
+                Some(decode_utf16_path(
+                    data.get(start..start.checked_add(length)?)?,
+                ))
+            });
+        unicode.filter(|path| !path.is_empty()).unwrap_or(ansi)
+    } else if clsid == URL_MONIKER {
+        decode_utf16_path(data.get(4..)?)
+    } else {
+        return None;
+    };
+    let target = target.trim();
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+/// 리틀 엔디언 DWORD 읽기 / Read a little-endian DWORD
+fn read_dword(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// OLE 저장소 안의 패키지 개체 (파일 이름, 내용) / Package objects inside an OLE storage (file name, content)
+fn ole_packages(storage: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let Ok(mut cfb) = CompoundFile::open(Cursor::new(storage)) else {
+        return Vec::new();
+    };
+    let paths: Vec<_> = cfb
+        .walk()
+        .filter(|entry| entry.is_stream() && entry.name() == OLE10_NATIVE)
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let mut data = Vec::new();
+            cfb.open_stream(&path).ok()?.read_to_end(&mut data).ok()?;
+            parse_ole10_native(&data)
+        })
+        .collect()
+}
+
+/// `\u{1}Ole10Native` 스트림 해석 / Parse an `\u{1}Ole10Native` stream
+///
+/// 전체 크기(4), 형식(2), 표시 이름, 원본 경로, 예약(4), 임시 경로 길이(4)와 경로, 내용 크기(4)와
+/// 내용 순서입니다. 이름은 표시 이름, 없으면 원본 경로를 씁니다.
+/// Laid out as total size (4), type (2), label, source path, reserved (4), temporary path
+/// length (4) and path, content size (4) and content. The name is the label, or the source
+/// path when the label is empty.
+fn parse_ole10_native(data: &[u8]) -> Option<(String, Vec<u8>)> {
+    let c_string = |offset: usize| -> Option<(String, usize)> {
+        let length = data.get(offset..)?.iter().position(|&b| b == 0)?;
+        let text = String::from_utf8_lossy(&data[offset..offset + length]).into_owned();
+        Some((text, offset + length + 1))
+    };
+    let dword = |offset: usize| read_dword(data, offset).map(|value| value as usize);
+
+    let (label, offset) = c_string(6)?;
+    let (source_path, offset) = c_string(offset)?;
+    let name = if label.is_empty() { source_path } else { label };
+    let offset = offset + 4;
+    let offset = (offset + 4).saturating_add(dword(offset)?);
+    let content = dword(offset)
+        .and_then(|size| {
+            let start = offset.checked_add(4)?;
+            data.get(start..start.checked_add(size)?)
+        })
+        .unwrap_or_default();
+    Some((name, content.to_vec()))
+}
+
+/// 실행될 수 있는 파일인지 확인 / Whether a file can run when opened
+fn is_executable(name: &str, content: &[u8]) -> bool {
+    let extension = name
+        .rsplit(['/', '\\'])
+        .next()
+        .and_then(|file| file.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_ascii_lowercase());
+    content.starts_with(b"MZ")
+        || extension.is_some_and(|extension| EXECUTABLE_EXTENSIONS.contains(&extension.as_str()))
+}
+
+/// DocOptions 안에 스트림이 있는지 확인 / Whether a stream exists inside DocOptions
+fn has_nested_stream(cfb: &CompoundFile<Cursor<&[u8]>>, name: &str) -> bool {
+    cfb.exists(format!("/{DOC_OPTIONS}/{name}"))
+        || cfb.exists(format!("/Root Entry/{DOC_OPTIONS}/{name}"))
+}
+
+/// UTF-16LE 경로를 첫 NUL까지 읽기 / Decode a UTF-16LE path up to the first NUL
+fn decode_utf16_path(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::empty_document;
+
+    fn header(document_flags: u32) -> FileHeader {
+        FileHeader {
+            document_flags,
+            ..empty_document().file_header
+        }
+    }
+
+    #[test]
+    fn test_decode_utf16_path() {
+        let mut data: Vec<u8> = "C:\\문서\\원본.hwp"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+        data.extend([0, 0, b'x', 0]);
+        assert_eq!(decode_utf16_path(&data), "C:\\문서\\원본.hwp");
+    }
+
+    #[test]
+    fn test_file_header_flags() {
+        let header = header(0x10 | 0x80 | 0x04);
+        let mut info = SecurityInfo::default();
+        info.apply_file_header(&header);
+        assert!(info.drm_protected && info.signed && info.distribution);
+        assert!(!info.password_protected);
+        assert_eq!(info.rejection_reason(), Some("Document is DRM protected"));
+        assert!(matches!(
+            header.ensure_readable(),
+            Err(HwpError::DrmProtected { .. })
+        ));
+    }
+
+    #[test]
+    fn test_script_code_skips_default_template() {
+        let mut script = Script {
+            header: "var Documents = XHwpDocuments;\r\nvar Document = Documents.Active_XHwpDocument;\r\n".to_string(),
+            source: "function OnDocument_New()\r\n{\r\n\t//todo : \r\n}\r\n".to_string(),
+            pre_source: String::new(),
+            post_source: String::new(),
+        };
+        assert_eq!(script_code_length(&script), None);
+
+        script
+            .source
+            .push_str("function OnDocument_Open() {\n  /* 실행 */ Run(\"cmd // x\");\n}\n");
+        assert_eq!(
+            script_code_length(&script),
+            Some("functionOnDocument_Open(){Run(\"cmd//x\");}".len())
+        );
+    }
+
+    #[test]
+    fn test_security_findings_report_ole_package_executable() {
+        let mut native = vec![0u8; 4];
+        native.extend(2u16.to_le_bytes());
+        native.extend(b"setup.exe\0C:\\temp\\setup.exe\0");
+        native.extend(0x0003_0000u32.to_le_bytes());
+        native.extend(4u32.to_le_bytes());
+        native.extend(b"tmp\0");
+        native.extend(4u32.to_le_bytes());
+        native.extend(b"MZ\x90\0");
+
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        std::io::Write::write_all(
+            &mut cfb.create_stream(format!("/{OLE10_NATIVE}")).unwrap(),
+            &native,
+        )
+        .unwrap();
+        let storage = cfb.into_inner().into_inner();
+        let mut data = (storage.len() as u32).to_le_bytes().to_vec();
+        data.extend(storage);
+
+        let mut document = empty_document();
+        document.bin_data.items.push(BinaryDataItem {
+            index: 1,
+            data: STANDARD.encode(&data),
+            name: None,
+        });
+        document.bin_data.items.push(BinaryDataItem {
+            index: 2,
+            data: STANDARD.encode(b"MZ\x90\0rest"),
+            name: None,
+        });
+        let kinds: Vec<_> = document
+            .security_findings()
+            .into_iter()
+            .map(|finding| (finding.kind, finding.location))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    SecurityFindingKind::OleObject,
+                    "BinData/BIN0001".to_string()
+                ),
+                (
+                    SecurityFindingKind::Executable,
+                    "BinData/BIN0001".to_string()
+                ),
+                (
+                    SecurityFindingKind::Executable,
+                    "BinData/BIN0002".to_string()
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn test_ole_link_target_reads_file_moniker() {
+        let path = b"C:\\data\\budget.xlsx\0";
+        let mut moniker = FILE_MONIKER.to_vec();
+        moniker.extend(0u16.to_le_bytes());
+        moniker.extend((path.len() as u32).to_le_bytes());
+        moniker.extend(path);
+        moniker.extend(0xFFFFu16.to_le_bytes());
+        moniker.extend(0xDEADu16.to_le_bytes());
+        moniker.extend([0u8; 20]);
+        moniker.extend(0u32.to_le_bytes());
+
+        let mut ole = Vec::new();
+        for dword in [0x0200_0001u32, 1, 0, 0, 0, 0] {
+            ole.extend(dword.to_le_bytes());
+        }
+        ole.extend((moniker.len() as u32).to_le_bytes());
+        ole.extend(&moniker);
+
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        std::io::Write::write_all(&mut cfb.create_stream(OLE_STREAM).unwrap(), &ole).unwrap();
+        let storage = cfb.into_inner().into_inner();
+        assert_eq!(
+            ole_link_target(&storage).as_deref(),
+            Some("C:\\data\\budget.xlsx")
+        );
+
+        // 연결 플래그가 없으면 포함된 개체 / Without the link flag the object is embedded
+        ole[4] = 0;
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        std::io::Write::write_all(&mut cfb.create_stream(OLE_STREAM).unwrap(), &ole).unwrap();
+        assert_eq!(ole_link_target(&cfb.into_inner().into_inner()), None);
+    }
+}
@@ -0,0 +1,167 @@
+/// 문서 통계 모듈 / Document statistics module
+///
+/// 구역/문단/표/이미지/글자 수와 추정 쪽수, 파싱 단계별 시간을 한 번에 모읍니다.
+/// Collects section, paragraph, table, image and character counts, an estimated page count and
+/// per-phase parse timing in one pass.
+use super::HwpDocument;
+use crate::document::bodytext::{ColumnDivideType, Paragraph, ParagraphRecord, Section};
+use crate::parser::ParseProfile;
+
+/// 문서 통계 / Document statistics
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentStats {
+    /// 구역 수 / Number of sections
+    pub sections: usize,
+    /// 문단 수 (표 셀, 머리말 등 중첩 문단 포함) / Number of paragraphs, including nested ones (table cells, headers, ...)
+    pub paragraphs: usize,
+    /// 표 수 (중첩 표 포함) / Number of tables, including nested ones
+    pub tables: usize,
+    /// 포함된 이미지 수 / Number of embedded images
+    pub images: usize,
+    /// 글자 수 (공백 포함) / Number of characters, including whitespace
+    pub characters: usize,
+    /// 글자 수 (공백 제외) / Number of characters, excluding whitespace
+    pub characters_without_spaces: usize,
+    /// 추정 쪽수 / Estimated number of pages
+    pub estimated_pages: usize,
+    /// 단계별 파싱 시간 (`ParseOptions::profiling`을 켰을 때) / Per-phase parse timing (when `ParseOptions::profiling` is enabled)
+    pub profile: Option<ParseProfile>,
+}
+
+impl HwpDocument {
+    /// 문서 통계 / Document statistics
+    ///
+    /// 쪽수는 저장된 줄 배치(HWP 5.0의 줄 세로 위치)와 쪽 나누기로 추정합니다. 줄 배치가 없는
+    /// 형식은 쪽 나누기만 세므로 실제보다 적을 수 있습니다.
+    /// Pages are estimated from the stored line layout (line vertical positions in HWP 5.0) and
+    /// page breaks. Formats without a stored line layout only count page breaks, so the estimate
+    /// may be lower than the real page count.
+    ///
+    /// # Returns / 반환값
+    /// 문서 통계 / Document statistics
+    pub fn stats(&self) -> DocumentStats {
+        let mut stats = DocumentStats {
+            sections: self.body_text.sections.len(),
+            images: self.images().len(),
+            profile: self.profile.clone(),
+            ..Default::default()
+        };
+        for section in &self.body_text.sections {
+            stats.estimated_pages += estimate_section_pages(section);
+            for paragraph in &section.paragraphs {
+                for paragraph in std::iter::once(paragraph).chain(paragraph.nested_paragraphs()) {
+                    stats.paragraphs += 1;
+                    stats.tables += paragraph.tables().len();
+                    for c in paragraph.text().chars() {
+                        stats.characters += 1;
+                        if !c.is_whitespace() {
+                            stats.characters_without_spaces += 1;
+                        }
+                    }
+                }
+            }
+        }
+        stats
+    }
+}
+
+/// 구역의 쪽수 추정 / Estimate the number of pages in a section
+fn estimate_section_pages(section: &Section) -> usize {
+    let mut pages = PageTracker::default();
+    1 + section
+        .paragraphs
+        .iter()
+        .map(|paragraph| pages.new_pages(paragraph))
+        .sum::<usize>()
+}
+
+/// 구역 안에서 새 쪽이 시작되는 곳 추적 / Tracks where new pages start within a section
+///
+/// 쪽 나누기가 있는 문단이나 앞 줄보다 위에 놓인 줄에서 새 쪽이 시작된다고 봅니다.
+/// A new page is assumed to start at a paragraph with a page break, or at a line placed above
+/// the previous line.
+#[derive(Debug, Default)]
+pub(crate) struct PageTracker {
+    started: bool,
+    previous_position: Option<i32>,
+}
+
+impl PageTracker {
+    /// 문단에서 시작되는 새 쪽 수 (구역의 문단을 차례로 넘김) / Number of new pages starting in a paragraph (pass the section's paragraphs in order)
+    pub(crate) fn new_pages(&mut self, paragraph: &Paragraph) -> usize {
+        let mut pages = 0;
+        let mut page_break = self.started && has_page_break(paragraph);
+        self.started = true;
+        for record in &paragraph.records {
+            let ParagraphRecord::ParaLineSeg { segments } = record else {
+                continue;
+            };
+            for segment in segments {
+                if self
+                    .previous_position
+                    .is_some_and(|previous| segment.vertical_position < previous)
+                {
+                    page_break = true;
+                }
+                if page_break {
+                    pages += 1;
+                    page_break = false;
+                }
+                self.previous_position = Some(segment.vertical_position);
+            }
+        }
+        if page_break {
+            pages += 1;
+        }
+        pages
+    }
+}
+
+fn has_page_break(paragraph: &Paragraph) -> bool {
+    paragraph
+        .para_header
+        .column_divide_type
+        .contains(&ColumnDivideType::Page)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{DocumentBuilder, ParagraphStyle};
+    use crate::document::bodytext::{ColumnDivideType, ParaLineSeg, ParagraphRecord};
+
+    #[test]
+    fn test_stats_counts_and_pages() {
+        let style = ParagraphStyle::default();
+        let mut document = DocumentBuilder::new()
+            .add_paragraph("첫 쪽", &style)
+            .add_table(&[vec!["가", "나"], vec!["다", "라"]])
+            .add_paragraph("둘째 쪽", &style)
+            .build();
+        let paragraphs = &mut document.body_text.sections[0].paragraphs;
+        let last = paragraphs.len() - 1;
+        paragraphs[last]
+            .para_header
+            .column_divide_type
+            .push(ColumnDivideType::Page);
+
+        let stats = document.stats();
+        assert_eq!(stats.sections, 1);
+        assert_eq!(stats.tables, 1);
+        assert_eq!(stats.characters, "첫 쪽가나다라둘째 쪽".chars().count());
+        assert_eq!(stats.characters_without_spaces, 9);
+        assert_eq!(stats.estimated_pages, 2);
+        assert_eq!(stats.profile, None);
+
+        // 앞 줄보다 위에 놓인 줄은 새 쪽 / A line placed above the previous line starts a new page
+        let paragraphs = &mut document.body_text.sections[0].paragraphs;
+        for (paragraph, position) in paragraphs.iter_mut().zip([1000, 0, 500]) {
+            let mut data = [0u8; 36];
+            data[4..8].copy_from_slice(&i32::to_le_bytes(position));
+            let segments = ParaLineSeg::parse(&data).unwrap().segments;
+            paragraph
+                .records
+                .push(ParagraphRecord::ParaLineSeg { segments });
+        }
+        assert_eq!(document.stats().estimated_pages, 3);
+    }
+}
@@ -0,0 +1,85 @@
+/// 스타일 목록 모듈 / Style catalog module
+///
+/// DocInfo의 스타일마다 이름, 종류, 다음 스타일과 스타일이 가리키는 문단 모양/글자 모양을 구체적인
+/// 값으로 풀어 모읍니다. `HwpDocument::styles_json`과 Python/Node 바인딩으로 JSON을 얻어 문서가
+/// 허용된 스타일만 쓰는지 확인할 수 있습니다.
+///
+/// Lists every style in DocInfo with its name, type, next style and the ParaShape/CharShape it
+/// references resolved into concrete values. The catalog is available as JSON through
+/// `HwpDocument::styles_json` and the Python/Node bindings, e.g. to check that a document only
+/// uses approved styles.
+use super::resolved_style::ResolvedStyle;
+use super::HwpDocument;
+use crate::document::docinfo::style::StyleType;
+use crate::error::HwpError;
+use serde::{Deserialize, Serialize};
+
+/// 스타일 목록 항목 / Style catalog entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StyleEntry {
+    /// 스타일 ID (문단 머리의 스타일 ID와 같은 0부터 센 값) / Style ID (0-based, as in paragraph headers)
+    pub id: usize,
+    /// 로컬/한글 스타일 이름 / Local/Korean style name
+    pub name: String,
+    /// 영문 스타일 이름 / English style name
+    pub english_name: String,
+    /// 스타일 종류 / Style type
+    pub style_type: StyleType,
+    /// 다음 스타일 이름 (Enter 뒤에 쓰는 스타일) / Next style name (the style used after Enter)
+    ///
+    /// HWP/HWPX 스타일은 바탕 스타일을 상속하지 않으므로 스타일 사이의 관계는 이것뿐입니다.
+    /// HWP/HWPX styles do not inherit from a base style, so this is the only link between styles.
+    pub next_style: Option<String>,
+    /// 문단 모양 ID / ParaShape ID
+    pub para_shape_id: Option<u16>,
+    /// 글자 모양 ID / CharShape ID
+    pub char_shape_id: Option<u16>,
+    /// 스타일의 문단/글자 속성 / Paragraph and character properties of the style
+    pub properties: ResolvedStyle,
+}
+
+impl HwpDocument {
+    /// 스타일 목록 / Style catalog
+    ///
+    /// # Returns / 반환값
+    /// 스타일 ID 순서의 스타일 목록 / Styles in style ID order
+    pub fn styles(&self) -> Vec<StyleEntry> {
+        let doc_info = &self.doc_info;
+        doc_info
+            .styles
+            .iter()
+            .enumerate()
+            .map(|(id, style)| {
+                let para_shape = style
+                    .para_shape_id
+                    .and_then(|id| doc_info.para_shapes.get(id as usize));
+                let char_shape = style
+                    .char_shape_id
+                    .and_then(|id| doc_info.char_shapes.get(id as usize));
+                StyleEntry {
+                    id,
+                    name: style.local_name.clone(),
+                    english_name: style.english_name.clone(),
+                    style_type: style.style_type,
+                    next_style: doc_info
+                        .styles
+                        .get(style.next_style_id as usize)
+                        .map(|next| next.local_name.clone()),
+                    para_shape_id: style.para_shape_id,
+                    char_shape_id: style.char_shape_id,
+                    properties: ResolvedStyle::from_shapes(
+                        doc_info,
+                        Some(style.local_name.clone()),
+                        para_shape,
+                        char_shape,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    /// 스타일 목록을 JSON 문자열로 변환 / Convert the style catalog to a JSON string
+    pub fn styles_json(&self) -> Result<String, HwpError> {
+        serde_json::to_string_pretty(&self.styles()).map_err(HwpError::from)
+    }
+}
@@ -17,7 +17,7 @@ use crate::types::{INT32, UINT32};
 use serde::{Deserialize, Serialize};
 
 /// 문서 요약 정보 / Document summary information
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct SummaryInformation {
     /// 제목 / Title (PIDSI_TITLE, 0x00000002)
     pub title: Option<String>,
@@ -66,9 +66,9 @@ impl FileTime {
         // FILETIME epoch: January 1, 1601
         // Unix epoch: January 1, 1970
         // Difference: 11644473600 seconds = 0x019DB1DED53E8000 in 100-nanosecond intervals
+        // 초 단위로 먼저 나누어 손상된 큰 값에서도 넘치지 않게 함 / Divide to seconds first so corrupt huge values cannot overflow
         let filetime = ((self.high as u64) << 32) | (self.low as u64);
-        let unix_time = filetime as i64 - 0x019DB1DED53E8000;
-        unix_time / 10_000_000 // Convert from 100-nanosecond intervals to seconds
+        (filetime / 10_000_000) as i64 - 11_644_473_600
     }
 
     /// FILETIME을 UTC+9 시간대의 ISO 8601 문자열로 변환 / Convert FILETIME to ISO 8601 string in UTC+9 timezone
@@ -0,0 +1,458 @@
+/// 표 데이터 추출 모듈 / Table data extraction module
+///
+/// 문서의 표를 셀 텍스트 격자로 꺼내 CSV 파일이나 표마다 시트 하나인 XLSX로 내보냅니다.
+/// Extracts document tables as grids of cell text and exports them as CSV files or as one XLSX
+/// workbook with a sheet per table.
+use super::HwpDocument;
+use crate::document::bodytext::Table;
+use crate::document::docinfo::BorderFill;
+use crate::error::HwpError;
+#[cfg(feature = "fs")]
+use std::fs;
+use std::io::{Cursor, Write};
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// 문서에서 꺼낸 표 / Table extracted from a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentTable {
+    /// 표가 들어 있는 구역 인덱스 / Index of the section containing the table
+    pub section_index: usize,
+    /// 중첩 문단(표 셀, 글상자, 머리말 등) 안의 표인지 여부 / Whether the table sits in a nested paragraph (table cell, text box, header, ...)
+    pub nested: bool,
+    /// 머리행 수 (`Table::header_rows` 참고) / Number of header rows (see `Table::header_rows`)
+    pub header_rows: usize,
+    /// 행별 셀 텍스트 (병합된 칸은 왼쪽 위 칸에만 텍스트) / Cell text per row (merged areas keep text in the top-left cell only)
+    pub rows: Vec<Vec<String>>,
+    /// 병합된 셀 영역 / Merged cell areas
+    pub merged_cells: Vec<MergedCell>,
+}
+
+/// 병합된 셀 영역 / Merged cell area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergedCell {
+    /// 시작 행 / First row
+    pub row: usize,
+    /// 시작 열 / First column
+    pub col: usize,
+    /// 행 병합 개수 / Row span
+    pub row_span: usize,
+    /// 열 병합 개수 / Column span
+    pub col_span: usize,
+}
+
+impl DocumentTable {
+    fn from_table(
+        table: &Table,
+        border_fills: &[BorderFill],
+        section_index: usize,
+        nested: bool,
+    ) -> Self {
+        // 셀 주소가 행/열 개수를 넘는 손상된 표도 모두 담도록 격자 크기를 셀에서 계산
+        // Size the grid from the cells so damaged tables whose addresses exceed the row/column counts still fit
+        let (mut row_count, mut col_count) = (
+            table.attributes.row_count as usize,
+            table.attributes.col_count as usize,
+        );
+        for cell in &table.cells {
+            let attributes = &cell.cell_attributes;
+            row_count = row_count
+                .max(attributes.row_address as usize + attributes.row_span.max(1) as usize);
+            col_count = col_count
+                .max(attributes.col_address as usize + attributes.col_span.max(1) as usize);
+        }
+
+        let mut rows = vec![vec![String::new(); col_count]; row_count];
+        let mut merged_cells = Vec::new();
+        for cell in &table.cells {
+            let attributes = &cell.cell_attributes;
+            let (row, col) = (
+                attributes.row_address as usize,
+                attributes.col_address as usize,
+            );
+            rows[row][col] = cell.text();
+            let (row_span, col_span) = (
+                attributes.row_span.max(1) as usize,
+                attributes.col_span.max(1) as usize,
+            );
+            if row_span > 1 || col_span > 1 {
+                merged_cells.push(MergedCell {
+                    row,
+                    col,
+                    row_span,
+                    col_span,
+                });
+            }
+        }
+
+        DocumentTable {
+            section_index,
+            nested,
+            header_rows: table.header_rows(border_fills),
+            rows,
+            merged_cells,
+        }
+    }
+
+    /// CSV 텍스트 (RFC 4180, 줄 끝은 CRLF) / CSV text (RFC 4180, CRLF line endings)
+    ///
+    /// 쉼표, 큰따옴표, 줄바꿈이 들어간 칸은 큰따옴표로 감쌉니다.
+    /// Fields containing commas, double quotes or line breaks are wrapped in double quotes.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter().map(|text| csv_field(text)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+impl HwpDocument {
+    /// 문서의 모든 표 (문서 순서, 셀 안의 중첩 표는 바깥 표 뒤에) / All tables in document order (nested tables follow their outer table)
+    ///
+    /// 머리말/꼬리말, 각주, 글상자 안의 표도 포함합니다.
+    /// Tables inside headers/footers, footnotes and text boxes are included.
+    ///
+    /// # Returns / 반환값
+    /// 셀 텍스트 격자로 꺼낸 표 목록 / Tables extracted as grids of cell text
+    pub fn tables(&self) -> Vec<DocumentTable> {
+        let mut tables = Vec::new();
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for paragraph in &section.paragraphs {
+                for table in paragraph.tables() {
+                    tables.push(DocumentTable::from_table(
+                        table,
+                        &self.doc_info.border_fill,
+                        section_index,
+                        false,
+                    ));
+                }
+                for nested in paragraph.nested_paragraphs() {
+                    for table in nested.tables() {
+                        tables.push(DocumentTable::from_table(
+                            table,
+                            &self.doc_info.border_fill,
+                            section_index,
+                            true,
+                        ));
+                    }
+                }
+            }
+        }
+        tables
+    }
+
+    /// 표마다 CSV 파일 하나씩 저장 ("table1.csv", "table2.csv", ...)
+    /// Save one CSV file per table ("table1.csv", "table2.csv", ...)
+    ///
+    /// 파일은 UTF-8 BOM으로 시작해 스프레드시트 프로그램이 한글을 바로 읽습니다.
+    /// Files start with a UTF-8 BOM so spreadsheet applications read Korean text correctly.
+    ///
+    /// # Arguments / 매개변수
+    /// * `dir` - 저장할 디렉토리 (없으면 생성) / Output directory (created if missing)
+    ///
+    /// # Returns / 반환값
+    /// 저장한 파일 경로 목록 / Paths of the written files
+    #[cfg(feature = "fs")]
+    pub fn export_tables_csv(&self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, HwpError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|e| {
+            HwpError::Io(format!(
+                "Failed to create directory '{}': {e}",
+                dir.display()
+            ))
+        })?;
+
+        self.tables()
+            .iter()
+            .enumerate()
+            .map(|(index, table)| {
+                let path = dir.join(format!("table{}.csv", index + 1));
+                let content = format!("\u{FEFF}{}", table.to_csv());
+                fs::write(&path, content).map_err(|e| {
+                    HwpError::Io(format!("Failed to write file '{}': {e}", path.display()))
+                })?;
+                Ok(path)
+            })
+            .collect()
+    }
+
+    /// 표마다 시트 하나인 XLSX 통합 문서 ("Table1", "Table2", ...)
+    /// XLSX workbook with one sheet per table ("Table1", "Table2", ...)
+    ///
+    /// 숫자로 읽히는 칸(천 단위 쉼표 허용)은 숫자 셀로, 나머지는 문자열 셀로 씁니다.
+    /// 병합 영역과 머리행 고정도 옮깁니다.
+    /// Cells that read as numbers (thousands separators allowed) become numeric cells and the
+    /// rest become string cells. Merged areas and frozen header rows are carried over.
+    ///
+    /// # Returns / 반환값
+    /// XLSX(ZIP) 바이트 / XLSX (ZIP) bytes
+    pub fn tables_to_xlsx(&self) -> Result<Vec<u8>, HwpError> {
+        xlsx::write(&self.tables())
+    }
+}
+
+/// CSV 칸 하나 / One CSV field
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+mod xlsx {
+    use super::*;
+
+    const NS_MAIN: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+    const NS_REL: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+    const NS_PACKAGE_REL: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+
+    /// XLSX 패키지 생성 / Build an XLSX package
+    pub(super) fn write(tables: &[DocumentTable]) -> Result<Vec<u8>, HwpError> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        // 시트가 하나도 없는 통합 문서는 열리지 않으므로 빈 시트 하나를 둠
+        // A workbook without sheets cannot be opened, so keep one empty sheet
+        let empty = [DocumentTable {
+            section_index: 0,
+            nested: false,
+            header_rows: 0,
+            rows: Vec::new(),
+            merged_cells: Vec::new(),
+        }];
+        let tables = if tables.is_empty() {
+            &empty[..]
+        } else {
+            tables
+        };
+
+        add_file(
+            &mut zip,
+            "[Content_Types].xml",
+            &content_types(tables.len()),
+        )?;
+        add_file(
+            &mut zip,
+            "_rels/.rels",
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{NS_PACKAGE_REL}"><Relationship Id="rId1" Type="{NS_REL}/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+            ),
+        )?;
+        add_file(&mut zip, "xl/workbook.xml", &workbook(tables.len()))?;
+        add_file(
+            &mut zip,
+            "xl/_rels/workbook.xml.rels",
+            &workbook_rels(tables.len()),
+        )?;
+        for (index, table) in tables.iter().enumerate() {
+            add_file(
+                &mut zip,
+                &format!("xl/worksheets/sheet{}.xml", index + 1),
+                &worksheet(table),
+            )?;
+        }
+
+        zip.finish()
+            .map(|cursor| cursor.into_inner())
+            .map_err(|e| HwpError::Io(format!("Failed to finish XLSX archive: {e}")))
+    }
+
+    fn add_file(
+        zip: &mut ZipWriter<Cursor<Vec<u8>>>,
+        path: &str,
+        content: &str,
+    ) -> Result<(), HwpError> {
+        zip.start_file(
+            path,
+            SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+        )
+        .map_err(|e| HwpError::Io(format!("Failed to add {path} to XLSX archive: {e}")))?;
+        zip.write_all(content.as_bytes())
+            .map_err(|e| HwpError::Io(format!("Failed to write {path}: {e}")))
+    }
+
+    fn content_types(sheet_count: usize) -> String {
+        let sheets: String = (1..=sheet_count)
+            .map(|n| {
+                format!(
+                    r#"<Override PartName="/xl/worksheets/sheet{n}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+                )
+            })
+            .collect();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{sheets}</Types>"#
+        )
+    }
+
+    fn workbook(sheet_count: usize) -> String {
+        let sheets: String = (1..=sheet_count)
+            .map(|n| format!(r#"<sheet name="Table{n}" sheetId="{n}" r:id="rId{n}"/>"#))
+            .collect();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="{NS_MAIN}" xmlns:r="{NS_REL}"><sheets>{sheets}</sheets></workbook>"#
+        )
+    }
+
+    fn workbook_rels(sheet_count: usize) -> String {
+        let relationships: String = (1..=sheet_count)
+            .map(|n| {
+                format!(
+                    r#"<Relationship Id="rId{n}" Type="{NS_REL}/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+                )
+            })
+            .collect();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{NS_PACKAGE_REL}">{relationships}</Relationships>"#
+        )
+    }
+
+    fn worksheet(table: &DocumentTable) -> String {
+        let mut xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="{NS_MAIN}">"#
+        );
+        if table.header_rows > 0 {
+            let top_left = cell_reference(table.header_rows, 0);
+            xml.push_str(&format!(
+                r#"<sheetViews><sheetView workbookViewId="0"><pane ySplit="{}" topLeftCell="{top_left}" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews>"#,
+                table.header_rows
+            ));
+        }
+        xml.push_str("<sheetData>");
+        for (row_index, row) in table.rows.iter().enumerate() {
+            xml.push_str(&format!(r#"<row r="{}">"#, row_index + 1));
+            for (col_index, text) in row.iter().enumerate() {
+                if text.is_empty() {
+                    continue;
+                }
+                let reference = cell_reference(row_index, col_index);
+                match parse_number(text) {
+                    Some(number) => {
+                        xml.push_str(&format!(r#"<c r="{reference}"><v>{number}</v></c>"#))
+                    }
+                    None => xml.push_str(&format!(
+                        r#"<c r="{reference}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                        escape_xml(text)
+                    )),
+                }
+            }
+            xml.push_str("</row>");
+        }
+        xml.push_str("</sheetData>");
+        if !table.merged_cells.is_empty() {
+            xml.push_str(&format!(
+                r#"<mergeCells count="{}">"#,
+                table.merged_cells.len()
+            ));
+            for merged in &table.merged_cells {
+                xml.push_str(&format!(
+                    r#"<mergeCell ref="{}:{}"/>"#,
+                    cell_reference(merged.row, merged.col),
+                    cell_reference(
+                        merged.row + merged.row_span - 1,
+                        merged.col + merged.col_span - 1
+                    )
+                ));
+            }
+            xml.push_str("</mergeCells>");
+        }
+        xml.push_str("</worksheet>");
+        xml
+    }
+
+    /// 0부터 센 행/열을 A1 형식으로 / Zero-based row and column in A1 notation
+    fn cell_reference(row: usize, col: usize) -> String {
+        let mut letters = Vec::new();
+        let mut n = col + 1;
+        while n > 0 {
+            let rem = (n - 1) % 26;
+            letters.push(b'A' + rem as u8);
+            n = (n - 1) / 26;
+        }
+        letters.reverse();
+        format!("{}{}", String::from_utf8_lossy(&letters), row + 1)
+    }
+
+    /// 숫자로 읽히는 칸 (천 단위 쉼표 허용) / Cell text that reads as a number (thousands separators allowed)
+    fn parse_number(text: &str) -> Option<f64> {
+        let text = text.trim();
+        let digits = text.trim_start_matches(['-', '+']);
+        // "007"처럼 앞자리 0이 의미 있는 코드는 문자열로 둠 / Keep codes with meaningful leading zeros such as "007" as strings
+        if digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.") {
+            return None;
+        }
+        if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let grouped = integer.contains(',');
+        if grouped {
+            let mut groups = integer.split(',');
+            let first = groups.next().unwrap_or_default();
+            if first.is_empty() || first.len() > 3 || groups.any(|group| group.len() != 3) {
+                return None;
+            }
+        }
+        let valid = |part: &str| part.chars().all(|c| c.is_ascii_digit() || c == ',');
+        if !valid(integer) || fraction.contains(',') || !valid(fraction) {
+            return None;
+        }
+        text.replace(',', "").parse::<f64>().ok()
+    }
+
+    /// XML 텍스트 이스케이프 (XML에 쓸 수 없는 제어 문자는 제거)
+    /// Escape XML text (control characters not allowed in XML are dropped)
+    fn escape_xml(text: &str) -> String {
+        text.chars()
+            .filter(|&c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+            .collect::<String>()
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_cell_reference_and_numbers() {
+            assert_eq!(cell_reference(0, 0), "A1");
+            assert_eq!(cell_reference(9, 25), "Z10");
+            assert_eq!(cell_reference(0, 27), "AB1");
+            assert_eq!(parse_number("1,234.5"), Some(1234.5));
+            assert_eq!(parse_number("-12"), Some(-12.0));
+            assert_eq!(parse_number("0.25"), Some(0.25));
+            assert_eq!(parse_number("007"), None);
+            assert_eq!(parse_number("12,34"), None);
+            assert_eq!(parse_number("3개"), None);
+            assert_eq!(parse_number(""), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        let table = DocumentTable {
+            section_index: 0,
+            nested: false,
+            header_rows: 1,
+            rows: vec![
+                vec!["구분".to_string(), "값, 단위".to_string()],
+                vec!["\"인구\"".to_string(), "1\n2".to_string()],
+            ],
+            merged_cells: Vec::new(),
+        };
+        assert_eq!(
+            table.to_csv(),
+            "구분,\"값, 단위\"\r\n\"\"\"인구\"\"\",\"1\n2\"\r\n"
+        );
+    }
+}
@@ -0,0 +1,556 @@
+/// 문서 평문 추출 모듈 / Document plain text extraction module
+///
+/// 바인딩의 `get_text`가 함께 쓰는 평문 규칙(문단 구분자, 공백 정리, 빈 문단 처리)을 한곳에 둡니다.
+/// Keeps the plain text rules shared by the bindings' `get_text` (paragraph separator, trimming,
+/// blank paragraphs) in one place.
+use super::bodytext::{ParaTextRun, Paragraph, ParagraphRecord};
+use super::{ContentFilter, HwpDocument};
+use serde::{Deserialize, Serialize};
+
+/// 문단 공백 정리 방식 / Paragraph whitespace trimming
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrimMode {
+    /// 앞뒤 공백 제거 / Trim both ends
+    #[default]
+    Both,
+    /// 뒤 공백만 제거 (들여쓰기 유지) / Trim the end only (keeps indentation)
+    End,
+    /// 그대로 유지 / Keep as is
+    None,
+}
+
+impl TrimMode {
+    /// 이름으로 찾기 ("both", "end", "none") / Look up by name ("both", "end", "none")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "both" => Some(TrimMode::Both),
+            "end" => Some(TrimMode::End),
+            "none" => Some(TrimMode::None),
+            _ => None,
+        }
+    }
+
+    /// 이름 / Name
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrimMode::Both => "both",
+            TrimMode::End => "end",
+            TrimMode::None => "none",
+        }
+    }
+
+    fn apply(self, text: &str) -> &str {
+        match self {
+            TrimMode::Both => text.trim(),
+            TrimMode::End => text.trim_end(),
+            TrimMode::None => text,
+        }
+    }
+}
+
+/// 평문 추출 옵션 / Plain text extraction options
+///
+/// 기본값은 이전 `get_text`와 같습니다: 앞뒤 공백 제거, 빈 문단 제외, 줄바꿈으로 연결.
+/// Defaults match the previous `get_text`: trimmed, blank paragraphs dropped, joined by newlines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextOptions {
+    /// 문단 구분자 / Paragraph separator
+    pub separator: String,
+    /// 공백 정리 방식 / Whitespace trimming
+    pub trim: TrimMode,
+    /// 빈 문단도 출력 (빈 줄 유지) / Also emit blank paragraphs (keeps blank lines)
+    pub keep_blank_paragraphs: bool,
+    /// 차트 제목, 축 제목, 범례, 항목 이름도 출력 / Also emit chart titles, axis titles, legends and category labels
+    pub include_charts: bool,
+    /// 걸러 낼 숨은 설명/개인 정보 필드/보이지 않는 문자 / Hidden comments, personal information fields and invisible characters to filter out
+    pub content_filter: ContentFilter,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            separator: "\n".to_string(),
+            trim: TrimMode::Both,
+            keep_blank_paragraphs: false,
+            include_charts: false,
+            content_filter: ContentFilter::default(),
+        }
+    }
+}
+
+impl TextOptions {
+    /// 문단 구분자 설정 / Set paragraph separator
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// 공백 정리 방식 설정 / Set whitespace trimming
+    pub fn with_trim(mut self, trim: TrimMode) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// 빈 문단 출력 여부 설정 / Set whether blank paragraphs are emitted
+    pub fn with_keep_blank_paragraphs(mut self, keep_blank_paragraphs: bool) -> Self {
+        self.keep_blank_paragraphs = keep_blank_paragraphs;
+        self
+    }
+
+    /// 차트 텍스트 출력 여부 설정 / Set whether chart text is emitted
+    pub fn with_include_charts(mut self, include_charts: bool) -> Self {
+        self.include_charts = include_charts;
+        self
+    }
+
+    /// 걸러 낼 내용 설정 / Set the content to filter out
+    pub fn with_content_filter(mut self, content_filter: ContentFilter) -> Self {
+        self.content_filter = content_filter;
+        self
+    }
+}
+
+impl HwpDocument {
+    /// 본문 평문 (기본 옵션) / Body plain text with the default options
+    pub fn text(&self) -> String {
+        self.text_with_options(&TextOptions::default())
+    }
+
+    /// 본문 평문 / Body plain text
+    ///
+    /// 구역의 최상위 문단만 이어 붙입니다. 표 셀이나 글상자 안의 문단은 포함하지 않습니다.
+    /// `include_charts`를 켜면 문단에 들어 있는 차트의 텍스트(`ChartData::labels`)가 문단 뒤에
+    /// 한 줄씩 붙습니다.
+    /// Joins the top-level paragraphs of each section. Paragraphs inside table cells or text boxes
+    /// are not included. With `include_charts`, the text of the paragraph's charts
+    /// (`ChartData::labels`) follows the paragraph, one label per line.
+    ///
+    /// `content_filter`가 있으면 걸러 낸 문서 사본의 평문입니다.
+    /// With a `content_filter`, this is the plain text of a filtered copy of the document.
+    pub fn text_with_options(&self, options: &TextOptions) -> String {
+        if !options.content_filter.is_empty() {
+            return self
+                .with_content_filter(Some(options.content_filter))
+                .text_with_options(
+                    &options
+                        .clone()
+                        .with_content_filter(ContentFilter::default()),
+                );
+        }
+        let mut result = String::new();
+        self.for_each_text_piece(options, |piece| {
+            if piece.separated {
+                result.push_str(&options.separator);
+            }
+            result.push_str(piece.text);
+        });
+        result
+    }
+
+    /// 평문 오프셋 → 원본 위치 대응표 (기본 옵션) / Plain text offset → source position map with the default options
+    pub fn build_offset_map(&self) -> TextOffsetMap {
+        self.build_offset_map_with_options(&TextOptions::default())
+    }
+
+    /// 평문 오프셋 → 원본 위치 대응표 / Plain text offset → source position map
+    ///
+    /// [`HwpDocument::text_with_options`]와 같은 평문을 만들고, 그 안의 각 구간이 어느 구역/문단/
+    /// 텍스트 조각(run)의 몇 번째 글자에서 왔는지 기록합니다. 오프셋은 바이트가 아닌 글자(char)
+    /// 단위라 Python 문자열 인덱스와 같습니다. 문단 구분자와 차트 텍스트는 원본 문단이 없어
+    /// 대응표에 들어가지 않습니다.
+    /// Builds the same plain text as [`HwpDocument::text_with_options`] and records which
+    /// section/paragraph/text run, and which character of it, each span came from. Offsets count
+    /// characters (chars), not bytes, so they match Python string indices. Paragraph separators
+    /// and chart text have no source paragraph and are left out of the map.
+    ///
+    /// `content_filter`가 있으면 위치도 걸러 낸 사본의 텍스트 조각을 가리킵니다.
+    /// With a `content_filter`, positions also refer to the text runs of the filtered copy.
+    pub fn build_offset_map_with_options(&self, options: &TextOptions) -> TextOffsetMap {
+        if !options.content_filter.is_empty() {
+            return self
+                .with_content_filter(Some(options.content_filter))
+                .build_offset_map_with_options(
+                    &options
+                        .clone()
+                        .with_content_filter(ContentFilter::default()),
+                );
+        }
+        let mut map = TextOffsetMap::default();
+        let mut offset = 0;
+        self.for_each_text_piece(options, |piece| {
+            if piece.separated {
+                map.text.push_str(&options.separator);
+                offset += options.separator.chars().count();
+            }
+            map.text.push_str(piece.text);
+            let len = piece.text.chars().count();
+            if let Some((section_index, paragraph_index, paragraph)) = piece.source {
+                // 잘라낸 앞 공백만큼 건너뛰고 run 경계로 나눔 / Skip the trimmed leading whitespace and split at run boundaries
+                let mut skip = piece.leading_chars;
+                let mut remaining = len;
+                for (run_index, run) in text_runs(paragraph).into_iter().enumerate() {
+                    let run_len = run.chars().count();
+                    if skip >= run_len {
+                        skip -= run_len;
+                        continue;
+                    }
+                    let span_len = (run_len - skip).min(remaining);
+                    if span_len == 0 {
+                        break;
+                    }
+                    map.spans.push(TextSpan {
+                        offset: offset + len - remaining,
+                        len: span_len,
+                        section_index,
+                        paragraph_index,
+                        run_index,
+                        run_offset: skip,
+                    });
+                    remaining -= span_len;
+                    skip = 0;
+                }
+            }
+            offset += len;
+        });
+        map
+    }
+
+    /// 평문을 이루는 조각을 순서대로 넘김 / Pass the pieces making up the plain text in order
+    fn for_each_text_piece<'a>(
+        &'a self,
+        options: &TextOptions,
+        mut f: impl FnMut(TextPiece<'_, 'a>),
+    ) {
+        let mut first = true;
+        let mut emit =
+            |text: &str, leading_chars: usize, source: Option<(usize, usize, &'a Paragraph)>| {
+                f(TextPiece {
+                    separated: !first,
+                    text,
+                    leading_chars,
+                    source,
+                });
+                first = false;
+            };
+        for (section_index, section) in self.body_text.sections.iter().enumerate() {
+            for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let full = paragraph.text();
+                let text = options.trim.apply(&full);
+                // 공백만 있는 문단은 빈 문단 / Whitespace-only paragraphs count as blank
+                if !text.trim().is_empty() || options.keep_blank_paragraphs {
+                    let leading_chars =
+                        full[..full.len() - full.trim_start().len()].chars().count()
+                            * usize::from(options.trim == TrimMode::Both);
+                    emit(
+                        text,
+                        leading_chars,
+                        Some((section_index, paragraph_index, paragraph)),
+                    );
+                }
+                if options.include_charts {
+                    for chart in paragraph.charts() {
+                        for label in chart.labels() {
+                            emit(label, 0, None);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 평문 조각 하나 / One piece of the plain text
+struct TextPiece<'t, 'a> {
+    /// 앞에 문단 구분자가 오는지 / Whether a paragraph separator precedes it
+    separated: bool,
+    text: &'t str,
+    /// 잘라낸 앞 공백 글자 수 / Number of trimmed leading whitespace characters
+    leading_chars: usize,
+    /// (구역, 문단, 문단) / (section, paragraph index, paragraph)
+    source: Option<(usize, usize, &'a Paragraph)>,
+}
+
+/// 문단의 텍스트 조각 (`ParaTextRun::Text`, 조각 정보가 없는 레코드는 텍스트 전체)
+/// Text runs of a paragraph (`ParaTextRun::Text`; records without run information are one run)
+///
+/// 조각을 이은 결과가 레코드 텍스트와 다르면 레코드 텍스트 전체를 한 조각으로 봅니다.
+/// When the joined runs differ from the record text, the whole record text is taken as one run.
+fn text_runs(paragraph: &Paragraph) -> Vec<&str> {
+    let mut result = Vec::new();
+    for record in &paragraph.records {
+        let ParagraphRecord::ParaText { text, runs, .. } = record else {
+            continue;
+        };
+        let run_texts: Vec<&str> = runs
+            .iter()
+            .filter_map(|run| match run {
+                ParaTextRun::Text { text } => Some(text.as_str()),
+                ParaTextRun::Control { .. } => None,
+            })
+            .collect();
+        if !run_texts.is_empty() && run_texts.concat() == *text {
+            result.extend(run_texts);
+        } else if !text.is_empty() {
+            result.push(text.as_str());
+        }
+    }
+    result
+}
+
+/// 평문 구간의 원본 위치 / Source position of a plain text span
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+    /// 평문에서 시작 글자 오프셋 / Starting character offset in the plain text
+    pub offset: usize,
+    /// 글자 수 / Number of characters
+    pub len: usize,
+    /// 구역 인덱스 / Section index
+    pub section_index: usize,
+    /// 구역 내 문단 인덱스 / Paragraph index within the section
+    pub paragraph_index: usize,
+    /// 문단 안 텍스트 조각 인덱스 (ParaText 레코드를 가로질러 셈)
+    /// Text run index within the paragraph (counted across ParaText records)
+    pub run_index: usize,
+    /// 조각 안 시작 글자 오프셋 / Starting character offset within the run
+    pub run_offset: usize,
+}
+
+/// 평문 글자 하나의 원본 위치 / Source position of one plain text character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextPosition {
+    /// 구역 인덱스 / Section index
+    pub section_index: usize,
+    /// 구역 내 문단 인덱스 / Paragraph index within the section
+    pub paragraph_index: usize,
+    /// 문단 안 텍스트 조각 인덱스 / Text run index within the paragraph
+    pub run_index: usize,
+    /// 조각 안 글자 오프셋 / Character offset within the run
+    pub run_offset: usize,
+}
+
+/// 문단의 문서 안 위치 / Location of a paragraph in the document
+///
+/// 검색 결과, 필드, 토큰, 덩어리 등이 원본 문단을 가리킬 때 함께 씁니다. 직렬화하면 세 필드가
+/// 바깥 구조체에 펼쳐집니다.
+/// Shared by search hits, fields, tokens, chunks and the like to point back at their source
+/// paragraph. When serialized, the three fields are flattened into the outer struct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ParagraphLocation {
+    /// 구역 인덱스 / Section index
+    pub section_index: usize,
+    /// 구역 내 최상위 문단 인덱스 / Top-level paragraph index within the section
+    pub paragraph_index: usize,
+    /// 중첩 문단 인덱스 (`Paragraph::nested_paragraphs` 순서, 최상위 문단이면 None)
+    /// Nested paragraph index (in `Paragraph::nested_paragraphs` order, None for the top-level paragraph)
+    pub nested_index: Option<usize>,
+}
+
+impl ParagraphLocation {
+    /// 최상위 문단 위치 / Location of a top-level paragraph
+    pub fn top_level(section_index: usize, paragraph_index: usize) -> Self {
+        Self {
+            section_index,
+            paragraph_index,
+            nested_index: None,
+        }
+    }
+
+    /// 같은 최상위 문단 안의 중첩 문단 위치 / Location of a nested paragraph within the same top-level paragraph
+    pub fn nested(self, nested_index: Option<usize>) -> Self {
+        Self {
+            nested_index,
+            ..self
+        }
+    }
+}
+
+/// 평문 오프셋 대응표 / Plain text offset map
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextOffsetMap {
+    /// 대응표가 가리키는 평문 (`text_with_options`와 같음) / The plain text the map refers to (same as `text_with_options`)
+    pub text: String,
+    /// 오프셋 순서의 구간 / Spans in offset order
+    pub spans: Vec<TextSpan>,
+}
+
+impl TextOffsetMap {
+    /// 글자 오프셋의 원본 위치 (문단 구분자나 차트 텍스트면 `None`)
+    /// Source position of a character offset (`None` for paragraph separators and chart text)
+    pub fn position(&self, offset: usize) -> Option<TextPosition> {
+        let index = self
+            .spans
+            .partition_point(|span| span.offset + span.len <= offset);
+        let span = self.spans.get(index).filter(|span| span.offset <= offset)?;
+        Some(TextPosition {
+            section_index: span.section_index,
+            paragraph_index: span.paragraph_index,
+            run_index: span.run_index,
+            run_offset: span.run_offset + offset - span.offset,
+        })
+    }
+
+    /// 글자 구간 `[start, end)`를 원본 구간들로 투영 (구분자 등 대응 없는 글자는 빠짐)
+    /// Project the character range `[start, end)` onto source spans (unmapped characters such as
+    /// separators are dropped)
+    pub fn project(&self, start: usize, end: usize) -> Vec<TextSpan> {
+        let first = self
+            .spans
+            .partition_point(|span| span.offset + span.len <= start);
+        self.spans[first..]
+            .iter()
+            .take_while(|span| span.offset < end)
+            .map(|span| {
+                let from = start.max(span.offset);
+                let to = end.min(span.offset + span.len);
+                TextSpan {
+                    offset: from,
+                    len: to - from,
+                    run_offset: span.run_offset + from - span.offset,
+                    ..*span
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+
+    fn document() -> HwpDocument {
+        let style = Default::default();
+        DocumentBuilder::new()
+            .add_paragraph("  제목  ", &style)
+            .add_paragraph("", &style)
+            .add_paragraph("    들여쓴 줄", &style)
+            .build()
+    }
+
+    #[test]
+    fn test_text_defaults_trim_and_drop_blank() {
+        assert_eq!(document().text(), "제목\n들여쓴 줄");
+    }
+
+    #[test]
+    fn test_text_with_options() {
+        let options = TextOptions::default()
+            .with_separator("\r\n")
+            .with_trim(TrimMode::End)
+            .with_keep_blank_paragraphs(true);
+        assert_eq!(
+            document().text_with_options(&options),
+            "  제목\r\n\r\n    들여쓴 줄"
+        );
+
+        let options = TextOptions::default().with_trim(TrimMode::None);
+        assert_eq!(
+            document().text_with_options(&options),
+            "  제목  \n    들여쓴 줄"
+        );
+        assert_eq!(TrimMode::from_name("end"), Some(TrimMode::End));
+        assert_eq!(TrimMode::from_name("left"), None);
+    }
+
+    #[test]
+    fn test_offset_map_positions() {
+        let map = document().build_offset_map();
+        assert_eq!(map.text, "제목\n들여쓴 줄");
+        // 잘라낸 앞 공백은 조각 안 오프셋에 반영 / Trimmed leading whitespace shows in the run offset
+        assert_eq!(
+            map.position(0),
+            Some(TextPosition {
+                section_index: 0,
+                paragraph_index: 0,
+                run_index: 0,
+                run_offset: 2,
+            })
+        );
+        assert_eq!(map.position(2), None);
+        assert_eq!(
+            map.position(3).map(|p| (p.paragraph_index, p.run_offset)),
+            Some((2, 4))
+        );
+        assert_eq!(map.position(8), None);
+
+        let spans = map.project(1, 5);
+        assert_eq!(spans.len(), 2);
+        assert_eq!(
+            (spans[0].offset, spans[0].len, spans[0].run_offset),
+            (1, 1, 3)
+        );
+        assert_eq!(
+            (spans[1].offset, spans[1].len, spans[1].run_offset),
+            (3, 2, 4)
+        );
+    }
+
+    #[test]
+    fn test_offset_map_splits_runs() {
+        let mut document = document();
+        document.body_text.sections[0].paragraphs[0].records = vec![ParagraphRecord::ParaText {
+            text: "가나다라".to_string(),
+            runs: vec![
+                ParaTextRun::Text {
+                    text: "가나".to_string(),
+                },
+                ParaTextRun::Control {
+                    position: 2,
+                    code: 9,
+                    name: "TAB".to_string(),
+                    size_wchars: 8,
+                    display_text: None,
+                },
+                ParaTextRun::Text {
+                    text: "다라".to_string(),
+                },
+            ],
+            control_char_positions: Vec::new(),
+            inline_control_params: Vec::new(),
+        }];
+        let map = document.build_offset_map();
+        assert_eq!(map.text, document.text());
+        assert_eq!(
+            map.position(3).map(|p| (p.run_index, p.run_offset)),
+            Some((1, 1))
+        );
+        let spans = map.project(1, 3);
+        assert_eq!(
+            spans
+                .iter()
+                .map(|span| (span.run_index, span.run_offset, span.len))
+                .collect::<Vec<_>>(),
+            vec![(0, 1, 1), (1, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_text_includes_charts() {
+        use crate::document::bodytext::{ChartData, ChartTitle, ParagraphRecord, VtChart};
+
+        let mut document = document();
+        let mut chart = VtChart {
+            title: Some(ChartTitle {
+                text: "분기 매출".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        chart.data_grid.row_labels = vec!["1분기".to_string(), "2분기".to_string()];
+        document.body_text.sections[0].paragraphs[0]
+            .records
+            .push(ParagraphRecord::ChartData {
+                chart_data: ChartData {
+                    vt_chart: Some(chart),
+                    raw_data: Vec::new(),
+                },
+            });
+
+        assert_eq!(document.text(), "제목\n들여쓴 줄");
+        assert_eq!(
+            document.text_with_options(&TextOptions::default().with_include_charts(true)),
+            "제목\n분기 매출\n1분기\n2분기\n들여쓴 줄"
+        );
+    }
+}
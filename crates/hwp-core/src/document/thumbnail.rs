@@ -0,0 +1,342 @@
+/// 썸네일 모듈 (`image-processing` 기능) / Thumbnail module (`image-processing` feature)
+///
+/// 파일 관리자나 미리보기 연동용으로 문서 첫 쪽을 PNG로 만듭니다. 문서에 저장된 미리보기
+/// 이미지(PrvImage)가 있으면 그것을 줄이고, 없으면 용지 크기에 맞춰 제목, 첫 문단들, 첫 그림을
+/// 간단히 배치합니다. 글꼴을 싣지 않으므로 글자는 줄마다 회색 막대로 그립니다.
+///
+/// Renders the first page of a document as a PNG for file-manager and preview integrations.
+/// When the document stores a preview image (PrvImage) it is scaled down; otherwise the title,
+/// first paragraphs and first picture are laid out simply on the paper size. No fonts are
+/// bundled, so text is drawn as a grey bar per line.
+use image::codecs::png::PngEncoder;
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, Rgb, RgbImage};
+
+use super::bodytext::{PageDef, ParagraphRecord};
+use super::HwpDocument;
+use crate::error::HwpError;
+use crate::viewer::markdown::utils::outline_level;
+
+/// A4 용지 크기 (HWPUNIT) / A4 paper size (HWPUNIT)
+const A4: (f64, f64) = (59528.0, 84188.0);
+/// 기본 여백 (20mm) / Default margin (20mm)
+const DEFAULT_MARGIN: f64 = 5669.0;
+/// 본문 글자 크기 (10pt) / Body font size (10pt)
+const FONT_SIZE: f64 = 1000.0;
+/// 제목 글자 크기 배율 / Title font size ratio
+const TITLE_SCALE: f64 = 1.6;
+/// 줄 간격 배율 / Line spacing ratio
+const LINE_SPACING: f64 = 1.6;
+/// 그림이 차지할 수 있는 용지 높이 비율 / Share of the paper height a picture may take
+const MAX_PICTURE_SHARE: f64 = 0.4;
+
+const PAPER: Rgb<u8> = Rgb([255, 255, 255]);
+const BORDER: Rgb<u8> = Rgb([200, 200, 200]);
+const TITLE: Rgb<u8> = Rgb([60, 60, 60]);
+const HEADING: Rgb<u8> = Rgb([110, 110, 110]);
+const BODY: Rgb<u8> = Rgb([170, 170, 170]);
+
+impl HwpDocument {
+    /// 첫 쪽 썸네일 PNG 만들기 / Render a first-page thumbnail as PNG
+    ///
+    /// 저장된 미리보기 이미지를 읽을 수 있으면 그것을, 아니면 첫 쪽의 간단한 배치를 그립니다.
+    /// 높이는 미리보기 이미지나 용지의 비율을 따릅니다.
+    /// Uses the stored preview image when it can be decoded, otherwise draws a simple layout of
+    /// the first page. The height follows the aspect ratio of the preview image or the paper.
+    ///
+    /// # Arguments / 매개변수
+    /// * `width` - 썸네일 너비 (픽셀, 16~4096으로 맞춤) / Thumbnail width (pixels, clamped to 16..=4096)
+    ///
+    /// # Returns / 반환값
+    /// PNG 바이트 / PNG bytes
+    pub fn render_thumbnail(&self, width: u32) -> Result<Vec<u8>, HwpError> {
+        let width = width.clamp(16, 4096);
+        let thumbnail = match self.preview_picture() {
+            Some(preview) => {
+                let height = scaled_height(preview.width(), preview.height(), width);
+                preview
+                    .resize_exact(width, height, FilterType::CatmullRom)
+                    .to_rgb8()
+            }
+            None => self.render_first_page(width),
+        };
+
+        let mut data = Vec::new();
+        DynamicImage::ImageRgb8(thumbnail)
+            .write_with_encoder(PngEncoder::new(&mut data))
+            .map_err(|e| HwpError::InternalError {
+                message: format!("Failed to encode thumbnail: {e}"),
+            })?;
+        Ok(data)
+    }
+
+    /// 저장된 미리보기 이미지 디코딩 / Decode the stored preview image
+    fn preview_picture(&self) -> Option<DynamicImage> {
+        let data = self.preview_image.as_ref()?.bytes().ok()?;
+        image::load_from_memory(&data)
+            .ok()
+            .filter(|picture| picture.width() > 0 && picture.height() > 0)
+    }
+
+    /// 첫 쪽 배치 그리기 / Draw the first page layout
+    fn render_first_page(&self, width: u32) -> RgbImage {
+        let page_def = self.first_page_def();
+        let (paper_width, paper_height) = page_def
+            .filter(|pd| pd.paper_width.0 > 0 && pd.paper_height.0 > 0)
+            .map(|pd| (pd.paper_width.0 as f64, pd.paper_height.0 as f64))
+            .unwrap_or(A4);
+        let (left, right, top, bottom) = page_def
+            .map(|pd| {
+                (
+                    pd.left_margin.0 as f64,
+                    pd.right_margin.0 as f64,
+                    pd.top_margin.0 as f64,
+                    pd.bottom_margin.0 as f64,
+                )
+            })
+            .filter(|(l, r, t, b)| l + r < paper_width && t + b < paper_height)
+            .unwrap_or((
+                DEFAULT_MARGIN,
+                DEFAULT_MARGIN,
+                DEFAULT_MARGIN,
+                DEFAULT_MARGIN,
+            ));
+
+        let scale = width as f64 / paper_width;
+        let height = ((paper_height * scale).round() as u32).max(1);
+        let mut canvas = RgbImage::from_pixel(width, height, PAPER);
+        outline(&mut canvas);
+
+        let mut layout = Layout {
+            canvas: &mut canvas,
+            scale,
+            left,
+            content_width: paper_width - left - right,
+            y: top,
+            bottom: paper_height - bottom,
+        };
+
+        let mut picture = self.images().into_iter().find_map(|image| {
+            image::load_from_memory(&image.data)
+                .ok()
+                .filter(|picture| picture.width() > 0 && picture.height() > 0)
+        });
+        let paragraphs = self
+            .body_text
+            .sections
+            .first()
+            .map(|section| section.paragraphs.as_slice())
+            .unwrap_or_default();
+        let mut title_drawn = false;
+        for paragraph in paragraphs {
+            if layout.is_full() {
+                break;
+            }
+            let text = paragraph.text();
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            if !title_drawn {
+                layout.text(text, FONT_SIZE * TITLE_SCALE, TITLE);
+                title_drawn = true;
+                // 첫 그림은 제목 바로 아래 / The first picture goes right below the title
+                if let Some(picture) = picture.take() {
+                    layout.picture(&picture);
+                }
+                continue;
+            }
+            let color = if outline_level(&paragraph.para_header, self).is_some() {
+                HEADING
+            } else {
+                BODY
+            };
+            layout.text(text, FONT_SIZE, color);
+        }
+        if let Some(picture) = picture {
+            layout.picture(&picture);
+        }
+        canvas
+    }
+
+    /// 첫 용지 설정 / First page definition
+    fn first_page_def(&self) -> Option<&PageDef> {
+        let section = self.body_text.sections.first()?;
+        section.paragraphs.iter().find_map(|paragraph| {
+            paragraph.records.iter().find_map(|record| match record {
+                ParagraphRecord::PageDef { page_def } => Some(page_def),
+                ParagraphRecord::CtrlHeader { children, .. } => {
+                    children.iter().find_map(|child| match child {
+                        ParagraphRecord::PageDef { page_def } => Some(page_def),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+        })
+    }
+}
+
+/// 쪽 위에 내용을 위에서 아래로 쌓는 배치 (좌표는 HWPUNIT) / Layout stacking content down a page (coordinates in HWPUNIT)
+struct Layout<'a> {
+    canvas: &'a mut RgbImage,
+    scale: f64,
+    left: f64,
+    content_width: f64,
+    y: f64,
+    bottom: f64,
+}
+
+impl Layout<'_> {
+    fn is_full(&self) -> bool {
+        self.y >= self.bottom
+    }
+
+    /// 문단을 줄 단위 막대로 그림 / Draw a paragraph as one bar per line
+    fn text(&mut self, text: &str, font_size: f64, color: Rgb<u8>) {
+        let mut line = 0.0;
+        let mut lines = Vec::new();
+        for c in text.chars() {
+            let advance = font_size * char_width(c);
+            if line + advance > self.content_width && line > 0.0 {
+                lines.push(line);
+                line = 0.0;
+            }
+            line += advance;
+        }
+        lines.push(line);
+
+        let line_height = font_size * LINE_SPACING;
+        for line in lines {
+            if self.y + line_height > self.bottom {
+                self.y = self.bottom;
+                return;
+            }
+            // 글자 높이의 가운데 60%를 칠함 / Fill the middle 60% of the glyph height
+            let bar_top = self.y + font_size * 0.2;
+            self.fill(self.left, bar_top, line, font_size * 0.6, color);
+            self.y += line_height;
+        }
+    }
+
+    /// 그림을 본문 너비에 맞춰 그림 / Draw a picture fitted to the body width
+    fn picture(&mut self, picture: &DynamicImage) {
+        let page_height = self.canvas.height() as f64 / self.scale;
+        let max_height = (self.bottom - self.y).min(page_height * MAX_PICTURE_SHARE);
+        if max_height <= 0.0 {
+            return;
+        }
+        let fit =
+            (self.content_width / picture.width() as f64).min(max_height / picture.height() as f64);
+        let (width, height) = (picture.width() as f64 * fit, picture.height() as f64 * fit);
+        let (pixel_width, pixel_height) = (
+            (width * self.scale).round() as u32,
+            (height * self.scale).round() as u32,
+        );
+        if pixel_width == 0 || pixel_height == 0 {
+            return;
+        }
+        let resized = picture
+            .resize_exact(pixel_width, pixel_height, FilterType::Triangle)
+            .to_rgb8();
+        let x = ((self.left + (self.content_width - width) / 2.0) * self.scale).round() as i64;
+        imageops::overlay(
+            self.canvas,
+            &resized,
+            x,
+            (self.y * self.scale).round() as i64,
+        );
+        self.y += height + FONT_SIZE * (LINE_SPACING - 1.0);
+    }
+
+    /// HWPUNIT 사각형 칠하기 (최소 1픽셀) / Fill a rectangle in HWPUNIT (at least one pixel)
+    fn fill(&mut self, x: f64, y: f64, width: f64, height: f64, color: Rgb<u8>) {
+        let x0 = (x * self.scale).round() as u32;
+        let y0 = (y * self.scale).round() as u32;
+        let x1 = (((x + width) * self.scale).round() as u32).max(x0 + 1);
+        let y1 = (((y + height) * self.scale).round() as u32).max(y0 + 1);
+        for py in y0..y1.min(self.canvas.height()) {
+            for px in x0..x1.min(self.canvas.width()) {
+                self.canvas.put_pixel(px, py, color);
+            }
+        }
+    }
+}
+
+/// 글자 너비 (글자 크기 배율): 한글/한자 등 전각은 1, 나머지는 0.5
+/// Character width as a ratio of the font size: 1 for full-width (Hangul, Han, ...), 0.5 otherwise
+fn char_width(c: char) -> f64 {
+    if (c as u32) >= 0x1100 {
+        1.0
+    } else {
+        0.5
+    }
+}
+
+/// 너비에 맞춘 높이 (비율 유지) / Height for a width, keeping the aspect ratio
+fn scaled_height(source_width: u32, source_height: u32, width: u32) -> u32 {
+    ((source_height as f64 * width as f64 / source_width as f64).round() as u32).max(1)
+}
+
+/// 용지 테두리 / Paper border
+fn outline(canvas: &mut RgbImage) {
+    let (width, height) = canvas.dimensions();
+    for x in 0..width {
+        canvas.put_pixel(x, 0, BORDER);
+        canvas.put_pixel(x, height - 1, BORDER);
+    }
+    for y in 0..height {
+        canvas.put_pixel(0, y, BORDER);
+        canvas.put_pixel(width - 1, y, BORDER);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::{DocumentBuilder, ParagraphStyle};
+    use crate::document::PreviewImage;
+    use image::{GenericImageView, ImageBuffer, Rgb};
+
+    fn png(width: u32, height: u32, color: [u8; 3]) -> Vec<u8> {
+        let picture = ImageBuffer::from_pixel(width, height, Rgb(color));
+        let mut data = Vec::new();
+        image::DynamicImage::ImageRgb8(picture)
+            .write_with_encoder(image::codecs::png::PngEncoder::new(&mut data))
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_thumbnail_uses_preview_image() {
+        let mut document = DocumentBuilder::new().build();
+        document.preview_image =
+            Some(PreviewImage::parse(&png(200, 100, [0, 0, 255]), None).unwrap());
+
+        let thumbnail = image::load_from_memory(&document.render_thumbnail(64).unwrap()).unwrap();
+        assert_eq!(thumbnail.dimensions(), (64, 32));
+        assert_eq!(thumbnail.to_rgb8().get_pixel(32, 16), &Rgb([0, 0, 255]));
+    }
+
+    #[test]
+    fn test_thumbnail_lays_out_first_page() {
+        let style = ParagraphStyle::default();
+        let document = DocumentBuilder::new()
+            .add_paragraph("제목", &style)
+            .add_paragraph(&"본문 ".repeat(200), &style)
+            .build();
+
+        let thumbnail = image::load_from_memory(&document.render_thumbnail(210).unwrap())
+            .unwrap()
+            .to_rgb8();
+        // A4 비율 / A4 aspect ratio
+        assert_eq!(thumbnail.dimensions(), (210, 297));
+        let inked = |color: Rgb<u8>| thumbnail.pixels().filter(|&&p| p == color).count();
+        assert!(inked(super::TITLE) > 0);
+        assert!(inked(super::BODY) > inked(super::TITLE));
+
+        // 빈 문서도 용지만 그림 / An empty document still draws the paper
+        let empty = DocumentBuilder::new().build();
+        let thumbnail = image::load_from_memory(&empty.render_thumbnail(0).unwrap()).unwrap();
+        assert_eq!(thumbnail.width(), 16);
+    }
+}
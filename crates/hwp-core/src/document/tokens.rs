@@ -0,0 +1,241 @@
+/// 텍스트 토큰 스트림 모듈 / Text token stream module
+///
+/// 맞춤법 검사나 형태소 분석처럼 문서 트리를 다시 순회하지 않고 텍스트만 차례로 받으려는 소비자를
+/// 위해, 문단 텍스트를 글자 모양이 같은 조각으로 나누고 문단/셀 위치와 머리말·꼬리말·각주·표 안인지를
+/// 함께 붙입니다.
+/// For consumers such as spellcheckers and morphological analyzers that want text in order
+/// without re-walking the document tree, splits paragraph text into pieces sharing one char shape
+/// and annotates them with their paragraph/cell location and whether they sit inside a header,
+/// footer, footnote or table.
+use serde::{Deserialize, Serialize};
+
+use super::bodytext::{CharShapeInfo, ParaTextRun, Paragraph, ParagraphRecord, Table, TableCell};
+use super::{CtrlHeader, CtrlId, HwpDocument, ParagraphLocation};
+use crate::types::{UINT16, UINT32, UINT8};
+use crate::viewer::core::visitor::{walk_document, DocumentVisitor};
+
+/// 텍스트 토큰 하나 / A single text token
+///
+/// 한 텍스트 조각(run) 안에서 글자 모양이 바뀌는 곳마다 나뉩니다.
+/// Split wherever the char shape changes within a text run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextToken {
+    pub text: String,
+    /// 원본 문단 위치 / Source paragraph location
+    #[serde(flatten)]
+    pub location: ParagraphLocation,
+    /// 문단 안 텍스트 조각 인덱스 (`TextPosition::run_index`와 같은 기준)
+    /// Text run index within the paragraph (same basis as `TextPosition::run_index`)
+    pub run_index: usize,
+    /// 조각 안 시작 글자 오프셋 / Starting character offset within the run
+    pub run_offset: usize,
+    /// 문단 스타일 ID / Paragraph style ID
+    pub style_id: UINT8,
+    /// 글자 모양 ID (글자 모양 정보가 없으면 None) / Char shape ID (None without char shape information)
+    pub char_shape_id: Option<UINT32>,
+    /// 가장 안쪽 표 셀 (행, 열) / Innermost table cell (row, column)
+    pub cell: Option<(UINT16, UINT16)>,
+    /// 머리말 안인지 / Whether inside a header
+    pub in_header: bool,
+    /// 꼬리말 안인지 / Whether inside a footer
+    pub in_footer: bool,
+    /// 각주나 미주 안인지 / Whether inside a footnote or endnote
+    pub in_footnote: bool,
+    /// 표 안인지 / Whether inside a table
+    pub in_table: bool,
+}
+
+impl HwpDocument {
+    /// 문서 텍스트를 토큰 스트림으로 / The document text as a token stream
+    ///
+    /// 본문, 표 셀, 글상자, 머리말/꼬리말, 각주/미주의 텍스트를 문서 순서대로 넘깁니다. 문단을
+    /// 나누는 구분자는 넣지 않으므로 토큰의 위치로 문단 경계를 알 수 있습니다.
+    /// Yields the text of the body, table cells, text boxes, headers/footers and footnotes/endnotes
+    /// in document order. No paragraph separators are inserted; paragraph boundaries follow from
+    /// the token locations.
+    pub fn tokens(&self) -> impl Iterator<Item = TextToken> {
+        let mut collector = TokenCollector::default();
+        walk_document(self, &mut collector);
+        collector.tokens.into_iter()
+    }
+}
+
+/// 토큰 수집 방문자 / Token collecting visitor
+#[derive(Default)]
+struct TokenCollector<'a> {
+    tokens: Vec<TextToken>,
+    /// 현재 최상위 문단 (구역, 문단)과 그 중첩 문단 / Current top-level (section, paragraph) and its nested paragraphs
+    location: ParagraphLocation,
+    nested: Vec<&'a Paragraph>,
+    /// 열린 컨트롤 ID / IDs of the open controls
+    controls: Vec<&'a str>,
+    /// 열린 표 셀 (행, 열) / Open table cells (row, column)
+    cells: Vec<(UINT16, UINT16)>,
+    tables: usize,
+}
+
+impl<'a> TokenCollector<'a> {
+    fn inside(&self, ids: &[&str]) -> bool {
+        self.controls.iter().any(|id| ids.contains(id))
+    }
+
+    fn push_paragraph(&mut self, paragraph: &'a Paragraph, nested_index: Option<usize>) {
+        let shapes: Vec<&CharShapeInfo> = paragraph
+            .records
+            .iter()
+            .filter_map(|record| match record {
+                ParagraphRecord::ParaCharShape { shapes } => Some(shapes),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let shape_at = |position: usize| {
+            shapes
+                .iter()
+                .take_while(|shape| shape.position as usize <= position)
+                .last()
+                .map(|shape| shape.shape_id)
+        };
+
+        // 글자 모양 위치와 같은 원본 WCHAR 기준 / Original WCHAR basis, same as char shape positions
+        let mut position = 0;
+        let mut run_index = 0;
+        let mut push_run = |this: &mut Self, text: &str, position: &mut usize| {
+            let mut start = 0;
+            let mut current = None;
+            for (offset, (byte, c)) in text.char_indices().enumerate() {
+                let shape_id = shape_at(*position);
+                if offset == 0 {
+                    current = Some((0, shape_id));
+                } else if current.is_some_and(|(_, id)| id != shape_id) {
+                    let (run_offset, char_shape_id) = current.unwrap();
+                    this.push(
+                        &text[start..byte],
+                        (nested_index, run_index, run_offset),
+                        paragraph,
+                        char_shape_id,
+                    );
+                    start = byte;
+                    current = Some((offset, shape_id));
+                }
+                *position += c.len_utf16();
+            }
+            if let Some((run_offset, char_shape_id)) = current {
+                this.push(
+                    &text[start..],
+                    (nested_index, run_index, run_offset),
+                    paragraph,
+                    char_shape_id,
+                );
+            }
+            run_index += 1;
+        };
+
+        for record in &paragraph.records {
+            let ParagraphRecord::ParaText { text, runs, .. } = record else {
+                continue;
+            };
+            // 조각을 이은 결과가 레코드 텍스트와 다르면 전체를 한 조각으로 (평문 대응표와 같은 규칙)
+            // When the joined runs differ from the record text, take it as one run (same rule as the offset map)
+            let run_texts: Vec<&str> = runs
+                .iter()
+                .filter_map(|run| match run {
+                    ParaTextRun::Text { text } => Some(text.as_str()),
+                    ParaTextRun::Control { .. } => None,
+                })
+                .collect();
+            if run_texts.is_empty() || run_texts.concat() != *text {
+                if !text.is_empty() {
+                    push_run(self, text, &mut position);
+                }
+                continue;
+            }
+            for run in runs {
+                match run {
+                    ParaTextRun::Text { text } => push_run(self, text, &mut position),
+                    ParaTextRun::Control {
+                        position: start,
+                        size_wchars,
+                        ..
+                    } => position = start + size_wchars,
+                }
+            }
+        }
+    }
+
+    fn push(
+        &mut self,
+        text: &str,
+        (nested_index, run_index, run_offset): (Option<usize>, usize, usize),
+        paragraph: &Paragraph,
+        char_shape_id: Option<UINT32>,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        self.tokens.push(TextToken {
+            text: text.to_string(),
+            location: self.location.nested(nested_index),
+            run_index,
+            run_offset,
+            style_id: paragraph.para_header.para_style_id,
+            char_shape_id,
+            cell: self.cells.last().copied(),
+            in_header: self.inside(&[CtrlId::HEADER]),
+            in_footer: self.inside(&[CtrlId::FOOTER]),
+            in_footnote: self.inside(&[CtrlId::FOOTNOTE, CtrlId::ENDNOTE]),
+            in_table: self.tables > 0,
+        });
+    }
+}
+
+impl<'a> DocumentVisitor<'a> for TokenCollector<'a> {
+    fn enter_paragraph(
+        &mut self,
+        paragraph: &'a Paragraph,
+        location: Option<(usize, usize)>,
+    ) -> bool {
+        let nested_index = match location {
+            Some((section_index, paragraph_index)) => {
+                self.location = ParagraphLocation::top_level(section_index, paragraph_index);
+                self.nested = paragraph.nested_paragraphs();
+                None
+            }
+            None => self
+                .nested
+                .iter()
+                .position(|nested| std::ptr::eq(*nested, paragraph)),
+        };
+        self.push_paragraph(paragraph, nested_index);
+        true
+    }
+
+    fn enter_control(&mut self, header: &'a CtrlHeader) -> bool {
+        self.controls.push(header.ctrl_id.as_str());
+        true
+    }
+
+    fn leave_control(&mut self, _header: &'a CtrlHeader) {
+        self.controls.pop();
+    }
+
+    fn enter_table(&mut self, _table: &'a Table) -> bool {
+        self.tables += 1;
+        true
+    }
+
+    fn leave_table(&mut self, _table: &'a Table) {
+        self.tables -= 1;
+    }
+
+    fn enter_cell(&mut self, cell: &'a TableCell) -> bool {
+        let attributes = &cell.cell_attributes;
+        self.cells
+            .push((attributes.row_address, attributes.col_address));
+        true
+    }
+
+    fn leave_cell(&mut self, _cell: &'a TableCell) {
+        self.cells.pop();
+    }
+}
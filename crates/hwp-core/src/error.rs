@@ -1,8 +1,17 @@
 /// Error types for HWP file parsing
 ///
 /// This module defines all error types that can occur during HWP file parsing.
+///
+/// Every error has a stable numeric code (`HwpError::code`) for aggregating failure causes.
+/// Errors raised while reading a part of the file carry an `ErrorContext` (container path,
+/// byte offset, XML line/column, record tag or element), and errors from underlying libraries
+/// keep the original error reachable through `std::error::Error::source`.
+use std::sync::Arc;
+
 use thiserror::Error;
 
+use crate::parser::limits::ResourceLimit;
+
 /// Main error type for HWP parsing operations
 #[derive(Debug, Clone, Error)]
 pub enum HwpError {
@@ -76,6 +85,14 @@ pub enum HwpError {
         supported_versions: String,
     },
 
+    /// Document is encrypted with a password (distribution or password-protected document)
+    #[error("Document is password protected")]
+    PasswordRequired,
+
+    /// Document is protected by DRM and needs a license to open
+    #[error("Document is DRM protected: {reason}")]
+    DrmProtected { reason: String },
+
     /// Invalid document signature
     #[error("Invalid HWP document signature: expected 'HWP Document File', got '{found}'")]
     InvalidSignature { found: String },
@@ -105,6 +122,10 @@ pub enum HwpError {
     #[error("Invalid HWPX structure: {reason}")]
     InvalidHwpxStructure { reason: String },
 
+    /// Resource limit exceeded (zip bomb, too many entries, deeply nested XML)
+    #[error("Resource limit exceeded: {limit} is over {max}")]
+    ResourceLimitExceeded { limit: ResourceLimit, max: u64 },
+
     // ===== Other errors =====
     /// IO error
     #[error("IO error: {0}")]
@@ -118,9 +139,167 @@ pub enum HwpError {
     #[error("JSON serialization error: {0}")]
     JsonError(String),
 
+    /// Invalid search query (e.g. malformed regular expression)
+    #[error("Invalid search query: {reason}")]
+    InvalidQuery { reason: String },
+
     /// Internal error (unexpected situation)
     #[error("Internal error: {message}")]
     InternalError { message: String },
+
+    // ===== Wrappers =====
+    /// Error with the location where it occurred
+    #[error("{source} ({context})")]
+    WithContext {
+        context: Box<ErrorContext>,
+        source: Box<HwpError>,
+    },
+
+    /// Error caused by an underlying library error (the message already describes the cause)
+    #[error("{error}")]
+    Caused {
+        error: Box<HwpError>,
+        #[source]
+        cause: ErrorCause,
+    },
+}
+
+/// Location of an error inside the file
+///
+/// Fields that are unknown at the point where the error is raised stay `None`; outer callers
+/// fill in what they know (e.g. the container path) without overwriting inner details.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Path inside the container (HWPX ZIP entry such as "Contents/section0.xml", or HWP 5.0
+    /// stream such as "BodyText/Section0")
+    pub container_path: Option<String>,
+    /// Byte offset within the part (XML text or decompressed stream)
+    pub byte_offset: Option<u64>,
+    /// Line number within the XML part (1-based)
+    pub line: Option<u32>,
+    /// Column within the line, in characters (1-based)
+    pub column: Option<u32>,
+    /// HWP 5.0 record tag ID
+    pub record_tag: Option<u16>,
+    /// XML element name (e.g. "hp:p")
+    pub element: Option<String>,
+}
+
+impl ErrorContext {
+    /// Create an empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Context for a part of the container
+    pub fn in_container(path: impl Into<String>) -> Self {
+        Self::new().with_container_path(path)
+    }
+
+    /// Set the container path
+    pub fn with_container_path(mut self, path: impl Into<String>) -> Self {
+        self.container_path = Some(path.into());
+        self
+    }
+
+    /// Set the byte offset
+    pub fn with_byte_offset(mut self, offset: u64) -> Self {
+        self.byte_offset = Some(offset);
+        self
+    }
+
+    /// Set the byte offset within XML text, deriving the line and column from it
+    pub fn with_xml_position(mut self, text: impl AsRef<[u8]>, offset: u64) -> Self {
+        let text = text.as_ref();
+        let before = &text[..(offset as usize).min(text.len())];
+        let line_start = before
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |index| index + 1);
+        // Count characters by skipping UTF-8 continuation bytes
+        let column = before[line_start..]
+            .iter()
+            .filter(|&&byte| byte & 0xC0 != 0x80)
+            .count();
+        self.byte_offset = Some(offset);
+        self.line = Some(before.iter().filter(|&&byte| byte == b'\n').count() as u32 + 1);
+        self.column = Some(column as u32 + 1);
+        self
+    }
+
+    /// Set the HWP 5.0 record tag ID
+    pub fn with_record_tag(mut self, tag_id: u16) -> Self {
+        self.record_tag = Some(tag_id);
+        self
+    }
+
+    /// Set the XML element name
+    pub fn with_element(mut self, element: impl Into<String>) -> Self {
+        self.element = Some(element.into());
+        self
+    }
+
+    /// Fill the fields that are still unknown from `other`
+    fn merge(&mut self, other: ErrorContext) {
+        self.container_path = self.container_path.take().or(other.container_path);
+        self.byte_offset = self.byte_offset.or(other.byte_offset);
+        self.line = self.line.or(other.line);
+        self.column = self.column.or(other.column);
+        self.record_tag = self.record_tag.or(other.record_tag);
+        self.element = self.element.take().or(other.element);
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(path) = &self.container_path {
+            parts.push(format!("in '{path}'"));
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => parts.push(format!("line {line}, column {column}")),
+            (Some(line), None) => parts.push(format!("line {line}")),
+            _ => {}
+        }
+        if let Some(offset) = self.byte_offset {
+            parts.push(format!("byte {offset}"));
+        }
+        if let Some(tag_id) = self.record_tag {
+            parts.push(format!("record tag 0x{tag_id:03X}"));
+        }
+        if let Some(element) = &self.element {
+            parts.push(format!("element <{element}>"));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// Underlying library error kept as the `source` of an `HwpError`
+///
+/// Shared so that `HwpError` stays `Clone`. It dereferences to the original error, which is
+/// what `source()` returns, so callers can downcast it (e.g. to `quick_xml::Error`).
+#[derive(Debug, Clone)]
+pub struct ErrorCause(Arc<dyn std::error::Error + Send + Sync>);
+
+impl ErrorCause {
+    /// Wrap an error
+    pub fn new(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self(Arc::new(error))
+    }
+}
+
+impl std::ops::Deref for ErrorCause {
+    type Target = dyn std::error::Error + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl std::fmt::Display for ErrorCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
 }
 
 /// Compression format type
@@ -146,6 +325,154 @@ impl std::fmt::Display for CompressionFormat {
 pub type HwpResult<T> = Result<T, HwpError>;
 
 impl HwpError {
+    /// Stable numeric code of the error kind, for telemetry
+    ///
+    /// Codes are grouped by area and never reused: 1xxx CFB, 2xxx decompression,
+    /// 3xxx record parsing, 4xxx document structure, 5xxx HWPX, 9xxx other.
+    /// Wrappers (`WithContext`, `Caused`) report the code of the wrapped error.
+    pub fn code(&self) -> u16 {
+        match self.kind() {
+            HwpError::CfbParse(_) => 1001,
+            HwpError::StreamNotFound { .. } => 1002,
+            HwpError::StreamReadError { .. } => 1003,
+            HwpError::CfbFileTooSmall { .. } => 1004,
+            HwpError::InvalidDirectorySector { .. } => 1005,
+            HwpError::InvalidSectorSize { .. } => 1006,
+            HwpError::DecompressError { .. } => 2001,
+            HwpError::InsufficientData { .. } => 3001,
+            HwpError::UnexpectedValue { .. } => 3002,
+            HwpError::RecordParseError { .. } => 3003,
+            HwpError::RecordTreeParseError { .. } => 3004,
+            HwpError::RequiredStreamMissing { .. } => 4001,
+            HwpError::UnsupportedVersion { .. } => 4002,
+            HwpError::PasswordRequired => 4003,
+            HwpError::DrmProtected { .. } => 4004,
+            HwpError::InvalidSignature { .. } => 4005,
+            HwpError::UnknownFormat => 4006,
+            HwpError::UnsupportedFormat { .. } => 4007,
+            HwpError::ZipParseError(_) => 5001,
+            HwpError::XmlParseError(_) => 5002,
+            HwpError::HwpxFileNotFound { .. } => 5003,
+            HwpError::InvalidHwpxStructure { .. } => 5004,
+            HwpError::ResourceLimitExceeded { .. } => 5005,
+            HwpError::Io(_) => 9001,
+            HwpError::EncodingError { .. } => 9002,
+            HwpError::JsonError(_) => 9003,
+            HwpError::InvalidQuery { .. } => 9004,
+            HwpError::InternalError { .. } => 9999,
+            HwpError::WithContext { .. } | HwpError::Caused { .. } => {
+                unreachable!("kind() unwraps wrappers")
+            }
+        }
+    }
+
+    /// Stable snake_case name of the error kind (e.g. "xml_parse_error"), matching `code`
+    pub fn code_name(&self) -> &'static str {
+        match self.code() {
+            1001 => "cfb_parse",
+            1002 => "stream_not_found",
+            1003 => "stream_read_error",
+            1004 => "cfb_file_too_small",
+            1005 => "invalid_directory_sector",
+            1006 => "invalid_sector_size",
+            2001 => "decompress_error",
+            3001 => "insufficient_data",
+            3002 => "unexpected_value",
+            3003 => "record_parse_error",
+            3004 => "record_tree_parse_error",
+            4001 => "required_stream_missing",
+            4002 => "unsupported_version",
+            4003 => "password_required",
+            4004 => "drm_protected",
+            4005 => "invalid_signature",
+            4006 => "unknown_format",
+            4007 => "unsupported_format",
+            5001 => "zip_parse_error",
+            5002 => "xml_parse_error",
+            5003 => "hwpx_file_not_found",
+            5004 => "invalid_hwpx_structure",
+            5005 => "resource_limit_exceeded",
+            9001 => "io",
+            9002 => "encoding_error",
+            9003 => "json_error",
+            9004 => "invalid_query",
+            _ => "internal_error",
+        }
+    }
+
+    /// The error without its `WithContext`/`Caused` wrappers, for matching on the kind
+    ///
+    /// ```ignore
+    /// if matches!(error.kind(), HwpError::PasswordRequired) { /* ... */ }
+    /// ```
+    pub fn kind(&self) -> &HwpError {
+        match self {
+            HwpError::WithContext { source, .. } => source.kind(),
+            HwpError::Caused { error, .. } => error.kind(),
+            error => error,
+        }
+    }
+
+    /// Location where the error occurred, if known
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            HwpError::WithContext { context, .. } => Some(context),
+            HwpError::Caused { error, .. } => error.context(),
+            _ => None,
+        }
+    }
+
+    /// Attach location context
+    ///
+    /// When the error already has context, only the fields it does not know yet are filled in,
+    /// so the innermost (most precise) location wins.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            HwpError::WithContext {
+                context: mut existing,
+                source,
+            } => {
+                existing.merge(context);
+                HwpError::WithContext {
+                    context: existing,
+                    source,
+                }
+            }
+            HwpError::Caused { error, cause } if error.context().is_some() => HwpError::Caused {
+                error: Box::new(error.with_context(context)),
+                cause,
+            },
+            error => HwpError::WithContext {
+                context: Box::new(context),
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// Keep the underlying library error as the `source` of this error
+    pub fn caused_by(self, cause: impl std::error::Error + Send + Sync + 'static) -> Self {
+        HwpError::Caused {
+            error: Box::new(self),
+            cause: ErrorCause::new(cause),
+        }
+    }
+
+    /// Create an `XmlParseError` that keeps the XML error as its source and its position
+    ///
+    /// * `what` - What was being parsed (e.g. "section XML")
+    /// * `text` - The XML text, used to derive the line and column
+    /// * `offset` - Byte offset of the error (`Reader::error_position`)
+    pub(crate) fn xml_parse(
+        what: &str,
+        error: quick_xml::Error,
+        text: impl AsRef<[u8]>,
+        offset: u64,
+    ) -> Self {
+        HwpError::XmlParseError(format!("Error parsing {what}: {error}"))
+            .caused_by(error)
+            .with_context(ErrorContext::new().with_xml_position(text, offset))
+    }
+
     /// Create an `InsufficientData` error with field name
     pub fn insufficient_data(field: impl Into<String>, expected: usize, actual: usize) -> Self {
         Self::InsufficientData {
@@ -207,14 +534,14 @@ impl From<&str> for HwpError {
 /// Conversion from std::io::Error to HwpError
 impl From<std::io::Error> for HwpError {
     fn from(err: std::io::Error) -> Self {
-        HwpError::Io(err.to_string())
+        HwpError::Io(err.to_string()).caused_by(err)
     }
 }
 
 /// Conversion from serde_json::Error to HwpError
 impl From<serde_json::Error> for HwpError {
     fn from(err: serde_json::Error) -> Self {
-        HwpError::JsonError(err.to_string())
+        HwpError::JsonError(err.to_string()).caused_by(err)
     }
 }
 
@@ -225,3 +552,50 @@ impl From<HwpError> for String {
         err.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_context_wraps_without_changing_kind_or_code() {
+        let error = HwpError::insufficient_data("ParaText", 4, 2)
+            .with_context(ErrorContext::new().with_record_tag(0x043))
+            .with_context(
+                ErrorContext::in_container("BodyText/Section0")
+                    .with_record_tag(0x042)
+                    .with_byte_offset(128),
+            );
+        assert!(matches!(error.kind(), HwpError::InsufficientData { .. }));
+        assert_eq!(
+            (error.code(), error.code_name()),
+            (3001, "insufficient_data")
+        );
+
+        // 안쪽 레코드 태그가 남고 바깥 정보가 채워짐 / The inner record tag stays, outer details fill in
+        let context = error.context().unwrap();
+        assert_eq!(context.record_tag, Some(0x043));
+        assert_eq!(context.container_path.as_deref(), Some("BodyText/Section0"));
+        assert_eq!(
+            error.to_string(),
+            "Insufficient data for field 'ParaText': expected at least 4 bytes, got 2 bytes \
+             (in 'BodyText/Section0', byte 128, record tag 0x043)"
+        );
+    }
+
+    #[test]
+    fn test_xml_position_and_source_chain() {
+        let context = ErrorContext::new().with_xml_position("<a>\n  <한글/>", 9);
+        assert_eq!((context.line, context.column), (Some(2), Some(5)));
+
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "truncated");
+        let error = HwpError::from(io).with_context(ErrorContext::in_container("DocInfo"));
+        assert_eq!(error.code(), 9001);
+        assert_eq!(error.to_string(), "IO error: truncated (in 'DocInfo')");
+        // WithContext → Caused → io::Error
+        let cause = error.source().and_then(|inner| inner.source()).unwrap();
+        let io = cause.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(io.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
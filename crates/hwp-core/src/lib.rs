@@ -32,40 +32,95 @@
 //! let parser = HwpParser::new();
 //! let document = parser.parse(&file_bytes)?;  // Automatically detects HWP or HWPX
 //! ```
-
+//!
+//! ## Untrusted Input
+//! `HwpParser::parse` does not panic on malformed input: truncated records, out-of-range
+//! offsets and self-referencing structures are reported as `HwpError`, so a bad upload cannot
+//! take down the calling thread. The HWP 5.0 record parsers, the chart data parser and the
+//! whole parser are covered by the cargo-fuzz targets in `fuzz/`. Use `ResourceLimits` to
+//! also bound memory use for HWPX.
+//!
+//! ## Cargo Features
+//! - `fs` (default): filesystem access — `HwpParser::parse_file`, the `batch` module and saving
+//!   pictures/tables as files. Without it, `image_output_dir` options are ignored and pictures
+//!   are embedded as data URIs (or handed to an `ImageSink`), so the parser and viewers build
+//!   for `wasm32-unknown-unknown`.
+//! - `async`: `parse_async` and `convert_dir_async`, which run parsing on the Tokio blocking pool.
+//! - `cache`: the `cache` module, which memoizes parse results and converted outputs by content
+//!   hash in a pluggable store.
+//! - `image-processing`: picture downscaling/recompression on export and
+//!   `HwpDocument::render_thumbnail`.
+//! - `chrono`, `test-support`: see `Cargo.toml`.
+
+#[cfg(feature = "async")]
+pub mod async_api;
+#[cfg(feature = "fs")]
+pub mod batch;
+pub mod builder;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod cfb;
+pub mod chunking;
 pub mod decompress;
+pub mod diff;
 pub mod document;
 pub mod error;
+pub mod mail_merge;
+pub mod merge;
 pub mod parser;
+pub mod review;
+#[cfg(any(test, feature = "test-support"))]
+pub mod testing;
 pub mod types;
+pub mod validate;
 pub mod viewer;
+pub mod writer;
 
 use ::cfb::CompoundFile;
-use std::io::Cursor;
+use parser::profile::Profiler;
+use std::io::{Cursor, Read, Seek};
 
+#[cfg(all(feature = "async", feature = "fs"))]
+pub use async_api::convert_dir_async;
+#[cfg(feature = "async")]
+pub use async_api::{parse_async, parse_async_with_options};
 pub use cfb::CfbParser;
 pub use decompress::{decompress_deflate, decompress_zlib};
 pub use document::{
-    BinData, BinDataRecord, BodyText, BorderFill, Bullet, CharShape, DocInfo, DocumentProperties,
-    FaceName, FileHeader, HwpDocument, IdMappings, Numbering, ParaShape, Section,
-    SummaryInformation, TabDef,
+    BinData, BinDataRecord, BodyText, BorderFill, Bullet, CharShape, ContentFilter, DocInfo,
+    DocumentProperties, DocumentStats, DocumentTable, FaceName, FileHeader, FormatInfo,
+    HwpDocument, IdMappings, JsonOptions, Numbering, ParaShape, ParagraphLocation, Section,
+    SecurityInfo, SourceFormat, StyleEntry, SummaryInformation, TabDef, TextOffsetMap, TextOptions,
+    TextPosition, TextSpan, TrimMode, JSON_SCHEMA_VERSION,
+};
+pub use error::{CompressionFormat, ErrorCause, ErrorContext, HwpError};
+pub use parser::{
+    ParseOptions, ParseOutcome, ParsePhase, ParseProfile, ParseWarning, PhaseTiming, ResourceLimit,
+    ResourceLimits, SalvageLog,
 };
-pub use error::{CompressionFormat, HwpError};
 pub use types::{
     RecordHeader, BYTE, COLORREF, DWORD, HWPUNIT, HWPUNIT16, INT16, INT32, INT8, SHWPUNIT, UINT,
     UINT16, UINT32, UINT8, WCHAR, WORD,
 };
+pub use validate::{validate, Severity, ValidationCode, ValidationFinding, ValidationReport};
 
 /// Main HWP parser structure
 pub struct HwpParser {
-    // Placeholder for future implementation
+    options: ParseOptions,
 }
 
 impl HwpParser {
     /// Create a new HWP parser
     pub fn new() -> Self {
-        Self {}
+        Self {
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// Create a parser with parse options
+    /// 파싱 옵션을 지정하여 파서 생성
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self { options }
     }
 
     /// Parse HWP or HWPX file from byte array
@@ -81,16 +136,200 @@ impl HwpParser {
     /// # Supported Formats
     /// - **HWP 5.0**: CFB-based binary format (magic: D0 CF 11 E0)
     /// - **HWPX**: ZIP-based XML format (magic: PK..)
+    /// - **Templates** (.hwt, .hwtx): same containers as above; set `ParseOptions::template`
+    ///   or use `parse_file` to mark the document as a template
+    ///
+    /// # Panics
+    /// Never panics on malformed input; corrupt files are reported as errors.
     pub fn parse(&self, data: &[u8]) -> Result<HwpDocument, HwpError> {
+        self.parse_with_warnings(data).map(|(document, _)| document)
+    }
+
+    /// Parse HWP or HWPX file and return warnings for repaired problems
+    ///
+    /// `ParseOptions::repair_xml`, `ParseOptions::lenient`처럼 복구 옵션이 켜져 있을 때 고치거나
+    /// 건너뛴 내용을 경고로 돌려줍니다. 같은 경고가 `HwpDocument::warnings`에도 담깁니다.
+    /// Returns what was fixed or skipped as warnings when recovery options such as
+    /// `ParseOptions::repair_xml` or `ParseOptions::lenient` are enabled. The same warnings are
+    /// also stored in `HwpDocument::warnings`.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    ///
+    /// # Returns
+    /// Parsed HWP document structure and parse warnings
+    pub fn parse_with_warnings(
+        &self,
+        data: &[u8],
+    ) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+        use parser::{detect_format, FileFormat};
+
+        let (mut document, warnings) = match detect_format(data) {
+            FileFormat::Hwp5 => (self.parse_hwp5(data)?, Vec::new()),
+            FileFormat::Hwpx => parser::hwpx::parse_with_options(data, &self.options)?,
+            FileFormat::Hwpml => parser::hwpml::parse_with_options(data, &self.options)?,
+            FileFormat::Hwp3 => parser::hwp3::parse_with_options(data, &self.options)?,
+            FileFormat::Unknown => return Err(HwpError::UnknownFormat),
+        };
+        document.is_template = self.options.template;
+        document.resource_limits = self.options.limits;
+        Ok((document, warnings))
+    }
+
+    /// Parse a damaged HWP or HWPX file, keeping whatever content is recoverable
+    ///
+    /// 읽을 수 없는 파트는 건너뛰고, 잘린 ZIP은 로컬 파일 헤더로 다시 만들며, 구역 순서는
+    /// spine(HWPX)이나 BodyText 스트림 번호(HWP 5.0)로 다시 맞춥니다. 본문을 하나도 읽지 못하면
+    /// 미리보기 텍스트를 본문으로 씁니다. HWPX는 항상 `lenient`와 `repair_xml`을 켜고 읽습니다.
+    /// Skips unreadable parts, rebuilds truncated ZIPs from their local file headers and
+    /// reconstructs section order from the spine (HWPX) or BodyText stream numbers (HWP 5.0).
+    /// When no body text can be read, the preview text becomes the body. HWPX is always read
+    /// with `lenient` and `repair_xml` enabled.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    ///
+    /// # Returns
+    /// Recovered document and a log of what was rebuilt or skipped; errors only when nothing
+    /// can be read (e.g. unknown format, unreadable CFB or FileHeader)
+    pub fn parse_salvage(&self, data: &[u8]) -> Result<(HwpDocument, SalvageLog), HwpError> {
+        use parser::{detect_format, salvage, FileFormat};
+
+        let (mut document, log) = match detect_format(data) {
+            FileFormat::Hwp5 => salvage::salvage_hwp5(self, data)?,
+            FileFormat::Hwpx => salvage::salvage_hwpx(data, &self.options)?,
+            FileFormat::Hwpml => salvage::salvage_hwpml(data, &self.options)?,
+            FileFormat::Hwp3 => salvage::salvage_hwp3(data, &self.options)?,
+            FileFormat::Unknown => return Err(HwpError::UnknownFormat),
+        };
+        document.is_template = self.options.template;
+        document.resource_limits = self.options.limits;
+        Ok((document, log))
+    }
+
+    /// Parse HWP or HWPX file, falling back to the preview text when parsing fails
+    ///
+    /// 전체 파싱이 실패하면 `PrvText` 스트림(HWP 5.0)이나 `Preview/PrvText.txt`(HWPX)를 읽어
+    /// `ParseOutcome::PreviewOnly`로 돌려줍니다. 리소스 제한 초과는 대체하지 않습니다.
+    /// When full parsing fails, the `PrvText` stream (HWP 5.0) or `Preview/PrvText.txt` (HWPX) is
+    /// read and returned as `ParseOutcome::PreviewOnly`. Exceeded resource limits do not fall back.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    ///
+    /// # Returns
+    /// The full document or the preview text; errors with the parse error when there is no
+    /// readable preview text either
+    pub fn parse_or_preview(&self, data: &[u8]) -> Result<ParseOutcome, HwpError> {
+        match self.parse(data) {
+            Ok(document) => Ok(ParseOutcome::Full(document)),
+            Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+                Err(error)
+            }
+            Err(error) => match parser::preview::read_preview_text(data, &self.options.limits) {
+                Some(text) => Ok(ParseOutcome::PreviewOnly { text, error }),
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Read only the security information of an HWP or HWPX file
+    ///
+    /// 본문을 파싱하지 않고 FileHeader, DocOptions(HWP 5.0)나 `META-INF`, content.hpf(HWPX)만
+    /// 읽습니다. DRM 문서도 오류 없이 정보를 돌려주므로 업로드 파이프라인이 파싱 전에
+    /// `SecurityInfo::rejection_reason`으로 거절 사유를 알릴 수 있습니다. HWPML과 HWP 3.0은
+    /// 빈 정보를 돌려줍니다.
+    /// Reads only the FileHeader and DocOptions (HWP 5.0) or `META-INF` and content.hpf (HWPX)
+    /// without parsing the body. DRM documents return their information without an error, so
+    /// upload pipelines can report `SecurityInfo::rejection_reason` before parsing. HWPML and
+    /// HWP 3.0 return empty information.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    ///
+    /// # Returns
+    /// Security information; errors only when the container or FileHeader cannot be read
+    pub fn security_info(&self, data: &[u8]) -> Result<SecurityInfo, HwpError> {
         use parser::{detect_format, FileFormat};
 
         match detect_format(data) {
-            FileFormat::Hwp5 => self.parse_hwp5(data),
-            FileFormat::Hwpx => parser::hwpx::parse(data),
+            FileFormat::Hwp5 => {
+                let mut cfb = CfbParser::parse(data)?;
+                let fileheader = self.parse_fileheader(&mut cfb)?;
+                let mut info = SecurityInfo::parse_doc_options(&mut cfb);
+                info.apply_file_header(&fileheader);
+                // 암호화된 DocInfo는 읽을 수 없음 / Encrypted DocInfo cannot be read
+                if info.rejection_reason().is_none() {
+                    if let Ok(doc_info) = self.parse_docinfo(&mut cfb, &fileheader) {
+                        info.add_bin_data_links(&doc_info.bin_data);
+                    }
+                }
+                Ok(info)
+            }
+            FileFormat::Hwpx => {
+                let mut container = parser::hwpx::container::HwpxContainer::open_with_limits(
+                    data,
+                    self.options.limits,
+                )?;
+                Ok(parser::hwpx::security::parse_security(&mut container))
+            }
+            FileFormat::Hwpml | FileFormat::Hwp3 => Ok(SecurityInfo::default()),
             FileFormat::Unknown => Err(HwpError::UnknownFormat),
         }
     }
 
+    /// Parse HWP or HWPX file from a path
+    ///
+    /// 확장자가 .hwt/.hwtx이면 `ParseOptions::template`이 꺼져 있어도 서식 파일로 표시합니다.
+    /// Files with a .hwt/.hwtx extension are marked as templates even when
+    /// `ParseOptions::template` is off.
+    ///
+    /// # Arguments
+    /// * `path` - Path to an .hwp, .hwpx, .hwt or .hwtx file
+    ///
+    /// # Returns
+    /// Parsed HWP document structure
+    #[cfg(feature = "fs")]
+    pub fn parse_file(&self, path: impl AsRef<std::path::Path>) -> Result<HwpDocument, HwpError> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let mut document = self.parse_reader(std::io::BufReader::new(file))?;
+        document.is_template |= parser::is_template_path(path);
+        Ok(document)
+    }
+
+    /// Parse HWP or HWPX file from a seekable reader
+    ///
+    /// HWPX는 ZIP 항목을 필요할 때마다 읽으므로 디스크나 네트워크 스트림의 큰 파일도 통째로
+    /// 메모리에 올리지 않습니다. 다른 형식은 남은 바이트를 모두 읽어 `parse`로 넘깁니다.
+    /// HWPX entries are read as needed, so large files on disk or behind a network stream are
+    /// never loaded into memory as a whole. Other formats read the remaining bytes and go
+    /// through `parse`.
+    ///
+    /// # Arguments
+    /// * `reader` - Seekable reader positioned at the start of the file data
+    ///
+    /// # Returns
+    /// Parsed HWP document structure
+    pub fn parse_reader(&self, mut reader: impl Read + Seek) -> Result<HwpDocument, HwpError> {
+        use parser::{detect_format_reader, FileFormat};
+
+        match detect_format_reader(&mut reader)? {
+            FileFormat::Hwpx => {
+                let (mut document, _) =
+                    parser::hwpx::parse_reader_with_options(reader, &self.options)?;
+                document.is_template = self.options.template;
+                document.resource_limits = self.options.limits;
+                Ok(document)
+            }
+            _ => {
+                let mut data = Vec::new();
+                reader.read_to_end(&mut data)?;
+                self.parse(&data)
+            }
+        }
+    }
+
     /// Parse HWP 5.0 file (CFB-based format)
     ///
     /// # Arguments
@@ -99,22 +338,39 @@ impl HwpParser {
     /// # Returns
     /// Parsed HWP document structure
     fn parse_hwp5(&self, data: &[u8]) -> Result<HwpDocument, HwpError> {
+        let mut profiler = Profiler::new(self.options.profiling);
+
         // Parse CFB structure
-        let mut cfb = CfbParser::parse(data)?;
+        let mut cfb = profiler.time(ParsePhase::Container, || CfbParser::parse(data))?;
 
         // Parse required streams
-        let fileheader = self.parse_fileheader(&mut cfb)?;
+        let fileheader = profiler.time(ParsePhase::Header, || self.parse_fileheader(&mut cfb))?;
+        fileheader.ensure_readable()?;
+        let security = profiler.time(ParsePhase::Header, || {
+            SecurityInfo::parse_doc_options(&mut cfb)
+        });
+        security.ensure_readable()?;
         let mut document = HwpDocument::new(fileheader.clone());
-        document.doc_info = self.parse_docinfo(&mut cfb, &fileheader)?;
-        document.body_text = self.parse_bodytext(&mut cfb, &fileheader, &document.doc_info)?;
-        document.bin_data = self.parse_bindata(&mut cfb, &document.doc_info)?;
+        document.security = security;
+        document.doc_info = profiler.time(ParsePhase::Header, || {
+            self.parse_docinfo(&mut cfb, &fileheader)
+        })?;
+        document.body_text = profiler.time(ParsePhase::Sections, || {
+            self.parse_bodytext(&mut cfb, &fileheader, &document.doc_info)
+        })?;
+        document.bin_data = profiler.time(ParsePhase::BinData, || {
+            self.parse_bindata(&mut cfb, &document.doc_info)
+        })?;
 
         // Parse optional streams
-        self.parse_optional_streams(&mut cfb, &fileheader, &mut document, data);
+        profiler.time(ParsePhase::Metadata, || {
+            self.parse_optional_streams(&mut cfb, &fileheader, &mut document, data)
+        });
 
         // Resolve derived display texts (e.g., AUTO_NUMBER in captions) for JSON/viewers.
-        document.resolve_display_texts();
+        profiler.time(ParsePhase::DisplayText, || document.resolve_display_texts());
 
+        document.profile = profiler.finish();
         Ok(document)
     }
 
@@ -127,6 +383,7 @@ impl HwpParser {
     ) -> Result<FileHeader, HwpError> {
         let fileheader_data = CfbParser::read_stream(cfb, "FileHeader")?;
         FileHeader::parse(&fileheader_data)
+            .map_err(|error| error.with_context(ErrorContext::in_container("FileHeader")))
     }
 
     /// Parse DocInfo stream
@@ -137,6 +394,7 @@ impl HwpParser {
     ) -> Result<DocInfo, HwpError> {
         let docinfo_data = CfbParser::read_stream(cfb, "DocInfo")?;
         DocInfo::parse(&docinfo_data, fileheader)
+            .map_err(|error| error.with_context(ErrorContext::in_container("DocInfo")))
     }
 
     /// Parse BodyText storage
@@ -179,7 +437,7 @@ impl HwpParser {
     ) {
         self.parse_preview_text(cfb, document);
         self.parse_preview_image(cfb, document);
-        self.parse_scripts(cfb, document);
+        self.parse_scripts(cfb, fileheader, document);
         self.parse_xml_template(cfb, fileheader, document);
         self.parse_summary_information(cfb, document, data);
     }
@@ -232,8 +490,13 @@ impl HwpParser {
     /// 스크립트 스토리지 파싱 / Parse scripts storage
     /// 스펙 문서 3.2.9: Scripts 스토리지에는 Script 코드가 저장됩니다.
     /// Spec 3.2.9: Scripts storage contains Script code.
-    fn parse_scripts(&self, cfb: &mut CompoundFile<Cursor<&[u8]>>, document: &mut HwpDocument) {
-        match crate::document::Scripts::parse(cfb) {
+    fn parse_scripts(
+        &self,
+        cfb: &mut CompoundFile<Cursor<&[u8]>>,
+        fileheader: &FileHeader,
+        document: &mut HwpDocument,
+    ) {
+        match crate::document::Scripts::parse(cfb, fileheader) {
             Ok(scripts) => {
                 document.scripts = Some(scripts);
             }
@@ -373,6 +636,85 @@ impl HwpParser {
         CfbParser::read_stream_by_bytes(data, stream_name_bytes)
     }
 
+    /// Open an embedded binary item as a streaming reader
+    ///
+    /// 원본 파일에서 BinData 항목을 지연 읽기로 엽니다. HWP 5.0은 CFB 스트림을 읽는 만큼
+    /// 압축 해제하고, HWPX는 ZIP 항목을 직접 읽습니다. 큰 동영상/OLE 데이터를 base64로
+    /// 메모리에 올리지 않고 처리할 때 사용합니다.
+    /// Opens a BinData item lazily from the source file. HWP 5.0 streams are decompressed on
+    /// the fly and HWPX entries are read straight from the ZIP, so large videos/OLE payloads
+    /// never need to be held in memory as base64.
+    ///
+    /// 이미 파싱한 문서가 있으면 DocInfo를 다시 읽지 않는 `HwpDocument::open_bin_data`를 쓰세요.
+    /// With an already parsed document, prefer `HwpDocument::open_bin_data`, which does not read
+    /// DocInfo again.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    /// * `index` - `BinaryDataItem::index` of the item to open
+    ///
+    /// # Returns
+    /// Reader yielding the decoded bytes
+    pub fn open_bin_data<'a>(
+        &self,
+        data: &'a [u8],
+        index: WORD,
+    ) -> Result<Box<dyn std::io::Read + 'a>, HwpError> {
+        use parser::{detect_format, FileFormat};
+
+        match detect_format(data) {
+            FileFormat::Hwp5 => {
+                let mut cfb = CfbParser::parse(data)?;
+                let fileheader = self.parse_fileheader(&mut cfb)?;
+                let doc_info = self.parse_docinfo(&mut cfb, &fileheader)?;
+                BinData::open_stream(cfb, index, &doc_info.bin_data, fileheader.is_compressed())
+            }
+            FileFormat::Hwpx => BinData::open_hwpx_item(data, index, self.options.limits),
+            FileFormat::Hwpml => Err(HwpError::UnsupportedFormat {
+                format: "HWPML".to_string(),
+                reason: "embedded binary data in <TAIL> is not read".to_string(),
+            }),
+            FileFormat::Hwp3 => Err(HwpError::UnsupportedFormat {
+                format: "HWP 3.0".to_string(),
+                reason: "embedded pictures are not read".to_string(),
+            }),
+            FileFormat::Unknown => Err(HwpError::UnknownFormat),
+        }
+    }
+
+    /// Stream paragraph text without building the document model
+    /// 문서 모델을 만들지 않고 문단 텍스트 스트리밍
+    ///
+    /// 검색 색인처럼 텍스트만 필요할 때 `parse`보다 훨씬 적은 메모리로 동작합니다. 비어 있지 않은
+    /// 문단마다 앞뒤 공백을 지운 텍스트로 `emit`을 한 번씩 부르며, 표 셀/글상자/머리말 안의 문단도
+    /// 포함합니다. HWPX에는 `ParseOptions::limits`가 적용됩니다.
+    /// Uses far less memory than `parse` when only text is needed, e.g. for search indexing.
+    /// `emit` is called once per non-blank paragraph with its trimmed text, including paragraphs
+    /// inside table cells, text boxes and headers. `ParseOptions::limits` applies to HWPX.
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWP or HWPX file data
+    /// * `emit` - Callback receiving each paragraph's text
+    pub fn extract_text_streaming(
+        &self,
+        data: &[u8],
+        emit: &mut impl FnMut(&str),
+    ) -> Result<(), HwpError> {
+        use parser::{detect_format, FileFormat};
+
+        match detect_format(data) {
+            FileFormat::Hwp5 => parser::text_stream::stream_hwp5_text(data, emit),
+            FileFormat::Hwpx => {
+                parser::text_stream::stream_hwpx_text(data, self.options.limits, emit)
+            }
+            FileFormat::Hwpml => {
+                parser::text_stream::stream_hwpml_text(data, self.options.limits, emit)
+            }
+            FileFormat::Hwp3 => parser::text_stream::stream_hwp3_text(data, &self.options, emit),
+            FileFormat::Unknown => Err(HwpError::UnknownFormat),
+        }
+    }
+
     /// Parse HWP file and return FileHeader as JSON
     ///
     /// # Arguments
@@ -421,6 +763,19 @@ impl HwpParser {
     }
 }
 
+/// Stream paragraph text of an HWP or HWPX file without building the document model
+/// 문서 모델을 만들지 않고 HWP/HWPX 파일의 문단 텍스트 스트리밍
+///
+/// 기본 옵션의 `HwpParser::extract_text_streaming`과 같습니다.
+/// Same as `HwpParser::extract_text_streaming` with the default options.
+///
+/// ```ignore
+/// hwp_core::extract_text_streaming(&data, &mut |text| index.add(text))?;
+/// ```
+pub fn extract_text_streaming(data: &[u8], emit: &mut impl FnMut(&str)) -> Result<(), HwpError> {
+    HwpParser::new().extract_text_streaming(data, emit)
+}
+
 impl Default for HwpParser {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,443 @@
+/// 편지 병합 모듈 / Mail merge module
+///
+/// 서식 문서에서 `{{이름}}` 꼴의 자리표시자와 이름 붙은 누름틀을 찾아, 레코드마다 값을 채운 문서를
+/// 하나씩 만듭니다. 만든 문서는 [`crate::writer::write_hwpx`]로 저장하거나 다른 형식으로 변환합니다.
+///
+/// Finds `{{name}}` placeholders and named click-here fields in a template document and builds
+/// one document per record with the values filled in. The resulting documents can be saved with
+/// [`crate::writer::write_hwpx`] or converted to other formats.
+use crate::document::bodytext::{CtrlHeaderData, Paragraph, ParagraphRecord};
+use crate::document::docinfo::doc_data::{ParameterItemData, ParameterSet};
+use crate::document::replace::{replace_in_paragraph, Target};
+use crate::document::{FieldKind, HwpDocument};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// `{{이름}}` 자리표시자 (이름 앞뒤 공백 허용) / `{{name}}` placeholder (spaces around the name allowed)
+const PLACEHOLDER_PATTERN: &str = r"\{\{\s*([^{}]+?)\s*\}\}";
+
+/// 자리표시자 종류 / Placeholder kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceholderKind {
+    /// 본문의 `{{이름}}` 텍스트 / `{{name}}` text in the body
+    Text,
+    /// 이름 붙은 누름틀 / Named click-here field
+    ClickHere,
+}
+
+/// 서식 문서의 자리표시자 / A placeholder in a template document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Placeholder {
+    /// 값을 찾을 이름 / Name used to look up the value
+    pub name: String,
+    pub kind: PlaceholderKind,
+}
+
+/// 값이 없는 자리표시자 처리 / Handling of placeholders without a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingValue {
+    /// 그대로 둠 / Leave as is
+    #[default]
+    Keep,
+    /// 빈 텍스트로 지움 / Clear to empty text
+    Remove,
+}
+
+/// 편지 병합 옵션 / Mail merge options
+#[derive(Debug, Clone, Default)]
+pub struct MailMergeOptions {
+    /// 값이 없는 자리표시자 처리 / Handling of placeholders without a value
+    pub missing: MissingValue,
+}
+
+impl MailMergeOptions {
+    /// 값이 없는 자리표시자 처리 설정 / Set handling of placeholders without a value
+    pub fn with_missing(mut self, missing: MissingValue) -> Self {
+        self.missing = missing;
+        self
+    }
+}
+
+/// 레코드 하나를 채운 문서 / Document filled in for one record
+#[derive(Debug, Clone)]
+pub struct MergedDocument {
+    pub document: HwpDocument,
+    /// 채운 자리표시자 수 / Number of placeholders filled in
+    pub filled: usize,
+    /// 레코드에 값이 없던 이름 (문서 순서, 중복 없음) / Names the record had no value for (document order, unique)
+    pub missing: Vec<String>,
+}
+
+/// 서식 문서의 자리표시자 찾기 / Find the placeholders of a template document
+///
+/// # Returns / 반환값
+/// 문서 순서대로, 같은 이름과 종류는 한 번만 / In document order, each name and kind once
+pub fn placeholders(template: &HwpDocument) -> Vec<Placeholder> {
+    let mut found: Vec<Placeholder> = Vec::new();
+    for paragraph in all_paragraphs(template) {
+        let names = click_here_names(paragraph)
+            .into_iter()
+            .flatten()
+            .map(|name| (name, PlaceholderKind::ClickHere));
+        let texts = text_placeholders(&paragraph.text())
+            .into_iter()
+            .map(|(_, name)| (name, PlaceholderKind::Text));
+        for (name, kind) in names.chain(texts) {
+            if !found.iter().any(|p| p.name == name && p.kind == kind) {
+                found.push(Placeholder { name, kind });
+            }
+        }
+    }
+    found
+}
+
+/// 레코드 하나로 서식 문서 채우기 / Fill a template document from one record
+///
+/// # Arguments / 매개변수
+/// * `template` - 서식 문서 / Template document
+/// * `values` - 이름 → 값 / Name → value
+/// * `options` - 편지 병합 옵션 / Mail merge options
+pub fn merge_record(
+    template: &HwpDocument,
+    values: &HashMap<String, String>,
+    options: &MailMergeOptions,
+) -> MergedDocument {
+    let mut document = template.clone();
+    let mut filled = 0;
+    let mut values = Values {
+        values,
+        missing_value: options.missing,
+        missing: Vec::new(),
+    };
+
+    for section in &mut document.body_text.sections {
+        for paragraph in &mut section.paragraphs {
+            for_each_paragraph(paragraph, &mut |paragraph| {
+                filled += fill_click_here(paragraph, &mut values);
+            });
+        }
+    }
+
+    // 같은 이름도 공백이 다르면 따로 바꿈 / The same name with different spacing is replaced separately
+    let mut raws: Vec<(String, String)> = Vec::new();
+    for paragraph in all_paragraphs(&document) {
+        for (raw, name) in text_placeholders(&paragraph.text()) {
+            if !raws.iter().any(|(r, _)| *r == raw) {
+                raws.push((raw, name));
+            }
+        }
+    }
+    for (raw, name) in raws {
+        if let Some(value) = values.get(&name) {
+            filled += document.replace_text(&raw, value);
+        }
+    }
+
+    let missing = values.missing;
+    MergedDocument {
+        document,
+        filled,
+        missing,
+    }
+}
+
+/// 레코드마다 서식 문서를 채워 문서 하나씩 만들기 / Build one filled document per record
+///
+/// # Arguments / 매개변수
+/// * `template` - 서식 문서 / Template document
+/// * `records` - 레코드 목록 (이름 → 값) / Records (name → value)
+/// * `options` - 편지 병합 옵션 / Mail merge options
+///
+/// # Returns / 반환값
+/// 레코드 순서대로 채운 문서 / Filled documents in record order
+pub fn mail_merge(
+    template: &HwpDocument,
+    records: &[HashMap<String, String>],
+    options: &MailMergeOptions,
+) -> Vec<MergedDocument> {
+    records
+        .iter()
+        .map(|values| merge_record(template, values, options))
+        .collect()
+}
+
+/// 본문 문단과 중첩 문단 (문서 순서) / Body paragraphs and their nested paragraphs (document order)
+fn all_paragraphs(document: &HwpDocument) -> impl Iterator<Item = &Paragraph> {
+    document
+        .body_text
+        .sections
+        .iter()
+        .flat_map(|section| &section.paragraphs)
+        .flat_map(|paragraph| std::iter::once(paragraph).chain(paragraph.nested_paragraphs()))
+}
+
+/// 텍스트의 `{{이름}}` 자리표시자 (원문, 이름) / `{{name}}` placeholders in a text (raw text, name)
+fn text_placeholders(text: &str) -> Vec<(String, String)> {
+    let pattern = Regex::new(PLACEHOLDER_PATTERN).expect("valid placeholder pattern");
+    pattern
+        .captures_iter(text)
+        .map(|captures| (captures[0].to_string(), captures[1].to_string()))
+        .collect()
+}
+
+/// 레코드의 값과 값이 없던 이름 / A record's values and the names it had no value for
+struct Values<'v> {
+    values: &'v HashMap<String, String>,
+    missing_value: MissingValue,
+    missing: Vec<String>,
+}
+
+impl<'v> Values<'v> {
+    /// 이름의 값 (값이 없으면 기록하고 옵션에 따라 빈 텍스트 또는 `None`)
+    /// Value of a name (when missing, recorded and then empty text or `None` per the options)
+    fn get(&mut self, name: &str) -> Option<&'v str> {
+        if let Some(value) = self.values.get(name) {
+            return Some(value);
+        }
+        if !self.missing.iter().any(|m| m == name) {
+            self.missing.push(name.to_string());
+        }
+        match self.missing_value {
+            MissingValue::Keep => None,
+            MissingValue::Remove => Some(""),
+        }
+    }
+}
+
+/// 문단과 중첩 문단마다 함수 부르기 / Call a function for a paragraph and each nested paragraph
+fn for_each_paragraph(paragraph: &mut Paragraph, f: &mut dyn FnMut(&mut Paragraph)) {
+    fn visit_record(record: &mut ParagraphRecord, f: &mut dyn FnMut(&mut Paragraph)) {
+        match record {
+            ParagraphRecord::CtrlHeader {
+                children,
+                paragraphs,
+                ..
+            } => {
+                for child in children {
+                    visit_record(child, f);
+                }
+                for paragraph in paragraphs {
+                    for_each_paragraph(paragraph, f);
+                }
+            }
+            ParagraphRecord::ListHeader { paragraphs, .. } => {
+                for paragraph in paragraphs {
+                    for_each_paragraph(paragraph, f);
+                }
+            }
+            ParagraphRecord::MasterPage { master_page } => {
+                for paragraph in &mut master_page.paragraphs {
+                    for_each_paragraph(paragraph, f);
+                }
+            }
+            ParagraphRecord::Table { table } => {
+                for cell in &mut table.cells {
+                    for paragraph in &mut cell.paragraphs {
+                        for_each_paragraph(paragraph, f);
+                    }
+                }
+            }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                for child in children {
+                    visit_record(child, f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    f(paragraph);
+    for record in &mut paragraph.records {
+        visit_record(record, f);
+    }
+}
+
+/// 문단의 필드마다 누름틀 이름 (누름틀이 아니거나 이름이 없으면 `None`)
+/// Click-here name of each field in a paragraph (`None` for other fields or unnamed ones)
+///
+/// HWPX 필드와 HWP 5.0 필드 컨트롤을 따로 셉니다. HWP 5.0 필드 순서는 문단 텍스트의 필드 시작
+/// 제어 문자 순서와 같습니다.
+/// HWPX fields and HWP 5.0 field controls are counted separately. The order of HWP 5.0 fields
+/// matches the order of field start controls in the paragraph text.
+fn click_here_names(paragraph: &Paragraph) -> Vec<Option<String>> {
+    paragraph
+        .records
+        .iter()
+        .filter_map(|record| match record {
+            ParagraphRecord::HwpxField {
+                field_type, name, ..
+            } => Some(
+                name.clone()
+                    .filter(|_| FieldKind::from_field_type(field_type) == FieldKind::ClickHere),
+            ),
+            ParagraphRecord::CtrlHeader {
+                header, children, ..
+            } => match &header.data {
+                CtrlHeaderData::Field { field_type, .. } => Some(
+                    Some(field_type)
+                        .filter(|t| FieldKind::from_field_type(t) == FieldKind::ClickHere)
+                        .and_then(|_| hwp5_field_name(children)),
+                ),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|name| name.filter(|name| !name.trim().is_empty()))
+        .collect()
+}
+
+/// HWP 5.0 필드 이름 (컨트롤 임의 데이터의 첫 문자열) / HWP 5.0 field name (first string of the control data)
+fn hwp5_field_name(children: &[ParagraphRecord]) -> Option<String> {
+    fn first_string(set: &ParameterSet) -> Option<String> {
+        set.items.iter().find_map(|item| match &item.data {
+            ParameterItemData::Bstr(text) => Some(text.clone()),
+            ParameterItemData::Set(set) => first_string(set),
+            _ => None,
+        })
+    }
+
+    children.iter().find_map(|child| match child {
+        ParagraphRecord::CtrlData { ctrl_data } => first_string(&ctrl_data.parameter_set),
+        _ => None,
+    })
+}
+
+/// 문단 자체의 누름틀 채우기 / Fill the paragraph's own click-here fields
+///
+/// # Returns / 반환값
+/// 채운 누름틀 수 / Number of click-here fields filled in
+fn fill_click_here(paragraph: &mut Paragraph, values: &mut Values) -> usize {
+    let names = click_here_names(paragraph);
+    if names.iter().all(Option::is_none) {
+        return 0;
+    }
+
+    let mut filled = 0;
+    let mut hwpx_index = 0;
+    let mut hwp5_index = 0;
+    for record_index in 0..paragraph.records.len() {
+        let (target, name) = match &paragraph.records[record_index] {
+            ParagraphRecord::HwpxField { text, offset, .. } => {
+                let target = Target::Chars {
+                    start: *offset,
+                    len: text.chars().count(),
+                };
+                hwpx_index += 1;
+                (target, &names[hwpx_index + hwp5_index - 1])
+            }
+            ParagraphRecord::CtrlHeader { header, .. }
+                if matches!(header.data, CtrlHeaderData::Field { .. }) =>
+            {
+                let target = Target::Field(hwp5_index);
+                hwp5_index += 1;
+                (target, &names[hwpx_index + hwp5_index - 1])
+            }
+            _ => continue,
+        };
+        let Some(value) = name.as_deref().and_then(|name| values.get(name)) else {
+            continue;
+        };
+        let value = value.to_string();
+        if replace_in_paragraph(paragraph, target, &value) == 0 {
+            continue;
+        }
+        if let ParagraphRecord::HwpxField { text, .. } = &mut paragraph.records[record_index] {
+            *text = value;
+        }
+        filled += 1;
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{document_with_paragraphs, text_paragraph};
+
+    fn paragraph(text: &str, fields: Vec<ParagraphRecord>) -> Paragraph {
+        let mut paragraph = text_paragraph(text);
+        paragraph.records.extend(fields);
+        paragraph
+    }
+
+    fn click_here(name: &str, text: &str, offset: usize) -> ParagraphRecord {
+        ParagraphRecord::HwpxField {
+            field_type: "CLICK_HERE".to_string(),
+            name: Some(name.to_string()),
+            command: None,
+            text: text.to_string(),
+            offset,
+        }
+    }
+
+    fn template() -> HwpDocument {
+        document_with_paragraphs(vec![
+            paragraph("{{ 이름 }}님, 입사를 축하합니다.", Vec::new()),
+            // "부서: [부서 입력], 직급: " 뒤에 빈 누름틀 / An empty click-here field after "직급: "
+            paragraph(
+                "부서: 부서 입력, 직급: . {{입사일}}",
+                vec![
+                    click_here("부서", "부서 입력", 4),
+                    click_here("직급", "", 15),
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let names: Vec<(String, PlaceholderKind)> = placeholders(&template())
+            .into_iter()
+            .map(|p| (p.name, p.kind))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("이름".to_string(), PlaceholderKind::Text),
+                ("부서".to_string(), PlaceholderKind::ClickHere),
+                ("직급".to_string(), PlaceholderKind::ClickHere),
+                ("입사일".to_string(), PlaceholderKind::Text),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mail_merge_fills_each_record() {
+        let record = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        let records = vec![
+            record(&[("이름", "홍길동"), ("부서", "인사팀"), ("직급", "사원")]),
+            record(&[("이름", "김철수"), ("입사일", "2024-03-04")]),
+        ];
+        let merged = mail_merge(&template(), &records, &MailMergeOptions::default());
+        assert_eq!(merged.len(), 2);
+
+        let first = &merged[0];
+        assert_eq!(first.filled, 3);
+        assert_eq!(first.missing, vec!["입사일".to_string()]);
+        assert_eq!(
+            first.document.text(),
+            "홍길동님, 입사를 축하합니다.\n부서: 인사팀, 직급: 사원. {{입사일}}"
+        );
+        let fields = first.document.fields();
+        assert_eq!(
+            (fields[1].text.as_str(), fields[1].location.paragraph_index),
+            ("사원", 1)
+        );
+
+        let options = MailMergeOptions::default().with_missing(MissingValue::Remove);
+        let second = merge_record(&template(), &records[1], &options);
+        assert_eq!(second.missing, vec!["부서".to_string(), "직급".to_string()]);
+        assert_eq!(
+            second.document.text(),
+            "김철수님, 입사를 축하합니다.\n부서: , 직급: . 2024-03-04"
+        );
+    }
+}
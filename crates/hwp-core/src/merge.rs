@@ -0,0 +1,921 @@
+/// 문서 병합 모듈 / Document merge module
+///
+/// 두 문서를 하나로 합칠 때 글꼴/테두리·배경/글자 모양/탭/문단 번호/글머리표/문단 모양/스타일/
+/// 바이너리 데이터 ID를 다시 매핑합니다.
+/// 같은 이름의 스타일이 서로 다르게 정의된 경우 선택한 전략에 따라 충돌을 해결하고,
+/// 어떤 스타일이 어디로 매핑되었는지 보고서로 반환합니다.
+///
+/// Remaps face name / border fill / char shape / tab / numbering / bullet / para shape / style /
+/// binary data IDs when combining two documents.
+/// When both documents define a style with the same name differently, the conflict is
+/// resolved with the selected strategy and the resulting mapping is returned as a report.
+use std::collections::BTreeMap;
+
+use crate::document::bindata::BinaryDataItem;
+use crate::document::bodytext::video_data::VideoAttributes;
+use crate::document::bodytext::{CtrlHeaderData, Paragraph, ParagraphRecord, Section};
+use crate::document::docinfo::{
+    BinDataRecord, CharShape, DocInfo, FillInfo, HeaderShapeType, ParaShape, Style,
+};
+use crate::document::HwpDocument;
+use crate::error::HwpError;
+use crate::types::{UINT16, UINT32};
+use serde::{Deserialize, Serialize};
+
+/// 스타일 충돌 해결 전략 / Style conflict resolution strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StyleConflictStrategy {
+    /// 충돌하는 원본 스타일을 새 이름으로 추가 / Add the conflicting source style under a new name
+    #[default]
+    Rename,
+    /// 원본 정의로 대상 스타일을 덮어씀 / Overwrite the target style with the source definition
+    PreferSource,
+    /// 대상 정의를 유지하고 원본 문단을 대상 스타일로 매핑 / Keep the target definition and map source paragraphs onto it
+    PreferTarget,
+}
+
+/// 병합 옵션 / Merge options
+#[derive(Debug, Clone, Default)]
+pub struct MergeOptions {
+    /// 스타일 충돌 해결 전략 / Style conflict resolution strategy
+    pub style_conflict: StyleConflictStrategy,
+}
+
+impl MergeOptions {
+    /// 스타일 충돌 전략 설정 / Set style conflict strategy
+    pub fn with_style_conflict(mut self, strategy: StyleConflictStrategy) -> Self {
+        self.style_conflict = strategy;
+        self
+    }
+}
+
+/// 스타일 매핑 결과 종류 / Kind of style mapping outcome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StyleMappingAction {
+    /// 대상에 같은 이름의 스타일이 없어 새로 추가됨 / Added because the target had no style with this name
+    Added,
+    /// 이름과 정의가 같아 대상 스타일을 재사용 / Reused because name and definition match
+    Reused,
+    /// 정의가 달라 새 이름으로 추가됨 / Added under a new name because definitions differ
+    Renamed { new_name: String },
+    /// 정의가 달라 대상 스타일을 원본 정의로 덮어씀 / Target style overwritten with the source definition
+    ReplacedTarget,
+    /// 정의가 달라 대상 정의를 유지함 (원본 서식이 바뀜) / Target definition kept (source formatting changes)
+    KeptTarget,
+}
+
+/// 스타일 하나의 매핑 정보 / Mapping information for a single style
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StyleMapping {
+    /// 원본 문서의 스타일 ID / Style ID in the source document
+    pub source_id: usize,
+    /// 병합된 문서의 스타일 ID / Style ID in the merged document
+    pub target_id: usize,
+    /// 원본 스타일 이름 / Source style name
+    pub name: String,
+    /// 매핑 결과 / Mapping outcome
+    pub action: StyleMappingAction,
+}
+
+/// 병합 보고서 / Merge report
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeReport {
+    /// 스타일 매핑 목록 (원본 스타일 ID 순) / Style mappings (in source style ID order)
+    pub style_mappings: Vec<StyleMapping>,
+    /// 원본 글자 모양 ID → 병합된 글자 모양 ID / Source char shape ID → merged char shape ID
+    pub char_shape_map: Vec<usize>,
+    /// 원본 문단 모양 ID → 병합된 문단 모양 ID / Source para shape ID → merged para shape ID
+    pub para_shape_map: Vec<usize>,
+    /// 원본 글꼴 ID → 병합된 글꼴 ID / Source face name ID → merged face name ID
+    pub face_name_map: Vec<usize>,
+    /// 원본 테두리/배경 위치 → 병합된 위치 (ID는 위치 + 1) / Source border fill position → merged position (IDs are position + 1)
+    pub border_fill_map: Vec<usize>,
+    /// 원본 탭 정의 ID → 병합된 탭 정의 ID / Source tab definition ID → merged tab definition ID
+    pub tab_def_map: Vec<usize>,
+    /// 원본 문단 번호 위치 → 병합된 위치 (ID는 위치 + 1) / Source numbering position → merged position (IDs are position + 1)
+    pub numbering_map: Vec<usize>,
+    /// 원본 글머리표 위치 → 병합된 위치 (ID는 위치 + 1) / Source bullet position → merged position (IDs are position + 1)
+    pub bullet_map: Vec<usize>,
+    /// 원본 바이너리 데이터 ID → 병합된 ID (`merge_documents`만 채움) / Source binary data ID → merged ID (filled by `merge_documents` only)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bin_data_map: BTreeMap<UINT32, UINT32>,
+    /// 이름이 겹쳐 바뀐 HWPX 바이너리 아이템 이름 (원본 → 병합) / HWPX binary item names renamed because they clashed (source → merged)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bin_data_names: BTreeMap<String, String>,
+    /// 병합된 구역 수 / Number of sections appended
+    pub sections_appended: usize,
+}
+
+impl MergeReport {
+    /// 충돌이 발생한 스타일 목록 / Styles that had a conflicting definition
+    pub fn conflicts(&self) -> impl Iterator<Item = &StyleMapping> {
+        self.style_mappings.iter().filter(|m| {
+            !matches!(
+                m.action,
+                StyleMappingAction::Added | StyleMappingAction::Reused
+            )
+        })
+    }
+}
+
+/// 원본 문서를 대상 문서 뒤에 병합 / Merge the source document after the target document
+///
+/// 원본의 구역은 대상 문서 끝에 추가되며, 문단의 문단 모양/스타일/글자 모양 ID와 표·쪽 테두리,
+/// 구역의 개요 번호 ID는 병합된 DocInfo 기준으로 다시 매핑됩니다.
+/// Source sections are appended to the target, and paragraph para shape / style / char shape
+/// IDs, table and page border fills and the section's outline numbering ID are remapped against
+/// the merged DocInfo.
+///
+/// 원본의 바이너리 데이터 아이템과 DocInfo BinData 레코드는 대상 뒤에 추가되며, 그림/OLE/동영상과
+/// 그림 채우기, 그림 글머리표의 ID는 대상의 가장 큰 ID 뒤로 옮겨집니다. HWPX 그림은 이름으로
+/// 아이템을 찾으므로 대상과 겹치는 이름은 바꿉니다.
+/// Source binary data items and DocInfo BinData records are appended, and picture / OLE / video
+/// IDs, image fills and image bullets are shifted past the target's largest ID. HWPX pictures
+/// find items by name, so names that clash with the target are changed.
+///
+/// 병합된 스타일 ID가 문단 머리의 1바이트 범위(255)를 넘거나 바이너리 데이터 ID가 2바이트를
+/// 넘으면 오류를 반환하며, 이때 대상 문서는 바뀌지 않습니다.
+/// Returns an error, leaving the target unchanged, when a merged style ID no longer fits the
+/// one-byte field of paragraph headers (255) or a binary data ID no longer fits two bytes.
+pub fn merge_documents(
+    target: &mut HwpDocument,
+    source: &HwpDocument,
+    options: &MergeOptions,
+) -> Result<MergeReport, HwpError> {
+    let mut doc_info = target.doc_info.clone();
+    let mut report = merge_doc_info(&mut doc_info, &source.doc_info, options)?;
+
+    let bin_data = BinDataRemap::new(target, source);
+    let mut items = target.bin_data.items.clone();
+    for item in &source.bin_data.items {
+        items.push(BinaryDataItem {
+            index: bin_data.id(item.index)?,
+            data: item.data.clone(),
+            name: item.name.as_ref().map(|name| bin_data.name(name)),
+        });
+    }
+    for record in &source.doc_info.bin_data {
+        let mut record = record.clone();
+        match &mut record {
+            BinDataRecord::Embedding { embedding, .. } => {
+                embedding.binary_data_id = bin_data.id(embedding.binary_data_id)?
+            }
+            BinDataRecord::Storage { storage, .. } => {
+                storage.binary_data_id = bin_data.id(storage.binary_data_id)?
+            }
+            BinDataRecord::Link { .. } => {}
+        }
+        doc_info.bin_data.push(record);
+    }
+    // 추가된 그림 채우기와 그림 글머리표 / Appended image fills and image bullets
+    for &position in &report.border_fill_map {
+        if let FillInfo::Image(image) = &mut doc_info.border_fill[position].fill {
+            if let Some(bytes) = image.image_info.get_mut(3..5) {
+                let id = UINT16::from_le_bytes([bytes[0], bytes[1]]);
+                if id != 0 {
+                    bytes.copy_from_slice(&bin_data.id(id)?.to_le_bytes());
+                }
+            }
+        }
+    }
+    for &position in &report.bullet_map {
+        let bullet = &mut doc_info.bullets[position];
+        if let Ok(id @ 1..) = UINT16::try_from(bullet.image_bullet_id) {
+            bullet.image_bullet_id = bin_data.id(id)?.into();
+        }
+    }
+    let mut linked_items = target.security.linked_items.clone();
+    for (name, href) in &source.security.linked_items {
+        linked_items.insert(bin_data.name(name), href.clone());
+    }
+
+    let style_map: Vec<usize> = report.style_mappings.iter().map(|m| m.target_id).collect();
+    let remap = IdRemap {
+        char_shapes: &report.char_shape_map,
+        para_shapes: &report.para_shape_map,
+        styles: &style_map,
+        border_fills: &report.border_fill_map,
+        numberings: &report.numbering_map,
+        bin_data: &bin_data,
+    };
+
+    let base_index = target.body_text.sections.len();
+    let mut sections = Vec::with_capacity(source.body_text.sections.len());
+    for (offset, section) in source.body_text.sections.iter().enumerate() {
+        let mut paragraphs = section.paragraphs.clone();
+        for paragraph in &mut paragraphs {
+            remap.apply_paragraph(paragraph)?;
+        }
+        sections.push(Section {
+            index: (base_index + offset) as crate::types::WORD,
+            paragraphs,
+        });
+    }
+    report.sections_appended = sections.len();
+    report.bin_data_map = bin_data.report_map(source)?;
+    report.bin_data_names = bin_data.names;
+    target.doc_info = doc_info;
+    target.bin_data.items = items;
+    target.security.linked_items = linked_items;
+    target.body_text.sections.extend(sections);
+
+    Ok(report)
+}
+
+/// 원본 DocInfo의 글꼴/모양/스타일을 대상 DocInfo에 병합 / Merge source DocInfo fonts, shapes and styles into the target
+///
+/// 문단 본문은 건드리지 않고 ID 매핑만 보고합니다. 바이너리 데이터 아이템은 문서에 있으므로
+/// BinData 레코드와 그 ID는 `merge_documents`에서만 병합합니다.
+/// Body paragraphs are not touched; only the ID mapping is reported. Binary data items live on
+/// the document, so BinData records and their IDs are only merged by `merge_documents`.
+///
+/// 병합된 스타일 ID가 255를 넘으면 대상을 바꾸지 않고 오류를 반환합니다.
+/// Returns an error without changing the target when a merged style ID exceeds 255.
+pub fn merge_doc_info(
+    target: &mut DocInfo,
+    source: &DocInfo,
+    options: &MergeOptions,
+) -> Result<MergeReport, HwpError> {
+    let mut merged = target.clone();
+    let report = merge_doc_info_into(&mut merged, source, options)?;
+    *target = merged;
+    Ok(report)
+}
+
+fn merge_doc_info_into(
+    target: &mut DocInfo,
+    source: &DocInfo,
+    options: &MergeOptions,
+) -> Result<MergeReport, HwpError> {
+    let mut report = MergeReport::default();
+
+    // 글꼴: 같은 이름이면 재사용 / Face names: reuse by name
+    for face_name in &source.face_names {
+        let id = match target
+            .face_names
+            .iter()
+            .position(|f| f.name == face_name.name)
+        {
+            Some(id) => id,
+            None => {
+                target.face_names.push(face_name.clone());
+                target.face_names.len() - 1
+            }
+        };
+        report.face_name_map.push(id);
+    }
+
+    // 테두리/배경, 탭 정의: 뒤에 추가 / Border fills and tab definitions: append
+    report.border_fill_map = append(&mut target.border_fill, &source.border_fill);
+    report.tab_def_map = append(&mut target.tab_defs, &source.tab_defs);
+
+    // 글자 모양: 글꼴과 테두리/배경 ID를 다시 매핑하고 뒤에 추가 / Char shapes: remap font and border fill IDs and append
+    for char_shape in &source.char_shapes {
+        let mut char_shape = char_shape.clone();
+        remap_font_ids(&mut char_shape, &report.face_name_map);
+        if let Some(id) = &mut char_shape.border_fill_id {
+            *id = remap_one_based(*id, &report.border_fill_map);
+        }
+        target.char_shapes.push(char_shape);
+        report.char_shape_map.push(target.char_shapes.len() - 1);
+    }
+
+    // 문단 번호, 글머리표: 글자 모양 ID를 다시 매핑하고 뒤에 추가 ("없음"(-1)은 그대로)
+    // Numberings and bullets: remap char shape IDs and append ("none" (-1) is kept)
+    let first_numbering = target.numbering.len();
+    report.numbering_map = append(&mut target.numbering, &source.numbering);
+    for numbering in &mut target.numbering[first_numbering..] {
+        for level in &mut numbering.levels {
+            if let Some(&id) = report.char_shape_map.get(level.char_shape_id as usize) {
+                level.char_shape_id = id as u32;
+            }
+        }
+    }
+    let first_bullet = target.bullets.len();
+    report.bullet_map = append(&mut target.bullets, &source.bullets);
+    for bullet in &mut target.bullets[first_bullet..] {
+        let mapped = usize::try_from(bullet.char_shape_id)
+            .ok()
+            .and_then(|id| report.char_shape_map.get(id));
+        if let Some(&id) = mapped {
+            bullet.char_shape_id = id as i32;
+        }
+    }
+
+    // 문단 모양: 탭/번호/글머리표/테두리·배경 ID를 다시 매핑하고 뒤에 추가
+    // Para shapes: remap tab, numbering, bullet and border fill IDs and append
+    for para_shape in &source.para_shapes {
+        let mut para_shape = para_shape.clone();
+        if let Some(&id) = report.tab_def_map.get(para_shape.tab_def_id as usize) {
+            para_shape.tab_def_id = id as UINT16;
+        }
+        let number_bullet_map = match para_shape.attributes1.header_shape_type {
+            HeaderShapeType::Bullet => &report.bullet_map,
+            _ => &report.numbering_map,
+        };
+        para_shape.number_bullet_id =
+            remap_one_based(para_shape.number_bullet_id, number_bullet_map);
+        para_shape.border_fill_id =
+            remap_one_based(para_shape.border_fill_id, &report.border_fill_map);
+        target.para_shapes.push(para_shape);
+        report.para_shape_map.push(target.para_shapes.len() - 1);
+    }
+
+    let first_new_style = target.styles.len();
+    for (source_id, style) in source.styles.iter().enumerate() {
+        let mut remapped = style.clone();
+        remapped.char_shape_id = style
+            .char_shape_id
+            .and_then(|id| report.char_shape_map.get(id as usize))
+            .map(|&id| id as u16);
+        remapped.para_shape_id = style
+            .para_shape_id
+            .and_then(|id| report.para_shape_map.get(id as usize))
+            .map(|&id| id as u16);
+
+        // 원본 스타일끼리는 충돌 검사 대상이 아님 / Source styles are only checked against the original target styles
+        let existing = target.styles[..first_new_style]
+            .iter()
+            .position(|s| s.local_name == style.local_name);
+
+        let (target_id, action) = match existing {
+            None => {
+                target.styles.push(remapped);
+                (target.styles.len() - 1, StyleMappingAction::Added)
+            }
+            Some(id) if same_definition(target, id, &remapped) => (id, StyleMappingAction::Reused),
+            Some(id) => match options.style_conflict {
+                StyleConflictStrategy::PreferTarget => (id, StyleMappingAction::KeptTarget),
+                StyleConflictStrategy::PreferSource => {
+                    let next_style_id = target.styles[id].next_style_id;
+                    target.styles[id] = Style {
+                        next_style_id,
+                        ..remapped
+                    };
+                    (id, StyleMappingAction::ReplacedTarget)
+                }
+                StyleConflictStrategy::Rename => {
+                    let new_name = unique_style_name(&target.styles, &style.local_name);
+                    remapped.local_name = new_name.clone();
+                    if !remapped.english_name.is_empty() {
+                        remapped.english_name =
+                            unique_english_name(&target.styles, &remapped.english_name);
+                    }
+                    target.styles.push(remapped);
+                    (
+                        target.styles.len() - 1,
+                        StyleMappingAction::Renamed { new_name },
+                    )
+                }
+            },
+        };
+
+        style_id(target_id)?;
+        report.style_mappings.push(StyleMapping {
+            source_id,
+            target_id,
+            name: style.local_name.clone(),
+            action,
+        });
+    }
+
+    // 새로 추가된 스타일의 다음 스타일 ID 다시 매핑 (없는 스타일이면 자기 자신)
+    // Remap next style IDs of newly added styles (the style itself when the next one is missing)
+    for mapping in &report.style_mappings {
+        if mapping.target_id < first_new_style {
+            continue;
+        }
+        let next = source.styles[mapping.source_id].next_style_id as usize;
+        let next_target_id = report
+            .style_mappings
+            .get(next)
+            .map_or(mapping.target_id, |next_mapping| next_mapping.target_id);
+        target.styles[mapping.target_id].next_style_id = style_id(next_target_id)?;
+    }
+
+    Ok(report)
+}
+
+/// 병합된 스타일 위치를 1바이트 스타일 ID로 변환 / Convert a merged style position to a one-byte style ID
+fn style_id(id: usize) -> Result<u8, HwpError> {
+    u8::try_from(id).map_err(|_| HwpError::UnexpectedValue {
+        field: "merged style ID".to_string(),
+        expected: "at most 255".to_string(),
+        found: id.to_string(),
+    })
+}
+
+/// 바이너리 데이터 ID와 이름 매핑 / Binary data ID and name remapping
+///
+/// HWP 5.0의 BinData ID는 1부터(연결 레코드는 레코드 순서), HWPX와 빌더 아이템은 0부터 셉니다.
+/// 원본의 첫 ID가 대상의 가장 큰 ID 다음이 되도록 옮기므로, HWP 5.0끼리는 연결 레코드의 순서와
+/// ID가 계속 맞습니다.
+/// HWP 5.0 BinData IDs start at 1 (record order for links), HWPX and builder items at 0. Source
+/// IDs are shifted so the first one follows the target's largest ID, which keeps link record
+/// order and IDs in step between HWP 5.0 documents.
+struct BinDataRemap {
+    /// 원본 ID에 더하는 값 / Added to source IDs
+    offset: UINT32,
+    /// HWP 5.0 원본에서 0은 "없음" / 0 means "none" in an HWP 5.0 source
+    keeps_zero: bool,
+    /// 바뀐 아이템 이름 (원본 → 병합) / Renamed items (source → merged)
+    names: BTreeMap<String, String>,
+}
+
+impl BinDataRemap {
+    fn new(target: &HwpDocument, source: &HwpDocument) -> Self {
+        let next_free = bin_data_ids(target).max().map_or(0, |id| id + 1);
+        let source_first = bin_data_ids(source).min().unwrap_or(0);
+        let offset = next_free.max(source_first) - source_first;
+
+        let item_names = |document: &'_ HwpDocument| -> Vec<String> {
+            let items = document.bin_data.items.iter();
+            items
+                .filter_map(|item| item.name.clone())
+                .chain(document.security.linked_items.keys().cloned())
+                .collect()
+        };
+        let target_names = item_names(target);
+        let source_names = item_names(source);
+        let mut names = BTreeMap::new();
+        for name in &source_names {
+            if !target_names.contains(name) || names.contains_key(name) {
+                continue;
+            }
+            let new_name = (2..)
+                .map(|n| format!("{name}_{n}"))
+                .find(|candidate| {
+                    !target_names.contains(candidate)
+                        && !source_names.contains(candidate)
+                        && !names.values().any(|taken| taken == candidate)
+                })
+                .expect("unbounded candidate names");
+            names.insert(name.clone(), new_name);
+        }
+        Self {
+            offset,
+            keeps_zero: !source.doc_info.bin_data.is_empty(),
+            names,
+        }
+    }
+
+    /// 원본 ID를 병합된 ID로 (0은 없음) / Source ID to merged ID (0 means none)
+    fn id(&self, id: UINT16) -> Result<UINT16, HwpError> {
+        if id == 0 && self.keeps_zero {
+            return Ok(0);
+        }
+        let merged = UINT32::from(id) + self.offset;
+        UINT16::try_from(merged).map_err(|_| HwpError::UnexpectedValue {
+            field: "merged binary data ID".to_string(),
+            expected: format!("at most {}", UINT16::MAX),
+            found: merged.to_string(),
+        })
+    }
+
+    /// 원본 아이템 이름을 병합된 이름으로 / Source item name to merged name
+    fn name(&self, name: &str) -> String {
+        self.names
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// 보고서용 원본 ID → 병합된 ID / Source ID → merged ID for the report
+    fn report_map(&self, source: &HwpDocument) -> Result<BTreeMap<UINT32, UINT32>, HwpError> {
+        bin_data_ids(source)
+            .map(|id| Ok((id, UINT32::from(self.id(id as UINT16)?))))
+            .collect()
+    }
+}
+
+/// 문서의 바이너리 데이터 ID (레코드와 아이템) / Binary data IDs of a document (records and items)
+fn bin_data_ids(document: &HwpDocument) -> impl Iterator<Item = UINT32> + '_ {
+    let records = document
+        .doc_info
+        .bin_data
+        .iter()
+        .enumerate()
+        .map(|(position, record)| match record {
+            BinDataRecord::Embedding { embedding, .. } => UINT32::from(embedding.binary_data_id),
+            BinDataRecord::Storage { storage, .. } => UINT32::from(storage.binary_data_id),
+            BinDataRecord::Link { .. } => position as UINT32 + 1,
+        });
+    let items = document
+        .bin_data
+        .items
+        .iter()
+        .map(|item| UINT32::from(item.index));
+    records.chain(items)
+}
+
+/// 원본 항목을 뒤에 추가하고 원본 위치 → 병합된 위치 매핑 반환 / Append source entries and return the source → merged position mapping
+fn append<T: Clone>(target: &mut Vec<T>, source: &[T]) -> Vec<usize> {
+    let first = target.len();
+    target.extend_from_slice(source);
+    (first..target.len()).collect()
+}
+
+/// 1부터 센 ID 다시 매핑 (0은 없음, 범위 밖은 그대로) / Remap an ID counted from 1 (0 is none, out-of-range IDs are kept)
+fn remap_one_based(id: UINT16, map: &[usize]) -> UINT16 {
+    match (id as usize)
+        .checked_sub(1)
+        .and_then(|position| map.get(position))
+    {
+        Some(&position) => (position + 1) as UINT16,
+        None => id,
+    }
+}
+
+/// 두 스타일의 정의가 같은지 비교 / Compare whether two style definitions are the same
+///
+/// 모양 ID 값이 아니라 참조하는 모양의 내용을 비교합니다.
+/// Compares the referenced shape contents rather than the shape ID values.
+fn same_definition(doc_info: &DocInfo, target_id: usize, candidate: &Style) -> bool {
+    let existing = &doc_info.styles[target_id];
+    if existing.style_type != candidate.style_type {
+        return false;
+    }
+    let char_shape = |id: Option<u16>| -> Option<&CharShape> {
+        id.and_then(|id| doc_info.char_shapes.get(id as usize))
+    };
+    let para_shape = |id: Option<u16>| -> Option<&ParaShape> {
+        id.and_then(|id| doc_info.para_shapes.get(id as usize))
+    };
+    same_value(
+        char_shape(existing.char_shape_id),
+        char_shape(candidate.char_shape_id),
+    ) && same_value(
+        para_shape(existing.para_shape_id),
+        para_shape(candidate.para_shape_id),
+    )
+}
+
+/// 직렬화 결과로 두 값을 비교 / Compare two values by their serialized form
+fn same_value<T: Serialize>(a: Option<&T>, b: Option<&T>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => serde_json::to_value(a).ok() == serde_json::to_value(b).ok(),
+        _ => false,
+    }
+}
+
+/// 겹치지 않는 스타일 이름 생성 / Generate a non-conflicting style name
+fn unique_style_name(styles: &[Style], name: &str) -> String {
+    (2..)
+        .map(|n| format!("{name} ({n})"))
+        .find(|candidate| !styles.iter().any(|s| &s.local_name == candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// 겹치지 않는 영문 스타일 이름 생성 / Generate a non-conflicting English style name
+fn unique_english_name(styles: &[Style], name: &str) -> String {
+    (2..)
+        .map(|n| format!("{name} ({n})"))
+        .find(|candidate| !styles.iter().any(|s| &s.english_name == candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// 글자 모양의 언어별 글꼴 ID 다시 매핑 / Remap per-language font IDs of a char shape
+fn remap_font_ids(char_shape: &mut CharShape, face_name_map: &[usize]) {
+    let ids = &mut char_shape.font_ids;
+    for id in [
+        &mut ids.korean,
+        &mut ids.english,
+        &mut ids.chinese,
+        &mut ids.japanese,
+        &mut ids.other,
+        &mut ids.symbol,
+        &mut ids.user,
+    ] {
+        if let Some(&mapped) = face_name_map.get(*id as usize) {
+            *id = mapped as crate::types::WORD;
+        }
+    }
+}
+
+/// 문단 ID 매핑 테이블 / Paragraph ID remapping tables
+struct IdRemap<'a> {
+    char_shapes: &'a [usize],
+    para_shapes: &'a [usize],
+    styles: &'a [usize],
+    border_fills: &'a [usize],
+    numberings: &'a [usize],
+    bin_data: &'a BinDataRemap,
+}
+
+impl IdRemap<'_> {
+    fn apply_paragraph(&self, paragraph: &mut Paragraph) -> Result<(), HwpError> {
+        let header = &mut paragraph.para_header;
+        if let Some(&id) = self.para_shapes.get(header.para_shape_id as usize) {
+            header.para_shape_id = id as u16;
+        }
+        if let Some(&id) = self.styles.get(header.para_style_id as usize) {
+            header.para_style_id = style_id(id)?;
+        }
+        for record in &mut paragraph.records {
+            self.apply_record(record)?;
+        }
+        Ok(())
+    }
+
+    fn apply_record(&self, record: &mut ParagraphRecord) -> Result<(), HwpError> {
+        match record {
+            ParagraphRecord::ParaCharShape { shapes } => {
+                for shape in shapes {
+                    if let Some(&id) = self.char_shapes.get(shape.shape_id as usize) {
+                        shape.shape_id = id as u32;
+                    }
+                }
+            }
+            ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } => {
+                match &mut header.data {
+                    CtrlHeaderData::Overlap { char_shape_ids, .. } => {
+                        for id in char_shape_ids {
+                            if let Some(&mapped) = self.char_shapes.get(*id as usize) {
+                                *id = mapped as u32;
+                            }
+                        }
+                    }
+                    // 구역의 개요 문단 번호 / Outline numbering of the section
+                    CtrlHeaderData::SectionDefinition {
+                        number_para_shape_id,
+                        ..
+                    } => {
+                        *number_para_shape_id =
+                            remap_one_based(*number_para_shape_id, self.numberings);
+                    }
+                    _ => {}
+                }
+                for child in children {
+                    self.apply_record(child)?;
+                }
+                for paragraph in paragraphs {
+                    self.apply_paragraph(paragraph)?;
+                }
+            }
+            ParagraphRecord::ListHeader { paragraphs, .. } => {
+                for paragraph in paragraphs {
+                    self.apply_paragraph(paragraph)?;
+                }
+            }
+            ParagraphRecord::MasterPage { master_page } => {
+                for paragraph in &mut master_page.paragraphs {
+                    self.apply_paragraph(paragraph)?;
+                }
+            }
+            ParagraphRecord::Table { table } => {
+                let attributes = &mut table.attributes;
+                attributes.border_fill_id =
+                    remap_one_based(attributes.border_fill_id, self.border_fills);
+                for zone in &mut attributes.zones {
+                    zone.border_fill_id = remap_one_based(zone.border_fill_id, self.border_fills);
+                }
+                for cell in &mut table.cells {
+                    let attributes = &mut cell.cell_attributes;
+                    attributes.border_fill_id =
+                        remap_one_based(attributes.border_fill_id, self.border_fills);
+                    for paragraph in &mut cell.paragraphs {
+                        self.apply_paragraph(paragraph)?;
+                    }
+                }
+            }
+            ParagraphRecord::PageBorderFill { page_border_fill } => {
+                page_border_fill.border_fill_id =
+                    remap_one_based(page_border_fill.border_fill_id, self.border_fills);
+            }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                for child in children {
+                    self.apply_record(child)?;
+                }
+            }
+            ParagraphRecord::ShapeComponentPicture {
+                shape_component_picture,
+            } => {
+                let picture_info = &mut shape_component_picture.picture_info;
+                picture_info.bindata_id = self.bin_data.id(picture_info.bindata_id)?;
+            }
+            ParagraphRecord::ShapeComponentOle {
+                shape_component_ole,
+            } => {
+                shape_component_ole.bindata_id = self.bin_data.id(shape_component_ole.bindata_id)?;
+            }
+            ParagraphRecord::VideoData { video_data } => match &mut video_data.video_attributes {
+                VideoAttributes::Local {
+                    video_bindata_id,
+                    thumbnail_bindata_id,
+                } => {
+                    *video_bindata_id = self.bin_data.id(*video_bindata_id)?;
+                    *thumbnail_bindata_id = self.bin_data.id(*thumbnail_bindata_id)?;
+                }
+                VideoAttributes::Web {
+                    thumbnail_bindata_id,
+                    ..
+                } => *thumbnail_bindata_id = self.bin_data.id(*thumbnail_bindata_id)?,
+            },
+            ParagraphRecord::HwpxImage {
+                binary_item_ref, ..
+            } => *binary_item_ref = self.bin_data.name(binary_item_ref),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::docinfo::style::StyleType;
+    use crate::document::docinfo::{BorderFill, Bullet, TabDef};
+
+    fn char_shape(base_size: i32) -> CharShape {
+        let mut char_shape = CharShape::parse(&[0u8; 72], 0).unwrap();
+        char_shape.base_size = base_size;
+        char_shape
+    }
+
+    fn char_style(name: &str, char_shape_id: u16) -> Style {
+        Style {
+            local_name: name.to_string(),
+            english_name: String::new(),
+            style_type: StyleType::Character,
+            next_style_id: 0,
+            lang_id: 0,
+            para_shape_id: None,
+            char_shape_id: Some(char_shape_id),
+        }
+    }
+
+    fn doc_info(base_size: i32) -> DocInfo {
+        DocInfo {
+            char_shapes: vec![char_shape(base_size)],
+            styles: vec![char_style("강조", 0)],
+            ..Default::default()
+        }
+    }
+
+    fn merge(strategy: StyleConflictStrategy) -> (DocInfo, MergeReport) {
+        let mut target = doc_info(1000);
+        let source = doc_info(2000);
+        let options = MergeOptions::default().with_style_conflict(strategy);
+        let report = merge_doc_info(&mut target, &source, &options).unwrap();
+        (target, report)
+    }
+
+    #[test]
+    fn test_shape_references_are_remapped() {
+        let border_fill = || BorderFill::parse(&[0u8; 64]).unwrap();
+        let mut target = DocInfo {
+            border_fill: vec![border_fill(), border_fill()],
+            tab_defs: vec![TabDef::parse(&[0u8; 8]).unwrap()],
+            bullets: vec![Bullet::parse(&[0u8; 14]).unwrap()],
+            ..doc_info(1000)
+        };
+        let mut para_shape = ParaShape::parse(&[0u8; 54], 0x05010000).unwrap();
+        para_shape.attributes1.header_shape_type = HeaderShapeType::Bullet;
+        para_shape.number_bullet_id = 1;
+        para_shape.border_fill_id = 1;
+        let mut char_shape = char_shape(1000);
+        char_shape.border_fill_id = Some(1);
+        let source = DocInfo {
+            border_fill: vec![border_fill()],
+            tab_defs: vec![TabDef::parse(&[0u8; 8]).unwrap()],
+            bullets: vec![Bullet::parse(&[0u8; 14]).unwrap()],
+            para_shapes: vec![para_shape],
+            char_shapes: vec![char_shape],
+            ..Default::default()
+        };
+
+        let report = merge_doc_info(&mut target, &source, &MergeOptions::default()).unwrap();
+        assert_eq!(report.border_fill_map, [2]);
+        assert_eq!(report.tab_def_map, [1]);
+        assert_eq!(report.bullet_map, [1]);
+        let merged = &target.para_shapes[report.para_shape_map[0]];
+        assert_eq!(merged.border_fill_id, 3);
+        assert_eq!(merged.tab_def_id, 1);
+        assert_eq!(merged.number_bullet_id, 2);
+        assert_eq!(
+            target.char_shapes[report.char_shape_map[0]].border_fill_id,
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_identical_style_is_reused() {
+        let mut target = doc_info(1000);
+        let report =
+            merge_doc_info(&mut target, &doc_info(1000), &MergeOptions::default()).unwrap();
+        assert_eq!(report.style_mappings[0].action, StyleMappingAction::Reused);
+        assert_eq!(report.style_mappings[0].target_id, 0);
+        assert_eq!(target.styles.len(), 1);
+        assert_eq!(report.conflicts().count(), 0);
+    }
+
+    #[test]
+    fn test_conflict_rename() {
+        let (target, report) = merge(StyleConflictStrategy::Rename);
+        assert_eq!(target.styles.len(), 2);
+        assert_eq!(target.styles[1].local_name, "강조 (2)");
+        assert_eq!(target.styles[1].char_shape_id, Some(1));
+        assert_eq!(report.style_mappings[0].target_id, 1);
+        assert_eq!(report.conflicts().count(), 1);
+    }
+
+    #[test]
+    fn test_conflict_prefer_source() {
+        let (target, report) = merge(StyleConflictStrategy::PreferSource);
+        assert_eq!(target.styles.len(), 1);
+        assert_eq!(target.styles[0].char_shape_id, Some(1));
+        assert_eq!(
+            report.style_mappings[0].action,
+            StyleMappingAction::ReplacedTarget
+        );
+    }
+
+    #[test]
+    fn test_conflict_prefer_target() {
+        let (target, report) = merge(StyleConflictStrategy::PreferTarget);
+        assert_eq!(target.styles.len(), 1);
+        assert_eq!(target.styles[0].char_shape_id, Some(0));
+        assert_eq!(
+            report.style_mappings[0].action,
+            StyleMappingAction::KeptTarget
+        );
+    }
+
+    #[test]
+    fn test_style_id_overflow_is_an_error() {
+        let mut target = DocInfo {
+            styles: (0..256)
+                .map(|i| char_style(&format!("스타일 {}", i), 0))
+                .collect(),
+            ..doc_info(1000)
+        };
+        let result = merge_doc_info(&mut target, &doc_info(1000), &MergeOptions::default());
+        assert!(matches!(result, Err(HwpError::UnexpectedValue { .. })));
+        // 실패하면 대상은 그대로 / The target is unchanged on failure
+        assert_eq!(target.styles.len(), 256);
+    }
+
+    #[test]
+    fn test_style_id_overflow_without_next_style_is_an_error() {
+        let mut target = DocInfo {
+            styles: (0..256)
+                .map(|i| char_style(&format!("스타일 {}", i), 0))
+                .collect(),
+            ..doc_info(1000)
+        };
+        // 다음 스타일이 없는 원본 스타일 / Source style whose next style does not exist
+        let mut source = doc_info(1000);
+        source.styles[0].next_style_id = 9;
+        let result = merge_doc_info(&mut target, &source, &MergeOptions::default());
+        assert!(matches!(result, Err(HwpError::UnexpectedValue { .. })));
+        assert_eq!(target.styles.len(), 256);
+    }
+
+    #[test]
+    fn test_merged_images_keep_their_data() {
+        use crate::builder::{DocumentBuilder, ParagraphStyle};
+
+        let build = |bytes: &[u8]| {
+            DocumentBuilder::new()
+                .add_paragraph("그림", &ParagraphStyle::default())
+                .add_image(bytes)
+                .build()
+        };
+        let mut target = build(b"target");
+        let source = build(b"source");
+        let report = merge_documents(&mut target, &source, &MergeOptions::default()).unwrap();
+
+        let items = &target.bin_data.items;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].index, 1);
+        assert_eq!(report.bin_data_map, BTreeMap::from([(0, 1)]));
+        let renamed = &report.bin_data_names["image1"];
+        assert_eq!(items[1].name.as_ref(), Some(renamed));
+
+        // 추가된 구역의 그림은 원본 데이터를 가리킴 / Appended pictures point at the source data
+        let appended = target.body_text.sections.last().unwrap();
+        let reference = appended
+            .paragraphs
+            .iter()
+            .flat_map(|paragraph| &paragraph.records)
+            .find_map(|record| match record {
+                ParagraphRecord::HwpxImage {
+                    binary_item_ref, ..
+                } => Some(binary_item_ref),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(reference, renamed);
+        let item = items
+            .iter()
+            .find(|item| item.name.as_ref() == Some(reference));
+        assert_eq!(item.unwrap().data, source.bin_data.items[0].data);
+    }
+}
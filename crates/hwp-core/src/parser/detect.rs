@@ -1,7 +1,15 @@
-//! File format detection for HWP/HWPX files
+//! File format detection for HWP/HWPX/HWPML files
 //!
 //! HWP 5.0 uses CFB (Compound File Binary) format with magic bytes: D0 CF 11 E0 A1 B1 1A E1
 //! HWPX uses ZIP format with magic bytes: 50 4B 03 04 (PK..)
+//! HWPML (.hml) is a single XML file whose root element is `<HWPML>`
+//! HWP 3.0 (legacy binary) starts with the signature "HWP Document File V3.00"
+//!
+//! Template files share these containers: .hwt is an HWP 5.0 file and .hwtx is an HWPX file.
+//! Their bytes do not mark them as templates, so only the file name tells them apart.
+
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
 
 /// Supported file formats
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,6 +18,10 @@ pub enum FileFormat {
     Hwp5,
     /// HWPX format (ZIP-based, XML)
     Hwpx,
+    /// HWPML format (single XML file, .hml)
+    Hwpml,
+    /// HWP 3.0 format (legacy binary)
+    Hwp3,
     /// Unknown or unsupported format
     Unknown,
 }
@@ -22,8 +34,20 @@ const CFB_MAGIC: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
 /// Used by HWPX files
 const ZIP_MAGIC: [u8; 4] = [0x50, 0x4B, 0x03, 0x04];
 
+/// HWP 3.0 signature prefix (followed by the minor version, e.g. "00")
+/// Used by HWP 3.0 files
+const HWP3_SIGNATURE: &[u8] = b"HWP Document File V3.";
+
+/// Bytes scanned for the `<HWPML>` root element
+const HWPML_SNIFF_LEN: usize = 1024;
+
+/// Template file extensions (.hwt: HWP 5.0, .hwtx: HWPX)
+const TEMPLATE_EXTENSIONS: [&str; 2] = ["hwt", "hwtx"];
+
 /// Detect file format from byte array
 ///
+/// Templates are detected as their underlying format: .hwt as `Hwp5`, .hwtx as `Hwpx`.
+///
 /// # Arguments
 /// * `data` - Byte array containing the file data
 ///
@@ -41,6 +65,10 @@ const ZIP_MAGIC: [u8; 4] = [0x50, 0x4B, 0x03, 0x04];
 /// // HWPX file (ZIP magic bytes)
 /// let hwpx_data = [0x50, 0x4B, 0x03, 0x04];
 /// assert!(matches!(detect_format(&hwpx_data), hwp_core::parser::FileFormat::Hwpx));
+///
+/// // HWPML file (XML with an <HWPML> root)
+/// let hwpml_data = br#"<?xml version="1.0" encoding="UTF-8"?><HWPML Version="2.8">"#;
+/// assert!(matches!(detect_format(hwpml_data), hwp_core::parser::FileFormat::Hwpml));
 /// ```
 pub fn detect_format(data: &[u8]) -> FileFormat {
     // Check for CFB magic bytes (HWP 5.0)
@@ -53,9 +81,97 @@ pub fn detect_format(data: &[u8]) -> FileFormat {
         return FileFormat::Hwpx;
     }
 
+    // Check for the HWP 3.0 signature
+    if data.starts_with(HWP3_SIGNATURE) {
+        return FileFormat::Hwp3;
+    }
+
+    // Check for an <HWPML> root element (HWPML)
+    if is_hwpml(data) {
+        return FileFormat::Hwpml;
+    }
+
     FileFormat::Unknown
 }
 
+/// Detect file format from a seekable reader
+///
+/// Reads only the bytes `detect_format` looks at and seeks back to where the reader started.
+///
+/// # Arguments
+/// * `reader` - Seekable reader positioned at the start of the file data
+///
+/// # Returns
+/// Detected file format
+pub fn detect_format_reader(reader: &mut (impl Read + Seek)) -> std::io::Result<FileFormat> {
+    let start = reader.stream_position()?;
+    let mut head = Vec::with_capacity(HWPML_SNIFF_LEN);
+    reader.take(HWPML_SNIFF_LEN as u64).read_to_end(&mut head)?;
+    reader.seek(SeekFrom::Start(start))?;
+    Ok(detect_format(&head))
+}
+
+/// Check whether the data starts with an `<HWPML>` root element
+///
+/// Skips a byte order mark, the XML declaration, comments and whitespace. UTF-16LE files
+/// (FF FE) are decoded first.
+fn is_hwpml(data: &[u8]) -> bool {
+    let head = &data[..data.len().min(HWPML_SNIFF_LEN)];
+    let text = if let Some(utf16) = head.strip_prefix(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        let head = head.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(head);
+        String::from_utf8_lossy(head).into_owned()
+    };
+
+    let mut rest = text.as_str();
+    loop {
+        rest = rest.trim_start();
+        let end = if rest.starts_with("<?") {
+            rest.find("?>").map(|end| end + 2)
+        } else if rest.starts_with("<!--") {
+            rest.find("-->").map(|end| end + 3)
+        } else {
+            break;
+        };
+        match end {
+            Some(end) => rest = &rest[end..],
+            None => return false,
+        }
+    }
+
+    rest.strip_prefix("<HWPML").is_some_and(|after| {
+        after
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_whitespace() || c == '>' || c == '/')
+    })
+}
+
+/// Check whether a path has a template extension (.hwt, .hwtx)
+///
+/// # Examples
+/// ```
+/// use hwp_core::parser::is_template_path;
+///
+/// assert!(is_template_path("report.HWTX"));
+/// assert!(!is_template_path("report.hwpx"));
+/// ```
+pub fn is_template_path(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            TEMPLATE_EXTENSIONS
+                .iter()
+                .any(|template| ext.eq_ignore_ascii_case(template))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,6 +188,31 @@ mod tests {
         assert_eq!(detect_format(&data), FileFormat::Hwpx);
     }
 
+    #[test]
+    fn test_detect_hwp3() {
+        let data = b"HWP Document File V3.00 \x1A\x01\x02\x03\x04\x05";
+        assert_eq!(detect_format(data), FileFormat::Hwp3);
+        assert_eq!(
+            detect_format(b"HWP Document File V2.10"),
+            FileFormat::Unknown
+        );
+    }
+
+    #[test]
+    fn test_detect_hwpml() {
+        let data = b"\xEF\xBB\xBF<?xml version=\"1.0\"?>\r\n<!-- hml -->\n<HWPML Version=\"2.8\">";
+        assert_eq!(detect_format(data), FileFormat::Hwpml);
+
+        let utf16: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain("<HWPML>".encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+        assert_eq!(detect_format(&utf16), FileFormat::Hwpml);
+
+        assert_eq!(detect_format(b"<HWPMLX>"), FileFormat::Unknown);
+        assert_eq!(detect_format(b"<html></html>"), FileFormat::Unknown);
+    }
+
     #[test]
     fn test_detect_unknown() {
         let data = [0x00, 0x00, 0x00, 0x00];
@@ -84,6 +225,14 @@ mod tests {
         assert_eq!(detect_format(&data), FileFormat::Unknown);
     }
 
+    #[test]
+    fn test_template_paths() {
+        assert!(is_template_path("form.hwt"));
+        assert!(is_template_path("dir/form.Hwtx"));
+        assert!(!is_template_path("form.hwp"));
+        assert!(!is_template_path("hwt"));
+    }
+
     #[test]
     fn test_detect_too_short() {
         let data = [0xD0, 0xCF];
@@ -0,0 +1,397 @@
+/// HWP 3.0 문단 리스트 / HWP 3.0 paragraph lists
+///
+/// 문단 리스트는 문단 정보가 이어지다가 글자 수가 0인 문단 정보로 끝납니다. 문단 안의 특수
+/// 문자(코드 0~31)는 크기가 정해진 블록이거나, 길이가 앞에 붙은 블록이거나, 뒤에 문단 리스트가
+/// 따라오는 상자(표, 글상자, 그림 캡션, 머리말, 각주)입니다.
+/// A paragraph list is a run of paragraphs terminated by a paragraph header with zero
+/// characters. Special characters (codes 0-31) inside a paragraph are fixed-size blocks,
+/// length-prefixed blocks, or boxes followed by paragraph lists (tables, text boxes, picture
+/// captions, headers and footnotes).
+use super::hchar::decode_hchar;
+use crate::document::bodytext::list_header::{
+    LineBreak, ListHeader, ListHeaderAttribute, TextDirection, VerticalAlign,
+};
+use crate::document::bodytext::para_header::ParaHeader;
+use crate::document::bodytext::table::{
+    CellAttributes, PageBreakBehavior, Table, TableAttribute, TableAttributes, TableCell,
+    TablePadding,
+};
+use crate::document::bodytext::{InlineControl, ParaTextRun, Paragraph, ParagraphRecord};
+use crate::error::HwpError;
+use crate::types::HWPUNIT;
+
+/// 글자 모양 크기 / Char shape size
+pub(crate) const CHAR_SHAPE_SIZE: usize = 31;
+/// 문단 모양 크기 / Para shape size
+pub(crate) const PARA_SHAPE_SIZE: usize = 187;
+/// 문단 정보 크기 / Paragraph header size
+const PARA_HEADER_SIZE: usize = 12;
+/// 줄 정보 크기 / Line info size
+const LINE_INFO_SIZE: usize = 14;
+/// 상자(표, 글상자 등) 정보 크기 / Box (table, text box, ...) header size
+const BOX_HEADER_SIZE: usize = 84;
+/// 셀 정보 크기 / Cell info size
+const CELL_INFO_SIZE: usize = 27;
+/// 그림 정보 크기 / Picture header size
+const PICTURE_HEADER_SIZE: usize = 348;
+/// 상자 안 문단 리스트의 최대 중첩 / Maximum nesting of paragraph lists inside boxes
+const MAX_LIST_DEPTH: usize = 32;
+/// HWP 3.0 단위(1/1800인치) → HWPUNIT(1/7200인치) / HWP 3.0 unit (1/1800 inch) → HWPUNIT (1/7200 inch)
+const HUNIT_SCALE: u32 = 4;
+
+/// 문단 끝 / End of paragraph
+const CH_END_PARA: u16 = 13;
+
+/// 바이트 읽기 위치 / Byte read position
+pub(crate) struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize, field: &str) -> Result<&'a [u8], HwpError> {
+        let remaining = self.data.len() - self.pos;
+        if len > remaining {
+            return Err(HwpError::insufficient_data(field, len, remaining));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub(crate) fn skip(&mut self, len: usize, field: &str) -> Result<(), HwpError> {
+        self.bytes(len, field).map(|_| ())
+    }
+
+    pub(crate) fn u8(&mut self, field: &str) -> Result<u8, HwpError> {
+        Ok(self.bytes(1, field)?[0])
+    }
+
+    pub(crate) fn u16(&mut self, field: &str) -> Result<u16, HwpError> {
+        let bytes = self.bytes(2, field)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn u32(&mut self, field: &str) -> Result<u32, HwpError> {
+        let bytes = self.bytes(4, field)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// 본문 읽기 상태 / Body reading state
+#[derive(Default)]
+pub(crate) struct BodyReader {
+    depth: usize,
+    /// 변환하지 못한 글자 수 (한자, 특수 문자) / Characters that could not be converted (Hanja, symbols)
+    pub unmapped_chars: usize,
+}
+
+impl BodyReader {
+    /// 문단 리스트 읽기 / Read a paragraph list
+    pub(crate) fn read_paragraph_list(
+        &mut self,
+        cursor: &mut Cursor,
+    ) -> Result<Vec<Paragraph>, HwpError> {
+        self.depth += 1;
+        if self.depth > MAX_LIST_DEPTH {
+            return Err(HwpError::record_parse(
+                "paragraph list",
+                format!("nested deeper than {MAX_LIST_DEPTH} levels"),
+            ));
+        }
+        let mut paragraphs = Vec::new();
+        while self.read_paragraph(cursor, &mut paragraphs)? {}
+        self.depth -= 1;
+        Ok(paragraphs)
+    }
+
+    /// 문단 하나 읽기 (리스트 끝이면 false) / Read one paragraph (false at the end of the list)
+    fn read_paragraph(
+        &mut self,
+        cursor: &mut Cursor,
+        paragraphs: &mut Vec<Paragraph>,
+    ) -> Result<bool, HwpError> {
+        let header = cursor.bytes(PARA_HEADER_SIZE, "paragraph header")?;
+        let reuse_shape = header[0] != 0;
+        let char_count = u16::from_le_bytes([header[1], header[2]]);
+        let line_count = u16::from_le_bytes([header[3], header[4]]);
+        let contains_char_shapes = header[5] != 0;
+        cursor.skip(CHAR_SHAPE_SIZE, "paragraph char shape")?;
+        if char_count == 0 {
+            return Ok(false);
+        }
+        if !reuse_shape {
+            cursor.skip(PARA_SHAPE_SIZE, "paragraph para shape")?;
+        }
+        cursor.skip(line_count as usize * LINE_INFO_SIZE, "line info")?;
+        if contains_char_shapes {
+            for _ in 0..char_count {
+                if cursor.u8("char shape flag")? == 0 {
+                    cursor.skip(CHAR_SHAPE_SIZE, "char shape")?;
+                }
+            }
+        }
+
+        let mut text = String::new();
+        let mut boxes = Vec::new();
+        loop {
+            let code = cursor.u16("character")?;
+            match code {
+                CH_END_PARA => break,
+                32.. => match decode_hchar(code) {
+                    Some(c) => text.push(c),
+                    None => {
+                        self.unmapped_chars += 1;
+                        text.push(char::REPLACEMENT_CHARACTER);
+                    }
+                },
+                9 => {
+                    cursor.skip(6, "tab")?;
+                    text.push(InlineControl::Tab.as_char());
+                }
+                24 => {
+                    cursor.skip(4, "hyphen")?;
+                    text.push(InlineControl::Hyphen.as_char());
+                }
+                30 => {
+                    cursor.skip(2, "keep space")?;
+                    text.push(InlineControl::NonBreakingSpace.as_char());
+                }
+                31 => {
+                    cursor.skip(2, "fixed space")?;
+                    text.push(InlineControl::FixedWidthSpace.as_char());
+                }
+                10 => boxes.extend(self.read_box(cursor)?),
+                11 => {
+                    // 그림: 추가 정보 길이, 고정 정보, 추가 정보, 캡션
+                    // Picture: extra data length, fixed header, extra data, caption
+                    let extra = cursor.u32("picture")? as usize;
+                    cursor.skip(PICTURE_HEADER_SIZE - 6 + extra, "picture")?;
+                    boxes.extend(self.read_paragraph_list(cursor)?);
+                }
+                15..=17 => {
+                    // 숨은 설명, 머리말/꼬리말, 각주/미주는 본문에 넣지 않음
+                    // Hidden comments, headers/footers and footnotes/endnotes are left out of the body
+                    let size = match code {
+                        15 => 14,
+                        16 => 16,
+                        _ => 20,
+                    };
+                    cursor.skip(size, "note")?;
+                    self.read_paragraph_list(cursor)?;
+                }
+                _ => skip_special_char(cursor, code)?,
+            }
+        }
+
+        if !text.is_empty() || boxes.is_empty() {
+            paragraphs.push(text_paragraph(text));
+        }
+        paragraphs.extend(boxes);
+        Ok(true)
+    }
+
+    /// 상자 읽기: 표는 표 문단으로, 나머지는 안의 문단으로
+    /// Read a box: tables become a table paragraph, other boxes their inner paragraphs
+    fn read_box(&mut self, cursor: &mut Cursor) -> Result<Vec<Paragraph>, HwpError> {
+        let header = cursor.bytes(BOX_HEADER_SIZE - 2, "box")?;
+        let box_type = u16::from_le_bytes([header[76], header[77]]);
+        let cell_count = u16::from_le_bytes([header[78], header[79]]) as usize;
+
+        let mut cells = Vec::with_capacity(cell_count);
+        for _ in 0..cell_count {
+            let info = cursor.bytes(CELL_INFO_SIZE, "cell")?;
+            let value = |offset: usize| u16::from_le_bytes([info[offset], info[offset + 1]]);
+            cells.push(CellInfo {
+                x: value(4),
+                y: value(6),
+                width: value(8),
+                height: value(10),
+                vertical_align: info[19],
+            });
+        }
+        let mut cell_paragraphs = Vec::with_capacity(cell_count);
+        for _ in 0..cell_count {
+            cell_paragraphs.push(self.read_paragraph_list(cursor)?);
+        }
+        let mut paragraphs = self.read_paragraph_list(cursor)?;
+
+        // 0: 표, 1: 글상자, 2: 수식, 3: 버튼 / 0: table, 1: text box, 2: equation, 3: button
+        if box_type == 0 && cell_count > 0 {
+            paragraphs.push(table_paragraph(create_table(&cells, cell_paragraphs)));
+        } else {
+            paragraphs.extend(cell_paragraphs.into_iter().flatten());
+        }
+        Ok(paragraphs)
+    }
+}
+
+/// 문단 리스트가 따라오지 않는 특수 문자 건너뛰기
+/// Skip a special character that is not followed by a paragraph list
+fn skip_special_char(cursor: &mut Cursor, code: u16) -> Result<(), HwpError> {
+    // 코드 자신(2바이트)을 뺀 크기 / Size without the code itself (2 bytes)
+    let size = match code {
+        7 => 82,
+        8 => 94,
+        14 => 82,
+        18..=21 => 6,
+        22 => 22,
+        23 => 8,
+        25 => 4,
+        26 => 244,
+        28 => 62,
+        // 필드, 책갈피, 예약 코드: 길이(4바이트), 코드, 자료
+        // Fields, bookmarks, reserved codes: length (4 bytes), code, data
+        0..=6 | 12 | 27 | 29 => {
+            let len = cursor.u32("special character length")? as usize;
+            cursor.skip(2, "special character")?;
+            len
+        }
+        _ => {
+            return Err(HwpError::record_parse(
+                "special character",
+                format!("unknown code {code}"),
+            ))
+        }
+    };
+    cursor.skip(size, "special character")
+}
+
+/// 셀 정보 / Cell info
+struct CellInfo {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+    vertical_align: u8,
+}
+
+/// 셀 좌표로 행/열 주소와 병합을 계산해 표 만들기
+/// Build a table, deriving row/column addresses and spans from cell coordinates
+fn create_table(cells: &[CellInfo], cell_paragraphs: Vec<Vec<Paragraph>>) -> Table {
+    let boundaries = |edges: &mut dyn Iterator<Item = u32>| {
+        let mut edges: Vec<u32> = edges.collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    };
+    let columns = boundaries(
+        &mut cells
+            .iter()
+            .flat_map(|c| [u32::from(c.x), u32::from(c.x) + u32::from(c.width)]),
+    );
+    let rows = boundaries(
+        &mut cells
+            .iter()
+            .flat_map(|c| [u32::from(c.y), u32::from(c.y) + u32::from(c.height)]),
+    );
+    let index = |edges: &[u32], value: u32| edges.partition_point(|&edge| edge < value) as u16;
+
+    let mut table_cells: Vec<TableCell> = cells
+        .iter()
+        .zip(cell_paragraphs)
+        .map(|(cell, mut paragraphs)| {
+            let (x, y) = (u32::from(cell.x), u32::from(cell.y));
+            let (right, bottom) = (x + u32::from(cell.width), y + u32::from(cell.height));
+            if paragraphs.is_empty() {
+                paragraphs.push(text_paragraph(String::new()));
+            }
+            TableCell {
+                list_header: ListHeader {
+                    paragraph_count: paragraphs.len() as i16,
+                    attribute: ListHeaderAttribute {
+                        text_direction: TextDirection::Horizontal,
+                        line_break: LineBreak::Normal,
+                        vertical_align: match cell.vertical_align {
+                            1 => VerticalAlign::Center,
+                            2 => VerticalAlign::Bottom,
+                            _ => VerticalAlign::Top,
+                        },
+                    },
+                },
+                cell_attributes: CellAttributes {
+                    col_address: index(&columns, x),
+                    row_address: index(&rows, y),
+                    col_span: (index(&columns, right) - index(&columns, x)).max(1),
+                    row_span: (index(&rows, bottom) - index(&rows, y)).max(1),
+                    width: HWPUNIT(u32::from(cell.width) * HUNIT_SCALE),
+                    height: HWPUNIT(u32::from(cell.height) * HUNIT_SCALE),
+                    left_margin: 0,
+                    right_margin: 0,
+                    top_margin: 0,
+                    bottom_margin: 0,
+                    border_fill_id: 0,
+                },
+                paragraphs,
+            }
+        })
+        .collect();
+    table_cells.sort_by_key(|cell| {
+        (
+            cell.cell_attributes.row_address,
+            cell.cell_attributes.col_address,
+        )
+    });
+
+    let row_count = rows.len().saturating_sub(1) as u16;
+    let row_sizes = (0..row_count)
+        .map(|row| {
+            table_cells
+                .iter()
+                .filter(|cell| cell.cell_attributes.row_address == row)
+                .count() as i16
+        })
+        .collect();
+    Table {
+        attributes: TableAttributes {
+            attribute: TableAttribute {
+                page_break: PageBreakBehavior::NoBreak,
+                header_row_repeat: false,
+            },
+            row_count,
+            col_count: columns.len().saturating_sub(1) as u16,
+            cell_spacing: 0,
+            padding: TablePadding {
+                left: 0,
+                right: 0,
+                top: 0,
+                bottom: 0,
+            },
+            row_sizes,
+            border_fill_id: 0,
+            zones: vec![],
+        },
+        cells: table_cells,
+    }
+}
+
+fn text_paragraph(text: String) -> Paragraph {
+    Paragraph {
+        para_header: ParaHeader {
+            text_char_count: text.chars().count() as u32,
+            ..Default::default()
+        },
+        records: vec![ParagraphRecord::ParaText {
+            runs: vec![ParaTextRun::Text { text: text.clone() }],
+            text,
+            control_char_positions: vec![],
+            inline_control_params: vec![],
+        }],
+        resolved_number: None,
+    }
+}
+
+fn table_paragraph(table: Table) -> Paragraph {
+    Paragraph {
+        para_header: ParaHeader {
+            text_char_count: 1,
+            ..Default::default()
+        },
+        records: vec![ParagraphRecord::Table { table }],
+        resolved_number: None,
+    }
+}
@@ -0,0 +1,116 @@
+//! HWP 3.0 글자 코드 / HWP 3.0 character codes
+//!
+//! HWP 3.0 본문은 2바이트 "한글 코드"로 저장됩니다. 0x80 미만은 ASCII이고, 최상위 비트가 켜진
+//! 코드는 조합형(초성 5비트, 중성 5비트, 종성 5비트) 한글입니다. 한자와 특수 문자는 한/글 고유
+//! 순서라 변환표가 필요하므로 여기서는 읽지 않습니다.
+//! HWP 3.0 body text is stored as 2-byte "hchar" codes. Codes below 0x80 are ASCII and codes
+//! with the top bit set are Johab Hangul (5-bit initial, medial and final). Hanja and symbols use
+//! Hangul-specific orderings that need lookup tables, so they are not decoded here.
+
+/// 조합형 초성 코드 → 초성 순서 (없음은 None) / Johab initial code → initial index (None for fill)
+fn initial_index(code: u16) -> Option<u32> {
+    match code {
+        2..=20 => Some(u32::from(code) - 2),
+        _ => None,
+    }
+}
+
+/// 조합형 중성 코드 → 중성 순서 (없음은 None) / Johab medial code → medial index (None for fill)
+fn medial_index(code: u16) -> Option<u32> {
+    let code = u32::from(code);
+    match code {
+        3..=7 => Some(code - 3),
+        10..=15 => Some(code - 5),
+        18..=23 => Some(code - 7),
+        26..=29 => Some(code - 9),
+        _ => None,
+    }
+}
+
+/// 조합형 종성 코드 → 종성 순서 (없음은 0) / Johab final code → final index (0 for fill)
+fn final_index(code: u16) -> Option<u32> {
+    let code = u32::from(code);
+    match code {
+        1 => Some(0),
+        2..=17 => Some(code - 1),
+        19..=29 => Some(code - 2),
+        _ => None,
+    }
+}
+
+/// 초성 순서별 호환용 자모 / Compatibility jamo by initial index
+const INITIAL_JAMO: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 한글 코드 하나를 유니코드 문자로 변환 (변환할 수 없으면 None)
+/// Convert one hchar to a Unicode character (None when it cannot be converted)
+pub(crate) fn decode_hchar(code: u16) -> Option<char> {
+    if code < 0x80 {
+        return char::from_u32(u32::from(code));
+    }
+    if code & 0x8000 == 0 {
+        // 한자, 특수 문자 / Hanja, symbols
+        return None;
+    }
+
+    let initial = initial_index((code >> 10) & 0x1F);
+    let medial = medial_index((code >> 5) & 0x1F);
+    let last = final_index(code & 0x1F)?;
+    match (initial, medial) {
+        (Some(initial), Some(medial)) => {
+            char::from_u32(0xAC00 + (initial * 21 + medial) * 28 + last)
+        }
+        // 낱자 / Standalone jamo
+        (Some(initial), None) if last == 0 => Some(INITIAL_JAMO[initial as usize]),
+        (None, Some(medial)) if last == 0 => char::from_u32(0x314F + medial),
+        _ => None,
+    }
+}
+
+/// 0으로 끝나는 한글 코드 문자열 (변환할 수 없는 글자는 U+FFFD)
+/// Zero-terminated hchar string (U+FFFD for characters that cannot be converted)
+pub(crate) fn decode_hchar_string(bytes: &[u8]) -> String {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&code| code != 0)
+        .map(|code| decode_hchar(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// 문자를 조합형 한글 코드로 (테스트용) / Encode a character as an hchar (for tests)
+#[cfg(test)]
+pub(crate) fn encode_hchar(c: char) -> u16 {
+    let code = c as u32;
+    if code < 0x80 {
+        return code as u16;
+    }
+    let index = code - 0xAC00;
+    let (initial, medial, last) = (index / (21 * 28), index / 28 % 21, index % 28);
+    let initial = initial + 2;
+    let medial = [
+        3, 4, 5, 6, 7, 10, 11, 12, 13, 14, 15, 18, 19, 20, 21, 22, 23, 26, 27, 28, 29,
+    ][medial as usize];
+    let last = if last < 17 { last + 1 } else { last + 2 };
+    (0x8000 | initial << 10 | medial << 5 | last) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_johab_hangul() {
+        // "한" = 초성 ㅎ(20), 중성 ㅏ(3), 종성 ㄴ(5)
+        assert_eq!(decode_hchar(0x8000 | 20 << 10 | 3 << 5 | 5), Some('한'));
+        assert_eq!(decode_hchar(0x8000 | 2 << 10 | 2 << 5 | 1), Some('ㄱ'));
+        assert_eq!(decode_hchar(0x8000 | 1 << 10 | 29 << 5 | 1), Some('ㅣ'));
+        assert_eq!(decode_hchar(u16::from(b'A')), Some('A'));
+        assert_eq!(decode_hchar(0x4000), None);
+        for c in ['가', '힣', '글', '뷁'] {
+            assert_eq!(decode_hchar(encode_hchar(c)), Some(c));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+/// HWP 3.0 Parser module
+///
+/// HWP 3.0 (.hwp, 1990s) is a flat binary format, unrelated to the CFB container of HWP 5.0.
+/// Only body text and tables are read; character and paragraph shapes, pictures, headers,
+/// footers and footnotes are skipped.
+///
+/// HWP 3.0 file structure:
+/// ```text
+/// document.hwp
+/// ├── Signature (30 bytes)        # "HWP Document File V3.00 \x1A\x01\x02\x03\x04\x05"
+/// ├── Document info (128 bytes)   # Page setup, encryption and compression flags
+/// ├── Document summary (1008 bytes)
+/// ├── Info block                  # Length given by the document info
+/// └── (raw deflate when compressed)
+///     ├── Font names              # 7 language groups
+///     ├── Styles
+///     ├── Paragraph list          # Body text
+///     └── Extra blocks            # Embedded pictures, ...
+/// ```
+mod body;
+mod hchar;
+
+use crate::document::{FileHeader, HwpDocument, Section, SourceFormat, SummaryInformation};
+use crate::error::HwpError;
+use crate::parser::limits::{is_limit_error, LimitedReader, ResourceLimit};
+use crate::parser::options::{ParseOptions, ParseWarning};
+use body::{BodyReader, Cursor, CHAR_SHAPE_SIZE, PARA_SHAPE_SIZE};
+use hchar::decode_hchar_string;
+
+/// 파일 인식 정보 크기 / Signature size
+const SIGNATURE_SIZE: usize = 30;
+/// 문서 정보 크기 / Document info size
+const DOCUMENT_INFO_SIZE: usize = 128;
+/// 문서 요약 항목 크기 (56 글자) / Document summary field size (56 characters)
+const SUMMARY_FIELD_SIZE: usize = 112;
+/// 문서 요약 항목 수 / Number of document summary fields
+const SUMMARY_FIELD_COUNT: usize = 9;
+/// 글꼴 이름 크기 / Font name size
+const FONT_NAME_SIZE: usize = 40;
+/// 글꼴 이름의 언어 수 / Number of font name language groups
+const FONT_LANGUAGES: usize = 7;
+/// 스타일 이름 크기 / Style name size
+const STYLE_NAME_SIZE: usize = 20;
+/// HWP 3.0 버전 (3.0.0.0) / HWP 3.0 version (3.0.0.0)
+const VERSION: u32 = 0x0300_0000;
+
+/// Parse HWP 3.0 file from byte array
+///
+/// # Arguments
+/// * `data` - Byte array containing the HWP 3.0 file data
+///
+/// # Returns
+/// Parsed HWP document structure
+pub fn parse(data: &[u8]) -> Result<HwpDocument, HwpError> {
+    parse_with_options(data, &ParseOptions::default()).map(|(document, _)| document)
+}
+
+/// Parse HWP 3.0 file with parse options
+///
+/// # Arguments
+/// * `data` - Byte array containing the HWP 3.0 file data
+/// * `options` - Parse options (`limits.max_entry_size` caps the decompressed body)
+///
+/// # Returns
+/// Parsed HWP document structure and warnings, e.g. for characters that could not be converted
+pub fn parse_with_options(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+    let mut cursor = Cursor::new(data);
+    cursor.skip(SIGNATURE_SIZE, "signature")?;
+    let info = cursor.bytes(DOCUMENT_INFO_SIZE, "document info")?;
+    if u16::from_le_bytes([info[96], info[97]]) != 0 {
+        return Err(HwpError::PasswordRequired);
+    }
+    let compressed = info[124] != 0;
+    let info_block_len = u16::from_le_bytes([info[126], info[127]]) as usize;
+
+    let summary = cursor.bytes(SUMMARY_FIELD_SIZE * SUMMARY_FIELD_COUNT, "document summary")?;
+    cursor.skip(info_block_len, "info block")?;
+
+    let rest = &data[SIGNATURE_SIZE + DOCUMENT_INFO_SIZE + summary.len() + info_block_len..];
+    let body = if compressed {
+        inflate(rest, options.limits.max_entry_size)?
+    } else {
+        rest.to_vec()
+    };
+
+    let mut cursor = Cursor::new(&body);
+    for _ in 0..FONT_LANGUAGES {
+        let count = cursor.u16("font count")? as usize;
+        cursor.skip(count * FONT_NAME_SIZE, "font names")?;
+    }
+    let style_count = cursor.u16("style count")? as usize;
+    cursor.skip(
+        style_count * (STYLE_NAME_SIZE + CHAR_SHAPE_SIZE + PARA_SHAPE_SIZE),
+        "styles",
+    )?;
+    let mut reader = BodyReader::default();
+    let paragraphs = reader.read_paragraph_list(&mut cursor)?;
+
+    let mut warnings = Vec::new();
+    if reader.unmapped_chars > 0 {
+        warnings.push(ParseWarning {
+            part: "BodyText".to_string(),
+            element: None,
+            message: format!(
+                "{} Hanja or symbol characters could not be converted and were replaced with U+FFFD",
+                reader.unmapped_chars
+            ),
+        });
+    }
+
+    let mut document = HwpDocument::new(FileHeader {
+        signature: "HWP Document File".to_string(),
+        version: VERSION,
+        document_flags: u32::from(compressed),
+        license_flags: 0,
+        encrypt_version: 0,
+        kogl_country: 0,
+        reserved: vec![0; 207],
+    });
+    document.source_format = SourceFormat::Hwp3;
+    document.body_text.sections.push(Section {
+        index: 0,
+        paragraphs,
+    });
+    document.summary_information = parse_summary(summary);
+    document.resolve_display_texts();
+    document.warnings = warnings.clone();
+    Ok((document, warnings))
+}
+
+/// 압축된 본문 풀기 / Inflate the compressed body
+fn inflate(data: &[u8], max: u64) -> Result<Vec<u8>, HwpError> {
+    use flate2::read::DeflateDecoder;
+    use std::io::Read;
+
+    let mut body = Vec::new();
+    LimitedReader::new(DeflateDecoder::new(data), max)
+        .read_to_end(&mut body)
+        .map_err(|e| {
+            if is_limit_error(&e) {
+                HwpError::ResourceLimitExceeded {
+                    limit: ResourceLimit::EntrySize,
+                    max,
+                }
+            } else {
+                HwpError::decompress_error(crate::error::CompressionFormat::Deflate, e.to_string())
+            }
+        })?;
+    Ok(body)
+}
+
+/// 문서 요약: 제목, 주제, 지은이, 날짜, 키워드 2개, 기타 3개
+/// Document summary: title, subject, author, date, 2 keywords, 3 comments
+fn parse_summary(summary: &[u8]) -> Option<SummaryInformation> {
+    let fields: Vec<String> = summary
+        .chunks_exact(SUMMARY_FIELD_SIZE)
+        .map(|field| decode_hchar_string(field).trim().to_string())
+        .collect();
+    let join = |fields: &[String]| {
+        let joined = fields
+            .iter()
+            .filter(|field| !field.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!joined.is_empty()).then_some(joined)
+    };
+    let info = SummaryInformation {
+        title: join(&fields[0..1]),
+        subject: join(&fields[1..2]),
+        author: join(&fields[2..3]),
+        date_string: join(&fields[3..4]),
+        keywords: join(&fields[4..6]),
+        comments: join(&fields[6..9]),
+        ..Default::default()
+    };
+    (info != SummaryInformation::default()).then_some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hchar::encode_hchar;
+    use super::*;
+    use crate::document::ParagraphRecord;
+
+    fn hchars(text: &str) -> Vec<u8> {
+        text.chars()
+            .flat_map(|c| encode_hchar(c).to_le_bytes())
+            .collect()
+    }
+
+    /// 글자 모양을 담지 않은 문단 / Paragraph without per-character shapes
+    fn paragraph(chars: &[u8], char_count: u16) -> Vec<u8> {
+        let mut bytes = vec![0u8];
+        bytes.extend(char_count.to_le_bytes());
+        bytes.extend([0u8; 9]);
+        bytes.extend([0u8; CHAR_SHAPE_SIZE + PARA_SHAPE_SIZE]);
+        bytes.extend(chars);
+        bytes.extend(13u16.to_le_bytes());
+        bytes
+    }
+
+    fn text_paragraph(text: &str) -> Vec<u8> {
+        paragraph(&hchars(text), text.chars().count() as u16 + 1)
+    }
+
+    fn end_of_list() -> Vec<u8> {
+        vec![0u8; 12 + CHAR_SHAPE_SIZE]
+    }
+
+    /// 2x2 표에서 아래 줄은 병합 / 2x2 table whose bottom row is merged
+    fn table() -> Vec<u8> {
+        let mut bytes = 10u16.to_le_bytes().to_vec();
+        let mut header = vec![0u8; 82];
+        header[78..80].copy_from_slice(&3u16.to_le_bytes());
+        bytes.extend(header);
+        for (x, y, w) in [(0u16, 0u16, 100u16), (100, 0, 100), (0, 50, 200)] {
+            let mut cell = vec![0u8; 27];
+            cell[4..6].copy_from_slice(&x.to_le_bytes());
+            cell[6..8].copy_from_slice(&y.to_le_bytes());
+            cell[8..10].copy_from_slice(&w.to_le_bytes());
+            cell[10..12].copy_from_slice(&50u16.to_le_bytes());
+            bytes.extend(cell);
+        }
+        for text in ["이름", "값", "합계"] {
+            bytes.extend(text_paragraph(text));
+            bytes.extend(end_of_list());
+        }
+        // 캡션 없음 / No caption
+        bytes.extend(end_of_list());
+        bytes
+    }
+
+    fn document(compressed: bool) -> Vec<u8> {
+        let mut body = Vec::new();
+        for _ in 0..FONT_LANGUAGES {
+            body.extend(1u16.to_le_bytes());
+            body.extend([0u8; FONT_NAME_SIZE]);
+        }
+        body.extend(0u16.to_le_bytes());
+        body.extend(text_paragraph("한글 3.0 문서"));
+        let mut chars = hchars("A");
+        chars.extend(9u16.to_le_bytes());
+        chars.extend([0u8; 6]);
+        chars.extend(table());
+        chars.extend(hchars("B"));
+        body.extend(paragraph(&chars, 4 + 42 + 1 + 1));
+        body.extend(end_of_list());
+
+        let mut data = b"HWP Document File V3.00 \x1A\x01\x02\x03\x04\x05".to_vec();
+        let mut info = [0u8; DOCUMENT_INFO_SIZE];
+        info[124] = u8::from(compressed);
+        data.extend(info);
+        let mut summary = [0u8; SUMMARY_FIELD_SIZE * SUMMARY_FIELD_COUNT];
+        let title = hchars("보고서");
+        summary[..title.len()].copy_from_slice(&title);
+        data.extend(summary);
+        if compressed {
+            use flate2::{write::DeflateEncoder, Compression};
+            use std::io::Write;
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&body).unwrap();
+            data.extend(encoder.finish().unwrap());
+        } else {
+            data.extend(body);
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_text_and_table() {
+        for compressed in [false, true] {
+            let document = crate::HwpParser::new()
+                .parse(&document(compressed))
+                .unwrap();
+            assert_eq!(document.format().format, SourceFormat::Hwp3);
+            assert_eq!(document.format().version, "3.0.0.0");
+            assert_eq!(
+                document
+                    .summary_information
+                    .as_ref()
+                    .and_then(|s| s.title.as_deref()),
+                Some("보고서")
+            );
+
+            let paragraphs = &document.body_text.sections[0].paragraphs;
+            assert_eq!(paragraphs.len(), 3);
+            assert_eq!(paragraphs[0].text(), "한글 3.0 문서");
+            assert_eq!(paragraphs[1].text(), "A\tB");
+            let ParagraphRecord::Table { table } = &paragraphs[2].records[0] else {
+                panic!("expected a table");
+            };
+            assert_eq!(
+                (table.attributes.row_count, table.attributes.col_count),
+                (2, 2)
+            );
+            let cells: Vec<_> = table
+                .cells
+                .iter()
+                .map(|cell| {
+                    let a = &cell.cell_attributes;
+                    (a.row_address, a.col_address, a.col_span, cell.text())
+                })
+                .collect();
+            assert_eq!(
+                cells,
+                [
+                    (0, 0, 1, "이름".to_string()),
+                    (0, 1, 1, "값".to_string()),
+                    (1, 0, 2, "합계".to_string()),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncated_file_is_an_error() {
+        let data = document(false);
+        assert!(parse(&data[..data.len() - 10]).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+/// HWPML Parser module
+///
+/// HWPML (.hml) is the single-file XML format used by older Hangul versions. Its element
+/// vocabulary is an upper-case predecessor of OWPML, so documents are translated into OWPML
+/// header and section XML and read with the HWPX parsers.
+///
+/// HWPML file structure:
+/// ```text
+/// <HWPML Version="2.8">
+/// ├── <HEAD>
+/// │   ├── <DOCSUMMARY>            # Title, author, ...
+/// │   └── <MAPPINGTABLE>          # Fonts, char shapes, para shapes, styles
+/// ├── <BODY>
+/// │   └── <SECTION>               # Body content
+/// └── <TAIL>                      # Embedded binary data (not read)
+/// ```
+mod translate;
+
+use std::borrow::Cow;
+
+use crate::document::{FileHeader, HwpDocument, SourceFormat};
+use crate::error::HwpError;
+use crate::parser::hwpx::container::decode_xml;
+use crate::parser::hwpx::{header, section};
+use crate::parser::options::{ParseOptions, ParseWarning};
+
+pub(crate) use translate::{translate, OwpmlParts};
+
+/// Parse HWPML file from byte array
+///
+/// # Arguments
+/// * `data` - Byte array containing the HWPML file data (UTF-8 or UTF-16 XML)
+///
+/// # Returns
+/// Parsed HWP document structure
+pub fn parse(data: &[u8]) -> Result<HwpDocument, HwpError> {
+    parse_with_options(data, &ParseOptions::default()).map(|(document, _)| document)
+}
+
+/// Parse HWPML file with parse options
+///
+/// # Arguments
+/// * `data` - Byte array containing the HWPML file data (UTF-8 or UTF-16 XML)
+/// * `options` - Parse options
+///
+/// # Returns
+/// Parsed HWP document structure and warnings for repaired problems
+pub fn parse_with_options(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+    let mut warnings = Vec::new();
+    let parts = translate_data(data, options)?;
+
+    let mut document = HwpDocument::new(FileHeader {
+        signature: "HWP Document File".to_string(),
+        version: parts.version,
+        document_flags: 0,
+        license_flags: 0,
+        encrypt_version: 0,
+        kogl_country: 0,
+        reserved: vec![0; 207],
+    });
+    document.source_format = SourceFormat::Hwpml;
+    document.doc_info = header::parse_doc_info_xml(&parts.header, &options.limits)?;
+
+    for (index, content) in parts.sections.into_iter().enumerate() {
+        let part = format!("BODY/SECTION[{index}]");
+        let section = section::parse_section_str(
+            index,
+            &part,
+            content,
+            options,
+            &mut warnings,
+            &mut |_| None,
+            &mut |_| None,
+        )?;
+        document.body_text.sections.push(section);
+    }
+
+    if parts.summary != Default::default() {
+        document.summary_information = Some(parts.summary);
+    }
+
+    document.resolve_display_texts();
+    document.warnings = warnings.clone();
+    Ok((document, warnings))
+}
+
+/// 파일을 디코딩해 OWPML 파트로 옮김 / Decode a file and translate it into OWPML parts
+pub(crate) fn translate_data(data: &[u8], options: &ParseOptions) -> Result<OwpmlParts, HwpError> {
+    let content = decode(data)?;
+    translate(&content, options.limits.max_xml_depth)
+}
+
+/// XML 디코딩 (BOM과 XML 선언의 인코딩을 따름) / Decode XML following its BOM or declared encoding
+pub(crate) fn decode(data: &[u8]) -> Result<String, HwpError> {
+    decode_xml(data).map(Cow::into_owned)
+}
@@ -0,0 +1,490 @@
+/// HWPML → OWPML 변환 / HWPML to OWPML translation
+///
+/// HWPML 요소는 대부분 OWPML 요소와 하나씩 대응하므로, 이름과 속성만 바꿔 HWPX의 header.xml과
+/// 구역 XML 모양으로 옮깁니다. 대응하는 요소가 없으면 하위 요소까지 건너뜁니다.
+/// Most HWPML elements map one-to-one onto OWPML elements, so only names and attributes are
+/// rewritten into the shape of HWPX header.xml and section XML. Elements without a counterpart
+/// are skipped together with their children.
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::document::SummaryInformation;
+use crate::error::HwpError;
+use crate::parser::limits::DepthGuard;
+use crate::types::DWORD;
+
+/// 버전 속성이 없을 때의 HWPML 버전 (2.8) / HWPML version when the attribute is missing (2.8)
+const DEFAULT_VERSION: DWORD = 0x0208_0000;
+
+/// OWPML 모양으로 옮긴 HWPML 문서 / HWPML document translated into OWPML parts
+#[derive(Debug, Default)]
+pub(crate) struct OwpmlParts {
+    /// `<HWPML Version>` (major.minor.0.0) / `<HWPML Version>` (major.minor.0.0)
+    pub version: DWORD,
+    /// header.xml에 해당하는 XML / XML equivalent to header.xml
+    pub header: String,
+    /// 구역 XML / Section XML
+    pub sections: Vec<String>,
+    /// `<DOCSUMMARY>`의 문서 정보 / Document information from `<DOCSUMMARY>`
+    pub summary: SummaryInformation,
+}
+
+/// 옮긴 요소 / Translated element
+enum Mapped {
+    /// OWPML 요소: 이름, 속성, 시작 태그 바로 뒤에 쓸 자식
+    /// OWPML element: name, attributes and children written right after the start tag
+    Element {
+        name: &'static str,
+        attributes: Vec<(&'static str, String)>,
+        children: String,
+    },
+    /// 태그 없이 자식만 옮김 / Only the children are carried over
+    Transparent,
+    /// `<DOCSUMMARY>`의 항목 / Field of `<DOCSUMMARY>`
+    Summary(SummaryField),
+    /// 하위 요소까지 건너뜀 / Skipped with its children
+    Skip,
+}
+
+/// 열린 요소 / Open element
+enum Open {
+    Tag(&'static str),
+    Transparent,
+    Summary(SummaryField),
+}
+
+#[derive(Clone, Copy)]
+enum SummaryField {
+    Title,
+    Subject,
+    Author,
+    Date,
+    Keywords,
+    Comments,
+}
+
+/// 언어별 속성 (HWPML → OWPML) / Per-language attributes (HWPML → OWPML)
+const LANGUAGES: [(&str, &str); 7] = [
+    ("Hangul", "hangul"),
+    ("Latin", "latin"),
+    ("Hanja", "hanja"),
+    ("Japanese", "japanese"),
+    ("Other", "other"),
+    ("Symbol", "symbol"),
+    ("User", "user"),
+];
+
+/// 숫자 강조점 종류의 OWPML 이름 / OWPML names of numeric emphasis mark kinds
+const SYM_MARKS: [&str; 13] = [
+    "NONE",
+    "DOT_ABOVE",
+    "RING_ABOVE",
+    "CARON",
+    "TILDE",
+    "SIDE",
+    "COLON",
+    "GRAVE_ACCENT",
+    "ACUTE_ACCENT",
+    "CIRCUMFLEX",
+    "MACRON",
+    "HOOK_ABOVE",
+    "DOT_BELOW",
+];
+
+/// HWPML XML을 OWPML 파트로 옮김 / Translate HWPML XML into OWPML parts
+pub(crate) fn translate(content: &str, max_depth: usize) -> Result<OwpmlParts, HwpError> {
+    let mut reader = Reader::from_str(content);
+    let mut parts = OwpmlParts {
+        version: DEFAULT_VERSION,
+        header: String::from("<hh:head>"),
+        ..Default::default()
+    };
+    let mut xml_depth = DepthGuard::new(max_depth);
+    let mut open: Vec<Open> = Vec::new();
+    // 건너뛰는 요소 안의 깊이 / Depth inside a skipped element
+    let mut skip_depth = 0usize;
+    let mut in_body = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                xml_depth.enter()?;
+                if skip_depth > 0 {
+                    skip_depth += 1;
+                    continue;
+                }
+                let name = e.local_name();
+                match name.as_ref() {
+                    b"HWPML" => parts.version = version_attribute(e),
+                    b"BODY" => in_body = true,
+                    b"SECTION" => parts.sections.push(String::new()),
+                    _ => {}
+                }
+                let output = output(&mut parts, in_body);
+                match map_element(e) {
+                    Mapped::Element {
+                        name,
+                        attributes,
+                        children,
+                    } => {
+                        write_start(output, name, &attributes);
+                        output.push_str(&children);
+                        open.push(Open::Tag(name));
+                    }
+                    Mapped::Transparent => open.push(Open::Transparent),
+                    Mapped::Summary(field) => open.push(Open::Summary(field)),
+                    Mapped::Skip => skip_depth = 1,
+                }
+            }
+            Ok(Event::Empty(ref e)) => {
+                if skip_depth > 0 {
+                    continue;
+                }
+                if let Mapped::Element {
+                    name,
+                    attributes,
+                    children,
+                } = map_element(e)
+                {
+                    let output = output(&mut parts, in_body);
+                    if children.is_empty() {
+                        write_empty(output, name, &attributes);
+                    } else {
+                        write_start(output, name, &attributes);
+                        output.push_str(&children);
+                        output.push_str(&format!("</{name}>"));
+                    }
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                if skip_depth > 0 {
+                    continue;
+                }
+                match open.last() {
+                    // 이스케이프된 원문을 그대로 옮김 / The escaped source text is carried over as is
+                    Some(Open::Tag("hp:t")) => {
+                        output(&mut parts, in_body).push_str(&String::from_utf8_lossy(e.as_ref()))
+                    }
+                    Some(Open::Summary(field)) => {
+                        let text = e.unescape().unwrap_or_default().trim().to_string();
+                        if !text.is_empty() {
+                            *summary_field(&mut parts.summary, *field) = Some(text);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                xml_depth.leave();
+                if skip_depth > 0 {
+                    skip_depth -= 1;
+                    continue;
+                }
+                if let Some(Open::Tag(name)) = open.pop() {
+                    output(&mut parts, in_body).push_str(&format!("</{name}>"));
+                }
+                if e.local_name().as_ref() == b"BODY" {
+                    in_body = false;
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::xml_parse(
+                    "HWPML",
+                    e,
+                    content,
+                    reader.error_position(),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    parts.header.push_str("</hh:head>");
+    Ok(parts)
+}
+
+/// 지금 쓰는 파트 (본문이면 마지막 구역, 아니면 header) / Part being written (last section in the body, header otherwise)
+fn output(parts: &mut OwpmlParts, in_body: bool) -> &mut String {
+    match parts.sections.last_mut() {
+        Some(section) if in_body => section,
+        _ => &mut parts.header,
+    }
+}
+
+/// HWPML 요소 하나를 OWPML로 옮김 / Translate one HWPML element into OWPML
+fn map_element(e: &BytesStart) -> Mapped {
+    let element = |name, attributes| Mapped::Element {
+        name,
+        attributes,
+        children: String::new(),
+    };
+    let name = e.local_name();
+    match name.as_ref() {
+        b"HWPML" | b"HEAD" | b"BODY" | b"MAPPINGTABLE" | b"DOCSUMMARY" | b"SHAPEOBJECT" => {
+            Mapped::Transparent
+        }
+        b"TITLE" => Mapped::Summary(SummaryField::Title),
+        b"SUBJECT" => Mapped::Summary(SummaryField::Subject),
+        b"AUTHOR" => Mapped::Summary(SummaryField::Author),
+        b"DATE" => Mapped::Summary(SummaryField::Date),
+        b"KEYWORDS" => Mapped::Summary(SummaryField::Keywords),
+        b"COMMENTS" => Mapped::Summary(SummaryField::Comments),
+
+        // 글자 모양 / Character shapes
+        b"CHARSHAPELIST" => element("hh:charProperties", vec![]),
+        b"CHARSHAPE" => {
+            let mut attributes = renamed(e, &[("Id", "id"), ("Height", "height")]);
+            if let Some(color) = attribute(e, "TextColor").and_then(|v| hex_color(&v)) {
+                attributes.push(("textColor", color));
+            }
+            let shade = attribute(e, "ShadeColor").and_then(|v| hex_color(&v));
+            attributes.push(("shadeColor", shade.unwrap_or_else(|| "none".to_string())));
+            if let Some(mark) = attribute(e, "SymMark") {
+                let mark = match mark.parse::<usize>() {
+                    Ok(kind) => SYM_MARKS.get(kind).unwrap_or(&"NONE").to_string(),
+                    Err(_) => owpml_enum(&mark),
+                };
+                attributes.push(("symMark", mark));
+            }
+            element("hh:charPr", attributes)
+        }
+        b"FONTID" => element("hh:fontRef", renamed(e, &LANGUAGES)),
+        b"RATIO" => element("hh:ratio", renamed(e, &LANGUAGES)),
+        b"CHARSPACING" => element("hh:spacing", renamed(e, &LANGUAGES)),
+        b"RELSIZE" => element("hh:relSz", renamed(e, &LANGUAGES)),
+        b"CHAROFFSET" => element("hh:offset", renamed(e, &LANGUAGES)),
+        b"BOLD" => element("hh:bold", vec![]),
+        b"ITALIC" => element("hh:italic", vec![]),
+        b"SUPERSCRIPT" => element("hh:supscript", vec![]),
+        b"SUBSCRIPT" => element("hh:subscript", vec![]),
+        b"UNDERLINE" => element("hh:underline", enum_attribute(e, "Type", "type")),
+        b"STRIKEOUT" => element("hh:strikeout", enum_attribute(e, "Type", "shape")),
+
+        // 문단 모양 / Paragraph shapes
+        b"PARASHAPELIST" => element("hh:paraProperties", vec![]),
+        b"PARASHAPE" => Mapped::Element {
+            name: "hh:paraPr",
+            attributes: renamed(e, &[("Id", "id")]),
+            children: attribute(e, "Align")
+                .map(|align| format!(r#"<hh:align horizontal="{}"/>"#, owpml_enum(&align)))
+                .unwrap_or_default(),
+        },
+        b"PARAMARGIN" => Mapped::Element {
+            name: "hh:margin",
+            attributes: vec![],
+            // OWPML 표기 그대로 "intent" / Spelled "intent" in OWPML
+            children: [
+                ("Indent", "intent"),
+                ("Left", "left"),
+                ("Right", "right"),
+                ("Prev", "prev"),
+                ("Next", "next"),
+            ]
+            .iter()
+            .filter_map(|(from, to)| {
+                let value = attribute(e, from)?;
+                Some(format!(r#"<hc:{to} value="{}"/>"#, escape(&value)))
+            })
+            .collect(),
+        },
+
+        // 본문 / Body
+        b"SECTION" => element("hs:sec", vec![]),
+        b"P" => element(
+            "hp:p",
+            renamed(e, &[("ParaShape", "paraPrIDRef"), ("Style", "styleIDRef")]),
+        ),
+        b"TEXT" => element("hp:run", renamed(e, &[("CharShape", "charPrIDRef")])),
+        b"CHAR" => element("hp:t", vec![]),
+        b"TAB" => element("hp:tab", vec![]),
+        b"NBSPACE" => element("hp:nbSpace", vec![]),
+        b"FWSPACE" => element("hp:fwSpace", vec![]),
+        // HWPML 표기 그대로 "HYPEN" / Spelled "HYPEN" in HWPML
+        b"HYPEN" | b"HYPHEN" => element("hp:hyphen", vec![]),
+        b"LINEBREAK" => element("hp:lineBreak", vec![]),
+
+        // 표 / Tables
+        b"TABLE" => element(
+            "hp:tbl",
+            renamed(
+                e,
+                &[
+                    ("RowCount", "rowCnt"),
+                    ("ColCount", "colCnt"),
+                    ("CellSpacing", "cellSpacing"),
+                    ("BorderFill", "borderFillIDRef"),
+                ],
+            ),
+        ),
+        b"INSIDEMARGIN" => element("hp:inMargin", margin_attributes(e)),
+        b"ROW" => element("hp:tr", vec![]),
+        b"CELL" => Mapped::Element {
+            name: "hp:tc",
+            attributes: renamed(e, &[("BorderFill", "borderFillIDRef")]),
+            children: [
+                (
+                    "hp:cellAddr",
+                    [("ColAddr", "colAddr"), ("RowAddr", "rowAddr")],
+                ),
+                (
+                    "hp:cellSpan",
+                    [("ColSpan", "colSpan"), ("RowSpan", "rowSpan")],
+                ),
+                ("hp:cellSz", [("Width", "width"), ("Height", "height")]),
+            ]
+            .iter()
+            .map(|(name, attributes)| {
+                let mut child = String::new();
+                write_empty(&mut child, name, &renamed(e, attributes));
+                child
+            })
+            .collect(),
+        },
+        b"CELLMARGIN" => element("hp:cellMargin", margin_attributes(e)),
+        b"PARALIST" => {
+            let mut attributes = enum_attribute(e, "VertAlign", "vertAlign");
+            attributes.extend(enum_attribute(e, "LineWrap", "lineWrap"));
+            element("hp:subList", attributes)
+        }
+        b"CAPTION" => element("hp:caption", enum_attribute(e, "Side", "side")),
+
+        _ => Mapped::Skip,
+    }
+}
+
+/// 이름을 바꾼 속성 (없는 속성은 뺌) / Renamed attributes (missing ones are left out)
+fn renamed(e: &BytesStart, names: &[(&str, &'static str)]) -> Vec<(&'static str, String)> {
+    names
+        .iter()
+        .filter_map(|(from, to)| Some((*to, attribute(e, from)?)))
+        .collect()
+}
+
+/// 여백 속성 (Left, Right, Top, Bottom) / Margin attributes (Left, Right, Top, Bottom)
+fn margin_attributes(e: &BytesStart) -> Vec<(&'static str, String)> {
+    renamed(
+        e,
+        &[
+            ("Left", "left"),
+            ("Right", "right"),
+            ("Top", "top"),
+            ("Bottom", "bottom"),
+        ],
+    )
+}
+
+/// 열거형 속성 하나 (`Bottom` → `BOTTOM`) / One enumerated attribute (`Bottom` → `BOTTOM`)
+fn enum_attribute(e: &BytesStart, from: &str, to: &'static str) -> Vec<(&'static str, String)> {
+    attribute(e, from)
+        .map(|value| vec![(to, owpml_enum(&value))])
+        .unwrap_or_default()
+}
+
+/// HWPML 열거 값을 OWPML 표기로 (`DistributeSpace` → `DISTRIBUTE_SPACE`)
+/// HWPML enumeration value in OWPML spelling (`DistributeSpace` → `DISTRIBUTE_SPACE`)
+fn owpml_enum(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 4);
+    for (index, c) in value.chars().enumerate() {
+        if index > 0 && c.is_ascii_uppercase() {
+            result.push('_');
+        }
+        result.push(c.to_ascii_uppercase());
+    }
+    result
+}
+
+/// 10진수 COLORREF (0x00BBGGRR)를 "#RRGGBB"로, 색 없음(상위 바이트 사용)은 None
+/// Decimal COLORREF (0x00BBGGRR) as "#RRGGBB"; None for "no color" (high byte set)
+fn hex_color(value: &str) -> Option<String> {
+    let color: u32 = value.parse().ok()?;
+    if color >> 24 != 0 {
+        return None;
+    }
+    let (r, g, b) = (color & 0xFF, (color >> 8) & 0xFF, (color >> 16) & 0xFF);
+    Some(format!("#{r:02X}{g:02X}{b:02X}"))
+}
+
+/// `<HWPML Version="2.8">`을 2.8.0.0으로 / `<HWPML Version="2.8">` as 2.8.0.0
+fn version_attribute(e: &BytesStart) -> DWORD {
+    let Some(version) = attribute(e, "Version") else {
+        return DEFAULT_VERSION;
+    };
+    let mut numbers = version.split('.').map(|part| part.parse::<u8>().ok());
+    match (numbers.next().flatten(), numbers.next().flatten()) {
+        (Some(major), minor) => (major as DWORD) << 24 | (minor.unwrap_or(0) as DWORD) << 16,
+        _ => DEFAULT_VERSION,
+    }
+}
+
+fn summary_field(info: &mut SummaryInformation, field: SummaryField) -> &mut Option<String> {
+    match field {
+        SummaryField::Title => &mut info.title,
+        SummaryField::Subject => &mut info.subject,
+        SummaryField::Author => &mut info.author,
+        SummaryField::Date => &mut info.date_string,
+        SummaryField::Keywords => &mut info.keywords,
+        SummaryField::Comments => &mut info.comments,
+    }
+}
+
+fn write_start(output: &mut String, name: &str, attributes: &[(&str, String)]) {
+    write_tag(output, name, attributes);
+    output.push('>');
+}
+
+fn write_empty(output: &mut String, name: &str, attributes: &[(&str, String)]) {
+    write_tag(output, name, attributes);
+    output.push_str("/>");
+}
+
+fn write_tag(output: &mut String, name: &str, attributes: &[(&str, String)]) {
+    output.push('<');
+    output.push_str(name);
+    for (key, value) in attributes {
+        output.push_str(&format!(r#" {key}="{}""#, escape(value)));
+    }
+}
+
+fn attribute(e: &BytesStart, key: &str) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key.as_bytes())
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate_maps_hwpml_onto_owpml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<HWPML Version="2.8" SubVersion="8.0.0.0">
+<HEAD SecCnt="1"><DOCSUMMARY><TITLE>보고서</TITLE><AUTHOR>홍길동</AUTHOR></DOCSUMMARY>
+<MAPPINGTABLE><CHARSHAPELIST Count="1"><CHARSHAPE Id="0" Height="1000" TextColor="255" ShadeColor="4294967295" SymMark="1"><BOLD/></CHARSHAPE></CHARSHAPELIST>
+<PARASHAPELIST Count="1"><PARASHAPE Id="0" Align="DistributeSpace"><PARAMARGIN Indent="-200" Left="100"/></PARASHAPE></PARASHAPELIST></MAPPINGTABLE></HEAD>
+<BODY><SECTION Id="0"><P ParaShape="0" Style="0"><TEXT CharShape="0"><SECDEF/><CHAR>A &amp; B<TAB/>C</CHAR></TEXT></P></SECTION></BODY>
+<TAIL><BINDATASTORAGE><BINDATA Id="1">AAAA</BINDATA></BINDATASTORAGE></TAIL></HWPML>"#;
+
+        let parts = translate(xml, 64).unwrap();
+        assert_eq!(parts.version, 0x0208_0000);
+        assert_eq!(parts.summary.title.as_deref(), Some("보고서"));
+        assert_eq!(parts.summary.author.as_deref(), Some("홍길동"));
+        assert_eq!(
+            parts.header,
+            concat!(
+                r##"<hh:head><hh:charProperties><hh:charPr id="0" height="1000" textColor="#FF0000" shadeColor="none" symMark="DOT_ABOVE"><hh:bold/></hh:charPr></hh:charProperties>"##,
+                r#"<hh:paraProperties><hh:paraPr id="0"><hh:align horizontal="DISTRIBUTE_SPACE"/><hh:margin><hc:intent value="-200"/><hc:left value="100"/></hh:margin></hh:paraPr></hh:paraProperties></hh:head>"#
+            )
+        );
+        assert_eq!(
+            parts.sections,
+            [
+                r#"<hs:sec><hp:p paraPrIDRef="0" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>A &amp; B<hp:tab/>C</hp:t></hp:run></hp:p></hs:sec>"#
+            ]
+        );
+    }
+}
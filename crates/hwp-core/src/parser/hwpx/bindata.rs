@@ -40,6 +40,8 @@ pub fn parse_bindata(container: &mut HwpxContainer) -> Result<BinData, HwpError>
                     name,
                 });
             }
+            // 한도 초과는 건너뛰지 않음 / Limit errors are not skipped
+            Err(e) if matches!(e.kind(), HwpError::ResourceLimitExceeded { .. }) => return Err(e),
             Err(e) => {
                 // Log warning but continue parsing
                 #[cfg(debug_assertions)]
@@ -58,7 +60,6 @@ pub fn get_extension(path: &str) -> Option<&str> {
 }
 
 /// Get the MIME type from file extension
-#[allow(dead_code)]
 pub fn get_mime_type(extension: &str) -> &'static str {
     match extension.to_lowercase().as_str() {
         "jpg" | "jpeg" => "image/jpeg",
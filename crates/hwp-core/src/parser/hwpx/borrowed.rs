@@ -0,0 +1,421 @@
+/// Borrowed HWPX text model
+///
+/// 색인처럼 본문 텍스트만 대량으로 읽는 용도를 위한 가벼운 문서 모델입니다. 압축 해제한 구역 XML은
+/// `HwpxBuffers`에 두고, 문단 텍스트는 이스케이프가 없는 한 그 버퍼를 그대로 빌립니다(`Cow::Borrowed`).
+/// 버퍼는 다음 파일을 읽을 때 재사용되므로 문단마다 생기던 `String` 할당과 파일마다 생기던 버퍼
+/// 할당이 함께 줄어듭니다. 표, 그림, 스타일 같은 구조가 필요하면 `HwpParser::parse`를 사용하세요.
+///
+/// Lightweight document model for text-heavy workloads such as indexing. Decompressed section
+/// XML stays in `HwpxBuffers` and paragraph text borrows from it (`Cow::Borrowed`) unless it
+/// contains escapes. The buffers are reused for the next file, which removes the per-paragraph
+/// `String` allocations and the per-file buffer allocations. Use `HwpParser::parse` when tables,
+/// pictures or styles are needed.
+///
+/// ```ignore
+/// use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+///
+/// let mut buffers = HwpxBuffers::new();
+/// for data in files {
+///     buffers.load(&data)?;
+///     let document = buffers.parse()?;
+///     for paragraph in document.paragraphs() {
+///         index.add(&paragraph.text());
+///     }
+/// }
+/// ```
+use std::borrow::Cow;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::NsReader;
+
+use super::container::{decode_xml, HwpxContainer};
+use super::namespace::{ElementName, Namespace};
+use super::section::inline_control_text;
+use crate::error::HwpError;
+use crate::parser::limits::{DepthGuard, ResourceLimits};
+use crate::types::{UINT32, WORD};
+
+/// Decompressed section XML buffers
+/// 압축 해제한 구역 XML 버퍼
+#[derive(Debug, Default)]
+pub struct HwpxBuffers {
+    sections: Vec<Vec<u8>>,
+    /// 현재 문서의 구역 수 (남는 버퍼는 재사용을 위해 보관) / Section count of the current document (spare buffers are kept for reuse)
+    len: usize,
+    limits: ResourceLimits,
+}
+
+impl HwpxBuffers {
+    /// Create empty buffers with the default resource limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create empty buffers with the given resource limits
+    pub fn with_limits(limits: ResourceLimits) -> Self {
+        Self {
+            limits,
+            ..Self::default()
+        }
+    }
+
+    /// Load the sections of an HWPX file, reusing the existing allocations
+    /// 기존 할당을 재사용하여 HWPX 파일의 구역 읽기
+    ///
+    /// # Arguments
+    /// * `data` - Byte array containing the HWPX file data (ZIP format)
+    pub fn load(&mut self, data: &[u8]) -> Result<(), HwpError> {
+        self.len = 0;
+        let mut container = HwpxContainer::open_with_limits(data, self.limits)?;
+        let section_files = container.section_order();
+        if section_files.is_empty() {
+            return Err(HwpError::InvalidHwpxStructure {
+                reason: "No section files found in Contents/".to_string(),
+            });
+        }
+
+        for path in &section_files {
+            if self.len == self.sections.len() {
+                self.sections.push(Vec::new());
+            }
+            let buffer = &mut self.sections[self.len];
+            container.read_file_into(path, buffer)?;
+            // 빌려 쓸 수 있게 UTF-8 이외의 인코딩은 버퍼 안에서 바꿈 / Transcode other encodings in place so the text can be borrowed
+            match decode_xml(buffer)? {
+                Cow::Owned(text) => *buffer = text.into_bytes(),
+                Cow::Borrowed(text) => {
+                    let bom = buffer.len() - text.len();
+                    buffer.drain(..bom);
+                }
+            }
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Parse the loaded sections into a document borrowing from these buffers
+    /// 읽어 둔 구역을 이 버퍼를 빌리는 문서로 파싱
+    pub fn parse(&self) -> Result<BorrowedDocument<'_>, HwpError> {
+        let sections = self.sections[..self.len]
+            .iter()
+            .enumerate()
+            .map(|(index, buffer)| {
+                let content = std::str::from_utf8(buffer).map_err(|e| HwpError::EncodingError {
+                    reason: e.to_string(),
+                })?;
+                parse_section(content, index as WORD, self.limits.max_xml_depth)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(BorrowedDocument { sections })
+    }
+}
+
+/// Document whose text borrows from `HwpxBuffers`
+/// `HwpxBuffers`를 빌리는 문서
+#[derive(Debug, Clone, Default)]
+pub struct BorrowedDocument<'a> {
+    pub sections: Vec<BorrowedSection<'a>>,
+}
+
+impl<'a> BorrowedDocument<'a> {
+    /// All paragraphs in document order, including those in tables and text boxes
+    /// 표와 글상자 안을 포함한 모든 문단 (문서 순서)
+    pub fn paragraphs(&self) -> impl Iterator<Item = &BorrowedParagraph<'a>> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.paragraphs.iter())
+    }
+
+    /// Text of all non-empty paragraphs joined by newlines
+    /// 비어 있지 않은 문단의 텍스트를 줄바꿈으로 연결
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for paragraph in self.paragraphs().filter(|p| !p.is_empty()) {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            for piece in &paragraph.texts {
+                text.push_str(&piece.text);
+            }
+        }
+        text
+    }
+}
+
+/// Section of a borrowed document
+/// 빌린 문서의 구역
+#[derive(Debug, Clone, Default)]
+pub struct BorrowedSection<'a> {
+    pub index: WORD,
+    pub paragraphs: Vec<BorrowedParagraph<'a>>,
+}
+
+/// Paragraph (`<hp:p>`) of a borrowed document
+/// 빌린 문서의 문단
+#[derive(Debug, Clone, Default)]
+pub struct BorrowedParagraph<'a> {
+    /// 문단 모양 ID (`paraPrIDRef`) / Para shape ID (`paraPrIDRef`)
+    pub para_shape_id: UINT32,
+    /// 스타일 ID (`styleIDRef`) / Style ID (`styleIDRef`)
+    pub style_id: UINT32,
+    /// 문단 중첩 깊이 (본문은 0, 표 셀과 글상자 안은 1 이상) / Paragraph nesting depth (0 in the body, 1+ inside cells and text boxes)
+    pub depth: usize,
+    /// 표 안의 문단인지 여부 / Whether the paragraph is inside a table
+    pub in_table: bool,
+    /// 텍스트 조각 (`<hp:t>`와 인라인 제어 요소) / Text pieces (`<hp:t>` and inline control elements)
+    pub texts: Vec<BorrowedText<'a>>,
+}
+
+impl<'a> BorrowedParagraph<'a> {
+    /// Paragraph text; borrowed when it is a single piece
+    /// 문단 텍스트 (조각이 하나면 빌린 값)
+    pub fn text(&self) -> Cow<'a, str> {
+        match self.texts.as_slice() {
+            [] => Cow::Borrowed(""),
+            [piece] => piece.text.clone(),
+            pieces => Cow::Owned(pieces.iter().map(|piece| piece.text.as_ref()).collect()),
+        }
+    }
+
+    /// Whether the paragraph has no text
+    pub fn is_empty(&self) -> bool {
+        self.texts.iter().all(|piece| piece.text.is_empty())
+    }
+}
+
+/// Text piece with its char shape
+/// 글자 모양이 붙은 텍스트 조각
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorrowedText<'a> {
+    /// 글자 모양 ID (`charPrIDRef`) / Char shape ID (`charPrIDRef`)
+    pub char_shape_id: UINT32,
+    pub text: Cow<'a, str>,
+}
+
+/// Read a numeric ID attribute (0 when missing or invalid)
+/// 숫자 ID 속성 읽기 (없거나 잘못되면 0)
+fn id_attribute(e: &BytesStart, key: &[u8]) -> UINT32 {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .and_then(|attr| std::str::from_utf8(&attr.value).ok()?.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Event produced while walking section XML
+/// 구역 XML을 훑으며 생기는 이벤트
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SectionEvent<'a> {
+    /// 문단 시작 (`<hp:p>`) / Paragraph start (`<hp:p>`)
+    ParagraphStart {
+        para_shape_id: UINT32,
+        style_id: UINT32,
+        in_table: bool,
+    },
+    /// 텍스트 조각 (`<hp:t>`와 인라인 제어 요소) / Text piece (`<hp:t>` and inline control elements)
+    Text {
+        char_shape_id: UINT32,
+        text: Cow<'a, str>,
+    },
+    /// 문단 끝 / Paragraph end
+    ParagraphEnd,
+}
+
+/// Walk one section XML, reporting paragraphs and text without building a model
+/// 모델을 만들지 않고 구역 XML을 훑으며 문단과 텍스트를 알림
+///
+/// 텍스트 규칙은 `section::parse_sections`와 같습니다 (앞뒤 공백 제거, 탭 채움, 메모 제외).
+/// Text rules match `section::parse_sections` (trimmed text, tab leaders, memos excluded).
+pub(crate) fn walk_section<'a>(
+    content: &'a str,
+    max_depth: usize,
+    mut on_event: impl FnMut(SectionEvent<'a>),
+) -> Result<(), HwpError> {
+    let mut reader = NsReader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut open_paragraphs = 0usize;
+    let mut char_shape_id = 0;
+    let mut in_text = false;
+    let mut table_depth = 0usize;
+    // fieldBegin 안(메모 내용, 필드 매개변수)은 본문이 아님 / Inside fieldBegin (memo contents, field parameters) is not body text
+    let mut field_depth = 0usize;
+    let mut xml_depth = DepthGuard::new(max_depth);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                xml_depth.enter()?;
+                let name = ElementName::resolve(&reader, e.name()).local_in(Namespace::Paragraph);
+                if field_depth > 0 {
+                    if name == Some("fieldBegin") {
+                        field_depth += 1;
+                    }
+                    continue;
+                }
+                match name {
+                    Some("p") => {
+                        open_paragraphs += 1;
+                        on_event(SectionEvent::ParagraphStart {
+                            para_shape_id: id_attribute(&e, b"paraPrIDRef"),
+                            style_id: id_attribute(&e, b"styleIDRef"),
+                            in_table: table_depth > 0,
+                        });
+                    }
+                    Some("run") => char_shape_id = id_attribute(&e, b"charPrIDRef"),
+                    Some("t") => in_text = true,
+                    Some("tbl") => table_depth += 1,
+                    Some("fieldBegin") => field_depth = 1,
+                    _ => {}
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                if field_depth == 0 && open_paragraphs > 0 {
+                    if let Some(text) =
+                        inline_control_text(ElementName::resolve(&reader, e.name()), &e)
+                    {
+                        on_event(SectionEvent::Text {
+                            char_shape_id,
+                            text,
+                        });
+                    }
+                }
+            }
+            Ok(Event::Text(e)) => {
+                if !in_text || field_depth > 0 || open_paragraphs == 0 {
+                    continue;
+                }
+                // 잘못된 이스케이프는 기본 파서처럼 건너뜀 / Bad escapes are skipped like the default parser does
+                if let Ok(text) = e.unescape() {
+                    if !text.is_empty() {
+                        on_event(SectionEvent::Text {
+                            char_shape_id,
+                            text,
+                        });
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                xml_depth.leave();
+                let name = ElementName::resolve(&reader, e.name()).local_in(Namespace::Paragraph);
+                if field_depth > 0 {
+                    if name == Some("fieldBegin") {
+                        field_depth -= 1;
+                    }
+                    continue;
+                }
+                match name {
+                    Some("p") if open_paragraphs > 0 => {
+                        open_paragraphs -= 1;
+                        on_event(SectionEvent::ParagraphEnd);
+                    }
+                    Some("t") => in_text = false,
+                    Some("tbl") => table_depth = table_depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::xml_parse(
+                    "section XML",
+                    e,
+                    content,
+                    reader.error_position(),
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Parse one section XML into borrowed paragraphs
+/// 구역 XML 하나를 빌린 문단으로 파싱
+fn parse_section(
+    content: &str,
+    index: WORD,
+    max_depth: usize,
+) -> Result<BorrowedSection<'_>, HwpError> {
+    let mut paragraphs: Vec<BorrowedParagraph> = Vec::new();
+    // 열린 문단의 인덱스 (안쪽이 마지막) / Indices of open paragraphs, innermost last
+    let mut open: Vec<usize> = Vec::new();
+
+    walk_section(content, max_depth, |event| match event {
+        SectionEvent::ParagraphStart {
+            para_shape_id,
+            style_id,
+            in_table,
+        } => {
+            paragraphs.push(BorrowedParagraph {
+                para_shape_id,
+                style_id,
+                depth: open.len(),
+                in_table,
+                texts: Vec::new(),
+            });
+            open.push(paragraphs.len() - 1);
+        }
+        SectionEvent::Text {
+            char_shape_id,
+            text,
+        } => {
+            if let Some(&current) = open.last() {
+                paragraphs[current].texts.push(BorrowedText {
+                    char_shape_id,
+                    text,
+                });
+            }
+        }
+        SectionEvent::ParagraphEnd => {
+            open.pop();
+        }
+    })?;
+
+    Ok(BorrowedSection { index, paragraphs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: &str = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+        <hp:p paraPrIDRef="3" styleIDRef="1"><hp:run charPrIDRef="7"><hp:t>본문 텍스트</hp:t></hp:run></hp:p>
+        <hp:p paraPrIDRef="0"><hp:run charPrIDRef="2"><hp:t>A &amp; B<hp:tab/>C</hp:t></hp:run>
+            <hp:run charPrIDRef="0"><hp:ctrl><hp:fieldBegin type="MEMO"><hp:subList><hp:p><hp:run><hp:t>메모</hp:t></hp:run></hp:p></hp:subList></hp:fieldBegin></hp:ctrl></hp:run>
+            <hp:run charPrIDRef="0"><hp:tbl><hp:tr><hp:tc><hp:subList><hp:p><hp:run charPrIDRef="4"><hp:t>셀</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr></hp:tbl></hp:run>
+        </hp:p>
+    </hs:sec>"#;
+
+    #[test]
+    fn test_parse_section_borrows_text() {
+        let section = parse_section(SECTION, 0, 256).unwrap();
+        assert_eq!(section.paragraphs.len(), 3);
+
+        let first = &section.paragraphs[0];
+        assert_eq!((first.para_shape_id, first.style_id), (3, 1));
+        assert_eq!(first.texts[0].char_shape_id, 7);
+        assert!(matches!(first.text(), Cow::Borrowed("본문 텍스트")));
+
+        let second = &section.paragraphs[1];
+        assert_eq!(second.text(), "A & B\tC");
+        assert!(matches!(second.texts[0].text, Cow::Owned(_)));
+        assert!(matches!(second.texts[2].text, Cow::Borrowed("C")));
+
+        // 메모 내용은 제외, 셀 문단은 중첩 깊이 1 / Memo contents are excluded, cell paragraphs are nested once
+        let cell = &section.paragraphs[2];
+        assert_eq!(cell.text(), "셀");
+        assert_eq!(
+            (cell.depth, cell.in_table, cell.texts[0].char_shape_id),
+            (1, true, 4)
+        );
+    }
+
+    #[test]
+    fn test_parse_section_depth_limit() {
+        let deep = format!("{}{}", "<a>".repeat(10), "</a>".repeat(10));
+        assert!(matches!(
+            parse_section(&deep, 0, 4),
+            Err(HwpError::ResourceLimitExceeded { .. })
+        ));
+    }
+}
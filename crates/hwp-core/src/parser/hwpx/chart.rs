@@ -0,0 +1,252 @@
+/// HWPX chart XML parser
+///
+/// Charts are stored as separate DrawingML parts (`Chart/chart1.xml`, ...) referenced by
+/// `<hp:chart chartIDRef="...">`. Only the text a reader would search for is extracted: the
+/// chart title, axis titles, series names and category labels. They are mapped onto the same
+/// `VtChart` structure HWP 5.0 uses so both formats are read through `ChartData`.
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::document::bodytext::{Axis, ChartData, ChartTitle, ChartType, Legend, Series, VtChart};
+use crate::error::HwpError;
+use crate::parser::limits::DepthGuard;
+
+/// Chart type for a DrawingML plot element (`<c:barChart>`, ...)
+/// DrawingML 플롯 요소에 해당하는 차트 유형
+fn chart_type(local_name: &[u8]) -> Option<ChartType> {
+    let chart_type = match local_name {
+        b"barChart" => ChartType::Bar2D,
+        b"bar3DChart" => ChartType::Bar3D,
+        b"lineChart" => ChartType::Line2D,
+        b"line3DChart" => ChartType::Line3D,
+        b"areaChart" => ChartType::Area2D,
+        b"area3DChart" => ChartType::Area3D,
+        b"pieChart" | b"ofPieChart" => ChartType::Pie2D,
+        b"pie3DChart" => ChartType::Pie3D,
+        b"doughnutChart" => ChartType::Doughnut2D,
+        b"scatterChart" => ChartType::XY2D,
+        b"radarChart" => ChartType::Radar2D,
+        b"bubbleChart" => ChartType::Bubble2D,
+        b"stockChart" => ChartType::HiLo2D,
+        b"surfaceChart" | b"surface3DChart" => ChartType::Surface3D,
+        _ => return None,
+    };
+    Some(chart_type)
+}
+
+/// Whether the element is an axis (`<c:catAx>`, `<c:valAx>`, ...)
+fn is_axis(local_name: &[u8]) -> bool {
+    matches!(local_name, b"catAx" | b"valAx" | b"dateAx" | b"serAx")
+}
+
+/// Parse a chart XML part into chart data
+/// 차트 XML 파트를 차트 데이터로 파싱
+///
+/// 축은 `plot.axes`, 계열 이름은 `Series::legend_text`와 `data_grid.column_labels`, 항목 이름은
+/// 첫 계열의 `<c:cat>`에서 읽어 `data_grid.row_labels`에 넣습니다.
+/// Axes go to `plot.axes`, series names to `Series::legend_text` and `data_grid.column_labels`,
+/// and category labels are read from the first series' `<c:cat>` into `data_grid.row_labels`.
+pub fn parse_chart_xml(content: &str, max_depth: usize) -> Result<ChartData, HwpError> {
+    let mut reader = Reader::from_str(content);
+
+    let mut chart = VtChart {
+        chart_type: ChartType::Unknown,
+        ..Default::default()
+    };
+    // 열린 요소의 로컬 이름 / Local names of open elements
+    let mut open: Vec<Vec<u8>> = Vec::new();
+    let mut title: Option<String> = None;
+    let mut axis: Option<Axis> = None;
+    let mut series: Option<Series> = None;
+    let mut categories: Vec<String> = Vec::new();
+    let mut xml_depth = DepthGuard::new(max_depth);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                xml_depth.enter()?;
+                let name = e.local_name();
+                start_element(name.as_ref(), &e, &mut chart, &mut title, &mut axis);
+                match name.as_ref() {
+                    b"ser" => series = Some(Series::default()),
+                    b"cat" if series.is_some() => categories.clear(),
+                    _ => {}
+                }
+                open.push(name.as_ref().to_vec());
+            }
+            Ok(Event::Empty(e)) => {
+                let name = e.local_name();
+                start_element(name.as_ref(), &e, &mut chart, &mut title, &mut axis);
+            }
+            Ok(Event::Text(e)) => {
+                let Some(current) = open.last() else {
+                    continue;
+                };
+                if current != b"t" && current != b"v" {
+                    continue;
+                }
+                let Ok(text) = e.unescape() else {
+                    continue;
+                };
+                if let Some(title) = title.as_mut() {
+                    title.push_str(&text);
+                } else if let Some(series) = series.as_mut() {
+                    if open.iter().any(|name| name == b"tx") {
+                        series.legend_text.push_str(&text);
+                    } else if open.iter().any(|name| name == b"cat") {
+                        categories.push(text.trim().to_string());
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                xml_depth.leave();
+                open.pop();
+                match e.local_name().as_ref() {
+                    b"title" => {
+                        let text = title.take().unwrap_or_default().trim().to_string();
+                        match axis.as_mut() {
+                            Some(axis) => {
+                                axis.title.text = text;
+                                axis.title.visible = true;
+                            }
+                            None => {
+                                chart.title = Some(ChartTitle {
+                                    text,
+                                    ..Default::default()
+                                })
+                            }
+                        }
+                    }
+                    name if is_axis(name) => chart.plot.axes.extend(axis.take()),
+                    b"ser" => {
+                        if let Some(mut series) = series.take() {
+                            series.legend_text = series.legend_text.trim().to_string();
+                            chart
+                                .data_grid
+                                .column_labels
+                                .push(series.legend_text.clone());
+                            chart.plot.series.push(series);
+                        }
+                    }
+                    // 항목 이름은 모든 계열이 공유하므로 첫 계열 것만 사용 / Categories are shared by all series, so the first one is used
+                    b"cat" if chart.data_grid.row_labels.is_empty() => {
+                        chart.data_grid.row_labels = std::mem::take(&mut categories);
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::xml_parse(
+                    "chart XML",
+                    e,
+                    content,
+                    reader.error_position(),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let grid = &mut chart.data_grid;
+    grid.column_count = grid.column_labels.len() as u32;
+    grid.row_count = grid.row_labels.len() as u32;
+    grid.column_label_count = u32::from(grid.column_count > 0);
+    grid.row_label_count = u32::from(grid.row_count > 0);
+
+    Ok(ChartData {
+        vt_chart: Some(chart),
+        raw_data: Vec::new(),
+    })
+}
+
+/// Apply a start or empty element
+/// 시작 요소 또는 빈 요소 적용
+fn start_element(
+    name: &[u8],
+    e: &BytesStart,
+    chart: &mut VtChart,
+    title: &mut Option<String>,
+    axis: &mut Option<Axis>,
+) {
+    match name {
+        b"title" => *title = Some(String::new()),
+        b"legend" => {
+            chart.legend = Some(Legend::default());
+            chart.show_legend = true;
+        }
+        name if is_axis(name) => *axis = Some(Axis::default()),
+        // <c:delete val="1"/>은 숨긴 축 / <c:delete val="1"/> marks a hidden axis
+        b"delete" => {
+            if let Some(axis) = axis.as_mut() {
+                axis.scale.hide = e.attributes().flatten().any(|attr| {
+                    attr.key.as_ref() == b"val" && matches!(&*attr.value, b"1" | b"true")
+                });
+            }
+        }
+        name => {
+            if let Some(chart_type) = chart_type(name) {
+                if chart.chart_type == ChartType::Unknown {
+                    chart.chart_type = chart_type;
+                    chart.chart_3d = name.windows(2).any(|w| w == b"3D");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHART: &str = r#"<c:chartSpace xmlns:c="c" xmlns:a="a"><c:chart>
+        <c:title><c:tx><c:rich><a:p><a:r><a:t>연도별 매출</a:t></a:r><a:r><a:t> (억 원)</a:t></a:r></a:p></c:rich></c:tx></c:title>
+        <c:plotArea><c:barChart><c:barDir val="col"/>
+            <c:ser><c:tx><c:strRef><c:strCache><c:pt idx="0"><c:v>국내</c:v></c:pt></c:strCache></c:strRef></c:tx>
+                <c:cat><c:strRef><c:strCache><c:pt idx="0"><c:v>2023</c:v></c:pt><c:pt idx="1"><c:v>2024</c:v></c:pt></c:strCache></c:strRef></c:cat>
+                <c:val><c:numRef><c:numCache><c:pt idx="0"><c:v>12.5</c:v></c:pt></c:numCache></c:numRef></c:val></c:ser>
+            <c:ser><c:tx><c:v>해외</c:v></c:tx><c:cat><c:strLit><c:pt idx="0"><c:v>무시</c:v></c:pt></c:strLit></c:cat></c:ser>
+        </c:barChart>
+        <c:catAx><c:axId val="1"/><c:delete val="0"/></c:catAx>
+        <c:valAx><c:axId val="2"/><c:title><c:tx><c:rich><a:p><a:r><a:t>금액</a:t></a:r></a:p></c:rich></c:tx></c:title></c:valAx>
+        </c:plotArea><c:legend><c:legendPos val="r"/></c:legend></c:chart></c:chartSpace>"#;
+
+    #[test]
+    fn test_parse_chart_xml() {
+        let chart = parse_chart_xml(CHART, 64).unwrap();
+        let vt_chart = chart.vt_chart.as_ref().unwrap();
+        assert_eq!(chart.get_title(), Some("연도별 매출 (억 원)"));
+        assert_eq!(vt_chart.chart_type, ChartType::Bar2D);
+        assert!(vt_chart.show_legend);
+
+        let axes = &vt_chart.plot.axes;
+        assert_eq!(axes.len(), 2);
+        assert!(axes[0].title.text.is_empty());
+        assert_eq!(axes[1].title.text, "금액");
+
+        // 값(<c:val>)은 항목이 아님 / Values (<c:val>) are not categories
+        assert_eq!(vt_chart.data_grid.column_labels, ["국내", "해외"]);
+        assert_eq!(vt_chart.data_grid.row_labels, ["2023", "2024"]);
+        assert_eq!(chart.get_series()[1].legend_text, "해외");
+        assert_eq!(
+            chart.labels(),
+            [
+                "연도별 매출 (억 원)",
+                "금액",
+                "국내",
+                "해외",
+                "2023",
+                "2024"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_chart_xml_depth_limit() {
+        let deep = format!("{}{}", "<a>".repeat(10), "</a>".repeat(10));
+        assert!(matches!(
+            parse_chart_xml(&deep, 4),
+            Err(HwpError::ResourceLimitExceeded { .. })
+        ));
+    }
+}
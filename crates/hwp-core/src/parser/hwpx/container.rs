@@ -1,24 +1,160 @@
 /// HWPX ZIP container handling
 ///
 /// HWPX files are ZIP archives containing XML files and binary data.
-use std::io::{Cursor, Read};
+use std::borrow::Cow;
+use std::io::{Cursor, Read, Seek};
+use std::rc::Rc;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
 use zip::ZipArchive;
 
-use crate::error::HwpError;
+use crate::error::{ErrorContext, HwpError};
+use crate::parser::limits::{is_limit_error, LimitedReader, ResourceLimit, ResourceLimits};
+
+use super::integrity::{attribute, local_name, visit_elements, PackageManifest};
+
+/// OCF 컨테이너 파일 / OCF container file
+pub(super) const CONTAINER_XML: &str = "META-INF/container.xml";
+/// container.xml이 없거나 쓸 수 없을 때의 패키지 문서 경로 / Package document path when container.xml is missing or unusable
+pub const DEFAULT_PACKAGE_PATH: &str = "Contents/content.hpf";
+/// 한컴 패키지 문서의 미디어 형식 / Media type of the Hancom package document
+const PACKAGE_MEDIA_TYPE: &str = "application/hwpml-package+xml";
+
+/// 읽기와 이동이 되는 원본 / Source that can be read and seeked
+trait ReadSeek: Read + Seek {}
+
+impl<T: Read + Seek> ReadSeek for T {}
 
 /// HWPX container wrapper around ZIP archive
 pub struct HwpxContainer<'a> {
-    archive: ZipArchive<Cursor<&'a [u8]>>,
+    /// 메모리에 있는 원본 (리더에서 열었으면 None) / In-memory source (None when opened from a reader)
+    data: Option<&'a [u8]>,
+    archive: ZipArchive<Box<dyn ReadSeek + 'a>>,
+    limits: ResourceLimits,
+    /// 지금까지 압축 해제한 바이트 수 / Bytes decompressed so far
+    total_read: u64,
+    /// container.xml의 rootfile이 가리키는 패키지 문서 경로 / Package document path named by the container.xml rootfile
+    package_path: String,
+    /// 읽어 둔 패키지 문서 (처음 쓸 때 읽음) / Parsed package document (read on first use)
+    pub(super) package: Option<Rc<PackageManifest>>,
 }
 
 impl<'a> HwpxContainer<'a> {
-    /// Open HWPX container from byte array
+    /// Open HWPX container from byte array with the default resource limits
     pub fn open(data: &'a [u8]) -> Result<Self, HwpError> {
-        let cursor = Cursor::new(data);
-        let archive =
-            ZipArchive::new(cursor).map_err(|e| HwpError::ZipParseError(e.to_string()))?;
+        Self::open_with_limits(data, ResourceLimits::default())
+    }
+
+    /// Open HWPX container from byte array with the given resource limits
+    pub fn open_with_limits(data: &'a [u8], limits: ResourceLimits) -> Result<Self, HwpError> {
+        Self::from_source(Box::new(Cursor::new(data)), Some(data), limits)
+    }
+
+    /// Open HWPX container from a reader with the default resource limits
+    ///
+    /// 파일 전체를 메모리에 올리지 않고 필요한 항목만 읽어 풉니다.
+    /// Only the entries that are needed are read and decompressed, without loading the whole
+    /// file into memory.
+    pub fn from_reader(reader: impl Read + Seek + 'a) -> Result<Self, HwpError> {
+        Self::from_reader_with_limits(reader, ResourceLimits::default())
+    }
+
+    /// Open HWPX container from a reader with the given resource limits
+    pub fn from_reader_with_limits(
+        reader: impl Read + Seek + 'a,
+        limits: ResourceLimits,
+    ) -> Result<Self, HwpError> {
+        Self::from_source(Box::new(reader), None, limits)
+    }
+
+    fn from_source(
+        source: Box<dyn ReadSeek + 'a>,
+        data: Option<&'a [u8]>,
+        limits: ResourceLimits,
+    ) -> Result<Self, HwpError> {
+        let archive = ZipArchive::new(source)
+            .map_err(|e| HwpError::ZipParseError(e.to_string()).caused_by(e))?;
+        if archive.len() > limits.max_entry_count {
+            return Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::EntryCount,
+                max: limits.max_entry_count as u64,
+            });
+        }
+
+        let mut container = Self {
+            data,
+            archive,
+            limits,
+            total_read: 0,
+            package_path: DEFAULT_PACKAGE_PATH.to_string(),
+            package: None,
+        };
+        if let Some(package_path) = container.find_package_path() {
+            container.package_path = package_path;
+        }
+        Ok(container)
+    }
+
+    /// META-INF/container.xml의 rootfile에서 패키지 문서 찾기 / Find the package document from the rootfiles of META-INF/container.xml
+    ///
+    /// 한글은 미리보기 등도 rootfile로 적으므로 패키지 미디어 형식인 것을 먼저 고르고, 없으면
+    /// `.hpf`/`.opf` 파일을 고릅니다. ZIP에 없는 경로는 쓰지 않습니다.
+    /// Hancom also lists previews and the like as rootfiles, so the one with the package media
+    /// type is preferred, then any `.hpf`/`.opf` file. Paths missing from the ZIP are not used.
+    fn find_package_path(&mut self) -> Option<String> {
+        if !self.file_exists(CONTAINER_XML) {
+            return None;
+        }
+        let content = self.read_file(CONTAINER_XML).ok()?;
+        let mut rootfiles = Vec::new();
+        visit_elements(CONTAINER_XML, &content, |e| {
+            if local_name(e) == "rootfile" {
+                if let Some(path) = attribute(e, b"full-path") {
+                    rootfiles.push((path, attribute(e, b"media-type")));
+                }
+            }
+        })
+        .ok()?;
+        rootfiles.retain(|(path, _)| self.file_exists(path));
+        let package = rootfiles
+            .iter()
+            .find(|(_, media_type)| media_type.as_deref() == Some(PACKAGE_MEDIA_TYPE))
+            .or_else(|| {
+                rootfiles
+                    .iter()
+                    .find(|(path, _)| path.ends_with(".hpf") || path.ends_with(".opf"))
+            })?;
+        Some(package.0.clone())
+    }
+
+    /// 패키지 문서(content.hpf) 경로 / Package document (content.hpf) path
+    pub fn package_path(&self) -> &str {
+        &self.package_path
+    }
 
-        Ok(Self { archive })
+    /// 패키지 문서가 있는 폴더, 끝에 `/` 포함 (보통 `Contents/`) / Folder of the package document, with a trailing `/` (usually `Contents/`)
+    pub fn content_dir(&self) -> &str {
+        self.package_path
+            .rfind('/')
+            .map_or("", |end| &self.package_path[..=end])
+    }
+
+    /// 매니페스트 경로를 ZIP 안의 경로로 / Resolve a manifest path into a path inside the ZIP
+    ///
+    /// 한글은 패키지 루트 기준 경로(`Contents/section0.xml`)를 쓰지만 OPF는 패키지 문서 기준
+    /// 경로를 쓰므로, ZIP에 그대로 없으면 패키지 문서 폴더 기준으로 봅니다. 어디에도 없으면 적힌
+    /// 그대로 둡니다.
+    /// Hancom writes paths relative to the package root (`Contents/section0.xml`) while OPF uses
+    /// paths relative to the package document, so paths missing from the ZIP as written are
+    /// taken relative to the package document folder. Paths found in neither place are kept
+    /// as written.
+    pub fn resolve_href(&self, href: &str) -> String {
+        let relative = format!("{}{href}", self.content_dir());
+        if !self.file_exists(href) && self.file_exists(&relative) {
+            relative
+        } else {
+            href.to_string()
+        }
     }
 
     /// Verify mimetype file contains "application/hwp+zip" or similar
@@ -39,6 +175,9 @@ impl<'a> HwpxContainer<'a> {
                     })
                 }
             }
+            Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+                Err(error)
+            }
             Err(_) => {
                 // mimetype file is optional in some HWPX implementations
                 Ok(())
@@ -47,27 +186,134 @@ impl<'a> HwpxContainer<'a> {
     }
 
     /// Read a file from the archive
+    ///
+    /// 헤더에 적힌 크기를 믿지 않고 실제로 풀린 바이트 수로 한도를 확인합니다.
+    /// Limits are checked against the bytes actually decompressed, not the size in the header.
     pub fn read_file(&mut self, path: &str) -> Result<Vec<u8>, HwpError> {
-        let mut file = self
-            .archive
-            .by_name(path)
-            .map_err(|_| HwpError::HwpxFileNotFound {
+        let mut buffer = Vec::new();
+        self.read_file_into(path, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read a file from the archive into an existing buffer
+    ///
+    /// 버퍼를 비우고 채우므로 여러 파일을 읽을 때 할당을 재사용할 수 있습니다.
+    /// The buffer is cleared and refilled, so its allocation can be reused across files.
+    pub fn read_file_into(&mut self, path: &str, buffer: &mut Vec<u8>) -> Result<(), HwpError> {
+        buffer.clear();
+        let file = self.archive.by_name(path).map_err(|e| {
+            HwpError::HwpxFileNotFound {
                 path: path.to_string(),
+            }
+            .caused_by(e)
+        })?;
+
+        let max_entry_size = self.limits.max_entry_size;
+        if file.size() > max_entry_size {
+            return Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::EntrySize,
+                max: max_entry_size,
+            }
+            .with_context(ErrorContext::in_container(path)));
+        }
+        let remaining_total = self.limits.max_total_size.saturating_sub(self.total_read);
+        let (max, exceeded) = if remaining_total < max_entry_size {
+            (
+                remaining_total,
+                HwpError::ResourceLimitExceeded {
+                    limit: ResourceLimit::TotalSize,
+                    max: self.limits.max_total_size,
+                },
+            )
+        } else {
+            (
+                max_entry_size,
+                HwpError::ResourceLimitExceeded {
+                    limit: ResourceLimit::EntrySize,
+                    max: max_entry_size,
+                },
+            )
+        };
+
+        buffer.reserve(file.size().min(max) as usize);
+        LimitedReader::new(file, max)
+            .read_to_end(buffer)
+            .map_err(|e| {
+                if is_limit_error(&e) {
+                    exceeded
+                } else {
+                    HwpError::Io(e.to_string()).caused_by(e)
+                }
+                .with_context(ErrorContext::in_container(path))
             })?;
 
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)
-            .map_err(|e| HwpError::Io(e.to_string()))?;
+        self.total_read += buffer.len() as u64;
+        Ok(())
+    }
 
-        Ok(buffer)
+    /// Open a file in the archive as a lazy reader
+    ///
+    /// 압축 해제 결과를 메모리에 모으지 않고 원본 바이트에서 바로 읽습니다. 리더에서 연
+    /// 컨테이너는 항목을 한 번에 읽어 둡니다. `read_file`처럼 항목 크기 한도와 남은 전체 크기
+    /// 한도를 넘게 풀리면 읽기 오류를 냅니다.
+    /// Reads straight from the source bytes without collecting the decompressed output.
+    /// Containers opened from a reader read the entry at once instead. Like `read_file`, reading
+    /// fails once the entry decompresses past the entry size limit or the remaining total size.
+    pub fn open_file_reader(&mut self, path: &str) -> Result<Box<dyn Read + 'a>, HwpError> {
+        use flate2::read::DeflateDecoder;
+        use zip::CompressionMethod;
+
+        let file = self.archive.by_name(path).map_err(|e| {
+            HwpError::HwpxFileNotFound {
+                path: path.to_string(),
+            }
+            .caused_by(e)
+        })?;
+
+        if file.size() > self.limits.max_entry_size {
+            return Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::EntrySize,
+                max: self.limits.max_entry_size,
+            }
+            .with_context(ErrorContext::in_container(path)));
+        }
+        let start = file.data_start() as usize;
+        let end = start.saturating_add(file.compressed_size() as usize);
+        let method = file.compression();
+        let encrypted = file.encrypted();
+        drop(file);
+
+        let raw = self.data.and_then(|data: &'a [u8]| data.get(start..end));
+        let max = self
+            .limits
+            .max_entry_size
+            .min(self.limits.max_total_size.saturating_sub(self.total_read));
+        match (method, raw) {
+            (CompressionMethod::Stored, Some(raw)) if !encrypted => {
+                Ok(Box::new(LimitedReader::new(raw, max)))
+            }
+            (CompressionMethod::Deflated, Some(raw)) if !encrypted => {
+                Ok(Box::new(LimitedReader::new(DeflateDecoder::new(raw), max)))
+            }
+            // 그 외 압축 방식은 zip 크레이트로 읽음 / Other methods are read through the zip crate
+            _ => Ok(Box::new(Cursor::new(self.read_file(path)?))),
+        }
     }
 
     /// Read a file as UTF-8 string
+    ///
+    /// BOM이나 XML 선언의 인코딩(UTF-16, EUC-KR 등)으로 쓰인 파일은 UTF-8로 바꿔 읽습니다.
+    /// Files written in another encoding (UTF-16, EUC-KR, ...) by BOM or XML declaration are transcoded to UTF-8.
     pub fn read_file_string(&mut self, path: &str) -> Result<String, HwpError> {
         let data = self.read_file(path)?;
-        String::from_utf8(data).map_err(|e| HwpError::EncodingError {
-            reason: e.to_string(),
-        })
+        match decode_xml(&data)? {
+            Cow::Owned(text) => Ok(text),
+            // BOM 없는 UTF-8은 복사하지 않음 / UTF-8 without a BOM is not copied
+            Cow::Borrowed(text) if text.len() == data.len() => {
+                Ok(String::from_utf8(data).expect("validated as UTF-8"))
+            }
+            Cow::Borrowed(text) => Ok(text.to_string()),
+        }
     }
 
     /// List all files in a directory
@@ -85,11 +331,15 @@ impl<'a> HwpxContainer<'a> {
     }
 
     /// Get the list of section files (section0.xml, section1.xml, etc.)
+    ///
+    /// 패키지 문서 폴더(보통 `Contents/`)의 `section*.xml`을 번호 순으로 나열합니다.
+    /// Lists `section*.xml` in the package document folder (usually `Contents/`) in number order.
     pub fn get_section_files(&self) -> Vec<String> {
+        let prefix = format!("{}section", self.content_dir());
         let mut sections: Vec<String> = self
             .archive
             .file_names()
-            .filter(|name| name.starts_with("Contents/section") && name.ends_with(".xml"))
+            .filter(|name| name.starts_with(&prefix) && name.ends_with(".xml"))
             .map(|s| s.to_string())
             .collect();
 
@@ -103,6 +353,36 @@ impl<'a> HwpxContainer<'a> {
         sections
     }
 
+    /// 읽을 순서대로 나열된 구역 파트 경로 / Section part paths in reading order
+    ///
+    /// 패키지 문서(content.hpf)의 spine 순서를 따르므로 `section*.xml`이 아닌 이름도 구역이
+    /// 됩니다. spine에 없는 `section*.xml`은 번호 순으로 뒤에 붙고, 패키지 문서를 읽을 수
+    /// 없으면 번호 순만 씁니다.
+    /// Follows the spine order of the package document (content.hpf), so parts not named
+    /// `section*.xml` can be sections too. `section*.xml` files missing from the spine are
+    /// appended in number order, and number order alone is used when the package document
+    /// cannot be read.
+    pub fn section_order(&mut self) -> Vec<String> {
+        let header_path = self.header_path();
+        let mut order: Vec<String> = Vec::new();
+        for path in self.spine_parts().unwrap_or_default() {
+            // spine의 머리 정보는 구역이 아님 / The header in the spine is not a section
+            if path != header_path
+                && path.ends_with(".xml")
+                && self.file_exists(&path)
+                && !order.contains(&path)
+            {
+                order.push(path);
+            }
+        }
+        for path in self.get_section_files() {
+            if !order.contains(&path) {
+                order.push(path);
+            }
+        }
+        order
+    }
+
     /// Get the list of binary data files
     pub fn get_bindata_files(&self) -> Vec<String> {
         self.archive
@@ -113,6 +393,52 @@ impl<'a> HwpxContainer<'a> {
     }
 }
 
+/// XML 바이트를 UTF-8 텍스트로 디코딩 / Decode XML bytes into UTF-8 text
+///
+/// 순서대로 BOM(UTF-8, UTF-16LE/BE), BOM 없는 UTF-16의 `<?` 패턴, XML 선언의 `encoding`을 보고
+/// 인코딩을 정합니다. UTF-8이면 BOM만 떼고 빌려 주고, 그 밖의 인코딩은 UTF-8로 바꿉니다.
+/// The encoding is taken from a BOM (UTF-8, UTF-16LE/BE), then the `<?` pattern of BOM-less
+/// UTF-16, then the XML declaration's `encoding`. UTF-8 is borrowed with any BOM removed; other
+/// encodings are transcoded to UTF-8.
+pub fn decode_xml(data: &[u8]) -> Result<Cow<'_, str>, HwpError> {
+    let (encoding, body) = match data {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => (UTF_8, rest),
+        [0xFF, 0xFE, rest @ ..] => (UTF_16LE, rest),
+        [0xFE, 0xFF, rest @ ..] => (UTF_16BE, rest),
+        [b'<', 0, b'?', 0, ..] => (UTF_16LE, data),
+        [0, b'<', 0, b'?', ..] => (UTF_16BE, data),
+        _ => (declared_encoding(data).unwrap_or(UTF_8), data),
+    };
+
+    let invalid = |reason: String| HwpError::EncodingError {
+        reason: format!("Invalid {} XML: {reason}", encoding.name()),
+    };
+    if encoding == UTF_8 {
+        return std::str::from_utf8(body)
+            .map(Cow::Borrowed)
+            .map_err(|e| invalid(e.to_string()));
+    }
+    encoding
+        .decode_without_bom_handling_and_without_replacement(body)
+        .map(|text| Cow::Owned(text.into_owned()))
+        .ok_or_else(|| invalid("malformed byte sequence".to_string()))
+}
+
+/// XML 선언의 `encoding` 값 (ASCII 호환 인코딩만) / `encoding` of the XML declaration (ASCII-compatible encodings only)
+///
+/// BOM 없이 UTF-16이라고 선언한 파일은 이미 `<?` 패턴으로 가려지므로, 여기서 UTF-16 이름은 무시합니다.
+/// BOM-less files declaring UTF-16 are already caught by the `<?` pattern, so UTF-16 labels are ignored here.
+fn declared_encoding(data: &[u8]) -> Option<&'static Encoding> {
+    let declaration = data.strip_prefix(b"<?xml")?;
+    let end = declaration.iter().position(|&b| b == b'>')?;
+    let declaration = std::str::from_utf8(&declaration[..end]).ok()?;
+    let value = declaration.split("encoding").nth(1)?;
+    let value = value.trim_start().strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let label = value[1..].split(quote).next()?;
+    Encoding::for_label(label.as_bytes()).filter(|encoding| encoding.is_ascii_compatible())
+}
+
 /// Extract section number from filename (e.g., "Contents/section0.xml" -> 0)
 fn extract_section_number(path: &str) -> Option<usize> {
     let filename = path.split('/').next_back()?;
@@ -123,6 +449,93 @@ fn extract_section_number(path: &str) -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
+
+    fn zip(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer
+                .start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn limit_of(result: Result<Vec<u8>, HwpError>) -> Option<ResourceLimit> {
+        match result.as_ref().map_err(HwpError::kind) {
+            Err(HwpError::ResourceLimitExceeded { limit, .. }) => Some(*limit),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_resource_limits() {
+        // 1 MiB의 0은 1 KiB 남짓으로 압축됨 / 1 MiB of zeros compresses to about 1 KiB
+        let bomb = zip(&[("Contents/section0.xml", vec![0; 1024 * 1024])]);
+        assert!(bomb.len() < 16 * 1024);
+        let limits = ResourceLimits::default().with_max_entry_size(64 * 1024);
+        let mut container = HwpxContainer::open_with_limits(&bomb, limits).unwrap();
+        assert_eq!(
+            limit_of(container.read_file("Contents/section0.xml")),
+            Some(ResourceLimit::EntrySize)
+        );
+        // 헤더에 적힌 크기로 먼저 거절 / Rejected up front by the size in the header
+        assert_eq!(
+            limit_of(
+                container
+                    .open_file_reader("Contents/section0.xml")
+                    .map(|_| Vec::new())
+            ),
+            Some(ResourceLimit::EntrySize)
+        );
+
+        let data = zip(&[("a", vec![1; 600]), ("b", vec![2; 600])]);
+        let limits = ResourceLimits::default().with_max_total_size(1000);
+        let mut container = HwpxContainer::open_with_limits(&data, limits).unwrap();
+        assert_eq!(container.read_file("a").unwrap().len(), 600);
+        assert_eq!(
+            limit_of(container.read_file("b")),
+            Some(ResourceLimit::TotalSize)
+        );
+        // 스트리밍도 남은 전체 크기까지만 풂 / Streaming also stops at the remaining total size
+        let mut reader = container.open_file_reader("b").unwrap();
+        assert!(reader.read_to_end(&mut Vec::new()).is_err());
+
+        let limits = ResourceLimits::default().with_max_entry_count(1);
+        assert!(matches!(
+            HwpxContainer::open_with_limits(&data, limits),
+            Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::EntryCount,
+                max: 1
+            })
+        ));
+        assert!(HwpxContainer::open_with_limits(&data, ResourceLimits::unlimited()).is_ok());
+    }
+
+    #[test]
+    fn test_from_reader_reads_entries_and_limits() {
+        let data = zip(&[("a", vec![1; 600]), ("b", vec![2; 600])]);
+        let mut container = HwpxContainer::from_reader(Cursor::new(data.clone())).unwrap();
+        assert_eq!(container.list_files(""), ["a", "b"]);
+        assert_eq!(container.read_file("a").unwrap(), vec![1; 600]);
+        let mut lazy = Vec::new();
+        container
+            .open_file_reader("b")
+            .unwrap()
+            .read_to_end(&mut lazy)
+            .unwrap();
+        assert_eq!(lazy, vec![2; 600]);
+
+        let limits = ResourceLimits::default().with_max_total_size(1000);
+        let mut container =
+            HwpxContainer::from_reader_with_limits(Cursor::new(data), limits).unwrap();
+        assert!(container.read_file("a").is_ok());
+        assert_eq!(
+            limit_of(container.read_file("b")),
+            Some(ResourceLimit::TotalSize)
+        );
+    }
 
     #[test]
     fn test_extract_section_number() {
@@ -130,4 +543,117 @@ mod tests {
         assert_eq!(extract_section_number("Contents/section10.xml"), Some(10));
         assert_eq!(extract_section_number("Contents/header.xml"), None);
     }
+
+    #[test]
+    fn test_package_path_from_container_xml() {
+        let container_xml = br#"<ocf:container xmlns:ocf="urn:oasis:names:tc:opendocument:xmlns:container"><ocf:rootfiles>
+<ocf:rootfile full-path="Preview/PrvText.txt" media-type="text/plain"/>
+<ocf:rootfile full-path="Doc/package.hpf" media-type="application/hwpml-package+xml"/>
+</ocf:rootfiles></ocf:container>"#;
+        let entries = [
+            ("META-INF/container.xml", container_xml.to_vec()),
+            ("Preview/PrvText.txt", b"text".to_vec()),
+            ("Doc/package.hpf", b"<opf:package/>".to_vec()),
+            ("Doc/section1.xml", b"<x/>".to_vec()),
+            ("Doc/section0.xml", b"<x/>".to_vec()),
+            ("Contents/section0.xml", b"<x/>".to_vec()),
+        ];
+        let data = zip(&entries);
+        let container = HwpxContainer::open(&data).unwrap();
+        assert_eq!(container.package_path(), "Doc/package.hpf");
+        assert_eq!(container.content_dir(), "Doc/");
+        assert_eq!(
+            container.get_section_files(),
+            ["Doc/section0.xml", "Doc/section1.xml"]
+        );
+        assert_eq!(container.resolve_href("section1.xml"), "Doc/section1.xml");
+        assert_eq!(
+            container.resolve_href("Contents/section0.xml"),
+            "Contents/section0.xml"
+        );
+        assert_eq!(container.resolve_href("missing.xml"), "missing.xml");
+
+        // rootfile이 ZIP에 없으면 기본 경로 / Default path when the rootfile is missing from the ZIP
+        let data = zip(&[entries[0].clone(), entries[5].clone()]);
+        let container = HwpxContainer::open(&data).unwrap();
+        assert_eq!(container.package_path(), DEFAULT_PACKAGE_PATH);
+        assert_eq!(container.content_dir(), "Contents/");
+    }
+
+    #[test]
+    fn test_section_order_follows_spine() {
+        let content = br#"<opf:package xmlns:opf="http://www.idpf.org/2007/opf/"><opf:manifest>
+<opf:item id="header" href="Contents/header.xml"/><opf:item id="body" href="Contents/body.xml"/>
+<opf:item id="s0" href="Contents/section0.xml"/><opf:item id="s1" href="section1.xml"/>
+</opf:manifest><opf:spine><opf:itemref idref="header"/><opf:itemref idref="body"/>
+<opf:itemref idref="s1"/><opf:itemref idref="s0"/><opf:itemref idref="body"/></opf:spine></opf:package>"#;
+        let parts = [
+            "Contents/header.xml",
+            "Contents/body.xml",
+            "Contents/section0.xml",
+            "Contents/section1.xml",
+            "Contents/section2.xml",
+        ];
+        let mut entries: Vec<(&str, Vec<u8>)> =
+            parts.iter().map(|part| (*part, b"<x/>".to_vec())).collect();
+        entries.push(("Contents/content.hpf", content.to_vec()));
+        let data = zip(&entries);
+        let mut container = HwpxContainer::open(&data).unwrap();
+        assert_eq!(
+            container.section_order(),
+            [
+                "Contents/body.xml",
+                "Contents/section1.xml",
+                "Contents/section0.xml",
+                "Contents/section2.xml"
+            ]
+        );
+
+        // content.hpf가 없으면 번호 순 / Number order without content.hpf
+        let data = zip(&entries[..5]);
+        let mut container = HwpxContainer::open(&data).unwrap();
+        assert_eq!(
+            container.section_order(),
+            [
+                "Contents/section0.xml",
+                "Contents/section1.xml",
+                "Contents/section2.xml"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_xml_encodings() {
+        let xml = r#"<?xml version="1.0"?><p>한글</p>"#;
+        assert!(matches!(
+            decode_xml(xml.as_bytes()).unwrap(),
+            Cow::Borrowed(_)
+        ));
+
+        let mut with_bom = vec![0xEF, 0xBB, 0xBF];
+        with_bom.extend_from_slice(xml.as_bytes());
+        assert_eq!(decode_xml(&with_bom).unwrap(), xml);
+
+        let utf16le: Vec<u8> = xml.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let utf16be: Vec<u8> = xml.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        assert_eq!(decode_xml(&utf16le).unwrap(), xml);
+        assert_eq!(decode_xml(&utf16be).unwrap(), xml);
+        assert_eq!(
+            decode_xml(&[&[0xFF, 0xFE][..], &utf16le].concat()).unwrap(),
+            xml
+        );
+        assert_eq!(
+            decode_xml(&[&[0xFE, 0xFF][..], &utf16be].concat()).unwrap(),
+            xml
+        );
+
+        let declared = r#"<?xml version="1.0" encoding='EUC-KR'?><p>한글</p>"#;
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode(declared);
+        assert_eq!(decode_xml(&euc_kr).unwrap(), declared);
+
+        assert!(matches!(
+            decode_xml(&[b'<', b'p', b'>', 0xFF]),
+            Err(HwpError::EncodingError { .. })
+        ));
+    }
 }
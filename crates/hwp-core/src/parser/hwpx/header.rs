@@ -3,13 +3,26 @@
 /// header.xml contains document settings like character shapes, paragraph shapes,
 /// styles, fonts, and other document-wide properties.
 use quick_xml::events::Event;
-use quick_xml::Reader;
+use quick_xml::{NsReader, Reader};
 
-use crate::document::{DocInfo, FileHeader};
-use crate::error::HwpError;
-use crate::types::DWORD;
+use crate::document::bodytext::NumberShape;
+use crate::document::docinfo::bullet::{
+    BulletAlignType, BulletDistanceType, BulletHeaderAttributes,
+};
+use crate::document::docinfo::numbering::{
+    DistanceType, ExtendedNumberingLevel, NumberingHeaderAttributes, NumberingLevelInfo,
+    ParagraphAlignType,
+};
+use crate::document::docinfo::para_shape::{HeaderShapeType, ParagraphAlignment};
+use crate::document::docinfo::style::StyleType;
+use crate::document::docinfo::{TrackChangeAuthor, TrackChangeContent, TrackChangeKind};
+use crate::document::{Bullet, CharShape, DocInfo, FileHeader, Numbering, ParaShape, Style};
+use crate::error::{ErrorContext, HwpError};
+use crate::parser::limits::{DepthGuard, ResourceLimits};
+use crate::types::{COLORREF, DWORD, INT32};
 
 use super::container::HwpxContainer;
+use super::namespace::{ElementName, Namespace};
 
 /// Parse header.xml and create FileHeader
 pub fn parse_file_header(container: &mut HwpxContainer) -> Result<FileHeader, HwpError> {
@@ -56,9 +69,13 @@ fn parse_version_xml(container: &mut HwpxContainer) -> Result<DWORD, HwpError> {
             }
             Ok(Event::Eof) => break,
             Err(e) => {
-                return Err(HwpError::XmlParseError(format!(
-                    "Error parsing version.xml: {e}"
-                )))
+                return Err(HwpError::xml_parse(
+                    "version.xml",
+                    e,
+                    &content,
+                    reader.error_position(),
+                )
+                .with_context(ErrorContext::in_container("version.xml")))
             }
             _ => {}
         }
@@ -69,9 +86,27 @@ fn parse_version_xml(container: &mut HwpxContainer) -> Result<DWORD, HwpError> {
 
 /// Parse header.xml and create DocInfo
 pub fn parse_doc_info(container: &mut HwpxContainer) -> Result<DocInfo, HwpError> {
-    let content = container.read_file_string("Contents/header.xml")?;
+    parse_doc_info_with_limits(container, &ResourceLimits::default())
+}
 
-    let mut reader = Reader::from_str(&content);
+/// Parse header.xml with resource limits (XML nesting depth)
+pub fn parse_doc_info_with_limits(
+    container: &mut HwpxContainer,
+    limits: &ResourceLimits,
+) -> Result<DocInfo, HwpError> {
+    let header_path = container.header_path();
+    let content = container.read_file_string(&header_path)?;
+    parse_doc_info_xml(&content, limits)
+        .map_err(|error| error.with_context(ErrorContext::in_container(&header_path)))
+}
+
+/// Parse header.xml content into DocInfo
+/// header.xml 내용으로 DocInfo 생성
+pub(crate) fn parse_doc_info_xml(
+    content: &str,
+    limits: &ResourceLimits,
+) -> Result<DocInfo, HwpError> {
+    let mut reader = NsReader::from_str(content);
     reader.config_mut().trim_text(true);
 
     // Create a basic DocInfo structure
@@ -80,63 +115,515 @@ pub fn parse_doc_info(container: &mut HwpxContainer) -> Result<DocInfo, HwpError
 
     // Parse the XML and extract relevant information
     // For now, we create a minimal DocInfo that allows the document to be processed
-    parse_header_xml_content(&mut reader, &mut doc_info)?;
+    parse_header_xml_content(content, &mut reader, &mut doc_info, limits.max_xml_depth)?;
 
     Ok(doc_info)
 }
 
+/// Paragraph shape with HWP defaults (justified, no margins)
+/// HWP 기본값 문단 모양 (양쪽 정렬, 여백 없음)
+fn default_para_shape() -> ParaShape {
+    ParaShape::parse(&[0u8; 54], 0x05010000).expect("54 bytes is a valid ParaShape")
+}
+
+/// Apply a child of <hh:paraPr> (alignment, margins) to the paragraph shape
+/// <hh:paraPr>의 자식 요소(정렬, 여백)를 문단 모양에 반영
+///
+/// 여백 값은 HWP 5.0과 같은 HWPUNIT입니다.
+/// Margin values are in HWPUNIT, the same as HWP 5.0.
+fn apply_para_pr_child(
+    name: ElementName,
+    e: &quick_xml::events::BytesStart,
+    shape: &mut ParaShape,
+) {
+    // 여백 값(<hc:left> 등)은 공통 네임스페이스 / Margin values (<hc:left>, ...) are in the core namespace
+    let namespace = if matches!(name.local_name, "align" | "heading") {
+        Namespace::Head
+    } else {
+        Namespace::Core
+    };
+    let Some(element) = name.local_in(namespace) else {
+        return;
+    };
+    let value = || {
+        attribute(e, b"value")
+            .and_then(|v| v.parse::<INT32>().ok())
+            .unwrap_or(0)
+    };
+    match element {
+        "align" => {
+            if let Some(horizontal) = attribute(e, b"horizontal") {
+                shape.attributes1.align = match horizontal.as_str() {
+                    "LEFT" => ParagraphAlignment::Left,
+                    "RIGHT" => ParagraphAlignment::Right,
+                    "CENTER" => ParagraphAlignment::Center,
+                    "DISTRIBUTE" => ParagraphAlignment::Distribute,
+                    "DISTRIBUTE_SPACE" => ParagraphAlignment::Divide,
+                    _ => ParagraphAlignment::Justify,
+                };
+            }
+        }
+        "heading" => {
+            shape.attributes1.header_shape_type = match attribute(e, b"type").as_deref() {
+                Some("OUTLINE") => HeaderShapeType::Outline,
+                Some("NUMBER") => HeaderShapeType::Number,
+                Some("BULLET") => HeaderShapeType::Bullet,
+                _ => HeaderShapeType::None,
+            };
+            shape.number_bullet_id = attribute(e, b"idRef")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0);
+            // 속성1에는 3비트(수준 1~7)만 들어감 / Attributes 1 only hold three bits (levels 1-7)
+            let level = attribute(e, b"level")
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(0);
+            shape.attributes1.paragraph_level = level.min(6) as u8;
+            shape.paragraph_level = Some(level);
+        }
+        // OWPML 표기 그대로 "intent" / Spelled "intent" in OWPML
+        "intent" => shape.indent = value(),
+        "left" => shape.left_margin = value(),
+        "right" => shape.right_margin = value(),
+        "prev" => shape.top_spacing = value(),
+        "next" => shape.bottom_spacing = value(),
+        _ => {}
+    }
+}
+
+/// Level information from the attributes of <hh:paraHead> (the number format is the element text)
+/// <hh:paraHead> 속성으로 만든 수준 정보 (번호 형식은 요소의 글자)
+///
+/// 돌려주는 수준은 1부터 세며, 글머리표의 `<hh:paraHead>`는 수준 0입니다.
+/// The returned level counts from 1; the `<hh:paraHead>` of a bullet has level 0.
+fn numbering_level_from_para_head(e: &quick_xml::events::BytesStart) -> (u32, NumberingLevelInfo) {
+    let number = |key: &[u8]| attribute(e, key).and_then(|v| v.parse::<i64>().ok());
+    let flag = |key: &[u8]| attribute(e, key).is_some_and(|v| v == "1" || v == "true");
+    let info = NumberingLevelInfo {
+        attributes: NumberingHeaderAttributes {
+            align_type: match attribute(e, b"align").as_deref() {
+                Some("CENTER") => ParagraphAlignType::Center,
+                Some("RIGHT") => ParagraphAlignType::Right,
+                _ => ParagraphAlignType::Left,
+            },
+            instance_like: flag(b"useInstWidth"),
+            auto_outdent: flag(b"autoIndent"),
+            distance_type: if attribute(e, b"textOffsetType").as_deref() == Some("HWPUNIT") {
+                DistanceType::Value
+            } else {
+                DistanceType::Ratio
+            },
+            number_shape: attribute(e, b"numFormat")
+                .and_then(|name| NumberShape::from_hwpx_name(&name))
+                .unwrap_or(NumberShape::Arabic),
+        },
+        width: number(b"widthAdjust").unwrap_or(0) as i16,
+        distance: number(b"textOffset").unwrap_or(0) as i16,
+        // 없으면 0xFFFFFFFF (글자 모양 없음) / 0xFFFFFFFF (no character shape) when absent
+        char_shape_id: number(b"charPrIDRef").unwrap_or(u32::MAX as i64) as u32,
+        format_length: 0,
+        format_string: String::new(),
+        start_number: 0,
+        level_start_number: number(b"start").map(|start| start as u32),
+    };
+    (number(b"level").unwrap_or(0) as u32, info)
+}
+
+/// Put a finished <hh:paraHead> into the numbering or bullet being read
+/// 다 읽은 <hh:paraHead>를 읽고 있는 문단 번호나 글머리표에 넣기
+fn finish_para_head(
+    (level, mut info): (u32, NumberingLevelInfo),
+    format: String,
+    numbering: Option<&mut Numbering>,
+    bullet: Option<&mut Bullet>,
+) {
+    if let Some(numbering) = numbering {
+        let format_length = format.encode_utf16().count() as u16;
+        if level > 7 {
+            numbering.extended_levels.push(ExtendedNumberingLevel {
+                format_length,
+                format_string: format,
+            });
+        } else {
+            info.format_length = format_length;
+            info.format_string = format;
+            numbering.levels.push(info);
+        }
+    } else if let Some(bullet) = bullet {
+        let attributes = &info.attributes;
+        bullet.attributes = BulletHeaderAttributes {
+            align_type: match attributes.align_type {
+                ParagraphAlignType::Left => BulletAlignType::Left,
+                ParagraphAlignType::Center => BulletAlignType::Center,
+                ParagraphAlignType::Right => BulletAlignType::Right,
+            },
+            instance_like: attributes.instance_like,
+            auto_outdent: attributes.auto_outdent,
+            distance_type: match attributes.distance_type {
+                DistanceType::Ratio => BulletDistanceType::Ratio,
+                DistanceType::Value => BulletDistanceType::Value,
+            },
+        };
+        bullet.width = info.width;
+        bullet.space = info.distance;
+        bullet.char_shape_id = info.char_shape_id as INT32;
+    }
+}
+
+/// Bullet from the attributes of <hh:bullet> (the paragraph head comes from its child)
+/// <hh:bullet> 속성으로 만든 글머리표 (문단 머리 정보는 자식 요소에서 채움)
+fn bullet_from_element(e: &quick_xml::events::BytesStart) -> Bullet {
+    let first_unit = |key: &[u8]| {
+        attribute(e, key)
+            .and_then(|value| value.encode_utf16().next())
+            .unwrap_or(0)
+    };
+    Bullet {
+        attributes: BulletHeaderAttributes {
+            align_type: BulletAlignType::Left,
+            instance_like: false,
+            auto_outdent: false,
+            distance_type: BulletDistanceType::Ratio,
+        },
+        width: 0,
+        space: 0,
+        char_shape_id: -1,
+        bullet_char: first_unit(b"char"),
+        image_bullet_id: attribute(e, b"useImage").map_or(0, |v| i32::from(v == "1")),
+        image_bullet_attributes: None,
+        check_bullet_char: first_unit(b"checkedChar"),
+    }
+}
+
+/// Style from the attributes of <hh:style>
+/// <hh:style> 속성으로 만든 스타일
+fn style_from_element(e: &quick_xml::events::BytesStart) -> Style {
+    Style {
+        local_name: attribute(e, b"name").unwrap_or_default(),
+        english_name: attribute(e, b"engName").unwrap_or_default(),
+        style_type: match attribute(e, b"type").as_deref() {
+            Some("CHAR") => StyleType::Character,
+            _ => StyleType::Paragraph,
+        },
+        next_style_id: attribute(e, b"nextStyleIDRef")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0),
+        lang_id: attribute(e, b"langID")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0),
+        para_shape_id: attribute(e, b"paraPrIDRef").and_then(|v| v.parse().ok()),
+        char_shape_id: attribute(e, b"charPrIDRef").and_then(|v| v.parse().ok()),
+    }
+}
+
+/// Character shape from the attributes of <hh:charPr> (size, colors, emphasis mark)
+/// <hh:charPr> 속성(크기, 색, 강조점)으로 만든 글자 모양
+fn char_shape_from_char_pr(e: &quick_xml::events::BytesStart) -> CharShape {
+    let mut shape = CharShape::parse(&[0u8; 72], 0).expect("72 bytes is a valid CharShape");
+    shape.base_size = attribute(e, b"height")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1000);
+    shape.text_color = attribute(e, b"textColor")
+        .and_then(|v| parse_color(&v))
+        .unwrap_or(COLORREF(0));
+    // "none"은 음영 없음 / "none" means no shade
+    shape.shading_color = attribute(e, b"shadeColor")
+        .and_then(|v| parse_color(&v))
+        .unwrap_or(COLORREF(0xFFFF_FFFF));
+    shape.attributes.emphasis_mark = match attribute(e, b"symMark").as_deref() {
+        Some("DOT_ABOVE") => 1,
+        Some("RING_ABOVE") => 2,
+        Some("CARON") => 3,
+        Some("TILDE") => 4,
+        Some("SIDE") => 5,
+        Some("COLON") => 6,
+        Some("GRAVE_ACCENT") => 7,
+        Some("ACUTE_ACCENT") => 8,
+        Some("CIRCUMFLEX") => 9,
+        Some("MACRON") => 10,
+        Some("HOOK_ABOVE") => 11,
+        Some("DOT_BELOW") => 12,
+        _ => 0,
+    };
+    for size in language_values(&mut shape.relative_size) {
+        *size = 100;
+    }
+    for ratio in language_values(&mut shape.font_stretch) {
+        *ratio = 100;
+    }
+    shape
+}
+
+/// Apply a child of <hh:charPr> (fonts, offsets, emphasis) to the character shape
+/// <hh:charPr>의 자식 요소(글꼴, 위치, 강조)를 글자 모양에 반영
+fn apply_char_pr_child(
+    name: ElementName,
+    e: &quick_xml::events::BytesStart,
+    shape: &mut CharShape,
+) {
+    let Some(element) = name.local_in(Namespace::Head) else {
+        return;
+    };
+    // 언어별 값: hangul, latin, hanja, japanese, other, symbol, user 순서
+    // Per-language values in the order hangul, latin, hanja, japanese, other, symbol, user
+    let languages = || {
+        [
+            "hangul", "latin", "hanja", "japanese", "other", "symbol", "user",
+        ]
+        .map(|lang| attribute(e, lang.as_bytes()).and_then(|v| v.parse::<i32>().ok()))
+    };
+    match element {
+        "fontRef" => {
+            let font_ids = &mut shape.font_ids;
+            let ids = [
+                &mut font_ids.korean,
+                &mut font_ids.english,
+                &mut font_ids.chinese,
+                &mut font_ids.japanese,
+                &mut font_ids.other,
+                &mut font_ids.symbol,
+                &mut font_ids.user,
+            ];
+            for (id, value) in ids.into_iter().zip(languages()) {
+                *id = value.unwrap_or(0) as u16;
+            }
+        }
+        "ratio" => {
+            for (ratio, value) in language_values(&mut shape.font_stretch).zip(languages()) {
+                *ratio = value.unwrap_or(100) as u8;
+            }
+        }
+        "relSz" => {
+            for (size, value) in language_values(&mut shape.relative_size).zip(languages()) {
+                *size = value.unwrap_or(100) as u8;
+            }
+        }
+        "spacing" | "offset" => {
+            let values = if element == "spacing" {
+                &mut shape.letter_spacing
+            } else {
+                &mut shape.text_position
+            };
+            let targets = [
+                &mut values.korean,
+                &mut values.english,
+                &mut values.chinese,
+                &mut values.japanese,
+                &mut values.other,
+                &mut values.symbol,
+                &mut values.user,
+            ];
+            for (target, value) in targets.into_iter().zip(languages()) {
+                *target = value.unwrap_or(0) as i8;
+            }
+        }
+        "bold" => shape.attributes.bold = true,
+        "italic" => shape.attributes.italic = true,
+        "underline" => {
+            shape.attributes.underline_type = match attribute(e, b"type").as_deref() {
+                Some("BOTTOM") => 1,
+                Some("CENTER") => 2,
+                Some("TOP") => 3,
+                _ => 0,
+            };
+        }
+        "strikeout" => {
+            let shape_name = attribute(e, b"shape");
+            shape.attributes.strikethrough =
+                u8::from(!matches!(shape_name.as_deref(), None | Some("NONE")));
+        }
+        "supscript" => shape.attributes.superscript = true,
+        "subscript" => shape.attributes.subscript = true,
+        _ => {}
+    }
+}
+
+/// 언어별 u8 값 7개 / The seven per-language u8 values
+fn language_values(
+    values: &mut crate::document::docinfo::char_shape::LanguageCharAttributesU8,
+) -> impl Iterator<Item = &mut u8> {
+    [
+        &mut values.korean,
+        &mut values.english,
+        &mut values.chinese,
+        &mut values.japanese,
+        &mut values.other,
+        &mut values.symbol,
+        &mut values.user,
+    ]
+    .into_iter()
+}
+
+/// "#RRGGBB" → COLORREF (0x00BBGGRR), "none" 등은 None / "none" and the like give None
+fn parse_color(value: &str) -> Option<COLORREF> {
+    let hex = value.strip_prefix('#')?;
+    let rgb = u32::from_str_radix(hex.get(..6)?, 16).ok()?;
+    Some(COLORREF::rgb(
+        (rgb >> 16) as u8,
+        (rgb >> 8) as u8,
+        rgb as u8,
+    ))
+}
+
+fn attribute(e: &quick_xml::events::BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+/// Parse <hh:trackChange> and <hh:trackChangeAuthor> into DocInfo
+/// <hh:trackChange>와 <hh:trackChangeAuthor>를 DocInfo에 추가
+fn parse_track_change_element(
+    name: ElementName,
+    e: &quick_xml::events::BytesStart,
+    doc_info: &mut DocInfo,
+) {
+    let Some(name @ ("trackChange" | "trackChangeAuthor")) = name.local_in(Namespace::Head) else {
+        return;
+    };
+
+    let mut id = None;
+    let mut kind = None;
+    let mut author_id = None;
+    let mut date = None;
+    let mut author_name = None;
+    for attr in e.attributes().flatten() {
+        let value = String::from_utf8_lossy(&attr.value);
+        match attr.key.as_ref() {
+            b"id" => id = value.parse().ok(),
+            b"type" => {
+                kind = Some(match value.as_ref() {
+                    "Insert" => TrackChangeKind::Insert,
+                    "Delete" => TrackChangeKind::Delete,
+                    _ => TrackChangeKind::Format,
+                })
+            }
+            b"authorID" => author_id = value.parse().ok(),
+            b"date" => date = Some(value.to_string()),
+            b"name" => author_name = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    if name == "trackChange" {
+        doc_info.track_change_contents.push(TrackChangeContent {
+            id,
+            kind,
+            author_id,
+            date,
+            ..Default::default()
+        });
+    } else {
+        doc_info.track_change_authors.push(TrackChangeAuthor {
+            id,
+            name: author_name,
+            ..Default::default()
+        });
+    }
+}
+
 /// Parse header.xml content
 fn parse_header_xml_content(
-    reader: &mut Reader<&[u8]>,
+    content: &str,
+    reader: &mut NsReader<&[u8]>,
     doc_info: &mut DocInfo,
+    max_depth: usize,
 ) -> Result<(), HwpError> {
-    let mut in_char_shapes = false;
-    let mut in_para_shapes = false;
-    let mut in_face_names = false;
+    let mut xml_depth = DepthGuard::new(max_depth);
+    // 읽고 있는 <hh:paraPr>, <hp:case> 안의 값은 건너뛰고 <hp:default>를 씀
+    // The <hh:paraPr> being read; values inside <hp:case> are skipped in favour of <hp:default>
+    let mut para_shape: Option<ParaShape> = None;
+    let mut char_shape: Option<CharShape> = None;
+    let mut in_switch_case = false;
+    // 읽고 있는 <hh:numbering>/<hh:bullet>과 번호 형식 글자를 모으는 <hh:paraHead>
+    // The <hh:numbering>/<hh:bullet> being read and the <hh:paraHead> collecting its format text
+    let mut numbering: Option<Numbering> = None;
+    let mut bullet: Option<Bullet> = None;
+    let mut para_head: Option<((u32, NumberingLevelInfo), String)> = None;
 
     loop {
         match reader.read_event() {
             Ok(Event::Start(ref e)) => {
-                let name = e.name();
-                let local_name = String::from_utf8_lossy(name.as_ref());
-
-                match local_name.as_ref() {
-                    s if s.ends_with("charShapes") => in_char_shapes = true,
-                    s if s.ends_with("paraShapes") => in_para_shapes = true,
-                    s if s.ends_with("faceNames") || s.ends_with("fontfaces") => {
-                        in_face_names = true
-                    }
-                    s if s.ends_with("charShape") && in_char_shapes => {
-                        // Parse character shape - simplified for now
-                        // In full implementation, parse all attributes
-                    }
-                    s if s.ends_with("paraShape") && in_para_shapes => {
-                        // Parse paragraph shape - simplified for now
-                    }
-                    s if (s.ends_with("font") || s.ends_with("faceName")) && in_face_names => {
-                        // Parse font face - simplified for now
-                    }
-                    _ => {}
+                xml_depth.enter()?;
+                let name = ElementName::resolve(reader, e.name());
+                if name.is(Namespace::Head, "paraPr") {
+                    para_shape = Some(default_para_shape());
+                } else if name.is(Namespace::Head, "charPr") {
+                    char_shape = Some(char_shape_from_char_pr(e));
+                } else if name.is(Namespace::Paragraph, "case") {
+                    in_switch_case = true;
+                } else if name.is(Namespace::Head, "numbering") {
+                    numbering = Some(Numbering {
+                        levels: Vec::new(),
+                        extended_levels: Vec::new(),
+                    });
+                } else if name.is(Namespace::Head, "style") {
+                    doc_info.styles.push(style_from_element(e));
+                } else if name.is(Namespace::Head, "bullet") {
+                    bullet = Some(bullet_from_element(e));
+                } else if name.is(Namespace::Head, "paraHead") {
+                    para_head = Some((numbering_level_from_para_head(e), String::new()));
+                } else if let Some(shape) = para_shape.as_mut().filter(|_| !in_switch_case) {
+                    apply_para_pr_child(name, e, shape);
+                } else if let Some(shape) = char_shape.as_mut() {
+                    apply_char_pr_child(name, e, shape);
                 }
+                parse_track_change_element(name, e, doc_info);
+            }
+            Ok(Event::Empty(ref e)) => {
+                let name = ElementName::resolve(reader, e.name());
+                if name.is(Namespace::Head, "charPr") {
+                    doc_info.char_shapes.push(char_shape_from_char_pr(e));
+                } else if name.is(Namespace::Head, "style") {
+                    doc_info.styles.push(style_from_element(e));
+                } else if name.is(Namespace::Head, "bullet") {
+                    doc_info.bullets.push(bullet_from_element(e));
+                } else if name.is(Namespace::Head, "paraHead") {
+                    finish_para_head(
+                        numbering_level_from_para_head(e),
+                        String::new(),
+                        numbering.as_mut(),
+                        bullet.as_mut(),
+                    );
+                } else if let Some(shape) = para_shape.as_mut().filter(|_| !in_switch_case) {
+                    apply_para_pr_child(name, e, shape);
+                } else if let Some(shape) = char_shape.as_mut() {
+                    apply_char_pr_child(name, e, shape);
+                }
+                parse_track_change_element(name, e, doc_info);
             }
             Ok(Event::End(ref e)) => {
-                let name = e.name();
-                let local_name = String::from_utf8_lossy(name.as_ref());
-
-                match local_name.as_ref() {
-                    s if s.ends_with("charShapes") => in_char_shapes = false,
-                    s if s.ends_with("paraShapes") => in_para_shapes = false,
-                    s if s.ends_with("faceNames") || s.ends_with("fontfaces") => {
-                        in_face_names = false
+                xml_depth.leave();
+                let name = ElementName::resolve(reader, e.name());
+                if name.is(Namespace::Head, "paraPr") {
+                    doc_info.para_shapes.extend(para_shape.take());
+                } else if name.is(Namespace::Head, "charPr") {
+                    doc_info.char_shapes.extend(char_shape.take());
+                } else if name.is(Namespace::Paragraph, "case") {
+                    in_switch_case = false;
+                } else if name.is(Namespace::Head, "numbering") {
+                    doc_info.numbering.extend(numbering.take());
+                } else if name.is(Namespace::Head, "bullet") {
+                    doc_info.bullets.extend(bullet.take());
+                } else if name.is(Namespace::Head, "paraHead") {
+                    if let Some((level, format)) = para_head.take() {
+                        finish_para_head(level, format, numbering.as_mut(), bullet.as_mut());
                     }
-                    _ => {}
+                }
+            }
+            Ok(Event::Text(ref t)) => {
+                if let Some((_, format)) = para_head.as_mut() {
+                    format.push_str(&t.unescape().unwrap_or_default());
                 }
             }
             Ok(Event::Eof) => break,
             Err(e) => {
-                return Err(HwpError::XmlParseError(format!(
-                    "Error parsing header.xml: {e}"
-                )))
+                return Err(HwpError::xml_parse(
+                    "header.xml",
+                    e,
+                    content,
+                    reader.error_position(),
+                ))
             }
             _ => {}
         }
@@ -0,0 +1,318 @@
+/// HWPX package integrity report
+///
+/// 패키지가 가리키는 파트와 ZIP에 실제로 있는 파트를 맞춰 보아, 손상된 패키지를 고칠 때 무엇이
+/// 빠졌고 무엇이 남는지 알려 줍니다. `validate`와 달리 심각도를 매기지 않고 목록만 돌려줍니다.
+/// Compares the parts the package refers to with the parts actually in the ZIP, showing what is
+/// missing and what is left over when repairing a damaged package. Unlike `validate`, it does
+/// not grade severity and only returns the lists.
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorContext, HwpError};
+
+use super::container::{HwpxContainer, CONTAINER_XML};
+
+/// content.hpf의 매니페스트와 spine / The content.hpf manifest and spine
+#[derive(Debug, Default)]
+pub(crate) struct PackageManifest {
+    /// 항목 ID → ZIP 안의 경로 / Item ID → path inside the ZIP
+    pub items: BTreeMap<String, String>,
+    /// spine 순서의 항목 ID / Item IDs in spine order
+    pub spine: Vec<String>,
+}
+
+/// ZIP에 없는 파트 / Part missing from the ZIP
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MissingPart {
+    /// 매니페스트 항목 ID (container.xml의 rootfile이면 None) / Manifest item ID (None for a container.xml rootfile)
+    pub id: Option<String>,
+    /// 파트 경로 / Part path
+    pub href: String,
+}
+
+/// 패키지 무결성 보고서 / Package integrity report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// content.hpf 매니페스트나 container.xml이 가리키지만 ZIP에 없는 파트
+    /// Parts referenced by the content.hpf manifest or container.xml but missing from the ZIP
+    pub missing_parts: Vec<MissingPart>,
+    /// 어느 구역이나 header.xml에서도 참조하지 않는 BinData 파일 / BinData files not referenced by any section or header.xml
+    pub orphan_bin_data: Vec<String>,
+    /// ZIP에 있지만 spine에 없는 구역 파일 / Section files in the ZIP but not in the spine
+    pub sections_not_in_spine: Vec<String>,
+}
+
+impl IntegrityReport {
+    /// 문제가 없는지 확인 / Whether no problem was found
+    pub fn is_clean(&self) -> bool {
+        self.missing_parts.is_empty()
+            && self.orphan_bin_data.is_empty()
+            && self.sections_not_in_spine.is_empty()
+    }
+}
+
+impl HwpxContainer<'_> {
+    /// 패키지 무결성 보고서 작성 / Build a package integrity report
+    ///
+    /// content.hpf가 없으면 매니페스트와 spine이 비어 있는 것으로 보므로 모든 구역이
+    /// `sections_not_in_spine`에 들어갑니다.
+    /// A missing content.hpf is treated as an empty manifest and spine, so every section ends up
+    /// in `sections_not_in_spine`.
+    ///
+    /// # Returns / 반환값
+    /// 보고서, 패키지 XML을 읽을 수 없으면 오류 / The report, or an error if package XML cannot be read
+    pub fn integrity_report(&mut self) -> Result<IntegrityReport, HwpError> {
+        let mut report = IntegrityReport::default();
+
+        if self.file_exists(CONTAINER_XML) {
+            let content = self.read_file(CONTAINER_XML)?;
+            visit_elements(CONTAINER_XML, &content, |e| {
+                if local_name(e) == "rootfile" {
+                    if let Some(href) = attribute(e, b"full-path") {
+                        if !self.file_exists(&href) {
+                            report.missing_parts.push(MissingPart { id: None, href });
+                        }
+                    }
+                }
+            })?;
+        }
+
+        let package = self.read_package()?;
+        let (manifest, spine) = (&package.items, &package.spine);
+        for (id, href) in manifest {
+            if !self.file_exists(href) {
+                report.missing_parts.push(MissingPart {
+                    id: Some(id.clone()),
+                    href: href.clone(),
+                });
+            }
+        }
+
+        let sections = self.get_section_files();
+        for section in &sections {
+            let in_spine = manifest
+                .iter()
+                .any(|(id, href)| href == section && spine.contains(id));
+            if !in_spine {
+                report.sections_not_in_spine.push(section.clone());
+            }
+        }
+
+        // 그림과 채우기 이미지는 binaryItemIDRef로 BinData를 가리킴
+        // Pictures and image fills point at BinData through binaryItemIDRef
+        let mut referenced = BTreeSet::new();
+        let mut parts = sections;
+        let header_path = self.header_path();
+        if self.file_exists(&header_path) {
+            parts.push(header_path);
+        }
+        for part in &parts {
+            let content = self.read_file(part)?;
+            visit_elements(part, &content, |e| {
+                referenced.extend(attribute(e, b"binaryItemIDRef"));
+            })?;
+        }
+        for path in self.get_bindata_files() {
+            if path.ends_with('/') {
+                continue;
+            }
+            let stem = file_stem(&path);
+            let is_referenced = referenced.contains(stem)
+                || manifest
+                    .iter()
+                    .any(|(id, href)| *href == path && referenced.contains(id));
+            if !is_referenced {
+                report.orphan_bin_data.push(path);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// spine 순서대로 나열된 파트 경로 / Part paths in spine order
+    pub(crate) fn spine_parts(&mut self) -> Result<Vec<String>, HwpError> {
+        let package = self.read_package()?;
+        Ok(package
+            .spine
+            .iter()
+            .filter_map(|id| package.items.get(id).cloned())
+            .collect())
+    }
+
+    /// 매니페스트 항목 ID의 파트 경로 / Part path of a manifest item ID
+    pub(crate) fn manifest_path(&mut self, id: &str) -> Option<String> {
+        self.read_package().ok()?.items.get(id).cloned()
+    }
+
+    /// 머리 정보 파트 경로 / Header part path
+    ///
+    /// 매니페스트의 `header` 항목을 따르고, 없으면 패키지 문서 폴더의 `header.xml`입니다.
+    /// Follows the manifest `header` item, or `header.xml` in the package document folder.
+    pub fn header_path(&mut self) -> String {
+        self.manifest_path("header")
+            .unwrap_or_else(|| format!("{}header.xml", self.content_dir()))
+    }
+
+    /// content.hpf의 매니페스트와 spine / The content.hpf manifest and spine
+    ///
+    /// 처음 한 번만 읽어 컨테이너에 두므로 다시 불러도 압축 해제 한도를 쓰지 않습니다.
+    /// Read once and kept on the container, so later calls do not count against the
+    /// decompression limits again.
+    fn read_package(&mut self) -> Result<Rc<PackageManifest>, HwpError> {
+        if let Some(package) = &self.package {
+            return Ok(Rc::clone(package));
+        }
+        let mut package = PackageManifest::default();
+        let package_path = self.package_path().to_string();
+        if self.file_exists(&package_path) {
+            let content = self.read_file(&package_path)?;
+            visit_elements(&package_path, &content, |e| match local_name(e).as_str() {
+                "item" => {
+                    if let (Some(id), Some(href)) = (attribute(e, b"id"), attribute(e, b"href")) {
+                        package.items.insert(id, self.resolve_href(&href));
+                    }
+                }
+                "itemref" => package.spine.extend(attribute(e, b"idref")),
+                _ => {}
+            })?;
+        }
+        let package = Rc::new(package);
+        self.package = Some(Rc::clone(&package));
+        Ok(package)
+    }
+}
+
+/// 시작 요소마다 `visit` 호출 / Call `visit` for each start element
+pub(super) fn visit_elements(
+    part: &str,
+    content: &[u8],
+    mut visit: impl FnMut(&BytesStart),
+) -> Result<(), HwpError> {
+    let mut reader = Reader::from_reader(content);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => visit(e),
+            Ok(Event::Eof) => return Ok(()),
+            Err(e) => {
+                return Err(
+                    HwpError::xml_parse(part, e, content, reader.error_position())
+                        .with_context(ErrorContext::in_container(part)),
+                )
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// 접두사를 뺀 요소 이름 / Element name without prefix
+pub(super) fn local_name(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.local_name().as_ref()).into_owned()
+}
+
+pub(super) fn attribute(e: &BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+/// "BinData/image1.png" → "image1"
+fn file_stem(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::limits::ResourceLimits;
+    use std::io::{Cursor, Write};
+
+    fn zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer
+                .start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_integrity_report() {
+        let content = br#"<opf:package xmlns:opf="o"><opf:manifest>
+<opf:item id="header" href="Contents/header.xml"/>
+<opf:item id="section0" href="Contents/section0.xml"/>
+<opf:item id="section1" href="Contents/section1.xml"/>
+<opf:item id="logo" href="BinData/logo.png"/>
+<opf:item id="table" href="BinData/table.bmp"/>
+</opf:manifest><opf:spine><opf:itemref idref="header"/><opf:itemref idref="section0"/></opf:spine></opf:package>"#;
+        let data = zip(&[
+            (
+                "META-INF/container.xml",
+                br#"<ocf:container xmlns:ocf="c"><ocf:rootfiles>
+<ocf:rootfile full-path="Contents/content.hpf"/></ocf:rootfiles></ocf:container>"#,
+            ),
+            ("Contents/content.hpf", content),
+            ("Contents/header.xml", br#"<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head"/>"#),
+            (
+                "Contents/section0.xml",
+                br#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hc:img binaryItemIDRef="logo"/></hs:sec>"#,
+            ),
+            ("Contents/section1.xml", br#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section"/>"#),
+            ("Contents/section2.xml", br#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section"/>"#),
+            ("BinData/logo.png", b"png"),
+            ("BinData/unused.jpg", b"jpg"),
+        ]);
+
+        let mut container = HwpxContainer::open(&data).unwrap();
+        let report = container.integrity_report().unwrap();
+        assert_eq!(
+            report.missing_parts,
+            [MissingPart {
+                id: Some("table".to_string()),
+                href: "BinData/table.bmp".to_string()
+            }]
+        );
+        assert_eq!(report.orphan_bin_data, ["BinData/unused.jpg"]);
+        assert_eq!(
+            report.sections_not_in_spine,
+            ["Contents/section1.xml", "Contents/section2.xml"]
+        );
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_manifest_is_read_once() {
+        let container_xml: &[u8] = br#"<ocf:container xmlns:ocf="c"><ocf:rootfiles>
+<ocf:rootfile full-path="Contents/content.hpf"/></ocf:rootfiles></ocf:container>"#;
+        let content: &[u8] = br#"<opf:package xmlns:opf="o"><opf:manifest>
+<opf:item id="header" href="Contents/head.xml"/>
+<opf:item id="section0" href="Contents/section0.xml"/>
+</opf:manifest><opf:spine><opf:itemref idref="section0"/></opf:spine></opf:package>"#;
+        let data = zip(&[
+            ("META-INF/container.xml", container_xml),
+            ("Contents/content.hpf", content),
+        ]);
+        // 패키지 문서를 한 번만 읽을 수 있는 한도 / Limit that allows reading the package document once
+        let limits = ResourceLimits::default()
+            .with_max_total_size((container_xml.len() + content.len() * 3 / 2) as u64);
+
+        let mut container = HwpxContainer::open_with_limits(&data, limits).unwrap();
+        for _ in 0..10 {
+            assert_eq!(container.header_path(), "Contents/head.xml");
+            assert_eq!(
+                container.manifest_path("section0").as_deref(),
+                Some("Contents/section0.xml")
+            );
+        }
+        assert_eq!(container.spine_parts().unwrap(), ["Contents/section0.xml"]);
+    }
+}
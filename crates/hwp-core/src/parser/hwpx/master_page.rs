@@ -0,0 +1,110 @@
+/// HWPX master page XML parser
+///
+/// Master pages (바탕쪽) are stored as separate parts (`Contents/masterpage0.xml`, ...) referenced
+/// by `<hp:masterPage idRef="...">` in the section definition. The root `<masterPage type="...">`
+/// names the pages it applies to and its `<hp:subList>` holds ordinary paragraphs, which are read
+/// with the section parser.
+use quick_xml::events::Event;
+use quick_xml::NsReader;
+
+use super::namespace::{ElementName, Namespace};
+use super::section::parse_part_paragraphs;
+use crate::document::bodytext::ctrl_header::ApplyPage;
+use crate::document::bodytext::MasterPage;
+use crate::error::HwpError;
+use crate::types::HWPUNIT;
+
+/// Part path of a master page ID by naming convention (`masterpage0` → `Contents/masterpage0.xml`)
+/// 이름 관례에 따른 바탕쪽 ID의 파트 경로 (`content_dir`는 패키지 문서 폴더)
+pub fn master_page_path(content_dir: &str, id_ref: &str) -> String {
+    format!("{content_dir}{id_ref}.xml")
+}
+
+/// Parse a master page XML part
+/// 바탕쪽 XML 파트 파싱
+///
+/// 마지막 쪽/임의 쪽 바탕쪽(`LAST_PAGE`, `OPTIONAL_PAGE`)은 특정 쪽에만 쓰여 None을 돌려줍니다.
+/// Last-page and optional-page master pages (`LAST_PAGE`, `OPTIONAL_PAGE`) apply to specific
+/// pages only, so None is returned for them.
+pub fn parse_master_page_xml(
+    content: &str,
+    max_depth: usize,
+) -> Result<Option<MasterPage>, HwpError> {
+    let mut reader = NsReader::from_str(content);
+    let mut apply_page = ApplyPage::Both;
+    let mut text_width = HWPUNIT::from(0);
+    let mut text_height = HWPUNIT::from(0);
+
+    // 루트와 첫 subList의 속성만 읽음 / Only the root and first subList attributes are read
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                match ElementName::resolve(&reader, e.name()) {
+                    name if name.is(Namespace::MasterPage, "masterPage") => {
+                        for attr in e.attributes().flatten() {
+                            if attr.key.local_name().as_ref() == b"type" {
+                                apply_page = match attr.value.as_ref() {
+                                    b"EVEN" => ApplyPage::EvenOnly,
+                                    b"ODD" => ApplyPage::OddOnly,
+                                    b"BOTH" => ApplyPage::Both,
+                                    _ => return Ok(None),
+                                };
+                            }
+                        }
+                    }
+                    name if name.is(Namespace::Paragraph, "subList") => {
+                        for attr in e.attributes().flatten() {
+                            let value = String::from_utf8_lossy(&attr.value);
+                            match attr.key.as_ref() {
+                                b"textWidth" => text_width = HWPUNIT(value.parse().unwrap_or(0)),
+                                b"textHeight" => text_height = HWPUNIT(value.parse().unwrap_or(0)),
+                                _ => {}
+                            }
+                        }
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(HwpError::xml_parse(
+                    "master page XML",
+                    e,
+                    content,
+                    reader.error_position(),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Some(MasterPage {
+        apply_page,
+        text_width,
+        text_height,
+        paragraphs: parse_part_paragraphs(content, max_depth)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_master_page_xml() {
+        let xml = r#"<masterPage xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" id="masterpage0" type="ODD" pageNumber="0">
+<hp:subList textWidth="42520" textHeight="65762"><hp:p><hp:run><hp:t>대외비</hp:t></hp:run></hp:p></hp:subList>
+</masterPage>"#;
+        let master_page = parse_master_page_xml(xml, 64).unwrap().unwrap();
+        assert_eq!(master_page.apply_page, ApplyPage::OddOnly);
+        assert_eq!(master_page.text_width, HWPUNIT(42520));
+        assert_eq!(master_page.text_height, HWPUNIT(65762));
+        assert_eq!(master_page.paragraphs.len(), 1);
+        assert_eq!(master_page.paragraphs[0].text(), "대외비");
+
+        // 마지막 쪽 바탕쪽은 건너뜀 / Last-page master pages are skipped
+        let last = xml.replace("ODD", "LAST_PAGE");
+        assert!(parse_master_page_xml(&last, 64).unwrap().is_none());
+    }
+}
@@ -0,0 +1,144 @@
+/// HWPX content.hpf metadata parser
+///
+/// content.hpf is an OPF package whose `<opf:metadata>` block carries the document
+/// title and `<opf:meta name="...">` entries (creator, dates, keywords). The values are
+/// mapped onto the same `SummaryInformation` structure HWP 5.0 fills from its
+/// `\005HwpSummaryInformation` stream.
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::document::SummaryInformation;
+use crate::error::{ErrorContext, HwpError};
+
+use super::container::HwpxContainer;
+
+/// Parse document metadata from content.hpf
+///
+/// # Returns
+/// `None` if the package has no content.hpf or its metadata is empty
+pub fn parse_metadata(
+    container: &mut HwpxContainer,
+) -> Result<Option<SummaryInformation>, HwpError> {
+    let package_path = container.package_path().to_string();
+    if !container.file_exists(&package_path) {
+        return Ok(None);
+    }
+    let content = container.read_file_string(&package_path)?;
+    parse_metadata_xml(&content, &package_path)
+}
+
+/// Parse the `<opf:metadata>` block of a content.hpf document
+fn parse_metadata_xml(content: &str, part: &str) -> Result<Option<SummaryInformation>, HwpError> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut info = SummaryInformation::default();
+    let mut in_metadata = false;
+    // Element whose text is being read: "title" or the meta entry's name
+    let mut current: Option<String> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                let name = e.name();
+                let local_name = String::from_utf8_lossy(name.as_ref());
+                match local_name.as_ref() {
+                    s if s.ends_with("metadata") => in_metadata = true,
+                    s if in_metadata && s.ends_with("title") => current = Some("title".into()),
+                    s if in_metadata && s.ends_with("meta") => {
+                        current = e
+                            .attributes()
+                            .flatten()
+                            .find(|attr| attr.key.as_ref() == b"name")
+                            .map(|attr| String::from_utf8_lossy(&attr.value).into_owned());
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                if let Some(key) = current.as_deref() {
+                    let value = e.unescape().unwrap_or_default().to_string();
+                    if !value.is_empty() {
+                        set_field(&mut info, key, value);
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let name = e.name();
+                let local_name = String::from_utf8_lossy(name.as_ref());
+                if local_name.ends_with("metadata") {
+                    break;
+                }
+                current = None;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(
+                    HwpError::xml_parse("content.hpf", e, content, reader.error_position())
+                        .with_context(ErrorContext::in_container(part)),
+                )
+            }
+            _ => {}
+        }
+    }
+
+    if info == SummaryInformation::default() {
+        Ok(None)
+    } else {
+        Ok(Some(info))
+    }
+}
+
+/// Store a metadata value in the matching `SummaryInformation` field
+fn set_field(info: &mut SummaryInformation, key: &str, value: String) {
+    let field = match key {
+        "title" => &mut info.title,
+        "creator" => &mut info.author,
+        "subject" => &mut info.subject,
+        "description" => &mut info.comments,
+        "keyword" => &mut info.keywords,
+        "lastsaveby" => &mut info.last_saved_by,
+        "CreatedDate" => &mut info.create_time,
+        "ModifiedDate" => &mut info.last_saved_time,
+        "date" => &mut info.date_string,
+        _ => return,
+    };
+    *field = Some(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metadata_xml() {
+        let xml = r#"<opf:package xmlns:opf="http://www.idpf.org/2007/opf/">
+            <opf:metadata>
+                <opf:title>분기 보고서</opf:title>
+                <opf:meta name="creator" content="text">홍길동</opf:meta>
+                <opf:meta name="subject" content="text"/>
+                <opf:meta name="CreatedDate" content="text">2011-08-05T17:00:06Z</opf:meta>
+                <opf:meta name="keyword" content="text">예산 &amp; 결산</opf:meta>
+            </opf:metadata>
+            <opf:manifest><opf:item id="x" href="Contents/header.xml"/></opf:manifest>
+        </opf:package>"#;
+
+        let info = parse_metadata_xml(xml, "Contents/content.hpf")
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.title.as_deref(), Some("분기 보고서"));
+        assert_eq!(info.author.as_deref(), Some("홍길동"));
+        assert_eq!(info.subject, None);
+        assert_eq!(info.create_time.as_deref(), Some("2011-08-05T17:00:06Z"));
+        assert_eq!(info.keywords.as_deref(), Some("예산 & 결산"));
+    }
+
+    #[test]
+    fn test_parse_metadata_xml_empty() {
+        let xml = r#"<opf:package><opf:metadata><opf:title/></opf:metadata></opf:package>"#;
+        assert_eq!(
+            parse_metadata_xml(xml, "Contents/content.hpf").unwrap(),
+            None
+        );
+    }
+}
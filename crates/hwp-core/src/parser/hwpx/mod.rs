@@ -12,17 +12,29 @@
 /// ├── Contents/
 /// │   ├── header.xml              # Document settings (styles, fonts)
 /// │   ├── content.hpf             # Section list (OPF format)
-/// │   └── section0.xml            # Body content
+/// │   ├── section0.xml            # Body content
+/// │   └── masterpage0.xml         # Master pages (page backgrounds, watermarks)
 /// ├── BinData/                    # Binary data (images, OLE)
 /// └── Preview/                    # Preview images
 /// ```
 pub mod bindata;
+pub mod borrowed;
+pub mod chart;
 pub mod container;
 pub mod header;
+pub mod integrity;
+pub mod master_page;
+pub mod metadata;
+pub mod namespace;
+pub mod repair;
 pub mod section;
+pub mod security;
 
-use crate::document::HwpDocument;
+use crate::document::{HwpDocument, SourceFormat};
 use crate::error::HwpError;
+use crate::parser::options::{ParseOptions, ParseWarning};
+use crate::parser::profile::{ParsePhase, Profiler};
+use std::io::{Read, Seek};
 
 use container::HwpxContainer;
 
@@ -43,27 +55,141 @@ use container::HwpxContainer;
 /// println!("Parsed {} sections", document.body_text.sections.len());
 /// ```
 pub fn parse(data: &[u8]) -> Result<HwpDocument, HwpError> {
+    parse_with_options(data, &ParseOptions::default()).map(|(document, _)| document)
+}
+
+/// Parse HWPX file with parse options
+///
+/// # Arguments
+/// * `data` - Byte array containing the HWPX file data (ZIP format)
+/// * `options` - Parse options
+///
+/// # Returns
+/// Parsed HWP document structure and warnings for repaired problems
+pub fn parse_with_options(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+    parse_container(options, || {
+        HwpxContainer::open_with_limits(data, options.limits)
+    })
+}
+
+/// Parse HWPX file from a reader with parse options
+///
+/// ZIP 항목을 필요할 때마다 리더에서 읽으므로 큰 파일도 통째로 메모리에 올리지 않습니다.
+/// ZIP entries are read from the reader as needed, so large files are never loaded into memory
+/// as a whole.
+///
+/// # Arguments
+/// * `reader` - Seekable reader over the HWPX file data (ZIP format)
+/// * `options` - Parse options
+///
+/// # Returns
+/// Parsed HWP document structure and warnings for repaired problems
+pub fn parse_reader_with_options(
+    reader: impl Read + Seek,
+    options: &ParseOptions,
+) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+    parse_container(options, || {
+        HwpxContainer::from_reader_with_limits(reader, options.limits)
+    })
+}
+
+/// 컨테이너를 열어 문서 파싱 / Open the container and parse the document
+fn parse_container<'a>(
+    options: &ParseOptions,
+    open: impl FnOnce() -> Result<HwpxContainer<'a>, HwpError>,
+) -> Result<(HwpDocument, Vec<ParseWarning>), HwpError> {
+    let mut warnings = Vec::new();
+    let mut profiler = Profiler::new(options.profiling);
+
     // Open the ZIP container
-    let mut container = HwpxContainer::open(data)?;
+    let mut container = profiler.time(ParsePhase::Container, open)?;
 
     // Verify mimetype (optional but recommended)
-    container.verify_mimetype()?;
+    if let Err(error) = profiler.time(ParsePhase::Container, || container.verify_mimetype()) {
+        if !options.lenient {
+            return Err(error);
+        }
+        warnings.push(ParseWarning {
+            part: "mimetype".to_string(),
+            element: None,
+            message: error.to_string(),
+        });
+    }
+
+    // DRM and password protected packages cannot be read, so report them before parsing
+    let security = profiler.time(ParsePhase::Container, || {
+        security::parse_security(&mut container)
+    });
+    security.ensure_readable()?;
 
     // Parse file header from version.xml
-    let file_header = header::parse_file_header(&mut container)?;
+    let file_header = profiler.time(ParsePhase::Header, || {
+        header::parse_file_header(&mut container)
+    })?;
 
     // Create document with file header
     let mut document = HwpDocument::new(file_header);
+    document.source_format = SourceFormat::Hwpx;
+    document.security = security;
 
     // Parse document info from header.xml
-    document.doc_info = header::parse_doc_info(&mut container)?;
+    // In lenient mode a broken header.xml falls back to default styles
+    document.doc_info = match profiler.time(ParsePhase::Header, || {
+        header::parse_doc_info_with_limits(&mut container, &options.limits)
+    }) {
+        Ok(doc_info) => doc_info,
+        Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+            return Err(error)
+        }
+        Err(error) if options.lenient => {
+            warnings.push(ParseWarning {
+                part: "Contents/header.xml".to_string(),
+                element: None,
+                message: error.to_string(),
+            });
+            Default::default()
+        }
+        Err(error) => return Err(error),
+    };
 
     // Parse body text from section files
-    document.body_text = section::parse_sections(&mut container)?;
+    document.body_text = profiler.time(ParsePhase::Sections, || {
+        section::parse_sections_with_options(&mut container, options, &mut warnings)
+    })?;
 
     // Parse binary data (images, etc.)
-    document.bin_data = bindata::parse_bindata(&mut container)?;
+    document.bin_data = profiler.time(ParsePhase::BinData, || {
+        bindata::parse_bindata(&mut container)
+    })?;
+
+    profiler.time(ParsePhase::Metadata, || {
+        parse_optional_parts(
+            &mut container,
+            &mut document,
+            options.lenient,
+            &mut warnings,
+        )
+    });
+
+    // Resolve display texts for compatibility
+    profiler.time(ParsePhase::DisplayText, || document.resolve_display_texts());
 
+    document.warnings = warnings.clone();
+    document.profile = profiler.finish();
+    Ok((document, warnings))
+}
+
+/// 미리보기와 메타데이터처럼 없어도 되는 파트 파싱 (실패해도 문서는 계속 읽음)
+/// Parse optional parts such as previews and metadata (failures never stop the document)
+pub(crate) fn parse_optional_parts(
+    container: &mut HwpxContainer,
+    document: &mut HwpDocument,
+    lenient: bool,
+    warnings: &mut Vec<ParseWarning>,
+) {
     // Parse preview text if available
     if container.file_exists("Preview/PrvText.txt") {
         if let Ok(text) = container.read_file_string("Preview/PrvText.txt") {
@@ -71,10 +197,63 @@ pub fn parse(data: &[u8]) -> Result<HwpDocument, HwpError> {
         }
     }
 
-    // Resolve display texts for compatibility
-    document.resolve_display_texts();
+    // Parse preview image (thumbnail) if available
+    if let Some(path) = container.list_files("Preview/PrvImage").into_iter().next() {
+        if let Ok(data) = container.read_file(&path) {
+            document.preview_image = crate::document::PreviewImage::parse(&data, None).ok();
+        }
+    }
+
+    // Parse document scripts (Scripts/headerScripts, Scripts/sourceScripts) if available
+    let header = container.read_file_string("Scripts/headerScripts").ok();
+    let source = container.read_file_string("Scripts/sourceScripts").ok();
+    if header.is_some() || source.is_some() {
+        document.scripts = Some(crate::document::Scripts {
+            version: None,
+            default_script: Some(crate::document::scripts::Script {
+                header: header.unwrap_or_default(),
+                source: source.unwrap_or_default(),
+                pre_source: String::new(),
+                post_source: String::new(),
+            }),
+        });
+    }
+
+    // 모델에 없는 파트(Scripts/, Custom/, DocHistory/ 등)는 원본 그대로 보관
+    // Keep parts the model does not cover (Scripts/, Custom/, DocHistory/, ...) as raw bytes
+    for path in container.list_files("") {
+        if is_modelled_part(&path, container.content_dir()) {
+            continue;
+        }
+        if let Ok(data) = container.read_file(&path) {
+            document.unparsed_parts.insert(path, data);
+        }
+    }
+
+    // Parse document metadata (title, author, dates) from content.hpf
+    // Metadata is optional; a malformed content.hpf must not fail the whole document
+    document.summary_information = match metadata::parse_metadata(container) {
+        Ok(info) => info,
+        Err(error) => {
+            if lenient {
+                warnings.push(ParseWarning {
+                    part: "Contents/content.hpf".to_string(),
+                    element: None,
+                    message: error.to_string(),
+                });
+            }
+            None
+        }
+    };
+}
 
-    Ok(document)
+/// 모델로 읽는 파트인지 (본문 폴더, BinData/, Preview/, 폴더 항목)
+/// Whether a part is read into the model (content folder, BinData/, Preview/, folder entries)
+fn is_modelled_part(path: &str, content_dir: &str) -> bool {
+    path.ends_with('/')
+        || (!content_dir.is_empty() && path.starts_with(content_dir))
+        || path.starts_with("BinData/")
+        || path.starts_with("Preview/")
 }
 
 #[cfg(test)]
@@ -0,0 +1,167 @@
+/// OWPML 네임스페이스 처리 / OWPML namespace handling
+///
+/// 요소 이름을 접두사 문자열이 아니라 (네임스페이스, 로컬 이름) 쌍으로 비교합니다. 그래서 `hp:` 대신
+/// 다른 접두사나 기본 네임스페이스(`xmlns="..."`)를 쓰는 파일도 같은 요소로 읽습니다.
+///
+/// Element names are compared as (namespace, local name) pairs rather than by prefix strings, so
+/// files using other prefixes than `hp:` or a default namespace (`xmlns="..."`) read the same.
+use quick_xml::name::{QName, ResolveResult};
+use quick_xml::NsReader;
+
+/// 한컴 OWPML 네임스페이스 URI의 앞부분 / Common start of Hancom OWPML namespace URIs
+const HWPML_URI_PREFIX: &[u8] = b"http://www.hancom.co.kr/hwpml/";
+
+/// OWPML 네임스페이스 / OWPML namespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    /// 문단 (`hp`, `hp10`) / Paragraph
+    Paragraph,
+    /// 구역 (`hs`) / Section
+    Section,
+    /// 머리 정보 (`hh`) / Head
+    Head,
+    /// 공통 (`hc`) / Core
+    Core,
+    /// 바탕쪽 (`hm`) / Master page
+    MasterPage,
+    /// 그 밖의 네임스페이스 / Any other namespace
+    Other,
+    /// 네임스페이스 없음 (접두사도 기본 네임스페이스도 없음) / No namespace (neither a prefix nor a default namespace)
+    Unqualified,
+}
+
+impl Namespace {
+    /// 네임스페이스 URI로 찾기 / Look up by namespace URI
+    ///
+    /// 연도가 다른 판(`2011`, `2016` 등)도 같은 네임스페이스로 봅니다.
+    /// Editions from different years (`2011`, `2016`, ...) map to the same namespace.
+    pub fn from_uri(uri: &[u8]) -> Self {
+        let Some(path) = uri.strip_prefix(HWPML_URI_PREFIX) else {
+            return Namespace::Other;
+        };
+        match path.rsplit(|&b| b == b'/').next() {
+            Some(b"paragraph") => Namespace::Paragraph,
+            Some(b"section") => Namespace::Section,
+            Some(b"head") => Namespace::Head,
+            Some(b"core") => Namespace::Core,
+            Some(b"master-page") => Namespace::MasterPage,
+            _ => Namespace::Other,
+        }
+    }
+
+    /// 선언되지 않은 관례 접두사로 찾기 / Look up by a conventional prefix that was never declared
+    ///
+    /// 네임스페이스 선언을 빠뜨린 파일을 위한 대체 경로입니다.
+    /// A fallback for files that omit their namespace declarations.
+    pub fn from_prefix(prefix: &[u8]) -> Self {
+        match prefix {
+            b"hp" | b"hp10" => Namespace::Paragraph,
+            b"hs" => Namespace::Section,
+            b"hh" => Namespace::Head,
+            b"hc" => Namespace::Core,
+            b"hm" => Namespace::MasterPage,
+            _ => Namespace::Other,
+        }
+    }
+}
+
+/// (네임스페이스, 로컬 이름)으로 푼 요소 이름 / Element name resolved into (namespace, local name)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementName<'a> {
+    /// 네임스페이스 / Namespace
+    pub namespace: Namespace,
+    /// 로컬 이름 / Local name
+    pub local_name: &'a str,
+}
+
+impl<'a> ElementName<'a> {
+    /// 읽는 중인 위치의 네임스페이스 범위로 요소 이름 풀기 / Resolve an element name in the reader's current namespace scope
+    pub fn resolve<R>(reader: &NsReader<R>, name: QName<'a>) -> Self {
+        let (resolved, local_name) = reader.resolve_element(name);
+        let namespace = match resolved {
+            ResolveResult::Bound(namespace) => Namespace::from_uri(namespace.as_ref()),
+            ResolveResult::Unknown(prefix) => Namespace::from_prefix(&prefix),
+            ResolveResult::Unbound => Namespace::Unqualified,
+        };
+        ElementName {
+            namespace,
+            local_name: std::str::from_utf8(local_name.into_inner()).unwrap_or_default(),
+        }
+    }
+
+    /// 네임스페이스와 로컬 이름이 맞는지 확인 / Whether the namespace and local name match
+    ///
+    /// 네임스페이스가 없는 요소는 예전 파일과 맞추려고 로컬 이름만 비교합니다.
+    /// Elements without a namespace compare by local name only, for older files.
+    pub fn is(self, namespace: Namespace, local_name: &str) -> bool {
+        self.local_in(namespace) == Some(local_name)
+    }
+
+    /// 네임스페이스가 맞으면 로컬 이름 / Local name when the namespace matches
+    ///
+    /// 네임스페이스가 없는 요소는 어느 네임스페이스와도 맞습니다.
+    /// Elements without a namespace match any namespace.
+    pub fn local_in(self, namespace: Namespace) -> Option<&'a str> {
+        (self.namespace == namespace || self.namespace == Namespace::Unqualified)
+            .then_some(self.local_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::events::Event;
+
+    fn names(xml: &str) -> Vec<(Namespace, String)> {
+        let mut reader = NsReader::from_str(xml);
+        let mut names = Vec::new();
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(e) | Event::Empty(e) => {
+                    let name = ElementName::resolve(&reader, e.name());
+                    names.push((name.namespace, name.local_name.to_string()));
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        names
+    }
+
+    #[test]
+    fn test_resolve_element_names() {
+        let xml = r#"<x:sec xmlns:x="http://www.hancom.co.kr/hwpml/2011/section">
+            <p xmlns="http://www.hancom.co.kr/hwpml/2016/paragraph"><t/></p>
+            <hc:img/><hp:t/><t/><o:t xmlns:o="urn:other"/></x:sec>"#;
+        assert_eq!(
+            names(xml),
+            vec![
+                (Namespace::Section, "sec".to_string()),
+                (Namespace::Paragraph, "p".to_string()),
+                (Namespace::Paragraph, "t".to_string()),
+                (Namespace::Core, "img".to_string()),
+                (Namespace::Paragraph, "t".to_string()),
+                (Namespace::Unqualified, "t".to_string()),
+                (Namespace::Other, "t".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_element_name_is() {
+        let name = |namespace, local_name| ElementName {
+            namespace,
+            local_name,
+        };
+        assert!(name(Namespace::Paragraph, "t").is(Namespace::Paragraph, "t"));
+        assert!(name(Namespace::Unqualified, "t").is(Namespace::Paragraph, "t"));
+        assert!(!name(Namespace::Head, "t").is(Namespace::Paragraph, "t"));
+        assert!(!name(Namespace::Other, "t").is(Namespace::Paragraph, "t"));
+        assert!(!name(Namespace::Paragraph, "tc").is(Namespace::Paragraph, "t"));
+        assert_eq!(
+            name(Namespace::Core, "img").local_in(Namespace::Core),
+            Some("img")
+        );
+        assert_eq!(name(Namespace::Core, "img").local_in(Namespace::Head), None);
+    }
+}
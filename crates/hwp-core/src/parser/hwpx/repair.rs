@@ -0,0 +1,144 @@
+/// XML 복구 패스 / XML repair pass
+///
+/// 일부 생성기가 만든 구역 XML에는 이스케이프되지 않은 `&`, 태그가 아닌 `<`,
+/// XML 1.0에서 허용되지 않는 제어 문자가 들어 있어 quick-xml 파싱이 중단되거나
+/// 텍스트가 사라집니다. 파싱 전에 이런 부분만 최소한으로 고칩니다.
+///
+/// Some generators emit section XML with unescaped `&`, `<` that does not start a tag, or
+/// control characters that XML 1.0 forbids, which aborts quick-xml or drops text.
+/// This pass minimally fixes just those spots before parsing.
+use std::borrow::Cow;
+
+/// 복구 결과 통계 / Repair statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairStats {
+    /// `&amp;`로 바꾼 `&` 개수 / Number of `&` escaped as `&amp;`
+    pub escaped_ampersands: usize,
+    /// `&lt;`로 바꾼 `<` 개수 / Number of `<` escaped as `&lt;`
+    pub escaped_less_than: usize,
+    /// 제거한 제어 문자 개수 / Number of removed control characters
+    pub removed_control_chars: usize,
+}
+
+impl RepairStats {
+    /// 고친 것이 없는지 확인 / Whether nothing was repaired
+    pub fn is_empty(&self) -> bool {
+        *self == RepairStats::default()
+    }
+
+    /// 경고 메시지 목록 / Warning messages
+    pub fn messages(&self) -> Vec<String> {
+        let mut messages = Vec::new();
+        if self.escaped_ampersands > 0 {
+            messages.push(format!(
+                "escaped {} unescaped '&' character(s)",
+                self.escaped_ampersands
+            ));
+        }
+        if self.escaped_less_than > 0 {
+            messages.push(format!(
+                "escaped {} stray '<' character(s)",
+                self.escaped_less_than
+            ));
+        }
+        if self.removed_control_chars > 0 {
+            messages.push(format!(
+                "removed {} invalid control character(s)",
+                self.removed_control_chars
+            ));
+        }
+        messages
+    }
+}
+
+/// XML 문자열 복구 / Repair an XML string
+///
+/// 고칠 것이 없으면 입력을 빌린 그대로 돌려줍니다.
+/// Returns the input borrowed when nothing needs fixing.
+pub fn repair_xml(content: &str) -> (Cow<'_, str>, RepairStats) {
+    let mut stats = RepairStats::default();
+    if !needs_repair(content) {
+        return (Cow::Borrowed(content), stats);
+    }
+
+    let mut out = String::with_capacity(content.len() + 16);
+    let mut rest = content;
+    while let Some(ch) = rest.chars().next() {
+        let after = &rest[ch.len_utf8()..];
+        match ch {
+            '&' if !starts_with_reference(after) => {
+                out.push_str("&amp;");
+                stats.escaped_ampersands += 1;
+            }
+            '<' if !starts_markup(after) => {
+                out.push_str("&lt;");
+                stats.escaped_less_than += 1;
+            }
+            c if is_invalid_xml_char(c) => stats.removed_control_chars += 1,
+            c => out.push(c),
+        }
+        rest = after;
+    }
+
+    (Cow::Owned(out), stats)
+}
+
+fn needs_repair(content: &str) -> bool {
+    content.char_indices().any(|(i, c)| {
+        let after = &content[i + c.len_utf8()..];
+        (c == '&' && !starts_with_reference(after))
+            || (c == '<' && !starts_markup(after))
+            || is_invalid_xml_char(c)
+    })
+}
+
+/// `&` 뒤가 올바른 엔티티/문자 참조인지 / Whether the text after `&` is a valid entity or character reference
+fn starts_with_reference(after: &str) -> bool {
+    let Some(end) = after.find(';') else {
+        return false;
+    };
+    let name = &after[..end];
+    if let Some(hex) = name.strip_prefix("#x") {
+        !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(dec) = name.strip_prefix('#') {
+        !dec.is_empty() && dec.chars().all(|c| c.is_ascii_digit())
+    } else {
+        let mut chars = name.chars();
+        matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_' || c == ':')
+            && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))
+    }
+}
+
+/// `<` 뒤가 태그/주석/선언의 시작인지 / Whether the text after `<` starts a tag, comment or declaration
+fn starts_markup(after: &str) -> bool {
+    matches!(after.chars().next(), Some(c) if c.is_alphabetic() || matches!(c, '_' | ':' | '/' | '!' | '?'))
+}
+
+/// XML 1.0에서 허용되지 않는 문자 / Character not allowed by XML 1.0
+fn is_invalid_xml_char(c: char) -> bool {
+    matches!(c, '\u{0}'..='\u{8}' | '\u{B}' | '\u{C}' | '\u{E}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_formed_xml_is_borrowed() {
+        let xml = "<hp:t>A &amp; B &#x41; &lt;</hp:t><!-- c --><?pi?>";
+        let (repaired, stats) = repair_xml(xml);
+        assert!(matches!(repaired, Cow::Borrowed(_)));
+        assert!(stats.is_empty());
+    }
+
+    #[test]
+    fn test_repairs_ampersands_brackets_and_control_chars() {
+        let xml = "<hp:t>R&D \u{1}a < b && c</hp:t>";
+        let (repaired, stats) = repair_xml(xml);
+        assert_eq!(repaired, "<hp:t>R&amp;D a &lt; b &amp;&amp; c</hp:t>");
+        assert_eq!(stats.escaped_ampersands, 3);
+        assert_eq!(stats.escaped_less_than, 1);
+        assert_eq!(stats.removed_control_chars, 1);
+        assert_eq!(stats.messages().len(), 3);
+    }
+}
@@ -2,9 +2,16 @@
 ///
 /// Section files (section0.xml, section1.xml, etc.) contain the main document content
 /// including paragraphs, tables, images, and other elements.
+use std::borrow::Cow;
+use std::cell::RefCell;
+
 use quick_xml::events::Event;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 
+use crate::document::bodytext::ctrl_header::{
+    HorzRelTo, ObjectAttribute, ObjectTextOption, ObjectTextPositionOption, VertRelTo,
+};
+use crate::document::bodytext::footnote_shape::FootnoteShapeAttributes;
 use crate::document::bodytext::list_header::{
     LineBreak, ListHeader, ListHeaderAttribute, TextDirection, VerticalAlign,
 };
@@ -13,62 +20,411 @@ use crate::document::bodytext::table::{
     CellAttributes, PageBreakBehavior, Table, TableAttribute, TableAttributes, TableCell,
     TablePadding,
 };
-use crate::document::bodytext::{ParaTextRun, Paragraph, ParagraphRecord, Section};
+use crate::document::bodytext::{
+    CharShapeInfo, ChartData, FootnoteShape, ImageCrop, ImageEffects, InlineControl, Margin,
+    MasterPage, NumberShape, NumberingMethod, ObjectPlacement, PagePosition, ParaTextRun,
+    Paragraph, ParagraphRecord, Section,
+};
+use crate::document::citations::CitationKind;
+use crate::document::docinfo::TrackChangeKind;
 use crate::document::BodyText;
-use crate::error::HwpError;
-use crate::types::{HWPUNIT, UINT16, WORD};
+use crate::error::{ErrorContext, HwpError};
+use crate::types::{COLORREF, HWPUNIT, SHWPUNIT, UINT16, WORD};
 
+use super::chart::parse_chart_xml;
 use super::container::HwpxContainer;
+use super::master_page::{master_page_path, parse_master_page_xml};
+use super::namespace::{ElementName, Namespace};
+use super::repair::repair_xml;
+use crate::parser::limits::DepthGuard;
+use crate::parser::options::{ParseOptions, ParseWarning};
 
-/// Content item type within a cell paragraph
-/// 셀 문단 내 콘텐츠 항목 유형
-#[derive(Debug, Clone)]
-enum CellContentItem {
-    Text(String),
-    Image(String),
-    NestedTable(Table),
+/// Picture properties collected inside <hp:pic>
+/// <hp:pic> 안에서 모은 그림 속성
+#[derive(Debug, Default)]
+struct HwpxPicture {
+    binary_item_ref: Option<String>,
+    /// 표시 크기 (HWPUNIT) / Display size (HWPUNIT)
+    width: u32,
+    height: u32,
+    /// <hp:imgClip> 남길 영역 (left, top, right, bottom) / Area kept by <hp:imgClip>
+    clip: Option<(i32, i32, i32, i32)>,
+    /// <hp:imgDim> 원본 크기 / Original size from <hp:imgDim>
+    dim: Option<(i32, i32)>,
+    effects: ImageEffects,
+    /// <hp:pic> textWrap, <hp:pos>, <hp:outMargin>에서 읽은 배치 / Placement from <hp:pic> textWrap, <hp:pos> and <hp:outMargin>
+    placement: Option<ObjectPlacement>,
+    /// <hp:shapeComment> 설명문 / Description from <hp:shapeComment>
+    description: String,
+    /// <hp:caption> 텍스트 / Text of <hp:caption>
+    caption: String,
 }
 
-/// Cell data with colspan/rowspan and address information
-#[derive(Debug, Clone)]
-struct HwpxCell {
-    /// 현재 문단의 텍스트 / Current paragraph text
-    current_text: String,
-    col_span: u16,
-    row_span: u16,
-    col_addr: Option<u16>,
-    row_addr: Option<u16>,
-    /// 셀 내부의 콘텐츠 항목 목록 (순서 보존) / List of content items inside the cell (order preserved)
-    content_items: Vec<CellContentItem>,
+impl HwpxPicture {
+    /// Apply an empty child element of <hp:pic>
+    /// <hp:pic>의 빈 자식 요소 적용
+    fn apply_element(&mut self, name: ElementName, e: &quick_xml::events::BytesStart) {
+        // <hc:img>만 공통 네임스페이스 / Only <hc:img> is in the core namespace
+        let namespace = if name.local_name == "img" {
+            Namespace::Core
+        } else {
+            Namespace::Paragraph
+        };
+        let Some(local_name) = name.local_in(namespace) else {
+            return;
+        };
+        let attrs: Vec<(Vec<u8>, String)> = e
+            .attributes()
+            .flatten()
+            .map(|attr| {
+                (
+                    attr.key.as_ref().to_vec(),
+                    String::from_utf8_lossy(&attr.value).to_string(),
+                )
+            })
+            .collect();
+        let get = |key: &[u8]| {
+            attrs
+                .iter()
+                .find(|(k, _)| k.as_slice() == key)
+                .map(|(_, v)| v.as_str())
+        };
+        let int = |key: &[u8]| get(key).and_then(|v| v.parse::<i32>().ok());
+
+        match local_name {
+            "img" => {
+                // <hc:img binaryItemIDRef="image1" bright="0" contrast="0" effect="REAL_PIC" alpha="0"/>
+                self.binary_item_ref = get(b"binaryItemIDRef").map(|v| v.to_string());
+                self.effects.brightness = int(b"bright").unwrap_or(0).clamp(-100, 100) as i8;
+                self.effects.contrast = int(b"contrast").unwrap_or(0).clamp(-100, 100) as i8;
+                self.effects.effect = match get(b"effect") {
+                    Some("GRAY_SCALE") => 1,
+                    Some("BLACK_WHITE") => 2,
+                    Some("PATTERN8x8") => 4,
+                    _ => 0,
+                };
+                self.effects.alpha = int(b"alpha").unwrap_or(0).clamp(0, 255) as u8;
+            }
+            "imgClip" => {
+                if let (Some(left), Some(top), Some(right), Some(bottom)) =
+                    (int(b"left"), int(b"top"), int(b"right"), int(b"bottom"))
+                {
+                    self.clip = Some((left, top, right, bottom));
+                }
+            }
+            "imgDim" => {
+                if let (Some(width), Some(height)) = (int(b"dimwidth"), int(b"dimheight")) {
+                    self.dim = Some((width, height));
+                }
+            }
+            "sz" => {
+                self.width = int(b"width").unwrap_or(0).max(0) as u32;
+                self.height = int(b"height").unwrap_or(0).max(0) as u32;
+            }
+            "curSz" if self.width == 0 && self.height == 0 => {
+                self.width = int(b"width").unwrap_or(0).max(0) as u32;
+                self.height = int(b"height").unwrap_or(0).max(0) as u32;
+            }
+            "pic" => {
+                // <hp:pic zOrder="1" textWrap="SQUARE" textFlow="BOTH_SIDES">
+                let placement = self.placement_mut();
+                placement.z_order = int(b"zOrder").unwrap_or(0);
+                let attribute = &mut placement.attribute;
+                attribute.object_text_option = match get(b"textWrap") {
+                    Some("TIGHT") => ObjectTextOption::Tight,
+                    Some("THROUGH") => ObjectTextOption::Through,
+                    Some("TOP_AND_BOTTOM") => ObjectTextOption::TopAndBottom,
+                    Some("BEHIND_TEXT") => ObjectTextOption::BehindText,
+                    Some("IN_FRONT_OF_TEXT") => ObjectTextOption::InFrontOfText,
+                    _ => ObjectTextOption::Square,
+                };
+                attribute.object_text_position_option = match get(b"textFlow") {
+                    Some("LEFT_ONLY") => ObjectTextPositionOption::LeftOnly,
+                    Some("RIGHT_ONLY") => ObjectTextPositionOption::RightOnly,
+                    Some("LARGEST_ONLY") => ObjectTextPositionOption::LargestOnly,
+                    _ => ObjectTextPositionOption::BothSides,
+                };
+            }
+            "pos" => {
+                // <hp:pos treatAsChar="0" affectLSpacing="0" vertRelTo="PARA" horzRelTo="COLUMN"
+                //   vertAlign="TOP" horzAlign="LEFT" vertOffset="0" horzOffset="0"/>
+                let flag = |key: &[u8]| matches!(get(key), Some("1") | Some("true"));
+                let placement = self.placement_mut();
+                placement.offset_x = SHWPUNIT(int(b"horzOffset").unwrap_or(0));
+                placement.offset_y = SHWPUNIT(int(b"vertOffset").unwrap_or(0));
+                let attribute = &mut placement.attribute;
+                attribute.like_letters = flag(b"treatAsChar");
+                attribute.affect_line_spacing = flag(b"affectLSpacing");
+                attribute.vert_rel_to_para_limit = flag(b"flowWithText");
+                attribute.overlap = flag(b"allowOverlap");
+                attribute.vert_rel_to = match get(b"vertRelTo") {
+                    Some("PAPER") => VertRelTo::Paper,
+                    Some("PAGE") => VertRelTo::Page,
+                    _ => VertRelTo::Para,
+                };
+                attribute.horz_rel_to = match get(b"horzRelTo") {
+                    Some("PAPER") => HorzRelTo::Paper,
+                    Some("PAGE") => HorzRelTo::Page,
+                    Some("PARA") => HorzRelTo::Para,
+                    _ => HorzRelTo::Column,
+                };
+                // 표 70과 같은 값: 0=위/왼쪽, 1=가운데, 2=아래/오른쪽 / Same values as Table 70: 0=top/left, 1=center, 2=bottom/right
+                let relative = |key: &[u8]| match get(key) {
+                    Some("CENTER") => 1,
+                    Some("BOTTOM") | Some("RIGHT") => 2,
+                    Some("INSIDE") => 3,
+                    Some("OUTSIDE") => 4,
+                    _ => 0,
+                };
+                attribute.vert_relative = relative(b"vertAlign");
+                attribute.horz_relative = relative(b"horzAlign");
+            }
+            "outMargin" => {
+                let padding = parse_margin(e);
+                self.placement_mut().margin = Margin {
+                    top: padding.top,
+                    right: padding.right,
+                    bottom: padding.bottom,
+                    left: padding.left,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// 배치 정보 (없으면 기본값으로 생성) / Placement, created with defaults when missing
+    fn placement_mut(&mut self) -> &mut ObjectPlacement {
+        self.placement.get_or_insert_with(|| ObjectPlacement {
+            attribute: ObjectAttribute::default(),
+            offset_x: SHWPUNIT(0),
+            offset_y: SHWPUNIT(0),
+            z_order: 0,
+            margin: Margin {
+                top: 0,
+                right: 0,
+                bottom: 0,
+                left: 0,
+            },
+        })
+    }
 }
 
-/// Saved table state for nested table handling
-/// 중첩 테이블 처리를 위해 저장되는 부모 테이블 상태
+/// Memo collected from <hp:fieldBegin type="MEMO">
+/// <hp:fieldBegin type="MEMO">에서 모은 메모
+#[derive(Debug, Default)]
+struct HwpxMemo {
+    /// fieldEnd의 beginIDRef와 맞출 ID / ID matched against fieldEnd's beginIDRef
+    field_id: Option<String>,
+    author: Option<String>,
+    date: Option<String>,
+    text: String,
+    quoted: String,
+}
+
+impl HwpxMemo {
+    fn into_record(self) -> ParagraphRecord {
+        ParagraphRecord::HwpxMemo {
+            author: self.author,
+            date: self.date,
+            text: self.text.trim().to_string(),
+            quoted: self.quoted,
+        }
+    }
+}
+
+/// Field other than a memo, from <hp:fieldBegin> to <hp:fieldEnd>
+/// <hp:fieldBegin>부터 <hp:fieldEnd>까지 모은 메모 외의 필드
+#[derive(Debug)]
+struct HwpxField {
+    /// fieldEnd의 beginIDRef와 맞출 ID / ID matched against fieldEnd's beginIDRef
+    field_id: Option<String>,
+    field_type: String,
+    name: Option<String>,
+    command: Option<String>,
+    text: String,
+    /// 문단 텍스트에서 필드가 시작하는 문자 위치 / Character offset where the field starts in the paragraph text
+    offset: usize,
+}
+
+impl HwpxField {
+    fn into_record(self) -> ParagraphRecord {
+        let kind = match self.field_type.as_str() {
+            "CITATION" => CitationKind::Citation,
+            "CROSSREF" => CitationKind::CrossReference,
+            _ => {
+                return ParagraphRecord::HwpxField {
+                    field_type: self.field_type,
+                    name: self.name,
+                    command: self.command,
+                    text: self.text,
+                    offset: self.offset,
+                }
+            }
+        };
+        ParagraphRecord::HwpxCitation {
+            kind,
+            name: self.name,
+            command: self.command,
+            text: self.text,
+        }
+    }
+}
+
+/// Open tracked change span between <hp:insertBegin>/<hp:deleteBegin> and its end marker
+/// <hp:insertBegin>/<hp:deleteBegin>과 끝 표시 사이의 열린 변경 추적 구간
+#[derive(Debug)]
+struct HwpxTrackSpan {
+    id: Option<String>,
+    kind: TrackChangeKind,
+    change_id: u32,
+    text: String,
+}
+
+/// Table-level properties from <hp:tbl> and its <hp:inMargin>
+/// <hp:tbl> 및 <hp:inMargin>에서 읽은 표 속성
 #[derive(Debug, Clone)]
-struct TableState {
-    table_rows: Vec<Vec<HwpxCell>>,
-    current_row: Vec<HwpxCell>,
-    current_cell: HwpxCell,
-    table_caption: String,
-    in_cell: bool,
+struct HwpxTableProps {
+    cell_spacing: i16,
+    border_fill_id: u16,
+    padding: TablePadding,
+    page_break: PageBreakBehavior,
+    header_row_repeat: bool,
 }
 
-impl Default for HwpxCell {
+impl Default for HwpxTableProps {
     fn default() -> Self {
         Self {
-            current_text: String::new(),
+            cell_spacing: 0,
+            border_fill_id: 0,
+            padding: zero_padding(),
+            page_break: PageBreakBehavior::NoBreak,
+            header_row_repeat: false,
+        }
+    }
+}
+
+/// 셀 크기를 알 수 없을 때 사용하는 기본 너비 (HWPUNIT) / Fallback cell width when size is unknown (HWPUNIT)
+const DEFAULT_CELL_WIDTH: u32 = 5000;
+/// 셀 크기를 알 수 없을 때 사용하는 기본 높이 (HWPUNIT) / Fallback cell height when size is unknown (HWPUNIT)
+const DEFAULT_CELL_HEIGHT: u32 = 1000;
+/// hp:cellAddr가 없는 셀의 주소 표시 (행 순서로 계산) / Marks a cell address missing hp:cellAddr (computed from row order)
+const UNSET_ADDRESS: u16 = u16::MAX;
+
+/// Create an empty table cell with HWPX defaults
+/// HWPX 기본값으로 빈 표 셀 생성
+fn new_table_cell() -> TableCell {
+    TableCell {
+        list_header: ListHeader {
+            paragraph_count: 0,
+            attribute: ListHeaderAttribute {
+                text_direction: TextDirection::Horizontal,
+                line_break: LineBreak::Normal,
+                vertical_align: VerticalAlign::Top,
+            },
+        },
+        cell_attributes: CellAttributes {
+            col_address: UNSET_ADDRESS,
+            row_address: UNSET_ADDRESS,
             col_span: 1,
             row_span: 1,
-            col_addr: None,
-            row_addr: None,
-            content_items: Vec::new(),
+            width: HWPUNIT(DEFAULT_CELL_WIDTH),
+            height: HWPUNIT(DEFAULT_CELL_HEIGHT),
+            left_margin: 0,
+            right_margin: 0,
+            top_margin: 0,
+            bottom_margin: 0,
+            border_fill_id: 0,
+        },
+        paragraphs: Vec::new(),
+    }
+}
+
+/// Apply <hp:subList> attributes to the cell list header
+/// <hp:subList> 속성을 셀 리스트 헤더에 적용
+fn apply_sub_list_attributes(e: &quick_xml::events::BytesStart, header: &mut ListHeader) {
+    for attr in e.attributes().flatten() {
+        let value = String::from_utf8_lossy(&attr.value);
+        match attr.key.as_ref() {
+            b"textDirection" => {
+                header.attribute.text_direction = match value.as_ref() {
+                    "VERTICAL" => TextDirection::Vertical,
+                    "VERTICALALL" => TextDirection::VerticalUpright,
+                    _ => TextDirection::Horizontal,
+                }
+            }
+            b"lineWrap" => {
+                header.attribute.line_break = match value.as_ref() {
+                    "SQUEEZE" => LineBreak::MaintainOneLine,
+                    "KEEP" => LineBreak::ExpandByContent,
+                    _ => LineBreak::Normal,
+                }
+            }
+            b"vertAlign" => {
+                header.attribute.vertical_align = match value.as_ref() {
+                    "CENTER" => VerticalAlign::Center,
+                    "BOTTOM" => VerticalAlign::Bottom,
+                    _ => VerticalAlign::Top,
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn zero_padding() -> TablePadding {
+    TablePadding {
+        left: 0,
+        right: 0,
+        top: 0,
+        bottom: 0,
+    }
+}
+
+/// Parse left/right/top/bottom margin attributes (hp:cellMargin, hp:inMargin)
+/// 여백 속성 파싱 (hp:cellMargin, hp:inMargin)
+fn parse_margin(e: &quick_xml::events::BytesStart) -> TablePadding {
+    let mut padding = zero_padding();
+    for attr in e.attributes().flatten() {
+        let value = String::from_utf8_lossy(&attr.value);
+        let value: i16 = value.parse::<u32>().unwrap_or(0).min(i16::MAX as u32) as i16;
+        match attr.key.as_ref() {
+            b"left" => padding.left = value,
+            b"right" => padding.right = value,
+            b"top" => padding.top = value,
+            b"bottom" => padding.bottom = value,
+            _ => {}
         }
     }
+    padding
+}
+
+/// Saved table state for nested table handling
+/// 중첩 테이블 처리를 위해 저장되는 부모 테이블 상태
+#[derive(Debug, Clone)]
+struct TableState {
+    table_rows: Vec<Vec<TableCell>>,
+    current_row: Vec<TableCell>,
+    current_cell: TableCell,
+    current_cell_text: String,
+    cell_char_shapes: Vec<CharShapeInfo>,
+    table_caption: String,
+    table_props: HwpxTableProps,
+    in_cell: bool,
 }
 
 /// Parse all section files and create BodyText
 pub fn parse_sections(container: &mut HwpxContainer) -> Result<BodyText, HwpError> {
-    let section_files = container.get_section_files();
+    parse_sections_with_options(container, &ParseOptions::default(), &mut Vec::new())
+}
+
+/// Parse all section files with parse options, collecting warnings
+/// 파싱 옵션을 적용하여 모든 구역 파일 파싱 (경고 수집)
+pub fn parse_sections_with_options(
+    container: &mut HwpxContainer,
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<BodyText, HwpError> {
+    let section_files = container.section_order();
 
     if section_files.is_empty() {
         return Err(HwpError::InvalidHwpxStructure {
@@ -79,17 +435,231 @@ pub fn parse_sections(container: &mut HwpxContainer) -> Result<BodyText, HwpErro
     let mut sections = Vec::new();
 
     for (index, section_path) in section_files.iter().enumerate() {
-        let content = container.read_file_string(section_path)?;
-        let section = parse_section_xml(&content, index as WORD)?;
-        sections.push(section);
+        let content = match container.read_file_string(section_path) {
+            Ok(content) => content,
+            Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+                return Err(error)
+            }
+            Err(error) if options.lenient => {
+                // 읽을 수 없는 구역은 빈 구역으로 남김 / Leave unreadable sections empty
+                warnings.push(ParseWarning {
+                    part: section_path.clone(),
+                    element: None,
+                    message: error.to_string(),
+                });
+                sections.push(Section {
+                    index: index as WORD,
+                    paragraphs: Vec::new(),
+                });
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        sections.push(parse_section_content(
+            container,
+            index,
+            section_path,
+            content,
+            options,
+            warnings,
+        )?);
     }
 
     Ok(BodyText { sections })
 }
 
-/// Parse a single section XML file
+/// 읽어 둔 구역 XML 파싱 (XML 복구, 차트 불러오기 포함)
+/// Parse already-read section XML, including XML repair and chart loading
+pub(crate) fn parse_section_content(
+    container: &mut HwpxContainer,
+    index: usize,
+    section_path: &str,
+    content: String,
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Section, HwpError> {
+    let max_depth = options.limits.max_xml_depth;
+    // 읽을 수 없는 차트는 건너뜀 (본문 파싱에는 영향 없음) / Unreadable charts are skipped without failing the section
+    let container = RefCell::new(container);
+    let mut load_chart = |path: &str| {
+        let content = container.borrow_mut().read_file_string(path).ok()?;
+        parse_chart_xml(&content, max_depth).ok()
+    };
+    // 읽을 수 없는 바탕쪽도 건너뜀 / Unreadable master pages are skipped as well
+    let mut load_master_page = |id_ref: &str| {
+        let mut container = container.borrow_mut();
+        // 매니페스트에 없으면 이름 관례를 따름 / Fall back to the naming convention when not in the manifest
+        let path = container
+            .manifest_path(id_ref)
+            .unwrap_or_else(|| master_page_path(container.content_dir(), id_ref));
+        let content = container.read_file_string(&path).ok()?;
+        parse_master_page_xml(&content, max_depth).ok().flatten()
+    };
+    parse_section_str(
+        index,
+        section_path,
+        content,
+        options,
+        warnings,
+        &mut load_chart,
+        &mut load_master_page,
+    )
+}
+
+/// 구역 XML 문자열 파싱 (XML 복구, 관대한 모드 포함)
+/// Parse a section XML string, including XML repair and lenient mode
+///
+/// `load_chart`는 `<hp:chart>`의 `chartIDRef` 경로로 차트 데이터를 읽어 옵니다.
+/// `load_chart` reads chart data from the `chartIDRef` path of `<hp:chart>`.
+pub(crate) fn parse_section_str(
+    index: usize,
+    section_path: &str,
+    content: String,
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+    load_chart: &mut dyn FnMut(&str) -> Option<ChartData>,
+    load_master_page: &mut dyn FnMut(&str) -> Option<MasterPage>,
+) -> Result<Section, HwpError> {
+    let max_depth = options.limits.max_xml_depth;
+    let content = if options.repair_xml {
+        let (repaired, stats) = repair_xml(&content);
+        warnings.extend(stats.messages().into_iter().map(|message| ParseWarning {
+            part: section_path.to_string(),
+            element: None,
+            message,
+        }));
+        repaired.into_owned()
+    } else {
+        content
+    };
+    let result = if options.lenient {
+        let mut recovery = Recovery::new(section_path, warnings);
+        parse_section_xml_with(
+            &content,
+            index as WORD,
+            max_depth,
+            load_chart,
+            load_master_page,
+            Some(&mut recovery),
+        )
+    } else {
+        parse_section_xml_with(
+            &content,
+            index as WORD,
+            max_depth,
+            load_chart,
+            load_master_page,
+            None,
+        )
+    };
+    result.map_err(|error| error.with_context(ErrorContext::in_container(section_path)))
+}
+
+/// Error recovery state for lenient parsing
+/// 관대한 파싱의 오류 복구 상태
+///
+/// XML 오류를 경고로 기록하고, 오류 위치를 알 수 있도록 열린 요소를 추적합니다.
+/// Records XML errors as warnings and tracks open elements so each warning names where it happened.
+struct Recovery<'a> {
+    part: &'a str,
+    warnings: &'a mut Vec<ParseWarning>,
+    open_elements: Vec<String>,
+    /// 마지막 오류의 바이트 위치 (같은 위치에서 반복되면 중단) / Byte position of the last error (stop if it repeats)
+    last_error_position: Option<u64>,
+}
+
+impl<'a> Recovery<'a> {
+    fn new(part: &'a str, warnings: &'a mut Vec<ParseWarning>) -> Self {
+        Self {
+            part,
+            warnings,
+            open_elements: Vec::new(),
+            last_error_position: None,
+        }
+    }
+
+    fn warn(&mut self, message: String) {
+        self.warnings.push(ParseWarning {
+            part: self.part.to_string(),
+            element: self.open_elements.last().cloned(),
+            message,
+        });
+    }
+
+    /// XML 오류 기록. 더 진행할 수 없으면 false / Record an XML error; false if parsing cannot advance
+    fn xml_error(&mut self, error: &quick_xml::Error, position: u64) -> bool {
+        self.warn(format!("{error} (byte {position})"));
+        // 잘못 닫힌 태그도 리더는 요소를 닫은 것으로 처리 / The reader still closes the element on a mismatched end tag
+        if is_mismatched_end(error) {
+            self.open_elements.pop();
+        }
+        let advanced = self.last_error_position != Some(position);
+        self.last_error_position = Some(position);
+        advanced
+    }
+}
+
+fn is_mismatched_end(error: &quick_xml::Error) -> bool {
+    matches!(
+        error,
+        quick_xml::Error::IllFormed(quick_xml::errors::IllFormedError::MismatchedEndTag { .. })
+    )
+}
+
+/// Decode text content; in lenient mode bad escapes keep the raw text
+/// 텍스트 내용 디코딩 (관대한 모드에서는 잘못된 이스케이프가 있으면 원문 유지)
+fn event_text(e: &quick_xml::events::BytesText, recovery: &mut Option<&mut Recovery>) -> String {
+    match e.unescape() {
+        Ok(text) => text.into_owned(),
+        Err(error) => match recovery {
+            Some(recovery) => {
+                recovery.warn(format!("Invalid text escape: {error}"));
+                String::from_utf8_lossy(e).into_owned()
+            }
+            None => String::new(),
+        },
+    }
+}
+
+/// Parse a single section XML file with the default limits
+#[cfg(test)]
 fn parse_section_xml(content: &str, index: WORD) -> Result<Section, HwpError> {
-    let mut reader = Reader::from_str(content);
+    parse_section_xml_with(
+        content,
+        index,
+        crate::parser::limits::ResourceLimits::default().max_xml_depth,
+        &mut |_| None,
+        &mut |_| None,
+        None,
+    )
+}
+
+/// Parse the paragraphs of a part laid out like a section (e.g. a master page)
+/// 구역처럼 문단을 담은 파트(바탕쪽 등)의 문단 파싱
+pub(super) fn parse_part_paragraphs(
+    content: &str,
+    max_depth: usize,
+) -> Result<Vec<Paragraph>, HwpError> {
+    parse_section_xml_with(content, 0, max_depth, &mut |_| None, &mut |_| None, None)
+        .map(|section| section.paragraphs)
+}
+
+/// Parse a single section XML file, recovering from errors when `recovery` is given
+/// 구역 XML 파싱 (`recovery`가 있으면 오류를 경고로 남기고 계속 진행)
+///
+/// `load_chart`는 `<hp:chart>`의 `chartIDRef` 경로로 차트 데이터를, `load_master_page`는
+/// `<hp:masterPage>`의 `idRef`로 바탕쪽을 읽어 옵니다.
+/// `load_chart` reads chart data from the `chartIDRef` path of `<hp:chart>`, and
+/// `load_master_page` reads a master page from the `idRef` of `<hp:masterPage>`.
+fn parse_section_xml_with(
+    content: &str,
+    index: WORD,
+    max_depth: usize,
+    load_chart: &mut dyn FnMut(&str) -> Option<ChartData>,
+    load_master_page: &mut dyn FnMut(&str) -> Option<MasterPage>,
+    mut recovery: Option<&mut Recovery>,
+) -> Result<Section, HwpError> {
+    let mut reader = NsReader::from_str(content);
     reader.config_mut().trim_text(true);
 
     let mut paragraphs = Vec::new();
@@ -97,223 +667,489 @@ fn parse_section_xml(content: &str, index: WORD) -> Result<Section, HwpError> {
     let mut in_text = false;
     let mut in_cell = false;
     let mut in_caption = false;
-    let mut _in_picture = false;
 
     // Image parsing
-    let mut current_image_ref: Option<String> = None;
+    let mut current_picture: Option<HwpxPicture> = None;
+    let mut in_shape_comment = false;
+
+    // Memo and tracked change parsing
+    // 메모 내용(<hp:fieldBegin type="MEMO"> 내부)은 본문에 넣지 않고 따로 모음
+    // Memo contents (inside <hp:fieldBegin type="MEMO">) are collected separately from the body
+    let mut memo_body: Option<HwpxMemo> = None;
+    let mut field_param: Option<String> = None;
+    let mut open_memos: Vec<HwpxMemo> = Vec::new();
+    // 매개변수를 읽는 중인 필드와 fieldEnd를 기다리는 필드 (인용, 날짜, 요약 정보 등)
+    // Field whose parameters are being read, and fields awaiting their fieldEnd (citation, date, summary, ...)
+    let mut field_begin: Option<HwpxField> = None;
+    let mut open_fields: Vec<HwpxField> = Vec::new();
+    let mut open_changes: Vec<HwpxTrackSpan> = Vec::new();
+    // 숨은 설명(<hp:hiddenComment>) 내용도 본문에 넣지 않고 따로 모음
+    // Hidden comment contents (<hp:hiddenComment>) are also kept out of the body
+    let mut hidden_comment: Option<String> = None;
+    // 다음 문단에 붙일 메모/변경 추적 레코드 / Memo/tracked change records for the next paragraph
+    let mut pending_annotations: Vec<ParagraphRecord> = Vec::new();
+    // 구역 정의(<hp:secPr>)의 각주/미주 모양과 바탕쪽, 첫 문단 앞에 둠
+    // Footnote/endnote shapes and master pages of the section definition (<hp:secPr>), placed before the first paragraph
+    let mut note_shape: Option<FootnoteShape> = None;
+    let mut definition_records: Vec<ParagraphRecord> = Vec::new();
 
     // Table parsing with colspan/rowspan support
-    let mut table_rows: Vec<Vec<HwpxCell>> = Vec::new();
-    let mut current_row: Vec<HwpxCell> = Vec::new();
-    let mut current_cell = HwpxCell::default();
+    let mut table_rows: Vec<Vec<TableCell>> = Vec::new();
+    let mut current_row: Vec<TableCell> = Vec::new();
+    let mut current_cell = new_table_cell();
+    // 셀 안의 현재 문단 텍스트 / Text of the current paragraph inside the cell
+    let mut current_cell_text = String::new();
+    // <hp:run charPrIDRef>로 바뀌는 글자 모양 (본문/셀 문단) / Char shape changes from <hp:run charPrIDRef> (body/cell paragraph)
+    let mut text_char_shapes: Vec<CharShapeInfo> = Vec::new();
+    let mut cell_char_shapes: Vec<CharShapeInfo> = Vec::new();
     let mut table_caption = String::new();
+    let mut table_props = HwpxTableProps::default();
 
     // Track nesting depth for paragraphs and tables
     // 문단과 테이블의 중첩 깊이 추적
     let mut para_depth: u32 = 0;
     let mut table_depth: u32 = 0;
+    // 열린 <hp:p>마다 (paraPrIDRef, styleIDRef) (문단 모양 ID, 스타일 ID)
+    // (paraPrIDRef, styleIDRef) (paragraph shape ID, style ID) of each open <hp:p>
+    let mut para_shape_ids: Vec<(u16, u8)> = Vec::new();
 
     // Stack to save parent table state when entering nested table
     // 중첩 테이블에 진입할 때 부모 테이블 상태를 저장하는 스택
     let mut table_state_stack: Vec<TableState> = Vec::new();
+    let mut xml_depth = DepthGuard::new(max_depth);
 
     loop {
         match reader.read_event() {
             Ok(Event::Empty(ref e)) => {
-                // Handle self-closing tags like <hp:cellSpan ... />, <hp:cellAddr ... />, <hp:tab ... />
-                let name = e.name();
-                let local_name = String::from_utf8_lossy(name.as_ref());
-
-                if local_name.ends_with(":tab") || local_name == "tab" {
-                    // Parse tab element and convert to appropriate text representation
-                    // Tab attributes: width (HWPUNIT), leader (0=none, 1=solid, 2=dash, 3=dot), type
-                    let mut leader: u8 = 0;
-                    let mut width: u32 = 0;
-
-                    for attr in e.attributes().flatten() {
-                        let key = String::from_utf8_lossy(attr.key.as_ref());
-                        let value = String::from_utf8_lossy(&attr.value);
-                        match key.as_ref() {
-                            "leader" => {
-                                leader = value.parse().unwrap_or(0);
-                            }
-                            "width" => {
-                                width = value.parse().unwrap_or(0);
-                            }
-                            _ => {}
-                        }
-                    }
-
-                    // Generate tab representation based on leader type
-                    // Leader: 0=none, 1=solid, 2=dash, 3=dot
-                    let tab_text = match leader {
-                        3 => {
-                            // Dot leader - generate dots based on approximate width
-                            // HWPUNIT: 7200 units = 1 inch, roughly 6 chars per inch
-                            let dot_count = (width / 1200).min(80).max(3) as usize;
-                            ".".repeat(dot_count)
-                        }
-                        2 => {
-                            // Dash leader
-                            let dash_count = (width / 2400).min(40).max(2) as usize;
-                            "-".repeat(dash_count)
-                        }
-                        1 => {
-                            // Solid line leader
-                            let line_count = (width / 2400).min(40).max(2) as usize;
-                            "_".repeat(line_count)
-                        }
-                        _ => {
-                            // No leader - use tab character or spaces
-                            "\t".to_string()
-                        }
-                    };
+                // Handle self-closing tags like <hp:cellSpan ... />, <hp:cellAddr ... />, <hp:tab ... />, <hp:nbSpace/>
+                let name = ElementName::resolve(&reader, e.name());
 
-                    // Add tab representation to current text context
+                if let Some(control_text) = inline_control_text(name, e)
+                    .filter(|_| memo_body.is_none() && hidden_comment.is_none())
+                {
+                    // Add tab, hyphen or space representation to current text context
                     let in_table = table_depth > 0;
-                    if in_table && in_caption {
-                        table_caption.push_str(&tab_text);
+                    if let Some(picture) = current_picture.as_mut().filter(|_| in_caption) {
+                        picture.caption.push_str(&control_text);
+                    } else if in_table && in_caption {
+                        table_caption.push_str(&control_text);
                     } else if in_table && in_cell {
-                        current_cell.current_text.push_str(&tab_text);
+                        current_cell_text.push_str(&control_text);
                     } else if !in_table {
-                        current_text.push_str(&tab_text);
+                        current_text.push_str(&control_text);
                     }
-                } else if local_name.ends_with(":cellSpan") || local_name == "cellSpan" {
+                } else if let Some(shape) = note_shape.as_mut() {
+                    apply_note_shape_element(shape, name, e);
+                } else if name.is(Namespace::Paragraph, "cellSpan") {
                     // Parse colspan and rowspan attributes
                     for attr in e.attributes().flatten() {
                         let key = String::from_utf8_lossy(attr.key.as_ref());
                         let value = String::from_utf8_lossy(&attr.value);
                         match key.as_ref() {
                             "colSpan" => {
-                                current_cell.col_span = value.parse().unwrap_or(1);
+                                current_cell.cell_attributes.col_span = value.parse().unwrap_or(1);
                             }
                             "rowSpan" => {
-                                current_cell.row_span = value.parse().unwrap_or(1);
+                                current_cell.cell_attributes.row_span = value.parse().unwrap_or(1);
                             }
                             _ => {}
                         }
                     }
-                } else if local_name.ends_with(":cellAddr") || local_name == "cellAddr" {
+                } else if name.is(Namespace::Paragraph, "cellAddr") {
                     // Parse cell address (actual column and row position)
                     for attr in e.attributes().flatten() {
                         let key = String::from_utf8_lossy(attr.key.as_ref());
                         let value = String::from_utf8_lossy(&attr.value);
                         match key.as_ref() {
                             "colAddr" => {
-                                current_cell.col_addr = Some(value.parse().unwrap_or(0));
+                                current_cell.cell_attributes.col_address =
+                                    value.parse().unwrap_or(0);
                             }
                             "rowAddr" => {
-                                current_cell.row_addr = Some(value.parse().unwrap_or(0));
+                                current_cell.cell_attributes.row_address =
+                                    value.parse().unwrap_or(0);
                             }
                             _ => {}
                         }
                     }
-                } else if local_name.ends_with(":img") || local_name == "img" {
-                    // Parse image element - extract binaryItemIDRef
-                    // <hc:img binaryItemIDRef="image1" bright="0" contrast="0" effect="REAL_PIC" alpha="0"/>
+                } else if name.is(Namespace::Paragraph, "cellSz") {
+                    // Parse actual cell size (HWPUNIT)
                     for attr in e.attributes().flatten() {
-                        let key = String::from_utf8_lossy(attr.key.as_ref());
                         let value = String::from_utf8_lossy(&attr.value);
-                        if key == "binaryItemIDRef" {
-                            current_image_ref = Some(value.to_string());
+                        match attr.key.as_ref() {
+                            b"width" => {
+                                current_cell.cell_attributes.width =
+                                    HWPUNIT(value.parse().unwrap_or(DEFAULT_CELL_WIDTH))
+                            }
+                            b"height" => {
+                                current_cell.cell_attributes.height =
+                                    HWPUNIT(value.parse().unwrap_or(DEFAULT_CELL_HEIGHT))
+                            }
+                            _ => {}
                         }
                     }
+                } else if name.is(Namespace::Paragraph, "cellMargin") {
+                    let margin = parse_margin(e);
+                    let cell_attributes = &mut current_cell.cell_attributes;
+                    cell_attributes.left_margin = margin.left;
+                    cell_attributes.right_margin = margin.right;
+                    cell_attributes.top_margin = margin.top;
+                    cell_attributes.bottom_margin = margin.bottom;
+                } else if name.is(Namespace::Paragraph, "inMargin")
+                    && table_depth > 0
+                    && !in_cell
+                    && current_picture.is_none()
+                {
+                    // Table inner margin (not picture/shape inMargin inside cells)
+                    table_props.padding = parse_margin(e);
+                } else if name.is(Namespace::Paragraph, "fieldBegin") {
+                    if let Some(memo) = memo_field_begin(e) {
+                        open_memos.push(memo);
+                    } else if let Some(field) = other_field_begin(
+                        e,
+                        field_offset(&current_text, &current_cell_text, table_depth, in_cell),
+                    ) {
+                        open_fields.push(field);
+                    }
+                } else if name.is(Namespace::Paragraph, "fieldEnd") {
+                    let begin_id = attribute_value(e, b"beginIDRef");
+                    if let Some(index) = open_memos
+                        .iter()
+                        .position(|memo| memo.field_id.is_some() && memo.field_id == begin_id)
+                    {
+                        pending_annotations.push(open_memos.remove(index).into_record());
+                    } else if let Some(index) = open_fields
+                        .iter()
+                        .position(|field| field.field_id.is_some() && field.field_id == begin_id)
+                    {
+                        pending_annotations.push(open_fields.remove(index).into_record());
+                    }
+                } else if let Some(kind) = track_change_marker(name, "Begin") {
+                    open_changes.push(HwpxTrackSpan {
+                        id: attribute_value(e, b"Id"),
+                        kind,
+                        change_id: attribute_value(e, b"TcId")
+                            .and_then(|v| v.parse().ok())
+                            .unwrap_or(0),
+                        text: String::new(),
+                    });
+                } else if let Some(kind) = track_change_marker(name, "End") {
+                    let id = attribute_value(e, b"Id");
+                    if let Some(index) = open_changes
+                        .iter()
+                        .position(|span| span.kind == kind && span.id == id)
+                    {
+                        let span = open_changes.remove(index);
+                        pending_annotations.push(ParagraphRecord::HwpxTrackChange {
+                            kind: span.kind,
+                            change_id: span.change_id,
+                            text: span.text,
+                        });
+                    }
+                } else if name.is(Namespace::Paragraph, "chart") && memo_body.is_none() {
+                    if let Some(chart_data) = chart_record(e, load_chart) {
+                        pending_annotations.push(chart_data);
+                    }
+                } else if name.is(Namespace::Paragraph, "masterPage") {
+                    if let Some(master_page) =
+                        attribute_value(e, b"idRef").and_then(|id_ref| load_master_page(&id_ref))
+                    {
+                        definition_records.push(ParagraphRecord::MasterPage { master_page });
+                    }
+                } else if let Some(picture) = current_picture.as_mut() {
+                    picture.apply_element(name, e);
                 }
             }
             Ok(Event::Start(ref e)) => {
-                let name = e.name();
-                let local_name = String::from_utf8_lossy(name.as_ref());
+                xml_depth.enter()?;
+                let name = ElementName::resolve(&reader, e.name());
+                if let Some(recovery) = recovery.as_mut() {
+                    recovery
+                        .open_elements
+                        .push(String::from_utf8_lossy(e.name().as_ref()).into_owned());
+                }
 
-                match local_name.as_ref() {
-                    s if s.ends_with(":p") || s == "p" => {
+                match name.local_in(Namespace::Paragraph) {
+                    Some("p") => {
                         para_depth += 1;
+                        para_shape_ids.push((
+                            attribute_value(e, b"paraPrIDRef")
+                                .and_then(|id| id.parse().ok())
+                                .unwrap_or(0),
+                            attribute_value(e, b"styleIDRef")
+                                .and_then(|id| id.parse().ok())
+                                .unwrap_or(0),
+                        ));
                         if table_depth == 0 && para_depth == 1 {
                             current_text.clear();
+                            text_char_shapes.clear();
+                        }
+                    }
+                    Some("run")
+                        if memo_body.is_none() && hidden_comment.is_none() && !in_caption =>
+                    {
+                        let shape_id = attribute_value(e, b"charPrIDRef")
+                            .and_then(|id| id.parse().ok())
+                            .unwrap_or(0);
+                        if table_depth == 0 {
+                            push_char_shape(&mut text_char_shapes, &current_text, shape_id);
+                        } else if in_cell {
+                            push_char_shape(&mut cell_char_shapes, &current_cell_text, shape_id);
                         }
                     }
-                    s if s.ends_with(":t") || s == "t" => {
+                    Some("t") => {
                         in_text = true;
                     }
-                    s if s.ends_with(":tbl") || s == "tbl" => {
+                    Some("tbl") => {
                         // If already in a table (nested table), save current state
                         // 이미 테이블 안에 있으면 (중첩 테이블) 현재 상태 저장
                         if table_depth > 0 {
                             table_state_stack.push(TableState {
                                 table_rows: std::mem::take(&mut table_rows),
                                 current_row: std::mem::take(&mut current_row),
-                                current_cell: std::mem::take(&mut current_cell),
+                                current_cell: std::mem::replace(
+                                    &mut current_cell,
+                                    new_table_cell(),
+                                ),
+                                current_cell_text: std::mem::take(&mut current_cell_text),
+                                cell_char_shapes: std::mem::take(&mut cell_char_shapes),
                                 table_caption: std::mem::take(&mut table_caption),
+                                table_props: std::mem::take(&mut table_props),
                                 in_cell,
                             });
                         }
                         table_depth += 1;
                         table_rows.clear();
                         table_caption.clear();
+                        table_props = HwpxTableProps::default();
+                        for attr in e.attributes().flatten() {
+                            let value = String::from_utf8_lossy(&attr.value);
+                            match attr.key.as_ref() {
+                                b"cellSpacing" => {
+                                    table_props.cell_spacing = value.parse().unwrap_or(0)
+                                }
+                                b"borderFillIDRef" => {
+                                    table_props.border_fill_id = value.parse().unwrap_or(0)
+                                }
+                                b"repeatHeader" => {
+                                    table_props.header_row_repeat = value == "1" || value == "true"
+                                }
+                                b"pageBreak" => {
+                                    table_props.page_break = match value.as_ref() {
+                                        "CELL" => PageBreakBehavior::BreakByCell,
+                                        "TABLE" => PageBreakBehavior::NoBreakOther,
+                                        _ => PageBreakBehavior::NoBreak,
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
                     }
-                    s if s.ends_with(":caption") || s == "caption" => {
+                    Some("caption") => {
                         in_caption = true;
                     }
-                    s if s.ends_with(":tr") || s == "tr" => {
+                    Some("shapeComment") if current_picture.is_some() => {
+                        in_shape_comment = true;
+                    }
+                    Some("tr") => {
                         current_row.clear();
                     }
-                    s if s.ends_with(":tc") || s == "tc" => {
+                    Some("tc") => {
                         in_cell = true;
-                        current_cell = HwpxCell::default();
+                        current_cell = new_table_cell();
+                        current_cell_text.clear();
+                        cell_char_shapes.clear();
+                        for attr in e.attributes().flatten() {
+                            if attr.key.as_ref() == b"borderFillIDRef" {
+                                current_cell.cell_attributes.border_fill_id =
+                                    String::from_utf8_lossy(&attr.value).parse().unwrap_or(0);
+                            }
+                        }
+                    }
+                    Some("subList")
+                        if in_cell
+                            && memo_body.is_none()
+                            && hidden_comment.is_none()
+                            && current_cell.paragraphs.is_empty() =>
+                    {
+                        // 셀의 첫 subList가 셀 자체의 문단 목록 / The first subList of a cell is the cell's own paragraph list
+                        apply_sub_list_attributes(e, &mut current_cell.list_header);
+                    }
+                    Some("fieldBegin") => {
+                        if let Some(memo) = memo_field_begin(e) {
+                            memo_body = Some(memo);
+                        } else if memo_body.is_none() && hidden_comment.is_none() {
+                            field_begin = other_field_begin(
+                                e,
+                                field_offset(
+                                    &current_text,
+                                    &current_cell_text,
+                                    table_depth,
+                                    in_cell,
+                                ),
+                            );
+                        }
+                    }
+                    Some("stringParam") if (memo_body.is_some() || field_begin.is_some()) => {
+                        field_param = attribute_value(e, b"name");
+                    }
+                    Some("chart") if memo_body.is_none() => {
+                        // 차트는 그것을 담은 문단에 붙임 / Charts are attached to the paragraph holding them
+                        if let Some(chart_data) = chart_record(e, load_chart) {
+                            pending_annotations.push(chart_data);
+                        }
+                    }
+                    Some("footNotePr" | "endNotePr") => {
+                        note_shape = Some(new_note_shape());
+                    }
+                    Some("hiddenComment") => {
+                        hidden_comment = Some(String::new());
                     }
-                    s if s.ends_with(":pic") || s == "pic" => {
-                        _in_picture = true;
-                        current_image_ref = None;
+                    Some("pic") => {
+                        let mut picture = HwpxPicture::default();
+                        picture.apply_element(name, e);
+                        current_picture = Some(picture);
                     }
                     _ => {}
                 }
             }
             Ok(Event::Text(ref e)) => {
-                if in_text {
-                    let text = e.unescape().unwrap_or_default().to_string();
+                if let Some(memo) = memo_body.as_mut() {
+                    let text = event_text(e, &mut recovery);
+                    if in_text {
+                        memo.text.push_str(&text);
+                    } else {
+                        match field_param.as_deref() {
+                            Some("Author") => memo.author = Some(text),
+                            Some("CreateDateTime") => memo.date = Some(text),
+                            _ => {}
+                        }
+                    }
+                } else if let Some(comment) = hidden_comment.as_mut() {
+                    if in_text {
+                        comment.push_str(&event_text(e, &mut recovery));
+                    }
+                } else if let Some(field) = field_begin.as_mut() {
+                    if field_param.as_deref() == Some("Command") {
+                        field.command = Some(event_text(e, &mut recovery));
+                    }
+                } else if let Some(picture) = current_picture.as_mut().filter(|_| in_shape_comment)
+                {
+                    picture.description.push_str(&event_text(e, &mut recovery));
+                } else if in_text {
+                    let text = event_text(e, &mut recovery);
                     let in_table = table_depth > 0;
-                    if in_table && in_caption {
+                    if let Some(picture) = current_picture.as_mut().filter(|_| in_caption) {
+                        // 그림 캡션은 본문이 아니라 그림에 붙임 / Picture captions belong to the picture, not the body
+                        picture.caption.push_str(&text);
+                    } else if in_table && in_caption {
                         // Text inside table caption
                         table_caption.push_str(&text);
                     } else if in_table && in_cell {
-                        current_cell.current_text.push_str(&text);
+                        current_cell_text.push_str(&text);
                     } else if !in_table {
                         current_text.push_str(&text);
                     }
+                    for memo in open_memos.iter_mut() {
+                        memo.quoted.push_str(&text);
+                    }
+                    for field in open_fields.iter_mut() {
+                        field.text.push_str(&text);
+                    }
+                    for span in open_changes.iter_mut() {
+                        span.text.push_str(&text);
+                    }
                 }
             }
             Ok(Event::End(ref e)) => {
-                let name = e.name();
-                let local_name = String::from_utf8_lossy(name.as_ref());
+                xml_depth.leave();
+                let name = ElementName::resolve(&reader, e.name());
+                if let Some(recovery) = recovery.as_mut() {
+                    recovery.open_elements.pop();
+                }
 
-                match local_name.as_ref() {
-                    s if s.ends_with(":p") || s == "p" => {
+                match name.local_in(Namespace::Paragraph) {
+                    Some("footNotePr" | "endNotePr") => {
+                        if let Some(footnote_shape) = note_shape.take() {
+                            definition_records
+                                .push(ParagraphRecord::FootnoteShape { footnote_shape });
+                        }
+                    }
+                    Some("p") if (memo_body.is_some() || hidden_comment.is_some()) => {
+                        // 메모/숨은 설명 안의 문단은 줄바꿈으로 구분 / Paragraphs inside a memo or hidden comment are separated by newlines
+                        if let Some(memo) = memo_body.as_mut() {
+                            memo.text.push('\n');
+                        } else if let Some(comment) = hidden_comment.as_mut() {
+                            comment.push('\n');
+                        }
+                        para_depth = para_depth.saturating_sub(1);
+                        para_shape_ids.pop();
+                    }
+                    Some("p") => {
                         let in_table = table_depth > 0;
-                        if para_depth == 1 && !in_table && !current_text.is_empty() {
-                            paragraphs.push(create_paragraph(&current_text));
+                        let (para_shape_id, para_style_id) = para_shape_ids.pop().unwrap_or((0, 0));
+                        if para_depth == 1
+                            && !in_table
+                            && (!current_text.is_empty() || !pending_annotations.is_empty())
+                        {
+                            let mut paragraph = create_paragraph(&current_text);
+                            paragraph.para_header.para_shape_id = para_shape_id;
+                            paragraph.para_header.para_style_id = para_style_id;
+                            attach_char_shapes(&mut paragraph, &mut text_char_shapes);
+                            paragraph.records.append(&mut pending_annotations);
+                            paragraphs.push(paragraph);
                             current_text.clear();
                         }
                         // Save current paragraph text as a content item when paragraph ends inside cell
                         // 셀 내부 문단이 끝나면 현재 텍스트를 콘텐츠 항목으로 저장
-                        if in_cell && !current_cell.current_text.is_empty() {
-                            current_cell.content_items.push(CellContentItem::Text(
-                                current_cell.current_text.clone(),
-                            ));
-                            current_cell.current_text.clear();
+                        if in_cell
+                            && (!current_cell_text.is_empty() || !pending_annotations.is_empty())
+                        {
+                            let mut paragraph = create_paragraph(&current_cell_text);
+                            paragraph.para_header.para_shape_id = para_shape_id;
+                            paragraph.para_header.para_style_id = para_style_id;
+                            attach_char_shapes(&mut paragraph, &mut cell_char_shapes);
+                            paragraph.records.append(&mut pending_annotations);
+                            current_cell.paragraphs.push(paragraph);
+                            current_cell_text.clear();
                         }
                         // Add newline between nested paragraphs (e.g., in drawText/container)
                         // This ensures proper line breaks in TOC and other nested structures
-                        if para_depth > 1 && !in_table && !current_text.is_empty() {
+                        if let Some(picture) = current_picture.as_mut().filter(|_| in_caption) {
+                            // 캡션 문단은 줄바꿈으로 구분 / Caption paragraphs are separated by newlines
+                            picture.caption.push('\n');
+                        } else if para_depth > 1 && !in_table && !current_text.is_empty() {
                             current_text.push('\n');
                         }
                         para_depth = para_depth.saturating_sub(1);
                     }
-                    s if s.ends_with(":t") || s == "t" => {
+                    Some("t") => {
                         in_text = false;
                     }
-                    s if s.ends_with(":caption") || s == "caption" => {
+                    Some("fieldBegin") => {
+                        if let Some(memo) = memo_body.take() {
+                            open_memos.push(memo);
+                        } else if let Some(field) = field_begin.take() {
+                            open_fields.push(field);
+                        }
+                    }
+                    Some("stringParam") => {
+                        field_param = None;
+                    }
+                    Some("hiddenComment") => {
+                        if let Some(text) = hidden_comment.take() {
+                            pending_annotations.push(ParagraphRecord::HwpxHiddenComment {
+                                text: text.trim().to_string(),
+                            });
+                        }
+                    }
+                    Some("caption") => {
                         in_caption = false;
                     }
-                    s if s.ends_with(":tbl") || s == "tbl" => {
+                    Some("shapeComment") => {
+                        in_shape_comment = false;
+                    }
+                    Some("tbl") => {
                         table_depth = table_depth.saturating_sub(1);
 
                         if table_depth == 0 {
@@ -324,14 +1160,20 @@ fn parse_section_xml(content: &str, index: WORD) -> Result<Section, HwpError> {
                                 paragraphs.push(create_paragraph(caption_trimmed));
                             }
                             if !table_rows.is_empty() {
-                                paragraphs.push(create_table_paragraph_with_spans(&table_rows));
+                                paragraphs.push(create_table_paragraph(create_table_from_rows(
+                                    std::mem::take(&mut table_rows),
+                                    &table_props,
+                                )));
                             }
                             table_caption.clear();
                         } else {
                             // Nested table complete - convert to content for parent cell
                             // 중첩 테이블 완료 - 부모 셀의 콘텐츠로 변환
                             let nested_table = if !table_rows.is_empty() {
-                                Some(create_table_from_rows(&table_rows))
+                                Some(create_table_from_rows(
+                                    std::mem::take(&mut table_rows),
+                                    &table_props,
+                                ))
                             } else {
                                 None
                             };
@@ -342,66 +1184,247 @@ fn parse_section_xml(content: &str, index: WORD) -> Result<Section, HwpError> {
                                 table_rows = parent_state.table_rows;
                                 current_row = parent_state.current_row;
                                 current_cell = parent_state.current_cell;
+                                current_cell_text = parent_state.current_cell_text;
+                                cell_char_shapes = parent_state.cell_char_shapes;
                                 table_caption = parent_state.table_caption;
+                                table_props = parent_state.table_props;
                                 in_cell = parent_state.in_cell;
 
                                 // Add nested table to parent cell's content
                                 // 중첩 테이블을 부모 셀의 콘텐츠에 추가
                                 if let Some(table) = nested_table {
-                                    current_cell
-                                        .content_items
-                                        .push(CellContentItem::NestedTable(table));
+                                    current_cell.paragraphs.push(create_table_paragraph(table));
                                 }
                             }
                         }
                     }
-                    s if s.ends_with(":tr") || s == "tr" => {
+                    Some("tr") => {
                         if !current_row.is_empty() {
-                            table_rows.push(current_row.clone());
+                            table_rows.push(std::mem::take(&mut current_row));
                         }
                     }
-                    s if s.ends_with(":tc") || s == "tc" => {
+                    Some("tc") => {
                         // Cell parsing complete, push to current row
                         // 셀 파싱 완료, 현재 행에 추가
-                        current_row.push(current_cell.clone());
+                        if current_cell.paragraphs.is_empty() {
+                            current_cell.paragraphs.push(create_paragraph(""));
+                        }
+                        current_cell.list_header.paragraph_count =
+                            current_cell.paragraphs.len() as i16;
+                        current_row.push(std::mem::replace(&mut current_cell, new_table_cell()));
                         in_cell = false;
                     }
-                    s if s.ends_with(":pic") || s == "pic" => {
+                    Some("pic") => {
                         // Create image paragraph when picture element ends
                         // 테이블 셀 내부의 이미지는 셀에 저장하고, 그 외에는 별도 paragraph로 추가
                         // Store images inside table cells, otherwise add as separate paragraph
-                        if let Some(ref image_ref) = current_image_ref {
+                        if let Some(image) = current_picture.take().and_then(create_image_paragraph)
+                        {
                             let in_table = table_depth > 0;
                             if in_table && in_cell {
-                                // 테이블 셀 내부의 이미지는 순서대로 콘텐츠 항목에 추가
-                                // Add image to content items in order
-                                current_cell
-                                    .content_items
-                                    .push(CellContentItem::Image(image_ref.clone()));
+                                // 테이블 셀 내부의 이미지는 순서대로 셀 문단에 추가
+                                // Add image to the cell paragraphs in order
+                                current_cell.paragraphs.push(image);
                             } else {
                                 // 테이블 밖의 이미지는 별도 paragraph로 추가
-                                paragraphs.push(create_image_paragraph(image_ref));
+                                paragraphs.push(image);
                             }
                         }
-                        _in_picture = false;
-                        current_image_ref = None;
                     }
                     _ => {}
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => {
-                return Err(HwpError::XmlParseError(format!(
-                    "Error parsing section XML: {e}"
-                )))
-            }
+            Err(e) => match recovery.as_mut() {
+                Some(recovery) => {
+                    if recovery.xml_error(&e, reader.error_position()) {
+                        // 잘못 닫힌 태그도 요소를 닫음 / A mismatched end tag still closes the element
+                        if is_mismatched_end(&e) {
+                            xml_depth.leave();
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                None => {
+                    return Err(HwpError::xml_parse(
+                        "section XML",
+                        e,
+                        content,
+                        reader.error_position(),
+                    ))
+                }
+            },
             _ => {}
         }
     }
 
+    // 복구 중 닫히지 않은 문단의 텍스트 보존 / Keep text of a paragraph left open by an error
+    if recovery.is_some() && !current_text.is_empty() {
+        let mut paragraph = create_paragraph(&current_text);
+        attach_char_shapes(&mut paragraph, &mut text_char_shapes);
+        paragraphs.push(paragraph);
+    }
+
+    if let Some(first) = paragraphs.first_mut() {
+        first.records.splice(0..0, definition_records);
+    }
+
     Ok(Section { index, paragraphs })
 }
 
+/// Chart record for a `<hp:chart>` element
+/// `<hp:chart>` 요소의 차트 레코드
+fn chart_record(
+    e: &quick_xml::events::BytesStart,
+    load_chart: &mut dyn FnMut(&str) -> Option<ChartData>,
+) -> Option<ParagraphRecord> {
+    let path = attribute_value(e, b"chartIDRef")?;
+    let chart_data = load_chart(&path)?;
+    Some(ParagraphRecord::ChartData { chart_data })
+}
+
+/// Text of an inline control element (`<hp:tab>`, `<hp:hyphen>`, `<hp:nbSpace>`, `<hp:fwSpace>`)
+/// 인라인 제어 요소의 텍스트, 다른 요소면 None
+///
+/// 탭 외에는 HWP 5.0과 같이 `InlineControl`의 문자로 남깁니다.
+/// Apart from tabs, controls are left as their `InlineControl` character, as in HWP 5.0.
+pub(super) fn inline_control_text(
+    name: ElementName,
+    e: &quick_xml::events::BytesStart,
+) -> Option<Cow<'static, str>> {
+    match InlineControl::from_element(name.local_in(Namespace::Paragraph)?)? {
+        InlineControl::Tab => Some(tab_text(e)),
+        control => Some(Cow::Owned(control.as_char().to_string())),
+    }
+}
+
+/// Text representation of a `<hp:tab>` element
+/// 탭 요소의 텍스트 표현 (채움 모양이 있으면 점/선으로 채움)
+fn tab_text(e: &quick_xml::events::BytesStart) -> Cow<'static, str> {
+    // Parse tab element and convert to appropriate text representation
+    // Tab attributes: width (HWPUNIT), leader (0=none, 1=solid, 2=dash, 3=dot), type
+    let mut leader: u8 = 0;
+    let mut width: u32 = 0;
+
+    for attr in e.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref());
+        let value = String::from_utf8_lossy(&attr.value);
+        match key.as_ref() {
+            "leader" => {
+                leader = value.parse().unwrap_or(0);
+            }
+            "width" => {
+                width = value.parse().unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    // Generate tab representation based on leader type
+    // Leader: 0=none, 1=solid, 2=dash, 3=dot
+    match leader {
+        3 => {
+            // Dot leader - generate dots based on approximate width
+            // HWPUNIT: 7200 units = 1 inch, roughly 6 chars per inch
+            let dot_count = (width / 1200).min(80).max(3) as usize;
+            Cow::Owned(".".repeat(dot_count))
+        }
+        2 => {
+            // Dash leader
+            let dash_count = (width / 2400).min(40).max(2) as usize;
+            Cow::Owned("-".repeat(dash_count))
+        }
+        1 => {
+            // Solid line leader
+            let line_count = (width / 2400).min(40).max(2) as usize;
+            Cow::Owned("_".repeat(line_count))
+        }
+        // No leader - use tab character
+        _ => Cow::Borrowed("\t"),
+    }
+}
+
+/// Read an attribute value
+/// 속성 값 읽기
+fn attribute_value(e: &quick_xml::events::BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+/// Start a memo for <hp:fieldBegin type="MEMO">, None for other fields
+/// <hp:fieldBegin type="MEMO">이면 메모 시작, 다른 필드는 None
+fn memo_field_begin(e: &quick_xml::events::BytesStart) -> Option<HwpxMemo> {
+    (attribute_value(e, b"type").as_deref() == Some("MEMO")).then(|| HwpxMemo {
+        field_id: attribute_value(e, b"id"),
+        ..Default::default()
+    })
+}
+
+/// Start a field for <hp:fieldBegin> other than a memo, None when the type is missing
+/// 메모가 아닌 <hp:fieldBegin>이면 필드 시작, 종류가 없으면 None
+fn other_field_begin(e: &quick_xml::events::BytesStart, offset: usize) -> Option<HwpxField> {
+    let field_type = attribute_value(e, b"type").filter(|t| !t.is_empty() && t != "MEMO")?;
+    Some(HwpxField {
+        field_id: attribute_value(e, b"id"),
+        field_type,
+        name: attribute_value(e, b"name").filter(|name| !name.is_empty()),
+        command: None,
+        text: String::new(),
+        offset,
+    })
+}
+
+/// Character offset of a field starting now in the paragraph being read
+/// 읽고 있는 문단에서 지금 시작하는 필드의 문자 위치
+fn field_offset(current_text: &str, cell_text: &str, table_depth: u32, in_cell: bool) -> usize {
+    if table_depth == 0 {
+        current_text.chars().count()
+    } else if in_cell {
+        cell_text.chars().count()
+    } else {
+        0
+    }
+}
+
+/// Tracked change kind of <hp:insertBegin>/<hp:deleteEnd> and similar markers
+/// <hp:insertBegin>/<hp:deleteEnd> 등 변경 추적 표시의 종류
+fn track_change_marker(name: ElementName, suffix: &str) -> Option<TrackChangeKind> {
+    match name.local_in(Namespace::Paragraph)?.strip_suffix(suffix)? {
+        "insert" => Some(TrackChangeKind::Insert),
+        "delete" => Some(TrackChangeKind::Delete),
+        _ => None,
+    }
+}
+
+/// Record the char shape of a run starting at the end of `text`
+/// `text` 끝에서 시작하는 런의 글자 모양 기록
+///
+/// 같은 위치의 앞 기록은 덮어쓰고, 모양이 그대로면 기록하지 않습니다.
+/// An earlier entry at the same position is overwritten, and an unchanged shape is not recorded.
+fn push_char_shape(shapes: &mut Vec<CharShapeInfo>, text: &str, shape_id: u32) {
+    let position = text.chars().count() as u32;
+    match shapes.last_mut() {
+        Some(last) if last.position == position => last.shape_id = shape_id,
+        Some(last) if last.shape_id == shape_id => {}
+        _ => shapes.push(CharShapeInfo { position, shape_id }),
+    }
+}
+
+/// Attach the recorded char shapes to a paragraph as a ParaCharShape record
+/// 기록한 글자 모양을 ParaCharShape 레코드로 문단에 붙임
+fn attach_char_shapes(paragraph: &mut Paragraph, shapes: &mut Vec<CharShapeInfo>) {
+    if shapes.is_empty() {
+        return;
+    }
+    paragraph.para_header.char_shape_count = shapes.len() as UINT16;
+    paragraph.records.push(ParagraphRecord::ParaCharShape {
+        shapes: std::mem::take(shapes),
+    });
+}
+
 /// Create a paragraph from text content
 fn create_paragraph(text: &str) -> Paragraph {
     let para_header = ParaHeader {
@@ -426,243 +1449,61 @@ fn create_paragraph(text: &str) -> Paragraph {
     Paragraph {
         para_header,
         records,
+        resolved_number: None,
     }
 }
 
-/// Create a Table struct from rows (used for nested tables)
-/// 행 데이터로부터 Table 구조체 생성 (중첩 테이블용)
-fn create_table_from_rows(rows: &[Vec<HwpxCell>]) -> Table {
+/// Create a Table struct from parsed rows
+/// 파싱한 행들로부터 Table 구조체 생성
+fn create_table_from_rows(rows: Vec<Vec<TableCell>>, props: &HwpxTableProps) -> Table {
     let row_count = rows.len() as UINT16;
-
-    // Calculate actual column count from maximum (col_addr + col_span) across all cells
-    let col_count = rows
-        .iter()
-        .flat_map(|row| row.iter())
-        .map(|c| {
-            let col_addr = c.col_addr.unwrap_or(0) as usize;
-            col_addr + c.col_span as usize
-        })
-        .max()
-        .unwrap_or(0) as UINT16;
-
-    let table_attributes = TableAttributes {
-        attribute: TableAttribute {
-            page_break: PageBreakBehavior::NoBreak,
-            header_row_repeat: false,
-        },
-        row_count,
-        col_count,
-        cell_spacing: 0,
-        padding: TablePadding {
-            left: 0,
-            right: 0,
-            top: 0,
-            bottom: 0,
-        },
-        row_sizes: vec![],
-        border_fill_id: 0,
-        zones: vec![],
-    };
+    let row_sizes = rows.iter().map(|row| row.len() as i16).collect();
 
     let mut cells = Vec::new();
-
-    for (row_idx, row) in rows.iter().enumerate() {
+    for (row_idx, row) in rows.into_iter().enumerate() {
         let mut calc_col_address: u16 = 0;
 
-        for cell_data in row.iter() {
-            let col_address = cell_data.col_addr.unwrap_or(calc_col_address);
-            let row_address = cell_data.row_addr.unwrap_or(row_idx as u16);
-
-            let mut cell_paragraphs = Vec::new();
-
-            for item in &cell_data.content_items {
-                match item {
-                    CellContentItem::Text(text) => {
-                        if !text.is_empty() {
-                            cell_paragraphs.push(create_paragraph(text));
-                        }
-                    }
-                    CellContentItem::Image(image_ref) => {
-                        cell_paragraphs.push(create_image_paragraph(image_ref));
-                    }
-                    CellContentItem::NestedTable(nested_table) => {
-                        // Create a paragraph containing the nested table
-                        // 중첩 테이블을 포함하는 paragraph 생성
-                        let para_header = ParaHeader {
-                            text_char_count: 1,
-                            ..Default::default()
-                        };
-                        let records = vec![ParagraphRecord::Table {
-                            table: nested_table.clone(),
-                        }];
-                        cell_paragraphs.push(Paragraph {
-                            para_header,
-                            records,
-                        });
-                    }
-                }
+        for mut cell in row {
+            // hp:cellAddr가 없으면 행 안의 순서로 주소 계산 / Compute address from row order when hp:cellAddr is missing
+            let attributes = &mut cell.cell_attributes;
+            if attributes.col_address == UNSET_ADDRESS {
+                attributes.col_address = calc_col_address;
             }
-
-            if cell_paragraphs.is_empty() {
-                cell_paragraphs.push(create_paragraph(""));
+            if attributes.row_address == UNSET_ADDRESS {
+                attributes.row_address = row_idx as u16;
             }
-
-            let cell = TableCell {
-                list_header: ListHeader {
-                    paragraph_count: cell_paragraphs.len() as i16,
-                    attribute: ListHeaderAttribute {
-                        text_direction: TextDirection::Horizontal,
-                        line_break: LineBreak::Normal,
-                        vertical_align: VerticalAlign::Top,
-                    },
-                },
-                cell_attributes: CellAttributes {
-                    col_address,
-                    row_address,
-                    col_span: cell_data.col_span,
-                    row_span: cell_data.row_span,
-                    width: HWPUNIT(5000),
-                    height: HWPUNIT(1000),
-                    left_margin: 0,
-                    right_margin: 0,
-                    top_margin: 0,
-                    bottom_margin: 0,
-                    border_fill_id: 0,
-                },
-                paragraphs: cell_paragraphs,
-            };
+            calc_col_address = attributes.col_address + attributes.col_span;
             cells.push(cell);
-
-            calc_col_address = col_address + cell_data.col_span;
         }
     }
 
-    Table {
-        attributes: table_attributes,
-        cells,
-    }
-}
-
-/// Create a paragraph containing a table with proper colspan/rowspan
-fn create_table_paragraph_with_spans(rows: &[Vec<HwpxCell>]) -> Paragraph {
-    let row_count = rows.len() as UINT16;
-
     // Calculate actual column count from maximum (col_addr + col_span) across all cells
-    // This handles cells with explicit addresses correctly
-    let col_count = rows
+    let col_count = cells
         .iter()
-        .flat_map(|row| row.iter())
-        .map(|c| {
-            let col_addr = c.col_addr.unwrap_or(0) as usize;
-            col_addr + c.col_span as usize
-        })
+        .map(|c| c.cell_attributes.col_address + c.cell_attributes.col_span)
         .max()
         .unwrap_or(0) as UINT16;
 
-    let table_attributes = TableAttributes {
-        attribute: TableAttribute {
-            page_break: PageBreakBehavior::NoBreak,
-            header_row_repeat: false,
-        },
-        row_count,
-        col_count,
-        cell_spacing: 0,
-        padding: TablePadding {
-            left: 0,
-            right: 0,
-            top: 0,
-            bottom: 0,
+    Table {
+        attributes: TableAttributes {
+            attribute: TableAttribute {
+                page_break: props.page_break,
+                header_row_repeat: props.header_row_repeat,
+            },
+            row_count,
+            col_count,
+            cell_spacing: props.cell_spacing,
+            padding: props.padding.clone(),
+            row_sizes,
+            border_fill_id: props.border_fill_id,
+            zones: vec![],
         },
-        row_sizes: vec![],
-        border_fill_id: 0,
-        zones: vec![],
-    };
-
-    let mut cells = Vec::new();
-
-    for (row_idx, row) in rows.iter().enumerate() {
-        // Track calculated col_address for cells without explicit address
-        let mut calc_col_address: u16 = 0;
-
-        for cell_data in row.iter() {
-            // Use explicit address if available, otherwise use calculated
-            let col_address = cell_data.col_addr.unwrap_or(calc_col_address);
-            let row_address = cell_data.row_addr.unwrap_or(row_idx as u16);
-
-            // 셀 내용 구성: 콘텐츠 항목 순서대로 paragraph 추가
-            // Build cell content: add paragraphs in content item order
-            let mut cell_paragraphs = Vec::new();
-
-            // 콘텐츠 항목을 순서대로 처리 / Process content items in order
-            for item in &cell_data.content_items {
-                match item {
-                    CellContentItem::Text(text) => {
-                        if !text.is_empty() {
-                            cell_paragraphs.push(create_paragraph(text));
-                        }
-                    }
-                    CellContentItem::Image(image_ref) => {
-                        cell_paragraphs.push(create_image_paragraph(image_ref));
-                    }
-                    CellContentItem::NestedTable(nested_table) => {
-                        // Create a paragraph containing the nested table
-                        // 중첩 테이블을 포함하는 paragraph 생성
-                        let para_header = ParaHeader {
-                            text_char_count: 1,
-                            ..Default::default()
-                        };
-                        let records = vec![ParagraphRecord::Table {
-                            table: nested_table.clone(),
-                        }];
-                        cell_paragraphs.push(Paragraph {
-                            para_header,
-                            records,
-                        });
-                    }
-                }
-            }
-
-            // 내용이 없으면 빈 paragraph 추가 / Add empty paragraph if no content
-            if cell_paragraphs.is_empty() {
-                cell_paragraphs.push(create_paragraph(""));
-            }
-
-            let cell = TableCell {
-                list_header: ListHeader {
-                    paragraph_count: cell_paragraphs.len() as i16,
-                    attribute: ListHeaderAttribute {
-                        text_direction: TextDirection::Horizontal,
-                        line_break: LineBreak::Normal,
-                        vertical_align: VerticalAlign::Top,
-                    },
-                },
-                cell_attributes: CellAttributes {
-                    col_address,
-                    row_address,
-                    col_span: cell_data.col_span,
-                    row_span: cell_data.row_span,
-                    width: HWPUNIT(5000),  // Default width
-                    height: HWPUNIT(1000), // Default height
-                    left_margin: 0,
-                    right_margin: 0,
-                    top_margin: 0,
-                    bottom_margin: 0,
-                    border_fill_id: 0,
-                },
-                paragraphs: cell_paragraphs,
-            };
-            cells.push(cell);
-
-            // Update calculated col_address based on actual position + colspan
-            calc_col_address = col_address + cell_data.col_span;
-        }
-    }
-
-    let table = Table {
-        attributes: table_attributes,
         cells,
-    };
+    }
+}
 
+/// Create a paragraph containing a table
+fn create_table_paragraph(table: Table) -> Paragraph {
     // Create paragraph with table
     let para_header = ParaHeader {
         text_char_count: 1, // Table control character
@@ -674,22 +1515,613 @@ fn create_table_paragraph_with_spans(rows: &[Vec<HwpxCell>]) -> Paragraph {
     Paragraph {
         para_header,
         records,
+        resolved_number: None,
     }
 }
 
 /// Create a paragraph containing an image reference
-fn create_image_paragraph(binary_item_ref: &str) -> Paragraph {
+fn create_image_paragraph(picture: HwpxPicture) -> Option<Paragraph> {
+    let binary_item_ref = picture.binary_item_ref?;
     let para_header = ParaHeader {
         text_char_count: 1, // Image control character
         ..Default::default()
     };
 
+    let mut effects = picture.effects;
+    if let (Some(clip), Some((width, height))) = (picture.clip, picture.dim) {
+        effects.crop = ImageCrop::from_rect(clip, width, height);
+    }
+
     let records = vec![ParagraphRecord::HwpxImage {
-        binary_item_ref: binary_item_ref.to_string(),
+        binary_item_ref,
+        width: picture.width,
+        height: picture.height,
+        effects,
+        placement: picture.placement,
+        description: non_empty(picture.description),
+        caption: non_empty(picture.caption),
     }];
 
-    Paragraph {
+    Some(Paragraph {
         para_header,
         records,
+        resolved_number: None,
+    })
+}
+
+/// 하위 요소가 없을 때의 각주/미주 모양 (아라비아 숫자, 이어서, 1부터)
+/// Footnote/endnote shape before its child elements (Arabic digits, continuous, from 1)
+fn new_note_shape() -> FootnoteShape {
+    FootnoteShape {
+        attributes: FootnoteShapeAttributes {
+            number_shape: NumberShape::Arabic,
+            page_position: PagePosition::Separate,
+            numbering: NumberingMethod::Continue,
+            superscript: false,
+            prefix: false,
+        },
+        custom_symbol: 0,
+        front_decoration: 0,
+        back_decoration: 0,
+        start_number: 1,
+        breakline_length: 0,
+        breakline_top_margin: 0,
+        breakline_bottom_margin: 0,
+        remark_between_margin: 0,
+        breakline_type: 0,
+        breakline_thickness: 0,
+        breakline_color: COLORREF(0),
+    }
+}
+
+/// 각주/미주 모양의 하위 요소 적용 / Apply a child element of a footnote/endnote shape
+fn apply_note_shape_element(
+    shape: &mut FootnoteShape,
+    name: ElementName,
+    e: &quick_xml::events::BytesStart,
+) {
+    let Some(local_name) = name.local_in(Namespace::Paragraph) else {
+        return;
+    };
+    let get = |key: &[u8]| attribute_value(e, key);
+    let int = |key: &[u8]| get(key).and_then(|v| v.parse::<i32>().ok());
+    let unit = |key: &[u8]| int(key).map(|v| v.clamp(0, i16::MAX as i32) as i16);
+    let character = |key: &[u8]| get(key).and_then(|v| v.encode_utf16().next()).unwrap_or(0);
+    match local_name {
+        "autoNumFormat" => {
+            if let Some(number_shape) = get(b"type").and_then(|t| NumberShape::from_hwpx_name(&t)) {
+                shape.attributes.number_shape = number_shape;
+            }
+            shape.custom_symbol = character(b"userChar");
+            shape.front_decoration = character(b"prefixChar");
+            shape.back_decoration = character(b"suffixChar");
+            shape.attributes.superscript =
+                matches!(get(b"supscript").as_deref(), Some("1" | "true"));
+        }
+        "numbering" => {
+            shape.attributes.numbering = match get(b"type").as_deref() {
+                Some("ON_SECTION") => NumberingMethod::Restart,
+                Some("ON_PAGE") => NumberingMethod::PerPage,
+                _ => NumberingMethod::Continue,
+            };
+            if let Some(start) = int(b"newNum") {
+                shape.start_number = start.clamp(0, u16::MAX as i32) as u16;
+            }
+        }
+        "placement" => {
+            shape.attributes.page_position = match get(b"place").as_deref() {
+                Some("MERGED_COLUMN" | "END_OF_SECTION") => PagePosition::Across,
+                Some("RIGHT_MOST_COLUMN") => PagePosition::Rightmost,
+                _ => PagePosition::Separate,
+            };
+            shape.attributes.prefix = matches!(get(b"beneathText").as_deref(), Some("1" | "true"));
+        }
+        "noteLine" => {
+            shape.breakline_length = unit(b"length").unwrap_or(0);
+        }
+        "noteSpacing" => {
+            shape.remark_between_margin = unit(b"betweenNotes").unwrap_or(0);
+            shape.breakline_bottom_margin = unit(b"belowLine").unwrap_or(0);
+            shape.breakline_top_margin = unit(b"aboveLine").unwrap_or(0);
+        }
+        _ => {}
+    }
+}
+
+/// 앞뒤 공백을 뺀 문자열, 비어 있으면 None / Trimmed string, or None when empty
+fn non_empty(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_uses_parsed_cell_dimensions() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run>
+<hp:tbl rowCnt="1" colCnt="2" cellSpacing="20" borderFillIDRef="3" repeatHeader="1" pageBreak="CELL">
+<hp:inMargin left="510" right="510" top="141" bottom="141"/>
+<hp:tr>
+<hp:tc borderFillIDRef="4"><hp:subList textDirection="HORIZONTAL" lineWrap="SQUEEZE" vertAlign="CENTER"><hp:p><hp:run><hp:t>A</hp:t></hp:run></hp:p></hp:subList>
+<hp:cellAddr colAddr="0" rowAddr="0"/><hp:cellSpan colSpan="1" rowSpan="1"/>
+<hp:cellSz width="21260" height="2830"/><hp:cellMargin left="100" right="200" top="30" bottom="40"/></hp:tc>
+<hp:tc><hp:subList><hp:p><hp:run><hp:t>B</hp:t></hp:run></hp:p></hp:subList>
+<hp:cellAddr colAddr="1" rowAddr="0"/><hp:cellSpan colSpan="1" rowSpan="1"/></hp:tc>
+</hp:tr></hp:tbl></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let table = section
+            .paragraphs
+            .iter()
+            .flat_map(|p| p.records.iter())
+            .find_map(|r| match r {
+                ParagraphRecord::Table { table } => Some(table),
+                _ => None,
+            })
+            .expect("table paragraph");
+
+        let attrs = &table.attributes;
+        assert_eq!(attrs.cell_spacing, 20);
+        assert_eq!(attrs.border_fill_id, 3);
+        assert!(attrs.attribute.header_row_repeat);
+        assert!(matches!(
+            attrs.attribute.page_break,
+            PageBreakBehavior::BreakByCell
+        ));
+        assert_eq!((attrs.padding.left, attrs.padding.top), (510, 141));
+
+        let first = &table.cells[0].cell_attributes;
+        assert_eq!(first.width.0, 21260);
+        assert_eq!(first.height.0, 2830);
+        assert_eq!(
+            (
+                first.left_margin,
+                first.right_margin,
+                first.top_margin,
+                first.bottom_margin
+            ),
+            (100, 200, 30, 40)
+        );
+        assert_eq!(first.border_fill_id, 4);
+
+        // cellSz가 없으면 기본값 사용 / Falls back to defaults without cellSz
+        let second = &table.cells[1].cell_attributes;
+        assert_eq!(second.width.0, DEFAULT_CELL_WIDTH);
+        assert_eq!(second.height.0, DEFAULT_CELL_HEIGHT);
+    }
+
+    #[test]
+    fn test_table_cells_keep_list_header_attributes() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run>
+<hp:tbl rowCnt="1" colCnt="2"><hp:tr>
+<hp:tc><hp:subList lineWrap="SQUEEZE" vertAlign="CENTER"><hp:p><hp:run><hp:t>A</hp:t></hp:run></hp:p>
+<hp:p><hp:run><hp:t>B</hp:t></hp:run></hp:p></hp:subList><hp:cellSpan colSpan="2" rowSpan="1"/></hp:tc>
+</hp:tr></hp:tbl></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let table = section
+            .paragraphs
+            .iter()
+            .flat_map(|p| p.records.iter())
+            .find_map(|r| match r {
+                ParagraphRecord::Table { table } => Some(table),
+                _ => None,
+            })
+            .expect("table paragraph");
+
+        assert_eq!(table.attributes.col_count, 2);
+        assert_eq!(table.attributes.row_sizes, vec![1]);
+
+        let cell = &table.cells[0];
+        assert_eq!(cell.list_header.paragraph_count, 2);
+        assert!(matches!(
+            cell.list_header.attribute.vertical_align,
+            VerticalAlign::Center
+        ));
+        assert!(matches!(
+            cell.list_header.attribute.line_break,
+            LineBreak::MaintainOneLine
+        ));
+        // cellAddr가 없으면 행 순서로 주소 계산 / Address computed from row order without cellAddr
+        assert_eq!(
+            (
+                cell.cell_attributes.row_address,
+                cell.cell_attributes.col_address
+            ),
+            (0, 0)
+        );
+        assert_eq!(cell.paragraphs.len(), 2);
+    }
+
+    #[test]
+    fn test_picture_crop_and_effects() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hp:p><hp:run>
+<hp:pic><hp:imgClip left="1000" right="4000" top="0" bottom="2000"/>
+<hp:imgDim dimwidth="4000" dimheight="2000"/>
+<hc:img binaryItemIDRef="image1" bright="-20" contrast="10" effect="GRAY_SCALE" alpha="51"/>
+<hp:sz width="3000" height="2000"/></hp:pic></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let (binary_item_ref, width, height, effects) = section
+            .paragraphs
+            .iter()
+            .flat_map(|p| p.records.iter())
+            .find_map(|r| match r {
+                ParagraphRecord::HwpxImage {
+                    binary_item_ref,
+                    width,
+                    height,
+                    effects,
+                    ..
+                } => Some((binary_item_ref, width, height, effects)),
+                _ => None,
+            })
+            .expect("image paragraph");
+
+        assert_eq!(binary_item_ref, "image1");
+        assert_eq!((*width, *height), (3000, 2000));
+        let crop = effects.crop.expect("crop");
+        assert_eq!((crop.left, crop.right, crop.top), (0.25, 0.0, 0.0));
+        assert_eq!((effects.brightness, effects.contrast), (-20, 10));
+        assert_eq!((effects.effect, effects.alpha), (1, 51));
+    }
+    #[test]
+    fn test_picture_description_and_caption() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hp:p><hp:run><hp:t>본문</hp:t>
+<hp:pic><hp:sz width="3000" height="2000"/><hc:img binaryItemIDRef="image1"/>
+<hp:shapeComment>서울 지도</hp:shapeComment>
+<hp:caption side="BOTTOM"><hp:subList><hp:p><hp:run><hp:t>그림 1. 서울</hp:t></hp:run></hp:p>
+<hp:p><hp:run><hp:t>출처: 통계청</hp:t></hp:run></hp:p></hp:subList></hp:caption>
+</hp:pic></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let (description, caption) = section
+            .paragraphs
+            .iter()
+            .flat_map(|p| p.records.iter())
+            .find_map(|r| match r {
+                ParagraphRecord::HwpxImage {
+                    description,
+                    caption,
+                    ..
+                } => Some((description.clone(), caption.clone())),
+                _ => None,
+            })
+            .expect("image paragraph");
+
+        assert_eq!(description.as_deref(), Some("서울 지도"));
+        assert_eq!(caption.as_deref(), Some("그림 1. 서울\n출처: 통계청"));
+        // 캡션은 본문에 섞이지 않음 / The caption does not leak into the body text
+        let body: Vec<String> = section.paragraphs.iter().map(|p| p.text()).collect();
+        assert!(body.iter().all(|text| !text.contains("서울")));
+        assert!(body.iter().any(|text| text == "본문"));
+    }
+
+    #[test]
+    fn test_picture_placement() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hp:p><hp:run>
+<hp:pic zOrder="3" textWrap="BEHIND_TEXT" textFlow="LEFT_ONLY">
+<hp:sz width="3000" height="2000"/>
+<hp:pos treatAsChar="0" affectLSpacing="0" vertRelTo="PAPER" horzRelTo="PAGE" vertAlign="TOP" horzAlign="RIGHT" vertOffset="1200" horzOffset="-300"/>
+<hp:outMargin left="100" right="200" top="300" bottom="400"/>
+<hc:img binaryItemIDRef="image1"/></hp:pic></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let placement = section
+            .paragraphs
+            .iter()
+            .flat_map(|p| p.records.iter())
+            .find_map(|r| match r {
+                ParagraphRecord::HwpxImage { placement, .. } => placement.clone(),
+                _ => None,
+            })
+            .expect("image placement");
+
+        let attribute = &placement.attribute;
+        assert!(!attribute.like_letters);
+        assert!(matches!(attribute.vert_rel_to, VertRelTo::Paper));
+        assert!(matches!(attribute.horz_rel_to, HorzRelTo::Page));
+        assert_eq!(attribute.horz_relative, 2);
+        assert_eq!(attribute.object_text_option, ObjectTextOption::BehindText);
+        assert_eq!(
+            attribute.object_text_position_option,
+            ObjectTextPositionOption::LeftOnly
+        );
+        assert_eq!((placement.offset_x.0, placement.offset_y.0), (-300, 1200));
+        assert_eq!(placement.z_order, 3);
+        assert_eq!(
+            (
+                placement.margin.left,
+                placement.margin.top,
+                placement.margin.bottom
+            ),
+            (100, 300, 400)
+        );
+    }
+    #[test]
+    fn test_chart_is_attached_to_its_paragraph() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+<hp:p><hp:run><hp:t>그림 1</hp:t><hp:chart chartIDRef="Chart/chart1.xml"><hp:sz width="100" height="100"/></hp:chart></hp:run></hp:p>
+<hp:p><hp:run><hp:chart chartIDRef="Chart/missing.xml"/></hp:run></hp:p></hs:sec>"#;
+
+        let mut requested = Vec::new();
+        let mut load_chart = |path: &str| {
+            requested.push(path.to_string());
+            (path == "Chart/chart1.xml").then(|| ChartData {
+                vt_chart: Some(Default::default()),
+                raw_data: Vec::new(),
+            })
+        };
+        let section =
+            parse_section_xml_with(xml, 0, 64, &mut load_chart, &mut |_| None, None).unwrap();
+        assert_eq!(requested, ["Chart/chart1.xml", "Chart/missing.xml"]);
+
+        // 읽지 못한 차트만 있는 문단은 생기지 않음 / No paragraph is created for an unreadable chart
+        assert_eq!(section.paragraphs.len(), 1);
+        assert_eq!(section.paragraphs[0].text(), "그림 1");
+        assert_eq!(section.paragraphs[0].charts().len(), 1);
+    }
+
+    #[test]
+    fn test_section_note_shapes() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:secPr>
+<hp:footNotePr><hp:autoNumFormat type="CIRCLED_DIGIT" userChar="" prefixChar="" suffixChar=")" supscript="1"/>
+<hp:noteLine length="-1" type="SOLID"/><hp:noteSpacing betweenNotes="283" belowLine="567" aboveLine="850"/>
+<hp:numbering type="ON_PAGE" newNum="3"/><hp:placement place="EACH_COLUMN" beneathText="0"/></hp:footNotePr>
+<hp:endNotePr><hp:autoNumFormat type="ROMAN_SMALL"/><hp:numbering type="ON_SECTION" newNum="1"/>
+<hp:placement place="END_OF_SECTION" beneathText="0"/></hp:endNotePr>
+</hp:secPr></hp:run><hp:run><hp:t>본문</hp:t></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 1);
+        assert_eq!(section.paragraphs[0].text(), "본문");
+
+        let (footnote, endnote) = section.note_shapes();
+        let footnote = footnote.unwrap();
+        assert_eq!(footnote.attributes.number_shape, NumberShape::CircledArabic);
+        assert_eq!(footnote.attributes.numbering, NumberingMethod::PerPage);
+        assert!(footnote.attributes.superscript);
+        assert_eq!(footnote.start_number, 3);
+        assert_eq!(footnote.remark_between_margin, 283);
+        assert_eq!(footnote.decorated_number(3), "③)");
+
+        let endnote = endnote.unwrap();
+        assert_eq!(endnote.attributes.number_shape, NumberShape::RomanLower);
+        assert_eq!(endnote.attributes.numbering, NumberingMethod::Restart);
+        assert!(endnote.endnotes_at_section_end());
+    }
+
+    #[test]
+    fn test_section_master_pages_stay_out_of_body() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:secPr>
+<hp:masterPage idRef="masterpage0"/><hp:masterPage idRef="missing"/>
+</hp:secPr></hp:run><hp:run><hp:t>본문</hp:t></hp:run></hp:p></hs:sec>"#;
+        let master_xml = r#"<masterPage xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph" type="EVEN"><hp:subList>
+<hp:p><hp:run><hp:t>대외비</hp:t></hp:run></hp:p></hp:subList></masterPage>"#;
+
+        let mut load_master_page = |id_ref: &str| {
+            (id_ref == "masterpage0")
+                .then(|| parse_master_page_xml(master_xml, 64))
+                .and_then(|result| result.ok().flatten())
+        };
+        let section =
+            parse_section_xml_with(xml, 0, 64, &mut |_| None, &mut load_master_page, None).unwrap();
+        // 바탕쪽 글자는 본문에 섞이지 않음 / Master page text does not leak into the body
+        assert_eq!(section.paragraphs.len(), 1);
+        assert_eq!(section.paragraphs[0].text(), "본문");
+
+        let master_pages = section.master_pages();
+        assert_eq!(master_pages.len(), 1);
+        assert_eq!(
+            master_pages[0].apply_page,
+            crate::document::bodytext::ctrl_header::ApplyPage::EvenOnly
+        );
+        assert_eq!(master_pages[0].paragraphs[0].text(), "대외비");
+    }
+
+    #[test]
+    fn test_memo_and_track_changes_are_kept_out_of_body() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>예산은</hp:t>
+<hp:ctrl><hp:fieldBegin id="11" type="MEMO"><hp:parameters>
+<hp:stringParam name="Author">홍길동</hp:stringParam>
+<hp:stringParam name="CreateDateTime">2024-03-01 09:00:00</hp:stringParam></hp:parameters>
+<hp:subList><hp:p><hp:run><hp:t>근거 추가</hp:t></hp:run></hp:p></hp:subList></hp:fieldBegin></hp:ctrl>
+<hp:t>10억</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="11"/></hp:ctrl>
+<hp:deleteBegin Id="1" TcId="7"/><hp:t>원</hp:t><hp:deleteEnd Id="1" TcId="7"/>
+<hp:insertBegin Id="2" TcId="8"/><hp:t>입니다</hp:t><hp:insertEnd Id="2" TcId="8"/>
+</hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 1);
+        let paragraph = &section.paragraphs[0];
+        assert_eq!(paragraph.text(), "예산은10억원입니다");
+
+        let annotations: Vec<_> = paragraph
+            .records
+            .iter()
+            .filter(|r| {
+                matches!(
+                    r,
+                    ParagraphRecord::HwpxMemo { .. } | ParagraphRecord::HwpxTrackChange { .. }
+                )
+            })
+            .collect();
+        assert_eq!(annotations.len(), 3);
+        match annotations[0] {
+            ParagraphRecord::HwpxMemo {
+                author,
+                date,
+                text,
+                quoted,
+            } => {
+                assert_eq!(author.as_deref(), Some("홍길동"));
+                assert_eq!(date.as_deref(), Some("2024-03-01 09:00:00"));
+                assert_eq!((text.as_str(), quoted.as_str()), ("근거 추가", "10억"));
+            }
+            other => panic!("unexpected record: {other:?}"),
+        }
+        assert!(matches!(
+            annotations[1],
+            ParagraphRecord::HwpxTrackChange { kind: TrackChangeKind::Delete, change_id: 7, text } if text == "원"
+        ));
+        assert!(matches!(
+            annotations[2],
+            ParagraphRecord::HwpxTrackChange { kind: TrackChangeKind::Insert, change_id: 8, text } if text == "입니다"
+        ));
+    }
+
+    #[test]
+    fn test_hidden_comments_are_kept_out_of_body() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>계약 금액</hp:t>
+<hp:ctrl><hp:hiddenComment><hp:subList><hp:p><hp:run><hp:t>내부 검토용</hp:t></hp:run></hp:p>
+<hp:p><hp:run><hp:t>외부 공개 금지</hp:t></hp:run></hp:p></hp:subList></hp:hiddenComment></hp:ctrl>
+<hp:t>확정</hp:t></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 1);
+        let paragraph = &section.paragraphs[0];
+        assert_eq!(paragraph.text(), "계약 금액확정");
+        assert!(paragraph.records.iter().any(|record| matches!(
+            record,
+            ParagraphRecord::HwpxHiddenComment { text } if text == "내부 검토용\n외부 공개 금지"
+        )));
+    }
+
+    #[test]
+    fn test_citation_fields_are_attached_to_their_paragraph() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>판례(</hp:t>
+<hp:ctrl><hp:fieldBegin id="21" type="CITATION" name="판례1"><hp:parameters>
+<hp:stringParam name="Command">대법원 2019. 1. 17. 선고 2018다12345 판결</hp:stringParam></hp:parameters></hp:fieldBegin></hp:ctrl>
+<hp:t>2018다12345</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="21"/></hp:ctrl><hp:t>)·</hp:t>
+<hp:ctrl><hp:fieldBegin id="22" type="CROSSREF"/></hp:ctrl><hp:t>제3조</hp:t>
+<hp:ctrl><hp:fieldEnd beginIDRef="22"/></hp:ctrl><hp:t>.</hp:t>
+</hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let paragraph = &section.paragraphs[0];
+        // 매개변수는 본문에 들어가지 않음 / Parameters stay out of the body
+        assert_eq!(paragraph.text(), "판례(2018다12345)·제3조.");
+
+        let citations: Vec<_> = paragraph
+            .records
+            .iter()
+            .filter(|r| matches!(r, ParagraphRecord::HwpxCitation { .. }))
+            .collect();
+        assert!(matches!(
+            citations[..],
+            [
+                ParagraphRecord::HwpxCitation {
+                    kind: CitationKind::Citation,
+                    name: Some(name),
+                    command: Some(command),
+                    text,
+                },
+                ParagraphRecord::HwpxCitation {
+                    kind: CitationKind::CrossReference,
+                    name: None,
+                    command: None,
+                    text: cross_text,
+                },
+            ] if name == "판례1"
+                && command == "대법원 2019. 1. 17. 선고 2018다12345 판결"
+                && text == "2018다12345"
+                && cross_text == "제3조"
+        ));
+    }
+
+    #[test]
+    fn test_other_fields_keep_type_command_and_offset() {
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>작성일:</hp:t>
+<hp:ctrl><hp:fieldBegin id="31" type="DOC_DATE"><hp:parameters>
+<hp:stringParam name="Command">yyyy-MM-dd</hp:stringParam></hp:parameters></hp:fieldBegin></hp:ctrl>
+<hp:ctrl><hp:fieldEnd beginIDRef="31"/></hp:ctrl><hp:t>/</hp:t>
+<hp:ctrl><hp:fieldBegin id="32" type="SUMMARY"/></hp:ctrl><hp:t>보고서</hp:t>
+<hp:ctrl><hp:fieldEnd beginIDRef="32"/></hp:ctrl></hp:run></hp:p></hs:sec>"#;
+
+        let section = parse_section_xml(xml, 0).unwrap();
+        let paragraph = &section.paragraphs[0];
+        assert_eq!(paragraph.text(), "작성일:/보고서");
+
+        let fields: Vec<_> = paragraph
+            .records
+            .iter()
+            .filter(|r| matches!(r, ParagraphRecord::HwpxField { .. }))
+            .collect();
+        assert!(matches!(
+            fields[..],
+            [
+                ParagraphRecord::HwpxField {
+                    field_type: date_type,
+                    command: Some(command),
+                    text: date_text,
+                    offset: 4,
+                    ..
+                },
+                ParagraphRecord::HwpxField {
+                    field_type: summary_type,
+                    command: None,
+                    text: summary_text,
+                    offset: 5,
+                    ..
+                },
+            ] if date_type == "DOC_DATE"
+                && command == "yyyy-MM-dd"
+                && date_text.is_empty()
+                && summary_type == "SUMMARY"
+                && summary_text == "보고서"
+        ));
+    }
+
+    #[test]
+    fn test_xml_depth_limit() {
+        let nested = format!(
+            "<hs:sec>{}<hp:p><hp:run><hp:t>깊음</hp:t></hp:run></hp:p>{}</hs:sec>",
+            "<hp:x>".repeat(20),
+            "</hp:x>".repeat(20)
+        );
+        assert!(parse_section_xml_with(&nested, 0, 30, &mut |_| None, &mut |_| None, None).is_ok());
+        assert!(matches!(
+            parse_section_xml_with(&nested, 0, 10, &mut |_| None, &mut |_| None, None),
+            Err(HwpError::ResourceLimitExceeded {
+                limit: crate::parser::limits::ResourceLimit::XmlDepth,
+                max: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn test_lenient_parsing_recovers_from_xml_errors() {
+        // 잘못 닫힌 태그, 잘못된 엔티티, 중간에 잘린 문서
+        // Mismatched end tag, bad entity and a document cut off midway
+        let xml = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>첫째</hp:x></hp:run></hp:p>
+<hp:p><hp:run><hp:t>R&D 둘째</hp:t></hp:run></hp:p>
+<hp:p><hp:run><hp:t>셋째</hp:t></hp:run></hp:p>
+<hp:p><hp:run><hp:t>넷째</hp:t></hp:run"#;
+        assert!(parse_section_xml(xml, 0).is_err());
+
+        let mut warnings = Vec::new();
+        let mut recovery = Recovery::new("Contents/section0.xml", &mut warnings);
+        let section = parse_section_xml_with(
+            xml,
+            0,
+            usize::MAX,
+            &mut |_| None,
+            &mut |_| None,
+            Some(&mut recovery),
+        )
+        .unwrap();
+        let texts: Vec<String> = section.paragraphs.iter().map(|p| p.text()).collect();
+        assert_eq!(texts, ["첫째", "R&D 둘째", "셋째", "넷째"]);
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings
+            .iter()
+            .all(|warning| warning.part == "Contents/section0.xml"));
+        assert_eq!(warnings[0].element.as_deref(), Some("hp:t"));
+        assert!(warnings[0].message.contains("</hp:x>"));
+        assert!(warnings[1].message.starts_with("Invalid text escape"));
+        assert_eq!(warnings[2].element.as_deref(), Some("hp:run"));
     }
 }
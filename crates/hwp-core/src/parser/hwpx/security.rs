@@ -0,0 +1,110 @@
+/// HWPX security part detection
+///
+/// 서명 파트(`META-INF/signatures.xml` 등)와 DRM 파트, `META-INF/manifest.xml`의 암호화 정보,
+/// content.hpf에서 패키지에 포함되지 않은(`isEmbeded="0"`) 항목을 찾습니다.
+/// Finds signature parts (such as `META-INF/signatures.xml`), DRM parts, encryption data in
+/// `META-INF/manifest.xml` and content.hpf items that are not embedded in the package
+/// (`isEmbeded="0"`).
+use crate::document::SecurityInfo;
+
+use super::container::HwpxContainer;
+use super::integrity::{attribute, local_name, visit_elements};
+
+const META_INF: &str = "META-INF/";
+const MANIFEST_XML: &str = "META-INF/manifest.xml";
+
+/// Read security information from the package
+///
+/// Unreadable or malformed parts are skipped; they are reported by the regular parse.
+pub(crate) fn parse_security(container: &mut HwpxContainer) -> SecurityInfo {
+    let mut info = SecurityInfo::default();
+
+    let mut parts = container.list_files(META_INF);
+    parts.sort();
+    for part in parts {
+        let name = part[META_INF.len()..].to_ascii_lowercase();
+        let signature = name.contains("signature");
+        let drm = name.contains("drm");
+        if signature || drm {
+            info.signed |= signature;
+            info.drm_protected |= drm;
+            info.parts.push(part);
+        }
+    }
+
+    if let Ok(content) = container.read_file(MANIFEST_XML) {
+        let mut encrypted = false;
+        let _ = visit_elements(MANIFEST_XML, &content, |e| {
+            encrypted |= local_name(e) == "encryption-data";
+        });
+        if encrypted {
+            info.password_protected = true;
+            info.parts.push(MANIFEST_XML.to_string());
+        }
+    }
+
+    let package_path = container.package_path().to_string();
+    if let Ok(content) = container.read_file(&package_path) {
+        let _ = visit_elements(&package_path, &content, |e| {
+            if local_name(e) == "item" && attribute(e, b"isEmbeded").as_deref() == Some("0") {
+                if let Some(href) = attribute(e, b"href") {
+                    info.add_external_link(&href);
+                    if let Some(id) = attribute(e, b"id") {
+                        info.linked_items.insert(id, href);
+                    }
+                }
+            }
+        });
+    }
+
+    info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn package(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, content) in files {
+            zip.start_file(*path, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_parse_security_finds_signature_encryption_and_links() {
+        let data = package(&[
+            ("mimetype", "application/hwp+zip"),
+            ("META-INF/container.xml", "<container/>"),
+            ("META-INF/signatures.xml", "<signatures/>"),
+            (
+                "META-INF/manifest.xml",
+                r#"<odf:manifest xmlns:odf="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0"><odf:file-entry odf:full-path="Contents/section0.xml"><odf:encryption-data/></odf:file-entry></odf:manifest>"#,
+            ),
+            (
+                "Contents/content.hpf",
+                r#"<opf:package xmlns:opf="http://www.idpf.org/2007/opf/"><opf:manifest><opf:item id="image1" href="BinData/image1.png" isEmbeded="1"/><opf:item id="image2" href="file:///C:/images/logo.png" isEmbeded="0"/></opf:manifest></opf:package>"#,
+            ),
+        ]);
+        let mut container = HwpxContainer::open(&data).unwrap();
+        let info = parse_security(&mut container);
+
+        assert!(info.signed);
+        assert!(info.password_protected);
+        assert!(!info.drm_protected);
+        assert_eq!(info.external_links, vec!["file:///C:/images/logo.png"]);
+        assert_eq!(
+            info.linked_items.get("image2").map(String::as_str),
+            Some("file:///C:/images/logo.png")
+        );
+        assert_eq!(
+            info.parts,
+            vec!["META-INF/signatures.xml", "META-INF/manifest.xml"]
+        );
+    }
+}
@@ -0,0 +1,211 @@
+/// 자원 사용 한도 / Resource limits
+///
+/// 신뢰할 수 없는 업로드를 파싱할 때 ZIP 폭탄이나 지나치게 깊은 XML로 메모리를 다 쓰지 않도록
+/// 압축 해제 크기, 항목 수, XML 중첩 깊이를 제한합니다. 한도를 넘으면
+/// `HwpError::ResourceLimitExceeded`를 돌려주며, 관대한 파싱(`lenient`)에서도 복구하지 않습니다.
+///
+/// Caps decompressed sizes, entry counts and XML nesting depth so hostile uploads such as zip
+/// bombs or deeply nested XML cannot exhaust memory. Exceeding a cap returns
+/// `HwpError::ResourceLimitExceeded`, which lenient parsing does not recover from.
+use std::io::{self, Read};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::HwpError;
+
+/// 한도 종류 / Kind of limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceLimit {
+    /// 항목 하나의 압축 해제 크기 (바이트) / Decompressed size of a single entry (bytes)
+    EntrySize,
+    /// 읽은 항목 전체의 압축 해제 크기 (바이트) / Total decompressed size of all entries read (bytes)
+    TotalSize,
+    /// ZIP 항목 수 / Number of ZIP entries
+    EntryCount,
+    /// XML 요소 중첩 깊이 / XML element nesting depth
+    XmlDepth,
+}
+
+impl std::fmt::Display for ResourceLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ResourceLimit::EntrySize => "entry size",
+            ResourceLimit::TotalSize => "total uncompressed size",
+            ResourceLimit::EntryCount => "entry count",
+            ResourceLimit::XmlDepth => "XML nesting depth",
+        })
+    }
+}
+
+/// 자원 사용 한도 설정 / Resource limit settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// 항목 하나의 최대 압축 해제 크기 (기본 256 MiB) / Max decompressed size of one entry (default 256 MiB)
+    pub max_entry_size: u64,
+    /// 전체 최대 압축 해제 크기 (기본 1 GiB) / Max total decompressed size (default 1 GiB)
+    pub max_total_size: u64,
+    /// 최대 ZIP 항목 수 (기본 10,000) / Max number of ZIP entries (default 10,000)
+    pub max_entry_count: usize,
+    /// 최대 XML 중첩 깊이 (기본 256) / Max XML nesting depth (default 256)
+    pub max_xml_depth: usize,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        Self {
+            max_entry_size: 256 * 1024 * 1024,
+            max_total_size: 1024 * 1024 * 1024,
+            max_entry_count: 10_000,
+            max_xml_depth: 256,
+        }
+    }
+}
+
+impl ResourceLimits {
+    /// 한도 없음 (신뢰하는 입력에만 사용) / No limits (only for trusted input)
+    pub fn unlimited() -> Self {
+        Self {
+            max_entry_size: u64::MAX,
+            max_total_size: u64::MAX,
+            max_entry_count: usize::MAX,
+            max_xml_depth: usize::MAX,
+        }
+    }
+
+    /// 항목 하나의 최대 크기 설정 / Set the max size of one entry
+    pub fn with_max_entry_size(mut self, max_entry_size: u64) -> Self {
+        self.max_entry_size = max_entry_size;
+        self
+    }
+
+    /// 전체 최대 크기 설정 / Set the max total size
+    pub fn with_max_total_size(mut self, max_total_size: u64) -> Self {
+        self.max_total_size = max_total_size;
+        self
+    }
+
+    /// 최대 항목 수 설정 / Set the max entry count
+    pub fn with_max_entry_count(mut self, max_entry_count: usize) -> Self {
+        self.max_entry_count = max_entry_count;
+        self
+    }
+
+    /// 최대 XML 중첩 깊이 설정 / Set the max XML nesting depth
+    pub fn with_max_xml_depth(mut self, max_xml_depth: usize) -> Self {
+        self.max_xml_depth = max_xml_depth;
+        self
+    }
+}
+
+/// XML 중첩 깊이 추적 / XML nesting depth tracker
+pub(crate) struct DepthGuard {
+    depth: usize,
+    max: usize,
+}
+
+impl DepthGuard {
+    pub(crate) fn new(max: usize) -> Self {
+        Self { depth: 0, max }
+    }
+
+    /// 시작 태그 / Start tag
+    pub(crate) fn enter(&mut self) -> Result<(), HwpError> {
+        self.depth += 1;
+        if self.depth > self.max {
+            return Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::XmlDepth,
+                max: self.max as u64,
+            });
+        }
+        Ok(())
+    }
+
+    /// 끝 태그 / End tag
+    pub(crate) fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
+/// 한도를 넘게 읽으면 오류를 내는 리더 / Reader that fails once it reads past a limit
+pub(crate) struct LimitedReader<R> {
+    inner: R,
+    remaining: u64,
+    max: u64,
+}
+
+impl<R: Read> LimitedReader<R> {
+    pub(crate) fn new(inner: R, max: u64) -> Self {
+        Self {
+            inner,
+            remaining: max,
+            max,
+        }
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 {
+            // 한도에 도달: 더 읽을 내용이 있는지 1바이트로 확인 / At the limit: probe one byte for more data
+            let mut probe = [0u8; 1];
+            return match self.inner.read(&mut probe)? {
+                0 => Ok(0),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    HwpError::ResourceLimitExceeded {
+                        limit: ResourceLimit::EntrySize,
+                        max: self.max,
+                    },
+                )),
+            };
+        }
+        let len = buf
+            .len()
+            .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+        let read = self.inner.read(&mut buf[..len])?;
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+/// `LimitedReader`가 낸 한도 초과 오류인지 확인 / Whether an I/O error is a `LimitedReader` limit error
+pub(crate) fn is_limit_error(error: &io::Error) -> bool {
+    error.get_ref().is_some_and(|inner| inner.is::<HwpError>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depth_guard() {
+        let mut guard = DepthGuard::new(2);
+        assert!(guard.enter().is_ok());
+        assert!(guard.enter().is_ok());
+        assert!(matches!(
+            guard.enter(),
+            Err(HwpError::ResourceLimitExceeded {
+                limit: ResourceLimit::XmlDepth,
+                max: 2
+            })
+        ));
+        guard.leave();
+        guard.leave();
+        assert!(guard.enter().is_ok());
+    }
+
+    #[test]
+    fn test_limited_reader() {
+        let mut exact = LimitedReader::new(&b"abcd"[..], 4);
+        let mut out = Vec::new();
+        exact.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"abcd");
+
+        let mut over = LimitedReader::new(&b"abcde"[..], 4);
+        let error = over.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(is_limit_error(&error));
+        assert!(error.to_string().contains("entry size"));
+    }
+}
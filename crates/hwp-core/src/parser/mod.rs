@@ -1,8 +1,21 @@
 /// Parser module for HWP/HWPX file format detection and parsing
 ///
-/// This module provides format detection and parsing for both HWP 5.0 (CFB-based)
-/// and HWPX (ZIP-based) file formats.
+/// This module provides format detection and parsing for HWP 5.0 (CFB-based),
+/// HWPX (ZIP-based), HWPML (single XML) and legacy HWP 3.0 file formats.
 pub mod detect;
+pub mod hwp3;
+pub mod hwpml;
 pub mod hwpx;
+pub mod limits;
+pub mod options;
+pub mod preview;
+pub mod profile;
+pub mod salvage;
+pub mod text_stream;
 
-pub use detect::{detect_format, FileFormat};
+pub use detect::{detect_format, detect_format_reader, is_template_path, FileFormat};
+pub use limits::{ResourceLimit, ResourceLimits};
+pub use options::{ParseOptions, ParseWarning};
+pub use preview::ParseOutcome;
+pub use profile::{ParsePhase, ParseProfile, PhaseTiming};
+pub use salvage::SalvageLog;
@@ -0,0 +1,80 @@
+/// 파싱 옵션 및 경고 / Parse options and warnings
+use serde::{Deserialize, Serialize};
+
+use super::limits::ResourceLimits;
+
+/// 파싱 옵션 / Parse options
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// 손상된 XML 복구 (이스케이프되지 않은 `&`/`<`, 허용되지 않는 제어 문자 정리)
+    /// Repair malformed XML (unescaped `&`/`<`, disallowed control characters)
+    pub repair_xml: bool,
+
+    /// 관대한 파싱: XML 오류가 나도 중단하지 않고 경고로 기록한 뒤 계속 진행
+    /// Lenient parsing: record XML errors as warnings and keep going instead of failing
+    pub lenient: bool,
+
+    /// 단계별 소요 시간을 `HwpDocument::profile`에 기록
+    /// Record per-phase timing in `HwpDocument::profile`
+    pub profiling: bool,
+
+    /// 서식 파일(.hwt, .hwtx)로 표시 (형식만으로는 구분할 수 없음)
+    /// Mark the document as a template (.hwt, .hwtx), which the bytes alone cannot tell
+    pub template: bool,
+
+    /// 압축 해제 크기, 항목 수, XML 중첩 깊이 한도 / Limits on decompressed size, entry count and XML nesting depth
+    pub limits: ResourceLimits,
+}
+
+impl ParseOptions {
+    /// XML 복구 여부 설정 / Set whether malformed XML is repaired
+    pub fn with_repair_xml(mut self, repair_xml: bool) -> Self {
+        self.repair_xml = repair_xml;
+        self
+    }
+
+    /// 관대한 파싱 여부 설정 / Set whether parsing recovers from errors
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// 단계별 시간 측정 여부 설정 / Set whether per-phase timing is recorded
+    pub fn with_profiling(mut self, profiling: bool) -> Self {
+        self.profiling = profiling;
+        self
+    }
+
+    /// 서식 파일 여부 설정 / Set whether the input is a template file
+    pub fn with_template(mut self, template: bool) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// 자원 사용 한도 설정 / Set resource limits
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// 파싱 중 복구되거나 무시된 문제 / Problem that was repaired or ignored during parsing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseWarning {
+    /// 문제가 발생한 파트 경로 (예: "Contents/section0.xml") / Part path where the problem occurred (e.g. "Contents/section0.xml")
+    pub part: String,
+    /// 문제가 발생한 요소 (예: "hp:p") / Element where the problem occurred (e.g. "hp:p")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<String>,
+    /// 설명 / Description
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.element {
+            Some(element) => write!(f, "{} <{}>: {}", self.part, element, self.message),
+            None => write!(f, "{}: {}", self.part, self.message),
+        }
+    }
+}
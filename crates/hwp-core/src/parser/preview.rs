@@ -0,0 +1,69 @@
+/// 미리보기 텍스트 대체 파싱 / Preview text fallback parsing
+///
+/// 본문을 읽지 못해도 HWP 5.0의 `PrvText` 스트림이나 HWPX의 `Preview/PrvText.txt`는 따로
+/// 읽을 수 있는 경우가 많습니다. 전체 파싱이 실패하면 이 미리보기 텍스트라도 돌려줍니다.
+/// Even when the body cannot be read, the `PrvText` stream (HWP 5.0) or `Preview/PrvText.txt`
+/// (HWPX) can often still be read on its own. When full parsing fails, this preview text is
+/// returned instead.
+use super::hwpx::container::HwpxContainer;
+use super::{detect_format, FileFormat, ResourceLimits};
+use crate::cfb::CfbParser;
+use crate::document::{HwpDocument, PreviewText};
+use crate::error::HwpError;
+
+/// 전체 파싱 또는 미리보기 텍스트 결과 / Result of full parsing or the preview text fallback
+#[derive(Debug, Clone)]
+pub enum ParseOutcome {
+    /// 문서 전체를 읽음 / The whole document was read
+    Full(HwpDocument),
+    /// 전체 파싱이 실패해 미리보기 텍스트만 얻음 / Full parsing failed, only the preview text was read
+    PreviewOnly {
+        /// 미리보기 텍스트 / Preview text
+        text: String,
+        /// 전체 파싱이 실패한 이유 / Why full parsing failed
+        error: HwpError,
+    },
+}
+
+impl ParseOutcome {
+    /// 미리보기 텍스트만 얻었는지 확인 / Whether only the preview text was read
+    pub fn is_degraded(&self) -> bool {
+        matches!(self, ParseOutcome::PreviewOnly { .. })
+    }
+
+    /// 전체 파싱된 문서 (미리보기만 있으면 None) / Fully parsed document (None for preview only)
+    pub fn document(&self) -> Option<&HwpDocument> {
+        match self {
+            ParseOutcome::Full(document) => Some(document),
+            ParseOutcome::PreviewOnly { .. } => None,
+        }
+    }
+
+    /// 문서의 일반 텍스트 또는 미리보기 텍스트 / Plain text of the document, or the preview text
+    pub fn text(&self) -> String {
+        match self {
+            ParseOutcome::Full(document) => document.text(),
+            ParseOutcome::PreviewOnly { text, .. } => text.clone(),
+        }
+    }
+}
+
+/// 파일에서 미리보기 텍스트만 읽기 (없거나 읽을 수 없으면 None)
+/// Read only the preview text from a file (None when missing or unreadable)
+pub(crate) fn read_preview_text(data: &[u8], limits: &ResourceLimits) -> Option<String> {
+    let text = match detect_format(data) {
+        FileFormat::Hwp5 => {
+            let mut cfb = CfbParser::parse(data).ok()?;
+            let stream = CfbParser::read_stream(&mut cfb, "PrvText").ok()?;
+            PreviewText::parse(&stream).ok()?.text
+        }
+        FileFormat::Hwpx => {
+            let mut container = HwpxContainer::open_with_limits(data, *limits).ok()?;
+            container.read_file_string("Preview/PrvText.txt").ok()?
+        }
+        // HWPML과 HWP 3.0에는 미리보기 텍스트가 없음 / HWPML and HWP 3.0 have no preview text
+        FileFormat::Hwpml | FileFormat::Hwp3 | FileFormat::Unknown => return None,
+    };
+    let text = text.trim_end_matches('\0').trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
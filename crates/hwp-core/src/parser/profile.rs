@@ -0,0 +1,153 @@
+/// 파싱 단계별 소요 시간 / Per-phase parse timing
+///
+/// `ParseOptions::profiling`을 켜면 파서가 단계마다 걸린 시간을 `HwpDocument::profile`에 남깁니다.
+/// When `ParseOptions::profiling` is enabled the parser records the time spent in each phase
+/// in `HwpDocument::profile`.
+use std::time::{Duration, Instant};
+
+/// 파싱 단계 / Parse phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParsePhase {
+    /// 컨테이너 열기 (CFB 또는 ZIP) / Opening the container (CFB or ZIP)
+    Container,
+    /// 파일 헤더와 문서 정보 (HWPX: version.xml, header.xml) / File header and document info (HWPX: version.xml, header.xml)
+    Header,
+    /// 본문 구역 / Body sections
+    Sections,
+    /// 바이너리 데이터 (이미지 등) / Binary data (images, etc.)
+    BinData,
+    /// 미리보기, 요약 정보, 스크립트 등 선택 항목 / Optional parts such as previews, summary information and scripts
+    Metadata,
+    /// 표시 텍스트 해석 (자동 번호 등) / Display text resolution (auto numbers, etc.)
+    DisplayText,
+}
+
+impl ParsePhase {
+    /// 단계 이름 / Phase name
+    pub fn name(self) -> &'static str {
+        match self {
+            ParsePhase::Container => "container",
+            ParsePhase::Header => "header",
+            ParsePhase::Sections => "sections",
+            ParsePhase::BinData => "bin_data",
+            ParsePhase::Metadata => "metadata",
+            ParsePhase::DisplayText => "display_text",
+        }
+    }
+}
+
+impl std::fmt::Display for ParsePhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// 단계 하나의 소요 시간 / Time spent in one phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseTiming {
+    /// 단계 / Phase
+    pub phase: ParsePhase,
+    /// 소요 시간 / Elapsed time
+    pub duration: Duration,
+}
+
+/// 파싱 프로파일 (실행 순서대로) / Parse profile, in execution order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseProfile {
+    /// 단계별 소요 시간 / Per-phase timings
+    pub phases: Vec<PhaseTiming>,
+}
+
+impl ParseProfile {
+    /// 단계의 소요 시간 / Time spent in a phase
+    ///
+    /// # Returns / 반환값
+    /// 실행되지 않은 단계는 None / None if the phase did not run
+    pub fn duration(&self, phase: ParsePhase) -> Option<Duration> {
+        self.phases
+            .iter()
+            .find(|timing| timing.phase == phase)
+            .map(|timing| timing.duration)
+    }
+
+    /// 전체 소요 시간 / Total time across all phases
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|timing| timing.duration).sum()
+    }
+
+    /// 소요 시간 기록 (같은 단계는 누적) / Record elapsed time, accumulating repeated phases
+    fn record(&mut self, phase: ParsePhase, duration: Duration) {
+        match self.phases.iter_mut().find(|timing| timing.phase == phase) {
+            Some(timing) => timing.duration += duration,
+            None => self.phases.push(PhaseTiming { phase, duration }),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for timing in &self.phases {
+            writeln!(
+                f,
+                "{:<12} {:>10.3} ms",
+                timing.phase,
+                millis(timing.duration)
+            )?;
+        }
+        write!(f, "{:<12} {:>10.3} ms", "total", millis(self.total()))
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// 단계 시간 측정기 (비활성 시 측정하지 않음) / Phase timer that does nothing when disabled
+pub(crate) struct Profiler {
+    profile: Option<ParseProfile>,
+}
+
+impl Profiler {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            profile: enabled.then(ParseProfile::default),
+        }
+    }
+
+    /// `f`를 실행하고 걸린 시간을 `phase`에 기록 / Run `f` and record its time under `phase`
+    pub(crate) fn time<T>(&mut self, phase: ParsePhase, f: impl FnOnce() -> T) -> T {
+        let Some(profile) = self.profile.as_mut() else {
+            return f();
+        };
+        let start = Instant::now();
+        let result = f();
+        profile.record(phase, start.elapsed());
+        result
+    }
+
+    pub(crate) fn finish(self) -> Option<ParseProfile> {
+        self.profile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiler_accumulates_phases() {
+        let mut profiler = Profiler::new(true);
+        assert_eq!(profiler.time(ParsePhase::Container, || 1), 1);
+        profiler.time(ParsePhase::Sections, || {});
+        profiler.time(ParsePhase::Container, || {});
+        let profile = profiler.finish().unwrap();
+        let phases: Vec<ParsePhase> = profile.phases.iter().map(|t| t.phase).collect();
+        assert_eq!(phases, [ParsePhase::Container, ParsePhase::Sections]);
+        assert_eq!(profile.duration(ParsePhase::BinData), None);
+        assert!(profile.to_string().ends_with(" ms"));
+
+        let mut disabled = Profiler::new(false);
+        assert_eq!(disabled.time(ParsePhase::Header, || 2), 2);
+        assert_eq!(disabled.finish(), None);
+    }
+}
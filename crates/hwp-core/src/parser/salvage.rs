@@ -0,0 +1,467 @@
+/// 손상된 문서 복구 파싱 / Salvage parsing for damaged documents
+///
+/// 잘리거나 일부가 깨진 파일에서 읽을 수 있는 내용만 모읍니다. 읽을 수 없는 파트는 건너뛰고,
+/// ZIP 중앙 디렉터리가 없으면 로컬 파일 헤더로 패키지를 다시 만들며, 본문을 하나도 읽지 못하면
+/// 미리보기 텍스트로 대신합니다. 무엇을 했는지는 `SalvageLog`에 남습니다.
+/// Collects whatever can be read from truncated or partially corrupted files. Unreadable parts
+/// are skipped, a ZIP without a central directory is rebuilt from its local file headers, and
+/// the preview text stands in when no body text can be read. `SalvageLog` records what was done.
+use std::io::{Cursor, Read, Write};
+
+use ::cfb::CompoundFile;
+use serde::{Deserialize, Serialize};
+
+use super::hwpx::container::HwpxContainer;
+use super::hwpx::integrity::IntegrityReport;
+use super::hwpx::{bindata, header, parse_optional_parts, section};
+use super::{ParseOptions, ParseWarning, ResourceLimit, ResourceLimits};
+use crate::cfb::CfbParser;
+use crate::decompress::decompress_deflate;
+use crate::document::bodytext::{ParaHeader, ParaTextRun, Section};
+use crate::document::{
+    HwpDocument, Paragraph, ParagraphRecord, PreviewText, SecurityInfo, SourceFormat,
+};
+use crate::error::HwpError;
+use crate::types::WORD;
+use crate::HwpParser;
+
+/// 복구 기록 / Salvage log
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SalvageLog {
+    /// 로컬 파일 헤더로 ZIP 패키지를 다시 만들었는지 / Whether the ZIP package was rebuilt from local file headers
+    pub rebuilt_archive: bool,
+    /// 구역을 읽은 순서 (파트 또는 스트림 경로) / Order in which sections were read (part or stream paths)
+    pub section_order: Vec<String>,
+    /// 읽지 못해 건너뛴 파트 / Parts skipped because they could not be read
+    pub skipped_parts: Vec<String>,
+    /// 본문 대신 미리보기 텍스트를 썼는지 / Whether the preview text replaced the body text
+    pub used_preview_text: bool,
+    /// HWPX 패키지 무결성 보고서 / HWPX package integrity report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity: Option<IntegrityReport>,
+    /// 복구 중 경고 / Warnings raised while salvaging
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl SalvageLog {
+    /// 아무것도 잃지 않고 읽었는지 확인 / Whether everything was read without loss
+    pub fn is_clean(&self) -> bool {
+        !self.rebuilt_archive
+            && self.skipped_parts.is_empty()
+            && !self.used_preview_text
+            && self.warnings.is_empty()
+    }
+
+    fn skip(&mut self, part: impl Into<String>, error: &HwpError) {
+        let part = part.into();
+        self.warnings.push(ParseWarning {
+            part: part.clone(),
+            element: None,
+            message: error.to_string(),
+        });
+        self.skipped_parts.push(part);
+    }
+}
+
+/// HWPML 파일 복구 파싱 / Salvage an HWPML file
+///
+/// 하나의 XML 파일이라 다시 만들 패키지는 없고, `lenient`와 `repair_xml`을 켜고 읽기만 합니다.
+/// Being a single XML file there is no package to rebuild; it is read with `lenient` and
+/// `repair_xml` enabled.
+pub(crate) fn salvage_hwpml(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, SalvageLog), HwpError> {
+    let options = options.clone().with_lenient(true).with_repair_xml(true);
+    let (document, warnings) = super::hwpml::parse_with_options(data, &options)?;
+    let log = SalvageLog {
+        section_order: (0..document.body_text.sections.len())
+            .map(|index| format!("BODY/SECTION[{index}]"))
+            .collect(),
+        warnings,
+        ..Default::default()
+    };
+    Ok((document, log))
+}
+
+/// HWP 3.0 파일 복구 파싱 / Salvage an HWP 3.0 file
+///
+/// 구역이 하나뿐인 평평한 바이너리라 다시 맞출 것이 없어 그대로 읽습니다.
+/// A flat binary with a single section leaves nothing to rebuild, so it is read as is.
+pub(crate) fn salvage_hwp3(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, SalvageLog), HwpError> {
+    let (document, warnings) = super::hwp3::parse_with_options(data, options)?;
+    let log = SalvageLog {
+        section_order: vec!["BodyText".to_string()],
+        warnings,
+        ..Default::default()
+    };
+    Ok((document, log))
+}
+
+/// HWPX 패키지 복구 파싱 / Salvage an HWPX package
+pub(crate) fn salvage_hwpx(
+    data: &[u8],
+    options: &ParseOptions,
+) -> Result<(HwpDocument, SalvageLog), HwpError> {
+    let options = options.clone().with_lenient(true).with_repair_xml(true);
+    let mut log = SalvageLog::default();
+
+    let rebuilt;
+    let mut container = match HwpxContainer::open_with_limits(data, options.limits) {
+        Ok(container) => container,
+        Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+            return Err(error)
+        }
+        Err(error) => {
+            rebuilt = rebuild_archive(data, &options.limits, &mut log).ok_or(error)?;
+            log.rebuilt_archive = true;
+            HwpxContainer::open_with_limits(&rebuilt, options.limits)?
+        }
+    };
+    log.integrity = container.integrity_report().ok();
+
+    let mut document = HwpDocument::new(header::parse_file_header(&mut container)?);
+    document.source_format = SourceFormat::Hwpx;
+    document.doc_info = match header::parse_doc_info_with_limits(&mut container, &options.limits) {
+        Ok(doc_info) => doc_info,
+        Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+            return Err(error)
+        }
+        Err(error) => {
+            log.skip(container.header_path(), &error);
+            Default::default()
+        }
+    };
+
+    // spine 순서를 먼저 따르고, spine에 없는 구역은 번호 순으로 뒤에 붙임
+    // Follow the spine first, then append sections missing from it in number order
+    let order = container.section_order();
+
+    let mut warnings = Vec::new();
+    for path in &order {
+        let index = document.body_text.sections.len();
+        let section = container.read_file_string(path).and_then(|content| {
+            section::parse_section_content(
+                &mut container,
+                index,
+                path,
+                content,
+                &options,
+                &mut warnings,
+            )
+        });
+        match section {
+            Ok(section) => {
+                log.section_order.push(path.clone());
+                document.body_text.sections.push(section);
+            }
+            Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+                return Err(error)
+            }
+            Err(error) => log.skip(path.as_str(), &error),
+        }
+    }
+
+    document.bin_data = match bindata::parse_bindata(&mut container) {
+        Ok(bin_data) => bin_data,
+        Err(error) if matches!(error.kind(), HwpError::ResourceLimitExceeded { .. }) => {
+            return Err(error)
+        }
+        Err(error) => {
+            log.skip("BinData", &error);
+            Default::default()
+        }
+    };
+    parse_optional_parts(&mut container, &mut document, true, &mut warnings);
+    log.warnings.splice(0..0, warnings);
+
+    finish(&mut document, &mut log);
+    Ok((document, log))
+}
+
+/// HWP 5.0 파일 복구 파싱 / Salvage an HWP 5.0 file
+///
+/// FileHeader는 압축 여부와 버전을 알려 주므로 읽지 못하면 실패합니다.
+/// The FileHeader tells compression and version, so failing to read it is an error.
+pub(crate) fn salvage_hwp5(
+    parser: &HwpParser,
+    data: &[u8],
+) -> Result<(HwpDocument, SalvageLog), HwpError> {
+    let mut log = SalvageLog::default();
+    let mut cfb = CfbParser::parse(data)?;
+    let fileheader = parser.parse_fileheader(&mut cfb)?;
+    fileheader.ensure_readable()?;
+    let security = SecurityInfo::parse_doc_options(&mut cfb);
+    security.ensure_readable()?;
+
+    let mut document = HwpDocument::new(fileheader.clone());
+    document.security = security;
+    document.doc_info = parser
+        .parse_docinfo(&mut cfb, &fileheader)
+        .unwrap_or_else(|error| {
+            log.skip("DocInfo", &error);
+            Default::default()
+        });
+
+    for number in hwp5_section_numbers(&cfb, &document) {
+        let stream_name = format!("Section{number}");
+        let paragraphs = CfbParser::read_nested_stream(&mut cfb, "BodyText", &stream_name)
+            .and_then(|section_data| {
+                if fileheader.is_compressed() {
+                    decompress_deflate(&section_data)
+                } else {
+                    Ok(section_data)
+                }
+            })
+            .and_then(|section_data| Section::parse_data(&section_data, fileheader.version));
+        let path = format!("BodyText/{stream_name}");
+        match paragraphs {
+            Ok(paragraphs) => {
+                let index = document.body_text.sections.len() as WORD;
+                document
+                    .body_text
+                    .sections
+                    .push(Section { index, paragraphs });
+                log.section_order.push(path);
+            }
+            Err(error) => log.skip(path, &error),
+        }
+    }
+
+    document.bin_data = parser
+        .parse_bindata(&mut cfb, &document.doc_info)
+        .unwrap_or_else(|error| {
+            log.skip("BinData", &error);
+            Default::default()
+        });
+    parser.parse_optional_streams(&mut cfb, &fileheader, &mut document, data);
+
+    finish(&mut document, &mut log);
+    Ok((document, log))
+}
+
+/// 읽을 구역 번호: DocInfo의 구역 개수와 실제 BodyText 스트림을 합쳐 번호 순으로
+/// Section numbers to read: the DocInfo section count merged with the actual BodyText streams, in number order
+fn hwp5_section_numbers(cfb: &CompoundFile<Cursor<&[u8]>>, document: &HwpDocument) -> Vec<u32> {
+    let declared = document
+        .doc_info
+        .document_properties
+        .as_ref()
+        .map_or(1, |props| props.area_count as u32);
+    let mut numbers: Vec<u32> = (0..declared).collect();
+    if let Ok(entries) = cfb.read_storage("/BodyText") {
+        numbers.extend(entries.filter_map(|entry| {
+            entry
+                .name()
+                .strip_prefix("Section")
+                .and_then(|number| number.parse::<u32>().ok())
+        }));
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+    numbers
+}
+
+/// 본문이 비었으면 미리보기 텍스트로 채우고 표시 텍스트 계산
+/// Fill an empty body from the preview text and resolve display texts
+fn finish(document: &mut HwpDocument, log: &mut SalvageLog) {
+    let has_body = document
+        .body_text
+        .sections
+        .iter()
+        .any(|section| !section.paragraphs.is_empty());
+    if !has_body {
+        if let Some(PreviewText { text }) = &document.preview_text {
+            let paragraphs: Vec<Paragraph> = text
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(preview_paragraph)
+                .collect();
+            if !paragraphs.is_empty() {
+                document.body_text.sections = vec![Section {
+                    index: 0,
+                    paragraphs,
+                }];
+                log.used_preview_text = true;
+            }
+        }
+    }
+    document.resolve_display_texts();
+    document.warnings = log.warnings.clone();
+}
+
+/// 미리보기 텍스트 한 줄로 만든 문단 / Paragraph made from one line of preview text
+fn preview_paragraph(text: &str) -> Paragraph {
+    Paragraph {
+        para_header: ParaHeader {
+            text_char_count: text.chars().count() as u32,
+            ..Default::default()
+        },
+        records: vec![ParagraphRecord::ParaText {
+            text: text.to_string(),
+            runs: vec![ParaTextRun::Text {
+                text: text.to_string(),
+            }],
+            control_char_positions: vec![],
+            inline_control_params: vec![],
+        }],
+        resolved_number: None,
+    }
+}
+
+/// 로컬 파일 헤더를 차례로 읽어 ZIP 패키지 재구성 (읽을 수 있는 항목이 없으면 None)
+/// Rebuild a ZIP package by walking its local file headers (None when no entry is readable)
+///
+/// 중앙 디렉터리가 잘린 파일도 앞쪽 항목은 온전한 경우가 많습니다. 처음으로 읽지 못한 항목에서
+/// 멈추며, 그 뒤의 데이터는 위치를 알 수 없으므로 버립니다.
+/// Files with a truncated central directory often still have intact leading entries. Walking
+/// stops at the first unreadable entry, since nothing after it can be located reliably.
+fn rebuild_archive(data: &[u8], limits: &ResourceLimits, log: &mut SalvageLog) -> Option<Vec<u8>> {
+    let mut reader = Cursor::new(data);
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let mut count = 0;
+    let mut total = 0u64;
+    loop {
+        let mut file = match zip::read::read_zipfile_from_stream(&mut reader) {
+            Ok(Some(file)) => file,
+            Ok(None) => break,
+            Err(error) => {
+                log.warnings.push(ParseWarning {
+                    part: "ZIP".to_string(),
+                    element: None,
+                    message: format!("stopped reading local file headers: {error}"),
+                });
+                break;
+            }
+        };
+        let name = file.name().to_string();
+        if file.is_dir() {
+            continue;
+        }
+        count += 1;
+        let mut content = Vec::new();
+        let read = (&mut file)
+            .take(limits.max_entry_size.saturating_add(1))
+            .read_to_end(&mut content);
+        total += content.len() as u64;
+        let exceeded = if count > limits.max_entry_count {
+            Some(ResourceLimit::EntryCount)
+        } else if content.len() as u64 > limits.max_entry_size {
+            Some(ResourceLimit::EntrySize)
+        } else if total > limits.max_total_size {
+            Some(ResourceLimit::TotalSize)
+        } else {
+            None
+        };
+        if let Some(limit) = exceeded {
+            log.warnings.push(ParseWarning {
+                part: name,
+                element: None,
+                message: format!("stopped rebuilding the package: {limit} limit reached"),
+            });
+            break;
+        }
+        if let Err(error) = read {
+            log.skip(name, &HwpError::from(error));
+            break;
+        }
+        let stored = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        if writer.start_file(name.as_str(), stored).is_err() || writer.write_all(&content).is_err()
+        {
+            break;
+        }
+    }
+    if count == 0 {
+        return None;
+    }
+    writer.finish().ok().map(Cursor::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zip(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer
+                .start_file(*name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn section(text: &str) -> String {
+        format!(
+            r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>{text}</hp:t></hp:run></hp:p></hs:sec>"#
+        )
+    }
+
+    #[test]
+    fn test_salvage_truncated_package_in_spine_order() {
+        let content = r#"<opf:package xmlns:opf="o"><opf:manifest>
+<opf:item id="s1" href="Contents/section1.xml"/><opf:item id="s0" href="Contents/section0.xml"/>
+</opf:manifest><opf:spine><opf:itemref idref="s1"/><opf:itemref idref="s0"/></opf:spine></opf:package>"#;
+        let mut data = zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/content.hpf", content),
+            (
+                "Contents/header.xml",
+                r#"<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head"/>"#,
+            ),
+            ("Contents/section0.xml", &section("둘째")),
+            ("Contents/section1.xml", &section("첫째")),
+            ("Contents/section2.xml", &section("셋째")),
+        ]);
+        // 셋째 구역 중간에서 잘림 (중앙 디렉터리 없음) / Truncated inside the third section (no central directory)
+        let name = b"Contents/section2.xml";
+        let cut = data
+            .windows(name.len())
+            .position(|window| window == name)
+            .unwrap();
+        data.truncate(cut + 30);
+
+        assert!(HwpParser::new().parse(&data).is_err());
+        let (document, log) = HwpParser::new().parse_salvage(&data).unwrap();
+        assert!(log.rebuilt_archive);
+        assert_eq!(
+            log.section_order,
+            ["Contents/section1.xml", "Contents/section0.xml"]
+        );
+        assert_eq!(log.skipped_parts, ["Contents/section2.xml"]);
+        let texts: Vec<String> = document
+            .body_text
+            .sections
+            .iter()
+            .map(|section| section.paragraphs[0].text())
+            .collect();
+        assert_eq!(texts, ["첫째", "둘째"]);
+        assert!(!log.is_clean());
+    }
+
+    #[test]
+    fn test_salvage_falls_back_to_preview_text() {
+        let data = zip(&[
+            (
+                "Contents/header.xml",
+                r#"<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head"/>"#,
+            ),
+            ("Contents/section0.xml", "<hs:sec"),
+            ("Preview/PrvText.txt", "남은 내용\r\n둘째 줄"),
+        ]);
+
+        let (document, log) = HwpParser::new().parse_salvage(&data).unwrap();
+        assert!(log.used_preview_text);
+        assert_eq!(document.body_text.sections[0].paragraphs.len(), 2);
+        assert_eq!(
+            document.body_text.sections[0].paragraphs[1].text(),
+            "둘째 줄"
+        );
+    }
+}
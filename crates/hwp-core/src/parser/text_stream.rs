@@ -0,0 +1,219 @@
+use super::hwpml;
+/// Streaming text extraction
+///
+/// 색인처럼 본문 텍스트만 필요한 경우를 위해 문단/표 구조를 만들지 않고 문단 텍스트를 하나씩
+/// 콜백으로 넘깁니다. HWPX는 구역 XML을 quick-xml로 훑고, HWP 5.0은 구역 스트림의 레코드를
+/// 트리로 만들지 않고 차례로 읽어 `PARA_TEXT`만 해독합니다. 한 번에 메모리에 있는 것은 구역 하나와
+/// 재사용하는 문단 버퍼뿐입니다.
+///
+/// For text-only workloads such as indexing, paragraph text is handed to a callback one
+/// paragraph at a time without building paragraph or table structures. HWPX section XML is walked
+/// with quick-xml; HWP 5.0 section streams are scanned record by record, without a record tree,
+/// decoding only `PARA_TEXT`. Only one section and a reused paragraph buffer are held at a time.
+use super::hwpx::borrowed::{walk_section, SectionEvent};
+use super::hwpx::container::{decode_xml, HwpxContainer};
+use super::limits::ResourceLimits;
+use super::options::ParseOptions;
+use crate::cfb::CfbParser;
+use crate::decompress::decompress_deflate;
+use crate::document::bodytext::{ControlChar, HwpTag};
+use crate::document::FileHeader;
+use crate::error::HwpError;
+use crate::types::RecordHeader;
+
+/// Emit the trimmed paragraph text if it is not blank, then clear the buffer
+/// 문단 텍스트가 비어 있지 않으면 앞뒤 공백을 지워 넘기고 버퍼를 비움
+fn flush(text: &mut String, emit: &mut impl FnMut(&str)) {
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        emit(trimmed);
+    }
+    text.clear();
+}
+
+/// Stream paragraph text of an HWP 3.0 file
+/// HWP 3.0 파일의 문단 텍스트 스트리밍
+///
+/// 본문 전체가 하나의 (압축된) 블록이라 문서를 읽은 뒤 문단을 차례로 넘깁니다.
+/// The whole body is a single (compressed) block, so the document is read first and its
+/// paragraphs are emitted in order.
+pub fn stream_hwp3_text(
+    data: &[u8],
+    options: &ParseOptions,
+    emit: &mut impl FnMut(&str),
+) -> Result<(), HwpError> {
+    let (document, _) = super::hwp3::parse_with_options(data, options)?;
+    for section in &document.body_text.sections {
+        for paragraph in &section.paragraphs {
+            for paragraph in std::iter::once(paragraph).chain(paragraph.nested_paragraphs()) {
+                let mut text = paragraph.text();
+                flush(&mut text, emit);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Stream paragraph text of an HWPML file
+/// HWPML 파일의 문단 텍스트 스트리밍
+///
+/// 문서 전체를 OWPML 구역 XML로 옮긴 뒤 HWPX와 같은 방법으로 훑습니다.
+/// The whole document is translated into OWPML section XML and then walked as for HWPX.
+pub fn stream_hwpml_text(
+    data: &[u8],
+    limits: ResourceLimits,
+    emit: &mut impl FnMut(&str),
+) -> Result<(), HwpError> {
+    let content = hwpml::decode(data)?;
+    let parts = hwpml::translate(&content, limits.max_xml_depth)?;
+
+    let mut text = String::new();
+    for section in &parts.sections {
+        walk_section(section, limits.max_xml_depth, |event| match event {
+            SectionEvent::ParagraphStart { .. } | SectionEvent::ParagraphEnd => {
+                flush(&mut text, emit)
+            }
+            SectionEvent::Text { text: piece, .. } => text.push_str(&piece),
+        })?;
+    }
+    Ok(())
+}
+
+/// Stream paragraph text of an HWPX file
+/// HWPX 파일의 문단 텍스트 스트리밍
+///
+/// 표 셀과 글상자 안의 문단도 문서 순서대로 나옵니다. 표나 글상자를 담은 문단은 그 앞뒤로 나뉘어
+/// 나올 수 있습니다. 메모 내용은 제외합니다.
+/// Paragraphs inside table cells and text boxes are emitted in document order. A paragraph holding
+/// a table or text box may be emitted in two parts, before and after it. Memo contents are excluded.
+pub fn stream_hwpx_text(
+    data: &[u8],
+    limits: ResourceLimits,
+    emit: &mut impl FnMut(&str),
+) -> Result<(), HwpError> {
+    let mut container = HwpxContainer::open_with_limits(data, limits)?;
+    let section_files = container.section_order();
+    if section_files.is_empty() {
+        return Err(HwpError::InvalidHwpxStructure {
+            reason: "No section files found in Contents/".to_string(),
+        });
+    }
+
+    let mut buffer = Vec::new();
+    let mut text = String::new();
+    for path in &section_files {
+        container.read_file_into(path, &mut buffer)?;
+        let content = decode_xml(&buffer)?;
+        walk_section(&content, limits.max_xml_depth, |event| match event {
+            // 중첩 문단이 시작되면 바깥 문단의 앞부분을 먼저 넘김 / A nested paragraph flushes the outer paragraph's text so far
+            SectionEvent::ParagraphStart { .. } | SectionEvent::ParagraphEnd => {
+                flush(&mut text, emit)
+            }
+            SectionEvent::Text { text: piece, .. } => text.push_str(&piece),
+        })?;
+    }
+    Ok(())
+}
+
+/// Stream paragraph text of an HWP 5.0 file
+/// HWP 5.0 파일의 문단 텍스트 스트리밍
+///
+/// 레코드 순서대로 나오므로 문단 다음에 그 문단이 담은 표 셀, 머리말, 각주의 문단이 옵니다.
+/// 텍스트 규칙은 `Paragraph::text`와 같습니다.
+/// Emitted in record order, so a paragraph is followed by the paragraphs of its table cells,
+/// headers and footnotes. Text rules match `Paragraph::text`.
+pub fn stream_hwp5_text(data: &[u8], emit: &mut impl FnMut(&str)) -> Result<(), HwpError> {
+    let mut cfb = CfbParser::parse(data)?;
+    let file_header = FileHeader::parse(&CfbParser::read_stream(&mut cfb, "FileHeader")?)?;
+    file_header.ensure_readable()?;
+
+    let mut text = String::new();
+    // 구역 스트림은 Section0부터 빠짐없이 이어짐 / Section streams run from Section0 without gaps
+    for index in 0.. {
+        let Ok(mut section) =
+            CfbParser::read_nested_stream(&mut cfb, "BodyText", &format!("Section{index}"))
+        else {
+            break;
+        };
+        if file_header.is_compressed() {
+            section = decompress_deflate(&section)?;
+        }
+
+        let mut offset = 0;
+        while offset < section.len() {
+            let (header, header_size) = RecordHeader::parse(&section[offset..])?;
+            let start = offset + header_size;
+            let body = start
+                .checked_add(header.size as usize)
+                .and_then(|end| section.get(start..end))
+                .ok_or_else(|| {
+                    HwpError::insufficient_data(
+                        "record data",
+                        header.size as usize,
+                        section.len().saturating_sub(start),
+                    )
+                })?;
+            if header.tag_id == HwpTag::PARA_TEXT {
+                push_para_text(body, &mut text);
+                flush(&mut text, emit);
+            }
+            offset = start + body.len();
+        }
+    }
+    Ok(())
+}
+
+/// Decode a `PARA_TEXT` record into `out`
+/// `PARA_TEXT` 레코드를 해독하여 `out`에 붙임
+///
+/// 변환 가능한 제어 문자(탭, 하이픈, 빈칸)만 텍스트로 바꾸고 줄/문단 끝과 나머지 제어 문자는 뺍니다.
+/// Only convertible control characters (tab, hyphen, spaces) become text; line/paragraph breaks
+/// and other controls are dropped.
+fn push_para_text(data: &[u8], out: &mut String) {
+    // 홀수 길이면 마지막 1바이트는 무시 / Ignore a trailing odd byte
+    let count = data.len() / 2;
+    let unit = |index: usize| u16::from_le_bytes([data[index * 2], data[index * 2 + 1]]);
+
+    let mut index = 0;
+    while index < count {
+        let code = unit(index);
+        if code <= 31 {
+            let code = code as u8;
+            if code != ControlChar::PARA_BREAK && code != ControlChar::LINE_BREAK {
+                if let Some(text) = ControlChar::to_text(code) {
+                    out.push_str(text);
+                }
+            }
+            index += ControlChar::get_size_by_code(code);
+        } else {
+            let end = (index..count).find(|&i| unit(i) <= 31).unwrap_or(count);
+            out.extend(
+                char::decode_utf16((index..end).map(unit))
+                    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+            );
+            index = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[test]
+    fn test_push_para_text_skips_controls() {
+        let mut data = utf16("표");
+        // 확장 제어 문자(표, 8 WCHAR)는 통째로 건너뜀 / Extended controls (table, 8 WCHARs) are skipped whole
+        data.extend(utf16("\u{000B}"));
+        data.extend([b't', 0, b'b', 0, b'l', 0, b' ', 0, 0, 0, 0, 0, 0, 0]);
+        data.extend(utf16("제목\u{0018}부제\u{001E}끝\u{000D}"));
+
+        let mut text = String::new();
+        push_para_text(&data, &mut text);
+        assert_eq!(text, "표제목-부제 끝");
+    }
+}
@@ -0,0 +1,292 @@
+/// 검토 보고서 모듈 / Review report module
+///
+/// 문서의 메모와 변경 추적을 본문 변환과 별개로 모아 작성자, 일시, 인용 본문, 위치와 함께
+/// 마크다운이나 JSON 보고서로 내보냅니다. HWP 5.0의 메모/변경 추적 레코드는 구조가 공개되지
+/// 않아 현재는 HWPX 문서만 항목이 채워집니다.
+///
+/// Collects a document's memos and tracked changes independently of the body conversion and
+/// exports them with author, date, quoted context and location as a markdown or JSON report.
+/// HWP 5.0 memo/track change records have no published layout, so only HWPX documents
+/// currently produce entries.
+use crate::document::bodytext::{Paragraph, ParagraphRecord};
+use crate::document::docinfo::TrackChangeKind;
+use crate::document::HwpDocument;
+use crate::error::HwpError;
+use serde::{Deserialize, Serialize};
+
+/// 검토 항목 종류 / Review entry kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewKind {
+    /// 메모 / Comment (memo)
+    Comment,
+    /// 삽입된 본문 / Inserted text
+    Insertion,
+    /// 삭제된 본문 / Deleted text
+    Deletion,
+    /// 서식 변경 / Formatting change
+    Format,
+}
+
+/// 검토 항목 하나 / A single review entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewEntry {
+    pub kind: ReviewKind,
+    /// 작성자 / Author
+    pub author: Option<String>,
+    /// 작성 일시 (문서에 저장된 그대로) / Date (as stored in the document)
+    pub date: Option<String>,
+    /// 메모 내용 (변경 추적은 None) / Memo text (None for tracked changes)
+    pub comment: Option<String>,
+    /// 메모가 달린 본문 또는 변경된 본문 / Annotated or changed body text
+    pub quoted: String,
+    /// 구역 인덱스 / Section index
+    pub section_index: usize,
+    /// 구역 안의 최상위 문단 인덱스 (표 안의 항목은 표를 담은 문단) / Top-level paragraph index within the section (entries in tables use the paragraph holding the table)
+    pub paragraph_index: usize,
+}
+
+/// 검토 보고서 / Review report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewReport {
+    /// 문서 순서대로 정렬된 항목 / Entries in document order
+    pub entries: Vec<ReviewEntry>,
+}
+
+impl ReviewReport {
+    /// 항목이 없는지 확인 / Whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 마크다운으로 렌더링 / Render as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Review Report\n\n");
+        if self.entries.is_empty() {
+            out.push_str("No comments or tracked changes.\n");
+            return out;
+        }
+
+        let mut current_section = None;
+        for entry in &self.entries {
+            if current_section != Some(entry.section_index) {
+                if current_section.is_some() {
+                    out.push('\n');
+                }
+                current_section = Some(entry.section_index);
+                out.push_str(&format!("## Section {}\n\n", entry.section_index + 1));
+            }
+
+            let label = match entry.kind {
+                ReviewKind::Comment => "Comment",
+                ReviewKind::Insertion => "Inserted",
+                ReviewKind::Deletion => "Deleted",
+                ReviewKind::Format => "Formatted",
+            };
+            let mut line = format!("- **{label}** (paragraph {})", entry.paragraph_index + 1);
+            let by = [entry.author.as_deref(), entry.date.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            if !by.is_empty() {
+                line.push_str(&format!(" — {}", by.join(", ")));
+            }
+            let quoted = entry.quoted.trim();
+            if !quoted.is_empty() {
+                match entry.kind {
+                    ReviewKind::Deletion => line.push_str(&format!(": ~~{quoted}~~")),
+                    _ => line.push_str(&format!(": “{quoted}”")),
+                }
+            }
+            out.push_str(&line);
+            out.push('\n');
+            if let Some(comment) = &entry.comment {
+                for comment_line in comment.lines() {
+                    out.push_str(&format!("  > {comment_line}\n"));
+                }
+            }
+        }
+        out
+    }
+
+    /// JSON으로 직렬화 / Serialize as JSON
+    pub fn to_json(&self) -> Result<String, HwpError> {
+        serde_json::to_string_pretty(self).map_err(HwpError::from)
+    }
+}
+
+/// 문서의 메모와 변경 추적 모으기 / Collect a document's memos and tracked changes
+///
+/// # Arguments / 매개변수
+/// * `document` - 대상 문서 / Source document
+///
+/// # Returns / 반환값
+/// 구역 순서, 문단 순서대로 정렬된 검토 보고서 / Review report ordered by section, then paragraph
+pub fn review_report(document: &HwpDocument) -> ReviewReport {
+    let mut entries = Vec::new();
+    for (section_index, section) in document.body_text.sections.iter().enumerate() {
+        for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+            collect_entries(
+                document,
+                paragraph,
+                section_index,
+                paragraph_index,
+                &mut entries,
+            );
+        }
+    }
+    ReviewReport { entries }
+}
+
+/// 문단과 그 안의 표 셀에서 항목 수집 / Collect entries from a paragraph and its table cells
+fn collect_entries(
+    document: &HwpDocument,
+    paragraph: &Paragraph,
+    section_index: usize,
+    paragraph_index: usize,
+    entries: &mut Vec<ReviewEntry>,
+) {
+    for record in &paragraph.records {
+        let entry = match record {
+            ParagraphRecord::HwpxMemo {
+                author,
+                date,
+                text,
+                quoted,
+            } => ReviewEntry {
+                kind: ReviewKind::Comment,
+                author: author.clone(),
+                date: date.clone(),
+                comment: Some(text.clone()),
+                quoted: quoted.clone(),
+                section_index,
+                paragraph_index,
+            },
+            ParagraphRecord::HwpxTrackChange {
+                kind,
+                change_id,
+                text,
+            } => {
+                let (author, date) = track_change_author_and_date(document, *change_id);
+                ReviewEntry {
+                    kind: match kind {
+                        TrackChangeKind::Insert => ReviewKind::Insertion,
+                        TrackChangeKind::Delete => ReviewKind::Deletion,
+                        TrackChangeKind::Format => ReviewKind::Format,
+                    },
+                    author,
+                    date,
+                    comment: None,
+                    quoted: text.clone(),
+                    section_index,
+                    paragraph_index,
+                }
+            }
+            _ => continue,
+        };
+        entries.push(entry);
+    }
+
+    for table in paragraph.tables() {
+        for cell in &table.cells {
+            for cell_paragraph in &cell.paragraphs {
+                collect_entries(
+                    document,
+                    cell_paragraph,
+                    section_index,
+                    paragraph_index,
+                    entries,
+                );
+            }
+        }
+    }
+}
+
+/// DocInfo에서 변경 추적의 작성자와 일시 찾기 / Look up a tracked change's author and date in DocInfo
+fn track_change_author_and_date(
+    document: &HwpDocument,
+    change_id: u32,
+) -> (Option<String>, Option<String>) {
+    let doc_info = &document.doc_info;
+    let Some(change) = doc_info
+        .track_change_contents
+        .iter()
+        .find(|change| change.id == Some(change_id))
+    else {
+        return (None, None);
+    };
+    let author = change.author_id.and_then(|author_id| {
+        doc_info
+            .track_change_authors
+            .iter()
+            .find(|author| author.id == Some(author_id))
+            .and_then(|author| author.name.clone())
+    });
+    (author, change.date.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::docinfo::{TrackChangeAuthor, TrackChangeContent};
+    use crate::document::Section;
+    use crate::testing::fixtures::empty_document;
+
+    #[test]
+    fn test_review_report_resolves_authors() {
+        let mut document = empty_document();
+        document
+            .doc_info
+            .track_change_contents
+            .push(TrackChangeContent {
+                id: Some(7),
+                kind: Some(TrackChangeKind::Delete),
+                author_id: Some(1),
+                date: Some("2024-03-01T09:00:00Z".to_string()),
+                ..Default::default()
+            });
+        document
+            .doc_info
+            .track_change_authors
+            .push(TrackChangeAuthor {
+                id: Some(1),
+                name: Some("김검토".to_string()),
+                ..Default::default()
+            });
+        document.body_text.sections.push(Section {
+            index: 0,
+            paragraphs: vec![
+                Paragraph::default(),
+                Paragraph {
+                    para_header: Default::default(),
+                    records: vec![
+                        ParagraphRecord::HwpxMemo {
+                            author: Some("홍길동".to_string()),
+                            date: None,
+                            text: "근거 추가".to_string(),
+                            quoted: "예산".to_string(),
+                        },
+                        ParagraphRecord::HwpxTrackChange {
+                            kind: TrackChangeKind::Delete,
+                            change_id: 7,
+                            text: "초안".to_string(),
+                        },
+                    ],
+                    resolved_number: None,
+                },
+            ],
+        });
+
+        let report = review_report(&document);
+        assert_eq!(report.entries.len(), 2);
+        assert_eq!(report.entries[0].paragraph_index, 1);
+        assert_eq!(report.entries[1].author.as_deref(), Some("김검토"));
+        assert_eq!(
+            report.to_markdown(),
+            "# Review Report\n\n## Section 1\n\n\
+             - **Comment** (paragraph 2) — 홍길동: “예산”\n  > 근거 추가\n\
+             - **Deleted** (paragraph 2) — 김검토, 2024-03-01T09:00:00Z: ~~초안~~\n"
+        );
+        assert!(report.to_json().unwrap().contains("\"kind\": \"deletion\""));
+    }
+}
@@ -17,6 +17,40 @@ impl RoundTo2dp for f64 {
     }
 }
 
+/// 1인치당 HWPUNIT 수 / HWPUNITs per inch
+pub const HWPUNIT_PER_INCH: f64 = 7200.0;
+
+/// 1인치당 밀리미터 / Millimeters per inch
+pub const MM_PER_INCH: f64 = 25.4;
+
+/// 1인치당 포인트 / Points per inch
+pub const PT_PER_INCH: f64 = 72.0;
+
+/// HWPUNIT 값을 밀리미터로 변환 / Convert HWPUNIT value to millimeters
+///
+/// HWPUNIT, SHWPUNIT, HWPUNIT16 모두 1/7200인치 단위이므로 부호 있는 정수로 받습니다.
+/// HWPUNIT, SHWPUNIT and HWPUNIT16 all use 1/7200 inch, so a signed integer is accepted.
+pub fn hwpunit_to_mm(value: i64) -> f64 {
+    value as f64 / HWPUNIT_PER_INCH * MM_PER_INCH
+}
+
+/// 밀리미터를 HWPUNIT 값으로 변환 (반올림) / Convert millimeters to HWPUNIT value (rounded)
+pub fn mm_to_hwpunit(mm: f64) -> i64 {
+    (mm / MM_PER_INCH * HWPUNIT_PER_INCH).round() as i64
+}
+
+/// HWPUNIT 값을 포인트로 변환 / Convert HWPUNIT value to points
+///
+/// 1pt = 100 HWPUNIT (글자 크기의 `base_size`와 같은 단위) / 1pt = 100 HWPUNIT (same unit as `base_size`)
+pub fn hwpunit_to_pt(value: i64) -> f64 {
+    value as f64 / HWPUNIT_PER_INCH * PT_PER_INCH
+}
+
+/// 포인트를 HWPUNIT 값으로 변환 (반올림) / Convert points to HWPUNIT value (rounded)
+pub fn pt_to_hwpunit(pt: f64) -> i64 {
+    (pt / PT_PER_INCH * HWPUNIT_PER_INCH).round() as i64
+}
+
 /// BYTE: 부호 없는 한 바이트(0~255)
 pub type BYTE = u8;
 
@@ -56,6 +90,16 @@ impl HWPUNIT {
         Self::from_inches(mm / 25.4)
     }
 
+    /// 포인트 단위로 변환
+    pub fn to_pt(self) -> f64 {
+        hwpunit_to_pt(self.0 as i64)
+    }
+
+    /// 포인트 단위에서 생성
+    pub fn from_pt(pt: f64) -> Self {
+        Self(pt_to_hwpunit(pt).max(0) as u32)
+    }
+
     /// 내부 값 반환
     pub fn value(self) -> u32 {
         self.0
@@ -100,6 +144,16 @@ impl SHWPUNIT {
         Self::from_inches(mm / 25.4)
     }
 
+    /// 포인트 단위로 변환
+    pub fn to_pt(self) -> f64 {
+        hwpunit_to_pt(self.0 as i64)
+    }
+
+    /// 포인트 단위에서 생성
+    pub fn from_pt(pt: f64) -> Self {
+        Self(pt_to_hwpunit(pt) as i32)
+    }
+
     /// 내부 값 반환
     pub fn value(self) -> i32 {
         self.0
@@ -122,11 +176,18 @@ impl From<SHWPUNIT> for i32 {
 pub trait Hwpunit16ToMm {
     /// 밀리미터 단위로 변환 / Convert to millimeters
     fn to_mm(self) -> f64;
+
+    /// 포인트 단위로 변환 / Convert to points
+    fn to_pt(self) -> f64;
 }
 
 impl Hwpunit16ToMm for i16 {
     fn to_mm(self) -> f64 {
-        (self as f64 / 7200.0) * 25.4
+        hwpunit_to_mm(self as i64)
+    }
+
+    fn to_pt(self) -> f64 {
+        hwpunit_to_pt(self as i64)
     }
 }
 
@@ -0,0 +1,690 @@
+/// 문서 구조 검증 모듈 / Document structure validation module
+///
+/// 파일을 파싱하지 않고 컨테이너 구조만 검사하여, 변환 파이프라인에 넣기 전에 손상된 파일을
+/// 골라낼 수 있도록 기계가 읽을 수 있는 결과를 돌려줍니다.
+///
+/// Checks the container structure without parsing the document and returns machine-readable
+/// findings, so damaged files can be triaged before they enter a conversion pipeline.
+///
+/// | 코드 / Code | 검사 / Check |
+/// |---|---|
+/// | `unknown_format` | HWP 5.0(CFB)도 HWPX(ZIP)도 아님 / Neither HWP 5.0 (CFB) nor HWPX (ZIP) |
+/// | `invalid_container` | CFB/ZIP을 열 수 없음 / CFB/ZIP cannot be opened |
+/// | `missing_mimetype`, `invalid_mimetype` | HWPX `mimetype` 파일 / HWPX `mimetype` file |
+/// | `missing_container_xml`, `missing_root_file` | `META-INF/container.xml`과 그 rootfile / `META-INF/container.xml` and its root files |
+/// | `missing_manifest_file`, `dangling_spine_reference` | content.hpf 매니페스트와 spine / content.hpf manifest and spine |
+/// | `missing_section_file`, `missing_stream` | 구역 파일, 필수 스트림 / Section files, required streams |
+/// | `malformed_xml` | XML 문법 오류 / XML syntax errors |
+/// | `dangling_binary_item_ref` | 없는 바이너리 아이템을 가리키는 `binaryItemIDRef` / `binaryItemIDRef` to a missing binary item |
+/// | `unreadable_image` | 읽을 수 없거나 이미지 형식이 아닌 그림 / Pictures that cannot be read or are not images |
+/// | `password_required` | 암호화된 문서 / Encrypted document |
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Cursor;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::cfb::CfbParser;
+use crate::document::fileheader::FileHeader;
+use crate::document::images::sniff_image_mime_type;
+use crate::document::SourceFormat;
+use crate::parser::hwpx::bindata::get_mime_type;
+use crate::parser::hwpx::container::HwpxContainer;
+use crate::parser::{detect_format, FileFormat};
+
+/// 심각도 / Severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// 읽을 수는 있지만 표준과 다름 / Readable but deviates from the standard
+    Warning,
+    /// 내용이 빠지거나 파싱이 실패할 수 있음 / Content may be lost or parsing may fail
+    Error,
+}
+
+/// 검사 항목 코드 / Finding code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    UnknownFormat,
+    InvalidContainer,
+    MissingMimetype,
+    InvalidMimetype,
+    MissingContainerXml,
+    MissingRootFile,
+    MissingManifestFile,
+    DanglingSpineReference,
+    MissingSectionFile,
+    MissingStream,
+    MalformedXml,
+    DanglingBinaryItemRef,
+    UnreadableImage,
+    PasswordRequired,
+    DrmProtected,
+}
+
+/// 검사 결과 하나 / A single finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationFinding {
+    /// 심각도 / Severity
+    pub severity: Severity,
+    /// 검사 항목 코드 / Finding code
+    pub code: ValidationCode,
+    /// 관련 파트 경로 또는 스트림 이름 / Related part path or stream name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part: Option<String>,
+    /// 설명 / Description
+    pub message: String,
+}
+
+/// 검증 결과 / Validation report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// 감지한 파일 형식 (알 수 없으면 None) / Detected file format (None if unknown)
+    pub format: Option<SourceFormat>,
+    /// 검사 결과 목록 / Findings
+    pub findings: Vec<ValidationFinding>,
+}
+
+impl ValidationReport {
+    /// 오류가 없는지 확인 (경고는 허용) / Whether there are no errors (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// 오류 목록 / Errors
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Error)
+    }
+
+    /// 경고 목록 / Warnings
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationFinding> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == Severity::Warning)
+    }
+
+    /// 특정 코드의 검사 결과가 있는지 확인 / Whether any finding has the given code
+    pub fn has(&self, code: ValidationCode) -> bool {
+        self.findings.iter().any(|finding| finding.code == code)
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        code: ValidationCode,
+        part: Option<&str>,
+        message: impl Into<String>,
+    ) {
+        self.findings.push(ValidationFinding {
+            severity,
+            code,
+            part: part.map(str::to_string),
+            message: message.into(),
+        });
+    }
+
+    fn error(&mut self, code: ValidationCode, part: Option<&str>, message: impl Into<String>) {
+        self.push(Severity::Error, code, part, message);
+    }
+
+    fn warning(&mut self, code: ValidationCode, part: Option<&str>, message: impl Into<String>) {
+        self.push(Severity::Warning, code, part, message);
+    }
+}
+
+/// 문서 구조 검증 / Validate document structure
+///
+/// # Arguments / 매개변수
+/// * `data` - HWP 또는 HWPX 파일 바이트 / HWP or HWPX file bytes
+///
+/// # Returns / 반환값
+/// 발견 순서대로 정리된 검사 결과 / Findings in the order they were found
+pub fn validate(data: &[u8]) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    match detect_format(data) {
+        FileFormat::Hwp5 => {
+            report.format = Some(SourceFormat::Hwp5);
+            validate_hwp5(data, &mut report);
+        }
+        FileFormat::Hwpx => {
+            report.format = Some(SourceFormat::Hwpx);
+            validate_hwpx(data, &mut report);
+        }
+        FileFormat::Hwpml => {
+            report.format = Some(SourceFormat::Hwpml);
+            if let Err(error) = crate::parser::hwpml::parse(data) {
+                report.error(ValidationCode::MalformedXml, None, error.to_string());
+            }
+        }
+        FileFormat::Hwp3 => {
+            report.format = Some(SourceFormat::Hwp3);
+            if let Err(error) = crate::parser::hwp3::parse(data) {
+                report.error(ValidationCode::InvalidContainer, None, error.to_string());
+            }
+        }
+        FileFormat::Unknown => report.error(
+            ValidationCode::UnknownFormat,
+            None,
+            "Not an HWP 5.0 (CFB), HWPX (ZIP), HWPML (XML) or HWP 3.0 file",
+        ),
+    }
+    report
+}
+
+// ===== HWP 5.0 =====
+
+fn validate_hwp5(data: &[u8], report: &mut ValidationReport) {
+    let mut cfb = match CfbParser::parse(data) {
+        Ok(cfb) => cfb,
+        Err(error) => {
+            report.error(ValidationCode::InvalidContainer, None, error.to_string());
+            return;
+        }
+    };
+
+    for stream in ["FileHeader", "DocInfo", "BodyText/Section0"] {
+        if !cfb.exists(stream) {
+            report.error(
+                ValidationCode::MissingStream,
+                Some(stream),
+                format!("Required stream '{stream}' is missing"),
+            );
+        }
+    }
+
+    if let Ok(data) = CfbParser::read_stream(&mut cfb, "FileHeader") {
+        match FileHeader::parse(&data) {
+            Ok(header) if header.is_drm() => report.warning(
+                ValidationCode::DrmProtected,
+                Some("FileHeader"),
+                "Document is DRM protected",
+            ),
+            Ok(header) if header.is_encrypted() => report.warning(
+                ValidationCode::PasswordRequired,
+                Some("FileHeader"),
+                "Document is password protected",
+            ),
+            Ok(_) => {}
+            Err(error) => {
+                report.error(
+                    ValidationCode::InvalidContainer,
+                    Some("FileHeader"),
+                    error.to_string(),
+                );
+            }
+        }
+    }
+}
+
+// ===== HWPX =====
+
+/// content.hpf 매니페스트 항목 / content.hpf manifest item
+struct ManifestItem {
+    href: String,
+    media_type: Option<String>,
+}
+
+fn validate_hwpx(data: &[u8], report: &mut ValidationReport) {
+    let mut container = match HwpxContainer::open(data) {
+        Ok(container) => container,
+        Err(error) => {
+            report.error(ValidationCode::InvalidContainer, None, error.to_string());
+            return;
+        }
+    };
+
+    check_mimetype(&mut container, report);
+    check_container_xml(&mut container, report);
+    let manifest = check_content_hpf(&mut container, report);
+
+    let header_path = container.header_path();
+    if !container.file_exists(&header_path) {
+        report.error(
+            ValidationCode::MissingManifestFile,
+            Some(&header_path),
+            format!("{header_path} is missing"),
+        );
+    } else if let Ok(content) = container.read_file(&header_path) {
+        check_xml(&header_path, &content, report, |_| {});
+    }
+
+    let binary_refs = check_sections(&mut container, report);
+    check_binary_refs(&container, &manifest, &binary_refs, report);
+    check_images(&mut container, &manifest, report);
+}
+
+fn check_mimetype(container: &mut HwpxContainer, report: &mut ValidationReport) {
+    match container.read_file("mimetype") {
+        Ok(content) => {
+            let mimetype = String::from_utf8_lossy(&content);
+            let mimetype = mimetype.trim();
+            if !(mimetype.contains("hwp") || mimetype.contains("owpml")) {
+                report.error(
+                    ValidationCode::InvalidMimetype,
+                    Some("mimetype"),
+                    format!("Expected 'application/hwp+zip', found '{mimetype}'"),
+                );
+            }
+        }
+        Err(_) => report.warning(
+            ValidationCode::MissingMimetype,
+            Some("mimetype"),
+            "mimetype file is missing",
+        ),
+    }
+}
+
+fn check_container_xml(container: &mut HwpxContainer, report: &mut ValidationReport) {
+    const PART: &str = "META-INF/container.xml";
+    let Ok(content) = container.read_file(PART) else {
+        report.warning(
+            ValidationCode::MissingContainerXml,
+            Some(PART),
+            "META-INF/container.xml is missing",
+        );
+        return;
+    };
+
+    let mut root_files = Vec::new();
+    check_xml(PART, &content, report, |e| {
+        if local_name(e) == "rootfile" {
+            if let Some(path) = attribute(e, b"full-path") {
+                root_files.push(path);
+            }
+        }
+    });
+    for path in root_files {
+        if !container.file_exists(&path) {
+            report.error(
+                ValidationCode::MissingRootFile,
+                Some(PART),
+                format!("Root file '{path}' does not exist"),
+            );
+        }
+    }
+}
+
+/// content.hpf 검사 후 매니페스트 반환 (id → 항목) / Check content.hpf and return its manifest (id → item)
+fn check_content_hpf(
+    container: &mut HwpxContainer,
+    report: &mut ValidationReport,
+) -> BTreeMap<String, ManifestItem> {
+    let part = container.package_path().to_string();
+    let mut manifest = BTreeMap::new();
+    let Ok(content) = container.read_file(&part) else {
+        report.warning(
+            ValidationCode::MissingManifestFile,
+            Some(&part),
+            format!("{part} is missing"),
+        );
+        return manifest;
+    };
+
+    let mut spine = Vec::new();
+    check_xml(&part, &content, report, |e| match local_name(e).as_str() {
+        "item" => {
+            if let (Some(id), Some(href)) = (attribute(e, b"id"), attribute(e, b"href")) {
+                let href = container.resolve_href(&href);
+                let media_type = attribute(e, b"media-type");
+                manifest.insert(id, ManifestItem { href, media_type });
+            }
+        }
+        "itemref" => spine.extend(attribute(e, b"idref")),
+        _ => {}
+    });
+
+    for (id, item) in &manifest {
+        if !container.file_exists(&item.href) {
+            let code = if is_section_path(&item.href) {
+                ValidationCode::MissingSectionFile
+            } else {
+                ValidationCode::MissingManifestFile
+            };
+            report.error(
+                code,
+                Some(&item.href),
+                format!(
+                    "Manifest item '{id}' points to missing file '{}'",
+                    item.href
+                ),
+            );
+        }
+    }
+    for idref in spine {
+        if !manifest.contains_key(&idref) {
+            report.error(
+                ValidationCode::DanglingSpineReference,
+                Some(&part),
+                format!("Spine references unknown manifest item '{idref}'"),
+            );
+        }
+    }
+    manifest
+}
+
+/// 구역 파일 검사 후 binaryItemIDRef 목록 반환 / Check section files and return their binaryItemIDRef values
+fn check_sections(
+    container: &mut HwpxContainer,
+    report: &mut ValidationReport,
+) -> BTreeMap<String, String> {
+    let mut binary_refs = BTreeMap::new();
+    let section_files = container.section_order();
+    if section_files.is_empty() {
+        report.error(
+            ValidationCode::MissingSectionFile,
+            Some(container.content_dir()),
+            "No section files found",
+        );
+        return binary_refs;
+    }
+
+    // section0.xml부터 빠진 번호 확인 / Look for gaps starting at section0.xml
+    let numbers: BTreeSet<usize> = section_files
+        .iter()
+        .filter_map(|path| section_number(path))
+        .collect();
+    let last = numbers.iter().next_back().copied().unwrap_or(0);
+    for number in (0..last).filter(|number| !numbers.contains(number)) {
+        let path = format!("{}section{number}.xml", container.content_dir());
+        let reported = report.findings.iter().any(|finding| {
+            finding.code == ValidationCode::MissingSectionFile
+                && finding.part.as_deref() == Some(path.as_str())
+        });
+        if reported {
+            continue;
+        }
+        report.error(
+            ValidationCode::MissingSectionFile,
+            Some(&path),
+            format!("{path} is missing between existing sections"),
+        );
+    }
+
+    for path in section_files {
+        let content = match container.read_file(&path) {
+            Ok(content) => content,
+            Err(error) => {
+                report.error(
+                    ValidationCode::InvalidContainer,
+                    Some(&path),
+                    error.to_string(),
+                );
+                continue;
+            }
+        };
+        check_xml(&path, &content, report, |e| {
+            if let Some(id) = attribute(e, b"binaryItemIDRef") {
+                binary_refs.entry(id).or_insert_with(|| path.clone());
+            }
+        });
+    }
+    binary_refs
+}
+
+/// binaryItemIDRef가 매니페스트나 BinData 파일로 이어지는지 확인
+/// Check that each binaryItemIDRef resolves to a manifest item or BinData file
+fn check_binary_refs(
+    container: &HwpxContainer,
+    manifest: &BTreeMap<String, ManifestItem>,
+    binary_refs: &BTreeMap<String, String>,
+    report: &mut ValidationReport,
+) {
+    let bindata_stems: BTreeSet<String> = container
+        .get_bindata_files()
+        .iter()
+        .filter_map(|path| file_stem(path))
+        .collect();
+    for (id, section) in binary_refs {
+        let in_manifest = manifest
+            .get(id)
+            .is_some_and(|item| container.file_exists(&item.href));
+        if !in_manifest && !bindata_stems.contains(id) {
+            report.error(
+                ValidationCode::DanglingBinaryItemRef,
+                Some(section),
+                format!("binaryItemIDRef '{id}' does not match any binary item"),
+            );
+        }
+    }
+}
+
+/// BinData의 그림 파일을 읽고 형식 확인 / Read picture files in BinData and check their format
+fn check_images(
+    container: &mut HwpxContainer,
+    manifest: &BTreeMap<String, ManifestItem>,
+    report: &mut ValidationReport,
+) {
+    let media_types: BTreeMap<&str, &str> = manifest
+        .values()
+        .filter_map(|item| Some((item.href.as_str(), item.media_type.as_deref()?)))
+        .collect();
+
+    for path in container.get_bindata_files() {
+        if path.ends_with('/') {
+            continue;
+        }
+        let extension = path.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+        let media_type = media_types
+            .get(path.as_str())
+            .copied()
+            .unwrap_or_else(|| get_mime_type(extension));
+        // SVG는 텍스트 형식이라 매직 바이트가 없음 / SVG is text and has no magic bytes
+        if !media_type.starts_with("image/") || media_type == "image/svg+xml" {
+            continue;
+        }
+        match container.read_file(&path) {
+            Ok(data) if sniff_image_mime_type(&data).is_some() => {}
+            Ok(data) => report.error(
+                ValidationCode::UnreadableImage,
+                Some(&path),
+                format!(
+                    "{} bytes declared as {media_type} are not a recognized image",
+                    data.len()
+                ),
+            ),
+            Err(error) => {
+                report.error(
+                    ValidationCode::UnreadableImage,
+                    Some(&path),
+                    error.to_string(),
+                );
+            }
+        }
+    }
+}
+
+// ===== Helpers =====
+
+/// XML 문법 확인, 시작 요소마다 `visit` 호출 / Check XML syntax, calling `visit` for each start element
+fn check_xml(
+    part: &str,
+    content: &[u8],
+    report: &mut ValidationReport,
+    mut visit: impl FnMut(&BytesStart),
+) {
+    let mut reader = Reader::from_reader(Cursor::new(content));
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                depth += 1;
+                visit(e);
+            }
+            Ok(Event::Empty(ref e)) => visit(e),
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) => {
+                // quick_xml은 닫히지 않은 요소를 오류로 보지 않음 / quick_xml does not report unclosed elements
+                if depth > 0 {
+                    report.error(
+                        ValidationCode::MalformedXml,
+                        Some(part),
+                        format!("{depth} element(s) not closed at end of file"),
+                    );
+                }
+                break;
+            }
+            Err(error) => {
+                report.error(
+                    ValidationCode::MalformedXml,
+                    Some(part),
+                    format!("{error} (byte {})", reader.error_position()),
+                );
+                break;
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// 접두사를 뺀 요소 이름 / Element name without prefix
+fn local_name(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.local_name().as_ref()).into_owned()
+}
+
+fn attribute(e: &BytesStart, key: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+fn is_section_path(path: &str) -> bool {
+    section_number(path).is_some()
+}
+
+/// "Contents/section3.xml" → 3
+fn section_number(path: &str) -> Option<usize> {
+    path.rsplit('/')
+        .next()?
+        .strip_prefix("section")?
+        .strip_suffix(".xml")?
+        .parse()
+        .ok()
+}
+
+/// "BinData/image1.png" → "image1"
+fn file_stem(path: &str) -> Option<String> {
+    let name = path.rsplit('/').next()?;
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    (!stem.is_empty()).then(|| stem.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    fn hwpx(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    const CONTAINER: &[u8] = br#"<ocf:container xmlns:ocf="c"><ocf:rootfiles><ocf:rootfile full-path="Contents/content.hpf"/></ocf:rootfiles></ocf:container>"#;
+    const CONTENT: &[u8] = br#"<opf:package xmlns:opf="o"><opf:manifest>
+<opf:item id="header" href="Contents/header.xml" media-type="application/xml"/>
+<opf:item id="section0" href="Contents/section0.xml" media-type="application/xml"/>
+<opf:item id="image1" href="BinData/image1.png" media-type="image/png"/>
+</opf:manifest><opf:spine><opf:itemref idref="header"/><opf:itemref idref="section0"/></opf:spine></opf:package>"#;
+    const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    #[test]
+    fn test_valid_package_has_no_findings() {
+        let data = hwpx(&[
+            ("mimetype", b"application/hwp+zip"),
+            ("META-INF/container.xml", CONTAINER),
+            ("Contents/content.hpf", CONTENT),
+            ("Contents/header.xml", b"<hh:head xmlns:hh=\"http://www.hancom.co.kr/hwpml/2011/head\"/>"),
+            (
+                "Contents/section0.xml",
+                br#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hc:img binaryItemIDRef="image1"/></hs:sec>"#,
+            ),
+            ("BinData/image1.png", PNG),
+        ]);
+        let report = validate(&data);
+        assert_eq!(report.format, Some(SourceFormat::Hwpx));
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_broken_package_findings() {
+        let data = hwpx(&[
+            ("mimetype", b"text/plain"),
+            ("META-INF/container.xml", CONTAINER),
+            (
+                "Contents/content.hpf",
+                br#"<opf:package xmlns:opf="o"><opf:manifest>
+<opf:item id="section0" href="Contents/section0.xml"/>
+<opf:item id="section1" href="Contents/section1.xml"/>
+</opf:manifest><opf:spine><opf:itemref idref="header"/></opf:spine></opf:package>"#,
+            ),
+            ("Contents/header.xml", b"<hh:head>"),
+            (
+                "Contents/section0.xml",
+                br#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hc="http://www.hancom.co.kr/hwpml/2011/core"><hc:img binaryItemIDRef="image9"/></hs:sec>"#,
+            ),
+            ("Contents/section2.xml", b"<hs:sec></hs:wrong>"),
+            ("BinData/image1.png", b"not a png"),
+        ]);
+        let report = validate(&data);
+        assert!(!report.is_valid());
+
+        let codes: Vec<(ValidationCode, Option<&str>)> = report
+            .findings
+            .iter()
+            .map(|finding| (finding.code, finding.part.as_deref()))
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                (ValidationCode::InvalidMimetype, Some("mimetype")),
+                (
+                    ValidationCode::MissingSectionFile,
+                    Some("Contents/section1.xml")
+                ),
+                (
+                    ValidationCode::DanglingSpineReference,
+                    Some("Contents/content.hpf")
+                ),
+                (ValidationCode::MalformedXml, Some("Contents/header.xml")),
+                (ValidationCode::MalformedXml, Some("Contents/section2.xml")),
+                (
+                    ValidationCode::DanglingBinaryItemRef,
+                    Some("Contents/section0.xml")
+                ),
+                (ValidationCode::UnreadableImage, Some("BinData/image1.png")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_and_truncated_files() {
+        let report = validate(b"plain text");
+        assert_eq!(report.format, None);
+        assert!(report.has(ValidationCode::UnknownFormat));
+
+        let report = validate(&[0x50, 0x4B, 0x03, 0x04, 0, 0]);
+        assert!(report.has(ValidationCode::InvalidContainer));
+    }
+
+    #[test]
+    fn test_report_serializes_with_snake_case_codes() {
+        let report = validate(b"plain text");
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(
+            json,
+            r#"{"format":null,"findings":[{"severity":"error","code":"unknown_format","message":"Not an HWP 5.0 (CFB), HWPX (ZIP), HWPML (XML) or HWP 3.0 file"}]}"#
+        );
+    }
+}
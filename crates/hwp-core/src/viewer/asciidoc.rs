@@ -0,0 +1,246 @@
+/// AsciiDoc output
+/// AsciiDoc 출력
+///
+/// Antora/Asciidoctor 문서로 옮길 수 있도록 공통 블록 트리([`crate::viewer::core`])를 AsciiDoc으로
+/// 씁니다. 개요 문단은 `[[toc-*]]` 앵커가 붙은 구역 제목, 표는 병합 칸(`2.3+|`)을 살린 표,
+/// 각주/미주는 `footnote:[]`, 그림은 data URI `image:` 매크로가 됩니다.
+/// Writes the shared block tree ([`crate::viewer::core`]) as AsciiDoc for Antora/Asciidoctor.
+/// Outline paragraphs become section titles with a `[[toc-*]]` anchor, tables keep merged cells
+/// (`2.3+|`), footnotes/endnotes become `footnote:[]` and pictures become data URI `image:`
+/// macros.
+use crate::document::HwpDocument;
+use crate::viewer::core::blocks::{
+    close_heading_gaps, document_blocks, plain_text, Block, Emphasis, Inline, TableBlock,
+};
+
+/// 문서를 AsciiDoc으로 변환 / Convert a document to AsciiDoc
+///
+/// 요약 정보에 제목이 있으면 문서 제목(`= 제목`)으로 씁니다.
+/// A title in the summary information becomes the document title (`= Title`).
+pub fn to_asciidoc(document: &HwpDocument) -> String {
+    let mut output = String::new();
+    let title = document
+        .summary_information
+        .as_ref()
+        .and_then(|summary| summary.title.as_deref())
+        .map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|title| !title.is_empty());
+    if let Some(title) = title {
+        output.push_str(&format!("= {}\n\n", escape_text(&title)));
+    }
+    let mut document_blocks = document_blocks(document);
+    // 건너뛴 구역 수준은 Asciidoctor가 경고함 / Asciidoctor warns about skipped section levels
+    close_heading_gaps(&mut document_blocks);
+    output.push_str(&blocks(&document_blocks, 0));
+    output
+}
+
+/// 블록 사이에 빈 줄을 넣어 씀 / Write blocks with blank lines between them
+///
+/// `depth`는 표 안에 들어간 깊이입니다 (중첩 표의 구분자를 고르는 데 씀).
+/// `depth` is the table nesting depth (used to pick the nested table separator).
+fn blocks(blocks: &[Block], depth: usize) -> String {
+    let mut output = String::new();
+    for block in blocks {
+        let text = match block {
+            Block::Heading {
+                level,
+                anchor,
+                inlines,
+            } => {
+                // AsciiDoc 구역은 5수준까지 / AsciiDoc sections go down to level 5
+                let marker = "=".repeat((*level as usize).clamp(1, 5) + 1);
+                format!(
+                    "[[{anchor}]]\n{marker} {}\n",
+                    inline_text(inlines).replace(" +\n", " ")
+                )
+            }
+            Block::Paragraph(inlines) => format!("{}\n", escape_line_start(&inline_text(inlines))),
+            Block::Table(table) => table_text(table, depth),
+        };
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&text);
+    }
+    output
+}
+
+/// 인라인 목록 (줄바꿈은 ` +` 강제 줄바꿈) / An inline list (line breaks as ` +` hard breaks)
+fn inline_text(inlines: &[Inline]) -> String {
+    let mut output = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => output.push_str(&escape_text(text)),
+            Inline::Space => output.push(' '),
+            Inline::LineBreak => output.push_str(" +\n"),
+            Inline::Styled(emphasis, inner) => {
+                let text = inline_text(inner);
+                output.push_str(&match emphasis {
+                    // 붙어 쓰는 한글 사이에서도 되도록 겹친 기호(unconstrained)를 씀
+                    // Use the doubled (unconstrained) marks so they work between adjoining Hangul
+                    Emphasis::Strong => format!("**{text}**"),
+                    Emphasis::Emph => format!("__{text}__"),
+                    Emphasis::Underline => format!("[.underline]##{text}##"),
+                    Emphasis::Strikeout => format!("[.line-through]##{text}##"),
+                    // 위/아래 첨자 안에는 공백을 쓸 수 없음 / Superscript/subscript cannot contain spaces
+                    Emphasis::Superscript => format!("^{}^", text.replace(' ', "{sp}")),
+                    Emphasis::Subscript => format!("~{}~", text.replace(' ', "{sp}")),
+                });
+            }
+            Inline::Note(blocks) => {
+                // 각주는 인라인이므로 문단을 공백으로 이음 / Footnotes are inline, so paragraphs are joined with spaces
+                let text: Vec<String> = blocks
+                    .iter()
+                    .map(|block| match block {
+                        Block::Heading { inlines, .. } | Block::Paragraph(inlines) => {
+                            inline_text(inlines).replace(" +\n", " ")
+                        }
+                        Block::Table(table) => escape_text(&table_plain_text(table)),
+                    })
+                    .collect();
+                output.push_str(&format!("footnote:[{}]", text.join(" ")));
+            }
+            Inline::Image(image) => output.push_str(&format!(
+                "image:{}[alt=\"{}\"]",
+                image.data_uri(),
+                escape_text(&image.alt).replace('"', "&#34;")
+            )),
+        }
+    }
+    output
+}
+
+/// 표 (병합 칸은 `열.행+` 지정자로) / A table (merged cells with the `cols.rows+` specifier)
+///
+/// 첫 단계 중첩 표는 `!===` 구분자로 `a|` 칸 안에 쓰고, 그보다 깊은 표는 글자만 남깁니다.
+/// First-level nested tables are written inside an `a|` cell with the `!===` delimiter; deeper
+/// tables keep only their text.
+fn table_text(table: &TableBlock, depth: usize) -> String {
+    let separator = match depth {
+        0 => '|',
+        1 => '!',
+        _ => return format!("{}\n", escape_text(&table_plain_text(table))),
+    };
+
+    let mut output = String::new();
+    let cols = vec!["1"; table.col_count.max(1)].join(",");
+    // 머리행에서 본문으로 이어지는 병합 칸이 있으면 머리행으로 쓰지 않음
+    // Leave out the header when a merged cell runs from the header into the body
+    let header = table.header_rows > 0
+        && table.cells.iter().all(|cell| {
+            cell.row >= table.header_rows || cell.row + cell.row_span <= table.header_rows
+        });
+    if header {
+        output.push_str(&format!("[cols=\"{cols}\",options=\"header\"]\n"));
+    } else {
+        output.push_str(&format!("[cols=\"{cols}\"]\n"));
+    }
+    output.push_str(&format!("{separator}===\n"));
+    for cell in &table.cells {
+        let span = match (cell.col_span, cell.row_span) {
+            (1, 1) => String::new(),
+            (cols, 1) => format!("{cols}+"),
+            (1, rows) => format!(".{rows}+"),
+            (cols, rows) => format!("{cols}.{rows}+"),
+        };
+        match cell.blocks.as_slice() {
+            [] => output.push_str(&format!("{span}{separator}\n")),
+            [Block::Paragraph(inlines)] => {
+                let text =
+                    inline_text(inlines).replace(separator, &format!("&#{};", separator as u32));
+                output.push_str(&format!("{span}{separator}{text}\n"));
+            }
+            // 여러 블록이나 표가 든 칸은 AsciiDoc 칸(`a|`)으로 / Cells with several blocks or a table become AsciiDoc cells (`a|`)
+            content => {
+                output.push_str(&format!("{span}a{separator}{}", blocks(content, depth + 1)))
+            }
+        }
+    }
+    output.push_str(&format!("{separator}===\n"));
+    output
+}
+
+/// 표의 글자만 (칸은 공백으로 구분) / Only the text of a table (cells separated by spaces)
+fn table_plain_text(table: &TableBlock) -> String {
+    table
+        .cells
+        .iter()
+        .flat_map(|cell| &cell.blocks)
+        .map(|block| match block {
+            Block::Heading { inlines, .. } | Block::Paragraph(inlines) => plain_text(inlines),
+            Block::Table(table) => table_plain_text(table),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 서식 기호를 문자 참조로 이스케이프 / Escape formatting marks as character references
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '*' | '_' | '#' | '^' | '~' | '`' | '+' | '[' | ']' | '|' | '{' | '\\' => {
+                escaped.push_str(&format!("&#{};", ch as u32))
+            }
+            '<' => escaped.push_str("&lt;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// 블록 문법으로 읽히는 줄 첫머리 이스케이프 (제목, 목록, 블록 제목, 주석 등)
+/// Escape line starts that would read as block syntax (titles, lists, block titles, comments, ...)
+fn escape_line_start(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if line.starts_with(['=', '-', '.', '/', ':', '>', '\'']) {
+                let first = line.chars().next().unwrap();
+                format!("&#{};{}", first as u32, &line[1..])
+            } else if digits > 0 && line[digits..].starts_with(". ") {
+                format!("{}&#46;{}", &line[..digits], &line[digits + 1..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::viewer::core::blocks::{text_inlines, CellBlock};
+
+    #[test]
+    fn test_table_spans_and_escaping() {
+        let cell = |row, col, row_span, col_span, text: &str| CellBlock {
+            row,
+            col,
+            row_span,
+            col_span,
+            blocks: vec![Block::Paragraph(text_inlines(text))],
+        };
+        let table = TableBlock {
+            row_count: 3,
+            col_count: 2,
+            header_rows: 1,
+            cells: vec![
+                cell(0, 0, 1, 2, "제목"),
+                cell(1, 0, 2, 1, "a|b"),
+                cell(1, 1, 1, 1, "*1*"),
+                cell(2, 1, 1, 1, "2"),
+            ],
+        };
+        assert_eq!(
+            table_text(&table, 0),
+            "[cols=\"1,1\",options=\"header\"]\n|===\n2+|제목\n.2+|a&#124;b\n|&#42;1&#42;\n|2\n|===\n"
+        );
+        assert_eq!(
+            escape_line_start("1. 항목\n- 목록"),
+            "1&#46; 항목\n&#45; 목록"
+        );
+    }
+}
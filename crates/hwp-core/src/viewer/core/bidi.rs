@@ -0,0 +1,351 @@
+/// 양방향 텍스트 처리 / Bidirectional text handling
+///
+/// HWP는 텍스트를 논리 순서로 저장하므로 뷰어도 논리 순서를 그대로 출력하고, 화면 순서는
+/// 브라우저/편집기의 양방향 알고리즘(UAX #9)에 맡깁니다. 이 모듈은 그 알고리즘이 올바르게
+/// 동작하도록 문단의 기본 방향을 판별하고, 끝나지 않은 방향 제어 문자가 뒤따르는 셀이나
+/// 문단으로 번지지 않게 짝을 맞춥니다.
+///
+/// HWP stores text in logical order, so viewers emit logical order and leave visual order to the
+/// consumer's bidi algorithm (UAX #9). This module helps that algorithm by detecting a
+/// paragraph's base direction and by balancing directional controls so an unterminated one
+/// cannot leak into the following cell or paragraph.
+use std::borrow::Cow;
+
+/// LEFT-TO-RIGHT EMBEDDING
+const LRE: char = '\u{202A}';
+/// RIGHT-TO-LEFT EMBEDDING
+const RLE: char = '\u{202B}';
+/// POP DIRECTIONAL FORMATTING
+const PDF: char = '\u{202C}';
+/// LEFT-TO-RIGHT OVERRIDE
+const LRO: char = '\u{202D}';
+/// RIGHT-TO-LEFT OVERRIDE
+const RLO: char = '\u{202E}';
+/// LEFT-TO-RIGHT ISOLATE
+const LRI: char = '\u{2066}';
+/// RIGHT-TO-LEFT ISOLATE
+const RLI: char = '\u{2067}';
+/// FIRST STRONG ISOLATE
+const FSI: char = '\u{2068}';
+/// POP DIRECTIONAL ISOLATE
+const PDI: char = '\u{2069}';
+/// LEFT-TO-RIGHT MARK
+const LRM: char = '\u{200E}';
+/// RIGHT-TO-LEFT MARK
+const RLM: char = '\u{200F}';
+/// ARABIC LETTER MARK
+const ALM: char = '\u{061C}';
+
+/// 텍스트 방향 / Text direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    /// 왼쪽에서 오른쪽 (한글, 라틴 문자 등) / Left to right (Hangul, Latin, etc.)
+    Ltr,
+    /// 오른쪽에서 왼쪽 (아랍 문자, 히브리 문자 등) / Right to left (Arabic, Hebrew, etc.)
+    Rtl,
+}
+
+impl TextDirection {
+    /// HTML `dir` 속성 값 / HTML `dir` attribute value
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+/// 문자의 강한 방향 (약한/중립 문자는 None) / Strong direction of a character (None for weak/neutral)
+fn strong_direction(c: char) -> Option<TextDirection> {
+    match c {
+        LRM => Some(TextDirection::Ltr),
+        RLM | ALM => Some(TextDirection::Rtl),
+        _ if !c.is_alphabetic() => None,
+        // 히브리, 아랍, 시리아, 타나, 응코 등 / Hebrew, Arabic, Syriac, Thaana, NKo, etc.
+        '\u{0590}'..='\u{08FF}'
+        // 히브리/아랍 표현형 / Hebrew and Arabic presentation forms
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        // 고대 RTL 문자, 아들람, 아랍 수학 기호 / Historic RTL scripts, Adlam, Arabic math
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}' => Some(TextDirection::Rtl),
+        _ => Some(TextDirection::Ltr),
+    }
+}
+
+/// 격리 시작 문자인지 확인 / Whether a character starts an isolate
+fn is_isolate_initiator(c: char) -> bool {
+    matches!(c, LRI | RLI | FSI)
+}
+
+/// 양방향 제어 문자인지 확인 / Whether a character is a bidi control
+pub fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        LRE | RLE | PDF | LRO | RLO | LRI | RLI | FSI | PDI | LRM | RLM | ALM
+    )
+}
+
+/// 문단의 기본 방향 (UAX #9 P2, P3) / Base direction of a paragraph (UAX #9 P2, P3)
+///
+/// 격리 구간 밖의 첫 강한 문자로 정하며, 강한 문자가 없으면 None입니다.
+/// Decided by the first strong character outside isolates; None if there is none.
+pub fn base_direction(text: &str) -> Option<TextDirection> {
+    let mut isolate_depth = 0usize;
+    for c in text.chars() {
+        match c {
+            LRI | RLI | FSI => isolate_depth += 1,
+            PDI => isolate_depth = isolate_depth.saturating_sub(1),
+            _ if isolate_depth == 0 => {
+                if let Some(direction) = strong_direction(c) {
+                    return Some(direction);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 오른쪽에서 왼쪽으로 쓰는 문자가 있는지 확인 / Whether the text contains right-to-left characters
+pub fn has_rtl(text: &str) -> bool {
+    text.chars()
+        .any(|c| strong_direction(c) == Some(TextDirection::Rtl))
+}
+
+/// 방향 제어 문자의 짝 맞추기 / Balance directional control characters
+///
+/// 짝이 없는 PDF/PDI는 지우고, 끝나지 않은 임베딩/재정의/격리는 끝에서 닫습니다.
+/// Unmatched PDF/PDI are dropped and unterminated embeddings/overrides/isolates are closed at the end.
+pub fn balance_bidi_controls(text: &str) -> Cow<'_, str> {
+    if !text
+        .chars()
+        .any(|c| matches!(c, LRE | RLE | PDF | LRO | RLO | LRI | RLI | FSI | PDI))
+    {
+        return Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut open: Vec<char> = Vec::new();
+    for c in text.chars() {
+        match c {
+            LRE | RLE | LRO | RLO | LRI | RLI | FSI => open.push(c),
+            // PDF는 현재 격리 안의 임베딩만 닫음 / PDF only closes an embedding inside the current isolate
+            PDF => match open.last() {
+                Some(&last) if !is_isolate_initiator(last) => {
+                    open.pop();
+                }
+                _ => continue,
+            },
+            // PDI는 짝이 되는 격리와 그 안의 임베딩을 함께 닫음 / PDI closes its isolate and any embeddings inside it
+            PDI => match open.iter().rposition(|&o| is_isolate_initiator(o)) {
+                Some(index) => open.truncate(index),
+                None => continue,
+            },
+            _ => {}
+        }
+        result.push(c);
+    }
+    for &o in open.iter().rev() {
+        result.push(if is_isolate_initiator(o) { PDI } else { PDF });
+    }
+    Cow::Owned(result)
+}
+
+/// 오른쪽에서 왼쪽으로 쓰는 문자가 있으면 격리 구간으로 감쌈 / Wrap text in an isolate if it contains right-to-left characters
+///
+/// 한 줄에 나란히 놓이는 표 셀처럼, 주변 구분자와 방향이 섞여 순서가 뒤바뀌지 않게 할 때 씁니다.
+/// Used where pieces share a line, such as table cells, so neighbouring separators cannot swap their order.
+pub fn isolate_rtl(text: &str) -> Cow<'_, str> {
+    let balanced = balance_bidi_controls(text);
+    if !has_rtl(&balanced) {
+        return balanced;
+    }
+    Cow::Owned(format!("{FSI}{balanced}{PDI}"))
+}
+
+/// 같은 방향의 텍스트 구간 / Run of text sharing one direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectionalRun<'a> {
+    /// 구간의 방향 (강한 문자가 없는 중립 구간이면 None) / Direction of the run (None for a neutral run without strong characters)
+    pub direction: Option<TextDirection>,
+    /// 텍스트 / Text
+    pub text: &'a str,
+}
+
+/// 텍스트를 방향별 구간으로 나눔 / Split text into runs by direction
+///
+/// 같은 방향 문자 사이의 중립 문자(공백, 숫자, 문장 부호)는 그 구간에 붙고, 방향이 바뀌는 경계의
+/// 중립 문자는 따로 중립 구간이 됩니다.
+/// Neutral characters (spaces, digits, punctuation) between characters of one direction join that
+/// run, while neutrals at a direction change form their own neutral run.
+pub fn directional_runs(text: &str) -> Vec<DirectionalRun<'_>> {
+    let mut runs = Vec::new();
+    // 현재 구간의 시작과 방향, 아직 구간에 붙지 않은 중립 문자의 시작 / Current run start and direction, and start of neutrals not yet joined
+    let mut start = 0;
+    let mut current: Option<TextDirection> = None;
+    let mut neutral_start: Option<usize> = None;
+    for (index, c) in text.char_indices() {
+        match strong_direction(c) {
+            Some(direction) if current == Some(direction) => neutral_start = None,
+            Some(direction) => {
+                let run_end = neutral_start.unwrap_or(index);
+                if current.is_some() && run_end > start {
+                    runs.push(DirectionalRun {
+                        direction: current,
+                        text: &text[start..run_end],
+                    });
+                }
+                let neutral_from = if current.is_some() { run_end } else { start };
+                if index > neutral_from {
+                    runs.push(DirectionalRun {
+                        direction: None,
+                        text: &text[neutral_from..index],
+                    });
+                }
+                start = index;
+                current = Some(direction);
+                neutral_start = None;
+            }
+            None => {
+                if neutral_start.is_none() {
+                    neutral_start = Some(index);
+                }
+            }
+        }
+    }
+    let run_end = match current {
+        Some(_) => neutral_start.unwrap_or(text.len()),
+        None => start,
+    };
+    if run_end > start {
+        runs.push(DirectionalRun {
+            direction: current,
+            text: &text[start..run_end],
+        });
+    }
+    if text.len() > run_end {
+        runs.push(DirectionalRun {
+            direction: None,
+            text: &text[run_end..],
+        });
+    }
+    runs
+}
+
+/// 문단 방향과 반대인 구간 뒤에 방향 표시 문자를 넣음 / Insert directional marks after runs opposing the paragraph direction
+///
+/// 평문이나 Markdown처럼 마크업으로 격리할 수 없는 출력에서, 반대 방향 구간 뒤의 숫자나 문장
+/// 부호가 그 구간에 끌려가 순서가 뒤섞이지 않게 문단 방향의 LRM/RLM을 붙입니다.
+/// For output without isolating markup, such as plain text or Markdown, an LRM/RLM of the
+/// paragraph direction follows each opposing run so trailing digits and punctuation are not
+/// pulled into it and reordered.
+pub fn mark_directional_runs(text: &str) -> Cow<'_, str> {
+    if !has_rtl(text) {
+        return Cow::Borrowed(text);
+    }
+    let base = base_direction(text).unwrap_or(TextDirection::Ltr);
+    let mark = match base {
+        TextDirection::Ltr => LRM,
+        TextDirection::Rtl => RLM,
+    };
+    let runs = directional_runs(text);
+    let mut result = String::with_capacity(text.len() + runs.len() * mark.len_utf8());
+    for (index, run) in runs.iter().enumerate() {
+        result.push_str(run.text);
+        let opposing = run.direction.is_some_and(|direction| direction != base);
+        if opposing && index + 1 < runs.len() {
+            result.push(mark);
+        }
+    }
+    Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_direction() {
+        assert_eq!(base_direction("안녕 مرحبا"), Some(TextDirection::Ltr));
+        assert_eq!(base_direction("123 مرحبا abc"), Some(TextDirection::Rtl));
+        assert_eq!(base_direction("שלום world"), Some(TextDirection::Rtl));
+        // 격리 구간 안은 건너뜀 / Isolated text is skipped
+        assert_eq!(
+            base_direction("\u{2067}مرحبا\u{2069} abc"),
+            Some(TextDirection::Ltr)
+        );
+        assert_eq!(base_direction("\u{200F}123"), Some(TextDirection::Rtl));
+        assert_eq!(base_direction("123 !?"), None);
+    }
+
+    #[test]
+    fn test_balance_bidi_controls() {
+        assert!(matches!(balance_bidi_controls("abc"), Cow::Borrowed(_)));
+        assert_eq!(balance_bidi_controls("\u{202E}abc"), "\u{202E}abc\u{202C}");
+        assert_eq!(balance_bidi_controls("abc\u{202C}\u{2069}"), "abc");
+        assert_eq!(
+            balance_bidi_controls("\u{2067}\u{202B}abc\u{2069}"),
+            "\u{2067}\u{202B}abc\u{2069}"
+        );
+        assert_eq!(
+            balance_bidi_controls("\u{202B}\u{2068}abc"),
+            "\u{202B}\u{2068}abc\u{2069}\u{202C}"
+        );
+    }
+
+    #[test]
+    fn test_isolate_rtl() {
+        assert_eq!(isolate_rtl("한글"), "한글");
+        assert_eq!(isolate_rtl("مرحبا 1"), "\u{2068}مرحبا 1\u{2069}");
+    }
+
+    #[test]
+    fn test_directional_runs() {
+        let runs = directional_runs("안녕 مرحبا 123 عالم, world");
+        let texts: Vec<_> = runs.iter().map(|run| (run.direction, run.text)).collect();
+        assert_eq!(
+            texts,
+            vec![
+                (Some(TextDirection::Ltr), "안녕"),
+                (None, " "),
+                (Some(TextDirection::Rtl), "مرحبا 123 عالم"),
+                (None, ", "),
+                (Some(TextDirection::Ltr), "world"),
+            ]
+        );
+        assert_eq!(
+            directional_runs("(123) abc."),
+            vec![
+                DirectionalRun {
+                    direction: None,
+                    text: "(123) ",
+                },
+                DirectionalRun {
+                    direction: Some(TextDirection::Ltr),
+                    text: "abc",
+                },
+                DirectionalRun {
+                    direction: None,
+                    text: ".",
+                },
+            ]
+        );
+        assert!(directional_runs("").is_empty());
+    }
+
+    #[test]
+    fn test_mark_directional_runs() {
+        assert!(matches!(
+            mark_directional_runs("한글 123"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            mark_directional_runs("가격 שלום 100원"),
+            "가격 שלום\u{200E} 100원"
+        );
+        assert_eq!(mark_directional_runs("مرحبا abc 1"), "مرحبا abc\u{200F} 1");
+        // 문단 끝의 구간 뒤에는 넣지 않음 / No mark after a run ending the paragraph
+        assert_eq!(mark_directional_runs("가 مرحبا"), "가 مرحبا");
+    }
+}
@@ -0,0 +1,490 @@
+/// Format-neutral block tree
+/// 형식 중립 블록 트리
+///
+/// [`walk_document`]의 방문자로 제목/문단/표와 강조/각주/그림 인라인으로 이루어진 트리를 만듭니다.
+/// 순회는 드라이버에만 있고, Pandoc, reStructuredText, AsciiDoc 출력은 이 트리를 각자의 문법으로
+/// 쓰기만 합니다.
+/// Builds a tree of headings/paragraphs/tables with emphasis/note/picture inlines as a visitor of
+/// [`walk_document`]. Traversal lives only in the driver; the Pandoc, reStructuredText and
+/// AsciiDoc outputs only write this tree in their own syntax.
+use crate::document::bindata::BinaryDataItem;
+use crate::document::bodytext::{CharShapeInfo, Paragraph, ParagraphRecord, Table, TableCell};
+use crate::document::images::sniff_base64_mime_type;
+use crate::document::{CharShape, CtrlHeader, CtrlId, HwpDocument};
+use crate::viewer::core::visitor::{walk_document, DocumentVisitor};
+use crate::viewer::markdown::utils::outline_level;
+use crate::viewer::toc::heading_anchor;
+
+/// 블록 요소 / Block element
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Block<'a> {
+    /// 개요 문단 / Outline paragraph
+    Heading {
+        /// 제목 수준 (1부터) / Heading level (1-based)
+        level: u8,
+        /// 차례와 같은 앵커 ID / Anchor ID shared with the table of contents
+        anchor: String,
+        inlines: Vec<Inline<'a>>,
+    },
+    /// 일반 문단 / Regular paragraph
+    Paragraph(Vec<Inline<'a>>),
+    /// 표 / Table
+    Table(TableBlock<'a>),
+}
+
+/// 표 블록 / Table block
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TableBlock<'a> {
+    pub row_count: usize,
+    pub col_count: usize,
+    /// 머리행 수 (제목 줄 반복이 켜진 표는 1) / Number of header rows (1 for tables with header row repeat)
+    pub header_rows: usize,
+    /// 행, 열 순으로 정렬된 칸 / Cells sorted by row, then column
+    pub cells: Vec<CellBlock<'a>>,
+}
+
+/// 표 칸 / Table cell
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CellBlock<'a> {
+    pub row: usize,
+    pub col: usize,
+    pub row_span: usize,
+    pub col_span: usize,
+    pub blocks: Vec<Block<'a>>,
+}
+
+/// 인라인 요소 / Inline element
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Inline<'a> {
+    /// 공백 없는 낱말 / Word without whitespace
+    Text(String),
+    Space,
+    LineBreak,
+    Styled(Emphasis, Vec<Inline<'a>>),
+    /// 각주/미주 내용 / Footnote/endnote content
+    Note(Vec<Block<'a>>),
+    Image(ImageRef<'a>),
+}
+
+/// 강조 종류 / Kind of emphasis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Emphasis {
+    Strong,
+    Emph,
+    Underline,
+    Strikeout,
+    Superscript,
+    Subscript,
+}
+
+/// 그림 참조 / Picture reference
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImageRef<'a> {
+    /// 대체 텍스트 / Alt text
+    pub alt: String,
+    pub mime_type: &'static str,
+    /// base64 데이터 / Base64 data
+    pub data: &'a str,
+}
+
+impl ImageRef<'_> {
+    /// data URI / data URI
+    pub fn data_uri(&self) -> String {
+        format!("data:{};base64,{}", self.mime_type, self.data)
+    }
+}
+
+/// 강조를 뺀 텍스트 (각주와 그림은 제외) / Text without emphasis (notes and pictures left out)
+pub(crate) fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(word) => text.push_str(word),
+            Inline::Space => text.push(' '),
+            Inline::LineBreak => text.push('\n'),
+            Inline::Styled(_, inner) => text.push_str(&plain_text(inner)),
+            Inline::Note(_) | Inline::Image(_) => {}
+        }
+    }
+    text
+}
+
+/// 문서 본문을 블록 트리로 / The document body as a block tree
+///
+/// 개요 문단은 제목, 문단 안의 표와 글상자는 문단 뒤 블록, 각주/미주는 문단 끝의 `Note`가
+/// 됩니다. 머리말/꼬리말은 포함하지 않습니다.
+/// Outline paragraphs become headings, tables and text boxes in a paragraph become blocks after
+/// it, and footnotes/endnotes become a `Note` at the end of their paragraph. Headers and footers
+/// are left out.
+pub(crate) fn document_blocks(document: &HwpDocument) -> Vec<Block<'_>> {
+    let mut builder = BlockBuilder {
+        document,
+        frames: Vec::new(),
+        blocks: Vec::new(),
+    };
+    walk_document(document, &mut builder);
+    builder.blocks
+}
+
+/// 순회 중 아직 닫히지 않은 요소 / An element not yet closed during traversal
+enum Frame<'a> {
+    Paragraph {
+        location: Option<(usize, usize)>,
+        char_shapes: Vec<CharShapeInfo>,
+        inlines: Vec<Inline<'a>>,
+        /// 문단 뒤에 붙는 표와 글상자 / Tables and text boxes placed after the paragraph
+        trailing: Vec<Block<'a>>,
+    },
+    Note(Vec<Block<'a>>),
+    Table(Vec<CellBlock<'a>>),
+    Cell(CellBlock<'a>),
+}
+
+/// 방문자 콜백으로 블록 트리를 쌓음 / Builds the block tree from visitor callbacks
+struct BlockBuilder<'a> {
+    document: &'a HwpDocument,
+    frames: Vec<Frame<'a>>,
+    /// 본문 최상위 블록 / Top-level body blocks
+    blocks: Vec<Block<'a>>,
+}
+
+impl<'a> BlockBuilder<'a> {
+    /// 블록을 가장 가까운 문단(문단 뒤), 각주 또는 셀에 담음
+    /// Put blocks into the nearest paragraph (after it), note or cell
+    fn push_blocks(&mut self, blocks: Vec<Block<'a>>) {
+        match self.frames.last_mut() {
+            Some(Frame::Paragraph { trailing, .. }) => trailing.extend(blocks),
+            Some(Frame::Note(note)) => note.extend(blocks),
+            Some(Frame::Cell(cell)) => cell.blocks.extend(blocks),
+            // 셀 밖에서 표 안으로 바로 들어오는 블록은 없음 / No block goes straight into a table outside a cell
+            Some(Frame::Table(_)) => {}
+            None => self.blocks.extend(blocks),
+        }
+    }
+
+    /// 가장 가까운 문단의 인라인 / Inlines of the nearest paragraph
+    fn inlines(&mut self) -> Option<&mut Vec<Inline<'a>>> {
+        self.frames.iter_mut().rev().find_map(|frame| match frame {
+            Frame::Paragraph { inlines, .. } => Some(inlines),
+            _ => None,
+        })
+    }
+
+    fn is_note(header: &CtrlHeader) -> bool {
+        header.ctrl_id == CtrlId::FOOTNOTE || header.ctrl_id == CtrlId::ENDNOTE
+    }
+}
+
+impl<'a> DocumentVisitor<'a> for BlockBuilder<'a> {
+    fn enter_paragraph(
+        &mut self,
+        paragraph: &'a Paragraph,
+        location: Option<(usize, usize)>,
+    ) -> bool {
+        let char_shapes = paragraph
+            .records
+            .iter()
+            .filter_map(|record| match record {
+                ParagraphRecord::ParaCharShape { shapes } => Some(shapes.as_slice()),
+                _ => None,
+            })
+            .flatten()
+            .cloned()
+            .collect();
+        self.frames.push(Frame::Paragraph {
+            location,
+            char_shapes,
+            inlines: Vec::new(),
+            trailing: Vec::new(),
+        });
+        true
+    }
+
+    /// 본문 최상위 문단(`location`이 있는 문단)만 개요 수준에 따라 제목이 됩니다.
+    /// Only top-level body paragraphs (those with a `location`) become headings by outline level.
+    fn leave_paragraph(&mut self, paragraph: &'a Paragraph, _location: Option<(usize, usize)>) {
+        let Some(Frame::Paragraph {
+            location,
+            inlines,
+            trailing,
+            ..
+        }) = self.frames.pop()
+        else {
+            return;
+        };
+        let mut blocks = Vec::new();
+        let inlines = trim_inlines(inlines);
+        if !inlines.is_empty() {
+            let level = location.and_then(|_| outline_level(&paragraph.para_header, self.document));
+            match (level, location) {
+                (Some(level), Some((section_index, paragraph_index))) => {
+                    blocks.push(Block::Heading {
+                        level,
+                        anchor: heading_anchor(section_index, paragraph_index),
+                        inlines,
+                    })
+                }
+                _ => blocks.push(Block::Paragraph(inlines)),
+            }
+        }
+        blocks.extend(trailing);
+        self.push_blocks(blocks);
+    }
+
+    fn visit_text(&mut self, _paragraph: &'a Paragraph, text: &'a str) {
+        let document = self.document;
+        if let Some(Frame::Paragraph {
+            char_shapes,
+            inlines,
+            ..
+        }) = self.frames.last_mut()
+        {
+            inlines.extend(styled_inlines(text, char_shapes, document));
+        }
+    }
+
+    /// 그림이 아닌 바이너리(OLE 개체 등)는 건너뜁니다. / Non-image binaries (OLE objects, etc.) are skipped.
+    fn visit_picture(&mut self, item: &'a BinaryDataItem) {
+        let mime_type = sniff_base64_mime_type(&item.data);
+        if !mime_type.starts_with("image/") {
+            return;
+        }
+        let image = Inline::Image(ImageRef {
+            alt: self.document.image_label(item.index).alt_text(),
+            mime_type,
+            data: &item.data,
+        });
+        if let Some(inlines) = self.inlines() {
+            inlines.push(image);
+        }
+    }
+
+    /// 각주/미주, 표, 그리기 개체만 들어감 (머리말/꼬리말 등은 건너뜀)
+    /// Only footnotes/endnotes, tables and shape objects are entered (headers/footers etc. are skipped)
+    fn enter_control(&mut self, header: &'a CtrlHeader) -> bool {
+        if Self::is_note(header) {
+            self.frames.push(Frame::Note(Vec::new()));
+            true
+        } else {
+            header.ctrl_id == CtrlId::TABLE || header.ctrl_id == CtrlId::SHAPE_OBJECT
+        }
+    }
+
+    fn leave_control(&mut self, header: &'a CtrlHeader) {
+        if !Self::is_note(header) {
+            return;
+        }
+        if let Some(Frame::Note(note)) = self.frames.pop() {
+            if let (false, Some(inlines)) = (note.is_empty(), self.inlines()) {
+                inlines.push(Inline::Note(note));
+            }
+        }
+    }
+
+    fn enter_table(&mut self, _table: &'a Table) -> bool {
+        self.frames.push(Frame::Table(Vec::new()));
+        true
+    }
+
+    /// 셀 주소가 행/열 개수를 넘는 손상된 표도 담도록 크기를 셀에서 다시 계산합니다.
+    /// The size is recomputed from the cells so damaged tables whose addresses exceed the row/column
+    /// counts still fit.
+    fn leave_table(&mut self, table: &'a Table) {
+        let Some(Frame::Table(mut cells)) = self.frames.pop() else {
+            return;
+        };
+        let mut row_count = table.attributes.row_count as usize;
+        let mut col_count = table.attributes.col_count as usize;
+        cells.sort_by_key(|cell| (cell.row, cell.col));
+        for cell in &cells {
+            row_count = row_count.max(cell.row + cell.row_span);
+            col_count = col_count.max(cell.col + cell.col_span);
+        }
+        self.push_blocks(vec![Block::Table(TableBlock {
+            row_count,
+            col_count,
+            header_rows: table.header_rows(&self.document.doc_info.border_fill),
+            cells,
+        })]);
+    }
+
+    fn enter_cell(&mut self, cell: &'a TableCell) -> bool {
+        let attributes = &cell.cell_attributes;
+        self.frames.push(Frame::Cell(CellBlock {
+            row: attributes.row_address as usize,
+            col: attributes.col_address as usize,
+            row_span: attributes.row_span.max(1) as usize,
+            col_span: attributes.col_span.max(1) as usize,
+            blocks: Vec::new(),
+        }));
+        true
+    }
+
+    fn leave_cell(&mut self, _cell: &'a TableCell) {
+        if let Some(Frame::Cell(cell)) = self.frames.pop() {
+            if let Some(Frame::Table(cells)) = self.frames.last_mut() {
+                cells.push(cell);
+            }
+        }
+    }
+}
+
+/// 글자 모양에서 옮기는 강조 / Emphasis carried over from a character shape
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strikeout: bool,
+    superscript: bool,
+    subscript: bool,
+}
+
+impl InlineStyle {
+    fn from_char_shape(shape: &CharShape) -> Self {
+        InlineStyle {
+            bold: shape.attributes.bold,
+            italic: shape.attributes.italic,
+            underline: shape.attributes.underline_type != 0,
+            strikeout: shape.attributes.strikethrough != 0,
+            superscript: shape.is_superscript(),
+            subscript: shape.is_subscript(),
+        }
+    }
+
+    /// 인라인 목록을 강조 요소로 감쌈 (굵게가 가장 안쪽) / Wrap inlines in the emphasis elements (bold innermost)
+    fn apply(self, mut inlines: Vec<Inline>) -> Vec<Inline> {
+        for (enabled, emphasis) in [
+            (self.bold, Emphasis::Strong),
+            (self.italic, Emphasis::Emph),
+            (self.underline, Emphasis::Underline),
+            (self.strikeout, Emphasis::Strikeout),
+            (self.superscript, Emphasis::Superscript),
+            (self.subscript, Emphasis::Subscript),
+        ] {
+            if enabled {
+                inlines = vec![Inline::Styled(emphasis, inlines)];
+            }
+        }
+        inlines
+    }
+}
+
+/// 글자 모양 구간별로 강조를 적용한 인라인 / Inlines with emphasis applied per character shape run
+fn styled_inlines<'a>(
+    text: &str,
+    char_shapes: &[CharShapeInfo],
+    document: &HwpDocument,
+) -> Vec<Inline<'a>> {
+    let mut sorted: Vec<&CharShapeInfo> = char_shapes.iter().collect();
+    sorted.sort_by_key(|shape| shape.position);
+
+    // 같은 강조가 이어지는 구간을 합침 / Merge adjacent runs with the same emphasis
+    let mut runs: Vec<(InlineStyle, String)> = Vec::new();
+    for (index, ch) in text.chars().enumerate() {
+        let style = sorted
+            .iter()
+            .rev()
+            .find(|shape| shape.position as usize <= index)
+            .and_then(|shape| document.doc_info.char_shapes.get(shape.shape_id as usize))
+            .map(InlineStyle::from_char_shape)
+            .unwrap_or_default();
+        match runs.last_mut() {
+            Some((last, text)) if *last == style || ch.is_whitespace() => text.push(ch),
+            _ => runs.push((style, ch.to_string())),
+        }
+    }
+
+    runs.into_iter()
+        .flat_map(|(style, text)| {
+            if style == InlineStyle::default() {
+                text_inlines(&text)
+            } else {
+                // 강조 앞뒤 공백은 강조 밖으로 / Keep surrounding spaces outside the emphasis
+                let trimmed = text.trim();
+                let start = text.len() - text.trim_start().len();
+                let mut inlines = text_inlines(&text[..start]);
+                inlines.extend(style.apply(text_inlines(trimmed)));
+                inlines.extend(text_inlines(&text[start + trimmed.len()..]));
+                inlines
+            }
+        })
+        .collect()
+}
+
+/// 건너뛴 제목 수준을 메움 / Close gaps between heading levels
+///
+/// 각 제목은 앞 제목보다 한 수준까지만 깊어질 수 있게 낮춥니다 (첫 제목은 1수준). 구역을
+/// 문법으로 중첩하는 reStructuredText와 AsciiDoc은 수준을 건너뛰면 문서를 읽지 못하거나 경고합니다.
+/// Lowers each heading to at most one level deeper than the previous one (the first heading is
+/// level 1). reStructuredText and AsciiDoc nest sections syntactically and reject or warn about
+/// skipped levels.
+pub(crate) fn close_heading_gaps(blocks: &mut [Block]) {
+    let mut previous = 0;
+    for block in blocks {
+        if let Block::Heading { level, .. } = block {
+            *level = (*level).min(previous + 1);
+            previous = *level;
+        }
+    }
+}
+
+/// 일반 텍스트를 `Text`/`Space`/`LineBreak` 인라인으로 / Plain text as `Text`/`Space`/`LineBreak` inlines
+pub(crate) fn text_inlines<'a>(text: &str) -> Vec<Inline<'a>> {
+    let mut inlines = Vec::new();
+    let mut word = String::new();
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !word.is_empty() {
+                inlines.push(Inline::Text(std::mem::take(&mut word)));
+            }
+            let inline = if ch == '\n' {
+                Inline::LineBreak
+            } else {
+                Inline::Space
+            };
+            // 공백이 이어지면 하나로, 줄바꿈이 공백보다 우선 / Collapse runs of spaces; a line break wins over a space
+            match inlines.last_mut() {
+                Some(last @ Inline::Space) => *last = inline,
+                Some(Inline::LineBreak) => {}
+                _ => inlines.push(inline),
+            }
+        } else if !ch.is_control() {
+            word.push(ch);
+        }
+    }
+    if !word.is_empty() {
+        inlines.push(Inline::Text(word));
+    }
+    inlines
+}
+
+/// 문단 앞뒤의 공백/줄바꿈 인라인 제거 / Strip leading and trailing space/line break inlines
+fn trim_inlines(mut inlines: Vec<Inline>) -> Vec<Inline> {
+    let is_blank = |inline: &Inline| matches!(inline, Inline::Space | Inline::LineBreak);
+    while inlines.last().is_some_and(is_blank) {
+        inlines.pop();
+    }
+    let leading = inlines.iter().take_while(|inline| is_blank(inline)).count();
+    inlines.drain(..leading);
+    inlines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_inlines_collapse_spaces() {
+        assert_eq!(
+            text_inlines("가나  다\n라"),
+            vec![
+                Inline::Text("가나".to_string()),
+                Inline::Space,
+                Inline::Text("다".to_string()),
+                Inline::LineBreak,
+                Inline::Text("라".to_string()),
+            ]
+        );
+    }
+}
@@ -6,9 +6,15 @@
 ///
 /// Provides common bodytext processing logic used by all viewers.
 /// Output format is handled through the Renderer trait.
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io;
+
+use crate::document::notes::{NoteNumber, NoteNumbering};
 use crate::document::{ColumnDivideType, CtrlHeader, HwpDocument, Paragraph, ParagraphRecord};
+use crate::viewer::core::page_fields::{header_footer_paragraphs, substitute_page_fields};
 use crate::viewer::core::renderer::{DocumentParts, Renderer};
-use crate::viewer::markdown::utils::OutlineNumberTracker;
+use crate::viewer::markdown::utils::{note_definition, note_label, OutlineNumberTracker};
 use crate::viewer::{html, html::HtmlOptions, MarkdownOptions};
 
 /// Render paragraph using viewer-specific functions
@@ -100,6 +106,24 @@ impl TrackerRef for Tracker {
     }
 }
 
+/// Create outline number tracker for the renderer
+/// 렌더러에 맞는 개요 번호 추적기 생성
+fn new_tracker<R: Renderer>() -> Tracker
+where
+    R::Options: 'static,
+{
+    // 새로운 HTML 뷰어는 tracker를 사용하지 않음 / New HTML viewer does not use tracker
+    if std::any::TypeId::of::<R::Options>() == std::any::TypeId::of::<HtmlOptions>() {
+        Tracker::Html(())
+    } else if std::any::TypeId::of::<R::Options>() == std::any::TypeId::of::<MarkdownOptions>() {
+        Tracker::Markdown(OutlineNumberTracker::new())
+    } else {
+        // 기본 렌더러는 tracker가 필요 없을 수 있음 / Default renderer may not need tracker
+        // 하지만 일단 Markdown tracker를 사용 (나중에 필요시 수정) / But use Markdown tracker for now (modify later if needed)
+        Tracker::Markdown(OutlineNumberTracker::new())
+    }
+}
+
 /// Process bodytext and return document parts
 /// 본문을 처리하고 문서 부분들을 반환
 pub fn process_bodytext<R: Renderer>(
@@ -110,30 +134,101 @@ pub fn process_bodytext<R: Renderer>(
 where
     R::Options: 'static,
 {
+    let mut body_lines = Vec::new();
+    let mut parts = process_bodytext_with(document, renderer, options, |line| {
+        body_lines.push(line);
+        Ok(())
+    })
+    .expect("collecting body lines cannot fail");
+    parts.body_lines = body_lines;
+    parts
+}
+
+/// Render only the headers (머리말) of the document
+/// 문서의 머리말만 렌더링
+///
+/// 출력에서 머리말은 본문보다 앞에 오므로, 본문을 순차 출력하기 전에 먼저 수집합니다.
+/// Headers precede the body in the output, so they are collected before streaming the body.
+pub fn collect_headers<R: Renderer>(
+    document: &HwpDocument,
+    renderer: &R,
+    options: &R::Options,
+) -> Vec<String>
+where
+    R::Options: 'static,
+{
+    use crate::document::CtrlId;
     let mut parts = DocumentParts::default();
+    let mut tracker = new_tracker::<R>();
+    for section in &document.body_text.sections {
+        for paragraph in &section.paragraphs {
+            if !paragraph.para_header.control_mask.has_header_footer() {
+                continue;
+            }
+            for record in &paragraph.records {
+                if let ParagraphRecord::CtrlHeader {
+                    header,
+                    children,
+                    paragraphs: ctrl_paragraphs,
+                } = record
+                {
+                    if header.ctrl_id.as_str() == CtrlId::HEADER {
+                        process_header(
+                            header,
+                            children,
+                            ctrl_paragraphs,
+                            document,
+                            renderer,
+                            options,
+                            &mut parts,
+                            &mut tracker,
+                        );
+                    }
+                }
+            }
+        }
+    }
+    parts.headers
+}
 
-    // 각주/미주 번호 추적기 / Footnote/endnote number tracker
-    let mut footnote_counter = 1u32;
-    let mut endnote_counter = 1u32;
+/// Process bodytext, passing body lines to `emit` as they are completed
+/// 본문을 처리하며 완성된 본문 줄을 `emit`으로 바로 전달
+///
+/// 각주/미주 참조가 직전 문단 끝에 붙을 수 있으므로 마지막 줄 하나만 보관하고 나머지는 즉시
+/// 내보냅니다. 반환되는 `DocumentParts`의 `body_lines`는 비어 있습니다.
+///
+/// Footnote/endnote references may be appended to the preceding paragraph, so only the last
+/// line is held back and everything else is emitted immediately. The returned `DocumentParts`
+/// has empty `body_lines`.
+pub fn process_bodytext_with<R, F>(
+    document: &HwpDocument,
+    renderer: &R,
+    options: &R::Options,
+    mut emit: F,
+) -> io::Result<DocumentParts>
+where
+    R: Renderer,
+    R::Options: 'static,
+    F: FnMut(String) -> io::Result<()>,
+{
+    let mut parts = DocumentParts::default();
+
+    // 각주/미주 번호는 구역의 번호 규칙을 따르고, 앵커 id는 문서 전체에서 하나씩 늘어남
+    // Note numbers follow each section's numbering rules; anchor ids increase across the document
+    let mut notes = NoteNumbering::new(document);
+    let mut footnote_ids = NoteIds::default();
+    let mut endnote_ids = NoteIds::default();
 
     // 개요 번호 추적기 생성 (렌더러별로 다름) / Create outline number tracker (varies by renderer)
     // 문서 전체에 걸쳐 상태를 유지해야 하므로 한 번만 생성 / Created only once to maintain state across entire document
-    // 새로운 HTML 뷰어는 tracker를 사용하지 않음 / New HTML viewer does not use tracker
-    let mut tracker: Tracker = if std::any::TypeId::of::<R::Options>()
-        == std::any::TypeId::of::<HtmlOptions>()
-    {
-        Tracker::Html(())
-    } else if std::any::TypeId::of::<R::Options>() == std::any::TypeId::of::<MarkdownOptions>() {
-        Tracker::Markdown(OutlineNumberTracker::new())
-    } else {
-        // 기본 렌더러는 tracker가 필요 없을 수 있음 / Default renderer may not need tracker
-        // 하지만 일단 Markdown tracker를 사용 (나중에 필요시 수정) / But use Markdown tracker for now (modify later if needed)
-        Tracker::Markdown(OutlineNumberTracker::new())
-    };
+    let mut tracker = new_tracker::<R>();
 
     // Convert body text / 본문 텍스트를 변환
-    for section in &document.body_text.sections {
-        for paragraph in &section.paragraphs {
+    for (section_index, section) in document.body_text.sections.iter().enumerate() {
+        notes.start_section(section);
+        for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+            notes.start_paragraph(paragraph);
+
             // control_mask를 사용하여 빠른 필터링 (최적화) / Use control_mask for quick filtering (optimization)
             let control_mask = &paragraph.para_header.control_mask;
 
@@ -177,10 +272,10 @@ where
                             );
                         } else if header.ctrl_id.as_str() == CtrlId::FOOTNOTE {
                             // 각주 처리 / Process footnote
-                            let footnote_id = footnote_counter;
-                            footnote_counter += 1;
+                            let (footnote_id, number) = footnote_ids.next(notes.footnote.take());
                             process_footnote(
                                 footnote_id,
+                                &number,
                                 header,
                                 children,
                                 ctrl_paragraphs,
@@ -192,10 +287,10 @@ where
                             );
                         } else if header.ctrl_id.as_str() == CtrlId::ENDNOTE {
                             // 미주 처리 / Process endnote
-                            let endnote_id = endnote_counter;
-                            endnote_counter += 1;
+                            let (endnote_id, number) = endnote_ids.next(notes.endnote.take());
                             process_endnote(
                                 endnote_id,
+                                &number,
                                 header,
                                 children,
                                 ctrl_paragraphs,
@@ -237,13 +332,107 @@ where
                     &mut tracker,
                 );
                 if !para_content.is_empty() {
-                    parts.body_lines.push(para_content);
+                    // 차례 링크가 가리킬 앵커 / Anchor targeted by table of contents links
+                    let para_content = match toc_anchor(
+                        options,
+                        paragraph,
+                        document,
+                        section_index,
+                        paragraph_index,
+                    ) {
+                        Some(anchor) => format!("<a id=\"{anchor}\"></a>{para_content}"),
+                        None => para_content,
+                    };
+                    // 원본 문단 위치 주석 / Source paragraph location comment
+                    match source_anchor(options, section_index, paragraph_index) {
+                        Some(anchor) => parts
+                            .body_lines
+                            .push(format!("<!-- data-para=\"{anchor}\" -->\n{para_content}")),
+                        None => parts.body_lines.push(para_content),
+                    }
+                }
+            }
+
+            // 마지막 줄을 제외하고 내보내기 / Emit all but the last line
+            if parts.body_lines.len() > 1 {
+                let completed = parts.body_lines.len() - 1;
+                for line in parts.body_lines.drain(..completed) {
+                    emit(line)?;
                 }
             }
         }
+
+        // 구역 끝에 두는 미주는 구역 본문 뒤에 씀 (각주의 단 배치는 흐르는 출력에서 의미 없음)
+        // Endnotes placed at the section end follow the section body (footnote column placement
+        // has no meaning in flowing output)
+        let at_section_end = notes
+            .endnote
+            .shape()
+            .is_some_and(|shape| shape.endnotes_at_section_end());
+        if at_section_end && !parts.endnotes.is_empty() {
+            let section_notes = DocumentParts {
+                endnotes: std::mem::take(&mut parts.endnotes),
+                ..Default::default()
+            };
+            for line in parts.body_lines.drain(..) {
+                emit(line)?;
+            }
+            parts
+                .body_lines
+                .push(renderer.render_document_footer(&section_notes, options));
+        }
     }
 
-    parts
+    for line in parts.body_lines.drain(..) {
+        emit(line)?;
+    }
+
+    Ok(parts)
+}
+
+/// Anchor for an outline paragraph when the Markdown table of contents is enabled
+/// Markdown 차례가 켜져 있을 때 개요 문단에 붙일 앵커
+fn toc_anchor<O: 'static>(
+    options: &O,
+    paragraph: &Paragraph,
+    document: &HwpDocument,
+    section_index: usize,
+    paragraph_index: usize,
+) -> Option<String> {
+    let options = (options as &dyn std::any::Any).downcast_ref::<MarkdownOptions>()?;
+    if options.include_toc != Some(true) {
+        return None;
+    }
+    crate::viewer::markdown::utils::outline_level(&paragraph.para_header, document)?;
+    Some(crate::viewer::toc::heading_anchor(
+        section_index,
+        paragraph_index,
+    ))
+}
+
+/// Source location of a body paragraph when Markdown source anchors are enabled
+/// Markdown 원본 위치 주석이 켜져 있을 때 본문 문단의 위치 (`구역:문단`)
+fn source_anchor<O: 'static>(
+    options: &O,
+    section_index: usize,
+    paragraph_index: usize,
+) -> Option<String> {
+    let options = (options as &dyn std::any::Any).downcast_ref::<MarkdownOptions>()?;
+    (options.include_source_anchors == Some(true))
+        .then(|| crate::viewer::toc::source_anchor(section_index, paragraph_index))
+}
+
+/// 머리말/꼬리말의 쪽 번호 필드를 Markdown `{page}`/`{pages}` 토큰으로 바꾸거나 지운 문단
+/// Paragraph with header/footer page number fields turned into Markdown `{page}`/`{pages}`
+/// tokens, or removed
+fn with_page_tokens<'a, O: 'static>(paragraph: &'a Paragraph, options: &O) -> Cow<'a, Paragraph> {
+    let Some(options) = (options as &dyn std::any::Any).downcast_ref::<MarkdownOptions>() else {
+        return Cow::Borrowed(paragraph);
+    };
+    let strip = options.strip_page_fields == Some(true);
+    substitute_page_fields(paragraph, &|field, _| {
+        (!strip).then(|| field.token().to_string())
+    })
 }
 
 /// Check if a line is a page break line (renderer-specific)
@@ -268,32 +457,14 @@ fn process_header<R: Renderer>(
 ) where
     R::Options: 'static,
 {
-    // LIST_HEADER가 있으면 children에서 처리, 없으면 paragraphs에서 처리
-    // If LIST_HEADER exists, process from children, otherwise from paragraphs
-    let mut found_list_header = false;
-    for child_record in children {
-        if let ParagraphRecord::ListHeader { paragraphs, .. } = child_record {
-            found_list_header = true;
-            // LIST_HEADER 내부의 문단 처리 / Process paragraphs inside LIST_HEADER
-            // 기존 뷰어 함수를 직접 호출 (글자 모양, 개요 번호 등 복잡한 처리를 위해)
-            // Call existing viewer functions directly (for complex processing like character shapes, outline numbers, etc.)
-            for para in paragraphs {
-                let para_content =
-                    render_paragraph_with_viewer(para, document, renderer, options, tracker);
-                if !para_content.is_empty() {
-                    parts.headers.push(para_content);
-                }
-            }
-        }
-    }
-    // LIST_HEADER가 없으면 paragraphs 처리 / If no LIST_HEADER, process paragraphs
-    if !found_list_header {
-        for para in ctrl_paragraphs {
-            let para_content =
-                render_paragraph_with_viewer(para, document, renderer, options, tracker);
-            if !para_content.is_empty() {
-                parts.headers.push(para_content);
-            }
+    // LIST_HEADER가 있으면 그 안의 문단, 없으면 paragraphs 처리
+    // Process paragraphs inside the LIST_HEADER if present, otherwise the control's paragraphs
+    for para in header_footer_paragraphs(children, ctrl_paragraphs) {
+        let para = with_page_tokens(para, options);
+        let para_content =
+            render_paragraph_with_viewer(&para, document, renderer, options, tracker);
+        if !para_content.is_empty() {
+            parts.headers.push(para_content);
         }
     }
 }
@@ -312,30 +483,14 @@ fn process_footer<R: Renderer>(
 ) where
     R::Options: 'static,
 {
-    // LIST_HEADER가 있으면 children에서 처리, 없으면 paragraphs에서 처리
-    // If LIST_HEADER exists, process from children, otherwise from paragraphs
-    let mut found_list_header = false;
-    for child_record in children {
-        if let ParagraphRecord::ListHeader { paragraphs, .. } = child_record {
-            found_list_header = true;
-            // LIST_HEADER 내부의 문단 처리 / Process paragraphs inside LIST_HEADER
-            for para in paragraphs {
-                let para_content =
-                    render_paragraph_with_viewer(para, document, renderer, options, tracker);
-                if !para_content.is_empty() {
-                    parts.footers.push(para_content);
-                }
-            }
-        }
-    }
-    // LIST_HEADER가 없으면 paragraphs 처리 / If no LIST_HEADER, process paragraphs
-    if !found_list_header {
-        for para in ctrl_paragraphs {
-            let para_content =
-                render_paragraph_with_viewer(para, document, renderer, options, tracker);
-            if !para_content.is_empty() {
-                parts.footers.push(para_content);
-            }
+    // LIST_HEADER가 있으면 그 안의 문단, 없으면 paragraphs 처리
+    // Process paragraphs inside the LIST_HEADER if present, otherwise the control's paragraphs
+    for para in header_footer_paragraphs(children, ctrl_paragraphs) {
+        let para = with_page_tokens(para, options);
+        let para_content =
+            render_paragraph_with_viewer(&para, document, renderer, options, tracker);
+        if !para_content.is_empty() {
+            parts.footers.push(para_content);
         }
     }
 }
@@ -344,6 +499,7 @@ fn process_footer<R: Renderer>(
 /// 각주 처리
 fn process_footnote<R: Renderer>(
     footnote_id: u32,
+    footnote_number: &str,
     _header: &CtrlHeader,
     _children: &[ParagraphRecord],
     ctrl_paragraphs: &[Paragraph],
@@ -355,27 +511,24 @@ fn process_footnote<R: Renderer>(
 ) where
     R::Options: 'static,
 {
-    // 각주 번호 형식 (TODO: FootnoteShape에서 가져오기)
-    // Footnote number format (TODO: Get from FootnoteShape)
-    let footnote_number = format!("{footnote_id}");
-
     // 본문에 각주 참조 링크 삽입 / Insert footnote reference link in body
     if !parts.body_lines.is_empty() {
         let last_idx = parts.body_lines.len() - 1;
         let last_line = &mut parts.body_lines[last_idx];
         // 렌더러별로 각주 참조 링크 추가 방법이 다름
         // Method to add footnote reference link varies by renderer
-        let footnote_ref = renderer.render_footnote_ref(footnote_id, &footnote_number, options);
+        let footnote_ref = renderer.render_footnote_ref(footnote_id, footnote_number, options);
         *last_line = append_to_last_paragraph(last_line, &footnote_ref, renderer);
     } else {
         // 본문이 비어있으면 새 문단으로 추가 / Add as new paragraph if body is empty
-        let footnote_ref = renderer.render_footnote_ref(footnote_id, &footnote_number, options);
+        let footnote_ref = renderer.render_footnote_ref(footnote_id, footnote_number, options);
         parts
             .body_lines
             .push(renderer.render_paragraph(&footnote_ref));
     }
 
     // 각주 내용 수집 / Collect footnote content
+    let mut first = true;
     for para in ctrl_paragraphs {
         let para_content = render_paragraph_with_viewer(para, document, renderer, options, tracker);
         if !para_content.is_empty() {
@@ -387,12 +540,15 @@ fn process_footnote<R: Renderer>(
             // Footnote container format by renderer (HTML: <div>, Markdown: plain text)
             let footnote_container = format_footnote_container(
                 &footnote_id_str,
+                footnote_number,
+                first,
                 &footnote_back,
                 &para_content,
                 renderer,
                 options,
             );
             parts.footnotes.push(footnote_container);
+            first = false;
         }
     }
 }
@@ -401,6 +557,7 @@ fn process_footnote<R: Renderer>(
 /// 미주 처리
 fn process_endnote<R: Renderer>(
     endnote_id: u32,
+    endnote_number: &str,
     _header: &CtrlHeader,
     _children: &[ParagraphRecord],
     ctrl_paragraphs: &[Paragraph],
@@ -412,25 +569,22 @@ fn process_endnote<R: Renderer>(
 ) where
     R::Options: 'static,
 {
-    // 미주 번호 형식 (TODO: FootnoteShape에서 가져오기)
-    // Endnote number format (TODO: Get from FootnoteShape)
-    let endnote_number = format!("{endnote_id}");
-
     // 본문에 미주 참조 링크 삽입 / Insert endnote reference link in body
     if !parts.body_lines.is_empty() {
         let last_idx = parts.body_lines.len() - 1;
         let last_line = &mut parts.body_lines[last_idx];
-        let endnote_ref = renderer.render_endnote_ref(endnote_id, &endnote_number, options);
+        let endnote_ref = renderer.render_endnote_ref(endnote_id, endnote_number, options);
         *last_line = append_to_last_paragraph(last_line, &endnote_ref, renderer);
     } else {
         // 본문이 비어있으면 새 문단으로 추가 / Add as new paragraph if body is empty
-        let endnote_ref = renderer.render_endnote_ref(endnote_id, &endnote_number, options);
+        let endnote_ref = renderer.render_endnote_ref(endnote_id, endnote_number, options);
         parts
             .body_lines
             .push(renderer.render_paragraph(&endnote_ref));
     }
 
     // 미주 내용 수집 / Collect endnote content
+    let mut first = true;
     for para in ctrl_paragraphs {
         let para_content = render_paragraph_with_viewer(para, document, renderer, options, tracker);
         if !para_content.is_empty() {
@@ -440,12 +594,37 @@ fn process_endnote<R: Renderer>(
 
             parts.endnotes.push(format_endnote_container(
                 &endnote_id_str,
+                endnote_number,
+                first,
                 &endnote_back,
                 &para_content,
                 renderer,
                 options,
             ));
+            first = false;
+        }
+    }
+}
+
+/// 각주 또는 미주의 앵커 id와 겹치지 않는 표시 번호
+/// Anchor ids and unique display numbers of footnotes or endnotes
+#[derive(Default)]
+struct NoteIds {
+    last_id: u32,
+    used: HashSet<String>,
+}
+
+impl NoteIds {
+    /// 다음 id와 표시 번호 (쪽/구역마다 다시 시작해 겹친 번호에는 id를 붙임)
+    /// Next id and display number (numbers repeated by per-page/per-section restarts get the id appended)
+    fn next(&mut self, number: NoteNumber) -> (u32, String) {
+        self.last_id += 1;
+        let mut label = number.label;
+        if !self.used.insert(label.clone()) {
+            label = format!("{label}-{}", self.last_id);
+            self.used.insert(label.clone());
         }
+        (self.last_id, label)
     }
 }
 
@@ -465,6 +644,8 @@ fn append_to_last_paragraph<R: Renderer>(last_line: &str, content: &str, _render
 /// 각주 컨테이너 포맷 (렌더러별)
 fn format_footnote_container<R: Renderer>(
     id: &str,
+    number: &str,
+    first: bool,
     back_link: &str,
     content: &str,
     _renderer: &R,
@@ -489,9 +670,15 @@ where
 
     // Markdown 렌더러인 경우 / If Markdown renderer
     if std::any::TypeId::of::<R::Options>() == std::any::TypeId::of::<MarkdownOptions>() {
-        // 마크다운에서는 각주를 [^1]: 형식으로 표시
-        // In markdown, footnotes are shown as [^1]:
-        return format!("{back_link}{content}");
+        // 마크다운에서는 각주를 [^1]: 형식으로 표시 (방언이 없으면 내용만)
+        // In markdown, footnotes are shown as [^1]: (content only without a flavor)
+        let markdown_options =
+            unsafe { &*(options as *const R::Options as *const MarkdownOptions) };
+        let label = note_label(number, false, markdown_options);
+        return format!(
+            "{back_link}{}",
+            note_definition(&label, content, first, markdown_options)
+        );
     }
 
     // 기본: 일반 텍스트 / Default: plain text
@@ -502,6 +689,8 @@ where
 /// 미주 컨테이너 포맷 (렌더러별)
 fn format_endnote_container<R: Renderer>(
     id: &str,
+    number: &str,
+    first: bool,
     back_link: &str,
     content: &str,
     _renderer: &R,
@@ -526,9 +715,15 @@ where
 
     // Markdown 렌더러인 경우 / If Markdown renderer
     if std::any::TypeId::of::<R::Options>() == std::any::TypeId::of::<MarkdownOptions>() {
-        // 마크다운에서는 미주를 [^1]: 형식으로 표시
-        // In markdown, endnotes are shown as [^1]:
-        return format!("{back_link}{content}");
+        // 마크다운에서는 미주를 [^1]: 형식으로 표시 (방언이 없으면 내용만)
+        // In markdown, endnotes are shown as [^1]: (content only without a flavor)
+        let markdown_options =
+            unsafe { &*(options as *const R::Options as *const MarkdownOptions) };
+        let label = note_label(number, true, markdown_options);
+        return format!(
+            "{back_link}{}",
+            note_definition(&label, content, first, markdown_options)
+        );
     }
 
     // 기본: 일반 텍스트 / Default: plain text
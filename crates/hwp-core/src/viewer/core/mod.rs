@@ -6,10 +6,15 @@
 ///
 /// This module provides common logic used by all viewers (HTML, Markdown, PDF, Image, etc.).
 /// Uses a renderer pattern where only the output format differs.
+pub mod bidi;
+pub(crate) mod blocks;
 pub mod bodytext;
+pub(crate) mod page_fields;
 mod paragraph;
 pub mod renderer;
+pub mod visitor;
 
-pub use bodytext::process_bodytext;
+pub use bodytext::{collect_headers, process_bodytext, process_bodytext_with};
 pub use paragraph::process_paragraph;
 pub use renderer::{DocumentParts, Renderer, TextStyles};
+pub use visitor::{walk_document, walk_paragraph, DocumentVisitor};
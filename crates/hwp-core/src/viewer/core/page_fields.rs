@@ -0,0 +1,311 @@
+/// 머리말/꼬리말의 쪽 번호 필드
+/// Page number fields in headers and footers
+///
+/// 쪽 번호와 전체 쪽 수는 자동 번호 컨트롤(`atno`)로 저장되며 쪽을 나누기 전에는 값을 알 수
+/// 없습니다. 이 모듈은 그 컨트롤을 찾아 출력 형식마다 정한 문자열(실제 번호나 `{page}` 같은
+/// 토큰)로 바꾼 문단을 만듭니다.
+///
+/// Page numbers and total page counts are stored as auto number controls (`atno`) whose values
+/// are unknown before pagination. This module finds those controls and builds a paragraph with
+/// them replaced by a string chosen per output format (an actual number or a token such as
+/// `{page}`).
+use std::borrow::Cow;
+
+use crate::document::bodytext::ctrl_header::CtrlHeaderData;
+use crate::document::bodytext::{
+    ControlChar, NumberShape, ParaTextRun, Paragraph, ParagraphRecord,
+};
+use crate::document::{CtrlHeader, CtrlId};
+
+/// 쪽 번호 필드 종류 / Page number field kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PageField {
+    /// 현재 쪽 번호 / Current page number
+    Page,
+    /// 전체 쪽 수 / Total number of pages
+    TotalPages,
+}
+
+impl PageField {
+    /// Markdown 토큰 / Markdown token
+    pub(crate) fn token(self) -> &'static str {
+        match self {
+            PageField::Page => "{page}",
+            PageField::TotalPages => "{pages}",
+        }
+    }
+}
+
+/// 자동 번호 컨트롤의 쪽 번호 필드 (번호 종류: 표 143 bit 0-3, 0 = 쪽 번호, 6 = 전체 쪽 수)
+/// Page number field of an auto number control (number kind: Table 143 bits 0-3, 0 = page
+/// number, 6 = total pages)
+fn page_field(header: &CtrlHeader) -> Option<(PageField, NumberShape, String, String)> {
+    if header.ctrl_id != CtrlId::AUTO_NUMBER && header.ctrl_id != CtrlId::AUTO_NUMBER_ALT {
+        return None;
+    }
+    let CtrlHeaderData::AutoNumber {
+        attribute,
+        prefix,
+        suffix,
+        ..
+    } = &header.data
+    else {
+        return None;
+    };
+    let field = match attribute & 0x0F {
+        0 => PageField::Page,
+        6 => PageField::TotalPages,
+        _ => return None,
+    };
+    let shape = NumberShape::from_code(((attribute >> 4) & 0xFF) as u8);
+    let clean = |text: &str| text.chars().filter(|c| *c != '\0').collect();
+    Some((field, shape, clean(prefix), clean(suffix)))
+}
+
+/// 머리말/꼬리말 컨트롤의 문단 (LIST_HEADER가 있으면 그 안의 문단)
+/// Paragraphs of a header/footer control (those inside the LIST_HEADER when present)
+pub(crate) fn header_footer_paragraphs<'a>(
+    children: &'a [ParagraphRecord],
+    ctrl_paragraphs: &'a [Paragraph],
+) -> Vec<&'a Paragraph> {
+    let listed: Vec<&Paragraph> = children
+        .iter()
+        .filter_map(|record| match record {
+            ParagraphRecord::ListHeader { paragraphs, .. } => Some(paragraphs),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    if listed.is_empty() {
+        ctrl_paragraphs.iter().collect()
+    } else {
+        listed
+    }
+}
+
+/// 쪽 번호 필드를 `value`가 돌려준 문자열로 바꾼 문단 (필드가 없으면 원래 문단)
+/// Paragraph with its page number fields replaced by the strings `value` returns (the original
+/// paragraph when it has none)
+///
+/// `value`는 필드 종류와 번호 모양을 받고, None을 돌려주면 앞뒤 장식 문자와 함께 필드를
+/// 지웁니다. 컨트롤이 차지하던 WCHAR 자리는 바뀐 글자 수만큼 당기거나 밀어 글자 모양 위치를
+/// 맞춥니다.
+/// `value` receives the field kind and number shape; returning None removes the field along
+/// with its prefix and suffix. Positions after the control are shifted by the difference between
+/// the control's WCHAR size and the inserted text so char shapes stay aligned.
+pub(crate) fn substitute_page_fields<'a>(
+    paragraph: &'a Paragraph,
+    value: &dyn Fn(PageField, NumberShape) -> Option<String>,
+) -> Cow<'a, Paragraph> {
+    // 문단 안 자동 번호 컨트롤은 AUTO_NUMBER 제어 문자와 같은 순서 / Auto number controls follow the AUTO_NUMBER characters in order
+    let fields: Vec<Option<String>> = paragraph
+        .records
+        .iter()
+        .filter_map(|record| match record {
+            ParagraphRecord::CtrlHeader { header, .. }
+                if header.ctrl_id == CtrlId::AUTO_NUMBER
+                    || header.ctrl_id == CtrlId::AUTO_NUMBER_ALT =>
+            {
+                Some(page_field(header).map(|(field, shape, prefix, suffix)| {
+                    value(field, shape)
+                        .map(|value| format!("{prefix}{value}{suffix}"))
+                        .unwrap_or_default()
+                }))
+            }
+            _ => None,
+        })
+        .collect();
+    if fields.iter().all(Option::is_none) {
+        return Cow::Borrowed(paragraph);
+    }
+
+    let mut paragraph = paragraph.clone();
+    let mut fields = fields.into_iter();
+    // (WCHAR 위치, 늘어난 WCHAR 수) / (WCHAR position, WCHAR growth)
+    let mut shifts: Vec<(usize, isize)> = Vec::new();
+    for record in paragraph.records.iter_mut() {
+        let ParagraphRecord::ParaText {
+            text,
+            runs,
+            control_char_positions,
+            ..
+        } = record
+        else {
+            continue;
+        };
+        let mut new_text = String::new();
+        let mut new_runs = Vec::with_capacity(runs.len());
+        let mut removed: Vec<usize> = Vec::new();
+        let mut chars = text.chars();
+        for run in runs.drain(..) {
+            match run {
+                ParaTextRun::Text { text: chunk } => {
+                    // 원래 텍스트에서 같은 글자 수만큼 옮김 / Copy the same number of chars from the original text
+                    new_text.extend(chars.by_ref().take(chunk.chars().count()));
+                    new_runs.push(ParaTextRun::Text { text: chunk });
+                }
+                run => {
+                    let replacement = match &run {
+                        ParaTextRun::Control {
+                            position,
+                            code: ControlChar::AUTO_NUMBER,
+                            size_wchars,
+                            ..
+                        } => fields
+                            .next()
+                            .flatten()
+                            .map(|replacement| (replacement, *position, *size_wchars)),
+                        _ => None,
+                    };
+                    match replacement {
+                        Some((replacement, position, size_wchars)) => {
+                            new_text.push_str(&replacement);
+                            let growth =
+                                replacement.encode_utf16().count() as isize - size_wchars as isize;
+                            shifts.push((position, growth));
+                            removed.push(position);
+                            new_runs.push(ParaTextRun::Text { text: replacement });
+                        }
+                        None => new_runs.push(run),
+                    }
+                }
+            }
+        }
+        new_text.extend(chars);
+        *text = new_text;
+        *runs = new_runs;
+        control_char_positions.retain(|control| !removed.contains(&control.position));
+        for control in control_char_positions.iter_mut() {
+            control.position = shift(control.position, &shifts);
+        }
+        shift_runs(runs, &shifts);
+    }
+    // 바꾼 필드의 컨트롤 헤더는 뺌 / Drop the control headers of replaced fields
+    paragraph.records.retain(|record| {
+        !matches!(record, ParagraphRecord::CtrlHeader { header, .. } if page_field(header).is_some())
+    });
+    for record in paragraph.records.iter_mut() {
+        if let ParagraphRecord::ParaCharShape { shapes } = record {
+            for shape in shapes.iter_mut() {
+                shape.position = shift(shape.position as usize, &shifts) as u32;
+            }
+        }
+    }
+    Cow::Owned(paragraph)
+}
+
+/// 바뀐 컨트롤 뒤 위치를 옮김 / Shift a position past replaced controls
+fn shift(position: usize, shifts: &[(usize, isize)]) -> usize {
+    let growth: isize = shifts
+        .iter()
+        .filter(|(at, _)| *at < position)
+        .map(|(_, growth)| growth)
+        .sum();
+    (position as isize + growth).max(0) as usize
+}
+
+/// 남은 컨트롤 토큰의 위치를 옮김 / Shift positions of the remaining control tokens
+fn shift_runs(runs: &mut [ParaTextRun], shifts: &[(usize, isize)]) {
+    for run in runs {
+        if let ParaTextRun::Control { position, .. } = run {
+            *position = shift(*position, shifts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::para_header::ParaHeader;
+    use crate::document::bodytext::CharShapeInfo;
+
+    fn auto_number(kind: u32, prefix: &str) -> ParagraphRecord {
+        ParagraphRecord::CtrlHeader {
+            header: CtrlHeader {
+                ctrl_id: CtrlId::AUTO_NUMBER_ALT.to_string(),
+                ctrl_id_value: 0,
+                data: CtrlHeaderData::AutoNumber {
+                    attribute: kind,
+                    number: 1,
+                    user_symbol: "\0".to_string(),
+                    prefix: prefix.to_string(),
+                    suffix: "\0".to_string(),
+                },
+            },
+            children: Vec::new(),
+            paragraphs: Vec::new(),
+        }
+    }
+
+    fn control(position: usize) -> ParaTextRun {
+        ParaTextRun::Control {
+            position,
+            code: ControlChar::AUTO_NUMBER,
+            name: "AUTO_NUMBER".to_string(),
+            size_wchars: 8,
+            display_text: None,
+        }
+    }
+
+    #[test]
+    fn test_page_fields_are_substituted_in_order() {
+        // "쪽 [쪽 번호] / [전체 쪽 수]", 두 번째 글자 모양은 "/"부터
+        // "Page [page number] / [total pages]", the second char shape starts at "/"
+        let paragraph = Paragraph {
+            para_header: ParaHeader::default(),
+            records: vec![
+                ParagraphRecord::ParaText {
+                    text: "쪽  / ".to_string(),
+                    runs: vec![
+                        ParaTextRun::Text {
+                            text: "쪽 ".to_string(),
+                        },
+                        control(2),
+                        ParaTextRun::Text {
+                            text: " / ".to_string(),
+                        },
+                        control(13),
+                    ],
+                    control_char_positions: Vec::new(),
+                    inline_control_params: Vec::new(),
+                },
+                ParagraphRecord::ParaCharShape {
+                    shapes: vec![
+                        CharShapeInfo {
+                            position: 0,
+                            shape_id: 1,
+                        },
+                        CharShapeInfo {
+                            position: 11,
+                            shape_id: 2,
+                        },
+                    ],
+                },
+                auto_number(0x10, "- "),
+                auto_number(6, "\0"),
+            ],
+            resolved_number: None,
+        };
+
+        let substituted = substitute_page_fields(&paragraph, &|field, shape| {
+            Some(match field {
+                PageField::Page => shape.format(3),
+                PageField::TotalPages => "12".to_string(),
+            })
+        });
+        assert_eq!(substituted.text(), "쪽 - ③ / 12");
+        let ParagraphRecord::ParaCharShape { shapes } = &substituted.records[1] else {
+            panic!("char shapes");
+        };
+        // 8 WCHAR 컨트롤이 3 WCHAR "- ③"으로 바뀜 / The 8-WCHAR control became the 3-WCHAR "- ③"
+        assert_eq!(shapes[1].position, 6);
+
+        // None이면 장식 문자까지 지움 / None removes the decorations too
+        let stripped = substitute_page_fields(&paragraph, &|_, _| None);
+        assert_eq!(stripped.text(), "쪽  / ");
+        assert!(matches!(
+            substitute_page_fields(&stripped, &|_, _| None),
+            Cow::Borrowed(_)
+        ));
+    }
+}
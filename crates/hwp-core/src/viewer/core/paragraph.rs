@@ -142,6 +142,7 @@ fn process_ctrl_header<R: Renderer>(
                 }
                 collect_text_and_images_from_paragraph(
                     para,
+                    document,
                     &mut table_cell_texts,
                     &mut table_cell_image_ids,
                 );
@@ -180,7 +181,12 @@ fn process_ctrl_header<R: Renderer>(
                 // 재귀적으로 문단에서 텍스트와 이미지를 수집하여 표 셀 내부인지 확인
                 let mut para_texts = std::collections::HashSet::new();
                 let mut para_image_ids = std::collections::HashSet::new();
-                collect_text_and_images_from_paragraph(para, &mut para_texts, &mut para_image_ids);
+                collect_text_and_images_from_paragraph(
+                    para,
+                    document,
+                    &mut para_texts,
+                    &mut para_image_ids,
+                );
 
                 (!para_texts.is_empty()
                     && para_texts
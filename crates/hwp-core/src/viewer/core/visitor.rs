@@ -0,0 +1,207 @@
+/// Document visitor trait and traversal driver
+/// 문서 방문자 트레이트와 순회 드라이버
+///
+/// 문단 레코드 트리(컨트롤 헤더, 표와 셀, 그리기 개체 묶음, 글상자, 그림)를 한 곳에서 순회합니다.
+/// 새 출력 형식은 [`DocumentVisitor`]의 콜백만 구현하면 됩니다. 블록 모델(`viewer::core::blocks`),
+/// 마크다운 텍스트/이미지 수집, 토큰과 청크 추출이 이 드라이버를 씁니다.
+///
+/// HTML 뷰어와 마크다운 렌더러는 아직 자체 순회를 쓰므로, 두 뷰어 사이의 순회 차이는 이
+/// 드라이버로 해결되지 않습니다. 두 렌더러는 줄 세그먼트에 따른 쪽 나누기, 표와 표 안 그림의 출력
+/// 순서처럼 레코드 순서와 배치에 기대는 규칙이 있어 콜백으로 옮기지 않았습니다.
+///
+/// Walks the paragraph record tree (control headers, tables and cells, shape groups, text boxes,
+/// pictures) in one place. New output formats only implement the [`DocumentVisitor`] callbacks.
+/// The block model (`viewer::core::blocks`), markdown text/image collection, token and chunk
+/// extraction use this driver.
+///
+/// The HTML viewer and the markdown renderer still traverse on their own, so traversal
+/// differences between those two viewers are not resolved by this driver. They rely on record
+/// order and layout (page breaks from line segments, the output order of tables and pictures
+/// inside them), so they were not moved onto callbacks.
+use crate::document::bindata::BinaryDataItem;
+use crate::document::bodytext::{Table, TableCell};
+use crate::document::{CtrlHeader, CtrlId, HwpDocument, Paragraph, ParagraphRecord};
+
+/// 문서 순회 콜백 / Document traversal callbacks
+///
+/// 모든 콜백은 기본으로 아무것도 하지 않습니다. `enter_*`가 `false`를 반환하면 그 요소의 안쪽은
+/// 순회하지 않고 짝이 되는 `leave_*`도 부르지 않습니다.
+/// Every callback does nothing by default. When an `enter_*` callback returns `false`, the inside
+/// of that element is skipped and the matching `leave_*` is not called.
+pub trait DocumentVisitor<'a> {
+    /// 문단 시작 / Start of a paragraph
+    ///
+    /// `location`은 본문 최상위 문단의 (구역 번호, 문단 번호)이며, 표 셀/글상자/각주 안의 문단은
+    /// `None`입니다.
+    /// `location` is the (section index, paragraph index) of a top-level body paragraph, and `None`
+    /// for paragraphs inside table cells, text boxes and notes.
+    fn enter_paragraph(
+        &mut self,
+        _paragraph: &'a Paragraph,
+        _location: Option<(usize, usize)>,
+    ) -> bool {
+        true
+    }
+
+    /// 문단 끝 / End of a paragraph
+    fn leave_paragraph(&mut self, _paragraph: &'a Paragraph, _location: Option<(usize, usize)>) {}
+
+    /// 문단 텍스트 (`ParaText` 레코드마다) / Paragraph text (per `ParaText` record)
+    fn visit_text(&mut self, _paragraph: &'a Paragraph, _text: &'a str) {}
+
+    /// 그림 (HWP의 그림 개체와 HWPX의 그림 모두, 바이너리 데이터로 찾은 것만)
+    /// A picture (both HWP picture shapes and HWPX images, only those found in the binary data)
+    fn visit_picture(&mut self, _item: &'a BinaryDataItem) {}
+
+    /// 컨트롤 헤더 시작 (각주, 미주, 머리말, 표, 그리기 개체 등)
+    /// Start of a control header (footnotes, endnotes, headers, tables, shape objects, ...)
+    fn enter_control(&mut self, _header: &'a CtrlHeader) -> bool {
+        true
+    }
+
+    /// 컨트롤 헤더 끝 / End of a control header
+    fn leave_control(&mut self, _header: &'a CtrlHeader) {}
+
+    /// 표 시작 / Start of a table
+    fn enter_table(&mut self, _table: &'a Table) -> bool {
+        true
+    }
+
+    /// 표 끝 / End of a table
+    fn leave_table(&mut self, _table: &'a Table) {}
+
+    /// 표 셀 시작 / Start of a table cell
+    fn enter_cell(&mut self, _cell: &'a TableCell) -> bool {
+        true
+    }
+
+    /// 표 셀 끝 / End of a table cell
+    fn leave_cell(&mut self, _cell: &'a TableCell) {}
+}
+
+/// 문서 본문 전체 순회 / Walk the whole document body
+pub fn walk_document<'a, V: DocumentVisitor<'a>>(document: &'a HwpDocument, visitor: &mut V) {
+    for (section_index, section) in document.body_text.sections.iter().enumerate() {
+        for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+            walk_paragraph(
+                paragraph,
+                document,
+                Some((section_index, paragraph_index)),
+                visitor,
+            );
+        }
+    }
+}
+
+/// 문단 하나 순회 / Walk one paragraph
+pub fn walk_paragraph<'a, V: DocumentVisitor<'a>>(
+    paragraph: &'a Paragraph,
+    document: &'a HwpDocument,
+    location: Option<(usize, usize)>,
+    visitor: &mut V,
+) {
+    if !visitor.enter_paragraph(paragraph, location) {
+        return;
+    }
+    for record in &paragraph.records {
+        match record {
+            ParagraphRecord::ParaText { text, .. } => visitor.visit_text(paragraph, text),
+            _ => walk_record(record, document, visitor),
+        }
+    }
+    visitor.leave_paragraph(paragraph, location);
+}
+
+/// 문단 밖에서 만나는 레코드 순회 (컨트롤 헤더의 자식, 그리기 개체의 자식)
+/// Walk a record met outside paragraph text (control header children, shape component children)
+fn walk_record<'a, V: DocumentVisitor<'a>>(
+    record: &'a ParagraphRecord,
+    document: &'a HwpDocument,
+    visitor: &mut V,
+) {
+    match record {
+        ParagraphRecord::CtrlHeader {
+            header,
+            children,
+            paragraphs,
+        } => {
+            if !visitor.enter_control(header) {
+                return;
+            }
+            for child in children {
+                // 각주/미주/머리말 등의 목록 문단은 `paragraphs`에 있고, 자식 리스트 헤더에 붙은
+                // 문단은 믿을 수 없는 사본이므로 건너뜀
+                // The list paragraphs of notes/headers/etc. live in `paragraphs`; the paragraphs
+                // attached to a child list header are an unreliable copy and are skipped
+                if matches!(child, ParagraphRecord::ListHeader { .. }) && !paragraphs.is_empty() {
+                    continue;
+                }
+                walk_record(child, document, visitor);
+            }
+            // 표 컨트롤의 문단은 셀 문단과 겹치므로 표(`Table.cells`)로만 순회
+            // Paragraphs of a table control repeat the cell paragraphs, so they are walked only
+            // through the table (`Table.cells`)
+            let has_table = children
+                .iter()
+                .any(|child| matches!(child, ParagraphRecord::Table { .. }));
+            if !(header.ctrl_id == CtrlId::TABLE && has_table) {
+                for paragraph in paragraphs {
+                    walk_paragraph(paragraph, document, None, visitor);
+                }
+            }
+            visitor.leave_control(header);
+        }
+        ParagraphRecord::Table { table } => {
+            if !visitor.enter_table(table) {
+                return;
+            }
+            for cell in &table.cells {
+                if visitor.enter_cell(cell) {
+                    for paragraph in &cell.paragraphs {
+                        walk_paragraph(paragraph, document, None, visitor);
+                    }
+                    visitor.leave_cell(cell);
+                }
+            }
+            visitor.leave_table(table);
+        }
+        // 묶음 개체는 자식 그리기 개체를 다시 가짐 / Grouped shapes contain child shape components
+        ParagraphRecord::ShapeComponent { children, .. } => {
+            for child in children {
+                walk_record(child, document, visitor);
+            }
+        }
+        // 글상자와 캡션의 문단 / Paragraphs of text boxes and captions
+        ParagraphRecord::ListHeader { paragraphs, .. } => {
+            for paragraph in paragraphs {
+                walk_paragraph(paragraph, document, None, visitor);
+            }
+        }
+        ParagraphRecord::ShapeComponentPicture {
+            shape_component_picture,
+        } => {
+            let bindata_id = shape_component_picture.picture_info.bindata_id;
+            if let Some(item) = document
+                .bin_data
+                .items
+                .iter()
+                .find(|item| item.index == bindata_id)
+            {
+                visitor.visit_picture(item);
+            }
+        }
+        ParagraphRecord::HwpxImage {
+            binary_item_ref, ..
+        } => {
+            if let Some(item) = document
+                .bin_data
+                .items
+                .iter()
+                .find(|item| item.name.as_deref() == Some(binary_item_ref.as_str()))
+            {
+                visitor.visit_picture(item);
+            }
+        }
+        _ => {}
+    }
+}
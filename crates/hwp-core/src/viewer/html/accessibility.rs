@@ -0,0 +1,148 @@
+/// 접근성 출력 모듈 / Accessible output module
+///
+/// `HtmlOptions::accessibility`가 켜졌을 때 절대 배치 레이아웃은 그대로 두고 의미 태그와
+/// ARIA 속성만 덧붙입니다.
+/// When `HtmlOptions::accessibility` is on, the absolute layout is kept as is and only semantic
+/// tags and ARIA attributes are added.
+use crate::document::bodytext::ParaHeader;
+use crate::document::HwpDocument;
+use crate::viewer::html::common::escape_html;
+use crate::viewer::markdown::utils::outline_level;
+use crate::viewer::HtmlOptions;
+
+/// 개요 문단의 제목 수준 (1~6) / Heading level (1–6) of an outline paragraph
+///
+/// 개요 7~10 수준은 HTML에 대응하는 태그가 없어 `<h6>`으로 묶습니다.
+/// Outline levels 7–10 have no HTML counterpart and are folded into `<h6>`.
+pub fn heading_level(
+    para_header: &ParaHeader,
+    document: &HwpDocument,
+    options: &HtmlOptions,
+) -> Option<u8> {
+    if !options.accessibility {
+        return None;
+    }
+    outline_level(para_header, document).map(|level| level.min(6))
+}
+
+/// 최상위 줄(`hls`) div를 제목 태그로 바꿈 / Turn top-level line (`hls`) divs into heading tags
+///
+/// 줄 안에 중첩된 div(글자처럼 취급한 표 등)는 그대로 두고, 짝이 맞는 닫는 태그만 바꿉니다.
+/// Divs nested inside a line (treat-as-letter tables, etc.) are left alone; only the matching
+/// closing tag is replaced.
+pub fn into_heading(html: &str, level: u8) -> String {
+    const LINE_OPEN: &str = r#"<div class="hls "#;
+    let tag = format!("h{level}");
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(LINE_OPEN) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + "<div".len()..];
+        result.push('<');
+        result.push_str(&tag);
+
+        // 짝이 맞는 </div> 찾기 / Find the matching </div>
+        let mut depth = 1;
+        let mut cursor = 0;
+        let close = loop {
+            let next_open = rest[cursor..].find("<div").map(|i| cursor + i);
+            let Some(next_close) = rest[cursor..].find("</div>").map(|i| cursor + i) else {
+                break None;
+            };
+            match next_open {
+                Some(open) if open < next_close => {
+                    depth += 1;
+                    cursor = open + "<div".len();
+                }
+                _ => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break Some(next_close);
+                    }
+                    cursor = next_close + "</div>".len();
+                }
+            }
+        };
+        let Some(close) = close else {
+            // 닫는 태그가 없는 조각은 손대지 않음 / Leave fragments without a closing tag untouched
+            return html.to_string();
+        };
+        result.push_str(&rest[..close]);
+        result.push_str(&format!("</{tag}>"));
+        rest = &rest[close + "</div>".len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 절대 배치 그림을 `<figure>`로 묶음 / Wrap an absolutely placed picture in a `<figure>`
+///
+/// 그림 상자의 배치가 바뀌지 않도록 figure는 `display:contents`이고, 캡션은 화면에 이미
+/// 그려지므로 `<figcaption>`은 보조 기술에만 보이게 숨깁니다.
+/// The figure uses `display:contents` so the picture box keeps its placement, and the
+/// `<figcaption>` is visually hidden since the caption is already drawn on the page.
+pub fn wrap_figure(html: &str, caption: Option<&str>, options: &HtmlOptions) -> String {
+    if !options.accessibility {
+        return html.to_string();
+    }
+    let figcaption = caption
+        .map(|caption| {
+            format!(
+                r#"<figcaption class="hfc">{}</figcaption>"#,
+                escape_html(caption)
+            )
+        })
+        .unwrap_or_default();
+    format!(r#"<figure class="hfg">{html}{figcaption}</figure>"#)
+}
+
+/// 표 칸의 ARIA 역할 / ARIA role of a table cell
+///
+/// 표가 div로 배치되므로 `<th scope="col">` 대신 `role="columnheader"`를 씁니다.
+/// `header_rows`는 `Table::header_rows`로 정한 머리행 수입니다.
+/// Tables are laid out with divs, so `role="columnheader"` stands in for `<th scope="col">`.
+/// `header_rows` is the number of header rows decided by `Table::header_rows`.
+pub fn cell_role(row: u16, header_rows: usize, options: &HtmlOptions) -> &'static str {
+    match (options.accessibility, (row as usize) < header_rows) {
+        (false, _) => "",
+        (true, true) => r#" role="columnheader""#,
+        (true, false) => r#" role="cell""#,
+    }
+}
+
+/// 장식용 도형(표 테두리 SVG 등)을 보조 기술에서 숨기는 속성 / Attributes hiding decorative shapes (table border SVG, etc.) from assistive technology
+pub fn decorative_attributes(options: &HtmlOptions) -> &'static str {
+    if options.accessibility {
+        r#" aria-hidden="true" focusable="false""#
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_heading_keeps_nested_divs() {
+        let html = r#"<div class="hls ps1" style="top:0mm;">제목<div class="htb"><div class="hce"></div></div></div><div class="hls ps1">둘째 줄</div>"#;
+        assert_eq!(
+            into_heading(html, 2),
+            r#"<h2 class="hls ps1" style="top:0mm;">제목<div class="htb"><div class="hce"></div></div></h2><h2 class="hls ps1">둘째 줄</h2>"#
+        );
+    }
+
+    #[test]
+    fn test_cell_role_and_figure() {
+        let options = HtmlOptions::default().with_accessibility(true);
+        assert_eq!(cell_role(0, 1, &options), r#" role="columnheader""#);
+        assert_eq!(cell_role(1, 1, &options), r#" role="cell""#);
+        assert_eq!(cell_role(1, 2, &options), r#" role="columnheader""#);
+        assert_eq!(cell_role(0, 0, &options), r#" role="cell""#);
+        assert_eq!(cell_role(0, 1, &HtmlOptions::default()), "");
+        assert_eq!(
+            wrap_figure("<div></div>", Some("그림 1 <지도>"), &options),
+            r#"<figure class="hfg"><div></div><figcaption class="hfc">그림 1 &lt;지도&gt;</figcaption></figure>"#
+        );
+    }
+}
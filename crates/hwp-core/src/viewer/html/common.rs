@@ -1,87 +1,287 @@
 /// HTML 뷰어 공통 유틸리티 함수 / HTML viewer common utility functions
+use crate::document::bodytext::list_header::TextDirection;
+use crate::document::images::{extension_for_mime, sniff_base64_mime_type};
 use crate::document::{BinDataRecord, HwpDocument};
+use crate::viewer::html::HtmlOptions;
+use crate::viewer::image_export::{convert_base64_image, ConvertedImage};
 use crate::{HwpError, WORD};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use std::fs;
-use std::path::Path;
 
 /// Get file extension from BinData ID
 /// BinData ID에서 파일 확장자 가져오기
+///
+/// 레코드에 확장자가 없으면(HWPX) 내용의 매직 바이트로 정합니다.
+/// Falls back to the payload's magic bytes when no record has an extension (HWPX).
 pub fn get_extension_from_bindata_id(document: &HwpDocument, bindata_id: WORD) -> String {
-    for record in &document.doc_info.bin_data {
-        if let BinDataRecord::Embedding { embedding, .. } = record {
-            if embedding.binary_data_id == bindata_id {
-                return embedding.extension.clone();
-            }
-        }
+    if let Some(extension) = record_extension(document, bindata_id) {
+        return extension;
+    }
+    match bindata_base64(document, bindata_id) {
+        Some(data) => extension_for_mime(sniff_base64_mime_type(data)).to_string(),
+        None => "jpg".to_string(),
     }
-    "jpg".to_string()
 }
 
 /// Get MIME type from BinData ID
 /// BinData ID에서 MIME 타입 가져오기
+///
+/// 매직 바이트를 먼저 보고, 알 수 없으면 레코드의 확장자를 따릅니다.
+/// Magic bytes come first; the record extension is used when they are unknown.
 pub fn get_mime_type_from_bindata_id(document: &HwpDocument, bindata_id: WORD) -> String {
-    for record in &document.doc_info.bin_data {
-        if let BinDataRecord::Embedding { embedding, .. } = record {
-            if embedding.binary_data_id == bindata_id {
-                return match embedding.extension.to_lowercase().as_str() {
-                    "jpg" | "jpeg" => "image/jpeg",
-                    "png" => "image/png",
-                    "gif" => "image/gif",
-                    "bmp" => "image/bmp",
-                    _ => "image/jpeg",
-                }
-                .to_string();
+    let sniffed = bindata_base64(document, bindata_id)
+        .map(sniff_base64_mime_type)
+        .filter(|mime| *mime != "application/octet-stream");
+    if let Some(mime) = sniffed {
+        return mime.to_string();
+    }
+    match record_extension(document, bindata_id) {
+        Some(extension) => match extension.to_lowercase().as_str() {
+            "jpg" | "jpeg" => "image/jpeg",
+            "png" => "image/png",
+            "gif" => "image/gif",
+            "bmp" => "image/bmp",
+            _ => "application/octet-stream",
+        },
+        None => "image/jpeg",
+    }
+    .to_string()
+}
+
+/// DocInfo 레코드의 확장자 / Extension from the DocInfo record
+fn record_extension(document: &HwpDocument, bindata_id: WORD) -> Option<String> {
+    document
+        .doc_info
+        .bin_data
+        .iter()
+        .find_map(|record| match record {
+            BinDataRecord::Embedding { embedding, .. }
+                if embedding.binary_data_id == bindata_id && !embedding.extension.is_empty() =>
+            {
+                Some(embedding.extension.clone())
+            }
+            _ => None,
+        })
+}
+
+/// BinData 아이템의 base64 데이터 / Base64 data of a BinData item
+fn bindata_base64(document: &HwpDocument, bindata_id: WORD) -> Option<&str> {
+    document
+        .bin_data
+        .items
+        .iter()
+        .find(|item| item.index == bindata_id)
+        .map(|item| item.data.as_str())
+        .filter(|data| !data.is_empty())
+}
+
+/// URL이 그림을 가리키는지 확인 (data URI의 MIME 타입 또는 파일 확장자)
+/// Whether a URL points at a picture (data URI MIME type or file extension)
+pub(crate) fn is_image_url(url: &str) -> bool {
+    if let Some(data_uri) = url.strip_prefix("data:") {
+        return data_uri.starts_with("image/");
+    }
+    let extension = url.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+    matches!(
+        extension.to_lowercase().as_str(),
+        "jpg" | "jpeg" | "png" | "gif" | "bmp" | "tif" | "tiff" | "webp" | "wmf" | "emf" | "svg"
+    )
+}
+
+/// HTML 텍스트/속성 값 이스케이프 / Escape HTML text and attribute values
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// HTML 조각의 최상위 요소마다 속성 추가 / Add an attribute to every top-level element of an HTML fragment
+///
+/// 문단 하나가 줄마다 `hls` 요소로 나뉘어도 모든 줄에 같은 속성이 붙고, 표 셀처럼 안쪽에 든
+/// 요소에는 붙지 않습니다.
+/// When a paragraph is split into one `hls` element per line every line gets the attribute, while
+/// nested elements such as table cells do not.
+pub(crate) fn add_top_level_attribute(html: &str, attribute: &str) -> String {
+    const VOID_ELEMENTS: [&str; 6] = ["img", "br", "hr", "input", "meta", "link"];
+
+    let mut output = String::with_capacity(html.len());
+    let mut depth = 0usize;
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+        let tag = &rest[start..=end];
+        if tag.starts_with("</") {
+            depth = depth.saturating_sub(1);
+            output.push_str(tag);
+        } else if tag.starts_with("<!") {
+            output.push_str(tag);
+        } else {
+            let name_end = tag[1..]
+                .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .map_or(tag.len() - 1, |index| index + 1);
+            let name = tag[1..name_end].to_ascii_lowercase();
+            if depth == 0 {
+                output.push_str(&tag[..name_end]);
+                output.push(' ');
+                output.push_str(attribute);
+                output.push_str(&tag[name_end..]);
+            } else {
+                output.push_str(tag);
             }
+            if !tag.ends_with("/>") && !VOID_ELEMENTS.contains(&name.as_str()) {
+                depth += 1;
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// 배경 그림으로 그린 상자의 대체 텍스트 속성 / Alt text attributes of a box drawn with a background picture
+///
+/// 배경 그림에는 `alt`가 없으므로 `role="img"`와 `aria-label`로 나타냅니다.
+/// 그림이 아닌 바이너리는 내려받기 링크가 대신하므로 비워 둡니다.
+/// Background pictures have no `alt`, so `role="img"` and `aria-label` stand in for it.
+/// Non-image binaries are left alone as their download link already describes them.
+pub(crate) fn image_attributes(image_url: &str, alt: &str) -> String {
+    if !is_image_url(image_url) {
+        return String::new();
+    }
+    format!(r#" role="img" aria-label="{}""#, escape_html(alt))
+}
+
+/// 그림 상자의 배경 스타일과 내용 / Background style and content of a picture box
+///
+/// 그림이 아닌 바이너리(OLE 개체 등)는 깨진 배경 그림 대신 점선 상자 안에 내려받기 링크로 표시합니다.
+/// Non-image binaries (OLE objects, etc.) become a download link in a dashed box instead of a broken background.
+pub(crate) fn picture_box(image_url: &str) -> (String, String) {
+    if is_image_url(image_url) {
+        return (
+            format!(
+                "background-repeat:no-repeat;background-size:contain;background-image:url('{image_url}');"
+            ),
+            String::new(),
+        );
+    }
+    let label = match image_url.strip_prefix("data:") {
+        Some(data_uri) => {
+            let mime_type = data_uri.split(';').next().unwrap_or("");
+            format!("첨부 파일 ({mime_type})")
         }
+        None => {
+            let file_name = image_url.rsplit('/').next().unwrap_or(image_url);
+            format!("첨부 파일: {file_name}")
+        }
+    };
+    (
+        "border:1px dashed #999;box-sizing:border-box;overflow:hidden;font-size:9pt;".to_string(),
+        format!(r#"<a class="hwp-attachment" href="{image_url}" download>{label}</a>"#),
+    )
+}
+
+/// 글 방향의 CSS 쓰기 모드 / CSS writing mode of a text direction
+///
+/// 영문 눕힘은 CSS 기본값(`text-orientation:mixed`)과 같고, 영문 세움은 `upright`로 세웁니다.
+/// Sideways Latin text matches the CSS default (`text-orientation:mixed`); upright Latin text
+/// uses `upright`.
+pub(crate) fn writing_mode_style(direction: TextDirection) -> &'static str {
+    match direction {
+        TextDirection::Horizontal => "",
+        TextDirection::Vertical => "writing-mode:vertical-rl;",
+        TextDirection::VerticalUpright => "writing-mode:vertical-rl;text-orientation:upright;",
     }
-    "image/jpeg".to_string()
 }
 
-/// Save image to file and return file path
-/// 이미지를 파일로 저장하고 파일 경로 반환
-pub fn save_image_to_file(
+/// 위치/크기 스타일을 논리 속성으로 바꿈 / Rewrite position and size styles as logical properties
+///
+/// 세로쓰기 영역의 줄 세그먼트도 줄 안 위치와 줄의 위치로 저장되므로, `left`/`top`/`width`/`height`를
+/// `inset-inline-start`/`inset-block-start`/`inline-size`/`block-size`로 바꾸면 `vertical-rl`
+/// 안에서 줄이 오른쪽부터 왼쪽으로 쌓입니다.
+/// Line segments in vertical areas are still stored as positions within and across lines, so
+/// turning `left`/`top`/`width`/`height` into `inset-inline-start`/`inset-block-start`/
+/// `inline-size`/`block-size` stacks the lines from right to left inside `vertical-rl`.
+pub(crate) fn logical_position_styles(html: &str) -> String {
+    const STYLE: &str = "style=\"";
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(STYLE) {
+        let (before, after) = rest.split_at(start + STYLE.len());
+        output.push_str(before);
+        let end = after.find('"').unwrap_or(after.len());
+        let declarations: Vec<String> = after[..end]
+            .split(';')
+            .map(|declaration| match declaration.split_once(':') {
+                Some((name, value)) => {
+                    let name = match name.trim() {
+                        "left" => "inset-inline-start",
+                        "top" => "inset-block-start",
+                        "width" => "inline-size",
+                        "height" => "block-size",
+                        "padding-left" => "padding-inline-start",
+                        other => other,
+                    };
+                    format!("{name}:{value}")
+                }
+                None => declaration.to_string(),
+            })
+            .collect();
+        output.push_str(&declarations.join(";"));
+        rest = &after[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// File name and bytes of a picture to hand to an image sink
+/// 그림 저장소에 넘길 그림의 파일 이름과 바이트
+///
+/// 변환한 그림이 있으면 그 바이트와 형식의 확장자를 씁니다.
+/// Uses the converted picture's bytes and its format's extension when there is one.
+/// 데이터는 `bindata_id`에서 찾고, 파일 이름은 `name_index`로 짓습니다.
+/// Data is looked up by `bindata_id`; the file is named after `name_index`.
+pub fn image_file(
     document: &HwpDocument,
-    bindata_id: crate::types::WORD,
+    (bindata_id, name_index): (crate::types::WORD, crate::types::WORD),
     base64_data: &str,
-    dir_path: &str,
-) -> Result<String, HwpError> {
-    // base64 디코딩 / Decode base64
-    let image_data = STANDARD
-        .decode(base64_data)
-        .map_err(|e| HwpError::InternalError {
-            message: format!("Failed to decode base64: {e}"),
-        })?;
+    converted: Option<&ConvertedImage>,
+) -> Result<(String, Vec<u8>), HwpError> {
+    let (image_data, extension) = match converted {
+        Some(converted) => (
+            converted.data.clone(),
+            extension_for_mime(converted.mime_type).to_string(),
+        ),
+        None => {
+            // base64 디코딩 / Decode base64
+            let image_data = STANDARD
+                .decode(base64_data)
+                .map_err(|e| HwpError::InternalError {
+                    message: format!("Failed to decode base64: {e}"),
+                })?;
+            (
+                image_data,
+                get_extension_from_bindata_id(document, bindata_id),
+            )
+        }
+    };
 
     // 파일명 생성 / Generate filename
-    let extension = get_extension_from_bindata_id(document, bindata_id);
-    let file_name = format!("BIN{bindata_id:04X}.{extension}");
-    let file_path = Path::new(dir_path).join(&file_name);
-
-    // 디렉토리 생성 / Create directory
-    fs::create_dir_all(dir_path)
-        .map_err(|e| HwpError::Io(format!("Failed to create directory '{dir_path}': {e}")))?;
-
-    // 파일 저장 / Save file
-    fs::write(&file_path, &image_data).map_err(|e| {
-        HwpError::Io(format!(
-            "Failed to write file '{}': {}",
-            file_path.display(),
-            e
-        ))
-    })?;
-
-    Ok(file_path.to_string_lossy().to_string())
+    Ok((format!("BIN{name_index:04X}.{extension}"), image_data))
 }
 
 /// Get image URL (file path or base64 data URI)
 /// 이미지 URL 가져오기 (파일 경로 또는 base64 데이터 URI)
-pub fn get_image_url(
-    document: &HwpDocument,
-    bindata_id: WORD,
-    image_output_dir: Option<&str>,
-    html_output_dir: Option<&str>,
-) -> String {
+///
+/// 그림은 `image_max_dimension`/`image_format` 옵션에 따라 줄이거나 다시 압축합니다.
+/// Pictures are downscaled or recompressed according to the `image_max_dimension` /
+/// `image_format` options.
+pub fn get_image_url(document: &HwpDocument, bindata_id: WORD, options: &HtmlOptions) -> String {
+    // 내용이 같은 그림은 한 파일을 함께 씀 / Identical pictures share one file
+    let bindata_id = document.canonical_bin_data_index(bindata_id);
+
     // BinData에서 이미지 데이터 찾기 / Find image data from BinData
     let base64_data = document
         .bin_data
@@ -94,53 +294,110 @@ pub fn get_image_url(
     if base64_data.is_empty() {
         return String::new();
     }
+    let converted = if sniff_base64_mime_type(base64_data).starts_with("image/") {
+        convert_base64_image(
+            base64_data,
+            options.image_max_dimension,
+            options.image_format,
+        )
+    } else {
+        None
+    };
 
-    match image_output_dir {
-        Some(dir_path) => {
-            // 이미지를 파일로 저장 / Save image as file
-            match save_image_to_file(document, bindata_id, base64_data, dir_path) {
-                Ok(file_path) => {
-                    // HTML 출력 디렉토리가 있으면 상대 경로 계산 / Calculate relative path if HTML output directory is provided
-                    if let Some(html_dir) = html_output_dir {
-                        let image_path = Path::new(&file_path);
-                        let html_path = Path::new(html_dir);
-
-                        // 상대 경로 계산 / Calculate relative path
-                        match pathdiff::diff_paths(image_path, html_path) {
-                            Some(relative_path) => {
-                                // 경로 구분자를 슬래시로 통일 / Normalize path separators to forward slashes
-                                relative_path.to_string_lossy().replace('\\', "/")
-                            }
-                            None => {
-                                // 상대 경로 계산 실패 시 파일명만 반환 / Return filename only if relative path calculation fails
-                                image_path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .map(|s| s.to_string())
-                                    .unwrap_or_else(|| file_path)
-                            }
-                        }
-                    } else {
-                        // HTML 출력 디렉토리가 없으면 파일명만 반환 / Return filename only if HTML output directory is not provided
-                        let file_path_obj = Path::new(&file_path);
-                        file_path_obj
-                            .file_name()
-                            .and_then(|n| n.to_str())
-                            .unwrap_or(&file_path)
-                            .to_string()
-                    }
-                }
+    match options.resolved_image_sink() {
+        Some(sink) => {
+            // 저장소에 넘기고 돌려받은 URL을 씀 / Hand to the sink and use the returned URL
+            // 결정적 출력이면 BinData 이름순 번호로 파일 이름을 정함
+            // Deterministic output names the file by its BinData name order
+            let name_index = if options.deterministic {
+                document.stable_bin_data_index(bindata_id)
+            } else {
+                bindata_id
+            };
+            match image_file(
+                document,
+                (bindata_id, name_index),
+                base64_data,
+                converted.as_ref(),
+            )
+            .and_then(|(file_name, data)| sink.save(&file_name, &data))
+            {
+                Ok(url) => url,
                 Err(_) => {
                     // 실패 시 base64로 폴백 / Fallback to base64 on failure
-                    let mime_type = get_mime_type_from_bindata_id(document, bindata_id);
-                    format!("data:{mime_type};base64,{base64_data}")
+                    data_uri(document, bindata_id, base64_data, converted)
                 }
             }
         }
+        // base64 데이터 URI로 임베드 / Embed as base64 data URI
+        None => data_uri(document, bindata_id, base64_data, converted),
+    }
+}
+
+/// base64 데이터 URI (변환한 그림이 있으면 그것을 씀) / Base64 data URI (uses the converted picture if any)
+fn data_uri(
+    document: &HwpDocument,
+    bindata_id: WORD,
+    base64_data: &str,
+    converted: Option<ConvertedImage>,
+) -> String {
+    match converted {
+        Some(converted) => format!(
+            "data:{};base64,{}",
+            converted.mime_type,
+            STANDARD.encode(&converted.data)
+        ),
         None => {
-            // base64 데이터 URI로 임베드 / Embed as base64 data URI
             let mime_type = get_mime_type_from_bindata_id(document, bindata_id);
             format!("data:{mime_type};base64,{base64_data}")
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_top_level_attribute() {
+        let html = r#"<div class="hls">가</div><div class="htb"><div class="hce">나</div></div><img src="a.png">"#;
+        assert_eq!(
+            add_top_level_attribute(html, r#"data-para="0:3""#),
+            r#"<div data-para="0:3" class="hls">가</div><div data-para="0:3" class="htb"><div class="hce">나</div></div><img data-para="0:3" src="a.png">"#
+        );
+        assert_eq!(
+            add_top_level_attribute("<svg><path d=\"M0\"/></svg>", "data-x"),
+            "<svg data-x><path d=\"M0\"/></svg>"
+        );
+    }
+
+    #[test]
+    fn test_logical_position_styles() {
+        let html = r#"<div class="hls ps0" style="line-height:3.53mm;left:1.00mm;top:2.00mm;height:3.53mm;width:10.00mm;padding-left:2.00mm;">가</div>"#;
+        assert_eq!(
+            logical_position_styles(html),
+            r#"<div class="hls ps0" style="line-height:3.53mm;inset-inline-start:1.00mm;inset-block-start:2.00mm;block-size:3.53mm;inline-size:10.00mm;padding-inline-start:2.00mm;">가</div>"#
+        );
+        assert_eq!(writing_mode_style(TextDirection::Horizontal), "");
+        assert_eq!(
+            writing_mode_style(TextDirection::VerticalUpright),
+            "writing-mode:vertical-rl;text-orientation:upright;"
+        );
+    }
+
+    #[test]
+    fn test_picture_box_for_non_image_payloads() {
+        let (fill, content) = picture_box("data:image/png;base64,iVBORw0KGgo=");
+        assert!(fill.contains("background-image:url('data:image/png"));
+        assert!(content.is_empty());
+        assert!(is_image_url("images/BIN0001.JPG"));
+
+        let (fill, content) = picture_box("data:application/x-ole-storage;base64,0M8R4A==");
+        assert!(!fill.contains("background-image"));
+        assert!(content.contains(r#"href="data:application/x-ole-storage;base64,0M8R4A==""#));
+        assert!(content.contains("첨부 파일 (application/x-ole-storage)"));
+
+        let (_, content) = picture_box("images/BIN0002.ole");
+        assert!(content.contains("첨부 파일: BIN0002.ole"));
+    }
+}
@@ -12,11 +12,13 @@ mod shape_object;
 
 // table 모듈은 폴더로 분리되어 있음 / table module is separated into a folder
 pub mod table;
+pub mod text_box;
 
 use crate::document::{CtrlHeader, CtrlId, Paragraph, ParagraphRecord};
 use crate::viewer::html::line_segment::{ImageInfo, TableInfo};
 use crate::viewer::HtmlOptions;
 use crate::HwpDocument;
+use text_box::TextBoxInfo;
 
 /// CtrlHeader 처리 결과 / CtrlHeader processing result
 #[derive(Debug, Default)]
@@ -25,6 +27,8 @@ pub struct CtrlHeaderResult<'a> {
     pub tables: Vec<TableInfo<'a>>,
     /// 추출된 이미지들 / Extracted images
     pub images: Vec<ImageInfo>,
+    /// 추출된 글상자들 / Extracted text boxes
+    pub text_boxes: Vec<TextBoxInfo<'a>>,
 }
 
 impl<'a> CtrlHeaderResult<'a> {
@@ -32,6 +36,7 @@ impl<'a> CtrlHeaderResult<'a> {
         Self {
             tables: Vec::new(),
             images: Vec::new(),
+            text_boxes: Vec::new(),
         }
     }
 }
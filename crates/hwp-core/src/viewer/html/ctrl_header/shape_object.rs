@@ -1,5 +1,5 @@
-use super::CtrlHeaderResult;
-use crate::document::bodytext::ctrl_header::VertRelTo;
+use super::{text_box, CtrlHeaderResult};
+use crate::document::bodytext::ctrl_header::ObjectPlacement;
 use crate::document::bodytext::ParagraphRecord;
 use crate::document::{CtrlHeader, CtrlHeaderData, Paragraph};
 use crate::viewer::html::common;
@@ -18,14 +18,13 @@ pub fn process_shape_object<'a>(
     let mut result = CtrlHeaderResult::new();
 
     // object_common 속성 추출 / Extract object_common attributes
-    let (like_letters, affect_line_spacing, vert_rel_to) = match &header.data {
-        CtrlHeaderData::ObjectCommon { attribute, .. } => (
-            attribute.like_letters,
-            attribute.affect_line_spacing,
-            Some(attribute.vert_rel_to),
-        ),
-        _ => (false, false, None),
+    let (like_letters, affect_line_spacing) = match &header.data {
+        CtrlHeaderData::ObjectCommon { attribute, .. } => {
+            (attribute.like_letters, attribute.affect_line_spacing)
+        }
+        _ => (false, false),
     };
+    let placement = header.data.object_placement();
 
     // children과 paragraphs에서 첫 번째 ShapeComponent 찾기 (크기 정보 추출용) / Find first ShapeComponent in children and paragraphs (for size extraction)
     let mut initial_width = None;
@@ -95,7 +94,7 @@ pub fn process_shape_object<'a>(
             options,
             like_letters,
             affect_line_spacing,
-            vert_rel_to,
+            placement.as_ref(),
             initial_width,  // parent_shape_component_width
             initial_height, // parent_shape_component_height
             &mut result.images,
@@ -112,7 +111,7 @@ pub fn process_shape_object<'a>(
                 options,
                 like_letters,
                 affect_line_spacing,
-                vert_rel_to,
+                placement.as_ref(),
                 initial_width,
                 initial_height,
                 &mut result.images,
@@ -120,6 +119,11 @@ pub fn process_shape_object<'a>(
         }
     }
 
+    // 글상자 수집 / Collect text boxes
+    if !children.is_empty() {
+        text_box::collect_text_boxes(children, placement.as_ref(), &mut result.text_boxes);
+    }
+
     result
 }
 
@@ -130,7 +134,7 @@ fn collect_images_from_records(
     options: &HtmlOptions,
     like_letters: bool,
     affect_line_spacing: bool,
-    vert_rel_to: Option<VertRelTo>,
+    placement: Option<&ObjectPlacement>,
     parent_shape_component_width: Option<u32>,
     parent_shape_component_height: Option<u32>,
     images: &mut Vec<ImageInfo>,
@@ -141,12 +145,7 @@ fn collect_images_from_records(
                 shape_component_picture,
             } => {
                 let bindata_id = shape_component_picture.picture_info.bindata_id;
-                let image_url = common::get_image_url(
-                    document,
-                    bindata_id,
-                    options.image_output_dir.as_deref(),
-                    options.html_output_dir.as_deref(),
-                );
+                let image_url = common::get_image_url(document, bindata_id, options);
                 if !image_url.is_empty() {
                     // shape_component.width/height를 우선 사용 / Prioritize shape_component.width/height
                     let width = parent_shape_component_width.unwrap_or(0);
@@ -159,7 +158,9 @@ fn collect_images_from_records(
                             url: image_url,
                             like_letters,
                             affect_line_spacing,
-                            vert_rel_to,
+                            placement: placement.cloned(),
+                            effects: shape_component_picture.image_effects(),
+                            label: document.image_label(bindata_id),
                         });
                     }
                 }
@@ -175,7 +176,7 @@ fn collect_images_from_records(
                     options,
                     like_letters,
                     affect_line_spacing,
-                    vert_rel_to,
+                    placement,
                     Some(shape_component.width),
                     Some(shape_component.height),
                     images,
@@ -190,7 +191,7 @@ fn collect_images_from_records(
                     options,
                     like_letters,
                     affect_line_spacing,
-                    vert_rel_to,
+                    placement,
                     parent_shape_component_width,
                     parent_shape_component_height,
                     images,
@@ -3,12 +3,13 @@ use std::collections::HashMap;
 use crate::document::bodytext::list_header::VerticalAlign;
 use crate::document::bodytext::{LineSegmentInfo, ParagraphRecord, Table};
 use crate::document::CtrlHeaderData;
+use crate::viewer::core::bidi::base_direction;
 use crate::viewer::html::line_segment::{
     render_line_segments_with_content, DocumentRenderState, ImageInfo, LineSegmentContent,
     LineSegmentRenderContext,
 };
 use crate::viewer::html::styles::{int32_to_mm, round_to_2dp};
-use crate::viewer::html::{common, ctrl_header};
+use crate::viewer::html::{accessibility, common, ctrl_header};
 use crate::viewer::html::{image, text};
 use crate::viewer::HtmlOptions;
 use crate::{HwpDocument, INT32};
@@ -28,6 +29,8 @@ pub(crate) fn render_cells(
     pattern_counter: &mut usize, // 문서 레벨 pattern_counter (문서 전체에서 패턴 ID 공유) / Document-level pattern_counter (share pattern IDs across document)
     color_to_pattern: &mut HashMap<u32, String>, // 문서 레벨 color_to_pattern (문서 전체에서 패턴 ID 공유) / Document-level color_to_pattern (share pattern IDs across document)
 ) -> String {
+    let header_rows = table.header_rows(&document.doc_info.border_fill);
+
     // 각 행의 최대 셀 높이 계산 (실제 셀 높이만 사용) / Calculate max cell height for each row (use only actual cell height)
     let mut max_row_heights: HashMap<usize, f64> = HashMap::new();
 
@@ -182,7 +185,16 @@ pub(crate) fn render_cells(
     }
 
     let mut cells_html = String::new();
+    // 접근성 출력: 같은 행의 셀을 role="row"로 묶음 / Accessible output: group cells of one row under role="row"
+    let mut open_row: Option<u16> = None;
     for cell in &table.cells {
+        if options.accessibility && open_row != Some(cell.cell_attributes.row_address) {
+            if open_row.is_some() {
+                cells_html.push_str("</div>");
+            }
+            cells_html.push_str(r#"<div role="row" style="display:contents;">"#);
+            open_row = Some(cell.cell_attributes.row_address);
+        }
         let cell_left = calculate_cell_left(table, cell);
         // max_row_heights를 사용하여 cell_top 계산 (shape 높이 반영) / Calculate cell_top using max_row_heights (reflecting shape height)
         let row_address = cell.cell_attributes.row_address as usize;
@@ -208,7 +220,7 @@ pub(crate) fn render_cells(
 
         // 셀 마진(mm) 계산은 렌더링 전반(특히 special-case)에서 필요하므로 먼저 계산합니다.
         let left_margin_mm = cell_margin_to_mm(cell.cell_attributes.left_margin);
-        let _right_margin_mm = cell_margin_to_mm(cell.cell_attributes.right_margin);
+        let right_margin_mm = cell_margin_to_mm(cell.cell_attributes.right_margin);
         let top_margin_mm = cell_margin_to_mm(cell.cell_attributes.top_margin);
 
         // 셀 내부 문단 렌더링 / Render paragraphs inside cell
@@ -265,12 +277,7 @@ pub(crate) fn render_cells(
                     } = record
                     {
                         let bindata_id = shape_component_picture.picture_info.bindata_id;
-                        let image_url = common::get_image_url(
-                            document,
-                            bindata_id,
-                            options.image_output_dir.as_deref(),
-                            options.html_output_dir.as_deref(),
-                        );
+                        let image_url = common::get_image_url(document, bindata_id, options);
                         if !image_url.is_empty() {
                             // ShapeComponentPicture가 직접 올 때는 border_rectangle 사용 (부모 ShapeComponent가 없음)
                             // When ShapeComponentPicture comes directly, use border_rectangle (no parent ShapeComponent)
@@ -297,7 +304,9 @@ pub(crate) fn render_cells(
                                     url: image_url,
                                     like_letters: false, // 셀 내부 이미지는 ctrl_header 정보 없음 / Images inside cells have no ctrl_header info
                                     affect_line_spacing: false,
-                                    vert_rel_to: None,
+                                    placement: None,
+                                    effects: shape_component_picture.image_effects(),
+                                    label: document.image_label(bindata_id),
                                 });
                             }
                         }
@@ -320,12 +329,7 @@ pub(crate) fn render_cells(
                             shape_component_picture,
                         } => {
                             let bindata_id = shape_component_picture.picture_info.bindata_id;
-                            let image_url = common::get_image_url(
-                                document,
-                                bindata_id,
-                                options.image_output_dir.as_deref(),
-                                options.html_output_dir.as_deref(),
-                            );
+                            let image_url = common::get_image_url(document, bindata_id, options);
                             if !image_url.is_empty() {
                                 // shape_component.width/height를 직접 사용 / Use shape_component.width/height directly
                                 if shape_component_width > 0 && shape_component_height > 0 {
@@ -335,7 +339,9 @@ pub(crate) fn render_cells(
                                         url: image_url,
                                         like_letters: false, // 셀 내부 이미지는 ctrl_header 정보 없음 / Images inside cells have no ctrl_header info
                                         affect_line_spacing: false,
-                                        vert_rel_to: None,
+                                        placement: None,
+                                        effects: shape_component_picture.image_effects(),
+                                        label: document.image_label(bindata_id),
                                     });
                                 }
                             }
@@ -482,13 +488,17 @@ pub(crate) fn render_cells(
                     // 좌표는 fixture처럼: top = top_margin_mm + offset_y, left = left_margin_mm + offset_x
                     let abs_left_mm = round_to_2dp(left_margin_mm + obj_off_x_mm);
                     let abs_top_mm = round_to_2dp(top_margin_mm + obj_off_y_mm);
+                    let (fill, content) = common::picture_box(&image.url);
+                    let attributes = common::image_attributes(&image.url, &image.label.alt_text());
                     cell_outside_html.push_str(&format!(
-                        r#"<div class="hsR" style="top:{:.2}mm;left:{:.2}mm;width:{:.2}mm;height:{:.2}mm;background-repeat:no-repeat;background-size:contain;background-image:url('{}');"></div>"#,
+                        r#"<div class="hsR"{} style="top:{:.2}mm;left:{:.2}mm;width:{:.2}mm;height:{:.2}mm;{}">{}</div>"#,
+                        attributes,
                         abs_top_mm,
                         abs_left_mm,
                         round_to_2dp(int32_to_mm(image.width as INT32)),
                         round_to_2dp(int32_to_mm(image.height as INT32)),
-                        image.url
+                        fill,
+                        content
                     ));
                 } else {
                     // ParaText의 control_char_positions 수집 (원본 WCHAR 인덱스 기준)
@@ -537,10 +547,12 @@ pub(crate) fn render_cells(
                 }
             } else if !text.is_empty() {
                 // LineSegment가 없으면 텍스트만 렌더링 / Render text only if no LineSegment
+                let direction = base_direction(&text);
                 let rendered_text =
-                    text::render_text(&text, &char_shapes, document, &options.css_class_prefix);
+                    text::render_text(&text, &char_shapes, document, direction, options);
+                let dir_attr = text::dir_attribute(direction);
                 cell_content.push_str(&format!(
-                    r#"<div class="hls {para_shape_class}">{rendered_text}</div>"#
+                    r#"<div class="hls {para_shape_class}"{dir_attr}>{rendered_text}</div>"#
                 ));
                 cell_has_text = true;
             } else if !images.is_empty() {
@@ -549,12 +561,14 @@ pub(crate) fn render_cells(
                 for image in &images {
                     let image_html = image::render_image_with_style(
                         &image.url,
+                        &image.label.alt_text(),
                         0,
                         0,
                         image.width as INT32,
                         image.height as INT32,
                         0,
                         0,
+                        &image.effects,
                     );
                     cell_content.push_str(&image_html);
                     let h_mm = round_to_2dp(int32_to_mm(image.height as INT32));
@@ -568,7 +582,7 @@ pub(crate) fn render_cells(
         }
 
         // (마진 값은 위에서 이미 계산됨)
-        let _bottom_margin_mm = cell_margin_to_mm(cell.cell_attributes.bottom_margin);
+        let bottom_margin_mm = cell_margin_to_mm(cell.cell_attributes.bottom_margin);
 
         // hcI의 top 위치 계산 / Calculate hcI top position
         // NOTE: hcI는 "셀 안에서 컨텐츠 블록을 어디에 둘지"만 담당합니다(Top/Center/Bottom).
@@ -642,15 +656,31 @@ pub(crate) fn render_cells(
             0.0
         };
 
-        // hcI에 top 스타일 추가 (값이 0이 아닌 경우만) / Add top style to hcI (only if value is not 0)
-        let hci_style = if hci_top_mm.abs() > 0.01 {
+        // 세로쓰기 셀: 줄 좌표를 논리 속성으로 바꾸고 hcI를 셀 안쪽 크기로 고정합니다.
+        // Vertical cell: map line coordinates to logical properties and size hcI to the cell interior.
+        let text_direction = cell.list_header.attribute.text_direction;
+        let hci_style = if text_direction.is_vertical() {
+            cell_content = common::logical_position_styles(&cell_content);
+            format!(
+                r#" style="width:{}mm;height:{}mm;{}""#,
+                round_to_2dp((cell_width - left_margin_mm - right_margin_mm).max(0.0)),
+                round_to_2dp((cell_height - top_margin_mm - bottom_margin_mm).max(0.0)),
+                common::writing_mode_style(text_direction)
+            )
+        } else if hci_top_mm.abs() > 0.01 {
+            // hcI에 top 스타일 추가 (값이 0이 아닌 경우만) / Add top style to hcI (only if value is not 0)
             format!(r#" style="top:{}mm;""#, round_to_2dp(hci_top_mm))
         } else {
             String::new()
         };
 
         cells_html.push_str(&format!(
-            r#"<div class="hce" style="left:{}mm;top:{}mm;width:{}mm;height:{}mm;"><div class="hcD" style="left:{}mm;top:{}mm;"><div class="hcI"{}>{}</div></div>{}</div>"#,
+            r#"<div class="hce"{} style="left:{}mm;top:{}mm;width:{}mm;height:{}mm;"><div class="hcD" style="left:{}mm;top:{}mm;"><div class="hcI"{}>{}</div></div>{}</div>"#,
+            accessibility::cell_role(
+                cell.cell_attributes.row_address,
+                header_rows,
+                options
+            ),
             round_to_2dp(cell_left),
             round_to_2dp(cell_top),
             round_to_2dp(cell_width),
@@ -662,5 +692,8 @@ pub(crate) fn render_cells(
             cell_outside_html
         ));
     }
+    if open_row.is_some() {
+        cells_html.push_str("</div>");
+    }
     cells_html
 }
@@ -1,4 +1,6 @@
-use crate::document::bodytext::ctrl_header::{CtrlHeaderData, HorzRelTo, VertRelTo};
+use crate::document::bodytext::ctrl_header::{
+    CtrlHeaderData, HorzRelTo, ObjectPlacement, VertRelTo,
+};
 use crate::document::bodytext::PageDef;
 use crate::types::{RoundTo2dp, INT32};
 use crate::viewer::html::styles::{int32_to_mm, round_to_2dp};
@@ -34,25 +36,43 @@ pub(crate) fn table_position(
     para_segment_width_mm: Option<f64>,
     first_para_vertical_mm: Option<f64>, // 첫 번째 문단의 vertical_position (가설 O) / First paragraph's vertical_position (Hypothesis O)
 ) -> (f64, f64) {
-    // CtrlHeader에서 필요한 정보 추출 / Extract necessary information from CtrlHeader
-    let (offset_x, offset_y, vert_rel_to, horz_rel_to, horz_relative) =
-        if let Some(CtrlHeaderData::ObjectCommon {
-            attribute,
-            offset_x,
-            offset_y,
-            ..
-        }) = ctrl_header
-        {
-            (
-                Some(*offset_x),
-                Some(*offset_y),
-                Some(attribute.vert_rel_to),
-                Some(attribute.horz_rel_to),
-                Some(attribute.horz_relative),
-            )
-        } else {
-            (None, None, None, None, None)
-        };
+    let placement = ctrl_header.and_then(CtrlHeaderData::object_placement);
+    object_position(
+        hcd_position,
+        page_def,
+        segment_position,
+        placement.as_ref(),
+        obj_outer_width_mm,
+        para_start_vertical_mm,
+        para_start_column_mm,
+        para_segment_width_mm,
+        first_para_vertical_mm,
+    )
+}
+
+/// 개체(표/그림) 절대 위치 계산 / Calculate absolute object (table/picture) position
+pub(crate) fn object_position(
+    hcd_position: Option<(f64, f64)>,
+    page_def: Option<&PageDef>,
+    segment_position: Option<(INT32, INT32)>,
+    placement: Option<&ObjectPlacement>,
+    obj_outer_width_mm: Option<f64>,
+    para_start_vertical_mm: Option<f64>,
+    para_start_column_mm: Option<f64>,
+    para_segment_width_mm: Option<f64>,
+    first_para_vertical_mm: Option<f64>,
+) -> (f64, f64) {
+    // 배치 정보에서 필요한 값 추출 / Extract necessary values from placement
+    let (offset_x, offset_y, vert_rel_to, horz_rel_to, horz_relative) = match placement {
+        Some(placement) => (
+            Some(placement.offset_x),
+            Some(placement.offset_y),
+            Some(placement.attribute.vert_rel_to),
+            Some(placement.attribute.horz_rel_to),
+            Some(placement.attribute.horz_relative),
+        ),
+        None => (None, None, None, None, None),
+    };
 
     let (base_left, base_top) = if let Some((left, top)) = hcd_position {
         (left.round_to_2dp(), top.round_to_2dp())
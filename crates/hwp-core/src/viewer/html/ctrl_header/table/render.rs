@@ -92,7 +92,7 @@ pub fn render_table(
     let document = context.document;
     let ctrl_header = context.ctrl_header;
     let page_def = context.page_def;
-    let _options = context.options;
+    let options = context.options;
     let table_number = context.table_number;
     // pattern_counter와 color_to_pattern은 이미 &mut이므로 직접 사용 / pattern_counter and color_to_pattern are already &mut, so use directly
 
@@ -164,12 +164,13 @@ pub fn render_table(
         ctrl_header_height_mm,
         context.pattern_counter, // 문서 레벨 pattern_counter 전달 / Pass document-level pattern_counter
         context.color_to_pattern, // 문서 레벨 color_to_pattern 전달 / Pass document-level color_to_pattern
+        options,
     );
     let cells_html = cells::render_cells(
         table,
         ctrl_header_height_mm,
         document,
-        _options,
+        options,
         context.pattern_counter,
         context.color_to_pattern,
     );
@@ -692,7 +693,12 @@ pub fn render_table(
         "display:inline-block;position:relative;vertical-align:middle;"
     };
     let htb_html = format!(
-        r#"<div class="htb" style="left:{htb_left_mm}mm;width:{htb_width_mm}mm;top:{htb_top_mm}mm;height:{content_height_mm}mm;{htb_extra_style}">{svg}{cells_html}</div>"#,
+        r#"<div class="htb"{table_role} style="left:{htb_left_mm}mm;width:{htb_width_mm}mm;top:{htb_top_mm}mm;height:{content_height_mm}mm;{htb_extra_style}">{svg}{cells_html}</div>"#,
+        table_role = if options.accessibility {
+            r#" role="table""#
+        } else {
+            ""
+        },
     );
 
     // table-caption.html fixture 기준으로, 수직 캡션(Left/Right)이 있는 표의 htG top은
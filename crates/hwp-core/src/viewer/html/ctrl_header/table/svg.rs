@@ -1,5 +1,7 @@
 /// SVG 렌더링 모듈 / SVG rendering module
 use crate::document::bodytext::Table;
+use crate::viewer::html::accessibility;
+use crate::viewer::HtmlOptions;
 use crate::HwpDocument;
 
 use crate::viewer::html::ctrl_header::table::constants::BORDER_OFFSET_MM;
@@ -18,6 +20,7 @@ pub(crate) fn render_svg(
     ctrl_header_height_mm: Option<f64>,
     pattern_counter: &mut usize, // 문서 레벨 pattern_counter (문서 전체에서 패턴 ID 공유) / Document-level pattern_counter (share pattern IDs across document)
     color_to_pattern: &mut std::collections::HashMap<u32, String>, // 문서 레벨 color_to_pattern (문서 전체에서 패턴 ID 공유) / Document-level color_to_pattern (share pattern IDs across document)
+    options: &HtmlOptions,
 ) -> String {
     let (pattern_defs, fills) = fills::render_fills(
         table,
@@ -51,7 +54,8 @@ pub(crate) fn render_svg(
     );
 
     format!(
-        r#"<svg class="hs" viewBox="{} {} {} {}" style="left:{}mm;top:{}mm;width:{}mm;height:{}mm;"><defs>{}</defs>{}</svg>"#,
+        r#"<svg class="hs"{} viewBox="{} {} {} {}" style="left:{}mm;top:{}mm;width:{}mm;height:{}mm;"><defs>{}</defs>{}</svg>"#,
+        accessibility::decorative_attributes(options),
         view_box.left,
         view_box.top,
         view_box.width,
@@ -0,0 +1,185 @@
+/// 글상자 렌더링 모듈 / Text box rendering module
+///
+/// 그리기 개체(`gso `) 안의 글상자 문단을 세로쓰기와 회전 정보와 함께 렌더링합니다.
+/// Renders text box paragraphs inside drawing objects (`gso `) with vertical writing and rotation.
+use std::collections::HashMap;
+
+use crate::document::bodytext::ctrl_header::ObjectPlacement;
+use crate::document::bodytext::list_header::TextDirection;
+use crate::document::bodytext::{Paragraph, ParagraphRecord};
+use crate::viewer::core::bidi::base_direction;
+use crate::viewer::html::common;
+use crate::viewer::html::line_segment::{
+    render_line_segments_with_content, DocumentRenderState, LineSegmentContent,
+    LineSegmentRenderContext,
+};
+use crate::viewer::html::styles::round_to_2dp;
+use crate::viewer::html::text;
+use crate::viewer::HtmlOptions;
+use crate::{HwpDocument, INT32};
+
+/// 글상자 안쪽 기본 여백 (mm) / Default text box inner margins (mm)
+const TEXT_BOX_MARGIN_X_MM: f64 = 1.8;
+const TEXT_BOX_MARGIN_Y_MM: f64 = 0.5;
+
+/// 글상자 정보 / Text box information
+#[derive(Debug, Clone)]
+pub struct TextBoxInfo<'a> {
+    /// 너비 (HWPUNIT) / Width (HWPUNIT)
+    pub width: u32,
+    /// 높이 (HWPUNIT) / Height (HWPUNIT)
+    pub height: u32,
+    /// 회전각 (도) / Rotation angle (degrees)
+    pub rotation_angle: i16,
+    /// 텍스트 방향 / Text direction
+    pub text_direction: TextDirection,
+    /// 글상자 문단들 / Text box paragraphs
+    pub paragraphs: &'a [Paragraph],
+    /// object_common 배치 정보 / object_common placement
+    pub placement: Option<ObjectPlacement>,
+}
+
+/// ParagraphRecord 배열에서 재귀적으로 글상자 수집 / Recursively collect text boxes from ParagraphRecord array
+pub fn collect_text_boxes<'a>(
+    records: &'a [ParagraphRecord],
+    placement: Option<&ObjectPlacement>,
+    text_boxes: &mut Vec<TextBoxInfo<'a>>,
+) {
+    for record in records {
+        match record {
+            ParagraphRecord::ShapeComponent {
+                shape_component,
+                children,
+            } => {
+                let list = children.iter().find_map(|child| match child {
+                    ParagraphRecord::ListHeader { header, paragraphs }
+                        if !paragraphs.is_empty() =>
+                    {
+                        Some((header, paragraphs))
+                    }
+                    _ => None,
+                });
+                match list {
+                    Some((header, paragraphs)) => text_boxes.push(TextBoxInfo {
+                        width: shape_component.width,
+                        height: shape_component.height,
+                        rotation_angle: shape_component.rotation_angle,
+                        text_direction: header.attribute.text_direction,
+                        paragraphs,
+                        placement: placement.cloned(),
+                    }),
+                    // 묶음 개체 안의 글상자 찾기 / Find text boxes inside grouped objects
+                    None => collect_text_boxes(children, placement, text_boxes),
+                }
+            }
+            ParagraphRecord::CtrlHeader { children, .. } => {
+                collect_text_boxes(children, placement, text_boxes);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 글상자를 HTML로 렌더링 / Render text box to HTML
+pub fn render_text_box(
+    text_box: &TextBoxInfo,
+    left_mm: f64,
+    top_mm: f64,
+    document: &HwpDocument,
+    options: &HtmlOptions,
+    pattern_counter: &mut usize,
+    color_to_pattern: &mut HashMap<u32, String>,
+) -> String {
+    let width_mm = round_to_2dp(text_box.width as INT32 as f64 * 25.4 / 7200.0);
+    let height_mm = round_to_2dp(text_box.height as INT32 as f64 * 25.4 / 7200.0);
+
+    let mut content = String::new();
+    for para in text_box.paragraphs {
+        let para_shape_id = para.para_header.para_shape_id;
+        let para_shape = document.doc_info.para_shapes.get(para_shape_id as usize);
+        let para_shape_class = if para_shape.is_some() {
+            format!("ps{para_shape_id}")
+        } else {
+            String::new()
+        };
+        let (text, char_shapes) = text::extract_text_and_shapes(para);
+
+        let line_segments = para
+            .records
+            .iter()
+            .find_map(|record| match record {
+                ParagraphRecord::ParaLineSeg { segments } => Some(segments.as_slice()),
+                _ => None,
+            })
+            .unwrap_or(&[]);
+        let control_char_positions = para
+            .records
+            .iter()
+            .find_map(|record| match record {
+                ParagraphRecord::ParaText {
+                    control_char_positions,
+                    ..
+                } => Some(control_char_positions.as_slice()),
+                _ => None,
+            })
+            .unwrap_or(&[]);
+
+        if !line_segments.is_empty() {
+            let line_content = LineSegmentContent {
+                segments: line_segments,
+                text: &text,
+                char_shapes: &char_shapes,
+                control_char_positions,
+                original_text_len: para.para_header.text_char_count as usize,
+                images: &[],
+                tables: &[],
+            };
+            let context = LineSegmentRenderContext {
+                document,
+                para_shape_class: &para_shape_class,
+                options,
+                para_shape_indent: para_shape.map(|shape| shape.indent),
+                hcd_position: None,
+                page_def: None,
+            };
+            let mut state = DocumentRenderState {
+                table_counter_start: 0, // 글상자 안에서는 표 번호를 쓰지 않음 / No table numbers inside text boxes
+                pattern_counter,
+                color_to_pattern,
+            };
+            content.push_str(&render_line_segments_with_content(
+                &line_content,
+                &context,
+                &mut state,
+            ));
+        } else if !text.is_empty() {
+            // LineSegment가 없으면 텍스트만 렌더링 / Render text only if no LineSegment
+            let direction = base_direction(&text);
+            let rendered_text =
+                text::render_text(&text, &char_shapes, document, direction, options);
+            let dir_attr = text::dir_attribute(direction);
+            content.push_str(&format!(
+                r#"<div class="hls {para_shape_class}"{dir_attr}>{rendered_text}</div>"#
+            ));
+        }
+    }
+
+    if text_box.text_direction.is_vertical() {
+        content = common::logical_position_styles(&content);
+    }
+
+    let inner_width_mm = round_to_2dp((width_mm - TEXT_BOX_MARGIN_X_MM * 2.0).max(0.0));
+    let inner_height_mm = round_to_2dp((height_mm - TEXT_BOX_MARGIN_Y_MM * 2.0).max(0.0));
+    let rotation = if text_box.rotation_angle != 0 {
+        format!("transform:rotate({}deg);", text_box.rotation_angle)
+    } else {
+        String::new()
+    };
+
+    format!(
+        r#"<div class="hsT" style="left:{}mm;top:{}mm;width:{width_mm}mm;height:{height_mm}mm;{rotation}"><div class="hcI" style="left:{TEXT_BOX_MARGIN_X_MM}mm;top:{TEXT_BOX_MARGIN_Y_MM}mm;width:{inner_width_mm}mm;height:{inner_height_mm}mm;{}">{content}</div></div>"#,
+        round_to_2dp(left_mm),
+        round_to_2dp(top_mm),
+        common::writing_mode_style(text_box.text_direction),
+    )
+}
@@ -1,15 +1,20 @@
-use super::page;
+use std::io::{self, Write};
+
+use super::common::{self, escape_html};
+use super::page::{self, PageMargin};
 use super::pagination::{PageBreakReason, PaginationContext};
 use super::paragraph::{
     render_paragraph, ParagraphPosition, ParagraphRenderContext, ParagraphRenderState,
 };
 use super::styles;
 use super::styles::round_to_2dp;
-use super::HtmlOptions;
-use crate::document::bodytext::ctrl_header::{CtrlHeaderData, CtrlId};
-use crate::document::bodytext::{PageDef, ParagraphRecord};
+use super::{HtmlOptions, MasterPageMode};
+use crate::document::bodytext::ctrl_header::{ApplyPage, CtrlHeaderData, CtrlId};
+use crate::document::bodytext::{MasterPage, PageDef, Paragraph, ParagraphRecord, Section};
 use crate::document::HwpDocument;
 use crate::types::RoundTo2dp;
+use crate::viewer::core::page_fields::header_footer_paragraphs;
+use crate::viewer::toc::source_anchor;
 use crate::INT32;
 
 /// 문서에서 첫 번째 PageDef 찾기 / Find first PageDef in document
@@ -55,6 +60,168 @@ fn find_page_number_position(document: &HwpDocument) -> Option<&CtrlHeaderData>
     None
 }
 
+/// 구역의 바탕쪽 (구역 정의가 바탕쪽을 감추면 비어 있음)
+/// Master pages of a section (empty when the section definition hides them)
+fn visible_master_pages(section: &Section) -> Vec<&MasterPage> {
+    // 구역 정의 속성 bit 2: 바탕쪽 감추기 / Section definition attribute bit 2: hide master page
+    let hidden = section
+        .paragraphs
+        .first()
+        .into_iter()
+        .flat_map(|paragraph| &paragraph.records)
+        .any(|record| {
+            matches!(
+                record,
+                ParagraphRecord::CtrlHeader { header, .. }
+                    if matches!(header.data, CtrlHeaderData::SectionDefinition { attribute, .. } if attribute & 0x04 != 0)
+            )
+        });
+    if hidden {
+        Vec::new()
+    } else {
+        section.master_pages()
+    }
+}
+
+/// 쪽 출력 / Page output
+///
+/// 머리말/꼬리말이나 바탕쪽을 넣을 때는 전체 쪽 수를 알 때까지 쪽을 모아 둡니다.
+/// When headers and footers or master pages are included, pages are held until the total page
+/// count is known.
+struct PageOutput<'a> {
+    headers: Vec<PageMargin<'a>>,
+    footers: Vec<PageMargin<'a>>,
+    /// 현재 구역의 바탕쪽 / Master pages of the current section
+    master_pages: Vec<&'a MasterPage>,
+    include_headers_footers: bool,
+    /// 바탕쪽 층의 불투명도 (바탕쪽을 넣지 않으면 None) / Master page layer opacity (None without master pages)
+    master_page_opacity: Option<f32>,
+    /// 모아 둔 쪽 (머리말/꼬리말과 바탕쪽을 넣지 않으면 None)
+    /// Held pages (None without headers, footers and master pages)
+    held: Option<Vec<String>>,
+}
+
+impl<'a> PageOutput<'a> {
+    fn new(options: &HtmlOptions) -> Self {
+        let include_headers_footers = options.include_headers_footers == Some(true);
+        let master_page_opacity = match options.master_pages {
+            Some(MasterPageMode::Layer { opacity }) => Some(opacity),
+            Some(MasterPageMode::Skip) | Some(MasterPageMode::Once) | None => None,
+        };
+        // 맨 앞에 한 번 넣는 바탕쪽에도 전체 쪽 수 필드가 있을 수 있음
+        // The master page put once at the top may also hold a total page count field
+        let master_page_once = options.master_pages == Some(MasterPageMode::Once);
+        Self {
+            headers: Vec::new(),
+            footers: Vec::new(),
+            master_pages: Vec::new(),
+            include_headers_footers,
+            master_page_opacity,
+            held: (include_headers_footers || master_page_opacity.is_some() || master_page_once)
+                .then(Vec::new),
+        }
+    }
+
+    /// 구역의 바탕쪽으로 바꾸기 (구역 정의가 바탕쪽을 감추면 비움)
+    /// Take over the master pages of a section (cleared when the section definition hides them)
+    fn set_master_pages(&mut self, section: &'a Section) {
+        if self.master_page_opacity.is_none() {
+            return;
+        }
+        self.master_pages = visible_master_pages(section);
+    }
+
+    /// 문단의 머리말/꼬리말 컨트롤로 바꾸기 (같은 쪽에 쓰이던 것을 대체)
+    /// Take over the header/footer controls of a paragraph (replacing those for the same pages)
+    fn set_margins(&mut self, paragraph: &'a Paragraph) {
+        if !self.include_headers_footers {
+            return;
+        }
+        for record in &paragraph.records {
+            let ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } = record
+            else {
+                continue;
+            };
+            let CtrlHeaderData::HeaderFooter { attribute, .. } = &header.data else {
+                continue;
+            };
+            let margins = if header.ctrl_id == CtrlId::HEADER {
+                &mut self.headers
+            } else if header.ctrl_id == CtrlId::FOOTER {
+                &mut self.footers
+            } else {
+                continue;
+            };
+            let apply = attribute.apply_page;
+            margins.retain(|(existing, _)| apply != ApplyPage::Both && *existing != apply);
+            margins.push((apply, header_footer_paragraphs(children, paragraphs)));
+        }
+    }
+
+    /// 쪽의 바탕쪽과 머리말/꼬리말 HTML / Master page, header and footer HTML of a page
+    fn margins(
+        &self,
+        page_number: usize,
+        page_start_number: u16,
+        page_def: Option<&PageDef>,
+        document: &HwpDocument,
+        options: &HtmlOptions,
+    ) -> String {
+        let page_number = page_start_number as usize + page_number - 1;
+        let mut html = String::new();
+        if let Some(opacity) = self.master_page_opacity {
+            html.push_str(&page::render_master_page(
+                page_number,
+                &self.master_pages,
+                page_def,
+                document,
+                options,
+                opacity,
+            ));
+        }
+        if self.include_headers_footers {
+            html.push_str(&page::render_page_margins(
+                page_number,
+                &self.headers,
+                &self.footers,
+                page_def,
+                document,
+                options,
+            ));
+        }
+        html
+    }
+
+    fn write<W: Write + ?Sized>(&mut self, writer: &mut W, page: String) -> io::Result<()> {
+        match self.held.as_mut() {
+            Some(held) => {
+                held.push(page);
+                Ok(())
+            }
+            None => writer.write_all(page.as_bytes()),
+        }
+    }
+
+    /// 모아 둔 쪽에 전체 쪽 수를 채워 출력 / Write held pages with the total page count filled in
+    fn finish<W: Write + ?Sized>(self, writer: &mut W) -> io::Result<()> {
+        let Some(held) = self.held else {
+            return Ok(());
+        };
+        let total = held.len().to_string();
+        for page in held {
+            writer.write_all(
+                page.replace(page::TOTAL_PAGES_PLACEHOLDER, &total)
+                    .as_bytes(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Convert HWP document to HTML format
 /// HWP 문서를 HTML 형식으로 변환
 ///
@@ -65,25 +232,62 @@ fn find_page_number_position(document: &HwpDocument) -> Option<&CtrlHeaderData>
 /// # Returns / 반환값
 /// HTML string representation of the document / 문서의 HTML 문자열 표현
 pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
-    let mut html = String::new();
+    let mut html = Vec::new();
+    to_html_writer(document, options, &mut html).expect("writing to a Vec cannot fail");
+    String::from_utf8(html).expect("HTML output is valid UTF-8")
+}
+
+/// Stream HWP document as HTML into a writer
+/// HWP 문서를 HTML로 변환하여 writer에 순차 출력
+///
+/// 문서 전체를 하나의 문자열로 만들지 않고 페이지 단위로 출력하므로, 큰 문서의 최대
+/// 메모리 사용량이 줄고 HTTP 응답을 나누어 보낼 수 있습니다. 출력은 `to_html`과 같습니다.
+///
+/// Writes page by page instead of building the whole document in one string, which lowers
+/// peak memory for large documents and allows chunked HTTP responses. The output is the same
+/// as `to_html`.
+///
+/// # Arguments / 매개변수
+/// * `document` - The HWP document to convert / 변환할 HWP 문서
+/// * `options` - HTML conversion options / HTML 변환 옵션
+/// * `writer` - Output destination / 출력 대상
+pub fn to_html_writer<W: Write + ?Sized>(
+    document: &HwpDocument,
+    options: &HtmlOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    let document = &*document.with_content_filter(options.content_filter);
 
     // HTML 문서 시작 / Start HTML document
-    html.push_str("<!DOCTYPE html>\n");
-    html.push_str("<html>\n");
-    html.push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge,chrome=1\">\n");
-    html.push('\n');
-    html.push_str("<head>\n");
-    html.push_str("  <title></title>\n");
-    html.push_str("  <meta http_quiv=\"content-type\" content=\"text/html; charset=utf-8\">\n");
+    writer.write_all(b"<!DOCTYPE html>\n")?;
+    if options.accessibility {
+        // 화면 낭독기가 한국어 발음을 쓰도록 언어 지정 / Declare the language so screen readers use Korean pronunciation
+        writer.write_all(b"<html lang=\"ko\">\n")?;
+    } else {
+        writer.write_all(b"<html>\n")?;
+    }
+    writer.write_all(b"<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge,chrome=1\">\n")?;
+    writer.write_all(b"\n")?;
+    writer.write_all(b"<head>\n")?;
+    let title = document
+        .summary_information
+        .as_ref()
+        .and_then(|summary| summary.title.as_deref())
+        .filter(|_| options.accessibility)
+        .map(escape_html)
+        .unwrap_or_default();
+    writer.write_all(format!("  <title>{title}</title>\n").as_bytes())?;
+    writer
+        .write_all(b"  <meta http_quiv=\"content-type\" content=\"text/html; charset=utf-8\">\n")?;
 
     // CSS 스타일 생성 / Generate CSS styles
-    html.push_str("  <style>\n");
-    html.push_str(&styles::generate_css_styles(document));
-    html.push_str("  </style>\n");
-    html.push_str("</head>\n");
-    html.push('\n');
-    html.push('\n');
-    html.push_str("<body>\n");
+    writer.write_all(b"  <style>\n")?;
+    writer.write_all(styles::generate_css_styles(document, options).as_bytes())?;
+    writer.write_all(b"  </style>\n")?;
+    writer.write_all(b"</head>\n")?;
+    writer.write_all(b"\n")?;
+    writer.write_all(b"\n")?;
+    writer.write_all(b"<body>\n")?;
 
     // PageDef 찾기 / Find PageDef
     let page_def = find_page_def(document);
@@ -100,6 +304,20 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
         .unwrap_or(1);
 
     // 페이지별로 렌더링 / Render by page
+    let mut pages = PageOutput::new(options);
+    if options.master_pages == Some(MasterPageMode::Once) {
+        if let Some(section) = document.body_text.sections.first() {
+            pages.write(
+                writer,
+                page::render_master_page_once(
+                    page_start_number as usize,
+                    &visible_master_pages(section),
+                    document,
+                    options,
+                ),
+            )?;
+        }
+    }
     let mut page_number = 1;
     let mut page_content = String::new();
     let mut page_tables = Vec::new(); // 테이블을 별도로 저장 / Store tables separately
@@ -171,8 +389,8 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
     // 문단 인덱스 추적 (vertical_position이 있는 문단만 카운트) / Track paragraph index (only count paragraphs with vertical_position)
     let mut para_index = 0;
 
-    for section in &document.body_text.sections {
-        for paragraph in &section.paragraphs {
+    for (section_index, section) in document.body_text.sections.iter().enumerate() {
+        for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
             // 페이지네이션 컨텍스트는 아래에서 업데이트됨 / Pagination context will be updated below
 
             // 1. 문단 페이지 나누기 확인 (렌더링 전) / Check paragraph page break (before rendering)
@@ -232,17 +450,27 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
                         .unwrap_or(24.99);
                     Some((left, top))
                 };
-                html.push_str(&page::render_page(
-                    page_number,
-                    &page_content,
-                    &page_tables,
-                    current_page_def,
-                    first_segment_pos,
-                    hcd_pos,
-                    page_number_position,
-                    page_start_number,
-                    document,
-                ));
+                pages.write(
+                    writer,
+                    page::render_page(
+                        page_number,
+                        &page_content,
+                        &page_tables,
+                        current_page_def,
+                        first_segment_pos,
+                        hcd_pos,
+                        page_number_position,
+                        page_start_number,
+                        &pages.margins(
+                            page_number,
+                            page_start_number,
+                            current_page_def,
+                            document,
+                            options,
+                        ),
+                        document,
+                    ),
+                )?;
                 page_number += 1;
                 page_content.clear();
                 page_tables.clear();
@@ -258,6 +486,16 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
             let has_header_footer = control_mask.has_header_footer();
             let has_footnote_endnote = control_mask.has_footnote_endnote();
 
+            // 구역 첫 문단부터 쓰일 바탕쪽 / Master pages in effect from the section's first paragraph on
+            if paragraph_index == 0 {
+                pages.set_master_pages(section);
+            }
+
+            // 이 쪽부터 쓰일 머리말/꼬리말 / Headers and footers in effect from this page on
+            if has_header_footer {
+                pages.set_margins(paragraph);
+            }
+
             if !has_header_footer && !has_footnote_endnote {
                 // 첫 번째 LineSegment 위치 저장 / Store first LineSegment position
                 // PageDef 여백을 직접 사용 / Use PageDef margins directly
@@ -349,6 +587,23 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
                     &mut pagination_context, // 페이지네이션 컨텍스트 전달 / Pass pagination context
                 );
 
+                // 원본 문단 위치 속성 / Source paragraph location attribute
+                let (para_html, table_htmls) = if options.include_source_anchors == Some(true) {
+                    let attribute = format!(
+                        r#"data-para="{}""#,
+                        source_anchor(section_index, paragraph_index)
+                    );
+                    (
+                        common::add_top_level_attribute(&para_html, &attribute),
+                        table_htmls
+                            .iter()
+                            .map(|html| common::add_top_level_attribute(html, &attribute))
+                            .collect(),
+                    )
+                } else {
+                    (para_html, table_htmls)
+                };
+
                 // 3. 객체 페이지네이션 결과 처리 / Handle object pagination result
                 if let Some(obj_result) = obj_pagination_result {
                     if obj_result.has_page_break
@@ -372,17 +627,27 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
                                 .unwrap_or(24.99);
                             Some((left, top))
                         };
-                        html.push_str(&page::render_page(
-                            page_number,
-                            &page_content,
-                            &page_tables,
-                            page_def,
-                            first_segment_pos,
-                            hcd_pos,
-                            page_number_position,
-                            page_start_number,
-                            document,
-                        ));
+                        pages.write(
+                            writer,
+                            page::render_page(
+                                page_number,
+                                &page_content,
+                                &page_tables,
+                                page_def,
+                                first_segment_pos,
+                                hcd_pos,
+                                page_number_position,
+                                page_start_number,
+                                &pages.margins(
+                                    page_number,
+                                    page_start_number,
+                                    page_def,
+                                    document,
+                                    options,
+                                ),
+                                document,
+                            ),
+                        )?;
                         page_number += 1;
                         page_content.clear();
                         page_tables.clear();
@@ -446,24 +711,55 @@ pub fn to_html(document: &HwpDocument, options: &HtmlOptions) -> String {
                 .unwrap_or(24.99);
             Some((left, top))
         };
-        html.push_str(&page::render_page(
-            page_number,
-            &page_content,
-            &page_tables,
-            current_page_def,
-            first_segment_pos,
-            hcd_pos,
-            page_number_position,
-            page_start_number,
-            document,
-        ));
+        pages.write(
+            writer,
+            page::render_page(
+                page_number,
+                &page_content,
+                &page_tables,
+                current_page_def,
+                first_segment_pos,
+                hcd_pos,
+                page_number_position,
+                page_start_number,
+                &pages.margins(
+                    page_number,
+                    page_start_number,
+                    current_page_def,
+                    document,
+                    options,
+                ),
+                document,
+            ),
+        )?;
+    }
+
+    pages.finish(writer)?;
+
+    // 참고 문헌 출력 / Output references
+    if options.include_references == Some(true) {
+        let references = document.references();
+        if !references.is_empty() {
+            let prefix = &options.css_class_prefix;
+            writer.write_all(
+                format!("<div class=\"{prefix}references\"><h2>참고 문헌</h2><ol>").as_bytes(),
+            )?;
+            for reference in &references {
+                let escaped = reference
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                writer.write_all(format!("<li>{escaped}</li>").as_bytes())?;
+            }
+            writer.write_all(b"</ol></div>\n")?;
+        }
     }
 
-    html.push_str("</body>");
-    html.push('\n');
-    html.push('\n');
-    html.push_str("</html>");
-    html.push('\n');
+    writer.write_all(b"</body>")?;
+    writer.write_all(b"\n")?;
+    writer.write_all(b"\n")?;
+    writer.write_all(b"</html>")?;
+    writer.write_all(b"\n")?;
 
-    html
+    Ok(())
 }
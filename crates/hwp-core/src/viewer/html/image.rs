@@ -1,34 +1,117 @@
 /// 이미지 렌더링 모듈 / Image rendering module
-use crate::types::INT32;
+use crate::document::bodytext::ctrl_header::{ObjectTextOption, ObjectTextPositionOption};
+use crate::document::bodytext::{ImageEffects, ObjectPlacement};
+use crate::types::{HWPUNIT16, INT32};
+use crate::viewer::html::common::{image_attributes, picture_box};
 use crate::viewer::html::styles::{int32_to_mm, round_to_2dp};
 
 /// 이미지를 HTML로 렌더링 / Render image to HTML
 pub fn render_image(
     image_url: &str,
+    alt: &str,
     left: INT32,
     top: INT32,
     width: INT32,
     height: INT32,
+    placement: Option<&ObjectPlacement>,
+    effects: &ImageEffects,
 ) -> String {
     let left_mm = round_to_2dp(int32_to_mm(left));
     let top_mm = round_to_2dp(int32_to_mm(top));
     let width_mm = round_to_2dp(int32_to_mm(width));
     let height_mm = round_to_2dp(int32_to_mm(height));
 
+    let (fill, content) = picture_box(image_url);
+    let attributes = image_attributes(image_url, alt);
     format!(
-        r#"<div class="hsR" style="top:{top_mm}mm;left:{left_mm}mm;width:{width_mm}mm;height:{height_mm}mm;background-repeat:no-repeat;background-size:contain;background-image:url('{image_url}');"></div>"#
+        r#"<div class="hsR"{attributes} style="top:{top_mm}mm;left:{left_mm}mm;width:{width_mm}mm;height:{height_mm}mm;{fill}{layer_style}{effects_style}">{content}</div>"#,
+        layer_style = layer_style(placement),
+        effects_style = effects_style(effects)
     )
 }
 
+/// 흐름 배치 문단에서 글자처럼 취급하지 않는 그림을 렌더링 / Render a non-inline picture in a flow-laid-out paragraph
+///
+/// 어울림/자리차지는 바깥 여백을 둔 float/블록으로, 글 뒤로/글 앞으로는 오프셋 위치의 absolute로 배치합니다.
+/// Square/top-and-bottom wrap become a float/block with outer margins; behind/in-front text is absolutely positioned at the offsets.
+pub fn render_floating_image(
+    image_url: &str,
+    alt: &str,
+    width: INT32,
+    height: INT32,
+    placement: &ObjectPlacement,
+    effects: &ImageEffects,
+) -> String {
+    let width_mm = round_to_2dp(int32_to_mm(width));
+    let height_mm = round_to_2dp(int32_to_mm(height));
+    let margin_mm = |value: HWPUNIT16| format!("{}mm", round_to_2dp(int32_to_mm(value as INT32)));
+    let margin = &placement.margin;
+    let (top, right, bottom, left) = (
+        margin_mm(margin.top),
+        margin_mm(margin.right),
+        margin_mm(margin.bottom),
+        margin_mm(margin.left),
+    );
+    let attribute = &placement.attribute;
+
+    let layout = match attribute.object_text_option {
+        ObjectTextOption::BehindText | ObjectTextOption::InFrontOfText => format!(
+            "left:{}mm;top:{}mm;{}",
+            round_to_2dp(placement.offset_x.to_mm()),
+            round_to_2dp(placement.offset_y.to_mm()),
+            layer_style(Some(placement))
+        ),
+        ObjectTextOption::TopAndBottom => {
+            // 가로 정렬은 auto 여백으로 표현 / Horizontal alignment via auto margins
+            let (left, right) = match attribute.horz_relative {
+                1 => ("auto".to_string(), "auto".to_string()),
+                2 => ("auto".to_string(), right),
+                _ => (left, right),
+            };
+            format!(
+                "position:relative;display:block;clear:both;margin:{top} {right} {bottom} {left};"
+            )
+        }
+        ObjectTextOption::Square | ObjectTextOption::Tight | ObjectTextOption::Through => {
+            // 본문이 한쪽에만 흐르면 반대쪽으로 붙임 / Stick to the opposite side when text flows on one side only
+            let side = match attribute.object_text_position_option {
+                ObjectTextPositionOption::LeftOnly => "right",
+                ObjectTextPositionOption::RightOnly => "left",
+                _ if attribute.horz_relative == 2 => "right",
+                _ => "left",
+            };
+            format!("position:relative;float:{side};margin:{top} {right} {bottom} {left};")
+        }
+    };
+
+    let (fill, content) = picture_box(image_url);
+    let attributes = image_attributes(image_url, alt);
+    format!(
+        r#"<div class="hsR"{attributes} style="{layout}width:{width_mm}mm;height:{height_mm}mm;{fill}{effects_style}">{content}</div>"#,
+        effects_style = effects_style(effects)
+    )
+}
+
+/// 글 뒤로/글 앞으로 배치의 쌓임 순서 / Stacking order for behind/in-front text placement
+fn layer_style(placement: Option<&ObjectPlacement>) -> &'static str {
+    match placement.map(|p| p.attribute.object_text_option) {
+        Some(ObjectTextOption::BehindText) => "z-index:-1;",
+        Some(ObjectTextOption::InFrontOfText) => "z-index:1;",
+        _ => "",
+    }
+}
+
 /// 이미지를 배경 이미지로 렌더링 (인라인 스타일 포함) / Render image as background image (with inline styles)
 pub fn render_image_with_style(
     image_url: &str,
+    alt: &str,
     left: INT32,
     top: INT32,
     width: INT32,
     height: INT32,
     margin_bottom: INT32,
     margin_right: INT32,
+    effects: &ImageEffects,
 ) -> String {
     let left_mm = round_to_2dp(int32_to_mm(left));
     let top_mm = round_to_2dp(int32_to_mm(top));
@@ -37,7 +120,134 @@ pub fn render_image_with_style(
     let margin_bottom_mm = round_to_2dp(int32_to_mm(margin_bottom));
     let margin_right_mm = round_to_2dp(int32_to_mm(margin_right));
 
+    let (fill, content) = picture_box(image_url);
+    let attributes = image_attributes(image_url, alt);
     format!(
-        r#"<div class="hsR" style="top:{top_mm}mm;left:{left_mm}mm;margin-bottom:{margin_bottom_mm}mm;margin-right:{margin_right_mm}mm;width:{width_mm}mm;height:{height_mm}mm;display:inline-block;position:relative;vertical-align:middle;background-repeat:no-repeat;background-size:contain;background-image:url('{image_url}');"></div>"#
+        r#"<div class="hsR"{attributes} style="top:{top_mm}mm;left:{left_mm}mm;margin-bottom:{margin_bottom_mm}mm;margin-right:{margin_right_mm}mm;width:{width_mm}mm;height:{height_mm}mm;display:inline-block;position:relative;vertical-align:middle;{fill}{effects_style}">{content}</div>"#,
+        effects_style = effects_style(effects)
     )
 }
+
+/// 그림 자르기/보정을 CSS로 변환 / Convert picture crop and adjustments to CSS
+///
+/// 자르기는 배경 이미지를 확대하고 위치를 옮겨 보이는 영역만 남깁니다.
+/// Cropping scales up the background image and shifts it so only the kept area is visible.
+fn effects_style(effects: &ImageEffects) -> String {
+    if effects.is_default() {
+        return String::new();
+    }
+
+    let mut style = String::new();
+    if let Some(crop) = &effects.crop {
+        let kept_width = 1.0 - crop.left - crop.right;
+        let kept_height = 1.0 - crop.top - crop.bottom;
+        let position_x = if crop.left + crop.right > 0.0 {
+            crop.left / (crop.left + crop.right) * 100.0
+        } else {
+            0.0
+        };
+        let position_y = if crop.top + crop.bottom > 0.0 {
+            crop.top / (crop.top + crop.bottom) * 100.0
+        } else {
+            0.0
+        };
+        style.push_str(&format!(
+            "background-size:{}% {}%;background-position:{}% {}%;",
+            round_to_2dp(100.0 / kept_width),
+            round_to_2dp(100.0 / kept_height),
+            round_to_2dp(position_x),
+            round_to_2dp(position_y)
+        ));
+    }
+
+    let mut filters = Vec::new();
+    if effects.brightness != 0 {
+        filters.push(format!(
+            "brightness({})",
+            round_to_2dp(1.0 + effects.brightness as f64 / 100.0)
+        ));
+    }
+    if effects.contrast != 0 {
+        filters.push(format!(
+            "contrast({})",
+            round_to_2dp(1.0 + effects.contrast as f64 / 100.0)
+        ));
+    }
+    match effects.effect {
+        // 회색조 / Gray scale
+        1 => filters.push("grayscale(1)".to_string()),
+        // 흑백 / Black and white
+        2 => filters.push("grayscale(1) contrast(10)".to_string()),
+        _ => {}
+    }
+    if !filters.is_empty() {
+        style.push_str(&format!("filter:{};", filters.join(" ")));
+    }
+
+    if effects.alpha != 0 {
+        style.push_str(&format!(
+            "opacity:{};",
+            round_to_2dp(1.0 - effects.alpha as f64 / 255.0)
+        ));
+    }
+
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::{ImageCrop, Margin};
+    use crate::types::SHWPUNIT;
+
+    #[test]
+    fn test_effects_style() {
+        assert_eq!(effects_style(&ImageEffects::default()), "");
+
+        let effects = ImageEffects {
+            crop: ImageCrop::from_rect((1000, 0, 4000, 4000), 4000, 4000),
+            brightness: 20,
+            contrast: 0,
+            effect: 1,
+            alpha: 0,
+        };
+        assert_eq!(
+            effects_style(&effects),
+            "background-size:133.33% 100%;background-position:100% 0%;\
+             filter:brightness(1.2) grayscale(1);"
+        );
+    }
+
+    #[test]
+    fn test_render_floating_image_by_text_wrap() {
+        let mut placement = ObjectPlacement {
+            attribute: Default::default(),
+            offset_x: SHWPUNIT(7200),
+            offset_y: SHWPUNIT(0),
+            z_order: 0,
+            margin: Margin {
+                top: 0,
+                right: 720,
+                bottom: 0,
+                left: 0,
+            },
+        };
+        let effects = ImageEffects::default();
+        let html = |placement: &ObjectPlacement| {
+            render_floating_image("a.png", "그림", 7200, 7200, placement, &effects)
+        };
+
+        assert!(html(&placement).contains("float:left;margin:0mm 2.54mm 0mm 0mm;"));
+        assert!(html(&placement).contains(r#"role="img" aria-label="그림""#));
+
+        placement.attribute.object_text_position_option = ObjectTextPositionOption::LeftOnly;
+        assert!(html(&placement).contains("float:right;"));
+
+        placement.attribute.object_text_option = ObjectTextOption::TopAndBottom;
+        placement.attribute.horz_relative = 1;
+        assert!(html(&placement).contains("display:block;clear:both;margin:0mm auto 0mm auto;"));
+
+        placement.attribute.object_text_option = ObjectTextOption::BehindText;
+        assert!(html(&placement).contains("left:25.4mm;top:0mm;z-index:-1;"));
+    }
+}
@@ -1,12 +1,14 @@
-use crate::document::bodytext::ctrl_header::VertRelTo;
+use crate::document::bodytext::ctrl_header::ObjectPlacement;
 /// 라인 세그먼트 렌더링 모듈 / Line segment rendering module
 use crate::document::bodytext::{
     control_char::{ControlChar, ControlCharPosition},
-    CharShapeInfo, LineSegmentInfo, PageDef, Table,
+    CharShapeInfo, ImageEffects, LineSegmentInfo, PageDef, Table,
 };
-use crate::document::CtrlHeaderData;
+use crate::document::{CtrlHeaderData, ImageLabel};
+use crate::viewer::core::bidi::{base_direction, TextDirection};
 use crate::viewer::html::ctrl_header::table::{CaptionData, TablePosition, TableRenderContext};
 use crate::viewer::html::styles::{int32_to_mm, round_to_2dp};
+use crate::viewer::html::text::dir_attribute;
 use crate::viewer::HtmlOptions;
 use crate::{HwpDocument, ParaShape};
 use std::collections::HashMap;
@@ -61,8 +63,12 @@ pub struct ImageInfo {
     /// object_common 속성: 줄 간격에 영향 여부 / object_common attribute: affect line spacing
     #[allow(dead_code)]
     pub affect_line_spacing: bool,
-    /// object_common 속성: 세로 기준 위치 / object_common attribute: vertical reference position
-    pub vert_rel_to: Option<VertRelTo>,
+    /// object_common 배치 정보 (위치 기준, 오프셋, 본문 배치, 바깥 여백) / object_common placement (anchor, offsets, text wrap, outer margins)
+    pub placement: Option<ObjectPlacement>,
+    /// 자르기/보정 정보 / Crop and adjustment information
+    pub effects: ImageEffects,
+    /// 설명문과 캡션 / Description and caption
+    pub label: ImageLabel,
 }
 
 /// 라인 세그먼트를 HTML로 렌더링 / Render line segment to HTML
@@ -74,6 +80,7 @@ pub fn render_line_segment(
     para_shape: Option<&ParaShape>, // ParaShape 정보 (옵션) / ParaShape info (optional)
     is_text_segment: bool,          // 텍스트 세그먼트 여부 (테이블/이미지 like_letters 등은 false)
     override_size_mm: Option<(f64, f64)>, // 비텍스트 세그먼트(이미지 등)에서 hls box 크기 override
+    direction: Option<TextDirection>, // 문단의 기본 방향 / Paragraph base direction
 ) -> String {
     let left_mm = round_to_2dp(int32_to_mm(segment.column_start_position));
     let vertical_pos_mm = int32_to_mm(segment.vertical_position);
@@ -134,23 +141,25 @@ pub fn render_line_segment(
 
     // padding-left 처리 (들여쓰기) / Handle padding-left (indentation)
     if segment.tag.has_indentation {
-        // NOTE:
-        // HWP ParaShape의 `indent`는 첫 줄 들여쓰기(음수 가능; 내어쓰기/행잉 인덴트 표현)이고,
-        // `outdent`는 다음 줄(들여쓰기 적용 라인)의 오프셋으로 사용하는 값입니다.
-        // fixture(noori.html)에서 line_segment.tag.has_indentation=true인 라인은
-        // padding-left가 양수로 적용되는데, 이는 `indent`가 아니라 `outdent`에 해당합니다.
+        // HWP ParaShape의 `indent`는 양수면 첫 줄 들여쓰기, 음수면 둘째 줄부터의 내어쓰기입니다.
+        // 들여쓰기가 적용된 줄(has_indentation)은 어느 쪽이든 그 크기만큼 밀립니다.
+        // A positive HWP ParaShape `indent` indents the first line, a negative one outdents the
+        // following lines; lines with the indentation applied (has_indentation) shift by its size
+        // either way. 문단 모양의 여백 값은 HWPUNIT의 두 배로 저장됩니다 (noori.html과 일치).
+        // Paragraph shape margin values are stored as twice the HWPUNIT (matches noori.html).
         //
-        // 우선순위: ParaShape.outdent → (fallback) 전달받은 para_shape_indent
+        // 우선순위: ParaShape.indent → (fallback) 전달받은 para_shape_indent
         if let Some(ps) = para_shape {
-            let outdent_mm = round_to_2dp(int32_to_mm(ps.outdent));
-            style.push_str(&format!("padding-left:{outdent_mm:.2}mm;"));
+            let indent_mm = round_to_2dp(int32_to_mm(ps.indent.abs() / 2));
+            style.push_str(&format!("padding-left:{indent_mm:.2}mm;"));
         } else if let Some(indent) = para_shape_indent {
             let indent_mm = round_to_2dp(int32_to_mm(indent));
             style.push_str(&format!("padding-left:{indent_mm:.2}mm;"));
         }
     }
 
-    format!(r#"<div class="hls {para_shape_class}" style="{style}">{content}</div>"#)
+    let dir_attr = dir_attribute(direction);
+    format!(r#"<div class="hls {para_shape_class}"{dir_attr} style="{style}">{content}</div>"#)
 }
 
 /// 라인 세그먼트를 HTML로 렌더링 (ParaShape indent 포함) / Render line segment to HTML (with ParaShape indent)
@@ -169,6 +178,7 @@ pub fn render_line_segment_with_indent(
         None,
         true,
         None,
+        None,
     )
 }
 
@@ -237,6 +247,9 @@ pub fn render_line_segments_with_content(
     let hcd_position = context.hcd_position;
     let page_def = context.page_def;
 
+    // 기본 방향은 줄이 아니라 문단 전체로 판별 / Base direction is decided by the whole paragraph, not per line
+    let direction = base_direction(text);
+
     let table_counter_start = state.table_counter_start;
     // pattern_counter와 color_to_pattern은 이미 &mut이므로 직접 사용 / pattern_counter and color_to_pattern are already &mut, so use directly
 
@@ -437,12 +450,14 @@ pub fn render_line_segments_with_content(
             use crate::viewer::html::image::render_image_with_style;
             let image_html = render_image_with_style(
                 &image.url,
+                &image.label.alt_text(),
                 0,
                 0,
                 image.width as crate::types::INT32,
                 image.height as crate::types::INT32,
                 0,
                 0,
+                &image.effects,
             );
             content.push_str(&image_html);
             // IMPORTANT: 일부 파일(noori 'BIN0002.bmp')에서 LineSegment의 segment_width/line_height가 0에 가깝게 나와
@@ -456,7 +471,13 @@ pub fn render_line_segments_with_content(
         } else if !is_text_empty {
             // 텍스트 렌더링 / Render text
             use crate::viewer::html::text::render_text;
-            let rendered_text = render_text(&segment_text, &segment_char_shapes, document, "");
+            let rendered_text = render_text(
+                &segment_text,
+                &segment_char_shapes,
+                document,
+                direction,
+                options,
+            );
             content.push_str(&rendered_text);
         }
 
@@ -485,6 +506,7 @@ pub fn render_line_segments_with_content(
             !(!tables_for_segment.is_empty()
                 || ((is_empty_segment || is_text_empty) && !images.is_empty())),
             override_size_mm,
+            direction,
         ));
     }
 
@@ -5,6 +5,7 @@
 /// 이 모듈은 HWP 문서를 HTML 형식으로 변환하는 기능을 제공합니다.
 ///
 /// noori.html 스타일의 정확한 레이아웃 HTML 뷰어
+mod accessibility;
 mod common;
 mod ctrl_header;
 mod document;
@@ -18,5 +19,5 @@ mod styles;
 mod text;
 
 // Re-export public API
-pub use document::to_html;
-pub use options::HtmlOptions;
+pub use document::{to_html, to_html_writer};
+pub use options::{HtmlOptions, MasterPageMode};
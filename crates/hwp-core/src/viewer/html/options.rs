@@ -1,10 +1,51 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::document::bodytext::InlineControl;
+use crate::document::ContentFilter;
+use crate::viewer::image_export::ImageFormat;
+use crate::viewer::image_sink::ImageSink;
+
+/// 바탕쪽 렌더링 방식 / Master page rendering mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MasterPageMode {
+    /// 넣지 않음 / Skip them
+    Skip,
+    /// 쪽마다 본문 뒤에 반투명 층으로 넣음 (불투명도 0.0-1.0)
+    /// Lay them behind the body of each page as a translucent layer (opacity 0.0-1.0)
+    Layer {
+        /// 층의 불투명도 / Opacity of the layer
+        opacity: f32,
+    },
+    /// 첫 쪽의 바탕쪽 글을 문서 맨 앞에 한 번만 넣음 (반복되는 편지지 머리 등)
+    /// Put the master page text of the first page once at the top of the document (repeated
+    /// letterheads and the like)
+    Once,
+}
+
 /// HTML 변환 옵션 / HTML conversion options
 #[derive(Debug, Clone)]
 pub struct HtmlOptions {
-    /// 이미지를 파일로 저장할 디렉토리 경로 (None이면 base64 데이터 URI로 임베드)
-    /// Optional directory path to save images as files. If None, images are embedded as base64 data URIs.
+    /// 이미지를 파일로 저장할 디렉토리 경로 (None이거나 `fs` 기능이 없으면 base64 데이터 URI로 임베드)
+    /// Optional directory path to save images as files. If None, or without the `fs` feature,
+    /// images are embedded as base64 data URIs.
     pub image_output_dir: Option<String>,
 
+    /// 그림을 넘길 저장소 (Some이면 `image_output_dir`보다 우선, `src`는 저장소가 돌려준 URL)
+    /// Sink that receives pictures (takes precedence over `image_output_dir`; `src` is the URL
+    /// the sink returns)
+    pub image_sink: Option<Arc<dyn ImageSink>>,
+
+    /// 그림 긴 변의 최대 픽셀 수 (`image-processing` 기능 필요, None이면 줄이지 않음)
+    /// Maximum number of pixels on a picture's longer side (requires the `image-processing`
+    /// feature; None keeps the original size)
+    pub image_max_dimension: Option<u32>,
+
+    /// 내보낼 그림 형식 (`image-processing` 기능 필요, None이면 원래 형식)
+    /// Picture format for export (requires the `image-processing` feature; None keeps the
+    /// original format)
+    pub image_format: Option<ImageFormat>,
+
     /// HTML 파일이 저장되는 디렉토리 경로 (이미지 상대 경로 계산에 사용)
     /// Directory path where HTML file is saved (used for calculating relative image paths)
     pub html_output_dir: Option<String>,
@@ -15,6 +56,68 @@ pub struct HtmlOptions {
     /// 페이지 정보 포함 여부 / Whether to include page information
     pub include_page_info: Option<bool>,
 
+    /// 인용 필드로 참고 문헌 절을 만들지 여부 / Whether to append a references section built from citation fields
+    pub include_references: Option<bool>,
+
+    /// 글자 색과 음영(형광펜) 색을 스타일로 표시할지 여부 (기본값: 표시)
+    /// Whether text color and shade (highlight) color are rendered as styles (default: rendered)
+    pub include_text_colors: Option<bool>,
+
+    /// 본문 문단의 최상위 요소에 원본 위치(`data-para="구역:문단"`)를 붙일지 여부
+    /// Whether top-level elements of body paragraphs carry their source location
+    /// (`data-para="section:paragraph"`)
+    ///
+    /// 주석 도구가 변환 결과를 파싱된 문단(`body_text.sections[구역].paragraphs[문단]`)으로
+    /// 되짚을 수 있게 합니다.
+    /// Lets annotation tools map the output back to the parsed paragraph
+    /// (`body_text.sections[section].paragraphs[paragraph]`).
+    pub include_source_anchors: Option<bool>,
+
+    /// 각 쪽에 머리말/꼬리말을 넣을지 여부 (쪽 번호와 전체 쪽 수 필드는 실제 값으로 바꿈)
+    /// Whether each page carries its header and footer (page number and total page fields are
+    /// replaced with actual values)
+    ///
+    /// 전체 쪽 수를 알아야 하므로 켜면 모든 쪽을 만든 뒤에 출력합니다.
+    /// The total page count must be known, so when enabled pages are written only after all of
+    /// them are built.
+    pub include_headers_footers: Option<bool>,
+
+    /// 바탕쪽(워터마크, 쪽 배경 개체) 렌더링 방식 (기본값: 넣지 않음)
+    /// How master pages (watermarks, page background objects) are rendered (default: skipped)
+    pub master_pages: Option<MasterPageMode>,
+
+    /// 걸러 낼 숨은 설명/개인 정보 필드/보이지 않는 문자 (기본값: 걸러 내지 않음)
+    /// Hidden comments, personal information fields and invisible characters to filter out (default: none)
+    pub content_filter: Option<ContentFilter>,
+
+    /// 결정적 출력 여부: 그림 파일 이름과 참조 라벨을 ZIP 항목 순서 대신 BinData 이름순 번호로
+    /// 정해, 같은 문서는 어떻게 묶였든 늘 바이트 단위로 같은 결과를 냅니다 (스냅숏 테스트용).
+    /// Deterministic output: picture file names and reference labels are numbered in BinData name
+    /// order instead of ZIP entry order, so the same document always produces byte-identical
+    /// output however it was packed (for snapshot testing).
+    pub deterministic: bool,
+
+    /// HWP 글꼴 이름 → CSS font-family 대체 목록 (예: "바탕" → "'Nanum Myeongjo', serif")
+    /// HWP face name → CSS font-family fallback list (e.g. "바탕" → "'Nanum Myeongjo', serif")
+    ///
+    /// 원래 글꼴 이름 뒤에 붙으며, 없는 글꼴은 내장 기본값을 씁니다.
+    /// Appended after the original face name; faces not listed use the built-in defaults.
+    pub font_map: HashMap<String, String>,
+
+    /// 인라인 제어 문자(탭, 하이픈, 묶음/고정폭 빈칸)의 출력 HTML
+    /// Output HTML for inline control characters (tab, hyphen, non-breaking/fixed-width space)
+    ///
+    /// 없는 종류는 기본값(`"\t"`, `"-"`, `"&nbsp;"`, `"&#8199;"`)을 씁니다.
+    /// Kinds not listed use the defaults (`"\t"`, `"-"`, `"&nbsp;"`, `"&#8199;"`).
+    pub inline_controls: HashMap<InlineControl, String>,
+
+    /// 접근성 출력 여부: 개요 문단은 `<h1>`~`<h6>`, 표 머리행은 머리글 셀, 그림은
+    /// `<figure>`로 내보내고 `lang="ko"`를 붙이며 장식용 도형은 보조 기술에서 숨깁니다.
+    /// Accessible output: outline paragraphs become `<h1>`–`<h6>`, table header rows become
+    /// header cells, pictures are wrapped in `<figure>`, `lang="ko"` is set and decorative
+    /// shapes are hidden from assistive technology.
+    pub accessibility: bool,
+
     /// CSS 클래스 접두사 (기본값: "" - noori.html 스타일)
     /// CSS class prefix (default: "" - noori.html style)
     pub css_class_prefix: String,
@@ -24,9 +127,22 @@ impl Default for HtmlOptions {
     fn default() -> Self {
         Self {
             image_output_dir: None,
+            image_sink: None,
+            image_max_dimension: None,
+            image_format: None,
             html_output_dir: None,
             include_version: Some(true),
             include_page_info: Some(false),
+            include_references: Some(false),
+            include_text_colors: Some(true),
+            include_source_anchors: Some(false),
+            include_headers_footers: Some(false),
+            master_pages: None,
+            content_filter: None,
+            deterministic: false,
+            font_map: HashMap::new(),
+            inline_controls: HashMap::new(),
+            accessibility: false,
             css_class_prefix: String::new(), // noori.html 스타일은 접두사 없음
         }
     }
@@ -39,6 +155,50 @@ impl HtmlOptions {
         self
     }
 
+    /// 그림 저장소 설정 / Set the image sink
+    pub fn with_image_sink(mut self, sink: Option<Arc<dyn ImageSink>>) -> Self {
+        self.image_sink = sink;
+        self
+    }
+
+    /// 실제로 그림을 넘길 저장소 (`image_sink`, 없으면 `image_output_dir`의 `FileImageSink`)
+    /// Sink pictures actually go to (`image_sink`, else a `FileImageSink` for `image_output_dir`)
+    ///
+    /// 파일 저장소의 URL은 `html_output_dir`이 있으면 그곳에서 본 상대 경로, 없으면 파일 이름입니다.
+    /// File sink URLs are relative to `html_output_dir` when set, otherwise just the file name.
+    pub(crate) fn resolved_image_sink(&self) -> Option<Arc<dyn ImageSink>> {
+        if let Some(sink) = &self.image_sink {
+            return Some(Arc::clone(sink));
+        }
+        #[cfg(feature = "fs")]
+        if let Some(dir) = &self.image_output_dir {
+            // 상대 경로 계산 실패 시 파일 이름만 씀 / Falls back to the file name when no relative path exists
+            let prefix = self
+                .html_output_dir
+                .as_deref()
+                .and_then(|html_dir| pathdiff::diff_paths(dir, html_dir))
+                .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+                .filter(|relative| !relative.is_empty())
+                .map(|relative| format!("{relative}/"))
+                .unwrap_or_default();
+            let sink = crate::viewer::image_sink::FileImageSink::new(dir).with_url_prefix(prefix);
+            return Some(Arc::new(sink));
+        }
+        None
+    }
+
+    /// 그림 최대 크기 설정 / Set the maximum picture dimension
+    pub fn with_image_max_dimension(mut self, max_dimension: Option<u32>) -> Self {
+        self.image_max_dimension = max_dimension;
+        self
+    }
+
+    /// 그림 형식 설정 / Set the picture format
+    pub fn with_image_format(mut self, format: Option<ImageFormat>) -> Self {
+        self.image_format = format;
+        self
+    }
+
     /// 버전 정보 포함 설정 / Set version information inclusion
     pub fn with_include_version(mut self, include: Option<bool>) -> Self {
         self.include_version = include;
@@ -51,6 +211,67 @@ impl HtmlOptions {
         self
     }
 
+    /// 참고 문헌 절 포함 설정 / Set references section inclusion
+    pub fn with_include_references(mut self, include: Option<bool>) -> Self {
+        self.include_references = include;
+        self
+    }
+
+    /// 글자 색/음영 색 표시 설정 / Set text and shade color rendering
+    pub fn with_include_text_colors(mut self, include: Option<bool>) -> Self {
+        self.include_text_colors = include;
+        self
+    }
+
+    /// 원본 위치 속성 포함 설정 / Set source location attribute inclusion
+    pub fn with_include_source_anchors(mut self, include: Option<bool>) -> Self {
+        self.include_source_anchors = include;
+        self
+    }
+
+    /// 머리말/꼬리말 포함 설정 / Set header and footer inclusion
+    pub fn with_include_headers_footers(mut self, include: Option<bool>) -> Self {
+        self.include_headers_footers = include;
+        self
+    }
+
+    /// 바탕쪽 렌더링 방식 설정 / Set the master page rendering mode
+    pub fn with_master_pages(mut self, mode: Option<MasterPageMode>) -> Self {
+        self.master_pages = mode;
+        self
+    }
+
+    /// 걸러 낼 내용 설정 / Set the content to filter out
+    pub fn with_content_filter(mut self, filter: Option<ContentFilter>) -> Self {
+        self.content_filter = filter;
+        self
+    }
+
+    /// 결정적 출력 설정 / Set deterministic output
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// 글꼴 대체 목록 하나 추가 / Add one font fallback mapping
+    pub fn with_font_mapping(mut self, face_name: &str, font_family: &str) -> Self {
+        self.font_map
+            .insert(face_name.to_string(), font_family.to_string());
+        self
+    }
+
+    /// 인라인 제어 문자 출력 HTML 하나 설정 / Set the output HTML of one inline control character
+    pub fn with_inline_control(mut self, control: InlineControl, output: &str) -> Self {
+        self.inline_controls.insert(control, output.to_string());
+        self
+    }
+
+    /// 접근성 출력 설정 / Set accessible output
+    pub fn with_accessibility(mut self, accessibility: bool) -> Self {
+        self.accessibility = accessibility;
+        self
+    }
+
     /// CSS 클래스 접두사 설정 / Set CSS class prefix
     pub fn with_css_class_prefix(mut self, prefix: &str) -> Self {
         self.css_class_prefix = prefix.to_string();
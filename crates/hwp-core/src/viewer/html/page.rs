@@ -1,8 +1,228 @@
-use crate::document::bodytext::ctrl_header::{CtrlHeaderData, PageNumberPosition};
+use crate::document::bodytext::ctrl_header::{ApplyPage, CtrlHeaderData, PageNumberPosition};
+use crate::document::bodytext::{MasterPage, NumberShape, Paragraph, ParagraphRecord};
 use crate::document::HwpDocument;
 use crate::types::RoundTo2dp;
+use crate::viewer::core::page_fields::{substitute_page_fields, PageField};
 use crate::{document::bodytext::PageDef, INT32};
 
+use super::ctrl_header::{self, table::position::object_position};
+use super::image::render_image;
+use super::text::{extract_text_and_shapes, render_text};
+use super::HtmlOptions;
+
+/// 전체 쪽 수 자리표시 (모든 쪽을 만든 뒤 실제 쪽 수로 바꿈)
+/// Placeholder for the total page count (replaced once every page is built)
+pub const TOTAL_PAGES_PLACEHOLDER: &str = "\u{FDD0}";
+
+/// 쪽에 쓰이는 머리말/꼬리말 문단 (적용 쪽, 문단) / Header/footer paragraphs in effect (applied pages, paragraphs)
+pub type PageMargin<'a> = (ApplyPage, Vec<&'a Paragraph>);
+
+/// 쪽의 머리말과 꼬리말을 HTML로 렌더링 (쪽 번호 필드는 실제 번호로 바꿈)
+/// Render the header and footer of a page to HTML (page number fields become actual numbers)
+///
+/// `page_number`는 시작 번호를 반영한 쪽 번호이며 홀수/짝수 쪽 머리말 선택에도 씁니다. 전체 쪽
+/// 수는 아라비아 숫자로 나중에 채웁니다.
+/// `page_number` includes the start number and also picks odd/even page headers. The total page
+/// count is filled in later as Arabic digits.
+pub fn render_page_margins(
+    page_number: usize,
+    headers: &[PageMargin],
+    footers: &[PageMargin],
+    page_def: Option<&PageDef>,
+    document: &HwpDocument,
+    options: &HtmlOptions,
+) -> String {
+    let height_mm = page_def.map(|pd| pd.paper_height.to_mm()).unwrap_or(297.0);
+    let left_mm = page_def
+        .map(|pd| (pd.left_margin.to_mm() + pd.binding_margin.to_mm()).round_to_2dp())
+        .unwrap_or(20.0);
+    let header_top_mm = page_def
+        .map(|pd| pd.top_margin.to_mm().round_to_2dp())
+        .unwrap_or(10.0);
+    let footer_top_mm = page_def
+        .map(|pd| (height_mm - pd.bottom_margin.to_mm() - pd.footer_margin.to_mm()).round_to_2dp())
+        .unwrap_or((height_mm - 10.0).round_to_2dp());
+
+    let value = page_field_value(page_number);
+    let mut html = String::new();
+    for (margins, top_mm, class) in [
+        (headers, header_top_mm, "page-header"),
+        (footers, footer_top_mm, "page-footer"),
+    ] {
+        // 홀수/짝수 쪽 전용이 양쪽용보다 우선 / Odd/even-only ones take precedence over both-page ones
+        let Some((_, paragraphs)) = margins
+            .iter()
+            .filter(|(apply, _)| apply.applies_to(page_number))
+            .max_by_key(|(apply, _)| *apply != ApplyPage::Both)
+        else {
+            continue;
+        };
+        let content: String = paragraphs
+            .iter()
+            .map(|paragraph| {
+                let paragraph = substitute_page_fields(paragraph, &value);
+                let (text, shapes) = extract_text_and_shapes(&paragraph);
+                format!(
+                    "<div>{}</div>",
+                    render_text(&text, &shapes, document, None, options)
+                )
+            })
+            .collect();
+        html.push_str(&format!(
+            r#"<div class="hcD {}{class}" style="left:{left_mm}mm;top:{top_mm}mm;"><div class="hcI">{content}</div></div>"#,
+            options.css_class_prefix
+        ));
+    }
+    html
+}
+
+/// 쪽 번호 필드 값 (전체 쪽 수는 자리표시) / Page number field values (the total page count is a placeholder)
+fn page_field_value(page_number: usize) -> impl Fn(PageField, NumberShape) -> Option<String> {
+    move |field, shape| {
+        Some(match field {
+            PageField::Page => shape.format(page_number as u32),
+            PageField::TotalPages => TOTAL_PAGES_PLACEHOLDER.to_string(),
+        })
+    }
+}
+
+/// 쪽의 바탕쪽을 본문 뒤 반투명 층으로 렌더링 / Render the master page of a page as a translucent layer behind the body
+///
+/// 바탕쪽 글은 본문 영역에, 그림은 개체 배치대로 쪽 전체를 덮는 층 안에 둡니다.
+/// Master page text goes into the body area and pictures follow their object placement, inside a
+/// layer covering the whole page.
+pub fn render_master_page(
+    page_number: usize,
+    master_pages: &[&MasterPage],
+    page_def: Option<&PageDef>,
+    document: &HwpDocument,
+    options: &HtmlOptions,
+    opacity: f32,
+) -> String {
+    let Some(master_page) = master_page_for(page_number, master_pages) else {
+        return String::new();
+    };
+    let left_mm = page_def
+        .map(|pd| (pd.left_margin.to_mm() + pd.binding_margin.to_mm()).round_to_2dp())
+        .unwrap_or(20.0);
+    let top_mm = page_def
+        .map(|pd| (pd.top_margin.to_mm() + pd.header_margin.to_mm()).round_to_2dp())
+        .unwrap_or(24.99);
+
+    let value = page_field_value(page_number);
+    let mut text = String::new();
+    let mut pictures = String::new();
+    for paragraph in &master_page.paragraphs {
+        let substituted = substitute_page_fields(paragraph, &value);
+        let (content, shapes) = extract_text_and_shapes(&substituted);
+        if !content.trim().is_empty() {
+            text.push_str(&format!(
+                "<div>{}</div>",
+                render_text(&content, &shapes, document, None, options)
+            ));
+        }
+        for record in &paragraph.records {
+            let ParagraphRecord::CtrlHeader {
+                header,
+                children,
+                paragraphs,
+            } = record
+            else {
+                continue;
+            };
+            let result =
+                ctrl_header::process_ctrl_header(header, children, paragraphs, document, options);
+            for image in result.images {
+                let width_mm = image.width as f64 * 25.4 / 7200.0;
+                let (left, top) = object_position(
+                    None,
+                    page_def,
+                    None,
+                    image.placement.as_ref(),
+                    Some(width_mm),
+                    None,
+                    None,
+                    None,
+                    None,
+                );
+                pictures.push_str(&render_image(
+                    &image.url,
+                    &image.label.alt_text(),
+                    (left * 7200.0 / 25.4) as INT32,
+                    (top * 7200.0 / 25.4) as INT32,
+                    image.width as INT32,
+                    image.height as INT32,
+                    image.placement.as_ref(),
+                    &image.effects,
+                ));
+            }
+        }
+    }
+    if text.is_empty() && pictures.is_empty() {
+        return String::new();
+    }
+    if !text.is_empty() {
+        text = format!(
+            r#"<div class="hcD" style="left:{left_mm}mm;top:{top_mm}mm;"><div class="hcI">{text}</div></div>"#
+        );
+    }
+    format!(
+        r#"<div class="{}master-page" aria-hidden="true" style="position:absolute;left:0;top:0;width:100%;height:100%;opacity:{opacity};z-index:-1;pointer-events:none;">{text}{pictures}</div>"#,
+        options.css_class_prefix
+    )
+}
+
+/// 첫 쪽의 바탕쪽 글을 문서 맨 앞에 한 번만 렌더링 / Render the master page text of the first page once at the top of the document
+///
+/// 쪽 배치 없이 본문 앞의 블록으로 넣으므로 글만 옮기고 그림은 넣지 않습니다.
+/// It goes in as a block before the body without page layout, so only the text is carried over
+/// and pictures are left out.
+pub fn render_master_page_once(
+    page_number: usize,
+    master_pages: &[&MasterPage],
+    document: &HwpDocument,
+    options: &HtmlOptions,
+) -> String {
+    let Some(master_page) = master_page_for(page_number, master_pages) else {
+        return String::new();
+    };
+    let value = page_field_value(page_number);
+    let text: String = master_page
+        .paragraphs
+        .iter()
+        .filter_map(|paragraph| {
+            let paragraph = substitute_page_fields(paragraph, &value);
+            let (content, shapes) = extract_text_and_shapes(&paragraph);
+            (!content.trim().is_empty()).then(|| {
+                format!(
+                    "<div>{}</div>",
+                    render_text(&content, &shapes, document, None, options)
+                )
+            })
+        })
+        .collect();
+    if text.is_empty() {
+        return String::new();
+    }
+    format!(
+        r#"<div class="{}master-page">{text}</div>"#,
+        options.css_class_prefix
+    )
+}
+
+/// 쪽에 쓰이는 바탕쪽 (홀수/짝수 쪽 전용이 양쪽용보다 우선)
+/// Master page in effect on a page (odd/even-only ones take precedence over both-page ones)
+fn master_page_for<'a>(
+    page_number: usize,
+    master_pages: &[&'a MasterPage],
+) -> Option<&'a MasterPage> {
+    master_pages
+        .iter()
+        .copied()
+        .filter(|master_page| master_page.apply_page.applies_to(page_number))
+        .max_by_key(|master_page| master_page.apply_page != ApplyPage::Both)
+}
+
 /// 페이지를 HTML로 렌더링 / Render page to HTML
 pub fn render_page(
     page_number: usize,
@@ -13,6 +233,7 @@ pub fn render_page(
     hcd_position: Option<(f64, f64)>,
     page_number_position: Option<&CtrlHeaderData>,
     page_start_number: u16,
+    margins: &str,
     document: &HwpDocument,
 ) -> String {
     let width_mm = page_def
@@ -188,6 +409,9 @@ pub fn render_page(
         }
     }
 
+    // 머리말/꼬리말 / Header and footer
+    html.push_str(margins);
+
     html.push_str("</div>");
     html
 }
@@ -1,9 +1,11 @@
+use super::accessibility;
 use super::common;
 use super::ctrl_header;
 use super::line_segment::{
     DocumentRenderState, ImageInfo, LineSegmentContent, LineSegmentRenderContext, TableInfo,
 };
 use super::pagination::{self, PaginationContext, PaginationResult};
+use super::styles::paragraph_flow_style;
 use super::text;
 use super::HtmlOptions;
 use crate::document::bodytext::{
@@ -11,7 +13,8 @@ use crate::document::bodytext::{
     ctrl_header::{CtrlHeaderData, VertRelTo},
     PageDef, ParagraphRecord,
 };
-use crate::document::{HwpDocument, Paragraph};
+use crate::document::{HwpDocument, ImageLabel, Paragraph};
+use crate::viewer::core::bidi::base_direction;
 use crate::viewer::html::ctrl_header::table::{render_table, TablePosition, TableRenderContext};
 use crate::INT32;
 use std::collections::HashMap;
@@ -63,6 +66,7 @@ pub fn render_paragraph(
 
     // ParaShape 클래스 가져오기 / Get ParaShape class
     let para_shape_id = paragraph.para_header.para_shape_id;
+    let heading_level = accessibility::heading_level(&paragraph.para_header, document, options);
     // HWP 파일의 para_shape_id는 0-based indexing을 사용합니다 / HWP file uses 0-based indexing for para_shape_id
     let para_shape_class = if (para_shape_id as usize) < document.doc_info.para_shapes.len() {
         format!("ps{para_shape_id}")
@@ -97,6 +101,7 @@ pub fn render_paragraph(
 
     // 이미지와 테이블 수집 / Collect images and tables
     let mut images = Vec::new();
+    let mut text_boxes = Vec::new();
     let mut tables: Vec<TableInfo> = Vec::new();
 
     // ParaText의 control_char_positions에서 SHAPE_OBJECT(표/그리기 개체) 앵커 위치 수집
@@ -130,12 +135,7 @@ pub fn render_paragraph(
                     } = child
                     {
                         let bindata_id = shape_component_picture.picture_info.bindata_id;
-                        let image_url = common::get_image_url(
-                            document,
-                            bindata_id,
-                            options.image_output_dir.as_deref(),
-                            options.html_output_dir.as_deref(),
-                        );
+                        let image_url = common::get_image_url(document, bindata_id, options);
                         if !image_url.is_empty() {
                             // shape_component.width/height를 직접 사용 / Use shape_component.width/height directly
                             images.push(ImageInfo {
@@ -144,7 +144,9 @@ pub fn render_paragraph(
                                 url: image_url,
                                 like_letters: false, // ShapeComponent에서 직접 온 이미지는 ctrl_header 정보 없음 / Images from ShapeComponent directly have no ctrl_header info
                                 affect_line_spacing: false,
-                                vert_rel_to: None,
+                                placement: None,
+                                effects: shape_component_picture.image_effects(),
+                                label: document.image_label(bindata_id),
                             });
                         }
                     }
@@ -154,12 +156,7 @@ pub fn render_paragraph(
                 shape_component_picture,
             } => {
                 let bindata_id = shape_component_picture.picture_info.bindata_id;
-                let image_url = common::get_image_url(
-                    document,
-                    bindata_id,
-                    options.image_output_dir.as_deref(),
-                    options.html_output_dir.as_deref(),
-                );
+                let image_url = common::get_image_url(document, bindata_id, options);
                 if !image_url.is_empty() {
                     // ShapeComponentPicture가 직접 올 때는 border_rectangle 사용 (부모 ShapeComponent가 없음)
                     // When ShapeComponentPicture comes directly, use border_rectangle (no parent ShapeComponent)
@@ -184,7 +181,44 @@ pub fn render_paragraph(
                         url: image_url,
                         like_letters: false, // ShapeComponentPicture에서 직접 온 이미지는 ctrl_header 정보 없음 / Images from ShapeComponentPicture directly have no ctrl_header info
                         affect_line_spacing: false,
-                        vert_rel_to: None,
+                        placement: None,
+                        effects: shape_component_picture.image_effects(),
+                        label: document.image_label(bindata_id),
+                    });
+                }
+            }
+            ParagraphRecord::HwpxImage {
+                binary_item_ref,
+                width,
+                height,
+                effects,
+                placement,
+                description,
+                caption,
+            } => {
+                // HWPX 그림은 이름으로 BinData를 찾음 / HWPX pictures look up BinData by name
+                let image_url = document
+                    .bin_data
+                    .items
+                    .iter()
+                    .find(|item| item.name.as_deref() == Some(binary_item_ref.as_str()))
+                    .map(|item| common::get_image_url(document, item.index, options))
+                    .unwrap_or_default();
+                if !image_url.is_empty() && *width > 0 && *height > 0 {
+                    images.push(ImageInfo {
+                        width: *width,
+                        height: *height,
+                        url: image_url,
+                        like_letters: placement.as_ref().is_some_and(|p| p.attribute.like_letters),
+                        affect_line_spacing: placement
+                            .as_ref()
+                            .is_some_and(|p| p.attribute.affect_line_spacing),
+                        effects: effects.clone(),
+                        placement: placement.clone(),
+                        label: ImageLabel {
+                            description: description.clone(),
+                            caption: caption.clone(),
+                        },
                     });
                 }
             }
@@ -222,6 +256,7 @@ pub fn render_paragraph(
                     tables.extend(ctrl_result.tables);
                 }
                 images.extend(ctrl_result.images);
+                text_boxes.extend(ctrl_result.text_boxes);
             }
             _ => {}
         }
@@ -343,11 +378,15 @@ pub fn render_paragraph(
             color_to_pattern: state.color_to_pattern,
         };
 
-        result.push_str(&super::line_segment::render_line_segments_with_content(
+        let lines_html = super::line_segment::render_line_segments_with_content(
             &content,
             &context,
             &mut line_segment_state,
-        ));
+        );
+        match heading_level {
+            Some(level) => result.push_str(&accessibility::into_heading(&lines_html, level)),
+            None => result.push_str(&lines_html),
+        }
 
         // inline_tables의 개수만큼 table_counter 증가 (이미 line_segment에 포함되었으므로) / Increment table_counter by inline_tables count (already included in line_segment)
         *state.table_counter += inline_table_infos.len() as u32;
@@ -357,31 +396,41 @@ pub fn render_paragraph(
 
         // like_letters=false인 이미지를 별도로 렌더링 (hpa 레벨에 배치) / Render images with like_letters=false separately (placed at hpa level)
         for image_info in absolute_images.iter() {
+            use crate::viewer::html::ctrl_header::table::position::object_position;
             use crate::viewer::html::image::render_image;
 
-            // vert_rel_to에 따라 위치 계산 / Calculate position based on vert_rel_to
-            let (left_mm, top_mm) = match image_info.vert_rel_to {
-                Some(VertRelTo::Para) => {
-                    // para 기준: hcd_position 사용 / Use hcd_position for para reference
-                    if let Some((hcd_left, hcd_top)) = hcd_position {
-                        // offset_x, offset_y는 현재 image_info에 없으므로 0으로 설정
-                        // offset_x, offset_y are not in image_info currently, so set to 0
-                        (hcd_left, hcd_top)
-                    } else {
-                        // hcd_position이 없으면 para_start_vertical_mm 사용 / Use para_start_vertical_mm if hcd_position not available
-                        (0.0, para_start_vertical_mm.unwrap_or(0.0))
-                    }
-                }
-                Some(VertRelTo::Page) | Some(VertRelTo::Paper) | None => {
-                    // page/paper 기준: 절대 위치 (현재는 0,0으로 설정, 나중에 object_common의 offset_x, offset_y 사용)
-                    // page/paper reference: absolute position (currently set to 0,0, later use object_common's offset_x, offset_y)
-                    (0.0, 0.0)
-                }
-            };
-
             // 이미지 크기 계산 (mm 단위) / Calculate image size (in mm)
+            let width_mm = image_info.width as f64 * 25.4 / 7200.0;
             let height_mm = image_info.height as f64 * 25.4 / 7200.0;
 
+            // 표와 같은 기준(종이/쪽/단/문단)과 오프셋으로 위치 계산
+            // Calculate position with the same anchors (paper/page/column/para) and offsets as tables
+            let (left_mm, top_mm) = match &image_info.placement {
+                Some(placement) => {
+                    let ref_para_vertical_mm =
+                        if matches!(placement.attribute.vert_rel_to, VertRelTo::Para) {
+                            current_para_index
+                                .and_then(|idx| para_vertical_positions.get(idx).copied())
+                                .or(para_start_vertical_mm)
+                        } else {
+                            para_start_vertical_mm
+                        };
+                    object_position(
+                        hcd_position,
+                        page_def,
+                        None,
+                        Some(placement),
+                        Some(width_mm),
+                        ref_para_vertical_mm.map(|v| v + base_top_mm),
+                        para_start_column_mm,
+                        para_segment_width_mm,
+                        first_para_vertical_mm.map(|v| v + base_top_mm),
+                    )
+                }
+                // 개체 공통 속성이 없는 그림은 원점에 배치 / Pictures without object common properties go to the origin
+                None => (0.0, 0.0),
+            };
+
             // 페이지네이션 체크 (렌더링 직전) / Check pagination (before rendering)
             let image_result =
                 pagination::check_object_page_break(top_mm, height_mm, pagination_context);
@@ -393,12 +442,71 @@ pub fn render_paragraph(
 
             let image_html = render_image(
                 &image_info.url,
+                &image_info.label.alt_text(),
                 (left_mm * 7200.0 / 25.4) as INT32,
                 (top_mm * 7200.0 / 25.4) as INT32,
                 image_info.width as INT32,
                 image_info.height as INT32,
+                image_info.placement.as_ref(),
+                &image_info.effects,
             );
-            result.push_str(&image_html);
+            result.push_str(&accessibility::wrap_figure(
+                &image_html,
+                image_info.label.caption.as_deref(),
+                options,
+            ));
+        }
+
+        // 글상자를 별도로 렌더링 (hpa 레벨에 배치) / Render text boxes separately (placed at hpa level)
+        for text_box in text_boxes.iter() {
+            use crate::viewer::html::ctrl_header::table::position::object_position;
+            use crate::viewer::html::ctrl_header::text_box::render_text_box;
+
+            let width_mm = text_box.width as f64 * 25.4 / 7200.0;
+            let height_mm = text_box.height as f64 * 25.4 / 7200.0;
+
+            // 그림과 같은 기준과 오프셋으로 위치 계산 / Calculate position with the same anchors and offsets as pictures
+            let (left_mm, top_mm) = match &text_box.placement {
+                Some(placement) => {
+                    let ref_para_vertical_mm =
+                        if matches!(placement.attribute.vert_rel_to, VertRelTo::Para) {
+                            current_para_index
+                                .and_then(|idx| para_vertical_positions.get(idx).copied())
+                                .or(para_start_vertical_mm)
+                        } else {
+                            para_start_vertical_mm
+                        };
+                    object_position(
+                        hcd_position,
+                        page_def,
+                        None,
+                        Some(placement),
+                        Some(width_mm),
+                        ref_para_vertical_mm.map(|v| v + base_top_mm),
+                        para_start_column_mm,
+                        para_segment_width_mm,
+                        first_para_vertical_mm.map(|v| v + base_top_mm),
+                    )
+                }
+                None => (0.0, 0.0),
+            };
+
+            // 페이지네이션 체크 (렌더링 직전) / Check pagination (before rendering)
+            let text_box_result =
+                pagination::check_object_page_break(top_mm, height_mm, pagination_context);
+            if text_box_result.has_page_break {
+                return (result, table_htmls, Some(text_box_result));
+            }
+
+            result.push_str(&render_text_box(
+                text_box,
+                left_mm,
+                top_mm,
+                document,
+                options,
+                state.pattern_counter,
+                state.color_to_pattern,
+            ));
         }
 
         // like_letters=false인 테이블을 별도로 렌더링 (hpa 레벨에 배치) / Render tables with like_letters=false separately (placed at hpa level)
@@ -483,13 +591,81 @@ pub fn render_paragraph(
             table_htmls.push(table_html);
             *state.table_counter += 1; // table_counter 증가 / Increment table_counter
         }
-    } else if !text.is_empty() {
-        // LineSegment가 없으면 텍스트만 렌더링 / Render text only if no LineSegment
-        let rendered_text =
-            text::render_text(&text, &char_shapes, document, &options.css_class_prefix);
-        result.push_str(&format!(
-            r#"<div class="hls {para_shape_class}">{rendered_text}</div>"#
-        ));
+    } else {
+        if !text.is_empty() {
+            // LineSegment가 없으면 텍스트만 렌더링 / Render text only if no LineSegment
+            let direction = base_direction(&text);
+            let rendered_text =
+                text::render_text(&text, &char_shapes, document, direction, options);
+            let dir_attr = text::dir_attribute(direction);
+            let style_attr = document
+                .doc_info
+                .para_shapes
+                .get(para_shape_id as usize)
+                .map(paragraph_flow_style)
+                .filter(|style| !style.is_empty())
+                .map(|style| format!(r#" style="{style}""#))
+                .unwrap_or_default();
+            let line_html = format!(
+                r#"<div class="hls {para_shape_class}"{dir_attr}{style_attr}>{rendered_text}</div>"#
+            );
+            match heading_level {
+                Some(level) => result.push_str(&accessibility::into_heading(&line_html, level)),
+                None => result.push_str(&line_html),
+            }
+        }
+
+        // LineSegment가 없는 문단(HWPX 등)의 그림은 흐름에 따라 배치
+        // Images in paragraphs without LineSegment (e.g. HWPX) are placed in flow
+        // 글자처럼 취급하는 그림은 줄 안에, 나머지는 본문 배치에 따라 float/block/absolute로 배치
+        // Treat-as-letter pictures stay inline; others float, block or go absolute by text wrap
+        for image_info in images.iter() {
+            use crate::viewer::html::image::{render_floating_image, render_image_with_style};
+            let alt = image_info.label.alt_text();
+            let html = match &image_info.placement {
+                Some(placement) if !image_info.like_letters => render_floating_image(
+                    &image_info.url,
+                    &alt,
+                    image_info.width as INT32,
+                    image_info.height as INT32,
+                    placement,
+                    &image_info.effects,
+                ),
+                _ => render_image_with_style(
+                    &image_info.url,
+                    &alt,
+                    0,
+                    0,
+                    image_info.width as INT32,
+                    image_info.height as INT32,
+                    0,
+                    0,
+                    &image_info.effects,
+                ),
+            };
+            match &image_info.label.caption {
+                // 캡션이 있거나 접근성 출력이면 figure로 묶음 / Wrap in a figure when there is a caption or for accessible output
+                _ if options.accessibility => result.push_str(&format!(
+                    r#"<figure class="{}figure" style="margin:0;display:flow-root;">{html}{}</figure>"#,
+                    options.css_class_prefix,
+                    image_info
+                        .label
+                        .caption
+                        .as_deref()
+                        .map(|caption| format!(
+                            "<figcaption>{}</figcaption>",
+                            common::escape_html(caption)
+                        ))
+                        .unwrap_or_default()
+                )),
+                Some(caption) => result.push_str(&format!(
+                    r#"<figure class="{}figure" style="margin:0;display:flow-root;">{html}<figcaption>{}</figcaption></figure>"#,
+                    options.css_class_prefix,
+                    common::escape_html(caption)
+                )),
+                None => result.push_str(&html),
+            }
+        }
     }
 
     (result, table_htmls, None)
@@ -1,12 +1,14 @@
 use crate::document::docinfo::para_shape::ParagraphAlignment;
 /// CSS 스타일 생성 모듈 / CSS style generation module
 /// noori_style.css 기반으로 CSS 생성
-use crate::document::HwpDocument;
+use crate::document::{HwpDocument, ParaShape};
 use crate::types::{COLORREF, INT32};
+use crate::viewer::html::text::shade_color;
+use crate::viewer::HtmlOptions;
 
 /// CSS 스타일 생성 / Generate CSS styles
 /// 문서에 정의된 모든 스타일을 미리 생성하여 누락 방지 / Pre-generate all styles defined in document to prevent missing styles
-pub fn generate_css_styles(document: &HwpDocument) -> String {
+pub fn generate_css_styles(document: &HwpDocument, options: &HtmlOptions) -> String {
     let mut css = String::new();
 
     // 기본 스타일 (noori_style.css 기반) / Base styles (based on noori_style.css)
@@ -73,6 +75,12 @@ pub fn generate_css_styles(document: &HwpDocument) -> String {
     css.push_str(".hcc {margin:0;padding:0;position:absolute;}\n");
     css.push_str(".hls {clear:both;}\n");
     css.push_str("[onclick] {cursor:pointer;}\n");
+    if options.accessibility {
+        // 제목 태그가 줄 배치를 바꾸지 않도록 기본 스타일 제거 / Reset heading defaults so heading tags keep the line layout
+        css.push_str("h1.hls, h2.hls, h3.hls, h4.hls, h5.hls, h6.hls {font-size:inherit;font-weight:inherit;}\n");
+        css.push_str(".hfg {margin:0;padding:0;display:contents;}\n");
+        css.push_str(".hfc {position:absolute;width:1px;height:1px;overflow:hidden;clip:rect(0 0 0 0);white-space:nowrap;}\n");
+    }
 
     // 모든 CharShape 스타일 생성 (cs0, cs1, cs2, ...) / Generate all CharShape styles (cs0, cs1, cs2, ...)
     // 문서에 정의된 모든 char_shape를 미리 정의하여 누락 방지 / Pre-define all char_shapes in document to prevent missing styles
@@ -87,14 +95,16 @@ pub fn generate_css_styles(document: &HwpDocument) -> String {
 
         css.push_str(&format!("  font-size:{size_pt}pt;"));
 
-        // 텍스트 색상 / Text color
-        let color = &char_shape.text_color;
-        css.push_str(&format!(
-            "color:rgb({},{},{});",
-            color.r(),
-            color.g(),
-            color.b()
-        ));
+        // 글자 색과 음영 색 / Text and shade colors
+        if options.include_text_colors.unwrap_or(true) {
+            css.push_str(&format!(
+                "color:{};",
+                colorref_to_rgb(char_shape.text_color)
+            ));
+            if let Some(shade) = shade_color(char_shape.shading_color) {
+                css.push_str(&format!("background-color:{shade};"));
+            }
+        }
 
         // 폰트 패밀리 / Font family
         // CharShape의 font_ids에서 한글 폰트 ID를 가져와서 face_names에서 폰트 이름 찾기
@@ -107,7 +117,7 @@ pub fn generate_css_styles(document: &HwpDocument) -> String {
             "함초롬바탕" // 기본값 / Default
         };
 
-        css.push_str(&format!("font-family:\"{font_name}\";"));
+        css.push_str(&format!("font-family:{};", font_family(font_name, options)));
 
         // 속성 / Attributes
         if char_shape.attributes.bold {
@@ -192,6 +202,30 @@ pub fn generate_css_styles(document: &HwpDocument) -> String {
     css
 }
 
+/// 줄 배치 정보 없이 흐름대로 놓이는 문단의 여백/들여쓰기 스타일
+/// Margin and indent style for a paragraph laid out in flow, without line segments
+///
+/// 줄 배치 정보가 있는 문단은 줄마다 위치가 정해져 있으므로 쓰지 않습니다. 음수 들여쓰기(내어쓰기)는
+/// 첫 줄만 왼쪽 여백에 두고 나머지 줄을 그만큼 들여씁니다.
+/// Not used for paragraphs with line segments, whose lines are already positioned. A negative
+/// indent (hanging indent) keeps only the first line at the left margin and indents the rest.
+pub fn paragraph_flow_style(para_shape: &ParaShape) -> String {
+    let indent = para_shape.indent;
+    let left = para_shape.left_margin + if indent < 0 { -indent } else { 0 };
+    let mut style = String::new();
+    for (property, value) in [
+        ("margin-left", left),
+        ("margin-right", para_shape.right_margin),
+        ("text-indent", indent),
+    ] {
+        if value != 0 {
+            let value_mm = round_to_2dp(int32_to_mm(value));
+            style.push_str(&format!("{property}:{value_mm:.2}mm;"));
+        }
+    }
+    style
+}
+
 /// INT32를 mm 단위로 변환 / Convert INT32 to millimeters
 /// 값을 소수점 2자리로 반올림 / Round value to 2 decimal places
 pub fn round_to_2dp(value: f64) -> f64 {
@@ -200,19 +234,83 @@ pub fn round_to_2dp(value: f64) -> f64 {
 
 pub fn int32_to_mm(value: INT32) -> f64 {
     // INT32는 1/7200인치 단위 (SHWPUNIT와 동일)
-    (value as f64 / 7200.0) * 25.4
+    crate::types::hwpunit_to_mm(value as i64)
 }
 
 /// mm를 INT32 단위로 변환 / Convert millimeters to INT32
 #[allow(dead_code)]
 pub fn mm_to_int32(value_mm: f64) -> INT32 {
     // INT32는 1/7200인치 단위 (SHWPUNIT와 동일)
-    // 1 inch = 25.4 mm, 따라서 1 mm = 7200 / 25.4 INT32
-    ((value_mm / 25.4) * 7200.0) as INT32
+    crate::types::mm_to_hwpunit(value_mm) as INT32
+}
+
+/// 글꼴이 없을 때 쓸 내장 대체 목록 / Built-in fallback lists for when a face is not installed
+///
+/// 명조 계열은 serif, 고딕/굴림 계열은 sans-serif 웹 글꼴로 대체합니다.
+/// Myeongjo-style faces fall back to serif web fonts, gothic/gulim-style faces to sans-serif ones.
+const BUILTIN_FONT_FALLBACKS: &[(&[&str], &str)] = &[
+    (
+        &[
+            "함초롬바탕",
+            "한컴바탕",
+            "바탕",
+            "바탕체",
+            "신명조",
+            "HY신명조",
+            "휴먼명조",
+            "나눔명조",
+            "궁서",
+            "궁서체",
+            "Batang",
+        ],
+        "'Nanum Myeongjo','Noto Serif KR',serif",
+    ),
+    (
+        &[
+            "함초롬돋움",
+            "한컴돋움",
+            "돋움",
+            "돋움체",
+            "굴림",
+            "굴림체",
+            "맑은 고딕",
+            "나눔고딕",
+            "HY중고딕",
+            "HY견고딕",
+            "휴먼고딕",
+            "Dotum",
+            "Gulim",
+            "Malgun Gothic",
+        ],
+        "'Malgun Gothic','Noto Sans KR',sans-serif",
+    ),
+    (&["Times New Roman"], "'Times New Roman',serif"),
+    (&["Arial"], "Arial,Helvetica,sans-serif"),
+    (&["Courier New", "D2Coding"], "'Courier New',monospace"),
+];
+
+/// 글꼴 이름의 CSS font-family 값 (원래 이름 뒤에 대체 목록)
+/// CSS font-family value for a face name (the original name followed by fallbacks)
+///
+/// `HtmlOptions::font_map`이 내장 기본값보다 우선합니다. / `HtmlOptions::font_map` takes precedence over the built-in defaults.
+pub fn font_family(face_name: &str, options: &HtmlOptions) -> String {
+    let fallback = options
+        .font_map
+        .get(face_name)
+        .map(String::as_str)
+        .or_else(|| {
+            BUILTIN_FONT_FALLBACKS
+                .iter()
+                .find(|(names, _)| names.contains(&face_name))
+                .map(|(_, family)| *family)
+        });
+    match fallback {
+        Some(fallback) if !fallback.trim().is_empty() => format!("\"{face_name}\",{fallback}"),
+        _ => format!("\"{face_name}\""),
+    }
 }
 
 /// COLORREF를 RGB 문자열로 변환 / Convert COLORREF to RGB string
-#[allow(dead_code)]
 pub fn colorref_to_rgb(color: COLORREF) -> String {
     format!("rgb({},{},{})", color.r(), color.g(), color.b())
 }
@@ -1,15 +1,23 @@
 /// 텍스트 렌더링 모듈 / Text rendering module
 use crate::document::{
-    bodytext::{CharShapeInfo, ParagraphRecord},
+    bodytext::{normalize_inline_controls, CharShapeInfo, InlineControl, ParagraphRecord},
     HwpDocument,
 };
+use crate::types::COLORREF;
+use crate::viewer::core::bidi::{base_direction, directional_runs, DirectionalRun, TextDirection};
+use crate::viewer::html::styles::colorref_to_rgb;
+use crate::viewer::HtmlOptions;
 
 /// 텍스트를 HTML로 렌더링 / Render text to HTML
+///
+/// `direction`은 문단의 기본 방향이며, 방향이 다른 구간은 `dir` 속성으로 격리합니다.
+/// `direction` is the paragraph's base direction; segments running the other way are isolated with `dir`.
 pub fn render_text(
     text: &str,
     char_shapes: &[CharShapeInfo],
     document: &HwpDocument,
-    _css_prefix: &str,
+    direction: Option<TextDirection>,
+    options: &HtmlOptions,
 ) -> String {
     if text.is_empty() {
         return String::new();
@@ -64,6 +72,41 @@ pub fn render_text(
             continue;
         }
 
+        // 문단과 방향이 다른 구간 / Segment running against the paragraph direction
+        let paragraph_direction = direction.unwrap_or(TextDirection::Ltr);
+        let runs = directional_runs(&segment_text);
+        let opposing = |run: &DirectionalRun| {
+            run.direction
+                .is_some_and(|run_direction| run_direction != paragraph_direction)
+        };
+        let (dir_attr, segment_text) = if runs
+            .iter()
+            .all(|run| run.direction.is_none() || opposing(run))
+        {
+            // 구간 전체가 반대 방향이면 hrt span에 방향을 붙임 / Whole segment opposes: put the direction on the hrt span
+            let dir_attr = match base_direction(&segment_text) {
+                Some(segment_direction) if segment_direction != paragraph_direction => {
+                    format!(r#" dir="{}""#, segment_direction.as_str())
+                }
+                _ => String::new(),
+            };
+            (dir_attr, segment_text)
+        } else {
+            // 섞인 구간은 반대 방향 부분만 격리 / Mixed segment: isolate only the opposing parts
+            let isolated: String = runs
+                .iter()
+                .map(|run| match run.direction {
+                    Some(run_direction) if opposing(run) => format!(
+                        r#"<span dir="{}" style="unicode-bidi:isolate;">{}</span>"#,
+                        run_direction.as_str(),
+                        run.text
+                    ),
+                    _ => run.text.to_string(),
+                })
+                .collect();
+            (String::new(), isolated)
+        };
+
         // CharShape 가져오기 / Get CharShape
         // HWP 파일의 shape_id는 0-based indexing을 사용합니다 / HWP file uses 0-based indexing for shape_id
         let char_shape_opt = char_shape_id_opt.and_then(|id| {
@@ -86,6 +129,13 @@ pub fn render_text(
             text_for_styling.pop();
             text_for_styling.push_str("&nbsp;");
         }
+        // 인라인 제어 문자 / Inline control characters
+        let text_for_styling = normalize_inline_controls(
+            &text_for_styling,
+            &options.inline_controls,
+            inline_control_html,
+        )
+        .into_owned();
 
         if let Some(char_shape) = char_shape_opt {
             // CharShape 클래스 적용 / Apply CharShape class (0-based indexing to match XSL/XML format)
@@ -98,14 +148,21 @@ pub fn render_text(
             let size_pt = char_shape.base_size as f64 / 100.0;
             inline_style.push_str(&format!("font-size:{size_pt}pt;"));
 
-            // 텍스트 색상 / Text color
-            let color = &char_shape.text_color;
-            inline_style.push_str(&format!(
-                "color:rgb({},{},{});",
-                color.r(),
-                color.g(),
-                color.b()
-            ));
+            // 글자 색과 음영 색 / Text and shade colors
+            if options.include_text_colors.unwrap_or(true) {
+                inline_style.push_str(&format!(
+                    "color:{};",
+                    colorref_to_rgb(char_shape.text_color)
+                ));
+                if let Some(shade) = shade_color(char_shape.shading_color) {
+                    inline_style.push_str(&format!("background-color:{shade};"));
+                }
+            }
+
+            // 강조점 / Emphasis mark
+            if let Some(emphasis) = emphasis_style(char_shape.attributes.emphasis_mark) {
+                inline_style.push_str(&emphasis);
+            }
 
             // 속성 / Attributes
             // bold는 CSS의 font-weight:bold로 처리되므로 <strong> 태그 사용하지 않음
@@ -120,32 +177,88 @@ pub fn render_text(
             if char_shape.attributes.strikethrough > 0 {
                 styled_text = format!("<s>{styled_text}</s>");
             }
-            if char_shape.attributes.superscript {
+            if char_shape.is_superscript() {
                 styled_text = format!("<sup>{styled_text}</sup>");
-            }
-            if char_shape.attributes.subscript {
+            } else if char_shape.is_subscript() {
                 styled_text = format!("<sub>{styled_text}</sub>");
             }
 
             // .hrt span으로 래핑 / Wrap with .hrt span
             if !inline_style.is_empty() {
                 result.push_str(&format!(
-                    r#"<span class="hrt {class_name}" style="{inline_style}">{styled_text}</span>"#
+                    r#"<span class="hrt {class_name}"{dir_attr} style="{inline_style}">{styled_text}</span>"#
                 ));
             } else {
                 result.push_str(&format!(
-                    r#"<span class="hrt {class_name}">{styled_text}</span>"#
+                    r#"<span class="hrt {class_name}"{dir_attr}>{styled_text}</span>"#
                 ));
             }
         } else {
             // CharShape가 없는 경우 기본 스타일 / Default style when no CharShape
-            result.push_str(&format!(r#"<span class="hrt">{text_for_styling}</span>"#));
+            result.push_str(&format!(
+                r#"<span class="hrt"{dir_attr}>{text_for_styling}</span>"#
+            ));
         }
     }
 
     result
 }
 
+/// 강조점의 CSS (`text-emphasis`), 강조점이 없으면 None / CSS for an emphasis mark (`text-emphasis`), None without one
+///
+/// 종류 번호는 HWP 5.0 글자 모양 속성 bit 21-24 값입니다 (HWPX `symMark`도 같은 번호로 읽음).
+/// Kind numbers are the HWP 5.0 char shape attribute bits 21-24 (HWPX `symMark` is read into
+/// the same numbers).
+fn emphasis_style(mark: u8) -> Option<String> {
+    let (style, position) = match mark {
+        1 => ("filled dot", "over"),
+        2 => ("open dot", "over"),
+        3 => ("'ˇ'", "over"),
+        4 => ("'˜'", "over"),
+        5 => ("'･'", "over"),
+        6 => ("':'", "over"),
+        7 => ("'ˋ'", "over"),
+        8 => ("'ˊ'", "over"),
+        9 => ("'ˆ'", "over"),
+        10 => ("'ˉ'", "over"),
+        12 => ("filled dot", "under"),
+        _ => return None,
+    };
+    Some(format!(
+        "text-emphasis:{style};-webkit-text-emphasis:{style};text-emphasis-position:{position} right;"
+    ))
+}
+
+/// 인라인 제어 문자의 기본 HTML / Default HTML of an inline control character
+fn inline_control_html(control: InlineControl) -> &'static str {
+    match control {
+        InlineControl::NonBreakingSpace => "&nbsp;",
+        // U+2007 figure space: 숫자 너비의 빈칸 / U+2007 figure space: a digit-wide space
+        InlineControl::FixedWidthSpace => "&#8199;",
+        _ => control.plain_text(),
+    }
+}
+
+/// 음영 색 CSS 값 (음영 없음이면 None) / CSS value of a shade color (None when there is no shade)
+///
+/// 흰색은 기본값이고, 상위 바이트가 채워진 값(0xFFFFFFFF 등)은 "색 없음"을 뜻합니다.
+/// White is the default, and values with the high byte set (such as 0xFFFFFFFF) mean "no color".
+pub(crate) fn shade_color(color: COLORREF) -> Option<String> {
+    if color.value() >> 24 != 0 || color.value() == 0x00FF_FFFF {
+        return None;
+    }
+    Some(colorref_to_rgb(color))
+}
+
+/// 블록(hls)의 `dir` 속성 (기본값인 LTR이면 생략) / `dir` attribute of a block (hls), omitted for the default LTR
+pub fn dir_attribute(direction: Option<TextDirection>) -> &'static str {
+    if direction == Some(TextDirection::Rtl) {
+        r#" dir="rtl""#
+    } else {
+        ""
+    }
+}
+
 /// 문단에서 텍스트와 CharShape 추출 / Extract text and CharShape from paragraph
 pub fn extract_text_and_shapes(
     paragraph: &crate::document::bodytext::Paragraph,
@@ -0,0 +1,209 @@
+//! 내보내기 그림 축소/재압축 모듈 / Export picture downscaling and recompression module
+//!
+//! 마크다운/HTML로 내보낼 때 큰 그림(스캔 이미지 등)을 줄이고 다른 형식으로 다시 압축합니다.
+//! 실제 변환은 `image-processing` 기능이 켜져 있을 때만 하며, 꺼져 있거나 읽을 수 없는 그림
+//! (WMF/EMF 등)은 원본을 그대로 씁니다.
+//!
+//! Shrinks large pictures (scans, etc.) and recompresses them in another format when exporting
+//! to markdown/HTML. Conversion only happens with the `image-processing` feature enabled; without
+//! it, or for pictures that cannot be decoded (WMF/EMF, ...), the original is used unchanged.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+/// 내보낼 그림 형식 / Picture format for export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFormat {
+    /// 원래 형식 유지 (줄일 때 PNG/JPEG/WebP가 아니면 PNG) / Keep the original format (PNG when a non-PNG/JPEG/WebP picture is downscaled)
+    #[default]
+    Keep,
+    /// PNG
+    Png,
+    /// JPEG (품질 1~100) / JPEG (quality 1 to 100)
+    Jpeg {
+        /// 품질 / Quality
+        quality: u8,
+    },
+    /// WebP (무손실) / WebP (lossless)
+    Webp,
+}
+
+impl ImageFormat {
+    /// JPEG 기본 품질 / Default JPEG quality
+    pub const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+    /// 이름으로 형식 찾기 ("keep", "png", "jpeg"/"jpg", "webp") / Look up a format by name ("keep", "png", "jpeg"/"jpg", "webp")
+    ///
+    /// # Arguments / 매개변수
+    /// * `name` - 형식 이름 / Format name
+    /// * `quality` - JPEG 품질 (None이면 기본값) / JPEG quality (None for the default)
+    pub fn from_name(name: &str, quality: Option<u8>) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "keep" => Some(ImageFormat::Keep),
+            "png" => Some(ImageFormat::Png),
+            "jpeg" | "jpg" => Some(ImageFormat::Jpeg {
+                quality: quality.unwrap_or(Self::DEFAULT_JPEG_QUALITY).clamp(1, 100),
+            }),
+            "webp" => Some(ImageFormat::Webp),
+            _ => None,
+        }
+    }
+}
+
+/// 변환한 그림 / Converted picture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConvertedImage {
+    /// 그림 바이트 / Picture bytes
+    pub data: Vec<u8>,
+    /// MIME 타입 / MIME type
+    pub mime_type: &'static str,
+}
+
+/// 그림을 줄이거나 다른 형식으로 다시 압축 / Downscale a picture or recompress it in another format
+///
+/// # Arguments / 매개변수
+/// * `data` - 원본 그림 바이트 / Original picture bytes
+/// * `max_dimension` - 긴 변의 최대 픽셀 수 / Maximum number of pixels on the longer side
+/// * `format` - 출력 형식 / Output format
+///
+/// # Returns / 반환값
+/// 변환한 그림, 바꿀 것이 없거나 변환할 수 없으면 None / Converted picture, or None when nothing changes or conversion is not possible
+pub(crate) fn convert_image(
+    data: &[u8],
+    max_dimension: Option<u32>,
+    format: Option<ImageFormat>,
+) -> Option<ConvertedImage> {
+    let format = format.unwrap_or_default();
+    if max_dimension.is_none() && format == ImageFormat::Keep {
+        return None;
+    }
+    #[cfg(feature = "image-processing")]
+    {
+        processing::convert(data, max_dimension, format)
+    }
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = data;
+        None
+    }
+}
+
+/// base64 그림을 줄이거나 다시 압축 / Downscale or recompress a base64 picture
+///
+/// 옵션이 비어 있으면 디코딩하지 않습니다. / Skips decoding when no option is set.
+pub(crate) fn convert_base64_image(
+    base64_data: &str,
+    max_dimension: Option<u32>,
+    format: Option<ImageFormat>,
+) -> Option<ConvertedImage> {
+    if max_dimension.is_none() && format.unwrap_or_default() == ImageFormat::Keep {
+        return None;
+    }
+    let data = STANDARD.decode(base64_data).ok()?;
+    convert_image(&data, max_dimension, format)
+}
+
+#[cfg(feature = "image-processing")]
+mod processing {
+    use super::{ConvertedImage, ImageFormat};
+    use image::codecs::jpeg::JpegEncoder;
+    use image::codecs::png::PngEncoder;
+    use image::codecs::webp::WebPEncoder;
+    use image::imageops::FilterType;
+    use image::{DynamicImage, ImageFormat as SourceFormat};
+
+    pub(super) fn convert(
+        data: &[u8],
+        max_dimension: Option<u32>,
+        format: ImageFormat,
+    ) -> Option<ConvertedImage> {
+        let source = image::guess_format(data).ok()?;
+        let mut picture = image::load_from_memory_with_format(data, source).ok()?;
+
+        let resize =
+            max_dimension.filter(|&max| max > 0 && picture.width().max(picture.height()) > max);
+        if let Some(max) = resize {
+            picture = picture.resize(max, max, FilterType::CatmullRom);
+        }
+
+        let target = match format {
+            ImageFormat::Keep => match source {
+                SourceFormat::Jpeg => ImageFormat::Jpeg {
+                    quality: ImageFormat::DEFAULT_JPEG_QUALITY,
+                },
+                SourceFormat::WebP => ImageFormat::Webp,
+                _ => ImageFormat::Png,
+            },
+            format => format,
+        };
+        let unchanged = matches!(
+            (source, target),
+            (SourceFormat::Png, ImageFormat::Png) | (SourceFormat::WebP, ImageFormat::Webp)
+        );
+        if resize.is_none() && unchanged {
+            return None;
+        }
+        encode(&picture, target)
+    }
+
+    fn encode(picture: &DynamicImage, format: ImageFormat) -> Option<ConvertedImage> {
+        let mut data = Vec::new();
+        let mime_type = match format {
+            ImageFormat::Keep | ImageFormat::Png => {
+                picture
+                    .write_with_encoder(PngEncoder::new(&mut data))
+                    .ok()?;
+                "image/png"
+            }
+            ImageFormat::Jpeg { quality } => {
+                // JPEG에는 알파 채널이 없음 / JPEG has no alpha channel
+                DynamicImage::ImageRgb8(picture.to_rgb8())
+                    .write_with_encoder(JpegEncoder::new_with_quality(&mut data, quality))
+                    .ok()?;
+                "image/jpeg"
+            }
+            ImageFormat::Webp => {
+                DynamicImage::ImageRgba8(picture.to_rgba8())
+                    .write_with_encoder(WebPEncoder::new_lossless(&mut data))
+                    .ok()?;
+                "image/webp"
+            }
+        };
+        Some(ConvertedImage { data, mime_type })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use image::{ImageBuffer, Rgb};
+
+        fn png(width: u32, height: u32) -> Vec<u8> {
+            let picture = ImageBuffer::from_pixel(width, height, Rgb([200u8, 30, 30]));
+            let mut data = Vec::new();
+            DynamicImage::ImageRgb8(picture)
+                .write_with_encoder(PngEncoder::new(&mut data))
+                .unwrap();
+            data
+        }
+
+        #[test]
+        fn test_convert_downscales_and_recompresses() {
+            let data = png(400, 100);
+            assert_eq!(convert(&data, Some(800), ImageFormat::Keep), None);
+
+            let converted = convert(&data, Some(40), ImageFormat::Keep).unwrap();
+            assert_eq!(converted.mime_type, "image/png");
+            let picture = image::load_from_memory(&converted.data).unwrap();
+            assert_eq!((picture.width(), picture.height()), (40, 10));
+
+            let converted = convert(&data, None, ImageFormat::Jpeg { quality: 50 }).unwrap();
+            assert_eq!(converted.mime_type, "image/jpeg");
+            assert!(converted.data.starts_with(&[0xFF, 0xD8, 0xFF]));
+
+            let converted = convert(&data, None, ImageFormat::Webp).unwrap();
+            assert_eq!(converted.mime_type, "image/webp");
+
+            // 읽을 수 없는 그림은 그대로 / Undecodable pictures are left alone
+            assert_eq!(convert(b"not a picture", Some(10), ImageFormat::Png), None);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! 내보내기 그림 저장소 모듈 / Export image sink module
+//!
+//! 마크다운/HTML로 내보낼 때 그림 바이트를 어디에 둘지 정합니다. 뷰어는 파일 이름과 바이트를
+//! `ImageSink`에 넘기고, 돌려받은 URL을 출력에 씁니다. 객체 저장소(S3 등)나 메모리에 두려면
+//! 직접 구현해 옵션의 `image_sink`로 넘기면 되고, `image_output_dir`은 `FileImageSink`를
+//! 쓰는 것과 같습니다.
+//!
+//! Decides where picture bytes go when exporting to markdown/HTML. The viewers hand the file
+//! name and bytes to an `ImageSink` and write the URL it returns into the output. To keep
+//! pictures in object storage (S3, ...) or in memory, implement the trait and pass it as the
+//! options' `image_sink`; `image_output_dir` is the same as using a `FileImageSink`.
+
+use crate::error::HwpError;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+/// 내보낸 그림을 받아 URL을 돌려주는 저장소 / Sink that stores exported pictures and returns their URL
+pub trait ImageSink: std::fmt::Debug + Send + Sync {
+    /// 그림 저장 / Save a picture
+    ///
+    /// 같은 그림이 여러 번 나오는 문서에서는 같은 이름으로 여러 번 불릴 수 있습니다.
+    /// May be called several times with the same name when a document repeats a picture.
+    ///
+    /// # Arguments / 매개변수
+    /// * `name` - 파일 이름 (예: "BIN0001.png") / File name (e.g. "BIN0001.png")
+    /// * `data` - 그림 바이트 / Picture bytes
+    ///
+    /// # Returns / 반환값
+    /// 출력에 넣을 URL. 실패하면 뷰어는 데이터 URI로 넣습니다.
+    /// URL to put in the output. On failure the viewers embed a data URI instead.
+    fn save(&self, name: &str, data: &[u8]) -> Result<String, HwpError>;
+}
+
+/// 디렉토리에 파일로 저장하는 그림 저장소 / Image sink that writes files into a directory
+#[cfg(feature = "fs")]
+#[derive(Debug, Clone)]
+pub struct FileImageSink {
+    dir: PathBuf,
+    url_prefix: String,
+}
+
+#[cfg(feature = "fs")]
+impl FileImageSink {
+    /// 디렉토리로 저장소 만들기 (없으면 처음 저장할 때 생성) / Create a sink for a directory (created on the first save)
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            url_prefix: String::new(),
+        }
+    }
+
+    /// 파일 이름 앞에 붙일 URL 접두사 설정 (기본값: 없음) / Set the URL prefix put before file names (default: none)
+    pub fn with_url_prefix(mut self, url_prefix: impl Into<String>) -> Self {
+        self.url_prefix = url_prefix.into();
+        self
+    }
+}
+
+#[cfg(feature = "fs")]
+impl ImageSink for FileImageSink {
+    fn save(&self, name: &str, data: &[u8]) -> Result<String, HwpError> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| {
+            HwpError::Io(format!(
+                "Failed to create directory '{}': {e}",
+                self.dir.display()
+            ))
+        })?;
+        // 같은 내용이면 건너뜀 / Skipped when the content is unchanged
+        crate::document::images::write_if_changed(&self.dir.join(name), data)?;
+        Ok(format!("{}{name}", self.url_prefix))
+    }
+}
@@ -2,146 +2,261 @@
 ///
 /// 마크다운 변환에 사용되는 공통 함수들을 제공합니다.
 /// Provides common functions used in markdown conversion.
+use crate::document::images::{extension_for_mime, sniff_base64_mime_type};
 use crate::document::{BinDataRecord, HwpDocument};
 use crate::error::HwpError;
+use crate::viewer::image_export::{convert_base64_image, ConvertedImage};
+use crate::viewer::markdown::MarkdownOptions;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
-use std::fs;
-use std::path::Path;
-
-/// Detect MIME type from base64 encoded image data using magic bytes
-/// base64 인코딩된 이미지 데이터의 매직 바이트로 MIME 타입 감지
-///
-/// Magic bytes (base64 encoded):
-/// - PNG: starts with "iVBORw" (0x89 0x50 0x4E 0x47)
-/// - JPEG: starts with "/9j/" (0xFF 0xD8 0xFF)
-/// - BMP: starts with "Qk" (0x42 0x4D = "BM")
-pub(crate) fn detect_mime_type_from_base64(base64_data: &str) -> &'static str {
-    if base64_data.starts_with("iVBORw") {
-        "image/png"
-    } else if base64_data.starts_with("/9j/") {
-        "image/jpeg"
-    } else if base64_data.starts_with("Qk") {
-        "image/bmp"
-    } else {
-        "application/octet-stream"
-    }
-}
-
-/// Get MIME type from BinData ID using bin_data_records
-/// bin_data_records를 사용하여 BinData ID에서 MIME 타입 가져오기
-pub(crate) fn get_mime_type_from_bindata_id(
-    document: &HwpDocument,
-    bindata_id: crate::types::WORD,
-) -> String {
-    // bin_data_records에서 EMBEDDING 타입의 extension 찾기 / Find extension from EMBEDDING type in bin_data_records
-    for record in &document.doc_info.bin_data {
-        if let BinDataRecord::Embedding { embedding, .. } = record {
-            if embedding.binary_data_id == bindata_id {
-                return match embedding.extension.to_lowercase().as_str() {
-                    "jpg" | "jpeg" => "image/jpeg",
-                    "png" => "image/png",
-                    "gif" => "image/gif",
-                    "bmp" => "image/bmp",
-                    _ => "image/jpeg", // 기본값 / default
-                }
-                .to_string();
-            }
-        }
-    }
-    // 기본값 / default
-    "image/jpeg".to_string()
-}
 
 /// Get file extension from BinData ID using bin_data_records
 /// bin_data_records를 사용하여 BinData ID에서 파일 확장자 가져오기
+///
+/// 레코드에 확장자가 없으면(HWPX) 내용의 매직 바이트로 정합니다.
+/// Falls back to the payload's magic bytes when no record has an extension (HWPX).
 pub(crate) fn get_extension_from_bindata_id(
     document: &HwpDocument,
     bindata_id: crate::types::WORD,
+    base64_data: &str,
 ) -> String {
     // bin_data_records에서 EMBEDDING 타입의 extension 찾기 / Find extension from EMBEDDING type in bin_data_records
     for record in &document.doc_info.bin_data {
         if let BinDataRecord::Embedding { embedding, .. } = record {
-            if embedding.binary_data_id == bindata_id {
+            if embedding.binary_data_id == bindata_id && !embedding.extension.is_empty() {
                 return embedding.extension.clone();
             }
         }
     }
-    // 기본값 / default
-    "jpg".to_string()
+    extension_for_mime(sniff_base64_mime_type(base64_data)).to_string()
 }
 
 /// Format image markdown - either as base64 data URI or file path
 /// 이미지 마크다운 포맷 - base64 데이터 URI 또는 파일 경로
+///
+/// 그림이 아닌 바이너리(OLE 개체 등)는 깨진 data URI 대신 첨부 파일 링크(저장한 경우)나
+/// 자리 표시 텍스트로 나타냅니다.
+/// Non-image binaries (OLE objects, etc.) become an attachment link when saved, or placeholder
+/// text otherwise, instead of a broken data URI.
+///
+/// 그림은 `image_max_dimension`/`image_format` 옵션에 따라 줄이거나 다시 압축하고, 설명문이나
+/// 캡션이 있으면 대체 텍스트로 씁니다.
+/// Pictures are downscaled or recompressed according to the `image_max_dimension` /
+/// `image_format` options, and use their description or caption as the alt text.
 pub(crate) fn format_image_markdown(
     document: &HwpDocument,
     bindata_id: crate::types::WORD,
     base64_data: &str,
-    image_output_dir: Option<&str>,
+    options: &MarkdownOptions,
 ) -> String {
-    match image_output_dir {
-        Some(dir_path) => {
-            // 이미지를 파일로 저장하고 파일 경로를 마크다운에 포함 / Save image as file and include file path in markdown
-            match save_image_to_file(document, bindata_id, base64_data, dir_path) {
-                Ok(file_path) => {
-                    // 상대 경로로 변환 (images/ 디렉토리 포함) / Convert to relative path (include images/ directory)
-                    let file_path_obj = Path::new(&file_path);
-                    let file_name = file_path_obj
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or(&file_path);
-                    // images/ 디렉토리 경로 포함 / Include images/ directory path
-                    format!("![이미지](images/{file_name})")
+    // 매직 바이트로 실제 MIME 타입 감지 (HWPX 등에서 확장자 정보가 없을 때 정확한 MIME 타입 사용)
+    // Detect actual MIME type from magic bytes (use accurate MIME type when extension info is missing in HWPX, etc.)
+    let mime_type = sniff_base64_mime_type(base64_data);
+    let is_image = mime_type.starts_with("image/");
+    let alt = image_alt_text(document, bindata_id);
+    // 내용이 같은 그림은 한 파일/한 data URI를 함께 씀 / Identical pictures share one file / one data URI
+    let bindata_id = document.canonical_bin_data_index(bindata_id);
+    let name_index = name_index(document, bindata_id, options);
+    let converted = if is_image {
+        convert_base64_image(
+            base64_data,
+            options.image_max_dimension,
+            options.image_format,
+        )
+    } else {
+        None
+    };
+
+    match options.resolved_image_sink() {
+        Some(sink) => {
+            // 저장소에 넘기고 돌려받은 URL을 마크다운에 포함 / Hand to the sink and include the returned URL in markdown
+            let saved = image_file(
+                document,
+                (bindata_id, name_index),
+                base64_data,
+                converted.as_ref(),
+            )
+            .and_then(|(file_name, data)| Ok((sink.save(&file_name, &data)?, file_name)));
+            match saved {
+                Ok((url, file_name)) => {
+                    if is_image {
+                        format!("![{alt}]({url})")
+                    } else {
+                        format!("[첨부 파일: {file_name}]({url})")
+                    }
                 }
                 Err(e) => {
                     eprintln!("Failed to save image: {e}");
                     // 실패 시 base64로 폴백 / Fallback to base64 on failure
-                    let mime_type = detect_mime_type_from_base64(base64_data);
-                    format!("![이미지](data:{mime_type};base64,{base64_data})")
+                    embedded_markdown(
+                        document,
+                        (bindata_id, name_index),
+                        base64_data,
+                        converted,
+                        mime_type,
+                        &alt,
+                    )
                 }
             }
         }
-        None => {
-            // base64 데이터 URI로 임베드 / Embed as base64 data URI
-            // 매직 바이트로 실제 MIME 타입 감지 (HWPX 등에서 확장자 정보가 없을 때 정확한 MIME 타입 사용)
-            // Detect actual MIME type from magic bytes (use accurate MIME type when extension info is missing in HWPX, etc.)
-            let mime_type = detect_mime_type_from_base64(base64_data);
-            format!("![이미지](data:{mime_type};base64,{base64_data})")
-        }
+        // base64 데이터 URI로 임베드 / Embed as base64 data URI
+        None => embedded_markdown(
+            document,
+            (bindata_id, name_index),
+            base64_data,
+            converted,
+            mime_type,
+            &alt,
+        ),
     }
 }
 
-/// Save image to file from base64 data
-/// base64 데이터에서 이미지를 파일로 저장
-fn save_image_to_file(
+/// 파일로 저장하지 않을 때의 마크다운 (그림은 data URI, 그 외는 자리 표시)
+/// Markdown when nothing is saved (data URI for pictures, a placeholder otherwise)
+fn embedded_markdown(
     document: &HwpDocument,
-    bindata_id: crate::types::WORD,
+    (bindata_id, name_index): (crate::types::WORD, crate::types::WORD),
     base64_data: &str,
-    dir_path: &str,
-) -> Result<String, HwpError> {
-    // base64 디코딩 / Decode base64
-    let image_data = STANDARD
-        .decode(base64_data)
-        .map_err(|e| HwpError::InternalError {
-            message: format!("Failed to decode base64: {e}"),
-        })?;
+    converted: Option<ConvertedImage>,
+    mime_type: &str,
+    alt: &str,
+) -> String {
+    if mime_type.starts_with("image/") && is_shared(document, base64_data) {
+        // 정의는 문서 끝에 한 번 / The definition is written once at the end of the document
+        format!("![{alt}][BIN{name_index:04X}]")
+    } else if mime_type.starts_with("image/") {
+        format!(
+            "![{alt}]({})",
+            image_data_uri(base64_data, converted, mime_type)
+        )
+    } else {
+        let extension = get_extension_from_bindata_id(document, bindata_id, base64_data);
+        format!("[첨부 파일: BIN{name_index:04X}.{extension} ({mime_type})]")
+    }
+}
 
-    // 파일명 생성 / Generate filename
-    let extension = get_extension_from_bindata_id(document, bindata_id);
-    let file_name = format!("BIN{bindata_id:04X}.{extension}");
-    let file_path = Path::new(dir_path).join(&file_name);
+/// 파일 이름과 참조 라벨에 쓸 번호 (결정적 출력이면 BinData 이름순 번호)
+/// Number used in file names and reference labels (BinData name order in deterministic output)
+fn name_index(
+    document: &HwpDocument,
+    bindata_id: crate::types::WORD,
+    options: &MarkdownOptions,
+) -> crate::types::WORD {
+    if options.deterministic {
+        document.stable_bin_data_index(bindata_id)
+    } else {
+        bindata_id
+    }
+}
+
+/// 마크다운 대체 텍스트 (대괄호는 이스케이프) / Markdown alt text (square brackets escaped)
+fn image_alt_text(document: &HwpDocument, bindata_id: crate::types::WORD) -> String {
+    document
+        .image_label(bindata_id)
+        .alt_text()
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// 그림 data URI (변환한 그림이 있으면 그것을 씀) / Picture data URI (uses the converted picture if any)
+fn image_data_uri(base64_data: &str, converted: Option<ConvertedImage>, mime_type: &str) -> String {
+    match converted {
+        Some(converted) => format!(
+            "data:{};base64,{}",
+            converted.mime_type,
+            STANDARD.encode(&converted.data)
+        ),
+        None => format!("data:{mime_type};base64,{base64_data}"),
+    }
+}
 
-    // 디렉토리 생성 / Create directory
-    fs::create_dir_all(dir_path)
-        .map_err(|e| HwpError::Io(format!("Failed to create directory '{dir_path}': {e}")))?;
+/// 내용이 같은 BinData 아이템이 둘 이상인지 확인 / Whether two or more BinData items have this content
+fn is_shared(document: &HwpDocument, base64_data: &str) -> bool {
+    document
+        .bin_data
+        .items
+        .iter()
+        .filter(|item| item.data == base64_data)
+        .nth(1)
+        .is_some()
+}
 
-    // 파일 저장 / Save file
-    fs::write(&file_path, &image_data).map_err(|e| {
-        HwpError::Io(format!(
-            "Failed to write file '{}': {}",
-            file_path.display(),
-            e
-        ))
-    })?;
+/// 여러 번 쓰인 그림의 data URI 참조 정의 / Reference definitions for data URIs of pictures used more than once
+///
+/// 파일로 저장하지 않을 때 `format_image_markdown`이 만든 `![...][BINxxxx]` 참조를 풉니다.
+/// Resolves the `![...][BINxxxx]` references produced by `format_image_markdown` when
+/// nothing is saved to files.
+pub(crate) fn shared_image_definitions(
+    document: &HwpDocument,
+    options: &MarkdownOptions,
+) -> Vec<String> {
+    let mut definitions: Vec<(crate::types::WORD, String)> = document
+        .duplicate_bin_data()
+        .into_iter()
+        .filter_map(|group| {
+            let index = group[0];
+            let item = document
+                .bin_data
+                .items
+                .iter()
+                .find(|item| item.index == index)?;
+            let mime_type = sniff_base64_mime_type(&item.data);
+            if !mime_type.starts_with("image/") {
+                return None;
+            }
+            let converted = convert_base64_image(
+                &item.data,
+                options.image_max_dimension,
+                options.image_format,
+            );
+            let label = name_index(document, index, options);
+            Some((
+                label,
+                format!(
+                    "[BIN{label:04X}]: {}",
+                    image_data_uri(&item.data, converted, mime_type)
+                ),
+            ))
+        })
+        .collect();
+    // 라벨 순서로 (결정적 출력이면 이름순 번호가 인덱스 순서와 다를 수 있음)
+    // In label order (name order can differ from index order in deterministic output)
+    definitions.sort_by_key(|(label, _)| *label);
+    definitions
+        .into_iter()
+        .map(|(_, definition)| definition)
+        .collect()
+}
 
-    Ok(file_path.to_string_lossy().to_string())
+/// File name and bytes of a picture to hand to an image sink
+/// 그림 저장소에 넘길 그림의 파일 이름과 바이트
+///
+/// 변환한 그림이 있으면 그 바이트와 형식의 확장자를 씁니다.
+/// Uses the converted picture's bytes and its format's extension when there is one.
+/// 데이터는 `bindata_id`에서 찾고, 파일 이름은 `name_index`로 짓습니다.
+/// Data is looked up by `bindata_id`; the file is named after `name_index`.
+fn image_file(
+    document: &HwpDocument,
+    (bindata_id, name_index): (crate::types::WORD, crate::types::WORD),
+    base64_data: &str,
+    converted: Option<&ConvertedImage>,
+) -> Result<(String, Vec<u8>), HwpError> {
+    let (image_data, extension) = match converted {
+        Some(converted) => (
+            converted.data.clone(),
+            extension_for_mime(converted.mime_type).to_string(),
+        ),
+        None => {
+            // base64 디코딩 / Decode base64
+            let image_data = STANDARD
+                .decode(base64_data)
+                .map_err(|e| HwpError::InternalError {
+                    message: format!("Failed to decode base64: {e}"),
+                })?;
+            let extension = get_extension_from_bindata_id(document, bindata_id, base64_data);
+            (image_data, extension)
+        }
+    };
+
+    // 파일명 생성 / Generate filename
+    Ok((format!("BIN{name_index:04X}.{extension}"), image_data))
 }
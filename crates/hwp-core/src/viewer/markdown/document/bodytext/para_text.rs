@@ -5,6 +5,8 @@
 /// Spec mapping: Table 57 - BodyText data records, PARA_TEXT (HWPTAG_BEGIN + 51)
 use crate::document::bodytext::{CharShapeInfo, ControlChar, ControlCharPosition};
 use crate::document::CharShape;
+use crate::viewer::markdown::utils::{break_text, inline_controls_text, insert_breaks};
+use crate::viewer::markdown::{LineBreakMode, MarkdownFlavor, MarkdownOptions};
 
 /// 의미 있는 텍스트인지 확인합니다. / Check if text is meaningful.
 ///
@@ -51,6 +53,9 @@ fn convert_text_with_char_shapes<'a>(
     control_positions: &[ControlCharPosition],
     char_shapes: &[CharShapeInfo],
     get_char_shape: &'a dyn Fn(u32) -> Option<&'a CharShape>,
+    use_html: bool,
+    line_break_mode: Option<LineBreakMode>,
+    flavor: Option<MarkdownFlavor>,
 ) -> Option<String> {
     if text.trim().is_empty() {
         return None;
@@ -102,71 +107,45 @@ fn convert_text_with_char_shapes<'a>(
     }
 
     // PARA_BREAK/LINE_BREAK 위치 수집 / Collect PARA_BREAK/LINE_BREAK positions
-    let mut break_positions: Vec<usize> = control_positions
+    let mut break_positions: Vec<(usize, u8)> = control_positions
         .iter()
         .filter(|pos| pos.code == ControlChar::PARA_BREAK || pos.code == ControlChar::LINE_BREAK)
-        .map(|pos| pos.position)
+        .map(|pos| (pos.position, pos.code))
         .collect();
     break_positions.sort();
 
-    // 각 구간에 스타일 적용하여 결과 생성 / Generate result by applying styles to each segment
+    // 구간을 개행 위치로 나누어 스타일별 조각으로 모음
+    // Split segments at breaks into styled pieces
+    let mut pieces: Vec<Piece> = Vec::new();
     for (start, end, char_shape) in &segments {
-        if *start < *end && *end <= text_len {
-            // 이 구간 내에 PARA_BREAK/LINE_BREAK가 있는지 확인 / Check if there are breaks in this segment
-            let mut segment_breaks: Vec<usize> = break_positions
-                .iter()
-                .filter(|&&break_pos| break_pos >= *start && break_pos < *end)
-                .copied()
-                .collect();
-
-            if segment_breaks.is_empty() {
-                // 구간 내에 break가 없으면 전체 구간에 스타일 적용 / No breaks in segment, apply style to entire segment
-                let segment_text: String = text_chars[*start..*end].iter().collect();
-                if !segment_text.trim().is_empty() {
-                    if let Some(shape) = char_shape {
-                        let styled = apply_markdown_styles(
-                            &segment_text,
-                            shape.attributes.bold,
-                            shape.attributes.italic,
-                            shape.attributes.strikethrough != 0,
-                        );
-                        result.push_str(&styled);
-                    } else {
-                        result.push_str(&segment_text);
-                    }
-                }
-            } else {
-                // 구간 내에 break가 있으면 break 위치로 나누어 처리 / Split segment by breaks
-                segment_breaks.insert(0, *start);
-                segment_breaks.push(*end);
-
-                for i in 0..segment_breaks.len() - 1 {
-                    let seg_start = segment_breaks[i];
-                    let seg_end = segment_breaks[i + 1];
-
-                    if seg_start < seg_end && seg_end <= text_len {
-                        let segment_text: String = text_chars[seg_start..seg_end].iter().collect();
-                        if !segment_text.trim().is_empty() {
-                            if let Some(shape) = char_shape {
-                                let styled = apply_markdown_styles(
-                                    &segment_text,
-                                    shape.attributes.bold,
-                                    shape.attributes.italic,
-                                    shape.attributes.strikethrough != 0,
-                                );
-                                result.push_str(&styled);
-                            } else {
-                                result.push_str(&segment_text);
-                            }
-                        }
-                    }
-
-                    // break 다음이면 마크다운 개행 추가 / Add markdown line break after break
-                    if i < segment_breaks.len() - 2 {
-                        result.push_str("  \n");
-                    }
-                }
-            }
+        if *start >= *end || *end > text_len {
+            continue;
+        }
+        let style = char_shape
+            .map(|shape| RunStyle::from_char_shape(shape, use_html))
+            .unwrap_or_default();
+        let mut piece_start = *start;
+        for &(break_pos, code) in break_positions
+            .iter()
+            .filter(|&&(break_pos, _)| break_pos >= *start && break_pos < *end)
+        {
+            push_piece(&mut pieces, style, &text_chars[piece_start..break_pos]);
+            pieces.push(Piece::Break(code));
+            piece_start = break_pos;
+        }
+        push_piece(&mut pieces, style, &text_chars[piece_start..*end]);
+    }
+
+    // 각 조각에 스타일 적용, 개행은 줄 나눔 방식에 따라 (기본값: 마크다운 개행)
+    // Apply styles to each piece, breaks follow the line break mode (default: markdown line break)
+    for piece in &pieces {
+        match piece {
+            Piece::Run(style, text) => result.push_str(&style.apply(text, use_html)),
+            Piece::Break(code) => result.push_str(
+                line_break_mode.map_or(flavor.map_or("  \n", MarkdownFlavor::hard_break), |mode| {
+                    break_text(mode, *code, false, flavor)
+                }),
+            ),
         }
     }
 
@@ -178,49 +157,110 @@ fn convert_text_with_char_shapes<'a>(
     }
 }
 
-/// 텍스트에 마크다운 스타일을 적용합니다. / Apply markdown styles to text.
-///
-/// # Arguments / 매개변수
-/// * `text` - 원본 텍스트 / Original text
-/// * `bold` - 진하게 여부 / Bold
-/// * `italic` - 기울임 여부 / Italic
-/// * `strikethrough` - 가운뎃줄 여부 / Strikethrough
-///
-/// # Returns / 반환값
-/// 마크다운 스타일이 적용된 텍스트 / Text with markdown styles applied
-fn apply_markdown_styles(text: &str, bold: bool, italic: bool, strikethrough: bool) -> String {
-    if text.is_empty() {
-        return String::new();
-    }
-
-    let mut result = String::from(text);
-
-    // 마크다운 스타일 적용 순서: strikethrough (가장 바깥) -> bold -> italic (가장 안쪽)
-    // Markdown style application order: strikethrough (outermost) -> bold -> italic (innermost)
-
-    // 기울임 적용 (가장 안쪽) / Apply italic (innermost)
-    if italic {
-        result = format!("*{result}*");
-    }
+/// 글자 모양에서 마크다운으로 옮길 수 있는 강조 / Emphasis from a char shape that markdown can express
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct RunStyle {
+    bold: bool,
+    italic: bool,
+    /// 글자 아래 밑줄만 해당하며 `use_html`일 때만 `<u>`로 표시
+    /// Below-text underline only, rendered as `<u>` only with `use_html`
+    underline: bool,
+    strikethrough: bool,
+    superscript: bool,
+    subscript: bool,
+}
 
-    // 진하게 적용 / Apply bold
-    if bold {
-        result = format!("**{result}**");
+impl RunStyle {
+    fn from_char_shape(shape: &CharShape, use_html: bool) -> Self {
+        RunStyle {
+            bold: shape.attributes.bold,
+            italic: shape.attributes.italic,
+            // 밑줄 종류 2(가운데)와 3(위)은 취소선/윗줄이므로 제외
+            // Underline types 2 (center) and 3 (above) are strike/overlines, so they are skipped
+            underline: use_html && shape.attributes.underline_type == 1,
+            strikethrough: shape.attributes.strikethrough != 0,
+            superscript: shape.is_superscript(),
+            subscript: shape.is_subscript(),
+        }
     }
 
-    // 가운뎃줄 적용 (가장 바깥쪽) / Apply strikethrough (outermost)
-    if strikethrough {
-        result = format!("~~{result}~~");
+    /// 텍스트에 마크다운 스타일 적용 / Apply markdown styles to text
+    ///
+    /// 앞뒤 공백은 표시 밖으로 빼야 `**굵게 **`처럼 강조가 깨지지 않습니다.
+    /// Leading and trailing whitespace is kept outside the markers, otherwise emphasis such as
+    /// `**bold **` does not render.
+    ///
+    /// 첨자는 `use_html`이면 `<sup>`/`<sub>`, 아니면 `^위^`/`~아래~` (Pandoc 표기)로 표시합니다.
+    /// Sub/superscripts become `<sup>`/`<sub>` with `use_html`, otherwise `^sup^`/`~sub~`
+    /// (Pandoc syntax).
+    fn apply(&self, text: &str, use_html: bool) -> String {
+        let trimmed = text.trim();
+        if trimmed.is_empty() || *self == RunStyle::default() {
+            return text.to_string();
+        }
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        // 적용 순서: 첨자 (가장 안쪽) -> 기울임 -> 진하게 -> 밑줄 -> 가운뎃줄 (가장 바깥)
+        // Application order: sub/superscript (innermost) -> italic -> bold -> underline -> strikethrough (outermost)
+        let mut result = trimmed.to_string();
+        if self.superscript {
+            result = if use_html {
+                format!("<sup>{result}</sup>")
+            } else {
+                format!("^{result}^")
+            };
+        } else if self.subscript {
+            result = if use_html {
+                format!("<sub>{result}</sub>")
+            } else {
+                format!("~{result}~")
+            };
+        }
+        if self.italic {
+            result = format!("*{result}*");
+        }
+        if self.bold {
+            result = format!("**{result}**");
+        }
+        if self.underline {
+            result = format!("<u>{result}</u>");
+        }
+        if self.strikethrough {
+            result = format!("~~{result}~~");
+        }
+        format!("{leading}{result}{trailing}")
     }
+}
 
-    result
+/// 스타일이 같은 텍스트 조각 또는 개행 / A run of same-styled text or a break
+enum Piece {
+    Run(RunStyle, String),
+    /// LINE_BREAK 또는 PARA_BREAK 코드 / LINE_BREAK or PARA_BREAK code
+    Break(u8),
 }
 
-pub fn convert_para_text_to_markdown(
-    text: &str,
-    control_positions: &[ControlCharPosition],
-) -> Option<String> {
-    convert_para_text_to_markdown_with_char_shapes(text, control_positions, &[], None)
+/// 조각 추가 (같은 스타일의 이웃 조각과 합쳐 `**a****b**`를 피함)
+/// Push a piece, merging with a same-styled neighbour to avoid `**a****b**`
+///
+/// 공백만 있는 조각은 스타일과 상관없이 앞 조각에 붙입니다 (공백은 `apply`에서 표시 밖으로 빠짐).
+/// Whitespace-only pieces join the previous piece whatever its style (`apply` moves the
+/// whitespace outside the markers).
+fn push_piece(pieces: &mut Vec<Piece>, style: RunStyle, chars: &[char]) {
+    if chars.is_empty() {
+        return;
+    }
+    let text: String = chars.iter().collect();
+    let blank = text.trim().is_empty();
+    match pieces.last_mut() {
+        Some(Piece::Run(last_style, last_text)) if blank || *last_style == style => {
+            last_text.push_str(&text)
+        }
+        _ => pieces.push(Piece::Run(
+            if blank { RunStyle::default() } else { style },
+            text,
+        )),
+    }
 }
 
 /// CharShape 정보를 사용하여 ParaText를 마크다운으로 변환
@@ -231,6 +271,8 @@ pub fn convert_para_text_to_markdown(
 /// * `control_positions` - 제어 문자 위치 정보 / Control character positions
 /// * `char_shapes` - 글자 모양 정보 리스트 / Character shape information list
 /// * `get_char_shape` - shape_id로 CharShape를 가져오는 함수 / Function to get CharShape by shape_id
+/// * `options` - 마크다운 변환 옵션 (`use_html`이면 밑줄을 `<u>`로 표시, `line_break_mode`로 개행 처리)
+///   / Markdown options (`use_html` renders underline as `<u>`, `line_break_mode` decides breaks)
 ///
 /// # Returns / 반환값
 /// 마크다운 문자열 / Markdown string
@@ -239,15 +281,36 @@ pub fn convert_para_text_to_markdown_with_char_shapes<'a>(
     control_positions: &[ControlCharPosition],
     char_shapes: &[CharShapeInfo],
     get_char_shape: Option<&'a dyn Fn(u32) -> Option<&'a CharShape>>,
+    options: &MarkdownOptions,
 ) -> Option<String> {
     // CharShape 정보가 있으면 텍스트를 구간별로 나누어 스타일 적용 / If CharShape info exists, divide text into segments and apply styles
-    if !char_shapes.is_empty() && get_char_shape.is_some() {
-        return convert_text_with_char_shapes(
+    let markdown = if !char_shapes.is_empty() && get_char_shape.is_some() {
+        convert_text_with_char_shapes(
             text,
             control_positions,
             char_shapes,
             get_char_shape.unwrap(),
-        );
+            options.html_enabled(),
+            options.line_break_mode,
+            options.flavor,
+        )
+    } else {
+        convert_plain_para_text(text, control_positions, options)
+    };
+    // 글자 위치가 어긋나지 않도록 인라인 제어 문자는 마지막에 바꿈 / Inline controls are replaced last so character positions stay aligned
+    markdown.map(|markdown| inline_controls_text(&markdown, options).into_owned())
+}
+
+/// 글자 모양 없이 ParaText를 마크다운으로 변환 / Convert ParaText to markdown without char shapes
+fn convert_plain_para_text(
+    text: &str,
+    control_positions: &[ControlCharPosition],
+    options: &MarkdownOptions,
+) -> Option<String> {
+    if let Some(mode) = options.line_break_mode {
+        let result = insert_breaks(text, control_positions, mode, false, options.flavor);
+        let trimmed = result.trim();
+        return (!trimmed.is_empty()).then(|| trimmed.to_string());
     }
 
     // PARA_BREAK나 LINE_BREAK가 있는지 확인 / Check for PARA_BREAK or LINE_BREAK
@@ -302,7 +365,7 @@ pub fn convert_para_text_to_markdown_with_char_shapes<'a>(
 
         // PARA_BREAK나 LINE_BREAK를 마크다운 개행(스페이스 2개 + 개행)으로 변환
         // Convert PARA_BREAK or LINE_BREAK to markdown line break (two spaces + newline)
-        result.push_str("  \n");
+        result.push_str(options.hard_break());
 
         // 제어 문자 다음 위치 / Position after control character
         last_char_pos = pos.position + 1;
@@ -327,3 +390,109 @@ pub fn convert_para_text_to_markdown_with_char_shapes<'a>(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(use_html: bool, line_break_mode: Option<LineBreakMode>) -> MarkdownOptions {
+        MarkdownOptions {
+            image_output_dir: None,
+            image_sink: None,
+            use_html: Some(use_html),
+            include_version: None,
+            include_page_info: None,
+            include_references: None,
+            include_toc: None,
+            line_break_mode,
+            preserve_alignment: None,
+            inline_controls: Default::default(),
+            image_max_dimension: None,
+            image_format: None,
+            flavor: None,
+            include_source_anchors: None,
+            strip_page_fields: None,
+            content_filter: None,
+            deterministic: false,
+        }
+    }
+
+    fn break_at(position: usize, code: u8) -> ControlCharPosition {
+        ControlCharPosition {
+            position,
+            code,
+            name: ControlChar::to_name(code),
+        }
+    }
+
+    #[test]
+    fn test_runs_keep_spaces_outside_markers_and_underline_needs_html() {
+        let plain = CharShape::parse(&[0u8; 72], 0).unwrap();
+        let mut bold = plain.clone();
+        bold.attributes.bold = true;
+        let mut underline = plain.clone();
+        underline.attributes.underline_type = 1;
+        let shapes = [plain, bold, underline];
+        let get_char_shape = |id: u32| shapes.get(id as usize);
+        let runs = [(0, 0), (2, 1), (6, 0), (7, 1), (9, 0), (10, 2)]
+            .map(|(position, shape_id)| CharShapeInfo { position, shape_id });
+
+        // "가 " + "굵게 " (굵게) + " " + "표시" (굵게) + " " + "밑줄"
+        let text = "가 굵게   표시 밑줄";
+        let convert = |use_html| {
+            convert_para_text_to_markdown_with_char_shapes(
+                text,
+                &[],
+                &runs,
+                Some(&get_char_shape),
+                &options(use_html, None),
+            )
+        };
+        assert_eq!(convert(false).unwrap(), "가 **굵게   표시** 밑줄");
+        assert_eq!(convert(true).unwrap(), "가 **굵게   표시** <u>밑줄</u>");
+    }
+
+    #[test]
+    fn test_line_break_mode_applies_with_and_without_char_shapes() {
+        let plain = CharShape::parse(&[0u8; 72], 0).unwrap();
+        let shapes = [plain];
+        let get_char_shape = |id: u32| shapes.get(id as usize);
+        let runs = [CharShapeInfo {
+            position: 0,
+            shape_id: 0,
+        }];
+
+        // "첫 줄" LINE_BREAK "둘째 줄" PARA_BREAK "셋째 줄" PARA_BREAK (문단 끝 / paragraph end)
+        let text = "첫 줄둘째 줄셋째 줄";
+        let breaks = [
+            break_at(3, ControlChar::LINE_BREAK),
+            break_at(7, ControlChar::PARA_BREAK),
+            break_at(11, ControlChar::PARA_BREAK),
+        ];
+        for char_shapes in [&runs[..], &[]] {
+            let convert = |mode| {
+                convert_para_text_to_markdown_with_char_shapes(
+                    text,
+                    &breaks,
+                    char_shapes,
+                    Some(&get_char_shape),
+                    &options(false, mode),
+                )
+                .unwrap()
+            };
+            assert_eq!(convert(Some(LineBreakMode::Space)), "첫 줄 둘째 줄 셋째 줄");
+            assert_eq!(
+                convert(Some(LineBreakMode::Br)),
+                "첫 줄<br>둘째 줄<br>셋째 줄"
+            );
+            assert_eq!(
+                convert(Some(LineBreakMode::Newline)),
+                "첫 줄  \n둘째 줄  \n셋째 줄"
+            );
+            assert_eq!(
+                convert(Some(LineBreakMode::Preserve)),
+                "첫 줄  \n둘째 줄\n\n셋째 줄"
+            );
+        }
+    }
+}
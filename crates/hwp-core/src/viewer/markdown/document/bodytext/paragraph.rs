@@ -4,12 +4,12 @@
 /// 스펙 문서 매핑: 표 57 - 본문의 데이터 레코드
 /// Spec mapping: Table 57 - BodyText data records
 use crate::document::bodytext::CharShapeInfo;
+use crate::document::docinfo::para_shape::ParagraphAlignment;
 use crate::document::CharShape;
 use crate::document::{HwpDocument, Paragraph, ParagraphRecord};
+use crate::viewer::core::bidi::{balance_bidi_controls, mark_directional_runs};
 use crate::viewer::markdown::collect::collect_text_and_images_from_paragraph;
-use crate::viewer::markdown::document::bodytext::para_text::{
-    convert_para_text_to_markdown, convert_para_text_to_markdown_with_char_shapes,
-};
+use crate::viewer::markdown::document::bodytext::para_text::convert_para_text_to_markdown_with_char_shapes;
 use crate::viewer::markdown::document::bodytext::shape_component::convert_shape_component_children_to_markdown;
 use crate::viewer::markdown::document::bodytext::shape_component_picture::{
     convert_hwpx_image_to_markdown, convert_shape_component_picture_to_markdown,
@@ -66,6 +66,7 @@ pub fn convert_paragraph_to_markdown(
                                     let mut dummy_texts = std::collections::HashSet::new();
                                     collect_text_and_images_from_paragraph(
                                         para,
+                                        document,
                                         &mut dummy_texts,
                                         &mut para_table_cell_image_ids,
                                     );
@@ -105,18 +106,19 @@ pub fn convert_paragraph_to_markdown(
                 // 여기서는 표 앞뒤의 일반 텍스트도 정상적으로 처리됨
                 // Text inside table cells is already included in Table.cells and processed in convert_table_to_markdown,
                 // so regular text before/after tables is also processed normally here
-                let text_md = if !char_shapes.is_empty() {
-                    convert_para_text_to_markdown_with_char_shapes(
-                        text,
-                        control_char_positions,
-                        &char_shapes,
-                        Some(&get_char_shape),
-                    )
-                } else {
-                    convert_para_text_to_markdown(text, control_char_positions)
-                };
+                let text_md = convert_para_text_to_markdown_with_char_shapes(
+                    text,
+                    control_char_positions,
+                    &char_shapes,
+                    Some(&get_char_shape),
+                    options,
+                );
 
                 if let Some(text_md) = text_md {
+                    // 끝나지 않은 방향 제어 문자가 뒤 내용으로 번지지 않게 닫음 / Close unterminated bidi controls so they cannot leak into what follows
+                    let text_md = balance_bidi_controls(&text_md);
+                    // 반대 방향 구간 뒤에 방향 표시 문자 / Directional marks after opposing runs
+                    let text_md = mark_directional_runs(&text_md).into_owned();
                     // 같은 문단 내의 텍스트는 나중에 합침 / Text in the same paragraph will be combined later
                     text_parts.push(text_md);
                 }
@@ -137,7 +139,7 @@ pub fn convert_paragraph_to_markdown(
                             if let Some(image_md) = convert_shape_component_picture_to_markdown(
                                 shape_component_picture,
                                 document,
-                                options.image_output_dir.as_deref(),
+                                options,
                             ) {
                                 parts.push(image_md);
                             }
@@ -145,9 +147,9 @@ pub fn convert_paragraph_to_markdown(
                         _ => {
                             // 다른 타입은 기존 방식으로 처리
                             let shape_parts = convert_shape_component_children_to_markdown(
-                                &[child.clone()],
+                                std::slice::from_ref(child),
                                 document,
-                                options.image_output_dir.as_deref(),
+                                options,
                                 tracker,
                             );
                             parts.extend(shape_parts);
@@ -166,19 +168,23 @@ pub fn convert_paragraph_to_markdown(
                 if let Some(image_md) = convert_shape_component_picture_to_markdown(
                     shape_component_picture,
                     document,
-                    options.image_output_dir.as_deref(),
+                    options,
                 ) {
                     parts.push(image_md);
                 }
             }
-            ParagraphRecord::HwpxImage { binary_item_ref } => {
+            ParagraphRecord::HwpxImage {
+                binary_item_ref,
+                caption,
+                ..
+            } => {
                 // HWPX 이미지 참조 변환 / Convert HWPX image reference
-                if let Some(image_md) = convert_hwpx_image_to_markdown(
-                    binary_item_ref,
-                    document,
-                    options.image_output_dir.as_deref(),
-                ) {
+                if let Some(image_md) =
+                    convert_hwpx_image_to_markdown(binary_item_ref, document, options)
+                {
                     parts.push(image_md);
+                    // 캡션은 그림 아래 문단으로 / The caption becomes a paragraph below the picture
+                    parts.extend(caption.clone());
                 }
             }
             ParagraphRecord::CtrlHeader {
@@ -207,7 +213,7 @@ pub fn convert_paragraph_to_markdown(
                                 if let Some(image_md) = convert_shape_component_picture_to_markdown(
                                     shape_component_picture,
                                     document,
-                                    options.image_output_dir.as_deref(),
+                                    options,
                                 ) {
                                     parts.push(image_md);
                                 }
@@ -258,6 +264,7 @@ pub fn convert_paragraph_to_markdown(
                             // 재귀적으로 모든 레코드를 확인하여 이미지 ID 수집 / Recursively check all records to collect image IDs
                             collect_text_and_images_from_paragraph(
                                 para,
+                                document,
                                 &mut table_cell_texts,
                                 &mut table_cell_image_ids,
                             );
@@ -364,7 +371,7 @@ pub fn convert_paragraph_to_markdown(
                                                 convert_shape_component_picture_to_markdown(
                                                     shape_component_picture,
                                                     document,
-                                                    options.image_output_dir.as_deref(),
+                                                    options,
                                                 )
                                             {
                                                 shape_parts_to_output.push(image_md);
@@ -418,7 +425,7 @@ pub fn convert_paragraph_to_markdown(
                                 if let Some(image_md) = convert_shape_component_picture_to_markdown(
                                     shape_component_picture,
                                     document,
-                                    options.image_output_dir.as_deref(),
+                                    options,
                                 ) {
                                     if has_table {
                                         // 표가 있지만 셀 내부가 아닌 경우 (표 위/아래 이미지 등)
@@ -462,6 +469,7 @@ pub fn convert_paragraph_to_markdown(
                             let mut para_image_ids = std::collections::HashSet::new();
                             collect_text_and_images_from_paragraph(
                                 para,
+                                document,
                                 &mut para_texts,
                                 &mut para_image_ids,
                             );
@@ -515,7 +523,7 @@ pub fn convert_paragraph_to_markdown(
             document,
             tracker,
         );
-        parts.push(outline_md);
+        parts.push(align_block(outline_md, paragraph, document, options));
     }
 
     // 마크다운 문법에 맞게 개행 처리 / Handle line breaks according to markdown syntax
@@ -535,7 +543,7 @@ pub fn convert_paragraph_to_markdown(
             // 이전 part가 텍스트이고 현재 part도 텍스트인 경우: 스페이스 2개 + 개행
             // If previous part is text and current part is also text: two spaces + newline
             if is_text_part(&parts[i - 1]) && is_text_part(part) {
-                result.push_str("  \n");
+                result.push_str(options.hard_break());
             }
             // 블록 요소가 포함된 경우: 빈 줄
             // When block elements are involved, use blank line
@@ -548,3 +556,32 @@ pub fn convert_paragraph_to_markdown(
 
     result
 }
+
+/// 가운데/오른쪽 정렬 문단을 `<div align>`으로 감쌈 (`use_html`과 `preserve_alignment`가 켜졌을 때만)
+/// Wrap a centered or right-aligned paragraph in `<div align>` (only with `use_html` and `preserve_alignment`)
+///
+/// 앞뒤 빈 줄이 있어야 안쪽 마크다운(제목, 강조)이 그대로 해석됩니다.
+/// The blank lines around the content keep the markdown inside (headings, emphasis) rendered.
+fn align_block(
+    markdown: String,
+    paragraph: &Paragraph,
+    document: &HwpDocument,
+    options: &MarkdownOptions,
+) -> String {
+    if !options.html_enabled() || options.preserve_alignment != Some(true) {
+        return markdown;
+    }
+    let align = document
+        .doc_info
+        .para_shapes
+        .get(paragraph.para_header.para_shape_id as usize)
+        .and_then(|shape| match shape.attributes1.align {
+            ParagraphAlignment::Center => Some("center"),
+            ParagraphAlignment::Right => Some("right"),
+            _ => None,
+        });
+    match align {
+        Some(align) => format!("<div align=\"{align}\">\n\n{markdown}\n\n</div>"),
+        None => markdown,
+    }
+}
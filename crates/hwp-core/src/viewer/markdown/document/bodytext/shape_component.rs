@@ -12,7 +12,7 @@ use crate::viewer::markdown::document::bodytext::shape_component_picture::conver
 /// # Arguments / 매개변수
 /// * `children` - ShapeComponent의 자식 레코드들 / Child records of ShapeComponent
 /// * `document` - HWP 문서 / HWP document
-/// * `image_output_dir` - 이미지 출력 디렉토리 (선택) / Image output directory (optional)
+/// * `options` - 마크다운 변환 옵션 / Markdown conversion options
 /// * `tracker` - 개요 번호 추적기 / Outline number tracker
 ///
 /// # Returns / 반환값
@@ -20,18 +20,31 @@ use crate::viewer::markdown::document::bodytext::shape_component_picture::conver
 pub(crate) fn convert_shape_component_children_to_markdown(
     children: &[ParagraphRecord],
     document: &HwpDocument,
-    image_output_dir: Option<&str>,
+    options: &crate::viewer::markdown::MarkdownOptions,
     tracker: &mut crate::viewer::markdown::utils::OutlineNumberTracker,
 ) -> Vec<String> {
     use crate::viewer::markdown::document::bodytext::paragraph::convert_paragraph_to_markdown;
     use crate::viewer::markdown::MarkdownOptions;
 
     let mut parts = Vec::new();
-    let options = MarkdownOptions {
-        image_output_dir: image_output_dir.map(|s| s.to_string()),
+    let paragraph_options = MarkdownOptions {
+        image_output_dir: options.image_output_dir.clone(),
+        image_sink: options.image_sink.clone(),
         use_html: None,
         include_version: None,
         include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: options.image_max_dimension,
+        image_format: options.image_format,
+        flavor: options.flavor,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: options.deterministic,
     };
 
     // SHAPE_COMPONENT의 children을 재귀적으로 처리 / Recursively process SHAPE_COMPONENT's children
@@ -48,7 +61,7 @@ pub(crate) fn convert_shape_component_children_to_markdown(
                 if let Some(image_md) = convert_shape_component_picture_to_markdown(
                     shape_component_picture,
                     document,
-                    image_output_dir,
+                    options,
                 ) {
                     parts.push(image_md);
                 }
@@ -58,12 +71,19 @@ pub(crate) fn convert_shape_component_children_to_markdown(
                 // SHAPE_COMPONENT 내부의 LIST_HEADER는 글상자 텍스트를 포함할 수 있음
                 // LIST_HEADER inside SHAPE_COMPONENT can contain textbox text
                 for para in paragraphs {
-                    let para_md = convert_paragraph_to_markdown(para, document, &options, tracker);
+                    let para_md =
+                        convert_paragraph_to_markdown(para, document, &paragraph_options, tracker);
                     if !para_md.is_empty() {
                         parts.push(para_md);
                     }
                 }
             }
+            ParagraphRecord::ShapeComponent { children, .. } => {
+                // 묶음 개체 안의 개체도 HTML 뷰어처럼 재귀적으로 처리 / Recurse into grouped shapes like the HTML viewer
+                parts.extend(convert_shape_component_children_to_markdown(
+                    children, document, options, tracker,
+                ));
+            }
             _ => {
                 // 기타 children은 무시 / Ignore other children
             }
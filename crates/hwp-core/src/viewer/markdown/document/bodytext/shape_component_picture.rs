@@ -5,6 +5,7 @@
 /// Spec mapping: Table 57 - BodyText data records, SHAPE_COMPONENT_PICTURE
 use crate::document::{bodytext::ShapeComponentPicture, HwpDocument};
 use crate::viewer::markdown::common::format_image_markdown;
+use crate::viewer::markdown::MarkdownOptions;
 
 /// Convert ShapeComponentPicture to markdown
 /// ShapeComponentPicture를 마크다운으로 변환
@@ -12,14 +13,14 @@ use crate::viewer::markdown::common::format_image_markdown;
 /// # Arguments / 매개변수
 /// * `shape_component_picture` - 그림 개체 / Picture shape component
 /// * `document` - HWP 문서 / HWP document
-/// * `image_output_dir` - 이미지 출력 디렉토리 (선택) / Image output directory (optional)
+/// * `options` - 마크다운 변환 옵션 / Markdown conversion options
 ///
 /// # Returns / 반환값
 /// 마크다운 문자열 / Markdown string
 pub(crate) fn convert_shape_component_picture_to_markdown(
     shape_component_picture: &ShapeComponentPicture,
     document: &HwpDocument,
-    image_output_dir: Option<&str>,
+    options: &MarkdownOptions,
 ) -> Option<String> {
     // 그림 개체를 마크다운 이미지로 변환 / Convert picture shape component to markdown image
     let bindata_id = shape_component_picture.picture_info.bindata_id;
@@ -31,8 +32,7 @@ pub(crate) fn convert_shape_component_picture_to_markdown(
         .iter()
         .find(|item| item.index == bindata_id)
     {
-        let image_markdown =
-            format_image_markdown(document, bindata_id, &bin_item.data, image_output_dir);
+        let image_markdown = format_image_markdown(document, bindata_id, &bin_item.data, options);
         if !image_markdown.is_empty() {
             return Some(image_markdown);
         }
@@ -46,14 +46,14 @@ pub(crate) fn convert_shape_component_picture_to_markdown(
 /// # Arguments / 매개변수
 /// * `binary_item_ref` - 바이너리 아이템 참조 이름 (예: "image1") / Binary item reference name (e.g., "image1")
 /// * `document` - HWP 문서 / HWP document
-/// * `image_output_dir` - 이미지 출력 디렉토리 (선택) / Image output directory (optional)
+/// * `options` - 마크다운 변환 옵션 / Markdown conversion options
 ///
 /// # Returns / 반환값
 /// 마크다운 문자열 / Markdown string
 pub(crate) fn convert_hwpx_image_to_markdown(
     binary_item_ref: &str,
     document: &HwpDocument,
-    image_output_dir: Option<&str>,
+    options: &MarkdownOptions,
 ) -> Option<String> {
     // HWPX 이미지 참조를 마크다운 이미지로 변환 / Convert HWPX image reference to markdown image
     // BinData에서 이름으로 이미지 찾기 / Find image in BinData by name
@@ -64,7 +64,7 @@ pub(crate) fn convert_hwpx_image_to_markdown(
         .find(|item| item.name.as_deref() == Some(binary_item_ref))
     {
         let image_markdown =
-            format_image_markdown(document, bin_item.index, &bin_item.data, image_output_dir);
+            format_image_markdown(document, bin_item.index, &bin_item.data, options);
         if !image_markdown.is_empty() {
             return Some(image_markdown);
         }
@@ -3,7 +3,11 @@
 ///
 /// 스펙 문서 매핑: 표 57 - 본문의 데이터 레코드, TABLE (HWPTAG_BEGIN + 61)
 /// Spec mapping: Table 57 - BodyText data records, TABLE (HWPTAG_BEGIN + 61)
+use crate::document::bodytext::ControlChar;
 use crate::document::{bodytext::Table, HwpDocument, ParagraphRecord};
+use crate::viewer::core::bidi::{base_direction, isolate_rtl, TextDirection};
+use crate::viewer::markdown::utils::{break_text, inline_controls_text, insert_breaks};
+use crate::viewer::markdown::MarkdownFlavor;
 
 /// Convert nested table to text with line breaks
 /// 중첩 테이블을 줄바꿈이 포함된 텍스트로 변환
@@ -53,7 +57,8 @@ fn convert_nested_table_to_text(
             // 셀 내용 추출 (재귀적으로 중첩 테이블도 처리)
             let cell_text = get_nested_cell_content(cell, document, options, tracker);
             if !cell_text.trim().is_empty() {
-                cell_texts.push(cell_text);
+                // 탭으로 나란히 놓이므로 RTL 셀은 격리 / Cells sit side by side on one line, so RTL cells are isolated
+                cell_texts.push(isolate_rtl(&cell_text).into_owned());
             }
         }
 
@@ -82,7 +87,7 @@ fn get_nested_cell_content(
             match record {
                 ParagraphRecord::ParaText { text, .. } => {
                     if !text.trim().is_empty() {
-                        parts.push(text.clone());
+                        parts.push(inline_controls_text(text, options).into_owned());
                     }
                 }
                 ParagraphRecord::Table { table } => {
@@ -128,9 +133,16 @@ pub fn convert_table_to_markdown(
         .iter()
         .any(|cell| cell.cell_attributes.col_span > 1 || cell.cell_attributes.row_span > 1);
 
-    // HTML 모드이거나 병합된 셀이 있으면 HTML 테이블로 출력
-    // Use HTML table if in HTML mode or has merged cells
-    if options.use_html == Some(true) || has_merged_cells {
+    // HTML 모드이거나 병합된 셀이 있으면 HTML 테이블로 출력 (파이프 표가 없는 방언은 항상,
+    // 원시 HTML이 없는 방언은 절대 쓰지 않음)
+    // Use HTML table if in HTML mode or has merged cells (always for flavors without pipe
+    // tables, never for flavors without raw HTML)
+    let html_table = match options.flavor {
+        Some(flavor) if !flavor.has_pipe_tables() => true,
+        Some(flavor) if !flavor.allows_raw_html() => false,
+        _ => options.use_html == Some(true) || has_merged_cells,
+    };
+    if html_table {
         convert_table_to_html(table, document, options, tracker)
     } else {
         convert_table_to_markdown_simple(table, document, options, tracker)
@@ -147,6 +159,7 @@ fn convert_table_to_html(
 ) -> String {
     let row_count = table.attributes.row_count as usize;
     let col_count = table.attributes.col_count as usize;
+    let header_rows = table.header_rows(&document.doc_info.border_fill);
 
     // 셀을 row_address 기준으로 그룹화
     // Group cells by row_address
@@ -237,6 +250,8 @@ fn convert_table_to_html(
 
         html.push_str("  <tr>\n");
 
+        // 머리행 칸은 th로 / Header row cells become th
+        let tag = if row_idx < header_rows { "th" } else { "td" };
         for (cell_content, _col_idx, col_span, row_span) in row_cell_contents {
             // td 태그 생성
             // Generate td tag
@@ -247,6 +262,11 @@ fn convert_table_to_html(
             if row_span > 1 {
                 td_attrs.push(format!("rowspan=\"{row_span}\""));
             }
+            if !cell_content.contains("<table")
+                && base_direction(&cell_content) == Some(TextDirection::Rtl)
+            {
+                td_attrs.push("dir=\"rtl\"".to_string());
+            }
 
             let attrs_str = if td_attrs.is_empty() {
                 String::new()
@@ -264,7 +284,7 @@ fn convert_table_to_html(
                 cell_content.replace('\n', "<br>")
             };
 
-            html.push_str(&format!("    <td{attrs_str}>{cell_html}</td>\n"));
+            html.push_str(&format!("    <{tag}{attrs_str}>{cell_html}</{tag}>\n"));
         }
 
         html.push_str("  </tr>\n");
@@ -353,6 +373,18 @@ fn convert_table_to_markdown_simple(
     // 마크다운 표 형식으로 변환 / Convert to markdown table format
     let mut lines = Vec::new();
     lines.push(String::new());
+    let separator = format!(
+        "|{}|",
+        (0..col_count).map(|_| "---").collect::<Vec<_>>().join("|")
+    );
+
+    // 파이프 표는 머리행이 꼭 있어야 하므로 머리행이 없는 표는 빈 머리행을 둠
+    // Pipe tables require a header row, so tables without one get a blank header row
+    let has_header = table.header_rows(&document.doc_info.border_fill) > 0;
+    if !has_header {
+        lines.push(format!("| {} |", vec![" "; col_count].join(" | ")));
+        lines.push(separator.clone());
+    }
 
     for row_idx in 0..row_count {
         let row_data: Vec<String> = (0..col_count)
@@ -364,11 +396,8 @@ fn convert_table_to_markdown_simple(
             .collect();
         lines.push(format!("| {} |", row_data.join(" | ")));
 
-        if row_idx == 0 {
-            lines.push(format!(
-                "|{}|",
-                (0..col_count).map(|_| "---").collect::<Vec<_>>().join("|")
-            ));
+        if has_header && row_idx == 0 {
+            lines.push(separator.clone());
         }
     }
 
@@ -391,9 +420,19 @@ fn get_cell_content(
 
         for record in &para.records {
             match record {
-                ParagraphRecord::ParaText { text, .. } => {
+                ParagraphRecord::ParaText {
+                    text,
+                    control_char_positions,
+                    ..
+                } => {
                     if !text.trim().is_empty() {
-                        para_parts.push(text.clone());
+                        let text = match options.line_break_mode {
+                            Some(mode) => {
+                                insert_breaks(text, control_char_positions, mode, true, options.flavor)
+                            }
+                            None => text.clone(),
+                        };
+                        para_parts.push(inline_controls_text(&text, options).into_owned());
                     }
                 }
                 ParagraphRecord::ShapeComponentPicture { shape_component_picture } => {
@@ -401,7 +440,7 @@ fn get_cell_content(
                         crate::viewer::markdown::document::bodytext::shape_component_picture::convert_shape_component_picture_to_markdown(
                             shape_component_picture,
                             document,
-                            options.image_output_dir.as_deref(),
+                            options,
                         )
                     {
                         para_parts.push(image_md);
@@ -412,21 +451,26 @@ fn get_cell_content(
                         crate::viewer::markdown::document::bodytext::shape_component::convert_shape_component_children_to_markdown(
                             children,
                             document,
-                            options.image_output_dir.as_deref(),
+                            options,
                             tracker,
                         );
                     para_parts.extend(shape_parts);
                 }
-                ParagraphRecord::HwpxImage { binary_item_ref } => {
+                ParagraphRecord::HwpxImage {
+                    binary_item_ref,
+                    caption,
+                    ..
+                } => {
                     // HWPX 이미지 참조 변환 / Convert HWPX image reference
                     if let Some(image_md) =
                         crate::viewer::markdown::document::bodytext::shape_component_picture::convert_hwpx_image_to_markdown(
                             binary_item_ref,
                             document,
-                            options.image_output_dir.as_deref(),
+                            options,
                         )
                     {
                         para_parts.push(image_md);
+                        para_parts.extend(caption.clone());
                     }
                 }
                 ParagraphRecord::Table { table } => {
@@ -449,9 +493,17 @@ fn get_cell_content(
         }
     }
 
-    // 문단들을 줄바꿈으로 결합 (HTML 테이블에서 <br>로 변환됨)
-    // Join paragraphs with newline (will be converted to <br> in HTML table)
-    paragraph_contents.join("\n")
+    // 문단들을 줄바꿈으로 결합 (HTML 테이블에서 <br>로 변환됨), 줄 나눔 방식이 있으면 그대로 따름
+    // Join paragraphs with newline (will be converted to <br> in HTML table), or per the line break mode
+    match options.line_break_mode {
+        Some(mode) => paragraph_contents.join(break_text(
+            mode,
+            ControlChar::PARA_BREAK,
+            true,
+            options.flavor,
+        )),
+        None => paragraph_contents.join("\n"),
+    }
 }
 
 /// Fill cell content and handle cell merging
@@ -527,6 +579,17 @@ fn fill_cell_content(
                     continue;
                 }
 
+                if let Some(mode) = options.line_break_mode {
+                    para_text_result.push_str(&insert_breaks(
+                        text,
+                        control_char_positions,
+                        mode,
+                        true,
+                        options.flavor,
+                    ));
+                    continue;
+                }
+
                 let mut last_char_pos = 0;
 
                 // control_positions를 정렬하여 순서대로 처리 / Sort control_positions to process in order
@@ -555,7 +618,7 @@ fn fill_cell_content(
                     }
 
                     // PARA_BREAK나 LINE_BREAK 위치에 <br> 추가 / Add <br> at PARA_BREAK or LINE_BREAK position
-                    if options.use_html == Some(true) {
+                    if options.html_enabled() {
                         para_text_result.push_str("<br>");
                     } else {
                         para_text_result.push(' ');
@@ -582,7 +645,7 @@ fn fill_cell_content(
             }
 
             if !para_text_result.trim().is_empty() {
-                cell_parts.push(para_text_result);
+                cell_parts.push(inline_controls_text(&para_text_result, options).into_owned());
             }
         }
         // ParaText가 아닌 레코드(이미지 등)가 있으면 직접 처리
@@ -602,7 +665,7 @@ fn fill_cell_content(
                             crate::viewer::markdown::document::bodytext::shape_component_picture::convert_shape_component_picture_to_markdown(
                                 shape_component_picture,
                                 document,
-                                options.image_output_dir.as_deref(),
+                                options,
                             )
                         {
                             cell_parts.push(image_md);
@@ -618,32 +681,37 @@ fn fill_cell_content(
                             crate::viewer::markdown::document::bodytext::shape_component::convert_shape_component_children_to_markdown(
                                 children,
                                 document,
-                                options.image_output_dir.as_deref(),
+                                options,
                                 tracker,
                             );
                         for shape_part in shape_parts {
-                            if shape_part.contains("![이미지]") {
+                            if shape_part.starts_with("![") {
                                 has_image = true;
                             }
                             cell_parts.push(shape_part);
                         }
                     }
-                    ParagraphRecord::HwpxImage { binary_item_ref } => {
+                    ParagraphRecord::HwpxImage {
+                        binary_item_ref,
+                        caption,
+                        ..
+                    } => {
                         // HWPX 이미지 참조 변환 / Convert HWPX image reference
                         if let Some(image_md) =
                             crate::viewer::markdown::document::bodytext::shape_component_picture::convert_hwpx_image_to_markdown(
                                 binary_item_ref,
                                 document,
-                                options.image_output_dir.as_deref(),
+                                options,
                             )
                         {
                             cell_parts.push(image_md);
+                            cell_parts.extend(caption.clone());
                             has_image = true;
                         }
                     }
                     ParagraphRecord::Table { table } => {
                         // 중첩 테이블 처리 / Handle nested table
-                        if options.use_html == Some(true) {
+                        if options.html_enabled() {
                             // HTML 모드: 중첩 테이블을 HTML로 렌더링 (재귀)
                             let nested_table_html =
                                 convert_table_to_html(table, document, options, tracker);
@@ -669,7 +737,17 @@ fn fill_cell_content(
         // 마지막 문단이 아니면 문단 사이 줄바꿈 추가
         // If not last paragraph, add line break between paragraphs
         if idx < cell.paragraphs.len() - 1 {
-            cell_parts.push("<br>".to_string());
+            // 원시 HTML을 쓸 수 없는 방언은 공백으로 / Flavors without raw HTML use a space
+            let default_separator = if options.flavor.map_or(true, MarkdownFlavor::allows_raw_html)
+            {
+                "<br>"
+            } else {
+                " "
+            };
+            let separator = options.line_break_mode.map_or(default_separator, |mode| {
+                break_text(mode, ControlChar::PARA_BREAK, true, options.flavor)
+            });
+            cell_parts.push(separator.to_string());
         }
     }
 
@@ -677,10 +755,11 @@ fn fill_cell_content(
     let cell_text = cell_parts.join("");
 
     // 마크다운 표에서 파이프 문자 이스케이프 처리 / Escape pipe characters in markdown table
+    // RTL 셀은 같은 행의 구분자와 순서가 뒤바뀌지 않게 격리 / RTL cells are isolated so they cannot reorder with the row's separators
     let cell_content = if cell_text.is_empty() {
         " ".to_string() // 빈 셀은 공백으로 표시 / Empty cell shows as space
     } else {
-        cell_text.replace('|', "\\|") // 파이프 문자 이스케이프 / Escape pipe character
+        isolate_rtl(&cell_text.replace('|', "\\|")).into_owned() // 파이프 문자 이스케이프 / Escape pipe character
     };
 
     // 셀에 이미 내용이 있으면 덮어쓰지 않음 (병합 셀 처리)
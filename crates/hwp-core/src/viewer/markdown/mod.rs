@@ -20,7 +20,14 @@ pub mod document;
 mod renderer;
 pub mod utils;
 
-use crate::document::HwpDocument;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use crate::document::bodytext::InlineControl;
+use crate::document::{ContentFilter, HwpDocument};
+use crate::viewer::image_export::ImageFormat;
+use crate::viewer::image_sink::ImageSink;
 
 pub use ctrl_header::convert_control_to_markdown;
 pub use document::bodytext::convert_paragraph_to_markdown;
@@ -30,10 +37,26 @@ pub use renderer::MarkdownRenderer;
 /// Markdown 변환 옵션 / Markdown conversion options
 #[derive(Debug, Clone)]
 pub struct MarkdownOptions {
-    /// 이미지를 파일로 저장할 디렉토리 경로 (None이면 base64 데이터 URI로 임베드)
-    /// Optional directory path to save images as files. If None, images are embedded as base64 data URIs.
+    /// 이미지를 파일로 저장할 디렉토리 경로 (None이거나 `fs` 기능이 없으면 base64 데이터 URI로 임베드)
+    /// Optional directory path to save images as files. If None, or without the `fs` feature,
+    /// images are embedded as base64 data URIs.
     pub image_output_dir: Option<String>,
 
+    /// 그림을 넘길 저장소 (Some이면 `image_output_dir`보다 우선, 링크는 저장소가 돌려준 URL)
+    /// Sink that receives pictures (takes precedence over `image_output_dir`; links use the URL
+    /// the sink returns)
+    pub image_sink: Option<Arc<dyn ImageSink>>,
+
+    /// 그림 긴 변의 최대 픽셀 수 (`image-processing` 기능 필요, None이면 줄이지 않음)
+    /// Maximum number of pixels on a picture's longer side (requires the `image-processing`
+    /// feature; None keeps the original size)
+    pub image_max_dimension: Option<u32>,
+
+    /// 내보낼 그림 형식 (`image-processing` 기능 필요, None이면 원래 형식)
+    /// Picture format for export (requires the `image-processing` feature; None keeps the
+    /// original format)
+    pub image_format: Option<ImageFormat>,
+
     /// HTML 태그 사용 여부 (Some(true)인 경우 테이블 등 개행 불가 영역에 <br> 태그 사용)
     /// Whether to use HTML tags (if Some(true), use <br> tags in areas where line breaks are not possible, such as tables)
     pub use_html: Option<bool>,
@@ -43,6 +66,139 @@ pub struct MarkdownOptions {
 
     /// 페이지 정보 포함 여부 / Whether to include page information
     pub include_page_info: Option<bool>,
+
+    /// 인용 필드로 참고 문헌 절을 만들지 여부 / Whether to append a references section built from citation fields
+    pub include_references: Option<bool>,
+
+    /// 제목 앵커로 연결된 차례를 앞에 붙일지 여부 / Whether to prepend a table of contents linked to heading anchors
+    pub include_toc: Option<bool>,
+
+    /// 줄 나눔/문단 나눔 제어 문자를 옮기는 방식 (None이면 기존 동작: 본문은 마크다운 개행, 표 셀은
+    /// `use_html`에 따라 `<br>` 또는 공백)
+    /// How line-break and paragraph-break control characters are rendered (None keeps the legacy
+    /// behavior: markdown line breaks in the body, `<br>` or a space in table cells depending on `use_html`)
+    pub line_break_mode: Option<LineBreakMode>,
+
+    /// 가운데/오른쪽 정렬 문단을 `<div align>`으로 감쌀지 여부 (`use_html`일 때만 적용)
+    /// Whether centered and right-aligned paragraphs are wrapped in `<div align>` (only with `use_html`)
+    pub preserve_alignment: Option<bool>,
+
+    /// 인라인 제어 문자(탭, 하이픈, 묶음/고정폭 빈칸)의 출력 문자열
+    /// Output strings for inline control characters (tab, hyphen, non-breaking/fixed-width space)
+    ///
+    /// 없는 종류는 일반 텍스트 표현(`"\t"`, `"-"`, `" "`)을 씁니다.
+    /// Kinds not listed use their plain text representation (`"\t"`, `"-"`, `" "`).
+    pub inline_controls: HashMap<InlineControl, String>,
+
+    /// 대상 마크다운 방언 (None이면 기존 동작: GFM 문법에 필요한 곳마다 HTML을 섞음)
+    /// Target markdown flavor (None keeps the legacy behavior: GFM syntax mixed with HTML
+    /// wherever needed)
+    ///
+    /// 표 문법, 각주 문법, 줄 나눔, 원시 HTML 허용 여부를 정합니다.
+    /// Decides the table syntax, footnote syntax, line breaks and whether raw HTML is allowed.
+    pub flavor: Option<MarkdownFlavor>,
+
+    /// 본문 문단 앞에 원본 위치 주석(`<!-- data-para="구역:문단" -->`)을 넣을지 여부
+    /// Whether body paragraphs are preceded by a source location comment
+    /// (`<!-- data-para="section:paragraph" -->`)
+    ///
+    /// 주석 도구가 변환 결과를 파싱된 문단(`body_text.sections[구역].paragraphs[문단]`)으로
+    /// 되짚을 수 있게 합니다.
+    /// Lets annotation tools map the output back to the parsed paragraph
+    /// (`body_text.sections[section].paragraphs[paragraph]`).
+    pub include_source_anchors: Option<bool>,
+
+    /// 머리말/꼬리말의 쪽 번호 필드를 지울지 여부 (None이거나 false면 `{page}`/`{pages}` 토큰으로 출력)
+    /// Whether page number fields in headers and footers are removed (None or false renders them
+    /// as `{page}`/`{pages}` tokens)
+    ///
+    /// 흐르는 Markdown에는 쪽이 없으므로, 토큰은 나중에 쪽을 나누는 도구가 바꿀 자리입니다.
+    /// Flowing markdown has no pages, so the tokens are placeholders for a later paginating tool.
+    pub strip_page_fields: Option<bool>,
+
+    /// 걸러 낼 숨은 설명/개인 정보 필드/보이지 않는 문자 (기본값: 걸러 내지 않음)
+    /// Hidden comments, personal information fields and invisible characters to filter out (default: none)
+    pub content_filter: Option<ContentFilter>,
+
+    /// 결정적 출력 여부: 그림 파일 이름과 참조 라벨을 ZIP 항목 순서 대신 BinData 이름순 번호로
+    /// 정해, 같은 문서는 어떻게 묶였든 늘 바이트 단위로 같은 결과를 냅니다 (스냅숏 테스트용).
+    /// Deterministic output: picture file names and reference labels are numbered in BinData name
+    /// order instead of ZIP entry order, so the same document always produces byte-identical
+    /// output however it was packed (for snapshot testing).
+    pub deterministic: bool,
+}
+
+/// 마크다운 방언 / Markdown flavor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkdownFlavor {
+    /// GitHub Flavored Markdown: 파이프 표 (병합 셀은 HTML 표), `[^1]` 각주, `"  \n"` 줄 나눔,
+    /// 원시 HTML 허용
+    /// GitHub Flavored Markdown: pipe tables (HTML tables for merged cells), `[^1]` footnotes,
+    /// `"  \n"` line breaks, raw HTML allowed
+    Gfm,
+    /// CommonMark: 표 확장이 없어 표는 HTML, 각주는 `\[1\]` 텍스트, `"\\\n"` 줄 나눔,
+    /// 원시 HTML 허용
+    /// CommonMark: no table extension so tables are HTML, footnotes are `\[1\]` text,
+    /// `"\\\n"` line breaks, raw HTML allowed
+    CommonMark,
+    /// Pandoc Markdown: 파이프 표 (병합 셀은 왼쪽 위 칸에만 내용), `[^1]` 각주, `"\\\n"` 줄 나눔,
+    /// 원시 HTML 없음 (`use_html` 무시)
+    /// Pandoc Markdown: pipe tables (merged cells keep content in the top-left cell), `[^1]`
+    /// footnotes, `"\\\n"` line breaks, no raw HTML (`use_html` is ignored)
+    Pandoc,
+}
+
+impl MarkdownFlavor {
+    /// 이름으로 방언 찾기 ("gfm", "commonmark", "pandoc") / Look up a flavor by name ("gfm", "commonmark", "pandoc")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "gfm" | "github" => Some(MarkdownFlavor::Gfm),
+            "commonmark" => Some(MarkdownFlavor::CommonMark),
+            "pandoc" => Some(MarkdownFlavor::Pandoc),
+            _ => None,
+        }
+    }
+
+    /// 원시 HTML을 쓸 수 있는지 여부 / Whether raw HTML may be emitted
+    pub fn allows_raw_html(self) -> bool {
+        self != MarkdownFlavor::Pandoc
+    }
+
+    /// 파이프 표 문법이 있는지 여부 / Whether the flavor has pipe table syntax
+    pub fn has_pipe_tables(self) -> bool {
+        self != MarkdownFlavor::CommonMark
+    }
+
+    /// `[^1]` 각주 문법이 있는지 여부 / Whether the flavor has `[^1]` footnote syntax
+    pub fn has_footnotes(self) -> bool {
+        self != MarkdownFlavor::CommonMark
+    }
+
+    /// 강제 줄 나눔 / Hard line break
+    pub fn hard_break(self) -> &'static str {
+        match self {
+            MarkdownFlavor::Gfm => "  \n",
+            MarkdownFlavor::CommonMark | MarkdownFlavor::Pandoc => "\\\n",
+        }
+    }
+}
+
+/// 줄 나눔(LINE_BREAK)과 문단 나눔(PARA_BREAK)을 옮기는 방식 / How LINE_BREAK and PARA_BREAK are rendered
+///
+/// 본문과 표 셀에 똑같이 적용됩니다. 표 셀 안에는 개행을 쓸 수 없으므로 개행 대신 `<br>`을 씁니다.
+/// Applied the same way in the body and in table cells. Newlines cannot appear inside table
+/// cells, so `<br>` is used there instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineBreakMode {
+    /// 둘 다 공백 / Both become a space
+    Space,
+    /// 둘 다 `<br>` / Both become `<br>`
+    Br,
+    /// 둘 다 마크다운 개행 (`"  \n"`) / Both become a markdown hard line break (`"  \n"`)
+    Newline,
+    /// 줄 나눔은 마크다운 개행, 문단 나눔은 빈 줄 (새 문단)
+    /// Line breaks become a markdown hard line break, paragraph breaks a blank line (new paragraph)
+    Preserve,
 }
 
 impl MarkdownOptions {
@@ -52,6 +208,41 @@ impl MarkdownOptions {
         self
     }
 
+    /// 그림 저장소 설정 / Set the image sink
+    pub fn with_image_sink(mut self, sink: Option<Arc<dyn ImageSink>>) -> Self {
+        self.image_sink = sink;
+        self
+    }
+
+    /// 실제로 그림을 넘길 저장소 (`image_sink`, 없으면 `image_output_dir`의 `FileImageSink`)
+    /// Sink pictures actually go to (`image_sink`, else a `FileImageSink` for `image_output_dir`)
+    ///
+    /// 링크는 예전처럼 `images/파일 이름`입니다. / Links stay `images/<file name>` as before.
+    pub(crate) fn resolved_image_sink(&self) -> Option<Arc<dyn ImageSink>> {
+        if let Some(sink) = &self.image_sink {
+            return Some(Arc::clone(sink));
+        }
+        #[cfg(feature = "fs")]
+        if let Some(dir) = &self.image_output_dir {
+            let sink =
+                crate::viewer::image_sink::FileImageSink::new(dir).with_url_prefix("images/");
+            return Some(Arc::new(sink));
+        }
+        None
+    }
+
+    /// 그림 최대 크기 설정 / Set the maximum picture dimension
+    pub fn with_image_max_dimension(mut self, max_dimension: Option<u32>) -> Self {
+        self.image_max_dimension = max_dimension;
+        self
+    }
+
+    /// 그림 형식 설정 / Set the picture format
+    pub fn with_image_format(mut self, format: Option<ImageFormat>) -> Self {
+        self.image_format = format;
+        self
+    }
+
     /// HTML 태그 사용 설정 / Set HTML tag usage
     pub fn with_use_html(mut self, use_html: Option<bool>) -> Self {
         self.use_html = use_html;
@@ -69,6 +260,77 @@ impl MarkdownOptions {
         self.include_page_info = include;
         self
     }
+
+    /// 참고 문헌 절 포함 설정 / Set references section inclusion
+    pub fn with_include_references(mut self, include: Option<bool>) -> Self {
+        self.include_references = include;
+        self
+    }
+
+    /// 차례 포함 설정 / Set table of contents inclusion
+    pub fn with_include_toc(mut self, include: Option<bool>) -> Self {
+        self.include_toc = include;
+        self
+    }
+
+    /// 줄 나눔 방식 설정 / Set line break mode
+    pub fn with_line_break_mode(mut self, mode: Option<LineBreakMode>) -> Self {
+        self.line_break_mode = mode;
+        self
+    }
+
+    /// 문단 정렬 유지 설정 / Set paragraph alignment preservation
+    pub fn with_preserve_alignment(mut self, preserve: Option<bool>) -> Self {
+        self.preserve_alignment = preserve;
+        self
+    }
+
+    /// 인라인 제어 문자 출력 문자열 하나 설정 / Set the output string of one inline control character
+    pub fn with_inline_control(mut self, control: InlineControl, output: &str) -> Self {
+        self.inline_controls.insert(control, output.to_string());
+        self
+    }
+
+    /// 마크다운 방언 설정 / Set the markdown flavor
+    pub fn with_flavor(mut self, flavor: Option<MarkdownFlavor>) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// 원본 위치 주석 포함 설정 / Set source location comment inclusion
+    pub fn with_include_source_anchors(mut self, include: Option<bool>) -> Self {
+        self.include_source_anchors = include;
+        self
+    }
+
+    /// 쪽 번호 필드 제거 설정 / Set page number field removal
+    pub fn with_strip_page_fields(mut self, strip: Option<bool>) -> Self {
+        self.strip_page_fields = strip;
+        self
+    }
+
+    /// 걸러 낼 내용 설정 / Set the content to filter out
+    pub fn with_content_filter(mut self, filter: Option<ContentFilter>) -> Self {
+        self.content_filter = filter;
+        self
+    }
+
+    /// 결정적 출력 설정 / Set deterministic output
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// HTML 태그를 쓸지 여부 (`use_html`이 켜져 있고 방언이 원시 HTML을 허용할 때)
+    /// Whether HTML tags are used (`use_html` is on and the flavor allows raw HTML)
+    pub(crate) fn html_enabled(&self) -> bool {
+        self.use_html == Some(true) && self.flavor.map_or(true, MarkdownFlavor::allows_raw_html)
+    }
+
+    /// 강제 줄 나눔 (방언이 없으면 `"  \n"`) / Hard line break (`"  \n"` without a flavor)
+    pub(crate) fn hard_break(&self) -> &'static str {
+        self.flavor.map_or("  \n", MarkdownFlavor::hard_break)
+    }
 }
 
 /// Convert HWP document to Markdown format
@@ -81,16 +343,44 @@ impl MarkdownOptions {
 /// # Returns / 반환값
 /// Markdown string representation of the document / 문서의 마크다운 문자열 표현
 pub fn to_markdown(document: &HwpDocument, options: &MarkdownOptions) -> String {
-    let mut lines = Vec::new();
+    let mut markdown = Vec::new();
+    to_markdown_writer(document, options, &mut markdown).expect("writing to a Vec cannot fail");
+    String::from_utf8(markdown).expect("markdown output is valid UTF-8")
+}
+
+/// Stream HWP document as Markdown into a writer
+/// HWP 문서를 마크다운으로 변환하여 writer에 순차 출력
+///
+/// 문서 전체를 하나의 문자열로 만들지 않고 문단 단위로 출력하므로, 큰 문서의 최대
+/// 메모리 사용량이 줄고 HTTP 응답을 나누어 보낼 수 있습니다. 출력은 `to_markdown`과 같습니다.
+///
+/// Writes paragraph by paragraph instead of building the whole document in one string, which
+/// lowers peak memory for large documents and allows chunked HTTP responses. The output is the
+/// same as `to_markdown`.
+///
+/// # Arguments / 매개변수
+/// * `document` - The HWP document to convert / 변환할 HWP 문서
+/// * `options` - Markdown conversion options / 마크다운 변환 옵션
+/// * `writer` - Output destination / 출력 대상
+pub fn to_markdown_writer<W: Write + ?Sized>(
+    document: &HwpDocument,
+    options: &MarkdownOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    let document = &*document.with_content_filter(options.content_filter);
+
+    // 문단 사이에 빈 줄을 추가하여 마크다운에서 각 문단이 구분되도록 함
+    // Add blank lines between paragraphs so each paragraph is distinguished in markdown
+    let mut lines = BlockWriter::new(writer);
 
     // Add document title with version info / 문서 제목과 버전 정보 추가
-    lines.push("# HWP 문서".to_string());
-    lines.push(String::new());
+    lines.write("# HWP 문서")?;
+    lines.write("")?;
 
     // 버전 정보 추가 / Add version information
     if options.include_version != Some(false) {
-        lines.push(format!("**버전**: {}", document::format_version(document)));
-        lines.push(String::new());
+        lines.write(&format!("**버전**: {}", document::format_version(document)))?;
+        lines.write("")?;
     }
 
     // 페이지 정보 추가 / Add page information
@@ -103,58 +393,139 @@ pub fn to_markdown(document: &HwpDocument, options: &MarkdownOptions) -> String
             let top_margin_mm = page_def.top_margin.to_mm();
             let bottom_margin_mm = page_def.bottom_margin.to_mm();
 
-            lines.push(format!(
+            lines.write(&format!(
                 "**용지 크기**: {paper_width_mm:.2}mm x {paper_height_mm:.2}mm"
-            ));
-            lines.push(format!(
+            ))?;
+            lines.write(&format!(
                 "**용지 방향**: {:?}",
                 page_def.attributes.paper_direction
-            ));
-            lines.push(format!(
+            ))?;
+            lines.write(&format!(
                 "**여백**: 좌 {left_margin_mm:.2}mm / 우 {right_margin_mm:.2}mm / 상 {top_margin_mm:.2}mm / 하 {bottom_margin_mm:.2}mm"
-            ));
-            lines.push(String::new());
+            ))?;
+            lines.write("")?;
+        }
+    }
+
+    // 차례 추가 (제목 앞의 앵커로 연결) / Add table of contents (linked to anchors before headings)
+    if options.include_toc == Some(true) {
+        let entries = crate::viewer::toc::generate(document);
+        if !entries.is_empty() {
+            lines.write("## 목차")?;
+            lines.write("")?;
+            let toc = entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{}- [{}](#{})",
+                        "  ".repeat(usize::from(entry.level.saturating_sub(1))),
+                        entry.title.replace('[', "\\[").replace(']', "\\]"),
+                        entry.anchor()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            lines.write(&toc)?;
+            lines.write("")?;
         }
     }
 
     // Convert body text to markdown using common logic / 공통 로직을 사용하여 본문 텍스트를 마크다운으로 변환
-    use crate::viewer::core::bodytext::process_bodytext;
+    use crate::viewer::core::bodytext::{collect_headers, process_bodytext_with};
     use crate::viewer::markdown::renderer::MarkdownRenderer;
     let renderer = MarkdownRenderer;
-    let parts = process_bodytext(document, &renderer, options);
 
     // 머리말, 본문, 꼬리말, 각주, 미주 순서로 결합 / Combine in order: headers, body, footers, footnotes, endnotes
-    if !parts.headers.is_empty() {
-        lines.extend(parts.headers.clone());
-        lines.push(String::new());
+    let headers = collect_headers(document, &renderer, options);
+    if !headers.is_empty() {
+        for header in &headers {
+            lines.write(header)?;
+        }
+        lines.write("")?;
     }
-    lines.extend(parts.body_lines.clone());
+    let parts = process_bodytext_with(document, &renderer, options, |line| lines.write(&line))?;
     if !parts.footers.is_empty() {
-        if !lines.is_empty() && !lines.last().unwrap().is_empty() {
-            lines.push(String::new());
+        lines.separate()?;
+        for footer in &parts.footers {
+            lines.write(footer)?;
         }
-        lines.extend(parts.footers.clone());
     }
     if !parts.footnotes.is_empty() {
-        if !lines.is_empty() && !lines.last().unwrap().is_empty() {
-            lines.push(String::new());
-        }
+        lines.separate()?;
         // 각주 섹션 헤더 추가 / Add footnote section header
-        lines.push("## 각주".to_string());
-        lines.push(String::new());
-        lines.extend(parts.footnotes.clone());
+        lines.write("## 각주")?;
+        lines.write("")?;
+        for footnote in &parts.footnotes {
+            lines.write(footnote)?;
+        }
     }
     if !parts.endnotes.is_empty() {
-        if !lines.is_empty() && !lines.last().unwrap().is_empty() {
-            lines.push(String::new());
-        }
+        lines.separate()?;
         // 미주 섹션 헤더 추가 / Add endnote section header
-        lines.push("## 미주".to_string());
-        lines.push(String::new());
-        lines.extend(parts.endnotes.clone());
+        lines.write("## 미주")?;
+        lines.write("")?;
+        for endnote in &parts.endnotes {
+            lines.write(endnote)?;
+        }
+    }
+    if options.include_references == Some(true) {
+        let references = document.references();
+        if !references.is_empty() {
+            lines.separate()?;
+            // 참고 문헌 섹션 헤더 추가 / Add references section header
+            lines.write("## 참고 문헌")?;
+            lines.write("")?;
+            for (index, reference) in references.iter().enumerate() {
+                lines.write(&format!("{}. {reference}", index + 1))?;
+            }
+        }
+    }
+    if options.resolved_image_sink().is_none() {
+        let definitions = common::shared_image_definitions(document, options);
+        if !definitions.is_empty() {
+            lines.separate()?;
+            lines.write(&definitions.join("\n"))?;
+        }
     }
 
-    // 문단 사이에 빈 줄을 추가하여 마크다운에서 각 문단이 구분되도록 함
-    // Add blank lines between paragraphs so each paragraph is distinguished in markdown
-    lines.join("\n\n")
+    Ok(())
+}
+
+/// Writes lines joined by blank lines (`"\n\n"`)
+/// 빈 줄(`"\n\n"`)로 이어서 줄을 출력
+struct BlockWriter<'a, W: Write + ?Sized> {
+    writer: &'a mut W,
+    /// 출력한 줄이 있는지 / Whether any line has been written
+    started: bool,
+    /// 마지막으로 출력한 줄이 비어 있는지 / Whether the last written line was empty
+    last_empty: bool,
+}
+
+impl<'a, W: Write + ?Sized> BlockWriter<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            started: false,
+            last_empty: false,
+        }
+    }
+
+    /// 줄 출력 / Write a line
+    fn write(&mut self, line: &str) -> io::Result<()> {
+        if self.started {
+            self.writer.write_all(b"\n\n")?;
+        }
+        self.writer.write_all(line.as_bytes())?;
+        self.started = true;
+        self.last_empty = line.is_empty();
+        Ok(())
+    }
+
+    /// 마지막 줄이 비어 있지 않으면 빈 줄 추가 / Add an empty line unless the last line is empty
+    fn separate(&mut self) -> io::Result<()> {
+        if self.started && !self.last_empty {
+            self.write("")?;
+        }
+        Ok(())
+    }
 }
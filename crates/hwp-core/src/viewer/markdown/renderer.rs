@@ -2,6 +2,7 @@
 /// Markdown 렌더러 구현
 use crate::document::{bodytext::Table, HwpDocument};
 use crate::viewer::core::renderer::{DocumentParts, Renderer, TextStyles};
+use crate::viewer::markdown::utils::{note_label, note_reference};
 
 /// Markdown Renderer
 pub struct MarkdownRenderer;
@@ -81,12 +82,7 @@ impl Renderer for MarkdownRenderer {
             .iter()
             .find(|item| item.index == image_id)
         {
-            let image_markdown = format_image_markdown(
-                document,
-                image_id,
-                &bin_item.data,
-                options.image_output_dir.as_deref(),
-            );
+            let image_markdown = format_image_markdown(document, image_id, &bin_item.data, options);
             if !image_markdown.is_empty() {
                 return Some(image_markdown);
             }
@@ -150,16 +146,16 @@ impl Renderer for MarkdownRenderer {
     }
 
     // ===== Special Elements =====
-    fn render_footnote_ref(&self, _id: u32, number: &str, _options: &Self::Options) -> String {
-        // 마크다운에서는 각주 참조를 [^1] 형식으로 표시
-        // In markdown, footnote references are shown as [^1]
-        format!("[^{number}]")
+    fn render_footnote_ref(&self, _id: u32, number: &str, options: &Self::Options) -> String {
+        // 마크다운에서는 각주 참조를 [^1] 형식으로 표시 (각주 문법이 없는 방언은 \[1\])
+        // In markdown, footnote references are shown as [^1] (\[1\] for flavors without footnotes)
+        note_reference(&note_label(number, false, options), options)
     }
 
-    fn render_endnote_ref(&self, _id: u32, number: &str, _options: &Self::Options) -> String {
-        // 마크다운에서는 미주 참조를 [^1] 형식으로 표시
-        // In markdown, endnote references are shown as [^1]
-        format!("[^{number}]")
+    fn render_endnote_ref(&self, _id: u32, number: &str, options: &Self::Options) -> String {
+        // 마크다운에서는 미주 참조를 [^1] 형식으로 표시 (방언이 있으면 [^e1])
+        // In markdown, endnote references are shown as [^1] ([^e1] with a flavor)
+        note_reference(&note_label(number, true, options), options)
     }
 
     fn render_footnote_back(&self, _ref_id: &str, _options: &Self::Options) -> String {
@@ -1,7 +1,14 @@
 /// Utility functions for Markdown conversion
 /// 마크다운 변환을 위한 유틸리티 함수들
+use std::borrow::Cow;
+
+use crate::document::bodytext::{
+    normalize_inline_controls, ControlChar, ControlCharPosition, InlineControl,
+};
 use crate::document::{HeaderShapeType, HwpDocument};
 
+use super::{LineBreakMode, MarkdownFlavor, MarkdownOptions};
+
 /// 개요 번호 추적 구조체 / Outline number tracking structure
 /// 각 레벨별로 번호를 추적하여 개요 번호를 생성
 /// Tracks numbers per level to generate outline numbers
@@ -50,6 +57,17 @@ impl OutlineNumberTracker {
             0
         }
     }
+
+    /// 방금 센 레벨까지의 번호를 점으로 이은 경로 (`1.2.3`)
+    /// Numbers up to the level just counted, joined with dots (`1.2.3`)
+    pub(crate) fn path(&self, level: u8) -> String {
+        let depth = (level as usize).clamp(1, 7);
+        self.counters[..depth]
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
 }
 
 /// 버전 번호를 읽기 쉬운 문자열로 변환
@@ -92,10 +110,130 @@ pub(crate) fn is_text_part(part: &str) -> bool {
 /// Check if a part is a block element (image, table, etc.)
 /// part가 블록 요소인지 확인 (이미지, 표 등)
 pub(crate) fn is_block_element(part: &str) -> bool {
-    part.starts_with("![이미지]")
+    // 개요 번호는 블록 요소가 아님 / Outline numbers are not block elements
+    part.starts_with("![") // 이미지 / image
         || part.starts_with("|") // 테이블 / table
         || part.starts_with("---") // 페이지 구분선 / page break
-                                   // 개요 번호는 블록 요소가 아님 / Outline numbers are not block elements
+        || part.starts_with("<div align=") // 정렬 유지 문단 / aligned paragraph
+}
+
+/// Text a LINE_BREAK or PARA_BREAK control character is rendered as
+/// 줄 나눔/문단 나눔 제어 문자를 옮길 문자열
+///
+/// 표 셀 안에는 개행을 쓸 수 없으므로 개행 대신 `<br>`을 씁니다. 원시 HTML을 허용하지 않는
+/// 방언에서는 `<br>` 대신 본문은 강제 줄 나눔, 표 셀은 공백을 씁니다.
+/// Newlines cannot appear inside table cells, so `<br>` is used there instead. Flavors without
+/// raw HTML use a hard line break in the body and a space in table cells instead of `<br>`.
+pub(crate) fn break_text(
+    mode: LineBreakMode,
+    code: u8,
+    in_table: bool,
+    flavor: Option<MarkdownFlavor>,
+) -> &'static str {
+    let paragraph = code == ControlChar::PARA_BREAK;
+    let hard_break = flavor.map_or("  \n", MarkdownFlavor::hard_break);
+    if !flavor.map_or(true, MarkdownFlavor::allows_raw_html) {
+        return match (mode, in_table) {
+            (LineBreakMode::Space, _) | (_, true) => " ",
+            (LineBreakMode::Preserve, false) if paragraph => "\n\n",
+            (_, false) => hard_break,
+        };
+    }
+    match (mode, in_table) {
+        (LineBreakMode::Space, _) => " ",
+        (LineBreakMode::Br, _) => "<br>",
+        (LineBreakMode::Preserve, true) if paragraph => "<br><br>",
+        (LineBreakMode::Preserve, false) if paragraph => "\n\n",
+        (LineBreakMode::Newline | LineBreakMode::Preserve, true) => "<br>",
+        (LineBreakMode::Newline | LineBreakMode::Preserve, false) => hard_break,
+    }
+}
+
+/// 각주/미주 이름표 (방언이 있으면 미주 번호에 "e"를 붙여 각주 번호와 겹치지 않게 함)
+/// Footnote or endnote label (with a flavor, endnote numbers get an "e" prefix so they never
+/// clash with footnote numbers)
+pub(crate) fn note_label(number: &str, endnote: bool, options: &MarkdownOptions) -> String {
+    if endnote && options.flavor.is_some() {
+        format!("e{number}")
+    } else {
+        number.to_string()
+    }
+}
+
+/// 본문의 각주/미주 참조 / Footnote or endnote reference in the body
+///
+/// 각주 문법이 없는 방언은 이스케이프한 대괄호 텍스트(`\[1\]`)를 씁니다.
+/// Flavors without footnote syntax use escaped bracket text (`\[1\]`).
+pub(crate) fn note_reference(label: &str, options: &MarkdownOptions) -> String {
+    match options.flavor {
+        Some(flavor) if !flavor.has_footnotes() => format!("\\[{label}\\]"),
+        _ => format!("[^{label}]"),
+    }
+}
+
+/// 각주/미주 내용 문단 / Footnote or endnote content paragraph
+///
+/// 방언이 있으면 첫 문단 앞에 정의 표시(`[^1]: `)를 붙이고, 이어지는 문단은 같은 각주에
+/// 속하도록 네 칸 들여씁니다. 방언이 없으면 내용만 돌려줍니다.
+/// With a flavor, the first paragraph gets the definition marker (`[^1]: `) and following
+/// paragraphs are indented four spaces so they stay in the same note. Without a flavor only the
+/// content is returned.
+pub(crate) fn note_definition(
+    label: &str,
+    content: &str,
+    first: bool,
+    options: &MarkdownOptions,
+) -> String {
+    match options.flavor {
+        None => content.to_string(),
+        Some(flavor) if !flavor.has_footnotes() => match first {
+            true => format!("\\[{label}\\] {content}"),
+            false => content.to_string(),
+        },
+        Some(_) if first => format!("[^{label}]: {content}"),
+        Some(_) => content
+            .lines()
+            .map(|line| format!("    {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Insert rendered LINE_BREAK/PARA_BREAK controls back into paragraph text
+/// 문단 텍스트에 줄 나눔/문단 나눔 제어 문자를 옮긴 문자열을 다시 끼워 넣음
+///
+/// 텍스트 끝의 나눔은 문단을 끝내는 표시일 뿐이므로 버립니다.
+/// A break at the end of the text only terminates the paragraph, so it is dropped.
+pub(crate) fn insert_breaks(
+    text: &str,
+    control_positions: &[ControlCharPosition],
+    mode: LineBreakMode,
+    in_table: bool,
+    flavor: Option<MarkdownFlavor>,
+) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut breaks: Vec<_> = control_positions
+        .iter()
+        .filter(|pos| pos.code == ControlChar::PARA_BREAK || pos.code == ControlChar::LINE_BREAK)
+        .filter(|pos| pos.position < chars.len())
+        .collect();
+    breaks.sort_by_key(|pos| pos.position);
+
+    let mut result = String::new();
+    let mut last = 0;
+    for pos in breaks {
+        result.extend(&chars[last..pos.position.max(last)]);
+        result.push_str(break_text(mode, pos.code, in_table, flavor));
+        last = pos.position.max(last);
+    }
+    result.extend(&chars[last..]);
+    result
+}
+
+/// 인라인 제어 문자를 옵션의 출력 문자열로 바꿈 (기본값: 일반 텍스트 표현)
+/// Replace inline control characters with the options' output strings (default: plain text representation)
+pub(crate) fn inline_controls_text<'a>(text: &'a str, options: &MarkdownOptions) -> Cow<'a, str> {
+    normalize_inline_controls(text, &options.inline_controls, InlineControl::plain_text)
 }
 
 /// Check if control header should be processed for markdown
@@ -203,130 +341,114 @@ fn is_format_string_empty_or_null(format_string: &str) -> bool {
     false
 }
 
-/// 개요 레벨이면 텍스트 앞에 개요 번호를 추가
-/// Add outline number prefix to text if it's an outline level
-pub(crate) fn convert_to_outline_with_number(
-    text: &str,
+/// 개요 문단의 실제 레벨 (1~10), 개요 문단이 아니면 None
+/// Actual level (1~10) of an outline paragraph, None for other paragraphs
+pub(crate) fn outline_level(
     para_header: &crate::document::bodytext::ParaHeader,
     document: &HwpDocument,
-    tracker: &mut OutlineNumberTracker,
-) -> String {
+) -> Option<u8> {
     // ParaShape 찾기 (para_shape_id는 인덱스) / Find ParaShape (para_shape_id is index)
-    let para_shape_id = para_header.para_shape_id as usize;
-    if let Some(para_shape) = document.doc_info.para_shapes.get(para_shape_id) {
-        // 개요 타입이면 개요 번호 추가 / If outline type, add outline number
-        if para_shape.attributes1.header_shape_type == HeaderShapeType::Outline {
-            // paragraph_level + 1 = 실제 레벨 (0=레벨1, 1=레벨2, 2=레벨3, ...)
-            // paragraph_level + 1 = actual level (0=level1, 1=level2, 2=level3, ...)
-            let base_level = para_shape.attributes1.paragraph_level + 1;
-
-            // paragraph_level이 6이고 line_spacing이 7 이상이면 실제 레벨은 para_style_id를 사용하여 결정
-            // If paragraph_level is 6 and line_spacing is 7 or higher, determine actual level using para_style_id
-            let level = if base_level == 7 {
-                // paragraph_level이 6이면 base_level은 7 / If paragraph_level is 6, base_level is 7
-                // line_spacing이 7 이상이면 확장 레벨 (8-10) / If line_spacing >= 7, extended level (8-10)
-                // para_style_id를 사용하여 스타일 이름에서 레벨 추출
-                // Use para_style_id to extract level from style name
-                if let Some(line_spacing) = para_shape.line_spacing {
-                    if (7..=10).contains(&line_spacing) {
-                        // para_style_id로 스타일 찾기 / Find style by para_style_id
-                        if let Some(style) = document
-                            .doc_info
-                            .styles
-                            .get(para_header.para_style_id as usize)
-                        {
-                            // 스타일 이름에서 레벨 추출 (예: "개요 8" -> 8, "개요 9" -> 9, "개요 10" -> 10)
-                            // Extract level from style name (e.g., "개요 8" -> 8, "개요 9" -> 9, "개요 10" -> 10)
-                            if style.local_name.starts_with("개요 ") {
-                                if let Ok(style_level) = style.local_name[3..].trim().parse::<u8>()
-                                {
-                                    if (8..=10).contains(&style_level) {
-                                        style_level
-                                    } else {
-                                        // 스타일 레벨이 범위를 벗어나면 line_spacing 기반 계산
-                                        // If style level is out of range, calculate based on line_spacing
-                                        (line_spacing + 1) as u8
-                                    }
-                                } else {
-                                    // 스타일 이름 파싱 실패 시 line_spacing 기반 계산
-                                    // If style name parsing fails, calculate based on line_spacing
-                                    (line_spacing + 1) as u8
-                                }
-                            } else {
-                                // 스타일 이름이 "개요 "로 시작하지 않으면 line_spacing 기반 계산
-                                // If style name doesn't start with "개요 ", calculate based on line_spacing
-                                (line_spacing + 1) as u8
-                            }
-                        } else {
-                            // 스타일을 찾을 수 없으면 line_spacing 기반 계산
-                            // If style cannot be found, calculate based on line_spacing
-                            (line_spacing + 1) as u8
-                        }
-                    } else {
-                        base_level
+    let para_shape = document
+        .doc_info
+        .para_shapes
+        .get(para_header.para_shape_id as usize)?;
+    if para_shape.attributes1.header_shape_type != HeaderShapeType::Outline {
+        return None;
+    }
+
+    // 문단 수준 + 1 = 실제 레벨 (0=레벨1, 1=레벨2, 2=레벨3, ...)
+    // Paragraph level + 1 = actual level (0=level1, 1=level2, 2=level3, ...)
+    // 개요 8~10 수준은 속성1의 3비트에 담기지 않아 따로 저장된 문단 수준을 씀
+    // Outline levels 8-10 do not fit the three bits of attributes 1, so the separately stored
+    // paragraph level is used
+    let level = match para_shape.paragraph_level {
+        Some(level @ 0..=9) => level as u8 + 1,
+        _ => para_shape.attributes1.paragraph_level + 1,
+    };
+    Some(level)
+}
+
+/// 번호가 붙는 개요 문단의 레벨, 번호 형식이 비어 번호를 쓰지 않으면 None
+/// Level of an outline paragraph that gets a number, None when its number format is blank
+pub(crate) fn numbered_outline_level(
+    para_header: &crate::document::bodytext::ParaHeader,
+    document: &HwpDocument,
+) -> Option<u8> {
+    let level = outline_level(para_header, document)?;
+    let para_shape = document
+        .doc_info
+        .para_shapes
+        .get(para_header.para_shape_id as usize)?;
+
+    // numbering_id로 numbering 정보 찾기 / Find numbering info by numbering_id
+    let numbering_id = para_shape.number_bullet_id as usize;
+
+    // 레벨 8-10인 경우 extended_levels의 format_string 확인
+    // For levels 8-10, check format_string from extended_levels
+    if level >= 8 {
+        if let Some(numbering) = document.doc_info.numbering.get(numbering_id) {
+            // 레벨 8은 extended_levels[0], 레벨 9는 extended_levels[1], 레벨 10은 extended_levels[2]
+            // Level 8 is extended_levels[0], level 9 is extended_levels[1], level 10 is extended_levels[2]
+            let extended_index = (level - 8) as usize;
+            // extended_levels 배열 범위 확인
+            // Check extended_levels array bounds
+            if extended_index < numbering.extended_levels.len() {
+                if let Some(extended_level) = numbering.extended_levels.get(extended_index) {
+                    // 확장 수준은 기본 형식이 없어, 비었거나 null 문자만 있으면 번호 없음
+                    // Extended levels have no default format, so an empty or null-only
+                    // format_string means no number
+                    if extended_level.format_string.is_empty()
+                        || is_format_string_empty_or_null(&extended_level.format_string)
+                    {
+                        return None;
                     }
-                } else {
-                    base_level
                 }
             } else {
-                base_level
-            };
-
-            // numbering_id로 numbering 정보 찾기 / Find numbering info by numbering_id
-            let numbering_id = para_shape.number_bullet_id as usize;
-
-            // 레벨 8-10인 경우 extended_levels의 format_string 확인
-            // For levels 8-10, check format_string from extended_levels
-            if level >= 8 {
-                if let Some(numbering) = document.doc_info.numbering.get(numbering_id) {
-                    // 레벨 8은 extended_levels[0], 레벨 9는 extended_levels[1], 레벨 10은 extended_levels[2]
-                    // Level 8 is extended_levels[0], level 9 is extended_levels[1], level 10 is extended_levels[2]
-                    let extended_index = (level - 8) as usize;
-                    // extended_levels 배열 범위 확인
-                    // Check extended_levels array bounds
-                    if extended_index < numbering.extended_levels.len() {
-                        if let Some(extended_level) = numbering.extended_levels.get(extended_index)
-                        {
-                            // format_string이 null 문자만 포함하면 번호 없이 텍스트만 반환
-                            // If format_string contains only null character, return text only without number
-                            if is_format_string_empty_or_null(&extended_level.format_string) {
-                                return text.to_string();
-                            }
-                        }
-                    } else {
-                        // extended_levels 배열 범위를 벗어나면 넘버링 없이 텍스트만 반환
-                        // If extended_levels array index is out of bounds, return text only without number
-                        return text.to_string();
-                    }
-                    // extended_levels가 없으면 번호 생성 (기본 동작)
-                    // If extended_levels don't exist, generate number (default behavior)
-                } else {
-                    // numbering이 없으면 넘버링 없이 텍스트만 반환 (레벨 8 이상은 numbering이 필요)
-                    // If numbering doesn't exist, return text only without number (levels 8+ require numbering)
-                    return text.to_string();
-                }
+                // extended_levels 배열 범위를 벗어나면 번호 없음
+                // If extended_levels array index is out of bounds, no number
+                return None;
             }
+            // extended_levels가 없으면 번호 생성 (기본 동작)
+            // If extended_levels don't exist, generate number (default behavior)
+        } else {
+            // numbering이 없으면 번호 없음 (레벨 8 이상은 numbering이 필요)
+            // If numbering doesn't exist, no number (levels 8+ require numbering)
+            return None;
+        }
+    }
 
-            // 레벨 1-7인 경우 format_string 확인 (빈 문자열이면 기본 형식 사용)
-            // For levels 1-7, check format_string (empty string uses default format)
-            if let Some(numbering) = document.doc_info.numbering.get(numbering_id) {
-                let level_index = (level - 1) as usize;
-                if let Some(level_info) = numbering.levels.get(level_index) {
-                    // format_string이 null 문자만 포함하면 번호 없이 텍스트만 반환
-                    // If format_string contains only null character, return text only without number
-                    // 빈 문자열("")은 기본 형식 사용 (번호 표시)
-                    // Empty string ("") uses default format (show number)
-                    if is_format_string_empty_or_null(&level_info.format_string) {
-                        return text.to_string();
-                    }
-                }
+    // 레벨 1-7인 경우 format_string 확인 (빈 문자열이면 기본 형식 사용)
+    // For levels 1-7, check format_string (empty string uses default format)
+    if let Some(numbering) = document.doc_info.numbering.get(numbering_id) {
+        let level_index = (level - 1) as usize;
+        if let Some(level_info) = numbering.levels.get(level_index) {
+            // format_string이 null 문자만 포함하면 번호 없음
+            // If format_string contains only null character, no number
+            // 빈 문자열("")은 기본 형식 사용 (번호 표시)
+            // Empty string ("") uses default format (show number)
+            if is_format_string_empty_or_null(&level_info.format_string) {
+                return None;
             }
-
-            // format_string이 있으면 번호 생성 / Generate number if format_string exists
-            let number = tracker.get_and_increment(level);
-            let outline_number = format_outline_number(level, number);
-            return format!("{outline_number} {text}");
         }
     }
-    text.to_string()
+
+    Some(level)
+}
+
+/// 개요 레벨이면 텍스트 앞에 개요 번호를 추가
+/// Add outline number prefix to text if it's an outline level
+pub(crate) fn convert_to_outline_with_number(
+    text: &str,
+    para_header: &crate::document::bodytext::ParaHeader,
+    document: &HwpDocument,
+    tracker: &mut OutlineNumberTracker,
+) -> String {
+    let Some(level) = numbered_outline_level(para_header, document) else {
+        return text.to_string();
+    };
+
+    // format_string이 있으면 번호 생성 / Generate number if format_string exists
+    let number = tracker.get_and_increment(level);
+    let outline_number = format_outline_number(level, number);
+    format!("{outline_number} {text}")
 }
@@ -1,3 +1,4 @@
+pub mod asciidoc;
 #[allow(missing_docs)] // TODO: Implement Canvas viewer
 pub mod canvas;
 /// Viewer module for converting HWP documents to various formats
@@ -8,10 +9,24 @@ pub mod canvas;
 /// 이 모듈은 파싱된 HWP 문서를 마크다운, HTML, Canvas, PDF 등 다양한 출력 형식으로 변환하는 기능을 제공합니다.
 pub mod core;
 pub mod html;
+pub mod image_export;
+pub mod image_sink;
 pub mod markdown;
+pub mod pandoc;
 #[allow(missing_docs)] // TODO: Implement PDF viewer
 pub mod pdf;
+pub mod rst;
+pub mod toc;
 
+pub use asciidoc::to_asciidoc;
 pub use core::renderer::{DocumentParts, Renderer, TextStyles};
-pub use html::{to_html, HtmlOptions};
-pub use markdown::{to_markdown, MarkdownOptions};
+pub use core::visitor::{walk_document, walk_paragraph, DocumentVisitor};
+pub use html::{to_html, to_html_writer, HtmlOptions, MasterPageMode};
+pub use image_export::ImageFormat;
+#[cfg(feature = "fs")]
+pub use image_sink::FileImageSink;
+pub use image_sink::ImageSink;
+pub use markdown::{to_markdown, to_markdown_writer, MarkdownOptions};
+pub use pandoc::to_pandoc;
+pub use rst::to_rst;
+pub use toc::TocEntry;
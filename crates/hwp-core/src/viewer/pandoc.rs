@@ -0,0 +1,152 @@
+/// Pandoc JSON AST export
+/// Pandoc JSON AST 내보내기
+///
+/// 문서 모델을 Pandoc의 JSON AST(`pandoc -f json`)로 옮겨, LaTeX/ODT/reST 등 나머지 형식은
+/// pandoc으로 변환할 수 있게 합니다. 개요 문단은 제목, 표는 병합 정보를 가진 표, 각주/미주는
+/// 문단 끝의 `Note`, 그림은 data URI `Image`가 됩니다.
+/// Maps the document model to Pandoc's JSON AST (`pandoc -f json`) so every other format
+/// (LaTeX, ODT, reST, ...) can be produced by pandoc. Outline paragraphs become headers, tables
+/// keep their merged cells, footnotes/endnotes become a `Note` at the end of their paragraph and
+/// pictures become data URI `Image`s.
+use crate::document::HwpDocument;
+use crate::viewer::core::blocks::{
+    document_blocks, text_inlines, Block, Emphasis, Inline, TableBlock,
+};
+use serde_json::{json, Value};
+
+/// 출력하는 Pandoc AST 버전 / Pandoc AST version written to the output
+///
+/// pandoc 3.x가 읽는 `pandoc-types` 1.23 형식입니다.
+/// The `pandoc-types` 1.23 format read by pandoc 3.x.
+pub const PANDOC_API_VERSION: [u32; 3] = [1, 23, 1];
+
+/// 문서를 Pandoc JSON AST로 변환 / Convert a document to the Pandoc JSON AST
+///
+/// # Returns / 반환값
+/// `{"pandoc-api-version", "meta", "blocks"}` 객체 / A `{"pandoc-api-version", "meta", "blocks"}` object
+pub fn to_pandoc(document: &HwpDocument) -> Value {
+    let blocks: Vec<Value> = document_blocks(document).iter().map(block_json).collect();
+    json!({
+        "pandoc-api-version": PANDOC_API_VERSION,
+        "meta": convert_meta(document),
+        "blocks": blocks,
+    })
+}
+
+/// 문서 요약 정보를 메타데이터로 / Document summary information as metadata
+fn convert_meta(document: &HwpDocument) -> Value {
+    let mut meta = serde_json::Map::new();
+    if let Some(summary) = &document.summary_information {
+        for (key, value) in [
+            ("title", &summary.title),
+            ("author", &summary.author),
+            ("subject", &summary.subject),
+        ] {
+            if let Some(value) = value.as_deref().filter(|value| !value.trim().is_empty()) {
+                meta.insert(
+                    key.to_string(),
+                    json!({ "t": "MetaInlines", "c": inlines_json(&text_inlines(value)) }),
+                );
+            }
+        }
+    }
+    Value::Object(meta)
+}
+
+/// 블록 하나 / One block
+fn block_json(block: &Block) -> Value {
+    match block {
+        Block::Heading {
+            level,
+            anchor,
+            inlines,
+        } => json!({
+            "t": "Header",
+            "c": [level, [anchor, [], []], inlines_json(inlines)],
+        }),
+        Block::Paragraph(inlines) => json!({ "t": "Para", "c": inlines_json(inlines) }),
+        Block::Table(table) => table_json(table),
+    }
+}
+
+fn inlines_json(inlines: &[Inline]) -> Vec<Value> {
+    inlines.iter().map(inline_json).collect()
+}
+
+/// 인라인 하나 / One inline
+fn inline_json(inline: &Inline) -> Value {
+    match inline {
+        Inline::Text(text) => json!({ "t": "Str", "c": text }),
+        Inline::Space => json!({ "t": "Space" }),
+        Inline::LineBreak => json!({ "t": "LineBreak" }),
+        Inline::Styled(emphasis, inner) => {
+            let tag = match emphasis {
+                Emphasis::Strong => "Strong",
+                Emphasis::Emph => "Emph",
+                Emphasis::Underline => "Underline",
+                Emphasis::Strikeout => "Strikeout",
+                Emphasis::Superscript => "Superscript",
+                Emphasis::Subscript => "Subscript",
+            };
+            json!({ "t": tag, "c": inlines_json(inner) })
+        }
+        Inline::Note(blocks) => {
+            json!({ "t": "Note", "c": blocks.iter().map(block_json).collect::<Vec<_>>() })
+        }
+        Inline::Image(image) => json!({
+            "t": "Image",
+            "c": [
+                ["", [], []],
+                inlines_json(&text_inlines(&image.alt)),
+                [image.data_uri(), ""],
+            ],
+        }),
+    }
+}
+
+/// 표 (병합 셀은 rowspan/colspan으로) / A table (merged cells as rowspan/colspan)
+///
+/// 제목 줄 반복이 켜진 표의 첫 행은 머리행(`TableHead`)이 됩니다.
+/// The first row of a table with header row repeat becomes the `TableHead`.
+fn table_json(table: &TableBlock) -> Value {
+    let mut rows = vec![Vec::new(); table.row_count];
+    for cell in &table.cells {
+        // pandoc 표 칸 안에서는 Para 대신 Plain이 기본 / Plain is the default over Para inside pandoc cells
+        let content: Vec<Value> = cell
+            .blocks
+            .iter()
+            .map(|block| match block {
+                Block::Paragraph(inlines) => json!({ "t": "Plain", "c": inlines_json(inlines) }),
+                _ => block_json(block),
+            })
+            .collect();
+        rows[cell.row].push(json!([
+            ["", [], []],
+            { "t": "AlignDefault" },
+            cell.row_span,
+            cell.col_span,
+            content,
+        ]));
+    }
+
+    let mut rows: Vec<Value> = rows
+        .into_iter()
+        .map(|cells| json!([["", [], []], cells]))
+        .collect();
+    let body = rows.split_off(table.header_rows);
+    let col_specs: Vec<Value> = (0..table.col_count)
+        .map(|_| json!([{ "t": "AlignDefault" }, { "t": "ColWidthDefault" }]))
+        .collect();
+
+    json!({
+        "t": "Table",
+        "c": [
+            ["", [], []],
+            [null, []],
+            col_specs,
+            [["", [], []], rows],
+            [[["", [], []], 0, [], body]],
+            [["", [], []], []],
+        ],
+    })
+}
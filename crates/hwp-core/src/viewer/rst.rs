@@ -0,0 +1,434 @@
+/// reStructuredText output
+/// reStructuredText 출력
+///
+/// Sphinx 문서로 옮길 수 있도록 공통 블록 트리([`crate::viewer::core`])를 reStructuredText로
+/// 씁니다. 개요 문단은 밑줄 제목과 `.. _toc-*:` 레이블, 표는 병합 칸을 살린 grid table,
+/// 각주/미주는 자동 번호 각주(`[#]_`), 그림은 문서 끝에 정의한 치환(`|image1|`)이 됩니다.
+/// Writes the shared block tree ([`crate::viewer::core`]) as reStructuredText for Sphinx.
+/// Outline paragraphs become underlined titles with a `.. _toc-*:` label, tables become grid
+/// tables that keep merged cells, footnotes/endnotes become auto-numbered footnotes (`[#]_`) and
+/// pictures become substitutions (`|image1|`) defined at the end of the document.
+use crate::document::HwpDocument;
+use crate::viewer::core::blocks::{
+    close_heading_gaps, document_blocks, plain_text, Block, Emphasis, ImageRef, Inline, TableBlock,
+};
+
+/// 제목 수준별 밑줄 문자 (Sphinx 관례) / Underline characters per heading level (Sphinx convention)
+const HEADING_UNDERLINES: [char; 6] = ['=', '-', '~', '^', '"', '\''];
+
+/// 그림 data URI 한 줄의 길이 / Length of one line of a picture data URI
+const IMAGE_URI_LINE_WIDTH: usize = 76;
+
+/// 문서를 reStructuredText로 변환 / Convert a document to reStructuredText
+///
+/// 요약 정보에 제목이 있으면 문서 제목(윗줄과 밑줄)으로 씁니다. 그림은 data URI로 들어갑니다.
+/// A title in the summary information becomes the document title (overline and underline).
+/// Pictures are embedded as data URIs.
+pub fn to_rst(document: &HwpDocument) -> String {
+    let mut output = String::new();
+    let title = document
+        .summary_information
+        .as_ref()
+        .and_then(|summary| summary.title.as_deref())
+        .map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|title| !title.is_empty());
+    if let Some(title) = title {
+        let rule = "#".repeat(display_width(&title));
+        output.push_str(&format!("{rule}\n{}\n{rule}\n\n", escape_text(&title)));
+    }
+
+    let mut writer = RstWriter::default();
+    let mut blocks = document_blocks(document);
+    // docutils는 처음 나온 순서로 밑줄 문자의 수준을 정함 / docutils ranks underline characters in order of first appearance
+    close_heading_gaps(&mut blocks);
+    output.push_str(&join_blocks(&writer.blocks(&blocks)));
+
+    // 각주와 그림 치환 정의는 문서 끝에 / Footnote and picture substitution definitions go at the end
+    if !writer.notes.is_empty() {
+        output.push('\n');
+        for note in &writer.notes {
+            output.push('\n');
+            for (index, line) in note.iter().enumerate() {
+                match (index, line.is_empty()) {
+                    (0, _) => output.push_str(&format!(".. [#] {line}\n")),
+                    (_, true) => output.push('\n'),
+                    (_, false) => output.push_str(&format!("   {line}\n")),
+                }
+            }
+        }
+    }
+    if !writer.images.is_empty() {
+        output.push('\n');
+        for (index, image) in writer.images.iter().enumerate() {
+            // docutils의 줄 길이 제한을 넘지 않게 URI를 여러 줄로 나눔 (공백은 무시됨)
+            // Split the URI over several lines to stay under the docutils line length limit (whitespace is ignored)
+            let uri = image.data_uri();
+            let uri_lines: Vec<&str> = uri
+                .as_bytes()
+                .chunks(IMAGE_URI_LINE_WIDTH)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+                .collect();
+            output.push_str(&format!(
+                "\n.. |image{}| image:: {}\n   :alt: {}\n",
+                index + 1,
+                uri_lines.join("\n   "),
+                image.alt
+            ));
+        }
+    }
+    output
+}
+
+/// 각주와 그림을 모으며 블록을 쓰는 상태 / Writer state collecting footnotes and pictures
+#[derive(Default)]
+struct RstWriter<'a> {
+    /// 각주 내용 줄 (문서 순서) / Footnote content lines (document order)
+    notes: Vec<Vec<String>>,
+    images: Vec<ImageRef<'a>>,
+}
+
+impl<'a> RstWriter<'a> {
+    /// 블록마다 줄 목록 / A list of lines per block
+    fn blocks(&mut self, blocks: &[Block<'a>]) -> Vec<Vec<String>> {
+        blocks.iter().map(|block| self.block(block)).collect()
+    }
+
+    fn block(&mut self, block: &Block<'a>) -> Vec<String> {
+        match block {
+            Block::Heading {
+                level,
+                anchor,
+                inlines,
+            } => {
+                let title = self.inlines(inlines).replace('\n', " ");
+                let underline = HEADING_UNDERLINES[(*level as usize).clamp(1, 6) - 1];
+                vec![
+                    format!(".. _{anchor}:"),
+                    String::new(),
+                    title.clone(),
+                    underline.to_string().repeat(display_width(&title)),
+                ]
+            }
+            Block::Paragraph(inlines) => {
+                let text = self.inlines(inlines);
+                if text.contains('\n') {
+                    // 줄바꿈은 line block으로 / Line breaks become a line block
+                    text.lines().map(|line| format!("| {line}")).collect()
+                } else {
+                    vec![escape_line_start(&text)]
+                }
+            }
+            Block::Table(table) => self.table(table),
+        }
+    }
+
+    /// 인라인 목록 (줄바꿈은 `\n`) / An inline list (line breaks as `\n`)
+    ///
+    /// 인라인 마크업은 앞뒤에 공백이나 문장 부호가 있어야 하므로, 한글처럼 붙어 쓰는 글자와
+    /// 맞닿으면 보이지 않는 이스케이프 공백(`\ `)을 넣습니다.
+    /// Inline markup must be delimited by whitespace or punctuation, so an invisible escaped space
+    /// (`\ `) is inserted where it touches adjoining text such as Hangul.
+    fn inlines(&mut self, inlines: &[Inline<'a>]) -> String {
+        let mut output = String::new();
+        let mut after_markup = false;
+        for inline in inlines {
+            let markup = match inline {
+                Inline::Text(text) => {
+                    if after_markup && !text.starts_with(|c| MARKUP_END_FOLLOWERS.contains(c)) {
+                        output.push_str("\\ ");
+                    }
+                    output.push_str(&escape_text(text));
+                    after_markup = false;
+                    continue;
+                }
+                Inline::Space => {
+                    output.push(' ');
+                    after_markup = false;
+                    continue;
+                }
+                Inline::LineBreak => {
+                    output.push('\n');
+                    after_markup = false;
+                    continue;
+                }
+                Inline::Styled(_, inner) => match styled_markup(inline) {
+                    Some(markup) => markup,
+                    None => {
+                        // 대응하는 마크업이 없는 강조(밑줄, 취소선)는 글자만 / Emphasis without markup (underline, strikeout) keeps only the text
+                        let text = self.inlines(inner);
+                        if after_markup && !text.starts_with(|c| MARKUP_END_FOLLOWERS.contains(c)) {
+                            output.push_str("\\ ");
+                        }
+                        output.push_str(&text);
+                        after_markup = text.ends_with(['*', '`', '_', '|']);
+                        continue;
+                    }
+                },
+                Inline::Note(blocks) => {
+                    let lines = join_blocks(&self.blocks(blocks));
+                    self.notes
+                        .push(lines.trim_end().lines().map(str::to_string).collect());
+                    "[#]_".to_string()
+                }
+                Inline::Image(image) => {
+                    self.images.push(image.clone());
+                    format!("|image{}|", self.images.len())
+                }
+            };
+            if output
+                .chars()
+                .last()
+                .is_some_and(|c| !MARKUP_START_PRECEDERS.contains(c))
+            {
+                output.push_str("\\ ");
+            }
+            output.push_str(&markup);
+            after_markup = true;
+        }
+        output
+    }
+
+    /// grid table (병합 칸은 경계선을 지워서) / A grid table (merged cells by leaving out borders)
+    fn table(&mut self, table: &TableBlock<'a>) -> Vec<String> {
+        let (rows, cols) = (table.row_count.max(1), table.col_count.max(1));
+
+        // 겹치는 칸은 건너뛰고 빈 자리는 빈 칸으로 채움 / Skip overlapping cells and fill gaps with empty cells
+        let mut covered = vec![vec![false; cols]; rows];
+        let mut cells: Vec<(usize, usize, usize, usize, Vec<String>)> = Vec::new();
+        for cell in &table.cells {
+            let (row_end, col_end) = (cell.row + cell.row_span, cell.col + cell.col_span);
+            if (cell.row..row_end).any(|r| (cell.col..col_end).any(|c| covered[r][c])) {
+                continue;
+            }
+            for row in covered.iter_mut().take(row_end).skip(cell.row) {
+                row[cell.col..col_end].fill(true);
+            }
+            let lines = join_blocks(&self.blocks(&cell.blocks));
+            let lines = lines.lines().map(str::to_string).collect();
+            cells.push((cell.row, cell.col, cell.row_span, cell.col_span, lines));
+        }
+        for (row, covered) in covered.iter().enumerate() {
+            for (col, _) in covered.iter().enumerate().filter(|(_, covered)| !**covered) {
+                cells.push((row, col, 1, 1, Vec::new()));
+            }
+        }
+
+        // 열 너비와 행 높이 (병합 칸은 마지막 열/행을 늘림) / Column widths and row heights (merged cells grow the last column/row)
+        let mut widths = vec![1; cols];
+        let mut heights = vec![1; rows];
+        cells.sort_by_key(|&(row, col, row_span, col_span, _)| (row_span, col_span, row, col));
+        for (row, col, row_span, col_span, lines) in &cells {
+            let width = lines
+                .iter()
+                .map(|line| display_width(line))
+                .max()
+                .unwrap_or(0);
+            let available = widths[*col..col + col_span].iter().sum::<usize>() + 3 * (col_span - 1);
+            if width > available {
+                widths[col + col_span - 1] += width - available;
+            }
+            let available = heights[*row..row + row_span].iter().sum::<usize>() + (row_span - 1);
+            if lines.len() > available {
+                heights[row + row_span - 1] += lines.len() - available;
+            }
+        }
+        let mut xs = vec![0];
+        for width in &widths {
+            xs.push(xs.last().unwrap() + width + 3);
+        }
+        let mut ys = vec![0];
+        for height in &heights {
+            ys.push(ys.last().unwrap() + height + 1);
+        }
+
+        // 넓은 글자 뒤 칸은 '\0'으로 채우고 출력할 때 건너뜀 / Columns after wide characters hold '\0' and are skipped on output
+        let mut canvas = vec![vec![' '; xs[cols] + 1]; ys[rows] + 1];
+        // 머리행 경계를 가로지르는 병합 칸이 있으면 머리행 구분선을 쓸 수 없음
+        // The header separator cannot be drawn when a merged cell crosses the header boundary
+        let header_rows = table.header_rows;
+        let header_line = (header_rows > 0
+            && header_rows < rows
+            && cells.iter().all(|(row, _, row_span, _, _)| {
+                *row >= header_rows || row + row_span <= header_rows
+            }))
+        .then(|| ys[header_rows]);
+        for (row, col, row_span, col_span, lines) in &cells {
+            let (top, bottom) = (ys[*row], ys[row + row_span]);
+            let (left, right) = (xs[*col], xs[col + col_span]);
+            for y in [top, bottom] {
+                let rule = if Some(y) == header_line { '=' } else { '-' };
+                canvas[y][left + 1..right].fill(rule);
+            }
+            for line in canvas.iter_mut().take(bottom).skip(top + 1) {
+                line[left] = '|';
+                line[right] = '|';
+            }
+            for (index, text) in lines.iter().enumerate() {
+                let mut x = left + 2;
+                for ch in text.chars() {
+                    canvas[top + 1 + index][x] = ch;
+                    if char_width(ch) == 2 {
+                        canvas[top + 1 + index][x + 1] = '\0';
+                    }
+                    x += char_width(ch);
+                }
+            }
+        }
+        for (row, col, row_span, col_span, _) in &cells {
+            for y in [ys[*row], ys[row + row_span]] {
+                for x in [xs[*col], xs[col + col_span]] {
+                    canvas[y][x] = '+';
+                }
+            }
+        }
+        canvas
+            .into_iter()
+            .map(|line| line.into_iter().filter(|&c| c != '\0').collect())
+            .collect()
+    }
+}
+
+/// 인라인 마크업 앞에 올 수 있는 글자 / Characters allowed before inline markup
+const MARKUP_START_PRECEDERS: &str = " \n-:/'\"<([{";
+/// 인라인 마크업 뒤에 올 수 있는 글자 / Characters allowed after inline markup
+const MARKUP_END_FOLLOWERS: &str = " \n-.,:;!?\\/'\")]}>";
+
+/// 강조 인라인의 마크업 (위/아래 첨자, 굵게, 기울임 순으로 하나만)
+/// Markup for an emphasis inline (only one of superscript, subscript, strong, emphasis, in that order)
+///
+/// reStructuredText는 인라인 마크업을 겹칠 수 없어 안쪽 강조는 글자만 남깁니다.
+/// reStructuredText cannot nest inline markup, so inner emphasis keeps only its text.
+fn styled_markup(inline: &Inline) -> Option<String> {
+    let mut emphases = Vec::new();
+    let mut current = inline;
+    while let Inline::Styled(emphasis, inner) = current {
+        emphases.push(*emphasis);
+        match inner.as_slice() {
+            [only @ Inline::Styled(..)] => current = only,
+            _ => break,
+        }
+    }
+    let text = escape_text(plain_text(std::slice::from_ref(inline)).trim()).replace('\n', " ");
+    if text.is_empty() {
+        return None;
+    }
+    [
+        (Emphasis::Superscript, ":sup:`", "`"),
+        (Emphasis::Subscript, ":sub:`", "`"),
+        (Emphasis::Strong, "**", "**"),
+        (Emphasis::Emph, "*", "*"),
+    ]
+    .into_iter()
+    .find(|(emphasis, ..)| emphases.contains(emphasis))
+    .map(|(_, open, close)| format!("{open}{text}{close}"))
+}
+
+/// 블록 사이에 빈 줄을 넣어 합침 / Join blocks with blank lines between them
+fn join_blocks(blocks: &[Vec<String>]) -> String {
+    let mut output = String::new();
+    for lines in blocks.iter().filter(|lines| !lines.is_empty()) {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        for line in lines {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// 마크업 문자 이스케이프 / Escape markup characters
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '*' | '`' | '_' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// 목록 표시나 지시어로 읽히는 문단 첫머리 이스케이프 / Escape paragraph starts that would read as list markers or directives
+fn escape_line_start(line: &str) -> String {
+    let marker_end = line
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&end| end > 0 && line[end..].starts_with([',', '.', ')']));
+    let list_like = line.starts_with(['-', '+', '•', '#', '(']) || line.starts_with("..");
+    let enumerated = marker_end.is_some_and(|end| line[end + 1..].starts_with(' '));
+    if list_like || enumerated {
+        format!("\\{line}")
+    } else {
+        line.to_string()
+    }
+}
+
+/// 글자의 표시 너비 (동아시아 넓은 글자는 2) / Display width of a character (2 for East Asian wide characters)
+fn char_width(ch: char) -> usize {
+    match ch as u32 {
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// 문자열의 표시 너비 (제목 밑줄과 grid table 칸 너비) / Display width of a string (title underlines and grid table columns)
+fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::viewer::core::blocks::{text_inlines, CellBlock};
+
+    #[test]
+    fn test_inline_markup_next_to_hangul() {
+        let mut writer = RstWriter::default();
+        let mut inlines = text_inlines("가");
+        inlines.push(Inline::Styled(Emphasis::Strong, text_inlines("굵게")));
+        inlines.extend(text_inlines("나 *별표*"));
+        assert_eq!(writer.inlines(&inlines), "가\\ **굵게**\\ 나 \\*별표\\*");
+    }
+
+    #[test]
+    fn test_grid_table_with_merged_cells() {
+        let cell = |row, col, row_span, col_span, text: &str| CellBlock {
+            row,
+            col,
+            row_span,
+            col_span,
+            blocks: vec![Block::Paragraph(text_inlines(text))],
+        };
+        let table = TableBlock {
+            row_count: 2,
+            col_count: 2,
+            header_rows: 1,
+            cells: vec![
+                cell(0, 0, 1, 2, "제목"),
+                cell(1, 0, 1, 1, "a"),
+                cell(1, 1, 1, 1, "b"),
+            ],
+        };
+        assert_eq!(
+            RstWriter::default().table(&table),
+            vec![
+                "+-------+",
+                "| 제목  |",
+                "+===+===+",
+                "| a | b |",
+                "+---+---+"
+            ]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+/// Table of contents generation from outline headings
+/// 개요 문단으로 차례 생성
+///
+/// 본문 최상위 문단 중 개요 모양(`HeaderShapeType::Outline`)을 쓰는 문단을 제목으로 보고
+/// 수준과 위치를 모읍니다. Markdown 변환에서 `MarkdownOptions::include_toc`를 켜면 같은 목록으로
+/// 문서 앞에 링크가 걸린 차례를 붙입니다.
+/// Treats top-level body paragraphs using the outline shape (`HeaderShapeType::Outline`) as
+/// headings and collects their level and location. With `MarkdownOptions::include_toc`, the
+/// Markdown viewer prepends a linked table of contents built from the same list.
+use crate::document::HwpDocument;
+use crate::viewer::markdown::utils::outline_level;
+use serde::{Deserialize, Serialize};
+
+/// 차례 항목 하나 / A single table of contents entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// 개요 수준 (1부터 시작) / Outline level (1-based)
+    pub level: u8,
+    /// 제목 텍스트 / Heading text
+    pub title: String,
+    /// 구역 인덱스 / Section index
+    pub section_index: usize,
+    /// 구역 내 문단 인덱스 / Paragraph index within the section
+    pub paragraph_index: usize,
+}
+
+impl TocEntry {
+    /// 제목 앞에 붙는 앵커 ID / Anchor ID placed before the heading
+    pub fn anchor(&self) -> String {
+        heading_anchor(self.section_index, self.paragraph_index)
+    }
+}
+
+/// 문서의 차례 생성 / Generate the document's table of contents
+///
+/// # Returns / 반환값
+/// 문서 순서대로 정렬된 제목 목록 (빈 제목은 제외) / Headings in document order (blank headings skipped)
+pub fn generate(document: &HwpDocument) -> Vec<TocEntry> {
+    let mut entries = Vec::new();
+    for (section_index, section) in document.body_text.sections.iter().enumerate() {
+        for (paragraph_index, paragraph) in section.paragraphs.iter().enumerate() {
+            let Some(level) = outline_level(&paragraph.para_header, document) else {
+                continue;
+            };
+            let text = paragraph.text();
+            let title = text.trim();
+            if title.is_empty() {
+                continue;
+            }
+            entries.push(TocEntry {
+                level,
+                title: title.to_string(),
+                section_index,
+                paragraph_index,
+            });
+        }
+    }
+    entries
+}
+
+/// 구역/문단 인덱스로 만든 제목 앵커 ID / Heading anchor ID built from section/paragraph indices
+pub(crate) fn heading_anchor(section_index: usize, paragraph_index: usize) -> String {
+    format!("toc-{section_index}-{paragraph_index}")
+}
+
+/// 원본 문단 위치 (`구역:문단`, HTML `data-para`와 Markdown 주석에 씀)
+/// Source paragraph location (`section:paragraph`, used by HTML `data-para` and Markdown comments)
+pub(crate) fn source_anchor(section_index: usize, paragraph_index: usize) -> String {
+    format!("{section_index}:{paragraph_index}")
+}
@@ -0,0 +1,628 @@
+/// HWPX writer
+///
+/// `HwpDocument`를 OWPML(KS X 6101) 패키지로 저장합니다. 문단 텍스트, 글자/문단 모양,
+/// 개요 수준, 표, HWPX 이미지 참조(`ParagraphRecord::HwpxImage`)를 기록합니다.
+/// HWP 5.0 전용 레코드(그리기 개체, 필드 등)는 텍스트만 남습니다.
+///
+/// Saves an `HwpDocument` as an OWPML (KS X 6101) package. Paragraph text, char/para shapes,
+/// outline levels, tables and HWPX image references (`ParagraphRecord::HwpxImage`) are
+/// written. HWP 5.0 specific records (drawing objects, fields, etc.) keep only their text.
+///
+/// ```text
+/// mimetype, version.xml, META-INF/container.xml,
+/// Contents/content.hpf, Contents/header.xml, Contents/section{N}.xml, BinData/*
+/// ```
+use std::io::{Cursor, Write};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::document::bodytext::{Paragraph, ParagraphRecord, Table};
+use crate::document::docinfo::para_shape::{HeaderShapeType, ParagraphAlignment};
+use crate::document::HwpDocument;
+use crate::error::HwpError;
+
+const NS_HEAD: &str = "http://www.hancom.co.kr/hwpml/2011/head";
+const NS_PARA: &str = "http://www.hancom.co.kr/hwpml/2011/paragraph";
+const NS_SECTION: &str = "http://www.hancom.co.kr/hwpml/2011/section";
+const NS_CORE: &str = "http://www.hancom.co.kr/hwpml/2011/core";
+
+/// 96 DPI 픽셀당 HWPUNIT / HWPUNIT per pixel at 96 DPI
+const HWPUNIT_PER_PIXEL: u32 = 75;
+/// 크기를 알 수 없는 이미지의 기본 크기 (HWPUNIT, 약 50mm) / Fallback image size (HWPUNIT, about 50mm)
+const DEFAULT_IMAGE_SIZE: u32 = 14173;
+
+/// HWPX 파일로 저장 / Write an HWPX file
+///
+/// # Arguments
+/// * `document` - 저장할 문서 / Document to write
+///
+/// # Returns
+/// HWPX(ZIP) 바이트 / HWPX (ZIP) bytes
+pub fn write_hwpx(document: &HwpDocument) -> Result<Vec<u8>, HwpError> {
+    let images = collect_images(document)?;
+    let section_count = document.body_text.sections.len().max(1);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    // mimetype은 첫 항목이며 압축하지 않음 / mimetype is the first entry and stored uncompressed
+    add_file(
+        &mut zip,
+        "mimetype",
+        b"application/hwp+zip",
+        CompressionMethod::Stored,
+    )?;
+    add_file(
+        &mut zip,
+        "version.xml",
+        version_xml(document.file_header.version).as_bytes(),
+        CompressionMethod::Deflated,
+    )?;
+    add_file(
+        &mut zip,
+        "META-INF/container.xml",
+        CONTAINER_XML.as_bytes(),
+        CompressionMethod::Deflated,
+    )?;
+    add_file(
+        &mut zip,
+        "Contents/content.hpf",
+        content_hpf(section_count, &images).as_bytes(),
+        CompressionMethod::Deflated,
+    )?;
+    add_file(
+        &mut zip,
+        "Contents/header.xml",
+        header_xml(document, section_count).as_bytes(),
+        CompressionMethod::Deflated,
+    )?;
+
+    for index in 0..section_count {
+        let paragraphs = document
+            .body_text
+            .sections
+            .get(index)
+            .map(|s| s.paragraphs.as_slice())
+            .unwrap_or(&[]);
+        let xml = section_xml(paragraphs, &images, index == 0);
+        add_file(
+            &mut zip,
+            &format!("Contents/section{index}.xml"),
+            xml.as_bytes(),
+            CompressionMethod::Deflated,
+        )?;
+    }
+
+    for image in &images {
+        add_file(
+            &mut zip,
+            &image.path(),
+            &image.bytes,
+            CompressionMethod::Stored,
+        )?;
+    }
+
+    let cursor = zip
+        .finish()
+        .map_err(|e| HwpError::Io(format!("Failed to finish HWPX archive: {e}")))?;
+    Ok(cursor.into_inner())
+}
+
+fn add_file(
+    zip: &mut ZipWriter<Cursor<Vec<u8>>>,
+    path: &str,
+    content: &[u8],
+    method: CompressionMethod,
+) -> Result<(), HwpError> {
+    zip.start_file(
+        path,
+        SimpleFileOptions::default().compression_method(method),
+    )
+    .map_err(|e| HwpError::Io(format!("Failed to add {path} to HWPX archive: {e}")))?;
+    zip.write_all(content)
+        .map_err(|e| HwpError::Io(format!("Failed to write {path}: {e}")))
+}
+
+/// 패키지에 들어갈 이미지 / Image stored in the package
+struct PackageImage {
+    /// binaryItemIDRef 값 / binaryItemIDRef value
+    id: String,
+    extension: &'static str,
+    bytes: Vec<u8>,
+    /// 크기 (HWPUNIT) / Size (HWPUNIT)
+    width: u32,
+    height: u32,
+}
+
+impl PackageImage {
+    fn path(&self) -> String {
+        format!("BinData/{}.{}", self.id, self.extension)
+    }
+}
+
+fn collect_images(document: &HwpDocument) -> Result<Vec<PackageImage>, HwpError> {
+    let mut images = Vec::new();
+    for item in &document.bin_data.items {
+        let bytes = STANDARD
+            .decode(&item.data)
+            .map_err(|e| HwpError::EncodingError {
+                reason: format!("Invalid base64 in BinData item {}: {e}", item.index),
+            })?;
+        // 헤더의 크기가 터무니없으면 기본 크기 / Default size when the header size is absurd
+        let (width, height) = image_pixel_size(&bytes)
+            .and_then(|(w, h)| {
+                Some((
+                    w.checked_mul(HWPUNIT_PER_PIXEL)?,
+                    h.checked_mul(HWPUNIT_PER_PIXEL)?,
+                ))
+            })
+            .unwrap_or((DEFAULT_IMAGE_SIZE, DEFAULT_IMAGE_SIZE));
+        images.push(PackageImage {
+            id: item
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("image{}", item.index + 1)),
+            extension: image_extension(&bytes),
+            bytes,
+            width,
+            height,
+        });
+    }
+    Ok(images)
+}
+
+/// 매직 바이트로 확장자 결정 / Determine extension from magic bytes
+fn image_extension(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "png"
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        "jpg"
+    } else if bytes.starts_with(b"GIF8") {
+        "gif"
+    } else if bytes.starts_with(b"BM") {
+        "bmp"
+    } else {
+        "bin"
+    }
+}
+
+/// 이미지 헤더에서 픽셀 크기 읽기 / Read pixel size from the image header
+fn image_pixel_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    let be32 = |at: usize| -> Option<u32> {
+        Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+    };
+    let le16 = |at: usize| -> Option<u32> {
+        Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?) as u32)
+    };
+    let le32 = |at: usize| -> Option<u32> {
+        Some(i32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?).unsigned_abs())
+    };
+
+    match image_extension(bytes) {
+        "png" => Some((be32(16)?, be32(20)?)),
+        "gif" => Some((le16(6)?, le16(8)?)),
+        "bmp" => Some((le32(18)?, le32(22)?)),
+        "jpg" => {
+            // SOFn 마커 찾기 / Find the SOFn marker
+            let mut offset = 2;
+            while offset + 9 < bytes.len() {
+                if bytes[offset] != 0xFF {
+                    return None;
+                }
+                let marker = bytes[offset + 1];
+                let length = u16::from_be_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+                if matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+                    let height = u16::from_be_bytes([bytes[offset + 5], bytes[offset + 6]]);
+                    let width = u16::from_be_bytes([bytes[offset + 7], bytes[offset + 8]]);
+                    return Some((width as u32, height as u32));
+                }
+                offset += 2 + length;
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn version_xml(version: u32) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\
+<hv:HCFVersion xmlns:hv=\"http://www.hancom.co.kr/hwpml/2011/version\" tagetApplication=\"WORDPROCESSOR\" \
+major=\"{}\" minor=\"{}\" micro=\"{}\" buildNumber=\"{}\" os=\"1\" xmlVersion=\"1.4\" application=\"hwp-core\" appVersion=\"{}\"/>",
+        (version >> 24) & 0xFF,
+        (version >> 16) & 0xFF,
+        (version >> 8) & 0xFF,
+        version & 0xFF,
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+const CONTAINER_XML: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\
+<ocf:container xmlns:ocf=\"urn:oasis:names:tc:opendocument:xmlns:container\" \
+xmlns:hpf=\"http://www.hancom.co.kr/schema/2011/hpf\"><ocf:rootfiles>\
+<ocf:rootfile full-path=\"Contents/content.hpf\" media-type=\"application/hwpml-package+xml\"/>\
+</ocf:rootfiles></ocf:container>";
+
+fn content_hpf(section_count: usize, images: &[PackageImage]) -> String {
+    let mut manifest = String::from(
+        "<opf:item id=\"header\" href=\"Contents/header.xml\" media-type=\"application/xml\"/>",
+    );
+    let mut spine = String::from("<opf:itemref idref=\"header\" linear=\"yes\"/>");
+    for index in 0..section_count {
+        manifest.push_str(&format!(
+            "<opf:item id=\"section{index}\" href=\"Contents/section{index}.xml\" media-type=\"application/xml\"/>"
+        ));
+        spine.push_str(&format!(
+            "<opf:itemref idref=\"section{index}\" linear=\"yes\"/>"
+        ));
+    }
+    for image in images {
+        let media_type = match image.extension {
+            "png" => "image/png",
+            "jpg" => "image/jpg",
+            "gif" => "image/gif",
+            "bmp" => "image/bmp",
+            _ => "application/octet-stream",
+        };
+        manifest.push_str(&format!(
+            "<opf:item id=\"{}\" href=\"{}\" media-type=\"{}\" isEmbeded=\"1\"/>",
+            escape_xml(&image.id),
+            escape_xml(&image.path()),
+            media_type
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\
+<opf:package xmlns:opf=\"http://www.idpf.org/2007/opf/\" version=\"\" unique-identifier=\"\" id=\"\">\
+<opf:metadata><opf:language>ko</opf:language></opf:metadata>\
+<opf:manifest>{manifest}</opf:manifest><opf:spine>{spine}</opf:spine></opf:package>"
+    )
+}
+
+fn header_xml(document: &HwpDocument, section_count: usize) -> String {
+    let doc_info = &document.doc_info;
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\
+<hh:head xmlns:hh=\"{NS_HEAD}\" xmlns:hc=\"{NS_CORE}\" version=\"1.4\" secCnt=\"{section_count}\">\
+<hh:beginNum page=\"1\" footnote=\"1\" endnote=\"1\" pic=\"1\" tbl=\"1\" equation=\"1\"/><hh:refList>"
+    );
+
+    // 글꼴은 기본 글꼴 하나로 통일 / A single default font for every language
+    xml.push_str("<hh:fontfaces itemCnt=\"7\">");
+    for lang in [
+        "HANGUL", "LATIN", "HANJA", "JAPANESE", "OTHER", "SYMBOL", "USER",
+    ] {
+        xml.push_str(&format!(
+            "<hh:fontface lang=\"{lang}\" fontCnt=\"1\"><hh:font id=\"0\" face=\"함초롬바탕\" type=\"TTF\" isEmbedded=\"0\"/></hh:fontface>"
+        ));
+    }
+    xml.push_str("</hh:fontfaces>");
+    xml.push_str(
+        "<hh:borderFills itemCnt=\"1\"><hh:borderFill id=\"0\" threeD=\"0\" shadow=\"0\" centerLine=\"NONE\" breakCellSeparateLine=\"0\">\
+<hh:leftBorder type=\"SOLID\" width=\"0.12 mm\" color=\"#000000\"/><hh:rightBorder type=\"SOLID\" width=\"0.12 mm\" color=\"#000000\"/>\
+<hh:topBorder type=\"SOLID\" width=\"0.12 mm\" color=\"#000000\"/><hh:bottomBorder type=\"SOLID\" width=\"0.12 mm\" color=\"#000000\"/>\
+</hh:borderFill></hh:borderFills>",
+    );
+
+    let char_shape_count = doc_info.char_shapes.len().max(1);
+    xml.push_str(&format!(
+        "<hh:charProperties itemCnt=\"{char_shape_count}\">"
+    ));
+    if doc_info.char_shapes.is_empty() {
+        xml.push_str("<hh:charPr id=\"0\" height=\"1000\" textColor=\"#000000\" shadeColor=\"none\" useFontSpace=\"0\" useKerning=\"0\" symMark=\"NONE\" borderFillIDRef=\"0\"/>");
+    }
+    for (id, char_shape) in doc_info.char_shapes.iter().enumerate() {
+        let color = char_shape.text_color.0;
+        xml.push_str(&format!(
+            "<hh:charPr id=\"{id}\" height=\"{}\" textColor=\"#{:02X}{:02X}{:02X}\" shadeColor=\"none\" useFontSpace=\"0\" useKerning=\"0\" symMark=\"NONE\" borderFillIDRef=\"0\">",
+            char_shape.base_size,
+            color & 0xFF,
+            (color >> 8) & 0xFF,
+            (color >> 16) & 0xFF
+        ));
+        xml.push_str("<hh:fontRef hangul=\"0\" latin=\"0\" hanja=\"0\" japanese=\"0\" other=\"0\" symbol=\"0\" user=\"0\"/>");
+        if char_shape.attributes.bold {
+            xml.push_str("<hh:bold/>");
+        }
+        if char_shape.attributes.italic {
+            xml.push_str("<hh:italic/>");
+        }
+        if char_shape.attributes.underline_type != 0 {
+            xml.push_str("<hh:underline type=\"BOTTOM\" shape=\"SOLID\" color=\"#000000\"/>");
+        }
+        xml.push_str("</hh:charPr>");
+    }
+    xml.push_str("</hh:charProperties>");
+
+    let para_shape_count = doc_info.para_shapes.len().max(1);
+    xml.push_str(&format!(
+        "<hh:paraProperties itemCnt=\"{para_shape_count}\">"
+    ));
+    if doc_info.para_shapes.is_empty() {
+        xml.push_str("<hh:paraPr id=\"0\"><hh:align horizontal=\"JUSTIFY\" vertical=\"BASELINE\"/><hh:heading type=\"NONE\" idRef=\"0\" level=\"0\"/></hh:paraPr>");
+    }
+    for (id, para_shape) in doc_info.para_shapes.iter().enumerate() {
+        let attributes = &para_shape.attributes1;
+        let align = match attributes.align {
+            ParagraphAlignment::Justify => "JUSTIFY",
+            ParagraphAlignment::Left => "LEFT",
+            ParagraphAlignment::Right => "RIGHT",
+            ParagraphAlignment::Center => "CENTER",
+            ParagraphAlignment::Distribute => "DISTRIBUTE",
+            ParagraphAlignment::Divide => "DISTRIBUTE_SPACE",
+        };
+        let heading = match attributes.header_shape_type {
+            HeaderShapeType::None => "NONE",
+            HeaderShapeType::Outline => "OUTLINE",
+            HeaderShapeType::Number => "NUMBER",
+            HeaderShapeType::Bullet => "BULLET",
+        };
+        xml.push_str(&format!(
+            "<hh:paraPr id=\"{id}\"><hh:align horizontal=\"{align}\" vertical=\"BASELINE\"/>\
+<hh:heading type=\"{heading}\" idRef=\"{}\" level=\"{}\"/>\
+<hh:lineSpacing type=\"PERCENT\" value=\"{}\" unit=\"HWPUNIT\"/></hh:paraPr>",
+            para_shape.number_bullet_id,
+            attributes.paragraph_level,
+            para_shape.line_spacing.filter(|v| *v > 0).unwrap_or(160)
+        ));
+    }
+    xml.push_str("</hh:paraProperties>");
+
+    xml.push_str("<hh:styles itemCnt=\"1\"><hh:style id=\"0\" type=\"PARA\" name=\"바탕글\" engName=\"Normal\" paraPrIDRef=\"0\" charPrIDRef=\"0\" nextStyleIDRef=\"0\" langID=\"1042\" lockForm=\"0\"/></hh:styles>");
+    xml.push_str("</hh:refList></hh:head>");
+    xml
+}
+
+fn section_xml(paragraphs: &[Paragraph], images: &[PackageImage], first: bool) -> String {
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\
+<hs:sec xmlns:hs=\"{NS_SECTION}\" xmlns:hp=\"{NS_PARA}\" xmlns:hc=\"{NS_CORE}\">"
+    );
+    let mut ids = IdCounter::default();
+
+    // 구역 정의 (A4, 첫 구역의 첫 문단) / Section definition (A4, in the first paragraph of the first section)
+    let sec_pr = "<hp:secPr id=\"\" textDirection=\"HORIZONTAL\" spaceColumns=\"1134\" tabStop=\"8000\" outlineShapeIDRef=\"1\" memoShapeIDRef=\"0\" textVerticalWidthHead=\"0\" masterPageCnt=\"0\">\
+<hp:pagePr landscape=\"WIDELY\" width=\"59528\" height=\"84186\" gutterType=\"LEFT_ONLY\">\
+<hp:margin header=\"4252\" footer=\"4252\" gutter=\"0\" left=\"8504\" right=\"8504\" top=\"5668\" bottom=\"4252\"/></hp:pagePr></hp:secPr>";
+    if first {
+        xml.push_str(&format!(
+            "<hp:p id=\"{}\" paraPrIDRef=\"0\" styleIDRef=\"0\" pageBreak=\"0\" columnBreak=\"0\" merged=\"0\"><hp:run charPrIDRef=\"0\">{sec_pr}</hp:run></hp:p>",
+            ids.next()
+        ));
+    }
+
+    for paragraph in paragraphs {
+        write_paragraph(&mut xml, paragraph, images, &mut ids);
+    }
+    xml.push_str("</hs:sec>");
+    xml
+}
+
+#[derive(Default)]
+struct IdCounter(u32);
+
+impl IdCounter {
+    fn next(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn write_paragraph(
+    xml: &mut String,
+    paragraph: &Paragraph,
+    images: &[PackageImage],
+    ids: &mut IdCounter,
+) {
+    let mut text = String::new();
+    let mut shapes = Vec::new();
+    let mut objects = String::new();
+
+    for record in &paragraph.records {
+        match record {
+            ParagraphRecord::ParaText { text: t, .. } => text.push_str(t),
+            ParagraphRecord::ParaCharShape { shapes: s } => {
+                shapes.extend(s.iter().map(|s| (s.position as usize, s.shape_id)))
+            }
+            ParagraphRecord::Table { table } => write_table(&mut objects, table, images, ids),
+            ParagraphRecord::HwpxImage {
+                binary_item_ref, ..
+            } => write_image(&mut objects, binary_item_ref, images, ids),
+            ParagraphRecord::CtrlHeader { children, .. } => {
+                for child in children {
+                    if let ParagraphRecord::Table { table } = child {
+                        write_table(&mut objects, table, images, ids);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    xml.push_str(&format!(
+        "<hp:p id=\"{}\" paraPrIDRef=\"{}\" styleIDRef=\"0\" pageBreak=\"0\" columnBreak=\"0\" merged=\"0\">",
+        ids.next(),
+        paragraph.para_header.para_shape_id
+    ));
+    if shapes.is_empty() {
+        shapes.push((0, 0));
+    }
+    // 글자 모양이 바뀌는 위치마다 run을 나눔 / Split into runs wherever the char shape changes
+    let chars: Vec<char> = text.chars().collect();
+    for (index, &(start, shape_id)) in shapes.iter().enumerate() {
+        let end = shapes
+            .get(index + 1)
+            .map(|&(position, _)| position)
+            .unwrap_or(chars.len())
+            .min(chars.len());
+        let start = start.min(end);
+        let run_text: String = chars[start..end].iter().collect();
+        xml.push_str(&format!("<hp:run charPrIDRef=\"{shape_id}\">"));
+        if index == 0 {
+            xml.push_str(&objects);
+        }
+        if !run_text.is_empty() {
+            xml.push_str(&format!("<hp:t>{}</hp:t>", escape_xml(&run_text)));
+        }
+        xml.push_str("</hp:run>");
+    }
+    xml.push_str("</hp:p>");
+}
+
+fn write_table(xml: &mut String, table: &Table, images: &[PackageImage], ids: &mut IdCounter) {
+    let attributes = &table.attributes;
+    let width: u32 = table
+        .cells
+        .iter()
+        .filter(|c| c.cell_attributes.row_address == 0)
+        .map(|c| c.cell_attributes.width.0)
+        .sum();
+    let mut row_heights = vec![0u32; attributes.row_count as usize];
+    for cell in &table.cells {
+        if let Some(height) = row_heights.get_mut(cell.cell_attributes.row_address as usize) {
+            *height = (*height).max(cell.cell_attributes.height.0);
+        }
+    }
+    let height: u32 = row_heights.iter().sum();
+    let page_break = match attributes.attribute.page_break {
+        crate::document::bodytext::table::PageBreakBehavior::NoBreak => "NONE",
+        crate::document::bodytext::table::PageBreakBehavior::BreakByCell => "CELL",
+        crate::document::bodytext::table::PageBreakBehavior::NoBreakOther => "TABLE",
+    };
+
+    xml.push_str(&format!(
+        "<hp:tbl id=\"{}\" zOrder=\"0\" numberingType=\"TABLE\" textWrap=\"TOP_AND_BOTTOM\" textFlow=\"BOTH_SIDES\" lock=\"0\" dropcapstyle=\"None\" \
+pageBreak=\"{page_break}\" repeatHeader=\"{}\" rowCnt=\"{}\" colCnt=\"{}\" cellSpacing=\"{}\" borderFillIDRef=\"{}\" noAdjust=\"0\">\
+<hp:sz width=\"{width}\" widthRelTo=\"ABSOLUTE\" height=\"{height}\" heightRelTo=\"ABSOLUTE\" protect=\"0\"/>\
+<hp:pos treatAsChar=\"1\" affectLSpacing=\"0\" flowWithText=\"1\" allowOverlap=\"0\" holdAnchorAndSO=\"0\" vertRelTo=\"PARA\" horzRelTo=\"PARA\" vertAlign=\"TOP\" horzAlign=\"LEFT\" vertOffset=\"0\" horzOffset=\"0\"/>\
+<hp:outMargin left=\"0\" right=\"0\" top=\"0\" bottom=\"0\"/>\
+<hp:inMargin left=\"{}\" right=\"{}\" top=\"{}\" bottom=\"{}\"/>",
+        ids.next(),
+        u8::from(attributes.attribute.header_row_repeat),
+        attributes.row_count,
+        attributes.col_count,
+        attributes.cell_spacing,
+        attributes.border_fill_id,
+        attributes.padding.left,
+        attributes.padding.right,
+        attributes.padding.top,
+        attributes.padding.bottom,
+    ));
+
+    for row in 0..attributes.row_count {
+        xml.push_str("<hp:tr>");
+        for cell in table
+            .cells
+            .iter()
+            .filter(|c| c.cell_attributes.row_address == row)
+        {
+            let cell_attributes = &cell.cell_attributes;
+            xml.push_str(&format!(
+                "<hp:tc name=\"\" header=\"0\" hasMargin=\"1\" protect=\"0\" editable=\"0\" dirty=\"0\" borderFillIDRef=\"{}\">\
+<hp:subList id=\"\" textDirection=\"HORIZONTAL\" lineWrap=\"BREAK\" vertAlign=\"CENTER\" linkListIDRef=\"0\" linkListNextIDRef=\"0\" textWidth=\"0\" textHeight=\"0\" hasTextRef=\"0\" hasNumRef=\"0\">",
+                cell_attributes.border_fill_id
+            ));
+            if cell.paragraphs.is_empty() {
+                write_paragraph(xml, &Paragraph::default(), images, ids);
+            }
+            for paragraph in &cell.paragraphs {
+                write_paragraph(xml, paragraph, images, ids);
+            }
+            xml.push_str(&format!(
+                "</hp:subList><hp:cellAddr colAddr=\"{}\" rowAddr=\"{}\"/><hp:cellSpan colSpan=\"{}\" rowSpan=\"{}\"/>\
+<hp:cellSz width=\"{}\" height=\"{}\"/><hp:cellMargin left=\"{}\" right=\"{}\" top=\"{}\" bottom=\"{}\"/></hp:tc>",
+                cell_attributes.col_address,
+                cell_attributes.row_address,
+                cell_attributes.col_span,
+                cell_attributes.row_span,
+                cell_attributes.width.0,
+                cell_attributes.height.0,
+                cell_attributes.left_margin,
+                cell_attributes.right_margin,
+                cell_attributes.top_margin,
+                cell_attributes.bottom_margin,
+            ));
+        }
+        xml.push_str("</hp:tr>");
+    }
+    xml.push_str("</hp:tbl>");
+}
+
+fn write_image(
+    xml: &mut String,
+    binary_item_ref: &str,
+    images: &[PackageImage],
+    ids: &mut IdCounter,
+) {
+    let (width, height) = images
+        .iter()
+        .find(|image| image.id == binary_item_ref)
+        .map(|image| (image.width, image.height))
+        .unwrap_or((DEFAULT_IMAGE_SIZE, DEFAULT_IMAGE_SIZE));
+    xml.push_str(&format!(
+        "<hp:pic id=\"{}\" zOrder=\"0\" numberingType=\"PICTURE\" textWrap=\"TOP_AND_BOTTOM\" textFlow=\"BOTH_SIDES\" lock=\"0\" dropcapstyle=\"None\" href=\"\" groupLevel=\"0\" instid=\"0\" reverse=\"0\">\
+<hp:offset x=\"0\" y=\"0\"/><hp:orgSz width=\"{width}\" height=\"{height}\"/><hp:curSz width=\"{width}\" height=\"{height}\"/>\
+<hp:flip horizontal=\"0\" vertical=\"0\"/><hp:rotationInfo angle=\"0\" centerX=\"{}\" centerY=\"{}\" rotateimage=\"1\"/>\
+<hp:renderingInfo><hc:transMatrix e1=\"1\" e2=\"0\" e3=\"0\" e4=\"0\" e5=\"1\" e6=\"0\"/><hc:scaMatrix e1=\"1\" e2=\"0\" e3=\"0\" e4=\"0\" e5=\"1\" e6=\"0\"/><hc:rotMatrix e1=\"1\" e2=\"0\" e3=\"0\" e4=\"0\" e5=\"1\" e6=\"0\"/></hp:renderingInfo>\
+<hc:img binaryItemIDRef=\"{}\" bright=\"0\" contrast=\"0\" effect=\"REAL_PIC\" alpha=\"0\"/>\
+<hp:imgRect><hc:pt0 x=\"0\" y=\"0\"/><hc:pt1 x=\"{width}\" y=\"0\"/><hc:pt2 x=\"{width}\" y=\"{height}\"/><hc:pt3 x=\"0\" y=\"{height}\"/></hp:imgRect>\
+<hp:imgClip left=\"0\" right=\"{width}\" top=\"0\" bottom=\"{height}\"/><hp:inMargin left=\"0\" right=\"0\" top=\"0\" bottom=\"0\"/>\
+<hp:sz width=\"{width}\" widthRelTo=\"ABSOLUTE\" height=\"{height}\" heightRelTo=\"ABSOLUTE\" protect=\"0\"/>\
+<hp:pos treatAsChar=\"1\" affectLSpacing=\"0\" flowWithText=\"1\" allowOverlap=\"0\" holdAnchorAndSO=\"0\" vertRelTo=\"PARA\" horzRelTo=\"PARA\" vertAlign=\"TOP\" horzAlign=\"LEFT\" vertOffset=\"0\" horzOffset=\"0\"/>\
+<hp:outMargin left=\"0\" right=\"0\" top=\"0\" bottom=\"0\"/></hp:pic>",
+        ids.next(),
+        width / 2,
+        height / 2,
+        escape_xml(binary_item_ref)
+    ));
+}
+
+/// XML 특수 문자 이스케이프 / Escape XML special characters
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_pixel_size() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R']);
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(image_pixel_size(&png), Some((640, 480)));
+
+        let gif = [b'G', b'I', b'F', b'8', b'9', b'a', 10, 0, 20, 0];
+        assert_eq!(image_pixel_size(&gif), Some((10, 20)));
+
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00,
+            0x20, 0x00, 0x40, 0x01, 0x00,
+        ];
+        assert_eq!(image_pixel_size(&jpeg), Some((64, 32)));
+        assert_eq!(image_pixel_size(b"unknown"), None);
+    }
+
+    #[test]
+    fn test_oversized_image_header_uses_default_size() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R']);
+        png.extend_from_slice(&u32::MAX.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        let document = crate::builder::DocumentBuilder::new()
+            .add_image(&png)
+            .build();
+        let images = collect_images(&document).unwrap();
+        assert_eq!(
+            (images[0].width, images[0].height),
+            (DEFAULT_IMAGE_SIZE, DEFAULT_IMAGE_SIZE)
+        );
+        assert!(document.to_hwpx().is_ok());
+    }
+}
@@ -0,0 +1,9 @@
+/// Writer module for serializing documents to file formats
+///
+/// 문서 모델을 파일 형식으로 저장하는 모듈입니다.
+/// Serializes the document model back into file formats.
+pub mod hwpx;
+pub mod roundtrip;
+
+pub use hwpx::write_hwpx;
+pub use roundtrip::{assert_roundtrip, check_roundtrip, ImageMismatch, RoundtripReport};
@@ -0,0 +1,244 @@
+/// 왕복 검증 / Round-trip check
+///
+/// 문서를 HWPX로 저장한 뒤 다시 파싱하여 텍스트, 표, 이미지가 그대로인지 비교합니다.
+/// 문서를 생성하는 쪽에서 writer 결과를 확인할 때 사용합니다.
+///
+/// Writes a document as HWPX, parses it back and compares text, tables and images.
+/// Meant for document generators that need to verify what the writer produces.
+///
+/// ```ignore
+/// use hwp_core::writer::check_roundtrip;
+///
+/// let report = check_roundtrip(&document)?;
+/// assert!(report.is_identical(), "{report}");
+/// ```
+use serde::{Deserialize, Serialize};
+
+use crate::diff::{compare, DocumentDiff};
+use crate::document::{DocumentImage, HwpDocument};
+use crate::error::HwpError;
+use crate::HwpParser;
+
+/// 이미지 불일치 / Image mismatch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageMismatch {
+    /// 다시 읽은 문서에 없는 이미지 / Image missing from the re-parsed document
+    Missing { position: usize, name: String },
+    /// 원본에 없던 이미지 / Image not present in the original
+    Unexpected { position: usize, name: String },
+    /// MIME 타입이나 바이트가 달라진 이미지 / Image whose MIME type or bytes changed
+    Changed {
+        position: usize,
+        name: String,
+        reason: String,
+    },
+}
+
+/// 왕복 검증 결과 / Round-trip check result
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundtripReport {
+    /// 문단 텍스트와 표 셀 차이 (원본 → 다시 읽은 문서) / Paragraph text and table cell differences (original → re-parsed)
+    pub diff: DocumentDiff,
+    /// 이미지 차이 (문서 순서 기준) / Image differences, by position in document order
+    pub images: Vec<ImageMismatch>,
+}
+
+impl RoundtripReport {
+    /// 의미상 같은지 확인 / Whether the documents are semantically identical
+    pub fn is_identical(&self) -> bool {
+        self.diff.is_empty() && self.images.is_empty()
+    }
+
+    /// 마크다운으로 렌더링 / Render as markdown
+    pub fn to_markdown(&self) -> String {
+        if self.is_identical() {
+            return "Round trip is identical.\n".to_string();
+        }
+
+        let mut out = String::new();
+        if !self.diff.is_empty() {
+            out.push_str(&self.diff.to_markdown());
+        }
+        if !self.images.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str("## Images\n\n");
+            for mismatch in &self.images {
+                let line = match mismatch {
+                    ImageMismatch::Missing { position, name } => {
+                        format!("- **Missing** (image {}): {}", position + 1, name)
+                    }
+                    ImageMismatch::Unexpected { position, name } => {
+                        format!("- **Unexpected** (image {}): {}", position + 1, name)
+                    }
+                    ImageMismatch::Changed {
+                        position,
+                        name,
+                        reason,
+                    } => format!(
+                        "- **Changed** (image {}): {} ({})",
+                        position + 1,
+                        name,
+                        reason
+                    ),
+                };
+                out.push_str(&line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for RoundtripReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_markdown())
+    }
+}
+
+/// HWPX로 저장 후 다시 파싱하여 비교 / Write as HWPX, parse back and compare
+///
+/// # Arguments / 매개변수
+/// * `document` - 검증할 문서 / Document to check
+///
+/// # Returns / 반환값
+/// 차이 목록 (저장이나 파싱에 실패하면 오류) / Mismatch report, or an error if writing or parsing fails
+pub fn check_roundtrip(document: &HwpDocument) -> Result<RoundtripReport, HwpError> {
+    let bytes = document.to_hwpx()?;
+    let parsed = HwpParser::new().parse(&bytes)?;
+    Ok(compare_roundtrip(document, &parsed))
+}
+
+/// 왕복 결과가 원본과 같은지 단언 / Assert that a document survives the round trip
+///
+/// # Panics / 패닉
+/// 저장/파싱에 실패하거나 차이가 있으면 보고서와 함께 패닉 / Panics with the report if writing/parsing fails or anything differs
+pub fn assert_roundtrip(document: &HwpDocument) {
+    match check_roundtrip(document) {
+        Ok(report) if report.is_identical() => {}
+        Ok(report) => panic!("HWPX round trip changed the document:\n{report}"),
+        Err(error) => panic!("HWPX round trip failed: {error}"),
+    }
+}
+
+/// 원본과 다시 읽은 문서 비교 / Compare the original with the re-parsed document
+fn compare_roundtrip(original: &HwpDocument, parsed: &HwpDocument) -> RoundtripReport {
+    RoundtripReport {
+        diff: compare(original, parsed),
+        images: compare_images(&original.images(), &parsed.images()),
+    }
+}
+
+/// 이미지를 문서 순서대로 비교 (BinData 번호는 다시 매겨질 수 있음)
+/// Compare images in document order (BinData indices may be renumbered)
+fn compare_images(original: &[DocumentImage], parsed: &[DocumentImage]) -> Vec<ImageMismatch> {
+    let mut mismatches = Vec::new();
+    for position in 0..original.len().max(parsed.len()) {
+        match (original.get(position), parsed.get(position)) {
+            (Some(before), Some(after)) => {
+                let reason = if before.mime_type != after.mime_type {
+                    Some(format!("{} → {}", before.mime_type, after.mime_type))
+                } else if before.data != after.data {
+                    Some(format!(
+                        "{} bytes → {} bytes, content differs",
+                        before.data.len(),
+                        after.data.len()
+                    ))
+                } else {
+                    None
+                };
+                if let Some(reason) = reason {
+                    mismatches.push(ImageMismatch::Changed {
+                        position,
+                        name: before.name.clone(),
+                        reason,
+                    });
+                }
+            }
+            (Some(before), None) => mismatches.push(ImageMismatch::Missing {
+                position,
+                name: before.name.clone(),
+            }),
+            (None, Some(after)) => mismatches.push(ImageMismatch::Unexpected {
+                position,
+                name: after.name.clone(),
+            }),
+            (None, None) => {}
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::DocumentBuilder;
+
+    fn image(name: &str, mime_type: &str, data: &[u8]) -> DocumentImage {
+        DocumentImage {
+            index: 0,
+            name: name.to_string(),
+            mime_type: mime_type.to_string(),
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_built_document_survives_roundtrip() {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R']);
+        png.extend_from_slice(&[0, 0, 0, 4, 0, 0, 0, 4]);
+
+        let document = DocumentBuilder::new()
+            .add_heading(1, "보고서")
+            .add_paragraph("본문", &Default::default())
+            .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+            .add_image(&png)
+            .build();
+        assert_eq!(document.images().len(), 1);
+        let report = check_roundtrip(&document).unwrap();
+        assert!(report.is_identical(), "{report}");
+        assert_eq!(report.to_markdown(), "Round trip is identical.\n");
+        assert_roundtrip(&document);
+    }
+
+    #[test]
+    fn test_report_lists_text_and_image_mismatches() {
+        let original = DocumentBuilder::new()
+            .add_paragraph("가", &Default::default())
+            .build();
+        let changed = DocumentBuilder::new()
+            .add_paragraph("나", &Default::default())
+            .build();
+        let mut report = compare_roundtrip(&original, &changed);
+        assert!(!report.diff.is_empty());
+
+        report.images = compare_images(
+            &[
+                image("a.png", "image/png", b"aaa"),
+                image("b.png", "image/png", b"bbb"),
+            ],
+            &[image("a.png", "image/jpeg", b"aaa")],
+        );
+        assert_eq!(
+            report.images,
+            vec![
+                ImageMismatch::Changed {
+                    position: 0,
+                    name: "a.png".to_string(),
+                    reason: "image/png → image/jpeg".to_string(),
+                },
+                ImageMismatch::Missing {
+                    position: 1,
+                    name: "b.png".to_string(),
+                },
+            ]
+        );
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("~~가~~ → 나"));
+        assert!(markdown.contains("- **Missing** (image 2): b.png"));
+    }
+}
@@ -64,3 +64,3511 @@ fn test_hwp_parser_parse_with_invalid_data() {
     // Should fail because it's not a valid CFB structure
     assert!(result.is_err(), "Should fail for invalid CFB data");
 }
+
+#[test]
+fn test_document_json_round_trip() {
+    // JSON 내보내기 → from_json → 다시 내보내기 결과가 같아야 함
+    // Export to JSON → from_json → re-export should produce identical JSON
+    use crate::common::find_all_hwp_files;
+
+    for path in find_all_hwp_files() {
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        let Ok(document) = HwpParser::new().parse(&data) else {
+            continue;
+        };
+        let json = document.to_json().expect("Should serialize document");
+        let restored = HwpDocument::from_json(&json)
+            .unwrap_or_else(|e| panic!("Should deserialize {path}: {e}"));
+        let json_again = restored
+            .to_json()
+            .expect("Should serialize restored document");
+        assert_eq!(json, json_again, "JSON round trip differs for {path}");
+    }
+}
+
+#[test]
+fn test_document_json_legacy_layout() {
+    // 이전 배치(스키마 1)로 내보낸 JSON도 같은 문서로 읽혀야 함
+    // JSON exported in the previous layout (schema 1) must read back as the same document
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let json = document.to_json().unwrap();
+    assert!(json.starts_with(&format!("{{\n  \"schema_version\": {JSON_SCHEMA_VERSION},")));
+    assert!(json.contains("\"storage_type\": \"embedding\""));
+
+    let legacy = document
+        .to_json_with_options(&JsonOptions::default().with_legacy_layout(true))
+        .unwrap();
+    assert!(!legacy.contains("schema_version"));
+    assert!(legacy.contains("\"storage_type\": \"EMBEDDING\""));
+    // 스키마 3에서 빠진 문단 모양 `outdent` / Paragraph shape `outdent` dropped in schema 3
+    assert!(legacy.contains("\"outdent\": "));
+    assert!(!json.contains("\"outdent\": "));
+    let restored = HwpDocument::from_json(&legacy).expect("Should read schema 1 JSON");
+    assert_eq!(restored.to_json().unwrap(), json);
+}
+
+#[test]
+fn test_document_from_json_invalid() {
+    let result = HwpDocument::from_json("{\"file_header\": 1}");
+    assert!(matches!(
+        result.as_ref().map_err(HwpError::kind),
+        Err(HwpError::JsonError(_))
+    ));
+}
+
+#[test]
+fn test_diff_detects_removed_table_paragraph() {
+    // 같은 문서 비교는 변경 없음, 표 문단을 지우면 삭제로 보고
+    // Comparing a document with itself yields no changes; dropping a table paragraph is reported as removed
+    let Some(path) = common::find_fixture_file("table.hwp") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    assert!(hwp_core::diff::compare(&document, &document).is_empty());
+
+    let mut edited = document.clone();
+    let section = &mut edited.body_text.sections[0];
+    let Some(table_index) = section.paragraphs.iter().position(|p| {
+        p.records.iter().any(|r| match r {
+            hwp_core::document::ParagraphRecord::CtrlHeader { children, .. } => children
+                .iter()
+                .any(|c| matches!(c, hwp_core::document::ParagraphRecord::Table { .. })),
+            _ => false,
+        })
+    }) else {
+        return;
+    };
+    section.paragraphs.remove(table_index);
+
+    let diff = hwp_core::diff::compare(&document, &edited);
+    assert!(diff.changes.iter().any(|c| matches!(
+        c,
+        hwp_core::diff::DiffChange::ParagraphRemoved { old_index, .. } if *old_index == table_index
+    )));
+}
+
+/// HWPX 파일의 section0.xml을 바꿔서 다시 압축 / Re-zip an HWPX file with section0.xml rewritten
+fn rewrite_hwpx_section(data: &[u8], rewrite: impl Fn(&str) -> String) -> Vec<u8> {
+    rewrite_hwpx_part(data, "Contents/section0.xml", rewrite)
+}
+
+/// HWPX 파일의 파트 하나를 바꿔서 다시 압축 / Re-zip an HWPX file with one part rewritten
+fn rewrite_hwpx_part(data: &[u8], part: &str, rewrite: impl Fn(&str) -> String) -> Vec<u8> {
+    rewrite_hwpx_part_bytes(data, part, |xml| rewrite(xml).into_bytes())
+}
+
+/// HWPX 파일의 파트 하나를 임의의 바이트로 바꿔서 다시 압축 / Re-zip an HWPX file with one part rewritten to arbitrary bytes
+fn rewrite_hwpx_part_bytes(data: &[u8], part: &str, rewrite: impl Fn(&str) -> Vec<u8>) -> Vec<u8> {
+    use std::io::{Read, Write};
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).unwrap();
+        if name == part {
+            content = rewrite(&String::from_utf8(content).unwrap());
+        }
+        writer
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(&content).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+/// HWPX 파일의 파트 하나의 이름을 바꿔서 다시 압축 / Re-zip an HWPX file with one part renamed
+fn rename_hwpx_part(data: &[u8], from: &str, to: &str) -> Vec<u8> {
+    use std::io::{Read, Write};
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).unwrap();
+        writer
+            .start_file(
+                if name == from { to } else { name.as_str() },
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(&content).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_hwpx_repair_xml_option() {
+    // 태그가 아닌 `<`와 이스케이프되지 않은 `&`는 복구 모드에서만 변환됨
+    // Stray `<` and unescaped `&` only convert in repair mode
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let broken = rewrite_hwpx_section(&data, |xml| {
+        xml.replacen("<hp:t>", "<hp:t>R&D a < b\u{1} ", 1)
+    });
+
+    assert!(HwpParser::new().parse(&broken).is_err());
+
+    let parser = HwpParser::with_options(ParseOptions::default().with_repair_xml(true));
+    let (document, warnings) = parser
+        .parse_with_warnings(&broken)
+        .expect("Repair mode should parse");
+    assert!(!warnings.is_empty());
+    assert!(warnings.iter().all(|w| w.part == "Contents/section0.xml"));
+    assert!(document
+        .search("R&D a < b", &document::SearchOptions::default())
+        .map(|hits| !hits.is_empty())
+        .unwrap());
+
+    // 정상 문서는 경고 없음 / Well-formed documents produce no warnings
+    let (_, warnings) = parser.parse_with_warnings(&data).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_hwpx_lenient_option() {
+    // 관대한 모드는 XML 오류를 경고로 남기고 나머지 내용을 계속 읽음
+    // Lenient mode records XML errors as warnings and keeps reading the rest
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let original = HwpParser::new().parse(&data).unwrap();
+    let broken = rewrite_hwpx_section(&data, |xml| xml.replacen("</hp:t>", "</hp:x>", 1));
+
+    assert!(HwpParser::new().parse(&broken).is_err());
+
+    let parser = HwpParser::with_options(ParseOptions::default().with_lenient(true));
+    let (document, warnings) = parser
+        .parse_with_warnings(&broken)
+        .expect("Lenient mode should parse");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].part, "Contents/section0.xml");
+    assert_eq!(warnings[0].element.as_deref(), Some("hp:t"));
+    assert_eq!(document.warnings, warnings);
+
+    let paragraph_count = |document: &hwp_core::HwpDocument| {
+        document
+            .body_text
+            .sections
+            .iter()
+            .map(|section| section.paragraphs.len())
+            .sum::<usize>()
+    };
+    assert_eq!(paragraph_count(&document), paragraph_count(&original));
+
+    // 정상 문서는 경고 없음 / Well-formed documents produce no warnings
+    let document = parser.parse(&data).unwrap();
+    assert!(document.warnings.is_empty());
+}
+
+#[test]
+#[cfg(feature = "fs")]
+fn test_template_file_instantiation() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let template_path =
+        std::env::temp_dir().join(format!("hwp-core-template-{}.hwtx", std::process::id()));
+    std::fs::copy(&path, &template_path).expect("Should copy fixture");
+    let template = HwpParser::new().parse_file(&template_path);
+    std::fs::remove_file(&template_path).ok();
+    let template = template.expect("Template should parse");
+    assert!(template.is_template);
+    assert_eq!(template.source_format, SourceFormat::Hwpx);
+
+    let data = std::fs::read(&path).expect("Should read fixture");
+    assert!(!HwpParser::new().parse(&data).unwrap().is_template);
+    let parser = HwpParser::with_options(ParseOptions::default().with_template(true));
+    assert!(parser.parse(&data).unwrap().is_template);
+
+    let document = builder::DocumentBuilder::from_template(&template)
+        .add_paragraph("서식에서 만든 문단", &Default::default())
+        .build();
+    assert!(!document.is_template);
+    let reparsed = HwpParser::new()
+        .parse(&document.to_hwpx().expect("Should write HWPX"))
+        .expect("Should parse written document");
+    assert!(reparsed
+        .search("서식에서 만든 문단", &document::SearchOptions::default())
+        .map(|hits| !hits.is_empty())
+        .unwrap());
+}
+
+#[test]
+fn test_hwpx_resource_limits() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    assert!(HwpParser::new().parse(&data).is_ok());
+
+    // 관대한 파싱도 한도 초과는 복구하지 않음 / Lenient parsing does not recover from limits
+    for limits in [
+        ResourceLimits::default().with_max_entry_size(256),
+        ResourceLimits::default().with_max_total_size(1024),
+        ResourceLimits::default().with_max_entry_count(2),
+        ResourceLimits::default().with_max_xml_depth(3),
+    ] {
+        let options = ParseOptions::default()
+            .with_lenient(true)
+            .with_limits(limits);
+        let result = HwpParser::with_options(options).parse(&data);
+        assert!(
+            matches!(
+                result.as_ref().map_err(HwpError::kind),
+                Err(HwpError::ResourceLimitExceeded { .. })
+            ),
+            "{limits:?}"
+        );
+    }
+}
+
+/// HWP5 레코드 헤더 인코딩 / Encode an HWP5 record header
+fn hwp5_record(tag: u16, level: u32, data: &[u8]) -> Vec<u8> {
+    let header = u32::from(tag) | (level << 10) | ((data.len() as u32) << 20);
+    let mut record = header.to_le_bytes().to_vec();
+    record.extend_from_slice(data);
+    record
+}
+
+#[test]
+fn test_hwp5_self_referencing_list_header_terminates() {
+    use hwp_core::document::bodytext::HwpTag;
+
+    // 문단 수가 1인데 자식이 없는 LIST_HEADER는 원본 데이터에서 문단을 다시 찾습니다.
+    // 뒤따르는 문단 안에 같은 내용의 LIST_HEADER가 있어도 같은 위치를 반복해서 찾으면 안 됩니다.
+    // A LIST_HEADER claiming one paragraph without children rescans the raw data. An identical
+    // LIST_HEADER inside the following paragraph must not make the rescan find itself forever.
+    let list_header = [1, 0, 0, 0, 0, 0, 0, 0];
+    let para_header = [0u8; 22];
+    let mut data = hwp5_record(HwpTag::PARA_HEADER, 0, &para_header);
+    data.extend(hwp5_record(HwpTag::LIST_HEADER, 1, &list_header));
+    data.extend(hwp5_record(HwpTag::PARA_HEADER, 1, &para_header));
+    data.extend(hwp5_record(HwpTag::LIST_HEADER, 2, &list_header));
+
+    let paragraphs = document::Section::parse_data(&data, 0x05000300).unwrap();
+    assert_eq!(paragraphs.len(), 1);
+}
+
+#[test]
+fn test_hwp5_deeply_nested_records_are_rejected() {
+    use hwp_core::document::bodytext::HwpTag;
+
+    let list_header = [1, 0, 0, 0, 0, 0, 0, 0];
+    let para_header = [0u8; 22];
+    let mut data = Vec::new();
+    for level in 0..100 {
+        data.extend(hwp5_record(HwpTag::PARA_HEADER, level * 2, &para_header));
+        data.extend(hwp5_record(
+            HwpTag::LIST_HEADER,
+            level * 2 + 1,
+            &list_header,
+        ));
+    }
+
+    let error = document::Section::parse_data(&data, 0x05000300).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        HwpError::RecordTreeParseError { .. }
+    ));
+}
+
+#[test]
+fn test_hwp5_truncated_records_return_errors() {
+    // 레코드 하나씩 마지막 바이트를 잘라 모든 레코드 파서의 경계 검사를 확인합니다 (패닉이 없어야 함)
+    // Drop the last byte of each record in turn to exercise every record parser's bounds checks (must not panic)
+    fn truncate_record(data: &[u8], target: usize) -> Option<Vec<u8>> {
+        let mut offset = 0;
+        let mut index = 0;
+        while offset + 4 <= data.len() {
+            let header = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+            let size = (header >> 20) as usize;
+            if size == 0xFFF {
+                return None; // 확장 크기 레코드는 건너뜀 / Skip extended-size records
+            }
+            if index == target {
+                if size == 0 {
+                    return Some(data.to_vec());
+                }
+                let mut out = data[..offset].to_vec();
+                out.extend_from_slice(
+                    &((header & 0xFFFFF) | (((size - 1) as u32) << 20)).to_le_bytes(),
+                );
+                out.extend_from_slice(&data[offset + 4..offset + 4 + size - 1]);
+                out.extend_from_slice(&data[offset + 4 + size..]);
+                return Some(out);
+            }
+            offset += 4 + size;
+            index += 1;
+        }
+        None
+    }
+
+    for name in [
+        "noori.hwp",
+        "charshape.hwp",
+        "shaperect.hwp",
+        "pagedefs.hwp",
+    ] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let data = std::fs::read(&path).unwrap();
+        let mut cfb = CfbParser::parse(&data).unwrap();
+        let header =
+            document::FileHeader::parse(&CfbParser::read_stream(&mut cfb, "FileHeader").unwrap())
+                .unwrap();
+        let mut uncompressed = header.clone();
+        uncompressed.document_flags &= !0x01;
+        let doc_info =
+            decompress_deflate(&CfbParser::read_stream(&mut cfb, "DocInfo").unwrap()).unwrap();
+        let section = decompress_deflate(
+            &CfbParser::read_nested_stream(&mut cfb, "BodyText", "Section0").unwrap(),
+        )
+        .unwrap();
+
+        for target in 0.. {
+            let Some(mutated) = truncate_record(&doc_info, target) else {
+                break;
+            };
+            let _ = document::DocInfo::parse(&mutated, &uncompressed);
+        }
+        for target in 0.. {
+            let Some(mutated) = truncate_record(&section, target) else {
+                break;
+            };
+            let _ = document::Section::parse_data(&mutated, header.version);
+        }
+    }
+}
+
+#[test]
+fn test_compact_ids_keeps_content_and_is_idempotent() {
+    use crate::common::find_all_hwp_files;
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    for path in find_all_hwp_files() {
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        let Ok(mut document) = HwpParser::new().parse(&data) else {
+            continue;
+        };
+        let markdown = document.to_markdown(&options);
+        let images = document.images().len();
+
+        let report = document.compact_ids();
+        assert_eq!(report.dangling_references, 0, "{path}");
+        assert_eq!(document.to_markdown(&options), markdown, "{path}");
+        assert_eq!(document.images().len(), images, "{path}");
+        let doc_info = &document.doc_info;
+        assert_eq!(
+            doc_info.id_mappings.as_ref().map(|m| m.char_shape as usize),
+            Some(doc_info.char_shapes.len()),
+            "{path}"
+        );
+        assert!(document.compact_ids().is_unchanged(), "{path}");
+    }
+}
+
+#[test]
+fn test_viewers_render_non_image_bindata_as_attachments() {
+    // OLE 개체(CFB) 바이트 / OLE object (CFB) bytes
+    let ole = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1, 0, 0, 0, 0];
+    let document = builder::DocumentBuilder::new().add_image(&ole).build();
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options);
+    assert!(markdown.contains("[첨부 파일: BIN0000.ole (application/x-ole-storage)]"));
+    assert!(!markdown.contains("data:"));
+
+    #[cfg(feature = "fs")]
+    {
+        let dir = std::env::temp_dir().join(format!("hwp-core-attachments-{}", std::process::id()));
+        let options = options.with_image_output_dir(Some(dir.to_str().unwrap()));
+        let markdown = document.to_markdown(&options);
+        let saved = std::fs::read(dir.join("BIN0000.ole"));
+        std::fs::remove_dir_all(&dir).ok();
+        assert!(markdown.contains("[첨부 파일: BIN0000.ole](images/BIN0000.ole)"));
+        assert_eq!(saved.expect("Attachment should be saved"), ole);
+    }
+}
+
+#[test]
+fn test_viewers_share_identical_images() {
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let gif = *b"GIF89a\x01\x00";
+    let document = builder::DocumentBuilder::new()
+        .add_image(&png)
+        .add_image(&gif)
+        .add_image(&png)
+        .build();
+    assert_eq!(document.duplicate_bin_data(), [vec![0, 2]]);
+    assert_eq!(document.canonical_bin_data_index(2), 0);
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    // 같은 그림은 data URI 참조 정의 하나를 함께 씀 / Identical pictures share one data URI definition
+    let markdown = document.to_markdown(&options);
+    assert_eq!(markdown.matches("![이미지][BIN0000]").count(), 2);
+    assert_eq!(markdown.matches("data:image/png").count(), 1);
+    assert!(markdown.contains("[BIN0000]: data:image/png;base64,"));
+    assert!(markdown.contains("![이미지](data:image/gif;base64,"));
+
+    #[cfg(feature = "fs")]
+    {
+        // 파일로 저장하면 한 파일만 씀 / Saving to files writes one file
+        let dir =
+            std::env::temp_dir().join(format!("hwp-core-shared-images-{}", std::process::id()));
+        let markdown =
+            document.to_markdown(&options.with_image_output_dir(Some(dir.to_str().unwrap())));
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        std::fs::remove_dir_all(&dir).ok();
+        assert_eq!(files, ["BIN0000.png", "BIN0001.gif"]);
+        assert_eq!(markdown.matches("![이미지](images/BIN0000.png)").count(), 2);
+    }
+}
+
+#[test]
+fn test_deterministic_output_ignores_package_order() {
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let gif = *b"GIF89a\x01\x00";
+    let document = builder::DocumentBuilder::new()
+        .add_image(&png)
+        .add_image(&gif)
+        .add_image(&gif)
+        .build();
+    // 같은 문서를 ZIP 항목을 거꾸로 묶은 것처럼 / The same document as if its ZIP entries were packed in reverse
+    let mut reordered = document.clone();
+    reordered.bin_data.items.reverse();
+    for (index, item) in reordered.bin_data.items.iter_mut().enumerate() {
+        item.index = index as u16;
+    }
+    assert_eq!(document.stable_bin_data_index(2), 2);
+    assert_eq!(reordered.stable_bin_data_index(0), 2);
+    assert_eq!(reordered.stable_bin_data_index(2), 1);
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(true),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert_ne!(
+        document.to_markdown(&options),
+        reordered.to_markdown(&options)
+    );
+    let options = options.with_deterministic(true);
+    let markdown = document.to_markdown(&options);
+    assert_eq!(markdown, reordered.to_markdown(&options));
+    assert!(markdown.contains("[BIN0002]: data:image/gif;base64,"));
+
+    #[cfg(feature = "fs")]
+    {
+        // 저장한 파일 이름도 같음 / Saved file names match too
+        let saved = |document: &document::HwpDocument, name: &str| {
+            let dir = std::env::temp_dir().join(format!(
+                "hwp-core-deterministic-{name}-{}",
+                std::process::id()
+            ));
+            let dir_path = dir.to_str().unwrap();
+            let markdown =
+                document.to_markdown(&options.clone().with_image_output_dir(Some(dir_path)));
+            let html = document.to_html(
+                &viewer::html::HtmlOptions::default()
+                    .with_image_output_dir(Some(dir_path))
+                    .with_deterministic(true),
+            );
+            let mut files: Vec<_> = std::fs::read_dir(&dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect();
+            files.sort();
+            std::fs::remove_dir_all(&dir).ok();
+            (markdown, html, files)
+        };
+        let (markdown, html, files) = saved(&document, "original");
+        assert_eq!(
+            (markdown.clone(), html, files.clone()),
+            saved(&reordered, "reordered")
+        );
+        assert_eq!(files, ["BIN0001.png", "BIN0002.gif"]);
+        assert_eq!(markdown.matches("![이미지](images/BIN0002.gif)").count(), 2);
+    }
+}
+
+#[test]
+fn test_image_sink_receives_pictures() {
+    use std::sync::{Arc, Mutex};
+
+    // 객체 저장소 대신 메모리에 두는 저장소 / Sink that keeps pictures in memory instead of object storage
+    #[derive(Debug, Default)]
+    struct MemorySink(Mutex<Vec<(String, Vec<u8>)>>);
+
+    impl viewer::ImageSink for MemorySink {
+        fn save(&self, name: &str, data: &[u8]) -> Result<String, HwpError> {
+            self.0
+                .lock()
+                .unwrap()
+                .push((name.to_string(), data.to_vec()));
+            Ok(format!("https://cdn.example.com/{name}"))
+        }
+    }
+
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let mut document = builder::DocumentBuilder::new().add_image(&png).build();
+    // HTML은 크기가 있는 그림만 그림 / HTML only draws pictures with a size
+    for paragraph in &mut document.body_text.sections[0].paragraphs {
+        for record in &mut paragraph.records {
+            if let document::ParagraphRecord::HwpxImage { width, height, .. } = record {
+                (*width, *height) = (4800, 2400);
+            }
+        }
+    }
+    let sink = Arc::new(MemorySink::default());
+
+    let html = document.to_html(
+        &viewer::html::HtmlOptions::default()
+            .with_image_output_dir(Some("/nonexistent/ignored"))
+            .with_image_sink(Some(sink.clone()))
+            .with_deterministic(true),
+    );
+    assert!(html.contains("url('https://cdn.example.com/BIN0001.png')"));
+    assert!(!html.contains("data:image/png"));
+
+    let markdown = document.to_markdown(
+        &viewer::markdown::MarkdownOptions {
+            image_output_dir: None,
+            image_sink: None,
+            use_html: None,
+            include_version: None,
+            include_page_info: None,
+            include_references: None,
+            include_toc: None,
+            line_break_mode: None,
+            preserve_alignment: None,
+            inline_controls: Default::default(),
+            image_max_dimension: None,
+            image_format: None,
+            flavor: None,
+            include_source_anchors: None,
+            strip_page_fields: None,
+            content_filter: None,
+            deterministic: true,
+        }
+        .with_image_sink(Some(sink.clone())),
+    );
+    assert!(markdown.contains("![이미지](https://cdn.example.com/BIN0001.png)"));
+    assert!(!markdown.contains("base64"));
+
+    let saved = sink.0.lock().unwrap();
+    assert_eq!(saved.len(), 2);
+    assert!(saved
+        .iter()
+        .all(|(name, data)| name == "BIN0001.png" && data[..] == png));
+}
+
+#[test]
+fn test_image_export_options() {
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    }
+    .with_image_max_dimension(Some(16))
+    .with_image_format(viewer::ImageFormat::from_name("jpg", Some(70)));
+    assert_eq!(
+        options.image_format,
+        Some(viewer::ImageFormat::Jpeg { quality: 70 })
+    );
+
+    // 읽을 수 없는 그림은 원본 그대로 / Pictures that cannot be decoded are kept as they are
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let document = builder::DocumentBuilder::new().add_image(&png).build();
+    assert!(document
+        .to_markdown(&options)
+        .contains("![이미지](data:image/png;base64,iVBORw0KGgoBAgM=)"));
+
+    #[cfg(feature = "image-processing")]
+    {
+        let picture = image::RgbImage::from_pixel(64, 32, image::Rgb([0, 128, 255]));
+        let mut png = std::io::Cursor::new(Vec::new());
+        picture.write_to(&mut png, image::ImageFormat::Png).unwrap();
+        let mut document = builder::DocumentBuilder::new()
+            .add_image(png.get_ref())
+            .build();
+        assert!(document
+            .to_markdown(&options)
+            .contains("![이미지](data:image/jpeg;base64,"));
+
+        // HTML은 크기가 있는 그림만 그림 / HTML only draws pictures with a size
+        for paragraph in &mut document.body_text.sections[0].paragraphs {
+            for record in &mut paragraph.records {
+                if let document::ParagraphRecord::HwpxImage { width, height, .. } = record {
+                    (*width, *height) = (4800, 2400);
+                }
+            }
+        }
+        let html = document.to_html(
+            &viewer::html::HtmlOptions::default()
+                .with_image_format(Some(viewer::ImageFormat::Webp)),
+        );
+        assert!(html.contains("data:image/webp;base64,"));
+    }
+}
+
+#[test]
+fn test_image_alt_text_and_caption() {
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let mut document = builder::DocumentBuilder::new().add_image(&png).build();
+    assert_eq!(document.image_label(0).alt_text(), "이미지");
+    for paragraph in &mut document.body_text.sections[0].paragraphs {
+        for record in &mut paragraph.records {
+            if let document::ParagraphRecord::HwpxImage {
+                width,
+                height,
+                description,
+                caption,
+                ..
+            } = record
+            {
+                (*width, *height) = (4800, 2400);
+                *description = Some("서울 [지도]".to_string());
+                *caption = Some("그림 1. <서울>".to_string());
+            }
+        }
+    }
+    assert_eq!(document.image_label(0).alt_text(), "서울 [지도]");
+
+    let markdown = document.to_markdown(&viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    });
+    assert!(markdown.contains("![서울 \\[지도\\]](data:image/png;base64,"));
+    assert!(markdown.contains("그림 1. <서울>"));
+
+    let html = document.to_html(&viewer::html::HtmlOptions::default());
+    assert!(html.contains(r#"role="img" aria-label="서울 [지도]""#));
+    assert!(html.contains("<figcaption>그림 1. &lt;서울&gt;</figcaption>"));
+}
+
+#[test]
+fn test_accessible_html_output() {
+    let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3];
+    let mut document = builder::DocumentBuilder::new()
+        .add_heading(2, "개요")
+        .add_image(&png)
+        .build();
+    for paragraph in &mut document.body_text.sections[0].paragraphs {
+        for record in &mut paragraph.records {
+            if let document::ParagraphRecord::HwpxImage { width, height, .. } = record {
+                (*width, *height) = (4800, 2400);
+            }
+        }
+    }
+
+    let plain = document.to_html(&viewer::html::HtmlOptions::default());
+    assert!(plain.contains("<html>\n"));
+    assert!(!plain.contains("<h2") && !plain.contains("<figure"));
+
+    let options = viewer::html::HtmlOptions::default().with_accessibility(true);
+    let html = document.to_html(&options);
+    assert!(html.contains(r#"<html lang="ko">"#));
+    assert!(html.contains(r#"<h2 class="hls ps1">"#) && html.contains("개요</span></h2>"));
+    assert!(html.contains(r#"<figure class="figure""#));
+
+    let Some(path) = common::find_fixture_file("table.hwp") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let mut document = HwpParser::new()
+        .parse(&data)
+        .expect("Should parse table.hwp");
+    fn mark_header_rows(records: &mut [document::ParagraphRecord]) {
+        for record in records {
+            match record {
+                document::ParagraphRecord::Table { table } => {
+                    table.attributes.attribute.header_row_repeat = true;
+                }
+                document::ParagraphRecord::CtrlHeader { children, .. } => {
+                    mark_header_rows(children)
+                }
+                _ => {}
+            }
+        }
+    }
+    for section in &mut document.body_text.sections {
+        for paragraph in &mut section.paragraphs {
+            mark_header_rows(&mut paragraph.records);
+        }
+    }
+
+    let html = document.to_html(&options);
+    assert!(html.contains(r#"<div class="htb" role="table""#));
+    assert!(html.contains(r#"<div role="row" style="display:contents;">"#));
+    assert!(html.contains(r#"<div class="hce" role="columnheader""#));
+    assert!(html.contains(r#"<div class="hce" role="cell""#));
+    assert!(html.contains(r#"<svg class="hs" aria-hidden="true" focusable="false""#));
+    assert_eq!(html.matches("<div").count(), html.matches("</div>").count());
+}
+
+#[test]
+fn test_table_csv_and_xlsx_export() {
+    use std::io::Read;
+
+    let document = builder::DocumentBuilder::new()
+        .add_paragraph("통계표", &Default::default())
+        .add_table(&[vec!["지역", "인구, 명"], vec!["서울", "9,386,034"]])
+        .build();
+    let tables = document.tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].rows[1], vec!["서울", "9,386,034"]);
+    assert_eq!(tables[0].header_rows, 1);
+    assert_eq!(
+        tables[0].to_csv(),
+        "지역,\"인구, 명\"\r\n서울,\"9,386,034\"\r\n"
+    );
+
+    #[cfg(feature = "fs")]
+    {
+        let dir = std::env::temp_dir().join(format!("hwp-core-tables-{}", std::process::id()));
+        let paths = document.export_tables_csv(&dir).unwrap();
+        assert_eq!(paths, vec![dir.join("table1.csv")]);
+        assert!(std::fs::read_to_string(&paths[0])
+            .unwrap()
+            .starts_with("\u{FEFF}지역,"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    let xlsx = document.tables_to_xlsx().unwrap();
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+    let mut workbook = String::new();
+    archive
+        .by_name("xl/workbook.xml")
+        .unwrap()
+        .read_to_string(&mut workbook)
+        .unwrap();
+    assert!(workbook.contains(r#"<sheet name="Table1" sheetId="1" r:id="rId1"/>"#));
+    let mut sheet = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut sheet)
+        .unwrap();
+    assert!(sheet.contains(r#"state="frozen""#));
+    assert!(sheet
+        .contains(r#"<c r="B1" t="inlineStr"><is><t xml:space="preserve">인구, 명</t></is></c>"#));
+    assert!(sheet.contains(r#"<c r="B2"><v>9386034</v></c>"#));
+}
+
+#[test]
+fn test_viewers_handle_right_to_left_text() {
+    let document = builder::DocumentBuilder::new()
+        .add_paragraph("مرحبا بالعالم 2024", &Default::default())
+        .add_paragraph("한글 문단 (שלום) 끝", &Default::default())
+        .add_table(&[vec!["이름", "الاسم"], vec!["\u{202E}abc", "123"]])
+        .build();
+
+    let html = document.to_html(&viewer::html::HtmlOptions::default());
+    assert!(html.contains(r#"dir="rtl""#));
+    assert_eq!(html.matches(r#"<div class="hls ps0" dir="rtl""#).count(), 1);
+    // 한 글자 모양 안에서 방향이 섞이면 반대 방향만 격리 / Opposing text inside one char shape run is isolated
+    assert!(html
+        .contains(r#"한글 문단 (<span dir="rtl" style="unicode-bidi:isolate;">שלום</span>) 끝"#));
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options);
+    // 반대 방향 구간 뒤에 문단 방향 표시 문자 / Paragraph-direction mark after the opposing run
+    assert!(markdown.contains("한글 문단 (שלום\u{200E}) 끝"));
+    assert!(markdown.contains("مرحبا بالعالم 2024"));
+    assert!(markdown.contains("| 이름 | \u{2068}الاسم\u{2069} |"));
+    // 닫히지 않은 방향 재정의는 셀 안에서 닫힘 / An unterminated override is closed inside its cell
+    assert!(markdown.contains("| \u{202E}abc\u{202C} | 123 |"));
+}
+
+#[test]
+fn test_borrowed_hwpx_text_matches_parser() {
+    use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("R&D 현황", &Default::default())
+        .add_table(&[vec!["항목", "값"]])
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let mut files = vec![built];
+    if let Some(path) = common::find_fixture_file("linespacing.hwpx") {
+        files.push(std::fs::read(&path).expect("Should read fixture"));
+    }
+
+    // 같은 버퍼를 파일마다 재사용 / The same buffers are reused for every file
+    let mut buffers = HwpxBuffers::new();
+    for data in &files {
+        let document = HwpParser::new().parse(data).expect("Should parse HWPX");
+        buffers.load(data).expect("Should load HWPX");
+        let borrowed = buffers.parse().expect("Should parse borrowed HWPX");
+
+        let texts: Vec<String> = borrowed
+            .paragraphs()
+            .map(|p| p.text().into_owned())
+            .collect();
+        for paragraph in &document.body_text.sections[0].paragraphs {
+            let text = paragraph.text();
+            if !text.is_empty() && !text.contains('\n') {
+                assert!(texts.contains(&text), "{text:?} not in {texts:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hwpx_parts_in_other_encodings() {
+    use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("인코딩 확인", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let expected = HwpParser::new()
+        .parse(&built)
+        .expect("Should parse HWPX")
+        .text();
+
+    // BOM이 붙은 UTF-16LE 구역, BOM 없는 UTF-16BE 머리 / UTF-16LE section with a BOM, BOM-less UTF-16BE header
+    let utf16 = rewrite_hwpx_part_bytes(&built, "Contents/section0.xml", |xml| {
+        [0xFF, 0xFE]
+            .into_iter()
+            .chain(xml.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect()
+    });
+    let utf16 = rewrite_hwpx_part_bytes(&utf16, "Contents/header.xml", |xml| {
+        xml.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    });
+    // XML 선언으로 EUC-KR을 밝힌 구역 / Section declaring EUC-KR in its XML declaration
+    let euc_kr = rewrite_hwpx_part_bytes(&built, "Contents/section0.xml", |xml| {
+        let xml = xml.replacen(
+            r#"<?xml version="1.0" encoding="UTF-8""#,
+            r#"<?xml version="1.0" encoding="EUC-KR""#,
+            1,
+        );
+        assert!(xml.contains("EUC-KR"));
+        let (bytes, _, unmappable) = encoding_rs::EUC_KR.encode(&xml);
+        assert!(!unmappable);
+        bytes.into_owned()
+    });
+
+    let mut buffers = HwpxBuffers::new();
+    for data in [&utf16, &euc_kr] {
+        let document = HwpParser::new().parse(data).expect("Should parse");
+        assert_eq!(document.text(), expected);
+
+        let mut chunks = Vec::new();
+        hwp_core::extract_text_streaming(data, &mut |text| chunks.push(text.to_string()))
+            .expect("Should stream text");
+        assert!(chunks.iter().any(|chunk| chunk == "인코딩 확인"));
+
+        buffers.load(data).expect("Should load HWPX");
+        let borrowed = buffers.parse().expect("Should parse borrowed HWPX");
+        assert!(borrowed.paragraphs().any(|p| p.text() == "인코딩 확인"));
+    }
+}
+
+#[test]
+fn test_hwpx_elements_match_by_namespace() {
+    use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("네임스페이스", &Default::default())
+        .add_table(&[vec!["항목", "값"]])
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let expected = HwpParser::new()
+        .parse(&built)
+        .expect("Should parse HWPX")
+        .text();
+
+    // 다른 접두사 / A different prefix
+    let prefixed = rewrite_hwpx_section(&built, |xml| {
+        xml.replace("xmlns:hp=", "xmlns:para=")
+            .replace("<hp:", "<para:")
+            .replace("</hp:", "</para:")
+    });
+    // 기본 네임스페이스 / A default namespace
+    let default_namespace = rewrite_hwpx_section(&built, |xml| {
+        xml.replace("xmlns:hp=", "xmlns=")
+            .replace("<hp:", "<")
+            .replace("</hp:", "</")
+    });
+    // 같은 로컬 이름의 다른 네임스페이스 요소는 본문이 아님
+    // Elements of other namespaces with the same local name are not body text
+    let foreign = rewrite_hwpx_section(&built, |xml| {
+        assert!(xml.contains("<hp:t>네임스페이스</hp:t>"));
+        xml.replacen(
+            "<hp:t>네임스페이스</hp:t>",
+            r#"<hp:t>네임스페이스</hp:t><x:t xmlns:x="urn:example">숨김</x:t>"#,
+            1,
+        )
+    });
+
+    let mut buffers = HwpxBuffers::new();
+    for data in [&prefixed, &default_namespace, &foreign] {
+        let document = HwpParser::new().parse(data).expect("Should parse");
+        assert_eq!(document.text(), expected);
+
+        buffers.load(data).expect("Should load HWPX");
+        let borrowed = buffers.parse().expect("Should parse borrowed HWPX");
+        assert!(borrowed.paragraphs().any(|p| p.text() == "네임스페이스"));
+    }
+}
+
+#[test]
+fn test_hwpx_sections_follow_spine() {
+    use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("spine 구역", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+
+    // spine이 가리키는 section*.xml이 아닌 이름의 구역 / A section with a non-standard name referenced by the spine
+    let renamed = rename_hwpx_part(&built, "Contents/section0.xml", "Contents/body.xml");
+    let renamed = rewrite_hwpx_part(&renamed, "Contents/content.hpf", |xml| {
+        assert!(xml.contains("Contents/section0.xml"));
+        xml.replace("Contents/section0.xml", "Contents/body.xml")
+    });
+
+    let document = HwpParser::new().parse(&renamed).expect("Should parse");
+    assert_eq!(document.body_text.sections.len(), 1);
+    assert!(document.text().contains("spine 구역"));
+
+    let mut chunks = Vec::new();
+    hwp_core::extract_text_streaming(&renamed, &mut |text| chunks.push(text.to_string()))
+        .expect("Should stream text");
+    assert!(chunks.iter().any(|chunk| chunk == "spine 구역"));
+
+    let mut buffers = HwpxBuffers::new();
+    buffers.load(&renamed).expect("Should load HWPX");
+    let borrowed = buffers.parse().expect("Should parse borrowed HWPX");
+    assert!(borrowed.paragraphs().any(|p| p.text() == "spine 구역"));
+}
+
+#[test]
+fn test_hwpx_package_location_from_container_xml() {
+    use hwp_core::parser::hwpx::borrowed::HwpxBuffers;
+    use std::io::{Read, Write};
+
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("다른 폴더", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let expected = HwpParser::new()
+        .parse(&built)
+        .expect("Should parse HWPX")
+        .text();
+
+    // Contents/를 Main/으로 옮기고 content.hpf의 경로는 패키지 문서 기준으로 씀
+    // Move Contents/ to Main/ and write content.hpf paths relative to the package document
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&built)).unwrap();
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let name = file.name().replacen("Contents/", "Main/", 1);
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        let content = match name.as_str() {
+            "META-INF/container.xml" => content.replace("Contents/content.hpf", "Main/content.hpf"),
+            "Main/content.hpf" => content.replace("\"Contents/", "\""),
+            _ => content,
+        };
+        writer
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    let moved = writer.finish().unwrap().into_inner();
+
+    let document = HwpParser::new().parse(&moved).expect("Should parse");
+    assert_eq!(document.text(), expected);
+    assert!(!document.doc_info.char_shapes.is_empty());
+
+    let mut chunks = Vec::new();
+    hwp_core::extract_text_streaming(&moved, &mut |text| chunks.push(text.to_string()))
+        .expect("Should stream text");
+    assert!(chunks.iter().any(|chunk| chunk == "다른 폴더"));
+
+    let mut buffers = HwpxBuffers::new();
+    buffers.load(&moved).expect("Should load HWPX");
+    let borrowed = buffers.parse().expect("Should parse borrowed HWPX");
+    assert!(borrowed.paragraphs().any(|p| p.text() == "다른 폴더"));
+}
+
+#[test]
+fn test_hwpx_keeps_unparsed_parts() {
+    use std::io::Write;
+
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(path).unwrap();
+
+    // 모델에 없는 파트 추가 / Add parts the model does not cover
+    let mut writer = zip::ZipWriter::new_append(std::io::Cursor::new(data)).unwrap();
+    for (name, content) in [
+        ("Custom/custom.xml", "<custom>값</custom>"),
+        ("DocHistory/history.xml", "<history/>"),
+    ] {
+        writer
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    let data = writer.finish().unwrap().into_inner();
+
+    let document = HwpParser::new().parse(&data).expect("Should parse HWPX");
+    let parts = document.raw_parts();
+    for part in [
+        "Custom/custom.xml",
+        "DocHistory/history.xml",
+        "settings.xml",
+        "META-INF/manifest.xml",
+    ] {
+        assert!(parts.contains(&part), "{part} should be kept");
+    }
+    // 모델로 읽은 파트는 보관하지 않음 / Parts read into the model are not kept
+    assert!(!parts
+        .iter()
+        .any(|part| part.starts_with("Contents/") || part.starts_with("Preview/")));
+    assert_eq!(
+        document.read_part("Custom/custom.xml"),
+        Some("<custom>값</custom>".as_bytes())
+    );
+    assert_eq!(document.read_part("Contents/section0.xml"), None);
+}
+
+#[test]
+fn test_streaming_text_matches_parser() {
+    let built = builder::DocumentBuilder::new()
+        .add_paragraph("  요약  ", &Default::default())
+        .add_table(&[vec!["항목", "값"]])
+        .add_paragraph("", &Default::default())
+        .add_paragraph("끝", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let mut files = vec![built];
+    for name in ["linespacing.hwpx", "linespacing.hwp", "table.hwp"] {
+        if let Some(path) = common::find_fixture_file(name) {
+            files.push(std::fs::read(&path).expect("Should read fixture"));
+        }
+    }
+
+    for data in &files {
+        let document = HwpParser::new().parse(data).expect("Should parse");
+        let mut chunks = Vec::new();
+        hwp_core::extract_text_streaming(data, &mut |text| chunks.push(text.to_string()))
+            .expect("Should stream text");
+
+        // 모든 문단(중첩 포함)의 텍스트가 스트림에 있음 / Every paragraph's text, nested ones included, is streamed
+        for section in &document.body_text.sections {
+            for paragraph in &section.paragraphs {
+                for target in std::iter::once(paragraph).chain(paragraph.nested_paragraphs()) {
+                    let text = target.text();
+                    let text = text.trim();
+                    if !text.is_empty() && !text.contains('\n') {
+                        assert!(
+                            chunks.iter().any(|c| c == text),
+                            "{text:?} not in {chunks:?}"
+                        );
+                    }
+                }
+            }
+        }
+        assert!(chunks.iter().all(|c| !c.is_empty() && c.trim() == c));
+    }
+
+    assert!(matches!(
+        hwp_core::extract_text_streaming(b"not a document", &mut |_| {}),
+        Err(HwpError::UnknownFormat)
+    ));
+}
+
+#[test]
+fn test_validate_fixtures_and_broken_section() {
+    for name in ["linespacing.hwpx", "linespacing.hwp"] {
+        let Some(path) = common::find_fixture_file(name) else {
+            return;
+        };
+        let data = std::fs::read(&path).expect("Should read fixture");
+        let report = validate(&data);
+        assert!(report.is_valid(), "{name}: {:?}", report.findings);
+    }
+
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let broken = rewrite_hwpx_section(&data, |xml| {
+        xml.replacen("</hp:p>", "</hp:wrong>", 1).replacen(
+            "<hp:t>",
+            "<hp:pic binaryItemIDRef=\"missing\"/><hp:t>",
+            1,
+        )
+    });
+    let report = validate(&broken);
+    assert!(!report.is_valid());
+    assert!(report.has(ValidationCode::MalformedXml));
+    assert!(report.has(ValidationCode::DanglingBinaryItemRef));
+    assert!(report
+        .errors()
+        .all(|finding| finding.part.as_deref() == Some("Contents/section0.xml")));
+}
+
+#[test]
+fn test_parse_profiling_option() {
+    use hwp_core::ParsePhase;
+
+    let parser = HwpParser::with_options(ParseOptions::default().with_profiling(true));
+    for name in ["linespacing.hwp", "linespacing.hwpx"] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let data = std::fs::read(&path).unwrap();
+
+        let profile = parser
+            .parse(&data)
+            .unwrap()
+            .profile
+            .expect("Should profile");
+        let phases: Vec<ParsePhase> = profile.phases.iter().map(|timing| timing.phase).collect();
+        assert_eq!(
+            phases,
+            [
+                ParsePhase::Container,
+                ParsePhase::Header,
+                ParsePhase::Sections,
+                ParsePhase::BinData,
+                ParsePhase::Metadata,
+                ParsePhase::DisplayText,
+            ],
+            "{name}"
+        );
+        assert!(profile.duration(ParsePhase::Sections).unwrap() <= profile.total());
+
+        // 기본값은 측정하지 않음 / Not recorded by default
+        assert!(HwpParser::new().parse(&data).unwrap().profile.is_none());
+    }
+}
+
+#[test]
+fn test_document_format_reports_source() {
+    let parser = HwpParser::new();
+    if let Some(path) = common::find_fixture_file("linespacing.hwp") {
+        let document = parser.parse(&std::fs::read(&path).unwrap()).unwrap();
+        let format = document.format();
+        assert_eq!(format.format, hwp_core::SourceFormat::Hwp5);
+        assert_eq!(format.version, document.file_header.version_string());
+    }
+    if let Some(path) = common::find_fixture_file("linespacing.hwpx") {
+        let document = parser.parse(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(document.format().format, hwp_core::SourceFormat::Hwpx);
+        // JSON 왕복 후에도 형식 유지 / Format survives a JSON round trip
+        let restored = HwpDocument::from_json(&document.to_json().unwrap()).unwrap();
+        assert_eq!(restored.format(), document.format());
+    }
+}
+
+#[test]
+fn test_builder_document_round_trips_through_hwpx() {
+    // 생성한 문서를 HWPX로 저장한 뒤 다시 파싱해도 텍스트/표/이미지가 유지되어야 함
+    // Text, tables and images of a built document must survive writing to HWPX and parsing back
+    use hwp_core::builder::{DocumentBuilder, ParagraphStyle};
+
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    png.extend_from_slice(&[0, 0, 0, 13, b'I', b'H', b'D', b'R']);
+    png.extend_from_slice(&[0, 0, 0, 4, 0, 0, 0, 4]);
+
+    let document = DocumentBuilder::new()
+        .add_heading(1, "월간 보고서")
+        .add_paragraph(
+            "R&D 현황 <요약>",
+            &ParagraphStyle::default().with_bold(true),
+        )
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .add_image(&png)
+        .build();
+
+    let markdown = document.to_markdown(&hwp_core::viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: None,
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    });
+    assert!(markdown.contains("월간 보고서"));
+
+    let bytes = document.to_hwpx().expect("Should write HWPX");
+    let parsed = HwpParser::new()
+        .parse(&bytes)
+        .expect("Should parse written HWPX");
+
+    let paragraphs = &parsed.body_text.sections[0].paragraphs;
+    let texts: Vec<String> = paragraphs.iter().map(|p| p.text()).collect();
+    assert!(texts.contains(&"월간 보고서".to_string()));
+    assert!(texts.contains(&"R&D 현황 <요약>".to_string()));
+
+    let table = paragraphs
+        .iter()
+        .flat_map(|p| p.records.iter())
+        .find_map(|r| match r {
+            hwp_core::document::ParagraphRecord::Table { table } => Some(table),
+            _ => None,
+        })
+        .expect("Should contain table");
+    assert_eq!(table.attributes.row_count, 2);
+    assert_eq!(table.cells[3].paragraphs[0].text(), "120");
+
+    assert_eq!(parsed.bin_data.items.len(), 1);
+    assert_eq!(parsed.bin_data.items[0].name.as_deref(), Some("image1"));
+    assert!(paragraphs.iter().any(|p| p.records.iter().any(|r| matches!(
+        r,
+        hwp_core::document::ParagraphRecord::HwpxImage { binary_item_ref, .. } if binary_item_ref == "image1"
+    ))));
+}
+
+#[test]
+fn test_bin_data_readers_match_base64() {
+    // reader()와 원본 파일 스트리밍 결과가 base64 디코딩 결과와 같아야 함
+    // reader() and streaming from the source file should match the base64-decoded bytes
+    use crate::common::find_all_hwp_files;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use std::io::Read;
+
+    let parser = HwpParser::new();
+    for path in find_all_hwp_files() {
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        let Ok(document) = parser.parse(&data) else {
+            continue;
+        };
+        for item in &document.bin_data.items {
+            let expected = STANDARD.decode(&item.data).expect("Should decode base64");
+
+            let mut from_item = Vec::new();
+            item.reader().read_to_end(&mut from_item).unwrap();
+            assert_eq!(from_item, expected, "reader() differs for {path}");
+
+            let mut from_source = Vec::new();
+            parser
+                .open_bin_data(&data, item.index)
+                .and_then(|mut r| r.read_to_end(&mut from_source).map_err(HwpError::from))
+                .unwrap_or_else(|e| panic!("Should stream BinData {} of {path}: {e}", item.index));
+            assert_eq!(from_source, expected, "open_bin_data differs for {path}");
+
+            let mut from_document = Vec::new();
+            document
+                .open_bin_data(&data, item.index)
+                .and_then(|mut r| r.read_to_end(&mut from_document).map_err(HwpError::from))
+                .unwrap_or_else(|e| panic!("Should stream BinData {} of {path}: {e}", item.index));
+            assert_eq!(
+                from_document, expected,
+                "HwpDocument::open_bin_data differs for {path}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_open_bin_data_uses_parse_limits() {
+    use std::io::Read;
+
+    // 16x16 IHDR 뒤에 4 KiB 데이터 / 16x16 IHDR followed by 4 KiB of data
+    let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x10\0\0\0\x10".to_vec();
+    png.extend((0..4096u32).map(|i| (i * 31 % 251) as u8));
+    let data = builder::DocumentBuilder::new()
+        .add_image(&png)
+        .build()
+        .to_hwpx()
+        .unwrap();
+
+    // 파서가 쓴 한도를 문서에 남김 / The parser records the limits it used
+    let unlimited = ParseOptions::default().with_limits(ResourceLimits::unlimited());
+    let document = HwpParser::with_options(unlimited).parse(&data).unwrap();
+    assert_eq!(document.resource_limits, ResourceLimits::unlimited());
+    let index = document.bin_data.items[0].index;
+    let mut streamed = Vec::new();
+    document
+        .open_bin_data(&data, index)
+        .unwrap()
+        .read_to_end(&mut streamed)
+        .unwrap();
+    assert_eq!(streamed, png);
+
+    let mut limited = document.clone();
+    limited.resource_limits = ResourceLimits::default().with_max_entry_size(1024);
+    assert!(matches!(
+        limited
+            .open_bin_data(&data, index)
+            .map(|_| ())
+            .map_err(|e| e.kind().clone()),
+        Err(HwpError::ResourceLimitExceeded {
+            limit: ResourceLimit::EntrySize,
+            max: 1024
+        })
+    ));
+}
+
+#[test]
+fn test_merge_documents_remaps_pictures() {
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let original = HwpParser::new()
+        .parse(&std::fs::read(path).unwrap())
+        .unwrap();
+    let mut merged = original.clone();
+    let report =
+        merge::merge_documents(&mut merged, &original, &merge::MergeOptions::default()).unwrap();
+
+    let count = original.bin_data.items.len();
+    assert!(count > 0);
+    assert_eq!(merged.bin_data.items.len(), count * 2);
+    assert_eq!(
+        merged.doc_info.bin_data.len(),
+        original.doc_info.bin_data.len() * 2
+    );
+
+    // 모든 그림 ID 수집 / Collects every picture ID
+    fn picture_ids(value: &serde_json::Value, ids: &mut Vec<u32>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    match value.as_u64() {
+                        Some(id) if key == "bindata_id" => ids.push(id as u32),
+                        _ => picture_ids(value, ids),
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => {
+                values.iter().for_each(|value| picture_ids(value, ids))
+            }
+            _ => {}
+        }
+    }
+    let sections = &merged.body_text.sections;
+    let (own, appended) = sections.split_at(original.body_text.sections.len());
+    let (mut before, mut after) = (Vec::new(), Vec::new());
+    picture_ids(&serde_json::to_value(own).unwrap(), &mut before);
+    picture_ids(&serde_json::to_value(appended).unwrap(), &mut after);
+    assert!(!before.is_empty());
+    assert_eq!(before.len(), after.len());
+
+    // 추가된 그림은 같은 데이터의 새 아이템을 가리킴 / Appended pictures point at new items with the same data
+    let data = |document: &HwpDocument, id: u32| {
+        let item = document
+            .bin_data
+            .items
+            .iter()
+            .find(|i| u32::from(i.index) == id);
+        item.map(|item| item.data.clone())
+    };
+    for (&old, &new) in before.iter().zip(&after) {
+        assert_eq!(report.bin_data_map[&old], new);
+        assert!(new as usize > count);
+        assert_eq!(data(&merged, new), data(&original, old));
+        assert!(data(&merged, new).is_some());
+    }
+}
+
+#[test]
+#[cfg(feature = "fs")]
+fn test_extract_images_writes_embedded_pictures() {
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let images = document.images();
+    assert!(!images.is_empty());
+    assert!(images.iter().all(|i| i.mime_type.starts_with("image/")));
+
+    let dir = std::env::temp_dir().join(format!("hwp-core-images-{}", std::process::id()));
+    let paths = document.extract_images(&dir).expect("Should write images");
+    assert_eq!(paths.len(), images.len());
+    for (written, image) in paths.iter().zip(&images) {
+        assert!(written.ends_with(&image.name));
+        assert_eq!(std::fs::read(written).unwrap(), image.data);
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_password_protected_document_is_reported() {
+    let Some(path) = common::find_fixture_file("password-12345.hwp") else {
+        return;
+    };
+    let result = HwpParser::new().parse(&std::fs::read(&path).unwrap());
+    assert!(
+        matches!(result, Err(HwpError::PasswordRequired)),
+        "Expected PasswordRequired, got {result:?}"
+    );
+}
+
+#[test]
+fn test_security_info_reports_drm_and_password() {
+    let parser = HwpParser::new();
+    for path in common::find_all_hwp_files() {
+        let data = std::fs::read(&path).unwrap();
+        let info = parser.security_info(&data).expect("Should read security");
+        assert!(!info.drm_protected, "{path}");
+        if let Ok(document) = parser.parse(&data) {
+            assert_eq!(document.security_info(), info, "{path}");
+        }
+    }
+
+    if let Some(path) = common::find_fixture_file("password-12345.hwp") {
+        let info = parser
+            .security_info(&std::fs::read(&path).unwrap())
+            .unwrap();
+        assert!(info.password_protected);
+        assert_eq!(
+            info.rejection_reason(),
+            Some("Document is password protected")
+        );
+    }
+
+    // FileHeader 스트림은 압축되지 않으므로 속성 바이트를 직접 바꿈
+    // The FileHeader stream is stored uncompressed, so flip its flag byte in place
+    let Some(path) = common::find_test_file() else {
+        return;
+    };
+    let mut data = std::fs::read(&path).unwrap();
+    let header = data
+        .windows(17)
+        .position(|window| window == b"HWP Document File")
+        .expect("FileHeader signature");
+    data[header + 36] |= 0x10;
+    let info = parser.security_info(&data).unwrap();
+    assert!(info.drm_protected);
+    assert_eq!(info.rejection_reason(), Some("Document is DRM protected"));
+    let result = parser.parse(&data);
+    assert!(
+        matches!(result, Err(HwpError::DrmProtected { .. })),
+        "Expected DrmProtected, got {result:?}"
+    );
+}
+
+#[test]
+fn test_security_findings_skip_default_script_template() {
+    let parser = HwpParser::new();
+    for path in common::find_all_hwp_files() {
+        let Ok(document) = parser.parse(&std::fs::read(&path).unwrap()) else {
+            continue;
+        };
+        assert_eq!(document.security_findings(), Vec::new(), "{path}");
+    }
+
+    let Some(path) = common::find_test_file() else {
+        return;
+    };
+    let mut document = parser.parse(&std::fs::read(&path).unwrap()).unwrap();
+    let script = document
+        .scripts
+        .as_mut()
+        .and_then(|scripts| scripts.default_script.as_mut())
+        .expect("Compressed script streams should be read");
+    assert!(script.source.contains("OnDocument_New"));
+    script
+        .source
+        .push_str("function OnDocument_Open() { Run(\"calc.exe\"); }");
+    let findings = document.security_findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, document::SecurityFindingKind::Script);
+    assert_eq!(findings[0].location, "Scripts/DefaultJScript");
+}
+
+#[test]
+fn test_hwpx_security_parts_and_external_links() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).unwrap();
+    let parser = HwpParser::new();
+    assert!(parser.security_info(&data).unwrap().is_empty());
+
+    let linked = rewrite_hwpx_part(&data, "Contents/content.hpf", |xml| {
+        xml.replacen(
+            "</opf:manifest>",
+            r#"<opf:item id="logo" href="file:///C:/images/logo.png" media-type="image/png" isEmbeded="0"/></opf:manifest>"#,
+            1,
+        )
+    });
+    let document = parser.parse(&linked).expect("Should parse linked package");
+    assert_eq!(
+        document.security_info().external_links,
+        vec!["file:///C:/images/logo.png".to_string()]
+    );
+
+    let encrypted = rewrite_hwpx_part(&data, "META-INF/manifest.xml", |xml| {
+        xml.replace(
+            "/>",
+            r#"><odf:file-entry odf:full-path="Contents/section0.xml"><odf:encryption-data/></odf:file-entry></odf:manifest>"#,
+        )
+    });
+    assert!(parser.security_info(&encrypted).unwrap().password_protected);
+    assert!(matches!(
+        parser.parse(&encrypted),
+        Err(HwpError::PasswordRequired)
+    ));
+}
+
+#[test]
+fn test_hwpx_metadata_from_content_hpf() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let info = document
+        .summary_information
+        .expect("Should read content.hpf metadata");
+    assert_eq!(info.create_time.as_deref(), Some("2011-08-05T17:00:06Z"));
+    assert_eq!(
+        info.last_saved_time.as_deref(),
+        Some("2025-12-10T21:38:07Z")
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_summary_information_typed_dates() {
+    use hwp_core::document::dates::kst;
+
+    let Some(path) = common::find_fixture_file("hwpSummaryInformation.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let info = document.summary_information.expect("Should have summary");
+    assert_eq!(
+        info.created().unwrap().to_rfc3339(),
+        "2012-05-29T12:32:40+09:00"
+    );
+    assert_eq!(info.printed(), None);
+    assert_eq!(info.date().unwrap().offset(), &kst());
+    assert_eq!(
+        info.date().unwrap().to_rfc3339(),
+        "2025-12-01T23:39:55+09:00"
+    );
+}
+
+#[test]
+fn test_hwpx_preview_image() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let preview = document
+        .preview_image
+        .expect("Should read Preview/PrvImage.png");
+    assert_eq!(preview.format, "PNG");
+    assert_eq!(preview.mime_type(), "image/png");
+    let bytes = preview.bytes().unwrap();
+    assert!(bytes.starts_with(b"\x89PNG"));
+    assert_eq!(bytes.len(), 14537);
+}
+
+#[cfg(feature = "image-processing")]
+#[test]
+fn test_render_thumbnail() {
+    for name in ["linespacing.hwpx", "table.hwp"] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let document = HwpParser::new()
+            .parse(&std::fs::read(&path).unwrap())
+            .unwrap();
+        let png = document.render_thumbnail(128).unwrap();
+        assert!(png.starts_with(b"\x89PNG"), "{name}");
+        let thumbnail = image::load_from_memory(&png).unwrap();
+        assert_eq!(thumbnail.width(), 128, "{name}");
+        assert!(thumbnail.height() > 0, "{name}");
+    }
+}
+
+#[test]
+fn test_section_note_numbering_rules() {
+    use hwp_core::document::bodytext::{NumberShape, PagePosition};
+    use hwp_core::document::ParagraphRecord;
+
+    let path = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let mut document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    // 구역 정의의 각주/미주 모양 / Footnote/endnote shapes of the section definition
+    let (footnote, endnote) = document.body_text.sections[0].note_shapes();
+    assert_eq!(footnote.unwrap().decorated_number(1), "1)");
+    assert!(!endnote.unwrap().endnotes_at_section_end());
+
+    // 동그라미 숫자 각주, 구역 끝 미주로 바꾸기 / Switch to circled footnotes and section-end endnotes
+    fn set_shapes(records: &mut [ParagraphRecord], seen: &mut usize) {
+        for record in records {
+            match record {
+                ParagraphRecord::FootnoteShape { footnote_shape } => {
+                    if *seen == 0 {
+                        footnote_shape.attributes.number_shape = NumberShape::CircledArabic;
+                    } else {
+                        footnote_shape.attributes.page_position = PagePosition::Across;
+                    }
+                    *seen += 1;
+                }
+                ParagraphRecord::CtrlHeader { children, .. } => set_shapes(children, seen),
+                _ => {}
+            }
+        }
+    }
+    set_shapes(
+        &mut document.body_text.sections[0].paragraphs[0].records,
+        &mut 0,
+    );
+
+    let markdown = document.to_markdown(&viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    });
+    assert!(markdown.contains("[^①] [^②] [^1] [^2]"), "{markdown}");
+    // 미주는 구역 본문 바로 뒤, 각주보다 앞 / Endnotes follow the section body, before footnotes
+    let endnotes = markdown.find("## 미주").expect("endnotes");
+    let footnotes = markdown.find("## 각주").expect("footnotes");
+    assert!(endnotes < footnotes, "{markdown}");
+}
+
+#[test]
+fn test_header_footer_page_fields() {
+    use hwp_core::viewer::html::HtmlOptions;
+    use hwp_core::viewer::markdown::MarkdownOptions;
+
+    let path = common::find_fixture_file("headerfooter.hwp").expect("Should find fixture");
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    // Markdown: 쪽 번호 필드는 토큰, 원하면 지움 / Markdown: page number fields are tokens, optionally stripped
+    let options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert!(document
+        .to_markdown(&options)
+        .contains("Header 이것은 머리말입니다{page}."));
+    let stripped = document.to_markdown(&options.with_strip_page_fields(Some(true)));
+    assert!(
+        stripped.contains("Header 이것은 머리말입니다."),
+        "{stripped}"
+    );
+
+    // HTML: 머리말/꼬리말은 켰을 때만, 쪽 번호는 실제 번호 / HTML: headers and footers only when enabled, with actual page numbers
+    assert!(!document
+        .to_html(&HtmlOptions::default())
+        .contains("page-header"));
+    let html = document.to_html(&HtmlOptions::default().with_include_headers_footers(Some(true)));
+    assert_eq!(
+        html.matches("class=\"hcD page-header\"").count(),
+        1,
+        "{html}"
+    );
+    assert!(html.contains("머리말입니다1."), "{html}");
+    // 꼬리말은 홀수 쪽 전용 / The footer is for odd pages only
+    assert!(html.contains("class=\"hcD page-footer\""), "{html}");
+}
+
+#[test]
+fn test_master_pages_render_as_layers() {
+    use hwp_core::document::bodytext::ctrl_header::{ApplyPage, CtrlId};
+    use hwp_core::document::bodytext::{MasterPage, ParaTextRun, ParagraphRecord};
+    use hwp_core::viewer::html::{HtmlOptions, MasterPageMode};
+    use hwp_core::viewer::markdown::MarkdownOptions;
+
+    let path = common::find_fixture_file("headerfooter.hwp").expect("Should find fixture");
+    let mut document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    // 워터마크 문단을 구역 정의의 바탕쪽으로 넣기 / Add a watermark paragraph as a master page of the section definition
+    let section = &mut document.body_text.sections[0];
+    let mut watermark = section.paragraphs[0].clone();
+    watermark.records = vec![ParagraphRecord::ParaText {
+        text: "대외비 워터마크".to_string(),
+        runs: vec![ParaTextRun::Text {
+            text: "대외비 워터마크".to_string(),
+        }],
+        control_char_positions: Vec::new(),
+        inline_control_params: Vec::new(),
+    }];
+    let master_page = MasterPage {
+        apply_page: ApplyPage::Both,
+        text_width: 42520.into(),
+        text_height: 65762.into(),
+        paragraphs: vec![watermark],
+    };
+    let children = section.paragraphs[0]
+        .records
+        .iter_mut()
+        .find_map(|record| match record {
+            ParagraphRecord::CtrlHeader {
+                header, children, ..
+            } if header.ctrl_id == CtrlId::SECTION_DEF => Some(children),
+            _ => None,
+        })
+        .expect("Should find section definition");
+    children.push(ParagraphRecord::MasterPage { master_page });
+    assert_eq!(document.body_text.sections[0].master_pages().len(), 1);
+
+    // 본문 텍스트에는 섞이지 않음 / Stays out of body text
+    let options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options);
+    assert!(!markdown.contains("워터마크"), "{markdown}");
+    let html = document.to_html(&HtmlOptions::default());
+    assert!(!html.contains("워터마크"));
+    let skipped =
+        document.to_html(&HtmlOptions::default().with_master_pages(Some(MasterPageMode::Skip)));
+    assert!(!skipped.contains("master-page"));
+
+    // 층으로 넣으면 쪽마다 반투명 층 / As layers, one translucent layer per page
+    let html = document.to_html(
+        &HtmlOptions::default().with_master_pages(Some(MasterPageMode::Layer { opacity: 0.3 })),
+    );
+    assert_eq!(
+        html.matches("class=\"master-page\"").count(),
+        html.matches("class=\"hpa\"").count(),
+        "{html}"
+    );
+    assert!(html.contains("opacity:0.3;"), "{html}");
+    assert!(html.contains("대외비 워터마크"), "{html}");
+
+    // 한 번만 넣으면 첫 쪽 앞의 블록 하나 / Once, a single block before the first page
+    let html =
+        document.to_html(&HtmlOptions::default().with_master_pages(Some(MasterPageMode::Once)));
+    assert_eq!(html.matches("class=\"master-page\"").count(), 1, "{html}");
+    assert_eq!(html.matches("대외비 워터마크").count(), 1, "{html}");
+    assert!(
+        html.find("class=\"master-page\"").unwrap() < html.find("class=\"hpa\"").unwrap(),
+        "{html}"
+    );
+    assert!(!html.contains("opacity:"), "{html}");
+}
+
+#[test]
+fn test_table_header_rows_follow_header_repeat() {
+    use hwp_core::document::ParagraphRecord;
+    use hwp_core::viewer::markdown::MarkdownOptions;
+
+    let mut document = builder::DocumentBuilder::new()
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+    let mut options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+
+    // 제목 줄 반복이 켜진 표는 첫 행이 머리행 / First row is the header with header row repeat
+    assert_eq!(document.tables()[0].header_rows, 1);
+    assert!(document
+        .to_markdown(&options)
+        .contains("| 항목 | 값 |\n|---|---|\n| 매출 | 120 |"));
+
+    // 반복이 꺼지면 빈 머리행 뒤에 모든 행이 본문 / Without repeat every row follows a blank header
+    for paragraph in &mut document.body_text.sections[0].paragraphs {
+        for record in &mut paragraph.records {
+            if let ParagraphRecord::Table { table } = record {
+                table.attributes.attribute.header_row_repeat = false;
+            }
+        }
+    }
+    assert_eq!(document.tables()[0].header_rows, 0);
+    assert!(document
+        .to_markdown(&options)
+        .contains("|   |   |\n|---|---|\n| 항목 | 값 |\n| 매출 | 120 |"));
+    options.use_html = Some(true);
+    let html_table = document.to_markdown(&options);
+    assert!(html_table.contains("<td>항목</td>") && !html_table.contains("<th>"));
+
+    // HTML 표 형식에서는 머리행 칸이 th / Header cells become th in HTML tables
+    let path = common::find_fixture_file("noori.hwp").expect("Should find fixture");
+    let noori = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let markdown = noori.to_markdown(&options);
+    assert!(markdown.contains("<th>구 분</th>"));
+    assert!(markdown.contains("<td>붙임"));
+}
+
+#[test]
+fn test_vertical_text_and_rotated_text_box_html() {
+    use hwp_core::document::bodytext::list_header::TextDirection;
+    use hwp_core::document::ParagraphRecord;
+
+    let Some(path) = common::find_fixture_file("textbox.hwp") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let mut document = HwpParser::new()
+        .parse(&data)
+        .expect("Should parse textbox.hwp");
+    let options = viewer::html::HtmlOptions::default();
+
+    // 글상자 문단이 hsT 안에 렌더링됨 / Text box paragraphs render inside hsT
+    let html = document.to_html(&options);
+    assert!(html.contains(r#"<div class="hsT""#));
+    assert!(!html.contains("writing-mode") && !html.contains("transform:rotate("));
+
+    fn make_vertical(records: &mut [ParagraphRecord]) {
+        for record in records {
+            match record {
+                ParagraphRecord::ShapeComponent {
+                    shape_component,
+                    children,
+                } => {
+                    shape_component.rotation_angle = 90;
+                    make_vertical(children);
+                }
+                ParagraphRecord::ListHeader { header, .. } => {
+                    header.attribute.text_direction = TextDirection::Vertical;
+                }
+                ParagraphRecord::Table { table } => {
+                    for cell in &mut table.cells {
+                        cell.list_header.attribute.text_direction = TextDirection::VerticalUpright;
+                    }
+                }
+                ParagraphRecord::CtrlHeader { children, .. } => make_vertical(children),
+                _ => {}
+            }
+        }
+    }
+    for section in &mut document.body_text.sections {
+        for paragraph in &mut section.paragraphs {
+            make_vertical(&mut paragraph.records);
+        }
+    }
+
+    // 세로쓰기와 회전이 스타일로 남음 / Vertical writing and rotation survive as styles
+    let html = document.to_html(&options);
+    assert!(html.contains("transform:rotate(90deg);"));
+    assert!(html.contains("writing-mode:vertical-rl;"));
+    assert!(html.contains("inset-block-start:"));
+
+    let Some(path) = common::find_fixture_file("table.hwp") else {
+        return;
+    };
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let mut document = HwpParser::new()
+        .parse(&data)
+        .expect("Should parse table.hwp");
+    for section in &mut document.body_text.sections {
+        for paragraph in &mut section.paragraphs {
+            make_vertical(&mut paragraph.records);
+        }
+    }
+    let html = document.to_html(&options);
+    assert!(html.contains("writing-mode:vertical-rl;text-orientation:upright;"));
+}
+
+#[test]
+fn test_outline_paragraphs_expose_resolved_numbers() {
+    let path = common::find_fixture_file("outline.hwp").expect("Should find fixture");
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let numbers: Vec<(&str, String)> = document.body_text.sections[0]
+        .paragraphs
+        .iter()
+        .filter_map(|paragraph| Some((paragraph.resolved_number()?, paragraph.text())))
+        .collect();
+    assert_eq!(numbers[0], ("1", "개요 1".to_string()));
+    assert_eq!(numbers[1], ("1.1", "개요2".to_string()));
+    assert_eq!(numbers[2], ("1.1.1", "개요3".to_string()));
+    assert_eq!(numbers[6], ("1.2", "개요2-2".to_string()));
+
+    // 개요가 아닌 문단은 번호 없음, JSON에는 번호가 함께 나옴
+    // Non-outline paragraphs have no number, and JSON carries the numbers
+    let plain = document.body_text.sections[0]
+        .paragraphs
+        .iter()
+        .find(|paragraph| paragraph.resolved_number().is_none());
+    assert!(plain.is_some());
+    assert!(document
+        .to_json()
+        .unwrap()
+        .contains("\"resolved_number\": \"1.1.1\""));
+}
+
+#[test]
+fn test_content_filter_removes_invisible_chars_from_outputs() {
+    use hwp_core::document::{ContentFilter, FilteredKind};
+    use hwp_core::viewer::html::HtmlOptions;
+    use hwp_core::viewer::markdown::MarkdownOptions;
+
+    let path = common::find_fixture_file("headerfooter.hwp").expect("Should find fixture");
+    let mut document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let text = document.text();
+    let word = text
+        .split_whitespace()
+        .next()
+        .expect("Should have body text");
+    let marked = format!("\u{200B}{word}\u{202E}");
+    assert!(document.replace_text(word, &marked) > 0);
+
+    let filter = ContentFilter::default().with_invisible_chars(true);
+    let filtered = document.filtered_content(&filter);
+    assert!(!filtered.is_empty());
+    assert!(filtered
+        .iter()
+        .all(|item| item.kind == FilteredKind::InvisibleChars && item.text == "\u{200B}\u{202E}"));
+
+    // 평문/Markdown/HTML 모두 보이지 않는 문자 없이 / Plain text, Markdown and HTML all drop the invisible characters
+    assert!(document.text().contains('\u{200B}'));
+    let text_options = TextOptions::default().with_content_filter(filter);
+    assert_eq!(document.text_with_options(&text_options), text);
+    let options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert!(document.to_markdown(&options).contains('\u{200B}'));
+    let markdown = document.to_markdown(&options.with_content_filter(Some(filter)));
+    assert!(!markdown.contains('\u{200B}') && !markdown.contains('\u{202E}'));
+    assert!(markdown.contains(word));
+    let html = document.to_html(&HtmlOptions::default().with_content_filter(Some(filter)));
+    assert!(!html.contains('\u{200B}') && html.contains(word));
+
+    // 문서에 적용하면 목록을 돌려주고 더 걸러 낼 것이 없음 / Applying returns the list and leaves nothing to filter
+    assert_eq!(document.apply_content_filter(&filter), filtered);
+    assert!(document.filtered_content(&filter).is_empty());
+}
+
+#[test]
+fn test_streaming_writers_match_string_output() {
+    use hwp_core::viewer::html::HtmlOptions;
+    use hwp_core::viewer::markdown::MarkdownOptions;
+
+    /// Counts write calls to check that output arrives in pieces
+    struct CountingWriter {
+        data: Vec<u8>,
+        writes: usize,
+    }
+
+    impl std::io::Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes += 1;
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let markdown_options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: Some(true),
+        include_page_info: Some(true),
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let html_options = HtmlOptions::default();
+
+    for name in [
+        "footnote-endnote.hwp",
+        "headerfooter.hwp",
+        "linespacing.hwpx",
+    ] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let document = HwpParser::new()
+            .parse(&std::fs::read(&path).unwrap())
+            .unwrap();
+
+        let mut writer = CountingWriter {
+            data: Vec::new(),
+            writes: 0,
+        };
+        document
+            .to_markdown_writer(&markdown_options, &mut writer)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(writer.data).unwrap(),
+            document.to_markdown(&markdown_options),
+            "{name}"
+        );
+        assert!(writer.writes > 1);
+
+        let mut html = Vec::new();
+        document.to_html_writer(&html_options, &mut html).unwrap();
+        assert_eq!(
+            String::from_utf8(html).unwrap(),
+            document.to_html(&html_options),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn test_toc_links_outline_headings() {
+    use hwp_core::builder::{DocumentBuilder, ParagraphStyle};
+    use hwp_core::viewer::toc;
+
+    let document = DocumentBuilder::new()
+        .add_heading(1, "제1장 총칙")
+        .add_paragraph("목적 조항", &ParagraphStyle::default())
+        .add_heading(2, "제1조 [목적]")
+        .build();
+
+    let entries = toc::generate(&document);
+    let summary: Vec<_> = entries
+        .iter()
+        .map(|e| {
+            (
+                e.level,
+                e.title.as_str(),
+                e.section_index,
+                e.paragraph_index,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [(1, "제1장 총칙", 0, 0), (2, "제1조 [목적]", 0, 2)]
+    );
+
+    let options = hwp_core::viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: None,
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: Some(true),
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options);
+    assert!(markdown.contains("## 목차"));
+    assert!(markdown.contains("- [제1장 총칙](#toc-0-0)\n  - [제1조 \\[목적\\]](#toc-0-2)"));
+    assert!(markdown.contains("<a id=\"toc-0-0\"></a>"));
+    assert!(markdown.contains("<a id=\"toc-0-2\"></a>"));
+    assert!(!markdown.contains("<a id=\"toc-0-1\"></a>"));
+
+    // 옵션을 끄면 출력이 바뀌지 않음 / Output is unchanged with the option off
+    let plain = document.to_markdown(&options.clone().with_include_toc(None));
+    assert!(!plain.contains("목차") && !plain.contains("<a id="));
+}
+
+#[test]
+fn test_hwp5_hyperlink_fields() {
+    use hwp_core::document::FieldKind;
+
+    let Some(path) = common::find_fixture_file("issue144-fields-crossing-lineseg-boundary.hwp")
+    else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    let fields = document.fields();
+    assert!(!fields.is_empty());
+    let first = &fields[0];
+    assert_eq!(first.kind, FieldKind::Hyperlink);
+    assert_eq!(first.field_type, "%hlk");
+    assert_eq!(first.command.as_deref(), Some("http\\://google.com;1;0;0;"));
+    assert_eq!(first.text, "google google google google google google");
+}
+
+#[test]
+fn test_salvage_intact_hwp5_matches_parse() {
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let data = std::fs::read(&path).unwrap();
+    let document = HwpParser::new().parse(&data).unwrap();
+    let (salvaged, log) = HwpParser::new().parse_salvage(&data).unwrap();
+
+    assert!(log.is_clean(), "{log:?}");
+    assert_eq!(log.section_order, ["BodyText/Section0"]);
+    assert_eq!(
+        salvaged.text(),
+        document.text(),
+        "an intact file salvages to the same text"
+    );
+}
+
+#[test]
+fn test_errors_carry_context_code_and_source() {
+    use std::error::Error as _;
+
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).unwrap();
+    let broken = rewrite_hwpx_section(&data, |xml| xml.replacen("</hp:p>", "</hp:q>", 1));
+    let error = HwpParser::new().parse(&broken).unwrap_err();
+    assert!(
+        matches!(error.kind(), HwpError::XmlParseError(_)),
+        "{error:?}"
+    );
+    assert_eq!((error.code(), error.code_name()), (5002, "xml_parse_error"));
+
+    // 구역 경로와 XML 위치 / Section path and XML position
+    let context = error.context().expect("XML errors carry a location");
+    assert_eq!(
+        context.container_path.as_deref(),
+        Some("Contents/section0.xml")
+    );
+    assert!(context.byte_offset.is_some() && context.line.is_some());
+    assert!(error
+        .to_string()
+        .contains("in 'Contents/section0.xml', line "));
+
+    // 원인 사슬이 quick-xml 오류까지 이어짐 / The source chain reaches the quick-xml error
+    let mut source = error.source();
+    let mut found = false;
+    while let Some(cause) = source {
+        found |= cause.downcast_ref::<quick_xml::Error>().is_some();
+        source = cause.source();
+    }
+    assert!(found, "{error:?}");
+}
+
+#[test]
+fn test_parse_reader_matches_parse() {
+    let parser = HwpParser::new();
+    for name in ["linespacing.hwpx", "table.hwp"] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let data = std::fs::read(&path).unwrap();
+        let expected = parser.parse(&data).unwrap().to_json().unwrap();
+
+        // 앞에 다른 데이터가 있어도 리더의 현재 위치부터 읽음 / Reads from the reader's position even after other data
+        let mut padded = b"ignored".to_vec();
+        padded.extend_from_slice(&data);
+        let mut cursor = std::io::Cursor::new(padded);
+        cursor.set_position(7);
+        let document = parser.parse_reader(cursor).unwrap();
+        assert_eq!(document.to_json().unwrap(), expected, "{name}");
+        #[cfg(feature = "fs")]
+        assert_eq!(
+            parser.parse_file(&path).unwrap().to_json().unwrap(),
+            expected,
+            "{name}"
+        );
+    }
+
+    assert!(matches!(
+        parser.parse_reader(std::io::Cursor::new(b"not a document")),
+        Err(HwpError::UnknownFormat)
+    ));
+}
+
+#[test]
+fn test_parse_or_preview_falls_back_to_preview_text() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).unwrap();
+    let outcome = HwpParser::new().parse_or_preview(&data).unwrap();
+    assert!(!outcome.is_degraded());
+    assert!(outcome.document().is_some());
+
+    let broken = rewrite_hwpx_section(&data, |xml| xml.replacen("</hp:p>", "</hp:q>", 1));
+    assert!(HwpParser::new().parse(&broken).is_err());
+    let outcome = HwpParser::new().parse_or_preview(&broken).unwrap();
+    assert!(outcome.is_degraded());
+    assert!(outcome.document().is_none());
+    assert!(!outcome.text().is_empty());
+    match outcome {
+        hwp_core::ParseOutcome::PreviewOnly { error, .. } => {
+            assert!(
+                matches!(error.kind(), hwp_core::HwpError::XmlParseError(_)),
+                "{error:?}"
+            )
+        }
+        hwp_core::ParseOutcome::Full(_) => unreachable!(),
+    }
+
+    // 미리보기도 없으면 원래 오류 / Without a preview the original error is returned
+    assert!(HwpParser::new()
+        .parse_or_preview(b"not a document")
+        .is_err());
+}
+
+#[test]
+fn test_parse_hwpml_document() {
+    let hml = r#"<?xml version="1.0" encoding="UTF-8" standalone="no" ?>
+<HWPML Version="2.8" SubVersion="8.0.0.0" Style2="embed">
+<HEAD SecCnt="1">
+<DOCSUMMARY><TITLE>회의록</TITLE><AUTHOR>홍길동</AUTHOR></DOCSUMMARY>
+<MAPPINGTABLE>
+<CHARSHAPELIST Count="2">
+<CHARSHAPE Id="0" Height="1000" TextColor="0" ShadeColor="4294967295"/>
+<CHARSHAPE Id="1" Height="1200" TextColor="255" ShadeColor="4294967295"><BOLD/></CHARSHAPE>
+</CHARSHAPELIST>
+<PARASHAPELIST Count="1"><PARASHAPE Id="0" Align="Center"><PARAMARGIN Indent="0" Left="0"/></PARASHAPE></PARASHAPELIST>
+</MAPPINGTABLE>
+</HEAD>
+<BODY><SECTION Id="0">
+<P ParaShape="0" Style="0"><TEXT CharShape="1"><CHAR>첫 문단</CHAR></TEXT></P>
+<P ParaShape="0" Style="0"><TEXT CharShape="0"><TABLE RowCount="1" ColCount="1" CellSpacing="0" BorderFill="1">
+<SHAPEOBJECT><SIZE Width="1000" Height="1000"/></SHAPEOBJECT><INSIDEMARGIN Left="141" Right="141" Top="141" Bottom="141"/>
+<ROW><CELL ColAddr="0" RowAddr="0" ColSpan="1" RowSpan="1" Width="1000" Height="1000" BorderFill="1">
+<PARALIST VertAlign="Center" LineWrap="Break"><P ParaShape="0"><TEXT CharShape="0"><CHAR>셀 내용</CHAR></TEXT></P></PARALIST>
+</CELL></ROW></TABLE></TEXT></P>
+</SECTION></BODY>
+<TAIL></TAIL>
+</HWPML>"#;
+
+    assert_eq!(
+        hwp_core::parser::detect_format(hml.as_bytes()),
+        hwp_core::parser::FileFormat::Hwpml
+    );
+    let document = HwpParser::new().parse(hml.as_bytes()).unwrap();
+    assert_eq!(document.format().format, SourceFormat::Hwpml);
+    assert_eq!(document.format().version, "2.8.0.0");
+
+    assert_eq!(document.text(), "첫 문단");
+
+    let paragraphs = &document.body_text.sections[0].paragraphs;
+    assert_eq!(paragraphs.len(), 2);
+    let table = paragraphs[1]
+        .records
+        .iter()
+        .find_map(|record| match record {
+            hwp_core::document::ParagraphRecord::Table { table } => Some(table),
+            _ => None,
+        })
+        .expect("table");
+    assert_eq!(table.cells.len(), 1);
+    assert_eq!(table.cells[0].text(), "셀 내용");
+
+    assert_eq!(document.doc_info.char_shapes.len(), 2);
+    assert!(document.doc_info.char_shapes[1].attributes.bold);
+    assert_eq!(
+        document.doc_info.para_shapes[0].attributes1.align,
+        hwp_core::document::docinfo::para_shape::ParagraphAlignment::Center
+    );
+    let summary = document.summary_information.as_ref().unwrap();
+    assert_eq!(summary.title.as_deref(), Some("회의록"));
+    assert_eq!(summary.author.as_deref(), Some("홍길동"));
+
+    let mut streamed = Vec::new();
+    HwpParser::new()
+        .extract_text_streaming(hml.as_bytes(), &mut |text| streamed.push(text.to_string()))
+        .unwrap();
+    assert_eq!(streamed, ["첫 문단", "셀 내용"]);
+}
+
+#[test]
+fn test_html_shade_color_follows_option() {
+    use hwp_core::viewer::HtmlOptions;
+
+    let Some(path) = common::find_fixture_file("selfintroduce.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    let highlight = "background-color:rgb(255,215,0);";
+    assert!(document
+        .to_html(&HtmlOptions::default())
+        .contains(highlight));
+    let plain = document.to_html(&HtmlOptions::default().with_include_text_colors(Some(false)));
+    assert!(!plain.contains(highlight));
+    assert!(!plain.contains("color:rgb(0,0,0);"));
+}
+
+#[test]
+fn test_html_font_map_overrides_builtin_fallbacks() {
+    use hwp_core::viewer::HtmlOptions;
+
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    let html = document.to_html(&HtmlOptions::default());
+    assert!(html.contains(r#"font-family:"바탕",'Nanum Myeongjo','Noto Serif KR',serif;"#));
+
+    let options = HtmlOptions::default().with_font_mapping("바탕", "'Gowun Batang',serif");
+    let html = document.to_html(&options);
+    assert!(html.contains(r#"font-family:"바탕",'Gowun Batang',serif;"#));
+    assert!(html.contains(r#"font-family:"휴먼명조",'Nanum Myeongjo','Noto Serif KR',serif;"#));
+}
+
+#[test]
+fn test_hwpx_paragraph_alignment_reaches_html_and_markdown() {
+    use hwp_core::document::docinfo::para_shape::ParagraphAlignment;
+    use hwp_core::viewer::HtmlOptions;
+
+    let centered = builder::ParagraphStyle::default().with_alignment(ParagraphAlignment::Center);
+    let data = builder::DocumentBuilder::new()
+        .add_paragraph("가운데 제목", &centered)
+        .add_paragraph("본문", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    // <hp:case>가 아닌 <hp:default>의 여백을 씀 / Margins come from <hp:default>, not <hp:case>
+    let data = rewrite_hwpx_part(&data, "Contents/header.xml", |xml| {
+        xml.replacen(
+            r#"<hh:align horizontal="CENTER" vertical="BASELINE"/>"#,
+            r#"<hh:align horizontal="CENTER" vertical="BASELINE"/><hp:switch><hp:case><hh:margin><hc:intent value="999"/></hh:margin></hp:case><hp:default><hh:margin><hc:intent value="-1440" unit="HWPUNIT"/><hc:left value="720" unit="HWPUNIT"/></hh:margin></hp:default></hp:switch>"#,
+            1,
+        )
+    });
+    let document = HwpParser::new().parse(&data).expect("Should parse HWPX");
+
+    let paragraphs = &document.body_text.sections[0].paragraphs;
+    let title = paragraphs
+        .iter()
+        .find(|p| p.text() == "가운데 제목")
+        .expect("title paragraph");
+    let shape_id = title.para_header.para_shape_id;
+    assert_eq!(
+        document.doc_info.para_shapes[shape_id as usize]
+            .attributes1
+            .align,
+        ParagraphAlignment::Center
+    );
+
+    let html = document.to_html(&HtmlOptions::default());
+    assert!(html.contains(&format!(".ps{shape_id} {{\n  text-align:center;")));
+    // 내어쓰기: 첫 줄만 왼쪽 여백에 / Hanging indent: only the first line sits at the left margin
+    assert!(html.contains(&format!(
+        r#"<div class="hls ps{shape_id}" style="margin-left:7.62mm;text-indent:-5.08mm;">"#
+    )));
+
+    let options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: Some(true),
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options);
+    assert!(markdown.contains("<div align=\"center\">\n\n가운데 제목\n\n</div>"));
+    assert!(!markdown.contains("<div align=\"center\">\n\n본문"));
+    let markdown = document.to_markdown(&options.clone().with_use_html(Some(false)));
+    assert!(!markdown.contains("<div align"));
+}
+
+#[test]
+fn test_hwpx_scripts_and_emphasis_marks() {
+    use hwp_core::viewer::HtmlOptions;
+
+    let data = builder::DocumentBuilder::new()
+        .add_paragraph("H2O는 매우중요하다 각주1", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    // 1: 아래 첨자, 2: 강조점, 3: 줄이고 올린 글자 (위 첨자 속성 없음)
+    // 1: subscript, 2: emphasis dots, 3: shrunk and raised text (no superscript attribute)
+    let data = rewrite_hwpx_part(&data, "Contents/header.xml", |xml| {
+        xml.replacen(
+            "</hh:charProperties>",
+            r##"<hh:charPr id="1" height="1000" textColor="#000000" shadeColor="none" symMark="NONE"><hh:subscript/></hh:charPr><hh:charPr id="2" height="1000" textColor="#000000" shadeColor="none" symMark="DOT_ABOVE"/><hh:charPr id="3" height="1000" textColor="#000000" shadeColor="none" symMark="NONE"><hh:relSz hangul="60" latin="60" hanja="60" japanese="60" other="60" symbol="60" user="60"/><hh:offset hangul="-30" latin="-30" hanja="-30" japanese="-30" other="-30" symbol="-30" user="-30"/></hh:charPr></hh:charProperties>"##,
+            1,
+        )
+    });
+    let data = rewrite_hwpx_section(&data, |xml| {
+        xml.replacen(
+            "<hp:t>H2O는 매우중요하다 각주1</hp:t></hp:run>",
+            r#"<hp:t>H</hp:t></hp:run><hp:run charPrIDRef="1"><hp:t>2</hp:t></hp:run><hp:run charPrIDRef="0"><hp:t>O는 매우</hp:t></hp:run><hp:run charPrIDRef="2"><hp:t>중요</hp:t></hp:run><hp:run charPrIDRef="0"><hp:t>하다 각주</hp:t></hp:run><hp:run charPrIDRef="3"><hp:t>1</hp:t></hp:run>"#,
+            1,
+        )
+    });
+    let document = HwpParser::new().parse(&data).expect("Should parse HWPX");
+
+    let mut options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(false),
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert!(document
+        .to_markdown(&options)
+        .contains("H~2~O는 매우중요하다 각주^1^"));
+    options.use_html = Some(true);
+    assert!(document
+        .to_markdown(&options)
+        .contains("H<sub>2</sub>O는 매우중요하다 각주<sup>1</sup>"));
+
+    let html = document.to_html(&HtmlOptions::default());
+    assert!(html.contains("<sub>2</sub>"));
+    assert!(html.contains("<sup>1</sup>"));
+    assert!(html.contains("text-emphasis:filled dot;"));
+}
+
+#[test]
+fn test_hwpx_inline_controls_map_per_viewer() {
+    use hwp_core::document::bodytext::InlineControl;
+    use hwp_core::viewer::HtmlOptions;
+
+    let data = builder::DocumentBuilder::new()
+        .add_paragraph("제1조제2항", &Default::default())
+        .build()
+        .to_hwpx()
+        .expect("Should write HWPX");
+    let data = rewrite_hwpx_section(&data, |xml| {
+        xml.replacen(
+            "<hp:t>제1조제2항</hp:t>",
+            "<hp:t>제1조<hp:nbSpace/>제2항<hp:fwSpace/>A<hp:hyphen/>B<hp:tab/>끝</hp:t>",
+            1,
+        )
+    });
+    let document = HwpParser::new().parse(&data).expect("Should parse HWPX");
+
+    let text = document.text();
+    assert!(text.contains("제1조\u{a0}제2항\u{2007}A\u{2010}B\t끝"));
+
+    let mut options = viewer::markdown::MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(false),
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert!(document
+        .to_markdown(&options)
+        .contains("제1조 제2항 A-B\t끝"));
+    options = options
+        .with_inline_control(InlineControl::NonBreakingSpace, "&nbsp;")
+        .with_inline_control(InlineControl::Tab, "    ");
+    assert!(document
+        .to_markdown(&options)
+        .contains("제1조&nbsp;제2항 A-B    끝"));
+
+    let html = document.to_html(&HtmlOptions::default());
+    assert!(html.contains("제1조&nbsp;제2항&#8199;A-B\t끝"));
+    let html = document
+        .to_html(&HtmlOptions::default().with_inline_control(InlineControl::Hyphen, "&shy;"));
+    assert!(html.contains("A&shy;B"));
+}
+
+#[test]
+fn test_pandoc_json_export() {
+    // 제목/강조/표/각주가 Pandoc AST 요소로 옮겨져야 함
+    // Headings, emphasis, tables and footnotes must map to Pandoc AST elements
+    let document = builder::DocumentBuilder::new()
+        .add_heading(1, "개요")
+        .add_paragraph(
+            "굵은 글씨",
+            &builder::ParagraphStyle::default().with_bold(true),
+        )
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+    let json: serde_json::Value =
+        serde_json::from_str(&document.to_pandoc_json().unwrap()).unwrap();
+    assert_eq!(json["pandoc-api-version"], serde_json::json!([1, 23, 1]));
+
+    let blocks = json["blocks"].as_array().unwrap();
+    assert_eq!(blocks[0]["t"], "Header");
+    assert_eq!(blocks[0]["c"][0], 1);
+    assert!(blocks[0]["c"][2].to_string().contains(r#""c":"개요""#));
+    assert_eq!(blocks[1]["t"], "Para");
+    assert_eq!(blocks[1]["c"][0]["t"], "Strong");
+
+    let table = blocks.iter().find(|block| block["t"] == "Table").unwrap();
+    let head_rows = &table["c"][3][1];
+    assert_eq!(head_rows[0][1][0][4][0]["c"][0]["c"], "항목");
+    let body_rows = &table["c"][4][0][3];
+    assert_eq!(body_rows[0][1][1][4][0]["t"], "Plain");
+    assert_eq!(body_rows[0][1][1][4][0]["c"][0]["c"], "120");
+
+    let path = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    let json = viewer::to_pandoc(&document);
+    let notes: Vec<&serde_json::Value> = json["blocks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|block| block["c"].as_array().into_iter().flatten())
+        .filter(|inline| inline["t"] == "Note")
+        .collect();
+    assert_eq!(notes.len(), 4);
+    assert_eq!(notes[0]["c"][0]["c"][0]["c"], "각주입니다.");
+}
+
+#[test]
+fn test_markdown_flavor_selection() {
+    // 방언에 따라 각주/미주 표기와 표 출력 방식이 달라져야 함
+    // Footnote/endnote syntax and table output must follow the selected flavor
+    use hwp_core::builder::DocumentBuilder;
+    use hwp_core::viewer::markdown::{MarkdownFlavor, MarkdownOptions};
+
+    let options = |flavor| MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    assert_eq!(
+        MarkdownFlavor::from_name("pandoc"),
+        Some(MarkdownFlavor::Pandoc)
+    );
+    assert_eq!(MarkdownFlavor::from_name("markdown-x"), None);
+
+    let path = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+
+    let gfm = document.to_markdown(&options(Some(MarkdownFlavor::Gfm)));
+    assert!(gfm.contains("[^1] [^2] [^e1] [^e2]"));
+    assert!(gfm.contains("[^1]: 각주입니다."));
+    assert!(gfm.contains("[^e2]: 미주 두 번째입니다."));
+
+    let commonmark = document.to_markdown(&options(Some(MarkdownFlavor::CommonMark)));
+    assert!(commonmark.contains("\\[1\\] 각주입니다."));
+    assert!(!commonmark.contains("[^"));
+
+    // 방언을 지정하지 않으면 기존 출력 유지 / Without a flavor the legacy output is kept
+    let legacy = document.to_markdown(&options(None));
+    assert!(legacy.contains("[^1] [^2] [^1] [^2]"));
+    assert!(!legacy.contains("[^1]:"));
+
+    let built = DocumentBuilder::new()
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+    let pandoc = built.to_markdown(&options(Some(MarkdownFlavor::Pandoc)));
+    assert!(pandoc.contains("| 매출 | 120 |"));
+    assert!(!pandoc.contains('<'));
+    let commonmark = built.to_markdown(&options(Some(MarkdownFlavor::CommonMark)));
+    assert!(commonmark.contains("<table"));
+    assert!(!commonmark.contains("| 매출 |"));
+}
+
+#[test]
+fn test_rst_and_asciidoc_output() {
+    // 제목/강조/표/각주가 reStructuredText와 AsciiDoc 문법으로 옮겨져야 함
+    // Headings, emphasis, tables and footnotes must map to reStructuredText and AsciiDoc syntax
+    let document = builder::DocumentBuilder::new()
+        .add_heading(1, "개요")
+        .add_paragraph(
+            "굵은 글씨",
+            &builder::ParagraphStyle::default().with_bold(true),
+        )
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+
+    let rst = document.to_rst();
+    assert!(rst.contains(".. _toc-0-0:"));
+    assert!(rst.contains("**개요**\n========"));
+    assert!(rst.contains("**굵은 글씨**"));
+    assert!(rst.contains("| 매출 | 120 |"));
+    assert!(rst.contains("+======+=====+"));
+
+    let asciidoc = document.to_asciidoc();
+    assert!(asciidoc.contains("[[toc-0-0]]\n== **개요**"));
+    assert!(asciidoc.contains("**굵은 글씨**"));
+    assert!(asciidoc.contains("[cols=\"1,1\",options=\"header\"]\n|===\n|항목\n|값\n"));
+
+    let path = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    let rst = viewer::to_rst(&document);
+    assert_eq!(rst.matches("[#]_").count(), 4);
+    assert!(rst.contains(".. [#] 각주입니다."));
+    let asciidoc = viewer::to_asciidoc(&document);
+    assert!(asciidoc.contains("footnote:[각주입니다.]"));
+}
+
+#[test]
+fn test_document_visitor_traversal() {
+    // 공통 순회 드라이버가 표 셀, 각주, 그림을 콜백으로 알려야 함
+    // The shared traversal driver must report table cells, notes and pictures through callbacks
+    use hwp_core::document::bindata::BinaryDataItem;
+    use hwp_core::document::bodytext::TableCell;
+    use hwp_core::document::{CtrlHeader, CtrlId};
+    use hwp_core::viewer::{walk_document, DocumentVisitor};
+
+    #[derive(Default)]
+    struct Counter {
+        cells: usize,
+        notes: usize,
+        pictures: usize,
+        texts: Vec<String>,
+    }
+
+    impl<'a> DocumentVisitor<'a> for Counter {
+        fn visit_text(&mut self, _paragraph: &'a hwp_core::document::Paragraph, text: &'a str) {
+            self.texts.push(text.trim().to_string());
+        }
+
+        fn visit_picture(&mut self, _item: &'a BinaryDataItem) {
+            self.pictures += 1;
+        }
+
+        fn enter_control(&mut self, header: &'a CtrlHeader) -> bool {
+            if header.ctrl_id == CtrlId::FOOTNOTE || header.ctrl_id == CtrlId::ENDNOTE {
+                self.notes += 1;
+            }
+            // 머리말/꼬리말은 건너뜀 / Skip headers and footers
+            header.ctrl_id != CtrlId::HEADER && header.ctrl_id != CtrlId::FOOTER
+        }
+
+        fn enter_cell(&mut self, _cell: &'a TableCell) -> bool {
+            self.cells += 1;
+            true
+        }
+    }
+
+    let document = builder::DocumentBuilder::new()
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+    let mut counter = Counter::default();
+    walk_document(&document, &mut counter);
+    assert_eq!(counter.cells, 4);
+    // 표 컨트롤의 문단은 셀 문단과 겹치므로 한 번만 방문 / Cell paragraphs are visited only once
+    assert_eq!(
+        counter.texts.iter().filter(|text| *text == "매출").count(),
+        1
+    );
+
+    let path = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    let mut counter = Counter::default();
+    walk_document(&document, &mut counter);
+    assert_eq!(counter.notes, 4);
+    assert_eq!(
+        counter
+            .texts
+            .iter()
+            .filter(|text| *text == "각주입니다.")
+            .count(),
+        1
+    );
+
+    let path = common::find_fixture_file("noori.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    let mut counter = Counter::default();
+    walk_document(&document, &mut counter);
+    assert_eq!(counter.pictures, 4);
+}
+
+#[test]
+fn test_source_anchors_in_output() {
+    // 원본 위치가 HTML `data-para` 속성과 Markdown 주석으로 들어가야 함
+    // Source locations must appear as HTML `data-para` attributes and Markdown comments
+    use hwp_core::viewer::markdown::MarkdownOptions;
+    use hwp_core::viewer::HtmlOptions;
+
+    let document = builder::DocumentBuilder::new()
+        .add_paragraph("첫 문단", &builder::ParagraphStyle::default())
+        .add_paragraph("둘째 문단", &builder::ParagraphStyle::default())
+        .add_table(&[vec!["항목", "값"], vec!["매출", "120"]])
+        .build();
+
+    let html = document.to_html(&HtmlOptions::default().with_include_source_anchors(Some(true)));
+    assert!(html.contains(r#"data-para="0:0""#));
+    assert!(html.contains(r#"data-para="0:1""#));
+    assert!(!document
+        .to_html(&HtmlOptions::default())
+        .contains("data-para"));
+
+    // 표도 속한 문단의 위치를 가짐 / Tables carry the location of their paragraph
+    let path = common::find_fixture_file("table.hwp").expect("Should find fixture");
+    let data = std::fs::read(&path).expect("Should read fixture");
+    let table_document = HwpParser::new().parse(&data).expect("Should parse fixture");
+    let html =
+        table_document.to_html(&HtmlOptions::default().with_include_source_anchors(Some(true)));
+    assert!(html.contains(r#"<div data-para="0:0" class="htG""#));
+    assert!(!html.contains(r#"data-para="0:0" class="hce"#));
+
+    let options = MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: Some(false),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    };
+    let markdown = document.to_markdown(&options.clone().with_include_source_anchors(Some(true)));
+    assert!(markdown.contains("<!-- data-para=\"0:0\" -->\n첫 문단"));
+    assert!(markdown.contains("<!-- data-para=\"0:1\" -->\n둘째 문단"));
+    assert!(!document.to_markdown(&options).contains("data-para"));
+}
+
+#[test]
+fn test_text_offset_map_matches_text() {
+    // 모든 예제 문서에서 대응표의 평문이 get_text와 같고, 구간이 문단 텍스트를 빠짐없이 덮어야 함
+    // For every fixture the map's text must equal get_text and the spans must cover each
+    // paragraph's text without gaps
+    let fixture = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let fixtures_dir = std::path::Path::new(&fixture)
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let mut checked = 0;
+    for entry in std::fs::read_dir(fixtures_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        let Ok(document) = HwpParser::new().parse(&data) else {
+            continue;
+        };
+        let map = document.build_offset_map();
+        assert_eq!(map.text, document.text(), "{}", path.display());
+
+        let chars: Vec<char> = map.text.chars().collect();
+        let mut paragraphs: std::collections::BTreeMap<(usize, usize), String> = Default::default();
+        for span in &map.spans {
+            paragraphs
+                .entry((span.section_index, span.paragraph_index))
+                .or_default()
+                .extend(&chars[span.offset..span.offset + span.len]);
+        }
+        for ((section_index, paragraph_index), text) in paragraphs {
+            let paragraph = &document.body_text.sections[section_index].paragraphs[paragraph_index];
+            assert_eq!(text, paragraph.text().trim(), "{}", path.display());
+        }
+        checked += 1;
+    }
+    assert!(checked > 10);
+}
+
+#[test]
+fn test_replace_text_round_trip() {
+    // 모든 예제 문서에서 바꾼 뒤 되돌리면 텍스트와 위치 정보가 처음과 같아야 함
+    // For every fixture, replacing and then replacing back must restore the text and positions
+    use hwp_core::document::bodytext::{ParaTextRun, ParagraphRecord};
+
+    fn positions(document: &HwpDocument) -> Vec<(u32, Vec<u32>, Vec<usize>)> {
+        let mut result = Vec::new();
+        for section in &document.body_text.sections {
+            for paragraph in &section.paragraphs {
+                for paragraph in std::iter::once(paragraph).chain(paragraph.nested_paragraphs()) {
+                    let mut shapes = Vec::new();
+                    let mut controls = Vec::new();
+                    for record in &paragraph.records {
+                        match record {
+                            ParagraphRecord::ParaCharShape { shapes: list } => {
+                                shapes.extend(list.iter().map(|shape| shape.position))
+                            }
+                            ParagraphRecord::ParaText { runs, .. } => {
+                                controls.extend(runs.iter().filter_map(|run| match run {
+                                    ParaTextRun::Control { position, .. } => Some(*position),
+                                    ParaTextRun::Text { .. } => None,
+                                }))
+                            }
+                            _ => {}
+                        }
+                    }
+                    result.push((paragraph.para_header.text_char_count, shapes, controls));
+                }
+            }
+        }
+        result
+    }
+
+    let fixture = common::find_fixture_file("footnote-endnote.hwp").expect("Should find fixture");
+    let fixtures_dir = std::path::Path::new(&fixture)
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let mut replaced_total = 0;
+    for entry in std::fs::read_dir(fixtures_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let Ok(data) = std::fs::read(&path) else {
+            continue;
+        };
+        let Ok(mut document) = HwpParser::new().parse(&data) else {
+            continue;
+        };
+        let text = document.text();
+        let before = positions(&document);
+
+        let replaced = document.replace_text("다", "{{다}}");
+        assert_eq!(
+            document.text().matches("{{다}}").count(),
+            text.matches('다').count(),
+            "{}",
+            path.display()
+        );
+        assert_eq!(
+            document.replace_text("{{다}}", "다"),
+            replaced,
+            "{}",
+            path.display()
+        );
+        assert_eq!(document.text(), text, "{}", path.display());
+        assert_eq!(positions(&document), before, "{}", path.display());
+        replaced_total += replaced;
+    }
+    assert!(replaced_total > 0);
+}
+
+#[test]
+fn test_mail_merge_writes_one_document_per_record() {
+    use hwp_core::mail_merge::{mail_merge, placeholders, MailMergeOptions};
+    use std::collections::HashMap;
+
+    let template = builder::DocumentBuilder::new()
+        .add_heading(1, "재직 증명서")
+        .add_paragraph("성명: {{이름}}", &Default::default())
+        .add_table(&[vec!["부서", "{{ 부서 }}"], vec!["성명", "{{이름}}"]])
+        .build();
+    let names: Vec<String> = placeholders(&template)
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    assert_eq!(names, vec!["이름".to_string(), "부서".to_string()]);
+
+    let records: Vec<HashMap<String, String>> = [("홍길동", "인사팀"), ("김철수", "재무팀")]
+        .iter()
+        .map(|(name, team)| {
+            HashMap::from([
+                ("이름".to_string(), name.to_string()),
+                ("부서".to_string(), team.to_string()),
+            ])
+        })
+        .collect();
+    let merged = mail_merge(&template, &records, &MailMergeOptions::default());
+    assert_eq!(merged.len(), 2);
+    for (record, (name, team)) in merged
+        .iter()
+        .zip([("홍길동", "인사팀"), ("김철수", "재무팀")])
+    {
+        assert_eq!(record.filled, 3);
+        assert!(record.missing.is_empty());
+        let reparsed = HwpParser::new()
+            .parse(&record.document.to_hwpx().expect("Should write HWPX"))
+            .expect("Should parse written document");
+        let count = |query: &str| {
+            reparsed
+                .search(query, &document::SearchOptions::default())
+                .unwrap()
+                .len()
+        };
+        assert_eq!(count(&format!("성명: {name}")), 1);
+        assert_eq!(count(team), 1);
+        assert_eq!(count("{{"), 0);
+    }
+}
+
+#[test]
+fn test_external_references_hyperlinks_and_linked_pictures() {
+    use hwp_core::document::docinfo::bin_data::BinDataLink;
+    use hwp_core::document::{BinDataRecord, ExternalReferenceKind};
+
+    let parser = HwpParser::new();
+    if let Some(path) = common::find_fixture_file("issue144-fields-crossing-lineseg-boundary.hwp") {
+        let document = parser.parse(&std::fs::read(&path).unwrap()).unwrap();
+        let references = document.external_references();
+        let first = &references[0];
+        assert_eq!(first.kind, ExternalReferenceKind::Hyperlink);
+        assert_eq!(first.target, "http://google.com");
+        assert_eq!(
+            first.text.as_deref(),
+            Some("google google google google google google")
+        );
+    }
+
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let mut document = parser.parse(&std::fs::read(&path).unwrap()).unwrap();
+    assert!(document.external_references().is_empty());
+
+    // 포함된 그림을 연결 그림으로 바꾸면 표 안의 그림도 한 번씩 나옴
+    // Turning embedded pictures into linked ones reports pictures inside tables once each
+    for record in &mut document.doc_info.bin_data {
+        if let BinDataRecord::Embedding { attributes, .. } = record {
+            *record = BinDataRecord::Link {
+                attributes: *attributes,
+                link: BinDataLink {
+                    absolute_path: String::new(),
+                    relative_path: "images\\linked.png".to_string(),
+                },
+            };
+        }
+    }
+    let references = document.external_references();
+    assert_eq!(references.len(), 4);
+    assert!(references.iter().all(|reference| {
+        reference.kind == ExternalReferenceKind::LinkedImage
+            && reference.target == "images\\linked.png"
+    }));
+    let locations: Vec<_> = references
+        .iter()
+        .map(|reference| {
+            (
+                reference.location.paragraph_index,
+                reference.location.nested_index,
+            )
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        vec![(0, None), (18, Some(0)), (20, Some(4)), (20, Some(5))]
+    );
+}
+
+#[test]
+fn test_tokens_annotate_context_and_char_shapes() {
+    let parser = HwpParser::new();
+    let parse = |name: &str| {
+        let path = common::find_fixture_file(name)?;
+        Some(parser.parse(&std::fs::read(path).unwrap()).unwrap())
+    };
+
+    // 글자 모양이 바뀌는 곳에서 나뉘고, 이어 붙이면 문단 텍스트 / Split at char shape changes; joined they give the paragraph text
+    if let Some(document) = parse("charshape.hwp") {
+        let tokens: Vec<_> = document
+            .tokens()
+            .filter(|token| token.location.paragraph_index == 1)
+            .collect();
+        let texts: Vec<_> = tokens.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, ["기울임", "진하게"]);
+        assert_eq!(tokens[1].run_offset, 3);
+        assert_ne!(tokens[0].char_shape_id, tokens[1].char_shape_id);
+        assert_eq!(
+            texts.concat(),
+            document.body_text.sections[0].paragraphs[1].text()
+        );
+    }
+
+    if let Some(document) = parse("headerfooter.hwp") {
+        let tokens: Vec<_> = document.tokens().collect();
+        assert!(tokens[0].text == "첫 페이지" && !tokens[0].in_header);
+        assert!(tokens
+            .iter()
+            .any(|token| token.in_header && token.text.starts_with("Header")));
+        assert!(tokens
+            .iter()
+            .any(|token| token.in_footer && token.text.starts_with("Footer")));
+    }
+
+    if let Some(document) = parse("footnote-endnote.hwp") {
+        let notes: Vec<_> = document
+            .tokens()
+            .filter(|token| token.in_footnote)
+            .map(|token| token.text.trim().to_string())
+            .collect();
+        assert_eq!(
+            notes,
+            [
+                "각주입니다.",
+                "각주 두 번째입니다.",
+                "미주입니다.",
+                "미주 두 번째입니다."
+            ]
+        );
+    }
+
+    // 표 셀 문단은 사본 없이 한 번씩, 셀 주소와 함께 / Table cell paragraphs appear once, with their cell address
+    if let Some(document) = parse("noori.hwp") {
+        let tokens: Vec<_> = document.tokens().collect();
+        let first = tokens.iter().find(|token| token.in_table).unwrap();
+        assert_eq!(first.text, "보도일시");
+        assert_eq!(first.cell, Some((0, 0)));
+        assert_eq!(
+            tokens
+                .iter()
+                .filter(|token| token.text == "보도일시")
+                .count(),
+            1
+        );
+        assert!(tokens
+            .iter()
+            .all(|token| token.in_table == token.cell.is_some()));
+    }
+}
+
+#[test]
+fn test_chunks_respect_limits_and_tables() {
+    use hwp_core::chunking::{chunk_document, ChunkOptions};
+
+    let Some(path) = common::find_fixture_file("noori.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+
+    let options = ChunkOptions::default().with_max_chars(300).with_overlap(80);
+    let chunks = chunk_document(&document, &options);
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|chunk| chunk.char_count <= 300));
+    assert!(chunks.iter().all(|chunk| !chunk.anchors.is_empty()));
+
+    // 표 행은 겹침으로도 되풀이되지 않음 / Table rows are not repeated by the overlap
+    let with_release_date: Vec<_> = chunks
+        .iter()
+        .filter(|chunk| chunk.text.contains("보도일시"))
+        .collect();
+    assert_eq!(with_release_date.len(), 1);
+    assert!(with_release_date[0]
+        .anchors
+        .iter()
+        .any(|anchor| anchor.in_table && anchor.location.paragraph_index == 1));
+
+    // 한도 안의 본문 문단은 덩어리 하나에 통째로 / Body paragraphs within the limit land whole in one chunk
+    let paragraphs: Vec<String> = document.body_text.sections[0]
+        .paragraphs
+        .iter()
+        .map(|paragraph| paragraph.text().trim().to_string())
+        .filter(|text| !text.is_empty() && text.chars().count() <= 300)
+        .collect();
+    assert!(!paragraphs.is_empty());
+    for text in &paragraphs {
+        assert!(
+            chunks
+                .iter()
+                .any(|chunk| chunk.text.contains(text.as_str())),
+            "{text}"
+        );
+    }
+}
+
+/// 문단 머리 라벨 모으기 (머리 없는 문단은 건너뜀) / Collect paragraph head labels (skipping paragraphs without one)
+fn list_labels(document: &HwpDocument) -> Vec<String> {
+    let mut labels = document.list_labels();
+    document
+        .body_text
+        .sections
+        .iter()
+        .flat_map(|section| &section.paragraphs)
+        .map(|paragraph| labels.resolve_list_label(paragraph))
+        .filter(|label| !label.is_empty())
+        .collect()
+}
+
+#[test]
+fn test_list_labels_hwp() {
+    let Some(path) = common::find_fixture_file("lists.hwp") else {
+        return;
+    };
+    let document = HwpParser::new()
+        .parse(&std::fs::read(&path).unwrap())
+        .unwrap();
+    let labels = list_labels(&document);
+
+    // 글머리표 / Bullets
+    assert_eq!(labels[..6], ["●", "●", "●", "•", "•", "•"]);
+    // 이어지는 문단 번호: 하위 수준은 상위 번호가 바뀌면 다시 시작
+    // Continued numbering: lower levels restart when a higher level moves on
+    assert_eq!(
+        labels[6..18],
+        ["1.", "2.", "가.", "3.", "가.", "나.", "1)", "2)", "3)", "4.", "5.", "가."]
+    );
+    // 새 번호는 다른 문단 번호 정의 / A new numbering uses another numbering definition
+    assert_eq!(labels[18..22], ["I.", "II.", "A.", "III."]);
+    // 개요 번호는 구역 정의가 고름 / The section definition picks the outline numbering
+    assert_eq!(labels.last().map(String::as_str), Some("제4장"));
+    assert!(labels.contains(&"제1절".to_string()));
+}
+
+#[test]
+fn test_hwpx_numbering_and_bullets() {
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(&path).unwrap();
+    let data = rewrite_hwpx_part(&data, "Contents/header.xml", |xml| {
+        xml.replacen(
+            "</hh:numberings>",
+            r#"</hh:numberings><hh:bullets itemCnt="1"><hh:bullet id="1" char="■" useImage="0"><hh:paraHead level="0" align="LEFT" useInstWidth="0" autoIndent="1" widthAdjust="0" textOffsetType="PERCENT" textOffset="50" numFormat="DIGIT" charPrIDRef="4294967295" checkable="0"/></hh:bullet></hh:bullets>"#,
+            1,
+        )
+        .replacen(
+            r#"<hh:heading type="OUTLINE" idRef="0" level="6"/>"#,
+            r#"<hh:heading type="BULLET" idRef="1" level="0"/>"#,
+            1,
+        )
+    });
+    // 개요 1·2 수준과 글머리표 문단 만들기 / Make outline level 1/2 and bullet paragraphs
+    let data = rewrite_hwpx_section(&data, |xml| {
+        ["4", "5", "5", "4", "10"]
+            .iter()
+            .fold(xml.to_string(), |xml, id| {
+                xml.replacen(r#"paraPrIDRef="13""#, &format!(r#"paraPrIDRef="{id}""#), 1)
+            })
+    });
+    let document = HwpParser::new().parse(&data).unwrap();
+
+    let numbering = &document.doc_info.numbering[0];
+    assert_eq!(numbering.levels.len(), 7);
+    assert_eq!(numbering.levels[1].format_string, "^2.");
+    assert_eq!(
+        numbering.levels[1].attributes.number_shape,
+        document::bodytext::NumberShape::Hangul
+    );
+    assert_eq!(numbering.levels[0].level_start_number, Some(1));
+    assert_eq!(document.doc_info.bullets[0].bullet_char, '■' as u16);
+
+    assert_eq!(list_labels(&document), ["1.", "가.", "나.", "2.", "■"]);
+}
+
+#[test]
+fn test_style_catalog() {
+    for name in ["linespacing.hwp", "linespacing.hwpx"] {
+        let Some(path) = common::find_fixture_file(name) else {
+            continue;
+        };
+        let data = std::fs::read(&path).unwrap();
+        let document = HwpParser::new().parse(&data).unwrap();
+        let styles = document.styles();
+
+        // HWP와 HWPX가 같은 목록 / HWP and HWPX give the same catalog
+        assert_eq!(styles.len(), 14, "{name}");
+        assert_eq!(styles[0].name, "바탕글");
+        assert_eq!(styles[0].english_name, "Normal");
+        assert_eq!(styles[0].next_style.as_deref(), Some("바탕글"));
+        assert_eq!(styles[1].name, "본문");
+        assert_eq!(styles[1].properties.style_name.as_deref(), Some("본문"));
+        assert_eq!(styles[1].properties.font_size, Some(10.0));
+        assert_eq!(styles[1].properties.left_margin, 3000);
+        assert!(styles.iter().enumerate().all(|(i, style)| style.id == i));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&document.styles_json().unwrap()).unwrap();
+        assert_eq!(json[2]["name"], "개요 1");
+        assert_eq!(json[2]["style_type"], "paragraph");
+    }
+
+    // HWPX 문단의 styleIDRef / styleIDRef of HWPX paragraphs
+    let Some(path) = common::find_fixture_file("linespacing.hwpx") else {
+        return;
+    };
+    let data = rewrite_hwpx_section(&std::fs::read(&path).unwrap(), |xml| {
+        xml.replacen(r#"styleIDRef="0""#, r#"styleIDRef="1""#, 1)
+    });
+    let document = HwpParser::new().parse(&data).unwrap();
+    let paragraph = &document.body_text.sections[0].paragraphs[0];
+    assert_eq!(paragraph.para_header.para_style_id, 1);
+    assert_eq!(
+        document.resolved_style(paragraph).style_name.as_deref(),
+        Some("본문")
+    );
+}
@@ -64,8 +64,9 @@ fn test_full_document_json_snapshot() {
         // Convert to JSON
         // serde_json already outputs unicode characters as-is (not escaped)
         // Only control characters are escaped according to JSON standard
-        let json =
-            serde_json::to_string_pretty(&document).expect("Should serialize document to JSON");
+        let json = document
+            .to_json()
+            .expect("Should serialize document to JSON");
         assert_snapshot_with_path!(snapshot_name_json.as_str(), json);
 
         // 실제 JSON 파일로도 저장 / Also save as actual JSON file
@@ -111,7 +112,8 @@ fn test_all_fixtures_json_snapshots() {
                 match parser.parse(&data) {
                     Ok(document) => {
                         // Convert to JSON
-                        let json = serde_json::to_string_pretty(&document)
+                        let json = document
+                            .to_json()
                             .expect("Should serialize document to JSON");
 
                         // 스냅샷 생성 / Create snapshot
@@ -283,6 +285,8 @@ fn test_debug_list_header_children() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_document_markdown_snapshot() {
     let file_path = match find_test_file() {
         Some(path) => path,
@@ -316,9 +320,22 @@ fn test_document_markdown_snapshot() {
         std::fs::create_dir_all(&images_dir).unwrap_or(());
         let options = hwp_core::viewer::markdown::MarkdownOptions {
             image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+            image_sink: None,
             use_html: Some(true),
             include_version: Some(true),
             include_page_info: Some(true),
+            include_references: None,
+            include_toc: None,
+            line_break_mode: None,
+            preserve_alignment: None,
+            inline_controls: Default::default(),
+            image_max_dimension: None,
+            image_format: None,
+            flavor: None,
+            include_source_anchors: None,
+            strip_page_fields: None,
+            content_filter: None,
+            deterministic: false,
         };
         let markdown = document.to_markdown(&options);
         assert_snapshot_with_path!(snapshot_name_md.as_str(), markdown);
@@ -332,6 +349,8 @@ fn test_document_markdown_snapshot() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_headerfooter_markdown() {
     // headerfooter.hwp 파일에 대해 Markdown 스냅샷 생성 / Generate Markdown snapshot for headerfooter.hwp
     let file_path = match find_headerfooter_file() {
@@ -366,9 +385,22 @@ fn test_headerfooter_markdown() {
 
                     let options = hwp_core::viewer::markdown::MarkdownOptions {
                         image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+                        image_sink: None,
                         use_html: Some(true),
                         include_version: Some(true),
                         include_page_info: Some(true),
+                        include_references: None,
+                        include_toc: None,
+                        line_break_mode: None,
+                        preserve_alignment: None,
+                        inline_controls: Default::default(),
+                        image_max_dimension: None,
+                        image_format: None,
+                        flavor: None,
+                        include_source_anchors: None,
+                        strip_page_fields: None,
+                        content_filter: None,
+                        deterministic: false,
                     };
 
                     let markdown = document.to_markdown(&options);
@@ -395,6 +427,8 @@ fn test_headerfooter_markdown() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_all_fixtures_markdown_snapshots() {
     // 모든 fixtures 파일에 대해 Markdown 스냅샷 생성 / Generate Markdown snapshots for all fixtures files
     let hwp_files = find_all_hwp_files();
@@ -430,9 +464,22 @@ fn test_all_fixtures_markdown_snapshots() {
 
                         let options = hwp_core::viewer::markdown::MarkdownOptions {
                             image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+                            image_sink: None,
                             use_html: Some(true),
                             include_version: Some(true),
                             include_page_info: Some(true),
+                            include_references: None,
+                            include_toc: None,
+                            line_break_mode: None,
+                            preserve_alignment: None,
+                            inline_controls: Default::default(),
+                            image_max_dimension: None,
+                            image_format: None,
+                            flavor: None,
+                            include_source_anchors: None,
+                            strip_page_fields: None,
+                            content_filter: None,
+                            deterministic: false,
                         };
                         let markdown = document.to_markdown(&options);
 
@@ -459,6 +506,8 @@ fn test_all_fixtures_markdown_snapshots() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_document_html_snapshot() {
     let file_path = match find_fixture_file("linespacing.hwp") {
         Some(path) => path,
@@ -492,9 +541,22 @@ fn test_document_html_snapshot() {
         std::fs::create_dir_all(&images_dir).unwrap_or(());
         let options = hwp_core::viewer::HtmlOptions {
             image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+            image_sink: None,
             html_output_dir: snapshots_dir.to_str().map(|s| s.to_string()),
             include_version: Some(true),
             include_page_info: Some(true),
+            include_references: None,
+            include_text_colors: None,
+            include_source_anchors: None,
+            include_headers_footers: None,
+            master_pages: None,
+            content_filter: None,
+            deterministic: false,
+            font_map: Default::default(),
+            inline_controls: Default::default(),
+            accessibility: false,
+            image_max_dimension: None,
+            image_format: None,
             css_class_prefix: "ohah-hwpjs-".to_string(),
         };
         let html = document.to_html(&options);
@@ -509,6 +571,8 @@ fn test_document_html_snapshot() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_headerfooter_html() {
     // headerfooter.hwp 파일에 대해 HTML 스냅샷 생성 / Generate HTML snapshot for headerfooter.hwp
     let file_path = match find_headerfooter_file() {
@@ -543,9 +607,22 @@ fn test_headerfooter_html() {
 
                     let options = hwp_core::viewer::html::HtmlOptions {
                         image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+                        image_sink: None,
                         html_output_dir: snapshots_dir.to_str().map(|s| s.to_string()),
                         include_version: Some(true),
                         include_page_info: Some(true),
+                        include_references: None,
+                        include_text_colors: None,
+                        include_source_anchors: None,
+                        include_headers_footers: None,
+                        master_pages: None,
+                        content_filter: None,
+                        deterministic: false,
+                        font_map: Default::default(),
+                        inline_controls: Default::default(),
+                        accessibility: false,
+                        image_max_dimension: None,
+                        image_format: None,
                         css_class_prefix: "ohah-hwpjs-".to_string(),
                     };
 
@@ -573,6 +650,8 @@ fn test_headerfooter_html() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_all_fixtures_html_snapshots() {
     // 모든 fixtures 파일에 대해 HTML 스냅샷 생성 / Generate HTML snapshots for all fixtures files
     let hwp_files = find_all_hwp_files();
@@ -608,9 +687,22 @@ fn test_all_fixtures_html_snapshots() {
 
                         let options = hwp_core::viewer::html::HtmlOptions {
                             image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+                            image_sink: None,
                             html_output_dir: snapshots_dir.to_str().map(|s| s.to_string()),
                             include_version: Some(true),
                             include_page_info: Some(true),
+                            include_references: None,
+                            include_text_colors: None,
+                            include_source_anchors: None,
+                            include_headers_footers: None,
+                            master_pages: None,
+                            content_filter: None,
+                            deterministic: false,
+                            font_map: Default::default(),
+                            inline_controls: Default::default(),
+                            accessibility: false,
+                            image_max_dimension: None,
+                            image_format: None,
                             css_class_prefix: String::new(), // table.html과 일치하도록 빈 문자열 사용
                         };
                         if file_name == "table" {
@@ -641,6 +733,8 @@ fn test_all_fixtures_html_snapshots() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_table2_html_snapshot() {
     // table2.hwp 파일만 테스트 / Test only table2.hwp file
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
@@ -673,9 +767,22 @@ fn test_table2_html_snapshot() {
 
                     let options = hwp_core::viewer::html::HtmlOptions {
                         image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+                        image_sink: None,
                         html_output_dir: snapshots_dir.to_str().map(|s| s.to_string()),
                         include_version: Some(true),
                         include_page_info: Some(true),
+                        include_references: None,
+                        include_text_colors: None,
+                        include_source_anchors: None,
+                        include_headers_footers: None,
+                        master_pages: None,
+                        content_filter: None,
+                        deterministic: false,
+                        font_map: Default::default(),
+                        inline_controls: Default::default(),
+                        accessibility: false,
+                        image_max_dimension: None,
+                        image_format: None,
                         css_class_prefix: String::new(), // table.html과 일치하도록 빈 문자열 사용
                     };
                     eprintln!("DEBUG: Processing table2.hwp file");
@@ -972,6 +1079,8 @@ fn test_analyze_object_common_properties_size() {
 }
 
 #[test]
+// 그림을 파일로 저장 / Saves pictures as files
+#[cfg(feature = "fs")]
 fn test_document_markdown_with_image_files() {
     let file_path = match find_test_file() {
         Some(path) => path,
@@ -1006,9 +1115,22 @@ fn test_document_markdown_with_image_files() {
         // Convert to markdown with image files
         let options = hwp_core::viewer::markdown::MarkdownOptions {
             image_output_dir: images_dir.to_str().map(|s| s.to_string()),
+            image_sink: None,
             use_html: Some(true),
             include_version: Some(true),
             include_page_info: Some(true),
+            include_references: None,
+            include_toc: None,
+            line_break_mode: None,
+            preserve_alignment: None,
+            inline_controls: Default::default(),
+            image_max_dimension: None,
+            image_format: None,
+            flavor: None,
+            include_source_anchors: None,
+            strip_page_fields: None,
+            content_filter: None,
+            deterministic: false,
         };
         let markdown = document.to_markdown(&options);
 
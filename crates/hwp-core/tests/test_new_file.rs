@@ -46,9 +46,22 @@ fn test_parse_new_hwpx_file() {
 
             let options = hwp_core::viewer::markdown::MarkdownOptions {
                 image_output_dir: None,
+                image_sink: None,
                 use_html: Some(true),
                 include_version: None,
                 include_page_info: None,
+                include_references: None,
+                include_toc: None,
+                line_break_mode: None,
+                preserve_alignment: None,
+                inline_controls: Default::default(),
+                image_max_dimension: None,
+                image_format: None,
+                flavor: None,
+                include_source_anchors: None,
+                strip_page_fields: None,
+                content_filter: None,
+                deterministic: false,
             };
             let md = hwp_core::viewer::markdown::to_markdown(&doc, &options);
             println!("\n=== Full Markdown Output ===");
@@ -0,0 +1,199 @@
+#ifndef HWPX_H
+#define HWPX_H
+
+/* Generated by cbindgen from src/lib.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result code of fallible calls
+ */
+typedef enum HwpxStatus {
+  /**
+   * Success
+   */
+  HWPX_STATUS_OK = 0,
+  /**
+   * A required pointer was null or a path was not valid UTF-8
+   */
+  HWPX_STATUS_INVALID_ARGUMENT = 1,
+  /**
+   * The file could not be read
+   */
+  HWPX_STATUS_IO_ERROR = 2,
+  /**
+   * The data is not a supported HWP/HWPX format or version
+   */
+  HWPX_STATUS_UNSUPPORTED_FORMAT = 3,
+  /**
+   * The document is password protected
+   */
+  HWPX_STATUS_PASSWORD_REQUIRED = 4,
+  /**
+   * The document is malformed
+   */
+  HWPX_STATUS_PARSE_ERROR = 5,
+  /**
+   * The library panicked; `hwpx_last_error` has the panic message
+   */
+  HWPX_STATUS_PANIC = 6,
+} HwpxStatus;
+
+/**
+ * Paragraph trimming for `hwpx_get_text_with_options`
+ */
+typedef enum HwpxTrimMode {
+  /**
+   * Trim both ends of each paragraph
+   */
+  HWPX_TRIM_MODE_BOTH = 0,
+  /**
+   * Trim the end only, keeping indentation
+   */
+  HWPX_TRIM_MODE_END = 1,
+  /**
+   * Keep paragraphs as they are
+   */
+  HWPX_TRIM_MODE_NONE = 2,
+} HwpxTrimMode;
+
+/**
+ * Parsed document handle
+ *
+ * Opaque to C; create with `hwpx_parse` or `hwpx_parse_file` and release with
+ * `hwpx_document_free`.
+ */
+typedef struct HwpxDocument HwpxDocument;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parse an HWP/HWPX document from memory
+ *
+ * On success stores a new handle in `*out` and returns `HWPX_STATUS_OK`; otherwise
+ * leaves `*out` untouched and `hwpx_last_error` describes the failure.
+ *
+ * # Safety
+ * `data` must point to `len` readable bytes and `out` must be a valid pointer.
+ */
+enum HwpxStatus hwpx_parse(const uint8_t *data, size_t len, struct HwpxDocument **out);
+
+/**
+ * Parse an HWP/HWPX document from a file path (UTF-8)
+ *
+ * # Safety
+ * `path` must be a NUL-terminated string and `out` must be a valid pointer.
+ */
+enum HwpxStatus hwpx_parse_file(const char *path, struct HwpxDocument **out);
+
+/**
+ * Release a document handle (null is ignored)
+ *
+ * # Safety
+ * `document` must come from `hwpx_parse`/`hwpx_parse_file` and not be freed twice.
+ */
+void hwpx_document_free(struct HwpxDocument *document);
+
+/**
+ * Plain text content with paragraphs separated by newlines
+ *
+ * Returns null if `document` is null. Free the result with `hwpx_string_free`.
+ *
+ * # Safety
+ * `document` must be a live handle or null.
+ */
+char *hwpx_get_text(const struct HwpxDocument *document);
+
+/**
+ * Plain text content with a custom separator and trimming
+ *
+ * A null `separator` means a newline. Blank paragraphs are kept as empty entries when
+ * `keep_blank` is true. Returns null if `document` is null or `separator` is not valid
+ * UTF-8. Free the result with `hwpx_string_free`.
+ *
+ * # Safety
+ * `document` must be a live handle or null; `separator` must be a NUL-terminated string
+ * or null.
+ */
+char *hwpx_get_text_with_options(const struct HwpxDocument *document,
+                                 const char *separator,
+                                 enum HwpxTrimMode trim,
+                                 bool keep_blank);
+
+/**
+ * Convert the document to markdown (HTML tags enabled, images embedded as base64)
+ *
+ * Returns null if `document` is null. Free the result with `hwpx_string_free`.
+ *
+ * # Safety
+ * `document` must be a live handle or null.
+ */
+char *hwpx_to_markdown(const struct HwpxDocument *document);
+
+/**
+ * Stream the document as markdown into a file (same output as `hwpx_to_markdown`)
+ *
+ * The markdown is written as it is produced instead of being built in memory first.
+ *
+ * # Safety
+ * `document` must be a live handle or null; `path` must be a NUL-terminated string or null.
+ */
+enum HwpxStatus hwpx_write_markdown(const struct HwpxDocument *document, const char *path);
+
+/**
+ * Convert the document to HTML (images embedded as base64)
+ *
+ * Returns null if `document` is null. Free the result with `hwpx_string_free`.
+ *
+ * # Safety
+ * `document` must be a live handle or null.
+ */
+char *hwpx_to_html(const struct HwpxDocument *document);
+
+/**
+ * Stream the document as HTML into a file (same output as `hwpx_to_html`)
+ *
+ * The HTML is written page by page instead of being built in memory first.
+ *
+ * # Safety
+ * `document` must be a live handle or null; `path` must be a NUL-terminated string or null.
+ */
+enum HwpxStatus hwpx_write_html(const struct HwpxDocument *document, const char *path);
+
+/**
+ * Convert the document to JSON
+ *
+ * Returns null if `document` is null or serialization fails. Free the result with
+ * `hwpx_string_free`.
+ *
+ * # Safety
+ * `document` must be a live handle or null.
+ */
+char *hwpx_to_json(const struct HwpxDocument *document);
+
+/**
+ * Release a string returned by this library (null is ignored)
+ *
+ * # Safety
+ * `text` must come from this library and not be freed twice.
+ */
+void hwpx_string_free(char *text);
+
+/**
+ * Message of the last failure on the calling thread, or null if none
+ *
+ * The pointer stays valid until the next failing call on the same thread; do not free it.
+ */
+const char *hwpx_last_error(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HWPX_H */
@@ -0,0 +1,597 @@
+//! C ABI for the HWP/HWPX document parser
+//!
+//! Every function is `extern "C"` and declared in `include/hwpx.h`, which is regenerated
+//! by `build.rs`. Typical use:
+//!
+//! ```c
+//! HwpxDocument *doc = NULL;
+//! if (hwpx_parse(data, len, &doc) != HWPX_STATUS_OK) {
+//!     fprintf(stderr, "%s\n", hwpx_last_error());
+//!     return 1;
+//! }
+//! char *markdown = hwpx_to_markdown(doc);
+//! puts(markdown);
+//! hwpx_string_free(markdown);
+//! hwpx_document_free(doc);
+//! ```
+//!
+//! Strings returned by this library are owned by the caller and must be released with
+//! `hwpx_string_free`. A document handle may be shared across threads for reading.
+//!
+//! A panic inside the library never unwinds into the caller: functions returning a status
+//! return `HWPX_STATUS_PANIC`, functions returning a pointer return null, and
+//! `hwpx_last_error` holds the panic message.
+
+// `guard` relies on unwinding, so refuse to build with panic = "abort"
+#[cfg(panic = "abort")]
+compile_error!("hwpx-ffi catches panics at the C ABI and must be built with panic = \"unwind\"");
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use hwp_core::viewer::html::{to_html, to_html_writer, HtmlOptions};
+use hwp_core::viewer::markdown::{to_markdown, to_markdown_writer, MarkdownOptions};
+use hwp_core::{HwpDocument, HwpError, HwpParser, TextOptions, TrimMode};
+
+/// Result code of fallible calls
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HwpxStatus {
+    /// Success
+    Ok = 0,
+    /// A required pointer was null or a path was not valid UTF-8
+    InvalidArgument = 1,
+    /// The file could not be read
+    IoError = 2,
+    /// The data is not a supported HWP/HWPX format or version
+    UnsupportedFormat = 3,
+    /// The document is password protected
+    PasswordRequired = 4,
+    /// The document is malformed
+    ParseError = 5,
+    /// The library panicked; `hwpx_last_error` has the panic message
+    Panic = 6,
+}
+
+/// Paragraph trimming for `hwpx_get_text_with_options`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HwpxTrimMode {
+    /// Trim both ends of each paragraph
+    Both = 0,
+    /// Trim the end only, keeping indentation
+    End = 1,
+    /// Keep paragraphs as they are
+    None = 2,
+}
+
+/// Parsed document handle
+///
+/// Opaque to C; create with `hwpx_parse` or `hwpx_parse_file` and release with
+/// `hwpx_document_free`.
+pub struct HwpxDocument {
+    inner: HwpDocument,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Remember an error message for `hwpx_last_error` on this thread
+fn set_last_error(message: impl Into<String>) {
+    // Interior NULs cannot be represented in a C string
+    let message = message.into().replace('\0', " ");
+    LAST_ERROR.with(|last| *last.borrow_mut() = CString::new(message).ok());
+}
+
+/// Run an entry point body, turning a panic into `on_panic` and recording its message
+///
+/// Unwinding across an `extern "C"` boundary aborts the process, so every entry point
+/// goes through here.
+fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_string());
+            set_last_error(format!("Internal error (panic): {message}"));
+            on_panic
+        }
+    }
+}
+
+/// Map a core error to a status code, recording its message
+fn error_status(error: HwpError) -> HwpxStatus {
+    let status = match error.kind() {
+        HwpError::UnknownFormat
+        | HwpError::UnsupportedFormat { .. }
+        | HwpError::UnsupportedVersion { .. }
+        | HwpError::InvalidSignature { .. } => HwpxStatus::UnsupportedFormat,
+        HwpError::PasswordRequired => HwpxStatus::PasswordRequired,
+        _ => HwpxStatus::ParseError,
+    };
+    set_last_error(error.to_string());
+    status
+}
+
+/// Parse bytes and store the handle in `out`
+fn parse_into(data: &[u8], out: *mut *mut HwpxDocument) -> HwpxStatus {
+    match HwpParser::new().parse(data) {
+        Ok(document) => {
+            let handle = Box::new(HwpxDocument { inner: document });
+            // SAFETY: callers check `out` for null before calling
+            unsafe { *out = Box::into_raw(handle) };
+            HwpxStatus::Ok
+        }
+        Err(error) => error_status(error),
+    }
+}
+
+/// Hand a Rust string to C, or null if it contains a NUL byte
+fn into_c_string(text: String) -> *mut c_char {
+    match CString::new(text) {
+        Ok(text) => text.into_raw(),
+        Err(error) => {
+            set_last_error(format!("Output contains a NUL byte: {error}"));
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Markdown options of the C API (HTML tags enabled, images embedded as base64)
+fn markdown_options() -> MarkdownOptions {
+    MarkdownOptions {
+        image_output_dir: None,
+        image_sink: None,
+        use_html: Some(true),
+        include_version: Some(true),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    }
+}
+
+/// Create the file at `path` and stream `write`'s output into it
+///
+/// # Safety
+/// Same as the `hwpx_write_*` functions.
+unsafe fn write_file(
+    document: *const HwpxDocument,
+    path: *const c_char,
+    write: impl FnOnce(&HwpDocument, &mut BufWriter<File>) -> std::io::Result<()>,
+) -> HwpxStatus {
+    let Some(document) = document.as_ref() else {
+        set_last_error("document must not be null");
+        return HwpxStatus::InvalidArgument;
+    };
+    if path.is_null() {
+        set_last_error("path must not be null");
+        return HwpxStatus::InvalidArgument;
+    }
+    let Ok(path) = CStr::from_ptr(path).to_str() else {
+        set_last_error("path is not valid UTF-8");
+        return HwpxStatus::InvalidArgument;
+    };
+    let result = File::create(path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        write(&document.inner, &mut writer)?;
+        writer.flush()
+    });
+    match result {
+        Ok(()) => HwpxStatus::Ok,
+        Err(error) => {
+            set_last_error(format!("Failed to write file '{path}': {error}"));
+            HwpxStatus::IoError
+        }
+    }
+}
+
+/// Parse an HWP/HWPX document from memory
+///
+/// On success stores a new handle in `*out` and returns `HWPX_STATUS_OK`; otherwise
+/// leaves `*out` untouched and `hwpx_last_error` describes the failure.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_parse(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut HwpxDocument,
+) -> HwpxStatus {
+    guard(HwpxStatus::Panic, || {
+        if data.is_null() || out.is_null() {
+            set_last_error("data and out must not be null");
+            return HwpxStatus::InvalidArgument;
+        }
+        let data = std::slice::from_raw_parts(data, len);
+        parse_into(data, out)
+    })
+}
+
+/// Parse an HWP/HWPX document from a file path (UTF-8)
+///
+/// # Safety
+/// `path` must be a NUL-terminated string and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_parse_file(
+    path: *const c_char,
+    out: *mut *mut HwpxDocument,
+) -> HwpxStatus {
+    guard(HwpxStatus::Panic, || {
+        if path.is_null() || out.is_null() {
+            set_last_error("path and out must not be null");
+            return HwpxStatus::InvalidArgument;
+        }
+        let Ok(path) = CStr::from_ptr(path).to_str() else {
+            set_last_error("path is not valid UTF-8");
+            return HwpxStatus::InvalidArgument;
+        };
+        match std::fs::read(path) {
+            Ok(data) => parse_into(&data, out),
+            Err(error) => {
+                set_last_error(format!("Failed to read file '{path}': {error}"));
+                HwpxStatus::IoError
+            }
+        }
+    })
+}
+
+/// Release a document handle (null is ignored)
+///
+/// # Safety
+/// `document` must come from `hwpx_parse`/`hwpx_parse_file` and not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_document_free(document: *mut HwpxDocument) {
+    guard((), || {
+        if !document.is_null() {
+            drop(Box::from_raw(document));
+        }
+    })
+}
+
+/// Plain text content with paragraphs separated by newlines
+///
+/// Returns null if `document` is null. Free the result with `hwpx_string_free`.
+///
+/// # Safety
+/// `document` must be a live handle or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_get_text(document: *const HwpxDocument) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            set_last_error("document must not be null");
+            return ptr::null_mut();
+        };
+        into_c_string(document.inner.text())
+    })
+}
+
+/// Plain text content with a custom separator and trimming
+///
+/// A null `separator` means a newline. Blank paragraphs are kept as empty entries when
+/// `keep_blank` is true. Returns null if `document` is null or `separator` is not valid
+/// UTF-8. Free the result with `hwpx_string_free`.
+///
+/// # Safety
+/// `document` must be a live handle or null; `separator` must be a NUL-terminated string
+/// or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_get_text_with_options(
+    document: *const HwpxDocument,
+    separator: *const c_char,
+    trim: HwpxTrimMode,
+    keep_blank: bool,
+) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            set_last_error("document must not be null");
+            return ptr::null_mut();
+        };
+        let mut options = TextOptions::default()
+            .with_trim(match trim {
+                HwpxTrimMode::Both => TrimMode::Both,
+                HwpxTrimMode::End => TrimMode::End,
+                HwpxTrimMode::None => TrimMode::None,
+            })
+            .with_keep_blank_paragraphs(keep_blank);
+        if !separator.is_null() {
+            let Ok(separator) = CStr::from_ptr(separator).to_str() else {
+                set_last_error("separator is not valid UTF-8");
+                return ptr::null_mut();
+            };
+            options = options.with_separator(separator);
+        }
+        into_c_string(document.inner.text_with_options(&options))
+    })
+}
+
+/// Convert the document to markdown (HTML tags enabled, images embedded as base64)
+///
+/// Returns null if `document` is null. Free the result with `hwpx_string_free`.
+///
+/// # Safety
+/// `document` must be a live handle or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_to_markdown(document: *const HwpxDocument) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            set_last_error("document must not be null");
+            return ptr::null_mut();
+        };
+        into_c_string(to_markdown(&document.inner, &markdown_options()))
+    })
+}
+
+/// Stream the document as markdown into a file (same output as `hwpx_to_markdown`)
+///
+/// The markdown is written as it is produced instead of being built in memory first.
+///
+/// # Safety
+/// `document` must be a live handle or null; `path` must be a NUL-terminated string or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_write_markdown(
+    document: *const HwpxDocument,
+    path: *const c_char,
+) -> HwpxStatus {
+    guard(HwpxStatus::Panic, || {
+        write_file(document, path, |document, writer| {
+            to_markdown_writer(document, &markdown_options(), writer)
+        })
+    })
+}
+
+/// Convert the document to HTML (images embedded as base64)
+///
+/// Returns null if `document` is null. Free the result with `hwpx_string_free`.
+///
+/// # Safety
+/// `document` must be a live handle or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_to_html(document: *const HwpxDocument) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            set_last_error("document must not be null");
+            return ptr::null_mut();
+        };
+        into_c_string(to_html(&document.inner, &HtmlOptions::default()))
+    })
+}
+
+/// Stream the document as HTML into a file (same output as `hwpx_to_html`)
+///
+/// The HTML is written page by page instead of being built in memory first.
+///
+/// # Safety
+/// `document` must be a live handle or null; `path` must be a NUL-terminated string or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_write_html(
+    document: *const HwpxDocument,
+    path: *const c_char,
+) -> HwpxStatus {
+    guard(HwpxStatus::Panic, || {
+        write_file(document, path, |document, writer| {
+            to_html_writer(document, &HtmlOptions::default(), writer)
+        })
+    })
+}
+
+/// Convert the document to JSON
+///
+/// Returns null if `document` is null or serialization fails. Free the result with
+/// `hwpx_string_free`.
+///
+/// # Safety
+/// `document` must be a live handle or null.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_to_json(document: *const HwpxDocument) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let Some(document) = document.as_ref() else {
+            set_last_error("document must not be null");
+            return ptr::null_mut();
+        };
+        match document.inner.to_json() {
+            Ok(json) => into_c_string(json),
+            Err(error) => {
+                error_status(error);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Release a string returned by this library (null is ignored)
+///
+/// # Safety
+/// `text` must come from this library and not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn hwpx_string_free(text: *mut c_char) {
+    guard((), || {
+        if !text.is_null() {
+            drop(CString::from_raw(text));
+        }
+    })
+}
+
+/// Message of the last failure on the calling thread, or null if none
+///
+/// The pointer stays valid until the next failing call on the same thread; do not free it.
+#[no_mangle]
+pub extern "C" fn hwpx_last_error() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|last| {
+            last.borrow()
+                .as_ref()
+                .map_or(ptr::null(), |message| message.as_ptr())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> CString {
+        let path = format!(
+            "{}/../../crates/hwp-core/tests/fixtures/{name}",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        CString::new(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors_set_status_and_message() {
+        let mut document = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                hwpx_parse(b"xx".as_ptr(), 2, &mut document),
+                HwpxStatus::UnsupportedFormat
+            );
+            assert!(document.is_null());
+            let message = CStr::from_ptr(hwpx_last_error()).to_str().unwrap();
+            assert!(message.starts_with("Unknown file format"));
+
+            let missing = CString::new("/nonexistent/document.hwp").unwrap();
+            assert_eq!(
+                hwpx_parse_file(missing.as_ptr(), &mut document),
+                HwpxStatus::IoError
+            );
+            assert_eq!(
+                hwpx_parse(ptr::null(), 0, &mut document),
+                HwpxStatus::InvalidArgument
+            );
+            assert!(hwpx_get_text(ptr::null()).is_null());
+        }
+    }
+
+    #[test]
+    fn test_guard_turns_panic_into_status_and_message() {
+        assert_eq!(
+            guard(HwpxStatus::Panic, || -> HwpxStatus { panic!("boom") }),
+            HwpxStatus::Panic
+        );
+        let message = unsafe { CStr::from_ptr(hwpx_last_error()) }
+            .to_str()
+            .unwrap();
+        assert_eq!(message, "Internal error (panic): boom");
+
+        let index = 3;
+        let pointer = guard(ptr::null_mut::<c_char>(), || panic!("index {index}"));
+        assert!(pointer.is_null());
+        let message = unsafe { CStr::from_ptr(hwpx_last_error()) }
+            .to_str()
+            .unwrap();
+        assert_eq!(message, "Internal error (panic): index 3");
+
+        assert_eq!(guard(HwpxStatus::Panic, || HwpxStatus::Ok), HwpxStatus::Ok);
+    }
+
+    #[test]
+    fn test_parse_file_and_convert() {
+        let path = fixture("linespacing.hwpx");
+        let mut document = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                hwpx_parse_file(path.as_ptr(), &mut document),
+                HwpxStatus::Ok
+            );
+            for text in [
+                hwpx_get_text(document),
+                hwpx_to_markdown(document),
+                hwpx_to_html(document),
+                hwpx_to_json(document),
+            ] {
+                assert!(!text.is_null());
+                assert!(!CStr::from_ptr(text).to_bytes().is_empty());
+                hwpx_string_free(text);
+            }
+            hwpx_document_free(document);
+        }
+    }
+
+    #[test]
+    fn test_write_markdown_and_html_match_strings() {
+        let path = fixture("linespacing.hwpx");
+        let dir = std::env::temp_dir().join(format!("hwpx-ffi-write-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let markdown_path = CString::new(dir.join("out.md").to_str().unwrap()).unwrap();
+        let html_path = CString::new(dir.join("out.html").to_str().unwrap()).unwrap();
+        let mut document = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                hwpx_parse_file(path.as_ptr(), &mut document),
+                HwpxStatus::Ok
+            );
+            assert_eq!(
+                hwpx_write_markdown(document, markdown_path.as_ptr()),
+                HwpxStatus::Ok
+            );
+            assert_eq!(
+                hwpx_write_html(document, html_path.as_ptr()),
+                HwpxStatus::Ok
+            );
+            assert_eq!(
+                hwpx_write_html(document, ptr::null()),
+                HwpxStatus::InvalidArgument
+            );
+
+            let markdown = hwpx_to_markdown(document);
+            let html = hwpx_to_html(document);
+            assert_eq!(
+                std::fs::read(dir.join("out.md")).unwrap(),
+                CStr::from_ptr(markdown).to_bytes()
+            );
+            assert_eq!(
+                std::fs::read(dir.join("out.html")).unwrap(),
+                CStr::from_ptr(html).to_bytes()
+            );
+            hwpx_string_free(markdown);
+            hwpx_string_free(html);
+            hwpx_document_free(document);
+        }
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_get_text_with_options() {
+        let path = fixture("linespacing.hwpx");
+        let mut document = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                hwpx_parse_file(path.as_ptr(), &mut document),
+                HwpxStatus::Ok
+            );
+            // Defaults match hwpx_get_text
+            let default = hwpx_get_text(document);
+            let same = hwpx_get_text_with_options(document, ptr::null(), HwpxTrimMode::Both, false);
+            assert_eq!(CStr::from_ptr(same), CStr::from_ptr(default));
+
+            let separator = CString::new("<P>").unwrap();
+            let custom =
+                hwpx_get_text_with_options(document, separator.as_ptr(), HwpxTrimMode::End, true);
+            assert!(CStr::from_ptr(custom).to_str().unwrap().contains("<P>"));
+
+            for text in [default, same, custom] {
+                hwpx_string_free(text);
+            }
+            hwpx_document_free(document);
+        }
+    }
+}
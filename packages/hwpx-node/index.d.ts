@@ -0,0 +1,75 @@
+/** Options for `Document.toMarkdown()` */
+export interface MarkdownConvertOptions {
+  /** Whether to use HTML tags (default: true) */
+  useHtml?: boolean
+  /** Whether to include version info (default: true) */
+  includeVersion?: boolean
+  /** Directory to save images (default: embeds as base64) */
+  imageOutputDir?: string
+}
+
+/** Options for `Document.toHtml()` */
+export interface HtmlConvertOptions {
+  /** Directory to save images (default: embeds as base64) */
+  imageOutputDir?: string
+}
+
+/** Options for `Document.getText()` */
+export interface TextExtractOptions {
+  /** String placed between paragraphs (default: "\n") */
+  separator?: string
+  /** "both", "end" (keeps indentation) or "none" (default: "both") */
+  trim?: string
+  /** Whether blank paragraphs are kept as empty lines (default: false) */
+  keepBlankParagraphs?: boolean
+  /** Whether chart titles, axis titles, legends and category labels are included (default: false) */
+  includeCharts?: boolean
+  /** Whether hidden comments are left out (default: false) */
+  excludeHiddenText?: boolean
+  /** Whether personal information fields are left out (default: false) */
+  excludePrivateInfo?: boolean
+  /** Whether zero-width spaces, direction controls and other invisible characters are removed (default: false) */
+  excludeInvisibleChars?: boolean
+}
+
+/** HWP/HWPX document */
+export declare class Document {
+  /** Document version as string (e.g. "5.1.0.1") */
+  get version(): string
+  /** Source file format ("hwp5", "hwpx", "hwpml" or "hwp3") */
+  get format(): string
+  /** Whether the document was read from a template file (.hwt, .hwtx) */
+  get isTemplate(): boolean
+  /** Number of sections */
+  get sectionCount(): number
+  /** Convert document to markdown */
+  toMarkdown(options?: MarkdownConvertOptions): string
+  /** Stream markdown into a file without building the whole string in memory */
+  writeMarkdown(path: string, options?: MarkdownConvertOptions): void
+  /** Convert document to HTML */
+  toHtml(options?: HtmlConvertOptions): string
+  /** Stream HTML into a file without building the whole string in memory */
+  writeHtml(path: string, options?: HtmlConvertOptions): void
+  /**
+   * Convert document to JSON
+   *
+   * Pass `legacyLayout: true` to emit the previous layout (schema 1).
+   */
+  toJson(legacyLayout?: boolean): string
+  /** Style catalog as JSON: name, type, next style and resolved properties of every style */
+  stylesJson(): string
+  /** Plain text content, paragraphs separated by newlines unless `separator` is given */
+  getText(options?: TextExtractOptions): string
+}
+
+/**
+ * Parse HWP/HWPX file from a Buffer
+ *
+ * Throws an `Error` with code `InvalidArg` for unsupported formats and
+ * password-protected documents, `GenericFailure` otherwise.
+ */
+export declare function parse(data: Buffer): Document
+/** Parse HWP/HWPX file from a file path */
+export declare function parseFile(path: string): Document
+/** Restore a document from JSON produced by `Document.toJson()` */
+export declare function fromJson(json: string): Document
@@ -0,0 +1,25 @@
+// Loads the native addon built by `napi build --platform` (hwpx.<platform>-<arch>[-abi].node)
+// and falls back to an unsuffixed hwpx.node.
+const { existsSync } = require('fs')
+const { join } = require('path')
+
+function platformSuffix() {
+  const { platform, arch } = process
+  if (platform === 'linux') {
+    const report = typeof process.report?.getReport === 'function' ? process.report.getReport() : null
+    const isMusl = report ? !report.header.glibcVersionRuntime : false
+    return `linux-${arch}-${isMusl ? 'musl' : 'gnu'}`
+  }
+  if (platform === 'win32') {
+    return `win32-${arch}-msvc`
+  }
+  return `${platform}-${arch}`
+}
+
+const candidates = [join(__dirname, `hwpx.${platformSuffix()}.node`), join(__dirname, 'hwpx.node')]
+const binding = candidates.find((path) => existsSync(path))
+if (!binding) {
+  throw new Error(`hwpx: native binding not found (looked for ${candidates.join(', ')}). Run \`npm run build\`.`)
+}
+
+module.exports = require(binding)
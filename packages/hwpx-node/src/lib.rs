@@ -0,0 +1,286 @@
+#![deny(clippy::all)]
+
+use hwp_core::viewer::html::{to_html, to_html_writer, HtmlOptions};
+use hwp_core::viewer::markdown::{to_markdown, to_markdown_writer, MarkdownOptions};
+use hwp_core::{
+    ContentFilter, HwpDocument, HwpError, HwpParser, JsonOptions, SourceFormat, TextOptions,
+    TrimMode,
+};
+use napi::bindgen_prelude::Buffer;
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Map a core error to a JavaScript error
+///
+/// Unsupported formats, password-protected and DRM-protected documents are reported with the
+/// `InvalidArg` code; everything else uses `GenericFailure`.
+fn to_js_err(error: HwpError) -> Error {
+    let status = match error.kind() {
+        HwpError::UnknownFormat
+        | HwpError::UnsupportedFormat { .. }
+        | HwpError::UnsupportedVersion { .. }
+        | HwpError::InvalidSignature { .. }
+        | HwpError::PasswordRequired
+        | HwpError::DrmProtected { .. } => Status::InvalidArg,
+        _ => Status::GenericFailure,
+    };
+    Error::new(status, error.to_string())
+}
+
+/// Options for `Document.toMarkdown()`
+#[napi(object)]
+#[derive(Default)]
+pub struct MarkdownConvertOptions {
+    /// Whether to use HTML tags (default: true)
+    pub use_html: Option<bool>,
+    /// Whether to include version info (default: true)
+    pub include_version: Option<bool>,
+    /// Directory to save images (default: embeds as base64)
+    pub image_output_dir: Option<String>,
+}
+
+/// Options for `Document.toHtml()`
+#[napi(object)]
+#[derive(Default)]
+pub struct HtmlConvertOptions {
+    /// Directory to save images (default: embeds as base64)
+    pub image_output_dir: Option<String>,
+}
+
+/// Options for `Document.getText()`
+#[napi(object)]
+#[derive(Default)]
+pub struct TextExtractOptions {
+    /// String placed between paragraphs (default: "\n")
+    pub separator: Option<String>,
+    /// "both", "end" (keeps indentation) or "none" (default: "both")
+    pub trim: Option<String>,
+    /// Whether blank paragraphs are kept as empty lines (default: false)
+    pub keep_blank_paragraphs: Option<bool>,
+    /// Whether chart titles, axis titles, legends and category labels are included (default: false)
+    pub include_charts: Option<bool>,
+    /// Whether hidden comments are left out (default: false)
+    pub exclude_hidden_text: Option<bool>,
+    /// Whether personal information fields are left out (default: false)
+    pub exclude_private_info: Option<bool>,
+    /// Whether zero-width spaces, direction controls and other invisible characters are removed (default: false)
+    pub exclude_invisible_chars: Option<bool>,
+}
+
+/// Core markdown options with the binding defaults applied
+fn markdown_options(options: Option<MarkdownConvertOptions>) -> MarkdownOptions {
+    let options = options.unwrap_or_default();
+    MarkdownOptions {
+        image_output_dir: options.image_output_dir,
+        image_sink: None,
+        use_html: Some(options.use_html.unwrap_or(true)),
+        include_version: Some(options.include_version.unwrap_or(true)),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    }
+}
+
+/// Core HTML options with the binding defaults applied
+fn html_options(options: Option<HtmlConvertOptions>) -> HtmlOptions {
+    HtmlOptions {
+        image_output_dir: options.and_then(|options| options.image_output_dir),
+        image_sink: None,
+        html_output_dir: None,
+        include_version: Some(true),
+        include_page_info: None,
+        include_references: None,
+        include_text_colors: None,
+        include_source_anchors: None,
+        include_headers_footers: None,
+        master_pages: None,
+        content_filter: None,
+        deterministic: false,
+        font_map: Default::default(),
+        inline_controls: Default::default(),
+        accessibility: false,
+        image_max_dimension: None,
+        image_format: None,
+        css_class_prefix: String::new(),
+    }
+}
+
+/// Create `path` and stream output into it through a buffered writer
+fn write_file(
+    path: &str,
+    write: impl FnOnce(&mut BufWriter<File>) -> std::io::Result<()>,
+) -> Result<()> {
+    let result = File::create(path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        write(&mut writer)?;
+        writer.flush()
+    });
+    result.map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to write file '{path}': {e}"),
+        )
+    })
+}
+
+/// HWP/HWPX document
+#[napi]
+pub struct Document {
+    inner: HwpDocument,
+}
+
+#[napi]
+impl Document {
+    /// Document version as string (e.g. "5.1.0.1")
+    #[napi(getter)]
+    pub fn version(&self) -> String {
+        self.inner.format().version
+    }
+
+    /// Source file format ("hwp5", "hwpx" or "hwpml")
+    #[napi(getter)]
+    pub fn format(&self) -> &'static str {
+        match self.inner.format().format {
+            SourceFormat::Hwp5 => "hwp5",
+            SourceFormat::Hwpx => "hwpx",
+            SourceFormat::Hwpml => "hwpml",
+            SourceFormat::Hwp3 => "hwp3",
+        }
+    }
+
+    /// Whether the document was read from a template file (.hwt, .hwtx)
+    #[napi(getter)]
+    pub fn is_template(&self) -> bool {
+        self.inner.is_template
+    }
+
+    /// Number of sections
+    #[napi(getter)]
+    pub fn section_count(&self) -> u32 {
+        self.inner.body_text.sections.len() as u32
+    }
+
+    /// Convert document to markdown
+    #[napi]
+    pub fn to_markdown(&self, options: Option<MarkdownConvertOptions>) -> String {
+        to_markdown(&self.inner, &markdown_options(options))
+    }
+
+    /// Stream markdown into a file without building the whole string in memory
+    #[napi]
+    pub fn write_markdown(
+        &self,
+        path: String,
+        options: Option<MarkdownConvertOptions>,
+    ) -> Result<()> {
+        let options = markdown_options(options);
+        write_file(&path, |writer| {
+            to_markdown_writer(&self.inner, &options, writer)
+        })
+    }
+
+    /// Convert document to HTML
+    #[napi]
+    pub fn to_html(&self, options: Option<HtmlConvertOptions>) -> String {
+        to_html(&self.inner, &html_options(options))
+    }
+
+    /// Stream HTML into a file without building the whole string in memory
+    #[napi]
+    pub fn write_html(&self, path: String, options: Option<HtmlConvertOptions>) -> Result<()> {
+        let options = html_options(options);
+        write_file(&path, |writer| {
+            to_html_writer(&self.inner, &options, writer)
+        })
+    }
+
+    /// Convert document to JSON
+    ///
+    /// Pass `legacyLayout: true` to emit the previous layout (schema 1).
+    #[napi]
+    pub fn to_json(&self, legacy_layout: Option<bool>) -> Result<String> {
+        let options = JsonOptions::default().with_legacy_layout(legacy_layout.unwrap_or(false));
+        self.inner.to_json_with_options(&options).map_err(to_js_err)
+    }
+
+    /// Style catalog as JSON: name, type, next style and resolved properties of every style
+    #[napi]
+    pub fn styles_json(&self) -> Result<String> {
+        self.inner.styles_json().map_err(to_js_err)
+    }
+
+    /// Plain text content, paragraphs separated by newlines unless `separator` is given
+    #[napi]
+    pub fn get_text(&self, options: Option<TextExtractOptions>) -> Result<String> {
+        let options = options.unwrap_or_default();
+        let mut text_options = TextOptions::default()
+            .with_keep_blank_paragraphs(options.keep_blank_paragraphs.unwrap_or(false))
+            .with_include_charts(options.include_charts.unwrap_or(false))
+            .with_content_filter(
+                ContentFilter::default()
+                    .with_hidden_text(options.exclude_hidden_text.unwrap_or(false))
+                    .with_private_info(options.exclude_private_info.unwrap_or(false))
+                    .with_invisible_chars(options.exclude_invisible_chars.unwrap_or(false)),
+            );
+        if let Some(separator) = options.separator {
+            text_options = text_options.with_separator(separator);
+        }
+        if let Some(trim) = options.trim {
+            let mode = TrimMode::from_name(&trim).ok_or_else(|| {
+                Error::new(
+                    Status::InvalidArg,
+                    format!("Unknown trim mode '{trim}' (expected 'both', 'end' or 'none')"),
+                )
+            })?;
+            text_options = text_options.with_trim(mode);
+        }
+        Ok(self.inner.text_with_options(&text_options))
+    }
+}
+
+/// Parse HWP/HWPX file from a Buffer
+#[napi]
+pub fn parse(data: Buffer) -> Result<Document> {
+    HwpParser::new()
+        .parse(&data)
+        .map(|doc| Document { inner: doc })
+        .map_err(to_js_err)
+}
+
+/// Parse HWP/HWPX file from a file path (.hwt/.hwtx templates set `isTemplate`)
+#[napi]
+pub fn parse_file(path: String) -> Result<Document> {
+    let data = std::fs::read(&path).map_err(|e| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to read file '{path}': {e}"),
+        )
+    })?;
+    HwpParser::new()
+        .parse(&data)
+        .map(|mut doc| {
+            doc.is_template = hwp_core::parser::is_template_path(&path);
+            Document { inner: doc }
+        })
+        .map_err(to_js_err)
+}
+
+/// Restore a document from JSON produced by `Document.toJson()`
+#[napi]
+pub fn from_json(json: String) -> Result<Document> {
+    HwpDocument::from_json(&json)
+        .map(|doc| Document { inner: doc })
+        .map_err(to_js_err)
+}
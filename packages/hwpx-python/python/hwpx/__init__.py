@@ -21,11 +21,76 @@ Example usage:
     >>> # Get plain text
     >>> text = doc.get_text()
     >>>
+    >>> # Walk paragraphs and tables
+    >>> for section in doc.sections:
+    ...     for paragraph in section.paragraphs:
+    ...         for table in paragraph.tables:
+    ...             print(table.rows[0][0].text)
+    >>>
+    >>> # Access embedded images
+    >>> for image in doc.images():
+    ...     print(image.name, image.mime_type, len(image.data))
+    >>> doc.extract_images("images/")
+    >>>
+    >>> # Save the preview thumbnail
+    >>> if doc.preview_image:
+    ...     open("thumb.png", "wb").write(doc.preview_image.data)
+    >>>
     >>> # Convert to JSON
     >>> json_str = doc.to_json()
+    >>>
+    >>> # Restore from (possibly edited) JSON
+    >>> doc = hwpx.from_json(json_str)
+    >>>
+    >>> # Read metadata and handle errors
+    >>> try:
+    ...     doc = hwpx.parse_file("secret.hwp")
+    ... except hwpx.HwpxPasswordRequiredError:
+    ...     print("password protected")
+    >>> print(doc.metadata)
+    >>>
+    >>> # Convert a whole directory
+    >>> report = hwpx.convert_directory("docs/", "out/", format="markdown", recursive=True)
+    >>> print(len(report["converted"]), len(report["failed"]))
 """
 
-from .hwpx import parse, parse_file, Document
+from .hwpx import (
+    parse,
+    parse_file,
+    parse_or_preview,
+    from_json,
+    convert_directory,
+    Document,
+    Image,
+    PreviewImage,
+    ParseResult,
+    Section,
+    Paragraph,
+    Table,
+    Cell,
+    HwpxError,
+    HwpxParseError,
+    HwpxUnsupportedFormatError,
+    HwpxPasswordRequiredError,
+)
 
-__all__ = ["parse", "parse_file", "Document"]
+__all__ = [
+    "parse",
+    "parse_file",
+    "parse_or_preview",
+    "from_json",
+    "convert_directory",
+    "Document",
+    "Image",
+    "PreviewImage",
+    "ParseResult",
+    "Section",
+    "Paragraph",
+    "Table",
+    "Cell",
+    "HwpxError",
+    "HwpxParseError",
+    "HwpxUnsupportedFormatError",
+    "HwpxPasswordRequiredError",
+]
 __version__ = "0.1.0"
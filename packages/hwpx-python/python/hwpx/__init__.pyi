@@ -1,5 +1,139 @@
 """Type stubs for hwpx module"""
-from typing import Optional
+from typing import Any, Callable, Dict, List, Literal, Optional, TypedDict
+
+class HwpxError(ValueError):
+    """Base class for all hwpx errors"""
+
+class HwpxParseError(HwpxError):
+    """The document is malformed and could not be parsed"""
+
+class HwpxUnsupportedFormatError(HwpxError):
+    """The file is not a supported HWP/HWPX format or version"""
+
+class HwpxPasswordRequiredError(HwpxError):
+    """The document is password protected"""
+
+class Image:
+    """Image embedded in a document"""
+
+    @property
+    def name(self) -> str:
+        """Get file name (e.g., 'image1.png')"""
+        ...
+
+    @property
+    def mime_type(self) -> str:
+        """Get MIME type (e.g., 'image/png')"""
+        ...
+
+    @property
+    def data(self) -> bytes:
+        """Get image bytes"""
+        ...
+
+class PreviewImage:
+    """Preview (thumbnail) image of a document"""
+
+    @property
+    def format(self) -> str:
+        """Get image format ('BMP', 'GIF', 'PNG', 'JPEG' or 'UNKNOWN')"""
+        ...
+
+    @property
+    def mime_type(self) -> str:
+        """Get MIME type (e.g., 'image/png')"""
+        ...
+
+    @property
+    def data(self) -> bytes:
+        """Get image bytes"""
+        ...
+
+class Cell:
+    """Table cell"""
+
+    @property
+    def text(self) -> str:
+        """Get cell text (paragraphs joined by newlines)"""
+        ...
+
+    @property
+    def row(self) -> int:
+        """Get row address"""
+        ...
+
+    @property
+    def col(self) -> int:
+        """Get column address"""
+        ...
+
+    @property
+    def row_span(self) -> int:
+        """Get number of merged rows"""
+        ...
+
+    @property
+    def col_span(self) -> int:
+        """Get number of merged columns"""
+        ...
+
+    @property
+    def paragraphs(self) -> List["Paragraph"]:
+        """Get paragraphs inside the cell"""
+        ...
+
+class Table:
+    """Table with cells addressed by row and column"""
+
+    @property
+    def row_count(self) -> int:
+        """Get number of rows"""
+        ...
+
+    @property
+    def col_count(self) -> int:
+        """Get number of columns"""
+        ...
+
+    @property
+    def rows(self) -> List[List[Cell]]:
+        """
+        Get cells grouped by row, ordered by column.
+
+        Merged cells appear once, in the row and column where they start.
+        """
+        ...
+
+class Paragraph:
+    """Paragraph of a section or table cell"""
+
+    @property
+    def text(self) -> str:
+        """Get paragraph text (excluding nested table text)"""
+        ...
+
+    @property
+    def tables(self) -> List[Table]:
+        """Get tables contained in the paragraph"""
+        ...
+
+    @property
+    def resolved_number(self) -> Optional[str]:
+        """Get computed outline number such as "1.2.3" (None for non-outline paragraphs)"""
+        ...
+
+class Section:
+    """Section of a document"""
+
+    @property
+    def index(self) -> int:
+        """Get section index"""
+        ...
+
+    @property
+    def paragraphs(self) -> List[Paragraph]:
+        """Get top-level paragraphs of the section"""
+        ...
 
 class Document:
     """HWP/HWPX Document wrapper"""
@@ -9,11 +143,52 @@ class Document:
         """Get document version as string (e.g., '5.1.0.1')"""
         ...
 
+    @property
+    def format(self) -> str:
+        """Get source file format ('hwp5', 'hwpx', 'hwpml' or 'hwp3')"""
+        ...
+
+    @property
+    def is_template(self) -> bool:
+        """Whether the document was read from a template file (.hwt, .hwtx)"""
+        ...
+
+    @property
+    def metadata(self) -> Optional[Dict[str, Optional[str]]]:
+        """
+        Get document metadata.
+
+        Returns:
+            Dict with 'title', 'author', 'subject', 'keywords', 'comments',
+            'last_saved_by', 'created' and 'modified' (each a string or None),
+            or None if the document stores no metadata.
+        """
+        ...
+
+    @property
+    def stats(self) -> Dict[str, Any]:
+        """
+        Get document statistics.
+
+        Returns:
+            Dict with 'sections', 'paragraphs', 'tables', 'images',
+            'characters', 'characters_without_spaces' and 'estimated_pages'
+            (each an int), and 'timings' (dict of parse phase name to
+            milliseconds, or None when the document was parsed without
+            profiling).
+        """
+        ...
+
     @property
     def section_count(self) -> int:
         """Get number of sections in the document"""
         ...
 
+    @property
+    def sections(self) -> List[Section]:
+        """Get sections of the document"""
+        ...
+
     def to_markdown(
         self,
         use_html: bool = True,
@@ -33,6 +208,27 @@ class Document:
         """
         ...
 
+    def write_markdown(
+        self,
+        path: str,
+        use_html: bool = True,
+        include_version: bool = True,
+        image_output_dir: Optional[str] = None,
+    ) -> None:
+        """
+        Stream markdown into a file without building the whole string in memory.
+
+        Args:
+            path: Output file path.
+            use_html: Whether to use HTML tags (default: True)
+            include_version: Whether to include version info (default: True)
+            image_output_dir: Directory to save images. If None, embeds as base64.
+
+        Raises:
+            HwpxError: If the file cannot be written.
+        """
+        ...
+
     def to_html(self, image_output_dir: Optional[str] = None) -> str:
         """
         Convert document to HTML format.
@@ -45,21 +241,127 @@ class Document:
         """
         ...
 
-    def to_json(self) -> str:
+    def write_html(self, path: str, image_output_dir: Optional[str] = None) -> None:
+        """
+        Stream HTML into a file without building the whole string in memory.
+
+        Args:
+            path: Output file path.
+            image_output_dir: Directory to save images. If None, embeds as base64.
+
+        Raises:
+            HwpxError: If the file cannot be written.
+        """
+        ...
+
+    def to_json(self, legacy_layout: bool = False) -> str:
         """
         Convert document to JSON format.
 
+        Args:
+            legacy_layout: Emit the previous layout (schema 1) without
+                'schema_version' (default: False)
+
         Returns:
             JSON string representation of the document structure.
         """
         ...
 
-    def get_text(self) -> str:
+    def styles_json(self) -> str:
+        """
+        Convert the style catalog to JSON.
+
+        Returns:
+            JSON array with one object per style: 'id', 'name',
+            'english_name', 'style_type' ("paragraph" or "character"),
+            'next_style', 'para_shape_id', 'char_shape_id' and 'properties'
+            (font, size, emphasis, alignment, spacing and margins).
+        """
+        ...
+
+    @property
+    def preview_image(self) -> Optional[PreviewImage]:
+        """Get the preview (thumbnail) image, or None if the document has no preview"""
+        ...
+
+    def images(self) -> List[Image]:
+        """
+        Get images embedded in the document.
+
+        Returns:
+            List of Image objects in BinData order.
+        """
+        ...
+
+    def extract_images(self, path: str) -> List[str]:
+        """
+        Save embedded images as files.
+
+        Args:
+            path: Output directory (created if missing).
+
+        Returns:
+            List of written file paths.
+
+        Raises:
+            HwpxError: If the directory or a file cannot be written.
+        """
+        ...
+
+    def get_text(
+        self,
+        separator: str = "\n",
+        trim: Literal["both", "end", "none"] = "both",
+        keep_blank: bool = False,
+        include_charts: bool = False,
+        exclude_hidden_text: bool = False,
+        exclude_private_info: bool = False,
+        exclude_invisible_chars: bool = False,
+    ) -> str:
         """
         Get plain text content from the document.
 
+        Args:
+            separator: String placed between paragraphs.
+            trim: "both" trims each paragraph, "end" keeps indentation, "none" keeps it as is.
+            keep_blank: Keep blank paragraphs as empty lines.
+            include_charts: Add chart titles, axis titles, legends and category labels
+                after the paragraph holding the chart.
+            exclude_hidden_text: Leave out hidden comments.
+            exclude_private_info: Leave out the text of personal information fields.
+            exclude_invisible_chars: Remove zero-width spaces, direction controls and other
+                invisible characters.
+
+        Returns:
+            Plain text content.
+
+        Raises:
+            ValueError: If `trim` is not a known mode.
+        """
+        ...
+
+    def chunks(
+        self,
+        max_chars: int = 1000,
+        overlap: int = 0,
+        max_tokens: Optional[int] = None,
+    ) -> List[Dict[str, Any]]:
+        """
+        Split the document into overlapping text chunks for retrieval pipelines.
+
+        Paragraphs are split into sentences and tables into rows; a paragraph or
+        table that fits in one chunk is never split. Headers and footers are left out.
+
+        Args:
+            max_chars: Maximum characters per chunk.
+            overlap: Maximum characters repeated from the end of the previous chunk,
+                in whole sentences.
+            max_tokens: Maximum whitespace-separated words per chunk, or None for no limit.
+
         Returns:
-            Plain text content with paragraphs separated by newlines.
+            List of dicts with 'text', 'char_count', 'token_count' and 'anchors'
+            (list of dicts with 'section_index', 'paragraph_index', 'nested_index'
+            and 'in_table').
         """
         ...
 
@@ -74,7 +376,52 @@ def parse(data: bytes) -> Document:
         Parsed Document object.
 
     Raises:
-        ValueError: If the file format is invalid or parsing fails.
+        HwpxUnsupportedFormatError: If the file is not a supported format or version.
+        HwpxPasswordRequiredError: If the document is password protected.
+        HwpxParseError: If the document is malformed.
+    """
+    ...
+
+class ParseResult:
+    """Result of `parse_or_preview`: the full document or only its preview text"""
+
+    @property
+    def is_degraded(self) -> bool:
+        """Whether only the preview text could be read"""
+        ...
+
+    @property
+    def document(self) -> Optional[Document]:
+        """Parsed document, or None when only the preview text could be read"""
+        ...
+
+    @property
+    def text(self) -> str:
+        """Plain text of the document, or the preview text when degraded"""
+        ...
+
+    @property
+    def error(self) -> Optional[str]:
+        """Why full parsing failed, or None when the document was read fully"""
+        ...
+
+def parse_or_preview(data: bytes) -> ParseResult:
+    """
+    Parse HWP/HWPX file from bytes, falling back to the preview text.
+
+    When the document body cannot be parsed, the preview text stored in the
+    file is returned instead and `ParseResult.is_degraded` is True.
+
+    Args:
+        data: File content as bytes.
+
+    Returns:
+        ParseResult object.
+
+    Raises:
+        HwpxUnsupportedFormatError: If the file is not a supported format or version.
+        HwpxPasswordRequiredError: If the document is password protected and has no preview text.
+        HwpxParseError: If the document is malformed and has no preview text.
     """
     ...
 
@@ -89,6 +436,73 @@ def parse_file(path: str) -> Document:
         Parsed Document object.
 
     Raises:
-        ValueError: If the file cannot be read or parsing fails.
+        OSError: If the file cannot be read.
+        HwpxUnsupportedFormatError: If the file is not a supported format or version.
+        HwpxPasswordRequiredError: If the document is password protected.
+        HwpxParseError: If the document is malformed.
+    """
+    ...
+
+def from_json(json: str) -> Document:
+    """
+    Restore a document from JSON produced by `Document.to_json()`.
+
+    Args:
+        json: JSON string.
+
+    Returns:
+        Document object.
+
+    Raises:
+        HwpxParseError: If the JSON does not describe a valid document.
+    """
+    ...
+
+class BatchItem(TypedDict):
+    input: str
+    output: str
+
+class BatchFailure(TypedDict):
+    input: str
+    error: str
+
+class BatchReport(TypedDict):
+    converted: List[BatchItem]
+    skipped: List[BatchItem]
+    failed: List[BatchFailure]
+
+def convert_directory(
+    input_dir: str,
+    output_dir: str,
+    format: Literal["markdown", "html", "json", "text", "hwpx"] = "markdown",
+    recursive: bool = False,
+    skip_existing: bool = False,
+    on_progress: Optional[Callable[[int, int, str, str], None]] = None,
+    on_error: Optional[Callable[[str, str], None]] = None,
+) -> BatchReport:
+    """
+    Convert every HWP/HWPX/HWPML file in a directory.
+
+    Files are written under `output_dir` at the same relative path with the
+    extension replaced. A file that fails to convert does not stop the run;
+    it is listed in the report and passed to `on_error`.
+
+    Args:
+        input_dir: Directory to read documents from.
+        output_dir: Directory to write converted files to (created when missing).
+        format: Output format.
+        recursive: Also convert files in subdirectories.
+        skip_existing: Skip files whose output already exists.
+        on_progress: Called as `on_progress(current, total, input, output)`
+            after each converted or skipped file.
+        on_error: Called as `on_error(input, message)` after each failed file.
+
+    Returns:
+        Report listing converted, skipped and failed files.
+
+    Raises:
+        ValueError: If `format` is not a known format.
+        HwpxParseError: If the input directory cannot be read or the output
+            directory cannot be created.
     """
     ...
@@ -1,19 +1,59 @@
 #![allow(clippy::useless_conversion)]
 
-use hwp_core::viewer::html::{to_html, HtmlOptions};
-use hwp_core::viewer::markdown::{to_markdown, MarkdownOptions};
-use hwp_core::{HwpDocument, HwpParser};
+use hwp_core::batch::{self, BatchItem, BatchOptions, Format};
+use hwp_core::chunking::{chunk_document, ChunkOptions};
+use hwp_core::document::bodytext::{Paragraph, Table, TableCell};
+use hwp_core::document::{DocumentImage, PreviewImage};
+use hwp_core::viewer::html::{to_html, to_html_writer, HtmlOptions};
+use hwp_core::viewer::markdown::{to_markdown, to_markdown_writer, MarkdownOptions};
+use hwp_core::{
+    ContentFilter, HwpDocument, HwpError, HwpParser, JsonOptions, ParseOutcome, SourceFormat,
+    TextOptions, TrimMode,
+};
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use std::fs::File;
+use std::io::{BufWriter, Write};
 
-/// Format version DWORD to "M.n.P.r" string
-/// Format: 0xMMnnPPrr (e.g., 0x05000300 = "5.0.3.0")
-fn format_version(version: u32) -> String {
-    let major = (version >> 24) & 0xFF;
-    let minor = (version >> 16) & 0xFF;
-    let patch = (version >> 8) & 0xFF;
-    let revision = version & 0xFF;
-    format!("{major}.{minor}.{patch}.{revision}")
+// Subclasses ValueError so existing `except ValueError` handlers keep working
+create_exception!(
+    hwpx,
+    HwpxError,
+    PyValueError,
+    "Base class for all hwpx errors."
+);
+create_exception!(
+    hwpx,
+    HwpxParseError,
+    HwpxError,
+    "The document is malformed and could not be parsed."
+);
+create_exception!(
+    hwpx,
+    HwpxUnsupportedFormatError,
+    HwpxError,
+    "The file is not a supported HWP/HWPX format or version."
+);
+create_exception!(
+    hwpx,
+    HwpxPasswordRequiredError,
+    HwpxError,
+    "The document is password protected."
+);
+
+/// Map a core error to the matching Python exception
+fn to_py_err(error: HwpError) -> PyErr {
+    let message = error.to_string();
+    match error.kind() {
+        HwpError::UnknownFormat
+        | HwpError::UnsupportedFormat { .. }
+        | HwpError::UnsupportedVersion { .. }
+        | HwpError::InvalidSignature { .. } => HwpxUnsupportedFormatError::new_err(message),
+        HwpError::PasswordRequired => HwpxPasswordRequiredError::new_err(message),
+        _ => HwpxParseError::new_err(message),
+    }
 }
 
 /// HWP/HWPX Document wrapper for Python
@@ -27,7 +67,80 @@ impl Document {
     /// Get document version as string
     #[getter]
     fn version(&self) -> String {
-        format_version(self.inner.file_header.version)
+        self.inner.format().version
+    }
+
+    /// Get source file format ("hwp5", "hwpx" or "hwpml")
+    #[getter]
+    fn format(&self) -> &'static str {
+        match self.inner.format().format {
+            SourceFormat::Hwp5 => "hwp5",
+            SourceFormat::Hwpx => "hwpx",
+            SourceFormat::Hwpml => "hwpml",
+            SourceFormat::Hwp3 => "hwp3",
+        }
+    }
+
+    /// Whether the document was read from a template file (.hwt, .hwtx)
+    #[getter]
+    fn is_template(&self) -> bool {
+        self.inner.is_template
+    }
+
+    /// Get document metadata
+    ///
+    /// Returns:
+    ///     Dict with title, author, subject, keywords, comments, last_saved_by,
+    ///     created and modified (each a string or None), or None if the
+    ///     document stores no metadata
+    #[getter]
+    fn metadata<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(info) = &self.inner.summary_information else {
+            return Ok(None);
+        };
+        let dict = PyDict::new_bound(py);
+        dict.set_item("title", &info.title)?;
+        dict.set_item("author", &info.author)?;
+        dict.set_item("subject", &info.subject)?;
+        dict.set_item("keywords", &info.keywords)?;
+        dict.set_item("comments", &info.comments)?;
+        dict.set_item("last_saved_by", &info.last_saved_by)?;
+        dict.set_item("created", &info.create_time)?;
+        dict.set_item("modified", &info.last_saved_time)?;
+        Ok(Some(dict))
+    }
+
+    /// Get document statistics
+    ///
+    /// Returns:
+    ///     Dict with sections, paragraphs, tables, images, characters,
+    ///     characters_without_spaces and estimated_pages (each an int), and
+    ///     timings (dict of parse phase name to milliseconds, or None when the
+    ///     document was parsed without profiling)
+    #[getter]
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = self.inner.stats();
+        let dict = PyDict::new_bound(py);
+        dict.set_item("sections", stats.sections)?;
+        dict.set_item("paragraphs", stats.paragraphs)?;
+        dict.set_item("tables", stats.tables)?;
+        dict.set_item("images", stats.images)?;
+        dict.set_item("characters", stats.characters)?;
+        dict.set_item("characters_without_spaces", stats.characters_without_spaces)?;
+        dict.set_item("estimated_pages", stats.estimated_pages)?;
+        let timings = stats
+            .profile
+            .map(|profile| -> PyResult<_> {
+                let timings = PyDict::new_bound(py);
+                for timing in &profile.phases {
+                    timings
+                        .set_item(timing.phase.name(), timing.duration.as_secs_f64() * 1000.0)?;
+                }
+                Ok(timings)
+            })
+            .transpose()?;
+        dict.set_item("timings", timings)?;
+        Ok(dict)
     }
 
     /// Get number of sections
@@ -36,6 +149,21 @@ impl Document {
         self.inner.body_text.sections.len()
     }
 
+    /// Get sections of the document
+    #[getter]
+    fn sections(&self) -> Vec<PySection> {
+        self.inner
+            .body_text
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(index, section)| PySection {
+                index,
+                inner: section.clone(),
+            })
+            .collect()
+    }
+
     /// Convert document to markdown
     ///
     /// Args:
@@ -48,17 +176,41 @@ impl Document {
     #[pyo3(signature = (use_html=true, include_version=true, image_output_dir=None))]
     fn to_markdown(
         &self,
+        py: Python<'_>,
         use_html: bool,
         include_version: bool,
         image_output_dir: Option<String>,
     ) -> String {
-        let options = MarkdownOptions {
-            image_output_dir,
-            use_html: Some(use_html),
-            include_version: Some(include_version),
-            include_page_info: None,
-        };
-        to_markdown(&self.inner, &options)
+        let options = markdown_options(use_html, include_version, image_output_dir);
+        // Release the GIL while converting so other Python threads can run
+        py.allow_threads(|| to_markdown(&self.inner, &options))
+    }
+
+    /// Stream markdown into a file without building the whole string in memory
+    ///
+    /// Args:
+    ///     path: Output file path
+    ///     use_html: Whether to use HTML tags (default: True)
+    ///     include_version: Whether to include version info (default: True)
+    ///     image_output_dir: Directory to save images (default: None, embeds as base64)
+    ///
+    /// Raises:
+    ///     HwpxError: If the file cannot be written
+    #[pyo3(signature = (path, use_html=true, include_version=true, image_output_dir=None))]
+    fn write_markdown(
+        &self,
+        py: Python<'_>,
+        path: &str,
+        use_html: bool,
+        include_version: bool,
+        image_output_dir: Option<String>,
+    ) -> PyResult<()> {
+        let options = markdown_options(use_html, include_version, image_output_dir);
+        py.allow_threads(|| {
+            write_file(path, |writer| {
+                to_markdown_writer(&self.inner, &options, writer)
+            })
+        })
     }
 
     /// Convert document to HTML
@@ -69,46 +221,583 @@ impl Document {
     /// Returns:
     ///     HTML string
     #[pyo3(signature = (image_output_dir=None))]
-    fn to_html(&self, image_output_dir: Option<String>) -> String {
-        let options = HtmlOptions {
-            image_output_dir,
-            html_output_dir: None,
-            include_version: Some(true),
-            include_page_info: None,
-            css_class_prefix: String::new(),
-        };
-        to_html(&self.inner, &options)
+    fn to_html(&self, py: Python<'_>, image_output_dir: Option<String>) -> String {
+        let options = html_options(image_output_dir);
+        py.allow_threads(|| to_html(&self.inner, &options))
+    }
+
+    /// Stream HTML into a file without building the whole string in memory
+    ///
+    /// Args:
+    ///     path: Output file path
+    ///     image_output_dir: Directory to save images (default: None, embeds as base64)
+    ///
+    /// Raises:
+    ///     HwpxError: If the file cannot be written
+    #[pyo3(signature = (path, image_output_dir=None))]
+    fn write_html(
+        &self,
+        py: Python<'_>,
+        path: &str,
+        image_output_dir: Option<String>,
+    ) -> PyResult<()> {
+        let options = html_options(image_output_dir);
+        py.allow_threads(|| {
+            write_file(path, |writer| to_html_writer(&self.inner, &options, writer))
+        })
     }
 
     /// Convert document to JSON
     ///
+    /// Args:
+    ///     legacy_layout: Emit the previous layout (schema 1) without
+    ///         `schema_version` (default: False)
+    ///
     /// Returns:
     ///     JSON string representation of the document
-    fn to_json(&self) -> PyResult<String> {
-        serde_json::to_string_pretty(&self.inner)
-            .map_err(|e| PyValueError::new_err(format!("JSON serialization error: {e}")))
+    #[pyo3(signature = (legacy_layout=false))]
+    fn to_json(&self, legacy_layout: bool) -> PyResult<String> {
+        let options = JsonOptions::default().with_legacy_layout(legacy_layout);
+        self.inner
+            .to_json_with_options(&options)
+            .map_err(|e| HwpxError::new_err(e.to_string()))
+    }
+
+    /// Convert the style catalog to JSON
+    ///
+    /// Returns:
+    ///     JSON array of styles with id, name, english_name, style_type,
+    ///     next_style, para_shape_id, char_shape_id and resolved properties
+    fn styles_json(&self) -> PyResult<String> {
+        self.inner
+            .styles_json()
+            .map_err(|e| HwpxError::new_err(e.to_string()))
+    }
+
+    /// Get the preview (thumbnail) image
+    ///
+    /// Returns:
+    ///     PreviewImage object, or None if the document has no preview
+    #[getter]
+    fn preview_image(&self) -> Option<PyPreviewImage> {
+        self.inner
+            .preview_image
+            .clone()
+            .map(|inner| PyPreviewImage { inner })
+    }
+
+    /// Get images embedded in the document
+    ///
+    /// Returns:
+    ///     List of Image objects in BinData order
+    fn images(&self) -> Vec<PyImage> {
+        self.inner
+            .images()
+            .into_iter()
+            .map(|image| PyImage { inner: image })
+            .collect()
+    }
+
+    /// Save embedded images as files
+    ///
+    /// Args:
+    ///     path: Output directory (created if missing)
+    ///
+    /// Returns:
+    ///     List of written file paths
+    ///
+    /// Raises:
+    ///     HwpxError: If the directory or a file cannot be written
+    fn extract_images(&self, path: &str) -> PyResult<Vec<String>> {
+        self.inner
+            .extract_images(path)
+            .map(|paths| {
+                paths
+                    .into_iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect()
+            })
+            .map_err(|e| HwpxError::new_err(e.to_string()))
     }
 
     /// Get plain text content from the document
-    fn get_text(&self) -> String {
-        let mut text_parts = Vec::new();
-
-        for section in &self.inner.body_text.sections {
-            for paragraph in &section.paragraphs {
-                for record in &paragraph.records {
-                    if let hwp_core::document::bodytext::ParagraphRecord::ParaText {
-                        text, ..
-                    } = record
-                    {
-                        if !text.trim().is_empty() {
-                            text_parts.push(text.trim().to_string());
-                        }
-                    }
-                }
+    ///
+    /// Args:
+    ///     separator: String placed between paragraphs (default: "\n")
+    ///     trim: "both", "end" (keeps indentation) or "none" (default: "both")
+    ///     keep_blank: Whether blank paragraphs are kept as empty lines (default: False)
+    ///     include_charts: Whether chart titles, axis titles, legends and category labels
+    ///         follow the paragraph holding the chart (default: False)
+    ///     exclude_hidden_text: Whether hidden comments are left out (default: False)
+    ///     exclude_private_info: Whether personal information fields are left out (default: False)
+    ///     exclude_invisible_chars: Whether zero-width spaces, direction controls and other
+    ///         invisible characters are removed (default: False)
+    ///
+    /// Returns:
+    ///     Plain text content
+    ///
+    /// Raises:
+    ///     ValueError: If `trim` is not a known mode
+    #[pyo3(signature = (
+        separator="\n",
+        trim="both",
+        keep_blank=false,
+        include_charts=false,
+        exclude_hidden_text=false,
+        exclude_private_info=false,
+        exclude_invisible_chars=false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn get_text(
+        &self,
+        separator: &str,
+        trim: &str,
+        keep_blank: bool,
+        include_charts: bool,
+        exclude_hidden_text: bool,
+        exclude_private_info: bool,
+        exclude_invisible_chars: bool,
+    ) -> PyResult<String> {
+        let trim = TrimMode::from_name(trim).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown trim mode '{trim}' (expected 'both', 'end' or 'none')"
+            ))
+        })?;
+        let options = TextOptions::default()
+            .with_separator(separator)
+            .with_trim(trim)
+            .with_keep_blank_paragraphs(keep_blank)
+            .with_include_charts(include_charts)
+            .with_content_filter(
+                ContentFilter::default()
+                    .with_hidden_text(exclude_hidden_text)
+                    .with_private_info(exclude_private_info)
+                    .with_invisible_chars(exclude_invisible_chars),
+            );
+        Ok(self.inner.text_with_options(&options))
+    }
+
+    /// Split the document into overlapping text chunks for retrieval pipelines
+    ///
+    /// Paragraphs are split into sentences and tables into rows; a paragraph
+    /// or table that fits in one chunk is never split. Headers and footers are
+    /// left out.
+    ///
+    /// Args:
+    ///     max_chars: Maximum characters per chunk (default: 1000)
+    ///     overlap: Maximum characters repeated from the end of the previous
+    ///         chunk, in whole sentences (default: 0)
+    ///     max_tokens: Maximum whitespace-separated words per chunk
+    ///         (default: None, no limit)
+    ///
+    /// Returns:
+    ///     List of dicts with text, char_count, token_count and anchors (list
+    ///     of dicts with section_index, paragraph_index, nested_index and
+    ///     in_table)
+    #[pyo3(signature = (max_chars=1000, overlap=0, max_tokens=None))]
+    fn chunks<'py>(
+        &self,
+        py: Python<'py>,
+        max_chars: usize,
+        overlap: usize,
+        max_tokens: Option<usize>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let mut options = ChunkOptions::default()
+            .with_max_chars(max_chars)
+            .with_overlap(overlap);
+        if let Some(max_tokens) = max_tokens {
+            options = options.with_max_tokens(max_tokens);
+        }
+        chunk_document(&self.inner, &options)
+            .into_iter()
+            .map(|chunk| {
+                let anchors = chunk
+                    .anchors
+                    .iter()
+                    .map(|anchor| {
+                        let dict = PyDict::new_bound(py);
+                        dict.set_item("section_index", anchor.location.section_index)?;
+                        dict.set_item("paragraph_index", anchor.location.paragraph_index)?;
+                        dict.set_item("nested_index", anchor.location.nested_index)?;
+                        dict.set_item("in_table", anchor.in_table)?;
+                        Ok(dict)
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                let dict = PyDict::new_bound(py);
+                dict.set_item("text", chunk.text)?;
+                dict.set_item("char_count", chunk.char_count)?;
+                dict.set_item("token_count", chunk.token_count)?;
+                dict.set_item("anchors", anchors)?;
+                Ok(dict)
+            })
+            .collect()
+    }
+}
+
+/// Markdown options shared by `to_markdown` and `write_markdown`
+fn markdown_options(
+    use_html: bool,
+    include_version: bool,
+    image_output_dir: Option<String>,
+) -> MarkdownOptions {
+    MarkdownOptions {
+        image_output_dir,
+        image_sink: None,
+        use_html: Some(use_html),
+        include_version: Some(include_version),
+        include_page_info: None,
+        include_references: None,
+        include_toc: None,
+        line_break_mode: None,
+        preserve_alignment: None,
+        inline_controls: Default::default(),
+        image_max_dimension: None,
+        image_format: None,
+        flavor: None,
+        include_source_anchors: None,
+        strip_page_fields: None,
+        content_filter: None,
+        deterministic: false,
+    }
+}
+
+/// HTML options shared by `to_html` and `write_html`
+fn html_options(image_output_dir: Option<String>) -> HtmlOptions {
+    HtmlOptions {
+        image_output_dir,
+        image_sink: None,
+        html_output_dir: None,
+        include_version: Some(true),
+        include_page_info: None,
+        include_references: None,
+        include_text_colors: None,
+        include_source_anchors: None,
+        include_headers_footers: None,
+        master_pages: None,
+        content_filter: None,
+        deterministic: false,
+        font_map: Default::default(),
+        inline_controls: Default::default(),
+        accessibility: false,
+        image_max_dimension: None,
+        image_format: None,
+        css_class_prefix: String::new(),
+    }
+}
+
+/// Create `path` and stream output into it through a buffered writer
+fn write_file(
+    path: &str,
+    write: impl FnOnce(&mut BufWriter<File>) -> std::io::Result<()>,
+) -> PyResult<()> {
+    let result = File::create(path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        write(&mut writer)?;
+        writer.flush()
+    });
+    result.map_err(|e| HwpxError::new_err(format!("Failed to write file '{path}': {e}")))
+}
+
+/// Image embedded in a document
+#[pyclass(name = "Image")]
+struct PyImage {
+    inner: DocumentImage,
+}
+
+#[pymethods]
+impl PyImage {
+    /// Get file name (e.g., "image1.png")
+    #[getter]
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Get MIME type (e.g., "image/png")
+    #[getter]
+    fn mime_type(&self) -> &str {
+        &self.inner.mime_type
+    }
+
+    /// Get image bytes
+    #[getter]
+    fn data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.inner.data)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Image name={:?} mime_type={:?} size={}>",
+            self.inner.name,
+            self.inner.mime_type,
+            self.inner.data.len()
+        )
+    }
+}
+
+/// Preview (thumbnail) image of a document
+#[pyclass(name = "PreviewImage")]
+struct PyPreviewImage {
+    inner: PreviewImage,
+}
+
+#[pymethods]
+impl PyPreviewImage {
+    /// Get image format ("BMP", "GIF", "PNG", "JPEG" or "UNKNOWN")
+    #[getter]
+    fn format(&self) -> &str {
+        &self.inner.format
+    }
+
+    /// Get MIME type (e.g., "image/png")
+    #[getter]
+    fn mime_type(&self) -> &'static str {
+        self.inner.mime_type()
+    }
+
+    /// Get image bytes
+    #[getter]
+    fn data<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let data = self.inner.bytes().map_err(to_py_err)?;
+        Ok(PyBytes::new_bound(py, &data))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<PreviewImage format={:?} mime_type={:?}>",
+            self.inner.format,
+            self.inner.mime_type()
+        )
+    }
+}
+
+/// Section of a document
+#[pyclass(name = "Section")]
+struct PySection {
+    index: usize,
+    inner: hwp_core::Section,
+}
+
+#[pymethods]
+impl PySection {
+    /// Get section index
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get top-level paragraphs of the section
+    #[getter]
+    fn paragraphs(&self) -> Vec<PyParagraph> {
+        self.inner
+            .paragraphs
+            .iter()
+            .map(|paragraph| PyParagraph {
+                inner: paragraph.clone(),
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Section index={} paragraphs={}>",
+            self.index,
+            self.inner.paragraphs.len()
+        )
+    }
+}
+
+/// Paragraph of a section or table cell
+#[pyclass(name = "Paragraph")]
+struct PyParagraph {
+    inner: Paragraph,
+}
+
+#[pymethods]
+impl PyParagraph {
+    /// Get paragraph text (excluding nested table text)
+    #[getter]
+    fn text(&self) -> String {
+        self.inner.text()
+    }
+
+    /// Get tables contained in the paragraph
+    #[getter]
+    fn tables(&self) -> Vec<PyTable> {
+        self.inner
+            .tables()
+            .into_iter()
+            .map(|table| PyTable {
+                inner: table.clone(),
+            })
+            .collect()
+    }
+
+    /// Get computed outline number such as "1.2.3" (None for non-outline paragraphs)
+    #[getter]
+    fn resolved_number(&self) -> Option<String> {
+        self.inner.resolved_number().map(str::to_string)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Paragraph text={:?}>", self.inner.text())
+    }
+}
+
+/// Table with cells addressed by row and column
+#[pyclass(name = "Table")]
+struct PyTable {
+    inner: Table,
+}
+
+#[pymethods]
+impl PyTable {
+    /// Get number of rows
+    #[getter]
+    fn row_count(&self) -> u16 {
+        self.inner.attributes.row_count
+    }
+
+    /// Get number of columns
+    #[getter]
+    fn col_count(&self) -> u16 {
+        self.inner.attributes.col_count
+    }
+
+    /// Get cells grouped by row, ordered by column
+    ///
+    /// Merged cells appear once, in the row and column where they start.
+    #[getter]
+    fn rows(&self) -> Vec<Vec<PyCell>> {
+        let mut rows: Vec<Vec<PyCell>> = (0..self.inner.attributes.row_count)
+            .map(|_| Vec::new())
+            .collect();
+        let mut cells: Vec<&TableCell> = self.inner.cells.iter().collect();
+        cells.sort_by_key(|cell| {
+            (
+                cell.cell_attributes.row_address,
+                cell.cell_attributes.col_address,
+            )
+        });
+        for cell in cells {
+            let row = cell.cell_attributes.row_address as usize;
+            if rows.len() <= row {
+                rows.resize_with(row + 1, Vec::new);
             }
+            rows[row].push(PyCell {
+                inner: cell.clone(),
+            });
         }
+        rows
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Table rows={} cols={}>",
+            self.inner.attributes.row_count, self.inner.attributes.col_count
+        )
+    }
+}
+
+/// Table cell
+#[pyclass(name = "Cell")]
+struct PyCell {
+    inner: TableCell,
+}
+
+#[pymethods]
+impl PyCell {
+    /// Get cell text (paragraphs joined by newlines)
+    #[getter]
+    fn text(&self) -> String {
+        self.inner.text()
+    }
+
+    /// Get row address
+    #[getter]
+    fn row(&self) -> u16 {
+        self.inner.cell_attributes.row_address
+    }
+
+    /// Get column address
+    #[getter]
+    fn col(&self) -> u16 {
+        self.inner.cell_attributes.col_address
+    }
+
+    /// Get number of merged rows
+    #[getter]
+    fn row_span(&self) -> u16 {
+        self.inner.cell_attributes.row_span
+    }
+
+    /// Get number of merged columns
+    #[getter]
+    fn col_span(&self) -> u16 {
+        self.inner.cell_attributes.col_span
+    }
+
+    /// Get paragraphs inside the cell
+    #[getter]
+    fn paragraphs(&self) -> Vec<PyParagraph> {
+        self.inner
+            .paragraphs
+            .iter()
+            .map(|paragraph| PyParagraph {
+                inner: paragraph.clone(),
+            })
+            .collect()
+    }
 
-        text_parts.join("\n")
+    fn __repr__(&self) -> String {
+        format!(
+            "<Cell row={} col={} text={:?}>",
+            self.inner.cell_attributes.row_address,
+            self.inner.cell_attributes.col_address,
+            self.inner.text()
+        )
+    }
+}
+
+/// Result of `parse_or_preview`: the full document or only its preview text
+#[pyclass(name = "ParseResult")]
+struct PyParseResult {
+    document: Option<HwpDocument>,
+    text: String,
+    error: Option<String>,
+}
+
+#[pymethods]
+impl PyParseResult {
+    /// Whether only the preview text could be read
+    #[getter]
+    fn is_degraded(&self) -> bool {
+        self.document.is_none()
+    }
+
+    /// Parsed document, or None when only the preview text could be read
+    #[getter]
+    fn document(&self) -> Option<Document> {
+        self.document.clone().map(|inner| Document { inner })
+    }
+
+    /// Plain text of the document, or the preview text when degraded
+    #[getter]
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Why full parsing failed, or None when the document was read fully
+    #[getter]
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ParseResult is_degraded={} text_length={}>",
+            self.document.is_none(),
+            self.text.chars().count()
+        )
     }
 }
 
@@ -121,18 +810,62 @@ impl Document {
 ///     Document object
 ///
 /// Raises:
-///     ValueError: If the file format is invalid or parsing fails
+///     HwpxUnsupportedFormatError: If the file is not a supported format or version
+///     HwpxPasswordRequiredError: If the document is password protected
+///     HwpxParseError: If the document is malformed
 #[pyfunction]
-fn parse(data: &[u8]) -> PyResult<Document> {
-    let parser = HwpParser::new();
-    match parser.parse(data) {
-        Ok(doc) => Ok(Document { inner: doc }),
-        Err(e) => Err(PyValueError::new_err(format!("Parse error: {e}"))),
-    }
+fn parse(py: Python<'_>, data: &[u8]) -> PyResult<Document> {
+    // Release the GIL while parsing so other Python threads can run
+    py.allow_threads(|| parse_bytes(data))
+}
+
+/// Parse bytes into a Document (called without the GIL held)
+fn parse_bytes(data: &[u8]) -> PyResult<Document> {
+    HwpParser::new()
+        .parse(data)
+        .map(|doc| Document { inner: doc })
+        .map_err(to_py_err)
+}
+
+/// Parse HWP/HWPX file from bytes, falling back to the preview text
+///
+/// When the document body cannot be parsed, the preview text stored in the
+/// file is returned instead and `ParseResult.is_degraded` is True.
+///
+/// Args:
+///     data: File content as bytes
+///
+/// Returns:
+///     ParseResult object
+///
+/// Raises:
+///     HwpxUnsupportedFormatError: If the file is not a supported format or version
+///     HwpxPasswordRequiredError: If the document is password protected and has no preview text
+///     HwpxParseError: If the document is malformed and has no preview text
+#[pyfunction]
+fn parse_or_preview(py: Python<'_>, data: &[u8]) -> PyResult<PyParseResult> {
+    py.allow_threads(|| {
+        let outcome = HwpParser::new().parse_or_preview(data).map_err(to_py_err)?;
+        let text = outcome.text();
+        Ok(match outcome {
+            ParseOutcome::Full(document) => PyParseResult {
+                document: Some(document),
+                text,
+                error: None,
+            },
+            ParseOutcome::PreviewOnly { error, .. } => PyParseResult {
+                document: None,
+                text,
+                error: Some(error.to_string()),
+            },
+        })
+    })
 }
 
 /// Parse HWP/HWPX file from file path
 ///
+/// Template files (.hwt, .hwtx) are marked with `Document.is_template`.
+///
 /// Args:
 ///     path: Path to the HWP/HWPX file
 ///
@@ -140,12 +873,135 @@ fn parse(data: &[u8]) -> PyResult<Document> {
 ///     Document object
 ///
 /// Raises:
-///     ValueError: If the file cannot be read or parsing fails
+///     OSError: If the file cannot be read
+///     HwpxUnsupportedFormatError: If the file is not a supported format or version
+///     HwpxPasswordRequiredError: If the document is password protected
+///     HwpxParseError: If the document is malformed
+#[pyfunction]
+fn parse_file(py: Python<'_>, path: &str) -> PyResult<Document> {
+    py.allow_threads(|| {
+        let data = std::fs::read(path)?;
+        let mut document = parse_bytes(&data)?;
+        document.inner.is_template = hwp_core::parser::is_template_path(path);
+        Ok(document)
+    })
+}
+
+/// Restore a document from JSON produced by `Document.to_json()`
+///
+/// Args:
+///     json: JSON string
+///
+/// Returns:
+///     Document object
+///
+/// Raises:
+///     HwpxParseError: If the JSON does not describe a valid document
 #[pyfunction]
-fn parse_file(path: &str) -> PyResult<Document> {
-    let data = std::fs::read(path)
-        .map_err(|e| PyValueError::new_err(format!("Failed to read file: {e}")))?;
-    parse(&data)
+fn from_json(json: &str) -> PyResult<Document> {
+    HwpDocument::from_json(json)
+        .map(|doc| Document { inner: doc })
+        .map_err(|e| HwpxParseError::new_err(e.to_string()))
+}
+
+/// Convert every HWP/HWPX/HWPML file in a directory
+///
+/// Files are written under `output_dir` at the same relative path with the extension
+/// replaced. A file that fails to convert does not stop the run; it is listed in the
+/// report and passed to `on_error`.
+///
+/// Args:
+///     input_dir: Directory to read documents from
+///     output_dir: Directory to write converted files to (created when missing)
+///     format: "markdown", "html", "json", "text" or "hwpx" (default: "markdown")
+///     recursive: Also convert files in subdirectories (default: False)
+///     skip_existing: Skip files whose output already exists (default: False)
+///     on_progress: Called as `on_progress(current, total, input, output)` after each
+///         converted or skipped file
+///     on_error: Called as `on_error(input, message)` after each failed file
+///
+/// Returns:
+///     Dict with "converted", "skipped" and "failed" lists
+///
+/// Raises:
+///     ValueError: If `format` is not a known format
+///     HwpxParseError: If the input directory cannot be read or the output directory
+///         cannot be created
+#[pyfunction]
+#[pyo3(signature = (input_dir, output_dir, format="markdown", recursive=false, skip_existing=false, on_progress=None, on_error=None))]
+#[allow(clippy::too_many_arguments)]
+fn convert_directory<'py>(
+    py: Python<'py>,
+    input_dir: &str,
+    output_dir: &str,
+    format: &str,
+    recursive: bool,
+    skip_existing: bool,
+    on_progress: Option<PyObject>,
+    on_error: Option<PyObject>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let format = Format::from_name(format)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown format: {format}")))?;
+    let mut options = BatchOptions::default()
+        .with_recursive(recursive)
+        .with_skip_existing(skip_existing);
+    if let Some(callback) = on_progress {
+        options = options.with_on_progress(move |p| {
+            Python::with_gil(|py| {
+                let args = (
+                    p.current,
+                    p.total,
+                    p.input.to_string_lossy(),
+                    p.output.to_string_lossy(),
+                );
+                if let Err(err) = callback.call1(py, args) {
+                    err.write_unraisable_bound(py, None);
+                }
+            })
+        });
+    }
+    if let Some(callback) = on_error {
+        options = options.with_on_error(move |f| {
+            Python::with_gil(|py| {
+                let args = (f.input.to_string_lossy(), f.error.to_string());
+                if let Err(err) = callback.call1(py, args) {
+                    err.write_unraisable_bound(py, None);
+                }
+            })
+        });
+    }
+
+    let report = py
+        .allow_threads(|| batch::convert_dir(input_dir, output_dir, format, &options))
+        .map_err(to_py_err)?;
+
+    let items = |items: &[BatchItem]| -> PyResult<Vec<Bound<'py, PyDict>>> {
+        items
+            .iter()
+            .map(|item| {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("input", item.input.to_string_lossy())?;
+                dict.set_item("output", item.output.to_string_lossy())?;
+                Ok(dict)
+            })
+            .collect()
+    };
+    let failed = report
+        .failed
+        .iter()
+        .map(|failure| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("input", failure.input.to_string_lossy())?;
+            dict.set_item("error", failure.error.to_string())?;
+            Ok(dict)
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("converted", items(&report.converted)?)?;
+    dict.set_item("skipped", items(&report.skipped)?)?;
+    dict.set_item("failed", failed)?;
+    Ok(dict)
 }
 
 /// hwpx - Python bindings for HWP/HWPX document parser
@@ -158,10 +1014,33 @@ fn parse_file(path: &str) -> PyResult<Document> {
 ///     >>> print(doc.to_markdown())
 ///     >>> print(doc.to_html())
 ///     >>> print(doc.get_text())
+///     >>> for section in doc.sections:
+///     ...     for paragraph in section.paragraphs:
+///     ...         print(paragraph.text)
 #[pymodule]
 fn hwpx(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse, m)?)?;
     m.add_function(wrap_pyfunction!(parse_file, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_or_preview, m)?)?;
+    m.add_function(wrap_pyfunction!(from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_directory, m)?)?;
+    m.add("HwpxError", m.py().get_type_bound::<HwpxError>())?;
+    m.add("HwpxParseError", m.py().get_type_bound::<HwpxParseError>())?;
+    m.add(
+        "HwpxUnsupportedFormatError",
+        m.py().get_type_bound::<HwpxUnsupportedFormatError>(),
+    )?;
+    m.add(
+        "HwpxPasswordRequiredError",
+        m.py().get_type_bound::<HwpxPasswordRequiredError>(),
+    )?;
     m.add_class::<Document>()?;
+    m.add_class::<PyImage>()?;
+    m.add_class::<PyPreviewImage>()?;
+    m.add_class::<PyParseResult>()?;
+    m.add_class::<PySection>()?;
+    m.add_class::<PyParagraph>()?;
+    m.add_class::<PyTable>()?;
+    m.add_class::<PyCell>()?;
     Ok(())
 }